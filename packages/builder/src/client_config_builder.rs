@@ -1,12 +1,21 @@
-use std::{collections::HashMap};
+use std::{collections::HashMap, path::PathBuf, sync::{Arc, Mutex}};
 
 use polywrap_core::{
+    archive::ArchiveFileReader,
     client::{ClientConfig, UriRedirect},
     env::{Env,Envs},
     resolvers::{uri_resolution_context::{UriWrapper, UriPackage}, uri_resolver_like::UriResolverLike}, 
-    uri::Uri
+    uri::Uri,
+    retry::{RetryPolicies, RetryPolicy},
+    circuit_breaker::CircuitBreakerConfig,
+    quota::{QuotaPolicies, QuotaPolicy},
+    permissions::PermissionPolicy,
+    integrity::IntegrityPolicy
 };
 
+use polywrap_resolvers::{extendable_uri_resolver::ExtensionOptions, lockfile::{Lockfile, LockfileMode}, wrapper_cache::WrapperCache};
+use polywrap_wasm::wasm_package::WasmPackage;
+
 use crate::{helpers::{merge, add_default, build_resolver}, types::{BuilderConfig, ClientBuilder, ClientConfigHandler}};
 
 impl BuilderConfig {
@@ -20,7 +29,16 @@ impl BuilderConfig {
                 wrappers: None,
                 packages: None,
                 redirects: None,
-                resolvers: None
+                resolvers: None,
+                validate_args: None,
+                retry_policies: None,
+                circuit_breaker: None,
+                quotas: None,
+                permissions: None,
+                wrapper_cache: None,
+                lockfile: None,
+                integrity: None,
+                extension_options: None
             }
         }
     }
@@ -32,7 +50,16 @@ impl BuilderConfig {
             wrappers: self.wrappers,
             packages: self.packages,
             redirects: self.redirects,
-            resolvers: self.resolvers
+            resolvers: self.resolvers,
+            validate_args: self.validate_args,
+            retry_policies: self.retry_policies,
+            circuit_breaker: self.circuit_breaker,
+            quotas: self.quotas,
+            permissions: self.permissions,
+            wrapper_cache: self.wrapper_cache,
+            lockfile: self.lockfile,
+            integrity: self.integrity,
+            extension_options: self.extension_options
         }
     }
 }
@@ -69,6 +96,42 @@ impl ClientBuilder for BuilderConfig {
             self.add_resolvers(resolvers);
         }
 
+        if let Some(validate_args) = config.validate_args {
+            self.set_validate_args(validate_args);
+        }
+
+        if let Some(retry_policies) = config.retry_policies {
+            self.add_retry_policies(retry_policies);
+        }
+
+        if let Some(circuit_breaker) = config.circuit_breaker {
+            self.set_circuit_breaker(circuit_breaker);
+        }
+
+        if let Some(quotas) = config.quotas {
+            self.add_quotas(quotas);
+        }
+
+        if let Some(permissions) = config.permissions {
+            self.set_permissions(permissions);
+        }
+
+        if let Some(cache) = config.wrapper_cache {
+            self.set_wrapper_cache(cache);
+        }
+
+        if let Some((lockfile, mode)) = config.lockfile {
+            self.set_lockfile(lockfile, mode);
+        }
+
+        if let Some(integrity) = config.integrity {
+            self.set_integrity_policy(integrity);
+        }
+
+        if let Some(options) = config.extension_options {
+            self.set_extension_options(options);
+        }
+
         self
     }
 
@@ -257,6 +320,11 @@ impl ClientBuilder for BuilderConfig {
         self
     }
 
+    // The archive is read when the wrapper is first resolved, not extracted
+    fn add_archive(&mut self, uri: Uri, path: PathBuf) -> &mut Self {
+        let package = WasmPackage::new(Arc::new(ArchiveFileReader::from_path(path)), None, None);
+        self.add_package(UriPackage { uri, package: Arc::new(Mutex::new(package)) })
+    }
 
     fn add_redirect(&mut self, from: Uri, to: Uri) -> &mut Self {
         let redirect = UriRedirect { from: from.clone(), to };
@@ -313,6 +381,76 @@ impl ClientBuilder for BuilderConfig {
         }
         self
     }
+
+    fn set_validate_args(&mut self, validate_args: bool) -> &mut Self {
+        self.validate_args = Some(validate_args);
+        self
+    }
+
+    fn set_retry_policy(&mut self, uri: Uri, method: Option<&str>, policy: RetryPolicy) -> &mut Self {
+        self.retry_policies
+            .get_or_insert_with(RetryPolicies::new)
+            .set_policy(&uri, method, policy);
+        self
+    }
+
+    fn add_retry_policies(&mut self, policies: RetryPolicies) -> &mut Self {
+        self.retry_policies
+            .get_or_insert_with(RetryPolicies::new)
+            .extend(policies);
+        self
+    }
+
+    fn set_circuit_breaker(&mut self, config: CircuitBreakerConfig) -> &mut Self {
+        self.circuit_breaker = Some(config);
+        self
+    }
+
+    fn set_uri_quota(&mut self, uri: Uri, policy: QuotaPolicy) -> &mut Self {
+        self.quotas
+            .get_or_insert_with(QuotaPolicies::new)
+            .set_uri_policy(&uri, policy);
+        self
+    }
+
+    fn set_authority_quota(&mut self, pattern: &str, policy: QuotaPolicy) -> &mut Self {
+        self.quotas
+            .get_or_insert_with(QuotaPolicies::new)
+            .set_authority_policy(pattern, policy);
+        self
+    }
+
+    fn add_quotas(&mut self, quotas: QuotaPolicies) -> &mut Self {
+        self.quotas
+            .get_or_insert_with(QuotaPolicies::new)
+            .extend(quotas);
+        self
+    }
+
+    fn set_permissions(&mut self, permissions: PermissionPolicy) -> &mut Self {
+        self.permissions = Some(permissions);
+        self
+    }
+
+    fn set_wrapper_cache(&mut self, cache: WrapperCache) -> &mut Self {
+        self.wrapper_cache = Some(cache);
+        self
+    }
+
+    fn set_lockfile(&mut self, lockfile: Lockfile, mode: LockfileMode) -> &mut Self {
+        self.lockfile = Some((lockfile, mode));
+        self
+    }
+
+    fn set_integrity_policy(&mut self, integrity: IntegrityPolicy) -> &mut Self {
+        self.integrity = Some(integrity);
+        self
+    }
+
+    fn set_extension_options(&mut self, options: ExtensionOptions) -> &mut Self {
+        self.extension_options = Some(options);
+        self
+    }
 }
 
 impl ClientConfigHandler for BuilderConfig {
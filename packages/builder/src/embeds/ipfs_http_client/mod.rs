@@ -1,24 +1,5 @@
-use std::sync::Arc;
-use base64::{decode, DecodeError};
-use polywrap_core::file_reader::SimpleFileReader;
-use polywrap_wasm::wasm_package::WasmPackage;
-
-fn wrap_wasm() -> Result<Vec<u8>, DecodeError> {
-  decode(
-  "AGFzbQEAAAABZhFgAX8Bf2ACf38AYAJ/fwF/YAN/f38AYAABf2ADf39/AX9gAX8AYAR/f39/AGAEf39/fwF/YAAAYAF/AX5gBn9/f39/fwBgA39+fwBgAX4Bf2AGf39/f39/AX9gAnx/AXxgAX8BfAKOAgoEd3JhcAxfX3dyYXBfYWJvcnQACwR3cmFwEl9fd3JhcF9pbnZva2VfYXJncwABBHdyYXAQX193cmFwX3N1Ymludm9rZQAOBHdyYXAaX193cmFwX3N1Ymludm9rZV9lcnJvcl9sZW4ABAR3cmFwFl9fd3JhcF9zdWJpbnZva2VfZXJyb3IABgR3cmFwG19fd3JhcF9zdWJpbnZva2VfcmVzdWx0X2xlbgAEBHdyYXAXX193cmFwX3N1Ymludm9rZV9yZXN1bHQABgR3cmFwFF9fd3JhcF9pbnZva2VfcmVzdWx0AAEEd3JhcBNfX3dyYXBfaW52b2tlX2Vycm9yAAEDZW52Bm1lbW9yeQIAAQO0AbIBAAcHAAIBAQUEAwIAAAMDCAACAggCAAIDAAADAAICBQIDAAAAAAAAAAIDAQcABgoAAAAKDA0ABAAEAAUDBAABAQEBAQUFAgUAAAACAgACCAIIAgMAAQIFAAQABAACAAAGAwACAAAAAQMADxAAAAICAAECAAAEAAQFAQUAAQECAAAABAADAAAAAQECBAECBQABAQEBAQEBAAMDAAAAAAEBBwEGAAACAgMJAwMDAwMGCQYJBAQEAXAAEwYzCn8BQQALfwFBAAt/AUEAC38BQQALfwFBAAt/AUEAC34BQgALfwFBAAt/AUEAC38BQQALB54BCAxfd3JhcF9pbnZva2UAlgEJd3JhcEFib3J0AAsGbWVtb3J5AgAVYXN5bmNpZnlfc3RhcnRfdW53aW5kALYBFGFzeW5jaWZ5X3N0b3BfdW53aW5kALcBFWFzeW5jaWZ5X3N0YXJ0X3Jld2luZAC4ARRhc3luY2lmeV9zdG9wX3Jld2luZAC5ARJhc3luY2lmeV9nZXRfc3RhdGUAugEIArABCSEBAEEBCxJHR0dHSVBQXnqGAYcBiAGQAZIBhwGIAZABlQEK760HsgGYBQEFfyMIQQJGBEAjCSMJKAIAQQxrNgIAIwkoAgAiAygCACEAIAMoAgQhASADKAIIIQMLAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQILIwhFBEACQAJAIwBBAWsOAwEBAQALAAsgACIDIANBFGsoAhBqIQQDQCADIARJBEAgAy8BACIFQYABSQR/IAFBAWoFIAVBgBBJBH8gAUECagUgBUGA+ANxQYCwA0YgA0ECaiAESXEEQCADLwECQYD4A3FBgLgDRgRAIAFBBGohASADQQRqIQMMBQsLIAFBA2oLCyEBIANBAmohAwwBCwsLIAJBACMIG0UEQCABQQAQDSECQQAjCEEBRg0BGiACIQMLIwhFBEAgAEEUaygCEEEBdkEBdCAAaiEFIAMhAQNAIAAgBUkEQCAALwEAIgJBgAFJBH8gASACOgAAIAFBAWoFIAJBgBBJBH8gASACQQZ2QcABciACQT9xQYABckEIdHI7AQAgAUECagUgAkGAuANJIAUgAEECaktxIAJBgPADcUGAsANGcQRAIAAvAQIiBEGA+ANxQYC4A0YEQCABIAJB/wdxQQp0QYCABGogBEH/B3FyIgRBP3FBgAFyQRh0IARBBnZBP3FBgAFyQRB0ciAEQQx2QT9xQYABckEIdHIgBEESdkHwAXJyNgIAIAFBBGohASAAQQRqIQAMBQsLIAEgAkEMdkHgAXIgAkEGdkE/cUGAAXJBCHRyOwEAIAEgAkE/cUGAAXI6AAIgAUEDagsLIQEgAEECaiEADAELCyADDwsACyECIwkoAgAgAjYCACMJIwkoAgBBBGo2AgAjCSgCACICIAA2AgAgAiABNgIEIAIgAzYCCCMJIwkoAgBBDGo2AgBBAAvTAgEEfyMIQQJGBEAjCSMJKAIAQRhrNgIAIwkoAgAiBCgCACEAIAQoAgghAiAEKAIMIQMgBCgCECEFIAQoAhQhByAEKAIEIQELAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQYLIwhFBEBBASQACyAGQQAjCBtFBEAgABAJIQRBACMIQQFGDQEaIAQhAAsjCEUEQEEBJAALIAZBAUZBASMIGwRAIAEQCSEEQQEjCEEBRg0BGiAEIQELIwhFBEAgAUEUaygCECEHIABBFGsoAhAhBQsgBkECRkEBIwgbBEAgACAFIAEgByACIAMQAEECIwhBAUYNARoLDwshBCMJKAIAIAQ2AgAjCSMJKAIAQQRqNgIAIwkoAgAiBCAANgIAIAQgATYCBCAEIAI2AgggBCADNgIMIAQgBTYCECAEIAc2AhQjCSMJKAIAQRhqNgIAC90BAQF/IwhBAkYEQCMJIwkoAgBBEGs2AgAjCSgCACIDKAIAIQAgAygCBCEBIAMoAgghAiADKAIMIQMLAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQQLIwhFBEAgAUGgCCABGyEBIABBoAggABshAAsgBEEAIwgbRQRAIAAgASACIAMQCkEAIwhBAUYNARoLDwshBCMJKAIAIAQ2AgAjCSMJKAIAQQRqNgIAIwkoAgAiBCAANgIAIAQgATYCBCAEIAI2AgggBCADNgIMIwkjCSgCAEEQajYCAAuqAgEGfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAAsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAgsjCEECRiABIABB/P///wNLIwgbcgRAIAJBACMIG0UEQEGwCUHwCUEhQR0QCkEAIwhBAUYNAhoLIwhFBEAACwsjCEUEQD8AIgJBEHRBD2pBcHEiASAAQRNqQXBxQQRrIgAjASIEQQRqIgVqIgNJBEAgAiADIAFrQf//A2pBgIB8cUEQdiIBSiEGIAIgASAGG0AAQQBIBEAgAUAAQQBIBEAACwsLIAMkASAEIAA2AgAgBQ8LAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAgADYCACMJIwkoAgBBBGo2AgBBAAvCAgEDfyMIQQJGBEAjCSMJKAIAQRBrNgIAIwkoAgAiAygCACEAIAMoAgQhASADKAIIIQQgAygCDCEDCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACECCyAEIABB7P///wNLIwgbIgQjCEECRnIEQCACQQAjCBtFBEBBsAlB8AlB1gBBHhAKQQAjCEEBRg0CGgsjCEUEQAALCyAEIABBEGojCBshBCACQQFGQQEjCBsEQCAEEAwhAkEBIwhBAUYNARogAiEDCyMIRQRAIANBBGsiBEEANgIEIARBADYCCCAEIAE2AgwgBCAANgIQIANBEGoPCwALIQIjCSgCACACNgIAIwkjCSgCAEEEajYCACMJKAIAIgIgADYCACACIAE2AgQgAiAENgIIIAIgAzYCDCMJIwkoAgBBEGo2AgBBAAvtAQECfyMIQQJGBEAjCSMJKAIAQQxrNgIAIwkoAgAiAigCACEAIAIoAgQhASACKAIIIQILAn8jCEECRgR/IwkjCSgCAEEEazYCACMJKAIAKAIABSADC0EAIwgbRQRAQRBBAxANIQNBACMIQQFGDQEaIAMhAgsjCEUEQCACQQA2AgAgAkEANgIEIAJBADYCCCACQX82AgwgAiAANgIAIAIgATYCBCACQQA2AggLDwshAyMJKAIAIAM2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAyAANgIAIAMgATYCBCADIAI2AggjCSMJKAIAQQxqNgIAC9YCAQF/AkAgAUUNACAAQQA6AAAgACABaiICQQFrQQA6AAAgAUECTQ0AIABBADoAASAAQQA6AAIgAkECa0EAOgAAIAJBA2tBADoAACABQQZNDQAgAEEAOgADIAJBBGtBADoAACABQQhNDQBBACAAa0EDcSICIABqIgBBADYCACABIAJrQXxxIgEgAGoiAkEEa0EANgIAIAFBCE0NACAAQQA2AgQgAEEANgIIIAJBDGtBADYCACACQQhrQQA2AgAgAUEYTQ0AIABBADYCDCAAQQA2AhAgAEEANgIUIABBADYCGCACQRxrQQA2AgAgAkEYa0EANgIAIAJBFGtBADYCACACQRBrQQA2AgAgAEEEcUEYaiICIABqIQAgASACayEBA0AgAUEgTwRAIABCADcDACAAQgA3AwggAEIANwMQIABCADcDGCABQSBrIQEgAEEgaiEADAELCwsLiwMBA38jCEECRgRAIwkjCSgCAEEQazYCACMJKAIAIgMoAgAhACADKAIIIQIgAygCDCEEIAMoAgQhAQsCf0EAIAQgAEUjCBsiBCMIQQJGcgJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEFCyAFQQAjCBsLGwRAQQxBAhANIQNBACMIQQFGDQEaIAMhAAsjCAR/IAQFIABBADYCACAAQQA2AgQgAEEANgIIQfz///8DIAJ2IAFJCyMIQQJGcgRAIAVBAUZBASMIGwRAQbAKQeAKQRNBORAKQQEjCEEBRg0CGgsjCEUEQAALCyABIAEgAnQjCBshASAFQQJGQQEjCBsEQCABQQAQDSEDQQIjCEEBRg0BGiADIQILIwhFBEAgAiABEA8gACACNgIAIAAgAjYCBCAAIAE2AgggAA8LAAshAyMJKAIAIAM2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAyAANgIAIAMgATYCBCADIAI2AgggAyAENgIMIwkjCSgCAEEQajYCAEEAC6UCAQR/IwhBAkYEQCMJIwkoAgBBCGs2AgAjCSgCACIAKAIAIQEgACgCBCECCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEDCyADQQAjCBtFBEBBCUEEEA0hAEEAIwhBAUYNARogACEBCyMIRQRAIAFBADYCACABQQA2AgQgAUEAOgAICyADQQFGQQEjCBsEQEEMQQUQDSEAQQEjCEEBRg0BGiAAIQILIANBAkZBASMIGwRAIAJBBUECEBAhAEECIwhBAUYNARogACECCyMIRQRAIAEgAjYCACABQQA6AAggAQ8LAAshACMJKAIAIAA2AgAjCSMJKAIAQQRqNgIAIwkoAgAiACABNgIAIAAgAjYCBCMJIwkoAgBBCGo2AgBBAAvyAQECfyMIQQJGBEAjCSMJKAIAQQxrNgIAIwkoAgAiAigCACEAIAIoAgQhASACKAIIIQILAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQMLIwgEfyAEBSAAKAIIQQJ2IAFNCyMIQQJGcgRAIANBACMIG0UEQEGgC0HgC0HyBkHAABAKQQAjCEEBRg0CGgsjCEUEQAALCyMIRQRAIAAoAgQgAUECdGogAjYCAAsPCyEDIwkoAgAgAzYCACMJIwkoAgBBBGo2AgAjCSgCACIDIAA2AgAgAyABNgIEIAMgAjYCCCMJIwkoAgBBDGo2AgAL5QEBAn8jCEECRgRAIwkjCSgCAEEIazYCACMJKAIAIgEoAgAhACABKAIEIQELAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQILIwgEfyADBSAAKAIIQQJ2IAFNCyMIQQJGcgRAIAJBACMIG0UEQEGgC0HgC0HnBkHAABAKQQAjCEEBRg0CGgsjCEUEQAALCyMIRQRAIAAoAgQgAUECdGooAgAPCwALIQIjCSgCACACNgIAIwkjCSgCAEEEajYCACMJKAIAIgIgADYCACACIAE2AgQjCSMJKAIAQQhqNgIAQQALrAIBBH8jCEECRgRAIwkjCSgCAEEMazYCACMJKAIAIgEoAgAhACABKAIIIQMgASgCBCECCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEECyAEQQAjCBtFBEAQESEBQQAjCEEBRg0BGiABIQILIwhFBEAgAigCACEDIABB//8DcSEACyAEQQFGQQEjCBsEQCADQQAgABASQQEjCEEBRg0BGgsjCEUEQCACKAIAIQALIARBAkZBASMIGwRAIABBABATIQFBAiMIQQFGDQEaIAEhAAsjCEUEQCACIABFRTYCBCACDwsACyEBIwkoAgAgATYCACMJIwkoAgBBBGo2AgAjCSgCACIBIAA2AgAgASACNgIEIAEgAzYCCCMJIwkoAgBBDGo2AgBBAAuEBAEIfyMIQQJGBEAjCSMJKAIAQRxrNgIAIwkoAgAiASgCACEAIAEoAgQhAiABKAIIIQMgASgCDCEEIAEoAhQhBiABKAIYIQcgASgCECEFCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEICyAIQQAjCBtFBEAQESEBQQAjCEEBRg0BGiABIQILA0AgACMIQQJGcgRAIwhFBEAgAyEFIAIoAgAhBiAAQf////8AcSEHIANBAWohAwsgCEEBRkEBIwgbBEAgBiAFIAcQEkEBIwhBAUYNAxoLIwhFBEAgAEEcdiEADAILCwsjCEUEQCACIAM2AgQLA0AgBAJ/IwhFBEAgAigCBEEASiEACyAACyMIGyIEIwhBAkZyBEAjCEUEQCACKAIEQQFrIQMgAigCACEACyAIQQJGQQEjCBsEfyAAIAMQEyEBQQIjCEEBRg0DGiABBSAACyEAC0EBIwhBASAAQQAgBEUjCEECRnIjCBsbG0UEQCACIAIoAgRBAWsiADYCBAwBCwsjCEUEQCACKAIERQRAIAJBADoACAsgAg8LAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAiASAANgIAIAEgAjYCBCABIAM2AgggASAENgIMIAEgBTYCECABIAY2AhQgASAHNgIYIwkjCSgCAEEcajYCAEEAC78KAQN/A0AgAUEDcUEAIAIbBEAgACIDQQFqIQAgASEEIAFBAWohASADIAQtAAA6AAAgAkEBayECDAELCyAAQQNxRQRAA0AgAkEQTwRAIAAgASgCADYCACAAIAEoAgQ2AgQgACABKAIINgIIIAAgASgCDDYCDCABQRBqIQEgAEEQaiEAIAJBEGshAgwBCwsgAkEIcQRAIAAgASgCADYCACAAIAEoAgQ2AgQgAUEIaiEBIABBCGohAAsgAkEEcQRAIAAgASgCADYCACABQQRqIQEgAEEEaiEACyACQQJxBEAgACABLwEAOwEAIAFBAmohASAAQQJqIQALIAJBAXEEQCAAIAEtAAA6AAALDwsgAkEgTwRAAkACQAJAAkAgAEEDcUEBaw4DAAECAwsgASgCACEFIAAgAS0AADoAACAAIAEtAAE6AAEgAEECaiIDQQFqIQAgAUECaiIEQQFqIQEgAyAELQAAOgAAIAJBA2shAgNAIAJBEU8EQCAAIAEoAgEiA0EIdCAFQRh2cjYCACAAIAEoAgUiBEEIdCADQRh2cjYCBCAAIAEoAgkiA0EIdCAEQRh2cjYCCCAAIAEoAg0iBUEIdCADQRh2cjYCDCABQRBqIQEgAEEQaiEAIAJBEGshAgwBCwsMAgsgASgCACEFIAAgAS0AADoAACAAIgNBAmohACABIQQgAUECaiEBIAMgBC0AAToAASACQQJrIQIDQCACQRJPBEAgACABKAICIgNBEHQgBUEQdnI2AgAgACABKAIGIgRBEHQgA0EQdnI2AgQgACABKAIKIgNBEHQgBEEQdnI2AgggACABKAIOIgVBEHQgA0EQdnI2AgwgAUEQaiEBIABBEGohACACQRBrIQIMAQsLDAELIAEoAgAhBSAAIgNBAWohACABIQQgAUEBaiEBIAMgBC0AADoAACACQQFrIQIDQCACQRNPBEAgACABKAIDIgNBGHQgBUEIdnI2AgAgACABKAIHIgRBGHQgA0EIdnI2AgQgACABKAILIgNBGHQgBEEIdnI2AgggACABKAIPIgVBGHQgA0EIdnI2AgwgAUEQaiEBIABBEGohACACQRBrIQIMAQsLCwsgAkEQcQRAIAAgAS0AADoAACAAIAEtAAE6AAEgAEECaiIAIAFBAmoiAS0AADoAACAAIAEtAAE6AAEgAEECaiIAIAFBAmoiAS0AADoAACAAIAEtAAE6AAEgAEECaiIAIAFBAmoiAS0AADoAACAAIAEtAAE6AAEgAEECaiIAIAFBAmoiAS0AADoAACAAIAEtAAE6AAEgAEECaiIAIAFBAmoiAS0AADoAACAAIAEtAAE6AAEgAEECaiIAIAFBAmoiAS0AADoAACAAIAEtAAE6AAEgAEECaiIAIAFBAmoiAy0AADoAACADQQJqIQEgACADLQABOgABIABBAmohAAsgAkEIcQRAIAAgAS0AADoAACAAIAEtAAE6AAEgAEECaiIAIAFBAmoiAS0AADoAACAAIAEtAAE6AAEgAEECaiIAIAFBAmoiAS0AADoAACAAIAEtAAE6AAEgAEECaiIAIAFBAmoiAy0AADoAACADQQJqIQEgACADLQABOgABIABBAmohAAsgAkEEcQRAIAAgAS0AADoAACAAIAEtAAE6AAEgAEECaiIAIAFBAmoiAy0AADoAACADQQJqIQEgACADLQABOgABIABBAmohAAsgAkECcQRAIAAgAS0AADoAACAAIgNBAmohACADIAEtAAE6AAEgAUECaiEBCyACQQFxBEAgACABLQAAOgAACwvSAgECfwJAIAIhAyAAIAFGDQAgASAAayADa0EAIANBAXRrTQRAIAAgASADEBYMAQsgACABSQRAIAFBB3EgAEEHcUYEQANAIABBB3EEQCADRQ0EIANBAWshAyAAIgJBAWohACABIQQgAUEBaiEBIAIgBC0AADoAAAwBCwsDQCADQQhPBEAgACABKQMANwMAIANBCGshAyAAQQhqIQAgAUEIaiEBDAELCwsDQCADBEAgACICQQFqIQAgASEEIAFBAWohASACIAQtAAA6AAAgA0EBayEDDAELCwUgAUEHcSAAQQdxRgRAA0AgACADakEHcQRAIANFDQQgA0EBayIDIABqIAEgA2otAAA6AAAMAQsLA0AgA0EITwRAIANBCGsiAyAAaiABIANqKQMANwMADAELCwsDQCADBEAgA0EBayIDIABqIAEgA2otAAA6AAAMAQsLCwsLvAIBA38jCEECRgRAIwkjCSgCAEEUazYCACMJKAIAIgQoAgAhACAEKAIIIQIgBCgCDCEDIAQoAhAhBSAEKAIEIQELAn8gBSAAIAF0IwgbIQUjCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQYLIAZBACMIG0UEQCAFQQAQDSEEQQAjCEEBRg0BGiAEIQELIwhBASADG0UEQCABIAMgBRAXCyAGQQFGQQEjCBsEQEEQIAIQDSEEQQEjCEEBRg0BGiAEIQILIwhFBEAgAiABNgIAIAIgATYCBCACIAU2AgggAiAANgIMIAIPCwALIQQjCSgCACAENgIAIwkjCSgCAEEEajYCACMJKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIAQgAzYCDCAEIAU2AhAjCSMJKAIAQRRqNgIAQQAL/QEBA38jCEECRgRAIwkjCSgCAEEIazYCACMJKAIAIgIoAgAhACACKAIEIQELAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQMLIAEgAEH8////A0sjCBsiASMIQQJGcgRAIANBACMIG0UEQEGwCkHgCkE0QSsQCkEAIwhBAUYNAhoLIwhFBEAACwsgA0EBRkEBIwgbBEAgAEEAEA0hAkEBIwhBAUYNARogAiEBCyMIRQRAIAEgABAPIAEPCwALIQIjCSgCACACNgIAIwkjCSgCAEEEajYCACMJKAIAIgIgADYCACACIAE2AgQjCSMJKAIAQQhqNgIAQQALnAYBCX8jCEECRgRAIwkjCSgCAEEoazYCACMJKAIAIgQoAgAhACAEKAIEIQEgBCgCCCEDIAQoAgwhBSAEKAIQIQYgBCgCFCEHIAQoAhghCCAEKAIcIQkgBCgCICEKIAQoAiQhBAsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAgsgAyABQez///8DSyMIGyIDIwhBAkZyBEAgAkEAIwgbRQRAQbAJQfAJQeMAQR4QCkEAIwhBAUYNAhoLIwhFBEAACwsjCEUEQCAAQRBrIgBBD3FBASAAGyEDCyADIwhBAkZyBEAgAkEBRkEBIwgbBEBBoAhB8AlBLUEDEApBASMIQQFGDQIaCyMIRQRAAAsLIAoCfyMIRQRAIwEgAEEEayIDKAIAIgYgAGpGIQcgAUEQaiIFQRNqQXBxQQRrIQggBSAGSyEJCyAJCyMIGyIKIwhBAkZyBEAgBCAHIwgbIgQjCEECRnIEQCMIQQJGIAUgBUH8////A0sjCBtyBEAgAkECRkEBIwgbBEBBsAlB8AlBNEEhEApBAiMIQQFGDQQaCyMIRQRAAAsLIwhFBEA/ACIHQRB0QQ9qQXBxIgUgACAIaiIGSQRAIAcgBiAFa0H//wNqQYCAfHFBEHYiBUohCSAHIAUgCRtAAEEASCIHBEAgBUAAQQBIBEAACwsLIAYkASADIAg2AgALCyAERSMIQQJGcgR/IwhFBEAgCCAGQQF0IgNLIQUgCCADIAUbIQMLIAJBA0ZBASMIGwRAIAMQDCECQQMjCEEBRg0DGiACIQMLIwgEfyAABSADIAAgBhAXIAMLBSAACyEAC0EAIApFIwhBAkZyIwgbQQAgBxsEQCAAIAhqJAEgAyAINgIACyMIRQRAIABBBGsgATYCECAAQRBqDwsACyECIwkoAgAgAjYCACMJIwkoAgBBBGo2AgAjCSgCACICIAA2AgAgAiABNgIEIAIgAzYCCCACIAU2AgwgAiAGNgIQIAIgBzYCFCACIAg2AhggAiAJNgIcIAIgCjYCICACIAQ2AiQjCSMJKAIAQShqNgIAQQAL7AQBBn8jCEECRgRAIwkjCSgCAEEQazYCACMJKAIAIgIoAgAhACACKAIIIQQgAigCDCEFIAIoAgQhAQsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBgsjCEUEQCAAIAFqIgUgAEkhBAsgBCMIQQJGcgRAIAZBACMIG0UEQEGgCEGACUGBBkEHEApBACMIQQFGDQIaCyMIRQRAAAsLIAEgAUEBdCMIGyEBIAZBAUZBASMIGwRAIAFBARANIQJBASMIQQFGDQEaIAIhBAsjCEUEQCAEIQEDQCAAIAVJBEACQCAALQAAIQIgAEEBaiEAIAJBgAFxBEAgACAFRg0BIAAtAABBP3EhByAAQQFqIQAgAkHgAXFBwAFGBEAgASAHIAJBH3FBBnRyOwEABSAAIAVGDQIgAC0AAEE/cSEDIABBAWohACACQfABcUHgAUYEQCACQQ9xQQx0IAdBBnRyIANyIQMFIAAgBUYNAyAALQAAQT9xIAJBB3FBEnQgB0EMdHIgA0EGdHJyIQMgAEEBaiEACyADQYCABEkEQCABIAM7AQAFIANBgIAEayIDQQp2QYCwA3IhAiABIAIgA0H/B3FBgLgDckEQdHI2AgAgAUECaiEBCwsFIAEgAjsBAAsgAUECaiEBDAILCwsgASAEayEACyAGQQJGQQEjCBsEQCAEIAAQGiECQQIjCEEBRg0BGiACIQALIwhFBEAgAA8LAAshAiMJKAIAIAI2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAiAANgIAIAIgATYCBCACIAQ2AgggAiAFNgIMIwkjCSgCAEEQajYCAEEAC4cBAQF/IAFBAXQgAGoiAUEHcSACQQdxckUgA0EET3EEQANAIAEpAwAgAikDAFEEQCABQQhqIQEgAkEIaiECIANBBGsiA0EETw0BCwsLA0AgAyIAQQFrIQMgAARAIAEvAQAiACACLwEAIgRHBEAgACAEaw8LIAFBAmohASACQQJqIQIMAQsLQQALRwEBfyAAIAFGBEBBAQ8LIAFBACAAG0UEQEEADwsgAEEUaygCEEEBdiICIAFBFGsoAhBBAXZHBEBBAA8LIABBACABIAIQHEULhQIBBH8jCEECRgRAIwkjCSgCAEEMazYCACMJKAIAIgEoAgAhACABKAIIIQMgASgCBCECCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEECyAEQQAjCBtFBEBBCEEPEA0hAUEAIwhBAUYNARogASECCyMIRQRAIAJBADYCAAsgBEEBRkEBIwgbBEBBAEECQRFBkA4QGCEBQQEjCEEBRg0BGiABIQMLIwhFBEAgAiADNgIEIAIgADYCACACDwsACyEBIwkoAgAgATYCACMJIwkoAgBBBGo2AgAjCSgCACIBIAA2AgAgASACNgIEIAEgAzYCCCMJIwkoAgBBDGo2AgBBAAuiAgEEfyMIQQJGBEAjCSMJKAIAQRRrNgIAIwkoAgAiAygCACEAIAMoAgQhASADKAIIIQQgAygCDCEFIAMoAhAhAwsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAgsjCEUEQCAAQRRrKAIQQQF2QQF0IgUgAUEUaygCEEEBdkEBdCIDaiIERQRAQaAIDwsLIAJBACMIG0UEQCAEQQEQDSECQQAjCEEBRg0BGiACIQQLIwhFBEAgBCAAIAUQFyAEIAVqIAEgAxAXIAQPCwALIQIjCSgCACACNgIAIwkjCSgCAEEEajYCACMJKAIAIgIgADYCACACIAE2AgQgAiAENgIIIAIgBTYCDCACIAM2AhAjCSMJKAIAQRRqNgIAQQALwAEBAX8DQCABQZDOAE8EQCABQZDOAHAhAyABQZDOAG4hASACQQRrIgJBAXQgAGogA0HkAG5BAnRB7BFqNQIAIANB5ABwQQJ0QewRajUCAEIghoQ3AwAMAQsLIAFB5ABPBEAgAkECayICQQF0IABqIAFB5ABwQQJ0QewRaigCADYCACABQeQAbiEBCyABQQpPBEAgAkECa0EBdCAAaiABQQJ0QewRaigCADYCAAUgAkEBa0EBdCAAaiABQTBqOwEACwu6AgEDfyMIQQJGBEAjCSMJKAIAQQxrNgIAIwkoAgAiAygCACEAIAMoAgQhAiADKAIIIQMLAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQELIwhFBEAgAEUEQEHgEQ8LIABBoI0GSQR/IABB5ABJBH8gAEEKT0EBagUgAEGQzgBPQQNqIABB6AdPagsFIABBgK3iBEkEfyAAQcCEPU9BBmoFIABBgJTr3ANPQQhqIABBgMLXL09qCwsiA0EBdCECCyABQQAjCBtFBEAgAkEBEA0hAUEAIwhBAUYNARogASECCyMIRQRAIAIgACADECAgAg8LAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAiASAANgIAIAEgAjYCBCABIAM2AggjCSMJKAIAQQxqNgIAQQAL7AIBBH8jCEECRgRAIwkjCSgCAEEQazYCACMJKAIAIgQoAgAhACAEKAIEIQEgBCgCCCECIAQoAgwhBAsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAwsjCEUEQCAARQRAQeARDwtBACAAayEBIAAiAkEfdiIAIAEgAiAAGyIBQaCNBkkEfyABQeQASQR/IAFBCk9BAWoFIAFBkM4AT0EDaiABQegHT2oLBSABQYCt4gRJBH8gAUHAhD1PQQZqBSABQYCU69wDT0EIaiABQYDC1y9PagsLaiIEQQF0IQILIANBACMIG0UEQCACQQEQDSEDQQAjCEEBRg0BGiADIQILIwhFBEAgAiABIAQQICAABEAgAkEtOwEACyACDwsACyEDIwkoAgAgAzYCACMJIwkoAgBBBGo2AgAjCSgCACIDIAA2AgAgAyABNgIEIAMgAjYCCCADIAQ2AgwjCSMJKAIAQRBqNgIAQQALKwEBfyABIAJsIQIDQCACIANLBEAgACADakHQHyABEBcgASADaiEDDAELCwvTAgEFfyMIQQJGBEAjCSMJKAIAQRBrNgIAIwkoAgAiASgCACEAIAEoAgQhAyABKAIMIQQgASgCCCECCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEFCyMIRQRAQcwfKAIAQQF2QQF0IgRFIABBAXQiAEGsHygCAEEBdkEBdCIDSXIiAgRAQbAfDwsLIAVBACMIG0UEQCAAQQEQDSEBQQAjCEEBRg0BGiABIQILIwhFBEAgAkGwHyADEBcgBCAAIANrIgFJBEAgBCABQQJrIARuIgBsIQUgAiADaiIDIAQgABAjIAMgBWpB0B8gASAFaxAXBSACIANqQdAfIAEQFwsgAg8LAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAiASAANgIAIAEgAzYCBCABIAI2AgggASAENgIMIwkjCSgCAEEQajYCAEEAC6ICAQJ/IwhBAkYEQCMJIwkoAgBBCGs2AgAjCSgCACIBKAIAIQAgASgCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACECCyMIBH8gAwUgACgCDCABTQsjCEECRnIEQCACQQAjCBtFBEBBoAtB4CBB8gBBKhAKQQAjCEEBRg0CGgsjCEUEQAALCyMIBH8gAQUgACgCBCABQQJ0aigCACIARQsjCEECRnIEQCACQQFGQQEjCBsEQEGQIUHgIEH2AEEoEApBASMIQQFGDQIaCyMIRQRAAAsLIwhFBEAgAA8LAAshAiMJKAIAIAI2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAiAANgIAIAIgATYCBCMJIwkoAgBBCGo2AgBBAAvdCQEJfyMIQQJGBEAjCSMJKAIAQSRrNgIAIwkoAgAiAigCACEAIAIoAgghAyACKAIMIQQgAigCECEGIAIoAhQhByACKAIYIQggAigCHCEJIAIoAiAhCiACKAIEIQELAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQULIAVBACMIG0UEQCABQbAfEB8hAkEAIwhBAUYNARogAiEKCyMIRQRAIAAhCEECIQFBnAgoAgBBAXZBAXQiB0EESyIDQcwfKAIAQQF2QQF0IgZFckUhBEGgCCEACyAEIwhBAkZyBEAgBUEBRkEBIwgbBEBBBEEBEA0hAkEBIwhBAUYNAhogAiEACyMIRQRAIAZBBCAHayIESSIDBEAgBEECayAGbiICIAZsIQMgACAGIAIQIyAAIANqIgZB0B8gBCADayIDEBcFIABB0B8gBBAXCyAAIARqIgRBoAggBxAXCwsjCEUEQCAIKAIAIQQLIAVBAkZBASMIGwRAQfAfIAQQHyECQQIjCEEBRg0BGiACIQQLIAVBA0ZBASMIGwRAIAAgBBAfIQJBAyMIQQFGDQEaIAIhAwsjCEUEQCAIKAIEKAIMRSEACwJAIAAjCEECRnIEQCAFQQRGQQEjCBsEQEEFECQhAkEEIwhBAUYNAxogAiEACyAFQQVGQQEjCBsEQCADIAAQHyECQQUjCEEBRg0DGiACIQALIAVBBkZBASMIGwRAIABBoCAQHyECQQYjCEEBRg0DGiACIQMLIwhFDQELIwhFBEAgCCgCBCgCDCIAQQFrIQYLA0AgACAGQQBOIwgbIgAjCEECRnIEQCMIRQRAIAgoAgQhAAsgBUEHRkEBIwgbBEAgACAGECUhAkEHIwhBAUYNBBogAiEHC0GgCCAEQQAgCQJ/IwhFBEAgBygCCEGgCBAdIQALIAALIwgbIgkjCEECRnIjCBsbIQQgCUUjCEECRnIEQCMIRQRAIAcoAgghAAsgBUEIRkEBIwgbBH9BkCIgABAfIQJBCCMIQQFGDQUaIAIFIAQLIQQLIwhFBEAgASIAQQFqIQEgAEEBdEEBaiEACyAFQQlGQQEjCBsEQCAAECQhAkEJIwhBAUYNBBogAiEACyAFQQpGQQEjCBsEQCADIAAQHyECQQojCEEBRg0EGiACIQALIwhFBEAgBygCACEDCyAFQQtGQQEjCBsEQEGwIiADEB8hAkELIwhBAUYNBBogAiEDCyAFQQxGQQEjCBsEQCADQdAPEB8hAkEMIwhBAUYNBBogAiEDCyMIRQRAIAcoAgQhBwsgBUENRkEBIwgbBEAgAyAHEB8hAkENIwhBAUYNBBogAiEDCyAFQQ5GQQEjCBsEQCADIAQQHyECQQ4jCEEBRg0EGiACIQQLIAVBD0ZBASMIGwRAIAAgBBAfIQJBDyMIQQFGDQQaIAIhAwsjCEUEQCAGQQFrIQYMAgsLCwsgBUEQRkEBIwgbBEAgCiADEB8hAkEQIwhBAUYNARogAiEACyMIRQRAIAAPCwALIQIjCSgCACACNgIAIwkjCSgCAEEEajYCACMJKAIAIgIgADYCACACIAE2AgQgAiADNgIIIAIgBDYCDCACIAY2AhAgAiAHNgIUIAIgCDYCGCACIAk2AhwgAiAKNgIgIwkjCSgCAEEkajYCAEEAC+MGAQR/IwhBAkYEQCMJIwkoAgBBFGs2AgAjCSgCACIDKAIAIQAgAygCCCECIAMoAgwhBCADKAIQIQYgAygCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEFCyAFQQAjCBtFBEBBFEEUEA0hA0EAIwhBAUYNARogAyEECyMIBH8gBgUgBEEANgIAIARBADYCBCAEQQA2AgggBEEANgIMIARBADYCECABQfz///8DSwR/QQEFIABBFGsoAhAgAUkLCyMIQQJGcgRAIAVBAUZBASMIGwRAQZAPQdAPEB8hA0EBIwhBAUYNAhogAyEECyAFQQJGQQEjCBsEQCAEQbAKEB8hA0ECIwhBAUYNAhogAyEECyAFQQNGQQEjCBsEQCAEQfAPEB8hA0EDIwhBAUYNAhogAyEECyAFQQRGQQEjCBsEQCABECEhA0EEIwhBAUYNAhogAyEBCyAFQQVGQQEjCBsEQCAEIAEQHyEDQQUjCEEBRg0CGiADIQELIAVBBkZBASMIGwRAIAFBkB4QHyEDQQYjCEEBRg0CGiADIQELIAVBB0ZBASMIGwRAQfz///8DECEhA0EHIwhBAUYNAhogAyEECyAFQQhGQQEjCBsEQCABIAQQHyEDQQgjCEEBRg0CGiADIQELIAVBCUZBASMIGwRAIAFB0B4QHyEDQQkjCEEBRg0CGiADIQELIwhFBEAgAEEUaygCECEACyAFQQpGQQEjCBsEQCAAECIhA0EKIwhBAUYNAhogAyEACyAFQQtGQQEjCBsEQCABIAAQHyEDQQsjCEEBRg0CGiADIQALIAVBDEZBASMIGwRAIABBkB8QHyEDQQwjCEEBRg0CGiADIQALIAVBDUZBASMIGwRAIAIgABAmIQNBDSMIQQFGDQIaIAMhAAsgACAAQaAIIAAbIwgbIQAgBUEORkEBIwgbBEAgAEHQIkEWQQcQCkEOIwhBAUYNAhoLIwhFBEAACwsjCEUEQCAEIAA2AgQgBCAANgIAIAQgATYCCCAEQQA2AgwgBCACNgIQIAQPCwALIQMjCSgCACADNgIAIwkjCSgCAEEEajYCACMJKAIAIgMgADYCACADIAE2AgQgAyACNgIIIAMgBDYCDCADIAY2AhAjCSMJKAIAQRRqNgIAQQAL2gIBBH8jCEECRgRAIwkjCSgCAEEQazYCACMJKAIAIgIoAgAhACACKAIIIQMgAigCDCEEIAIoAgQhAQsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBQsgBUEAIwgbRQRAQQhBEhANIQJBACMIQQFGDQEaIAIhAwsjCEUEQCADQQA2AgAgA0EANgIEIANFIQQLIAQjCEECRnJBACAFQQFGQQEjCBsbBEBBAEETEA0hAkEBIwhBAUYNARogAiEDCyMIRQRAIAMgATYCACAAQRRrKAIQIQQLIAVBAkZBASMIGwRAIAAgBCABECchAkECIwhBAUYNARogAiEACyMIRQRAIAMgADYCBCADDwsACyECIwkoAgAgAjYCACMJIwkoAgBBBGo2AgAjCSgCACICIAA2AgAgAiABNgIEIAIgAzYCCCACIAQ2AgwjCSMJKAIAQRBqNgIAQQAL7QUBBH8jCEECRgRAIwkjCSgCAEEUazYCACMJKAIAIgMoAgAhACADKAIIIQIgAygCDCEFIAMoAhAhBiADKAIEIQELAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQQLIwhFBEAgACgCDCACaiIGIAAoAghLIQULIAUjCEECRnIEQCMIRQRAIAAoAgwhBSAAKAIIIQYgACgCECEACyAEQQAjCBtFBEAgAUHQDxAfIQNBACMIQQFGDQIaIAMhAQsgBEEBRkEBIwgbBEAgAUGgCxAfIQNBASMIQQFGDQIaIAMhAQsgBEECRkEBIwgbBEAgAUHwIxAfIQNBAiMIQQFGDQIaIAMhAQsgBEEDRkEBIwgbBEAgAhAhIQNBAyMIQQFGDQIaIAMhAgsgBEEERkEBIwgbBEAgASACEB8hA0EEIwhBAUYNAhogAyEBCyAEQQVGQQEjCBsEQCABQaAkEB8hA0EFIwhBAUYNAhogAyEBCyAEQQZGQQEjCBsEQCAFECEhA0EGIwhBAUYNAhogAyECCyAEQQdGQQEjCBsEQCABIAIQHyEDQQcjCEEBRg0CGiADIQELIARBCEZBASMIGwRAIAFB0CQQHyEDQQgjCEEBRg0CGiADIQELIARBCUZBASMIGwRAIAYQISEDQQkjCEEBRg0CGiADIQILIARBCkZBASMIGwRAIAEgAhAfIQNBCiMIQQFGDQIaIAMhAQsgBEELRkEBIwgbBEAgAUGQHxAfIQNBCyMIQQFGDQIaIAMhAQsgBEEMRkEBIwgbBEAgACABECYhA0EMIwhBAUYNAhogAyEACyAAIABBoAggABsjCBshACAEQQ1GQQEjCBsEQCAAQYAlQRNBAxAKQQ0jCEEBRg0CGgsjCEUEQAALCw8LIQMjCSgCACADNgIAIwkjCSgCAEEEajYCACMJKAIAIgMgADYCACADIAE2AgQgAyACNgIIIAMgBTYCDCADIAY2AhAjCSMJKAIAQRRqNgIAC6QCAQJ/IwhBAkYEQCMJIwkoAgBBCGs2AgAjCSgCACIBKAIAIQAgASgCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACECCyMIRQRAIAAoAgQhAQsgAkEAIwgbRQRAIAFBwCNBABApQQAjCEEBRg0BGgsjCAR/IAEFIAEoAgwgASgCAGotAABBwAFGCyMIQQJGcgRAIwhFBEAgACgCBCEACyACQQFGQQEjCBsEQCAAQfAlQQEQKUEBIwhBAUYNAhoLIwhFBEAgACAAKAIMQQFqNgIMQQEPCwsjCEUEQEEADwsACyECIwkoAgAgAjYCACMJIwkoAgBBBGo2AgAjCSgCACICIAA2AgAgAiABNgIEIwkjCSgCAEEIajYCAEEAC7oBAQJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEACwJ/IwhBAkYEfyMJIwkoAgBBBGs2AgAjCSgCACgCAAUgAQtBACMIG0UEQCAAQaAmQQEQKUEAIwhBAUYNARoLIwhFBEAgACgCDCIBIAAoAgBqLQAAIQIgACABQQFqNgIMIAIPCwALIQEjCSgCACABNgIAIwkjCSgCAEEEajYCACMJKAIAIAA2AgAjCSMJKAIAQQRqNgIAQQALwwEBAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQALAn8jCEECRgR/IwkjCSgCAEEEazYCACMJKAIAKAIABSABC0EAIwgbRQRAIABB0CZBAhApQQAjCEEBRg0BGgsjCEUEQCAAKAIMIgIgACgCAGovAQAhASAAIAJBAmo2AgwgAUEIdCABQQh2cg8LAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAgADYCACMJIwkoAgBBBGo2AgBBAAvPAQECfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAAsCfyMIQQJGBH8jCSMJKAIAQQRrNgIAIwkoAgAoAgAFIAELQQAjCBtFBEAgAEGAJ0EEEClBACMIQQFGDQEaCyMIRQRAIAAoAgwiAiAAKAIAaigCACEBIAAgAkEEajYCDCABQYD+g3hxQQh3IAFB/4H8B3FBCHhyDwsACyEBIwkoAgAgATYCACMJIwkoAgBBBGo2AgAjCSgCACAANgIAIwkjCSgCAEEEajYCAEEAC/AFAQZ/IwhBAkYEQCMJIwkoAgBBCGs2AgAjCSgCACICKAIAIQAgAigCBCECCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEDC0GQKCAAQQAgBCABIABB4AFxQeABRiMIGyIBIwgbIgQjCEECRnIjCBsbIQAgBEUjCEECRnIEQEGQKCACIAEgAEH/AXFBB3YjCBsiASMIGyICIwhBAkZyBH9BwCggAEEAIAUgASAAQeABcUGgAUYjCBsiASMIGyIFIwhBAkZyIwgbGyEAIAVFIwhBAkZyBH9BgCkgAEEAIAYgASAAQfABcUGQAUYjCBsiASMIGyIGIwhBAkZyIwgbGyEAIAZFIwhBAkZyBH8jCEECRiABIABB8AFxQYABRyMIG3IEQCMIRQRAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAIABB/wFxQcABaw4gABcBAQIDBBcXFwUGBwgJCgsMDQ4PEBESExQUFBUVFhYXC0HgKQ8LQZAqDwtBwCoPC0HwKg8LQaArDwtB0CsPC0GQLA8LQdAsDwtBgC0PC0HALQ8LQYAuDwtBwC4PC0HwLg8LQaAvDwtB0C8PC0GAMA8LQcAwDwtBgDEPC0HAMQ8LQYAyDwtBwCgPC0GAKQ8LQbApDwsgAEH/AXEhAAsgA0EAIwgbRQRAIAAQISEBQQAjCEEBRg0GGiABIQALIANBAUZBASMIGwRAQcAyIAAQHyEBQQEjCEEBRg0GGiABIQALIAAgAEGgCCAAGyMIGyEAIANBAkZBASMIGwRAIABBoDNBvQVBCxAKQQIjCEEBRg0GGgsjCEUEQAALCyAAQbApIwgbBSAACwUgAAsFIAALQQAgAkUjCEECRnIjCBsbIQALIwhFBEAgAA8LAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAiASAANgIAIAEgAjYCBCMJIwkoAgBBCGo2AgBBAAvsBAEFfyMIQQJGBEAjCSMJKAIAQRBrNgIAIwkoAgAiASgCACEAIAEoAgghBCABKAIMIQUgASgCBCECCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEDCyADQQAjCBtFBEAgABAqIQFBACMIQQFGDQEaIAEhAgsjCEUEQCACBEBBAA8LIAAoAgQhAgsgA0EBRkEBIwgbBEAgAhArIQFBASMIQQFGDQEaIAEhAgsjCEUEQCACQfABcUGAAUYEQCACQQ9xDwsgAkH/AXEiBEHeAUchBQsCQAJAAkAjCEUEQCAFBEAgBEHfAUYNAiAEQcABRg0DDAQLIAAoAgQhAAsgA0ECRkEBIwgbBEAgABAsIQFBAiMIQQFGDQQaIAEhAAsjCEUEQCAAQf//A3EPCwsjCEUEQCAAKAIEIQALIANBA0ZBASMIGwRAIAAQLSEBQQMjCEEBRg0DGiABIQALIwhFBEAgAA8LCyMIRQRAQQAPCwsjCEUEQCAAKAIAIQALIANBBEZBASMIGwRAIAIQLiEBQQQjCEEBRg0BGiABIQILIANBBUZBASMIGwRAQbAnIAIQHyEBQQUjCEEBRg0BGiABIQILIANBBkZBASMIGwRAIAAgAhAmIQFBBiMIQQFGDQEaIAEhAAsgACAAQaAIIAAbIwgbIQAgA0EHRkEBIwgbBEAgAEGgM0GVAkEFEApBByMIQQFGDQEaCyMIRQRAAAsACyEBIwkoAgAgATYCACMJIwkoAgBBBGo2AgAjCSgCACIBIAA2AgAgASACNgIEIAEgBDYCCCABIAU2AgwjCSMJKAIAQRBqNgIAQQALxQUBBX8jCEECRgRAIwkjCSgCAEEQazYCACMJKAIAIgEoAgAhACABKAIIIQQgASgCDCEFIAEoAgQhAgsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAwsgA0EAIwgbRQRAIAAQKiEBQQAjCEEBRg0BGiABIQILIwhFBEAgAgRAQQAPCyAAKAIEIQILIANBAUZBASMIGwRAIAIQKyEBQQEjCEEBRg0BGiABIQILIwhFBEAgAkHgAXFBoAFGBEAgAkEfcQ8LIAJB8AFxQZABRgRAIAJBD3EPCyACQf8BcSIEQdkBRyEFCwJAAkACQAJAIwhFBEAgBQRAIARB2gFGDQIgBEHbAUYNAyAEQcABRg0EDAULIAAoAgQhAAsgA0ECRkEBIwgbBEAgABArIQFBAiMIQQFGDQUaIAEhAAsjCEUEQCAAQf8BcQ8LCyMIRQRAIAAoAgQhAAsgA0EDRkEBIwgbBEAgABAsIQFBAyMIQQFGDQQaIAEhAAsjCEUEQCAAQf//A3EPCwsjCEUEQCAAKAIEIQALIANBBEZBASMIGwRAIAAQLSEBQQQjCEEBRg0DGiABIQALIwhFBEAgAA8LCyMIRQRAQQAPCwsjCEUEQCAAKAIAIQALIANBBUZBASMIGwRAIAIQLiEBQQUjCEEBRg0BGiABIQILIANBBkZBASMIGwRAQZA0IAIQHyEBQQYjCEEBRg0BGiABIQILIANBB0ZBASMIGwRAIAAgAhAmIQFBByMIQQFGDQEaIAEhAAsgACAAQaAIIAAbIwgbIQAgA0EIRkEBIwgbBEAgAEGgM0GnAUEFEApBCCMIQQFGDQEaCyMIRQRAAAsACyEBIwkoAgAgATYCACMJIwkoAgBBBGo2AgAjCSgCACIBIAA2AgAgASACNgIEIAEgBDYCCCABIAU2AgwjCSMJKAIAQRBqNgIAQQALpgMBBn8jCEECRgRAIwkjCSgCAEEYazYCACMJKAIAIgIoAgAhACACKAIIIQMgAigCDCEEIAIoAhAhBSACKAIUIQYgAigCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEHCyAHQQAjCBtFBEAgAEHwNCABEClBACMIQQFGDQEaCyMIRQRAIAAoAgwiBSABaiEEIAAoAgQiBkEUaygCECEDIAVBAEgEfyADIAVqIgVBAEohAiAFQQAgAhsFIAUgAyADIAVKGwshBSAEQQBIBH8gAyAEaiIEQQBKIQMgBEEAIAMbBSAEIAMgAyAEShsLIAVrIgRBAEohAyAEQQAgAxshBAsgB0EBRkEBIwgbBEAgBEEAEA0hAkEBIwhBAUYNARogAiEDCyMIRQRAIAMgBSAGaiAEEBcgACAAKAIMIAFqNgIMIAMPCwALIQIjCSgCACACNgIAIwkjCSgCAEEEajYCACMJKAIAIgIgADYCACACIAE2AgQgAiADNgIIIAIgBDYCDCACIAU2AhAgAiAGNgIUIwkjCSgCAEEYajYCAEEAC9YDAQZ/IwhBAkYEQCMJIwkoAgBBHGs2AgAjCSgCACIDKAIAIQAgAygCCCECIAMoAgwhBCADKAIQIQUgAygCFCEGIAMoAhghByADKAIEIQELAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQgLIwhFBEAgACgCCCIFQQJ2IAFJIQQLIAQjCEECRnIEQCAEIAFB/////wBLIwgbIgQjCEECRnIEQCAIQQAjCBtFBEBBsApB4CBBE0EwEApBACMIQQFGDQMaCyMIRQRAAAsLIwhFBEAgAUEIIAFBCEsbQQJ0IQEgBSEGIAAoAgAiBCEHIAIEfyAFQQF0IgJB/P///wNJIQMgAkH8////AyADGyICIAFLIQMgAiABIAMbBSABCyEBCyAIQQFGQQEjCBsEQCAHIAEQGiEDQQEjCEEBRg0CGiADIQILIwhFBEAgAiAGaiABIAVrEA8gAiAERwRAIAAgAjYCACAAIAI2AgQLIAAgATYCCAsLDwshAyMJKAIAIAM2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAyAANgIAIAMgATYCBCADIAI2AgggAyAENgIMIAMgBTYCECADIAY2AhQgAyAHNgIYIwkjCSgCAEEcajYCAAvwAQEDfyMIQQJGBEAjCSMJKAIAQRBrNgIAIwkoAgAiAigCACEAIAIoAgQhASACKAIIIQQgAigCDCECCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEDCyMIRQRAIAAoAgwiAkEBaiEECyADQQAjCBtFBEAgACAEQQEQMkEAIwhBAUYNARoLIwhFBEAgACgCBCACQQJ0aiABNgIAIAAgBDYCDAsPCyEDIwkoAgAgAzYCACMJIwkoAgBBBGo2AgAjCSgCACIDIAA2AgAgAyABNgIEIAMgBDYCCCADIAI2AgwjCSMJKAIAQRBqNgIAC60CAQN/IwhBAkYEQCMJIwkoAgBBFGs2AgAjCSgCACIEKAIAIQAgBCgCCCECIAQoAgwhAyAEKAIQIQUgBCgCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEGCyMIRQRAIAAoAgQhBQsgBkEAIwgbRQRAQQxBEBANIQRBACMIQQFGDQEaIAQhAAsjCEUEQCAAQQA2AgAgAEEANgIEIABBADYCCCAAIAE2AgAgACACNgIEIAAgAzYCCAsgBkEBRkEBIwgbBEAgBSAAEDNBASMIQQFGDQEaCw8LIQQjCSgCACAENgIAIwkjCSgCAEEEajYCACMJKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIAQgAzYCDCAEIAU2AhAjCSMJKAIAQRRqNgIAC/MBAQN/IwhBAkYEQCMJIwkoAgBBCGs2AgAjCSgCACIBKAIAIQAgASgCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEDCyMIBH8gAgUgACgCDCIBQQBMCyMIQQJGcgRAIANBACMIG0UEQEHQOUHgIEGjAkESEApBACMIQQFGDQIaCyMIRQRAAAsLIwhFBEAgACgCBCABQQFrIgFBAnRqKAIAIQIgACABNgIMIAIPCwALIQIjCSgCACACNgIAIwkjCSgCAEEEajYCACMJKAIAIgIgADYCACACIAE2AgQjCSMJKAIAQQhqNgIAQQALhgQBBX8jCEECRgRAIwkjCSgCAEEQazYCACMJKAIAIgEoAgAhACABKAIIIQQgASgCDCEFIAEoAgQhAgsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAwsjCEUEQCAAKAIEKAIMRSECCyACIwhBAkZyBEAgA0EAIwgbRQRAQbA3QeA4QR9BBxAKQQAjCEEBRg0CGgsjCEUEQAALCyMIRQRAIAAoAgQhAAsgA0EBRkEBIwgbBEAgABA1IQFBASMIQQFGDQEaIAEhAAsjCEUEQCAAKAIAIQILIANBAkZBASMIGwRAIAJB0A8QHyEBQQIjCEEBRg0BGiABIQILIwhFBEAgACgCBCEECyADQQNGQQEjCBsEQCACIAQQHyEBQQMjCEEBRg0BGiABIQILQaAIIABBACAFAn8jCEUEQCAAKAIIQaAIEB0hBAsgBAsjCBsiBSMIQQJGciMIGxshACAFRSMIQQJGcgRAIwhFBEAgACgCCCEACyADQQRGQQEjCBsEf0GQIiAAEB8hAUEEIwhBAUYNAhogAQUgAAshAAsgA0EFRkEBIwgbBEAgAiAAEB8aQQUjCEEBRg0BGgsPCyEBIwkoAgAgATYCACMJIwkoAgBBBGo2AgAjCSgCACIBIAA2AgAgASACNgIEIAEgBDYCCCABIAU2AgwjCSMJKAIAQRBqNgIAC/8BAgF/An4jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQALAn8jCEECRgR/IwkjCSgCAEEEazYCACMJKAIAKAIABSABC0EAIwgbRQRAIABBgDxBCBApQQAjCEEBRg0BGgsjCEUEQCAAKAIMIgEgACgCAGopAwAhAiAAIAFBCGo2AgwgAkIIiEL/gfyH8J/A/wCDIAJC/4H8h/CfwP8Ag0IIhoQiAkIQiEL//4OA8P8/gyEDIAMgAkL//4OA8P8/g0IQhoRCIIoPCwALIQEjCSgCACABNgIAIwkjCSgCAEEEajYCACMJKAIAIAA2AgAjCSMJKAIAQQRqNgIAQgALugEBAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQALAn8jCEECRgR/IwkjCSgCAEEEazYCACMJKAIAKAIABSABC0EAIwgbRQRAIABBsDxBARApQQAjCEEBRg0BGgsjCEUEQCAAKAIMIgEgACgCAGosAAAhAiAAIAFBAWo2AgwgAg8LAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAgADYCACMJIwkoAgBBBGo2AgBBAAvIAQECfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAAsCfyMIQQJGBH8jCSMJKAIAQQRrNgIAIwkoAgAoAgAFIAELQQAjCBtFBEAgAEHgPEECEClBACMIQQFGDQEaCyMIRQRAIAAoAgwiAiAAKAIAai4BACEBIAAgAkECajYCDCABQQh0IAFB//8DcUEIdnIPCwALIQEjCSgCACABNgIAIwkjCSgCAEEEajYCACMJKAIAIAA2AgAjCSMJKAIAQQRqNgIAQQALzwEBAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQALAn8jCEECRgR/IwkjCSgCAEEEazYCACMJKAIAKAIABSABC0EAIwgbRQRAIABBkD1BBBApQQAjCEEBRg0BGgsjCEUEQCAAKAIMIgIgACgCAGooAgAhASAAIAJBBGo2AgwgAUGA/oN4cUEIdyABQf+B/AdxQQh4cg8LAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAgADYCACMJIwkoAgBBBGo2AgBBAAv/AQIBfwJ+IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEACwJ/IwhBAkYEfyMJIwkoAgBBBGs2AgAjCSgCACgCAAUgAQtBACMIG0UEQCAAQcA9QQgQKUEAIwhBAUYNARoLIwhFBEAgACgCDCIBIAAoAgBqKQMAIQIgACABQQhqNgIMIAJCCIhC/4H8h/CfwP8AgyACQv+B/Ifwn8D/AINCCIaEIgJCEIhC//+DgPD/P4MhAyADIAJC//+DgPD/P4NCEIaEQiCKDwsACyEBIwkoAgAgATYCACMJIwkoAgBBBGo2AgAjCSgCACAANgIAIwkjCSgCAEEEajYCAEIAC68BAgJ/An4DQCABQoDC1y9aBEAgASIFQoDC1y+AIgFCgMLXL34hBiACQQRrIgJBAXQgAGogBSAGfaciA0GQzgBwIgRB5ABuQQJ0QewRajUCACAEQeQAcEECdEHsEWo1AgBCIIaENwMAIAJBBGsiAkEBdCAAaiADQZDOAG4iA0HkAG5BAnRB7BFqNQIAIANB5ABwQQJ0QewRajUCAEIghoQ3AwAMAQsLIAAgAacgAhAgC90EAQZ/IwhBAkYEQCMJIwkoAgBBGGs2AgAjCSgCACIBKQIAIQAgASgCCCECIAEoAhAhBCABKAIUIQUgASgCDCEDCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEGCyAFAn8jCEUEQCAAUARAQeARDwsgAEL/////D1ghBAsgBAsjCBsiBSMIQQJGcgRAIwhFBEAgAKciAkGgjQZJBH8gAkHkAEkEfyACQQpPQQFqBSACQZDOAE9BA2ogAkHoB09qCwUgAkGAreIESQR/IAJBwIQ9T0EGagUgAkGAlOvcA09BCGogAkGAwtcvT2oLCyIEQQF0IQMLIAZBACMIG0UEQCADQQEQDSEBQQAjCEEBRg0CGiABIQMLIwhFBEAgAyACIAQQIAsLIAVFIwhBAkZyBEAjCEUEQCAAQoCAmqbqr+MBVAR/IABCgKCUpY0dVAR/IABCgNDbw/QCWkEKaiAAQoDIr6AlWmoFIABCgIDpg7HeFlpBDWogAEKAwMrzhKMCWmoLBSAAQoCAqOyFr9GxAVQEfyAAQoCAhP6m3uERWkEQagUgAEKAgKDPyODI44p/WkESaiAAQoCAkLu61q3wDVpqCwsiBEEBdCECCyAGQQFGQQEjCBsEQCACQQEQDSEBQQEjCEEBRg0CGiABIQMLIwhFBEAgAyAAIAQQPAsLIwhFBEAgAw8LAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAiASAANwIAIAEgAjYCCCABIAM2AgwgASAENgIQIAEgBTYCFCMJIwkoAgBBGGo2AgBBAAu/EQIFfwJ+IwhBAkYEQCMJIwkoAgBBGGs2AgAjCSgCACICKAIAIQAgAigCCCEEIAIpAgwhBiACKAIUIQUgAigCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEDCyADQQAjCBtFBEAgABAqIQJBACMIQQFGDQEaIAIhAQsjCEUEQCABBEBBAA8LIAAoAgQhAQsgBSAEAn8gA0EBRkEBIwgbBEAgARArIQJBASMIQQFGDQIaIAIhAQsgAUH/AXFBB3YLIwgbIgQjCBsiBSMIQQJGcgRAIAQgAUHgAXFB4AFGIwgbIgQjCEECRnIEQCMIRQRAIAAoAgAhAAsgA0ECRkEBIwgbBEAgARAuIQJBAiMIQQFGDQMaIAIhAQsgA0EDRkEBIwgbBEBBoDsgARAfIQJBAyMIQQFGDQMaIAIhAQsgA0EERkEBIwgbBEAgACABECYhAkEEIwhBAUYNAxogAiEACyAAIABBoAggABsjCBshACADQQVGQQEjCBsEQCAAQaAzQbEEQQcQCkEFIwhBAUYNAxoLIwhFBEAACwsLAkBBACAFRSMIQQJGciMIGwRAIAFB/wFxIgGtIQYMAQsgBCABQf8BcUHMAWsjCBshBAJAAkACQAJAAkACQAJAAkAjCEUEQAJAIAQOCAACAwQFBgcICQsgACgCBCEBCyADQQZGQQEjCBsEQCABECshAkEGIwhBAUYNChogAiEBCyMIRQRAIAFB/wFxIgGtIQYMCQsLIwhFBEAgACgCBCEBCyADQQdGQQEjCBsEQCABECwhAkEHIwhBAUYNCRogAiEBCyMIRQRAIAFB//8DcSIBrSEGDAgLCyMIRQRAIAAoAgQhAQsgA0EIRkEBIwgbBEAgARAtIQJBCCMIQQFGDQgaIAIhAQsjCEUEQCABrSEGDAcLCyMIRQRAIAAoAgQhAQsgA0EJRkEBIwgbBEAgARA3IQdBCSMIQQFGDQcaIAchBgsjCEUNBQsjCEUEQCAAKAIEIQQLIANBCkZBASMIGwRAIAQQOCECQQojCEEBRg0GGiACIQQLIwhFBEAgBMBBAE4EQCAEwCIBrCEGDAYLIAAoAgAhAAsgA0ELRkEBIwgbBEAgARAuIQJBCyMIQQFGDQYaIAIhAQsgA0EMRkEBIwgbBEBBoDsgARAfIQJBDCMIQQFGDQYaIAIhAQsgA0ENRkEBIwgbBEAgACABECYhAkENIwhBAUYNBhogAiEACyAAIABBoAggABsjCBshACADQQ5GQQEjCBsEQCAAQaAzQccEQQkQCkEOIwhBAUYNBhoLIwhFBEAACwsjCEUEQCAAKAIEIQQLIANBD0ZBASMIGwRAIAQQOSECQQ8jCEEBRg0FGiACIQQLIwhFBEAgBMFBAE4EQCAEwSIBrCEGDAULIAAoAgAhAAsgA0EQRkEBIwgbBEAgARAuIQJBECMIQQFGDQUaIAIhAQsgA0ERRkEBIwgbBEBBoDsgARAfIQJBESMIQQFGDQUaIAIhAQsgA0ESRkEBIwgbBEAgACABECYhAkESIwhBAUYNBRogAiEACyAAIABBoAggABsjCBshACADQRNGQQEjCBsEQCAAQaAzQdMEQQkQCkETIwhBAUYNBRoLIwhFBEAACwsjCEUEQCAAKAIEIQQLIANBFEZBASMIGwRAIAQQOiECQRQjCEEBRg0EGiACIQQLIwhFBEAgBEEATgRAIASsIQYMBAsgACgCACEACyADQRVGQQEjCBsEQCABEC4hAkEVIwhBAUYNBBogAiEBCyADQRZGQQEjCBsEQEGgOyABEB8hAkEWIwhBAUYNBBogAiEBCyADQRdGQQEjCBsEQCAAIAEQJiECQRcjCEEBRg0EGiACIQALIAAgAEGgCCAAGyMIGyEAIANBGEZBASMIGwRAIABBoDNB3wRBCRAKQRgjCEEBRg0EGgsjCEUEQAALCyMIRQRAIAAoAgQhBAsgA0EZRkEBIwgbBEAgBBA7IQdBGSMIQQFGDQMaIAchBgsjCEUEQCAGQgBZDQIgACgCACEACyADQRpGQQEjCBsEQCABEC4hAkEaIwhBAUYNAxogAiEBCyADQRtGQQEjCBsEQEGgOyABEB8hAkEbIwhBAUYNAxogAiEBCyADQRxGQQEjCBsEQCAAIAEQJiECQRwjCEEBRg0DGiACIQALIAAgAEGgCCAAGyMIGyEAIANBHUZBASMIGwRAIABBoDNB6wRBCRAKQR0jCEEBRg0DGgsjCEUEQAALCyMIRQRAIAAoAgAhAAsgA0EeRkEBIwgbBEAgARAuIQJBHiMIQQFGDQIaIAIhAQsgA0EfRkEBIwgbBEBB8D0gARAfIQJBHyMIQQFGDQIaIAIhAQsgA0EgRkEBIwgbBEAgACABECYhAkEgIwhBAUYNAhogAiEACyAAIABBoAggABsjCBshACADQSFGQQEjCBsEQCAAQaAzQfMEQQkQCkEhIwhBAUYNAhoLIwhFBEAACwsgASAGQv////8PWCMIGyEBAkAjCEUEQCABBEAgBqchAAwCCyAAKAIAIQALIANBIkZBASMIGwRAIAYQPSECQSIjCEEBRg0CGiACIQELIANBI0ZBASMIGwRAQdA+IAEQHyECQSMjCEEBRg0CGiACIQELIANBJEZBASMIGwRAIAFBsD8QHyECQSQjCEEBRg0CGiACIQELIANBJUZBASMIGwRAIAAgARAmIQJBJSMIQQFGDQIaIAIhAAsgACAAQaAIIAAbIwgbIQAgA0EmRkEBIwgbBEAgAEGgM0HwAEEFEApBJiMIQQFGDQIaCyMIRQRAAAsLIANBJ0ZBASMIGwRAQQRBDBANIQJBJyMIQQFGDQEaIAIhAQsjCEUEQCABIAA2AgAgAQ8LAAshAiMJKAIAIAI2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAiAANgIAIAIgATYCBCACIAQ2AgggAiAGNwIMIAIgBTYCFCMJIwkoAgBBGGo2AgBBAAu9AQECfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAAsCfyMIQQJGBH8jCSMJKAIAQQRrNgIAIwkoAgAoAgAFIAELQQAjCBtFBEBBEEELEA0hAUEAIwhBAUYNARogASEACyMIRQRAIABBADYCACAAQQA2AgQgAEEANgIIIABBADYCDCAADwsACyEBIwkoAgAgATYCACMJIwkoAgBBBGo2AgAjCSgCACAANgIAIwkjCSgCAEEEajYCAEEAC+QUARF/IwhBAkYEQCMJIwkoAgBBQGo2AgAjCSgCACIBKAIAIQAgASgCCCEDIAEoAgwhBSABKAIQIQYgASgCFCEHIAEoAhghCCABKAIcIQkgASgCICEKIAEoAiQhCyABKAIoIQwgASgCLCENIAEoAjAhDiABKAI0IRAgASgCOCERIAEoAjwhDyABKAIEIQILAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQQLIARBACMIG0UEQEGwDhAeIQFBACMIQQFGDQEaIAEhAgsgBEEBRkEBIwgbBEAgACACECghAUEBIwhBAUYNARogASEACyAEQQJGQQEjCBsEQCAAEC8hAUECIwhBAUYNARogASEHCyMIRQRAQaAIIQpBoAghCQsDQCAHIwhBAkZyBEAgByAHQQFrIwgbIQcgBEEDRkEBIwgbBEAgABAwIQFBAyMIQQFGDQMaIAEhAgsjCEUEQCAAKAIEIQMLIARBBEZBASMIGwRAIAMgAhAxIQFBBCMIQQFGDQMaIAEhAgsjCEUEQCACQRRrKAIQIQMLIARBBUZBASMIGwRAIAIgAxAbIQFBBSMIQQFGDQMaIAEhAgsjCEUEQCAAKAIAIQMLIARBBkZBASMIGwRAIAMgAkGgNUHQNRA0QQYjCEEBRg0DGgsgCwJ/IwhFBEAgAkGgNhAdIQMLIAMLIwgbIgsjCEECRnIEQCMIRQRAIAAoAgAhAwsgBEEHRkEBIwgbBEAgAyACQcA2QeA2EDRBByMIQQFGDQQaCyAEQQhGQQEjCBsEQCAAEDAhAUEIIwhBAUYNBBogASECCyMIRQRAIAAoAgQhAwsgBEEJRkEBIwgbBEAgAyACEDEhAUEJIwhBAUYNBBogASECCyMIRQRAIAJBFGsoAhAhAwsgBEEKRkEBIwgbBEAgAiADEBshAUEKIwhBAUYNBBogASEJCyMIRQRAQQEhECAAKAIAIQILIARBC0ZBASMIGwRAIAIQNkELIwhBAUYNBBoLCyALRSMIQQJGcgRAIAwCfyMIRQRAIAJBgDoQHSEDCyADCyMIGyIMIwhBAkZyBEAjCEUEQCAAKAIAIQMLIARBDEZBASMIGwRAIAMgAkHANkHgNhA0QQwjCEEBRg0FGgsgBEENRkEBIwgbBEAgABAwIQFBDSMIQQFGDQUaIAEhAgsjCEUEQCAAKAIEIQMLIARBDkZBASMIGwRAIAMgAhAxIQFBDiMIQQFGDQUaIAEhAgsjCEUEQCACQRRrKAIQIQMLIARBD0ZBASMIGwRAIAIgAxAbIQFBDyMIQQFGDQUaIAEhCgsjCEUEQEEBIREgACgCACECCyAEQRBGQQEjCBsEQCACEDZBECMIQQFGDQUaCwsgDEUjCEECRnIEQCANAn8jCEUEQCACQbA6EB0hAwsgAwsjCBsiDSMIQQJGcgRAIwhFBEAgACgCACEDCyAEQRFGQQEjCBsEQCADIAJB4DpB4DYQNEERIwhBAUYNBhoLIARBEkZBASMIGwRAIAAQPiEBQRIjCEEBRg0GGiABIQ8LIwhFBEAgACgCACECCyAEQRNGQQEjCBsEQCACEDZBEyMIQQFGDQYaCwsgDUUjCEECRnIEQCMIRQRAIAJB4D8QHSEDCyADIwhBAkZyBEAjCEUEQCAAKAIAIQULIARBFEZBASMIGwRAIAUgAkGQwABB4DYQNEEUIwhBAUYNBxoLIAVBACMIGyEFIARBFUZBASMIGwRAIAAQKiEBQRUjCEEBRg0HGiABIQILIAIgAkUjCBsiAiMIQQJGcgRAIwhFBEBBACEIQQAhAyAAQQhrKAIAQRJGIQILAkAgAiMIQQJGcgRAIARBFkZBASMIGwRAIAAQLyEBQRYjCEEBRg0KGiABIQULIwhFDQELIwhFBEAACwsDQCAFIwhBAkZyBEAgBSAFQQFrIwgbIQUgBEEXRkEBIwgbBEAgABCkASEBQRcjCEEBRg0KGiABIQILIwhFBEACQCAAQQhrKAIAQRJGBEAgACgCACEGDAELAAsLIARBGEZBASMIGwRAIAYgAkGgNUHQNRA0QRgjCEEBRg0KGgsgDgJ/IwhFBEAgAkHgwAAQHSEGCyAGCyMIGyIOIwhBAkZyBEAjCEUEQAJAIABBCGsoAgBBEkYEQCAAKAIAIQMMAQsACwsgBEEZRkEBIwgbBEAgAyACQYDBAEHgNhA0QRkjCEEBRg0LGgsgBEEaRkEBIwgbBEAgABClASEBQRojCEEBRg0LGiABIQMLIwhFBEACQCAAQQhrKAIAQRJGBEAgACgCACECDAELAAsLIARBG0ZBASMIGwRAIAIQNkEbIwhBAUYNCxoLCyAORSMIQQJGcgRAIwhFBEAgAkHAwQAQHSEGCyAGIwhBAkZyBEAjCEUEQAJAIABBCGsoAgBBEkYEQCAAKAIAIQgMAQsACwsgBEEcRkEBIwgbBEAgCCACQYDBAEHgNhA0QRwjCEEBRg0MGgsgBEEdRkEBIwgbBEAgABClASEBQR0jCEEBRg0MGiABIQgLIwhFBEACQCAAQQhrKAIAQRJGBEAgACgCACECDAELAAsLIARBHkZBASMIGwRAIAIQNkEeIwhBAUYNDBoLCwsjCEUEQAJAIABBCGsoAgBBEkYEQCAAKAIAIQIMAQsACwsgBEEfRkEBIwgbBEAgAhA2QR8jCEEBRg0KGgsjCEUNAQsLIARBIEZBASMIGwRAQQhBDRANIQFBICMIQQFGDQgaIAEhBQsjCEUEQCAFQQA2AgAgBUEANgIEIAUgAzYCACAFIAg2AgQLCyMIRQRAIAAoAgAhAgsgBEEhRkEBIwgbBEAgAhA2QSEjCEEBRg0HGgsLCwsLIwhFBEAgACgCACECCyAEQSJGQQEjCBsEQCACEDZBIiMIQQFGDQMaCyMIRQ0BCwsgAiAQRSMIGyICIwhBAkZyBEAjCEUEQCAAKAIAIQALIARBI0ZBASMIGwRAIABB4MEAECYhAUEjIwhBAUYNAhogASEACyAAIABBoAggABsjCBshACAEQSRGQQEjCBsEQCAAQdDCAEHFAEEFEApBJCMIQQFGDQIaCyMIRQRAAAsLIwhBAkYgAiARRSMIG3IEQCMIRQRAIAAoAgAhAAsgBEElRkEBIwgbBEAgAEGwwwAQJiEBQSUjCEEBRg0CGiABIQALIAAgAEGgCCAAGyMIGyEAIARBJkZBASMIGwRAIABB0MIAQcgAQQUQCkEmIwhBAUYNAhoLIwhFBEAACwsgBEEnRkEBIwgbBEAQPyEBQScjCEEBRg0BGiABIQALIwhFBEAgACAJNgIAIAAgCjYCBCAAIA82AgggACAFNgIMIAAPCwALIQEjCSgCACABNgIAIwkjCSgCAEEEajYCACMJKAIAIgEgADYCACABIAI2AgQgASADNgIIIAEgBTYCDCABIAY2AhAgASAHNgIUIAEgCDYCGCABIAk2AhwgASAKNgIgIAEgCzYCJCABIAw2AiggASANNgIsIAEgDjYCMCABIBA2AjQgASARNgI4IAEgDzYCPCMJIwkoAgBBQGs2AgBBAAumAgEEfyMIQQJGBEAjCSMJKAIAQQhrNgIAIwkoAgAiACgCACEBIAAoAgQhAgsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAwsgA0EAIwgbRQRAQRhBFhANIQBBACMIQQFGDQEaIAAhAQsgA0EBRkEBIwgbBEBBEBAZIQBBASMIQQFGDQEaIAAhAgsjCEUEQCABIAI2AgAgAUEDNgIECyADQQJGQQEjCBsEQEEwEBkhAEECIwhBAUYNARogACECCyMIRQRAIAEgAjYCCCABQQQ2AgwgAUEANgIQIAFBADYCFCABDwsACyEAIwkoAgAgADYCACMJIwkoAgBBBGo2AgAjCSgCACIAIAE2AgAgACACNgIEIwkjCSgCAEEIajYCAEEAC4kDAQd/IAAEfyAAIgFBFGsoAhBBAXZBAXQiA0EQTwR/QaiIjaECIQJB95Svr3ghBEHPjKKOBiEFIAEgA2pBEGshBwNAIAEgB00EQCABKAIAQfeUr694bCACakENd0Gx893xeWwhAiABKAIEQfeUr694bCAEakENd0Gx893xeWwhBCAGIAEoAghB95Svr3hsakENd0Gx893xeWwhBiAFIAEoAgxB95Svr3hsakENd0Gx893xeWwhBSABQRBqIQEMAQsLIAMgAkEBdyAEQQd3aiAGQQx3aiAFQRJ3amoFIANBsc/ZsgFqCyECIAAgA2pBBGshBANAIAEgBE0EQCABKAIAQb3cypV8bCACakERd0Gv1tO+AmwhAiABQQRqIQEMAQsLIAAgA2ohAANAIAAgAUsEQCABLQAAQbHP2bIBbCACakELd0Gx893xeWwhAiABQQFqIQEMAQsLIAIgAkEPdnNB95Svr3hsIgBBDXYhASAAIAFzQb3cypV8bCIAQRB2IQEgACABcwVBAAsLSgAgACgCACACIAAoAgRxQQJ0aigCACEAA0AgAARAIAAoAggiAkEBcQR/QQAFIAAoAgAgARAdCwRAIAAPCyACQX5xIQAMAQsLQQAL1QUBCX8jCEECRgRAIwkjCSgCAEEkazYCACMJKAIAIgYoAgAhACAGKAIEIQEgBigCCCECIAYoAgwhBCAGKAIQIQUgBigCFCEIIAYoAhghCSAGKAIcIQogBigCICEGCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEHC0EAIAMCfyMIRQRAIAAgASABEEIiChBDIQQLIAQLIwgbIgMjCEECRnIjCBsEQCAEIAI2AgQLIANFIwhBAkZyBEAjCEUEQCAAKAIQIgUgACgCDEYhCAsgCCMIQQJGcgRAIwhFBEAgACgCFCAAKAIMQQNsQQRtSAR/IAAoAgQFIAAoAgRBAXRBAXILIghBAWoiBEECdCEFCyAHQQAjCBtFBEAgBRAZIQNBACMIQQFGDQMaIAMhCQsjCEUEQCAEQQN0QQNtIgZBDGwhBQsgB0EBRkEBIwgbBEAgBRAZIQNBASMIQQFGDQMaIAMhBQsjCEUEQCAAKAIIIgMgACgCEEEMbGohCyAFIQQDQCADIAtHBEAgAygCCEEBcUUEQCAEIAMoAgAiBzYCACAEIAMoAgQ2AgQgBCAJIAcQQiAIcUECdGoiBygCADYCCCAHIAQ2AgAgBEEMaiEECyADQQxqIQMMAQsLIAAgCTYCACAAIAg2AgQgACAFNgIIIAAgBjYCDCAAIAAoAhQ2AhALCyMIRQRAIAAoAgghBCAAIAAoAhAiBUEBajYCECAEIAVBDGxqIgQgATYCACAEIAI2AgQgACAAKAIUQQFqNgIUIAQgACgCACAKIAAoAgRxQQJ0aiIAKAIANgIIIAAgBDYCAAsLDwshAyMJKAIAIAM2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAyAANgIAIAMgATYCBCADIAI2AgggAyAENgIMIAMgBTYCECADIAg2AhQgAyAJNgIYIAMgCjYCHCADIAY2AiAjCSMJKAIAQSRqNgIAC8sBAQJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEACwJ/IwhBAkYEfyMJIwkoAgBBBGs2AgAjCSgCACgCAAUgAQtBACMIG0UEQEEYQRUQDSEBQQAjCEEBRg0BGiABIQALIwhFBEAgAEEANgIAIABBADYCBCAAQQA2AgggAEEANgIMIABBADYCECAAQQA2AhQgAA8LAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAgADYCACMJIwkoAgBBBGo2AgBBAAuuAwEEfyMIQQJGBEAjCSMJKAIAQQxrNgIAIwkoAgAiASgCACEAIAEoAgghAyABKAIEIQILAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQQLIARBACMIG0UEQEEMQR0QDSEBQQAjCEEBRg0BGiABIQILIwhFBEAgAkEANgIAIAJBADYCBCACQQA2AgggAkUhAwsgAyMIQQJGckEAIARBAUZBASMIGxsEQEEAQR4QDSEBQQEjCEEBRg0BGiABIQILIwhFBEAgAiAANgIICyAEQQJGQQEjCBsEQEEQQQYQDSEBQQIjCEEBRg0BGiABIQALIwhFBEAgAEEANgIAIABBADYCBCAAQQA2AgggAEEANgIMCyAEQQNGQQEjCBsEQEEgQQAQDSEBQQMjCEEBRg0BGiABIQMLIwhFBEAgA0EgEA8gACADNgIAIAAgAzYCBCAAQSA2AgggAEEANgIMIAIgADYCBCACDwsACyEBIwkoAgAgATYCACMJIwkoAgBBBGo2AgAjCSgCACIBIAA2AgAgASACNgIEIAEgAzYCCCMJIwkoAgBBDGo2AgBBAAujAQEBfyMIQQJGBEAjCSMJKAIAQQhrNgIAIwkoAgAiASgCACEAIAEoAgQhAQsCfyMIQQJGBH8jCSMJKAIAQQRrNgIAIwkoAgAoAgAFIAILQQAjCBtFBEAgACABEKcBQQAjCEEBRg0BGgsPCyECIwkoAgAgAjYCACMJIwkoAgBBBGo2AgAjCSgCACICIAA2AgAgAiABNgIEIwkjCSgCAEEIajYCAAvXCQECfwJ/IwhBAkYEfyMJIwkoAgBBDGs2AgAjCSgCACIDKAIAIQAgAygCBCEBIAMoAggFIAMLIAEjCBshAyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAgsgAkEAIwgbRQRAIABBBBCmAUEAIwhBAUYNARoLIwhFBEACQAJAAkAgAEEIaygCACIBQSFHBEAgAUEdRg0BDAILIAAoAgAhAQwCCyAAKAIIIQEMAQsACwsgAkEBRkEBIwgbBEAgAUHQzgBBwDZBkMcAEDRBASMIQQFGDQEaCyACQQJGQQEjCBsEQCAAQdDOABCnAUECIwhBAUYNARoLIwhFBEAgAygCACEBCyACQQNGQQEjCBsEQCAAIAEQpwFBAyMIQQFGDQEaCyMIRQRAIAAhAQJAAkACQCAAQQhrKAIAIgBBIUcEQCAAQR1GDQEMAgsgASgCACEADAILIAEoAgghAAwBCwALCyACQQRGQQEjCBsEQCAAEDZBBCMIQQFGDQEaCyMIRQRAAkACQAJAIAEiAEEIaygCACIBQSFHBEAgAUEdRg0BDAILIAAoAgAhAQwCCyAAKAIIIQEMAQsACwsgAkEFRkEBIwgbBEAgAUHwzgBBkM0AQZDHABA0QQUjCEEBRg0BGgsgAkEGRkEBIwgbBEAgAEHwzgAQpwFBBiMIQQFGDQEaCyMIRQRAIAMoAgQhAQsgAkEHRkEBIwgbBEAgACABEKkBQQcjCEEBRg0BGgsjCEUEQCAAIQECQAJAAkAgAEEIaygCACIAQSFHBEAgAEEdRg0BDAILIAEoAgAhAAwCCyABKAIIIQAMAQsACwsgAkEIRkEBIwgbBEAgABA2QQgjCEEBRg0BGgsjCEUEQAJAAkACQCABIgBBCGsoAgAiAUEhRwRAIAFBHUYNAQwCCyAAKAIAIQEMAgsgACgCCCEBDAELAAsLIAJBCUZBASMIGwRAIAFBkM8AQZDNAEGQxwAQNEEJIwhBAUYNARoLIAJBCkZBASMIGwRAIABBkM8AEKcBQQojCEEBRg0BGgsjCEUEQCADKAIIIQELIAJBC0ZBASMIGwRAIAAgARCpAUELIwhBAUYNARoLIwhFBEAgACEBAkACQAJAIABBCGsoAgAiAEEhRwRAIABBHUYNAQwCCyABKAIAIQAMAgsgASgCCCEADAELAAsLIAJBDEZBASMIGwRAIAAQNkEMIwhBAUYNARoLIwhFBEACQAJAAkAgASIAQQhrKAIAIgFBIUcEQCABQR1GDQEMAgsgACgCACEBDAILIAAoAgghAQwBCwALCyACQQ1GQQEjCBsEQCABQcDPAEGQzQBBkMcAEDRBDSMIQQFGDQEaCyACQQ5GQQEjCBsEQCAAQcDPABCnAUEOIwhBAUYNARoLIwhFBEAgAygCDCEBCyACQQ9GQQEjCBsEQCAAIAEQqQFBDyMIQQFGDQEaCyMIRQRAAkACQAJAIABBCGsoAgAiAUEhRwRAIAFBHUYNAQwCCyAAKAIAIQAMAgsgACgCCCEADAELAAsLIAJBEEZBASMIGwRAIAAQNkEQIwhBAUYNARoLDwshAiMJKAIAIAI2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAiAANgIAIAIgATYCBCACIAM2AggjCSMJKAIAQQxqNgIAC6IBAQF/IwhBAkYEQCMJIwkoAgBBCGs2AgAjCSgCACIBKAIAIQAgASgCBCEBCwJ/IwhBAkYEfyMJIwkoAgBBBGs2AgAjCSgCACgCAAUgAgtBACMIG0UEQCAAIAEQSEEAIwhBAUYNARoLDwshAiMJKAIAIAI2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAiAANgIAIAIgATYCBCMJIwkoAgBBCGo2AgALxhwBC38jCEECRgRAIwkjCSgCAEEwazYCACMJKAIAIgUoAgAhACAFKAIEIQEgBSgCCCECIAUoAgwhAyAFKAIQIQYgBSgCFCEHIAUoAhghCCAFKAIcIQkgBSgCICEKIAUoAiQhCyAFKAIoIQwgBSgCLCEFCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEECyAEQQAjCBtFBEAgAEEGEKYBQQAjCEEBRg0BGgsjCEUEQAJAAkACQCAAQQhrKAIAIgJBIUciAwRAIAJBHUYNAQwCCyAAKAIAIQIMAgsgACgCCCECDAELAAsLIARBAUZBASMIGwRAIAJBwMkAQfDJAEGQxwAQNEEBIwhBAUYNARoLIARBAkZBASMIGwRAIABBwMkAEKcBQQIjCEEBRg0BGgsjCEUEQCABKAIAIQILIARBA0ZBASMIGwRAIAAgAkHAygBB4MoAEKgBQQMjCEEBRg0BGgsjCEUEQAJAAkACQCAAQQhrKAIAIgJBIUciAwRAIAJBHUYNAQwCCyAAKAIAIQIMAgsgACgCCCECDAELAAsLIARBBEZBASMIGwRAIAIQNkEEIwhBAUYNARoLIwhFBEACQAJAAkAgAEEIaygCACICQSFHIgMEQCACQR1GDQEMAgsgACgCACECDAILIAAoAgghAgwBCwALCyAEQQVGQQEjCBsEQCACQYDLAEHwyQBBkMcAEDRBBSMIQQFGDQEaCyAEQQZGQQEjCBsEQCAAQYDLABCnAUEGIwhBAUYNARoLIwhFBEAgASgCBCECCyAEQQdGQQEjCBsEQCAAIAJBsMsAQdDLABCoAUEHIwhBAUYNARoLIwhFBEACQAJAAkAgAEEIaygCACICQSFHIgMEQCACQR1GDQEMAgsgACgCACECDAILIAAoAgghAgwBCwALCyAEQQhGQQEjCBsEQCACEDZBCCMIQQFGDQEaCyMIRQRAAkACQAJAIABBCGsoAgAiAkEhRyIDBEAgAkEdRg0BDAILIAAoAgAhAgwCCyAAKAIIIQIMAQsACwsgBEEJRkEBIwgbBEAgAkHwywBBoMwAQZDHABA0QQkjCEEBRg0BGgsgBEEKRkEBIwgbBEAgAEHwywAQpwFBCiMIQQFGDQEaCyMIRQRAIABBCGsoAgAiA0EhRyEGIAEoAgghAgsCQAJAAkAgBwJ/IwhFBEAgBgRAIANBHUYiAw0DDAQLIAJBAE4iBiACQYABSHEhAwsgAwsjCBsiByMIQQJGcgRAIwhFBEAgACgCBCEDCyAEQQtGQQEjCBsEQCADIAIQmAFBCyMIQQFGDQUaCwsgB0UjCEECRnIEQCAIAn8jCEUEQCACQWBOIgYgAkEASHEhAwsgAwsjCBsiCCMIQQJGcgRAIwhFBEAgACgCBCEDIAJB4AFyIQILIARBDEZBASMIGwRAIAMgAhCYAUEMIwhBAUYNBhoLCyAIRSMIQQJGcgRAIAkCfyMIRQRAIAJBgH9OIgYgAkH/AExxIQMLIAMLIwgbIgkjCEECRnIEQCMIRQRAIAAoAgQhAwsgBEENRkEBIwgbBEAgA0HQARCYAUENIwhBAUYNBxoLIwhFBEAgACgCBCEDCyAEQQ5GQQEjCBsEQCADQcDQAUEBEClBDiMIQQFGDQcaCyMIRQRAIAMoAgAgAygCDGoiBiACOgAAIAMgAygCDEEBaiICNgIMCwsgCUUjCEECRnIEQCAKAn8jCEUEQCACQYCAfk4iBiACQf//AUxxIQMLIAMLIwgbIgojCEECRnIEQCMIRQRAIAAoAgQhAwsgBEEPRkEBIwgbBEAgA0HRARCYAUEPIwhBAUYNCBoLIwhFBEAgACgCBCEDCyAEQRBGQQEjCBsEQCADQfDQAUECEClBECMIQQFGDQgaCyMIRQRAIAMoAgAgAygCDGoiBiACQQh0IAJB//8DcUEIdnI7AQAgAyADKAIMQQJqIgI2AgwLCyAKRSMIQQJGcgRAIwhFBEAgACgCBCEDCyAEQRFGQQEjCBsEQCADQdIBEJgBQREjCEEBRg0IGgsjCEUEQCAAKAIEIQMLIARBEkZBASMIGwRAIANBoNEBQQQQKUESIwhBAUYNCBoLIwhFBEAgAygCACADKAIMaiIGIAJBgP6DeHFBCHcgAkH/gfwHcUEIeHI2AgAgAyADKAIMQQRqIgI2AgwLCwsLCyMIRQ0CCyMIRQRAIAJBYE4iBiACQYABSHEiAwRAIAAgACgCAEEBaiICNgIABSACQYB/TiIGIAJBgAFIcSIDBEAgACAAKAIAQQJqIgI2AgAFIAJBgIACSCIDIAJBgIB+TnEEQCAAIAAoAgBBA2oiAjYCAAUgACAAKAIAQQVqIgI2AgALCwsMAgsLIwhFBEAACwsjCEUEQAJAAkACQCAAIgJBCGsoAgAiAEEhRyIDBEAgAEEdRg0BDAILIAIoAgAhAAwCCyACKAIIIQAMAQsACwsgBEETRkEBIwgbBEAgABA2QRMjCEEBRg0BGgsjCEUEQAJAAkACQCACQQhrKAIAIgBBIUciAwRAIABBHUYNAQwCCyACKAIAIQAMAgsgAigCCCEADAELAAsLIARBFEZBASMIGwRAIABB8MwAQZDNAEGQxwAQNEEUIwhBAUYNARoLIARBFUZBASMIGwRAIAJB8MwAEKcBQRUjCEEBRg0BGgsjCEUEQCABKAIMIQALIARBFkZBASMIGwRAIAIgABCpAUEWIwhBAUYNARoLIwhFBEACQAJAAkAgAkEIaygCACIAQSFHIgMEQCAAQR1GDQEMAgsgAigCACEADAILIAIoAgghAAwBCwALCyAEQRdGQQEjCBsEQCAAEDZBFyMIQQFGDQEaCyMIRQRAAkACQAJAIAJBCGsoAgAiAEEhRyIDBEAgAEEdRg0BDAILIAIoAgAhAAwCCyACKAIIIQAMAQsACwsgBEEYRkEBIwgbBEAgAEHAzQBB8M0AQZDHABA0QRgjCEEBRg0BGgsgBEEZRkEBIwgbBEAgAkHAzQAQpwFBGSMIQQFGDQEaCyMIRQRAIAJBCGsoAgAiA0EhRyEGIAEoAhAhAAsCQAJAAkAjCEUEQCAGBEAgA0EdRiIDDQIMAwsgAEUhAwsCQCADIwhBAkZyBEAjCEUEQCACKAIEIQALIARBGkZBASMIGwRAIABBwAEQmAFBGiMIQQFGDQYaCyMIRQ0BCyAEQRtGQQEjCBsEQCACIABB4M8AEKABQRsjCEEBRg0FGgsLIwhFDQILAkAjCEEBIAMgAEUjCBsiAxtFBEAgAiACKAIAQQFqIgA2AgAMAQsgBEEcRkEBIwgbBEAgAiAAQeDPABChAUEcIwhBAUYNBBoLCyMIRQ0BCyMIRQRAAAsLIwhFBEACQAJAAkAgAkEIaygCACIAQSFHIgMEQCAAQR1GDQEMAgsgAigCACEADAILIAIoAgghAAwBCwALCyAEQR1GQQEjCBsEQCAAEDZBHSMIQQFGDQEaCyMIRQRAAkACQAJAIAJBCGsoAgAiAEEhRyIDBEAgAEEdRg0BDAILIAIoAgAhAAwCCyACKAIIIQAMAQsACwsgBEEeRkEBIwgbBEAgAEGwOkHgOkGQxwAQNEEeIwhBAUYNARoLIARBH0ZBASMIGwRAIAJBsDoQpwFBHyMIQQFGDQEaCyMIRQRAIAEoAhQhACACQQhrKAIAIgFBIUchAwsCQAJAAkAjCEUEQCADBEAgAUEdRg0CDAMLIABFIQELAkAgASMIQQJGcgRAIwhFBEAgAigCBCEACyAEQSBGQQEjCBsEQCAAQcABEJgBQSAjCEEBRg0GGgsjCEUNAQsgCwJ/IwhFBEAgACgCACIAQYABSSEBCyABCyMIGyILIwhBAkZyBEAjCEUEQCACKAIEIQELIARBIUZBASMIGwRAIAEgABCYAUEhIwhBAUYNBhoLCyALRSMIQQJGcgRAIAwgASAAQf8BTSMIGyIBIwgbIgwjCEECRnIEQCMIRQRAIAIoAgQhAQsgBEEiRkEBIwgbBEAgAUHMARCYAUEiIwhBAUYNBxoLIwhFBEAgAigCBCEBCyAEQSNGQQEjCBsEQCABIAAQmAFBIyMIQQFGDQcaCwsgDEUjCEECRnIEQCAFIAEgAEH//wNNIwgbIgEjCBsiBSMIQQJGcgRAIwhFBEAgAigCBCEBCyAEQSRGQQEjCBsEQCABQc0BEJgBQSQjCEEBRg0IGgsjCEUEQCACKAIEIQELIARBJUZBASMIGwRAIAEgABCZAUElIwhBAUYNCBoLCyAFRSMIQQJGcgRAIwhFBEAgAigCBCEBCyAEQSZGQQEjCBsEQCABQc4BEJgBQSYjCEEBRg0IGgsjCEUEQCACKAIEIQELIARBJ0ZBASMIGwRAIAEgABCaAUEnIwhBAUYNCBoLCwsLCyMIRQ0CCyMIRQRAIABFBEAgAiACKAIAQQFqIgA2AgAMAwsgACgCACIAQYABSQRAIAIgAigCAEEBaiIANgIABSAAQYACSQRAIAIgAigCAEECaiIANgIABSAAQYCABEkEQCACIAIoAgBBA2oiADYCAAUgAiACKAIAQQVqIgA2AgALCwsMAgsLIwhFBEAACwsjCEUEQAJAAkACQCACQQhrKAIAIgBBIUcEQCAAQR1GDQEMAgsgAigCACEADAILIAIoAgghAAwBCwALCyAEQShGQQEjCBsEQCAAEDZBKCMIQQFGDQEaCw8LIQQjCSgCACAENgIAIwkjCSgCAEEEajYCACMJKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIAQgAzYCDCAEIAY2AhAgBCAHNgIUIAQgCDYCGCAEIAk2AhwgBCAKNgIgIAQgCzYCJCAEIAw2AiggBCAFNgIsIwkjCSgCAEEwajYCAAvXBgEEfyMIQQJGBEAjCSMJKAIAQRRrNgIAIwkoAgAiBSgCACEAIAUoAgQhASAFKAIIIQIgBSgCDCEEIAUoAhAhBQsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAwsgA0EAIwgbRQRAIABBAhCmAUEAIwhBAUYNARoLIwhFBEACQAJAAkAgACICQQhrKAIAIgRBIUcEQCAEQR1GDQEMAgsgAigCACEEDAILIAIoAgghBAwBCwALCyADQQFGQQEjCBsEQCAEQfDGAEHANkGQxwAQNEEBIwhBAUYNARoLIANBAkZBASMIGwRAIAJB8MYAEKcBQQIjCEEBRg0BGgsjCEUEQCABKAIAIQQLIANBA0ZBASMIGwRAIAIgBBCnAUEDIwhBAUYNARoLIwhFBEACQAJAAkAgAkEIaygCACIEQSFHBEAgBEEdRg0BDAILIAIoAgAhAgwCCyACKAIIIQIMAQsACwsgA0EERkEBIwgbBEAgAhA2QQQjCEEBRg0BGgsjCEUEQAJAAkACQCAAQQhrKAIAIgJBIUcEQCACQR1GDQEMAgsgACgCACECDAILIAAoAgghAgwBCwALCyADQQVGQQEjCBsEQCACQdDHAEGAyABBkMcAEDRBBSMIQQFGDQEaCyADQQZGQQEjCBsEQCAAQdDHABCnAUEGIwhBAUYNARoLIAUCfyMIRQRAIAEoAgQhAgsgAgsjCBsiBSMIQQJGcgRAIwgEfyACBSABKAIEIgFFCyMIQQJGcgRAIANBB0ZBASMIGwRAQdDEAEHQyABB1QBBJhAKQQcjCEEBRg0DGgsjCEUEQAALCyADQQhGQQEjCBsEQCAAIAEQSkEIIwhBAUYNAhoLCyAFRSMIQQJGckEAIANBCUZBASMIGxsEQCAAEKoBQQkjCEEBRg0BGgsjCEUEQAJAAkACQCAAQQhrKAIAIgFBIUcEQCABQR1GDQEMAgsgACgCACEADAILIAAoAgghAAwBCwALCyADQQpGQQEjCBsEQCAAEDZBCiMIQQFGDQEaCw8LIQMjCSgCACADNgIAIwkjCSgCAEEEajYCACMJKAIAIgMgADYCACADIAE2AgQgAyACNgIIIAMgBDYCDCADIAU2AhAjCSMJKAIAQRRqNgIAC4QDAQR/IwhBAkYEQCMJIwkoAgBBFGs2AgAjCSgCACIDKAIAIQAgAygCCCECIAMoAgwhBCADKAIQIQUgAygCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEGCyAGQQAjCBtFBEBBEEEhEA0hA0EAIwhBAUYNARogAyEECyMIRQRAIARBADYCACAEQQA2AgQgBEEANgIIIARBADYCDCAERSEFCyAFIwhBAkZyQQAgBkEBRkEBIwgbGwRAQQBBHhANIQNBASMIQQFGDQEaIAMhBAsjCEUEQCAEIAI2AgAgAEEUaygCECEFCyAGQQJGQQEjCBsEQCAAIAUgAhAnIQNBAiMIQQFGDQEaIAMhAAsjCEUEQCAEIAA2AgQgBCABNgIIIARBADYCDCAEDwsACyEDIwkoAgAgAzYCACMJIwkoAgBBBGo2AgAjCSgCACIDIAA2AgAgAyABNgIEIAMgAjYCCCADIAQ2AgwgAyAFNgIQIwkjCSgCAEEUajYCAEEAC/kBAQJ/IwhBAkYEQCMJIwkoAgBBEGs2AgAjCSgCACIDKAIAIQAgAygCBCEBIAMoAgghAiADKAIMIQMLAn8jCEECRgR/IwkjCSgCAEEEazYCACMJKAIAKAIABSAEC0EAIwgbRQRAQQlBIhANIQRBACMIQQFGDQEaIAQhAwsjCEUEQCADQQA2AgAgA0EANgIEIANBADoACCADIAA6AAggAyABNgIAIAMgAjYCBCADDwsACyEEIwkoAgAgBDYCACMJIwkoAgBBBGo2AgAjCSgCACIEIAA2AgAgBCABNgIEIAQgAjYCCCAEIAM2AgwjCSMJKAIAQRBqNgIAQQALyAUBBn8jCEECRgRAIwkjCSgCAEEYazYCACMJKAIAIgIoAgAhACACKAIIIQQgAigCDCEFIAIoAhAhBiACKAIUIQcgAigCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEDCyMIRQRAQQEkAAsgA0EAIwgbRQRAQZANEAkhAkEAIwhBAUYNARogAiEECyMIRQRAQQEkAAsgA0EBRkEBIwgbBEAgABAJIQJBASMIQQFGDQEaIAIhAAsjCEUEQCAAQRRrKAIQIQYgAUEUaygCECEHIARBFGsoAhAhBQsgA0ECRkEBIwgbBEAgBCAFIAAgBiABIAcQAiECQQIjCEEBRg0BGiACIQALIAAgAEUjCBsiACMIQQJGcgRAIANBA0ZBASMIGwRAEAMhAkEDIwhBAUYNAhogAiEACyADQQRGQQEjCBsEQCAAEBkhAkEEIwhBAUYNAhogAiEACyADQQVGQQEjCBsEQCAAEARBBSMIQQFGDQIaCyMIRQRAIABBFGsoAhAhAQsgA0EGRkEBIwgbBEAgACABEBshAkEGIwhBAUYNAhogAiEACyADQQdGQQEjCBsEQEEAQQAgABBNIQJBByMIQQFGDQIaIAIhAAsjCEUEQCAADwsLIANBCEZBASMIGwRAEAUhAkEIIwhBAUYNARogAiEACyADQQlGQQEjCBsEQCAAEBkhAkEJIwhBAUYNARogAiEACyADQQpGQQEjCBsEQCAAEAZBCiMIQQFGDQEaCyMIRQRAQQIkAAsgA0ELRkEBIwgbBEBBASAAQQAQTSECQQsjCEEBRg0BGiACIQALIwhFBEAgAA8LAAshAiMJKAIAIAI2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAiAANgIAIAIgATYCBCACIAQ2AgggAiAFNgIMIAIgBjYCECACIAc2AhQjCSMJKAIAQRhqNgIAQQAL+QEBAn8jCEECRgRAIwkjCSgCAEEQazYCACMJKAIAIgMoAgAhACADKAIEIQEgAygCCCECIAMoAgwhAwsCfyMIQQJGBH8jCSMJKAIAQQRrNgIAIwkoAgAoAgAFIAQLQQAjCBtFBEBBCUEbEA0hBEEAIwhBAUYNARogBCEDCyMIRQRAIANBADYCACADQQA2AgQgA0EAOgAIIAMgADoACCADIAE2AgAgAyACNgIEIAMPCwALIQQjCSgCACAENgIAIwkjCSgCAEEEajYCACMJKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIAQgAzYCDCMJIwkoAgBBEGo2AgBBAAugAQEBfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAAsCfyMIQQJGBH8jCSMJKAIAQQRrNgIAIwkoAgAoAgAFIAELQQAjCBtFBEAgABCkASEBQQAjCEEBRg0BGiABIQALIwhFBEAgAA8LAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAgADYCACMJIwkoAgBBBGo2AgBBAAvgFgEPfyMIQQJGBEAjCSMJKAIAQThrNgIAIwkoAgAiAigCACEAIAIoAgghAyACKAIMIQUgAigCECEGIAIoAhQhByACKAIYIQggAigCHCEJIAIoAiAhCiACKAIkIQsgAigCKCEMIAIoAiwhDSACKAIwIQ4gAigCNCEPIAIoAgQhAQsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBAsjCEUEQCAAQQhrKAIAQRJGIQMLAkAgAyMIQQJGcgRAIARBACMIG0UEQCAAEC8hAkEAIwhBAUYNAxogAiEHCyMIRQ0BCyMIRQRAAAsLIAZBoAgjCBshBgNAIAcjCEECRnIEQCAHIAdBAWsjCBshByAEQQFGQQEjCBsEQCAAEKQBIQJBASMIQQFGDQMaIAIhAwsjCEUEQAJAIABBCGsoAgBBEkYEQCAAKAIAIQUMAQsACwsgBEECRkEBIwgbBEAgBSADQaA1QdA1EDRBAiMIQQFGDQMaCyAKAn8jCEUEQCADQcDVABAdIQULIAULIwgbIgojCEECRnIEQCMIRQRAAkAgAEEIaygCAEESRgRAIAAoAgAhCAwBCwALCyAEQQNGQQEjCBsEQCAIIANB4NUAQeA2EDRBAyMIQQFGDQQaCyMIRQRAIABBCGsoAgBBEkYhAwsCQCADIwhBAkZyBEAgBEEERkEBIwgbBEAgABCXASECQQQjCEEBRg0GGiACIQgLIwhFDQELIwhFBEAACwsjCEUEQEEBIQ4CQCAAQQhrKAIAQRJGBEAgACgCACEDDAELAAsLIARBBUZBASMIGwRAIAMQNkEFIwhBAUYNBBoLCyAKRSMIQQJGcgRAIAsCfyMIRQRAIANBgNYAEB0hBQsgBQsjCBsiCyMIQQJGcgRAIwhFBEACQCAAQQhrKAIAQRJGBEAgACgCACEGDAELAAsLIARBBkZBASMIGwRAIAYgA0HANkHgNhA0QQYjCEEBRg0FGgsgBEEHRkEBIwgbBEAgABCkASECQQcjCEEBRg0FGiACIQYLIwhFBEBBASEPAkAgAEEIaygCAEESRgRAIAAoAgAhAwwBCwALCyAEQQhGQQEjCBsEQCADEDZBCCMIQQFGDQUaCwsgC0UjCEECRnIEQCAMAn8jCEUEQCADQcDJABAdIQULIAULIwgbIgwjCEECRnIEQCMIRQRAAkAgAEEIaygCAEESRgRAIAAoAgAhAQwBCwALCyAEQQlGQQEjCBsEQCABIANB8MkAQeA2EDRBCSMIQQFGDQYaCyMIRQRAIABBCGsoAgBBEkYhAQsCQCABIwhBAkZyBEBBAAJ/IARBCkZBASMIGwRAIAAQKiECQQojCEEBRg0JGiACIQELIAELQQAgDSABIwgbIg0jCEECRnIjCBsbIQEgDUUjCEECRnIEQCMIRQRAIAAoAgQhAQsgBEELRkEBIwgbBEAgAUHAI0EAEClBCyMIQQFGDQkaCyMIRQRAIAEoAgAgASgCDGotAAAiAUHwAXFBgAFGIQMLAkAgAyMIQQJGcgRAIARBDEZBASMIGwRAIAAQogEhAkEMIwhBAUYNCxogAiEBCyMIRQ0BCyADIAFBgAFGIwgbIQMCQAJAAkACQAJAIwhFBEACQCADDQAgAUHeAUYiAw0AIAFB1AFGIgMNBiABQdUBRiIDDQYgAUHWAUYiAw0GIAFB1wFGIgMNBiABQdgBRiIDDQYgAUHHAUYiAw0CIAFByAFGIgMNAyABQckBRiIDDQQMBQsLIARBDUZBASMIGwRAIAAQogEhAkENIwhBAUYNDxogAiEBCyMIRQ0FCyMIRQRAIAAoAgQhAQsgBEEORkEBIwgbBEAgARArIQJBDiMIQQFGDQ4aIAIhAQsjCEUNAwsjCEUEQCAAKAIEIQELIARBD0ZBASMIGwRAIAEQLCECQQ8jCEEBRg0NGiACIQELIwhFDQILIwhFBEAgACgCBCEBCyAEQRBGQQEjCBsEQCABEC0hAkEQIwhBAUYNDBogAiEBCyMIRQ0BCyMIRQRAIAAoAgAhAAsgBEERRkEBIwgbBEAgARAuIQJBESMIQQFGDQsaIAIhAQsgBEESRkEBIwgbBEBB8NEBIAEQHyECQRIjCEEBRg0LGiACIQELIARBE0ZBASMIGwRAIAAgARAmIQJBEyMIQQFGDQsaIAIhAAsgACAAQaAIIAAbIwgbIQAgBEEURkEBIwgbBEAgAEGgM0HUAkEJEApBFCMIQQFGDQsaCyMIRQRAAAsLIwhFBEAgACgCBCEBCyAEQRVGQQEjCBsEQCABECshAkEVIwhBAUYNChogAiEBCyMIRQRAIAAoAgQhAQsgAwJ/IARBFkZBASMIGwRAIAEQKyECQRYjCEEBRg0LGiACIQELIAFB/wFxQQFHCyMIGyIDIwhBAkZyBEAjCEUEQCABQf8BcSEBIAAoAgAhAAsgBEEXRkEBIwgbBEAgARAhIQJBFyMIQQFGDQsaIAIhAQsgBEEYRkEBIwgbBEBB4NIBIAEQHyECQRgjCEEBRg0LGiACIQELIARBGUZBASMIGwRAIAAgARAmIQJBGSMIQQFGDQsaIAIhAAsgACAAQaAIIAAbIwgbIQAgBEEaRkEBIwgbBEAgAEGgM0HjAkEHEApBGiMIQQFGDQsaCyMIRQRAAAsLIARBG0ZBASMIGwRAIAAQogEhAkEbIwhBAUYNChogAiEBCwsLIwhFDQELIwhFBEAACwsjCEUEQAJAIABBCGsoAgBBEkYEQCAAKAIAIQMMAQsACwsgBEEcRkEBIwgbBEAgAxA2QRwjCEEBRg0GGgsLIAxFIwhBAkZyBEAjCEUEQCADQfDMABAdIQULIAUjCEECRnIEQCMIRQRAAkAgAEEIaygCAEESRgRAIAAoAgAhCQwBCwALCyAEQR1GQQEjCBsEQCAJIANBkM0AQeA2EDRBHSMIQQFGDQcaCyAEQR5GQQEjCBsEQCAAEKsBIQJBHiMIQQFGDQcaIAIhCQsjCEUEQAJAIABBCGsoAgBBEkYEQCAAKAIAIQMMAQsACwsgBEEfRkEBIwgbBEAgAxA2QR8jCEEBRg0HGgsLCwsLIwhFBEACQCAAQQhrKAIAQRJGBEAgACgCACEDDAELAAsLIARBIEZBASMIGwRAIAMQNkEgIwhBAUYNAxoLIwhFDQELCyADIA5FIwgbIgMjCEECRnIEQCMIRQRAAkAgAEEIaygCAEESRiIBBEAgACgCACEADAELAAsLIARBIUZBASMIGwRAIABB8NYAECYhAkEhIwhBAUYNAhogAiEACyAAIABBoAggABsjCBshACAEQSJGQQEjCBsEQCAAQeDXAEHmAEEFEApBIiMIQQFGDQIaCyMIRQRAAAsLIwhBAkYgAyAPRSMIG3IEQCMIRQRAAkAgAEEIaygCAEESRiIBBEAgACgCACEADAELAAsLIARBI0ZBASMIGwRAIABB4NgAECYhAkEjIwhBAUYNAhogAiEACyAAIABBoAggABsjCBshACAEQSRGQQEjCBsEQCAAQeDXAEHpAEEFEApBJCMIQQFGDQIaCyMIRQRAAAsLIARBJUZBASMIGwRAQRBBGhANIQJBJSMIQQFGDQEaIAIhAAsjCEUEQCAAQQA2AgAgAEEANgIEIABBADYCCCAAQQA2AgwgACAINgIAIAAgBjYCBCAAIAE2AgggACAJNgIMIAAPCwALIQIjCSgCACACNgIAIwkjCSgCAEEEajYCACMJKAIAIgIgADYCACACIAE2AgQgAiADNgIIIAIgBTYCDCACIAY2AhAgAiAHNgIUIAIgCDYCGCACIAk2AhwgAiAKNgIgIAIgCzYCJCACIAw2AiggAiANNgIsIAIgDjYCMCACIA82AjQjCSMJKAIAQThqNgIAQQALwwEBAX8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQALAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQELIwhFBEACQAJAAkAjAEEBaw4DAQICAAsAC0EAIQALCyABQQAjCBtFBEBBASAAQQAQTyEBQQAjCEEBRg0BGiABIQALIwhFBEAgAA8LAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAgADYCACMJIwkoAgBBBGo2AgBBAAvIAgEEfyMIQQJGBEAjCSMJKAIAQRBrNgIAIwkoAgAiAigCACEAIAIoAgghAyACKAIMIQQgAigCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEFCyMIRQRAIAFBACABQQBKGyEDIAMgAEEUaygCEEEBdiIBSCEEIAMgASAEGyIDIAFIIQQgAyABIAEgA0gbQQF0IgIgAyABIAQbQQF0IgRrIgNFBEBBoAgPC0EAIAIgAUEBdEYgBBsiAQRAIAAPCwsgBUEAIwgbRQRAIANBARANIQJBACMIQQFGDQEaIAIhAQsjCEUEQCABIAAgBGogAxAXIAEPCwALIQIjCSgCACACNgIAIwkjCSgCAEEEajYCACMJKAIAIgIgADYCACACIAE2AgQgAiADNgIIIAIgBDYCDCMJIwkoAgBBEGo2AgBBAAvvAQECfyMIQQJGBEAjCSMJKAIAQQxrNgIAIwkoAgAiAigCACEAIAIoAgQhASACKAIIIQILAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQMLIwhFBEAgAEEUaygCEEEBdiABTSICBEBBoAgPCwsgA0EAIwgbRQRAQQJBARANIQNBACMIQQFGDQEaIAMhAgsjCEUEQCACIAFBAXQgAGovAQA7AQAgAg8LAAshAyMJKAIAIAM2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAyAANgIAIAMgATYCBCADIAI2AggjCSMJKAIAQQxqNgIAQQALtggBDH8jCEECRgRAIwkjCSgCAEEYazYCACMJKAIAIgIoAgAhACACKAIIIQUgAigCDCEGIAIoAhAhByACKAIUIQggAigCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEKCyMIRQRAIABBFGsoAhBBAXYiCEUEQCAADwsgCEEGbCEBCyAKQQAjCBtFBEAgAUEBEA0hAkEAIwhBAUYNARogAiEGCyMIRQRAQYzaACgCAEEBdiELA0AgByAISQRAIAdBAXQgAGovAQAiAUEHdgRAAkAgAUH/rwNrQYEISSAHIAhBAWtJcQRAIAdBAXQgAGovAQIiA0H/twNrQYEISQRAIAdBAWohByABIQIgA0H/B3EgAUH/B3FBCnRyQYCABGoiAUGAgAhPBEAgBiAFQQF0aiADQRB0IAJyNgIAIAVBAWohBQwDCwsLIAFB0MkAa0EZTQRAIAYgBUEBdGogAUEaazsBAAVBfyEDIAFB3wFrQbj0A00EQAJAIAshAkEAIQQDQCACIAROBEAgAiAEakEDdkECdCIDQQF0QZDaAGovAQAgAWsiCUUNAiAJQR92BEAgA0EEaiEEBSADQQRrIQILDAELC0F/IQMLCyADQX9zBEAgA0EBdEGQ2gBqIgEvAQYhAiAGIAVBAXRqIgMgASgCAjYCACADIAI7AQQgBSACQQBHQQFqaiEFBSABIQIgAUEIdiIDQYT+AGotAAAhBCAEIANBzOEAai0AAEHWAGxBzOEAaiABQf8BcSIJQQNuai0AACAJQQNwQQJ0Qbj2AGooAgBsQQt2QQZwakECdEHE9gBqKAIAIgNB/wFxIQQCfyADQQh1IgNBACAEQQFza3EgAWogBEECSQ0AGiADQf8BcSEBIANBCHYhAwNAIAEEQCAJIAFBAXYiBCADakEBdEGEggFqLQAAIgxGBH9BACADIARqQQF0QYSCAWotAAFBAnRBxPYAaigCACIBQf8BcSIDQQFzayEEIAIgBCABQQh1cWogA0ECSQ0DGiACQQFrDAMFIAkgDEkEfyAEBSADIARqIQMgASAEawsLIQEMAQsLIAILQf///wBxIgFBgIAESQRAIAYgBUEBdGogATsBAAUgAUGAgARrIgFBCnZBgLADciEEIAYgBUEBdGogBCABQf8HcUGAuANyQRB0cjYCACAFQQFqIQULCwsLBSAGIAVBAXRqIAFBzOAAai0AADsBAAsgB0EBaiEHIAVBAWohBQwBCwsgBUEBdCEACyAKQQFGQQEjCBsEQCAGIAAQGiECQQEjCEEBRg0BGiACIQALIwhFBEAgAA8LAAshAiMJKAIAIAI2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAiAANgIAIAIgATYCBCACIAU2AgggAiAGNgIMIAIgBzYCECACIAg2AhQjCSMJKAIAQRhqNgIAQQAL+wMBB38jCEECRgRAIwkjCSgCAEEYazYCACMJKAIAIgIoAgAhACACKAIIIQMgAigCDCEEIAIoAhAhBiACKAIUIQcgAigCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEICyMIRQRAIAFBAWsiBkEASARAQaAIDwsgBkUEQCAAKAIAIgAEfyAABUGgCAsPCwNAIAEgBUoEQCAFQQJ0IABqKAIAIgIEQCADIAJBFGsoAhBBAXZqIQMLIAVBAWohBQwBCwsgAyAGQZwIKAIAQQF2IgFsakEBdCEHCyAIQQAjCBtFBEAgB0EBEA0hAkEAIwhBAUYNARogAiEDCyMIRQRAQQAhBQNAIAUgBkgEQCAFQQJ0IABqKAIAIgIEQCACIgdBFGsoAhBBAXYhAiADIARBAXRqIAcgAkEBdBAXIAIgBGohBAsgAQRAIAMgBEEBdGpBoAggAUEBdBAXIAEgBGohBAsgBUEBaiEFDAELCyAGQQJ0IABqKAIAIgAEQCADIARBAXRqIAAgAEEUaygCEEEBdkEBdBAXCyADDwsACyECIwkoAgAgAjYCACMJIwkoAgBBBGo2AgAjCSgCACICIAA2AgAgAiABNgIEIAIgAzYCCCACIAQ2AgwgAiAGNgIQIAIgBzYCFCMJIwkoAgBBGGo2AgBBAAurBgEFfyMIQQJGBEAjCSMJKAIAQRxrNgIAIwkoAgAiBCgCACEAIAQoAgghAiAEKAIMIQMgBCgCECEFIAQoAhQhByAEKAIYIQggBCgCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEGCyMIRQRAIAEEf0Hc2QAoAgBBAXYiBSABQRRrKAIQQQF2SgR/QQEFIAFBAEHg2QAgBRAcCwVBAQtFIQULIAUjCEECRnIEQCAGQQAjCBtFBEAgAUEHEFMhBEEAIwhBAUYNAhogBCEBCyAGQQFGQQEjCBsEQCABQQAQVCEEQQEjCEEBRg0CGiAEIQULIAZBAkZBASMIGwRAIAUQVSEEQQIjCEEBRg0CGiAEIQULIAZBA0ZBASMIGwRAIAFBARBTIQRBAyMIQQFGDQIaIAQhAQsgBkEERkEBIwgbBH8gBSABEB8hBEEEIwhBAUYNAhogBAUgAQshAQsjCEUEQEGUhgEgADYCAEGchgEgAUGgCCABGzYCAEGMhgEoAgBBAnYhAAsgBkEFRkEBIwgbBEBBkIYBIAAQViEEQQUjCEEBRg0BGiAEIQELQaAIIAcgAiMIGyIHIwhBAkZyBH8jCEUEQCACKAIAIQALIAZBBkZBASMIGwRAIAAQIiEEQQYjCEEBRg0CGiAEIQALIAZBB0ZBASMIGwR/QcCGASAAEB8hBEEHIwhBAUYNAhogBAUgAAsFIAALQQAgB0UjCEECRnIjCBsbIQAgBkEIRkEBIwgbBEAgASAAEB8hBEEIIwhBAUYNARogBCEBC0GgCCAIIAMjCBsiCCMIQQJGckEAIAZBCUZBASMIGxsEf0HwhgEgAxAfIQRBCSMIQQFGDQEaIAQFIAALQQAgCEUjCEECRnIjCBsbIQAgBkEKRkEBIwgbBEAgASAAEB8hBEEKIwhBAUYNARogBCEACyMIRQRAIAAPCwALIQQjCSgCACAENgIAIwkjCSgCAEEEajYCACMJKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIAQgAzYCDCAEIAU2AhAgBCAHNgIUIAQgCDYCGCMJIwkoAgBBHGo2AgBBAAuoCAEEfyMIQQJGBEAjCSMJKAIAQRBrNgIAIwkoAgAiAigCACEAIAIoAgghAyACKAIMIQUgAigCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEECyMIRQRAIAEtAAhFIQMLIAMjCEECRnIEQCMIRQRAIAEtAAghAwsgAyMIQQJGcgRAIARBACMIG0UEQEGg0QBB4NEAQbQBQQkQCkEAIwhBAUYNAxoLIwhFBEAACwsjCEUEQCABKAIEIQELIARBAUZBASMIGwRAIAAgAUEAQQAQVyECQQEjCEEBRg0CGiACIQALIAAgAEGgCCAAGyMIGyEAIARBAkZBASMIGwRAIABBkMUAQcQBQQUQCkECIwhBAUYNAhoLIwhFBEAACwsjCEUEQCABLQAIRSEDCyADIwhBAkZyBEAgBEEDRkEBIwgbBEBB0NIAQZDTABAfIQJBAyMIQQFGDQIaIAIhAAsjCEUEQCABKAIEIQELIARBBEZBASMIGwRAIAAgARAfIQJBBCMIQQFGDQIaIAIhAAsgACAAQaAIIAAbIwgbIQAgBEEFRkEBIwgbBEAgAEHg0QBBqwFBDRAKQQUjCEEBRg0CGgsjCEUEQAALCyMIRQRAIAEoAgAiAUUhAwsgAyMIQQJGcgRAIARBBkZBASMIGwRAIABBoIcBQQBBABBXIQJBBiMIQQFGDQIaIAIhAAsgACAAQaAIIAAbIwgbIQAgBEEHRkEBIwgbBEAgAEGQxQBBygFBBRAKQQcjCEEBRg0CGgsjCEUEQAALCyMIRQRAIAEoAgBByAFHIQMLIAMjCEECRnIEQCMIRQRAIAEoAgAhBSAAIQMLIARBCEZBASMIGwRAQQRBDhANIQJBCCMIQQFGDQIaIAIhAAsjCEUEQCAAIAU2AgAgASgCBCEBCyAEQQlGQQEjCBsEQCADQeCHASAAIAEQVyECQQkjCEEBRg0CGiACIQALIAAgAEGgCCAAGyMIGyEAIARBCkZBASMIGwRAIABBkMUAQc4BQQUQCkEKIwhBAUYNAhoLIwhFBEAACwsjCAR/IAMFIAEoAgxFCyMIQQJGcgRAIARBC0ZBASMIGwRAIABBkIgBQQBBABBXIQJBCyMIQQFGDQIaIAIhAAsgACAAQaAIIAAbIwgbIQAgBEEMRkEBIwgbBEAgAEGQxQBB0gFBBRAKQQwjCEEBRg0CGgsjCEUEQAALCyMIBH8gAQUgASgCDCIARQsjCEECRnIEQCAEQQ1GQQEjCBsEQEHQxABBkMUAQdUBQQoQCkENIwhBAUYNAhoLIwhFBEAACwsjCEUEQCAADwsACyECIwkoAgAgAjYCACMJIwkoAgBBBGo2AgAjCSgCACICIAA2AgAgAiABNgIEIAIgAzYCCCACIAU2AgwjCSMJKAIAQRBqNgIAQQAL+QkBBH8jCEECRgRAIwkjCSgCAEEYazYCACMJKAIAIgQoAgAhACAEKAIIIQIgBCgCDCEDIAQoAhAhBiAEKAIUIQcgBCgCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEFCyAFQQAjCBtFBEAgACADEB8hBEEAIwhBAUYNARogBCEDCyAFQQFGQQEjCBsEQEEIQRkQDSEEQQEjCEEBRg0BGiAEIQALIwhFBEAgAEEANgIAIABBADYCBCAAIAM2AgAgACABNgIEIAIhAwsgBUECRkEBIwgbBEBB8MUAEB4hBEECIwhBAUYNARogBCEBCyAFQQNGQQEjCBsEQCABEEYhBEEDIwhBAUYNARogBCEBCyAFQQRGQQEjCBsEQCABIAAQS0EEIwhBAUYNARoLIwhFBEAgASgCACECCyAFQQVGQQEjCBsEQCACEBkhBEEFIwhBAUYNARogBCECCyAFQQZGQQEjCBsEQEGA0AAQHiEEQQYjCEEBRg0BGiAEIQcLIAVBB0ZBASMIGwRAIAIgASAHEEwhBEEHIwhBAUYNARogBCEBCyAFQQhGQQEjCBsEQCABIAAQS0EIIwhBAUYNARoLIAVBCUZBASMIGwRAQYDRACACEE4hBEEJIwhBAUYNARogBCEACyMIRQRAIAAtAAhFIQELAkAgASMIQQJGcgRAIwhFBEAgAC0ACCEBCyABIwhBAkZyBEAgBUEKRkEBIwgbBEBBoNEAQeDRAEG0AUEJEApBCiMIQQFGDQQaCyMIRQRAAAsLIwhFBEAgACgCBCEACyAFQQtGQQEjCBsEQEEAQQAgABBPIQRBCyMIQQFGDQMaIAQhAAsjCEUNAQsjCEUEQCAALQAIRSEBCyABIwhBAkZyBEAgBUEMRkEBIwgbBEBB0NIAQZDTABAfIQRBDCMIQQFGDQMaIAQhAQsjCEUEQCAAKAIEIQALIAVBDUZBASMIGwRAIAEgABAfIQRBDSMIQQFGDQMaIAQhAAsgACAAQaAIIAAbIwgbIQAgBUEORkEBIwgbBEAgAEHg0QBBqwFBDRAKQQ4jCEEBRg0DGgsjCEUEQAALCyMIRQRAIAAoAgAhAAsgBUEPRkEBIwgbBEBBsNMAEB4hBEEPIwhBAUYNAhogBCEBCyAFQRBGQQEjCBsEQCAAIAEQKCEEQRAjCEEBRg0CGiAEIQALIwhFBEAgACgCACEBCyAFQRFGQQEjCBsEQCABQYDRAEGg1ABB8NQAEDRBESMIQQFGDQIaC0EAAn8gBUESRkEBIwgbBEAgABAqIQRBEiMIQQFGDQMaIAQhAQsgAQtBACAGIAEjCBsiBiMIQQJGciMIGxshASAGRSMIQQJGckEAIAVBE0ZBASMIGxsEQCAAEFEhBEETIwhBAUYNAhogBCEBCyMIRQRAIAAoAgAhAAsgBUEURkEBIwgbBEAgABA2QRQjCEEBRg0CGgsjCEUEQEECJAALIAVBFUZBASMIGwRAIAEQUiEEQRUjCEEBRg0CGiAEIQALCyAFQRZGQQEjCBsEQCADIAAQWCEEQRYjCEEBRg0BGiAEIQALIwhFBEAgAA8LAAshBCMJKAIAIAQ2AgAjCSMJKAIAQQRqNgIAIwkoAgAiBCAANgIAIAQgATYCBCAEIAI2AgggBCADNgIMIAQgBjYCECAEIAc2AhQjCSMJKAIAQRhqNgIAQQAL3wEBAn8jCEECRgRAIwkjCSgCAEEIazYCACMJKAIAIgEoAgAhACABKAIEIQELAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQILIwgEfyADBSAAQRRrKAIQIAFNCyMIQQJGcgRAIAJBACMIG0UEQEGgC0GgigFB9gBBKRAKQQAjCEEBRg0CGgsjCEUEQAALCyMIRQRAIAAgAWotAAAPCwALIQIjCSgCACACNgIAIwkjCSgCAEEEajYCACMJKAIAIgIgADYCACACIAE2AgQjCSMJKAIAQQhqNgIAQQAL6wEBAn8jCEECRgRAIwkjCSgCAEEMazYCACMJKAIAIgIoAgAhACACKAIEIQEgAigCCCECCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEDCyMIBH8gBAUgACgCCCABTQsjCEECRnIEQCADQQAjCBtFBEBBoAtB4AtBsQFBLRAKQQAjCEEBRg0CGgsjCEUEQAALCyMIRQRAIAEgACgCBGogAjoAAAsPCyEDIwkoAgAgAzYCACMJIwkoAgBBBGo2AgAjCSgCACIDIAA2AgAgAyABNgIEIAMgAjYCCCMJIwkoAgBBDGo2AgALvQ4BDH8jCEECRgRAIwkjCSgCAEEsazYCACMJKAIAIgEoAgAhACABKAIIIQMgASgCDCEEIAEoAhAhBiABKAIUIQcgASgCGCEIIAEoAhwhCSABKAIgIQogASgCJCELIAEoAighDCABKAIEIQILAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQULIwhFBEAgAEEUaygCEEEBdiIJRSEECyAEIwhBAkZyBEAgBUEAIwgbRQRAQQxBJxANIQFBACMIQQFGDQIaIAEhAAsgBUEBRkEBIwgbBEAgAEEAQQAQECEBQQEjCEEBRg0CGiABIQALIwhFBEAgAA8LCyAEIAlBAWsjCBshBCAFQQJGQQEjCBsEQCAAIAQQVCEBQQIjCEEBRg0BGiABIQQLIwhFBEAgBEHgiAEQHSEECyAEIwhBAkZyBEAgBCAJQQJrIwgbIQQgBUEDRkEBIwgbBEAgACAEEFQhAUEDIwhBAUYNAhogASEECyMIBH8gCQVBAkEBIARB4IgBEB0iBBshCiAJQQRrCyEJCyAFQQRGQQEjCBsEQEEMQScQDSEBQQQjCEEBRg0BGiABIQILIwhFBEAgCUEDcQR/IAlBAnZBAWpBA2wFIAlBAnZBA2wLQQMgCmtBACAKGyIDaiEECyAFQQVGQQEjCBsEQCACIARBABAQIQFBBSMIQQFGDQEaIAEhBAsDQCACIAcgCUkjCBsiAiMIQQJGcgRAIwhFBEAgBkEBaiELIwMhAyAHIABBFGsoAhBBAXZPBH9BfwUgB0EBdCAAai8BAAshAgsgBUEGRkEBIwgbBEAgAyACEFohAUEGIwhBAUYNAxogASECCyMIRQRAIAJBEnQhAyMDIQwgB0EBaiIIIABBFGsoAhBBAXZPBH9BfwUgCEEBdCAAai8BAAshAgsgBUEHRkEBIwgbBEAgDCACEFohAUEHIwhBAUYNAxogASECCyMIRQRAIAMgAkEMdHIhAyMDIQwgB0ECaiIIIABBFGsoAhBBAXZPBH9BfwUgCEEBdCAAai8BAAshAgsgBUEIRkEBIwgbBEAgDCACEFohAUEIIwhBAUYNAxogASECCyMIRQRAIAMgAkEGdHIhCCAEIQIgBiEDIwMhDCAHQQNqIgYgAEEUaygCEEEBdk8Ef0F/BSAGQQF0IABqLwEACyEGCyAFQQlGQQEjCBsEQCAMIAYQWiEBQQkjCEEBRg0DGiABIQYLIwhFBEAgBiAIciIIQRB2IQYLIAVBCkZBASMIGwRAIAIgAyAGEFtBCiMIQQFGDQMaCyAGIAhBCHZB/wFxIwgbIQYgBUELRkEBIwgbBEAgBCALIAYQW0ELIwhBAUYNAxoLIwhFBEAgC0EBaiILQQFqIQYgCEH/AXEhAgsgBUEMRkEBIwgbBEAgBCALIAIQW0EMIwhBAUYNAxoLIwhFBEAgB0EEaiEHDAILCwsgAiAKQQFHIwgbIQICQAJAIwhFBEAgAgRAIApBAkYiAg0CDAMLIAZBAWohCSAEIQIjAyEIIAcgAEEUaygCEEEBdk8Ef0F/BSAHQQF0IABqLwEACyEDCyAFQQ1GQQEjCBsEQCAIIAMQWiEBQQ0jCEEBRg0DGiABIQMLIwhFBEAgA0ESdCEIIwMhCyAHQQFqIgogAEEUaygCEEEBdk8Ef0F/BSAKQQF0IABqLwEACyEDCyAFQQ5GQQEjCBsEQCALIAMQWiEBQQ4jCEEBRg0DGiABIQMLIwhFBEAgCCADQQx0ciEDIwMhCCAHQQJqIgcgAEEUaygCEEEBdk8Ef0F/BSAHQQF0IgcgAGovAQALIQALIAVBD0ZBASMIGwRAIAggABBaIQFBDyMIQQFGDQMaIAEhAAsjCEUEQCADIABBBnRyIgBBEHYhAwsgBUEQRkEBIwgbBEAgAiAGIAMQW0EQIwhBAUYNAxoLIAAgAEEIdkH/AXEjCBshACAFQRFGQQEjCBsEQCAEIAkgABBbQREjCEEBRg0DGgsjCEUNAQsjCEUEQCAEIQIjAyEJIAcgAEEUaygCEEEBdk8Ef0F/BSAHQQF0IABqLwEACyEDCyAFQRJGQQEjCBsEQCAJIAMQWiEBQRIjCEEBRg0CGiABIQMLIwhFBEAgA0ESdCEDIwMhCCAHQQFqIgkgAEEUaygCEEEBdk8Ef0F/BSAJQQF0IABqLwEACyEACyAFQRNGQQEjCBsEQCAIIAAQWiEBQRMjCEEBRg0CGiABIQALIAAgAyAAQQx0ckEQdiMIGyEAIAVBFEZBASMIGwRAIAIgBiAAEFtBFCMIQQFGDQIaCwsjCEUEQCAEDwsACyEBIwkoAgAgATYCACMJIwkoAgBBBGo2AgAjCSgCACIBIAA2AgAgASACNgIEIAEgAzYCCCABIAQ2AgwgASAGNgIQIAEgBzYCFCABIAg2AhggASAJNgIcIAEgCjYCICABIAs2AiQgASAMNgIoIwkjCSgCAEEsajYCAEEAC5oIAQZ/IwhBAkYEQCMJIwkoAgBBHGs2AgAjCSgCACIGKAIAIQAgBigCBCEBIAYoAgghAiAGKAIMIQMgBigCECEFIAYoAhQhByAGKAIYIQYLAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQQLIwhFBEACQAJAAkAgACIDQQhrKAIAIgVBIUciAgRAIAVBHUYNAQwCCyADKAIAIQUMAgsgAygCCCEFDAELAAsLIARBACMIG0UEQCAFQfANQdCLAUGQxwAQNEEAIwhBAUYNARoLIwhFBEAgA0EIaygCACIFQSFHIQILAkACQAJAIwhFBEAgAgRAIAVBHUYNAgwDCyABQRRrKAIQRSEFCwJAIAUjCEECRnIEQCMIRQRAIAMoAgQhAQsgBEEBRkEBIwgbBEAgAUHAARCYAUEBIwhBAUYNBhoLIwhFDQELIAcCfyMIRQRAIAFBFGsoAhAiBUH/AU0hAgsgAgsjCBsiByMIQQJGcgRAIwhFBEAgAygCBCECCyAEQQJGQQEjCBsEQCACQcQBEJgBQQIjCEEBRg0GGgsjCEUEQCADKAIEIQILIARBA0ZBASMIGwRAIAIgBRCYAUEDIwhBAUYNBhoLCyAHRSMIQQJGcgRAIAYgAiAFQf//A00jCBsiAiMIGyIGIwhBAkZyBEAjCEUEQCADKAIEIQILIARBBEZBASMIGwRAIAJBxQEQmAFBBCMIQQFGDQcaCyMIRQRAIAMoAgQhAgsgBEEFRkEBIwgbBEAgAiAFEJkBQQUjCEEBRg0HGgsLIAZFIwhBAkZyBEAjCEUEQCADKAIEIQILIARBBkZBASMIGwRAIAJBxgEQmAFBBiMIQQFGDQcaCyMIRQRAIAMoAgQhAgsgBEEHRkEBIwgbBEAgAiAFEJoBQQcjCEEBRg0HGgsLCyMIRQRAIAMoAgQhAwsgBEEIRkEBIwgbBEAgAyABEJwBQQgjCEEBRg0FGgsLIwhFDQILIwhFBEAgAUEUaygCEEUEQCADIAMoAgBBAWo2AgAMAwsgAUEUaygCECIFQf8BTQRAIAMgAygCAEECajYCAAUgBUH//wNNBEAgAyADKAIAQQNqNgIABSADIAMoAgBBBWo2AgALCyADIAFBFGsoAhAgAygCAGo2AgAMAgsLIwhFBEAACwsjCEUEQAJAAkACQCAAQQhrKAIAIgFBIUcEQCABQR1GDQEMAgsgACgCACEADAILIAAoAgghAAwBCwALCyAEQQlGQQEjCBsEQCAAEDZBCSMIQQFGDQEaCw8LIQQjCSgCACAENgIAIwkjCSgCAEEEajYCACMJKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIAQgAzYCDCAEIAU2AhAgBCAHNgIUIAQgBjYCGCMJIwkoAgBBHGo2AgALlQkBBn8jCEECRgRAIwkjCSgCAEEYazYCACMJKAIAIgIoAgAhACACKAIIIQMgAigCDCEFIAIoAhAhBiACKAIUIQcgAigCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEECyAEQQAjCBtFBEAgABBAIQJBACMIQQFGDQEaIAIhAQsgBEEBRkEBIwgbBEAQPyECQQEjCEEBRg0BGiACIQALIwhFBEAgACABKAIANgIAIAAgASgCBDYCBCAAIAEoAgg2AgggACABKAIMNgIMIAAoAgAhAyAAKAIIIQYgACgCDCEBCyAEQQJGQQEjCBsEQBBBIQJBAiMIQQFGDQEaIAIhBQsgBEEDRkEBIwgbBEAgBUGwxAAgAxBEQQMjCEEBRg0BGgsgASMIQQJGcgRAIwhFBEAgASgCBCEDCyADIwhBAkZyBEAjCAR/IAcFIAEoAgQiA0ULIwhBAkZyBEAgBEEERkEBIwgbBEBB0MQAQZDFAEH4AEEfEApBBCMIQQFGDQQaCyMIRQRAAAsLIwhFBEAgAygCACEDCyAEQQVGQQEjCBsEQCADECIhAkEFIwhBAUYNAxogAiEDCyAEQQZGQQEjCBsEQCAFQcDBACADEERBBiMIQQFGDQMaCwsjCEUEQCABKAIAIQMLIAMjCEECRnIEQCMIRQRAIAEoAgAiAUUhAwsgAyMIQQJGcgRAIARBB0ZBASMIGwRAQdDEAEGQxQBB+wBBHxAKQQcjCEEBRg0EGgsjCEUEQAALCyMIRQRAIAEoAgAhAQsgBEEIRkEBIwgbBEAgARAiIQJBCCMIQQFGDQMaIAIhAQsgBEEJRkEBIwgbBEAgBUHgwAAgARBEQQkjCEEBRg0DGgsLCyAEQQpGQQEjCBsEQBBFIQJBCiMIQQFGDQEaIAIhAQsjCEUEQCABIAU2AgQgAUEBNgIIIAEgBjYCFCABQQA2AgAgAUEANgIMIAFBADYCECAAKAIEIQALIARBC0ZBASMIGwRAIAAgAUHwDUHAxQAQWSECQQsjCEEBRg0BGiACIQALIARBDEZBASMIGwRAIAAQXCECQQwjCEEBRg0BGiACIQALIwhFBEAgACgCACEACyAEQQ1GQQEjCBsEQEHgigEQHiECQQ0jCEEBRg0BGiACIQELIARBDkZBASMIGwRAIAEQRiECQQ4jCEEBRg0BGiACIQELIARBD0ZBASMIGwRAIAEgABBdQQ8jCEEBRg0BGgsjCEUEQCABKAIAIQMLIARBEEZBASMIGwRAIAMQGSECQRAjCEEBRg0BGiACIQMLIARBEUZBASMIGwRAQYCMARAeIQJBESMIQQFGDQEaIAIhBQsgBEESRkEBIwgbBEAgAyABIAUQTCECQRIjCEEBRg0BGiACIQELIARBE0ZBASMIGwRAIAEgABBdQRMjCEEBRg0BGgsjCEUEQCADDwsACyECIwkoAgAgAjYCACMJIwkoAgBBBGo2AgAjCSgCACICIAA2AgAgAiABNgIEIAIgAzYCCCACIAU2AgwgAiAGNgIQIAIgBzYCFCMJIwkoAgBBGGo2AgBBAAvjAwEDfyMIQQJGBEAjCSMJKAIAQRBrNgIAIwkoAgAiAygCACEAIAMoAgghAiADKAIMIQUgAygCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEECyAFIAIjCBsiBSMIQQJGcgRAIwhFBEAgACgCBCEAQQIkACACKAIAIQILIARBACMIG0UEQCAAIAEgAhECACEDQQAjCEEBRg0CGiADIQALIwhFBEAgAEEUaygCECEBCyAEQQFGQQEjCBsEQCAAIAEQB0EBIwhBAUYNAhoLIABBASMIGyEACyAFRSMIQQJGcgRAIwhFBEBBlI4BIAAoAgA2AgBBjI4BKAIAQQJ2IQALIARBAkZBASMIGwRAQZCOASAAEFYhA0ECIwhBAUYNAhogAyEACyMIRQRAQQEkAAsgBEEDRkEBIwgbBEAgABAJIQNBAyMIQQFGDQIaIAMhAAsjCEUEQCAAQRRrKAIQIQELIARBBEZBASMIGwRAIAAgARAIQQQjCEEBRg0CGgsgAEEAIwgbIQALIwhFBEAgAA8LAAshAyMJKAIAIAM2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAyAANgIAIAMgATYCBCADIAI2AgggAyAFNgIMIwkjCSgCAEEQajYCAEEAC7cJAQp/IwhBAkYEQCMJIwkoAgBBJGs2AgAjCSgCACIBKAIAIQAgASgCCCEDIAEoAgwhBSABKAIQIQYgASgCFCEHIAEoAhghCCABKAIcIQkgASgCICEKIAEoAgQhAgsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBAsjCEUEQCAAIgJBCGsoAgBBEkYhAAsCQCAAIwhBAkZyBEAgBEEAIwgbRQRAIAIQLyEBQQAjCEEBRg0DGiABIQALIwhFDQELIwhFBEAACwsDQCAAIwhBAkZyBEAgACAAQQFrIwgbIQAgBEEBRkEBIwgbBEAgAhCkASEBQQEjCEEBRg0DGiABIQMLIwhFBEACQCACQQhrKAIAQRJGBEAgAigCACEFDAELAAsLIARBAkZBASMIGwRAIAUgA0GgNUHQNRA0QQIjCEEBRg0DGgsgCQJ/IwhFBEAgA0HQkAEQHSEFCyAFCyMIGyIJIwhBAkZyBEAjCEUEQAJAIAJBCGsoAgBBEkYEQCACKAIAIQYMAQsACwsgBEEDRkEBIwgbBEAgBiADQYCRAUHgNhA0QQMjCEEBRg0EGgsgBEEERkEBIwgbBEAgAhCsASEBQQQjCEEBRg0EGiABIQYLIwhFBEACQCACQQhrKAIAQRJGBEAgAigCACEDDAELAAsLIARBBUZBASMIGwRAIAMQNkEFIwhBAUYNBBoLCyAJRSMIQQJGcgRAIAoCfyMIRQRAIANBwJEBEB0hBQsgBQsjCBsiCiMIQQJGcgRAIwhFBEACQCACQQhrKAIAQRJGBEAgAigCACEHDAELAAsLIARBBkZBASMIGwRAIAcgA0GAwQBB4DYQNEEGIwhBAUYNBRoLIARBB0ZBASMIGwRAIAIQpQEhAUEHIwhBAUYNBRogASEHCyMIRQRAAkAgAkEIaygCAEESRgRAIAIoAgAhAwwBCwALCyAEQQhGQQEjCBsEQCADEDZBCCMIQQFGDQUaCwsgCkUjCEECRnIEQCMIRQRAIANB8JEBEB0hBQsgBSMIQQJGcgRAIwhFBEACQCACQQhrKAIAQRJGBEAgAigCACEIDAELAAsLIARBCUZBASMIGwRAIAggA0GQzQBB4DYQNEEJIwhBAUYNBhoLIARBCkZBASMIGwRAIAIQqwEhAUEKIwhBAUYNBhogASEICyMIRQRAAkAgAkEIaygCAEESRgRAIAIoAgAhAwwBCwALCyAEQQtGQQEjCBsEQCADEDZBCyMIQQFGDQYaCwsLCyMIRQRAAkAgAkEIaygCAEESRgRAIAIoAgAhAwwBCwALCyAEQQxGQQEjCBsEQCADEDZBDCMIQQFGDQMaCyMIRQ0BCwsgBEENRkEBIwgbBEBBDEEsEA0hAUENIwhBAUYNARogASEACyMIRQRAIABBADYCACAAQQA2AgQgAEEANgIIIAAgBjYCACAAIAc2AgQgACAINgIIIAAPCwALIQEjCSgCACABNgIAIwkjCSgCAEEEajYCACMJKAIAIgEgADYCACABIAI2AgQgASADNgIIIAEgBTYCDCABIAY2AhAgASAHNgIUIAEgCDYCGCABIAk2AhwgASAKNgIgIwkjCSgCAEEkajYCAEEAC70BAQJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEACwJ/IwhBAkYEfyMJIwkoAgBBBGs2AgAjCSgCACgCAAUgAQtBACMIG0UEQEEQQSsQDSEBQQAjCEEBRg0BGiABIQALIwhFBEAgAEEANgIAIABBADYCBCAAQQA2AgggAEEANgIMIAAPCwALIQEjCSgCACABNgIAIwkjCSgCAEEEajYCACMJKAIAIAA2AgAjCSMJKAIAQQRqNgIAQQAL5gMBBn8jCEECRgRAIwkjCSgCAEEUazYCACMJKAIAIgMoAgAhACADKAIEIQEgAygCCCEEIAMoAgwhBSADKAIQIQMLAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQYLIAMCfyMIRQRAAkACQAJAIwBBAWsOAwEBAgALAAtBfyEBCyAAIQQgAEEUaygCECEFIAEiAEEASCEBCyABCyMIGyIDIwhBAkZyBEAgBSAAIABBf0YjCBsiAEEAIAIgACMIGyICIwhBAkZyIwgbGyEAIAJFIwhBAkZyBEAgBkEAIwgbRQRAQbAKQeALQdcOQQcQCkEAIwhBAUYNAxoLIwhFBEAACwsLIANFIwhBAkZyBEAgASAAIAVKIwgbIgEjCEECRnIEQCAGQQFGQQEjCBsEQEGwCkHgC0HcDkEHEApBASMIQQFGDQMaCyMIRQRAAAsLCyAGQQJGQQEjCBsEQEEMQScQDSECQQIjCEEBRg0BGiACIQELIwhFBEAgASAENgIAIAEgADYCCCABIAQ2AgQgAQ8LAAshAiMJKAIAIAI2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAiAANgIAIAIgATYCBCACIAQ2AgggAiAFNgIMIAIgAzYCECMJIwkoAgBBFGo2AgBBAAubAgEEfyMIQQJGBEAjCSMJKAIAQQhrNgIAIwkoAgAiASgCACEAIAEoAgQhAgsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAwsgA0EAIwgbRQRAQRBBNBANIQFBACMIQQFGDQEaIAEhAAsjCEUEQCAAQQA2AgAgAEEANgIEIABBADYCCCAAQQA2AgwLIANBAUZBASMIGwRAQSBBABANIQFBASMIQQFGDQEaIAEhAgsjCEUEQCACQSAQDyAAIAI2AgAgACACNgIEIABBIDYCCCAAQQA2AgwgAA8LAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAiASAANgIAIAEgAjYCBCMJIwkoAgBBCGo2AgBBAAvFAQECfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAAsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAQsjCAR/IAIFIAAoAgQiAEULIwhBAkZyBEAgAUEAIwgbRQRAQdDEAEGQlAFB/wBBGhAKQQAjCEEBRg0CGgsjCEUEQAALCyMIRQRAIAAPCwALIQEjCSgCACABNgIAIwkjCSgCAEEEajYCACMJKAIAIAA2AgAjCSMJKAIAQQRqNgIAQQAL3gEBAn8jCEECRgRAIwkjCSgCAEEIazYCACMJKAIAIgEoAgAhACABKAIEIQELAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQILIwgEfyADBSAAKAIIIAFNCyMIQQJGcgRAIAJBACMIG0UEQEGgC0HgC0GmAUEtEApBACMIQQFGDQIaCyMIRQRAAAsLIwhFBEAgASAAKAIEai0AAA8LAAshAiMJKAIAIAI2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAiAANgIAIAIgATYCBCMJIwkoAgBBCGo2AgBBAAuGAwEEfyMIQQJGBEAjCSMJKAIAQQxrNgIAIwkoAgAiASgCACEAIAEoAgghBCABKAIEIQILAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQMLIANBACMIG0UEQCAAEGQhAUEAIwhBAUYNARogASECCyMIRQRAIAIoAgQhAgsgA0EBRkEBIwgbBEAgABBkIQFBASMIQQFGDQEaIAEhBAsjCEUEQCAEKAIIKAIIIAJMIgIEQEF/DwsLIANBAkZBASMIGwRAIAAQZCEBQQIjCEEBRg0BGiABIQILIwhFBEAgAigCCCECCyADQQNGQQEjCBsEQCAAEGQhAUEDIwhBAUYNARogASEACyMIRQRAIAAoAgQhAAsgA0EERkEBIwgbBEAgAiAAEGUhAUEEIwhBAUYNARogASEACyMIRQRAIAAPCwALIQEjCSgCACABNgIAIwkjCSgCAEEEajYCACMJKAIAIgEgADYCACABIAI2AgQgASAENgIIIwkjCSgCAEEMajYCAEEAC+MDAQR/IwhBAkYEQCMJIwkoAgBBDGs2AgAjCSgCACIBKAIAIQAgASgCCCEDIAEoAgQhAgsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBAsgBEEAIwgbRQRAIAAQZCEBQQAjCEEBRg0BGiABIQILIwhFBEAgAigCBCECCyAEQQFGQQEjCBsEQCAAEGQhAUEBIwhBAUYNARogASEDCyMIRQRAIAMoAggoAggiAyACTCECCyACIwhBAkZyBEAgBEECRkEBIwgbBEBBgJUBQZCUAUGcAUEFEApBAiMIQQFGDQIaCyMIRQRAAAsLIARBA0ZBASMIGwRAIAAQZCEBQQMjCEEBRg0BGiABIQILIwhFBEAgAigCCCECCyAEQQRGQQEjCBsEQCAAEGQhAUEEIwhBAUYNARogASEDCyAEQQVGQQEjCBsEQCAAEGQhAUEFIwhBAUYNARogASEACyMIRQRAIAMgACgCBCIAQQFqNgIECyAEQQZGQQEjCBsEQCACIAAQZSEBQQYjCEEBRg0BGiABIQALIwhFBEAgAA8LAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAiASAANgIAIAEgAjYCBCABIAM2AggjCSMJKAIAQQxqNgIAQQAL+gEBA38jCEECRgRAIwkjCSgCAEEIazYCACMJKAIAIgIoAgAhACACKAIEIQELAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQMLA0AgA0EAIwgbRQRAIAAQZiECQQAjCEEBRg0CGiACIQELIwhFBEAgAUEKRiABQQlGciABQQ1GciABQSBGciEBCyABIwhBAkZyBEAgA0EBRkEBIwgbBEAgABBnIQJBASMIQQFGDQMaIAIhAQsjCEUNAQsLDwshAiMJKAIAIAI2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAiAANgIAIAIgATYCBCMJIwkoAgBBCGo2AgALgAgBBX8jCEECRgRAIwkjCSgCAEEYazYCACMJKAIAIgMoAgAhACADKAIIIQIgAygCDCEEIAMoAhAhBSADKAIUIQcgAygCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEGCyMIRQRAIAFBFGsoAhBBAXYEf0EBBSAAKAIAKAIMC0UhBAsgBCMIQQJGcgRAIwhFBEAgACgCACEACyAGQQAjCBtFBEAgACACEDNBACMIQQFGDQIaCyMIRQRADwsLIwhFBEAgACgCACIFKAIMQQFrIQQLIAZBAUZBASMIGwRAIAUgBBAlIQNBASMIQQFGDQEaIAMhBQsgBwJ/IwhFBEAgBQR/An8gBUEUaygCDCIFQZDWASgCAE0EQANAQQEgBUEvRg0CGiAFQQN0QZTWAWooAgQiBQ0ACwtBAAsFQQALIQQLIAQLIwgbIgcjCEECRnIEQCMIRQRAIAAoAgAiACgCDEEBayEECyAGQQJGQQEjCBsEQCAAIAQQJSEDQQIjCEEBRg0CGiADIQULIwhFBEACfyAFQRRrKAIMIgBBkNYBKAIATQRAA0BBASAAQS9GDQIaIABBA3RBlNYBaigCBCIADQALC0EACyIERSEACyAAIwhBAkZyBEAgBkEDRkEBIwgbBEBB4JUBQaCWAUHOAEEIEApBAyMIQQFGDQMaCyMIRQRAAAsLIwhFBEAgBSgCACEACyAGQQRGQQEjCBsEQCAAIAEgAhBEQQQjCEEBRg0CGgsLIAdFIwhBAkZyBEAjCEUEQCAAKAIAIgEoAgxBAWshBAsgBkEFRkEBIwgbBEAgASAEECUhA0EFIwhBAUYNAhogAyEBCyMIBH8gBAUgAQR/An8gAUEUaygCDCIBQZDWASgCAE0EQANAQQEgAUE3Rg0CGiABQQN0QZTWAWooAgQiAQ0ACwtBAAsFQQALCyMIQQJGcgRAIwhFBEAgACgCACIAKAIMQQFrIQELIAZBBkZBASMIGwRAIAAgARAlIQNBBiMIQQFGDQMaIAMhAQsjCEUEQAJ/IAFBFGsoAgwiAEGQ1gEoAgBNBEADQEEBIABBN0YNAhogAEEDdEGU1gFqKAIEIgANAAsLQQALRSEACyAAIwhBAkZyBEAgBkEHRkEBIwgbBEBB4JUBQaCWAUHQAEENEApBByMIQQFGDQQaCyMIRQRAAAsLIwhFBEAgASgCACEACyAGQQhGQQEjCBsEQCAAIAIQM0EIIwhBAUYNAxoLCwsPCyEDIwkoAgAgAzYCACMJIwkoAgBBBGo2AgAjCSgCACIDIAA2AgAgAyABNgIEIAMgAjYCCCADIAQ2AgwgAyAFNgIQIAMgBzYCFCMJIwkoAgBBGGo2AgALjAMBBX8jCEECRgRAIwkjCSgCAEEQazYCACMJKAIAIgEoAgAhACABKAIIIQMgASgCDCEEIAEoAgQhAgsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBQsgBUEAIwgbRQRAQRBBOBANIQFBACMIQQFGDQEaIAEhAgsjCEUEQCACQQA2AgAgAkEANgIEIAJBADYCCCACQQA2AgwgAEH/////AEshAwsgAyMIQQJGcgRAIAVBAUZBASMIGwRAQbAKQeAgQcYAQTwQCkEBIwhBAUYNAhoLIwhFBEAACwsgAyAAQQggAEEISxtBAnQjCBshAyAFQQJGQQEjCBsEQCADQQAQDSEBQQIjCEEBRg0BGiABIQQLIwhFBEAgBCADEA8gAiAENgIAIAIgBDYCBCACIAM2AgggAiAANgIMIAIPCwALIQEjCSgCACABNgIAIwkjCSgCAEEEajYCACMJKAIAIgEgADYCACABIAI2AgQgASADNgIIIAEgBDYCDCMJIwkoAgBBEGo2AgBBAAv7AQEDfyMIQQJGBEAjCSMJKAIAQQhrNgIAIwkoAgAiASgCACEAIAEoAgQhAQsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAwsjCEUEQAJAAkACQCMAQQFrDgIBAgALAAsgACgCBCEECyAAKAIIIgAoAgAhAiABIAIgACgCBCACa2pqIQAgBEEBayABayEBCyADQQAjCBtFBEAgACABEBshAkEAIwhBAUYNARogAiEACyMIRQRAIAAPCwALIQIjCSgCACACNgIAIwkjCSgCAEEEajYCACMJKAIAIgIgADYCACACIAE2AgQjCSMJKAIAQQhqNgIAQQALpAIBA38jCEECRgRAIwkjCSgCAEEIazYCACMJKAIAIgIoAgAhACACKAIEIQELAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQMLIANBACMIG0UEQCAAEGchAkEAIwhBAUYNARogAiEBCyMIBH8gAQUgAUEwayIAQQlKBEAgAUE3ayIAQQpIIABBD0pyBEAgAUHXAGshAAsLIABBEEggAEEATnFFCyMIQQJGcgRAIANBAUZBASMIGwRAQeCbAUGQlAFBvwJBBRAKQQEjCEEBRg0CGgsjCEUEQAALCyMIRQRAIAAPCwALIQIjCSgCACACNgIAIwkjCSgCAEEEajYCACMJKAIAIgIgADYCACACIAE2AgQjCSMJKAIAQQhqNgIAQQALjQIBBH8jCEECRgRAIwkjCSgCAEEQazYCACMJKAIAIgMoAgAhACADKAIEIQIgAygCCCEEIAMoAgwhAwsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAQsjCEUEQAJAAkACQCMAQQFrDgIBAgALAAtBfyEEC0ECIARBAEoiA3QhAgsgAUEAIwgbRQRAIAJBARANIQFBACMIQQFGDQEaIAEhAgsjCEUEQCACIAA7AQAgAwRAIAIgBDsBAgsgAg8LAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAiASAANgIAIAEgAjYCBCABIAQ2AgggASADNgIMIwkjCSgCAEEQajYCAEEAC8kPAQl/IwhBAkYEQCMJIwkoAgBBIGs2AgAjCSgCACICKAIAIQAgAigCCCEDIAIoAgwhBSACKAIQIQYgAigCFCEHIAIoAhghCCACKAIcIQkgAigCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEECyAEQQAjCBtFBEAgABBnIQJBACMIQQFGDQEaIAIhAwsjCEUEQEHsjQEoAgBBAXYEf0HwjQEvAQAFQX8LIANHIQELIAEjCEECRnIEQCAEQQFGQQEjCBsEQEGAmAFBkJQBQfUBQQUQCkEBIwhBAUYNAhoLIwhFBEAACwsgBEECRkEBIwgbBEAgABBkIQJBAiMIQQFGDQEaIAIhAQsjCEUEQCABKAIEIQMLIARBA0ZBASMIGwRAQQAQaiECQQMjCEEBRg0BGiACIQYLA0AgAQJ/IARBBEZBASMIGwRAIAAQZyECQQQjCEEBRg0DGiACIQULIAVBIEgLIwgbIgEjCEECRnIEQCAEQQVGQQEjCBsEQEHQmAFBkJQBQf4BQQcQCkEFIwhBAUYNAxoLIwhFBEAACwsgCAJ/IwhFBEBB7I0BKAIAQQF2BH9B8I0BLwEABUF/CyAFRiEBCyABCyMIGyIIIwhBAkZyBEAgBEEGRkEBIwgbBEAgABBkIQJBBiMIQQFGDQMaIAIhAAsjCEUEQEEBJAALIARBB0ZBASMIGwRAIAAgAxBrIQJBByMIQQFGDQMaIAIhAAsjCEUEQCAGKAIMRSIBBEAgAA8LCyAEQQhGQQEjCBsEQCAGIAAQM0EIIwhBAUYNAxoLIwhFBEAgBigCBCEBIAYoAgwhAAsgBEEJRkEBIwgbBH8gASAAEFYhAkEJIwhBAUYNAxogAgUgAAshAAsgCEUjCEECRnIEQCMIRQRAQZyZASgCAEEBdgR/QaCZAS8BAAVBfwsgBUYhAQsgASMIQQJGcgRAIARBCkZBASMIGwRAIAAQZCECQQojCEEBRg0EGiACIQELIwhFBEAgASgCBCIHIANBAWpKIQELIAEjCEECRnIEQCAEQQtGQQEjCBsEQCAAEGQhAkELIwhBAUYNBRogAiEFCyMIRQRAQQEkAAsgBEEMRkEBIwgbBEAgBSADEGshAkEMIwhBAUYNBRogAiEBCyAEQQ1GQQEjCBsEQCAGIAEQM0ENIwhBAUYNBRoLCyAEQQ5GQQEjCBsEQCAAEGchAkEOIwhBAUYNBBogAiEDCyMIRQRAQeyNASgCAEEBdgR/QfCNAS8BAAVBfwsiByADRiEBCwJAIwhFBEAgAQRAQfCNASEDDAILQZyZASgCAEEBdgR/QaCZAS8BAAVBfwsgA0YiAQRAQaCZASEDDAILQbyZASgCAEEBdgR/QcCZAS8BAAVBfwsgA0YiAQRAQcCZASEDDAILQdyZASgCAEEBdgR/QeCZAS8BAAVBfwsgA0YiAQRAQYCaASEDDAILQZyaASgCAEEBdgR/QaCaAS8BAAVBfwsgA0YiAQRAQbAfIQMMAgtBvJoBKAIAQQF2BH9BwJoBLwEABUF/CyADRiIBBEBB4JoBIQMMAgtB/JoBKAIAQQF2BH9BgJsBLwEABUF/CyADRiIBBEBBoJsBIQMMAgtBvJsBKAIAQQF2BH9BwJsBLwEABUF/CyADRiEBCyABIwhBAkZyBEAgAwJ/IARBD0ZBASMIGwRAIAAQbCECQQ8jCEEBRg0HGiACIQELIAFBDHQLIwgbIQMgBEEQRkEBIwgbBEAgABBsIQJBECMIQQFGDQYaIAIhAQsjCEUEQCADIAFBCHQiAWohAwsgBEERRkEBIwgbBEAgABBsIQJBESMIQQFGDQYaIAIhAQsjCEUEQCADIAFBBHQiAWohAwsgBEESRkEBIwgbBEAgABBsIQJBEiMIQQFGDQYaIAIhAQsjCEUEQEECIAEgA2oiBUH//wNLIgd0IQELIARBE0ZBASMIGwRAIAFBARANIQJBEyMIQQFGDQYaIAIhAwsgCSAHIwgbIgkjCEECRnIEQCABIAVB///DAEsjCBsiASMIQQJGcgRAIARBFEZBASMIGwRAQaAIQYAJQSdBBxAKQRQjCEEBRg0IGgsjCEUEQAALCyMIRQRAIAVBgIAEayIFQQp2QYCwA3IhByADIAcgBUH/B3FBgLgDckEQdHIiATYCAAsLQQAgCUUjCEECRnIjCBsEQCADIAU7AQALIwhFDQELIwhFBEBBASQACyAEQRVGQQEjCBsEQCADEG0hAkEVIwhBAUYNBRogAiEACyAEQRZGQQEjCBsEQEGgnAEgABAfIQJBFiMIQQFGDQUaIAIhAAsgACAAQaAIIAAbIwgbIQAgBEEXRkEBIwgbBEAgAEGQlAFBsgJBBRAKQRcjCEEBRg0FGgsjCEUEQAALCyAEQRhGQQEjCBsEQCAGIAMQM0EYIwhBAUYNBBoLIARBGUZBASMIGwRAIAAQZCECQRkjCEEBRg0EGiACIQELIwgEfyADBSABKAIECyEDCyMIRQ0BCwsjCEUEQCAADwsACyECIwkoAgAgAjYCACMJIwkoAgBBBGo2AgAjCSgCACICIAA2AgAgAiABNgIEIAIgAzYCCCACIAU2AgwgAiAGNgIQIAIgBzYCFCACIAg2AhggAiAJNgIcIwkjCSgCAEEgajYCAEEAC8cDAQV/IwhBAkYEQCMJIwkoAgBBFGs2AgAjCSgCACICKAIAIQAgAigCCCEDIAIoAgwhBCACKAIQIQYgAigCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEFCwNAIwhFBEAgBCABQRRrKAIQQQF2SCEDCyADIwhBAkZyBEAjCEUEQCAEIAFBFGsoAhBBAXZPBH9BfwUgBEEBdCABai8BAAshAwsgAwJ/IAVBACMIG0UEQCAAEGchAkEAIwhBAUYNBBogAiEGCyADIAZHCyMIGyIDIwhBAkZyBEAgBUEBRkEBIwgbBEBBgJ8BIAEQHyECQQEjCEEBRg0EGiACIQALIAVBAkZBASMIGwRAIABBsJ8BEB8hAkECIwhBAUYNBBogAiEACyAAIABBoAggABsjCBshACAFQQNGQQEjCBsEQCAAQZCUAUGMA0EHEApBAyMIQQFGDQQaCyMIRQRAAAsLIwhFBEAgBEEBaiEEDAILCwsPCyECIwkoAgAgAjYCACMJIwkoAgBBBGo2AgAjCSgCACICIAA2AgAgAiABNgIEIAIgAzYCCCACIAQ2AgwgAiAGNgIQIwkjCSgCAEEUajYCAAu1AgEEfyMIQQJGBEAjCSMJKAIAQRRrNgIAIwkoAgAiAygCACEAIAMoAgghAiADKAIMIQQgAygCECEFIAMoAgQhAQsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBgsgBkEAIwgbRQRAQQFBOhANIQNBACMIQQFGDQEaIAMhBAsjCEUEQCAEIAI6AAALIAUgBCMIGyIFRSMIQQJGckEAIAZBAUZBASMIGxsEQEEAQTAQDSEDQQEjCEEBRg0BGiADIQQLIAZBAkZBASMIGwRAIAAgASAEEGlBAiMIQQFGDQEaCw8LIQMjCSgCACADNgIAIwkjCSgCAEEEajYCACMJKAIAIgMgADYCACADIAE2AgQgAyACNgIIIAMgBDYCDCADIAU2AhAjCSMJKAIAQRRqNgIAC4ICAQJ/QQUhAUEBIQIgAEEATARAIABFDwUgAEEBRgRAQQUPBSAAQQJGBEBBGQ8FIABBIEgEQAJAAkACQAJAAkACQEEfIABnaw4FBAMCAQAFC0EFQQEgAEEBcRshAiAAQQF2IQBBGSEBCyABIAJsIAIgAEEBcRshAiAAQQF2IQAgASABbCEBCyABIAJsIAIgAEEBcRshAiAAQQF2IQAgASABbCEBCyABIAJsIAIgAEEBcRshAiAAQQF2IQAgASABbCEBCyABIAJsIAIgAEEBcRshAgsgAg8LCwsLA0AgAARAIAEgAmwgAiAAQQFxGyECIABBAXYhACABIAFsIQEMAQsLIAILrgEBAX8gAUH/B0oEfCAARAAAAAAAAOB/oiEAIAFB/wdrIgFB/wdKBHwgAUH/B2siAUH/B0ghAiABQf8HIAIbIQEgAEQAAAAAAADgf6IFIAALBSABQYJ4SAR8IABEAAAAAAAAYAOiIQAgAUHJB2oiAUGCeEgEfCABQckHaiIBQYJ4SiECIAFBgnggAhshASAARAAAAAAAAGADogUgAAsFIAALCyABrEL/B3xCNIa/ogvICwMHfwV+A3wCQCAAQRRrKAIQQQF2IgJFDQAgAC8BACEBRAAAAAAAAPA/IQ8DQCACBH8CfyABQYABckGgAUYgAUEJa0EETXIgAUGALUkNABpBASABQYBAakEKTQ0AGgJAAkAgAUGALUYNACABQajAAEYNACABQanAAEYNACABQa/AAEYNACABQd/AAEYNACABQYDgAEYNACABQf/9A0YNAAwBC0EBDAELQQALBUEACwRAIABBAmoiAC8BACEBIAJBAWshAgwBCwsgAkUNACABQS1GBH8gAkEBayICRQ0BRAAAAAAAAPC/IQ8gAEECaiIALwEABSABQStGBH8gAkEBayICRQ0CIABBAmoiAC8BAAUgAQsLIgFByQBGIAJBCE5xBEAgACkDAELJgLiD4IzANFEEfyAAKQMIQu6ApIPAjsA8UQVBAAsEQCAPRAAAAAAAAPB/og8LDAELIAFBLkcgAUEwa0EKT3ENACAAIQMDQCABQTBGBEAgAEECaiIALwEAIQEgAkEBayECDAELCyACQQBMBEBEAAAAAAAAAAAPCyABQS5GBEAgACADRiEDIABBAmohAEEAIAMgAkEBayICGw0BQQEhBwNAIAAvAQAiAUEwRgRAIAJBAWshAiAFQQFrIQUgAEECaiEADAELCyACQQBMBEBEAAAAAAAAAAAPCyABQTBrQQpPQQBBACADIAUbGw0BCyABQTBrIQMDQCAHRSABQS5GcSADQQpJcgRAAkAgA0EKSQRAIARBE0gEfiADrSAIQgp+fAUgA0VFrSAIhAshCCAEQQFqIQQFIAQhBUEBIQcLIAJBAWsiAkUNACAAQQJqIgAvAQAiAUEwayEDDAILCwsgBSAEIAcbQRMgBCAEQRNKG2shASAIUCEHQQEhBEQAAAAAAAAAACENAkAgBwJ/QQAgAC8BAEEgckHlAEcNABpBACACQQFrIgNFDQAaIABBAmoiAC8BACIFQS1GBH9BACADQQFrIgNFDQEaQX8hBCAAQQJqIgAvAQAFIAVBK0YEf0EAIANBAWsiA0UNAhogAEECaiIALwEABSAFCwshBQNAIAVBMEYEQEEAIANBAWsiA0UNAhogAEECaiIALwEAIQUMAQsLIAVBMGshBQNAIAVBCklBACADGwRAIARBgBlsIAZBgBlODQIaIAZBCmwgBWohBiADQQFrIQMgAEECaiIALwEAQTBrIQUMAQsLIAQgBmwLIAFqIgBBqn1Icg0ARAAAAAAAAPB/IQ0gAEG0AkoNACAIuiIOIQ0gAEUNACAAQSVMIABBFkpxBEAgDiAAQQN0QbCeAWorAwCiIQ5BFiEACyAIQv////////8PWAR/IABBH3UiBCAAaiAEc0EWTAVBAAsEfCAAQQBKBEAgDiAAQQN0QeCfAWorAwCiIQ0MAgsgDkEAIABrQQN0QeCfAWorAwCjBSAAQQBIBHwgCCAIeSIJhiEIIAAhBCAArCAJfSEJA0AgBEFyTARAIAhC6YOx3haCIAhC6YOx3haAIgh5IgpCEn2GukSbK6GGm4QGP6KesSELIAsgCCAKhnwhCCAJIAp9IQkgBEEOaiEEDAELCyAIQQAgBGsQcawiC4AiDHkhCiAIIAuCur0gCkI0hny/IAu6o7EgDCAKhny6IAkgCn2nEHIFIAggCHoiCYghCCAAIQYgAKwgCXwkBgNAIAZBDU4EQEIgIAhCIIhCleeJxgR+IAhC/////w+DQpXnicYEfiIIQiCIfCIJQiCIp2etIgp9IgsjBnwkBiAIIAqGQh+IQgGDIAkgCoYgCEL/////D4MgC4iEfCEIIAZBDWshBgwBCwsgBhBxrSIJIAhC/////w+DfiEKQiAgCSAIQiCIfiAKQiCIfCIIQiCIp2etIgl9IgsjBnwkBiAKIAmGQh+IQgGDIAggCYYgCkL/////D4MgC4iEfLojBqcQcgsLIQ0LIA0gD6YPC0QAAAAAAAD4fwuiKwMZfwJ8AX4jCEECRgRAIwkjCSgCAEH4AGs2AgAjCSgCACIBKAIAIQAgASgCCCEEIAEoAgwhBSABKAIQIQYgASgCFCEHIAEoAhghCCABKwIcIRogASgCJCEJIAErAighGyABKAIwIQogASgCNCELIAEoAjghDCABKAI8IQ0gASgCQCEOIAEoAkQhDyABKAJIIRggASgCTCEQIAEoAlAhESABKAJUIRIgASgCWCETIAEoAlwhFCABKAJgIRUgASgCZCEWIAEoAmghFyABKAJsIRkgASkCcCEcIAEoAgQhAgsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAwsgA0EAIwgbRQRAIAAQaEEAIwhBAUYNARoLIAQgACMIGyEEIANBAUZBASMIGwRAIAAQZiEBQQEjCEEBRg0BGiABIQILQQACfyMIRQRAQbyVASgCAEEBdgR/QcCVAS8BAAVBfwsgAkchAAsgAAtBACALIAAjCBsiCyMIQQJGciMIGxshACALRSMIQQJGcgRAIANBAkZBASMIGwRAIAQQZCEBQQIjCEEBRg0CGiABIQALIwhFBEAgACgCACEGCyADQQNGQQEjCBsEQCAEEGQhAUEDIwhBAUYNAhogASEACyMIRQRAIABBoAg2AgAgBCgCACIFIQILIANBBEZBASMIGwRAQQRBLxANIQFBBCMIQQFGDQIaIAEhAAsjCEUEQCAAQQA2AgAgAEUhBwsgByMIQQJGckEAIANBBUZBASMIGxsEQEEAQTAQDSEBQQUjCEEBRg0CGiABIQALIANBBkZBASMIGwRAQRhBMRANIQFBBiMIQQFGDQIaIAEhBwsgA0EHRkEBIwgbBEBBEBAZIQFBByMIQQFGDQIaIAEhCgsjCEUEQCAHIAo2AgAgB0EDNgIECyADQQhGQQEjCBsEQEEwEBkhAUEIIwhBAUYNAhogASEKCyMIRQRAIAcgCjYCCCAHQQQ2AgwgB0EANgIQIAdBADYCFCAAIAc2AgALIANBCUZBASMIGwRAIAIgBiAAEGlBCSMIQQFGDQIaCyMIRQRAIAUoAgAhAgsgA0EKRkEBIwgbBEAgAiAAEDNBCiMIQQFGDQIaCyADQQtGQQEjCBsEQCAEEGchAUELIwhBAUYNAhogASEACyADQQxGQQEjCBsEQCAEEGhBDCMIQQFGDQIaCyAAQQEjCBshAANAIANBDUZBASMIGwRAIAQQZiEBQQ0jCEEBRg0DGiABIQULIwhFBEBBjJcBKAIAQQF2BH9BkJcBLwEABUF/CyAFRyECCyACIwhBAkZyBEBBACAAQQAgDCAAIwgbIgwjCEECRnIjCBsbIQAgDEUjCEECRnIEQCADQQ5GQQEjCBsEQCAEEGchAUEOIwhBAUYNBRogASEFCyMIRQRAQayXASgCAEEBdgR/QbCXAS8BAAVBfwsgBUchAgsgAiMIQQJGcgRAIANBD0ZBASMIGwRAQdCXAUGQlAFBvgFBCxAKQQ8jCEEBRg0GGgsjCEUEQAALCwsgA0EQRkEBIwgbBEAgBBBoQRAjCEEBRg0EGgsgA0ERRkEBIwgbBEAgBBBkIQFBESMIQQFGDQQaIAEhAgsgA0ESRkEBIwgbBEAgBBBuIQFBEiMIQQFGDQQaIAEhBQsjCEUEQCACIAU2AgALIANBE0ZBASMIGwRAIAQQaEETIwhBAUYNBBoLIANBFEZBASMIGwRAIAQQZyEBQRQjCEEBRg0EGiABIQULIwhFBEBB7JwBKAIAQQF2BH9B8JwBLwEABUF/CyAFRyECCyACIwhBAkZyBEAgA0EVRkEBIwgbBEBBkJ0BQZCUAUHPAUEFEApBFSMIQQFGDQUaCyMIRQRAAAsLIANBFkZBASMIGwRAIAQQdCEBQRYjCEEBRg0EGiABIQILIwhFDQELCyADQRdGQQEjCBsEQCAEEGchAUEXIwhBAUYNAhogASECCyMIRQRAQYyXASgCAEEBdgR/QZCXAS8BAAVBfwsgAkchAAsgACMIQQJGcgRAIANBGEZBASMIGwRAQcCdAUGQlAFBxQFBBxAKQRgjCEEBRg0DGgsjCEUEQAALCyMIRQRAIAQoAgAiACgCACgCDEEBSiECCyACIwhBAkZyBH8jCEUEQCAAKAIAIQALIANBGUZBASMIGwR/IAAQNSEBQRkjCEEBRg0DGiABBSAACwUgAAtBASMIGyEACyACIABFIwgbIgIjCEECRnIEQCADQRpGQQEjCBsEQCAEEGYhAUEaIwhBAUYNAhogASECC0EAAn8jCEUEQEGMngEoAgBBAXYEf0GQngEvAQAFQX8LIAJHIQALIAALQQAgDSAAIwgbIg0jCEECRnIjCBsbIQAgDUUjCEECRnIEfyADQRtGQQEjCBsEQCAEEGQhAUEbIwhBAUYNAxogASEACyMIRQRAIAAoAgAhAgsgA0EcRkEBIwgbBEAgBBBkIQFBHCMIQQFGDQMaIAEhAAsjCEUEQCAAQaAINgIAIAQoAgAhBgsgA0EdRkEBIwgbBEBBBEE3EA0hAUEdIwhBAUYNAxogASEACyMIRQRAIABBADYCACAARSEFCyAFIwhBAkZyQQAgA0EeRkEBIwgbGwRAQQBBMBANIQFBHiMIQQFGDQMaIAEhAAsgA0EfRkEBIwgbBEAQYyEBQR8jCEEBRg0DGiABIQULIwhFBEAgACAFNgIAIAYoAgAoAgwhBQsgBSMIQQJGckEAIANBIEZBASMIGxsEQCAGIAIgABBpQSAjCEEBRg0DGgsjCEUEQCAGKAIAIQILIANBIUZBASMIGwRAIAIgABAzQSEjCEEBRg0DGgsgA0EiRkEBIwgbBEAgBBBnIQFBIiMIQQFGDQMaIAEhAAsgA0EjRkEBIwgbBEAgBBBoQSMjCEEBRg0DGgsgAEEBIwgbIQADQCADQSRGQQEjCBsEQCAEEGYhAUEkIwhBAUYNBBogASEFCyMIRQRAQYwfKAIAQQF2BH9BkB8vAQAFQX8LIAVHIQILIAIjCEECRnIEQEEAIABBACAOIAAjCBsiDiMIQQJGciMIGxshACAORSMIQQJGcgRAIANBJUZBASMIGwRAIAQQZyEBQSUjCEEBRg0GGiABIQULIwhFBEBBrJcBKAIAQQF2BH9BsJcBLwEABUF/CyAFRyECCyACIwhBAkZyBEAgA0EmRkEBIwgbBEBB0JcBQZCUAUHgAUELEApBJiMIQQFGDQcaCyMIRQRAAAsLCyADQSdGQQEjCBsEQCAEEHQhAUEnIwhBAUYNBRogASECCyMIRQ0BCwsgA0EoRkEBIwgbBEAgBBBnIQFBKCMIQQFGDQMaIAEhAgsjCEUEQEGMHygCAEEBdgR/QZAfLwEABUF/CyACRyEACyAAIwhBAkZyBEAgA0EpRkEBIwgbBEBBsJ4BQZCUAUHmAUEHEApBKSMIQQFGDQQaCyMIRQRAAAsLIwhFBEAgBCgCACIAKAIAKAIMQQFKIQILIAIjCEECRnIEfyMIRQRAIAAoAgAhAAsgA0EqRkEBIwgbBH8gABA1IQFBKiMIQQFGDQQaIAEFIAALBSAAC0EBIwgbBSAACyEACyACIABFIwgbIgIjCEECRnIEQCADQStGQQEjCBsEQCAEEGYhAUErIwhBAUYNAhogASECC0EAAn8jCEUEQEHsjQEoAgBBAXYEf0HwjQEvAQAFQX8LIAJHIQALIAALQQAgDyAAIwgbIg8jCEECRnIjCBsbIQAgD0UjCEECRnIEfyMIRQRAIAQoAgAhAAsgA0EsRkEBIwgbBEAgBBBkIQFBLCMIQQFGDQMaIAEhAgsjCEUEQCACKAIAIQILIANBLUZBASMIGwRAIAQQbiEBQS0jCEEBRg0DGiABIQYLIANBLkZBASMIGwRAQQRBORANIQFBLiMIQQFGDQMaIAEhBQsjCEUEQCAFIAY2AgALIBggBSMIGyIYRSMIQQJGckEAIANBL0ZBASMIGxsEQEEAQTAQDSEBQS8jCEEBRg0DGiABIQULIANBMEZBASMIGwRAIAAgAiAFEGlBMCMIQQFGDQMaCyAAQQEjCBsFIAALIQALIAIgAEUjCBsiAiMIQQJGcgRAIANBMUZBASMIGwRAIAQQZiEBQTEjCEEBRg0CGiABIQILAn8jCEUEQEG8kgEoAgBBAXYEf0HAkgEvAQAFQX8LIAJGIQALIAAjCEECRnIEQCADQTJGQQEjCBsEQCAEQcCSARBvQTIjCEEBRg0EGgsjCEUEQCAEKAIAIQALIANBM0ZBASMIGwRAIAQQZCEBQTMjCEEBRg0EGiABIQILIwhFBEAgAigCACECCyADQTRGQQEjCBsEQCAAIAJBABBwQTQjCEEBRg0EGgtBASMIRQ0BGgsgA0E1RkEBIwgbBEAgBBBmIQFBNSMIQQFGDQMaIAEhAgsjCEUEQEGckgEoAgBBAXYEf0GgkgEvAQAFQX8LIAJGIQALIAAjCEECRnIEQCADQTZGQQEjCBsEQCAEQaCSARBvQTYjCEEBRg0EGgsjCEUEQCAEKAIAIQALIANBN0ZBASMIGwRAIAQQZCEBQTcjCEEBRg0EGiABIQILIwhFBEAgAigCACECCyADQThGQQEjCBsEQCAAIAJBARBwQTgjCEEBRg0EGgtBASMIRQ0BGgsgAEEAIwgbCyEACyACIABFIwgbIgIjCEECRnIEQCAbRAAAAAAAAPA/IwgbIRtBoAggEAJ/IANBOUZBASMIGwRAIAQQZiEBQTkjCEEBRg0DGiABIQALIAAgAEEtRiMIGyIACyMIGyIQIwhBAkZyBH8gG0QAAAAAAADwvyMIGyEbIANBOkZBASMIGwRAIAQQZyEBQTojCEEBRg0DGiABIQALIwhFBEBBASQACyADQTtGQQEjCBsEQCAAEG0hAUE7IwhBAUYNAxogASEACyADQTxGQQEjCBsEf0GgCCAAEB8hAUE8IwhBAUYNAxogAQUgAAsFIAALQQAgEEUjCEECRnIjCBsbIQADQEEBQQAgEQJ/IANBPUZBASMIGwRAIAQQZiEBQT0jCEEBRg0EGiABIQILIAIgAkEwTiMIGyICCyMIGyIRIwhBAkZyBH8gA0E+RkEBIwgbBEAgBBBmIQFBPiMIQQFGDQQaIAEhAgsgAiACQTlMIwgbBSACC0EAIBFFIwhBAkZyIwgbGyICQQAgEiACIwgbIhIjCEECRnIjCBsbIQJBAQJ/IBJFIwhBAkZyBEAgA0E/RkEBIwgbBEAgBBBmIQFBPyMIQQFGDQUaIAEhAgsgAiACQS5GIwgbIQILIAILQQAgEyACIwgbIhMjCEECRnIjCBsbIQJBAQJ/IBNFIwhBAkZyBEAgA0HAAEZBASMIGwRAIAQQZiEBQcAAIwhBAUYNBRogASECCyACIAJBLUYjCBshAgsgAgtBACAUIAIjCBsiFCMIQQJGciMIGxshAkEBAn8gFEUjCEECRnIEQCADQcEARkEBIwgbBEAgBBBmIQFBwQAjCEEBRg0FGiABIQILIAIgAkErRiMIGyECCyACC0EAIBUgAiMIGyIVIwhBAkZyIwgbGyECQQECfyAVRSMIQQJGcgRAIANBwgBGQQEjCBsEQCAEEGYhAUHCACMIQQFGDQUaIAEhAgsgAiACQcUARiMIGyECCyACC0EAIBYgAiMIGyIWIwhBAkZyIwgbGyECIBZFIwhBAkZyBEAgA0HDAEZBASMIGwRAIAQQZiEBQcMAIwhBAUYNBBogASECCyACIAJB5QBGIwgbIQILIAIjCEECRnIEQCADQcQARkEBIwgbBEAgBBBnIQFBxAAjCEEBRg0EGiABIQILIwhFBEBBASQACyADQcUARkEBIwgbBEAgAhBtIQFBxQAjCEEBRg0EGiABIQULIANBxgBGQQEjCBsEQCAAIAUQHyEBQcYAIwhBAUYNBBogASEACyMIRQRAIAJB5QBGIAJBxQBGciACQS5GciACQStGciACQS1GciIFBEBBASEIBSAaIBpEAAAAAAAAJECiIAJBMGsiAregIAgbIRogCUEBaiEJCwwCCwsLAn8gAiAJQQBKIwgbIgIjCEECRnIEQCAXAn8jCEUEQCAIBH9BAQUgAEHQnwEQHQshAgsgAgsjCBsiFyMIQQJGcgRAIwhFBEAgBCgCACEICyADQccARkEBIwgbBEAgBBBkIQFBxwAjCEEBRg0FGiABIQILIwhFBEAgAigCACEJIAAQcyEaCyACAn8gA0HIAEZBASMIGwRAQQhBOxANIQFByAAjCEEBRg0GGiABIQALIABFCyMIGyICIwhBAkZyQQAgA0HJAEZBASMIGxsEQEEIQTwQDSEBQckAIwhBAUYNBRogASEACyMIRQRAIAAgGjkDAAsLIBdFIwhBAkZyBEAjCEUEQCAEKAIAIQgLIANBygBGQQEjCBsEQCAEEGQhAUHKACMIQQFGDQUaIAEhAAsjCEUEQCAaIBuisCEcIAAoAgAhCQsgA0HLAEZBASMIGwRAQQhBPRANIQFBywAjCEEBRg0FGiABIQALIwhFBEAgACAcNwMACwsgGSAAIwgbIhlFIwhBAkZyQQAgA0HMAEZBASMIGxsEQEEAQTAQDSEBQcwAIwhBAUYNBBogASEACyADQc0ARkEBIwgbBEAgCCAJIAAQaUHNACMIQQFGDQQaC0EBIwhFDQEaCyAAQQAjCBsLIQALIAIgAEUjCBsiAiMIQQJGcgRAIANBzgBGQQEjCBsEQCAEEGYhAUHOACMIQQFGDQIaIAEhAgsCfyMIRQRAQayhASgCAEEBdgR/QbChAS8BAAVBfwsgAkYhAAsgACMIQQJGcgRAIANBzwBGQQEjCBsEQCAEQbChARBvQc8AIwhBAUYNBBoLIwhFBEAgBCgCACEACyADQdAARkEBIwgbBEAgBBBkIQFB0AAjCEEBRg0EGiABIQILIwhFBEAjByEFIAIoAgAhAgsgA0HRAEZBASMIGwRAIAAgAiAFEGlB0QAjCEEBRg0EGgtBASMIRQ0BGgsgAEEAIwgbCyEACyADQdIARkEBIwgbBEAgBBBoQdIAIwhBAUYNARoLIwhFBEAgAA8LAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAiASAANgIAIAEgAjYCBCABIAQ2AgggASAFNgIMIAEgBjYCECABIAc2AhQgASAINgIYIAEgGjkCHCABIAk2AiQgASAbOQIoIAEgCjYCMCABIAs2AjQgASAMNgI4IAEgDTYCPCABIA42AkAgASAPNgJEIAEgGDYCSCABIBA2AkwgASARNgJQIAEgEjYCVCABIBM2AlggASAUNgJcIAEgFTYCYCABIBY2AmQgASAXNgJoIAEgGTYCbCABIBw3AnAjCSMJKAIAQfgAajYCAEEAC60FAQR/IwhBAkYEQCMJIwkoAgBBDGs2AgAjCSgCACIBKAIAIQAgASgCCCEDIAEoAgQhAgsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBAsjCEUEQEECJAALIARBACMIG0UEQCAAEAkhAUEAIwhBAUYNARogASEACyMIRQRAIABBFGsoAhBFIQILAkAgAiMIQQJGcgRAIARBAUZBASMIGwRAQQxBJxANIQFBASMIQQFGDQMaIAEhAAsgBEECRkEBIwgbBEAgAEEAQQAQECEBQQIjCEEBRg0DGiABIQILIwhFDQELIwhFBEBBASQACyAEQQNGQQEjCBsEQCAAEGIhAUEDIwhBAUYNAhogASECCwsgAyMFIwgbIQMgBEEERkEBIwgbBEBBDEE2EA0hAUEEIwhBAUYNARogASEACyMIRQRAIAAgAjYCCCAAQaAINgIAIABBADYCBCADIAA2AgQLIARBBUZBASMIGwRAIAMQdCEBQQUjCEEBRg0BGiABIQALIAAgAEUjCBsiACMIQQJGcgRAIARBBkZBASMIGwRAQdChAUGQlAFBkAFBBRAKQQYjCEEBRg0CGgsjCEUEQAALCyMIRQRAIwUoAgAoAgAiACgCDEEBayECCyAEQQdGQQEjCBsEQCAAIAIQJSEBQQcjCEEBRg0BGiABIQALIwhFBEAjBSgCACECCwNAIwhFBEAgAigCACgCDEEASiEDCyADIwhBAkZyBEAjCEUEQCACKAIAIQMLIARBCEZBASMIGwRAIAMQNSEBQQgjCEEBRg0DGiABIQMLIwhFDQELCyMIRQRAIAAPCwALIQEjCSgCACABNgIAIwkjCSgCAEEEajYCACMJKAIAIgEgADYCACABIAI2AgQgASADNgIIIwkjCSgCAEEMajYCAEEAC88BAQF/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEACwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACECCyMIRQRAIAAgASABEEIQQyIARSEBCyABIwhBAkZyBEAgAkEAIwgbRQRAQfCiAUGwowFB6QBBERAKQQAjCEEBRg0CGgsjCEUEQAALCyMIRQRAIAAoAgQPCwALIQEjCSgCACABNgIAIwkjCSgCAEEEajYCACMJKAIAIAA2AgAjCSMJKAIAQQRqNgIAQQAL5QMBA38jCEECRgRAIwkjCSgCAEEMazYCACMJKAIAIgIoAgAhACACKAIEIQEgAigCCCECCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEEC0EAIAIjCAR/IAMFIAAoAgAgASABEEIQQwsjCBsiAiMIQQJGcgR/IwhFBEAgACgCACEACyAEQQAjCBsEfyAABSAAIAEQdiEDQQAjCEEBRg0CGiADCwUgAAtBACACRSMIQQJGciMIGxshACMIRQRAIAAEfyAABH8CfyAAQRRrKAIMIgFBkNYBKAIATQRAA0BBASABQTlGDQIaIAFBA3RBlNYBaigCBCIBDQALC0EACwVBAAsFQQALIQELIAEjCEECRnIEQCMIBH8gAQUCfyAAQRRrKAIMIgFBkNYBKAIATQRAA0BBASABQTlGDQIaIAFBA3RBlNYBaigCBCIBDQALC0EAC0ULIwhBAkZyBEAgBEEBRkEBIwgbBEBB4JUBQaCWAUHuAkEVEApBASMIQQFGDQMaCyMIRQRAAAsLIwhFBEAgAA8LCyMIRQRAQQAPCwALIQMjCSgCACADNgIAIwkjCSgCAEEEajYCACMJKAIAIgMgADYCACADIAE2AgQgAyACNgIIIwkjCSgCAEEMajYCAEEAC9YKAQd/IwhBAkYEQCMJIwkoAgBBGGs2AgAjCSgCACICKAIAIQAgAigCCCEDIAIoAgwhBSACKAIQIQYgAigCFCEHIAIoAgQhAQsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBAsjCEUEQCAAKAIIIQYgACgCDCEDIAAoAgAhAQsgBEEAIwgbRQRAEEEhAkEAIwhBAUYNARogAiEFCyAEQQFGQQEjCBsEQCAFQbDEACABEERBASMIQQFGDQEaCyADIwhBAkZyBEAjCEUEQCADKAIAIQELIAEjCEECRnIEQCMIRQRAIAMoAgAiAUUhBwsgByMIQQJGcgRAIARBAkZBASMIGwRAQdDEAEGQxQBBiQFBIhAKQQIjCEEBRg0EGgsjCEUEQAALCyMIRQRAQaCSAUHAkgEgAS0AABshAQsgBEEDRkEBIwgbBEAgBUHQkAEgARBEQQMjCEEBRg0DGgsLIwhFBEAgAygCBCEBCyABIwhBAkZyBEAjCAR/IAcFIAMoAgQiAUULIwhBAkZyBEAgBEEERkEBIwgbBEBB0MQAQZDFAEGMAUEpEApBBCMIQQFGDQQaCyMIRQRAAAsLIwhFBEAgASgCACEBCyAEQQVGQQEjCBsEQCABECIhAkEFIwhBAUYNAxogAiEBCyAEQQZGQQEjCBsEQCAFQeCSASABEERBBiMIQQFGDQMaCwsjCEUEQCADKAIIIQELIAEjCEECRnIEQCMIRQRAIAMoAggiAUUhAwsgAyMIQQJGcgRAIARBB0ZBASMIGwRAQdDEAEGQxQBBjwFBJBAKQQcjCEEBRg0EGgsjCEUEQAALCyAEQQhGQQEjCBsEQCAFQaCTASABEERBCCMIQQFGDQMaCwsLIARBCUZBASMIGwRAEEUhAkEJIwhBAUYNARogAiEBCyMIRQRAIAEgBTYCBCABQQA2AgggASAGNgIUIAFBADYCACABQQA2AgwgAUEANgIQIAAoAgQhAwsgBEEKRkEBIwgbBEAgAyABQbCOAUHQkwEQWSECQQojCEEBRg0BGiACIQYLIARBC0ZBASMIGwRAQQhBLhANIQJBCyMIQQFGDQEaIAIhAwsjCEUEQCADQQA2AgAgA0EANgIECyAEQQxGQQEjCBsEQCAGEHUhAkEMIwhBAUYNARogAiEFCyMIRQRAAn8gBUEUaygCDCIBQZDWASgCAE0EQANAQQEgAUEvRg0CGiABQQN0QZTWAWooAgQiAQ0ACwtBAAtFIQELIAEjCEECRnIEQCAEQQ1GQQEjCBsEQEHglQFBkKIBQQZBLhAKQQ0jCEEBRg0CGgsjCEUEQAALCyAEQQ5GQQEjCBsEQCAFQdCiARB3IQJBDiMIQQFGDQEaIAIhAQsjCEECRiAFIAFFIwgbcgRAIARBD0ZBASMIGwRAQdDEAEGQogFBB0EqEApBDyMIQQFGDQIaCyMIRQRAAAsLAkAjCEEBIAEbRQRAIAEoAgAhAQwBCyAEQRBGQQEjCBsEQEHgowEgBhAfIQJBECMIQQFGDQIaIAIhAAsgBEERRkEBIwgbBEBBsI4BIABBAEEAEFchAkERIwhBAUYNAhogAiEACyAAIABBoAggABsjCBshACAEQRJGQQEjCBsEQCAAQZCiAUELQQUQCkESIwhBAUYNAhoLIwhFBEAACwsjCEUEQCADIAE2AgAgAyAAKAIENgIEIAMPCwALIQIjCSgCACACNgIAIwkjCSgCAEEEajYCACMJKAIAIgIgADYCACACIAE2AgQgAiADNgIIIAIgBTYCDCACIAY2AhAgAiAHNgIUIwkjCSgCAEEYajYCAEEAC50HAQR/IwhBAkYEQCMJIwkoAgBBFGs2AgAjCSgCACIEKAIAIQAgBCgCBCEBIAQoAgghAiAEKAIMIQUgBCgCECEECwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEDCyMIRQRAAkACQAJAIABBCGsoAgAiBEEhRyICBEAgBEEdRiICDQEMAgsgACgCACEFDAILIAAoAgghBQwBCwALCyADQQAjCBtFBEAgBUGwjgFBwKUBQZDHABA0QQAjCEEBRg0BGgsgBCABIwgbIQQgA0EBRkEBIwgbBEAgAEECEKYBQQEjCEEBRg0BGgsjCEUEQAJAAkACQCAAIgJBCGsoAgAiAUEhRyIFBEAgAUEdRg0BDAILIAIoAgAhAQwCCyACKAIIIQEMAQsACwsgA0ECRkEBIwgbBEAgAUGgNkHANkGQxwAQNEECIwhBAUYNARoLIANBA0ZBASMIGwRAIAJBoDYQpwFBAyMIQQFGDQEaCyMIRQRAIAQoAgAhAQsgA0EERkEBIwgbBEAgAiABEKcBQQQjCEEBRg0BGgsjCEUEQAJAAkACQCACQQhrKAIAIgFBIUcEQCABQR1GIgENAQwCCyACKAIAIQUMAgsgAigCCCEFDAELAAsLIANBBUZBASMIGwRAIAUQNkEFIwhBAUYNARoLIwhFBEACQAJAAkAgAkEIaygCACIBQSFHBEAgAUEdRiIBDQEMAgsgAigCACEFDAILIAIoAgghBQwBCwALCyADQQZGQQEjCBsEQCAFQYCmAUHANkGQxwAQNEEGIwhBAUYNARoLIANBB0ZBASMIGwRAIAJBgKYBEKcBQQcjCEEBRg0BGgsjCEUEQCAEKAIEIQELIANBCEZBASMIGwRAIAIgARCnAUEIIwhBAUYNARoLIwhFBEACQAJAAkAgAkEIaygCACIBQSFHBEAgAUEdRg0BDAILIAIoAgAhAQwCCyACKAIIIQEMAQsACwsgA0EJRkEBIwgbBEAgARA2QQkjCEEBRg0BGgsjCEUEQAJAAkACQCAAQQhrKAIAIgFBIUcEQCABQR1GDQEMAgsgACgCACEADAILIAAoAgghAAwBCwALCyADQQpGQQEjCBsEQCAAEDZBCiMIQQFGDQEaCw8LIQMjCSgCACADNgIAIwkjCSgCAEEEajYCACMJKAIAIgMgADYCACADIAE2AgQgAyACNgIIIAMgBTYCDCADIAQ2AhAjCSMJKAIAQRRqNgIAC/kRAQ5/IwhBAkYEQCMJIwkoAgBBOGs2AgAjCSgCACICKAIAIQAgAigCCCEDIAIoAgwhBSACKAIQIQYgAigCFCEHIAIoAhghCCACKAIcIQkgAigCICEKIAIoAiQhCyACKAIoIQwgAigCLCENIAIoAjAhDyACKAI0IQ4gAigCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEECyAEQQAjCBtFBEBB4I4BEB4hAkEAIwhBAUYNARogAiEBCyAEQQFGQQEjCBsEQCAAIAEQKCECQQEjCEEBRg0BGiACIQULIARBAkZBASMIGwRAIAUQLyECQQIjCEEBRg0BGiACIQALIwhFBEBBoAghCUGgCCEBCwNAIAAjCEECRnIEQCAAIABBAWsjCBshACAEQQNGQQEjCBsEQCAFEDAhAkEDIwhBAUYNAxogAiEDCyMIRQRAIAUoAgQhBgsgBEEERkEBIwgbBEAgBiADEDEhAkEEIwhBAUYNAxogAiEDCyMIRQRAIANBFGsoAhAhBgsgBEEFRkEBIwgbBEAgAyAGEBshAkEFIwhBAUYNAxogAiEDCyMIRQRAIAUoAgAhBgsgBEEGRkEBIwgbBEAgBiADQaA1QdA1EDRBBiMIQQFGDQMaCyAKAn8jCEUEQCADQaA2EB0hBgsgBgsjCBsiCiMIQQJGcgRAIwhFBEAgBSgCACEBCyAEQQdGQQEjCBsEQCABIANBwDZB4DYQNEEHIwhBAUYNBBoLIARBCEZBASMIGwRAIAUQMCECQQgjCEEBRg0EGiACIQELIwhFBEAgBSgCBCEDCyAEQQlGQQEjCBsEQCADIAEQMSECQQkjCEEBRg0EGiACIQELIwhFBEAgAUEUaygCECEDCyAEQQpGQQEjCBsEQCABIAMQGyECQQojCEEBRg0EGiACIQELIwhFBEBBASEPIAUoAgAhAwsgBEELRkEBIwgbBEAgAxA2QQsjCEEBRg0EGgsLIApFIwhBAkZyBEAgCwJ/IwhFBEAgA0GAOhAdIQYLIAYLIwgbIgsjCEECRnIEQCMIRQRAIAUoAgAhCAsgBEEMRkEBIwgbBEAgCCADQcA2QeA2EDRBDCMIQQFGDQUaCyAEQQ1GQQEjCBsEQCAFEDAhAkENIwhBAUYNBRogAiEICyMIRQRAIAUoAgQhAwsgBEEORkEBIwgbBEAgAyAIEDEhAkEOIwhBAUYNBRogAiEICyMIRQRAIAhBFGsoAhAhAwsgBEEPRkEBIwgbBEAgCCADEBshAkEPIwhBAUYNBRogAiEJCyMIRQRAQQEhCCAFKAIAIQMLIARBEEZBASMIGwRAIAMQNkEQIwhBAUYNBRoLCyALRSMIQQJGcgRAIAwCfyMIRQRAIANBsDoQHSEGCyAGCyMIGyIMIwhBAkZyBEAjCEUEQCAFKAIAIQYLIARBEUZBASMIGwRAIAYgA0HgOkHgNhA0QREjCEEBRg0GGgsgBEESRkEBIwgbBEAgBRA+IQJBEiMIQQFGDQYaIAIhDgsjCEUEQCAFKAIAIQMLIARBE0ZBASMIGwRAIAMQNkETIwhBAUYNBhoLCyAMRSMIQQJGcgRAIwhFBEAgA0HQjwEQHSEGCyAGIwhBAkZyBEAjCEUEQCAFKAIAIQcLIARBFEZBASMIGwRAIAcgA0GAkAFB4DYQNEEUIwhBAUYNBxoLQQACfyAEQRVGQQEjCBsEQCAFECohAkEVIwhBAUYNCBogAiEHCyAHC0EAIA0gByMIGyINIwhBAkZyIwgbGyEHIA1FIwhBAkZyQQAgBEEWRkEBIwgbGwRAIAUQYCECQRYjCEEBRg0HGiACIQcLIwhFBEAgBSgCACEDCyAEQRdGQQEjCBsEQCADEDZBFyMIQQFGDQcaCwsLCwsjCEUEQCAFKAIAIQMLIARBGEZBASMIGwRAIAMQNkEYIwhBAUYNAxoLIwhFDQELCyAAIA9FIwgbIgAjCEECRnIEQCMIRQRAIAUoAgAhAAsgBEEZRkEBIwgbBEAgAEHgwQAQJiECQRkjCEEBRg0CGiACIQALIAAgAEGgCCAAGyMIGyEAIARBGkZBASMIGwRAIABB0MIAQcsBQQUQCkEaIwhBAUYNAhoLIwhFBEAACwsgACAIRSMIGyIAIwhBAkZyBEAjCEUEQCAFKAIAIQALIARBG0ZBASMIGwRAIABBsMMAECYhAkEbIwhBAUYNAhogAiEACyAAIABBoAggABsjCBshACAEQRxGQQEjCBsEQCAAQdDCAEHOAUEFEApBHCMIQQFGDQIaCyMIRQRAAAsLIARBHUZBASMIGwRAEGEhAkEdIwhBAUYNARogAiEACyMIRQRAIAAgATYCACAAIAk2AgQgACAONgIIIAAgBzYCDAsgBEEeRkEBIwgbBEAQYSECQR4jCEEBRg0BGiACIQELIwhFBEAgASAAKAIANgIAIAEgACgCBDYCBCABIAAoAggiBzYCCCABIAAoAgwiADYCDAsgBEEfRkEBIwgbBEAgARB4IQJBHyMIQQFGDQEaIAIhAAsgBEEgRkEBIwgbBEBBwKQBEB4hAkEgIwhBAUYNARogAiEBCyAEQSFGQQEjCBsEQCABEEYhAkEhIwhBAUYNARogAiEBCyAEQSJGQQEjCBsEQCABIAAQeUEiIwhBAUYNARoLIwhFBEAgASgCACEHCyAEQSNGQQEjCBsEQCAHEBkhAkEjIwhBAUYNARogAiEHCyAEQSRGQQEjCBsEQEGwpgEQHiECQSQjCEEBRg0BGiACIQULIARBJUZBASMIGwRAIAcgASAFEEwhAkElIwhBAUYNARogAiEBCyAEQSZGQQEjCBsEQCABIAAQeUEmIwhBAUYNARoLIwhFBEAgBw8LAAshAiMJKAIAIAI2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAiAANgIAIAIgATYCBCACIAM2AgggAiAFNgIMIAIgBjYCECACIAc2AhQgAiAINgIYIAIgCTYCHCACIAo2AiAgAiALNgIkIAIgDDYCKCACIA02AiwgAiAPNgIwIAIgDjYCNCMJIwkoAgBBOGo2AgBBAAv3DgEKfyMIQQJGBEAjCSMJKAIAQSRrNgIAIwkoAgAiAigCACEAIAIoAgghBCACKAIMIQUgAigCECEGIAIoAhQhByACKAIYIQggAigCHCEJIAIoAiAhCiACKAIEIQELAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQMLIwhFBEAgAEEIaygCAEESRiEBCwJAIAEjCEECRnIEQCADQQAjCBtFBEAgABAvIQJBACMIQQFGDQMaIAIhBwsjCEUNAQsjCEUEQAALCyAGQaAIIwgbIQYgA0EBRkEBIwgbBEBBABAZIQJBASMIQQFGDQEaIAIhBAsDQCAHIwhBAkZyBEAgByAHQQFrIwgbIQcgA0ECRkEBIwgbBEAgABCkASECQQIjCEEBRg0DGiACIQULIwhFBEACQCAAQQhrKAIAQRJGBEAgACgCACEBDAELAAsLIANBA0ZBASMIGwRAIAEgBUGgNUHQNRA0QQMjCEEBRg0DGgsgCAJ/IwhFBEAgBUHQzgAQHSEBCyABCyMIGyIIIwhBAkZyBEAjCEUEQAJAIABBCGsoAgBBEkYiAQRAIAAoAgAhBgwBCwALCyADQQRGQQEjCBsEQCAGIAVBwDZB4DYQNEEEIwhBAUYNBBoLIANBBUZBASMIGwRAIAAQpAEhAkEFIwhBAUYNBBogAiEGCyMIRQRAQQEhCQJAIABBCGsoAgBBEkYEQCAAKAIAIQEMAQsACwsgA0EGRkEBIwgbBEAgARA2QQYjCEEBRg0EGgsLIAhFIwhBAkZyBEAjCEUEQCAFQfCoARAdIQELIAEjCEECRnIEQCMIRQRAAkAgAEEIaygCAEESRiIBBEAgACgCACEEDAELAAsLIANBB0ZBASMIGwRAIAQgBUHQiwFB4DYQNEEHIwhBAUYNBRoLIwhFBEAgAEEIaygCAEESRiEBCwJAIAEjCEECRnIEQCAEQQAjCBshBCADQQhGQQEjCBsEQCAAECohAkEIIwhBAUYNBxogAiEBCwJAIwhFBEAgAQ0BIAAoAgQhAQsgA0EJRkEBIwgbBEAgARArIQJBCSMIQQFGDQgaIAIhAQsjCEUEQCABQQ9xIQQgAUHwAXFBkAFGIgUNASABQf8BcUHAAWshBAsCQAJAAkACQCMIRQRAAkAgBA4HBAUFBQACAwULIAAoAgQhAQsgA0EKRkEBIwgbBEAgARArIQJBCiMIQQFGDQwaIAIhAQsjCEUEQCABQf8BcSEEDAULCyMIRQRAIAAoAgQhAQsgA0ELRkEBIwgbBEAgARAsIQJBCyMIQQFGDQsaIAIhAQsjCEUEQCABQf//A3EhBAwECwsjCEUEQCAAKAIEIQELIANBDEZBASMIGwRAIAEQLSECQQwjCEEBRg0KGiACIQQLIwhFDQILIwhFBEBBACEEDAILCyMIRQRAIAAoAgAhAAsgA0ENRkEBIwgbBEAgARAuIQJBDSMIQQFGDQgaIAIhAQsgA0EORkEBIwgbBEBBwNQBIAEQHyECQQ4jCEEBRg0IGiACIQELIANBD0ZBASMIGwRAIAAgARAmIQJBDyMIQQFGDQgaIAIhAAsgACAAQaAIIAAbIwgbIQAgA0EQRkEBIwgbBEAgAEGgM0HGAUEFEApBECMIQQFGDQgaCyMIRQRAAAsLIwhFBEAgACgCBCEBCyADQRFGQQEjCBsEQCABIAQQMSECQREjCEEBRg0HGiACIQQLIwhFDQELIwhFBEAACwsjCEUEQEEBIQoCQCAAQQhrKAIAQRJGBEAgACgCACEBDAELAAsLIANBEkZBASMIGwRAIAEQNkESIwhBAUYNBRoLCwsjCEUEQAJAIABBCGsoAgBBEkYEQCAAKAIAIQEMAQsACwsgA0ETRkEBIwgbBEAgARA2QRMjCEEBRg0DGgsjCEUNAQsLIAEgCUUjCBsiASMIQQJGcgRAIwhFBEACQCAAQQhrKAIAQRJGIgEEQCAAKAIAIQAMAQsACwsgA0EURkEBIwgbBEAgAEHQqQEQJiECQRQjCEEBRg0CGiACIQALIAAgAEGgCCAAGyMIGyEAIANBFUZBASMIGwRAIABBwKoBQcoAQQUQCkEVIwhBAUYNAhoLIwhFBEAACwsjCEECRiABIApFIwgbcgRAIwhFBEACQCAAQQhrKAIAQRJGBEAgACgCACEADAELAAsLIANBFkZBASMIGwRAIABBoKsBECYhAkEWIwhBAUYNAhogAiEACyAAIABBoAggABsjCBshACADQRdGQQEjCBsEQCAAQcCqAUHNAEEFEApBFyMIQQFGDQIaCyMIRQRAAAsLIANBGEZBASMIGwRAQQhBwAAQDSECQRgjCEEBRg0BGiACIQALIwhFBEAgAEEANgIAIABBADYCBCAAIAY2AgAgACAENgIEIAAPCwALIQIjCSgCACACNgIAIwkjCSgCAEEEajYCACMJKAIAIgIgADYCACACIAE2AgQgAiAENgIIIAIgBTYCDCACIAY2AhAgAiAHNgIUIAIgCDYCGCACIAk2AhwgAiAKNgIgIwkjCSgCAEEkajYCAEEAC7gJAQp/IwhBAkYEQCMJIwkoAgBBJGs2AgAjCSgCACIBKAIAIQAgASgCCCEDIAEoAgwhBSABKAIQIQYgASgCFCEHIAEoAhghCCABKAIcIQkgASgCICEKIAEoAgQhAgsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBAsjCEUEQCAAIgJBCGsoAgBBEkYhAAsCQCAAIwhBAkZyBEAgBEEAIwgbRQRAIAIQLyEBQQAjCEEBRg0DGiABIQALIwhFDQELIwhFBEAACwsDQCAAIwhBAkZyBEAgACAAQQFrIwgbIQAgBEEBRkEBIwgbBEAgAhCkASEBQQEjCEEBRg0DGiABIQMLIwhFBEACQCACQQhrKAIAQRJGBEAgAigCACEFDAELAAsLIARBAkZBASMIGwRAIAUgA0GgNUHQNRA0QQIjCEEBRg0DGgsgCQJ/IwhFBEAgA0GQrQEQHSEFCyAFCyMIGyIJIwhBAkZyBEAjCEUEQAJAIAJBCGsoAgBBEkYEQCACKAIAIQYMAQsACwsgBEEDRkEBIwgbBEAgBiADQYCRAUHgNhA0QQMjCEEBRg0EGgsgBEEERkEBIwgbBEAgAhCsASEBQQQjCEEBRg0EGiABIQYLIwhFBEACQCACQQhrKAIAQRJGBEAgAigCACEDDAELAAsLIARBBUZBASMIGwRAIAMQNkEFIwhBAUYNBBoLCyAJRSMIQQJGcgRAIAoCfyMIRQRAIANBsK0BEB0hBQsgBQsjCBsiCiMIQQJGcgRAIwhFBEACQCACQQhrKAIAQRJGBEAgAigCACEHDAELAAsLIARBBkZBASMIGwRAIAcgA0GAkQFB4DYQNEEGIwhBAUYNBRoLIARBB0ZBASMIGwRAIAIQrAEhAUEHIwhBAUYNBRogASEHCyMIRQRAAkAgAkEIaygCAEESRgRAIAIoAgAhAwwBCwALCyAEQQhGQQEjCBsEQCADEDZBCCMIQQFGDQUaCwsgCkUjCEECRnIEQCMIRQRAIANB4K0BEB0hBQsgBSMIQQJGcgRAIwhFBEACQCACQQhrKAIAQRJGBEAgAigCACEIDAELAAsLIARBCUZBASMIGwRAIAggA0GAkQFB4DYQNEEJIwhBAUYNBhoLIARBCkZBASMIGwRAIAIQrAEhAUEKIwhBAUYNBhogASEICyMIRQRAAkAgAkEIaygCAEESRgRAIAIoAgAhAwwBCwALCyAEQQtGQQEjCBsEQCADEDZBCyMIQQFGDQYaCwsLCyMIRQRAAkAgAkEIaygCAEESRgRAIAIoAgAhAwwBCwALCyAEQQxGQQEjCBsEQCADEDZBDCMIQQFGDQMaCyMIRQ0BCwsgBEENRkEBIwgbBEBBDEHBABANIQFBDSMIQQFGDQEaIAEhAAsjCEUEQCAAQQA2AgAgAEEANgIEIABBADYCCCAAIAY2AgAgACAHNgIEIAAgCDYCCCAADwsACyEBIwkoAgAgATYCACMJIwkoAgBBBGo2AgAjCSgCACIBIAA2AgAgASACNgIEIAEgAzYCCCABIAU2AgwgASAGNgIQIAEgBzYCFCABIAg2AhggASAJNgIcIAEgCjYCICMJIwkoAgBBJGo2AgBBAAu9AQECfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAAsCfyMIQQJGBH8jCSMJKAIAQQRrNgIAIwkoAgAoAgAFIAELQQAjCBtFBEBBEEE/EA0hAUEAIwhBAUYNARogASEACyMIRQRAIABBADYCACAAQQA2AgQgAEEANgIIIABBADYCDCAADwsACyEBIwkoAgAgATYCACMJIwkoAgBBBGo2AgAjCSgCACAANgIAIwkjCSgCAEEEajYCAEEAC4YKAQl/IwhBAkYEQCMJIwkoAgBBIGs2AgAjCSgCACIBKAIAIQAgASgCBCECIAEoAgghAyABKAIMIQQgASgCECEGIAEoAhghByABKAIcIQkgASgCFCEFCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEICyMIRQRAQQRBACAAKAIIIgNBA28iBxsiBiADQQNtQQJ0akEBdCEECyAIQQAjCBtFBEAgBEEBEA0hAUEAIwhBAUYNARogASEJCyMIRQRAIAAoAghFIgQEQEGgCA8LIAMgB2shByAJQQJrIQMLA0AgBCACIAdIIwgbIgQjCEECRnIEQCMIRQRAIANBAmoiAyEECyAIQQFGQQEjCBsEQCAAIAIQZSEBQQEjCEEBRg0DGiABIQYLIwhFBEAgBkEQdCEGIAJBAWohBQsgCEECRkEBIwgbBEAgACAFEGUhAUECIwhBAUYNAxogASEFCyMIRQRAIAVBCHQgBnIhBiACQQJqIQULIAhBA0ZBASMIGwRAIAAgBRBlIQFBAyMIQQFGDQMaIAEhBQsjCEUEQCAEIAUgBnIiBUESdiIGQYyvASgCAEEBdk8Ef0F/BSAGQQF0QZCvAWovAQALOwEAIANBAmoiAyAFQQx2QT9xIgZBjK8BKAIAQQF2TwR/QX8FIAZBAXRBkK8Bai8BAAs7AQAgA0ECaiIDIAVBBnZBP3EiBkGMrwEoAgBBAXZPBH9BfwUgBkEBdEGQrwFqLwEACzsBACADQQJqIgMiBCAFQT9xIgVBjK8BKAIAQQF2TwR/QX8FIAVBAXRBkK8Bai8BAAsiBjsBACACQQNqIQIMAgsLCyMIRQRAIAAoAgggB2siB0EBRyEECwJAAkAjCEUEQCAEBEAgB0ECRiIEDQIMAwsgA0ECaiIDIQQLIAhBBEZBASMIGwRAIAAgAhBlIQFBBCMIQQFGDQMaIAEhAAsjCEUEQCAEIABBEHQiAEESdiICQYyvASgCAEEBdk8Ef0F/BSACQQF0QZCvAWovAQALOwEAIANBAmoiAiAAQQx2QT9xIgBBjK8BKAIAQQF2TwR/QX8FIABBAXRBkK8Bai8BAAs7AQAgAkECaiIAQdyIASgCAEEBdgR/QeCIAS8BAAVBfws7AQAgAEHciAEoAgBBAXYEf0HgiAEvAQAFQX8LOwECDAILCyMIRQRAIANBAmoiAyEECyAIQQVGQQEjCBsEQCAAIAIQZSEBQQUjCEEBRg0CGiABIQcLIwhFBEAgAkEBaiECIAdBEHQhBwsgCEEGRkEBIwgbBEAgACACEGUhAUEGIwhBAUYNAhogASEACyMIRQRAIAQgByAAQQh0ciIAQRJ2IgJBjK8BKAIAQQF2TwR/QX8FIAJBAXRBkK8Bai8BAAs7AQAgA0ECaiICIABBDHZBP3EiA0GMrwEoAgBBAXZPBH9BfwUgA0EBdEGQrwFqLwEACzsBACACQQJqIgIgAEEGdkE/cSIAQYyvASgCAEEBdk8Ef0F/BSAAQQF0QZCvAWovAQALOwEAIAJB3IgBKAIAQQF2BH9B4IgBLwEABUF/CzsBAgsLIwhFBEAgCQ8LAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAiASAANgIAIAEgAjYCBCABIAM2AgggASAENgIMIAEgBjYCECABIAU2AhQgASAHNgIYIAEgCTYCHCMJIwkoAgBBIGo2AgBBAAu9AQECfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAAsCfyMIQQJGBH8jCSMJKAIAQQRrNgIAIwkoAgAoAgAFIAELQQAjCBtFBEBBEEEXEA0hAUEAIwhBAUYNARogASEACyMIRQRAIABBADYCACAAQQA2AgQgAEEANgIIIABBADYCDCAADwsACyEBIwkoAgAgATYCACMJIwkoAgBBBGo2AgAjCSgCACAANgIAIwkjCSgCAEEEajYCAEEAC80GAQZ/IwhBAkYEQCMJIwkoAgBBHGs2AgAjCSgCACIDKAIAIQAgAygCCCECIAMoAgwhBCADKAIQIQYgAygCFCEHIAMoAhghCCADKAIEIQELAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQULIAVBACMIG0UEQBBBIQNBACMIQQFGDQEaIAMhBwsgBUEBRkEBIwgbBEAgB0GAsQFBsLEBEERBASMIQQFGDQEaCyACIwhBAkZyBEAgBUECRkEBIwgbBEAQQSEDQQIjCEEBRg0CGiADIQYLIwhFBEAgAigCBCEECyAEIwhBAkZyBEAjCEUEQCACKAIEIgRFIQgLIAgjCEECRnIEQCAFQQNGQQEjCBsEQEHQxABBkMUAQZ8BQSIQCkEDIwhBAUYNBBoLIwhFBEAACwsjCEUEQEGgkgFBwJIBIAQtAAAbIQQLIAVBBEZBASMIGwRAIAZB8LEBIAQQREEEIwhBAUYNAxoLCyMIRQRAIAIoAgAhBAsgBCMIQQJGcgRAIwgEfyAIBSACKAIAIgRFCyMIQQJGcgRAIAVBBUZBASMIGwRAQdDEAEGQxQBBogFBHBAKQQUjCEEBRg0EGgsjCEUEQAALCyMIRQRAQaCSAUHAkgEgBC0AABshBAsgBUEGRkEBIwgbBEAgBkGQrQEgBBBEQQYjCEEBRg0DGgsLIwhFBEAgAigCCCEECyAEIwhBAkZyBEAjCAR/IAQFIAIoAggiAkULIwhBAkZyBEAgBUEHRkEBIwgbBEBB0MQAQZDFAEGlAUEsEApBByMIQQFGDQQaCyMIRQRAAAsLIwhFBEBBoJIBQcCSASACLQAAGyECCyAFQQhGQQEjCBsEQCAGQaCyASACEERBCCMIQQFGDQMaCwsLIAVBCUZBASMIGwRAEEUhA0EJIwhBAUYNARogAyECCyMIRQRAIAIgBzYCACACIAY2AgQgAkEANgIIIAIgADYCECACIAE2AhQgAkEANgIMIAIPCwALIQMjCSgCACADNgIAIwkjCSgCAEEEajYCACMJKAIAIgMgADYCACADIAE2AgQgAyACNgIIIAMgBDYCDCADIAY2AhAgAyAHNgIUIAMgCDYCGCMJIwkoAgBBHGo2AgBBAAvXBgEEfyMIQQJGBEAjCSMJKAIAQRRrNgIAIwkoAgAiBSgCACEAIAUoAgQhASAFKAIIIQIgBSgCDCEEIAUoAhAhBQsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAwsgA0EAIwgbRQRAIABBAhCmAUEAIwhBAUYNARoLIwhFBEACQAJAAkAgACICQQhrKAIAIgRBIUcEQCAEQR1GDQEMAgsgAigCACEEDAILIAIoAgghBAwBCwALCyADQQFGQQEjCBsEQCAEQfDGAEHANkGQxwAQNEEBIwhBAUYNARoLIANBAkZBASMIGwRAIAJB8MYAEKcBQQIjCEEBRg0BGgsjCEUEQCABKAIAIQQLIANBA0ZBASMIGwRAIAIgBBCnAUEDIwhBAUYNARoLIwhFBEACQAJAAkAgAkEIaygCACIEQSFHBEAgBEEdRg0BDAILIAIoAgAhAgwCCyACKAIIIQIMAQsACwsgA0EERkEBIwgbBEAgAhA2QQQjCEEBRg0BGgsjCEUEQAJAAkACQCAAQQhrKAIAIgJBIUcEQCACQR1GDQEMAgsgACgCACECDAILIAAoAgghAgwBCwALCyADQQVGQQEjCBsEQCACQdDHAEGAyABBkMcAEDRBBSMIQQFGDQEaCyADQQZGQQEjCBsEQCAAQdDHABCnAUEGIwhBAUYNARoLIAUCfyMIRQRAIAEoAgQhAgsgAgsjCBsiBSMIQQJGcgRAIwgEfyACBSABKAIEIgFFCyMIQQJGcgRAIANBB0ZBASMIGwRAQdDEAEHQyABBxgFBJhAKQQcjCEEBRg0DGgsjCEUEQAALCyADQQhGQQEjCBsEQCAAIAEQSkEIIwhBAUYNAhoLCyAFRSMIQQJGckEAIANBCUZBASMIGxsEQCAAEKoBQQkjCEEBRg0BGgsjCEUEQAJAAkACQCAAQQhrKAIAIgFBIUcEQCABQR1GDQEMAgsgACgCACEADAILIAAoAgghAAwBCwALCyADQQpGQQEjCBsEQCAAEDZBCiMIQQFGDQEaCw8LIQMjCSgCACADNgIAIwkjCSgCAEEEajYCACMJKAIAIgMgADYCACADIAE2AgQgAyACNgIIIAMgBDYCDCADIAU2AhAjCSMJKAIAQRRqNgIAC/4JAQV/IwhBAkYEQCMJIwkoAgBBGGs2AgAjCSgCACIDKAIAIQAgAygCCCECIAMoAgwhBSADKAIQIQYgAygCFCEHIAMoAgQhAQsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBAsgBEEAIwgbRQRAIABBgLMBEB8hA0EAIwhBAUYNARogAyEFCyAEQQFGQQEjCBsEQEEIQcMAEA0hA0EBIwhBAUYNARogAyEACyMIRQRAIABBADYCACAAQQA2AgQgACAFNgIAIAAgATYCBCACIQULIARBAkZBASMIGwRAQbCzARAeIQNBAiMIQQFGDQEaIAMhAQsgBEEDRkEBIwgbBEAgARBGIQNBAyMIQQFGDQEaIAMhAQsgBEEERkEBIwgbBEAgASAAEIEBQQQjCEEBRg0BGgsjCEUEQCABKAIAIQILIARBBUZBASMIGwRAIAIQGSEDQQUjCEEBRg0BGiADIQILIARBBkZBASMIGwRAQbC0ARAeIQNBBiMIQQFGDQEaIAMhBwsgBEEHRkEBIwgbBEAgAiABIAcQTCEDQQcjCEEBRg0BGiADIQELIARBCEZBASMIGwRAIAEgABCBAUEIIwhBAUYNARoLIARBCUZBASMIGwRAQbC1ASACEE4hA0EJIwhBAUYNARogAyEACyMIRQRAIAAtAAhFIQELAkAgASMIQQJGcgRAIwhFBEAgAC0ACCEBCyABIwhBAkZyBEAgBEEKRkEBIwgbBEBBoNEAQeDRAEG0AUEJEApBCiMIQQFGDQQaCyMIRQRAAAsLIwhFBEAgACgCBCEACyAEQQtGQQEjCBsEQEEAQQAgABBPIQNBCyMIQQFGDQMaIAMhAAsjCEUNAQsjCEUEQCAALQAIRSEBCyABIwhBAkZyBEAgBEEMRkEBIwgbBEBB0NIAQZDTABAfIQNBDCMIQQFGDQMaIAMhAQsjCEUEQCAAKAIEIQALIARBDUZBASMIGwRAIAEgABAfIQNBDSMIQQFGDQMaIAMhAAsgACAAQaAIIAAbIwgbIQAgBEEORkEBIwgbBEAgAEHg0QBBqwFBDRAKQQ4jCEEBRg0DGgsjCEUEQAALCyMIRQRAIAAoAgAhAAsgBEEPRkEBIwgbBEBB0LUBEB4hA0EPIwhBAUYNAhogAyEBCyAEQRBGQQEjCBsEQCAAIAEQKCEDQRAjCEEBRg0CGiADIQELIwhFBEAgASgCACEACyAEQRFGQQEjCBsEQCAAQbC1AUGg1ABB8NQAEDRBESMIQQFGDQIaC0EAAn8gBEESRkEBIwgbBEAgARAqIQNBEiMIQQFGDQMaIAMhAAsgAAtBACAGIAAjCBsiBiMIQQJGciMIGxshACAGRSMIQQJGckEAIARBE0ZBASMIGxsEQCABEFEhA0ETIwhBAUYNAhogAyEACyMIRQRAIAEoAgAhAQsgBEEURkEBIwgbBEAgARA2QRQjCEEBRg0CGgsjCEUEQEECJAALIARBFUZBASMIGwRAIAAQUiEDQRUjCEEBRg0CGiADIQALCyAEQRZGQQEjCBsEQCAFIAAQWCEDQRYjCEEBRg0BGiADIQALIwhFBEAgAA8LAAshAyMJKAIAIAM2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAyAANgIAIAMgATYCBCADIAI2AgggAyAFNgIMIAMgBjYCECADIAc2AhQjCSMJKAIAQRhqNgIAQQALuwQBBH8jCEECRgRAIwkjCSgCAEEMazYCACMJKAIAIgEoAgAhACABKAIIIQMgASgCBCECCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEECyAEQQAjCBtFBEBBDEHCABANIQFBACMIQQFGDQEaIAEhAgsjCEUEQCACQQA2AgAgAkEANgIEIAJBADYCCCACQaAINgIAIAJBoAg2AgQgAkGgCDYCCAsgBEEBRkEBIwgbBEAgABB1IQFBASMIQQFGDQEaIAEhAwsjCEUEQAJ/IANBFGsoAgwiAEGQ1gEoAgBNBEADQEEBIABBL0YNAhogAEEDdEGU1gFqKAIEIgANAAsLQQALRSEACyAAIwhBAkZyBEAgBEECRkEBIwgbBEBB4JUBQZCiAUEQQS4QCkECIwhBAUYNAhoLIwhFBEAACwsgBEEDRkEBIwgbBEAgA0HQtgEQdyEBQQMjCEEBRg0BGiABIQALIwhBASAAG0UEQCACIAAoAgAiADYCAAsgBEEERkEBIwgbBEAgA0HwtgEQdyEBQQQjCEEBRg0BGiABIQALIwhBASAAG0UEQCACIAAoAgAiADYCBAsgBEEFRkEBIwgbBEAgA0GQtwEQdyEBQQUjCEEBRg0BGiABIQALIwhFBEAgAARAIAIgACgCADYCCAsgAg8LAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAiASAANgIAIAEgAjYCBCABIAM2AggjCSMJKAIAQQxqNgIAQQALzAcBAn8CfyMIQQJGBH8jCSMJKAIAQQxrNgIAIwkoAgAiAygCACEAIAMoAgQhASADKAIIBSADCyABIwgbIQMjCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQILIAJBACMIG0UEQCAAQQMQpgFBACMIQQFGDQEaCyMIRQRAAkACQAJAIABBCGsoAgAiAUEhRwRAIAFBHUYNAQwCCyAAKAIAIQEMAgsgACgCCCEBDAELAAsLIAJBAUZBASMIGwRAIAFB0M4AQcA2QZDHABA0QQEjCEEBRg0BGgsgAkECRkEBIwgbBEAgAEHQzgAQpwFBAiMIQQFGDQEaCyMIRQRAIAMoAgAhAQsgAkEDRkEBIwgbBEAgACABEKcBQQMjCEEBRg0BGgsjCEUEQCAAIQECQAJAAkAgAEEIaygCACIAQSFHBEAgAEEdRg0BDAILIAEoAgAhAAwCCyABKAIIIQAMAQsACwsgAkEERkEBIwgbBEAgABA2QQQjCEEBRg0BGgsjCEUEQAJAAkACQCABIgBBCGsoAgAiAUEhRwRAIAFBHUYNAQwCCyAAKAIAIQEMAgsgACgCCCEBDAELAAsLIAJBBUZBASMIGwRAIAFB8LgBQcA2QZDHABA0QQUjCEEBRg0BGgsgAkEGRkEBIwgbBEAgAEHwuAEQpwFBBiMIQQFGDQEaCyMIRQRAIAMoAgQhAQsgAkEHRkEBIwgbBEAgACABEKcBQQcjCEEBRg0BGgsjCEUEQCAAIQECQAJAAkAgAEEIaygCACIAQSFHBEAgAEEdRg0BDAILIAEoAgAhAAwCCyABKAIIIQAMAQsACwsgAkEIRkEBIwgbBEAgABA2QQgjCEEBRg0BGgsjCEUEQAJAAkACQCABIgBBCGsoAgAiAUEhRwRAIAFBHUYNAQwCCyAAKAIAIQEMAgsgACgCCCEBDAELAAsLIAJBCUZBASMIGwRAIAFBkLkBQcA2QZDHABA0QQkjCEEBRg0BGgsgAkEKRkEBIwgbBEAgAEGQuQEQpwFBCiMIQQFGDQEaCyMIRQRAIAMoAgghAQsgAkELRkEBIwgbBEAgACABEKcBQQsjCEEBRg0BGgsjCEUEQAJAAkACQCAAQQhrKAIAIgFBIUcEQCABQR1GDQEMAgsgACgCACEADAILIAAoAgghAAwBCwALCyACQQxGQQEjCBsEQCAAEDZBDCMIQQFGDQEaCw8LIQIjCSgCACACNgIAIwkjCSgCAEEEajYCACMJKAIAIgIgADYCACACIAE2AgQgAiADNgIIIwkjCSgCAEEMajYCAAvqAgECfyMIQQJGBEAjCSMJKAIAQQxrNgIAIwkoAgAiAigCACEAIAIoAgQhASACKAIIIQILAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQMLIwhFBEACQAJAAkAgAEEIaygCACICQSFHBEAgAkEdRg0BDAILIAAoAgAhAgwCCyAAKAIIIQIMAQsACwsgA0EAIwgbRQRAIAJB0KcBQbC4AUGQxwAQNEEAIwhBAUYNARoLIANBAUZBASMIGwRAIAAgARCEAUEBIwhBAUYNARoLIwhFBEACQAJAAkAgAEEIaygCACIBQSFHBEAgAUEdRg0BDAILIAAoAgAhAAwCCyAAKAIIIQAMAQsACwsgA0ECRkEBIwgbBEAgABA2QQIjCEEBRg0BGgsPCyEDIwkoAgAgAzYCACMJIwkoAgBBBGo2AgAjCSgCACIDIAA2AgAgAyABNgIEIAMgAjYCCCMJIwkoAgBBDGo2AgAL7BMBDn8CfyMIQQJGBH8jCSMJKAIAQThrNgIAIwkoAgAiAigCACEAIAIoAgghAyACKAIMIQUgAigCECEGIAIoAhQhByACKAIYIQggAigCHCEJIAIoAiAhCiACKAIkIQsgAigCKCEMIAIoAiwhDSACKAIwIQ4gAigCNCEPIAIoAgQFIAELQQAjCBshASMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBAsgBEEAIwgbRQRAQYCoARAeIQJBACMIQQFGDQEaIAIhAwsgBEEBRkEBIwgbBEAgACADECghAkEBIwhBAUYNARogAiEDCyAEQQJGQQEjCBsEQCADEC8hAkECIwhBAUYNARogAiEKCyAAQaAIIwgbIQADQCAKIwhBAkZyBEAgCiAKQQFrIwgbIQogBEEDRkEBIwgbBEAgAxAwIQJBAyMIQQFGDQMaIAIhBgsjCEUEQCADKAIEIQcLIARBBEZBASMIGwRAIAcgBhAxIQJBBCMIQQFGDQMaIAIhBgsjCEUEQCAGQRRrKAIQIQcLIARBBUZBASMIGwRAIAYgBxAbIQJBBSMIQQFGDQMaIAIhBgsjCEUEQCADKAIAIQcLIARBBkZBASMIGwRAIAcgBkGgNUHQNRA0QQYjCEEBRg0DGgsgCwJ/IwhFBEAgBkHwqAEQHSEHCyAHCyMIGyILIwhBAkZyBEAjCEUEQCADKAIAIQkLIARBB0ZBASMIGwRAIAkgBkGQqQFB4DYQNEEHIwhBAUYNBBoLIARBCEZBASMIGwRAIAMQeyECQQgjCEEBRg0EGiACIQkLIwhFBEBBASEPIAMoAgAhBgsgBEEJRkEBIwgbBEAgBhA2QQkjCEEBRg0EGgsLIAtFIwhBAkZyBEAgDAJ/IwhFBEAgBkGAOhAdIQcLIAcLIwgbIgwjCEECRnIEQCMIRQRAIAMoAgAhAAsgBEEKRkEBIwgbBEAgACAGQcA2QeA2EDRBCiMIQQFGDQUaCyAEQQtGQQEjCBsEQCADEDAhAkELIwhBAUYNBRogAiEACyMIRQRAIAMoAgQhBQsgBEEMRkEBIwgbBEAgBSAAEDEhAkEMIwhBAUYNBRogAiEACyMIRQRAIABBFGsoAhAhBQsgBEENRkEBIwgbBEAgACAFEBshAkENIwhBAUYNBRogAiEACyMIRQRAIAMoAgAhBkEBIQULIARBDkZBASMIGwRAIAYQNkEOIwhBAUYNBRoLCyAMRSMIQQJGcgRAIA0CfyMIRQRAIAZBsDoQHSEHCyAHCyMIGyINIwhBAkZyBEAjCEUEQCADKAIAIQgLIARBD0ZBASMIGwRAIAggBkHgOkHgNhA0QQ8jCEEBRg0GGgsgBEEQRkEBIwgbBEAgAxA+IQJBECMIQQFGDQYaIAIhCAsjCEUEQCADKAIAIQYLIARBEUZBASMIGwRAIAYQNkERIwhBAUYNBhoLCyANRSMIQQJGcgRAIwhFBEAgBkGQrAEQHSEHCyAHIwhBAkZyBEAjCEUEQCADKAIAIQELIARBEkZBASMIGwRAIAEgBkHArAFB4DYQNEESIwhBAUYNBxoLQQACfyAEQRNGQQEjCBsEQCADECohAkETIwhBAUYNCBogAiEBCyABC0EAIA4gASMIGyIOIwhBAkZyIwgbGyEBIA5FIwhBAkZyQQAgBEEURkEBIwgbGwRAIAMQfCECQRQjCEEBRg0HGiACIQELIwhFBEAgAygCACEGCyAEQRVGQQEjCBsEQCAGEDZBFSMIQQFGDQcaCwsLCwsjCEUEQCADKAIAIQYLIARBFkZBASMIGwRAIAYQNkEWIwhBAUYNAxoLIwhFDQELCyMIQQJGIAYgD0EAIAkbRSMIG3IEQCMIRQRAIAMoAgAhAAsgBEEXRkEBIwgbBEAgAEGgrgEQJiECQRcjCEEBRg0CGiACIQALIAAgAEGgCCAAGyMIGyEAIARBGEZBASMIGwRAIABB0MIAQdMCQQUQCkEYIwhBAUYNAhoLIwhFBEAACwsgBSAFRSMIGyIFIwhBAkZyBEAjCEUEQCADKAIAIQALIARBGUZBASMIGwRAIABBsMMAECYhAkEZIwhBAUYNAhogAiEACyAAIABBoAggABsjCBshACAEQRpGQQEjCBsEQCAAQdDCAEHWAkEFEApBGiMIQQFGDQIaCyMIRQRAAAsLIARBG0ZBASMIGwRAEH0hAkEbIwhBAUYNARogAiEFCyMIRQRAIAUgCTYCACAFIAA2AgQgBSAINgIIIAUgATYCDAsgBEEcRkEBIwgbBEAQfSECQRwjCEEBRg0BGiACIQALIwhFBEAgACAFKAIANgIAIAAgBSgCBDYCBCAAIAUoAgg2AgggACAFKAIMIgE2AgwLIARBHUZBASMIGwRAQQFBAkEYQQAQGCECQR0jCEEBRg0BGiACIQELIwhFBEAgASgCBCEDCyAEQR5GQQEjCBsEQBB/IQJBHiMIQQFGDQEaIAIhBQsjCEUEQCAFIAAoAgAoAgA2AgAgACgCACIDKAIEIQhBASQACyAEQR9GQQEjCBsEQCAIEGIhAkEfIwhBAUYNARogAiEDCyAEQSBGQQEjCBsEQCADEH4hAkEgIwhBAUYNARogAiEDCyMIRQRAIAUgAzYCBCAFIAAoAgAoAgA2AgggBUGwsAE2AgwgASgCBCAFNgIAIAAoAgwhBSAAKAIIIQMLIARBIUZBASMIGwRAIAEgAyAFEIABIQJBISMIQQFGDQEaIAIhAQsjCEUEQCAAKAIEIQALIARBIkZBASMIGwRAIAAgAUHgsgEQggEhAkEiIwhBAUYNARogAiEACyAEQSNGQQEjCBsEQCAAEIMBIQJBIyMIQQFGDQEaIAIhAAsgBEEkRkEBIwgbBEBBsLcBEB4hAkEkIwhBAUYNARogAiEBCyAEQSVGQQEjCBsEQCABEEYhAkElIwhBAUYNARogAiEBCyAEQSZGQQEjCBsEQCABIAAQhQFBJiMIQQFGDQEaCyMIRQRAIAEoAgAhAwsgBEEnRkEBIwgbBEAgAxAZIQJBJyMIQQFGDQEaIAIhBQsgBEEoRkEBIwgbBEBBsLkBEB4hAkEoIwhBAUYNARogAiEDCyAEQSlGQQEjCBsEQCAFIAEgAxBMIQJBKSMIQQFGDQEaIAIhAQsgBEEqRkEBIwgbBEAgASAAEIUBQSojCEEBRg0BGgsjCEUEQCAFDwsACyECIwkoAgAgAjYCACMJIwkoAgBBBGo2AgAjCSgCACICIAA2AgAgAiABNgIEIAIgAzYCCCACIAU2AgwgAiAGNgIQIAIgBzYCFCACIAg2AhggAiAJNgIcIAIgCjYCICACIAs2AiQgAiAMNgIoIAIgDTYCLCACIA42AjAgAiAPNgI0IwkjCSgCAEE4ajYCAEEAC6ABAQF/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEACwJ/IwhBAkYEfyMJIwkoAgBBBGs2AgAjCSgCACgCAAUgAQtBACMIG0UEQCAAEIkBIQFBACMIQQFGDQEaIAEhAAsjCEUEQCAADwsACyEBIwkoAgAgATYCACMJIwkoAgBBBGo2AgAjCSgCACAANgIAIwkjCSgCAEEEajYCAEEAC58BAQF/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEACwJ/IwhBAkYEfyMJIwkoAgBBBGs2AgAjCSgCACgCAAUgAQtBACMIG0UEQCAAEHshAUEAIwhBAUYNARogASEACyMIRQRAIAAPCwALIQEjCSgCACABNgIAIwkjCSgCAEEEajYCACMJKAIAIAA2AgAjCSMJKAIAQQRqNgIAQQAL6goBC38jCEECRgRAIwkjCSgCAEEoazYCACMJKAIAIgEoAgAhACABKAIIIQQgASgCDCEFIAEoAhAhBiABKAIUIQcgASgCGCEIIAEoAhwhCSABKAIgIQogASgCJCELIAEoAgQhAgsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAwsjCEUEQCAAQQhrKAIAQRJGIQILAkAgAiMIQQJGcgRAIANBACMIG0UEQCAAEC8hAUEAIwhBAUYNAxogASEGCyMIRQ0BCyMIRQRAAAsLIAVBoAgjCBshBQNAIAYjCEECRnIEQCAGIAZBAWsjCBshBiADQQFGQQEjCBsEQCAAEKQBIQFBASMIQQFGDQMaIAEhAgsjCEUEQAJAIABBCGsoAgBBEkYEQCAAKAIAIQQMAQsACwsgA0ECRkEBIwgbBEAgBCACQaA1QdA1EDRBAiMIQQFGDQMaCyAJAn8jCEUEQCACQdDOABAdIQQLIAQLIwgbIgkjCEECRnIEQCMIRQRAAkAgAEEIaygCAEESRgRAIAAoAgAhBQwBCwALCyADQQNGQQEjCBsEQCAFIAJBwDZB4DYQNEEDIwhBAUYNBBoLIANBBEZBASMIGwRAIAAQpAEhAUEEIwhBAUYNBBogASEFCyMIRQRAQQEhCwJAIABBCGsoAgBBEkYEQCAAKAIAIQIMAQsACwsgA0EFRkEBIwgbBEAgAhA2QQUjCEEBRg0EGgsLIAlFIwhBAkZyBEAgCgJ/IwhFBEAgAkGQvAEQHSEECyAECyMIGyIKIwhBAkZyBEAjCEUEQAJAIABBCGsoAgBBEkYEQCAAKAIAIQcMAQsACwsgA0EGRkEBIwgbBEAgByACQcC8AUHgNhA0QQYjCEEBRg0FGgsgA0EHRkEBIwgbBEAgAEGgvQEQrQEhAUEHIwhBAUYNBRogASEHCyMIRQRAAkAgAEEIaygCAEESRgRAIAAoAgAhAgwBCwALCyADQQhGQQEjCBsEQCACEDZBCCMIQQFGDQUaCwsgCkUjCEECRnIEQCMIRQRAIAJBwL0BEB0hBAsgBCMIQQJGcgRAIwhFBEACQCAAQQhrKAIAQRJGBEAgACgCACEIDAELAAsLIANBCUZBASMIGwRAIAggAkHgvQFB4DYQNEEJIwhBAUYNBhoLIANBCkZBASMIGwRAIABBsL4BEK4BIQFBCiMIQQFGDQYaIAEhCAsjCEUEQAJAIABBCGsoAgBBEkYEQCAAKAIAIQIMAQsACwsgA0ELRkEBIwgbBEAgAhA2QQsjCEEBRg0GGgsLCwsjCEUEQAJAIABBCGsoAgBBEkYEQCAAKAIAIQIMAQsACwsgA0EMRkEBIwgbBEAgAhA2QQwjCEEBRg0DGgsjCEUNAQsLIwhBAkYgAiALRSMIG3IEQCMIRQRAAkAgAEEIaygCAEESRgRAIAAoAgAhAAwBCwALCyADQQ1GQQEjCBsEQCAAQdCpARAmIQFBDSMIQQFGDQIaIAEhAAsgACAAQaAIIAAbIwgbIQAgA0EORkEBIwgbBEAgAEHQvgFB3ABBBRAKQQ4jCEEBRg0CGgsjCEUEQAALCyADQQ9GQQEjCBsEQEEMQcUAEA0hAUEPIwhBAUYNARogASEACyMIRQRAIABBADYCACAAQQA2AgQgAEEANgIIIAAgBTYCACAAIAc2AgQgACAINgIIIAAPCwALIQEjCSgCACABNgIAIwkjCSgCAEEEajYCACMJKAIAIgEgADYCACABIAI2AgQgASAENgIIIAEgBTYCDCABIAY2AhAgASAHNgIUIAEgCDYCGCABIAk2AhwgASAKNgIgIAEgCzYCJCMJIwkoAgBBKGo2AgBBAAu+AQECfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAAsCfyMIQQJGBH8jCSMJKAIAQQRrNgIAIwkoAgAoAgAFIAELQQAjCBtFBEBBEEHEABANIQFBACMIQQFGDQEaIAEhAAsjCEUEQCAAQQA2AgAgAEEANgIEIABBADYCCCAAQQA2AgwgAA8LAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAgADYCACMJIwkoAgBBBGo2AgBBAAvLBQEHfyMIQQJGBEAjCSMJKAIAQRhrNgIAIwkoAgAiAigCACEAIAIoAgQhASACKAIMIQQgAigCECEGIAIoAhQhByACKAIIIQMLAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQULIANBoAgjCBshAwNAIwhFBEAgBiAAQRRrKAIQQQF2SCEBCyABIwhBAkZyBEAgBwJ/IwhFBEAgBiAAQRRrKAIQQQF2TwR/QX8FIAZBAXQgAGovAQALIgFB2gBMIAFBwQBOcSABQfoATCABQeEATnEgAUE5TCABQTBOcXJyIAFBLUZyIAFB3wBGciABQS5GciABQSFGciABQf4ARnIgAUEqRnIgAUHcAEZyIAFBKEZyIAFBKUZyIQQLIAQLIwgbIgcjCEECRnIEQCAFQQAjCBtFBEAgACAGEFQhAkEAIwhBAUYNBBogAiEBCyAFQQFGQQEjCBsEfyADIAEQHyECQQEjCEEBRg0EGiACBSADCyEDCyAHRSMIQQJGcgRAIAVBAkZBASMIGwRAIANBoMEBEB8hAkECIwhBAUYNBBogAiEDCyAEIAFB8AFxQQR2IwgbIQQgBUEDRkEBIwgbBEBB4MABIAQQVCECQQMjCEEBRg0EGiACIQQLIAVBBEZBASMIGwRAIAMgBBAfIQJBBCMIQQFGDQQaIAIhAwsgASABQQ9xIwgbIQEgBUEFRkEBIwgbBEBB4MABIAEQVCECQQUjCEEBRg0EGiACIQELIAVBBkZBASMIGwR/IAMgARAfIQJBBiMIQQFGDQQaIAIFIAMLIQMLIwhFBEAgBkEBaiEGDAILCwsjCEUEQCADDwsACyECIwkoAgAgAjYCACMJIwkoAgBBBGo2AgAjCSgCACICIAA2AgAgAiABNgIEIAIgAzYCCCACIAQ2AgwgAiAGNgIQIAIgBzYCFCMJIwkoAgBBGGo2AgBBAAuvBQEHfyMIQQJGBEAjCSMJKAIAQSBrNgIAIwkoAgAiAygCACEAIAMoAgghAiADKAIMIQQgAygCECEFIAMoAhQhByADKAIYIQggAygCHCEJIAMoAgQhAQsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBgsjCEUEQCAARSIEBEAPCwsDQCMIRQRAIAggACgCDEghBAsgBCMIQQJGcgRAIAZBACMIG0UEQCAAIAgQJSEDQQAjCEEBRg0DGiADIQcLIwhFBEAgBygCACEECyAGQQFGQQEjCBsEQCABIAQQHyEDQQEjCEEBRg0DGiADIQQLIAZBAkZBASMIGwRAEH8hA0ECIwhBAUYNAxogAyEFCyMIRQRAIAUgBDYCACAFQQA2AgQLIAZBA0ZBASMIGwRAIAQQiwEhA0EDIwhBAUYNAxogAyEECyMIRQRAIAUgBDYCCCAFQcDBATYCDAsgBkEERkEBIwgbBEAgAiAFEDNBBCMIQQFGDQMaCyMIRQRAIAcoAgAhBAsgBkEFRkEBIwgbBEAgASAEEB8hA0EFIwhBAUYNAxogAyEECyAGQQZGQQEjCBsEQCAEQcCZARAfIQNBBiMIQQFGDQMaIAMhBQsjCEUEQCAHKAIIIQkgBSEECyAJIwhBAkZyQQAgBkEHRkEBIwgbGwRAIAkgBCACELEBQQcjCEEBRg0DGgsjCEUEQCAHKAIEIQQLIAZBCEZBASMIGwRAIAQgBSACEIwBQQgjCEEBRg0DGgsjCEUEQCAIQQFqIQgMAgsLCw8LIQMjCSgCACADNgIAIwkjCSgCAEEEajYCACMJKAIAIgMgADYCACADIAE2AgQgAyACNgIIIAMgBDYCDCADIAU2AhAgAyAHNgIUIAMgCDYCGCADIAk2AhwjCSMJKAIAQSBqNgIAC+gRARJ/IwhBAkYEQCMJIwkoAgBBxABrNgIAIwkoAgAiASgCACEAIAEoAgghAyABKAIMIQUgASgCECEGIAEoAhQhByABKAIYIQggASgCHCEJIAEoAiAhDSABKAIkIQ4gASgCKCEKIAEoAiwhDyABKAIwIQsgASgCNCEQIAEoAjghDCABKAI8IREgASgCQCESIAEoAgQhAgsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBAsgBEEAIwgbRQRAQQBBAkEYQcDAARAYIQFBACMIQQFGDQEaIAEhCQsjCEUEQCAJIQcgACgCBCECCyACIwhBAkZyQQAgBEEBRkEBIwgbGwRAIAJBoAggBxCxAUEBIwhBAUYNARoLIwhFBEAgACgCACENIAkhBwsgDSMIQQJGcgRAA0AjCEUEQCAOIA0oAgxIIQALIAAjCEECRnIEQCAEQQJGQQEjCBsEQCANIA4QJSEBQQIjCEEBRg0EGiABIQALIwhFBEAgACgCACECCyAEQQNGQQEjCBsEQEGgCCACEB8hAUEDIwhBAUYNBBogASECCyAEQQRGQQEjCBsEQBB/IQFBBCMIQQFGDQQaIAEhBgsjCEUEQCAGIAI2AgAgBkEANgIECyAEQQVGQQEjCBsEQCACEIsBIQFBBSMIQQFGDQQaIAEhAgsjCEUEQCAGIAI2AgggBkHAwQE2AgwLIARBBkZBASMIGwRAIAcgBhAzQQYjCEEBRg0EGgsjCEUEQCAAKAIAIQILIARBB0ZBASMIGwRAQaAIIAIQHyEBQQcjCEEBRg0EGiABIQILIARBCEZBASMIGwRAIAJBwJkBEB8hAUEIIwhBAUYNBBogASEKCyMIRQRAIAAoAgghBiAKIQILIAYjCEECRnJBACAEQQlGQQEjCBsbBEAgBiACIAcQsQFBCSMIQQFGDQQaCyMIRQRAIAAoAgQhDwsgDyMIQQJGcgRAIAZBACMIGyEGA0AjCEUEQCAGIA8oAgxIIQALIAAjCEECRnIEQCAEQQpGQQEjCBsEQCAPIAYQJSEBQQojCEEBRg0HGiABIQALIwhFBEAgACgCACECCyAEQQtGQQEjCBsEQCAKIAIQHyEBQQsjCEEBRg0HGiABIQILIARBDEZBASMIGwRAEH8hAUEMIwhBAUYNBxogASEDCyMIRQRAIAMgAjYCACADQQA2AgQLIARBDUZBASMIGwRAIAIQiwEhAUENIwhBAUYNBxogASECCyMIRQRAIAMgAjYCCCADQcDBATYCDAsgBEEORkEBIwgbBEAgByADEDNBDiMIQQFGDQcaCyMIRQRAIAAoAgAhAgsgBEEPRkEBIwgbBEAgCiACEB8hAUEPIwhBAUYNBxogASECCyAEQRBGQQEjCBsEQCACQcCZARAfIQFBECMIQQFGDQcaIAEhCwsjCEUEQCAAKAIIIQMgCyECCyADIwhBAkZyQQAgBEERRkEBIwgbGwRAIAMgAiAHELEBQREjCEEBRg0HGgsjCEUEQCAAKAIEIRALIBAjCEECRnIEQCACQQAjCBshAgNAIwhFBEAgAiAQKAIMSCEACyAAIwhBAkZyBEAgBEESRkEBIwgbBEAgECACECUhAUESIwhBAUYNChogASEACyMIRQRAIAAoAgAhAwsgBEETRkEBIwgbBEAgCyADEB8hAUETIwhBAUYNChogASEDCyAEQRRGQQEjCBsEQBB/IQFBFCMIQQFGDQoaIAEhBQsjCEUEQCAFIAM2AgAgBUEANgIECyAEQRVGQQEjCBsEQCADEIsBIQFBFSMIQQFGDQoaIAEhAwsjCEUEQCAFIAM2AgggBUHAwQE2AgwLIARBFkZBASMIGwRAIAcgBRAzQRYjCEEBRg0KGgsjCEUEQCAAKAIAIQMLIARBF0ZBASMIGwRAIAsgAxAfIQFBFyMIQQFGDQoaIAEhAwsgBEEYRkEBIwgbBEAgA0HAmQEQHyEBQRgjCEEBRg0KGiABIQwLIwhFBEAgACgCCCEFIAwhAwsgBSMIQQJGckEAIARBGUZBASMIGxsEQCAFIAMgBxCxAUEZIwhBAUYNChoLIwhFBEAgACgCBCERCyARIwhBAkZyBEAgAEEAIwgbIQADQCMIRQRAIBEoAgwgAEohAwsgAyMIQQJGcgRAIARBGkZBASMIGwRAIBEgABAlIQFBGiMIQQFGDQ0aIAEhAwsjCEUEQCADKAIAIQULIARBG0ZBASMIGwRAIAwgBRAfIQFBGyMIQQFGDQ0aIAEhBQsgBEEcRkEBIwgbBEAQfyEBQRwjCEEBRg0NGiABIQgLIwhFBEAgCCAFNgIAIAhBADYCBAsgBEEdRkEBIwgbBEAgBRCLASEBQR0jCEEBRg0NGiABIQULIwhFBEAgCCAFNgIIIAhBwMEBNgIMCyAEQR5GQQEjCBsEQCAHIAgQM0EeIwhBAUYNDRoLIwhFBEAgAygCACEFCyAEQR9GQQEjCBsEQCAMIAUQHyEBQR8jCEEBRg0NGiABIQULIARBIEZBASMIGwRAIAVBwJkBEB8hAUEgIwhBAUYNDRogASEICyMIRQRAIAMoAgghEiAIIQULIBIjCEECRnJBACAEQSFGQQEjCBsbBEAgEiAFIAcQsQFBISMIQQFGDQ0aCyMIRQRAIAMoAgQhAwsgAyMIQQJGckEAIARBIkZBASMIGxsEQCADIAggBxCyAUEiIwhBAUYNDRoLIwhFBEAgAEEBaiEADAILCwsLIwhFBEAgAkEBaiECDAILCwsLIwhFBEAgBkEBaiEGDAILCwsLIwhFBEAgDkEBaiEODAILCwsLIwhFBEAgCQ8LAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAiASAANgIAIAEgAjYCBCABIAM2AgggASAFNgIMIAEgBjYCECABIAc2AhQgASAINgIYIAEgCTYCHCABIA02AiAgASAONgIkIAEgCjYCKCABIA82AiwgASALNgIwIAEgEDYCNCABIAw2AjggASARNgI8IAEgEjYCQCMJIwkoAgBBxABqNgIAQQALlwoBDX8jCEECRgRAIwkjCSgCAEEwazYCACMJKAIAIgEoAgAhACABKAIEIQIgASgCCCEDIAEoAgwhBCABKAIQIQUgASgCFCEGIAEoAhghCCABKAIgIQogASgCJCELIAEoAighDCABKAIsIQ0gASgCHCEJCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEHCyMIRQRAQawfKAIAIgRBAXYhCyAAQRRrKAIQQQF2IQgLIAogCyMIGyIKIwhBAkZyBEAgAiAIRSMIGyICIwhBAkZyBEAgB0EAIwgbRQRAQQFBAkE4QQAQGCEBQQAjCEEBRg0DGiABIQALIwhFBEAgACgCBEGgCDYCACAADwsLCwJAIApFIwhBAkZyBEAjCEUEQCAIRQ0CIAhB/////wcgCEH/////B0cbIQULIAdBAUZBASMIGwRAIAVBAkE4QQAQGCEBQQEjCEEBRg0DGiABIQkLIwhFBEAgCSgCBCEECwNAIAMgAiAFSCMIGyIDIwhBAkZyBEAgB0ECRkEBIwgbBEBBAkEBEA0hAUECIwhBAUYNBRogASEICyMIRQRAIAggAkEBdCAAai8BADsBACAEIAJBAnRqIgMgCDYCACACQQFqIQIMAgsLCyMIRQRAIAkPCwsgB0EDRkEBIwgbBEBBAEECQThBABAYIQFBAyMIQQFGDQIaIAEhBAsDQCMIRQRAQQAhAgJAQawfKAIAQQF2IgNFIgYNAEF/IQIgAEEUaygCEEEBdiIGRQ0AIAYgBUEAIAVBAEobIgJKIQogAiAGIAobIQIgBiADayEGA0AgAiAGTARAIAAgAkGwHyADEBxFDQIgAkEBaiECDAELC0F/IQILIAJBf3MhAwsgAyMIQQJGcgRAIAwCfyMIRQRAIAIgBWsiA0EASiEGCyAGCyMIGyIMIwhBAkZyBEAgAyADQQF0IwgbIQMgB0EERkEBIwgbBEAgA0EBEA0hAUEEIwhBAUYNBRogASEGCyMIRQRAIAYgBUEBdCAAaiIFIAMQFwsgB0EFRkEBIwgbBEAgBCAGEDNBBSMIQQFGDQUaCwsgDEUjCEECRnJBACAHQQZGQQEjCBsbBEAgBEGgCBAzQQYjCEEBRg0EGgsjCEUEQCAJQQFqIglB/////wdGBEAgBA8LIAIgC2ohBQwCCwsLIAIgBUUjCBsiAiMIQQJGcgRAIAdBB0ZBASMIGwRAIAQgABAzQQcjCEEBRg0DGgsjCEUEQCAEDwsLIA0CfyMIRQRAIAggBWsiAkEASiEDCyADCyMIGyINIwhBAkZyBEAgAiACQQF0IwgbIQIgB0EIRkEBIwgbBEAgAkEBEA0hAUEIIwhBAUYNAxogASEJCyMIRQRAIAkgBUEBdCAAaiIAIAIQFwsgB0EJRkEBIwgbBEAgBCAJEDNBCSMIQQFGDQMaCwsgDUUjCEECRnJBACAHQQpGQQEjCBsbBEAgBEGgCBAzQQojCEEBRg0CGgsjCEUEQCAEDwsLIAdBC0ZBASMIGwRAQQBBAkE4QQAQGCEBQQsjCEEBRg0BGiABIQALIwhFBEAgAA8LAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAiASAANgIAIAEgAjYCBCABIAM2AgggASAENgIMIAEgBTYCECABIAY2AhQgASAINgIYIAEgCTYCHCABIAo2AiAgASALNgIkIAEgDDYCKCABIA02AiwjCSMJKAIAQTBqNgIAQQALlAMBBX8jCEECRgRAIwkjCSgCAEEQazYCACMJKAIAIgEoAgAhACABKAIIIQMgASgCDCEEIAEoAgQhAgsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBQsgBUEAIwgbRQRAQQBBAkHKAEGQwgEQGCEBQQAjCEEBRg0BGiABIQMLIAVBAUZBASMIGwRAIAAQjgEhAUEBIwhBAUYNARogASEECyAAQQAjCBshAANAIwhFBEAgBCgCDEEBayAASiECCyACIwhBAkZyBEAgBUECRkEBIwgbBEAgBCAAECUhAUECIwhBAUYNAxogASECCyAFQQNGQQEjCBsEQCACEIMBIQFBAyMIQQFGDQMaIAEhAgsgBUEERkEBIwgbBEAgAyACEDNBBCMIQQFGDQMaCyMIRQRAIABBAWohAAwCCwsLIwhFBEAgAw8LAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAiASAANgIAIAEgAjYCBCABIAM2AgggASAENgIMIwkjCSgCAEEQajYCAEEAC6MBAQF/IwhBAkYEQCMJIwkoAgBBCGs2AgAjCSgCACIBKAIAIQAgASgCBCEBCwJ/IwhBAkYEfyMJIwkoAgBBBGs2AgAjCSgCACgCAAUgAgtBACMIG0UEQCAAIAEQhAFBACMIQQFGDQEaCw8LIQIjCSgCACACNgIAIwkjCSgCAEEEajYCACMJKAIAIgIgADYCACACIAE2AgQjCSMJKAIAQQhqNgIAC+4CAQJ/IwhBAkYEQCMJIwkoAgBBDGs2AgAjCSgCACICKAIAIQAgAigCBCEBIAIoAgghAgsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAwsjCEUEQAJAAkACQCAAQQhrKAIAIgJBIUcEQCACQR1GDQEMAgsgACgCACECDAILIAAoAgghAgwBCwALCyADQQAjCBtFBEAgAkHQugFBsMMBQZDHABA0QQAjCEEBRg0BGgsgA0EBRkEBIwgbBEAgACABQfDDARCvAUEBIwhBAUYNARoLIwhFBEACQAJAAkAgAEEIaygCACIBQSFHBEAgAUEdRg0BDAILIAAoAgAhAAwCCyAAKAIIIQAMAQsACwsgA0ECRkEBIwgbBEAgABA2QQIjCEEBRg0BGgsPCyEDIwkoAgAgAzYCACMJIwkoAgBBBGo2AgAjCSgCACIDIAA2AgAgAyABNgIEIAMgAjYCCCMJIwkoAgBBDGo2AgALtRMBDn8CfyMIQQJGBH8jCSMJKAIAQThrNgIAIwkoAgAiAigCACEAIAIoAgghBCACKAIMIQUgAigCECEGIAIoAhQhByACKAIYIQggAigCHCEJIAIoAiAhCiACKAIkIQsgAigCKCEMIAIoAiwhDSACKAIwIQ8gAigCNCEOIAIoAgQFIAELQQAjCBshASMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAwsgA0EAIwgbRQRAQfC6ARAeIQJBACMIQQFGDQEaIAIhBAsgA0EBRkEBIwgbBEAgACAEECghAkEBIwhBAUYNARogAiEECyADQQJGQQEjCBsEQCAEEC8hAkECIwhBAUYNARogAiEJCyAAQaAIIwgbIQADQCAJIwhBAkZyBEAgCSAJQQFrIwgbIQkgA0EDRkEBIwgbBEAgBBAwIQJBAyMIQQFGDQMaIAIhBQsjCEUEQCAEKAIEIQcLIANBBEZBASMIGwRAIAcgBRAxIQJBBCMIQQFGDQMaIAIhBQsjCEUEQCAFQRRrKAIQIQcLIANBBUZBASMIGwRAIAUgBxAbIQJBBSMIQQFGDQMaIAIhBQsjCEUEQCAEKAIAIQcLIANBBkZBASMIGwRAIAcgBUGgNUHQNRA0QQYjCEEBRg0DGgsgCgJ/IwhFBEAgBUHwqAEQHSEHCyAHCyMIGyIKIwhBAkZyBEAjCEUEQCAEKAIAIQgLIANBB0ZBASMIGwRAIAggBUHQuwFB4DYQNEEHIwhBAUYNBBoLIANBCEZBASMIGwRAIAQQiQEhAkEIIwhBAUYNBBogAiEICyMIRQRAQQEhDyAEKAIAIQULIANBCUZBASMIGwRAIAUQNkEJIwhBAUYNBBoLCyAKRSMIQQJGcgRAIAsCfyMIRQRAIAVBgDoQHSEHCyAHCyMIGyILIwhBAkZyBEAjCEUEQCAEKAIAIQALIANBCkZBASMIGwRAIAAgBUHANkHgNhA0QQojCEEBRg0FGgsgA0ELRkEBIwgbBEAgBBAwIQJBCyMIQQFGDQUaIAIhAAsjCEUEQCAEKAIEIQYLIANBDEZBASMIGwRAIAYgABAxIQJBDCMIQQFGDQUaIAIhAAsjCEUEQCAAQRRrKAIQIQYLIANBDUZBASMIGwRAIAAgBhAbIQJBDSMIQQFGDQUaIAIhAAsjCEUEQEEBIQYgBCgCACEFCyADQQ5GQQEjCBsEQCAFEDZBDiMIQQFGDQUaCwsgC0UjCEECRnIEQCAMAn8jCEUEQCAFQbA6EB0hBwsgBwsjCBsiDCMIQQJGcgRAIwhFBEAgBCgCACEHCyADQQ9GQQEjCBsEQCAHIAVB4DpB4DYQNEEPIwhBAUYNBhoLIANBEEZBASMIGwRAIAQQPiECQRAjCEEBRg0GGiACIQ4LIwhFBEAgBCgCACEFCyADQRFGQQEjCBsEQCAFEDZBESMIQQFGDQYaCwsgDEUjCEECRnIEQCMIRQRAIAVBkKwBEB0hBwsgByMIQQJGcgRAIwhFBEAgBCgCACEBCyADQRJGQQEjCBsEQCABIAVBwKwBQeA2EDRBEiMIQQFGDQcaC0EAAn8gA0ETRkEBIwgbBEAgBBAqIQJBEyMIQQFGDQgaIAIhAQsgAQtBACANIAEjCBsiDSMIQQJGciMIGxshASANRSMIQQJGckEAIANBFEZBASMIGxsEQCAEEHwhAkEUIwhBAUYNBxogAiEBCyMIRQRAIAQoAgAhBQsgA0EVRkEBIwgbBEAgBRA2QRUjCEEBRg0HGgsLCwsLIwhFBEAgBCgCACEFCyADQRZGQQEjCBsEQCAFEDZBFiMIQQFGDQMaCyMIRQ0BCwsjCEECRiAFIA9BACAIG0UjCBtyBEAjCEUEQCAEKAIAIQALIANBF0ZBASMIGwRAIABBwL8BECYhAkEXIwhBAUYNAhogAiEACyAAIABBoAggABsjCBshACADQRhGQQEjCBsEQCAAQdDCAEHbA0EFEApBGCMIQQFGDQIaCyMIRQRAAAsLIAYgBkUjCBsiBiMIQQJGcgRAIwhFBEAgBCgCACEACyADQRlGQQEjCBsEQCAAQbDDABAmIQJBGSMIQQFGDQIaIAIhAAsgACAAQaAIIAAbIwgbIQAgA0EaRkEBIwgbBEAgAEHQwgBB3gNBBRAKQRojCEEBRg0CGgsjCEUEQAALCyADQRtGQQEjCBsEQBCKASECQRsjCEEBRg0BGiACIQYLIwhFBEAgBiAINgIAIAYgADYCBCAGIA42AgggBiABNgIMCyADQRxGQQEjCBsEQBCKASECQRwjCEEBRg0BGiACIQALIwhFBEAgACAGKAIANgIAIAAgBigCBDYCBCAAIAYoAgg2AgggACAGKAIMIgE2AgwLIANBHUZBASMIGwRAQQhBywAQDSECQR0jCEEBRg0BGiACIQELIwhFBEAgAUEANgIAIAFBADYCBAsgA0EeRkEBIwgbBEBBAUECQcYAQQAQGCECQR4jCEEBRg0BGiACIQYLIwhFBEAgBigCBCIEIAAoAgA2AgAgASAGNgIAIAFBADYCBAsgA0EfRkEBIwgbBEAgARCNASECQR8jCEEBRg0BGiACIQELIwhFBEAgACgCDCEGIAAoAgghBAsgA0EgRkEBIwgbBEAgASAEIAYQgAEhAkEgIwhBAUYNARogAiEBCyMIRQRAIAAoAgQhAAsgA0EhRkEBIwgbBEAgACABQdC6ARCCASECQSEjCEEBRg0BGiACIQALIANBIkZBASMIGwRAIAAQjwEhAkEiIwhBAUYNARogAiEACyADQSNGQQEjCBsEQEGwwgEQHiECQSMjCEEBRg0BGiACIQELIANBJEZBASMIGwRAIAEQRiECQSQjCEEBRg0BGiACIQELIANBJUZBASMIGwRAIAEgABCRAUElIwhBAUYNARoLIwhFBEAgASgCACEECyADQSZGQQEjCBsEQCAEEBkhAkEmIwhBAUYNARogAiEGCyADQSdGQQEjCBsEQEGQxAEQHiECQScjCEEBRg0BGiACIQQLIANBKEZBASMIGwRAIAYgASAEEEwhAkEoIwhBAUYNARogAiEBCyADQSlGQQEjCBsEQCABIAAQkQFBKSMIQQFGDQEaCyMIRQRAIAYPCwALIQIjCSgCACACNgIAIwkjCSgCAEEEajYCACMJKAIAIgIgADYCACACIAE2AgQgAiAENgIIIAIgBTYCDCACIAY2AhAgAiAHNgIUIAIgCDYCGCACIAk2AhwgAiAKNgIgIAIgCzYCJCACIAw2AiggAiANNgIsIAIgDzYCMCACIA42AjQjCSMJKAIAQThqNgIAQQALvgEBAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQALAn8jCEECRgR/IwkjCSgCAEEEazYCACMJKAIAKAIABSABC0EAIwgbRQRAQRBBzQAQDSEBQQAjCEEBRg0BGiABIQALIwhFBEAgAEEANgIAIABBADYCBCAAQQA2AgggAEEANgIMIAAPCwALIQEjCSgCACABNgIAIwkjCSgCAEEEajYCACMJKAIAIAA2AgAjCSMJKAIAQQRqNgIAQQAL7gIBAn8jCEECRgRAIwkjCSgCAEEMazYCACMJKAIAIgIoAgAhACACKAIEIQEgAigCCCECCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEDCyMIRQRAAkACQAJAIABBCGsoAgAiAkEhRwRAIAJBHUYNAQwCCyAAKAIAIQIMAgsgACgCCCECDAELAAsLIANBACMIG0UEQCACQbDFAUGwwwFBkMcAEDRBACMIQQFGDQEaCyADQQFGQQEjCBsEQCAAIAFBsMkBEK8BQQEjCEEBRg0BGgsjCEUEQAJAAkACQCAAQQhrKAIAIgFBIUcEQCABQR1GDQEMAgsgACgCACEADAILIAAoAgghAAwBCwALCyADQQJGQQEjCBsEQCAAEDZBAiMIQQFGDQEaCw8LIQMjCSgCACADNgIAIwkjCSgCAEEEajYCACMJKAIAIgMgADYCACADIAE2AgQgAyACNgIIIwkjCSgCAEEMajYCAAuqGAEQfwJ/IwhBAkYEfyMJIwkoAgBBQGo2AgAjCSgCACICKAIAIQAgAigCCCEEIAIoAgwhBSACKAIQIQYgAigCFCEHIAIoAhghCCACKAIcIQkgAigCICELIAIoAiQhCiACKAIoIQwgAigCLCENIAIoAjAhDiACKAI0IQ8gAigCOCERIAIoAjwhECACKAIEBSABC0EAIwgbIQEjCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQMLIANBACMIG0UEQEHgxQEQHiECQQAjCEEBRg0BGiACIQQLIANBAUZBASMIGwRAIAAgBBAoIQJBASMIQQFGDQEaIAIhBAsgA0ECRkEBIwgbBEAgBBAvIQJBAiMIQQFGDQEaIAIhCAsgCUGgCCMIGyEJA0AgCCMIQQJGcgRAIAggCEEBayMIGyEIIANBA0ZBASMIGwRAIAQQMCECQQMjCEEBRg0DGiACIQALIwhFBEAgBCgCBCEFCyADQQRGQQEjCBsEQCAFIAAQMSECQQQjCEEBRg0DGiACIQALIwhFBEAgAEEUaygCECEFCyADQQVGQQEjCBsEQCAAIAUQGyECQQUjCEEBRg0DGiACIQALIwhFBEAgBCgCACEFCyADQQZGQQEjCBsEQCAFIABBoDVB0DUQNEEGIwhBAUYNAxoLIAsCfyMIRQRAIABB8KgBEB0hBQsgBQsjCBsiCyMIQQJGcgRAIwhFBEAgBCgCACEGCyADQQdGQQEjCBsEQCAGIABB0MYBQeA2EDRBByMIQQFGDQQaCyMIRQRAQQAhCkEAIQYgBEEIaygCAEESRiEACwJAIAAjCEECRnIEQCADQQhGQQEjCBsEQCAEEC8hAkEIIwhBAUYNBhogAiEHCyMIRQ0BCyMIRQRAAAsLA0AgByMIQQJGcgRAIAcgB0EBayMIGyEHIANBCUZBASMIGwRAIAQQpAEhAkEJIwhBAUYNBhogAiEFCyMIRQRAAkAgBEEIaygCAEESRgRAIAQoAgAhAAwBCwALCyADQQpGQQEjCBsEQCAAIAVBoDVB0DUQNEEKIwhBAUYNBhoLIAwCfyMIRQRAIAVBkLwBEB0hAAsgAAsjCBsiDCMIQQJGcgRAIwhFBEACQCAEQQhrKAIAQRJGBEAgBCgCACEADAELAAsLIANBC0ZBASMIGwRAIAAgBUHAvAFB4DYQNEELIwhBAUYNBxoLIANBDEZBASMIGwRAIARBgMcBEK0BIQJBDCMIQQFGDQcaIAIhCgsjCEUEQAJAIARBCGsoAgBBEkYEQCAEKAIAIQAMAQsACwsgA0ENRkEBIwgbBEAgABA2QQ0jCEEBRg0HGgsLIAxFIwhBAkZyBEAjCEUEQCAFQcC9ARAdIQALIAAjCEECRnIEQCMIRQRAAkAgBEEIaygCAEESRgRAIAQoAgAhAAwBCwALCyADQQ5GQQEjCBsEQCAAIAVB4L0BQeA2EDRBDiMIQQFGDQgaCyADQQ9GQQEjCBsEQCAEQaDHARCuASECQQ8jCEEBRg0IGiACIQYLIwhFBEACQCAEQQhrKAIAQRJGBEAgBCgCACEADAELAAsLIANBEEZBASMIGwRAIAAQNkEQIwhBAUYNCBoLCwsjCEUEQAJAIARBCGsoAgBBEkYEQCAEKAIAIQAMAQsACwsgA0ERRkEBIwgbBEAgABA2QREjCEEBRg0GGgsjCEUNAQsLIANBEkZBASMIGwRAQQhBywAQDSECQRIjCEEBRg0EGiACIQALIwhFBEAgAEEANgIAIABBADYCBCAAIAo2AgAgACAGNgIEIAAhBkEBIQcgBCgCACEACyADQRNGQQEjCBsEQCAAEDZBEyMIQQFGDQQaCwsgC0UjCEECRnIEQCANAn8jCEUEQCAAQYA6EB0hBQsgBQsjCBsiDSMIQQJGcgRAIwhFBEAgBCgCACEFCyADQRRGQQEjCBsEQCAFIABBwDZB4DYQNEEUIwhBAUYNBRoLIANBFUZBASMIGwRAIAQQMCECQRUjCEEBRg0FGiACIQALIwhFBEAgBCgCBCEFCyADQRZGQQEjCBsEQCAFIAAQMSECQRYjCEEBRg0FGiACIQALIwhFBEAgAEEUaygCECEFCyADQRdGQQEjCBsEQCAAIAUQGyECQRcjCEEBRg0FGiACIQkLIwhFBEBBASERIAQoAgAhAAsgA0EYRkEBIwgbBEAgABA2QRgjCEEBRg0FGgsLIA1FIwhBAkZyBEAgDgJ/IwhFBEAgAEGwOhAdIQULIAULIwgbIg4jCEECRnIEQCMIRQRAIAQoAgAhBQsgA0EZRkEBIwgbBEAgBSAAQeA6QeA2EDRBGSMIQQFGDQYaCyADQRpGQQEjCBsEQCAEED4hAkEaIwhBAUYNBhogAiEQCyMIRQRAIAQoAgAhAAsgA0EbRkEBIwgbBEAgABA2QRsjCEEBRg0GGgsLIA5FIwhBAkZyBEAjCEUEQCAAQZCsARAdIQULIAUjCEECRnIEQCMIRQRAIAQoAgAhAQsgA0EcRkEBIwgbBEAgASAAQcCsAUHgNhA0QRwjCEEBRg0HGgtBACABQQAgDwJ/IANBHUZBASMIGwRAIAQQKiECQR0jCEEBRg0IGiACIQALIAALIwgbIg8jCEECRnIjCBsbIQEgD0UjCEECRnJBACADQR5GQQEjCBsbBEAgBBB8IQJBHiMIQQFGDQcaIAIhAQsjCEUEQCAEKAIAIQALIANBH0ZBASMIGwRAIAAQNkEfIwhBAUYNBxoLCwsLCyMIRQRAIAQoAgAhAAsgA0EgRkEBIwgbBEAgABA2QSAjCEEBRg0DGgsjCEUNAQsLIAAgB0EAIAYbRSMIGyIAIwhBAkZyBEAjCEUEQCAEKAIAIQALIANBIUZBASMIGwRAIABBwMcBECYhAkEhIwhBAUYNAhogAiEACyAAIABBoAggABsjCBshACADQSJGQQEjCBsEQCAAQdDCAEHnBEEFEApBIiMIQQFGDQIaCyMIRQRAAAsLIAAgEUUjCBsiACMIQQJGcgRAIwhFBEAgBCgCACEACyADQSNGQQEjCBsEQCAAQbDDABAmIQJBIyMIQQFGDQIaIAIhAAsgACAAQaAIIAAbIwgbIQAgA0EkRkEBIwgbBEAgAEHQwgBB6gRBBRAKQSQjCEEBRg0CGgsjCEUEQAALCyADQSVGQQEjCBsEQBCTASECQSUjCEEBRg0BGiACIQALIwhFBEAgACAGNgIAIAAgCTYCBCAAIBA2AgggACABNgIMCyADQSZGQQEjCBsEQBCTASECQSYjCEEBRg0BGiACIQELIwhFBEAgASAAKAIANgIAIAEgACgCBDYCBCABIAAoAggiBjYCCCABIAAoAgw2AgwgASgCACEACyADQSdGQQEjCBsEQCAAEI0BIQJBJyMIQQFGDQEaIAIhAAsjCEUEQCABKAIIIQYgASgCDCEECyADQShGQQEjCBsEQCAAIAYgBBCAASECQSgjCEEBRg0BGiACIQALIwhFBEAgASgCBCEBCyADQSlGQQEjCBsEQCABIABBsMUBEIIBIQJBKSMIQQFGDQEaIAIhAAsgA0EqRkEBIwgbBEAgABCPASECQSojCEEBRg0BGiACIQALIANBK0ZBASMIGwRAQbDIARAeIQJBKyMIQQFGDQEaIAIhAQsgA0EsRkEBIwgbBEAgARBGIQJBLCMIQQFGDQEaIAIhAQsgA0EtRkEBIwgbBEAgASAAEJQBQS0jCEEBRg0BGgsjCEUEQCABKAIAIQYLIANBLkZBASMIGwRAIAYQGSECQS4jCEEBRg0BGiACIQYLIANBL0ZBASMIGwRAQdDJARAeIQJBLyMIQQFGDQEaIAIhBAsgA0EwRkEBIwgbBEAgBiABIAQQTCECQTAjCEEBRg0BGiACIQELIANBMUZBASMIGwRAIAEgABCUAUExIwhBAUYNARoLIwhFBEAgBg8LAAshAiMJKAIAIAI2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAiAANgIAIAIgATYCBCACIAQ2AgggAiAFNgIMIAIgBjYCECACIAc2AhQgAiAINgIYIAIgCTYCHCACIAs2AiAgAiAKNgIkIAIgDDYCKCACIA02AiwgAiAONgIwIAIgDzYCNCACIBE2AjggAiAQNgI8IwkjCSgCAEFAazYCAEEAC60HAQh/IwhBAkYEQCMJIwkoAgBBJGs2AgAjCSgCACIDKAIAIQAgAygCCCECIAMoAgwhBSADKAIQIQYgAygCFCEHIAMoAhghCCADKAIcIQkgAygCICEKIAMoAgQhAQsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBAsgBEEAIwgbRQRAIAAQGSEDQQAjCEEBRg0BGiADIQALIARBAUZBASMIGwRAIAEQGSEDQQEjCEEBRg0BGiADIQELIARBAkZBASMIGwRAIAAgARABQQIjCEEBRg0BGgsjCEUEQCAAQRRrKAIQIQULIARBA0ZBASMIGwRAIAAgBRAbIQNBAyMIQQFGDQEaIAMhBQsgBEEERkEBIwgbBEBBCEEKEA0hA0EEIwhBAUYNARogAyEACyAGAn8jCEUEQCAAIAU2AgAgACABNgIEIAAoAgBB8A0QHSEBCyABCyMIGyIGIwhBAkZyQQAgBEEFRkEBIwgbGwRAIAAgAkHwjAEQXyEDQQUjCEEBRg0BGiADIQALIAZFIwhBAkZyBEAgBwJ/IwhFBEAgACgCAEGwjgEQHSEBCyABCyMIGyIHIwhBAkZyQQAgBEEGRkEBIwgbGwRAIAAgAkGwpwEQXyEDQQYjCEEBRg0CGiADIQALIAdFIwhBAkZyBH8gCAJ/IwhFBEAgACgCAEHQpwEQHSEBCyABCyMIGyIIIwhBAkZyQQAgBEEHRkEBIwgbGwRAIAAgAkGwugEQXyEDQQcjCEEBRg0DGiADIQALIAhFIwhBAkZyBH8gCQJ/IwhFBEAgACgCAEHQugEQHSEBCyABCyMIGyIJIwhBAkZyQQAgBEEIRkEBIwgbGwRAIAAgAkGQxQEQXyEDQQgjCEEBRg0EGiADIQALIAlFIwhBAkZyBH8gCgJ/IwhFBEAgACgCAEGwxQEQHSEBCyABCyMIGyIKIwhBAkZyQQAgBEEJRkEBIwgbGwRAIAAgAkHQygEQXyEDQQkjCEEBRg0FGiADIQALIApFIwhBAkZyQQAgBEEKRkEBIwgbGwR/IAAgAkEAEF8hA0EKIwhBAUYNBRogAwUgAAsFIAALBSAACwUgAAshAAsjCEUEQCAADwsACyEDIwkoAgAgAzYCACMJIwkoAgBBBGo2AgAjCSgCACIDIAA2AgAgAyABNgIEIAMgAjYCCCADIAU2AgwgAyAGNgIQIAMgBzYCFCADIAg2AhggAyAJNgIcIAMgCjYCICMJIwkoAgBBJGo2AgBBAAuqDwIJfwJ+IwhBAkYEQCMJIwkoAgBBKGs2AgAjCSgCACICKAIAIQAgAikCCCEKIAIoAhAhBCACKAIUIQUgAigCGCEGIAIoAhwhByACKAIgIQggAigCJCEJIAIoAgQhAQsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAwsjCEUEQCAAKAIEIQELIAECfyADQQAjCBtFBEAgARArIQJBACMIQQFGDQIaIAIhBAsgBEH/AXFBB3ZFCyMIGyEBAkAjCEUEQCABBEAgBEH/AXGtIQoMAgsgBMAiB6whCiAEQeABcUHgAUYNASAEQf8BcUHMAWshAQsCQAJAAkACQAJAAkACQAJAIwhFBEACQCABDggFBgcIAAIDBAkLIAAoAgQhAQsgA0EBRkEBIwgbBEAgARA4IQJBASMIQQFGDQoaIAIhAQsjCEUEQCABwKwhCgwJCwsjCEUEQCAAKAIEIQELIANBAkZBASMIGwRAIAEQOSECQQIjCEEBRg0JGiACIQELIwhFBEAgAcGsIQoMCAsLIwhFBEAgACgCBCEBCyADQQNGQQEjCBsEQCABEDohAkEDIwhBAUYNCBogAiEBCyMIRQRAIAGsIQoMBwsLIwhFBEAgACgCBCEBCyADQQRGQQEjCBsEQCABEDshC0EEIwhBAUYNBxogCyEKCyMIRQ0FCyMIRQRAIAAoAgQhAQsgA0EFRkEBIwgbBEAgARArIQJBBSMIQQFGDQYaIAIhAQsjCEUEQCABQf8Bca0hCgwFCwsjCEUEQCAAKAIEIQELIANBBkZBASMIGwRAIAEQLCECQQYjCEEBRg0FGiACIQELIwhFBEAgAUH//wNxrSEKDAQLCyMIRQRAIAAoAgQhAQsgA0EHRkEBIwgbBEAgARAtIQJBByMIQQFGDQQaIAIhAQsjCEUEQCABrSEKDAMLCyMIRQRAIAAoAgQhAQsgA0EIRkEBIwgbBEAgARA3IQtBCCMIQQFGDQMaIAshCgsjCEUEQCAKQv///////////wBYDQIgACgCACEBCyADQQlGQQEjCBsEQCAKED0hAkEJIwhBAUYNAxogAiEACyADQQpGQQEjCBsEQEHwygEgABAfIQJBCiMIQQFGDQMaIAIhAAsgA0ELRkEBIwgbBEAgAEHAywEQHyECQQsjCEEBRg0DGiACIQALIANBDEZBASMIGwRAIAEgABAmIQJBDCMIQQFGDQMaIAIhAAsgACAAQaAIIAAbIwgbIQAgA0ENRkEBIwgbBEAgAEGgM0GcBEEJEApBDSMIQQFGDQMaCyMIRQRAAAsLIwhFBEAgACgCACEBCyADQQ5GQQEjCBsEQCAEEC4hAkEOIwhBAUYNAhogAiEACyADQQ9GQQEjCBsEQEHwywEgABAfIQJBDyMIQQFGDQIaIAIhAAsgA0EQRkEBIwgbBEAgASAAECYhAkEQIwhBAUYNAhogAiEACyAAIABBoAggABsjCBshACADQRFGQQEjCBsEQCAAQaAzQaMEQQkQCkERIwhBAUYNAhoLIwhFBEAACwtB4BECfyMIRQRAIApC/////wdXIApCgICAgHhZcQRAIAqnDwsgACgCACEHIApQIQALIAALQQAgCCAAIwgbIggjCEECRnIjCBsbIQAgCEUjCEECRnIEQCAJAn8jCEUEQEIAIAp9IAogCkI/iKciBhsiCkL/////D1ghAAsgAAsjCBsiCSMIQQJGcgRAIwhFBEAgCqciBUGgjQZJBH8gBUHkAEkEfyAFQQpPQQFqBSAFQZDOAE9BA2ogBUHoB09qCwUgBUGAreIESQR/IAVBwIQ9T0EGagUgBUGAlOvcA09BCGogBUGAwtcvT2oLCyAGaiIEQQF0IQALIANBEkZBASMIGwRAIABBARANIQJBEiMIQQFGDQMaIAIhAAsjCEUEQCAAIAUgBBAgCwsgCUUjCEECRnIEQCMIRQRAIApCgICapuqv4wFUBH8gCkKAoJSljR1UBH8gCkKA0NvD9AJaQQpqIApCgMivoCVaagUgCkKAgOmDsd4WWkENaiAKQoDAyvOEowJaagsFIApCgICo7IWv0bEBVAR/IApCgICE/qbe4RFaQRBqBSAKQoCAoM/I4Mjjin9aQRJqIApCgICQu7rWrfANWmoLCyAGaiIEQQF0IQALIANBE0ZBASMIGwRAIABBARANIQJBEyMIQQFGDQMaIAIhAAsjCEUEQCAAIAogBBA8CwsgBiMIRXEEQCAAQS07AQALCyADQRRGQQEjCBsEQEHwygEgABAfIQJBFCMIQQFGDQEaIAIhAAsgA0EVRkEBIwgbBEAgAEGwPxAfIQJBFSMIQQFGDQEaIAIhAAsgA0EWRkEBIwgbBEAgByAAECYhAkEWIwhBAUYNARogAiEACyAAIABBoAggABsjCBshACADQRdGQQEjCBsEQCAAQaAzQcwAQQUQCkEXIwhBAUYNARoLIwhFBEAACwALIQIjCSgCACACNgIAIwkjCSgCAEEEajYCACMJKAIAIgIgADYCACACIAE2AgQgAiAKNwIIIAIgBDYCECACIAU2AhQgAiAGNgIYIAIgBzYCHCACIAg2AiAgAiAJNgIkIwkjCSgCAEEoajYCAEEAC8kBAQF/IwhBAkYEQCMJIwkoAgBBCGs2AgAjCSgCACIBKAIAIQAgASgCBCEBCwJ/IwhBAkYEfyMJIwkoAgBBBGs2AgAjCSgCACgCAAUgAgtBACMIG0UEQCAAQdDMAUEBEClBACMIQQFGDQEaCyMIRQRAIAAoAgAgACgCDGogAToAACAAIAAoAgxBAWo2AgwLDwshAiMJKAIAIAI2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAiAANgIAIAIgATYCBCMJIwkoAgBBCGo2AgAL1wEBAX8jCEECRgRAIwkjCSgCAEEIazYCACMJKAIAIgEoAgAhACABKAIEIQELAn8jCEECRgR/IwkjCSgCAEEEazYCACMJKAIAKAIABSACC0EAIwgbRQRAIABBgM0BQQIQKUEAIwhBAUYNARoLIwhFBEAgACgCACAAKAIMaiABQQh0IAFB//8DcUEIdnI7AQAgACAAKAIMQQJqNgIMCw8LIQIjCSgCACACNgIAIwkjCSgCAEEEajYCACMJKAIAIgIgADYCACACIAE2AgQjCSMJKAIAQQhqNgIAC94BAQF/IwhBAkYEQCMJIwkoAgBBCGs2AgAjCSgCACIBKAIAIQAgASgCBCEBCwJ/IwhBAkYEfyMJIwkoAgBBBGs2AgAjCSgCACgCAAUgAgtBACMIG0UEQCAAQbDNAUEEEClBACMIQQFGDQEaCyMIRQRAIAAoAgwgACgCAGogAUGA/oN4cUEIdyABQf+B/AdxQQh4cjYCACAAIAAoAgxBBGo2AgwLDwshAiMJKAIAIAI2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAiAANgIAIAIgATYCBCMJIwkoAgBBCGo2AgAL4wMBBH8jCEECRgRAIwkjCSgCAEEUazYCACMJKAIAIgQoAgAhACAEKAIEIQEgBCgCCCEDIAQoAgwhBSAEKAIQIQQLAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQILIAUgAyABQRBJIwgbIgMjCBsiBSMIQQJGcgRAIwhFBEAgAUGAAXIhASAAKAIEIQALIAJBACMIG0UEQCAAIAEQmAFBACMIQQFGDQIaCwsgBUUjCEECRnIEQCAEIAMgAUH//wNNIwgbIgMjCBsiBCMIQQJGcgRAIwhFBEAgACgCBCEDCyACQQFGQQEjCBsEQCADQd4BEJgBQQEjCEEBRg0DGgsjCEUEQCAAKAIEIQALIAJBAkZBASMIGwRAIAAgARCZAUECIwhBAUYNAxoLCyAERSMIQQJGcgRAIwhFBEAgACgCBCEDCyACQQNGQQEjCBsEQCADQd8BEJgBQQMjCEEBRg0DGgsjCEUEQCAAKAIEIQALIAJBBEZBASMIGwRAIAAgARCaAUEEIwhBAUYNAxoLCwsPCyECIwkoAgAgAjYCACMJIwkoAgBBBGo2AgAjCSgCACICIAA2AgAgAiABNgIEIAIgAzYCCCACIAU2AgwgAiAENgIQIwkjCSgCAEEUajYCAAv1AQECfyMIQQJGBEAjCSMJKAIAQQxrNgIAIwkoAgAiAigCACEAIAIoAgQhASACKAIIIQILAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQMLIwhFBEAgAUEUaygCECECCyADQQAjCBtFBEAgAEHgzQEgAhApQQAjCEEBRg0BGgsjCEUEQCAAKAIAIAAoAgxqIAEgAUEUaygCEBAXIAAgAUEUaygCECAAKAIMajYCDAsPCyEDIwkoAgAgAzYCACMJIwkoAgBBBGo2AgAjCSgCACIDIAA2AgAgAyABNgIEIAMgAjYCCCMJIwkoAgBBDGo2AgAL4wUBB38jCEECRgRAIwkjCSgCAEEcazYCACMJKAIAIgMoAgAhACADKAIIIQIgAygCDCEFIAMoAhAhBiADKAIUIQcgAygCGCEIIAMoAgQhAQsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBAsjCEUEQEEBJAALIARBACMIG0UEQCABEAkhA0EAIwhBAUYNARogAyEFCyAGAn8jCEUEQCAFQRRrKAIQIgFBIEkhAgsgAgsjCBsiBiMIQQJGcgRAIwhFBEAgACgCBCECIAFBoAFyIQELIARBAUZBASMIGwRAIAIgARCYAUEBIwhBAUYNAhoLCyAGRSMIQQJGcgRAIAcgAiABQf8BTSMIGyICIwgbIgcjCEECRnIEQCMIRQRAIAAoAgQhAgsgBEECRkEBIwgbBEAgAkHZARCYAUECIwhBAUYNAxoLIwhFBEAgACgCBCECCyAEQQNGQQEjCBsEQCACIAEQmAFBAyMIQQFGDQMaCwsgB0UjCEECRnIEQCAIIAIgAUH//wNNIwgbIgIjCBsiCCMIQQJGcgRAIwhFBEAgACgCBCECCyAEQQRGQQEjCBsEQCACQdoBEJgBQQQjCEEBRg0EGgsjCEUEQCAAKAIEIQILIARBBUZBASMIGwRAIAIgARCZAUEFIwhBAUYNBBoLCyAIRSMIQQJGcgRAIwhFBEAgACgCBCECCyAEQQZGQQEjCBsEQCACQdsBEJgBQQYjCEEBRg0EGgsjCEUEQCAAKAIEIQILIARBB0ZBASMIGwRAIAIgARCaAUEHIwhBAUYNBBoLCwsLIwhFBEAgACgCBCEACyAEQQhGQQEjCBsEQCAAIAUQnAFBCCMIQQFGDQEaCw8LIQMjCSgCACADNgIAIwkjCSgCAEEEajYCACMJKAIAIgMgADYCACADIAE2AgQgAyACNgIIIAMgBTYCDCADIAY2AhAgAyAHNgIUIAMgCDYCGCMJIwkoAgBBHGo2AgALsgIBAn8jCEECRgRAIwkjCSgCAEEMazYCACMJKAIAIgIoAgAhACACKAIEIQEgAigCCCECCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEDCyMIRQRAQQEkAAsgA0EAIwgbRQRAIAEQCSEDQQAjCEEBRg0BGiADIQILIwhFBEAgAkEUaygCECIBQSBJBEAgACAAKAIAQQFqNgIABSABQf8BTQRAIAAgACgCAEECajYCAAUgAUH//wNNBEAgACAAKAIAQQNqNgIABSAAIAAoAgBBBWo2AgALCwsgACACQRRrKAIQIAAoAgBqNgIACw8LIQMjCSgCACADNgIAIwkjCSgCAEEEajYCACMJKAIAIgMgADYCACADIAE2AgQgAyACNgIIIwkjCSgCAEEMajYCAAvIBAEJfyMIQQJGBEAjCSMJKAIAQSBrNgIAIwkoAgAiASgCACEAIAEoAgQhAiABKAIMIQQgASgCECEFIAEoAhQhBiABKAIYIQggASgCHCEJIAEoAgghAwsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBwsjCEUEQCAAKAIIIQggACgCECEECyAHQQAjCBtFBEAgBBBqIQFBACMIQQFGDQEaIAEhAwsDQCAAIAQgBUojCBsiACMIQQJGcgRAIwhFBEAgCCAFQQxsaiICKAIIQQFxRSEACyAAIwhBAkZyBEAjCEUEQCAGIgBBAWohBiACKAIAIQkgAygCDCAATSECCyACIwhBAkZyBEAgAiAAQQBIIwgbIgIjCEECRnIEQCAHQQFGQQEjCBsEQEGgC0HgIEGCAUEWEApBASMIQQFGDQYaCyMIRQRAAAsLIAIgAEEBaiMIGyECIAdBAkZBASMIGwRAIAMgAkEBEDJBAiMIQQFGDQUaCyMIRQRAIAMgAjYCDAsLIwhFBEAgAygCBCICIABBAnRqIgAgCTYCAAsLIwhFBEAgBUEBaiEFDAILCwsgB0EDRkEBIwgbBEAgAyAGQQAQMkEDIwhBAUYNARoLIwhFBEAgAyAGNgIMIAMPCwALIQEjCSgCACABNgIAIwkjCSgCAEEEajYCACMJKAIAIgEgADYCACABIAI2AgQgASADNgIIIAEgBDYCDCABIAU2AhAgASAGNgIUIAEgCDYCGCABIAk2AhwjCSMJKAIAQSBqNgIAQQALmQUBB38jCEECRgRAIwkjCSgCAEEgazYCACMJKAIAIgQoAgAhACAEKAIIIQIgBCgCDCEDIAQoAhAhBSAEKAIUIQcgBCgCGCEIIAQoAhwhCSAEKAIEIQELAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQYLIAgCfyMIRQRAIAEoAgwiBUEQSSEDCyADCyMIGyIIIwhBAkZyBEAjCEUEQCAAKAIEIQMgBUGQAXIhBQsgBkEAIwgbRQRAIAMgBRCYAUEAIwhBAUYNAhoLCyAIRSMIQQJGcgRAIAkgAyAFQf//A00jCBsiAyMIGyIJIwhBAkZyBEAjCEUEQCAAKAIEIQMLIAZBAUZBASMIGwRAIANB3AEQmAFBASMIQQFGDQMaCyMIRQRAIAAoAgQhAwsgBkECRkEBIwgbBEAgAyAFEJkBQQIjCEEBRg0DGgsLIAlFIwhBAkZyBEAjCEUEQCAAKAIEIQMLIAZBA0ZBASMIGwRAIANB3QEQmAFBAyMIQQFGDQMaCyMIRQRAIAAoAgQhAwsgBkEERkEBIwgbBEAgAyAFEJoBQQQjCEEBRg0DGgsLCwNAIwhFBEAgByABKAIMSCEFCyAFIwhBAkZyBEAgBkEFRkEBIwgbBEAgASAHECUhBEEFIwhBAUYNAxogBCEFCyMIRQRAQQIkACACKAIAIQMLIAZBBkZBASMIGwRAIAAgBSADEQEAQQYjCEEBRg0DGgsjCEUEQCAHQQFqIQcMAgsLCw8LIQQjCSgCACAENgIAIwkjCSgCAEEEajYCACMJKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIAQgAzYCDCAEIAU2AhAgBCAHNgIUIAQgCDYCGCAEIAk2AhwjCSMJKAIAQSBqNgIAC40DAQV/IwhBAkYEQCMJIwkoAgBBGGs2AgAjCSgCACIDKAIAIQAgAygCCCECIAMoAgwhBCADKAIQIQUgAygCFCEGIAMoAgQhAQsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBwsjCEUEQCABKAIMIgRBEEkiBQRAIAAgACgCAEEBaiIENgIABSAEQf//A00EQCAAIAAoAgBBA2oiBDYCAAUgACAAKAIAQQVqIgQ2AgALCwsDQCMIRQRAIAYgASgCDEghBAsgBCMIQQJGcgRAIAdBACMIG0UEQCABIAYQJSEDQQAjCEEBRg0DGiADIQQLIwhFBEBBAiQAIAIoAgAhBQsgB0EBRkEBIwgbBEAgACAEIAURAQBBASMIQQFGDQMaCyMIRQRAIAZBAWohBgwCCwsLDwshAyMJKAIAIAM2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAyAANgIAIAMgATYCBCADIAI2AgggAyAENgIMIAMgBTYCECADIAY2AhQjCSMJKAIAQRhqNgIAC/8EAQd/IwhBAkYEQCMJIwkoAgBBGGs2AgAjCSgCACIBKAIAIQAgASgCBCEDIAEoAgwhBSABKAIQIQYgASgCFCEHIAEoAgghAgsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBAsgBEEAIwgbRQRAIAAQLyEBQQAjCEEBRg0BGiABIQcLIARBAUZBASMIGwRAEEEhAUEBIwhBAUYNARogASEGCwNAIAMgBSAHSSMIGyIDIwhBAkZyBEAjCEUEQCAAKAIAIQMLIARBAkZBASMIGwRAIAUQISEBQQIjCEEBRg0DGiABIQILIARBA0ZBASMIGwRAQdDRASACEB8hAUEDIwhBAUYNAxogASECCyAEQQRGQQEjCBsEQCACQZAfEB8hAUEEIwhBAUYNAxogASECCyAEQQVGQQEjCBsEQCADIAJBoAhBoAgQNEEFIwhBAUYNAxoLIwhFBEBBASQAQbDWACgCACEDCyAEQQZGQQEjCBsEQCAAIAMRAAAhAUEGIwhBAUYNAxogASEDCyMIRQRAQQEkAEHQ1gAoAgAhAgsgBEEHRkEBIwgbBEAgACACEQAAIQFBByMIQQFGDQMaIAEhAgsgBEEIRkEBIwgbBEAgBiADIAIQREEIIwhBAUYNAxoLIwhFBEAgACgCACEDCyAEQQlGQQEjCBsEQCADEDZBCSMIQQFGDQMaCyMIRQRAIAVBAWohBQwCCwsLIwhFBEAgBg8LAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAiASAANgIAIAEgAzYCBCABIAI2AgggASAFNgIMIAEgBjYCECABIAc2AhQjCSMJKAIAQRhqNgIAQQAL7QQBBX8jCEECRgRAIwkjCSgCAEEQazYCACMJKAIAIgEoAgAhACABKAIIIQQgASgCDCEFIAEoAgQhAgsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAwsgA0EAIwgbRQRAIAAQKiEBQQAjCEEBRg0BGiABIQILIwhFBEAgAgRAQQAPCyAAKAIEIQILIANBAUZBASMIGwRAIAIQKyEBQQEjCEEBRg0BGiABIQILIwhFBEAgAkHwAXFBkAFGBEAgAkEPcQ8LIAJB/wFxIgRB3AFHIQULAkACQAJAIwhFBEAgBQRAIARB3QFGDQIgBEHAAUYNAwwECyAAKAIEIQALIANBAkZBASMIGwRAIAAQLCEBQQIjCEEBRg0EGiABIQALIwhFBEAgAEH//wNxDwsLIwhFBEAgACgCBCEACyADQQNGQQEjCBsEQCAAEC0hAUEDIwhBAUYNAxogASEACyMIRQRAIAAPCwsjCEUEQEEADwsLIwhFBEAgACgCACEACyADQQRGQQEjCBsEQCACEC4hAUEEIwhBAUYNARogASECCyADQQVGQQEjCBsEQEGg1QEgAhAfIQFBBSMIQQFGDQEaIAEhAgsgA0EGRkEBIwgbBEAgACACECYhAUEGIwhBAUYNARogASEACyAAIABBoAggABsjCBshACADQQdGQQEjCBsEQCAAQaAzQfIBQQUQCkEHIwhBAUYNARoLIwhFBEAACwALIQEjCSgCACABNgIAIwkjCSgCAEEEajYCACMJKAIAIgEgADYCACABIAI2AgQgASAENgIIIAEgBTYCDCMJIwkoAgBBEGo2AgBBAAu6AgEDfyMIQQJGBEAjCSMJKAIAQQhrNgIAIwkoAgAiASgCACEAIAEoAgQhAgsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAwsjCEUEQCAAQQhrKAIAQRJGIQILIAIjCEECRnIEQCADQQAjCBtFBEAgABAwIQFBACMIQQFGDQIaIAEhAgsjCEUEQCAAKAIEIQALIANBAUZBASMIGwRAIAAgAhAxIQFBASMIQQFGDQIaIAEhAAsjCEUEQCAAQRRrKAIQIQILIANBAkZBASMIGwRAIAAgAhAbIQFBAiMIQQFGDQIaIAEhAAsjCEUEQCAADwsLIwhFBEAACwALIQEjCSgCACABNgIAIwkjCSgCAEEEajYCACMJKAIAIgEgADYCACABIAI2AgQjCSMJKAIAQQhqNgIAQQAL4QIBBH8jCEECRgRAIwkjCSgCAEEMazYCACMJKAIAIgEoAgAhACABKAIIIQMgASgCBCECCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEECyMIRQRAIABBCGsoAgBBEkYhAgsgAiMIQQJGcgRAQQAgAEEAIAMCfyAEQQAjCBtFBEAgABAqIQFBACMIQQFGDQMaIAEhAgsgAgsjCBsiAyMIQQJGciMIGxshACADRSMIQQJGcgRAIARBAUZBASMIGwRAIAAQlwEhAUEBIwhBAUYNAxogASECCyAEQQJGQQEjCBsEQEEEQQ4QDSEBQQIjCEEBRg0DGiABIQALIwhFBEAgACACNgIACwsjCEUEQCAADwsLIwhFBEAACwALIQEjCSgCACABNgIAIwkjCSgCAEEEajYCACMJKAIAIgEgADYCACABIAI2AgQgASADNgIIIwkjCSgCAEEMajYCAEEAC6ECAQN/IwhBAkYEQCMJIwkoAgBBCGs2AgAjCSgCACIBKAIAIQAgASgCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACECCwJAAkAjCEEBIwgEfyAEBSAAQQhrKAIAIgNBIUcLG0UEQCADQR1GDQEMAgsgAkEAIwgbRQRAIAAgARCbAUEAIwhBAUYNAxoLIwhFBEAPCwsjCEUEQCABQRBJBEAgACAAKAIAQQFqNgIABSABQf//A00EQCAAIAAoAgBBA2o2AgAFIAAgACgCAEEFajYCAAsLDwsLIwhFBEAACw8LIQIjCSgCACACNgIAIwkjCSgCAEEEajYCACMJKAIAIgIgADYCACACIAE2AgQjCSMJKAIAQQhqNgIAC4QCAQN/IwhBAkYEQCMJIwkoAgBBCGs2AgAjCSgCACIBKAIAIQAgASgCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACECCwJAAkAjCEEBIwgEfyAEBSAAQQhrKAIAIgNBIUcLG0UEQCADQR1GDQEMAgsgAkEAIwgbRQRAIAAgARCdAUEAIwhBAUYNAxoLIwhFBEAPCwsgAkEBRkEBIwgbBEAgACABEJ4BQQEjCEEBRg0CGgsjCEUEQA8LCyMIRQRAAAsPCyECIwkoAgAgAjYCACMJIwkoAgBBBGo2AgAjCSgCACICIAA2AgAgAiABNgIEIwkjCSgCAEEIajYCAAuGEgELfyMIQQJGBEAjCSMJKAIAQTRrNgIAIwkoAgAiBSgCACEAIAUoAgghAiAFKAIMIQMgBSgCECEEIAUoAhQhByAFKAIYIQggBSgCHCEJIAUoAiAhCiAFKAIkIQwgBSgCKCENIAUoAiwhCyAFKAIwIQ4gBSgCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEGCyMIRQRAIABBCGsoAgAiBEEhRyEHCwJAAkAjCEUEQCAHBEAgBEEdRiIEDQIMAwsgASEJIAFFIQELAkAgASMIQQJGcgRAIwhFBEAgACgCBCEACyAGQQAjCBtFBEAgAEHAARCYAUEAIwhBAUYNBRoLIwhFDQELIwhFBEAgACAAKAIMIgFBAWo2AgwgACgCDCIHIAAoAggoAgQoAgxLIQQLIAQjCEECRnIEQCMIRQRAIAAoAgAhAgsgBkEBRkEBIwgbBEBBkM4BQdDOARAfIQVBASMIQQFGDQUaIAUhAwsgBkECRkEBIwgbBEAgARAhIQVBAiMIQQFGDQUaIAUhAQsgBkEDRkEBIwgbBEAgAyABEB8hBUEDIwhBAUYNBRogBSEBCyAGQQRGQQEjCBsEQCABQZDPARAfIQVBBCMIQQFGDQUaIAUhAQsjCEUEQCAAKAIIKAIEKAIMIQALIAZBBUZBASMIGwRAIAAQIiEFQQUjCEEBRg0FGiAFIQALIAZBBkZBASMIGwRAIAEgABAfIQVBBiMIQQFGDQUaIAUhAAsgBkEHRkEBIwgbBEAgAiAAECYhBUEHIwhBAUYNBRogBSEACyAAIABBoAggABsjCBshACAGQQhGQQEjCBsEQCAAQdDPAUHUAUEHEApBCCMIQQFGDQUaCyMIRQRAAAsLIwhFBEAgACgCCCgCBCIEKAIMIAFNIQcLIAcjCEECRnIEQCAGQQlGQQEjCBsEQEGgC0HgIEHyAEEqEApBCSMIQQFGDQUaCyMIRQRAAAsLIAwCfyMIRQRAIAQoAgQgAUECdGooAgAiAUH/AU0hBAsgBAsjCBsiDCMIQQJGcgRAIwhFBEAgACgCBCEECyAGQQpGQQEjCBsEQCAEQccBEJgBQQojCEEBRg0FGgsjCEUEQCAAKAIEIQQLIAZBC0ZBASMIGwRAIAQgARCYAUELIwhBAUYNBRoLCyAMRSMIQQJGcgRAIA0gBCABQf//A00jCBsiBCMIGyINIwhBAkZyBEAjCEUEQCAAKAIEIQQLIAZBDEZBASMIGwRAIARByAEQmAFBDCMIQQFGDQYaCyMIRQRAIAAoAgQhBAsgBkENRkEBIwgbBEAgBCABEJkBQQ0jCEEBRg0GGgsLIA1FIwhBAkZyBEAjCEUEQCAAKAIEIQQLIAZBDkZBASMIGwRAIARByQEQmAFBDiMIQQFGDQYaCyMIRQRAIAAoAgQhBAsgBkEPRkEBIwgbBEAgBCABEJoBQQ8jCEEBRg0GGgsLCyMIRQRAIAAoAgQhAQsgBkEQRkEBIwgbBEAgAUEBEJgBQRAjCEEBRg0EGgsjCEUEQCAJKAIUIQELIAZBEUZBASMIGwRAIAAgARCbAUERIwhBAUYNBBoLIAZBEkZBASMIGwRAIAkQnwEhBUESIwhBAUYNBBogBSEECyABQQAjCBshAQNAIwhFBEAgBCgCDCABSiEHCyAHIwhBAkZyBEAgBkETRkEBIwgbBEAgBCABECUhBUETIwhBAUYNBhogBSEHCyAGQRRGQQEjCBsEQCAJIAcQdiEFQRQjCEEBRg0GGiAFIQoLIwhFBEBBAiQAIAIoAgAhCAsgBkEVRkEBIwgbBEAgACAHIAgRAQBBFSMIQQFGDQYaCyMIRQRAQQIkACADKAIAIQcLIAZBFkZBASMIGwRAIAAgCiAHEQEAQRYjCEEBRg0GGgsjCEUEQCABQQFqIQEMAgsLCwsjCEUEQA8LCyAEIAFFIwgbIQQCQCMIRQRAIAQEQCAAIAAoAgBBAWo2AgAMAgsgACgCBCIEKAIMIQcgBCgCDCIKQQFqIQgLIAZBF0ZBASMIGwRAIAQgCEEBEDJBFyMIQQFGDQMaCyMIRQRAIAQoAgQiCyAKQQJ0akEANgIAIAQgCDYCDCAAIAAoAgBBAWo2AgAgACgCACEKIAEoAhQiBEEQSSIIBEAgACAAKAIAQQFqIgQ2AgAFIARB//8DTQRAIAAgACgCAEEDaiIENgIABSAAIAAoAgBBBWoiBDYCAAsLCyAGQRhGQQEjCBsEQCABEJ8BIQVBGCMIQQFGDQMaIAUhCAsDQCMIRQRAIAkgCCgCDEghBAsgBCMIQQJGcgRAIAZBGUZBASMIGwRAIAggCRAlIQVBGSMIQQFGDQUaIAUhBAsgBkEaRkEBIwgbBEAgASAEEHYhBUEaIwhBAUYNBRogBSELCyMIRQRAQQIkACACKAIAIQ4LIAZBG0ZBASMIGwRAIAAgBCAOEQEAQRsjCEEBRg0FGgsjCEUEQEECJAAgAygCACEECyAGQRxGQQEjCBsEQCAAIAsgBBEBAEEcIwhBAUYNBRoLIwhFBEAgCUEBaiEJDAILCwsjCEUEQCAAKAIAIAprIgFB/wFNBEAgACAAKAIAQQJqNgIABSABQf//A00EQCAAIAAoAgBBA2o2AgAFIAAgACgCAEEFajYCAAsLIAcgACgCBCIAKAIMTyECCyACIwhBAkZyBEAgAiAHQQBIIwgbIgIjCEECRnIEQCAGQR1GQQEjCBsEQEGgC0HgIEGCAUEWEApBHSMIQQFGDQUaCyMIRQRAAAsLIAIgB0EBaiMIGyECIAZBHkZBASMIGwRAIAAgAkEBEDJBHiMIQQFGDQQaCyMIRQRAIAAgAjYCDAsLIwhFBEAgACgCBCAHQQJ0aiABNgIACwsjCEUEQA8LCyMIRQRAAAsPCyEFIwkoAgAgBTYCACMJIwkoAgBBBGo2AgAjCSgCACIFIAA2AgAgBSABNgIEIAUgAjYCCCAFIAM2AgwgBSAENgIQIAUgBzYCFCAFIAg2AhggBSAJNgIcIAUgCjYCICAFIAw2AiQgBSANNgIoIAUgCzYCLCAFIA42AjAjCSMJKAIAQTRqNgIAC/0CAQN/IwhBAkYEQCMJIwkoAgBBDGs2AgAjCSgCACICKAIAIQAgAigCBCEBIAIoAgghAgsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAwsjCEUEQCAAQQhrKAIAIgJBIUchBAsCQAJAIwhFBEAgBARAIAJBHUYiAg0CDAMLIAFFIQILAkAgAiMIQQJGcgRAIwhFBEAgACgCBCEACyADQQAjCBtFBEAgAEHAARCYAUEAIwhBAUYNBRoLIwhFDQELIANBAUZBASMIGwRAIAAgARCdAUEBIwhBAUYNBBoLCyMIRQRADwsLAkAjCEEBIAIgAUUjCBsbRQRAIAAgACgCAEEBajYCAAwBCyADQQJGQQEjCBsEQCAAIAEQngFBAiMIQQFGDQMaCwsjCEUEQA8LCyMIRQRAAAsPCyEDIwkoAgAgAzYCACMJIwkoAgBBBGo2AgAjCSgCACIDIAA2AgAgAyABNgIEIAMgAjYCCCMJIwkoAgBBDGo2AgAL6wEBA38jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQALAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQELIwhFBEAgAEEIaygCACIDQSFHIQILAkACQCMIRQRAIAIEQCADQR1GDQIMAwsgACgCBCEACyABQQAjCBtFBEAgAEHAARCYAUEAIwhBAUYNAxoLIwhFBEAPCwsjCEUEQCAAIAAoAgBBAWo2AgAPCwsjCEUEQAALDwshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAgADYCACMJIwkoAgBBBGo2AgALlgMBBH8jCEECRgRAIwkjCSgCAEEMazYCACMJKAIAIgEoAgAhACABKAIIIQQgASgCBCECCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEDCyMIRQRAIABBCGsoAgBBEkYhAgsgAiMIQQJGcgRAQQAgAEEAIAQCfyADQQAjCBtFBEAgABAqIQFBACMIQQFGDQMaIAEhAgsgAgsjCBsiBCMIQQJGciMIGxshACAERSMIQQJGcgRAIANBAUZBASMIGwRAIAAQMCEBQQEjCEEBRg0DGiABIQILIwhFBEAgACgCBCEACyADQQJGQQEjCBsEQCAAIAIQMSEBQQIjCEEBRg0DGiABIQALIwhFBEAgAEEUaygCECECCyADQQNGQQEjCBsEfyAAIAIQGyEBQQMjCEEBRg0DGiABBSAACyEACyMIRQRAIAAPCwsjCEUEQAALAAshASMJKAIAIAE2AgAjCSMJKAIAQQRqNgIAIwkoAgAiASAANgIAIAEgAjYCBCABIAQ2AggjCSMJKAIAQQxqNgIAQQALwQQBBX8jCEECRgRAIwkjCSgCAEEQazYCACMJKAIAIgEoAgAhACABKAIIIQMgASgCDCEFIAEoAgQhAgsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBAsjCEUEQCAAQQhrKAIAQRJGIQMLIAMjCEECRnIEQCAEQQAjCBtFBEAgABAqIQFBACMIQQFGDQIaIAEhAwsgAyADRSMIGyIDIwhBAkZyBEAjCEUEQEEBIQMgACgCBCECCyAFAn8gBEEBRkEBIwgbBEAgAhArIQFBASMIQQFGDQQaIAEhAgsgAkH/AXFBwwFGCyMIGyEFAkAjCEUEQCAFDQFBACEDIAJB/wFxQcIBRg0BIAAoAgAhAAsgBEECRkEBIwgbBEAgAhAuIQFBAiMIQQFGDQQaIAEhAgsgBEEDRkEBIwgbBEBB4NMBIAIQHyEBQQMjCEEBRg0EGiABIQILIARBBEZBASMIGwRAIAAgAhAmIQFBBCMIQQFGDQQaIAEhAAsgACAAQaAIIAAbIwgbIQAgBEEFRkEBIwgbBEAgAEGgM0EoQQUQCkEFIwhBAUYNBBoLIwhFBEAACwsgBEEGRkEBIwgbBEBBAUEtEA0hAUEGIwhBAUYNAxogASECCyMIRQRAIAIgAzoAAAsLIwhFBEAgAg8LCyMIRQRAAAsACyEBIwkoAgAgATYCACMJIwkoAgBBBGo2AgAjCSgCACIBIAA2AgAgASACNgIEIAEgAzYCCCABIAU2AgwjCSMJKAIAQRBqNgIAQQALngYBB38jCEECRgRAIwkjCSgCAEEcazYCACMJKAIAIgIoAgAhACACKAIIIQMgAigCDCEEIAIoAhAhBiACKAIUIQcgAigCGCEIIAIoAgQhAQsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBQsjCEUEQCAAQQhrKAIAQRJGIQMLIAMjCEECRnIEQCAFQQAjCBtFBEAgABAqIQJBACMIQQFGDQIaIAIhAwsgAyADRSMIGyIDIwhBAkZyBEAgBUEBRkEBIwgbBEAgABCjASECQQEjCEEBRg0DGiACIQgLIAVBAkZBASMIGwRAQRBBxgAQDSECQQIjCEEBRg0DGiACIQQLIwhFBEAgBEEANgIAIARBADYCBCAEQQA2AgggBEEANgIMCyAFQQNGQQEjCBsEQEEgQQAQDSECQQMjCEEBRg0DGiACIQMLIwhFBEAgA0EgEA8gBCADNgIAIAQgAzYCBCAEQSA2AgggBEEANgIMCwNAIAMgByAISSMIGyIDIwhBAkZyBEAjCEUEQCAAKAIAIQMLIAVBBEZBASMIGwRAIAcQISECQQQjCEEBRg0FGiACIQYLIAVBBUZBASMIGwRAQYDWASAGEB8hAkEFIwhBAUYNBRogAiEGCyAFQQZGQQEjCBsEQCAGQZAfEB8hAkEGIwhBAUYNBRogAiEGCyAFQQdGQQEjCBsEQCADIAZBoAhBoAgQNEEHIwhBAUYNBRoLIwhFBEBBASQAIAEoAgAhAwsgBUEIRkEBIwgbBEAgACADEQAAIQJBCCMIQQFGDQUaIAIhAwsgBUEJRkEBIwgbBEAgBCADEDNBCSMIQQFGDQUaCyMIRQRAIAAoAgAhAwsgBUEKRkEBIwgbBEAgAxA2QQojCEEBRg0FGgsjCEUEQCAHQQFqIQcMAgsLCwsjCEUEQCAEDwsLIwhFBEAACwALIQIjCSgCACACNgIAIwkjCSgCAEEEajYCACMJKAIAIgIgADYCACACIAE2AgQgAiADNgIIIAIgBDYCDCACIAY2AhAgAiAHNgIUIAIgCDYCGCMJIwkoAgBBHGo2AgBBAAueBgEHfyMIQQJGBEAjCSMJKAIAQRxrNgIAIwkoAgAiAigCACEAIAIoAgghAyACKAIMIQQgAigCECEGIAIoAhQhByACKAIYIQggAigCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEFCyMIRQRAIABBCGsoAgBBEkYhAwsgAyMIQQJGcgRAIAVBACMIG0UEQCAAECohAkEAIwhBAUYNAhogAiEDCyADIANFIwgbIgMjCEECRnIEQCAFQQFGQQEjCBsEQCAAEKMBIQJBASMIQQFGDQMaIAIhCAsgBUECRkEBIwgbBEBBEEHHABANIQJBAiMIQQFGDQMaIAIhBAsjCEUEQCAEQQA2AgAgBEEANgIEIARBADYCCCAEQQA2AgwLIAVBA0ZBASMIGwRAQSBBABANIQJBAyMIQQFGDQMaIAIhAwsjCEUEQCADQSAQDyAEIAM2AgAgBCADNgIEIARBIDYCCCAEQQA2AgwLA0AgAyAHIAhJIwgbIgMjCEECRnIEQCMIRQRAIAAoAgAhAwsgBUEERkEBIwgbBEAgBxAhIQJBBCMIQQFGDQUaIAIhBgsgBUEFRkEBIwgbBEBBgNYBIAYQHyECQQUjCEEBRg0FGiACIQYLIAVBBkZBASMIGwRAIAZBkB8QHyECQQYjCEEBRg0FGiACIQYLIAVBB0ZBASMIGwRAIAMgBkGgCEGgCBA0QQcjCEEBRg0FGgsjCEUEQEEBJAAgASgCACEDCyAFQQhGQQEjCBsEQCAAIAMRAAAhAkEIIwhBAUYNBRogAiEDCyAFQQlGQQEjCBsEQCAEIAMQM0EJIwhBAUYNBRoLIwhFBEAgACgCACEDCyAFQQpGQQEjCBsEQCADEDZBCiMIQQFGDQUaCyMIRQRAIAdBAWohBwwCCwsLCyMIRQRAIAQPCwsjCEUEQAALAAshAiMJKAIAIAI2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAiAANgIAIAIgATYCBCACIAM2AgggAiAENgIMIAIgBjYCECACIAc2AhQgAiAINgIYIwkjCSgCAEEcajYCAEEAC5YCAQN/IwhBAkYEQCMJIwkoAgBBDGs2AgAjCSgCACICKAIAIQAgAigCBCEBIAIoAgghAgsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhAwsCQAJAIwhBASMIBH8gBQUgAEEIaygCACIEQSFHCxtFBEAgBEEdRg0BDAILIANBACMIG0UEQCAAIAEgAhCgAUEAIwhBAUYNAxoLIwhFBEAPCwsgA0EBRkEBIwgbBEAgACABIAIQoQFBASMIQQFGDQIaCyMIRQRADwsLIwhFBEAACw8LIQMjCSgCACADNgIAIwkjCSgCAEEEajYCACMJKAIAIgMgADYCACADIAE2AgQgAyACNgIIIwkjCSgCAEEMajYCAAuiCgEGfyMIQQJGBEAjCSMJKAIAQRBrNgIAIwkoAgAiACgCACECIAAoAgghBCAAKAIMIQUgACgCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEDCyMIRQRAQYzbASQBCyADQQAjCBtFBEBBARAUIQBBACMIQQFGDQEaIAAhAgsgA0EBRkEBIwgbBEAgAkEAEA5BASMIQQFGDQEaCyADQQJGQQEjCBsEQEEFEBQhAEECIwhBAUYNARogACECCyADQQNGQQEjCBsEQCACQQEQDkEDIwhBAUYNARoLIANBBEZBASMIGwRAQQpBAkEHQQAQGCEAQQQjCEEBRg0BGiAAIQILIwhFBEAgAigCBCEBCyADQQVGQQEjCBsEQEEBEBQhAEEFIwhBAUYNARogACEBCyMIRQRAIAIoAgQiBCABNgIACyADQQZGQQEjCBsEQEEKEBQhAEEGIwhBAUYNARogACEBCyMIRQRAIAIoAgQiBCABNgIECyADQQdGQQEjCBsEQEHkABAUIQBBByMIQQFGDQEaIAAhAQsjCEUEQCACKAIEIgQgATYCCAsgA0EIRkEBIwgbBEBB6AcQFCEAQQgjCEEBRg0BGiAAIQELIwhFBEAgAigCBCIEIAE2AgwLIANBCUZBASMIGwRAQZDOABAUIQBBCSMIQQFGDQEaIAAhAQsjCEUEQCACKAIEIgQgATYCEAsgA0EKRkEBIwgbBEBBoI0GEBUhAEEKIwhBAUYNARogACEBCyMIRQRAIAIoAgQiBCABNgIUCyADQQtGQQEjCBsEQEHAhD0QFSEAQQsjCEEBRg0BGiAAIQELIwhFBEAgAigCBCIEIAE2AhgLIANBDEZBASMIGwRAQYCt4gQQFSEAQQwjCEEBRg0BGiAAIQELIwhFBEAgAigCBCIEIAE2AhwLIANBDUZBASMIGwRAQYDC1y8QFSEAQQ0jCEEBRg0BGiAAIQELIwhFBEAgAigCBCIEIAE2AiALIANBDkZBASMIGwRAQYCU69wDEBUhAEEOIwhBAUYNARogACEBCyMIRQRAIAIoAgQiBCABNgIkIAIkAiMCKAIMIQILIANBD0ZBASMIGwRAQYABQQBBKEGAiQEQGCEAQQ8jCEEBRg0BGiAAIQELIwhFBEAgASgCDCECCyADQRBGQQEjCBsEQCACQSkQDSEAQRAjCEEBRg0BGiAAIQQLIwhFBEAgBCABKAIEIgEgAhAXIAQkAwsgAQJ/IANBEUZBASMIGwRAQQRBMhANIQBBESMIQQFGDQIaIAAhAgsgAkULIwgbIgEjCEECRnJBACADQRJGQQEjCBsbBEBBAEEzEA0hAEESIwhBAUYNARogACECCyADQRNGQQEjCBsEQBBjIQBBEyMIQQFGDQEaIAAhAQsjCEUEQCACIAE2AgAgAiQEIwQhAgsgA0EURkEBIwgbBEBBCEE1EA0hAEEUIwhBAUYNARogACEBCyMIRQRAIAFBADYCACABQQA2AgQgASACNgIAIAEkBQsgBQJ/IANBFUZBASMIGwRAQQBBPhANIQBBFSMIQQFGDQIaIAAhAgsgAgsjCBsiBUUjCEECRnJBACADQRZGQQEjCBsbBEBBAEEwEA0hAEEWIwhBAUYNARogACECCyMIRQRAIAIkBwsPCyEAIwkoAgAgADYCACMJIwkoAgBBBGo2AgAjCSgCACIAIAI2AgAgACABNgIEIAAgBDYCCCAAIAU2AgwjCSMJKAIAQRBqNgIAC6cEAQZ/IwhBAkYEQCMJIwkoAgBBHGs2AgAjCSgCACIDKAIAIQAgAygCCCECIAMoAgwhBSADKAIQIQQgAygCFCEHIAMoAhghCCADKAIEIQELAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQYLA0AjCEUEQCAHIAAoAgxIIQULIAUjCEECRnIEQCAGQQAjCBtFBEAgACAHECUhA0EAIwhBAUYNAxogAyEECyMIRQRAIAQoAgAhBQsgBkEBRkEBIwgbBEAgASAFEB8hA0EBIwhBAUYNAxogAyEICyAGQQJGQQEjCBsEQBB/IQNBAiMIQQFGDQMaIAMhBQsjCEUEQCAFIAg2AgAgBCgCBCEEQQEkAAsgBkEDRkEBIwgbBEAgBBBiIQNBAyMIQQFGDQMaIAMhBAsgBkEERkEBIwgbBEAgBBB+IQNBBCMIQQFGDQMaIAMhBAsjCEUEQCAFIAQ2AgQLIAZBBUZBASMIGwRAIAgQiwEhA0EFIwhBAUYNAxogAyEECyMIRQRAIAUgBDYCCCAFQbCwATYCDAsgBkEGRkEBIwgbBEAgAiAFEDNBBiMIQQFGDQMaCyMIRQRAIAdBAWohBwwCCwsLDwshAyMJKAIAIAM2AgAjCSMJKAIAQQRqNgIAIwkoAgAiAyAANgIAIAMgATYCBCADIAI2AgggAyAFNgIMIAMgBDYCECADIAc2AhQgAyAINgIYIwkjCSgCAEEcajYCAAvzDAENfyMIQQJGBEAjCSMJKAIAQThrNgIAIwkoAgAiAygCACEAIAMoAgghAiADKAIMIQQgAygCECEFIAMoAhQhByADKAIYIQggAygCHCEJIAMoAiAhCiADKAIkIQwgAygCKCELIAMoAiwhDSADKAIwIQ4gAygCNCEPIAMoAgQhAQsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBgsDQCMIRQRAIAwgACgCDEghBAsgBCMIQQJGcgRAIAZBACMIG0UEQCAAIAwQJSEDQQAjCEEBRg0DGiADIQkLIwhFBEAgCSgCACEECyAGQQFGQQEjCBsEQCABIAQQHyEDQQEjCEEBRg0DGiADIQoLIAZBAkZBASMIGwRAEH8hA0ECIwhBAUYNAxogAyEFCyMIRQRAIAUgCjYCACAFQQA2AgQLIAZBA0ZBASMIGwRAIAoQiwEhA0EDIwhBAUYNAxogAyEECyMIRQRAIAUgBDYCCCAFQcDBATYCDAsgBkEERkEBIwgbBEAgAiAFEDNBBCMIQQFGDQMaCyMIRQRAIAkoAgAhBAsgBkEFRkEBIwgbBEAgASAEEB8hA0EFIwhBAUYNAxogAyEECyAGQQZGQQEjCBsEQCAEQcCZARAfIQNBBiMIQQFGDQMaIAMhCwsjCEUEQCALIQogCSgCCCEFCyAFIwhBAkZyQQAgBkEHRkEBIwgbGwRAIAUgCiACELEBQQcjCEEBRg0DGgsjCEUEQCAJKAIEIQ0LIA0jCEECRnIEQCAKQQAjCBshCgNAIwhFBEAgCiANKAIMSCEECyAEIwhBAkZyBEAgBkEIRkEBIwgbBEAgDSAKECUhA0EIIwhBAUYNBhogAyEJCyMIRQRAIAkoAgAhBAsgBkEJRkEBIwgbBEAgCyAEEB8hA0EJIwhBAUYNBhogAyEFCyAGQQpGQQEjCBsEQBB/IQNBCiMIQQFGDQYaIAMhCAsjCEUEQCAIIAU2AgAgCEEANgIECyAGQQtGQQEjCBsEQCAFEIsBIQNBCyMIQQFGDQYaIAMhBAsjCEUEQCAIIAQ2AgggCEHAwQE2AgwLIAZBDEZBASMIGwRAIAIgCBAzQQwjCEEBRg0GGgsjCEUEQCAJKAIAIQQLIAZBDUZBASMIGwRAIAsgBBAfIQNBDSMIQQFGDQYaIAMhBAsgBkEORkEBIwgbBEAgBEHAmQEQHyEDQQ4jCEEBRg0GGiADIQQLIwhFBEAgCSgCCCEIIAQhBQsgCCMIQQJGckEAIAZBD0ZBASMIGxsEQCAIIAUgAhCxAUEPIwhBAUYNBhoLIwhFBEAgCSgCBCEOCyAOIwhBAkZyBEAgCUEAIwgbIQkDQCMIRQRAIAkgDigCDEghBwsgByMIQQJGcgRAIAZBEEZBASMIGwRAIA4gCRAlIQNBECMIQQFGDQkaIAMhBQsjCEUEQCAFKAIAIQcLIAZBEUZBASMIGwRAIAQgBxAfIQNBESMIQQFGDQkaIAMhCAsgBkESRkEBIwgbBEAQfyEDQRIjCEEBRg0JGiADIQcLIwhFBEAgByAINgIAIAdBADYCBAsgBkETRkEBIwgbBEAgCBCLASEDQRMjCEEBRg0JGiADIQgLIwhFBEAgByAINgIIIAdBwMEBNgIMCyAGQRRGQQEjCBsEQCACIAcQM0EUIwhBAUYNCRoLIwhFBEAgBSgCACEHCyAGQRVGQQEjCBsEQCAEIAcQHyEDQRUjCEEBRg0JGiADIQcLIAZBFkZBASMIGwRAIAdBwJkBEB8hA0EWIwhBAUYNCRogAyEHCyMIRQRAIAUoAgghDyAHIQgLIA8jCEECRnJBACAGQRdGQQEjCBsbBEAgDyAIIAIQsQFBFyMIQQFGDQkaCyMIRQRAIAUoAgQhBQsgBSMIQQJGckEAIAZBGEZBASMIGxsEQCAFIAcgAhCzAUEYIwhBAUYNCRoLIwhFBEAgCUEBaiEJDAILCwsLIwhFBEAgCkEBaiEKDAILCwsLIwhFBEAgDEEBaiEMDAILCwsPCyEDIwkoAgAgAzYCACMJIwkoAgBBBGo2AgAjCSgCACIDIAA2AgAgAyABNgIEIAMgAjYCCCADIAQ2AgwgAyAFNgIQIAMgBzYCFCADIAg2AhggAyAJNgIcIAMgCjYCICADIAw2AiQgAyALNgIoIAMgDTYCLCADIA42AjAgAyAPNgI0IwkjCSgCAEE4ajYCAAvzDAENfyMIQQJGBEAjCSMJKAIAQThrNgIAIwkoAgAiAygCACEAIAMoAgghAiADKAIMIQQgAygCECEGIAMoAhQhByADKAIYIQggAygCHCEJIAMoAiAhCiADKAIkIQsgAygCKCENIAMoAiwhDCADKAIwIQ4gAygCNCEPIAMoAgQhAQsCfyMIQQJGBEAjCSMJKAIAQQRrNgIAIwkoAgAoAgAhBQsDQCMIRQRAIA8gACgCDEghBAsgBCMIQQJGcgRAIAVBACMIG0UEQCAAIA8QJSEDQQAjCEEBRg0DGiADIQYLIwhFBEAgBigCACEECyAFQQFGQQEjCBsEQCABIAQQHyEDQQEjCEEBRg0DGiADIQgLIAVBAkZBASMIGwRAEH8hA0ECIwhBAUYNAxogAyEKCyMIRQRAIAogCDYCACAKQQA2AgQLIAVBA0ZBASMIGwRAIAgQiwEhA0EDIwhBAUYNAxogAyEECyMIRQRAIAogBDYCCCAKQcDBATYCDAsgBUEERkEBIwgbBEAgAiAKEDNBBCMIQQFGDQMaCyMIRQRAIAYoAgAhBAsgBUEFRkEBIwgbBEAgASAEEB8hA0EFIwhBAUYNAxogAyEECyAFQQZGQQEjCBsEQCAEQcCZARAfIQNBBiMIQQFGDQMaIAMhCgsjCEUEQCAGKAIIIQ0gCiEICyANIwhBAkZyQQAgBUEHRkEBIwgbGwRAIA0gCCACELEBQQcjCEEBRg0DGgsjCEUEQCAGKAIEIQ0LIA0jCEECRnIEQCAIQQAjCBshCANAIwhFBEAgCCANKAIMSCEECyAEIwhBAkZyBEAgBUEIRkEBIwgbBEAgDSAIECUhA0EIIwhBAUYNBhogAyEGCyMIRQRAIAYoAgAhBAsgBUEJRkEBIwgbBEAgCiAEEB8hA0EJIwhBAUYNBhogAyEMCyAFQQpGQQEjCBsEQBB/IQNBCiMIQQFGDQYaIAMhBwsjCEUEQCAHIAw2AgAgB0EANgIECyAFQQtGQQEjCBsEQCAMEIsBIQNBCyMIQQFGDQYaIAMhBAsjCEUEQCAHIAQ2AgggB0HAwQE2AgwLIAVBDEZBASMIGwRAIAIgBxAzQQwjCEEBRg0GGgsjCEUEQCAGKAIAIQQLIAVBDUZBASMIGwRAIAogBBAfIQNBDSMIQQFGDQYaIAMhBAsgBUEORkEBIwgbBEAgBEHAmQEQHyEDQQ4jCEEBRg0GGiADIQwLIwhFBEAgBigCCCEJIAwhBwsgCSMIQQJGckEAIAVBD0ZBASMIGxsEQCAJIAcgAhCxAUEPIwhBAUYNBhoLIwhFBEAgBigCBCEHCyAHIwhBAkZyBEAgBkEAIwgbIQYDQCMIRQRAIAYgBygCDEghBAsgBCMIQQJGcgRAIAVBEEZBASMIGwRAIAcgBhAlIQNBECMIQQFGDQkaIAMhDgsjCEUEQCAOKAIAIQQLIAVBEUZBASMIGwRAIAwgBBAfIQNBESMIQQFGDQkaIAMhCQsgBUESRkEBIwgbBEAQfyEDQRIjCEEBRg0JGiADIQsLIwhFBEAgCyAJNgIAIAtBADYCBAsgBUETRkEBIwgbBEAgCRCLASEDQRMjCEEBRg0JGiADIQQLIwhFBEAgCyAENgIIIAtBwMEBNgIMCyAFQRRGQQEjCBsEQCACIAsQM0EUIwhBAUYNCRoLIwhFBEAgDigCACEECyAFQRVGQQEjCBsEQCAMIAQQHyEDQRUjCEEBRg0JGiADIQQLIAVBFkZBASMIGwRAIARBwJkBEB8hA0EWIwhBAUYNCRogAyELCyMIRQRAIA4oAgghCSALIQQLIAkjCEECRnJBACAFQRdGQQEjCBsbBEAgCSAEIAIQsQFBFyMIQQFGDQkaCyMIRQRAIA4oAgQhCQsgCSMIQQJGckEAIAVBGEZBASMIGxsEQCAJIAsgAhC0AUEYIwhBAUYNCRoLIwhFBEAgBkEBaiEGDAILCwsLIwhFBEAgCEEBaiEIDAILCwsLIwhFBEAgD0EBaiEPDAILCwsPCyEDIwkoAgAgAzYCACMJIwkoAgBBBGo2AgAjCSgCACIDIAA2AgAgAyABNgIEIAMgAjYCCCADIAQ2AgwgAyAGNgIQIAMgBzYCFCADIAg2AhggAyAJNgIcIAMgCjYCICADIAs2AiQgAyANNgIoIAMgDDYCLCADIA42AjAgAyAPNgI0IwkjCSgCAEE4ajYCAAukDQEPfyMIQQJGBEAjCSMJKAIAQUBqNgIAIwkoAgAiAygCACEAIAMoAgghAiADKAIMIQQgAygCECEGIAMoAhQhByADKAIYIQggAygCHCEJIAMoAiAhCiADKAIkIQsgAygCKCEMIAMoAiwhDSADKAIwIQ4gAygCNCEPIAMoAjghECADKAI8IREgAygCBCEBCwJ/IwhBAkYEQCMJIwkoAgBBBGs2AgAjCSgCACgCACEFCwNAIwhFBEAgDyAAKAIMSCEECyAEIwhBAkZyBEAgBUEAIwgbRQRAIAAgDxAlIQNBACMIQQFGDQMaIAMhBAsjCEUEQCAEKAIAIQcLIAVBAUZBASMIGwRAIAEgBxAfIQNBASMIQQFGDQMaIAMhCAsgBUECRkEBIwgbBEAQfyEDQQIjCEEBRg0DGiADIQcLIwhFBEAgByAINgIAIAdBADYCBAsgBUEDRkEBIwgbBEAgCBCLASEDQQMjCEEBRg0DGiADIQgLIwhFBEAgByAINgIIIAdBwMEBNgIMCyAFQQRGQQEjCBsEQCACIAcQM0EEIwhBAUYNAxoLIwhFBEAgBCgCACEHCyAFQQVGQQEjCBsEQCABIAcQHyEDQQUjCEEBRg0DGiADIQcLIAVBBkZBASMIGwRAIAdBwJkBEB8hA0EGIwhBAUYNAxogAyEICyMIRQRAIAQoAgghDCAIIQcLIAwjCEECRnJBACAFQQdGQQEjCBsbBEAgDCAHIAIQsQFBByMIQQFGDQMaCyMIRQRAIAIhByAEKAIEIQQLIAQjCEECRnIEQCMIRQRAIAghEEEAIQ4gBCEMCwNAIwhFBEAgDiAMKAIMSCEECyAEIwhBAkZyBEAgBUEIRkEBIwgbBEAgDCAOECUhA0EIIwhBAUYNBhogAyEKCyMIRQRAIAooAgAhBAsgBUEJRkEBIwgbBEAgECAEEB8hA0EJIwhBAUYNBhogAyEICyAFQQpGQQEjCBsEQBB/IQNBCiMIQQFGDQYaIAMhBAsjCEUEQCAEIAg2AgAgBEEANgIECyAFQQtGQQEjCBsEQCAIEIsBIQNBCyMIQQFGDQYaIAMhCAsjCEUEQCAEIAg2AgggBEHAwQE2AgwLIAVBDEZBASMIGwRAIAcgBBAzQQwjCEEBRg0GGgsjCEUEQCAKKAIAIQQLIAVBDUZBASMIGwRAIBAgBBAfIQNBDSMIQQFGDQYaIAMhBAsgBUEORkEBIwgbBEAgBEHAmQEQHyEDQQ4jCEEBRg0GGiADIQgLIwhFBEAgCigCCCEJIAghBAsgCSMIQQJGckEAIAVBD0ZBASMIGxsEQCAJIAQgBxCxAUEPIwhBAUYNBhoLIwhFBEAgCigCBCEKIAchBAsgCiMIQQJGcgRAIA1BACMIGyENA0AjCEUEQCANIAooAgxIIQYLIAYjCEECRnIEQCAFQRBGQQEjCBsEQCAKIA0QJSEDQRAjCEEBRg0JGiADIQsLIwhFBEAgCygCACEGCyAFQRFGQQEjCBsEQCAIIAYQHyEDQREjCEEBRg0JGiADIQkLIAVBEkZBASMIGwRAEH8hA0ESIwhBAUYNCRogAyEGCyMIRQRAIAYgCTYCACAGQQA2AgQLIAVBE0ZBASMIGwRAIAkQiwEhA0ETIwhBAUYNCRogAyEJCyMIRQRAIAYgCTYCCCAGQcDBATYCDAsgBUEURkEBIwgbBEAgBCAGEDNBFCMIQQFGDQkaCyMIRQRAIAsoAgAhBgsgBUEVRkEBIwgbBEAgCCAGEB8hA0EVIwhBAUYNCRogAyEGCyAFQRZGQQEjCBsEQCAGQcCZARAfIQNBFiMIQQFGDQkaIAMhCQsjCEUEQCALKAIIIREgCSEGCyARIwhBAkZyQQAgBUEXRkEBIwgbGwRAIBEgBiAEELEBQRcjCEEBRg0JGgsjCEUEQCALKAIEIQsgBCEGCyALIwhBAkZyQQAgBUEYRkEBIwgbGwRAIAsgCSAGELUBQRgjCEEBRg0JGgsjCEUEQCANQQFqIQ0MAgsLCwsjCEUEQCAOQQFqIQ4MAgsLCwsjCEUEQCAPQQFqIQ8MAgsLCw8LIQMjCSgCACADNgIAIwkjCSgCAEEEajYCACMJKAIAIgMgADYCACADIAE2AgQgAyACNgIIIAMgBDYCDCADIAY2AhAgAyAHNgIUIAMgCDYCGCADIAk2AhwgAyAKNgIgIAMgCzYCJCADIAw2AiggAyANNgIsIAMgDjYCMCADIA82AjQgAyAQNgI4IAMgETYCPCMJIwkoAgBBQGs2AgALoAUBB38jCEECRgRAIwkjCSgCAEEgazYCACMJKAIAIgMoAgAhACADKAIIIQIgAygCDCEEIAMoAhAhBSADKAIUIQcgAygCGCEIIAMoAhwhCSADKAIEIQELAn8jCEECRgRAIwkjCSgCAEEEazYCACMJKAIAKAIAIQYLA0AjCEUEQCAIIAAoAgxIIQQLIAQjCEECRnIEQCAGQQAjCBtFBEAgACAIECUhA0EAIwhBAUYNAxogAyEHCyMIRQRAIAcoAgAhBAsgBkEBRkEBIwgbBEAgASAEEB8hA0EBIwhBAUYNAxogAyEECyAGQQJGQQEjCBsEQBB/IQNBAiMIQQFGDQMaIAMhBQsjCEUEQCAFIAQ2AgAgBUEANgIECyAGQQNGQQEjCBsEQCAEEIsBIQNBAyMIQQFGDQMaIAMhBAsjCEUEQCAFIAQ2AgggBUHAwQE2AgwLIAZBBEZBASMIGwRAIAIgBRAzQQQjCEEBRg0DGgsjCEUEQCAHKAIAIQQLIAZBBUZBASMIGwRAIAEgBBAfIQNBBSMIQQFGDQMaIAMhBAsgBkEGRkEBIwgbBEAgBEHAmQEQHyEDQQYjCEEBRg0DGiADIQULIwhFBEAgBygCCCEJIAUhBAsgCSMIQQJGckEAIAZBB0ZBASMIGxsEQCAJIAQgAhCxAUEHIwhBAUYNAxoLIwhFBEAgBygCBCEECyAGQQhGQQEjCBsEQCAEIAUgAhCMAUEIIwhBAUYNAxoLIwhFBEAgCEEBaiEIDAILCwsPCyEDIwkoAgAgAzYCACMJIwkoAgBBBGo2AgAjCSgCACIDIAA2AgAgAyABNgIEIAMgAjYCCCADIAQ2AgwgAyAFNgIQIAMgBzYCFCADIAg2AhggAyAJNgIcIwkjCSgCAEEgajYCAAsZAEEBJAggACQJIwkoAgAjCSgCBEsEQAALCxUAQQAkCCMJKAIAIwkoAgRLBEAACwsZAEECJAggACQJIwkoAgAjCSgCBEsEQAALCxUAQQAkCCMJKAIAIwkoAgRLBEAACwsEACMICwvJ4wG8AgBBjAgLIBwAAAAAAAAAAAAAAAEAAAAAAAAAAAAAAAAAAAAAAAAAAEGsCAtAPAAAAAAAAAAAAAAAAQAAACQAAABVAG4AcABhAGkAcgBlAGQAIABzAHUAcgByAG8AZwBhAHQAZQAAAAAAAAAAAABB7AgLMCwAAAAAAAAAAAAAAAEAAAAcAAAAfgBsAGkAYgAvAHMAdAByAGkAbgBnAC4AdABzAABBnAkLQDwAAAAAAAAAAAAAAAEAAAAoAAAAQQBsAGwAbwBjAGEAdABpAG8AbgAgAHQAbwBvACAAbABhAHIAZwBlAAAAAAAAQdwJC0A8AAAAAAAAAAAAAAABAAAAHgAAAH4AbABpAGIALwByAHQALwBzAHQAdQBiAC4AdABzAAAAAAAAAAAAAAAAAAAAAEGcCgswLAAAAAAAAAAAAAAAAQAAABwAAABJAG4AdgBhAGwAaQBkACAAbABlAG4AZwB0AGgAAEHMCgtAPAAAAAAAAAAAAAAAAQAAACYAAAB+AGwAaQBiAC8AYQByAHIAYQB5AGIAdQBmAGYAZQByAC4AdABzAAAAAAAAAABBjAsLQDwAAAAAAAAAAAAAAAEAAAAkAAAASQBuAGQAZQB4ACAAbwB1AHQAIABvAGYAIAByAGEAbgBnAGUAAAAAAAAAAAAAQcwLC0A8AAAAAAAAAAAAAAABAAAAJAAAAH4AbABpAGIALwB0AHkAcABlAGQAYQByAHIAYQB5AC4AdABzAAAAAAAAAAAAAEGMDAtAPAAAAAAAAAAAAAAAAAAAACgAAAABAAAACgAAAGQAAADoAwAAECcAAKCGAQBAQg8AgJaYAADh9QUAypo7AAAAAABBzAwLMCwAAAAAAAAAAAAAAAYAAAAQAAAAIAYAACAGAAAoAAAACgAAAAAAAAAAAAAAAAAAAABB/AwLYFwAAAAAAAAAAAAAAAEAAAA+AAAAdwByAGEAcAA6AC8ALwBlAG4AcwAvAHcAcgBhAHAAcwAuAGUAdABoADoAaAB0AHQAcABAADEALgAxAC4AMAAAAAAAAAAAAAAAAAAAAABB3A0LIBwAAAAAAAAAAAAAAAEAAAAGAAAAYwBhAHQAAAAAAAAAAEH8DQsgHAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAQZwOC2BcAAAAAAAAAAAAAAABAAAARgAAAEQAZQBzAGUAcgBpAGEAbABpAHoAaQBuAGcAIABtAG8AZAB1AGwAZQAtAHQAeQBwAGUAOgAgAGMAYQB0ACAAQQByAGcAcwAAAAAAAAAAQfwOC0A8AAAAAAAAAAAAAAABAAAAKAAAAEQAYQB0AGEAVgBpAGUAdwAuAGMAbwBuAHMAdAByAHUAYwB0AG8AcgAAAAAAAEG8DwsgHAAAAAAAAAAAAAAAAQAAAAQAAAA6ACAAAAAAAAAAAAAAQdwPCzAsAAAAAAAAAAAAAAABAAAAHAAAAFsAYgB5AHQAZQBfAGwAZQBuAGcAdABoADoAIAAAQYwQC4ABfAAAAAAAAAAAAAAAAQAAAGQAAAB0AG8AUwB0AHIAaQBuAGcAKAApACAAcgBhAGQAaQB4ACAAYQByAGcAdQBtAGUAbgB0ACAAbQB1AHMAdAAgAGIAZQAgAGIAZQB0AHcAZQBlAG4AIAAyACAAYQBuAGQAIAAzADYAAAAAAAAAAAAAQYwRC0A8AAAAAAAAAAAAAAABAAAAJgAAAH4AbABpAGIALwB1AHQAaQBsAC8AbgB1AG0AYgBlAHIALgB0AHMAAAAAAAAAAEHMEQsgHAAAAAAAAAAAAAAAAQAAAAIAAAAwAAAAAAAAAAAAAAAAQewRC5ADMAAwADAAMQAwADIAMAAzADAANAAwADUAMAA2ADAANwAwADgAMAA5ADEAMAAxADEAMQAyADEAMwAxADQAMQA1ADEANgAxADcAMQA4ADEAOQAyADAAMgAxADIAMgAyADMAMgA0ADIANQAyADYAMgA3ADIAOAAyADkAMwAwADMAMQAzADIAMwAzADMANAAzADUAMwA2ADMANwAzADgAMwA5ADQAMAA0ADEANAAyADQAMwA0ADQANAA1ADQANgA0ADcANAA4ADQAOQA1ADAANQAxADUAMgA1ADMANQA0ADUANQA1ADYANQA3ADUAOAA1ADkANgAwADYAMQA2ADIANgAzADYANAA2ADUANgA2ADYANwA2ADgANgA5ADcAMAA3ADEANwAyADcAMwA3ADQANwA1ADcANgA3ADcANwA4ADcAOQA4ADAAOAAxADgAMgA4ADMAOAA0ADgANQA4ADYAOAA3ADgAOAA4ADkAOQAwADkAMQA5ADIAOQAzADkANAA5ADUAOQA2ADkANwA5ADgAOQA5AABB/BQLoAgcBAAAAAAAAAAAAAABAAAAAAQAADAAMAAwADEAMAAyADAAMwAwADQAMAA1ADAANgAwADcAMAA4ADAAOQAwAGEAMABiADAAYwAwAGQAMABlADAAZgAxADAAMQAxADEAMgAxADMAMQA0ADEANQAxADYAMQA3ADEAOAAxADkAMQBhADEAYgAxAGMAMQBkADEAZQAxAGYAMgAwADIAMQAyADIAMgAzADIANAAyADUAMgA2ADIANwAyADgAMgA5ADIAYQAyAGIAMgBjADIAZAAyAGUAMgBmADMAMAAzADEAMwAyADMAMwAzADQAMwA1ADMANgAzADcAMwA4ADMAOQAzAGEAMwBiADMAYwAzAGQAMwBlADMAZgA0ADAANAAxADQAMgA0ADMANAA0ADQANQA0ADYANAA3ADQAOAA0ADkANABhADQAYgA0AGMANABkADQAZQA0AGYANQAwADUAMQA1ADIANQAzADUANAA1ADUANQA2ADUANwA1ADgANQA5ADUAYQA1AGIANQBjADUAZAA1AGUANQBmADYAMAA2ADEANgAyADYAMwA2ADQANgA1ADYANgA2ADcANgA4ADYAOQA2AGEANgBiADYAYwA2AGQANgBlADYAZgA3ADAANwAxADcAMgA3ADMANwA0ADcANQA3ADYANwA3ADcAOAA3ADkANwBhADcAYgA3AGMANwBkADcAZQA3AGYAOAAwADgAMQA4ADIAOAAzADgANAA4ADUAOAA2ADgANwA4ADgAOAA5ADgAYQA4AGIAOABjADgAZAA4AGUAOABmADkAMAA5ADEAOQAyADkAMwA5ADQAOQA1ADkANgA5ADcAOQA4ADkAOQA5AGEAOQBiADkAYwA5AGQAOQBlADkAZgBhADAAYQAxAGEAMgBhADMAYQA0AGEANQBhADYAYQA3AGEAOABhADkAYQBhAGEAYgBhAGMAYQBkAGEAZQBhAGYAYgAwAGIAMQBiADIAYgAzAGIANABiADUAYgA2AGIANwBiADgAYgA5AGIAYQBiAGIAYgBjAGIAZABiAGUAYgBmAGMAMABjADEAYwAyAGMAMwBjADQAYwA1AGMANgBjADcAYwA4AGMAOQBjAGEAYwBiAGMAYwBjAGQAYwBlAGMAZgBkADAAZAAxAGQAMgBkADMAZAA0AGQANQBkADYAZAA3AGQAOABkADkAZABhAGQAYgBkAGMAZABkAGQAZQBkAGYAZQAwAGUAMQBlADIAZQAzAGUANABlADUAZQA2AGUANwBlADgAZQA5AGUAYQBlAGIAZQBjAGUAZABlAGUAZQBmAGYAMABmADEAZgAyAGYAMwBmADQAZgA1AGYANgBmADcAZgA4AGYAOQBmAGEAZgBiAGYAYwBmAGQAZgBlAGYAZgAAAAAAAAAAAAAAAAAAQZwdC2BcAAAAAAAAAAAAAAABAAAASAAAADAAMQAyADMANAA1ADYANwA4ADkAYQBiAGMAZABlAGYAZwBoAGkAagBrAGwAbQBuAG8AcABxAHIAcwB0AHUAdgB3AHgAeQB6AAAAAAAAQfwdC0A8AAAAAAAAAAAAAAABAAAAIAAAACAAQgBMAE8AQwBLAF8ATQBBAFgAUwBJAFoARQA6ACAAAAAAAAAAAAAAAAAAAEG8HgtAPAAAAAAAAAAAAAAAAQAAACYAAABiAHUAZgBmAGUAcgAuAGIAeQB0AGUATABlAG4AZwB0AGgAOgAgAAAAAAAAAABB/B4LIBwAAAAAAAAAAAAAAAEAAAACAAAAXQAAAAAAAAAAAAAAAEGcHwsgHAAAAAAAAAAAAAAAAQAAAAIAAAAKAAAAAAAAAAAAAAAAQbwfCyAcAAAAAAAAAAAAAAABAAAAAgAAACAAAAAAAAAAAAAAAABB3B8LMCwAAAAAAAAAAAAAAAEAAAASAAAAQwBvAG4AdABlAHgAdAA6ACAAAAAAAAAAAAAAAABBjCALQDwAAAAAAAAAAAAAAAEAAAAsAAAAYwBvAG4AdABlAHgAdAAgAHMAdABhAGMAawAgAGkAcwAgAGUAbQBwAHQAeQAAQcwgCzAsAAAAAAAAAAAAAAABAAAAGgAAAH4AbABpAGIALwBhAHIAcgBhAHkALgB0AHMAAAAAQfwgC4ABfAAAAAAAAAAAAAAAAQAAAF4AAABFAGwAZQBtAGUAbgB0ACAAdAB5AHAAZQAgAG0AdQBzAHQAIABiAGUAIABuAHUAbABsAGEAYgBsAGUAIABpAGYAIABhAHIAcgBhAHkAIABpAHMAIABoAG8AbABlAHkAAAAAAAAAAAAAAAAAAAAAQfwhCyAcAAAAAAAAAAAAAAABAAAACAAAACAAPgA+ACAAAAAAAABBnCILIBwAAAAAAAAAAAAAAAEAAAAGAAAAYQB0ACAAAAAAAAAAAEG8IgtwbAAAAAAAAAAAAAAAAQAAAFQAAAB+AGwAaQBiAC8AQABwAG8AbAB5AHcAcgBhAHAALwB3AGEAcwBtAC0AYQBzAC8AbQBzAGcAcABhAGMAawAvAEQAYQB0AGEAVgBpAGUAdwAuAHQAcwAAAAAAAAAAAABBrCMLMCwAAAAAAAAAAAAAAAEAAAASAAAAcABlAGUAawBVAGkAbgB0ADgAAAAAAAAAAAAAAABB3CMLMCwAAAAAAAAAAAAAAAEAAAASAAAAWwBsAGUAbgBnAHQAaAA6ACAAAAAAAAAAAAAAAABBjCQLMCwAAAAAAAAAAAAAAAEAAAAaAAAAIABiAHkAdABlAE8AZgBmAHMAZQB0ADoAIAAAAABBvCQLMCwAAAAAAAAAAAAAAAEAAAAaAAAAIABiAHkAdABlAEwAZQBuAGcAdABoADoAIAAAAABB7CQLcGwAAAAAAAAAAAAAAAEAAABOAAAAfgBsAGkAYgAvAEAAcABvAGwAeQB3AHIAYQBwAC8AdwBhAHMAbQAtAGEAcwAvAG0AcwBnAHAAYQBjAGsALwB1AHQAaQBsAHMALgB0AHMAAAAAAAAAAAAAAAAAAAAAQdwlCzAsAAAAAAAAAAAAAAABAAAADgAAAGQAaQBzAGMAYQByAGQAAAAAAAAAAAAAAAAAAAAAQYwmCzAsAAAAAAAAAAAAAAABAAAAEAAAAGcAZQB0AFUAaQBuAHQAOAAAAAAAAAAAAAAAAAAAQbwmCzAsAAAAAAAAAAAAAAABAAAAEgAAAGcAZQB0AFUAaQBuAHQAMQA2AAAAAAAAAAAAAAAAQewmCzAsAAAAAAAAAAAAAAABAAAAEgAAAGcAZQB0AFUAaQBuAHQAMwAyAAAAAAAAAAAAAAAAQZwnC2BcAAAAAAAAAAAAAAABAAAAQAAAAFAAcgBvAHAAZQByAHQAeQAgAG0AdQBzAHQAIABiAGUAIABvAGYAIAB0AHkAcABlACAAJwBtAGEAcAAnAC4AIAAAAAAAAAAAAAAAAAAAQfwnCzAsAAAAAAAAAAAAAAABAAAAGAAAAEYAbwB1AG4AZAAgACcAaQBuAHQAJwAuAAAAAAAAQawoC0A8AAAAAAAAAAAAAAABAAAAHgAAAEYAbwB1AG4AZAAgACcAcwB0AHIAaQBuAGcAJwAuAAAAAAAAAAAAAAAAAAAAAEHsKAswLAAAAAAAAAAAAAAAAQAAABwAAABGAG8AdQBuAGQAIAAnAGEAcgByAGEAeQAnAC4AAEGcKQswLAAAAAAAAAAAAAAAAQAAABgAAABGAG8AdQBuAGQAIAAnAG0AYQBwACcALgAAAAAAAEHMKQswLAAAAAAAAAAAAAAAAQAAABgAAABGAG8AdQBuAGQAIAAnAG4AaQBsACcALgAAAAAAAEH8KQswLAAAAAAAAAAAAAAAAQAAABoAAABGAG8AdQBuAGQAIAAnAGIAbwBvAGwAJwAuAAAAAEGsKgswLAAAAAAAAAAAAAAAAQAAABoAAABGAG8AdQBuAGQAIAAnAEIASQBOADgAJwAuAAAAAEHcKgswLAAAAAAAAAAAAAAAAQAAABwAAABGAG8AdQBuAGQAIAAnAEIASQBOADEANgAnAC4AAEGMKwswLAAAAAAAAAAAAAAAAQAAABwAAABGAG8AdQBuAGQAIAAnAEIASQBOADMAMgAnAC4AAEG8KwtAPAAAAAAAAAAAAAAAAQAAACAAAABGAG8AdQBuAGQAIAAnAGYAbABvAGEAdAAzADIAJwAuAAAAAAAAAAAAAAAAAABB/CsLQDwAAAAAAAAAAAAAAAEAAAAgAAAARgBvAHUAbgBkACAAJwBmAGwAbwBhAHQANgA0ACcALgAAAAAAAAAAAAAAAAAAQbwsCzAsAAAAAAAAAAAAAAABAAAAHAAAAEYAbwB1AG4AZAAgACcAdQBpAG4AdAA4ACcALgAAQewsC0A8AAAAAAAAAAAAAAABAAAAHgAAAEYAbwB1AG4AZAAgACcAdQBpAG4AdAAxADYAJwAuAAAAAAAAAAAAAAAAAAAAAEGsLQtAPAAAAAAAAAAAAAAAAQAAAB4AAABGAG8AdQBuAGQAIAAnAHUAaQBuAHQAMwAyACcALgAAAAAAAAAAAAAAAAAAAABB7C0LQDwAAAAAAAAAAAAAAAEAAAAeAAAARgBvAHUAbgBkACAAJwB1AGkAbgB0ADYANAAnAC4AAAAAAAAAAAAAAAAAAAAAQawuCzAsAAAAAAAAAAAAAAABAAAAGgAAAEYAbwB1AG4AZAAgACcAaQBuAHQAOAAnAC4AAAAAQdwuCzAsAAAAAAAAAAAAAAABAAAAHAAAAEYAbwB1AG4AZAAgACcAaQBuAHQAMQA2ACcALgAAQYwvCzAsAAAAAAAAAAAAAAABAAAAHAAAAEYAbwB1AG4AZAAgACcAaQBuAHQAMwAyACcALgAAQbwvCzAsAAAAAAAAAAAAAAABAAAAHAAAAEYAbwB1AG4AZAAgACcAaQBuAHQANgA0ACcALgAAQewvC0A8AAAAAAAAAAAAAAABAAAAIAAAAEYAbwB1AG4AZAAgACcARgBJAFgARQBYAFQAMQAnAC4AAAAAAAAAAAAAAAAAAEGsMAtAPAAAAAAAAAAAAAAAAQAAACAAAABGAG8AdQBuAGQAIAAnAEYASQBYAEUAWABUADIAJwAuAAAAAAAAAAAAAAAAAABB7DALQDwAAAAAAAAAAAAAAAEAAAAgAAAARgBvAHUAbgBkACAAJwBGAEkAWABFAFgAVAA0ACcALgAAAAAAAAAAAAAAAAAAQawxC0A8AAAAAAAAAAAAAAABAAAAIAAAAEYAbwB1AG4AZAAgACcARgBJAFgARQBYAFQAOAAnAC4AAAAAAAAAAAAAAAAAAEHsMQtAPAAAAAAAAAAAAAAAAQAAACIAAABGAG8AdQBuAGQAIAAnAEYASQBYAEUAWABUADEANgAnAC4AAAAAAAAAAAAAAABBrDILYFwAAAAAAAAAAAAAAAEAAABMAAAAaQBuAHYAYQBsAGkAZAAgAHAAcgBlAGYAaQB4ACwAIABiAGEAZAAgAGUAbgBjAG8AZABpAG4AZwAgAGYAbwByACAAdgBhAGwAOgAgAABBjDMLcGwAAAAAAAAAAAAAAAEAAABaAAAAfgBsAGkAYgAvAEAAcABvAGwAeQB3AHIAYQBwAC8AdwBhAHMAbQAtAGEAcwAvAG0AcwBnAHAAYQBjAGsALwBSAGUAYQBkAEQAZQBjAG8AZABlAHIALgB0AHMAAAAAQfwzC2BcAAAAAAAAAAAAAAABAAAARgAAAFAAcgBvAHAAZQByAHQAeQAgAG0AdQBzAHQAIABiAGUAIABvAGYAIAB0AHkAcABlACAAJwBzAHQAcgBpAG4AZwAnAC4AIAAAAAAAAAAAQdw0CzAsAAAAAAAAAAAAAAABAAAAEAAAAGcAZQB0AEIAeQB0AGUAcwAAAAAAAAAAAAAAAAAAQYw1CzAsAAAAAAAAAAAAAAABAAAADgAAAHUAbgBrAG4AbwB3AG4AAAAAAAAAAAAAAAAAAAAAQbw1C1BMAAAAAAAAAAAAAAABAAAANgAAAHMAZQBhAHIAYwBoAGkAbgBnACAAZgBvAHIAIABwAHIAbwBwAGUAcgB0AHkAIAB0AHkAcABlAAAAAAAAAABBjDYLIBwAAAAAAAAAAAAAAAEAAAAGAAAAYwBpAGQAAAAAAAAAAEGsNgsgHAAAAAAAAAAAAAAAAQAAAAwAAABzAHQAcgBpAG4AZwAAQcw2C1BMAAAAAAAAAAAAAAABAAAAOAAAAHQAeQBwAGUAIABmAG8AdQBuAGQALAAgAHIAZQBhAGQAaQBuAGcAIABwAHIAbwBwAGUAcgB0AHkAAAAAAABBnDcLsAGsAAAAAAAAAAAAAAABAAAAkAAAAE4AdQBsAGwAIABwAG8AaQBuAHQAZQByACAAZQB4AGMAZQBwAHQAaQBvAG4AOgAgAHQAcgBpAGUAZAAgAHQAbwAgAHAAbwBwACAAYQBuACAAaQB0AGUAbQAgAGYAcgBvAG0AIABhAG4AIABlAG0AcAB0AHkAIABDAG8AbgB0AGUAeAB0ACAAcwB0AGEAYwBrAAAAAAAAAAAAAAAAAABBzDgLcGwAAAAAAAAAAAAAAAEAAABOAAAAfgBsAGkAYgAvAEAAcABvAGwAeQB3AHIAYQBwAC8AdwBhAHMAbQAtAGEAcwAvAGQAZQBiAHUAZwAvAEMAbwBuAHQAZQB4AHQALgB0AHMAAAAAAAAAAAAAAAAAAAAAQbw5CzAsAAAAAAAAAAAAAAABAAAAHAAAAEEAcgByAGEAeQAgAGkAcwAgAGUAbQBwAHQAeQAAQew5CzAsAAAAAAAAAAAAAAABAAAAGAAAAGkAcABmAHMAUAByAG8AdgBpAGQAZQByAAAAAAAAQZw6CzAsAAAAAAAAAAAAAAABAAAADgAAAHQAaQBtAGUAbwB1AHQAAAAAAAAAAAAAAAAAAAAAQcw6C0A8AAAAAAAAAAAAAAABAAAAHgAAAEIAbwB4ADwAdQAzADIAPgAgAHwAIABuAHUAbABsAAAAAAAAAAAAAAAAAAAAAEGMOwtgXAAAAAAAAAAAAAAAAQAAAEoAAAB1AG4AcwBpAGcAbgBlAGQAIABpAG4AdABlAGcAZQByACAAYwBhAG4AbgBvAHQAIABiAGUAIABuAGUAZwBhAHQAaQB2AGUALgAgAAAAAEHsOwswLAAAAAAAAAAAAAAAAQAAABIAAABnAGUAdABVAGkAbgB0ADYANAAAAAAAAAAAAAAAAEGcPAswLAAAAAAAAAAAAAAAAQAAAA4AAABnAGUAdABJAG4AdAA4AAAAAAAAAAAAAAAAAAAAAEHMPAswLAAAAAAAAAAAAAAAAQAAABAAAABnAGUAdABJAG4AdAAxADYAAAAAAAAAAAAAAAAAAEH8PAswLAAAAAAAAAAAAAAAAQAAABAAAABnAGUAdABJAG4AdAAzADIAAAAAAAAAAAAAAAAAAEGsPQswLAAAAAAAAAAAAAAAAQAAABAAAABnAGUAdABJAG4AdAA2ADQAAAAAAAAAAAAAAAAAAEHcPQtgXAAAAAAAAAAAAAAAAQAAAEIAAABQAHIAbwBwAGUAcgB0AHkAIABtAHUAcwB0ACAAYgBlACAAbwBmACAAdAB5AHAAZQAgACcAdQBpAG4AdAAnAC4AIAAAAAAAAAAAAAAAAEG8PgtgXAAAAAAAAAAAAAAAAQAAAEYAAAB1AG4AcwBpAGcAbgBlAGQAIABpAG4AdABlAGcAZQByACAAbwB2AGUAcgBmAGwAbwB3ADoAIAB2AGEAbAB1AGUAIAA9ACAAAAAAAAAAAEGcPwswLAAAAAAAAAAAAAAAAQAAABYAAAA7ACAAYgBpAHQAcwAgAD0AIAAzADIAAAAAAAAAAEHMPwswLAAAAAAAAAAAAAAAAQAAABQAAABjAGEAdABPAHAAdABpAG8AbgBzAAAAAAAAAAAAAEH8PwtQTAAAAAAAAAAAAAAAAQAAAC4AAABUAHkAcABlAHMALgBDAGEAdABPAHAAdABpAG8AbgBzACAAfAAgAG4AdQBsAGwAAAAAAAAAAAAAAAAAAAAAQczAAAsgHAAAAAAAAAAAAAAAAQAAAAwAAABvAGYAZgBzAGUAdAAAQezAAAtAPAAAAAAAAAAAAAAAAQAAAB4AAABCAG8AeAA8AGkAMwAyAD4AIAB8ACAAbgB1AGwAbAAAAAAAAAAAAAAAAAAAAABBrMEACyAcAAAAAAAAAAAAAAABAAAADAAAAGwAZQBuAGcAdABoAABBzMEAC3BsAAAAAAAAAAAAAAABAAAAUAAAAE0AaQBzAHMAaQBuAGcAIAByAGUAcQB1AGkAcgBlAGQAIABhAHIAZwB1AG0AZQBuAHQAOgAgACcAYwBpAGQAOgAgAFMAdAByAGkAbgBnACcAAAAAAAAAAAAAAAAAAEG8wgALYFwAAAAAAAAAAAAAAAEAAABAAAAAcwByAGMALwB3AHIAYQBwAC8ATQBvAGQAdQBsAGUALwBzAGUAcgBpAGEAbABpAHoAYQB0AGkAbwBuAC4AdABzAAAAAAAAAAAAAAAAAABBnMMAC4ABfAAAAAAAAAAAAAAAAQAAAGIAAABNAGkAcwBzAGkAbgBnACAAcgBlAHEAdQBpAHIAZQBkACAAYQByAGcAdQBtAGUAbgB0ADoAIAAnAGkAcABmAHMAUAByAG8AdgBpAGQAZQByADoAIABTAHQAcgBpAG4AZwAnAAAAAAAAAAAAAAAAQZzEAAsgHAAAAAAAAAAAAAAAAQAAAAYAAABhAHIAZwAAAAAAAAAAQbzEAAtAPAAAAAAAAAAAAAAAAQAAAB4AAAB1AG4AZQB4AHAAZQBjAHQAZQBkACAAbgB1AGwAbAAAAAAAAAAAAAAAAAAAAABB/MQACzAsAAAAAAAAAAAAAAABAAAAGAAAAHMAcgBjAC8AaQBuAGQAZQB4AC4AdABzAAAAAAAAQazFAAswLAAAAAAAAAAAAAAAAQAAABYAAAAvAGEAcABpAC8AdgAwAC8AYwBhAHQAAAAAAAAAAEHcxQALgAF8AAAAAAAAAAAAAAABAAAAZgAAAFMAZQByAGkAYQBsAGkAegBpAG4AZwAgACgAcwBpAHoAaQBuAGcAKQAgAGkAbQBwAG8AcgB0AGUAZAAgAG0AbwBkAHUAbABlAC0AdAB5AHAAZQA6ACAAZwBlAHQAIABBAHIAZwBzAAAAAAAAAABB3MYACyAcAAAAAAAAAAAAAAABAAAABgAAAHUAcgBsAAAAAAAAAABB/MYAC0A8AAAAAAAAAAAAAAABAAAAIAAAAHcAcgBpAHQAaQBuAGcAIABwAHIAbwBwAGUAcgB0AHkAAAAAAAAAAAAAAAAAAEG8xwALMCwAAAAAAAAAAAAAAAEAAAAOAAAAcgBlAHEAdQBlAHMAdAAAAAAAAAAAAAAAAAAAAABB7McAC1BMAAAAAAAAAAAAAAABAAAAMgAAAFQAeQBwAGUAcwAuAEgAdAB0AHAAXwBSAGUAcQB1AGUAcwB0ACAAfAAgAG4AdQBsAGwAAAAAAAAAAAAAAABBvMgAC3BsAAAAAAAAAAAAAAABAAAAXAAAAHMAcgBjAC8AdwByAGEAcAAvAGkAbQBwAG8AcgB0AGUAZAAvAEgAdAB0AHAAXwBNAG8AZAB1AGwAZQAvAHMAZQByAGkAYQBsAGkAegBhAHQAaQBvAG4ALgB0AHMAAEGsyQALMCwAAAAAAAAAAAAAAAEAAAAOAAAAaABlAGEAZABlAHIAcwAAAAAAAAAAAAAAAAAAAABB3MkAC1BMAAAAAAAAAAAAAAABAAAANAAAAE0AYQBwADwAcwB0AHIAaQBuAGcALAAgAHMAdAByAGkAbgBnAD4AIAB8ACAAbgB1AGwAbAAAAAAAAAAAAABBrMoACyAcAAAAAAAAAAAAAAAfAAAACAAAAAEAAAAAAAAAAAAAAABBzMoACyAcAAAAAAAAAAAAAAAfAAAACAAAAAIAAAAAAAAAAAAAAABB7MoACzAsAAAAAAAAAAAAAAABAAAAEgAAAHUAcgBsAFAAYQByAGEAbQBzAAAAAAAAAAAAAAAAQZzLAAsgHAAAAAAAAAAAAAAAHwAAAAgAAAADAAAAAAAAAAAAAAAAQbzLAAsgHAAAAAAAAAAAAAAAHwAAAAgAAAAEAAAAAAAAAAAAAAAAQdzLAAswLAAAAAAAAAAAAAAAAQAAABgAAAByAGUAcwBwAG8AbgBzAGUAVAB5AHAAZQAAAAAAAEGMzAALUEwAAAAAAAAAAAAAAAEAAAAuAAAAVAB5AHAAZQBzAC4ASAB0AHQAcABfAFIAZQBzAHAAbwBuAHMAZQBUAHkAcABlAAAAAAAAAAAAAAAAAAAAAEHczAALIBwAAAAAAAAAAAAAAAEAAAAIAAAAYgBvAGQAeQAAAAAAAEH8zAALMCwAAAAAAAAAAAAAAAEAAAAaAAAAcwB0AHIAaQBuAGcAIAB8ACAAbgB1AGwAbAAAAABBrM0ACzAsAAAAAAAAAAAAAAABAAAAEAAAAGYAbwByAG0ARABhAHQAYQAAAAAAAAAAAAAAAAAAQdzNAAtgXAAAAAAAAAAAAAAAAQAAAEwAAABBAHIAcgBhAHkAPABUAHkAcABlAHMALgBIAHQAdABwAF8ARgBvAHIAbQBEAGEAdABhAEUAbgB0AHIAeQA+ACAAfAAgAG4AdQBsAGwAAEG8zgALIBwAAAAAAAAAAAAAAAEAAAAIAAAAbgBhAG0AZQAAAAAAAEHczgALIBwAAAAAAAAAAAAAAAEAAAAKAAAAdgBhAGwAdQBlAAAAAEH8zgALMCwAAAAAAAAAAAAAAAEAAAAQAAAAZgBpAGwAZQBOAGEAbQBlAAAAAAAAAAAAAAAAAABBrM8ACyAcAAAAAAAAAAAAAAABAAAACAAAAHQAeQBwAGUAAAAAAABBzM8ACyAcAAAAAAAAAAAAAAAgAAAACAAAAAUAAAAAAAAAAAAAAABB7M8AC4ABfAAAAAAAAAAAAAAAAQAAAGoAAABTAGUAcgBpAGEAbABpAHoAaQBuAGcAIAAoAGUAbgBjAG8AZABpAG4AZwApACAAaQBtAHAAbwByAHQAZQBkACAAbQBvAGQAdQBsAGUALQB0AHkAcABlADoAIABnAGUAdAAgAEEAcgBnAHMAAAAAQezQAAsgHAAAAAAAAAAAAAAAAQAAAAYAAABnAGUAdAAAAAAAAAAAQYzRAAtAPAAAAAAAAAAAAAAAAQAAACQAAABSAGUAcwB1AGwAdAA6ACAAVQBuAHcAcgBhAHAAIABFAHIAcgAAAAAAAAAAAABBzNEAC3BsAAAAAAAAAAAAAAABAAAAVgAAAH4AbABpAGIALwBAAHAAbwBsAHkAdwByAGEAcAAvAHcAYQBzAG0ALQBhAHMALwBjAG8AbgB0AGEAaQBuAGUAcgBzAC8AUgBlAHMAdQBsAHQALgB0AHMAAAAAAAAAAEG80gALQDwAAAAAAAAAAAAAAAEAAAAiAAAAUgBlAHMAdQBsAHQAOgAgAFUAbgB3AHIAYQBwACAATwBrAAAAAAAAAAAAAAAAQfzSAAsgHAAAAAAAAAAAAAAAAQAAAAQAAAA7ACAAAAAAAAAAAAAAQZzTAAtwbAAAAAAAAAAAAAAAAQAAAFwAAABEAGUAcwBlAHIAaQBhAGwAaQB6AGkAbgBnACAAaQBtAHAAbwByAHQAZQBkACAAbQBvAGQAdQBsAGUALQB0AHkAcABlADoAIABnAGUAdAAgAFIAZQBzAHUAbAB0AABBjNQAC1BMAAAAAAAAAAAAAAABAAAANAAAAFQAeQBwAGUAcwAuAEgAdAB0AHAAXwBSAGUAcwBwAG8AbgBzAGUAIAB8ACAAbgB1AGwAbAAAAAAAAAAAAABB3NQAC1BMAAAAAAAAAAAAAAABAAAALgAAAHIAZQBhAGQAaQBuAGcAIABmAHUAbgBjAHQAaQBvAG4AIABvAHUAdABwAHUAdAAAAAAAAAAAAAAAAAAAAABBrNUACyAcAAAAAAAAAAAAAAABAAAADAAAAHMAdABhAHQAdQBzAABBzNUACyAcAAAAAAAAAAAAAAABAAAABgAAAGkAMwAyAAAAAAAAAABB7NUACzAsAAAAAAAAAAAAAAABAAAAFAAAAHMAdABhAHQAdQBzAFQAZQB4AHQAAAAAAAAAAAAAQZzWAAsgHAAAAAAAAAAAAAAAJAAAAAgAAAAGAAAAAAAAAAAAAAAAQbzWAAsgHAAAAAAAAAAAAAAAJAAAAAgAAAAHAAAAAAAAAAAAAAAAQdzWAAtwbAAAAAAAAAAAAAAAAQAAAFAAAABNAGkAcwBzAGkAbgBnACAAcgBlAHEAdQBpAHIAZQBkACAAcAByAG8AcABlAHIAdAB5ADoAIAAnAHMAdABhAHQAdQBzADoAIABJAG4AdAAnAAAAAAAAAAAAAAAAAABBzNcAC4ABfAAAAAAAAAAAAAAAAQAAAGAAAABzAHIAYwAvAHcAcgBhAHAALwBpAG0AcABvAHIAdABlAGQALwBIAHQAdABwAF8AUgBlAHMAcABvAG4AcwBlAC8AcwBlAHIAaQBhAGwAaQB6AGEAdABpAG8AbgAuAHQAcwAAAAAAAAAAAAAAAAAAQczYAAuAAXwAAAAAAAAAAAAAAAEAAABeAAAATQBpAHMAcwBpAG4AZwAgAHIAZQBxAHUAaQByAGUAZAAgAHAAcgBvAHAAZQByAHQAeQA6ACAAJwBzAHQAYQB0AHUAcwBUAGUAeAB0ADoAIABTAHQAcgBpAG4AZwAnAAAAAAAAAAAAAAAAAAAAAEHM2QALMCwAAAAAAAAAAAAAAAEAAAAOAAAARQByAHIAbwByADoAIAAAAAAAAAAAAAAAAAAAAABB/NkAC9AGTAMAAAAAAAAAAAAAJQAAADADAADfAFMAUwAAAEkBvAJOAAAA8AFKAAwDAACQA5kDCAMBA7ADpQMIAwEDhwU1BVIFAACWHkgAMQMAAJceVAAIAwAAmB5XAAoDAACZHlkACgMAAJoeQQC+AgAAUB+lAxMDAABSH6UDEwMAA1QfpQMTAwEDVh+lAxMDQgOAHwgfmQMAAIEfCR+ZAwAAgh8KH5kDAACDHwsfmQMAAIQfDB+ZAwAAhR8NH5kDAACGHw4fmQMAAIcfDx+ZAwAAiB8IH5kDAACJHwkfmQMAAIofCh+ZAwAAix8LH5kDAACMHwwfmQMAAI0fDR+ZAwAAjh8OH5kDAACPHw8fmQMAAJAfKB+ZAwAAkR8pH5kDAACSHyofmQMAAJMfKx+ZAwAAlB8sH5kDAACVHy0fmQMAAJYfLh+ZAwAAlx8vH5kDAACYHygfmQMAAJkfKR+ZAwAAmh8qH5kDAACbHysfmQMAAJwfLB+ZAwAAnR8tH5kDAACeHy4fmQMAAJ8fLx+ZAwAAoB9oH5kDAAChH2kfmQMAAKIfah+ZAwAAox9rH5kDAACkH2wfmQMAAKUfbR+ZAwAAph9uH5kDAACnH28fmQMAAKgfaB+ZAwAAqR9pH5kDAACqH2ofmQMAAKsfax+ZAwAArB9sH5kDAACtH20fmQMAAK4fbh+ZAwAArx9vH5kDAACyH7ofmQMAALMfkQOZAwAAtB+GA5kDAAC2H5EDQgMAALcfkQNCA5kDvB+RA5kDAADCH8ofmQMAAMMflwOZAwAAxB+JA5kDAADGH5cDQgMAAMcflwNCA5kDzB+XA5kDAADSH5kDCAMAA9MfmQMIAwED1h+ZA0IDAADXH5kDCANCA+IfpQMIAwAD4x+lAwgDAQPkH6EDEwMAAOYfpQNCAwAA5x+lAwgDQgPyH/ofmQMAAPMfqQOZAwAA9B+PA5kDAAD2H6kDQgMAAPcfqQNCA5kD/B+pA5kDAAAA+0YARgAAAAH7RgBJAAAAAvtGAEwAAAAD+0YARgBJAAT7RgBGAEwABftTAFQAAAAG+1MAVAAAABP7RAVGBQAAFPtEBTUFAAAV+0QFOwUAABb7TgVGBQAAF/tEBT0FAAAAAAAAAAAAAAAAAAAAQczgAAuAAQABAgMEBQYHCAkKCwwNDg8QERITFBUWFxgZGhscHR4fICEiIyQlJicoKSorLC0uLzAxMjM0NTY3ODk6Ozw9Pj9AQUJDREVGR0hJSktMTU5PUFFSU1RVVldYWVpbXF1eX2BBQkNERUZHSElKS0xNTk9QUVJTVFVWV1hZWnt8fX5/AEHM4QAL6hQHCAkKCwwGBgYGBgYGBgYGDQYGDgYGBgYGBgYGDxAREgYTBgYGBgYGBgYGBhQVBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGFhcGBgYYBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYZBgYGBhoGBgYGBgYGGwYGBgYGBgYGBgYGHAYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYdBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYeBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACQrKysrKysrKwEAVFZWVlZWVlZWAAAAAAAAAAAAAAAAAAAAAAAAABgAAAArKysrKysrBysrW1ZWVlZWVlZKVlYFMVAxUDFQMVAxUDFQMVAxUCRQeTFQMVAxOFAxUDFQMVAxUDFQMVAxUE4xAk4NDU4DTgAkbgBOMSZuUU4kUE45FIEbHR1TMVAxUA0xUDFQMVAbUyRQMQJce1x7XHtce1x7FHlce1x7XC0rSQNIA3hcexQAlgoBKygGBgAqBioqKwe7tSseACsHKysrASsrKysrKysrKysrKysrKysrKysrKysrKysrKysrKysrASsrKysrKysrKysrKysrKysrKysrKysrKisrKysrKysrKysrKyvNRs0rACUrBwEGAVVWVlZWVlVWVgIkgYGBgYEVgYGBAAArALLRstGy0bLRAADNzAEA19fX19eDgYGBgYGBgYGBgaysrKysrKysrKwcAAAAAAAxUDFQMVAxUDFQMQIAADFQMVAxUDFQMVAxUDFQMVAxUE4xUDFQTjFQMVAxUDFQMVAxUDFQMQKHpoemh6aHpoemh6aHpoemKisrKysrKysrKysrKwAAAFRWVlZWVlZWVlZWVlYAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAVFZWVlZWVlZWVlZWVgwADCorKysrKysrKysrKysrByoBAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAqKysrKysrKysrKysrKysrKysrKysrKysrKytWVmyBFQArKysrKysrKysrKysrKysrKysrKysrKysrKysrKysrKysrKysrKysrKysHbANBKytWVlZWVlZWVlZWVlZWVixWKysrKysrKysrKysrKysrKysrKysrAQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAMbAAAAAAABgAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABiUGJQYlBiUGJQYlBiUGJQYlBiUGJQYlBiUGJQYlBiUGJQYlBiUGJQYlBiUGJQYlBiVWep4mBiUGJQYlBiUGJQYlBiUGJQYlBiUGJQYlBiUGJQYlBgErK09WViwrf1ZWOSsrVVZWKytPVlYsK39WVoE3dVt7XCsrT1ZWAqwEAAA5KytVVlYrK09WViwrK1ZWMhOBVwBvgX7J134tgYEOfjl/b1cAgYF+FQB+AysrKysrKysrKysrKwcrJCuXKysrKysrKysrKisrKysrVlZWVlaAgYGBgTm7KisrKysrKysrKysrKysrKysrKysrKysrKysrKysrKysrKysrKysrKysBgYGBgYGBgYGBgYGBgYGByaysrKysrKysrKysrKysrNANAE4xArTBwdfXJFAxUDFQMVAxUDFQMVAxUDFQMVAxUDFQMVAxUDFQMVAxUNfXU8FH1NfX1wUrKysrKysrKysrKysHAQABAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAE4xUDFQMVAxUDFQMVAxUA0AAAAAACRQMVAxUDFQMVAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAKysrKysrKysrKyt5XHtce097XHtce1x7XHtce1x7XHtce1x7XC0rK3kUXHtcLXkqXCdce1x7XHukAAq0XHtce08DeDgrKysrKysrKysrKysrTy0rKwEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABIAAAAAAAAAAAAKisrKysrKysrKysrKysrKysrKysrKysrKysrAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAKysrKysrKysHAEhWVlZWVlZWVgIAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAKysrKysrKysrKysrK1VWVlZWVlZWVlZWVlYOAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACQrKysrKysrKysrKwcAVlZWVlZWVlZWVlZWAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAkKysrKysrKysrKysrKysrKwcAAAAAVlZWVlZWVlZWVlZWVlZWVlYAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAKisrKysrKysrKytWVlZWVlZWVlZWDgAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAKisrKysrKysrKytWVlZWVlZWVlZWDgAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAArKysrKysrKysrK1VWVlZWVlZWVlZWDgAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABBuPYACwwACAAAVgEAADkAAAAAQcT2AAvABwAAAAABIAAAAOD//wC/HQAA5wIAAHkAAAIkAAABAQAAAP///wAAAAABAgAAAP7//wE5//8AGP//AYf//wDU/v8AwwAAAdIAAAHOAAABzQAAAU8AAAHKAAABywAAAc8AAABhAAAB0wAAAdEAAACjAAAB1QAAAIIAAAHWAAAB2gAAAdkAAAHbAAAAOAAAAwAAAACx//8Bn///Acj//wIoJAAAAAAAAQEAAAD///8AM///ACb//wF+//8BKyoAAV3//wEoKgAAPyoAAT3//wFFAAABRwAAAB8qAAAcKgAAHioAAC7//wAy//8ANv//ADX//wBPpQAAS6UAADH//wAopQAARKUAAC///wAt//8A9ykAAEGlAAD9KQAAK///ACr//wDnKQAAQ6UAACqlAAC7//8AJ///ALn//wAl//8AFaUAABKlAAIkTAAAAAAAASAAAADg//8BAQAAAP///wBUAAABdAAAASYAAAElAAABQAAAAT8AAADa//8A2///AOH//wDA//8Awf//AQgAAADC//8Ax///ANH//wDK//8A+P//AKr//wCw//8ABwAAAIz//wHE//8AoP//Afn//wIacAABAQAAAP///wEgAAAA4P//AVAAAAEPAAAA8f//AAAAAAEwAAAA0P//AQEAAAD///8AAAAAAMALAAFgHAAAAAAAAdCXAAEIAAAA+P//AgWKAAAAAAABQPT/AJ7n/wDCiQAA2+f/AJLn/wCT5/8AnOf/AJ3n/wCk5/8AAAAAADiKAAAEigAA5g4AAQEAAAD///8AAAAAAMX//wFB4v8CHY8AAAgAAAH4//8AAAAAAFYAAAGq//8ASgAAAGQAAACAAAAAcAAAAH4AAAAJAAABtv//Aff//wDb4/8BnP//AZD//wGA//8Bgv//AgWsAAAAAAABEAAAAPD//wEcAAABAQAAAaPi/wFB3/8But//AOT//wILsQABAQAAAP///wEwAAAA0P//AAAAAAEJ1v8BGvH/ARnW/wDV1f8A2NX/AeTV/wED1v8B4dX/AeLV/wHB1f8AAAAAAKDj/wAAAAABAQAAAP///wIMvAAAAAAAAQEAAAD///8BvFr/AaADAAH8df8B2Fr/ADAAAAGxWv8BtVr/Ab9a/wHuWv8B1lr/Aeta/wHQ//8BvVr/Ach1/wAAAAAAMGj/AGD8/wAAAAABIAAAAOD//wAAAAABKAAAANj//wAAAAABQAAAAMD//wAAAAABIAAAAOD//wAAAAABIAAAAOD//wAAAAABIgAAAN7//wBBhP4AC4AEAAYnUW93AAAAAAAAAAAAAHwAAH8AAAAAAAAAAIOOkpcAqgAAAAAAAAAAAAC0xAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAMbJAAAA2wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA3gAAAADhAAAAAAAAAOQAAAAAAAAAAAAAAOcAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA6gAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA7QAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAQYSCAQuQAzAMMQ14Dn8PgBCBEYYSiROKE44UjxWQFpMTlBeVGJYZlxqaG5wZnRyeHZ8eph+pH64fsSCyILchvyLFI8gjyyPdJPIj9iX3JiAtOi49Lz4wPzFAMUMyRDNFNFA1UTZSN1M4VDlZOls7XDxhPWM+ZT9mQGhBaUJqQGtDbERvQnFFckZ1R31IgkmHSolLikyLTIxNkk6dT55QRVd7HXwdfR1/WIZZiFqJWopajFuOXI9crF2tXq5er17CX8xgzWHOYc9i0GPRZNVl1mbXZ/Bo8WnyavNr9Gz1bflu/S3+Lf8tUGlRaVJpU2lUaVVpVmlXaVhpWWlaaVtpXGldaV5pX2mCAIMAhACFAIYAhwCIAIkAwHXPdoCJgYqCi4WMho1wnXGddp53nnifeZ96oHugfKF9obOiuqO7o7ykvqXDosyk2qbbpuVq6qfrp+xu86L4qPmo+qn7qfykJrAqsSuyTrOECGK6Y7tkvGW9Zr5tv27Ab8Fwwn7Df8N9z43QlNGr0qzTrdSw1bHWstfE2MXZxtoAQZyFAQswLAAAAAAAAAAAAAAAAQAAABoAAABJAFAARgBTACAAbQBlAHQAaABvAGQAIAAnAAAAAEHMhQELMCwAAAAAAAAAAAAAAAEAAAAUAAAAJwAgAGYAYQBpAGwAZQBkAC4AIAAAAAAAAAAAAABB/IUBCzAsAAAAAAAAAAAAAAAmAAAAFAAAALBCAAAAAAAA4EIAAAAAAAAgBAAAAAAAAAAAAAAAQayGAQswLAAAAAAAAAAAAAAAAQAAABwAAAAKAFMAdABhAHQAdQBzACAAYwBvAGQAZQA6ACAAAEHchgELMCwAAAAAAAAAAAAAAAEAAAASAAAACgBTAHQAYQB0AHUAcwA6ACAAAAAAAAAAAAAAAABBjIcBC0A8AAAAAAAAAAAAAAABAAAAKgAAAEgAdAB0AHAAIABSAGUAcwBwAG8AbgBzAGUAIABpAHMAIABuAHUAbABsAAAAAEHMhwELMCwAAAAAAAAAAAAAAAEAAAAUAAAASAB0AHQAcAAgAGUAcgByAG8AcgAAAAAAAAAAAABB/IcBC1BMAAAAAAAAAAAAAAABAAAANAAAAEgAdAB0AHAAIABSAGUAcwBwAG8AbgBzAGUAIABiAG8AZAB5ACAAaQBzACAAbgB1AGwAbAAAAAAAAAAAAABBzIgBCyAcAAAAAAAAAAAAAAABAAAAAgAAAD0AAAAAAAAAAAAAAABB7IgBC6ABnAAAAAAAAAAAAAAAAAAAAIAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAPgAAAD80NTY3ODk6Ozw9AAAAAAAAAAABAgMEBQYHCAkKCwwNDg8QERITFBUWFxgZAAAAAAAAGhscHR4fICEiIyQlJicoKSorLC0uLzAxMjMAAAAAAAAAAAAAAAAAAAAAAABBjIoBC0A8AAAAAAAAAAAAAAABAAAAJgAAAH4AbABpAGIALwBzAHQAYQB0AGkAYwBhAHIAcgBhAHkALgB0AHMAAAAAAAAAAEHMigELcGwAAAAAAAAAAAAAAAEAAABYAAAAUwBlAHIAaQBhAGwAaQB6AGkAbgBnACAAKABzAGkAegBpAG4AZwApACAAbQBvAGQAdQBsAGUALQB0AHkAcABlADoAIABjAGEAdAAgAFIAZQBzAHUAbAB0AAAAAAAAQbyLAQswLAAAAAAAAAAAAAAAAQAAABYAAABBAHIAcgBhAHkAQgB1AGYAZgBlAHIAAAAAAAAAAEHsiwELcGwAAAAAAAAAAAAAAAEAAABcAAAAUwBlAHIAaQBhAGwAaQB6AGkAbgBnACAAKABlAG4AYwBvAGQAaQBuAGcAKQAgAG0AbwBkAHUAbABlAC0AdAB5AHAAZQA6ACAAYwBhAHQAIABSAGUAcwB1AGwAdAAAQdyMAQsgHAAAAAAAAAAAAAAAKgAAAAgAAAAIAAAAAAAAAAAAAAAAQfyMAQtgXAAAAAAAAAAAAAAAAQAAAEAAAABDAG8AdQBsAGQAIABuAG8AdAAgAGYAaQBuAGQAIABpAG4AdgBvAGsAZQAgAGYAdQBuAGMAdABpAG8AbgAgACIAAAAAAAAAAAAAAAAAAEHcjQELIBwAAAAAAAAAAAAAAAEAAAACAAAAIgAAAAAAAAAAAAAAAEH8jQELIBwAAAAAAAAAAAAAACYAAAAMAAAAkEYAAAAAAADwRgAAAEGcjgELMCwAAAAAAAAAAAAAAAEAAAAOAAAAcgBlAHMAbwBsAHYAZQAAAAAAAAAAAAAAAAAAAABBzI4BC3BsAAAAAAAAAAAAAAABAAAATgAAAEQAZQBzAGUAcgBpAGEAbABpAHoAaQBuAGcAIABtAG8AZAB1AGwAZQAtAHQAeQBwAGUAOgAgAHIAZQBzAG8AbAB2AGUAIABBAHIAZwBzAAAAAAAAAAAAAAAAAAAAAEG8jwELMCwAAAAAAAAAAAAAAAEAAAAcAAAAcgBlAHMAbwBsAHYAZQBPAHAAdABpAG8AbgBzAABB7I8BC1BMAAAAAAAAAAAAAAABAAAANgAAAFQAeQBwAGUAcwAuAFIAZQBzAG8AbAB2AGUATwBwAHQAaQBvAG4AcwAgAHwAIABuAHUAbABsAAAAAAAAAABBvJABCzAsAAAAAAAAAAAAAAABAAAAEgAAAHIAZQBjAHUAcgBzAGkAdgBlAAAAAAAAAAAAAAAAQeyQAQtAPAAAAAAAAAAAAAAAAQAAACAAAABCAG8AeAA8AGIAbwBvAGwAPgAgAHwAIABuAHUAbABsAAAAAAAAAAAAAAAAAABBrJEBCzAsAAAAAAAAAAAAAAABAAAAHAAAAGQAaAB0AFIAZQBjAG8AcgBkAEMAbwB1AG4AdAAAQdyRAQswLAAAAAAAAAAAAAAAAQAAABQAAABkAGgAdABUAGkAbQBlAG8AdQB0AAAAAAAAAAAAAEGMkgELIBwAAAAAAAAAAAAAAAEAAAAIAAAAdAByAHUAZQAAAAAAAEGskgELIBwAAAAAAAAAAAAAAAEAAAAKAAAAZgBhAGwAcwBlAAAAAEHMkgELQDwAAAAAAAAAAAAAAAEAAAAgAAAAZABoAHQALQByAGUAYwBvAHIAZAAtAGMAbwB1AG4AdAAAAAAAAAAAAAAAAAAAQYyTAQswLAAAAAAAAAAAAAAAAQAAABYAAABkAGgAdAAtAHQAaQBtAGUAbwB1AHQAAAAAAAAAAEG8kwELQDwAAAAAAAAAAAAAAAEAAAAeAAAALwBhAHAAaQAvAHYAMAAvAHIAZQBzAG8AbAB2AGUAAAAAAAAAAAAAAAAAAAAAQfyTAQtwbAAAAAAAAAAAAAAAAQAAAFgAAAB+AGwAaQBiAC8AQAB3AGUAYgAzAGEAcABpAC8AYQBzAHMAZQBtAGIAbAB5AHMAYwByAGkAcAB0AC0AagBzAG8AbgAvAGQAZQBjAG8AZABlAHIALgB0AHMAAAAAAABB7JQBC0A8AAAAAAAAAAAAAAABAAAAKAAAAFUAbgBlAHgAcABlAGMAdABlAGQAIABpAG4AcAB1AHQAIABlAG4AZAAAAAAAAEGslQELIBwAAAAAAAAAAAAAAAEAAAACAAAAewAAAAAAAAAAAAAAAEHMlQELQDwAAAAAAAAAAAAAAAEAAAAiAAAAdQBuAGUAeABwAGUAYwB0AGUAZAAgAHUAcABjAGEAcwB0AAAAAAAAAAAAAAAAQYyWAQtwbAAAAAAAAAAAAAAAAQAAAFIAAAB+AGwAaQBiAC8AQAB3AGUAYgAzAGEAcABpAC8AYQBzAHMAZQBtAGIAbAB5AHMAYwByAGkAcAB0AC0AagBzAG8AbgAvAEoAUwBPAE4ALgB0AHMAAAAAAAAAAAAAAABB/JYBCyAcAAAAAAAAAAAAAAABAAAAAgAAAH0AAAAAAAAAAAAAAABBnJcBCyAcAAAAAAAAAAAAAAABAAAAAgAAACwAAAAAAAAAAAAAAABBvJcBCzAsAAAAAAAAAAAAAAABAAAAGAAAAEUAeABwAGUAYwB0AGUAZAAgACcALAAnAAAAAAAAQeyXAQtQTAAAAAAAAAAAAAAAAQAAADoAAABFAHgAcABlAGMAdABlAGQAIABkAG8AdQBiAGwAZQAtAHEAdQBvAHQAZQBkACAAcwB0AHIAaQBuAGcAAAAAQbyYAQtQTAAAAAAAAAAAAAAAAQAAADgAAABVAG4AZQB4AHAAZQBjAHQAZQBkACAAYwBvAG4AdAByAG8AbAAgAGMAaABhAHIAYQBjAHQAZQByAAAAAAAAQYyZAQsgHAAAAAAAAAAAAAAAAQAAAAIAAABcAAAAAAAAAAAAAAAAQayZAQsgHAAAAAAAAAAAAAAAAQAAAAIAAAAvAAAAAAAAAAAAAAAAQcyZAQsgHAAAAAAAAAAAAAAAAQAAAAIAAABiAAAAAAAAAAAAAAAAQeyZAQsgHAAAAAAAAAAAAAAAAQAAAAIAAAAIAAAAAAAAAAAAAAAAQYyaAQsgHAAAAAAAAAAAAAAAAQAAAAIAAABuAAAAAAAAAAAAAAAAQayaAQsgHAAAAAAAAAAAAAAAAQAAAAIAAAByAAAAAAAAAAAAAAAAQcyaAQsgHAAAAAAAAAAAAAAAAQAAAAIAAAANAAAAAAAAAAAAAAAAQeyaAQsgHAAAAAAAAAAAAAAAAQAAAAIAAAB0AAAAAAAAAAAAAAAAQYybAQsgHAAAAAAAAAAAAAAAAQAAAAIAAAAJAAAAAAAAAAAAAAAAQaybAQsgHAAAAAAAAAAAAAAAAQAAAAIAAAB1AAAAAAAAAAAAAAAAQcybAQtAPAAAAAAAAAAAAAAAAQAAACYAAABVAG4AZQB4AHAAZQBjAHQAZQBkACAAXAB1ACAAZABpAGcAaQB0AAAAAAAAAABBjJwBC1BMAAAAAAAAAAAAAAABAAAAPAAAAFUAbgBlAHgAcABlAGMAdABlAGQAIABlAHMAYwBhAHAAZQBkACAAYwBoAGEAcgBhAGMAdABlAHIAOgAgAABB3JwBCyAcAAAAAAAAAAAAAAABAAAAAgAAADoAAAAAAAAAAAAAAABB/JwBCzAsAAAAAAAAAAAAAAABAAAAGAAAAEUAeABwAGUAYwB0AGUAZAAgACcAOgAnAAAAAAAAQaydAQtQTAAAAAAAAAAAAAAAAQAAADAAAABVAG4AZQB4AHAAZQBjAHQAZQBkACAAZQBuAGQAIABvAGYAIABvAGIAagBlAGMAdAAAAAAAAAAAAAAAAAAAQfydAQsgHAAAAAAAAAAAAAAAAQAAAAIAAABbAAAAAAAAAAAAAAAAQZyeAQtQTAAAAAAAAAAAAAAAAQAAAC4AAABVAG4AZQB4AHAAZQBjAHQAZQBkACAAZQBuAGQAIABvAGYAIABhAHIAcgBhAHkAAAAAAAAAAAAAAAAAAAAAQeyeAQswLAAAAAAAAAAAAAAAAQAAABQAAABFAHgAcABlAGMAdABlAGQAIAAnAAAAAAAAAAAAAEGcnwELIBwAAAAAAAAAAAAAAAEAAAACAAAAJwAAAAAAAAAAAAAAAEG8nwELIBwAAAAAAAAAAAAAAAEAAAAEAAAALQAwAAAAAAAAAAAAAEHgnwELuAEAAAAAAADwPwAAAAAAACRAAAAAAAAAWUAAAAAAAECPQAAAAAAAiMNAAAAAAABq+EAAAAAAgIQuQQAAAADQEmNBAAAAAITXl0EAAAAAZc3NQQAAACBfoAJCAAAA6HZIN0IAAACilBptQgAAQOWcMKJCAACQHsS81kIAADQm9WsMQwCA4Dd5w0FDAKDYhVc0dkMAyE5nbcGrQwA9kWDkWOFDQIy1eB2vFURQ7+LW5BpLRJLVTQbP8IBEAEGcoQELIBwAAAAAAAAAAAAAAAEAAAAIAAAAbgB1AGwAbAAAAAAAAEG8oQELQDwAAAAAAAAAAAAAAAEAAAAiAAAAQwBhAG4AbgBvAHQAIABwAGEAcgBzAGUAIABKAFMATwBOAAAAAAAAAAAAAAAAQfyhAQtAPAAAAAAAAAAAAAAAAQAAACQAAABzAHIAYwAvAHUAdABpAGwAcwAvAHAAYQByAHMAZQAuAHQAcwAAAAAAAAAAAABBvKIBCyAcAAAAAAAAAAAAAAABAAAACAAAAFAAYQB0AGgAAAAAAABB3KIBC0A8AAAAAAAAAAAAAAABAAAAJAAAAEsAZQB5ACAAZABvAGUAcwAgAG4AbwB0ACAAZQB4AGkAcwB0AAAAAAAAAAAAAEGcowELMCwAAAAAAAAAAAAAAAEAAAAWAAAAfgBsAGkAYgAvAG0AYQBwAC4AdABzAAAAAAAAAABBzKMBC2BcAAAAAAAAAAAAAAABAAAASAAAAEYAYQBpAGwAZQBkACAAdABvACAAcABhAHIAcwBlACAAbQBhAGwAZgBvAHIAbQBlAGQAIAByAGUAcwBwAG8AbgBzAGUAOgAgAAAAAAAAQaykAQuAAXwAAAAAAAAAAAAAAAEAAABgAAAAUwBlAHIAaQBhAGwAaQB6AGkAbgBnACAAKABzAGkAegBpAG4AZwApACAAbQBvAGQAdQBsAGUALQB0AHkAcABlADoAIAByAGUAcwBvAGwAdgBlACAAUgBlAHMAdQBsAHQAAAAAAAAAAAAAAAAAAEGspQELQDwAAAAAAAAAAAAAAAEAAAAmAAAAVAB5AHAAZQBzAC4AUgBlAHMAbwBsAHYAZQBSAGUAcwB1AGwAdAAAAAAAAAAAQeylAQswLAAAAAAAAAAAAAAAAQAAABAAAABwAHIAbwB2AGkAZABlAHIAAAAAAAAAAAAAAAAAAEGcpgELgAF8AAAAAAAAAAAAAAABAAAAZAAAAFMAZQByAGkAYQBsAGkAegBpAG4AZwAgACgAZQBuAGMAbwBkAGkAbgBnACkAIABtAG8AZAB1AGwAZQAtAHQAeQBwAGUAOgAgAHIAZQBzAG8AbAB2AGUAIABSAGUAcwB1AGwAdAAAAAAAAAAAAABBnKcBCyAcAAAAAAAAAAAAAAAqAAAACAAAAAkAAAAAAAAAAAAAAABBvKcBCzAsAAAAAAAAAAAAAAABAAAADgAAAGEAZABkAEYAaQBsAGUAAAAAAAAAAAAAAAAAAAAAQeynAQtwbAAAAAAAAAAAAAAAAQAAAE4AAABEAGUAcwBlAHIAaQBhAGwAaQB6AGkAbgBnACAAbQBvAGQAdQBsAGUALQB0AHkAcABlADoAIABhAGQAZABGAGkAbABlACAAQQByAGcAcwAAAAAAAAAAAAAAAAAAAABB3KgBCyAcAAAAAAAAAAAAAAABAAAACAAAAGQAYQB0AGEAAAAAAABB/KgBC0A8AAAAAAAAAAAAAAABAAAAHgAAAFQAeQBwAGUAcwAuAEYAaQBsAGUARQBuAHQAcgB5AAAAAAAAAAAAAAAAAAAAAEG8qQELcGwAAAAAAAAAAAAAAAEAAABSAAAATQBpAHMAcwBpAG4AZwAgAHIAZQBxAHUAaQByAGUAZAAgAHAAcgBvAHAAZQByAHQAeQA6ACAAJwBuAGEAbQBlADoAIABTAHQAcgBpAG4AZwAnAAAAAAAAAAAAAAAAQayqAQtgXAAAAAAAAAAAAAAAAQAAAEYAAABzAHIAYwAvAHcAcgBhAHAALwBGAGkAbABlAEUAbgB0AHIAeQAvAHMAZQByAGkAYQBsAGkAegBhAHQAaQBvAG4ALgB0AHMAAAAAAAAAAEGMqwELcGwAAAAAAAAAAAAAAAEAAABQAAAATQBpAHMAcwBpAG4AZwAgAHIAZQBxAHUAaQByAGUAZAAgAHAAcgBvAHAAZQByAHQAeQA6ACAAJwBkAGEAdABhADoAIABCAHkAdABlAHMAJwAAAAAAAAAAAAAAAAAAQfyrAQswLAAAAAAAAAAAAAAAAQAAABQAAABhAGQAZABPAHAAdABpAG8AbgBzAAAAAAAAAAAAAEGsrAELUEwAAAAAAAAAAAAAAAEAAAAuAAAAVAB5AHAAZQBzAC4AQQBkAGQATwBwAHQAaQBvAG4AcwAgAHwAIABuAHUAbABsAAAAAAAAAAAAAAAAAAAAAEH8rAELIBwAAAAAAAAAAAAAAAEAAAAGAAAAcABpAG4AAAAAAAAAAEGcrQELMCwAAAAAAAAAAAAAAAEAAAAQAAAAbwBuAGwAeQBIAGEAcwBoAAAAAAAAAAAAAAAAAABBzK0BC0A8AAAAAAAAAAAAAAABAAAAIgAAAHcAcgBhAHAAVwBpAHQAaABEAGkAcgBlAGMAdABvAHIAeQAAAAAAAAAAAAAAAEGMrgELcGwAAAAAAAAAAAAAAAEAAABYAAAATQBpAHMAcwBpAG4AZwAgAHIAZQBxAHUAaQByAGUAZAAgAGEAcgBnAHUAbQBlAG4AdAA6ACAAJwBkAGEAdABhADoAIABGAGkAbABlAEUAbgB0AHIAeQAnAAAAAAAAQfyuAQugAZwAAAAAAAAAAAAAAAEAAACAAAAAQQBCAEMARABFAEYARwBIAEkASgBLAEwATQBOAE8AUABRAFIAUwBUAFUAVgBXAFgAWQBaAGEAYgBjAGQAZQBmAGcAaABpAGoAawBsAG0AbgBvAHAAcQByAHMAdAB1AHYAdwB4AHkAegAwADEAMgAzADQANQA2ADcAOAA5ACsALwAAAAAAAAAAAAAAAAAAQZywAQtQTAAAAAAAAAAAAAAAAQAAADAAAABhAHAAcABsAGkAYwBhAHQAaQBvAG4ALwBvAGMAdABlAHQALQBzAHQAcgBlAGEAbQAAAAAAAAAAAAAAAAAAQeywAQswLAAAAAAAAAAAAAAAAQAAABgAAABDAG8AbgB0AGUAbgB0AC0AVAB5AHAAZQAAAAAAAEGcsQELQDwAAAAAAAAAAAAAAAEAAAAmAAAAbQB1AGwAdABpAHAAYQByAHQALwBmAG8AcgBtAC0AZABhAHQAYQAAAAAAAAAAQdyxAQswLAAAAAAAAAAAAAAAAQAAABIAAABvAG4AbAB5AC0AaABhAHMAaAAAAAAAAAAAAAAAAEGMsgELQDwAAAAAAAAAAAAAAAEAAAAmAAAAdwByAGEAcAAtAHcAaQB0AGgALQBkAGkAcgBlAGMAdABvAHIAeQAAAAAAAAAAQcyyAQsgHAAAAAAAAAAAAAAAAQAAAAYAAABhAGQAZAAAAAAAAAAAQeyyAQswLAAAAAAAAAAAAAAAAQAAABYAAAAvAGEAcABpAC8AdgAwAC8AYQBkAGQAAAAAAAAAAEGcswELgAF8AAAAAAAAAAAAAAABAAAAaAAAAFMAZQByAGkAYQBsAGkAegBpAG4AZwAgACgAcwBpAHoAaQBuAGcAKQAgAGkAbQBwAG8AcgB0AGUAZAAgAG0AbwBkAHUAbABlAC0AdAB5AHAAZQA6ACAAcABvAHMAdAAgAEEAcgBnAHMAAAAAAABBnLQBC4ABfAAAAAAAAAAAAAAAAQAAAGwAAABTAGUAcgBpAGEAbABpAHoAaQBuAGcAIAAoAGUAbgBjAG8AZABpAG4AZwApACAAaQBtAHAAbwByAHQAZQBkACAAbQBvAGQAdQBsAGUALQB0AHkAcABlADoAIABwAG8AcwB0ACAAQQByAGcAcwAAQZy1AQsgHAAAAAAAAAAAAAAAAQAAAAgAAABwAG8AcwB0AAAAAAAAQby1AQuAAXwAAAAAAAAAAAAAAAEAAABeAAAARABlAHMAZQByAGkAYQBsAGkAegBpAG4AZwAgAGkAbQBwAG8AcgB0AGUAZAAgAG0AbwBkAHUAbABlAC0AdAB5AHAAZQA6ACAAcABvAHMAdAAgAFIAZQBzAHUAbAB0AAAAAAAAAAAAAAAAAAAAAEG8tgELIBwAAAAAAAAAAAAAAAEAAAAIAAAATgBhAG0AZQAAAAAAAEHctgELIBwAAAAAAAAAAAAAAAEAAAAIAAAASABhAHMAaAAAAAAAAEH8tgELIBwAAAAAAAAAAAAAAAEAAAAIAAAAUwBpAHoAZQAAAAAAAEGctwELgAF8AAAAAAAAAAAAAAABAAAAYAAAAFMAZQByAGkAYQBsAGkAegBpAG4AZwAgACgAcwBpAHoAaQBuAGcAKQAgAG0AbwBkAHUAbABlAC0AdAB5AHAAZQA6ACAAYQBkAGQARgBpAGwAZQAgAFIAZQBzAHUAbAB0AAAAAAAAAAAAAAAAAABBnLgBC0A8AAAAAAAAAAAAAAABAAAAHgAAAFQAeQBwAGUAcwAuAEEAZABkAFIAZQBzAHUAbAB0AAAAAAAAAAAAAAAAAAAAAEHcuAELIBwAAAAAAAAAAAAAAAEAAAAIAAAAaABhAHMAaAAAAAAAAEH8uAELIBwAAAAAAAAAAAAAAAEAAAAIAAAAcwBpAHoAZQAAAAAAAEGcuQELgAF8AAAAAAAAAAAAAAABAAAAZAAAAFMAZQByAGkAYQBsAGkAegBpAG4AZwAgACgAZQBuAGMAbwBkAGkAbgBnACkAIABtAG8AZAB1AGwAZQAtAHQAeQBwAGUAOgAgAGEAZABkAEYAaQBsAGUAIABSAGUAcwB1AGwAdAAAAAAAAAAAAABBnLoBCyAcAAAAAAAAAAAAAAAqAAAACAAAAAoAAAAAAAAAAAAAAABBvLoBCyAcAAAAAAAAAAAAAAABAAAADAAAAGEAZABkAEQAaQByAABB3LoBC2BcAAAAAAAAAAAAAAABAAAATAAAAEQAZQBzAGUAcgBpAGEAbABpAHoAaQBuAGcAIABtAG8AZAB1AGwAZQAtAHQAeQBwAGUAOgAgAGEAZABkAEQAaQByACAAQQByAGcAcwAAQby7AQtAPAAAAAAAAAAAAAAAAQAAACgAAABUAHkAcABlAHMALgBEAGkAcgBlAGMAdABvAHIAeQBFAG4AdAByAHkAAAAAAABB/LsBCzAsAAAAAAAAAAAAAAABAAAAFgAAAGQAaQByAGUAYwB0AG8AcgBpAGUAcwAAAAAAAAAAQay8AQtgXAAAAAAAAAAAAAAAAQAAAEQAAABBAHIAcgBhAHkAPABUAHkAcABlAHMALgBEAGkAcgBlAGMAdABvAHIAeQBFAG4AdAByAHkAPgAgAHwAIABuAHUAbABsAAAAAAAAAAAAAEGMvQELIBwAAAAAAAAAAAAAAEgAAAAIAAAACwAAAAAAAAAAAAAAAEGsvQELIBwAAAAAAAAAAAAAAAEAAAAKAAAAZgBpAGwAZQBzAAAAAEHMvQELUEwAAAAAAAAAAAAAAAEAAAA6AAAAQQByAHIAYQB5ADwAVAB5AHAAZQBzAC4ARgBpAGwAZQBFAG4AdAByAHkAPgAgAHwAIABuAHUAbABsAAAAAEGcvgELIBwAAAAAAAAAAAAAAEkAAAAIAAAADAAAAAAAAAAAAAAAAEG8vgELcGwAAAAAAAAAAAAAAAEAAABQAAAAcwByAGMALwB3AHIAYQBwAC8ARABpAHIAZQBjAHQAbwByAHkARQBuAHQAcgB5AC8AcwBlAHIAaQBhAGwAaQB6AGEAdABpAG8AbgAuAHQAcwAAAAAAAAAAAAAAAAAAQay/AQuAAXwAAAAAAAAAAAAAAAEAAABiAAAATQBpAHMAcwBpAG4AZwAgAHIAZQBxAHUAaQByAGUAZAAgAGEAcgBnAHUAbQBlAG4AdAA6ACAAJwBkAGEAdABhADoAIABEAGkAcgBlAGMAdABvAHIAeQBFAG4AdAByAHkAJwAAAAAAAAAAAAAAAEGswAELIBwAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAEHMwAELQDwAAAAAAAAAAAAAAAEAAAAgAAAAMAAxADIAMwA0ADUANgA3ADgAOQBBAEIAQwBEAEUARgAAAAAAAAAAAAAAAAAAQYzBAQsgHAAAAAAAAAAAAAAAAQAAAAIAAAAlAAAAAAAAAAAAAAAAQazBAQtQTAAAAAAAAAAAAAAAAQAAAC4AAABhAHAAcABsAGkAYwBhAHQAaQBvAG4ALwB4AC0AZABpAHIAZQBjAHQAbwByAHkAAAAAAAAAAAAAAAAAAAAAQfzBAQsgHAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAQZzCAQuAAXwAAAAAAAAAAAAAAAEAAABeAAAAUwBlAHIAaQBhAGwAaQB6AGkAbgBnACAAKABzAGkAegBpAG4AZwApACAAbQBvAGQAdQBsAGUALQB0AHkAcABlADoAIABhAGQAZABEAGkAcgAgAFIAZQBzAHUAbAB0AAAAAAAAAAAAAAAAAAAAAEGcwwELQDwAAAAAAAAAAAAAAAEAAAAsAAAAQQByAHIAYQB5ADwAVAB5AHAAZQBzAC4AQQBkAGQAUgBlAHMAdQBsAHQAPgAAQdzDAQsgHAAAAAAAAAAAAAAATAAAAAgAAAANAAAAAAAAAAAAAAAAQfzDAQuAAXwAAAAAAAAAAAAAAAEAAABiAAAAUwBlAHIAaQBhAGwAaQB6AGkAbgBnACAAKABlAG4AYwBvAGQAaQBuAGcAKQAgAG0AbwBkAHUAbABlAC0AdAB5AHAAZQA6ACAAYQBkAGQARABpAHIAIABSAGUAcwB1AGwAdAAAAAAAAAAAAAAAAEH8xAELIBwAAAAAAAAAAAAAACoAAAAIAAAADgAAAAAAAAAAAAAAAEGcxQELMCwAAAAAAAAAAAAAAAEAAAAOAAAAYQBkAGQAQgBsAG8AYgAAAAAAAAAAAAAAAAAAAABBzMUBC3BsAAAAAAAAAAAAAAABAAAATgAAAEQAZQBzAGUAcgBpAGEAbABpAHoAaQBuAGcAIABtAG8AZAB1AGwAZQAtAHQAeQBwAGUAOgAgAGEAZABkAEIAbABvAGIAIABBAHIAZwBzAAAAAAAAAAAAAAAAAAAAAEG8xgELMCwAAAAAAAAAAAAAAAEAAAAUAAAAVAB5AHAAZQBzAC4AQgBsAG8AYgAAAAAAAAAAAABB7MYBCyAcAAAAAAAAAAAAAABIAAAACAAAAA8AAAAAAAAAAAAAAABBjMcBCyAcAAAAAAAAAAAAAABJAAAACAAAABAAAAAAAAAAAAAAAABBrMcBC3BsAAAAAAAAAAAAAAABAAAATgAAAE0AaQBzAHMAaQBuAGcAIAByAGUAcQB1AGkAcgBlAGQAIABhAHIAZwB1AG0AZQBuAHQAOgAgACcAZABhAHQAYQA6ACAAQgBsAG8AYgAnAAAAAAAAAAAAAAAAAAAAAEGcyAELgAF8AAAAAAAAAAAAAAABAAAAYAAAAFMAZQByAGkAYQBsAGkAegBpAG4AZwAgACgAcwBpAHoAaQBuAGcAKQAgAG0AbwBkAHUAbABlAC0AdAB5AHAAZQA6ACAAYQBkAGQAQgBsAG8AYgAgAFIAZQBzAHUAbAB0AAAAAAAAAAAAAAAAAABBnMkBCyAcAAAAAAAAAAAAAABMAAAACAAAABEAAAAAAAAAAAAAAABBvMkBC4ABfAAAAAAAAAAAAAAAAQAAAGQAAABTAGUAcgBpAGEAbABpAHoAaQBuAGcAIAAoAGUAbgBjAG8AZABpAG4AZwApACAAbQBvAGQAdQBsAGUALQB0AHkAcABlADoAIABhAGQAZABCAGwAbwBiACAAUgBlAHMAdQBsAHQAAAAAAAAAAAAAQbzKAQsgHAAAAAAAAAAAAAAAKgAAAAgAAAASAAAAAAAAAAAAAAAAQdzKAQtQTAAAAAAAAAAAAAAAAQAAADQAAABpAG4AdABlAGcAZQByACAAbwB2AGUAcgBmAGwAbwB3ADoAIAB2AGEAbAB1AGUAIAA9ACAAAAAAAAAAAAAAQazLAQswLAAAAAAAAAAAAAAAAQAAABYAAAA7ACAAYgBpAHQAcwAgAD0AIAA2ADQAAAAAAAAAAEHcywELYFwAAAAAAAAAAAAAAAEAAABAAAAAUAByAG8AcABlAHIAdAB5ACAAbQB1AHMAdAAgAGIAZQAgAG8AZgAgAHQAeQBwAGUAIAAnAGkAbgB0ACcALgAgAAAAAAAAAAAAAAAAAABBvMwBCzAsAAAAAAAAAAAAAAABAAAAEAAAAHMAZQB0AFUAaQBuAHQAOAAAAAAAAAAAAAAAAAAAQezMAQswLAAAAAAAAAAAAAAAAQAAABIAAABzAGUAdABVAGkAbgB0ADEANgAAAAAAAAAAAAAAAEGczQELMCwAAAAAAAAAAAAAAAEAAAASAAAAcwBlAHQAVQBpAG4AdAAzADIAAAAAAAAAAAAAAABBzM0BCzAsAAAAAAAAAAAAAAABAAAAEAAAAHMAZQB0AEIAeQB0AGUAcwAAAAAAAAAAAAAAAAAAQfzNAQtAPAAAAAAAAAAAAAAAAQAAACQAAAB3AHIAaQB0AGUARQB4AHQARwBlAG4AZQByAGkAYwBNAGEAcAAAAAAAAAAAAABBvM4BC0A8AAAAAAAAAAAAAAABAAAAKAAAADoAIABJAG4AdgBhAGwAaQBkACAAZQB4AHQAIABpAG4AZABlAHgAIAAAAAAAAEH8zgELQDwAAAAAAAAAAAAAAAEAAAAkAAAALAAgAGUAeAB0ACAAYwBhAGMAaABlACAAbABlAG4AZwB0AGgAAAAAAAAAAAAAQbzPAQtwbAAAAAAAAAAAAAAAAQAAAFwAAAB+AGwAaQBiAC8AQABwAG8AbAB5AHcAcgBhAHAALwB3AGEAcwBtAC0AYQBzAC8AbQBzAGcAcABhAGMAawAvAFcAcgBpAHQAZQBFAG4AYwBvAGQAZQByAC4AdABzAABBrNABCzAsAAAAAAAAAAAAAAABAAAADgAAAHMAZQB0AEkAbgB0ADgAAAAAAAAAAAAAAAAAAAAAQdzQAQswLAAAAAAAAAAAAAAAAQAAABAAAABzAGUAdABJAG4AdAAxADYAAAAAAAAAAAAAAAAAAEGM0QELMCwAAAAAAAAAAAAAAAEAAAAQAAAAcwBlAHQASQBuAHQAMwAyAAAAAAAAAAAAAAAAAABBvNEBCyAcAAAAAAAAAAAAAAABAAAACAAAAG0AYQBwAFsAAAAAAABB3NEBC3BsAAAAAAAAAAAAAAABAAAAWAAAAFAAcgBvAHAAZQByAHQAeQAgAG0AdQBzAHQAIABiAGUAIABvAGYAIAB0AHkAcABlACAAJwBlAHgAdAAgAGcAZQBuAGUAcgBpAGMAIABtAGEAcAAnAC4AIAAAAAAAAEHM0gELgAF8AAAAAAAAAAAAAAABAAAAZgAAAEUAeAB0AGUAbgBzAGkAbwBuACAAbQB1AHMAdAAgAGIAZQAgAG8AZgAgAHQAeQBwAGUAIAAnAGUAeAB0ACAAZwBlAG4AZQByAGkAYwAgAG0AYQBwACcALgAgAEYAbwB1AG4AZAAgAAAAAAAAAABBzNMBC2BcAAAAAAAAAAAAAAABAAAAQgAAAFAAcgBvAHAAZQByAHQAeQAgAG0AdQBzAHQAIABiAGUAIABvAGYAIAB0AHkAcABlACAAJwBiAG8AbwBsACcALgAgAAAAAAAAAAAAAAAAQazUAQtgXAAAAAAAAAAAAAAAAQAAAEQAAABQAHIAbwBwAGUAcgB0AHkAIABtAHUAcwB0ACAAYgBlACAAbwBmACAAdAB5AHAAZQAgACcAYgB5AHQAZQBzACcALgAgAAAAAAAAAAAAAEGM1QELYFwAAAAAAAAAAAAAAAEAAABEAAAAUAByAG8AcABlAHIAdAB5ACAAbQB1AHMAdAAgAGIAZQAgAG8AZgAgAHQAeQBwAGUAIAAnAGEAcgByAGEAeQAnAC4AIAAAAAAAAAAAAABB7NUBCyAcAAAAAAAAAAAAAAABAAAADAAAAGEAcgByAGEAeQBbAABBkNYBC/QETgAAACAAAAAAAAAAIAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAQAAAgAAAAIBAAAAAAAAAkEAAAAAAAACCQAAAAAAACAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACAAAAAAAAAAAAAAAAAAAAAgAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACQQAAAAAAAAAAAAATAAAAIAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAEEGCAAAAAAAAAAAAAAAAAAJBAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAIAAAAAAAAAAAAAAAHgAAACAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAeAAAAAAAAAAAAAAAgAAAAAAAAAAAAAAAAAAAApAAAAAAAAAAEQQAAAAAAAEEAAAACAAAAQgAAAAAAAABkAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACAAAAAAAAAAAAAAAAAAAAAAAAAAMAAAACAAAAAAAAAAEEGCAAAAAAAAAAAAMwAAACAAAAAAAAAAAkEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAADAAAAACQQAAAAAAAAAAAAAwAAAAIAAAADAAAAAgAAAAPAAAACAAAAAwAAAAIAAAADAAAAAgAAAAMAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAkEAAAAAAAACQQAAAAAAAAAAAAAAAAAAAAAAAAAAAAACQQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=="
-  )
-}
-
-fn wrap_info() -> Result<Vec<u8>, DecodeError> {
-  decode(
-    "hKd2ZXJzaW9uozAuMaRuYW1ls2lwZnMtaHR0cC1jbGllbnQtYXOkdHlwZaR3YXNto2FiaYandmVyc2lvbqMwLjGrb2JqZWN0VHlwZXOYg6R0eXBlqkNhdE9wdGlvbnOka2luZAGqcHJvcGVydGllc5KEpHR5cGWlSW50MzKkbmFtZaZvZmZzZXSka2luZCKmc2NhbGFyg6RuYW1lpm9mZnNldKR0eXBlpUludDMypGtpbmQEhKR0eXBlpUludDMypG5hbWWmbGVuZ3RopGtpbmQipnNjYWxhcoOkbmFtZaZsZW5ndGikdHlwZaVJbnQzMqRraW5kBIOkdHlwZapBZGRPcHRpb25zpGtpbmQBqnByb3BlcnRpZXOThKR0eXBlp0Jvb2xlYW6kbmFtZaNwaW6ka2luZCKmc2NhbGFyg6RuYW1lo3BpbqR0eXBlp0Jvb2xlYW6ka2luZASEpHR5cGWnQm9vbGVhbqRuYW1lqG9ubHlIYXNopGtpbmQipnNjYWxhcoOkbmFtZahvbmx5SGFzaKR0eXBlp0Jvb2xlYW6ka2luZASEpHR5cGWnQm9vbGVhbqRuYW1lsXdyYXBXaXRoRGlyZWN0b3J5pGtpbmQipnNjYWxhcoOkbmFtZbF3cmFwV2l0aERpcmVjdG9yeaR0eXBlp0Jvb2xlYW6ka2luZASDpHR5cGWuUmVzb2x2ZU9wdGlvbnOka2luZAGqcHJvcGVydGllc5OEpHR5cGWnQm9vbGVhbqRuYW1lqXJlY3Vyc2l2ZaRraW5kIqZzY2FsYXKDpG5hbWWpcmVjdXJzaXZlpHR5cGWnQm9vbGVhbqRraW5kBISkdHlwZaVJbnQzMqRuYW1lrmRodFJlY29yZENvdW50pGtpbmQipnNjYWxhcoOkbmFtZa5kaHRSZWNvcmRDb3VudKR0eXBlpUludDMypGtpbmQEhKR0eXBlplN0cmluZ6RuYW1lqmRodFRpbWVvdXSka2luZCKmc2NhbGFyg6RuYW1lqmRodFRpbWVvdXSkdHlwZaZTdHJpbmeka2luZASDpHR5cGWtUmVzb2x2ZVJlc3VsdKRraW5kAapwcm9wZXJ0aWVzkoWkdHlwZaZTdHJpbmekbmFtZaNjaWSocmVxdWlyZWTDpGtpbmQipnNjYWxhcoSkbmFtZaNjaWSkdHlwZaZTdHJpbmeocmVxdWlyZWTDpGtpbmQEhaR0eXBlplN0cmluZ6RuYW1lqHByb3ZpZGVyqHJlcXVpcmVkw6RraW5kIqZzY2FsYXKEpG5hbWWocHJvdmlkZXKkdHlwZaZTdHJpbmeocmVxdWlyZWTDpGtpbmQEg6R0eXBlqUFkZFJlc3VsdKRraW5kAapwcm9wZXJ0aWVzk4WkdHlwZaZTdHJpbmekbmFtZaRuYW1lqHJlcXVpcmVkw6RraW5kIqZzY2FsYXKEpG5hbWWkbmFtZaR0eXBlplN0cmluZ6hyZXF1aXJlZMOka2luZASFpHR5cGWmU3RyaW5npG5hbWWkaGFzaKhyZXF1aXJlZMOka2luZCKmc2NhbGFyhKRuYW1lpGhhc2ikdHlwZaZTdHJpbmeocmVxdWlyZWTDpGtpbmQEhaR0eXBlplN0cmluZ6RuYW1lpHNpemWocmVxdWlyZWTDpGtpbmQipnNjYWxhcoSkbmFtZaRzaXplpHR5cGWmU3RyaW5nqHJlcXVpcmVkw6RraW5kBIOkdHlwZalGaWxlRW50cnmka2luZAGqcHJvcGVydGllc5KFpHR5cGWmU3RyaW5npG5hbWWkbmFtZahyZXF1aXJlZMOka2luZCKmc2NhbGFyhKRuYW1lpG5hbWWkdHlwZaZTdHJpbmeocmVxdWlyZWTDpGtpbmQEhaR0eXBlpUJ5dGVzpG5hbWWkZGF0YahyZXF1aXJlZMOka2luZCKmc2NhbGFyhKRuYW1lpGRhdGGkdHlwZaVCeXRlc6hyZXF1aXJlZMOka2luZASDpHR5cGWuRGlyZWN0b3J5RW50cnmka2luZAGqcHJvcGVydGllc5OFpHR5cGWmU3RyaW5npG5hbWWkbmFtZahyZXF1aXJlZMOka2luZCKmc2NhbGFyhKRuYW1lpG5hbWWkdHlwZaZTdHJpbmeocmVxdWlyZWTDpGtpbmQEhKR0eXBlsFtEaXJlY3RvcnlFbnRyeV2kbmFtZatkaXJlY3Rvcmllc6RraW5kIqVhcnJheYWkbmFtZatkaXJlY3Rvcmllc6R0eXBlsFtEaXJlY3RvcnlFbnRyeV2mb2JqZWN0hKRuYW1lq2RpcmVjdG9yaWVzqHJlcXVpcmVkw6R0eXBlrkRpcmVjdG9yeUVudHJ5pGtpbmTNIACka2luZBKkaXRlbYSkbmFtZatkaXJlY3Rvcmllc6hyZXF1aXJlZMOkdHlwZa5EaXJlY3RvcnlFbnRyeaRraW5kzSAAhKR0eXBlq1tGaWxlRW50cnldpG5hbWWlZmlsZXOka2luZCKlYXJyYXmFpG5hbWWlZmlsZXOkdHlwZatbRmlsZUVudHJ5XaZvYmplY3SEpG5hbWWlZmlsZXOocmVxdWlyZWTDpHR5cGWpRmlsZUVudHJ5pGtpbmTNIACka2luZBKkaXRlbYSkbmFtZaVmaWxlc6hyZXF1aXJlZMOkdHlwZalGaWxlRW50cnmka2luZM0gAIOkdHlwZaRCbG9ipGtpbmQBqnByb3BlcnRpZXOShKR0eXBlsFtEaXJlY3RvcnlFbnRyeV2kbmFtZatkaXJlY3Rvcmllc6RraW5kIqVhcnJheYWkbmFtZatkaXJlY3Rvcmllc6R0eXBlsFtEaXJlY3RvcnlFbnRyeV2mb2JqZWN0hKRuYW1lq2RpcmVjdG9yaWVzqHJlcXVpcmVkw6R0eXBlrkRpcmVjdG9yeUVudHJ5pGtpbmTNIACka2luZBKkaXRlbYSkbmFtZatkaXJlY3Rvcmllc6hyZXF1aXJlZMOkdHlwZa5EaXJlY3RvcnlFbnRyeaRraW5kzSAAhKR0eXBlq1tGaWxlRW50cnldpG5hbWWlZmlsZXOka2luZCKlYXJyYXmFpG5hbWWlZmlsZXOkdHlwZatbRmlsZUVudHJ5XaZvYmplY3SEpG5hbWWlZmlsZXOocmVxdWlyZWTDpHR5cGWpRmlsZUVudHJ5pGtpbmTNIACka2luZBKkaXRlbYSkbmFtZaVmaWxlc6hyZXF1aXJlZMOkdHlwZalGaWxlRW50cnmka2luZM0gAKptb2R1bGVUeXBlhKdpbXBvcnRzlYGkdHlwZatIdHRwX01vZHVsZYGkdHlwZaxIdHRwX1JlcXVlc3SBpHR5cGWxSHR0cF9SZXNwb25zZVR5cGWBpHR5cGWySHR0cF9Gb3JtRGF0YUVudHJ5gaR0eXBlrUh0dHBfUmVzcG9uc2WkdHlwZaZNb2R1bGWka2luZMyAp21ldGhvZHOVhqRuYW1lo2NhdKZyZXR1cm6FpHR5cGWlQnl0ZXOkbmFtZaNjYXSocmVxdWlyZWTDpGtpbmQipnNjYWxhcoSkbmFtZaNjYXSkdHlwZaVCeXRlc6hyZXF1aXJlZMOka2luZASkdHlwZaZNZXRob2Ska2luZECocmVxdWlyZWTDqWFyZ3VtZW50c5SFpHR5cGWmU3RyaW5npG5hbWWjY2lkqHJlcXVpcmVkw6RraW5kIqZzY2FsYXKEpG5hbWWjY2lkpHR5cGWmU3RyaW5nqHJlcXVpcmVkw6RraW5kBIWkdHlwZaZTdHJpbmekbmFtZaxpcGZzUHJvdmlkZXKocmVxdWlyZWTDpGtpbmQipnNjYWxhcoSkbmFtZaxpcGZzUHJvdmlkZXKkdHlwZaZTdHJpbmeocmVxdWlyZWTDpGtpbmQEhKR0eXBlplVJbnQzMqRuYW1lp3RpbWVvdXSka2luZCKmc2NhbGFyg6RuYW1lp3RpbWVvdXSkdHlwZaZVSW50MzKka2luZASEpHR5cGWqQ2F0T3B0aW9uc6RuYW1lqmNhdE9wdGlvbnOka2luZCKmb2JqZWN0g6RuYW1lqmNhdE9wdGlvbnOkdHlwZapDYXRPcHRpb25zpGtpbmTNIACGpG5hbWWncmVzb2x2ZaZyZXR1cm6FpHR5cGWtUmVzb2x2ZVJlc3VsdKRuYW1lp3Jlc29sdmWocmVxdWlyZWTDpGtpbmQipm9iamVjdISkbmFtZadyZXNvbHZlqHJlcXVpcmVkw6R0eXBlrVJlc29sdmVSZXN1bHSka2luZM0gAKR0eXBlpk1ldGhvZKRraW5kQKhyZXF1aXJlZMOpYXJndW1lbnRzlIWkdHlwZaZTdHJpbmekbmFtZaNjaWSocmVxdWlyZWTDpGtpbmQipnNjYWxhcoSkbmFtZaNjaWSkdHlwZaZTdHJpbmeocmVxdWlyZWTDpGtpbmQEhaR0eXBlplN0cmluZ6RuYW1lrGlwZnNQcm92aWRlcqhyZXF1aXJlZMOka2luZCKmc2NhbGFyhKRuYW1lrGlwZnNQcm92aWRlcqR0eXBlplN0cmluZ6hyZXF1aXJlZMOka2luZASEpHR5cGWmVUludDMypG5hbWWndGltZW91dKRraW5kIqZzY2FsYXKDpG5hbWWndGltZW91dKR0eXBlplVJbnQzMqRraW5kBISkdHlwZa5SZXNvbHZlT3B0aW9uc6RuYW1lrnJlc29sdmVPcHRpb25zpGtpbmQipm9iamVjdIOkbmFtZa5yZXNvbHZlT3B0aW9uc6R0eXBlrlJlc29sdmVPcHRpb25zpGtpbmTNIACGpG5hbWWnYWRkRmlsZaZyZXR1cm6FpHR5cGWpQWRkUmVzdWx0pG5hbWWnYWRkRmlsZahyZXF1aXJlZMOka2luZCKmb2JqZWN0hKRuYW1lp2FkZEZpbGWocmVxdWlyZWTDpHR5cGWpQWRkUmVzdWx0pGtpbmTNIACkdHlwZaZNZXRob2Ska2luZECocmVxdWlyZWTDqWFyZ3VtZW50c5SFpHR5cGWpRmlsZUVudHJ5pG5hbWWkZGF0YahyZXF1aXJlZMOka2luZCKmb2JqZWN0hKRuYW1lpGRhdGGocmVxdWlyZWTDpHR5cGWpRmlsZUVudHJ5pGtpbmTNIACFpHR5cGWmU3RyaW5npG5hbWWsaXBmc1Byb3ZpZGVyqHJlcXVpcmVkw6RraW5kIqZzY2FsYXKEpG5hbWWsaXBmc1Byb3ZpZGVypHR5cGWmU3RyaW5nqHJlcXVpcmVkw6RraW5kBISkdHlwZaZVSW50MzKkbmFtZad0aW1lb3V0pGtpbmQipnNjYWxhcoOkbmFtZad0aW1lb3V0pHR5cGWmVUludDMypGtpbmQEhKR0eXBlqkFkZE9wdGlvbnOkbmFtZaphZGRPcHRpb25zpGtpbmQipm9iamVjdIOkbmFtZaphZGRPcHRpb25zpHR5cGWqQWRkT3B0aW9uc6RraW5kzSAAhqRuYW1lpmFkZERpcqZyZXR1cm6FpHR5cGWrW0FkZFJlc3VsdF2kbmFtZaZhZGREaXKocmVxdWlyZWTDpGtpbmQipWFycmF5hqR0eXBlq1tBZGRSZXN1bHRdpG5hbWWmYWRkRGlyqHJlcXVpcmVkw6ZvYmplY3SEpG5hbWWmYWRkRGlyqHJlcXVpcmVkw6R0eXBlqUFkZFJlc3VsdKRraW5kzSAApGtpbmQSpGl0ZW2EpG5hbWWmYWRkRGlyqHJlcXVpcmVkw6R0eXBlqUFkZFJlc3VsdKRraW5kzSAApHR5cGWmTWV0aG9kpGtpbmRAqHJlcXVpcmVkw6lhcmd1bWVudHOUhaR0eXBlrkRpcmVjdG9yeUVudHJ5pG5hbWWkZGF0YahyZXF1aXJlZMOka2luZCKmb2JqZWN0hKRuYW1lpGRhdGGocmVxdWlyZWTDpHR5cGWuRGlyZWN0b3J5RW50cnmka2luZM0gAIWkdHlwZaZTdHJpbmekbmFtZaxpcGZzUHJvdmlkZXKocmVxdWlyZWTDpGtpbmQipnNjYWxhcoSkbmFtZaxpcGZzUHJvdmlkZXKkdHlwZaZTdHJpbmeocmVxdWlyZWTDpGtpbmQEhKR0eXBlplVJbnQzMqRuYW1lp3RpbWVvdXSka2luZCKmc2NhbGFyg6RuYW1lp3RpbWVvdXSkdHlwZaZVSW50MzKka2luZASEpHR5cGWqQWRkT3B0aW9uc6RuYW1lqmFkZE9wdGlvbnOka2luZCKmb2JqZWN0g6RuYW1lqmFkZE9wdGlvbnOkdHlwZapBZGRPcHRpb25zpGtpbmTNIACGpG5hbWWnYWRkQmxvYqZyZXR1cm6FpHR5cGWrW0FkZFJlc3VsdF2kbmFtZadhZGRCbG9iqHJlcXVpcmVkw6RraW5kIqVhcnJheYakdHlwZatbQWRkUmVzdWx0XaRuYW1lp2FkZEJsb2KocmVxdWlyZWTDpm9iamVjdISkbmFtZadhZGRCbG9iqHJlcXVpcmVkw6R0eXBlqUFkZFJlc3VsdKRraW5kzSAApGtpbmQSpGl0ZW2EpG5hbWWnYWRkQmxvYqhyZXF1aXJlZMOkdHlwZalBZGRSZXN1bHSka2luZM0gAKR0eXBlpk1ldGhvZKRraW5kQKhyZXF1aXJlZMOpYXJndW1lbnRzlIWkdHlwZaRCbG9ipG5hbWWkZGF0YahyZXF1aXJlZMOka2luZCKmb2JqZWN0hKRuYW1lpGRhdGGocmVxdWlyZWTDpHR5cGWkQmxvYqRraW5kzSAAhaR0eXBlplN0cmluZ6RuYW1lrGlwZnNQcm92aWRlcqhyZXF1aXJlZMOka2luZCKmc2NhbGFyhKRuYW1lrGlwZnNQcm92aWRlcqR0eXBlplN0cmluZ6hyZXF1aXJlZMOka2luZASEpHR5cGWmVUludDMypG5hbWWndGltZW91dKRraW5kIqZzY2FsYXKDpG5hbWWndGltZW91dKR0eXBlplVJbnQzMqRraW5kBISkdHlwZapBZGRPcHRpb25zpG5hbWWqYWRkT3B0aW9uc6RraW5kIqZvYmplY3SDpG5hbWWqYWRkT3B0aW9uc6R0eXBlqkFkZE9wdGlvbnOka2luZM0gALNpbXBvcnRlZE9iamVjdFR5cGVzk4akdHlwZaxIdHRwX1JlcXVlc3SjdXJpv3dyYXA6Ly9lbnMvd3JhcHMuZXRoOmh0dHBAMS4xLjCpbmFtZXNwYWNlpEh0dHCqbmF0aXZlVHlwZadSZXF1ZXN0pGtpbmTNBAGqcHJvcGVydGllc5aEpHR5cGWzTWFwPFN0cmluZywgU3RyaW5nPqRuYW1lp2hlYWRlcnOjbWFwhqR0eXBls01hcDxTdHJpbmcsIFN0cmluZz6mc2NhbGFyhKRuYW1lp2hlYWRlcnOkdHlwZaZTdHJpbmeocmVxdWlyZWTDpGtpbmQEpGtpbmTOAAQAAqRuYW1lp2hlYWRlcnOja2V5hKRuYW1lp2hlYWRlcnOkdHlwZaZTdHJpbmeocmVxdWlyZWTDpGtpbmQEpXZhbHVlhKRuYW1lp2hlYWRlcnOkdHlwZaZTdHJpbmeocmVxdWlyZWTDpGtpbmQEpGtpbmQihKR0eXBls01hcDxTdHJpbmcsIFN0cmluZz6kbmFtZal1cmxQYXJhbXOjbWFwhqR0eXBls01hcDxTdHJpbmcsIFN0cmluZz6mc2NhbGFyhKRuYW1lqXVybFBhcmFtc6R0eXBlplN0cmluZ6hyZXF1aXJlZMOka2luZASka2luZM4ABAACpG5hbWWpdXJsUGFyYW1zo2tleYSkbmFtZal1cmxQYXJhbXOkdHlwZaZTdHJpbmeocmVxdWlyZWTDpGtpbmQEpXZhbHVlhKRuYW1lqXVybFBhcmFtc6R0eXBlplN0cmluZ6hyZXF1aXJlZMOka2luZASka2luZCKFpHR5cGWxSHR0cF9SZXNwb25zZVR5cGWkbmFtZaxyZXNwb25zZVR5cGWocmVxdWlyZWTDpGtpbmQipGVudW2EpG5hbWWscmVzcG9uc2VUeXBlqHJlcXVpcmVkw6R0eXBlsUh0dHBfUmVzcG9uc2VUeXBlpGtpbmTNQACFpHR5cGWmU3RyaW5npG5hbWWkYm9keadjb21tZW502U1UaGUgYm9keSBvZiB0aGUgcmVxdWVzdC4gSWYgcHJlc2VudCwgdGhlIGBmb3JtRGF0YWAgcHJvcGVydHkgd2lsbCBiZSBpZ25vcmVkLqRraW5kIqZzY2FsYXKDpG5hbWWkYm9keaR0eXBlplN0cmluZ6RraW5kBIWkdHlwZbRbSHR0cF9Gb3JtRGF0YUVudHJ5XaRuYW1lqGZvcm1EYXRhp2NvbW1lbnTaATUgIEFuIGFsdGVybmF0aXZlIHRvIHRoZSBzdGFuZGFyZCByZXF1ZXN0IGJvZHksICdmb3JtRGF0YScgaXMgZXhwZWN0ZWQgdG8gYmUgaW4gdGhlICdtdWx0aXBhcnQvZm9ybS1kYXRhJyBmb3JtYXQuCklmIHByZXNlbnQsIHRoZSBgYm9keWAgcHJvcGVydHkgaXMgbm90IG51bGwsIGBmb3JtRGF0YWAgd2lsbCBiZSBpZ25vcmVkLgpPdGhlcndpc2UsIGlmIGZvcm1EYXRhIGlzIG5vdCBudWxsLCB0aGUgZm9sbG93aW5nIGhlYWRlciB3aWxsIGJlIGFkZGVkIHRvIHRoZSByZXF1ZXN0OiAnQ29udGVudC1UeXBlOiBtdWx0aXBhcnQvZm9ybS1kYXRhJy6ka2luZCKlYXJyYXmFpG5hbWWoZm9ybURhdGGkdHlwZbRbSHR0cF9Gb3JtRGF0YUVudHJ5XaZvYmplY3SEpG5hbWWoZm9ybURhdGGocmVxdWlyZWTDpHR5cGWySHR0cF9Gb3JtRGF0YUVudHJ5pGtpbmTNIACka2luZBKkaXRlbYSkbmFtZahmb3JtRGF0YahyZXF1aXJlZMOkdHlwZbJIdHRwX0Zvcm1EYXRhRW50cnmka2luZM0gAISkdHlwZaZVSW50MzKkbmFtZad0aW1lb3V0pGtpbmQipnNjYWxhcoOkbmFtZad0aW1lb3V0pHR5cGWmVUludDMypGtpbmQEhqR0eXBlskh0dHBfRm9ybURhdGFFbnRyeaN1cmm/d3JhcDovL2Vucy93cmFwcy5ldGg6aHR0cEAxLjEuMKluYW1lc3BhY2WkSHR0cKpuYXRpdmVUeXBlrUZvcm1EYXRhRW50cnmka2luZM0EAapwcm9wZXJ0aWVzlIakdHlwZaZTdHJpbmekbmFtZaRuYW1lp2NvbW1lbnSyRm9ybURhdGEgZW50cnkga2V5qHJlcXVpcmVkw6RraW5kIqZzY2FsYXKEpG5hbWWkbmFtZaR0eXBlplN0cmluZ6hyZXF1aXJlZMOka2luZASFpHR5cGWmU3RyaW5npG5hbWWldmFsdWWnY29tbWVudNk+SWYgJ3R5cGUnIGlzIGRlZmluZWQsIHZhbHVlIGlzIHRyZWF0ZWQgYXMgYSBiYXNlNjQgYnl0ZSBzdHJpbmeka2luZCKmc2NhbGFyg6RuYW1lpXZhbHVlpHR5cGWmU3RyaW5npGtpbmQEhaR0eXBlplN0cmluZ6RuYW1lqGZpbGVOYW1lp2NvbW1lbnTZIUZpbGUgbmFtZSB0byByZXBvcnQgdG8gdGhlIHNlcnZlcqRraW5kIqZzY2FsYXKDpG5hbWWoZmlsZU5hbWWkdHlwZaZTdHJpbmeka2luZASFpHR5cGWmU3RyaW5npG5hbWWkdHlwZadjb21tZW502XJNSU1FIHR5cGUgKGh0dHBzOi8vZGV2ZWxvcGVyLm1vemlsbGEub3JnL2VuLVVTL2RvY3MvV2ViL0hUVFAvQmFzaWNzX29mX0hUVFAvTUlNRV90eXBlcykuIERlZmF1bHRzIHRvIGVtcHR5IHN0cmluZy6ka2luZCKmc2NhbGFyg6RuYW1lpHR5cGWkdHlwZaZTdHJpbmeka2luZASGpHR5cGWtSHR0cF9SZXNwb25zZaN1cmm/d3JhcDovL2Vucy93cmFwcy5ldGg6aHR0cEAxLjEuMKluYW1lc3BhY2WkSHR0cKpuYXRpdmVUeXBlqFJlc3BvbnNlpGtpbmTNBAGqcHJvcGVydGllc5SFpHR5cGWjSW50pG5hbWWmc3RhdHVzqHJlcXVpcmVkw6RraW5kIqZzY2FsYXKEpG5hbWWmc3RhdHVzpHR5cGWjSW50qHJlcXVpcmVkw6RraW5kBIWkdHlwZaZTdHJpbmekbmFtZapzdGF0dXNUZXh0qHJlcXVpcmVkw6RraW5kIqZzY2FsYXKEpG5hbWWqc3RhdHVzVGV4dKR0eXBlplN0cmluZ6hyZXF1aXJlZMOka2luZASEpHR5cGWzTWFwPFN0cmluZywgU3RyaW5nPqRuYW1lp2hlYWRlcnOjbWFwhqR0eXBls01hcDxTdHJpbmcsIFN0cmluZz6mc2NhbGFyhKRuYW1lp2hlYWRlcnOkdHlwZaZTdHJpbmeocmVxdWlyZWTDpGtpbmQEpGtpbmTOAAQAAqRuYW1lp2hlYWRlcnOja2V5hKRuYW1lp2hlYWRlcnOkdHlwZaZTdHJpbmeocmVxdWlyZWTDpGtpbmQEpXZhbHVlhKRuYW1lp2hlYWRlcnOkdHlwZaZTdHJpbmeocmVxdWlyZWTDpGtpbmQEpGtpbmQihKR0eXBlplN0cmluZ6RuYW1lpGJvZHmka2luZCKmc2NhbGFyg6RuYW1lpGJvZHmkdHlwZaZTdHJpbmeka2luZASzaW1wb3J0ZWRNb2R1bGVUeXBlc5GHo3Vyab93cmFwOi8vZW5zL3dyYXBzLmV0aDpodHRwQDEuMS4wqW5hbWVzcGFjZaRIdHRwqm5hdGl2ZVR5cGWmTW9kdWxlq2lzSW50ZXJmYWNlwqR0eXBlq0h0dHBfTW9kdWxlpGtpbmTNAQCnbWV0aG9kc5KGpG5hbWWjZ2V0pnJldHVyboSkdHlwZa1IdHRwX1Jlc3BvbnNlpG5hbWWjZ2V0pGtpbmQipm9iamVjdIOkbmFtZaNnZXSkdHlwZa1IdHRwX1Jlc3BvbnNlpGtpbmTNIACkdHlwZaZNZXRob2Ska2luZECocmVxdWlyZWTDqWFyZ3VtZW50c5KFpHR5cGWmU3RyaW5npG5hbWWjdXJsqHJlcXVpcmVkw6RraW5kIqZzY2FsYXKEpG5hbWWjdXJspHR5cGWmU3RyaW5nqHJlcXVpcmVkw6RraW5kBISkdHlwZaxIdHRwX1JlcXVlc3SkbmFtZadyZXF1ZXN0pGtpbmQipm9iamVjdIOkbmFtZadyZXF1ZXN0pHR5cGWsSHR0cF9SZXF1ZXN0pGtpbmTNIACGpG5hbWWkcG9zdKZyZXR1cm6EpHR5cGWtSHR0cF9SZXNwb25zZaRuYW1lpHBvc3Ska2luZCKmb2JqZWN0g6RuYW1lpHBvc3SkdHlwZa1IdHRwX1Jlc3BvbnNlpGtpbmTNIACkdHlwZaZNZXRob2Ska2luZECocmVxdWlyZWTDqWFyZ3VtZW50c5KFpHR5cGWmU3RyaW5npG5hbWWjdXJsqHJlcXVpcmVkw6RraW5kIqZzY2FsYXKEpG5hbWWjdXJspHR5cGWmU3RyaW5nqHJlcXVpcmVkw6RraW5kBISkdHlwZaxIdHRwX1JlcXVlc3SkbmFtZadyZXF1ZXN0pGtpbmQipm9iamVjdIOkbmFtZadyZXF1ZXN0pHR5cGWsSHR0cF9SZXF1ZXN0pGtpbmTNIACxaW1wb3J0ZWRFbnVtVHlwZXORhqR0eXBlsUh0dHBfUmVzcG9uc2VUeXBlqWNvbnN0YW50c5KkVEVYVKZCSU5BUlmjdXJpv3dyYXA6Ly9lbnMvd3JhcHMuZXRoOmh0dHBAMS4xLjCpbmFtZXNwYWNlpEh0dHCqbmF0aXZlVHlwZaxSZXNwb25zZVR5cGWka2luZM0CCA=="
-)
-}
+use polywrap_wasm::{embed_wrapper, wasm_package::WasmPackage};
 
 pub fn wasm_package() -> WasmPackage {
-  WasmPackage::new(
-    Arc::new(SimpleFileReader::new()),
-    wrap_info().ok(),
-    wrap_wasm().ok(),
-  )
+    embed_wrapper!(".")
 }
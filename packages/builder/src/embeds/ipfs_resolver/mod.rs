@@ -1,24 +1,5 @@
-use std::sync::Arc;
-use base64::{decode, DecodeError};
-use polywrap_core::file_reader::SimpleFileReader;
-use polywrap_wasm::wasm_package::WasmPackage;
-
-fn wrap_wasm() -> Result<Vec<u8>, DecodeError> {
-  decode(
-    "AGFzbQEAAAABgwETYAJ/fwF/YAN/f38Bf2ACf38AYAN/f38AYAF/AGAGf39/f39/AGAEf39/fwBgB39/f39/f38AYAF/AX9gAAF/YAF/AX5gBX9/f39/AGAFf39/f38Bf2AAAGAGf39/f39/AX9gA39/fgBgBH9/f38Bf2AHf39/f39/fwF/YAN+f38BfwKaAw4Ed3JhcBJfX3dyYXBfaW52b2tlX2FyZ3MAAgR3cmFwD19fd3JhcF9sb2FkX2VudgAEBHdyYXAZX193cmFwX2dldEltcGxlbWVudGF0aW9ucwAABHdyYXAkX193cmFwX2dldEltcGxlbWVudGF0aW9uc19yZXN1bHRfbGVuAAkEd3JhcCBfX3dyYXBfZ2V0SW1wbGVtZW50YXRpb25zX3Jlc3VsdAAEBHdyYXATX193cmFwX2ludm9rZV9lcnJvcgACBHdyYXAUX193cmFwX2ludm9rZV9yZXN1bHQAAgR3cmFwEF9fd3JhcF9zdWJpbnZva2UADgR3cmFwG19fd3JhcF9zdWJpbnZva2VfcmVzdWx0X2xlbgAJBHdyYXAXX193cmFwX3N1Ymludm9rZV9yZXN1bHQABAR3cmFwGl9fd3JhcF9zdWJpbnZva2VfZXJyb3JfbGVuAAkEd3JhcBZfX3dyYXBfc3ViaW52b2tlX2Vycm9yAAQEd3JhcAxfX3dyYXBfYWJvcnQABQNlbnYGbWVtb3J5AgACA4AC/gEGAwgLBgIBAgIBAgYBDwICBgUQAwQGAAMGAAYOAgIBAQIABwcHBwcHBQUFBQUFAgIDAwMBBwcHCAAHBwcAAgMMEQMABQASAwsAAgQABAAGAAMDAgYCAAAACAUFBQUFBQQHAgMDAQYCAgwCAgYGAwYGAQMDAgUEBQUFBQUCBQUFBQUGBQUGBgYCBQICAgEBCwIGAwMDAgAAAwMDAAMDAwAAAgADAAAAAAAABAADBAEAAgQCAwMEAgIDAwADBAgIAgQMCAgEAgINCAIEAAQEBAgAAAAABAAGBgQAAAQEAAAACwAAAgECAQMAAAMDAwEAAAEAAQEDAwkKCgoEBA0ECQQFAXABSkoGEgN/AUGAgAQLfwFBAAt/AUEACwecAQgMX3dyYXBfaW52b2tlAEAFYWxsb2MA2wEHZGVhbGxvYwDvARVhc3luY2lmeV9zdGFydF91bndpbmQAhwIUYXN5bmNpZnlfc3RvcF91bndpbmQAiAIVYXN5bmNpZnlfc3RhcnRfcmV3aW5kAIkCFGFzeW5jaWZ5X3N0b3BfcmV3aW5kAIgCEmFzeW5jaWZ5X2dldF9zdGF0ZQCKAgmIAQEAQQELSQ0Y6QHtAfUB+gH7AfQB7gHrAe4BpwGjAUVJ3AHqAd0B6AHyAVqzAYYC5gGGAroBT6sBgwIsZLIB+QFjrAG7AcYBogG1AbcB5AEmLvwBXOEB3gHwAViuAZkB/QGwAboBT7EB5QHfAcYByAGaAVG0AecBhAKFAuABYYgB1QHxAdYB1wEKlLIO/gGyTwIXfwR+IwFBAkYEQCMCIwIoAgBB3ABrNgIAIwIoAgAiASgCACEAIAEoAgghAiABKAIMIQMgASgCECEEIAEoAhQhBSABKAIYIQggASgCHCEKIAEpAiAhGyABKAIoIQsgASgCLCEMIAEoAjAhDSABKAI0IQ4gASgCOCEPIAEoAjwhESABKAJAIRQgASkCRCEdIAEoAkwhFSABKAJQIRYgASgCVCEXIAEoAlghGCABKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQYLIwFFBEAjAEGwA2siBCQAIARBAjYCKCADRSEICwJAIwFFBEAgCA0BIARBEGohCAsgBkVBASMBGwRAIAggAxDRAUEAIwFBAUYNAhoLIwFFBEAgBCgCECEKCyAGQQFGQQEjARsEQCAKEAFBASMBQQFGDQIaCyAIIARBoAFqIwEbIQggBkECRkEBIwEbBEAgCCAKIAMQDkECIwFBAUYNAhoLIwFFBEAgBCgCsAFBAkYiA0UEQCAEQdgCaiAEQaABakE0EP8BGiAEQRhqELkBIARBGGogBEHYAmoiCEE0EP8BIQMgChAhDAILIARB4AJqIARBqAFqIgEpAwA3AwAgBCAEKQOgASIbNwPYAiAEQdgCaiEACyAGQQNGQQEjARsEQEH3ogVBKyAAQfTvBEGY8QQQmwFBAyMBQQFGDQIaCyMBRQRAAAsLIwFFBEBBGxAPIgNFIQgLAkACQAJAIwFFBEAgCA0BIANBF2pBu5wFKAAAIgU2AAAgA0EQakG0nAUpAAA3AAAgA0EIakGsnAUpAAA3AAAgA0GknAUpAAAiGzcAAEEvEA8iCkUNASAKQbT7BEEvEP8BIQogAxAhIARBADYC7AIgBEIENwLkAiAEQq+AgIDwBTcC3AIgBCAKNgLYAiAEQdgCaiEIIARBoAFqIQMLIAZBBEZBASMBGwRAIAMgASACIAgQW0EEIwFBAUYNBBoLIwFFBEAgBEGgAWohAiAEQdgCaiEBCyAGQQVGQQEjARsEQCABIAIQKUEFIwFBAUYNBBoLIwFFBEAgBCgC2AIiAUEWRyECCwJAAkAjAUUEQAJAIAJFBEAgBCgC3AIiAg0BQQAhDUEBIQVBASEOQQAhDwwDCyAEKQPgAiIbQiCIpyEMIAQoAtwCIQMgG6chAgwFCyAEQdABaiEKQQAhFEEAIQ1BASEOQQAhD0EAIRFBACEVQQAhFkEBIQULA0AjAUUEQCAEQaABaiEDIARB2AJqIQELIAZBBkZBASMBGwRAIAEgAxAUQQYjAUEBRg0HGgsjAUUEQCAEKQPgAiEbIAQoAtgCIgFBFkchCCAEKALcAiEDCwJAAkACQCMBRQRAIAgNASAbpyELIBtCIIgiG6ciDEEERiEBCwJAAkACQCMBRQRAIAENASAMQQlHIgENAiADQeP7BEEJEP4BIgENAgsgBkEHRkEBIwEbBEAgCiADQQlB7PsEQQZB8vsEQRwQbUEHIwFBAUYNDRoLIwFFBEAgBEGgAWohCCAEQdgCaiEBCyAGQQhGQQEjARsEQCABIAgQFEEIIwFBAUYNDRoLIwFFBEAgBCkD4AIhGyAEKALYAiIBQRZHIRQgBCgC3AIhCAsCQCMBRQRAIBQNASANRSINRQRAIA4QIQsgBEHYAmohDgsgBkEJRkEBIwEbBEAgDiAKEDxBCSMBQQFGDQ4aCyMBRQRAIBtCIIghHSAEKALcAgRAIAQoAtgCECELIB2nIRQgG6chDUEBIRUgCCEODAcLCyMBRQRAIBtCIIgiHachDCAbpyECDAULCyMBRQRAIAMoAABB8MLRwwZGIgENAgsLIAZBCkZBASMBGwRAIAQgDEEAEKgBQQojAUEBRg0LGgsjAUUEQCAEKAIEIQIgBCgCACIIIAMgDBD/ASEAQREhAQwDCwsgBkELRkEBIwEbBEAgCiADQQRB7PsEQQZB8vsEQRwQbUELIwFBAUYNChoLIwFFBEAgBEGgAWohCCAEQdgCaiEBCyAGQQxGQQEjARsEQCABIAgQFEEMIwFBAUYNChoLIwFFBEAgBCkD4AIhGyAEKALYAiIBQRZHIRcgBCgC3AIhCAsCQCMBRQRAIBcNASAPRSIPRQRAIAUQIQsgBEHYAmohBQsgBkENRkEBIwEbBEAgBSAKEDxBDSMBQQFGDQsaCyMBRQRAIBtCIIghHSAEKALcAgRAIAQoAtgCECELIB2nIREgG6chD0EBIRYgCCEFDAQLCyMBRQRAIBtCIIgiHachDCAbpyECDAILCyMBRQRAIBtCIIinIQwgG6chAgwFCwsjAUUEQCALRSIARQRAIAMQIQsgCCEDDAQLCyMBRQRAIAJBAWshAiALRSILRQRAIAMQIQsgAg0BCwsjAUUEQCAVQQFxRSICDQECQCAWQQFxRSICRQRAIARBoAFqENkBIA4NASANIQEgFCEDIAUhAiAPIQwMBwtBDSEMQQ0QDyIDRSIADQQgA0EFaiIAQaX8BCkAADcAACADQaD8BCkAADcAAEETIQFBDSECDAMLIARB5ABqIBE2AgAgBCAUNgJYIAQgDTYCVCAEIA42AlAgBCAFrSIdIA+tQiCGhCIbNwJcIAQoAigiA0ECRiECCwJAAkACQCMBRQRAIAINASAEQfAAaiAEQSBqKQMANwMAIARBhAFqIARBNGopAgA3AgAgBEGMAWogBEE8aikCADcCACAEQZQBaiAEQcQAaiIPKQIANwIAIAQgBCkDGDcDaCAEIAM2AnggBCAEKQIsIhs3AnxBASELQQAhCiAUQQRHIgINAyAOKAAAQengmZsHRyICDQMgBEHcAGohGEEBIQpBACELQQAhA0EBIQxBASECCwNAIwFFBEAgAiEBIAMgC2oiAkEFSyEICwJAAkAjAUUEQCAIRQRAIAJBqIMEai0AACICIApBqIMEai0AACIKSyIIRQRAIAIgCkYiAg0DQQEhDCABQQFqIQJBACEDIAEhCwwECyABIANqIgFBAWoiAiALayEMQQAhAwwDCwsgBkEORkEBIwEbBEAgAkEGQdiNBBCmAUEOIwFBAUYNDBoLIwFFBEAACwsjAUUEQCAMIANBAWoiAkYhCkEAIAIgChshAyACQQAgChsgAWohAgsLIwFFBEAgAiADaiIKQQZJIgENAQsLIwFFBEBBASEKQQAhCEEAIQNBASENQQEhAgsDQCMBRQRAIAIhASADIAhqIgJBBUshDwsCQAJAIwFFBEAgD0UEQCACQaiDBGotAAAiAiAKQaiDBGotAAAiCkkiD0UEQCACIApGIgINA0EBIQ0gAUEBaiECQQAhAyABIQgMBAsgASADaiIBQQFqIgIgCGshDUEAIQMMAwsLIAZBD0ZBASMBGwRAIAJBBkHYjQQQpgFBDyMBQQFGDQwaCyMBRQRAAAsLIwFFBEAgDSADQQFqIgJGIQpBACACIAobIQMgAkEAIAobIAFqIQILCyMBRQRAIAIgA2oiCkEGSSIBDQELCyMBRQRAIAsgCCAIIAtJIgMbIg9BB08hAQsCQCMBRQRAIAENASAMIA0gAxsiDSAPaiIDIA1JIQELAkAjAUUEQCABDQEgA0EGSyEBCwJAIwFFBEAgAQ0BQaiDBCANQaiDBGoiASAPEP4BIRQLAkAjAUUEQCAUDQFBASEKQQAhA0EAIQhBASEBCwNAAkAjAUUEQCABIQIgASADaiIMQQVLIgENASACQX9zIANrQQZqIgFBBUshDgsCQAJAAkAjAUUEQCAORQRAQQUgAyAIamsiC0EGTyIODQIgAUGogwRqLQAAIgEgC0GogwRqLQAAIgtJIg5FBEAgASALRiIBDQQgAkEBaiEBQQAhA0EBIQogAiEIDAULIAxBAWoiASAIayEKQQAhAwwECwsgBkEQRkEBIwEbBEAgAUEGQeiNBBCmAUEQIwFBAUYNEhoLIwFFBEAACwsgBkERRkEBIwEbBEAgC0EGQfiNBBCmAUERIwFBAUYNERoLIwFFBEAACwsjAUUEQCAKIANBAWoiAUYhC0EAIAEgCxshAyACIAFBACALG2ohAQsLIwFFBEAgCiANRyICDQILCwsjAUUEQEEBIQpBACEDQQAhCEEBIQELA0ACQCMBRQRAIAEhAiABIANqIgxBBUsiAQ0BIAJBf3MgA2tBBmoiAUEFSyEOCwJAAkACQCMBRQRAIA5FBEBBBSADIAhqayILQQZPIg4NAiABQaiDBGotAAAiASALQaiDBGotAAAiC0siDkUEQCABIAtGIgENBCACQQFqIQFBACEDQQEhCiACIQgMBQsgDEEBaiIBIAhrIQpBACEDDAQLCyAGQRJGQQEjARsEQCABQQZB6I0EEKYBQRIjAUEBRg0SGgsjAUUEQAALCyAGQRNGQQEjARsEQCALQQZB+I0EEKYBQRMjAUEBRg0RGgsjAUUEQAALCyMBRQRAIAogA0EBaiIBRiELQQAgASALGyEDIAIgAUEAIAsbaiEBCwsjAUUEQCAKIA1HIgINAgsLCyMBRQRAIA1BB08iAUUEQEEAIQ4gDUUEQEIAIRtBACENDAgLIA1BA3EhAgJAIA1BAWtBA0kEQEGogwQhA0IAIRsMAQsgDUF8cSEBQaiDBCEDQgAhGwNAQgEgA0EDajEAAIYiHUIBIAMxAACGIBuEQgEgA0EBaiILMQAAhoRCASADQQJqMQAAhoSEIRsgA0EEaiEDIAFBBGsiAQ0ACwsgAkUiAQ0HA0BCASADMQAAhiIdIBuEIRsgA0EBaiEDIAJBAWsiAg0ACwwHCwsgBkEURkEBIwEbBEAgDUEGQciNBBD3AUEUIwFBAUYNDRoLIwFFBEAACwsjAUUEQCAPQQYgD2siA0shASAPIAMgARsiAUEBaiENQX8hDkKAgICAgMjgBCEbDAULCyAGQRVGQQEjARsEQCADQQZBuI0EEPcBQRUjAUEBRg0LGgsjAUUEQAALCyAGQRZGQQEjARsEQCANIANBuI0EEPgBQRYjAUEBRg0KGgsjAUUEQAALCyAGQRdGQQEjARsEQCAPQQZBqI0EEPcBQRcjAUEBRg0JGgsjAUUEQAALCyAGQRhGQQEjARsEQEHI9ARBK0GA9QQQ8wFBGCMBQQFGDQgaCyMBRQRAAAsLIAEgEUEGSSMBGyEBAkAjAUUEQCABDQFBACAPayEWQQYgDWshF0EAIQxBBSEDCwNAIwFFBEAgGyADIAVqMQAAiEIBgyIdUCEBCwJAIA4CfwJAAkAjAUUEQCABDQFBBiAPIA8gDiAOIA9JGyAUGyIVayIBQQZLIQJBACABIAIbIQIgDCAVaiEDIBVBqIMEaiEBCwJAAkACQCMBRQRAAkACQANAIAJFBEBBACAOIBQbIQEgDyEDA0AgASADTyICDQMgA0EBayIDQQVLIgINBiARIAMgDGoiAk0iCw0HIANBqIMEai0AACILIAIgBWotAABGIgINAAsgDCANaiEMIBciAyAURSIBDQoaDAsLIAMgEU8iCA0CIAJBAWshAiADIAVqIQsgAS0AACADQQFqIgghAyABQQFqIQEgCy0AACIKRiILDQALIAggFmohDCAURSIBDQcMCQsCQCAMQQZqIgNFIgENACADIBFPBEAgAyARRiIBDQEMBgsgAyAFaiwAAEG/f0wiAQ0FCyARIANrIREgAyAFaiEFDAoLIBEgDCAVaiIESyEAIBEgBCAAGyEACyAGQRlGQQEjARsEQCAAIBFBuP8EEKYBQRkjAUEBRg0QGgsjAUUEQAALCyAGQRpGQQEjARsEQCADQQZByP8EEKYBQRojAUEBRg0PGgsjAUUEQAALCyAGQRtGQQEjARsEQCACIBFB2P8EEKYBQRsjAUEBRg0OGgsjAUUEQAALCyAGQRxGQQEjARsEQCAFIBEgAyARQej/BBDsAUEcIwFBAUYNDRoLIwFFBEAACwsjAUUEQCAMQQZqIQwgFA0DCwsgA0EAIwEbCyIDIwEbIQ4LIwFFBEAgESAMQQVqIgNLIgENAQsLCyABIBFBAkkjARshAQJAAkACQAJAAkACQCMBRQRAIAENASARQS5HIQELAkAjAUUEQCABRQRAIAUvAABB0doBRiIBDQILAkAgBSwAACIBQX9KBEAgAUH/AXEhAwwBCyAFLQABQT9xIQIgAUEfcSEDIAFBX0siD0UEQCACIANBBnRyIQMMAQsgBS0AAkE/cSIPIAJBBnRyIQIgAUFwSQRAIAIgA0EMdHIhAwwBCyAFLQADQT9xIAJBBnRyIgIgA0ESdEGAgPAAcXIiA0GAgMQARiIBDQULAn8CQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAgA0HTAEwEQCADQcEATARAAkAgA0E3ayIBDgMDHQQAC0EAIgIgA0UNFxogA0EwRyIBDRxBAQwXCyADQcIAayIBDgwGCBsbBBsbGxsPGxMbCwJAIANB4gBrIgEOGQUHGxsDGw0bGw4bEhsbGxsbGwsUCRsbGxEACyADQdQAayIBDgcLFAkaGhoPGgtBAgwUC0EDDBMLQQQMEgtBBQwRC0EGDBALQQcMDwtBCAwOC0EJDA0LQQoMDAtBCwwLC0EMDAoLQQ0MCQtBDgwIC0EPDAcLQRAMBgtBEQwFC0ESDAQLQRMMAwtBFAwCC0EVDAELQRYLIQIgBSwAAUG/f0wiAQ0DIAVBAWohAyARQQFrIQUgBEHYAmohAQsgBkEdRkEBIwEbBEAgASACIAMgBRARQR0jAUEBRg0OGgsjAUUEQCAEKALYAiIDRSIBDQQMBQsLIAEgBEHYAmojARshASAGQR5GQQEjARsEQCABQRIgBUEuEBFBHiMBQQFGDQ0aCyMBRQRAIAQoAtgCIgMNBCAEQQY6AKABDAULCyMBRQRAIARBBToAoAEMBAsLIAZBH0ZBASMBGwRAIAUgEUEBIBFB/IAEEOwBQR8jAUEBRg0LGgsjAUUEQAALCyMBRQRAIARBBjoAoAEMAgsLIwFFBEAgBCgC3AIhAiAEIAQoAuACNgKEAiAEIAM2AoACIARBgAJqIQUgBEHYAmohAQsgBkEgRkEBIwEbBEAgASAFEEpBICMBQQFGDQkaCyMBRQRAIAQoAtgCIQELAn8CQAJAAkACQAJAAkACQAJAIwFFBEAgAQ0BIAQpA+ACIRsgBEGAAmohBSAEQdgCaiEBCyAGQSFGQQEjARsEQCABIAUQSkEhIwFBAUYNEhoLIwFFBEAgBCgC2AIiAQ0CIAQgBCkD4AIiHTcD4AIgBCAbNwPYAiAEQdgCakHY/gRBEBD+AUUiAQ0DIBtCAVYiAQ0EIBunIgFBAWsNBQwGCwsjAUUEQCAEKQLcAiIbQv8Bg0IEUSIBRQRAIAQgGzcDoAEMCAsgBEELOgCgAQwHCwsjAUUEQCAEKQLcAiIbQv8Bg0IEUSIBRQRAIAQgGzcDoAEMBwsgBEELOgCgAQwGCwsjAUUEQCAEKAKEAiIBQSBJIgVFBEAgBCABQSBrNgKEAiAEIAQoAoACIgFBIGo2AoACIARBkANqQgA3AwAgBEGIA2pCADcDACAEQYADakIANwMAIARB4AJqIAFBCGopAAA3AwAgBEHoAmogAUEQaikAADcDACAEQfACaiABQRhqIg8pAAA3AwAgBEIANwP4AiAEIAEpAAA3A9gCIARBiAJqIARB2AJqIgVBwAAQ/wEaIBtCElEEQCAEQSA6ALABIARCEjcDqAEgBEGxAWogBEGIAmoiBUHAABD/ARogBEEAOgD4ASAEQvAANwOgAUEADAgLIARBAjoA+AEgBEEJOgCgAUECDAcLIARBAjoA+AEgBEKCgICAwJHTADcDoAFBAgwGCwsjAUUEQCAEQgc3A6ABDAMLCyMBRQRAIARBDToAoAEMAgsLIwFFBEAgBEGAAmohBSAEQdgCaiEBCyAGQSJGQQEjARsEQCABIRIgBSEHIwFBAkYEQCMCIwIoAgBBIGs2AgAjAigCACIHKAIAIRIgBygCCCEJIAcoAgwhECAHKQIQIR4gBykCGCEcIAcoAgQhBwsCQAJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACETCyMBRQRAIwBB0AFrIgkkACAJQZABaiEQCyATRUEBIwEbBEAgECAHEEpBACMBQQFGDQEaCyMBRQRAIAkoApABIRALAkACQAJAAkACQCMBRQRAIBANASAJKQOYASEeIAlBkAFqIRALIBNBAUZBASMBGwRAIBAgBxBKQQEjAUEBRg0GGgsjAUUEQCAJKAKQAQ0CIAkpA5gBIhxCwABYDQMgCUECOgCAASAJIBw3A4gBIBynIQcMBAsLIwFFBEAgCQJ/IAkpApQBIhxC/wGDQgRSBEAgCSAcNwKEASAcQiCIpyEHQQAMAQsgCSAcQgiIPACBAUEDCzoAgAEMAwsLIwFFBEAgCQJ/IAkpApQBIhxC/wGDQgRSBEAgCSAcNwKEASAcQiCIpyEHQQAMAQsgCSAcQgiIPACBAUEDCzoAgAEMAgsLIwFFBEAgCUGQAWpBAEHAABCBAiAcpyIQIAcoAgQiE00EQCATIBBrIRkgBygCACITIBBqIRoCQCAQQQFHBEAgCUGQAWogEyAQEP8BGgwBCyAJIBMtAAA6AJABCyAHIBk2AgQgByAaNgIAIAlBjAFqIgcgCUGTAWooAAA2AAAgCSAJKACQATYAiQEgCUFAayAJQZcBakE5EP8BGiAJIAkoAIkBNgIAIAkgBygAADYAAyAJQQdqIAlBQGtBORD/ARogEkERaiAJQcAAEP8BGiASQRBqIBw8AAAgEiAeNwMIIBJCADcDAAwDCyAJQQA6AIABIAlCgoCAgMCR0wA3AoQBQYyZBSEHCwsjAUUEQCAJIAkoAIkBNgIAIAkgCUGMAWooAAA2AAMgCSkDgAEhHCASQRRqIAkoAAM2AAAgEkERaiAJKAIANgAAIBJBEGogBzoAACASIBw3AwggEkIBNwMACwsjAUUEQCAJQdABaiQACwwBCyETIwIoAgAgEzYCACMCIwIoAgBBBGo2AgAjAigCACITIBI2AgAgEyAHNgIEIBMgCTYCCCATIBA2AgwgEyAeNwIQIBMgHDcCGCMCIwIoAgBBIGo2AgALQSIjAUEBRg0MGgsjAUUEQCAEKQPYAlAEQCAEQYgCaiAEQeACakHQABD/ARogBEGoAWogBEGIAmoiBUHQABD/ARogBCAdNwOgAUEBDAQLIARBkAJqIARB6AJqKQMAIhs3AwAgBCAEKQPgAiIdNwOIAiAEQeACaiAbNwMAIAQgHTcD2AIgBEHYAmohBSAEQaABaiEBCyAGQSNGQQEjARsEQCABIRIgBSEHQQAhCUEAIRAjAUECRgRAIwIjAigCAEEMazYCACMCKAIAIgkoAgAhEiAJKAIEIQcgCSgCCCEJCwJAAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIRALIwFFBEAgEkEGOgAAIActAAAhEgsCQCMBRQRAIBINASAHLQAEQQNHDQEgB0EIaigCACIHKAIAIRIgBygCBCgCACEJCyAQRUEBIwEbBEAgEiAJEQQAQQAjAUEBRg0CGgsjAUUEQCAHKAIEKAIEBEAgBygCABAhCyAHECELCwwBCyEQIwIoAgAgEDYCACMCIwIoAgBBBGo2AgAjAigCACIQIBI2AgAgECAHNgIEIBAgCTYCCCMCIwIoAgBBDGo2AgALQSMjAUEBRg0MGgsLQQIjAUUNARoLIwEEfyABBSAEQQI6APgBQQILCyEBIwFFBEAgAkUiAkUEQCADECELIAFBAkciAQ0CCwsjAUUEQCAELQCgASIDQQNHIQELAkAjAUUEQCABDQEgA0EEa0H/AXEiA0EJSyADQQhGIgJyRSIBDQEgBCgCpAEiAygCACEBIAMoAgQoAgAhAgsgBkEkRkEBIwEbBEAgASACEQQAQSQjAUEBRg0JGgsjAUUEQCADKAIEKAIERSIBRQRAIAMoAgAiARAhCyADECELCyMBRQRAQQAhAUEBIQpBACELDAILCyMBRQRAIARBAzYC3AIgBCAYNgKAAiAEIARBgAJqNgLYAiAEQQE2ArQBIARCAjcCpAEgBEGc9QQ2AqABIAQgBEHYAmo2ArABIARBoAFqIQIgBEGIAmohAQsgBkElRkEBIwEbBEAgASACEDtBJSMBQQFGDQcaCyMBRQRAIARB6ABqIQIgBEGgAWohAQsgBkEmRkEBIwEbBEAgASACQQAQS0EmIwFBAUYNBxoLIwFFBEAgBCgCiAIhAyAEKAKQAiECIARBoAFqIQggBEHYAmohAQsgBkEnRkEBIwEbBEAgASADIAIgCBB4QScjAUEBRg0HGgsjAUUEQCAEKALgAiEMIAQoAtwCIQggBCgC2AIhASAEQawBaigCAARAIAQoAqgBECELIAQoAowCRSICRQRAIAMQIQtBASEKQQAhCwsLIwFFBEAgBEHoAGoQwQEgBCgCVARAIAQoAlAQIQsgBEHgAGooAgAEQCAEKAJcECELQRsQDyIDRSICDQMgA0EXakG7nAUoAAA2AAAgA0EQakG0nAUpAAA3AAAgA0EIakGsnAUpAAA3AAAgA0GknAUpAAA3AABBOhAPIgJFIgUNAyACQa38BEE6EP8BIQIgAxAhIARB2AJqIQMLIAZBKEZBASMBGwRAIAMgAkE6ELgBQSgjAUEBRg0GGgsgAyAEQeQCaiMBGyEDIAZBKUZBASMBGwRAIANBBEEAEF5BKSMBQQFGDQYaCyMBRQRAIARB2AFqIARB4AJqKQMANwMAIARB4AFqIARB6AJqIgUpAwA3AwAgBEEANgLMASAEQgQ3AsQBIARCuoCAgKAHNwK8ASAEIAI2ArgBIARBADYCsAEgBEIBNwOoASAEQgA3A6ABIAQgBCkD2AI3A9ABIARB0AFqIQMLIAZBKkZBASMBGwRAIANB5/wEQQ1B9PwEQSVBmf0EQQ4QbUEqIwFBAUYNBhoLAkACQAJAAkACQAJAAkACQAJAAkAjAUUEQCAKDQEgBEGgAWohBSAEQdgCaiECCyAGQStGQQEjARsEQCACIAUQgAFBKyMBQQFGDRAaCyMBRQRAIAQoAtgCIgJBEUYiBQ0CIAQoAuQCIQMgBCgC4AIhASAEKALcAiEKDAkLCyMBRQRAIARBoAFqIQUgBEHYAmohAgsgBkEsRkEBIwEbBEAgAiAFQQIQPkEsIwFBAUYNDxoLIwFFBEAgBCgC2AIiAkERRyIFDQMLIAZBLUZBASMBGwRAIANB+P8EQQNB+/8EQQ5B14MFQRAQbUEtIwFBAUYNDxoLIwFFBEAgBEGgAWohBSAEQdgCaiECCyAGQS5GQQEjARsEQCACIAVB+P8EQQMQIkEuIwFBAUYNDxoLIwFFBEAgBCgC2AIiAkERRyIFDQQgBEGgAWohBSAEQdgCaiECCyAGQS9GQQEjARsEQCACIAUQgAFBLyMBQQFGDQ8aCyMBRQRAIAQoAtgCIgJBEUciBQ0FIARB2AJqIQILIAZBMEZBASMBGwRAIAIgAxA8QTAjAUEBRg0PGgsjAUUEQCAEKALcAkUiAkUEQCAEKALYAiICECELCyAGQTFGQQEjARsEQCADQYmABUEIQZGABUEPQdeDBUEQEG1BMSMBQQFGDQ8aCyMBRQRAIARBoAFqIQUgBEHYAmohAgsgBkEyRkEBIwEbBEAgAiAFQYmABUEIECJBMiMBQQFGDQ8aCyMBRQRAIAQoAtgCIgJBEUciBQ0GIARBoAFqIQUgBEHYAmohAgsgBkEzRkEBIwEbBEAgAiAFIAEgDBDiAUEzIwFBAUYNDxoLIwFFBEAgBCgC2AIiAkERRyIFDQIgBEHYAmohAgsgBkE0RkEBIwEbBEAgAiADEDxBNCMBQQFGDQ8aCyMBRQRAIAQoAtwCRSICDQEgBCgC2AIiAhAhCwsgAiAEQdgCaiMBGyECIAZBNUZBASMBGwRAIAIgAxA8QTUjAUEBRg0OGgsjAUUEQCAEKALcAgRAIAQoAtgCECELIARBCGohBSAEKAKwASEDIAQoAqgBIQILIAZBNkZBASMBGwRAIAUgAxDRAUE2IwFBAUYNDhoLIwFFBEAgBCgCDCEFIAQoAgggAiADEP8BIQIgBEGgAWoQ2QEgACADNgIIIAAgBTYCBCAAIAI2AgAgCEUgCyABRXJyRQRAIAEQIQsgBEGwA2okAA8LCyMBRQRAIARB8ABqIgAgBEHkAmooAgAiATYCACAEIAQpAtwCNwNoDAULCyMBRQRAIARB8ABqIgAgBEHkAmooAgAiATYCACAEIAQpAtwCNwNoDAQLCyMBRQRAIARB8ABqIgAgBEHkAmooAgAiATYCACAEIAQpAtwCNwNoDAMLCyMBRQRAIARB8ABqIgAgBEHkAmooAgAiATYCACAEIAQpAtwCNwNoDAILCyMBRQRAIARB8ABqIgAgBEHkAmooAgAiATYCACAEIAQpAtwCNwNoCwsjAUUEQCAEQeQCaiAEQfAAaigCADYCACAEIAI2AtgCIAQgBCkDaDcC3AIgBEEANgKQAiAEQgE3A4gCIARBiAJqIQEgBEHYAmohAAsgBkE3RkEBIwEbBEAgACABQYCABBAZQTcjAUEBRg0IGiEACyMBRQRAIAANAiAEKAKIAiEKIAQoAowCIQEgBCgCkAIhAyAEQdgCaiIAEGxBDyECCwsjAUUEQCAEQaABahDZASAEIAM2AqwBIAQgATYCqAEgBCAKNgKkASAEIAI2AqABIARBoAFqIQALIAZBOEZBASMBGwRAQfeiBUErIABBhPAEQbjxBBCbAUE4IwFBAUYNBxoLIwFFBEAACwsgACAEQdAAaiMBGyEAIAZBOUZBASMBGwRAQfCWBUE3IABBmIAEQYSYBRCbAUE5IwFBAUYNBhoLIwFFBEAACwsjAUUEQEESIQxBEhAPIgNFIgANAiADQRBqQZ78BC8AADsAACADQQhqIgBBlvwEKQAANwAAIANBjvwEKQAANwAAQRIhAkETIQELCyMBRQRAIA8EQCAFECELIA1FIgANAiAOECEMAgsLIwFFBEAACwsjAUUEQCAEQaABaiIAENkBCwsjAUUEQCAEIAM2AqQBIAQgATYCoAEgBCACrSAMrUIghoQ3A6gBIARBoAFqIQEgBEHYAmohAAsgBkE6RkEBIwEbBEAgACABEMIBQTojAUEBRg0BGgsgACAEQdgCaiMBGyEAIAZBO0ZBASMBGwRAIABBqPEEEMMBQTsjAUEBRg0BGgsjAUUEQAALDwshByMCKAIAIAc2AgAjAiMCKAIAQQRqNgIAIwIoAgAiByAANgIAIAcgATYCBCAHIAI2AgggByADNgIMIAcgBDYCECAHIAU2AhQgByAINgIYIAcgCjYCHCAHIBs3AiAgByALNgIoIAcgDDYCLCAHIA02AjAgByAONgI0IAcgDzYCOCAHIBE2AjwgByAUNgJAIAcgHTcCRCAHIBU2AkwgByAWNgJQIAcgFzYCVCAHIBg2AlgjAiMCKAIAQdwAajYCAAuPMQIWfwV+IwFBAkYEQCMCIwIoAgBBhAFrNgIAIwIoAgAiASgCACEAIAEoAgghAiABKAIMIQMgASgCECEEIAEoAhQhBSABKQIYIRkgASgCICEIIAEoAiQhCSABKAIoIQogASgCLCELIAEpAjAhGiABKAI4IQwgASgCPCENIAEoAkAhDiABKAJEIQ8gASgCSCEQIAEoAkwhESABKAJQIRIgASgCVCETIAEpAlghGyABKAJgIRQgASgCZCEVIAEoAmghFiABKAJsIRcgASkCcCEcIAEpAnghHSABKAKAASEYIAEoAgQhAQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBgsjAUUEQCMAQdABayIDJABBGxAPIglFIQQLAn8CQAJAIwFFBEAgBA0BIAlBF2pBu5wFKAAANgAAIAlBEGpBtJwFKQAANwAAIAlBCGpBrJwFKQAANwAAIAlBpJwFKQAANwAAQRsQDyIIRSIEDQEgCEEXakHT9QQoAAA2AAAgCEEQakHM9QQpAAA3AAAgCEEIakHE9QQpAAA3AAAgCEG89QQpAAA3AAAgCRAhIANBADYChAEgA0IENwJ8IANCm4CAgLADNwJ0IAMgCDYCcCADQRhqIQogA0HwAGohBAsgBkVBASMBGwRAIAogASACIAQQW0EAIwFBAUYNBBoLIwFFBEAgA0HwAGohAiADQRhqIQELIAZBAUZBASMBGwRAIAIgARApQQEjAUEBRg0EGgsjAUUEQCADKAJwIgJBFkchAQsCQAJAIwFFBEACQCABRQRAIAMoAnQhAUEAIRAgA0EANgJgIAENAUEBIREMAwsgAygCdCIJQQh2IQEgAykDeCIZQiCIpyEFQQIhDSAZpwwGCyADQZgBaiICQQRyIRggA0HIAGohCEEAIRRBASERQQAhDUEAIRVBAiEOQQIhCgsDQCMBRQRAIANB8ABqIQQgA0EYaiECCyAGQQJGQQEjARsEQCAEIAIQFEECIwFBAUYNBxoLIwFFBEAgAykDeCEZIAMoAnQhCSADKAJwIgJBFkchBAsCQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAIwFFBEAgBA0BIBmnIRYgGUIgiKciBUEHayECCwJAIwFFBEACQCACDgICDAALIAVBEUYiAg0MIAVBF0ciAg0NIAlBv/YEQRcQ/gEiAg0NCyAGQQNGQQEjARsEQCAIIAlBF0HW9gRBDEHt9QRBHBBtQQMjAUEBRg0fGgsjAUUEQCADQfAAaiEEIANBGGohAgsgBkEERkEBIwEbBEAgBCACEJQBQQQjAUEBRg0fGgsjAUUEQCADLQB0IQQgAygCcCICQRZHIgUNA0ECIQ4gBEH/AXEiAg0HIANB8ABqIQQgA0EYaiECCyAGQQVGQQEjARsEQCAEIAIQbkEFIwFBAUYNHxoLIwFFBEAgAy0AcEEERyICDQQgAy8AcSICQQh2IQUMBQsLIwFFBEAgCUHX9QRBBxD+AUUhAgsCQCMBRQRAIAINASAJQYn2BEEHEP4BIgINDQsgBkEGRkEBIwEbBEAgCCAJQQdBkPYEQQtB7fUEQRwQbUEGIwFBAUYNHxoLIwFFBEAgA0HwAGohBCADQRhqIQILIAZBB0ZBASMBGwRAIAQgAhASQQcjAUEBRg0fGgsjAUUEQCADKAJ4IQwgAygCdCENIAMoAnAiAkEWRyIEDRMgA0HwAGohAgsgBkEIRkEBIwEbBEAgAiAIEDxBCCMBQQFGDR8aCyMBRQRAIAMoAnRFIgINGCADKAJwIgIQIQwYCwsgBkEJRkEBIwEbBEAgCCAJQQdB3vUEQQ9B7fUEQRwQbUEJIwFBAUYNHhoLIwFFBEAgA0HwAGohBCADQRhqIQILIAZBCkZBASMBGwRAIAQgAhCUAUEKIwFBAUYNHhoLIwFFBEAgAy0AdCEEAkAgAygCcCICQRZGBEBBAiEKIARB/wFxRSICDQEMGAsgAy8AdSIKIAMtAHdBEHRyIQEgAykDeCEZDBULIANB8ABqIQQgA0EYaiECCyAGQQtGQQEjARsEQCAEIAIQKUELIwFBAUYNHhoLIwFFBEAgAygCdCEFIAMoAnAiBEEWRyECCwJAIwFFBEACQCACRQRAIAUNAUEAIQtBACEKDAMLIAMpA3ghGQwVC0EAIQtBACEKCwNAIwFFBEAgA0HwAGohBCADQRhqIQILIAZBDEZBASMBGwRAIAQgAhAUQQwjAUEBRg0gGgsjAUUEQCADKQN4IRkgAygCdCECIAMoAnAiBEEWRiIPRQRAIAIhBQwWCyAZpyESIBlCIIinIgRBB0YhDwsCQAJ+AkACQAJAAkAjAUUEQCAPDQEgBEENRyIKDQIgAkHn/ARBDRD+ASIKDQILIAZBDUZBASMBGwRAIAggAkENQZD2BEELQe31BEEcEG1BDSMBQQFGDSYaCyMBRQRAIANB8ABqIQogA0EYaiEECyAGQQ5GQQEjARsEQCAKIAQQEkEOIwFBAUYNJhoLIwFFBEAgAygCeCETIAMoAnQhCiADKAJwIgRBFkciDw0EIANB8ABqIQQLIAZBD0ZBASMBGwRAIAQgCBA8QQ8jAUEBRg0mGgsjAUUEQCADKAJ0RSIEDQYgAygCcCIEECEMBgsLIwFFBEAgAkGC/gRBBxD+AUUiCw0CCwsgASADQRBqIwEbIQEgBkEQRkEBIwEbBEAgASAEQQAQqAFBECMBQQFGDSQaCyMBRQRAIAM1AhQhGiADKAIQIgogAiAEEP8BIQFBESEEIBogGUKAgICAcIOEDAMLCyAGQRFGQQEjARsEQCAIIAJBB0GQ9gRBC0Ht9QRBHBBtQREjAUEBRg0jGgsjAUUEQCADQfAAaiELIANBGGohBAsgBkESRkEBIwEbBEAgCyAEEBJBEiMBQQFGDSMaCyMBRQRAIAMoAnghFyADKAJwIgRBFkchDyADKAJ0IQsLAkAjAUUEQCAPDQEgA0HwAGohBAsgBkETRkEBIwEbBEAgBCAIEDxBEyMBQQFGDSQaCyMBRQRAIAMoAnRFIgQNBCADKAJwIgQQIQwECwsjAUUEQCALIQogAzUCfEIghiIaIBethAwCCwsjAQR+IBkFIAM1AnxCIIYiGiATrYQLCyEZIwFFBEAgEkUiAUUEQCACECELIAohBQwWCwsjAUUEQCAFQQFrIQUgEkUiBEUEQCACECELIAUNAQsLCyMBRQRAIBetQiCGIhogC62EIRwMFgsLIwFFBEAgCUEIdiEBIBlCIIinIQUgGachDAwZCwsjAUUEQCADLwB1IgogAy0Ad0EQdHIhASADKQN4IRkMBgsLIwFFBEAgAykDcCIZQhCIIRogGUIIiCEdIBlC/wGDQgRSIgINAiAapyEFIB2nIQILCyMBRQRAQQAhDiACQf8BcUEGayEECwJAIwFFBEACQCAEDgIEAgALIANBwAFqIgogBUEIdCIEIAJB/wFxchAXIANBBDYCvAEgAyADQcABajYCuAEgA0EBNgKEASADQgE3AnQgA0G4iQU2AnAgAyADQbgBajYCgAEgA0GoAWohAiADQfAAaiEBCyAGQRRGQQEjARsEQCACIAEQO0EUIwFBAUYNGxoLIwFFBEAgAygCxAEEQCADKALAARAhCyADKAKsASECIAMoArABIQQgAygCqAEhAQsgBkEVRkEBIwEbBEAgGCAIIAEgBBAnQRUjAUEBRg0bGgsjAUUEQCADQRI2ApgBIAJFIgINBCABECEMBAsLIwFFBEBBASEODAILCyMBRQRAIAMgHUL/AYMgGkIIhkKA/gODhEIIhiIaIBlC/4GAeIOENwNwIANBmAFqIQQgA0HwAGohAgsgBkEWRkEBIwEbBEAgBCACEJcBQRYjAUEBRg0ZGgsjAUUEQCADKAKYAUEWRyICDQIgAy0AnAEhDgsLIAIgA0HwAGojARshAiAGQRdGQQEjARsEQCACIAgQPEEXIwFBAUYNGBoLIwFFBEAgAygCdEUiAg0RIAMoAnAiAhAhDBELCyMBRQRAIANB+ABqIANBoAFqKQMANwMAIAMgAykDmAE3A3AgA0EANgLIASADQgE3A8ABIANB8ABqIQIgA0HAAWohAQsgBkEYRkEBIwEbBEAgAiABQfyFBRAWQRgjAUEBRg0XGiEBCyMBRQRAIAENAiADLwDBASADLQDDAUEQdHIhASADLQDAASEEIAMpAsQBIRkgA0HwAGoQ5AFBASECCwsjAUUEQCAZQiCIpyEFIBmnIQwMDQsLIAAgA0GoAWojARshACAGQRlGQQEjARsEQEHwlgVBNyAAQZSGBUGEmAUQmwFBGSMBQQFGDRUaCyMBRQRAAAsLIwFFBEAgCSkAAELw5L2zl43ZsvIAUSICDQcMAgsLIwFFBEAgCUGb9gRBERD+ASICDQELIAZBGkZBASMBGwRAIAggCUERQaz2BEETQe31BEEcEG1BGiMBQQFGDRMaCyMBRQRAIANB8ABqIQQgA0EYaiECCyAGQRtGQQEjARsEQCAEIAIQlAFBGyMBQQFGDRMaCyMBRQRAIAMoAnAiAkEWRyEFIAMtAHQhBAsCQCMBRQRAIAUNAUEAIQVBACECCwJAAkACQAJAIwFFBEAgBA0BIANB8ABqIQQgA0EYaiECCyAGQRxGQQEjARsEQCAEIAIQKkEcIwFBAUYNGBoLIwFFBEAgAygCdCEFIAMoAnAiEkEWRyICDQMgA0EANgLIASADQgQ3A8ABCwJ/IwFFBEBBBCAFRQ0BGiAFrSEbQQQhBEIAIRlBACECCwNAIwFFBEAgA0EANgKgASADQgE3A5gBIANBAzoAkAEgA0KAgICAgAQ3A3AgA0H09gQ2AowBIANBADYCgAEgA0EANgJ4IAMgA0GYAWo2AogBIANB8ABqIQULIAZBHUZBASMBGwRAIBlBASAFEFJBHSMBQQFGDRoaIQULIwFFBEAgBQ0JIAMoApgBIQsgAygCoAEhBQsgBkEeRkEBIwEbBEAgCEGUiwVBBiALIAVBmosFQQEQbUEeIwFBAUYNGhoLIwFFBEAgAygCnAEEQCADKAKYARAhCyADQfAAaiELIANBGGohBQsgBkEfRkEBIwEbBEAgCyAFEBRBHyMBQQFGDRoaCyMBRQRAIAMpA3ghGiADKAJ0IQUgAygCcCISQRZHIgsNBCADKALEASACRyELCwJAIwFFBEAgCw0BIANBwAFqIQQLIAZBIEZBASMBGwRAIAQgAhBzQSAjAUEBRg0bGgsjAUUEQCADKALAASEEIAMoAsgBIQILCyMBRQRAIBlCAXwhGSAEIAJBDGxqIgIgGjcCBCACIAU2AgAgAyADKALIAUEBaiICNgLIASADQfAAaiEFCyAGQSFGQQEjARsEQCAFIAgQPEEhIwFBAUYNGhoLIwFFBEAgAygCdARAIAMoAnAQIQsgGSAbUiIFDQELCyMBBH8gAgUgAygCwAELCyECIwFFBEAgAykCxAEhGyACQYB+cSEFCwsjAUUEQCADQeAAaiIEENgBIAMgGzcCZCADIAUgAkH/AXFyNgJgIANB8ABqIQILIAZBIkZBASMBGwRAIAIgCBA8QSIjAUEBRg0XGgsjAUUEQCADKAJ0RSICDRAgAygCcCICECEMEAsLIwFFBEAgAgRAIAJBDGwhAiAEIQgDQCAIQQRqKAIABEAgCCgCABAhCyAIQQxqIQggAkEMayICDQALCyADKALEAUUiAQ0CIAQQIQwCCwsjAUUEQCADKQN4IRoLCyMBRQRAIAMgGjcDeCADIAU2AnQgAyASNgJwIANBADYCoAEgA0IBNwOYASADQfAAaiECIANBmAFqIQELIAZBI0ZBASMBGwRAIAIgAUH09gQQFkEjIwFBAUYNFBohAQsjAUUEQCABDQQgAygCmAEhBCADKQKcASEZIAMoAnhFIgENBSADKAJ0IgEQIQwFCwsjAUUEQCAEIAMvAHUiCiADLQB3QRB0ckEIdCIBciEEIAMpA3ghGQwFCwsgASADQQhqIwEbIQEgBkEkRkEBIwEbBEAgASAFQQAQqAFBJCMBQQFGDRIaCyMBRQRAIAMoAgwhDCADKAIIIgQgCSAFEP8BQQh2IQFBESECDAkLCyAAIANBqAFqIwEbIQAgBkElRkEBIwEbBEBB8JYFQTcgAEGM9wRBhJgFEJsBQSUjAUEBRg0RGgsjAUUEQAALCyAAIANBqAFqIwEbIQAgBkEmRkEBIwEbBEBB8JYFQTcgAEGM9wRBhJgFEJsBQSYjAUEBRg0QGgsjAUUEQAALCyACQQwjARshAgsjAUUEQCAEQQh2IQEgGUIgiKchBSAZpyEMDAULCyAGQSdGQQEjARsEQCAIIAlBCEHs+wRBBkHt9QRBHBBtQScjAUEBRg0NGgsjAUUEQCADQfAAaiEEIANBGGohAgsgBkEoRkEBIwEbBEAgBCACEBRBKCMBQQFGDQ0aCyMBRQRAIAMpA3ghGSADKAJwIgJBFkchBSADKAJ0IQQLAkAjAUUEQCAFDQEgEARAIBEQIQsgA0HwAGohAgsgBkEpRkEBIwEbBEAgAiAIEDxBKSMBQQFGDQ4aCyMBRQRAIBlCIIghGiADKAJ0RSICRQRAIAMoAnAiAhAhCyAapyEUIBmnIRBBASEVIAQhEQwHCwsjAUUEQCAEQQh2IQEgGUIgiKchBSAZpyEMDAQLCyMBRQRAIA1BCHYhASADKAJ8IQUgDSEEDAMLCyMBRQRAIAMgGTcDeCADIAU2AnQgAyAENgJwIANBADYCoAEgA0IBNwOYASADQfAAaiECIANBmAFqIQELIAZBKkZBASMBGwRAIAIgAUGAgAQQFkEqIwFBAUYNCxohAQsjAUUEQCABRQRAIAMoApgBIgRBCHYhASADKQKcASEZIANB8ABqEFlBFCECDAILIANBqAFqIQALIAZBK0ZBASMBGwRAQfCWBUE3IABBmIAEQYSYBRCbAUErIwFBAUYNCxoLIwFFBEAACwsjAUUEQCAZpyEMIBlCIIinIQULCyMBRQRAIBYEQCAJECELIAQhCQwFCwsgAiADQfAAaiMBGyECIAZBLEZBASMBGwRAIAIgCBA8QSwjAUEBRg0IGgsjAUUEQCADKAJ0RSICDQEgAygCcCICECELCyMBRQRAIAFBAWshASAWRSICRQRAIAkQIQsgAQ0BCwsjAUUEQCAVRSIBDQEgA0GgAWogA0HoAGooAgA2AgAgAyADKQNgNwOYASATQQh2IQEgHEIgiKchBSATIQkgCiECIBynDAULCyMBRQRAQREhBUEREA8iCUUiAQ0CIAlBEGpB8vYELQAAOgAAIAlBCGpB6vYEKQAANwAAIAlB4vYEKQAANwAAQRMhAkERIQwgCUEIdiEBCwsjAUUEQCADQeAAahDYAUECIQ0gEEUiBA0CIBEQIQwCCwsjAUUEQAALCyAIIAwjARsLIQgjAUUEQCADQcgAahDMASADQSRqKAIABEAgAygCIBAhCyADQTBqEMwBIA1BAkYhBAsCQAJAIwFFBEAgBEUEQCAAIBQ2AiAgACAQNgIcIAAgETYCGCAAIAw2AhQgACANNgIQIAAgAjYCACAAIAMpA5gBNwIkIAAgDjoAMCAAIAMvABg7ADEgACAJQf8BcSABQQh0cjYCBCAAQSxqIANBoAFqKAIANgIAIABBM2ogA0Eaai0AADoAACAAIAitIAWtQiCGhDcCCAwCCyADIAI2AhggAyAJQf8BcSABQQh0cjYCHCADIAitIAWtQiCGhDcDICADQQA2AnggA0IBNwNwIANBGGohAiADQfAAaiEBCyAGQS1GQQEjARsEQCACIAFBgIAEEBZBLSMBQQFGDQMaIQELIwFFBEAgAQ0CIAAgAykDcDcCBCAAQQxqIANB+ABqKAIANgIAIANBGGoQWSAAQQI2AhAgAEEUNgIACwsjAUUEQCADQdABaiQADwsLIAAgA0GoAWojARshACAGQS5GQQEjARsEQEHwlgVBNyAAQZiABEGEmAUQmwFBLiMBQQFGDQEaCyMBRQRAAAsPCyEHIwIoAgAgBzYCACMCIwIoAgBBBGo2AgAjAigCACIHIAA2AgAgByABNgIEIAcgAjYCCCAHIAM2AgwgByAENgIQIAcgBTYCFCAHIBk3AhggByAINgIgIAcgCTYCJCAHIAo2AiggByALNgIsIAcgGjcCMCAHIAw2AjggByANNgI8IAcgDjYCQCAHIA82AkQgByAQNgJIIAcgETYCTCAHIBI2AlAgByATNgJUIAcgGzcCWCAHIBQ2AmAgByAVNgJkIAcgFjYCaCAHIBc2AmwgByAcNwJwIAcgHTcCeCAHIBg2AoABIwIjAigCAEGEAWo2AgAL4R0CCH8BfgJAAkACQAJAAkAgAEH1AU8EQCAAQc3/e08NBCAAQQtqIgBBeHEhBEH8uAUoAgAiCEUNA0EAIARrIQICf0EAIARBgAJJDQAaQR8gBEH///8HSw0AGiAEQQYgAEEIdmciAGt2QQFxIABBAXRrQT5qCyIGQQJ0QYi7BWooAgAiAARAIARBAEEZIAZBAXZrQR9xIAZBH0YbdCEHA0ACQCAAKAIEQXhxIgUgBEkNACAFIARrIgUgAk8NACAAIQMgBSICDQBBACECDAQLIABBFGooAgAiBSABIAUgB0EddkEEcSAAakEQaigCACIARxsgASAFGyEBIAdBAXQhByAADQALIAEEQCABIQAMAwsgAw0DC0EAIQNBAEECIAZ0IgBrIQEgCCAAIAFycSIARQ0DQQAgAGsgAHFoQQJ0QYi7BWooAgAiAA0BDAMLAkACQAJAAn8CQAJAQfi4BSgCACIDQRAgAEELakF4cSAAQQtJGyIEQQN2IgF2IgBBA3FFBEAgBEGIvAUoAgBNDQkgAA0BQfy4BSgCACIARQ0JQQAgAGsgAHFoQQJ0QYi7BWooAgAiAygCBEF4cSAEayEBIAMoAhAiAEUEQCADQRRqKAIAIQALIAAEQANAIAAoAgRBeHEgBGsiBSABSSECIAUgASACGyEBIAAgAyACGyEDIAAoAhAiAgR/IAIFIABBFGooAgALIgANAAsLIAMQVyABQRBJDQUgAyAEQQNyNgIEIAMgBGoiBSABQQFyNgIEIAEgBWogATYCAEGIvAUoAgAiBEUNBCAEQXhxQYC5BWohAEGQvAUoAgAhAkEBIARBA3Z0IgRB+LgFKAIAIgZxRQ0CIAAoAggMAwsCQCABIABBf3NBAXFqIgBBA3QiBUGIuQVqKAIAIgFBCGoiBCgCACICIAVBgLkFaiIFRwRAIAIgBTYCDCAFIAI2AggMAQtB+LgFIANBfiAAd3E2AgALIAEgAEEDdCIAQQNyNgIEIAAgAWoiACgCBEEBciEDIAAgAzYCBCAEDwtBAEECIAFBH3EiAXQiAmshBUEAIAIgBXIgACABdHEiAGshAQJAIAAgAXFoIgFBA3QiBUGIuQVqKAIAIgBBCGoiBigCACICIAVBgLkFaiIFRwRAIAIgBTYCDCAFIAI2AggMAQtB+LgFIANBfiABd3E2AgALIAAgBEEDcjYCBCAAIARqIgUgAUEDdCIDIARrIgFBAXI2AgQgACADaiABNgIAQYi8BSgCACICBEAgAkF4cUGAuQVqIQBBkLwFKAIAIQMCf0EBIAJBA3Z0IgJB+LgFKAIAIgRxBEAgACgCCAwBC0H4uAUgAiAEcjYCACAACyECIAAgAzYCCCACIAM2AgwgAyAANgIMIAMgAjYCCAtBkLwFIAU2AgBBiLwFIAE2AgAgBg8LQfi4BSAEIAZyNgIAIAALIQQgACACNgIIIAQgAjYCDCACIAA2AgwgAiAENgIIC0GQvAUgBTYCAEGIvAUgATYCAAwBCyADIAEgBGoiAEEDcjYCBCAAIANqIgAoAgRBAXIhASAAIAE2AgQLDAQLA0AgBCAAKAIEQXhxIgFNIQUgACADIAUgASAEayIBIAJJcSIFGyEDIAEgAiAFGyECIAAoAhAiAQR/IAEFIABBFGooAgALIgANAAsgA0UNAQtBiLwFKAIAIgAgBE9BACACIAAgBGtPGw0AIAMQVwJAIAJBEE8EQCADIARBA3I2AgQgAyAEaiIAIAJBAXI2AgQgACACaiACNgIAIAJBgAJPBEAgACACEFYMAgsgAkF4cUGAuQVqIQECf0EBIAJBA3Z0IgJB+LgFKAIAIgVxBEAgASgCCAwBC0H4uAUgAiAFcjYCACABCyECIAEgADYCCCACIAA2AgwgACABNgIMIAAgAjYCCAwBCyADIAIgBGoiAEEDcjYCBCAAIANqIgAoAgRBAXIhASAAIAE2AgQLDAILAkACQAJAAkACQAJAAkACQAJAAkBBiLwFKAIAIgMgBEkEQCAEQYy8BSgCACIASQ0EQQAhAiAEQa+ABGoiAEEQdkAAIgNBf0YiAQ0LIANBEHQiA0UNC0GYvAVBACAAQYCAfHEgARsiBUGYvAUoAgBqIgA2AgBBnLwFKAIAIgEgAEshAkGcvAUgASAAIAIbNgIAQZS8BSgCACICRQ0BQaC8BSEAA0AgACgCACIBIAAoAgQiBmogA0YNAyAAKAIIIgANAAsMAwtBkLwFKAIAIQACQCADIARrIgFBD00EQEGQvAVBADYCAEGIvAVBADYCACAAIANBA3I2AgQgACADaiIDKAIEQQFyIQEgAyABNgIEDAELQYi8BSABNgIAQZC8BSAAIARqIgI2AgAgAiABQQFyNgIEIAAgA2ogATYCACAAIARBA3I2AgQLIABBCGoPC0G0vAUoAgAiAEUgACADS3INAwwHCyAAKAIMIAEgAktyDQAgAiADSQ0DCyADQbS8BSgCACIASyEBQbS8BSAAIAMgARs2AgAgAyAFaiEBQaC8BSEAAkACQAJAA0AgACgCACABRg0BIAAoAggiAA0ACwwBCyAAKAIMRQ0BC0GgvAUhAANAAkAgAiAAKAIAIgFPBEAgACgCBCABaiIGIAJLDQELIAAoAgghAAwBCwtBlLwFIAM2AgBBjLwFIAVBKGsiADYCACADIABBAXI2AgQgACADakEoNgIEQbC8BUGAgIABNgIAIAZBIGtBeHFBCGsiACACQRBqSSEBIAIgACABGyIBQRs2AgRBoLwFKQIAIQkgAUEQakGovAUpAgA3AgAgASAJNwIIQaS8BSAFNgIAQaC8BSADNgIAQai8BSABQQhqNgIAQay8BUEANgIAIAFBHGohAANAIABBBzYCACAGIABBBGoiAEsNAAsgASACRg0HIAEgASgCBEF+cTYCBCACIAEgAmsiAEEBcjYCBCABIAA2AgAgAEGAAk8EQCACIAAQVgwICyAAQXhxQYC5BWohAwJ/QQEgAEEDdnQiAEH4uAUoAgAiAXEEQCADKAIIDAELQfi4BSAAIAFyNgIAIAMLIQAgAyACNgIIIAAgAjYCDCACIAM2AgwgAiAANgIIDAcLIAAgAzYCACAAIAUgACgCBGo2AgQgAyAEQQNyNgIEIAEgAyAEaiIAayEEQZS8BSgCACABRwRAIAFBkLwFKAIARg0EIAEoAgQiAkEDcUEBRw0FAkAgAkF4cSIFQYACTwRAIAEQVwwBCyABQQxqKAIAIgYgAUEIaigCACIHRwRAIAcgBjYCDCAGIAc2AggMAQtB+LgFQfi4BSgCAEF+IAJBA3Z3cTYCAAsgBCAFaiEEIAEgBWoiASgCBCECDAULQZS8BSAANgIAQYy8BSAEQYy8BSgCAGoiATYCACAAIAFBAXI2AgQMCAtBjLwFIAAgBGsiAzYCAEGUvAUgBEGUvAUoAgAiAGoiATYCACABIANBAXI2AgQgACAEQQNyNgIEIABBCGohAgwGC0G0vAUgAzYCAAwDCyAAIAUgBmo2AgRBlLwFQZS8BSgCACIAQQ9qQXhxIgNBCGs2AgBBjLwFIAVBjLwFKAIAaiIBIAAgA2tqQQhqIgI2AgAgA0EEayACQQFyNgIAIAAgAWpBKDYCBEGwvAVBgICAATYCAAwDC0GQvAUgADYCAEGIvAUgBEGIvAUoAgBqIgE2AgAgACABQQFyNgIEIAAgAWogATYCAAwECyABIAJBfnE2AgQgACAEQQFyNgIEIAAgBGogBDYCACAEQYACTwRAIAAgBBBWDAQLIARBeHFBgLkFaiEBAn9B+LgFKAIAIgJBASAEQQN2dCIFcQRAIAEoAggMAQtB+LgFIAIgBXI2AgAgAQshAiABIAA2AgggAiAANgIMIAAgATYCDCAAIAI2AggMAwtBuLwFQf8fNgIAQaS8BSAFNgIAQaC8BSADNgIAQYy5BUGAuQU2AgBBlLkFQYi5BTYCAEGIuQVBgLkFNgIAQZy5BUGQuQU2AgBBkLkFQYi5BTYCAEGkuQVBmLkFNgIAQZi5BUGQuQU2AgBBrLkFQaC5BTYCAEGguQVBmLkFNgIAQbS5BUGouQU2AgBBqLkFQaC5BTYCAEG8uQVBsLkFNgIAQbC5BUGouQU2AgBBxLkFQbi5BTYCAEG4uQVBsLkFNgIAQay8BUEANgIAQcy5BUHAuQU2AgBBwLkFQbi5BTYCAEHIuQVBwLkFNgIAQdS5BUHIuQU2AgBB0LkFQci5BTYCAEHcuQVB0LkFNgIAQdi5BUHQuQU2AgBB5LkFQdi5BTYCAEHguQVB2LkFNgIAQey5BUHguQU2AgBB6LkFQeC5BTYCAEH0uQVB6LkFNgIAQfC5BUHouQU2AgBB/LkFQfC5BTYCAEH4uQVB8LkFNgIAQYS6BUH4uQU2AgBBgLoFQfi5BTYCAEGMugVBgLoFNgIAQZS6BUGIugU2AgBBiLoFQYC6BTYCAEGcugVBkLoFNgIAQZC6BUGIugU2AgBBpLoFQZi6BTYCAEGYugVBkLoFNgIAQay6BUGgugU2AgBBoLoFQZi6BTYCAEG0ugVBqLoFNgIAQai6BUGgugU2AgBBvLoFQbC6BTYCAEGwugVBqLoFNgIAQcS6BUG4ugU2AgBBuLoFQbC6BTYCAEHMugVBwLoFNgIAQcC6BUG4ugU2AgBB1LoFQci6BTYCAEHIugVBwLoFNgIAQdy6BUHQugU2AgBB0LoFQci6BTYCAEHkugVB2LoFNgIAQdi6BUHQugU2AgBB7LoFQeC6BTYCAEHgugVB2LoFNgIAQfS6BUHougU2AgBB6LoFQeC6BTYCAEH8ugVB8LoFNgIAQfC6BUHougU2AgBBhLsFQfi6BTYCAEH4ugVB8LoFNgIAQZS8BSADNgIAQYC7BUH4ugU2AgBBjLwFIAVBKGsiADYCACADIABBAXI2AgQgACADakEoNgIEQbC8BUGAgIABNgIAC0EAIQIgBEGMvAUoAgAiAE8NAEGMvAUgACAEayIDNgIAQZS8BSAEQZS8BSgCACIAaiIBNgIAIAEgA0EBcjYCBCAAIARBA3I2AgQgAEEIag8LIAIPCyADQQhqC6EfAhF/An4jAUECRgRAIwIjAigCAEHIAGs2AgAjAigCACIBKAIAIQAgASgCCCECIAEoAgwhAyABKAIQIQQgASgCFCEFIAEoAhghBiABKAIcIQcgASgCICEIIAEoAiQhCSABKAIoIQogASkCLCEWIAEoAjQhDSABKAI4IQ4gASgCPCEQIAEpAkAhFyABKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQ8LIwFFBEAjAEGwBGsiByQAIAFBA2pBfHEiBSABayIGIAJLIQgLAkACQAJAAkAjAUUEQAJAIAhFBEAgASgAAEGAgYKEeHEiCA0BQQQgBiABIAVGGyIFIAJBBGsiBk8iCEUEQANAIAEgBWooAgBBgIGChHhxIggNAyAGIAVBBGoiBUsiCA0ACwsgASAGaigAAEGAgYKEeHEiBQ0BDAMLIAIhBSABIQYDQCAFRSIIDQMgBUEBayEFIAYsAAAhCSAGQQFqIQYgCUF/SiIIDQALCyACRSEFCwJAAkAjAUUEQCAFDQFBASEJAn8gASwAACIFQX9KBEAgBUH/AXEhBiABQQFqDAELIAEtAAFBP3EhBiAFQR9xIQggBUFfTQRAIAhBBnQgBnIhBiABQQJqDAELIAEtAAJBP3EiCiAGQQZ0ciEGIAVBcEkEQCAIQQx0IAZyIQYgAUEDagwBCyAIQRJ0QYCA8ABxIAEtAANBP3EgBkEGdHJyIgZBgIDEAEYiBQ0CIAFBBGoLIQUgB0EQaiEKIAEgAmoiCEEDaiIQIAVrQQJ2IgFBA0shAiABQQMgAhtBAWohAQsgD0VBASMBGwRAIAogARCWAUEAIwFBAUYNBxoLIwFFBEAgBygCFCEKIAcoAhAiAiAGNgIAIAdBATYCICAHIAo2AhwgByACNgIYIAUgCEYiAQ0CQQEhCUEEIQYLA0ACQCMBRQRAAn8gBSwAACIBQX9KBEAgAUH/AXEhASAFQQFqDAELIAUtAAFBP3EhCiABQR9xIQ4gAUFfSyINRQRAIAogDkEGdHIhASAFQQJqDAELIAUtAAJBP3EiDSAKQQZ0ciEKIAFBcEkEQCAKIA5BDHRyIQEgBUEDagwBCyAOQRJ0QYCA8ABxIAUtAANBP3EgCkEGdHJyIgFBgIDEAEYiCg0CIAVBBGoLIQUgCSAHKAIcRyEKCwJAIwFFBEAgCg0BIBAgBWtBAnZBAWohCiAHQRhqIQILIA9BAUZBASMBGwRAIAIhCyAJIREgCiESQQAhDEEAIRQjAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQwLAkACfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhFAsjAUUEQCMAQSBrIgwkACARIBJqIhIgEUkhEQsCQCMBRQRAAkAgEQ0AIAsoAgQiEUEBdCITIBJLIRUgEyASIBUbIhJBBEshEyASQQQgExsiEkECdCETIBJBgICAgAJJQQJ0IRUCQCARBEAgDEEENgIYIAwgEUECdDYCFCAMIAsoAgA2AhAMAQsgDEEANgIYCyAMIBMgFSAMQRBqEI4BIAwoAgBFBEAgDCgCBCERIAsgEjYCBCALIBE2AgAMAwsgDEEIaigCACILQYGAgIB4Rg0CIAtFDQAACwsgFEVBASMBGwRAENMBQQAjAUEBRg0CGgsjAUUEQAALCyMBRQRAIAxBIGokAAsMAQshCyMCKAIAIAs2AgAjAiMCKAIAQQRqNgIAIwIoAgAgDDYCACMCIwIoAgBBBGo2AgALQQEjAUEBRg0KGgsjAUUEQCAHKAIYIQILCyMBRQRAIAIgBmoiCiABNgIAIAcgCUEBaiIJNgIgIAZBBGohBiAFIAhHIgENAgsLCyMBRQRAIAcoAhwhCiAHKAIYIQIMAgsLIwFFBEBBACEJQQAhCkEEIQILCyABIARFIwEbIQECQAJAAkAjAUUEQCABDQFBEBAPIgVFIgENBSAFQQA2AgAgB0KEgICAEDcCpAIgByAFNgKgAiAJQQJ0IQ4gAyAEaiEQIAmtIRcgAyEECwNAIwFFBEACfyAELAAAIgVBf0oEQCAFQf8BcSEIIARBAWoMAQsgBC0AAUE/cSEGIAVBH3EhASAFQV9NBEAgBiABQQZ0ciEIIARBAmoMAQsgBC0AAkE/cSAGQQZ0ciEGIAVBcE8iBUUEQCAGIAFBDHRyIQggBEEDagwBCyAGQQZ0IAQtAANBP3EiBnIiBSABQRJ0QYCA8ABxciIIQYCAxABGIgENBCAEQQRqCyEEIAlFIQELAkAjAUUEQCABDQFCACEWQQAhBiAOIQEgAiEFA0AgBSgCACAIRiINRQRAIAZBAWshBiAWQgF9IRYgBUEEaiEFIAFBBGsiAUUiDUUNAQwDCwsgBygCqAIiBUUhAQsCQCMBRQRAIAENASAFQQJ0IgUgBygCoAIiCGpBBGsiASgCACINIAlsIAZrIQYgASAGNgIAIBcgDa1+IBZ9QiCIIRYgASAIRwRAIAhBCGshAQNAIBcgASAFaiIGNQIAfiAWfCEWIAYgFj4CACAWQiCIIRYgBUEEayIFQQRHIgYNAAsLIBZQIgENASAWpyEFIAdBoAJqIQELIA9BAkZBASMBGwRAIAEgBRC8AUECIwFBAUYNCxoLCyMBRQRAIAQgEEciAQ0CDAQLCwsjAUUEQCAAQQA2AgAgBygCpAJFIgANAyAHKAKgAiIAECEMAwsLIwFFBEAgAEEANgIIIABCATcCAAwCCwsjAUUEQCAHKAKkAiEOIAcoAqACIQQgBygCqAIiCEUhAQsCQAJAIwFFBEAgAQ0BIAhBAnQhBkEAIQEDQAJAIAEgBGooAgAiBQRAIAVnQQN2IAFyIQEMAQsgBiABQQRqIgFHDQELCyAIQQJ0IgggAUYiBQ0BIAggAWshDSAEIQUDQCAFKAIAIgZBGHQgBkEIdEGAgPwHcXIhCyAFIAsgBkEIdkGA/gNxIAZBGHZyciIGNgIAIAVBBGohBSAIQQRrIggNAAsgB0EIaiEFCyAPQQNGQQEjARsEQCAFIA0Q0QFBAyMBQQFGDQgaCyMBRQRAIAcoAgwhBSAHKAIIIAEgBGoiASANEP8BIQYgByANNgIgIAcgBTYCHCAHIAY2AhgMAgsLIwFFBEAgB0EANgIgIAdCATcDGAsLIwFFBEAgDgRAIAQQIQsgCUUhAQsCQCMBRUEAIwFFBEAgAQ0BIAIoAgAhCUEAIQUDQAJAAn8gAywAACIBQX9KBEAgAUH/AXEhBiADQQFqDAELIAMtAAFBP3EhBiABQR9xIQggAUFfTQRAIAYgCEEGdCIEciEGIANBAmoMAQsgAy0AAkE/cSAGQQZ0ciEGIAFBcEkEQCAIQQx0IgQgBnIhBiADQQNqDAELIAhBEnRBgIDwAHEiBCADLQADQT9xIAZBBnRyciIGQYCAxABGDQEgA0EEagshAyAGIAlHDQAgBUEBaiEFIAMgEEcNAQsLIAVFIQELIAEbRQRAA0AgASAHQRhqIwEbIQEgD0EERkEBIwEbBEAgARC9AUEEIwFBAUYNCRoLIwFFBEAgBUEBayIFDQELCwsjAUUEQCAAIAcpAxgiFjcCACAAQQhqIgAgB0EgaigCACIBNgIADAILCyAPQQVGQQEjARsEQEEAQQBB7PAEEKYBQQUjAUEBRg0GGgsjAUUEQAALCyMBRQRAIApFDQQgAhAhDAQLCyMBRQRAIAdBoAJqQf8BQYACEIECIAIEQEEAIQUDQCABIAVqLQAAIAdBoAJqaiAFOgAAIAVBAWoiBSACRw0ACwsgB0EgaiAHQaACakGAAhD/ARogByACNgIcIAcgATYCGCAERQ0CQRAQDyIFRSIGDQEgBUEANgIAIAdChICAgBA3AqQEIAcgBTYCoAQgAq0hFyAEIAMiCGohCgsDQCAFIAggCkcjARshBQJAIwFFBEAgBQ0BIAcoAqQEIQ4gBygCoAQhCiAHKAKoBCIIRSEFCwJAAkAjAUUEQCAFDQEgCEECdCEGQQAhCQJAAkADQCAJIApqKAIAIgUNASAJQQRqIgkgBkcNAAsMAQsgCSAFZ0EDdnIhCQsgCSAIQQJ0IghGIgUNASAIIAlrIRAgCiEFA0AgBSgCACIGQRh0IAZBCHRBgID8B3FyIQ0gBSANIAZBCHZBgP4DcSAGQRh2cnIiBjYCACAFQQRqIQUgCEEEayIIDQALCyAPQQZGQQEjARsEQCAHIBAQ0QFBBiMBQQFGDQgaCyMBRQRAIAcoAgQhBSAHKAIAIAkgCmoiCCAQEP8BIQYgByAQNgKoAiAHIAU2AqQCIAcgBjYCoAIMAgsLIwFFBEAgB0EANgKoAiAHQgE3A6ACCwsjAUUEQCAORSIFRQRAIAoQIQsgAkUhAgsCQCMBRQRAIAINASABLQAAIgEhBkEAIQULA0AgASAEIAVGIwEbIQECQCMBRUEAIwFFBEAgAUUEQCAGIAMgBWotAABGIgENAiAFIQQLIARFIQELIAEbRQRAA0AgASAHQaACaiMBGyEBIA9BB0ZBASMBGwRAIAEQvQFBByMBQQFGDQsaCyMBRQRAIARBAWsiBA0BCwsLIwFFBEAgACAHKQOgAjcCACAAQQhqIAdBqAJqKAIANgIADAgLCyMBRQRAIAVBAWohBQwBCwsLIA9BCEZBASMBGwRAQQBBAEHs8AQQpgFBCCMBQQFGDQYaCyMBRQRAAAsLIwFFBEAgCC0AACIGIAdqQSBqMQAAIhZC/wFRIQULAkAjAUUEQCAFDQEgCEEBaiEIIAcoAqgEIgVFIgYNAiAXIAVBAnQiBSAHKAKgBCIJakEEayIGNQIAfiAWfCEWIAYgFj4CACAWQiCIIRYgBiAJRiIGRQRAIAlBCGshCQNAIBcgBSAJaiIGNQIAfiAWfCEWIAYgFj4CACAWQiCIIRYgBUEEayIFQQRHIgYNAAsLIBZQIgUNAiAWpyEGIAdBoARqIQULIA9BCUZBASMBGwRAIAUgBhC8AUEJIwFBAUYNBhoLIwFFDQELCyMBRQRAIABBADYCACAHKAKkBEUNAyAHKAKgBBAhDAMLCyMBRQRAAAsLIwFFBEAgAEEANgIIIABCATcCAAsLIwFFBEAgB0GwBGokAAsPCyELIwIoAgAgCzYCACMCIwIoAgBBBGo2AgAjAigCACILIAA2AgAgCyABNgIEIAsgAjYCCCALIAM2AgwgCyAENgIQIAsgBTYCFCALIAY2AhggCyAHNgIcIAsgCDYCICALIAk2AiQgCyAKNgIoIAsgFjcCLCALIA02AjQgCyAONgI4IAsgEDYCPCALIBc3AkAjAiMCKAIAQcgAajYCAAusIAEFfyMBQQJGBEAjAiMCKAIAQRxrNgIAIwIoAgAiBygCACEAIAcoAgQhASAHKAIIIQIgBygCDCEDIAcoAhAhBCAHKAIUIQUgBygCGCEHCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEGCyMBRQRAIwAiBUFAaiIEJAAgAUH/AXEhAQsCQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQCMBRQRAAkAgAUEBaw4WAgMEBQYHCAkKCwwNDg8QERITFBUWFwALIARBCGohAQsgBkVBASMBGwRAIAEgAxDRAUEAIwFBAUYNGhoLIwFFBEAgBCgCDCEBIAQoAgggAiADEP8BIQIgACADNgIIIAAgATYCBCAAIAI2AgAMGQsLIwFFBEAgBEE4akGIrAQoAgA2AgAgBEGArAQpAgA3AzAgBEEwaiEFIARBIGohAQsgBkEBRkEBIwEbBEAgASAFIAIgAxByQQEjAUEBRg0ZGgsjAUUEQCAEKAIgIgEEQCAAIAQvACk7AAkgAEELaiAEQStqLQAAOgAAIAQoAiQhAiAAIAQtACg6AAggACACNgIEIAAgATYCAAwZCyAAQoCAgICAgMAINwIADBgLCyMBRQRAIARBOGpBmLAEKAIANgIAIARBkLAEKQIANwMwIARBMGohBSAEQSBqIQELIAZBAkZBASMBGwRAIAEgBSACIAMQckECIwFBAUYNGBoLIwFFBEAgBCgCICIBBEAgACAELwApOwAJIABBC2ogBEErai0AADoAACAEKAIkIQIgACAELQAoOgAIIAAgAjYCBCAAIAE2AgAMGAsgAEKAgICAgIDACDcCAAwXCwsgASAEQTBqIwEbIQEgBkEDRkEBIwEbBEAgAUGcsARBCiACIAMQEEEDIwFBAUYNFxoLIwFFBEAgBCgCMCIBBEAgACAEKQI0NwIEIAAgATYCAAwXCyAAQoCAgICAgMAINwIADBYLCyMBRQRAIARBOGpBqMUEKAIANgIAIARBoMUEKQIANwMwIARBMGohBSAEQSBqIQELIAZBBEZBASMBGwRAIAEgBSACIAMQckEEIwFBAUYNFhoLIwFFBEAgBCgCICIBBEAgACAELwApOwAJIABBC2ogBEErai0AADoAACAEKAIkIQIgACAELQAoOgAIIAAgAjYCBCAAIAE2AgAMFgsgAEKAgICAgIDACDcCAAwVCwsjAUUEQCAEQThqQbjJBCgCADYCACAEQbDJBCkCADcDMCAEQTBqIQUgBEEgaiEBCyAGQQVGQQEjARsEQCABIAUgAiADEHJBBSMBQQFGDRUaCyMBRQRAIAQoAiAiAQRAIAAgBC8AKTsACSAAQQtqIARBK2otAAA6AAAgBCgCJCECIAAgBC0AKDoACCAAIAI2AgQgACABNgIADBULIABCgICAgICAwAg3AgAMFAsLIwFFBEAgBEE4akHIzQQoAgA2AgAgBEHAzQQpAgA3AzAgBEEwaiEFIARBIGohAQsgBkEGRkEBIwEbBEAgASAFIAIgAxByQQYjAUEBRg0UGgsjAUUEQCAEKAIgIgEEQCAAIAQvACk7AAkgAEELaiAEQStqLQAAOgAAIAQoAiQhAiAAIAQtACg6AAggACACNgIEIAAgATYCAAwUCyAAQoCAgICAgMAINwIADBMLCyMBRQRAIARBOGpB2NEEKAIANgIAIARB0NEEKQIANwMwIARBMGohBSAEQSBqIQELIAZBB0ZBASMBGwRAIAEgBSACIAMQckEHIwFBAUYNExoLIwFFBEAgBCgCICIBBEAgACAELwApOwAJIABBC2ogBEErai0AADoAACAEKAIkIQIgACAELQAoOgAIIAAgAjYCBCAAIAE2AgAMEwsgAEKAgICAgIDACDcCAAwSCwsjAUUEQCAEQThqQZjjBCgCADYCACAEQZDjBCkCADcDMCAEQTBqIQUgBEEgaiEBCyAGQQhGQQEjARsEQCABIAUgAiADEHJBCCMBQQFGDRIaCyMBRQRAIAQoAiAiAQRAIAAgBC8AKTsACSAAQQtqIARBK2otAAA6AAAgBCgCJCECIAAgBC0AKDoACCAAIAI2AgQgACABNgIADBILIABCgICAgICAwAg3AgAMEQsLIwFFBEAgBEE4akGo5wQoAgA2AgAgBEGg5wQpAgA3AzAgBEEwaiEFIARBIGohAQsgBkEJRkEBIwEbBEAgASAFIAIgAxByQQkjAUEBRg0RGgsjAUUEQCAEKAIgIgEEQCAAIAQvACk7AAkgAEELaiAEQStqLQAAOgAAIAQoAiQhAiAAIAQtACg6AAggACACNgIEIAAgATYCAAwRCyAAQoCAgICAgMAINwIADBALCyMBRQRAIARBOGpB+NoEKAIANgIAIARB8NoEKQIANwMwIARBMGohBSAEQSBqIQELIAZBCkZBASMBGwRAIAEgBSACIAMQckEKIwFBAUYNEBoLIwFFBEAgBCgCICIBBEAgACAELwApOwAJIABBC2ogBEErai0AADoAACAEKAIkIQIgACAELQAoOgAIIAAgAjYCBCAAIAE2AgAMEAsgAEKAgICAgIDACDcCAAwPCwsjAUUEQCAEQThqQYjfBCgCADYCACAEQYDfBCkCADcDMCAEQTBqIQUgBEEgaiEBCyAGQQtGQQEjARsEQCABIAUgAiADEHJBCyMBQQFGDQ8aCyMBRQRAIAQoAiAiAQRAIAAgBC8AKTsACSAAQQtqIARBK2otAAA6AAAgBCgCJCECIAAgBC0AKDoACCAAIAI2AgQgACABNgIADA8LIABCgICAgICAwAg3AgAMDgsLIwFFBEAgBEE4akG46wQoAgA2AgAgBEGw6wQpAgA3AzAgBEEwaiEFIARBIGohAQsgBkEMRkEBIwEbBEAgASAFIAIgAxByQQwjAUEBRg0OGgsjAUUEQCAEKAIgIgEEQCAAIAQvACk7AAkgAEELaiAEQStqLQAAOgAAIAQoAiQhAiAAIAQtACg6AAggACACNgIEIAAgATYCAAwOCyAAQoCAgICAgMAINwIADA0LCyMBRQRAIARBOGpByO8EKAIANgIAIARBwO8EKQIANwMwIARBMGohBSAEQSBqIQELIAZBDUZBASMBGwRAIAEgBSACIAMQckENIwFBAUYNDRoLIwFFBEAgBCgCICIBBEAgACAELwApOwAJIABBC2ogBEErai0AADoAACAEKAIkIQIgACAELQAoOgAIIAAgAjYCBCAAIAE2AgAMDQsgAEKAgICAgIDACDcCAAwMCwsjAUUEQCAEQThqQcC4BCgCADYCACAEQbi4BCkCADcDMCAEQTBqIQUgBEEgaiEBCyAGQQ5GQQEjARsEQCABIAUgAiADEHJBDiMBQQFGDQwaCyMBRQRAIAQoAiAiAQRAIAAgBC8AKTsACSAAQQtqIARBK2otAAA6AAAgBCgCJCECIAAgBC0AKDoACCAAIAI2AgQgACABNgIADAwLIABCgICAgICAwAg3AgAMCwsLIAEgBEEQaiMBGyEBIAZBD0ZBASMBGwRAIAEgAxDRAUEPIwFBAUYNCxoLIwFFBEAgBCgCFCEHIAQoAhAgAiADEP8BIQUgAwRAIAMhAiAFIQEDQCABIAEtAAAiCEHBAGtB/wFxQRpJQQV0IAhyOgAAIAFBAWohASACQQFrIgINAAsLIARBMGohAQsgBkEQRkEBIwEbBEAgAUHc0QRBJCAFIAMQEEEQIwFBAUYNCxoLIwFFBEAgBCgCMCIBBEAgACAEKQI0NwIEIAAgATYCACAHRQ0LDAoLIABCgICAgICAwAg3AgAgBw0JDAoLCyABIARBGGojARshASAGQRFGQQEjARsEQCABIAMQ0QFBESMBQQFGDQoaCyMBRQRAIAQoAhwhByAEKAIYIAIgAxD/ASEFIAMEQCADIQIgBSEBA0AgASABLQAAIghB4QBrQf8BcUEaSUEFdCAIczoAACABQQFqIQEgAkEBayICDQALCyAEQTBqIQELIAZBEkZBASMBGwRAIAFBgNIEQSQgBSADEBBBEiMBQQFGDQoaCyMBRQRAIAQoAjAiAQRAIAAgBCkCNDcCBCAAIAE2AgAgB0UNCgwICyAAQoCAgICAgMAINwIAIAcNBwwJCwsgASAEQTBqIwEbIQEgBkETRkEBIwEbBEAgAUGk0gRBOiACIAMQEEETIwFBAUYNCRoLIwFFBEAgBCgCMCIBBEAgACAEKQI0NwIEIAAgATYCAAwJCyAAQoCAgICAgMAINwIADAgLCyABIARBMGojARshASAGQRRGQQEjARsEQCABQcS4BEE6IAIgAxAQQRQjAUEBRg0IGgsjAUUEQCAEKAIwIgEEQCAAIAQpAjQ3AgQgACABNgIADAgLIABCgICAgICAwAg3AgAMBwsLIwFFBEAgBEE4akGwtAQoAgA2AgAgBEGotAQpAgA3AzAgBEEwaiEFIARBIGohAQsgBkEVRkEBIwEbBEAgASAFIAIgAxByQRUjAUEBRg0HGgsjAUUEQCAEKAIgIgEEQCAAIAQvACk7AAkgAEELaiAEQStqLQAAOgAAIAQoAiQhAiAAIAQtACg6AAggACACNgIEIAAgATYCAAwHCyAAQoCAgICAgMAINwIADAYLCyMBRQRAIARBOGpBiL0EKAIANgIAIARBgL0EKQIANwMwIARBMGohBSAEQSBqIQELIAZBFkZBASMBGwRAIAEgBSACIAMQckEWIwFBAUYNBhoLIwFFBEAgBCgCICIBBEAgACAELwApOwAJIABBC2ogBEErai0AADoAACAEKAIkIQIgACAELQAoOgAIIAAgAjYCBCAAIAE2AgAMBgsgAEKAgICAgIDACDcCAAwFCwsjAUUEQCAEQThqQZjBBCgCADYCACAEQZDBBCkCADcDMCAEQTBqIQUgBEEgaiEBCyAGQRdGQQEjARsEQCABIAUgAiADEHJBFyMBQQFGDQUaCyMBRQRAIAQoAiAiAQRAIAAgBC8AKTsACSAAQQtqIARBK2otAAA6AAAgBCgCJCECIAAgBC0AKDoACCAAIAI2AgQgACABNgIADAULIABCgICAgICAwAg3AgAMBAsLIwFFBEAgBEE4akHo1gQoAgA2AgAgBEHg1gQpAgA3AzAgBEEwaiEFIARBIGohAQsgBkEYRkEBIwEbBEAgASAFIAIgAxByQRgjAUEBRg0EGgsjAUUEQCAEKAIgIgEEQCAAIAQvACk7AAkgAEELaiAEQStqLQAAOgAAIAQoAiQhAiAAIAQtACg6AAggACACNgIEIAAgATYCAAwECyAAQoCAgICAgMAINwIADAMLCyMBRQRAIAUQIQwCCwsjAUUEQCAFECELCyMBRQRAIARBQGskAAsPCyEGIwIoAgAgBjYCACMCIwIoAgBBBGo2AgAjAigCACIGIAA2AgAgBiABNgIEIAYgAjYCCCAGIAM2AgwgBiAENgIQIAYgBTYCFCAGIAc2AhgjAiMCKAIAQRxqNgIAC4ojAgh/An4jAUECRgRAIwIjAigCAEEoazYCACMCKAIAIgEoAgAhACABKAIIIQIgASgCDCEDIAEoAhAhBCABKQIUIQogASgCHCEGIAEoAiAhCCABKAIkIQkgASgCBCEBCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEFCyMBRQRAIwBB8ABrIgIkACACQdgAaiEDCyAFRUEBIwEbBEAgAyABEJQBQQAjAUEBRg0BGgsjAUUEQCACKAJYIgRBFkchAwsCQCMBRQRAAkAgA0UEQCACLQBcRQ0BIABCFjcCAAwDCyAAIAIpAF03AAUgAEEMaiACQeQAaigAADYAACAAIAItAFw6AAQgACAENgIADAILIAJB2ABqIQMLIAVBAUZBASMBGwRAIAMgARBuQQEjAUEBRg0CGgsjAUUEQCACLQBYQQRHIQMLAkACQAJAAkACQAJAAkAjAUUEQAJ/IANFBEAgAi8AWQwBCyACKQNYIgpC/wGDQgRSIgMNAiAKQgiIIgqnCyIEIARBgP4DcUEIdhBiQRh0QRh1IgNBf0oiBg0CIANB4AFxIgZBJCADEGJB/wFxRiEDCwJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAIwFFBEAgA0UEQCAEQf8BcUEQayIDDggDBAUGBwgJCgILIAJByABqIAQQFyACQQQ2AkQgAiACQcgAajYCQCACQQE2AmwgAkIBNwJcIAJB4IgFNgJYIAIgAkFAazYCaCACQdgAaiEEIAJBMGohAwsgBUECRkEBIwEbBEAgAyAEEDtBAiMBQQFGDRwaCyMBRQRAIAIoAkwEQCACKAJIECELIAJBIGpBBHIhBCABQTBqIQggAigCMCEGIAIoAjghCSACKAI0IQMLIAVBA0ZBASMBGwRAIAQgCCAGIAkQJ0EDIwFBAUYNHBoLIwFFBEBBCyEEIAJBCzYCICADRSIDDRkgBhAhDBcLCyMBRQRAIAJByABqIAQQFyACQQQ2AkQgAiACQcgAajYCQCACQQE2AmwgAkIBNwJcIAJBjIkFNgJYIAIgAkFAazYCaCACQdgAaiEEIAJBMGohAwsgBUEERkEBIwEbBEAgAyAEEDtBBCMBQQFGDRsaCyMBRQRAIAIoAkwEQCACKAJIECELIAIoAjQhAyACQSBqQQRyIQQgAUEwaiEGIAIoAjghCCACKAIwIQELIAVBBUZBASMBGwRAIAQgBiABIAgQJ0EFIwFBAUYNGxoLIwFFBEBBEiEEIAJBEjYCICADRSIDDRggARAhDBgLCyMBRQRAIAJBADoASCACQcgAaiEEIAJB2ABqIQMLIAVBBkZBASMBGwRAIAMgASAEQQEQeUEGIwFBAUYNGhoLIwFFBEAgAi0AWEEERiIDRQRAIAIpA1giCkL/AYNCBFIiAw0SCyACIAIxAEgiCjcDKAwWCwsjAUUEQCACQQA7AUggAkHIAGohBCACQdgAaiEDCyAFQQdGQQEjARsEQCADIAEgBEECEHlBByMBQQFGDRkaCyMBRQRAIAItAFhBBEcEQCACKQNYIgpC/wGDQgRSIgMNEAsgAiACLwFIIgdBCHQgB0EIdiIEciIDrUL//wODIgo3AygMFQsLIwFFBEAgAkEANgJIIAJByABqIQQgAkHYAGohAwsgBUEIRkEBIwEbBEAgAyABIARBBBB5QQgjAUEBRg0YGgsjAUUEQCACLQBYQQRHBEAgAikDWCIKQv8Bg0IEUiIDDQ4LIAIgAigCSCIHQRh0IAdBCHRBgID8B3FyIAdBCHZBgP4DcSIGIAdBGHZyIgRyIgOtIgo3AygMFAsLIwFFBEAgAkIANwNIIAJByABqIQQgAkHYAGohAwsgBUEJRkEBIwEbBEAgAyABIARBCBB5QQkjAUEBRg0XGgsjAUUEQCACLQBYQQRGIgNFBEAgAikDWCIKQv8Bg0IEUiIDDQwLIAIpA0giCkI4hiELIAIgCkIoiEKA/gODIApCOIiEIApCCIhCgICA+A+DIApCGIhCgID8B4OEhCAKQhiGQoCAgICA4D+DIApCCIZCgICAgPAfg4QgCyAKQiiGQoCAgICAgMD/AIOEhIQiCjcDKAwTCwsjAUUEQCACQQA6AEggAkHIAGohBiACQdgAaiEDCyAFQQpGQQEjARsEQCADIAEgBkEBEHlBCiMBQQFGDRYaCyMBRQRAIAItAFhBBEcEQCACKQNYIgpC/wGDQgRSIgMNCQsgAiwASCIDQX9KIgYNCSACQcgAaiAEEBcgAkEENgJEIAIgAkHIAGo2AkAgAkEBNgJsIAJCATcCXCACQeCIBTYCWCACIAJBQGs2AmggAkHYAGohBCACQTBqIQMLIAVBC0ZBASMBGwRAIAMgBBA7QQsjAUEBRg0WGgsjAUUEQCACKAJMBEAgAigCSBAhCyACKAI0IQMgAkEgakEEciEEIAFBMGohBiACKAI4IQggAigCMCEBCyAFQQxGQQEjARsEQCAEIAYgASAIECdBDCMBQQFGDRYaCyMBRQRAQQshBCACQQs2AiAgA0UiAw0TIAEQIQwTCwsjAUUEQCACQQA7AUggAkHIAGohBiACQdgAaiEDCyAFQQ1GQQEjARsEQCADIAEgBkECEHlBDSMBQQFGDRUaCyMBRQRAIAItAFhBBEcEQCACKQNYIgpC/wGDQgRSIgMNBgsgAi8BSCIHQQh0IAdBCHZyQRB0QRB1IgNBf0oiBg0GIAJByABqIAQQFyACQQQ2AkQgAiACQcgAajYCQCACQQE2AmwgAkIBNwJcIAJB4IgFNgJYIAIgAkFAazYCaCACQdgAaiEEIAJBMGohAwsgBUEORkEBIwEbBEAgAyAEEDtBDiMBQQFGDRUaCyMBRQRAIAIoAkwEQCACKAJIECELIAIoAjQhAyACQSBqQQRyIQQgAUEwaiEGIAIoAjghCCACKAIwIQELIAVBD0ZBASMBGwRAIAQgBiABIAgQJ0EPIwFBAUYNFRoLIwFFBEBBCyEEIAJBCzYCICADRSIDDRIgARAhDBILCyMBRQRAIAJBADYCSCACQcgAaiEGIAJB2ABqIQMLIAVBEEZBASMBGwRAIAMgASAGQQQQeUEQIwFBAUYNFBoLIwFFBEAgAi0AWEEERwRAIAIpA1giCkL/AYNCBFIiAw0DCyACKAJIIgdBGHQgB0EIdEGAgPwHcXIgB0EIdkGA/gNxIgggB0EYdnJyIgNBf0oiBg0DIAJByABqIAQQFyACQQQ2AkQgAiACQcgAajYCQCACQQE2AmwgAkIBNwJcIAJB4IgFNgJYIAIgAkFAazYCaCACQdgAaiEEIAJBMGohAwsgBUERRkEBIwEbBEAgAyAEEDtBESMBQQFGDRQaCyMBRQRAIAIoAkwEQCACKAJIECELIAIoAjQhAyACQSBqQQRyIQQgAUEwaiEGIAIoAjghCCACKAIwIQELIAVBEkZBASMBGwRAIAQgBiABIAgQJ0ESIwFBAUYNFBoLIwFFBEBBCyEEIAJBCzYCICADRSIDDREgARAhDBELCyMBRQRAIAJCADcDSCACQcgAaiEGIAJB2ABqIQMLIAVBE0ZBASMBGwRAIAMgASAGQQgQeUETIwFBAUYNExoLIwFFBEAgAi0AWEEERwRAIAIpA1giCkL/AYNCBFIiAw0OCyACKQNIIgpCOIYhCyAKQiiIQoD+A4MgCkI4iIQgCkIIiEKAgID4D4MgCkIYiEKAgPwHg4SEIApCGIZCgICAgIDgP4MgCkIIhkKAgICA8B+DhCALIApCKIZCgICAgICAwP8Ag4SEhCIKQn9VIQMLAkAjAUUEQCADDQEgAkHIAGogBBAXIAJBBDYCRCACIAJByABqNgJAIAJBATYCbCACQgE3AlwgAkHgiAU2AlggAiACQUBrNgJoIAJB2ABqIQQgAkEwaiEDCyAFQRRGQQEjARsEQCADIAQQO0EUIwFBAUYNFBoLIwFFBEAgAigCTARAIAIoAkgQIQsgAigCNCEDIAJBIGpBBHIhBCABQTBqIQYgAigCOCEIIAIoAjAhAQsgBUEVRkEBIwEbBEAgBCAGIAEgCBAnQRUjAUEBRg0UGgsjAUUEQEELIQQgAkELNgIgIANFIgMNESABECEMEQsLIwFFBEAgAkEWNgIgIAIgCjcDKAwPCwsjAUUEQCACIAo3A1ggAkHYAGohBCACQSBqIQMLIAVBFkZBASMBGwRAIAMgBBCXAUEWIwFBAUYNEhoLIwFFDQwLIwFFBEAgAkEWNgIgIAIgA60iCjcDKAwNCwsjAUUEQCACIAo3A1ggAkHYAGohBCACQSBqIQMLIAVBF0ZBASMBGwRAIAMgBBCXAUEXIwFBAUYNEBoLIwFFDQoLIwFFBEAgAkEWNgIgIAIgA61C//8DgyIKNwMoDAsLCyMBRQRAIAIgCjcDWCACQdgAaiEEIAJBIGohAwsgBUEYRkEBIwEbBEAgAyAEEJcBQRgjAUEBRg0OGgsjAUUNCAsjAUUEQCACQRY2AiAgAiADrUL/AYMiCjcDKAwJCwsjAUUEQCACIAo3A1ggAkHYAGohBCACQSBqIQMLIAVBGUZBASMBGwRAIAMgBBCXAUEZIwFBAUYNDBoLIwFFDQYLIwFFBEAgAiAKNwNYIAJB2ABqIQQgAkEgaiEDCyAFQRpGQQEjARsEQCADIAQQlwFBGiMBQQFGDQsaCyMBRQ0FCyMBRQRAIAIgCjcDWCACQdgAaiEEIAJBIGohAwsgBUEbRkEBIwEbBEAgAyAEEJcBQRsjAUEBRg0KGgsjAUUNBAsjAUUEQCACIAo3A1ggAkHYAGohBCACQSBqIQMLIAVBHEZBASMBGwRAIAMgBBCXAUEcIwFBAUYNCRoLIwFFDQMLIwFFBEAgAiAKNwNYIAJB2ABqIQQgAkEgaiEDCyAFQR1GQQEjARsEQCADIAQQlwFBHSMBQQFGDQgaCyMBRQ0CCyMBRQRAIAJBFjYCICACIAOtQv8BgyIKNwMoDAMLCyMBRQRAIAIgCjcDWCACQdgAaiEEIAJBIGohAwsgBUEeRkEBIwEbBEAgAyAEEJcBQR4jAUEBRg0GGgsLIwFFBEAgAigCICIEQRZHIgMNAgsLIwFFBEAgAiACKQMoIgo3AxggCkKAgICAEFQhAwsCQCMBRQRAIAMNASACQQc2AkwgAiACQRhqNgJIIAJBATYCbCACQgI3AlwgAkGAigU2AlggAiACQcgAajYCaCACQdgAaiEEIAJBIGohAwsgBUEfRkEBIwEbBEAgAyAEEDtBHyMBQQFGDQUaCyMBRQRAIAJBCGpBBHIhAyABQTBqIQQgAigCKCEGIAIoAiAhAQsgBUEgRkEBIwEbBEAgAyAEIAEgBhAnQSAjAUEBRg0FGgsjAUUEQCACQQs2AgggAigCJEUiAw0DIAEQIQwDCwsjAUUEQCACQRY2AgggAiAKpyIBNgIMIABBCGogATYCACAAQpaAgIAQNwIADAMLCyMBRQRAIAIgAikDKDcDECACIAIoAiQiATYCDCACIAQ2AggLCyMBRQRAIAJB4ABqIAJBEGopAwA3AwAgAiACKQMINwNYIAJBADYCKCACQgE3AyAgAkEgaiEDIAJB2ABqIQELIAVBIUZBASMBGwRAIAEgA0H8hQUQFkEhIwFBAUYNAhohAQsjAUUEQCABRQRAIAAgAikDIDcCBCAAQQxqIAJBKGooAgA2AgAgAEEGNgIAIAJB2ABqEOQBDAILIAJByABqIQALIAVBIkZBASMBGwRAQfCWBUE3IABBlIYFQYSYBRCbAUEiIwFBAUYNAhoLIwFFBEAACwsjAUUEQCACQfAAaiQACw8LIQcjAigCACAHNgIAIwIjAigCAEEEajYCACMCKAIAIgcgADYCACAHIAE2AgQgByACNgIIIAcgAzYCDCAHIAQ2AhAgByAKNwIUIAcgBjYCHCAHIAg2AiAgByAJNgIkIwIjAigCAEEoajYCAAveEgEUfyMBQQJGBEAjAiMCKAIAQSRrNgIAIwIoAgAiASgCACEAIAEoAgghAiABKAIMIQMgASgCECEEIAEoAhQhBSABKAIYIQggASgCHCEHIAEoAiAhDCABKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQsLIwFFBEAgACgCCCIKQQFGIQQgACgCECEDCwJAAkAjAUUEQCAERQRAIANBAUciBA0CCwJAIANBAUciAw0AIAEgAmohCSAAQRRqKAIAQQFqIQhBACEHIAEhBAJAA0AgBCEDIAhBAWsiCEUNASADIAlGIgQNAgJ/IAMsAAAiBEF/SgRAIARB/wFxIQUgA0EBagwBCyADLQABQT9xIQUgBEEfcSEGIARBX00EQCAGQQZ0IAVyIQUgA0ECagwBCyADLQACQT9xIAVBBnRyIQUgBEFwSQRAIAZBDHQgBXIhBSADQQNqDAELIAZBEnRBgIDwAHEgAy0AA0E/cSAFQQZ0cnIiBUGAgMQARiIEDQMgA0EEagsiBCAHIANraiEHIAVBgIDEAEciAw0ACwwBCyADIAlGIgQNAAJAIAMsAAAiBEF/SiIFDQAgBEFgSSIFDQAgBEFwSSIFDQAgBEH/AXFBEnRBgIDwAHEiBCADLQABQT9xQQx0IgggAy0AAkE/cUEGdHIiBSADLQADQT9xcnJBgIDEAEYiAw0BCwJAAkAgB0UNACACIAdNBEBBACEDIAIgB0YiBA0BDAILQQAhAyABIAdqLAAAQUBIIgQNAQsgASEDCyAHIAIgAxshAiADIAEgAxshAQsLAkAjAUUEQCAKDQEgACgCGCEDIABBHGooAgAoAgwhAAsgC0VBASMBGwRAIAMgASACIAARAQBBACMBQQFGDQQaIQALIwFFBEAgAA8LCyMBRQRAIABBDGooAgAhDAJAAkACQCACQRBPBEAgAUEDakF8cSIDIAFrIgcgAksgB0EES3INAiACIAdrIglBBEkNAiAJQQNxIQpBACEGQQAhBAJAIAEgA0YiBQ0AIAdBA3EhBQJAIAFBf3MgA2pBA0kEQCABIQMMAQsgB0F8cSEIIAEhAwNAIAMsAABBv39KIARqIAMsAAFBv39KaiADLAACQb9/SmogAywAA0G/f0pqIQQgA0EEaiEDIAhBBGsiCA0ACwsgBUUiCA0AA0AgAywAAEG/f0oiCCAEaiEEIANBAWohAyAFQQFrIgUNAAsLIAEgB2ohAwJAIApFIgcNACAJQXxxIANqIgUsAABBv39KIQYgCkEBRiIHDQAgBiAFLAABQb9/SiIHaiEGIApBAkYNACAGIAUsAAJBv39KaiEGCyAJQQJ2IQkgBCAGaiEEA0AgAyEGIAlFDQQgCUHAASAJQcABSRsiB0EDcSEKIAdBAnQhDgJAIAdB/AFxIg1FBEBBACEFDAELIAYgDUECdGohD0EAIQUDQCADRSIIDQEgA0EMaigCACIIQX9zQQd2IRAgA0EIaigCACIRQX9zQQd2IRIgA0EEaigCACITQX9zQQd2IRQgAygCACIVQX9zQQd2IBVBBnZyQYGChAhxIAVqIBQgE0EGdnJBgYKECHFqIBIgEUEGdnJBgYKECHFqIBAgCEEGdnJBgYKECHFqIQUgDyADQRBqIgNHIggNAAsLIAkgB2shCSAGIA5qIQMgBUEIdkH/gfwHcSAFQf+B/AdxIgVqQYGABGxBEHYgBGohBCAKRSIHDQALIAZFBEBBACEDDAILIAYgDUECdGoiBSgCACIDQX9zQQd2IANBBnZyQYGChAhxIQMgCkEBRg0BIAUoAgQiCEF/c0EHdiAIQQZ2IgdyQYGChAhxIANqIQMgCkECRg0BIAUoAggiBUF/c0EHdiAFQQZ2IgdyQYGChAhxIANqIQMMAQsgAkUEQEEAIQQMAwsgAkEDcSEFAkAgAkEBa0EDSQRAQQAhBCABIQMMAQsgAkF8cSEIQQAhBCABIQMDQCADLAAAQb9/SiAEaiADLAABQb9/SmogAywAAkG/f0pqIAMsAANBv39KaiEEIANBBGohAyAIQQRrIggNAAsLIAVFDQIDQCADLAAAQb9/SiAEaiEEIANBAWohAyAFQQFrIgUNAAsMAgsgBCADQf+B/AdxIANBCHZB/4EccWpBgYAEbEEQdmohBAwBCyACQXxxIQVBACEEIAEhAwNAIAMsAABBv39KIARqIAMsAAFBv39KaiADLAACQb9/SmogAywAA0G/f0pqIQQgA0EEaiEDIAVBBGsiBQ0ACyACQQNxIghFDQBBACEFA0AgAyAFaiwAAEG/f0ogBGohBCAIIAVBAWoiBUcNAAsLIAQgDE8hAwsCQCMBRQRAIAMNASAMIARrIgQhByAALQAgIgNBA0YhBgJAAkACQEEAIAMgBhtBA3EiA0EBaw4CAAECC0EAIQcgBCEDDAELIARBAXYhAyAEQQFqQQF2IQcLIABBHGooAgAhBSAAQRhqKAIAIQggACgCBCEEIANBAWohAwsCQANAIwFFBEAgA0EBayIDRSIADQIgBSgCECEACyALQQFGQQEjARsEQCAIIAQgABEAAEEBIwFBAUYNBhohAAsjAUUEQCAARSIADQELCyMBRQRAQQEPCwsjAUUEQEEBIQMgBEGAgMQARg0DIAUoAgwhAAsgC0ECRkEBIwEbBEAgCCABIAIgABEBAEECIwFBAUYNBBohAAsjAUUEQCAADQNBACEDCwNAIwFFBEAgAyAHRgRAQQAPCyADQQFqIQMgBSgCECEACyALQQNGQQEjARsEQCAIIAQgABEAAEEDIwFBAUYNBRohAAsjAUUEQCAARSIADQELCyMBRQRAIAcgA0EBa0sPCwsjAUUEQCAAKAIYIQMgAEEcaigCACgCDCEACyALQQRGQQEjARsEQCADIAEgAiAAEQEAQQQjAUEBRg0DGiEACyMBRQRAIAAPCwsjAUUEQCAAKAIYIQMgAEEcaigCACgCDCEACyALQQVGQQEjARsEQCADIAEgAiAAEQEAQQUjAUEBRg0CGiEDCwsjAUUEQCADDwsACyEGIwIoAgAgBjYCACMCIwIoAgBBBGo2AgAjAigCACIGIAA2AgAgBiABNgIEIAYgAjYCCCAGIAM2AgwgBiAENgIQIAYgBTYCFCAGIAg2AhggBiAHNgIcIAYgDDYCICMCIwIoAgBBJGo2AgBBAAvsFwIMfwN+IwFBAkYEQCMCIwIoAgBByABrNgIAIwIoAgAiASgCACEAIAEoAgghAiABKAIMIQMgASgCECEEIAEoAhQhBSABKAIYIQcgASkCHCEOIAEpAiQhDyABKAIsIQkgASgCMCEKIAEoAjQhCyABKQI4IRAgASgCQCEMIAEoAkQhDSABKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQgLIwFFBEAjAEHgAGsiAiQAIAJByABqIQMLIAhFQQEjARsEQCADIAEQlAFBACMBQQFGDQEaCyMBRQRAIAIoAkgiA0EWRyEECwJAAkACQAJAIwFFBEAgBA0BIAItAEwhBEEAIQMLAkACQAJAAkAjAUUEQCAEDQEgAkHIAGohBAsgCEEBRkEBIwEbBEAgBCABEG5BASMBQQFGDQkaCyMBRQRAAn8gAi0ASEEERgRAIAIvAEkiB0EIdgwBCyACKQNIIg5CEIghDyAOQgiIIRAgDkL/AYNCBFIiBA0DIBCnIQcgD6cLIQQgB0H/AXEiCUECayEFCwJAIwFFBEACQCAFDgMCAgMACyAJQR1rIQMLAkACQAJAAkACQCMBRQRAAkAgAw4DAgMEAAsgAkE4aiAHQf8BcSIHIARBCHRyEBcgAkEENgI0IAIgAkE4ajYCMCACQQE2AlwgAkIBNwJMIAJBtIoFNgJIIAIgAkEwajYCWCACQcgAaiEEIAJBIGohAwsgCEECRkEBIwEbBEAgAyAEEDtBAiMBQQFGDQ8aCyMBRQRAIAIoAjwEQCACKAI4ECELIAIoAiQhByABQTBqIQUgAigCKCEJIAIoAiAhBCACQQhqQQRyIQMLIAhBA0ZBASMBGwRAIAMgBSAEIAkQJ0EDIwFBAUYNDxoLIwFFBEBBEiEDIAJBEjYCCCAHRSIHDQwgBBAhDAkLCyMBRQRAIAJBADoAOCACQThqIQQgAkHIAGohAwsgCEEERkEBIwEbBEAgAyABIARBARB5QQQjAUEBRg0OGgsjAUUEQCACLQBIQQRHBEAgAikDSCIOQv8BgyIPQgRSIgMNBQsgAi0AOCEDDAYLCyMBRQRAIAJBADsBOCACQThqIQQgAkHIAGohAwsgCEEFRkEBIwEbBEAgAyABIARBAhB5QQUjAUEBRg0NGgsjAUUEQCACLQBIQQRHBEAgAikDSCIOQv8BgyIPQgRSIgMNAwsgAi8BOCIGQQh0IgQgBkEIdnJB//8DcSEDDAULCyMBRQRAIAJBADYCOCACQThqIQQgAkHIAGohAwsgCEEGRkEBIwEbBEAgAyABIARBBBB5QQYjAUEBRg0MGgsjAUUEQAJAIAItAEhBBEcEQCACKQNIIg5C/wGDIg9CBFINAQsgAigCOCIGQRh0IAZBCHRBgID8B3FyIgQgBkEIdkGA/gNxIgcgBkEYdnJyIQMMBQsgAiAONwNIIAJByABqIQQgAkEIaiEDCyAIQQdGQQEjARsEQCADIAQQlwFBByMBQQFGDQwaCyMBRQ0FCyMBRQRAIAIgDjcDSCACQcgAaiEEIAJBCGohAwsgCEEIRkEBIwEbBEAgAyAEEJcBQQgjAUEBRg0LGgsjAUUNBAsjAUUEQCACIA43A0ggAkHIAGohBCACQQhqIQMLIAhBCUZBASMBGwRAIAMgBBCXAUEJIwFBAUYNChoLIwFFDQMLIAMgBEH/AXEjARshAwsjAUUEQCACIAM2AgwMAwsLIwFFBEAgAiAOQv+BgHiDIA9CCIZCgP4DgyAQQv8Bg4RCCIaEIg43A0ggAkHIAGohBCACQQhqIQMLIAhBCkZBASMBGwRAIAMgBBCXAUEKIwFBAUYNBxoLCyMBRQRAIAIoAggiA0EWRyIEDQMgAigCDCEDCwsjAUUEQCADrSEOIAJByABqIQQLIAhBC0ZBASMBGwRAIAQgASAOEBpBCyMBQQFGDQUaCyMBRQRAIAIoAkgiAUEWRyEDCwJAIwFFBEAgAw0BIAIoAkwhAyACQdAAaigCACEMIAJB1ABqKAIAIgdFDQQgByAHQQdrIgFJIQRBACABIAQbIQkgA0EDakF8cSADayENQQAhAQsDQCMBRQRAIAEgA2otAAAiBEEYdEEYdSILQQBIIQULAkACQCMBRQRAAkACQAJAAkACQAJAAkAgBUUEQCANIAFrQQNxIA1Bf0ZyDQEgASAJSQ0CDAkLQoCAgICAICEOQQAhCkKAgICAECEPAkACQAJAAkACQAJAIARBmIoEai0AAEECayIFDgMAAQIMC0EBIQogByABQQFqIgRLIgUNAwwJC0EBIQogByABQQFqIgVLDQEMCQtBASEKIAcgAUEBaiIFTQ0IIAMgBWosAAAhBQJAAkACQAJAIARB8AFrDgUBAAAAAgALIAVBf0ogC0EPakH/AXFBAktyIAVBQE9yDQkMAgsgBUHwAGpB/wFxQTBPDQgMAQsgBUGPf0oNBwtCACEOIAcgAUECaiIETSIFDQggAyAEaiwAAEG/f0oNBUIAIQ8gByABQQNqIgRNIgUNCSADIARqLAAAQb9/TCIFDQJCgICAgIDgACEODAYLIAMgBWosAAAhBQJAAkACQCAEQeABRwRAIARB7QFGDQEgC0EfakH/AXFBDEkNAiALQX5xQW5HIAVBf0pyIAVBQE9yDQkMAwsgBUFgcUGgf0cNCAwCCyAFQaB/Tg0HDAELIAVBv39KDQYLIAcgAUECaiIETSIFDQYgAyAEaiwAAEG/f0oiBQ0EDAELIAMgBGosAABBv39KIgUNBAsgBEEBaiEBDAkLIAFBAWohAQwICwNAIAEgA2oiBigCAEGAgYKEeHEiBQ0HIAZBBGooAgBBgIGChHhxDQcgAUEIaiIBIAlJDQALDAYLQoCAgICAwAAhDgtBACEKQoCAgIAQIQ8MAgtCACEOC0IAIQ8LIAIgBzYCECACIAw2AgwgAiADNgIIIAIgAa0gDiAPhIQ3AhQgAkEANgIoIAJCATcDICACQRRqIQELAkACQAJAIwFFBEAgCg0BIAIgDkIoiCIOPAAwIAJBxABqQQU2AgAgAiABNgJAIAJBBjYCPCACIAJBMGo2AjggAkECNgJcIAJCAjcCTCACQfiMBDYCSCACIAJBOGo2AlggAkHIAGohAyACQSBqIQELIAhBDEZBASMBGwRAIAFB/IUFIAMQK0EMIwFBAUYNDBohAQsjAUUEQCABRSIBDQIMAwsLIwFFBEAgAkEFNgI8IAIgATYCOCACQQE2AlwgAkIBNwJMIAJBxIwENgJIIAIgAkE4ajYCWCACQcgAaiEDIAJBIGohAQsgCEENRkEBIwEbBEAgAUH8hQUgAxArQQ0jAUEBRg0LGiEBCyMBRUEAIAEbDQELIwFFBEAgACACKQMgNwIEIABBDGogAkEoaigCADYCACAAQQ82AgAgAigCDEUNCSACKAIIECEMCQsLIAAgAkHIAGojARshACAIQQ5GQQEjARsEQEHwlgVBNyAAQZSGBUGEmAUQmwFBDiMBQQFGDQkaCyMBRQRAAAsLIwFFBEAgASAHTw0BA0AgASADaiwAAEEASA0CIAcgAUEBaiIBRw0ACwwGCwsjAUUEQCABIAdJIgQNAQsLIwFFDQMLIwFFBEAgAikCTCEOIAAgAigCVDYCDCAAIA43AgQgACABNgIADAQLCyMBRQRAIAJBFGogAkHUAGooAAA2AAAgAiACKQBNNwANIAIgAi0ATDoADCACIAM2AggLCyMBRQRAIAIoAgwhASAAIAIpAxA3AgggACABNgIEIAAgAzYCAAwCCwsjAUUEQCAAIAM2AgQgAEEWNgIAIABBDGogBzYCACAAQQhqIAw2AgALCyMBRQRAIAJB4ABqJAALDwshBiMCKAIAIAY2AgAjAiMCKAIAQQRqNgIAIwIoAgAiBiAANgIAIAYgATYCBCAGIAI2AgggBiADNgIMIAYgBDYCECAGIAU2AhQgBiAHNgIYIAYgDjcCHCAGIA83AiQgBiAJNgIsIAYgCjYCMCAGIAs2AjQgBiAQNwI4IAYgDDYCQCAGIA02AkQjAiMCKAIAQcgAajYCAAupGAIHfwJ+IwFBAkYEQCMCIwIoAgBBKGs2AgAjAigCACIGKAIAIQAgBigCBCEBIAYoAgghAiAGKAIMIQMgBigCECEEIAYpAhQhCSAGKAIcIQcgBigCICEIIAYoAiQhBgsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBQsjAUUEQCMAQeAAayICJAAgAkHIAGohAwsgBUVBASMBGwRAIAMgARBuQQAjAUEBRg0BGgsjAUUEQCACLQBIQQRHIQMLAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAIwFFBEACQAJ/IANFBEAgAi8ASQwBCyACKQNIIglC/wGDQgRSDQEgCUIIiCIJpwsiAyADQYD+A3FBCHYQYkEYdEEYdSIEQX9KIgcNAkEkIAQQYkH/AXEiCCAEQeABcUYiBw0DIANB/wFxQRBrIgQOCAUGBwgJCgsMBAsgAiAJNwNIIAJByABqIQQgAkEQaiEDCyAFQQFGQQEjARsEQCADIAQQlwFBASMBQQFGDRkaCyMBRQ0TCyMBRQRAIAIgBK1C/wGDIgk3AxgMFQsLIwFFBEAgAiAErCIJNwMYDBQLCyMBRQRAIAJBOGogAxAXIAJBBDYCNCACIAJBOGo2AjAgAkEBNgJcIAJCATcCTCACQfiHBTYCSCACIAJBMGo2AlggAkHIAGohBCACQSBqIQMLIAVBAkZBASMBGwRAIAMgBBA7QQIjAUEBRg0WGgsjAUUEQCACKAI8BEAgAigCOBAhCyACKAIkIQQgAkEQakEEciEDIAFBMGohByACKAIoIQggAigCICEBCyAFQQNGQQEjARsEQCADIAcgASAIECdBAyMBQQFGDRYaCyMBRQRAQRIhAyACQRI2AhAgBEUNFCABECEMFAsLIwFFBEAgAkEAOgA4IAJBOGohBCACQcgAaiEDCyAFQQRGQQEjARsEQCADIAEgBEEBEHlBBCMBQQFGDRUaCyMBRQRAIAItAEhBBEYiA0UEQCACKQNIIglC/wGDQgRSIgMNDwsgAiACMQA4Igk3AxgMEgsLIwFFBEAgAkEAOwE4IAJBOGohBCACQcgAaiEDCyAFQQVGQQEjARsEQCADIAEgBEECEHlBBSMBQQFGDRQaCyMBRQRAIAItAEhBBEcEQCACKQNIIglC/wGDQgRSIgMNDQsgAi8BOCIDQQh0IQQgAiAEIANBCHZyIgOtQv//A4MiCTcDGAwRCwsjAUUEQCACQQA2AjggAkE4aiEEIAJByABqIQMLIAVBBkZBASMBGwRAIAMgASAEQQQQeUEGIwFBAUYNExoLIwFFBEAgAi0ASEEERwRAIAIpA0giCUL/AYNCBFIiAw0LCyACKAI4IgNBGHQgA0EIdEGAgPwHcXIhBCACIAQgA0EIdkGA/gNxIgcgA0EYdnJyIgOtIgk3AxgMEAsLIwFFBEAgAkIANwM4IAJBOGohBCACQcgAaiEDCyAFQQdGQQEjARsEQCADIAEgBEEIEHlBByMBQQFGDRIaCyMBRQRAIAItAEhBBEcEQCACKQNIIglC/wGDQgRSIgMNCQsgAikDOCIJQjiGIAlCKIZCgICAgICAwP8Ag4QgCUIYhkKAgICAgOA/gyAJQgiGQoCAgIDwH4OEhCEKIAIgCiAJQiiIQoD+A4MgCUI4iIQgCUIIiEKAgID4D4MgCUIYiEKAgPwHg4SEhCIJNwMwIAlCf1UiAw0MIAJBBzYCJCACIAJBMGo2AiAgAkEBNgJcIAJCAjcCTCACQaiIBTYCSCACIAJBIGo2AlggAkHIAGohBCACQThqIQMLIAVBCEZBASMBGwRAIAMgBBA7QQgjAUEBRg0SGgsjAUUEQCACKAI8IQQgAUEwaiEIIAIoAjghByACKAJAIQYgAkEQakEEciEDCyAFQQlGQQEjARsEQCADIAggByAGECdBCSMBQQFGDRIaCyMBRQRAQQshAyACQQs2AhAgBEUiBA0OIAcQIQwOCwsjAUUEQCACQQA6ADggAkE4aiEEIAJByABqIQMLIAVBCkZBASMBGwRAIAMgASAEQQEQeUEKIwFBAUYNERoLIwFFBEAgAi0ASEEERiIDRQRAIAIpA0giCUL/AYNCBFIiAw0HCyACIAIwADgiCTcDGAwOCwsjAUUEQCACQQA7ATggAkE4aiEEIAJByABqIQMLIAVBC0ZBASMBGwRAIAMgASAEQQIQeUELIwFBAUYNEBoLIwFFBEAgAi0ASEEERwRAIAIpA0giCUL/AYNCBFIiAw0FCyACLwE4IgNBCHQhBCACIAQgA0EIdnIiA61CMIZCMIciCTcDGAwNCwsjAUUEQCACQQA2AjggAkE4aiEEIAJByABqIQMLIAVBDEZBASMBGwRAIAMgASAEQQQQeUEMIwFBAUYNDxoLIwFFBEAgAi0ASEEERwRAIAIpA0giCUL/AYNCBFIiAw0DCyACKAI4IgNBGHQgA0EIdEGAgPwHcXIhBCACIAQgA0EIdkGA/gNxIgcgA0EYdnJyIgOsIgk3AxgMDAsLIwFFBEAgAkIANwM4IAJBOGohBCACQcgAaiEDCyAFQQ1GQQEjARsEQCADIAEgBEEIEHlBDSMBQQFGDQ4aCyMBRQRAAkAgAi0ASEEERiIDRQRAIAIpA0giCUL/AYNCBFIiAw0BCyACKQM4IglCOIYgCUIohkKAgICAgIDA/wCDhCAJQhiGQoCAgICA4D+DIAlCCIZCgICAgPAfg4SEIQogAiAKIAlCKIhCgP4DgyAJQjiIhCAJQgiIQoCAgPgPgyAJQhiIQoCA/AeDhISEIgk3AxgMDAsgAiAJNwNIIAJByABqIQQgAkEQaiEDCyAFQQ5GQQEjARsEQCADIAQQlwFBDiMBQQFGDQ4aCyMBRQ0ICyMBRQRAIAIgCTcDSCACQcgAaiEEIAJBEGohAwsgBUEPRkEBIwEbBEAgAyAEEJcBQQ8jAUEBRg0NGgsjAUUNBwsjAUUEQCACIAk3A0ggAkHIAGohBCACQRBqIQMLIAVBEEZBASMBGwRAIAMgBBCXAUEQIwFBAUYNDBoLIwFFDQYLIwFFBEAgAiAJNwNIIAJByABqIQQgAkEQaiEDCyAFQRFGQQEjARsEQCADIAQQlwFBESMBQQFGDQsaCyMBRQ0FCyMBRQRAIAIgCTcDSCACQcgAaiEEIAJBEGohAwsgBUESRkEBIwEbBEAgAyAEEJcBQRIjAUEBRg0KGgsjAUUNBAsjAUUEQCACIAk3A0ggAkHIAGohBCACQRBqIQMLIAVBE0ZBASMBGwRAIAMgBBCXAUETIwFBAUYNCRoLIwFFDQMLIwFFBEAgAiAJNwNIIAJByABqIQQgAkEQaiEDCyAFQRRGQQEjARsEQCADIAQQlwFBFCMBQQFGDQgaCyMBRQ0CCyMBRQRAIAIgCTcDSCACQcgAaiEEIAJBEGohAwsgBUEVRkEBIwEbBEAgAyAEEJcBQRUjAUEBRg0HGgsjAUUNAQsjAUUEQEEWIQMgAkEWNgIQIAIgCTcDGAwCCwsjAUUEQCACKAIQIQMLCyMBRQRAIANBFkciBA0CCwsjAUUEQCACIAIpAxgiCTcDCCAJQoCAgIAIfEKAgICAEFQhAwsCQCMBRQRAIAMNASACQRI2AjwgAiACQQhqNgI4IAJBATYCXCACQgI3AkwgAkHMiQU2AkggAiACQThqNgJYIAJByABqIQQgAkEQaiEDCyAFQRZGQQEjARsEQCADIAQQO0EWIwFBAUYNBBoLIwFFBEAgAEEEaiEDIAFBMGohBCACKAIYIQcgAigCECEBCyAFQRdGQQEjARsEQCADIAQgASAHECdBFyMBQQFGDQQaCyMBRQRAIABBCzYCACACKAIURQ0DIAEQIQwDCwsjAUUEQCAAQRY2AgAgACAJPgIEDAILCyMBRQRAIAIoAhQhASAAIAIpAxg3AgggACABNgIEIAAgAzYCAAsLIwFFBEAgAkHgAGokAAsPCyEFIwIoAgAgBTYCACMCIwIoAgBBBGo2AgAjAigCACIFIAA2AgAgBSABNgIEIAUgAjYCCCAFIAM2AgwgBSAENgIQIAUgCTcCFCAFIAc2AhwgBSAINgIgIAUgBjYCJCMCIwIoAgBBKGo2AgALsRUBA38jAUECRgRAIwIjAigCAEEQazYCACMCKAIAIgMoAgAhACADKAIEIQEgAygCCCECIAMoAgwhAwsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBQsjAUUEQCMAQTBrIgMkACAAKAIAIQQLAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAjAUUEQAJAIARBAWsOFQIDBAUGBwgJCgsMDQ4PEBESExQVFgALIANBAzYCDCADIABBBGo2AhQgAyADQRRqNgIIIANBATYCLCADQgI3AhwgA0GwlAU2AhggAyADQQhqNgIoIANBGGohAAsgBUVBASMBGwRAIAEgAiAAECtBACMBQQFGDRcaIQALIwFFDRULIwFFBEAgA0EDNgIMIAMgAEEEajYCFCADIANBFGo2AgggA0EBNgIsIANCATcCHCADQZCkBTYCGCADIANBCGo2AiggA0EYaiEACyAFQQFGQQEjARsEQCABIAIgABArQQEjAUEBRg0WGiEACyMBRQ0UCyMBRQRAIANBAzYCDCADIABBBGo2AhQgAyADQRRqNgIIIANBATYCLCADQgE3AhwgA0GQpAU2AhggAyADQQhqNgIoIANBGGohAAsgBUECRkEBIwEbBEAgASACIAAQK0ECIwFBAUYNFRohAAsjAUUNEwsjAUUEQCADQQM2AgwgAyAAQQRqNgIUIAMgA0EUajYCCCADQQE2AiwgA0IBNwIcIANBkKQFNgIYIAMgA0EIajYCKCADQRhqIQALIAVBA0ZBASMBGwRAIAEgAiAAECtBAyMBQQFGDRQaIQALIwFFDRILIwFFBEAgA0EDNgIMIAMgAEEEajYCFCADIANBFGo2AgggA0EBNgIsIANCATcCHCADQZCkBTYCGCADIANBCGo2AiggA0EYaiEACyAFQQRGQQEjARsEQCABIAIgABArQQQjAUEBRg0TGiEACyMBRQ0RCyMBRQRAIANBAzYCDCADIABBBGo2AhQgAyADQRRqNgIIIANBATYCLCADQgE3AhwgA0GQpAU2AhggAyADQQhqNgIoIANBGGohAAsgBUEFRkEBIwEbBEAgASACIAAQK0EFIwFBAUYNEhohAAsjAUUNEAsjAUUEQCADQQM2AgwgAyAAQQRqNgIUIAMgA0EUajYCCCADQQE2AiwgA0IBNwIcIANBkKQFNgIYIAMgA0EIajYCKCADQRhqIQALIAVBBkZBASMBGwRAIAEgAiAAECtBBiMBQQFGDREaIQALIwFFDQ8LIwFFBEAgA0EDNgIMIAMgAEEEajYCFCADIANBFGo2AgggA0EBNgIsIANCATcCHCADQZCkBTYCGCADIANBCGo2AiggA0EYaiEACyAFQQdGQQEjARsEQCABIAIgABArQQcjAUEBRg0QGiEACyMBRQ0OCyMBRQRAIANBAzYCDCADIABBBGo2AhQgAyADQRRqNgIIIANBATYCLCADQgE3AhwgA0GQpAU2AhggAyADQQhqNgIoIANBGGohAAsgBUEIRkEBIwEbBEAgASACIAAQK0EIIwFBAUYNDxohAAsjAUUNDQsjAUUEQCADQQM2AgwgAyAAQQRqNgIUIAMgA0EUajYCCCADQQE2AiwgA0IBNwIcIANBkKQFNgIYIAMgA0EIajYCKCADQRhqIQALIAVBCUZBASMBGwRAIAEgAiAAECtBCSMBQQFGDQ4aIQALIwFFDQwLIwFFBEAgA0EDNgIMIAMgAEEEajYCFCADIANBFGo2AgggA0EBNgIsIANCATcCHCADQZCkBTYCGCADIANBCGo2AiggA0EYaiEACyAFQQpGQQEjARsEQCABIAIgABArQQojAUEBRg0NGiEACyMBRQ0LCyMBRQRAIANBAzYCDCADIABBBGo2AhQgAyADQRRqNgIIIANBATYCLCADQgE3AhwgA0GQpAU2AhggAyADQQhqNgIoIANBGGohAAsgBUELRkEBIwEbBEAgASACIAAQK0ELIwFBAUYNDBohAAsjAUUNCgsjAUUEQCADQQM2AgwgAyAAQQRqNgIUIAMgA0EUajYCCCADQQE2AiwgA0IBNwIcIANBkKQFNgIYIAMgA0EIajYCKCADQRhqIQALIAVBDEZBASMBGwRAIAEgAiAAECtBDCMBQQFGDQsaIQALIwFFDQkLIwFFBEAgA0EDNgIMIAMgAEEEajYCFCADIANBFGo2AgggA0EBNgIsIANCATcCHCADQZCkBTYCGCADIANBCGo2AiggA0EYaiEACyAFQQ1GQQEjARsEQCABIAIgABArQQ0jAUEBRg0KGiEACyMBRQ0ICyMBRQRAIANBAzYCDCADIABBBGo2AhQgAyADQRRqNgIIIANBATYCLCADQgE3AhwgA0GQpAU2AhggAyADQQhqNgIoIANBGGohAAsgBUEORkEBIwEbBEAgASACIAAQK0EOIwFBAUYNCRohAAsjAUUNBwsjAUUEQCADQQM2AgwgAyAAQQRqNgIUIAMgA0EUajYCCCADQQE2AiwgA0IBNwIcIANBkKQFNgIYIAMgA0EIajYCKCADQRhqIQALIAVBD0ZBASMBGwRAIAEgAiAAECtBDyMBQQFGDQgaIQALIwFFDQYLIwFFBEAgA0EDNgIMIAMgAEEEajYCFCADIANBFGo2AgggA0EBNgIsIANCATcCHCADQZCkBTYCGCADIANBCGo2AiggA0EYaiEACyAFQRBGQQEjARsEQCABIAIgABArQRAjAUEBRg0HGiEACyMBRQ0FCyMBRQRAIANBAzYCDCADIABBBGo2AhQgAyADQRRqNgIIIANBATYCLCADQgI3AhwgA0GElAU2AhggAyADQQhqNgIoIANBGGohAAsgBUERRkEBIwEbBEAgASACIAAQK0ERIwFBAUYNBhohAAsjAUUNBAsjAUUEQCADQQM2AgwgAyAAQQRqNgIUIAMgA0EUajYCCCADQQE2AiwgA0IBNwIcIANBkKQFNgIYIAMgA0EIajYCKCADQRhqIQALIAVBEkZBASMBGwRAIAEgAiAAECtBEiMBQQFGDQUaIQALIwFFDQMLIwFFBEAgA0EDNgIMIAMgAEEEajYCFCADIANBFGo2AgggA0EBNgIsIANCAjcCHCADQeCTBTYCGCADIANBCGo2AiggA0EYaiEACyAFQRNGQQEjARsEQCABIAIgABArQRMjAUEBRg0EGiEACyMBRQ0CCyMBRQRAIANBAzYCDCADIABBBGo2AhQgAyADQRRqNgIIIANBATYCLCADQgE3AhwgA0GQpAU2AhggAyADQQhqNgIoIANBGGohAAsgBUEURkEBIwEbBEAgASACIAAQK0EUIwFBAUYNAxohAAsjAUUNAQsjAUUEQCADQQM2AgwgAyAAQQRqNgIUIAMgA0EUajYCCCADQQE2AiwgA0IBNwIcIANBkKQFNgIYIAMgA0EIajYCKCADQRhqIQALIAVBFUZBASMBGwRAIAEgAiAAECtBFSMBQQFGDQIaIQALCyMBRQRAIANBMGokACAADwsACyEEIwIoAgAgBDYCACMCIwIoAgBBBGo2AgAjAigCACIEIAA2AgAgBCABNgIEIAQgAjYCCCAEIAM2AgwjAiMCKAIAQRBqNgIAQQALmQ0AAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAgAUH/AXFBAWsOIwECAwQFBgYHCAkKCwwNDg8QERITFBUWFxgZGhsDAwMCAgEBAAtBDBAPIgFFDRsgAEEMNgIEIAAgATYCACABQQhqQfyOBSgAADYAACABQfSOBSkAADcAAAwcC0EMEA8iAUUNGiAAQQw2AgQgACABNgIAIAFBCGpB8I4FKAAANgAAIAFB6I4FKQAANwAADBsLQQ4QDyIBRQ0ZIABBDjYCBCAAIAE2AgAgAUEGakHgjgUpAAA3AAAgAUHajgUpAAA3AAAMGwtBDxAPIgFFDRggAEEPNgIEIAAgATYCACABQQdqQdKOBSkAADcAACABQcuOBSkAADcAAAwbC0EMEA8iAUUNFyAAQQw2AgQgACABNgIAIAFBCGpBx44FKAAANgAAIAFBv44FKQAANwAADBgLQREQDyIBRQ0WIABBETYCBCAAIAE2AgAgAUEQakG+jgUtAAA6AAAgAUEIakG2jgUpAAA3AAAgAUGujgUpAAA3AAAgAEERNgIIDwtBDRAPIgFFDRUgAEENNgIEIAAgATYCACABQQVqQaaOBSkAADcAACABQaGOBSkAADcAAAwZC0ENEA8iAUUNFCAAQQ02AgQgACABNgIAIAFBBWpBmY4FKQAANwAAIAFBlI4FKQAANwAADBgLQQ4QDyIBRQ0TIABBDjYCBCAAIAE2AgAgAUEGakGMjgUpAAA3AAAgAUGGjgUpAAA3AAAMFQtBDhAPIgFFDRIgAEEONgIEIAAgATYCACABQQZqQf6NBSkAADcAACABQfiNBSkAADcAAAwUC0ENEA8iAUUNESAAQQ02AgQgACABNgIAIAFBBWpB8I0FKQAANwAAIAFB640FKQAANwAADBULQQ4QDyIBRQ0QIABBDjYCBCAAIAE2AgAgAUEGakHjjQUpAAA3AAAgAUHdjQUpAAA3AAAMEgtBDhAPIgFFDQ8gAEEONgIEIAAgATYCACABQQZqQdWNBSkAADcAACABQc+NBSkAADcAAAwRC0EQEA8iAUUNDiAAQRA2AgQgACABNgIAIAFBCGpBx40FKQAANwAAIAFBv40FKQAANwAADBMLQRAQDyIBRQ0NIABBEDYCBCAAIAE2AgAgAUEIakG3jQUpAAA3AAAgAUGvjQUpAAA3AAAMEgtBDhAPIgFFDQwgAEEONgIEIAAgATYCACABQQZqQaeNBSkAADcAACABQaGNBSkAADcAAAwOC0EPEA8iAUUNCyAAQQ82AgQgACABNgIAIAFBB2pBmY0FKQAANwAAIAFBko0FKQAANwAADA4LQQ8QDyIBRQ0KIABBDzYCBCAAIAE2AgAgAUEHakGKjQUpAAA3AAAgAUGDjQUpAAA3AAAMDQtBDxAPIgFFDQkgAEEPNgIEIAAgATYCACABQQdqQfuMBSkAADcAACABQfSMBSkAADcAAAwMC0ENEA8iAUUNCCAAQQ02AgQgACABNgIAIAFBBWpB7IwFKQAANwAAIAFB54wFKQAANwAADAwLQQ4QDyIBRQ0HIABBDjYCBCAAIAE2AgAgAUEGakHfjAUpAAA3AAAgAUHZjAUpAAA3AAAMCQtBDhAPIgFFDQYgAEEONgIEIAAgATYCACABQQZqQdGMBSkAADcAACABQcuMBSkAADcAAAwIC0EOEA8iAUUNBSAAQQ42AgQgACABNgIAIAFBBmpBw4wFKQAANwAAIAFBvYwFKQAANwAADAcLQRAQDyIBRQ0EIABBEDYCBCAAIAE2AgAgAUEIakG1jAUpAAA3AAAgAUGtjAUpAAA3AAAMCQtBEBAPIgFFDQMgAEEQNgIEIAAgATYCACABQQhqQaWMBSkAADcAACABQZ2MBSkAADcAAAwIC0EQEA8iAUUNAiAAQRA2AgQgACABNgIAIAFBCGpBlYwFKQAANwAAIAFBjYwFKQAANwAADAcLQRAQDyIBRQ0BIABBEDYCBCAAIAE2AgAgAUEIakGFjAUpAAA3AAAgAUH9iwUpAAA3AAAMBgtBERAPIgFFDQAgAEERNgIEIAAgATYCACABQRBqQfyLBS0AADoAACABQQhqQfSLBSkAADcAACABQeyLBSkAADcAACAAQRE2AggPCwALIABBDDYCCA8LIABBDjYCCA8LIABBDzYCCA8LIABBDTYCCA8LIABBEDYCCAu9FQIGfwN+IwFBAkYEQCMCIwIoAgBBNGs2AgAjAigCACIIKAIAIQAgCCgCBCEBIAgoAgghAiAIKAIMIQMgCCgCECEEIAgoAhQhBiAIKAIYIQcgCCkCHCEKIAgpAiQhCyAIKAIsIQkgCCgCMCEICwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEFCyMBRQRAIwBB4AFrIgQkACAEQQI2AiggA0UhBgsCQCMBRQRAIAYNASAEQRBqIQYLIAVFQQEjARsEQCAGIAMQ0QFBACMBQQFGDQIaCyMBRQRAIAQoAhAhBwsgBUEBRkEBIwEbBEAgBxABQQEjAUEBRg0CGgsgBiAEQZgBaiMBGyEGIAVBAkZBASMBGwRAIAYgByADEA5BAiMBQQFGDQIaCyMBRQRAIAQoAqgBQQJGIgNFBEAgBEHQAGogBEGYAWpBNBD/ARogBEEYahC5ASAEQRhqIARB0ABqIgZBNBD/ASEDIAcQIQwCCyAEQdgAaiAEQaABaiIBKQMANwMAIAQgBCkDmAEiCjcDUCAEQdAAaiEACyAFQQNGQQEjARsEQEH3ogVBKyAAQfTvBEHI8QQQmwFBAyMBQQFGDQIaCyMBRQRAAAsLIwFFBEBBGxAPIgNFIQYLAkACQAJAAkACQAJAAkAjAUUEQCAGDQEgA0EXakG7nAUoAAA2AAAgA0EQakG0nAUpAAA3AAAgA0EIakGsnAUpAAA3AAAgA0GknAUpAAAiCjcAAEEoEA8iB0UiBg0BIAdBp/0EQSgQ/wEhByADECEgBEEANgJkIARCBDcCXCAEQqiAgICABTcCVCAEIAc2AlAgBEHQAGohBiAEQZgBaiEDCyAFQQRGQQEjARsEQCADIAEgAiAGEFtBBCMBQQFGDQgaCyMBRQRAIARBmAFqIQIgBEHQAGohAQsgBUEFRkEBIwEbBEAgASACEClBBSMBQQFGDQgaCyMBRQRAIAQoAlAiB0EWRyEBCwJAIwFFBEAgAQ0BIAQoAlQiAkUhAQsCQCMBRQRAIAENASAEQcgBaiEJQgAhC0EBIQYLA0AjAUUEQCAEQZgBaiEDIARB0ABqIQELIAVBBkZBASMBGwRAIAEgAxAUQQYjAUEBRg0LGgsjAUUEQCAEKQNYIQogBCgCVCEDIAQoAlAiB0EWRwRAIAMhAQwJCyAKpyEIIApCIIinIgdBBEchAQsCQCMBRQRAIAFFBEAgAygAAEHwwtHDBkYiAQ0CCyAEQQhqIQALIAVBB0ZBASMBGwRAIAAgB0EAEKgBQQcjAUEBRg0MGgsjAUUEQCAENQIMIAQoAggiASADIAcQ/wEhACAKQoCAgIBwg4QhCkERIQcMCAsLIAVBCEZBASMBGwRAIAkgA0EEQez7BEEGQfL7BEEcEG1BCCMBQQFGDQsaCyMBRQRAIARBmAFqIQcgBEHQAGohAQsgBUEJRkEBIwEbBEAgASAHEBRBCSMBQQFGDQsaCyMBRQRAIAQpA1ghCiAEKAJUIQEgBCgCUCIHQRZHDQcgC6dFIgdFBEAgBhAhCyAEQdAAaiEGCyAFQQpGQQEjARsEQCAGIAkQPEEKIwFBAUYNCxoLIwFFBEAgBCgCVARAIAQoAlAQIQsgAkEBayECIAgEQCADECELIAEhBiAKIQsgAg0BCwsjAUUEQCAEQZgBahDZASAEKAIoIgNBAkYiAg0EIARBoAFqIARBIGopAwA3AwAgBEG0AWogBEE0aikCADcCACAEQbwBaiAEQTxqKQIANwIAIARBxAFqIARBxABqIgYpAgA3AgAgBCAEKQMYNwOYASAEIAM2AqgBIAQgBCkCLCILNwKsASAEQZgBaiEDIARB0ABqIQILIAVBC0ZBASMBGwRAIAIgA0EBEEtBCyMBQQFGDQoaCyMBRQRAIApCIIgiC6chAyAEQdAAaiEGIARBiAFqIQILIAVBDEZBASMBGwRAIAIgASADIAYQeEEMIwFBAUYNChoLIwFFBEAgBEHcAGooAgAEQCAEKAJYECELIARBmAFqEMEBIAqnRSICRQRAIAEQIQtBGxAPIgNFIgENAyADQRdqQbucBSgAADYAACADQRBqQbScBSkAADcAACADQQhqQaycBSkAADcAACADQaScBSkAACIKNwAAQTMQDyICRSIBDQMgAkHP/QRBMxD/ASECIAMQISAEQdAAaiEBCyAFQQ1GQQEjARsEQCABIAJBMxC4AUENIwFBAUYNChoLIAEgBEHcAGojARshASAFQQ5GQQEjARsEQCABQQRBABBeQQ4jAUEBRg0KGgsjAUUEQCAEQdABaiAEQdgAaikDADcDACAEQdgBaiIBIARB4ABqKQMANwMAIARBADYCxAEgBEIENwK8ASAEQrOAgICwBjcCtAEgBCACNgKwASAEQQA2AqgBIARCATcDoAEgBEIANwOYASAEIAQpA1AiCjcDyAEgBEHIAWohAwsgBUEPRkEBIwEbBEAgA0GC/gRBB0GRgAVBD0GZ/QRBDhBtQQ8jAUEBRg0KGgsjAUUEQCAEQZgBaiECIAQoAogBIQYgBCgCkAEhByAEQdAAaiEBCyAFQRBGQQEjARsEQCABIAIgBiAHEOIBQRAjAUEBRg0KGgsjAUUEQCAEKAJQIgJBEUciAQ0FIARB0ABqIQELIAVBEUZBASMBGwRAIAEgAxA8QREjAUEBRg0KGgsjAUUEQCAEKAJURSIBRQRAIAQoAlAiARAhCyAEKAKoASEDIAQoAqABIQILIAVBEkZBASMBGwRAIAQgAxDRAUESIwFBAUYNChoLIwFFBEAgBCgCBCEBIAQoAgAgAiADEP8BIQIgBEGYAWoQ2QEgACADNgIIIAAgATYCBCAAIAI2AgACQCAEKAKIASIARQ0AIAQoAowBRQ0AIAAQIQsgBEHgAWokAA8LCyMBRQRAQQ0QDyIBRSIADQIgAUEFaiIAQaX8BCkAADcAACABQaD8BCkAADcAAEKNgICA0AEhCkETIQcMBwsLIwFFBEAgBCkDWCEKIAQoAlQhASAEQZgBaiIAENkBDAcLCyMBRQRAAAsLIAVBE0ZBASMBGwRAQcj0BEErQaz1BBDzAUETIwFBAUYNBhoLIwFFBEAACwsjAUUEQCAEKQJUIQogBCgCXCEDIARBmAFqENkBIAQgAzYCpAEgBCAKNwKcASAEIAI2ApgBIARBmAFqIQALIAVBFEZBASMBGwRAQfeiBUErIABBhPAEQejxBBCbAUEUIwFBAUYNBRoLIwFFBEAACwsjAUUEQCAIRSIADQEgAxAhCwsjAUUEQCALp0UiAA0BIAYQIQsLIwFFBEAgBEGYAWoiABDZAQsLIwFFBEAgBCAKNwOgASAEIAE2ApwBIAQgBzYCmAEgBEGYAWohASAEQdAAaiEACyAFQRVGQQEjARsEQCAAIAEQwgFBFSMBQQFGDQEaCyAAIARB0ABqIwEbIQAgBUEWRkEBIwEbBEAgAEHY8QQQwwFBFiMBQQFGDQEaCyMBRQRAAAsPCyEFIwIoAgAgBTYCACMCIwIoAgBBBGo2AgAjAigCACIFIAA2AgAgBSABNgIEIAUgAjYCCCAFIAM2AgwgBSAENgIQIAUgBjYCFCAFIAc2AhggBSAKNwIcIAUgCzcCJCAFIAk2AiwgBSAINgIwIwIjAigCAEE0ajYCAAvyEAEDfyMBQQJGBEAjAiMCKAIAQRBrNgIAIwIoAgAiAygCACEAIAMoAgQhASADKAIIIQIgAygCDCEDCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEFCyMBRQRAIwBBMGsiAyQAIAAoAgAhBAsCQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAIwFFBEACQCAEQQFrDhACAwQFBgcICQoLDA0ODxARAAsgA0EDNgIMIAMgAEEEajYCFCADIANBFGo2AgggA0EBNgIsIANCATcCHCADQZCkBTYCGCADIANBCGo2AiggA0EYaiEACyAFRUEBIwEbBEAgASACIAAQK0EAIwFBAUYNEhohAAsjAUUNEAsjAUUEQCADQQM2AgwgAyAAQQRqNgIUIAMgA0EUajYCCCADQQE2AiwgA0IBNwIcIANBkKQFNgIYIAMgA0EIajYCKCADQRhqIQALIAVBAUZBASMBGwRAIAEgAiAAECtBASMBQQFGDREaIQALIwFFDQ8LIwFFBEAgA0EDNgIMIAMgAEEEajYCFCADIANBFGo2AgggA0EBNgIsIANCATcCHCADQZCkBTYCGCADIANBCGo2AiggA0EYaiEACyAFQQJGQQEjARsEQCABIAIgABArQQIjAUEBRg0QGiEACyMBRQ0OCyMBRQRAIANBAzYCDCADIABBBGo2AhQgAyADQRRqNgIIIANBATYCLCADQgE3AhwgA0GQpAU2AhggAyADQQhqNgIoIANBGGohAAsgBUEDRkEBIwEbBEAgASACIAAQK0EDIwFBAUYNDxohAAsjAUUNDQsjAUUEQCADQQM2AgwgAyAAQQRqNgIUIAMgA0EUajYCCCADQQE2AiwgA0IBNwIcIANBkKQFNgIYIAMgA0EIajYCKCADQRhqIQALIAVBBEZBASMBGwRAIAEgAiAAECtBBCMBQQFGDQ4aIQALIwFFDQwLIwFFBEAgA0EDNgIMIAMgAEEEajYCFCADIANBFGo2AgggA0EBNgIsIANCATcCHCADQZCkBTYCGCADIANBCGo2AiggA0EYaiEACyAFQQVGQQEjARsEQCABIAIgABArQQUjAUEBRg0NGiEACyMBRQ0LCyMBRQRAIANBAzYCDCADIABBBGo2AhQgAyADQRRqNgIIIANBATYCLCADQgE3AhwgA0GQpAU2AhggAyADQQhqNgIoIANBGGohAAsgBUEGRkEBIwEbBEAgASACIAAQK0EGIwFBAUYNDBohAAsjAUUNCgsjAUUEQCADQQM2AgwgAyAAQQRqNgIUIAMgA0EUajYCCCADQQE2AiwgA0IBNwIcIANBkKQFNgIYIAMgA0EIajYCKCADQRhqIQALIAVBB0ZBASMBGwRAIAEgAiAAECtBByMBQQFGDQsaIQALIwFFDQkLIwFFBEAgA0EDNgIMIAMgAEEEajYCFCADIANBFGo2AgggA0EBNgIsIANCATcCHCADQZCkBTYCGCADIANBCGo2AiggA0EYaiEACyAFQQhGQQEjARsEQCABIAIgABArQQgjAUEBRg0KGiEACyMBRQ0ICyMBRQRAIANBAzYCDCADIABBBGo2AhQgAyADQRRqNgIIIANBATYCLCADQgE3AhwgA0GQpAU2AhggAyADQQhqNgIoIANBGGohAAsgBUEJRkEBIwEbBEAgASACIAAQK0EJIwFBAUYNCRohAAsjAUUNBwsjAUUEQCADQQM2AgwgAyAAQQRqNgIUIAMgA0EUajYCCCADQQE2AiwgA0IBNwIcIANBkKQFNgIYIAMgA0EIajYCKCADQRhqIQALIAVBCkZBASMBGwRAIAEgAiAAECtBCiMBQQFGDQgaIQALIwFFDQYLIwFFBEAgA0EDNgIMIAMgAEEEajYCFCADIANBFGo2AgggA0EBNgIsIANCATcCHCADQZCkBTYCGCADIANBCGo2AiggA0EYaiEACyAFQQtGQQEjARsEQCABIAIgABArQQsjAUEBRg0HGiEACyMBRQ0FCyMBRQRAIANBAzYCDCADIABBBGo2AhQgAyADQRRqNgIIIANBATYCLCADQgE3AhwgA0GQpAU2AhggAyADQQhqNgIoIANBGGohAAsgBUEMRkEBIwEbBEAgASACIAAQK0EMIwFBAUYNBhohAAsjAUUNBAsjAUUEQCADQQM2AgwgAyAAQQRqNgIUIAMgA0EUajYCCCADQQE2AiwgA0IBNwIcIANBkKQFNgIYIAMgA0EIajYCKCADQRhqIQALIAVBDUZBASMBGwRAIAEgAiAAECtBDSMBQQFGDQUaIQALIwFFDQMLIwFFBEAgA0EDNgIMIAMgAEEEajYCFCADIANBFGo2AgggA0EBNgIsIANCATcCHCADQZCkBTYCGCADIANBCGo2AiggA0EYaiEACyAFQQ5GQQEjARsEQCABIAIgABArQQ4jAUEBRg0EGiEACyMBRQ0CCyMBRQRAIANBAzYCDCADIABBBGo2AhQgAyADQRRqNgIIIANBATYCLCADQgE3AhwgA0GQpAU2AhggAyADQQhqNgIoIANBGGohAAsgBUEPRkEBIwEbBEAgASACIAAQK0EPIwFBAUYNAxohAAsjAUUNAQsjAUUEQCADQQM2AgwgAyAAQQRqNgIUIAMgA0EUajYCCCADQQE2AiwgA0IBNwIcIANBkKQFNgIYIAMgA0EIajYCKCADQRhqIQALIAVBEEZBASMBGwRAIAEgAiAAECtBECMBQQFGDQIaIQALCyMBRQRAIANBMGokACAADwsACyEEIwIoAgAgBDYCACMCIwIoAgBBBGo2AgAjAigCACIEIAA2AgAgBCABNgIEIAQgAjYCCCAEIAM2AgwjAiMCKAIAQRBqNgIAQQALthMBD38jAUECRgRAIwIjAigCAEHEAGs2AgAjAigCACIBKAIAIQAgASkCCCECIAEoAhAhAyABKAIUIQQgASgCGCEFIAEoAhwhBiABKAIgIQcgASgCJCEIIAEoAighCyABKAIsIQwgASgCMCENIAEoAjQhDiABKAI4IRAgASgCPCERIAEoAkAhDyABKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQkLIwFFBEAjAEHwAGsiAyQAIANBADYCECADQgE3AwggAyABNgIgIAMgAjcDGCADQUBrIRAgA0E4aiERQQAhDEEAIQtBACEHIANBKGoiBEEIaiEPCwJAA0AjAUUEQCACQv////8PIAJC/////w9UIgQbpyENCwJAAkACQAJAAkACQAJAAkACQAJAAkACfgNAIAQgByAMRyMBGyEEAkAjAUUEQCAEDQEgA0EIaiEECyAJRUEBIwEbBEAgBCAMQSAQwAFBACMBQQFGDREaCyMBRQRAIAMoAhAhByADKAIMIQwLCyMBRQRAIAMgCzYCXCADQQA2AlggAyAMIAdrIgY2AlQgAyAHIAMoAghqIgQ2AlAgAlANDSAGrSACWiEFCwJAAn8CQAJAAkACQCMBRQRAIAUNASADQShqIQQgA0HQAGohBQsgCUEBRkEBIwEbBEAgBCABIAUQb0EBIwFBAUYNFhoLIwFFBEAgAy0AKCIGQQRHIgQNAyADIAIgAygCWCIGrX0iAjcDGAwCCwsjAUUEQCAGIAtJIgUNCCAGIA1JIgUNCSADQQA2AjAgAyAENgIoIAMgDTYCLCADIA0gCyALIA1LGzYCNCADQeAAaiEEIANBKGohBQsgCUECRkEBIwEbBEAgBCABIAUQb0ECIwFBAUYNFRoLIwFFBEAgAy0AYCIGQQRHIgQNAyADKAI0IgQgAygCMCIGSSIFDQogAygCLCIIIARJIgUNCyADIAY2AlggAyACIAatfSICNwMYIAsgBiAGIAtJGyIIIARLIQUgAyAIIAQgBRsiBDYCXAsLIwFFBEAgBg0EDBELCyMBRQRAIAMoAiwhCCADLwEqIQ4gAy0AKQwCCwsjAQR/IAQFIAMoAmQhCCADLwFiIQ4gAy0AYQsLIQQjAUUEQCAEIQUCQAJAAkACQCAGQQFrDgMBAAACCyAILQAIIQULIAVB/wFxQSNGIgUNAQsgBK1C/wGDIA6tQv//A4NCCIaEDAQLIAMgCDYCLCADIAYgDkEQdCIFIARB/wFxQQh0cnI2AiggA0EoaiEECyAJQQNGQQEjARsEQCAEENABQQMjAUEBRg0RGgsjAUUNAQsLIwFFBEAgBiADKAJcIgRLIgUNBiADKAJUIgggBEkiBQ0HIAYgCEsiBQ0IIAMgBiAHaiIHNgIQIAcgDHIiBQ0LIBBCADcDACARQgA3AwAgD0IANwMAIANCADcDKCADQRhqIQUgA0EoaiEHIANB4ABqIQELIAlBBEZBASMBGwRAIAEgBSAHEHBBBCMBQQFGDQ8aCyMBRQRAIAMtAGAiB0EERiIBDQoLA0ACQCMBRQRAAn8CQAJAAkAgB0H/AXEiAUEBaw4DAAECBQsgAy0AYQwCCyADKAJkLQAIDAELIAMoAmQtAAgLIgdB/wFxQSNHIgENASADQeAAaiEBCyAJQQVGQQEjARsEQCABENABQQUjAUEBRg0RGgsjAUUEQCADQRhqIQUgA0EoaiEHIANB4ABqIQELIAlBBkZBASMBGwRAIAEgBSAHEHBBBiMBQQFGDREaCyMBRQRAIAMtAGAiB0EERiIBDQwMAgsLCyMBBH4gAgUgAykDYCICpyIGQf8BcUEERiIBDQwgAkIgiKchCCACQgiICwshAiMBRQRAIAMgBq1C/wGDIAJCCIZCgP7//w+DIAitQiCGhIQiAjcDYCADQQA2AlggA0IBNwNQIANBxABqQfyFBTYCACADQQM6AEggA0KAgICAgAQ3AyggA0EANgI4IANBADYCMCADIANB0ABqNgJAIANBKGohBCADQeAAaiEBCyAJQQdGQQEjARsEQCABIAQQSUEHIwFBAUYNDhohAQsjAUUEQCABDQggACADKQNQIgI3AgQgAEEMaiIBIANB2ABqKAIAIgQ2AgAgAEECNgIAIAZB/wFxQQNHIQALAkAjAUUEQCAADQEgCCgCBCgCACEBIAgoAgAhAAsgCUEIRkEBIwEbBEAgACABEQQAQQgjAUEBRg0PGgsjAUUEQCAIKAIEKAIERSIARQRAIAgoAgAiABAhCyAIECELCyMBRQRAIAMoAgxFDQ0gAygCCBAhDA0LCyAJQQlGQQEjARsEQCALIAZBqJoFEPcBQQkjAUEBRg0NGgsjAUUEQAALCyAJQQpGQQEjARsEQCANIAZB9JoFEPcBQQojAUEBRg0MGgsjAUUEQAALCyAJQQtGQQEjARsEQCAGIARBqJoFEPgBQQsjAUEBRg0LGgsjAUUEQAALCyAJQQxGQQEjARsEQCAEIAhBqJoFEPcBQQwjAUEBRg0KGgsjAUUEQAALCyAJQQ1GQQEjARsEQCAGIARBqJoFEPgBQQ0jAUEBRg0JGgsjAUUEQAALCyAJQQ5GQQEjARsEQCAEIAhBqJoFEPcBQQ4jAUEBRg0IGgsjAUUEQAALCyAJQQ9GQQEjARsEQCAGIAhBmJkFEPcBQQ8jAUEBRg0HGgsjAUUEQAALCyAAIANB6ABqIwEbIQAgCUEQRkEBIwEbBEBB8JYFQTcgAEGUhgVBhJgFEJsBQRAjAUEBRg0GGgsjAUUEQAALCyMBRQRAIAMoAmQiB0UNAiAHQSFPIQELAkAjAUUEQCABDQEgA0EoaiEFIANBCGohAQsgCUERRkEBIwEbBEAgASAFIAcQxQFBESMBQQFGDQYaCyMBRQRAIAMoAgwhDCADKAIQIQcgAygCICEBIAMpAxghAgwCCwsgCUESRkEBIwEbBEAgB0EgQaiZBRD3AUESIwFBAUYNBRoLIwFFBEAACwsjAUUEQCAEIAZrIQsMAgsLCyMBRQRAIAAgAykDCDcCBCAAQRY2AgAgAEEMaiADQRBqKAIANgIACwsjAUUEQCADQfAAaiQACw8LIQojAigCACAKNgIAIwIjAigCAEEEajYCACMCKAIAIgogADYCACAKIAE2AgQgCiACNwIIIAogAzYCECAKIAQ2AhQgCiAFNgIYIAogBjYCHCAKIAc2AiAgCiAINgIkIAogCzYCKCAKIAw2AiwgCiANNgIwIAogDjYCNCAKIBA2AjggCiARNgI8IAogDzYCQCMCIwIoAgBBxABqNgIAC9EoAhF/AX4jAUECRgRAIwIjAigCAEEgazYCACMCKAIAIgMoAgAhACADKAIIIQIgAygCDCEFIAMoAhAhCyADKAIUIQogAygCGCEPIAMoAhwhECADKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQwLIwFFBEAjAEGAAWsiAiQAQRsQDyIFRSELCwJAAkACQAJAAkACQAJAAkACQAJAIwFFBEAgCw0BIAVBF2pBu5wFKAAANgAAIAVBEGpBtJwFKQAANwAAIAVBCGpBrJwFKQAANwAAIAVBpJwFKQAANwAAQTUQDyIDRSIKDQEgA0Gj8wRBNRD/ASELIAUQISACQegAaiEFCyAMRUEBIwEbBEAgBSALQTUQuAFBACMBQQFGDQsaCyAKIAJB9ABqIwEbIQogDEEBRkEBIwEbBEAgCkEEQQAQXkEBIwFBAUYNCxoLIwFFBEAgAkHIAGogAkHwAGopAwA3AwAgAkHQAGogAkH4AGoiDykDADcDACACQQA2AjwgAkIENwI0IAJCtYCAgNAGNwIsIAIgCzYCKCACQQA2AiAgAkIBNwMYIAJCADcDECACIAIpA2g3A0AgAkEQaiELIAJB6ABqIQULIAxBAkZBASMBGwRAIAUgC0EEED5BAiMBQQFGDQsaCyMBRQRAIAIoAmgiBUERRyELCwJAIwFFBEAgCw0BIAJBQGshCwsgDEEDRkEBIwEbBEAgC0HY8wRBA0Hs+wRBBkHXgwVBEBBtQQMjAUEBRg0MGgsjAUUEQCACQRBqIQogAkHoAGohBQsgDEEERkEBIwEbBEAgBSAKQdjzBEEDECJBBCMBQQFGDQwaCyMBRQRAIAIoAmgiBUERRw0DIAJBEGohCiABKAIAIQ8gASgCCCEQIAJB6ABqIQULIAxBBUZBASMBGwRAIAUgCiAPIBAQIkEFIwFBAUYNDBoLIwFFBEAgAigCaCIFQRFHIgoNBCACQegAaiEFCyAMQQZGQQEjARsEQCAFIAsQPEEGIwFBAUYNDBoLIwFFBEAgAigCbEUiBUUEQCACKAJoIgUQIQsLIAxBB0ZBASMBGwRAIAtB2/MEQQxB7PsEQQZB14MFQRAQbUEHIwFBAUYNDBoLIwFFBEAgAkEQaiEKIAJB6ABqIQULIAxBCEZBASMBGwRAIAUgCkHb8wRBDBAiQQgjAUEBRg0MGgsjAUUEQCACKAJoIgVBEUcNBSACQRBqIQogASgCDCEPIAFBFGooAgAhECACQegAaiEFCyAMQQlGQQEjARsEQCAFIAogDyAQECJBCSMBQQFGDQwaCyMBRQRAIAIoAmgiBUERRyIKDQYgAkHoAGohBQsgDEEKRkEBIwEbBEAgBSALEDxBCiMBQQFGDQwaCyMBRQRAIAIoAmxFIgVFBEAgAigCaCIFECELCyAMQQtGQQEjARsEQCALQYn2BEEHQZD2BEELQdeDBUEQEG1BCyMBQQFGDQwaCyMBRQRAIAJBEGohCiACQegAaiEFCyAMQQxGQQEjARsEQCAFIApBifYEQQcQIkEMIwFBAUYNDBoLIwFFBEAgAigCaCIFQRFHDQcgAkEQaiEKIAFBGGooAgAhDyABQRxqKAIAIRAgAkHoAGohBQsgDEENRkEBIwEbBEAgBSENIAohAyAPIQYgECEJIwFBAkYEQCMCIwIoAgBBHGs2AgAjAigCACIDKAIAIQ0gAygCCCEGIAMoAgwhCSADKAIQIQQgAykCFCETIAMoAgQhAwsCQAJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEICyMBRQRAIwBBQGoiBCQACwJAIwFFQQAgBhtFBEAgCEVBASMBGwRAIA0gAxCAAUEAIwFBAUYNAxoLIwFFDQELIAYgCUGAAUkjARshBgJ/AkACQAJAAkACQAJAAkACQAJAIwFFBEAgBg0BIAlBgAJJIgYNAiAJQYCABE8iBg0DIARBKGohBgsgCEEBRkEBIwEbBEAgBiADQREQqgFBASMBQQFGDQwaCyMBRQRAIAQtAChBBEcEQCAEKQMoIhNC/wGDQgRSIgYNBgsgBCAJQQh0IAlBgP4DcUEIdnI7AQggBEEIaiEJIARBKGohBgsgCEECRkEBIwEbBEAgBiADIAlBAhBgQQIjAUEBRg0MGgsjAUUEQCAELQAoQQRGDQkgBCkDKCITQv8Bg0IEUQ0JIAQgEzcDKCAEQShqIQYgBEEIaiEDCyAIQQNGQQEjARsEQCADIAYQmAFBAyMBQQFGDQwaCyMBRQ0GCyAGIARBKGojARshBiAIQQRGQQEjARsEQCAGIAMgCRB/QQQjAUEBRg0LGgsjAUUEQEERIAQoAigiA0ERRg0JGiAEQRRqIgYgBEE0aigCACIJNgIAIAQgBCkCLDcCDCAEIAM2AggMBwsLIAYgBEEoaiMBGyEGIAhBBUZBASMBGwRAIAYgA0EQEKoBQQUjAUEBRg0KGgsjAUUEQCAELQAoQQRHBEAgBCkDKCITQv8Bg0IEUiIGDQMLIAQgCToACCAEQQhqIQkgBEEoaiEGCyAIQQZGQQEjARsEQCAGIAMgCUEBEGBBBiMBQQFGDQoaCyMBRQRAIAQtAChBBEYNByAEKQMoIhNC/wGDQgRRDQcgBCATNwMoIARBKGohBiAEQQhqIQMLIAhBB0ZBASMBGwRAIAMgBhCYAUEHIwFBAUYNChoLIwFFDQQLIAYgBEEoaiMBGyEGIAhBCEZBASMBGwRAIAYgA0ESEKoBQQgjAUEBRg0JGgsjAUUEQCAELQAoQQRHBEAgBCkDKCITQv8Bg0IEUiIGDQQLIAQgCUEIdEGAgPwHcSAJQRh0ciAJQQh2QYD+A3EgCUEYdnJyNgIIIARBCGohCSAEQShqIQYLIAhBCUZBASMBGwRAIAYgAyAJQQQQYEEJIwFBAUYNCRoLIwFFBEAgBC0AKEEERg0GIAQpAygiE0L/AYNCBFENBiAEIBM3AyggBEEoaiEGIARBCGohAwsgCEEKRkEBIwEbBEAgAyAGEJgBQQojAUEBRg0JGgsjAUUNAwsjAUUEQCAEIBM3AyggBEEoaiEGIARBCGohAwsgCEELRkEBIwEbBEAgAyAGEJgBQQsjAUEBRg0IGgsjAUUNAgsjAUUEQCAEIBM3AyggBEEoaiEGIARBCGohAwsgCEEMRkEBIwEbBEAgAyAGEJgBQQwjAUEBRg0HGgsjAUUNAQsjAUUEQCAEIBM3AyggBEEoaiEGIARBCGohAwsgCEENRkEBIwEbBEAgAyAGEJgBQQ0jAUEBRg0GGgsLIwFFBEBBESIJIAQoAghBEUYiAw0DGgsLIwFFBEAgBEEwaiAEQRBqKQMANwMAIAQgBCkDCDcDKCAEQQA2AiAgBEIBNwMYIARBGGohBiAEQShqIQMLIAhBDkZBASMBGwRAIAMgBkHYlgUQGUEOIwFBAUYNBBohAwsjAUUEQCADRQRAIA0gBCkDGDcCBCANQQxqIARBIGooAgA2AgAgBEEoahDkAUEKDAMLIARBOGohDQsgCEEPRkEBIwEbBEBB8JYFQTcgDUGolwVBhJgFEJsBQQ8jAUEBRg0EGgsjAUUEQAALCyMBBH8gCQUgBEERNgIIQRELCyEDIwFFBEAgDSADNgIACwsjAUUEQCAEQUBrJAALDAELIQcjAigCACAHNgIAIwIjAigCAEEEajYCACMCKAIAIgcgDTYCACAHIAM2AgQgByAGNgIIIAcgCTYCDCAHIAQ2AhAgByATNwIUIwIjAigCAEEcajYCAAtBDSMBQQFGDQwaCyMBRQRAIAIoAmgiBUERRyIKDQggAkHoAGohBQsgDEEORkEBIwEbBEAgBSALEDxBDiMBQQFGDQwaCyMBRQRAIAIoAmxFIgVFBEAgAigCaCIFECELCyAMQQ9GQQEjARsEQCALQefzBEEKQfHzBEEYQdeDBUEQEG1BDyMBQQFGDQwaCyMBRQRAIAJBEGohCiACQegAaiEFCyAMQRBGQQEjARsEQCAFIApB5/MEQQoQIkEQIwFBAUYNDBoLIwFFBEAgAigCaCIFQRFHIgoNCSABKAIgQQJHIQULAkACQCMBRQRAIAUNASACQRBqIQUgAkHoAGohAQsgDEERRkEBIwEbBEAgASAFEIABQREjAUEBRg0OGgsjAUUEQCACKAJoIgVBEUYiAQ0CIAJB4ABqIAJB9ABqKAIANgIAIAIgAikCbDcDWAwMCwsjAUUEQCACQegAaiEFIAJBEGohCiABQSBqIQELIAxBEkZBASMBGwRAIAUhCSABIQMgCiEGQQAhB0EAIQRBACEIIwFBAkYEQCMCIwIoAgBBIGs2AgAjAigCACIDKAIAIQkgAygCCCEGIAMoAgwhByADKAIQIQQgAygCFCEOIAMoAhghESADKAIcIRIgAygCBCEDCwJAAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQgLIwFFBEAjAEFAaiIHJAAgB0EoaiEECyAIRUEBIwEbBEAgBCAGQQIQPkEAIwFBAUYNARoLIwFFBEAgBygCKCIEQRFHIQ4LAkACfwJAAkACQAJAAkACQCMBRQRAIA4NASAGQTBqIQ4LIAhBAUZBASMBGwRAIA5BoIAFQQZBpoAFQQtB14MFQRAQbUEBIwFBAUYNCRoLIAQgB0EoaiMBGyEEIAhBAkZBASMBGwRAIAQgBkGggAVBBhAiQQIjAUEBRg0JGgsjAUUEQCAHKAIoIgRBEUcNAiADKAIAIREgA0EEaigCACESIAdBKGohBAsgCEEDRkEBIwEbBEAgBCAGIBEgEhDjAUEDIwFBAUYNCRoLIwFFBEAgBygCKCIEQRFHIhENAyAHQShqIQQLIAhBBEZBASMBGwRAIAQgDhA8QQQjAUEBRg0JGgsjAUUEQCAHKAIsRSIERQRAIAcoAigiBBAhCwsgCEEFRkEBIwEbBEAgDkGxgAVBBkGmgAVBC0HXgwVBEBBtQQUjAUEBRg0JGgsgBCAHQShqIwEbIQQgCEEGRkEBIwEbBEAgBCAGQbGABUEGECJBBiMBQQFGDQkaCyMBRQRAIAcoAigiBEERRw0EIAdBKGohBCADQQhqKAIAIREgA0EMaigCACEDCyAIQQdGQQEjARsEQCAEIAYgESADEOMBQQcjAUEBRg0JGgsjAUUEQCAHKAIoIgRBEUciAw0FIAdBKGohAwsgCEEIRkEBIwEbBEAgAyAOEDxBCCMBQQFGDQkaCyMBRQRAIAcoAiwEQCAHKAIoECELQREMBwsLIwFFBEAgB0EQaiIDIAdBNGooAgAiBjYCACAHIAcpAiw3AwgMBQsLIwFFBEAgB0EQaiIDIAdBNGooAgAiBjYCACAHIAcpAiw3AwgMBAsLIwFFBEAgB0EQaiIDIAdBNGooAgAiBjYCACAHIAcpAiw3AwgMAwsLIwFFBEAgB0EQaiIDIAdBNGooAgAiBjYCACAHIAcpAiw3AwgMAgsLIwFFBEAgB0EQaiIDIAdBNGooAgAiBjYCACAHIAcpAiw3AwgLCyMBRQRAIAdBNGogB0EQaigCADYCACAHIAQ2AiggByAHKQMINwIsIAdBADYCICAHQgE3AxggB0EYaiEGIAdBKGohAwsgCEEJRkEBIwEbBEAgAyAGQbiABRAZQQkjAUEBRg0DGiEDCyMBBH8gBgUgAw0CIAkgBykDGDcCBCAJQQxqIAdBIGooAgA2AgAgB0EoahDkAUEPCwshBiMBRQRAIAkgBjYCACAHQUBrJAAMAwsLIAkgB0E4aiMBGyEJIAhBCkZBASMBGwRAQfCWBUE3IAlB0IAFQYSYBRCbAUEKIwFBAUYNARoLIwFFBEAACwwBCyENIwIoAgAgDTYCACMCIwIoAgBBBGo2AgAjAigCACINIAk2AgAgDSADNgIEIA0gBjYCCCANIAc2AgwgDSAENgIQIA0gDjYCFCANIBE2AhggDSASNgIcIwIjAigCAEEgajYCAAtBEiMBQQFGDQ0aCyMBRQRAIAIoAmgiBUERRiIBDQEgAkHgAGogAkH0AGooAgA2AgAgAiACKQJsNwNYDAsLCyABIAJB6ABqIwEbIQEgDEETRkEBIwEbBEAgASALEDxBEyMBQQFGDQwaCyMBRQRAIAIoAmwEQCACKAJoECELIAIoAhghCyACKAIgIQUgAkEIaiEBCyAMQRRGQQEjARsEQCABIAUQ0QFBFCMBQQFGDQwaCyMBRQRAIAIoAgwhASACKAIIIAsgBRD/ASEDIABBDGogBTYCACAAQQhqIAE2AgAgACADNgIEIABBETYCACACQRBqENkBDAsLCyMBRQRAIAJB4ABqIAooAgA2AgAgAiACKQJsNwNYDAkLCyMBRQRAAAsLIwFFBEAgAkHgAGogAkH0AGooAgA2AgAgAiACKQJsNwNYDAcLCyMBRQRAIAJB4ABqIAJB9ABqKAIANgIAIAIgAikCbDcDWAwGCwsjAUUEQCACQeAAaiACQfQAaigCADYCACACIAIpAmw3A1gMBQsLIwFFBEAgAkHgAGogAkH0AGooAgA2AgAgAiACKQJsNwNYDAQLCyMBRQRAIAJB4ABqIAJB9ABqKAIANgIAIAIgAikCbDcDWAwDCwsjAUUEQCACQeAAaiACQfQAaigCADYCACACIAIpAmw3A1gMAgsLIwFFBEAgAkHgAGogAkH0AGooAgA2AgAgAiACKQJsNwNYCwsjAUUEQCAAIAIpA1g3AgQgAEEMaiACQeAAaigCADYCACAAIAU2AgAgAkEQahDZAQsLIwFFBEAgAkGAAWokAAsPCyEDIwIoAgAgAzYCACMCIwIoAgBBBGo2AgAjAigCACIDIAA2AgAgAyABNgIEIAMgAjYCCCADIAU2AgwgAyALNgIQIAMgCjYCFCADIA82AhggAyAQNgIcIwIjAigCAEEgajYCAAvuDgIIfwN+IwFBAkYEQCMCIwIoAgBBKGs2AgAjAigCACIBKAIAIQAgASgCCCECIAEoAgwhAyABKAIQIQQgASkCFCEKIAEoAhwhByABKAIgIQggASgCJCEJIAEoAgQhAQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBgsjAUUEQCMAQdAAayICJAAgAkE4aiEDCyAGRUEBIwEbBEAgAyABEJQBQQAjAUEBRg0BGgsjAUUEQCACKAI4IgNBFkchBAsCQAJAAkACQAJAAkAjAUUEQCAEDQEgAi0APCIDDQMgAkE4aiEDCyAGQQFGQQEjARsEQCADIAEQbkEBIwFBAUYNBxoLIwFFBEAgAi0AOEEERyEDCwJAAkACQAJAAkACQAJAAkACQCMBRQRAAkACfyADRQRAIAIvADkiA0EIdgwBCyACKQM4IgpCEIghCyAKQgiIIQwgCkL/AYNCBFINASAMpyEDIAunCyEEIANB/wFxIgNBAmsiBw4JAwIEAgICBQYHAgsgAiAKQv+BgHiDIAtCCIZCgP4DgyAMQv8Bg4RCCIaEIgo3AzggAkE4aiEDCyAGQQJGQQEjARsEQCACIAMQlwFBAiMBQQFGDRAaCyMBRQ0ICyMBRQRAIAJBKGoiByAEQQh0IANyEBcgAkEENgIkIAIgAkEoajYCICACQQE2AkwgAkIBNwI8IAJB4IoFNgI4IAIgAkEgajYCSCACQThqIQQgAkEQaiEDCyAGQQNGQQEjARsEQCADIAQQO0EDIwFBAUYNDxoLIwFFBEAgAigCLARAIAIoAigQIQsgAigCFCEEIAFBMGohCCACKAIQIQcgAigCGCEJIAJBBHIhAwsgBkEERkEBIwEbBEAgAyAIIAcgCRAnQQQjAUEBRg0PGgsjAUUEQEESIQMgAkESNgIAIARFDQogBxAhDAgLCyMBRQRAIAJBFjYCACACIARB/wFxIgM2AgQMCwsLIwFFBEAgAkIWNwMAQQAhAwwKCwsjAUUEQCACQQA6ACggAkEoaiEEIAJBOGohAwsgBkEFRkEBIwEbBEAgAyABIARBARB5QQUjAUEBRg0MGgsjAUUEQCACLQA4QQRHBEAgAikDOCIKQv8Bg0IEUiIDDQULIAIgAi0AKCIDNgIEIAJBFjYCAAwJCwsjAUUEQCACQQA7ASggAkEoaiEEIAJBOGohAwsgBkEGRkEBIwEbBEAgAyABIARBAhB5QQYjAUEBRg0LGgsjAUUEQCACLQA4QQRHBEAgAikDOCIKQv8Bg0IEUiIDDQMLIAJBFjYCACACLwEoIgVBCHQhBCACIAQgBUEIdnJB//8DcSIDNgIEDAgLCyMBRQRAIAJBADYCKCACQShqIQQgAkE4aiEDCyAGQQdGQQEjARsEQCADIAEgBEEEEHlBByMBQQFGDQoaCyMBRQRAAkAgAi0AOEEERwRAIAIpAzgiCkL/AYNCBFINAQsgAkEWNgIAIAIoAigiBUEYdCAFQQh0QYCA/AdxciEEIAIgBCAFQQh2QYD+A3EgBUEYdnJyIgM2AgQMCAsgAiAKNwM4IAJBOGohAwsgBkEIRkEBIwEbBEAgAiADEJcBQQgjAUEBRg0KGgsjAUUNAgsjAUUEQCACIAo3AzggAkE4aiEDCyAGQQlGQQEjARsEQCACIAMQlwFBCSMBQQFGDQkaCyMBRQ0BCyMBRQRAIAIgCjcDOCACQThqIQMLIAZBCkZBASMBGwRAIAIgAxCXAUEKIwFBAUYNCBoLCyMBRQRAIAIoAgAiA0EWRw0CIAIoAgQhAwwECwsjAUUEQCACQQxqIAJBxABqKAAANgAAIAIgAikAPSIKNwAFIAIgAi0APCIBOgAEIAIgAzYCAAsLIwFFBEAgAigCBCEBIAAgAikDCDcCCCAAIAE2AgQgACADNgIADAMLCyMBRQRAIAJCFjcDAEEAIQMLCyAKIAOtIwEbIQogBkELRkEBIwEbBEAgAiABIAoQGkELIwFBAUYNAxoLIwFFBEAgAigCAEEWRyEDQRYhAQsCQCMBRQRAIANFBEAgACACKQIENwIEIABBDGogAkEMaigCADYCAAwCCyACQUBrIAJBCGopAwA3AwAgAiACKQMANwM4IAJBADYCMCACQgE3AyggAkEoaiEDIAJBOGohAQsgBkEMRkEBIwEbBEAgASADQfyFBRAWQQwjAUEBRg0EGiEBCyMBRQRAIAENAyAAIAIpAyg3AgQgAEEMaiACQTBqKAIANgIAIAJBOGoQ5AFBAiEBCwsjAUUEQCAAIAE2AgALCyMBRQRAIAJB0ABqJAAPCwsgACACQRBqIwEbIQAgBkENRkEBIwEbBEBB8JYFQTcgAEGUhgVBhJgFEJsBQQ0jAUEBRg0BGgsjAUUEQAALDwshBSMCKAIAIAU2AgAjAiMCKAIAQQRqNgIAIwIoAgAiBSAANgIAIAUgATYCBCAFIAI2AgggBSADNgIMIAUgBDYCECAFIAo3AhQgBSAHNgIcIAUgCDYCICAFIAk2AiQjAiMCKAIAQShqNgIAC6UKAQh/IwFBAkYEQCMCIwIoAgBBIGs2AgAjAigCACIBKAIAIQAgASgCCCECIAEoAgwhAyABKAIQIQQgASgCFCEFIAEoAhghCCABKAIcIQkgASgCBCEBCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEKCyMBRQRAIwBBEGsiCCQAIAEoAgghBQsCQAJAAkACQCMBRQRAIAVFBEAgAUF/NgIIIANBACADIAJBA2pBfHEgAmsiBGtBB3EgAyAESRsiBmshBSADIAZJDQIgBCADIAMgBEsbIQsgAUEMaiEJAkAgBkUNAAJ/IAIgA2oiBCACIAVqIgdrIgZBAWsgBEEBayIELQAAQQpGDQAaIAQgB0YNASAGQQJrIARBAWsiBC0AAEEKRg0AGiAEIAdGDQEgBkEDayAEQQFrIgQtAABBCkYNABogBCAHRg0BIAZBBGsgBEEBayIELQAAQQpGDQAaIAQgB0YNASAGQQVrIARBAWsiBC0AAEEKRg0AGiAEIAdGDQEgBkEGayAEQQFrIgQtAABBCkYNABogBCAHRg0BIAZBB2sgBEEBayIELQAAQQpGDQAaIAQgB0YNASAGQQhrCyAFaiEEDAULA0ACQCALIAUiBE8NACACIARqIgdBCGsoAgAiBkF/cyAGQYqUqNAAc0GBgoQIa3FBgIGChHhxDQAgBEEIayEFIAdBBGsoAgAiB0F/cyAHQYqUqNAAc0GBgoQIa3FBgIGChHhxRQ0BCwsgAyAESSIFDQMDQCAEBEAgBEEBayIEIAJqLQAAQQpGIgVFDQEMBgsLAkAgAUEUaigCACIERQRAQQAhBAwBCyAEIAEoAgxqQQFrLQAAQQpHDQBBACEEIAFBFGpBADYCACABQRhqQQA6AAALIAMgAUEQaigCACAEa08EQCAAIAkgAiADEJ0BDAYLIAQgASgCDGogAiADEP8BGiAAQQQ6AAAgAUEUaiADIARqNgIADAULIAhBCGohAAsgCkVBASMBGwRAQeihBUEQIABB+KEFQZCrBRCbAUEAIwFBAUYNBRoLIwFFBEAACwsgCkEBRkEBIwEbBEAgBSADQeCIBBD2AUEBIwFBAUYNBBoLIwFFBEAACwsgCkECRkEBIwEbBEAgBCADQfCIBBD3AUECIwFBAUYNAxoLIwFFBEAACwsjAUUEQCAEQQFqIgQgA0shBQsCQCMBRQRAIAVFBEACQCABQRRqKAIAIgVFDQACQCAEIAFBEGooAgAgBWtJBEAgASgCDCAFaiACIAQQ/wEaIAFBFGogBCAFaiIFNgIADAELIAggCSACIAQQnQEgCC0AACIFQQRHDQQgAUEUaigCACEFCyAFRQ0AIAFBFGpBADYCACABQRhqQQA6AAALIAIgBGohAiADIARrIgMgAUEQaigCAE8EQCAAIAkgAiADEJ0BDAQLIAEoAgwgAiADEP8BGiAAQQQ6AAAgAUEUaiADNgIADAMLCyAKQQNGQQEjARsEQEGJogVBI0HIpAUQvwFBAyMBQQFGDQMaCyMBRQRAAAsLIwFFBEAgACAIKAABNgABIABBBGogCCgABDYAACAAIAU6AAALCyMBRQRAIAEgASgCCEEBajYCCCAIQRBqJAALDwshBiMCKAIAIAY2AgAjAiMCKAIAQQRqNgIAIwIoAgAiBiAANgIAIAYgATYCBCAGIAI2AgggBiADNgIMIAYgBDYCECAGIAU2AhQgBiAINgIYIAYgCTYCHCMCIwIoAgBBIGo2AgALuQ8CDX8BfiMBQQJGBEAjAiMCKAIAQcQAazYCACMCKAIAIgEoAgAhACABKAIIIQIgASgCDCEDIAEoAhAhBCABKAIUIQUgASgCGCEGIAEoAhwhByABKAIgIQggASgCJCELIAEoAighDCABKAIsIQ0gASgCMCEOIAEoAjQhDyABKAI4IRAgASgCPCERIAEoAkAhEiABKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQoLIwFFBEAjAEGQAWsiBiQAIAZBKGohBwsgCkVBASMBGwRAIAcQggFBACMBQQFGDQEaCyMBRQRAIAZBNGooAgAhDyAGQTBqKAIAIRAgBigCLCEOIAYoAighBwsCQAJAIwFFBEAgBw0BQQAhCCAGQQA2AiAgBkIENwMYIAJFIQtBBCEHCwJAAkACQAJAIwFFBEAgCw0BIAJBA3QiAiABaiERCwNAIwFFBEAgAUEEaiISKAIAIQggBkEQaiEHIAEoAgAhAgsgCkEBRkEBIwEbBEAgByAEQQAQqAFBASMBQQFGDQgaCyMBRQRAIAYoAhQhDSAGKAIQIAMgBBD/ASEMIAZBCGohBwsgCkECRkEBIwEbBEAgByAIQQAQqAFBAiMBQQFGDQgaCyMBRQRAIAYgBigCDDYCOCAGIAYoAggiBzYCNCAHIAIgCBD/ARogBkECNgJIIAYgBTYCRCAGQQE2AkAgBiAENgIwIAYgDTYCLCAGIAw2AiggBiAINgI8IAZB2ABqIQcgBkEoaiECCyAKQQNGQQEjARsEQCAHIAIQG0EDIwFBAUYNCBoLIwFFBEAgBigCWEERRiECCwJAAn8CQAJAIwFFBEAgAg0BIAZBgAFqIAZB4ABqKQMANwMAIAYgBikDWDcDeCAGQQA2AnAgBkIBNwNoIAZB+ABqIQcgBkHoAGohAgsgCkEERkEBIwEbBEAgByACQbiABRAZQQQjAUEBRg0MGiECCyMBRQRAIAJFIgINAiAGQYgBaiEACyAKQQVGQQEjARsEQEHwlgVBNyAAQdCABUGEmAUQmwFBBSMBQQFGDQwaCyMBRQRAAAsLIwFFBEAgBikCXCETIAYgBigCZDYCYCAGIBM3A1ggBkH4AGohByAGQdgAaiECCyAKQQZGQQEjARsEQCAHIA4gD0HggAVBAyACEI8BQQYjAUEBRg0LGgsjAUUEQCAGKAKAASENIAYoAoQBIQggBigCfCICIAYoAngiBw0CGiAGQdgAaiEHCyAKQQdGQQEjARsEQCAHIAIgCBBTQQcjAUEBRg0LGgsjAUUEQCAGKAJYQRZHIQcLAn8jAUUEQCAHRQRAQQAhDCAGKAJkIQggBigCXCELIAYoAmAMAgsgBkGAAWogBkHgAGopAwA3AwAgBiAGKQNYNwN4IAZBADYCcCAGQgE3A2ggBkH4AGohCyAGQegAaiEHCyAKQQhGQQEjARsEQCALIAdBuIAFEBZBCCMBQQFGDQwaIQcLIwEEfyAHBSAHDQkgBigCgAEEQCAGKAJ8ECELIAYoAmghCyAGKAJwIQhBASEMIAYoAmwLCyEHIwFFBEAgDQRAIAIQIQsgBkEoahDaASAMRQ0GIAchDSALIQIMAwsLIwEEfyACBSAGQfgAahDkASAGKAJsIQ0gBigCcCEIIAYoAmgLCyECIwFFBEAgBkEoaiIHENoBCwsjAUUEQCAGQShqIQwgASgCACELIBIoAgAhBwsgCkEJRkEBIwEbBEAgDCALIAcgBSACIAgQlQFBCSMBQQFGDQgaCyMBRQRAIAYoAiAiCCAGKAIcRyEHCwJAIwFFBEAgBw0BIAZBGGohBwsgCkEKRkEBIwEbBEAgByAIEHNBCiMBQQFGDQkaCyMBRQRAIAYoAiAhCAsLIwFFBEAgBigCGCIHIAhBDGxqIgkgBikDKDcCACAJQQhqIgwgBkEwaigCADYCACAGIAhBAWoiCDYCICANRSILRQRAIAIQIQsgESABQQhqIgFHIgINAQsLCyABIABBBGojARshASAKQQtGQQEjARsEQCABIAcgCBBOQQsjAUEBRg0GGgsjAUUEQCAAQQE2AgAMAgsLIwFFBEAgACAINgIMIAAgBzYCCCAAIAs2AgQgAEEANgIAIAYoAiAhCCAGKAIYIQcLCyMBRQRAIAgEQCAIQQxsIQggByEBA0AgAUEEaigCAARAIAEoAgAQIQsgAUEMaiEBIAhBDGsiCA0ACwsgBigCHARAIAcQIQsgEEUNAyAOECEMAwsLIAAgBkGIAWojARshACAKQQxGQQEjARsEQEHwlgVBNyAAQdCABUGEmAUQmwFBDCMBQQFGDQMaCyMBRQRAAAsLIwFFBEAgACAONgIEIABBATYCACAAQQxqIA82AgAgAEEIaiAQNgIACwsjAUUEQCAGQZABaiQACw8LIQkjAigCACAJNgIAIwIjAigCAEEEajYCACMCKAIAIgkgADYCACAJIAE2AgQgCSACNgIIIAkgAzYCDCAJIAQ2AhAgCSAFNgIUIAkgBjYCGCAJIAc2AhwgCSAINgIgIAkgCzYCJCAJIAw2AiggCSANNgIsIAkgDjYCMCAJIA82AjQgCSAQNgI4IAkgETYCPCAJIBI2AkAjAiMCKAIAQcQAajYCAAu7DQEOfyMBQQJGBEAjAiMCKAIAQTxrNgIAIwIoAgAiASgCACEAIAEoAgghAiABKAIMIQMgASgCECEEIAEoAhQhBSABKAIYIQcgASgCHCEIIAEoAiAhCSABKAIkIQogASgCKCELIAEoAiwhDCABKAIwIQ4gASgCNCEPIAEoAjghECABKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQ0LIwFFBEAgAygCECEPCyANRUEBIwEbBEAgAkEiIA8RAABBACMBQQFGDQEaIQQLAkACQCMBRUEAIAQbRQRAAkAjAUUEQCABRQRAQQAhBAwCCyAAIAFqIRBBACELIAAhDEEAIQcLAkADQCMBRQRAAkAgDCwAACIEQX9KBEAgDEEBaiEOIARB/wFxIQgMAQsgDC0AAUE/cSEFIARBH3EhCCAEQV9NBEAgBSAIQQZ0ciEIIAxBAmohDgwBCyAMLQACQT9xIAVBBnRyIQUgDEEDaiEOIARBcEkEQCAFIAhBDHRyIQgMAQsgCEESdEGAgPAAcSAOLQAAQT9xIAVBBnRyciIIQYCAxABGIgQNAyAMQQRqIQ4LQTAhCUGCgMQAIQQLAkACfwJAAkACQAJAAkAjAUUEQAJAAkAgCA4jCQEBAQEBAQEBAwUBAQQBAQEBAQEBAQEBAQEBAQEBAQEBAQYACyAIQdwARiIFDQULCyANQQFGQQEjARsEQCAIEERBASMBQQFGDQ4aIQQLIwFFBEAgBEUiBA0FIAhBAXJnQQJ2IgRBB3MMBgsLIwFFBEBB9AAhCQwGCwsjAUUEQEHyACEJDAULCyMBRQRAQe4AIQkMBAsLIwFFBEAgCCEJDAMLCyMBRQRAIAghCUGBgMQAIQQLIA1BAkZBASMBGwRAIAgQZUECIwFBAUYNCRohBQsjAQR/IAkFIAUNAiAIQQFyZ0ECdiIEQQdzCwshCSAEIAgjARshBAsjAUUEQCAEQYCAxABrIgVBA0khCiAFQQMgChtBAUYhBQsCQAJAIwFFBEAgBQ0BIAcgC0sNAgJAIAdFDQAgASAHTQRAIAEgB0YNAQwECyAAIAdqLAAAQUBIDQMLAkAgC0UNACABIAtNBEAgASALRw0EDAELIAAgC2osAABBv39MDQMLIAAgB2ohBSADKAIMIQogCyAHayEHCyANQQNGQQEjARsEQCACIAUgByAKEQEAQQMjAUEBRg0JGiEFCyMBRQRAIAVFIgVFBEBBAQ8LQQUhCgsDQCMBRQRAIAohBiAEIQVBgYDEACEEQdwAIQcCQAJ/AkACQAJAAkAgBUGAgMQAayIRQQMgEUEDSRtBAWsOAwEFAAILQQAhCkH9ACEHIAUhBAJAAkACQCAGQf8BcUEBaw4FBwUAAQIEC0ECIQpB+wAMBQtBAyEKQfUADAQLQQQhCkHcAAwDC0GAgMQAIQQgCSIHQYCAxABHIgUNAwsgCwJ/QQEgCEGAAUkiBQ0AGkECIAhBgBBJIgUNABpBA0EEIAhBgIAESRsLIgRqIQcMBQsgBkEBIAkbIQpBMEHXACAFIAlBAnR2QQ9xIgRBCkkbIQcgCUEBa0EAIAkbIQkgBCAHagshByAFIQQLCyANQQRGQQEjARsEQCACIAcgDxEAAEEEIwFBAUYNChohBQsjAUUEQCAFRSIFDQELCyMBRQRAQQEPCwsjAUUEQCAOIAsgDGtqIQsgECAOIgxHIgQNAgwDCwsLIA1BBUZBASMBGwRAIAAgASAHIAtBkIgEEOwBQQUjAUEBRg0GGgsjAUUEQAALCyMBRQRAIAdFBEBBACEEDAILIAEgB00EQCAHIAEiBEYNAgwFCyAAIAdqLAAAQb9/TA0EIAchBAsLIwFFBEAgASAEayEBIAMoAgwhAyAAIARqIQALIA1BBkZBASMBGwRAIAIgACABIAMRAQBBBiMBQQFGDQQaIQALIwFFBEAgAEUiAA0CCwsjAUUEQEEBDwsLIA1BB0ZBASMBGwRAIAJBIiAPEQAAQQcjAUEBRg0CGiEACyMBRQRAIAAPCwsgDUEIRkEBIwEbBEAgACABIAcgAUGgiAQQ7AFBCCMBQQFGDQEaCyMBRQRAAAsACyEGIwIoAgAgBjYCACMCIwIoAgBBBGo2AgAjAigCACIGIAA2AgAgBiABNgIEIAYgAjYCCCAGIAM2AgwgBiAENgIQIAYgBTYCFCAGIAc2AhggBiAINgIcIAYgCTYCICAGIAo2AiQgBiALNgIoIAYgDDYCLCAGIA42AjAgBiAPNgI0IAYgEDYCOCMCIwIoAgBBPGo2AgBBAAveDgIEfwF+IwFBAkYEQCMCIwIoAgBBHGs2AgAjAigCACIBKAIAIQAgASgCCCECIAEoAgwhAyABKAIQIQQgASkCFCEHIAEoAgQhAQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBQsjAUUEQCMAQeAAayIDJAAgAkGAAUkhBAsCQAJ/AkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAjAUUEQCAEDQEgAkFfSyIEDQIgAqwiB0KAAXxCgAJUIgQNAyAHQoCAAnwiB0KAgARaIgQNBCADQTBqIQQLIAVFQQEjARsEQCAEIAFBFRCqAUEAIwFBAUYNEBoLIwFFBEAgAy0AMEEERwRAIAMpAzAiB0L/AYNCBFINCwsgAyACQQh0IgQgAkGA/gNxQQh2cjsBACADQTBqIQILIAVBAUZBASMBGwRAIAIgASADQQIQYEEBIwFBAUYNEBoLIwFFBEAgAy0AMEEERiIBDQUgAykDMCIHQv8Bg0IEUSIBDQUgAyAHNwMwIANBMGohAQsgBUECRkEBIwEbBEAgAyABEJgBQQIjAUEBRg0QGgsjAUUNCwsgBCADQTBqIwEbIQQgBUEDRkEBIwEbBEAgBCABIAIQf0EDIwFBAUYNDxoLIwFFBEBBESADKAIwIgFBEUYNDRogA0EMaiICIANBPGooAgA2AgAgAyADKQI0Igc3AgQMBwsLIwFFBEAgA0EQaiEEIAJBCHRBJHIhAgsgBUEERkEBIwEbBEAgBCABIAIQqgFBBCMBQQFGDQ4aCyMBRQRAIAMtABBBBEYiAQ0DIAMgAykDECIHNwMYIANBADYCKCADQgE3AyAgA0HMAGpB2JYFNgIAIANBAzoAUCADQoCAgICABDcDMCADQQA2AkAgA0EANgI4IAMgA0EgajYCSCADQTBqIQIgA0EYaiEBCyAFQQVGQQEjARsEQCABIAIQSUEFIwFBAUYNDhohAQsjAUUEQCABRSIBDQUgA0HYAGohAAsgBUEGRkEBIwEbBEBB8JYFQTcgAEGolwVBhJgFEJsBQQYjAUEBRg0OGgsjAUUEQAALCyAEIANBMGojARshBCAFQQdGQQEjARsEQCAEIAFBFBCqAUEHIwFBAUYNDRoLIwFFBEAgAy0AMEEERiIERQRAIAMpAzAiB0L/AYNCBFIiBA0HCyADIAI6AAAgA0EwaiECCyAFQQhGQQEjARsEQCACIAEgA0EBEGBBCCMBQQFGDQ0aCyMBRQRAIAMtADBBBEYiAQ0CIAMpAzAiB0L/AYNCBFEiAQ0CIAMgBzcDMCADQTBqIQELIAVBCUZBASMBGwRAIAMgARCYAUEJIwFBAUYNDRoLIwFFDQgLIAQgA0EwaiMBGyEEIAVBCkZBASMBGwRAIAQgAUEWEKoBQQojAUEBRg0MGgsjAUUEQCADLQAwQQRHBEAgAykDMCIHQv8Bg0IEUg0ICyADIAJBCHZBgP4DcSACQRh2ciACQQh0QYCA/AdxIAJBGHRycjYCACADQTBqIQILIAVBC0ZBASMBGwRAIAIgASADQQQQYEELIwFBAUYNDBoLIwFFBEAgAy0AMEEERiIBDQEgAykDMCIHQv8Bg0IEUiIBDQILCyMBRQRAIANBETYCAEERDAkLCyMBRQRAIAMgBzcDMCADQTBqIQELIAVBDEZBASMBGwRAIAMgARCYAUEMIwFBAUYNChoLIwFFDQULIwFFBEAgA0EMaiADQShqKAIAIgI2AgAgAyADKQMgIgc3AgQgA0EYaiEBCyAFQQ1GQQEjARsEQCABENABQQ0jAUEBRg0JGgsgAUEBIwEbIQELIwFFBEAgAyABNgIADAULCyMBRQRAIAMgBzcDMCADQTBqIQELIAVBDkZBASMBGwRAIAMgARCYAUEOIwFBAUYNBxoLIwFFDQILIwFFBEAgAyAHNwMwIANBMGohAQsgBUEPRkEBIwEbBEAgAyABEJgBQQ8jAUEBRg0GGgsjAUUNAQsjAUUEQCADIAc3AzAgA0EwaiEBCyAFQRBGQQEjARsEQCADIAEQmAFBECMBQQFGDQUaCwsjAUUEQEERIgIgAygCAEERRiIBDQIaCwsjAUUEQCADQThqIANBCGopAwA3AwAgAyADKQMANwMwIANBADYCKCADQgE3AyAgA0EgaiECIANBMGohAQsgBUERRkEBIwEbBEAgASACQdiWBRAZQREjAUEBRg0DGiEBCyMBBH8gAgUgAQ0CIAAgAykDIDcCBCAAQQxqIANBKGooAgA2AgAgA0EwahDkAUENCwshAiMBRQRAIAAgAjYCACADQeAAaiQADwsLIAAgA0HYAGojARshACAFQRJGQQEjARsEQEHwlgVBNyAAQaiXBUGEmAUQmwFBEiMBQQFGDQEaCyMBRQRAAAsPCyEGIwIoAgAgBjYCACMCIwIoAgBBBGo2AgAjAigCACIGIAA2AgAgBiABNgIEIAYgAjYCCCAGIAM2AgwgBiAENgIQIAYgBzcCFCMCIwIoAgBBHGo2AgALgAcBBX8gAEEIayEBIAEgAEEEaygCACIDQXhxIgBqIQICQAJAAkAgA0EBcQ0AIANBA3FFDQEgASgCACIDIABqIQAgASADayIBQZC8BSgCAEYEQCACKAIEQQNxQQNHDQFBiLwFIAA2AgAgAiACKAIEQX5xNgIEIAEgAEEBcjYCBCAAIAFqIAA2AgAPCyADQYACTwRAIAEQVwwBCyABQQxqKAIAIgQgAUEIaigCACIFRwRAIAUgBDYCDCAEIAU2AggMAQtB+LgFQfi4BSgCAEF+IANBA3Z3cTYCAAsCQCACKAIEIgNBAnEEQCACIANBfnE2AgQgASAAQQFyNgIEIAAgAWogADYCAAwBCwJAAkACQCACQZS8BSgCAEcEQEGQvAUoAgAgAkcNAUGQvAUgATYCAEGIvAVBiLwFKAIAIABqIgA2AgAgASAAQQFyNgIEIAAgAWogADYCAA8LQZS8BSABNgIAQYy8BUGMvAUoAgAgAGoiADYCACABIABBAXI2AgQgAUGQvAUoAgBGDQEMAgsgA0F4cSIEIABqIQACQCAEQYACTwRAIAIQVwwBCyACQQxqKAIAIgQgAkEIaigCACICRwRAIAIgBDYCDCAEIAI2AggMAQtB+LgFQfi4BSgCAEF+IANBA3Z3cTYCAAsgASAAQQFyNgIEIAAgAWogADYCACABQZC8BSgCAEcNAkGIvAUgADYCAAwDC0GIvAVBADYCAEGQvAVBADYCAAtBsLwFKAIAIgMgAE8NAUGUvAUoAgAiAkUNAUEAIQECQEGMvAUoAgAiBEEpSQ0AQaC8BSEAA0AgAiAAKAIAIgVPBEAgACgCBCAFaiACSw0CCyAAKAIIIgANAAsLQai8BSgCACIABEADQCABQQFqIQEgACgCCCIADQALC0G4vAUgAUH/HyABQf8fSxs2AgAgAyAETw0BQbC8BUF/NgIADwsgAEGAAkkNASABIAAQVkEAIQFBuLwFQbi8BSgCAEEBayIANgIAIAANAEGovAUoAgAiAARAA0AgAUEBaiEBIAAoAggiAA0ACwtBuLwFIAFB/x8gAUH/H0sbNgIADwsPCyAAQXhxQYC5BWohAgJ/QQEgAEEDdnQiAEH4uAUoAgAiA3EEQCACKAIIDAELQfi4BSAAIANyNgIAIAILIQAgAiABNgIIIAAgATYCDCABIAI2AgwgASAANgIIC+sNAgV/AX4jAUECRgRAIwIjAigCAEEkazYCACMCKAIAIgEoAgAhACABKAIIIQIgASgCDCEDIAEoAhAhBCABKAIUIQUgASgCGCEGIAEpAhwhCSABKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQcLIwFFBEAjAEHQAGsiBCQAIANBIEkhBQsCQAJAAkACQAJAAkACQAJAAkACQAJAIwFFBEAgBQ0BIANBgAJJIgUNAiADQYCABEkiBQ0DIARBEGohBQsgB0VBASMBGwRAIAUgAUEfEKoBQQAjAUEBRg0MGgsjAUUEQCAELQAQQQRGIgUNBCAEKQMQIglC/wGDQgRRIgUNBCAEIAk3AxAgBEEQaiEGIARBIGohBQsgB0EBRkEBIwEbBEAgBSAGEJgBQQEjAUEBRg0MGgsjAUUNBgsjAUUEQCADQQh0QQNyIQYgBEEQaiEFCyAHQQJGQQEjARsEQCAFIAEgBhCqAUECIwFBAUYNCxoLIwFFBEAgBC0AEEEERiIFDQcgBCkDECIJQv8Bg0IEUSIFDQcgBCAJNwMQIARBEGohBiAEQSBqIQULIAdBA0ZBASMBGwRAIAUgBhCYAUEDIwFBAUYNCxoLIwFFDQULIAUgBEEQaiMBGyEFIAdBBEZBASMBGwRAIAUgAUEdEKoBQQQjAUEBRg0KGgsjAUUEQCAELQAQQQRHBEAgBCkDECIJQv8Bg0IEUiIFDQQLIAQgAzoACCAEQQhqIQYgBEEQaiEFCyAHQQVGQQEjARsEQCAFIAEgBkEBEGBBBSMBQQFGDQoaCyMBRQRAIAQtABBBBEYiBQ0GIAQpAxAiCUL/AYNCBFEiBQ0GIAQgCTcDECAEQRBqIQYgBEEgaiEFCyAHQQZGQQEjARsEQCAFIAYQmAFBBiMBQQFGDQoaCyMBRQ0ECyAFIARBEGojARshBSAHQQdGQQEjARsEQCAFIAFBHhCqAUEHIwFBAUYNCRoLIwFFBEAgBC0AEEEERwRAIAQpAxAiCUL/AYNCBFIiBQ0ECyAEIANBCHQgA0GA/gNxQQh2cjsBCCAEQQhqIQYgBEEQaiEFCyAHQQhGQQEjARsEQCAFIAEgBkECEGBBCCMBQQFGDQkaCyMBRQRAIAQtABBBBEYiBQ0FIAQpAxAiCUL/AYNCBFEiBQ0FIAQgCTcDECAEQRBqIQYgBEEgaiEFCyAHQQlGQQEjARsEQCAFIAYQmAFBCSMBQQFGDQkaCyMBRQ0DCyMBRQRAIAQgA0EIdEGAgPwHcSADQRh0ciADQQh2QYD+A3EgA0EYdnJyNgIIIARBCGohBiAEQRBqIQULIAdBCkZBASMBGwRAIAUgASAGQQQQYEEKIwFBAUYNCBoLIwFFBEAgBC0AEEEERiIFDQQgBCkDECIJQv8Bg0IEUSIFDQQgBCAJNwMQIARBEGohBiAEQSBqIQULIAdBC0ZBASMBGwRAIAUgBhCYAUELIwFBAUYNCBoLIwFFDQILIwFFBEAgBCAJNwMQIARBEGohBiAEQSBqIQULIAdBDEZBASMBGwRAIAUgBhCYAUEMIwFBAUYNBxoLIwFFDQELIwFFBEAgBCAJNwMQIARBEGohBiAEQSBqIQULIAdBDUZBASMBGwRAIAUgBhCYAUENIwFBAUYNBhoLCyMBRQRAIAQoAiAiBUERRw0CCwsgB0EORkEBIwEbBEAgBCABIAIgAxBgQQ4jAUEBRg0EGgsjAUUEQEERIQMgBC0AAEEERiEBCwJAIwFFBEAgAQ0BIAQgBCkDADcDCCAEQQA2AhggBEIBNwMQIARBPGpB2JYFNgIAIARBAzoAQCAEQoCAgICABDcDICAEQQA2AjAgBEEANgIoIAQgBEEQajYCOCAEQSBqIQIgBEEIaiEBCyAHQQ9GQQEjARsEQCABIAIQSUEPIwFBAUYNBRohAQsjAUUEQCABDQQgACAEKQMQNwIEIABBDGogBEEYaigCADYCACAEQQhqIQELIAdBEEZBASMBGwRAIAEQ0AFBECMBQQFGDQUaCyADQQ4jARshAwsjAUUEQCAAIAM2AgAMAgsLIwFFBEAgACAEKQIkNwIEIABBDGogBEEsaigCADYCACAAIAU2AgALCyMBRQRAIARB0ABqJAAPCwsgACAEQcgAaiMBGyEAIAdBEUZBASMBGwRAQfCWBUE3IABBqJcFQYSYBRCbAUERIwFBAUYNARoLIwFFBEAACw8LIQgjAigCACAINgIAIwIjAigCAEEEajYCACMCKAIAIgggADYCACAIIAE2AgQgCCACNgIIIAggAzYCDCAIIAQ2AhAgCCAFNgIUIAggBjYCGCAIIAk3AhwjAiMCKAIAQSRqNgIAC+0FAQh/AkAgAUHM/3tLDQBBECABQQtqQXhxIAFBC0kbIQIgAEEEayIFKAIAIgZBeHEhBAJAAkACQAJAAkACQCAGQQNxBEAgAEEIayEIIAIgBE0NASAEIAhqIgdBlLwFKAIARg0CQZC8BSgCACAHRg0DIAcoAgQiBkECcQ0GIAZBeHEiCSAEaiIEIAJPDQQMBgsgAkGAAkkgAkEEciAES3IgBCACa0GBgAhPcg0FDAQLIAQgAmsiAUEQSQ0DIAUgAiAGQQFxckECcjYCACACIAhqIgMgAUEDcjYCBCABIANqIgIoAgRBAXIhBSACIAU2AgQgAyABEC0MAwtBjLwFKAIAIARqIgQgAk0NAyAFIAIgBkEBcXJBAnI2AgAgAiAIaiIBIAQgAmsiA0EBcjYCBEGMvAUgAzYCAEGUvAUgATYCAAwCC0GIvAUoAgAgBGoiBCACSQ0CAkAgBCACayIBQQ9NBEAgBSAEIAZBAXFyQQJyNgIAIAQgCGoiASgCBEEBciEDIAEgAzYCBEEAIQFBACEDDAELIAUgAiAGQQFxckECcjYCACACIAhqIgMgAUEBcjYCBCABIANqIgIgATYCACACIAIoAgRBfnE2AgQLQZC8BSADNgIAQYi8BSABNgIADAELIAQgAmshAQJAIAlBgAJPBEAgBxBXDAELIAdBDGooAgAiAyAHQQhqKAIAIgdHBEAgByADNgIMIAMgBzYCCAwBC0H4uAVB+LgFKAIAQX4gBkEDdndxNgIACyABQRBPBEAgBSACIAUoAgBBAXFyQQJyNgIAIAIgCGoiAyABQQNyNgIEIAEgA2oiAigCBEEBciEFIAIgBTYCBCADIAEQLQwBCyAFIAQgBSgCAEEBcXJBAnI2AgAgBCAIaiIBKAIEQQFyIQMgASADNgIECyAAIQMMAQsgARAPIgJFDQBBfEF4IAUoAgAiA0EDcRsgA0F4cWoiAyABSSEFIAIgACADIAEgBRsQ/wEgABAhDwsgAwuMDQIJfwN+IwFBAkYEQCMCIwIoAgBBQGo2AgAjAigCACIBKAIAIQAgASgCCCECIAEoAgwhAyABKAIQIQQgASgCFCEHIAEoAhghCCABKQIcIQwgASgCJCEJIAEoAighCiABKQIsIQ0gASkCNCEOIAEoAjwhCyABKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQYLIwFFBEAjACIHQaABayIDJAALIAZFQQEjARsEQCABIAIQAkEAIwFBAUYNARohAQsCQAJ/AkACQAJAIwFFQQAgASABRSMBGyIBG0UEQCAGQQFGQQEjARsEQBADQQEjAUEBRg0HGiEKCyAGQQJGQQEjARsEQCADIAoQ0QFBAiMBQQFGDQcaCyMBRQRAIAMoAgAhCQsgBkEDRkEBIwEbBEAgCRAEQQMjAUEBRg0HGgsjAUUEQEEbEA8iAkUiAQ0CIAJBpJwFKQAANwAAIAJBCGpBrJwFKQAANwAAIAJBEGpBtJwFKQAAIgw3AAAgAkEXakG7nAUoAAAiBzYAACADQYABakIANwMAIANBGzYCdCADIAI2AnAgA0KbgICAwAA3A3ggA0HwAGohAiADQQhqIQELIAZBBEZBASMBGwRAIAEgCSAKIAIQW0EEIwFBAUYNBxoLIAEgA0E4aiMBGyEBIAZBBUZBASMBGwRAIAFB0JQFQSBB8JQFQQtB+5QFQSQQbUEFIwFBAUYNBxoLIwFFBEAgA0EIaiEHIANB8ABqIQILIAZBBkZBASMBGwRAIAIgBxAqQQYjAUEBRg0HGgsjAUUEQCADKAJwIgRBFkchAgsCQCMBRQRAAkAgAkUEQCADKAJ0IQIgA0EANgJYIANCBDcDUCACDQFBBAwICyADKQN4IQ0gAygCdCEIDAILIAKtIQ5BBCEHQgAhDEEAIQILA0AjAUUEQCADQQA2AmggA0IBNwNgIANBAzoAkAEgA0KAgICAgAQ3A3AgA0H8hQU2AowBIANBADYCgAEgA0EANgJ4IAMgA0HgAGo2AogBIANB8ABqIQQLIAZBB0ZBASMBGwRAIAxBASAEEFJBByMBQQFGDQkaIQQLIwFFBEAgBA0FIAMoAmghCCADKAJgIQQLIAZBCEZBASMBGwRAIAFBlIsFQQYgBCAIQZqLBUEBEG1BCCMBQQFGDQkaCyMBRQRAIAMoAmQEQCADKAJgECELIANBCGohCCADQfAAaiEECyAGQQlGQQEjARsEQCAEIAgQFEEJIwFBAUYNCRoLIwFFBEAgAykDeCENIAMoAnAiBEEWRyELIAMoAnQhCAsCQCMBRQRAIAsNASADKAJUIAJHIQQLAkAjAUUEQCAEDQEgA0HQAGohBwsgBkEKRkEBIwEbBEAgByACEHNBCiMBQQFGDQsaCyMBRQRAIAMoAlAhByADKAJYIQILCyMBRQRAIAxCAXwhDCAHIAJBDGxqIgIgDTcCBCACIAg2AgAgAyADKAJYQQFqIgI2AlggA0HwAGohBAsgBkELRkEBIwEbBEAgBCABEDxBCyMBQQFGDQoaCyMBRQRAIAMoAnQEQCADKAJwECELIAwgDlEiBA0HDAILCwsjAUUEQCACBEAgAkEMbCEBIAchAgNAIAJBBGooAgAEQCACKAIAECELIAJBDGohAiABQQxrIgENAAsLIAMoAlRFIgANASAHECELCyMBRQRAIAMgDTcDeCADIAg2AnQgAyAENgJwIANB8ABqIQALIAZBDEZBASMBGwRAQfeiBUErIABBwJQFQZSWBRCbAUEMIwFBAUYNBxoLIwFFBEAACwsjAUUEQCAAQQA2AgggAEIENwIADAULCyMBRQRAAAsLIAAgA0GYAWojARshACAGQQ1GQQEjARsEQEHwlgVBNyAAQZSGBUGEmAUQmwFBDSMBQQFGDQQaCyMBRQRAAAsLIwEEfyACBSADKAJQCwshAiMBRQRAIAAgAykCVDcCBCAAIAI2AgAgARDMASADQRRqKAIABEAgAygCEBAhCyADQSBqEMwBIApFDQEgCRAhCwsjAUUEQCADQaABaiQACw8LIQUjAigCACAFNgIAIwIjAigCAEEEajYCACMCKAIAIgUgADYCACAFIAE2AgQgBSACNgIIIAUgAzYCDCAFIAQ2AhAgBSAHNgIUIAUgCDYCGCAFIAw3AhwgBSAJNgIkIAUgCjYCKCAFIA03AiwgBSAONwI0IAUgCzYCPCMCIwIoAgBBQGs2AgAL/QwCBX8BfiMBQQJGBEAjAiMCKAIAQSRrNgIAIwIoAgAiASgCACEAIAEoAgghAiABKAIMIQMgASgCECEEIAEoAhQhBSABKAIYIQYgASkCHCEJIAEoAgQhAQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBwsjAUUEQCMAQdAAayIEJAAgA0UhBQsCQAJAAkACQAJAAkACQAJAIwFFBEAgBQ0BIANBgAJJIQULAkACQAJAIwFFBEAgBQ0BIANBgIAESSIFDQIgBEEQaiEFCyAHRUEBIwEbBEAgBSABQQoQqgFBACMBQQFGDQwaCyMBRQRAIAQtABBBBEYiBQ0DIAQpAxAiCUL/AYNCBFEiBQ0DIAQgCTcDECAEQRBqIQYgBEEgaiEFCyAHQQFGQQEjARsEQCAFIAYQmAFBASMBQQFGDQwaCyMBRQ0GCyAFIARBEGojARshBSAHQQJGQQEjARsEQCAFIAFBCBCqAUECIwFBAUYNCxoLIwFFBEAgBC0AEEEERwRAIAQpAxAiCUL/AYNCBFIiBQ0FCyAEIAM6AAggBEEIaiEGIARBEGohBQsgB0EDRkEBIwEbBEAgBSABIAZBARBgQQMjAUEBRg0LGgsjAUUEQCAELQAQQQRGIgUNByAEKQMQIglC/wGDQgRRIgUNByAEIAk3AxAgBEEQaiEGIARBIGohBQsgB0EERkEBIwEbBEAgBSAGEJgBQQQjAUEBRg0LGgsjAUUNBQsgBSAEQRBqIwEbIQUgB0EFRkEBIwEbBEAgBSABQQkQqgFBBSMBQQFGDQoaCyMBRQRAIAQtABBBBEcEQCAEKQMQIglC/wGDQgRSIgUNBQsgBCADQQh0IANBgP4DcUEIdnI7AQggBEEIaiEGIARBEGohBQsgB0EGRkEBIwEbBEAgBSABIAZBAhBgQQYjAUEBRg0KGgsjAUUEQCAELQAQQQRGIgUNBiAEKQMQIglC/wGDQgRRIgUNBiAEIAk3AxAgBEEQaiEGIARBIGohBQsgB0EHRkEBIwEbBEAgBSAGEJgBQQcjAUEBRg0KGgsjAUUNBAsjAUUEQCAEIANBCHRBgID8B3EgA0EYdHIgA0EIdkGA/gNxIANBGHZycjYCCCAEQQhqIQYgBEEQaiEFCyAHQQhGQQEjARsEQCAFIAEgBkEEEGBBCCMBQQFGDQkaCyMBRQRAIAQtABBBBEYiBQ0FIAQpAxAiCUL/AYNCBFEiBQ0FIAQgCTcDECAEQRBqIQYgBEEgaiEFCyAHQQlGQQEjARsEQCAFIAYQmAFBCSMBQQFGDQkaCyMBRQ0DCyAHQQpGQQEjARsEQCAAIAEQgAFBCiMBQQFGDQgaCyMBRQ0GCyMBRQRAIAQgCTcDECAEQRBqIQYgBEEgaiEFCyAHQQtGQQEjARsEQCAFIAYQmAFBCyMBQQFGDQcaCyMBRQ0BCyMBRQRAIAQgCTcDECAEQRBqIQYgBEEgaiEFCyAHQQxGQQEjARsEQCAFIAYQmAFBDCMBQQFGDQYaCwsjAUUEQCAEKAIgIgVBEUcNAgsLIAdBDUZBASMBGwRAIAQgASACIAMQYEENIwFBAUYNBBoLIwFFBEBBESEDIAQtAABBBEYhAQsCQCMBRQRAIAENASAEIAQpAwA3AwggBEEANgIYIARCATcDECAEQTxqQdiWBTYCAEEDIQMgBEEDOgBAIARCgICAgIAENwMgIARBADYCMCAEQQA2AiggBCAEQRBqNgI4IARBIGohAiAEQQhqIQELIAdBDkZBASMBGwRAIAEgAhBJQQ4jAUEBRg0FGiEBCyMBRQRAIAENAyAAIAQpAxA3AgQgAEEMaiAEQRhqKAIANgIAIARBCGohAQsgB0EPRkEBIwEbBEAgARDQAUEPIwFBAUYNBRoLCyMBRQRAIAAgAzYCAAwDCwsjAUUEQCAAIAQpAiQ3AgQgAEEMaiAEQSxqKAIANgIAIAAgBTYCAAwCCwsgACAEQcgAaiMBGyEAIAdBEEZBASMBGwRAQfCWBUE3IABBqJcFQYSYBRCbAUEQIwFBAUYNAhoLIwFFBEAACwsjAUUEQCAEQdAAaiQACw8LIQgjAigCACAINgIAIwIjAigCAEEEajYCACMCKAIAIgggADYCACAIIAE2AgQgCCACNgIIIAggAzYCDCAIIAQ2AhAgCCAFNgIUIAggBjYCGCAIIAk3AhwjAiMCKAIAQSRqNgIAC4cOAQN/IwFBAkYEQCMCIwIoAgBBDGs2AgAjAigCACICKAIAIQAgAigCBCEBIAIoAgghAgsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBAsjAUUEQCMAQRBrIgIkACAAKAIAIQMLAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAjAUUEQAJAIANBAWsOFQIDBAUGBwgJCgsMDQ4PEBESExQVFgALIAIgAEEEajYCDCACQQxqIQALIARFQQEjARsEQCABQbCTBUETIABBiI8FEHVBACMBQQFGDRcaIQALIwFFDRULIwFFBEAgAiAAQQRqNgIMIAJBDGohAAsgBEEBRkEBIwEbBEAgAUGgkwVBECAAQYiPBRB1QQEjAUEBRg0WGiEACyMBRQ0UCyMBRQRAIAIgAEEEajYCDCACQQxqIQALIARBAkZBASMBGwRAIAFBkpMFQQ4gAEGIjwUQdUECIwFBAUYNFRohAAsjAUUNEwsjAUUEQCACIABBBGo2AgwgAkEMaiEACyAEQQNGQQEjARsEQCABQYKTBUEQIABBiI8FEHVBAyMBQQFGDRQaIQALIwFFDRILIwFFBEAgAiAAQQRqNgIMIAJBDGohAAsgBEEERkEBIwEbBEAgAUHvkgVBEyAAQYiPBRB1QQQjAUEBRg0TGiEACyMBRQ0RCyMBRQRAIAIgAEEEajYCDCACQQxqIQALIARBBUZBASMBGwRAIAFB45IFQQwgAEGIjwUQdUEFIwFBAUYNEhohAAsjAUUNEAsjAUUEQCACIABBBGo2AgwgAkEMaiEACyAEQQZGQQEjARsEQCABQdaSBUENIABBiI8FEHVBBiMBQQFGDREaIQALIwFFDQ8LIwFFBEAgAiAAQQRqNgIMIAJBDGohAAsgBEEHRkEBIwEbBEAgAUHIkgVBDiAAQYiPBRB1QQcjAUEBRg0QGiEACyMBRQ0OCyMBRQRAIAIgAEEEajYCDCACQQxqIQALIARBCEZBASMBGwRAIAFBuZIFQQ8gAEGIjwUQdUEIIwFBAUYNDxohAAsjAUUNDQsjAUUEQCACIABBBGo2AgwgAkEMaiEACyAEQQlGQQEjARsEQCABQaeSBUESIABBiI8FEHVBCSMBQQFGDQ4aIQALIwFFDQwLIwFFBEAgAiAAQQRqNgIMIAJBDGohAAsgBEEKRkEBIwEbBEAgAUGakgVBDSAAQYiPBRB1QQojAUEBRg0NGiEACyMBRQ0LCyMBRQRAIAIgAEEEajYCDCACQQxqIQALIARBC0ZBASMBGwRAIAFBjZIFQQ0gAEGIjwUQdUELIwFBAUYNDBohAAsjAUUNCgsjAUUEQCACIABBBGo2AgwgAkEMaiEACyAEQQxGQQEjARsEQCABQf+RBUEOIABBiI8FEHVBDCMBQQFGDQsaIQALIwFFDQkLIwFFBEAgAiAAQQRqNgIMIAJBDGohAAsgBEENRkEBIwEbBEAgAUHzkQVBDCAAQYiPBRB1QQ0jAUEBRg0KGiEACyMBRQ0ICyMBRQRAIAIgAEEEajYCDCACQQxqIQALIARBDkZBASMBGwRAIAFB3ZEFQRYgAEGIjwUQdUEOIwFBAUYNCRohAAsjAUUNBwsjAUUEQCACIABBBGo2AgwgAkEMaiEACyAEQQ9GQQEjARsEQCABQdGRBUEMIABBiI8FEHVBDyMBQQFGDQgaIQALIwFFDQYLIwFFBEAgAiAAQQRqNgIMIAJBDGohAAsgBEEQRkEBIwEbBEAgAUHEkQVBDSAAQYiPBRB1QRAjAUEBRg0HGiEACyMBRQ0FCyMBRQRAIAIgAEEEajYCDCACQQxqIQALIARBEUZBASMBGwRAIAFBtJEFQRAgAEGIjwUQdUERIwFBAUYNBhohAAsjAUUNBAsjAUUEQCACIABBBGo2AgwgAkEMaiEACyAEQRJGQQEjARsEQCABQaKRBUESIABBiI8FEHVBEiMBQQFGDQUaIQALIwFFDQMLIwFFBEAgAiAAQQRqNgIMIAJBDGohAAsgBEETRkEBIwEbBEAgAUGWkQVBDCAAQYiPBRB1QRMjAUEBRg0EGiEACyMBRQ0CCyMBRQRAIAIgAEEEajYCDCACQQxqIQALIARBFEZBASMBGwRAIAFBiZEFQQ0gAEGIjwUQdUEUIwFBAUYNAxohAAsjAUUNAQsjAUUEQCACIABBBGo2AgwgAkEMaiEACyAEQRVGQQEjARsEQCABQYCPBUEHIABBiI8FEHVBFSMBQQFGDQIaIQALCyMBRQRAIAJBEGokACAADwsACyEDIwIoAgAgAzYCACMCIwIoAgBBBGo2AgAjAigCACIDIAA2AgAgAyABNgIEIAMgAjYCCCMCIwIoAgBBDGo2AgBBAAvxCwELfyMBQQJGBEAjAiMCKAIAQThrNgIAIwIoAgAiCCgCACEAIAgoAgQhASAIKAIIIQIgCCgCDCEDIAgoAhAhBCAIKAIUIQYgCCgCGCEHIAgoAhwhCSAIKAIgIQogCCgCJCELIAgoAighDCAIKAIsIQ0gCCgCMCEOIAgoAjQhCAsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBQsjAUUEQCMAQYABayIEJAAgBEHcAGoiB0EINgIAIARBCTYCVCAEQbSeBTYCUCAEQQA2AkAgBCAEQUBrNgJYIARBAjYCfCAEQQE2AnQgBEG8ngU2AnAgBEEBNgJsIARBkKQFNgJoIAQgBEHQAGo2AnggBEHoAGohBgsgBUVBASMBGwRAIAQgBhA7QQAjAUEBRg0BGgsjAUUEQCAHQQg2AgAgBEEJNgJUIARBtJ4FNgJQIARBATYCQCAEIARBQGs2AlggBEECNgJ8IARBATYCdCAEQbyeBTYCcCAEQQE2AmwgBEHcngU2AmggBCAEQdAAajYCeCAEQegAaiEGIARBEGohBwsgBUEBRkEBIwEbBEAgByAGEDtBASMBQQFGDQEaCyMBRQRAIARBADYCKCAEQgE3AyAgBEEgaiEHIAQoAgghBiAEKAIAIQsLIAVBAkZBASMBGwRAIAcgCyAGEMUBQQIjAUEBRg0BGgsjAUUEQCAEQfQAaiABKAIINgIAIARBCTYCbCAEQeSeBTYCaCAEIAEoAgA2AnAgBEHoAGohBiAEQTBqIQcLIAVBA0ZBASMBGwRAIAcgBkECEF1BAyMBQQFGDQEaCyMBRQRAIARBIGohByAEKAI4IQYgBCgCMCEKCyAFQQRGQQEjARsEQCAHIAogBhDFAUEEIwFBAUYNARoLIwFFBEAgAUEUaigCACIHRSEGCwJAAkACQCMBRQRAIAYNASAEKAIYIQ0gBCgCECEMIAdBJGwiBiABKAIMakEMayEBCwNAIAYgBEEgaiMBGyEGIAVBBUZBASMBGwRAIAYgDCANEMUBQQUjAUEBRg0FGgsjAUUEQCAEQQQ2AmQgBEEENgJcIARBBDYCVCAEIAE2AmAgBCABQQxrNgJYIAQgAUEYazYCUCAEQQM2AnwgBEIDNwJsIARBjJ8FNgJoIAQgBEHQAGo2AnggBEFAayEGIARB6ABqIQkLIAVBBkZBASMBGwRAIAYgCRA7QQYjAUEBRg0FGgsjAUUEQCAEKAJEIQkgBEEgaiEOIAQoAkAhBiAEKAJIIQgLIAVBB0ZBASMBGwRAIA4gBiAIEMUBQQcjAUEBRg0FGgsjAUUEQCAHQQFrIQcgCUUiCUUEQCAGECELIAFBJGshASAHDQELCyMBRQRAIAQoAighByAEKAIkIQkgBCgCICEBIAQoAjQEQCAKECELIAQoAhQEQCAMECELIAQoAgQNAgwDCwsjAUUEQCAEKAIQIQYgBCgCGCEHIARBIGohAQsgBUEIRkEBIwEbBEAgASAGIAcQxQFBCCMBQQFGDQMaCyABIARBIGojARshASAFQQlGQQEjARsEQCABQe2eBUEWEMUBQQkjAUEBRg0DGgsjAUUEQCAEKAIoIQcgBCgCJCEJIAQoAiAhASAEKAI0BEAgChAhCyAEKAIUBEAgBhAhCyAEKAIERQ0CCwsjAUUEQCALECELCyMBRQRAIARB/ABqIAc2AgAgBEH0AGpBATYCACAEIAE2AnggBEGwngU2AnAgBCADNgJsIAQgAjYCaCAEQegAaiECCyAFQQpGQQEjARsEQCAAIAJBAxBdQQojAUEBRg0BGgsjAUUEQCAJBEAgARAhCyAEQYABaiQACw8LIQUjAigCACAFNgIAIwIjAigCAEEEajYCACMCKAIAIgUgADYCACAFIAE2AgQgBSACNgIIIAUgAzYCDCAFIAQ2AhAgBSAGNgIUIAUgBzYCGCAFIAk2AhwgBSAKNgIgIAUgCzYCJCAFIAw2AiggBSANNgIsIAUgDjYCMCAFIAg2AjQjAiMCKAIAQThqNgIAC+oLAQl/IwFBAkYEQCMCIwIoAgBBNGs2AgAjAigCACIBKAIAIQAgASgCCCECIAEoAgwhAyABKAIQIQQgASgCFCEFIAEoAhghByABKAIcIQggASgCICEJIAEoAiQhCiABKAIoIQwgASgCLCENIAEoAjAhDiABKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQsLIwFFBEACfyABBEBBK0GAgMQAIAAoAgAiCUEBcSIBGyEMIAEgBWoMAQsgACgCACEJQS0hDCAFQQFqCyEKAkAgCUEEcUUEQEEAIQIMAQsCQCADRQRAQQAhBwwBCyADQQNxIghFDQBBACEHIAIhAQNAIAEsAABBv39KIAdqIQcgAUEBaiEBIAhBAWsiCA0ACwsgByAKaiEKCyAAKAIIIQELAkACQCMBRQRAIAENASAAQRhqIgkoAgAhByAAQRxqIgAoAgAhCEEBIQELIAtFQQEjARsEQCAHIAggDCACIAMQzQFBACMBQQFGDQMaIQALIwFFBEAgAA0CIAgoAgwhAAsgC0EBRkEBIwEbBEAgByAEIAUgABEBAEEBIwFBAUYNAxohAAsjAUUEQCAADwsLIwFFBEAgAEEMaigCACINIApNIQELAkACQAJAAkAjAUUEQCABRQRAIAlBCHEiAQ0FIA0gCmsiByEJIAAtACAiAUEDRiEGQQEgASAGG0EDcSIBQQFrDgICAwQLIABBGGoiCSgCACEHIABBHGoiACgCACEIQQEhAQsgC0ECRkEBIwEbBEAgByAIIAwgAiADEM0BQQIjAUEBRg0GGiEACyMBRQRAIAANBSAIKAIMIQALIAtBA0ZBASMBGwRAIAcgBCAFIAARAQBBAyMBQQFGDQYaIQALIwFFBEAgAA8LCyMBRQRAQQAhCSAHIQEMAgsLIwFFBEAgB0EBakEBdiEJIAdBAXYhAQsLIwFFBEAgAEEcaigCACEIIABBGGooAgAhCiAAKAIEIQcgAUEBaiEBCwJAA0AjAUUEQCABQQFrIgFFIgANAiAIKAIQIQALIAtBBEZBASMBGwRAIAogByAAEQAAQQQjAUEBRg0FGiEACyMBRQRAIABFIgANAQsLIwFFBEBBAQ8LCyMBRQRAQQEhASAHQYCAxABGIgANAgsgC0EFRkEBIwEbBEAgCiAIIAwgAiADEM0BQQUjAUEBRg0DGiEACyMBRQRAIAANAiAIKAIMIQALIAtBBkZBASMBGwRAIAogBCAFIAARAQBBBiMBQQFGDQMaIQALIwFFBEAgAA0CQQAhAQsCfwNAIwFFBEAgCSABIAlHIgBFDQIaIAFBAWohASAIKAIQIQALIAtBB0ZBASMBGwRAIAogByAAEQAAQQcjAUEBRg0FGiEACyMBRQRAIABFIgANAQsLIAEgAUEBayMBGwshASMBRQRAIAEgCUkhAQwCCwsjAUUEQCAAKAIEIQkgAEEwNgIEIAAtACAhDiAAQQE6ACAgAEEYaigCACEHIABBHGooAgAhCEEBIQELIAtBCEZBASMBGwRAIAcgCCAMIAIgAxDNAUEIIwFBAUYNAhohAgsjAUUEQCACDQEgDSAKa0EBaiEBCwJAA0AjAUUEQCABQQFrIgFFIgINAiAIKAIQIQILIAtBCUZBASMBGwRAIAdBMCACEQAAQQkjAUEBRg0EGiECCyMBRQRAIAJFIgINAQsLIwFFBEBBAQ8LCyMBRQRAIAgoAgwhAkEBIQELIAtBCkZBASMBGwRAIAcgBCAFIAIRAQBBCiMBQQFGDQIaIQILIwFFBEAgAg0BIAAgDjoAICAAIAk2AgRBAA8LCyMBRQRAIAEPCwALIQYjAigCACAGNgIAIwIjAigCAEEEajYCACMCKAIAIgYgADYCACAGIAE2AgQgBiACNgIIIAYgAzYCDCAGIAQ2AhAgBiAFNgIUIAYgBzYCGCAGIAg2AhwgBiAJNgIgIAYgCjYCJCAGIAw2AiggBiANNgIsIAYgDjYCMCMCIwIoAgBBNGo2AgBBAAu8CgIGfwN+IwFBAkYEQCMCIwIoAgBBNGs2AgAjAigCACIGKAIAIQAgBigCBCEBIAYoAgghAiAGKAIMIQMgBigCECEFIAYpAhQhCCAGKAIcIQcgBikCICEJIAYpAighCiAGKAIwIQYLAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQQLIwFFBEAjAEFAaiICJAAgAkEoaiEDCyAERUEBIwEbBEAgAyABEJQBQQAjAUEBRg0BGgsjAUUEQCACKAIoIgNBFkchBQsCQAJAAkAjAUUEQCAFDQEgAi0ALCEDCwJAAkAjAUUEQCADDQEgAkEoaiEDCyAEQQFGQQEjARsEQCADIAEQbkEBIwFBAUYNBhoLIwFFBEAgAi0AKEEERgRAIAIvACkiA0EIdiEFDAULIAIpAygiCEIQiCEJIAhCCIghCiAIQv8Bg0IEUiIDDQIgCachBSAKpyEDDAQLCyMBRQRAIABCFjcCAAwECwsjAUUEQCACIAhC/4GAeIMgCUIIhkKA/gODIApC/wGDhEIIhoQiCDcDKCACQShqIQELIARBAkZBASMBGwRAIAAgARCXAUECIwFBAUYNBBoLIwFFDQILIwFFBEAgACACKQAtNwAFIABBDGogAkE0aigAADYAACAAIAItACw6AAQgACADNgIADAILCyMBRQRAIANB/wFxIgNBAWshBwsCQAJAAkACQAJAIwFFBEACQAJAIAcOBAMBAQQACyADQSJrDgIEBQALIAJBGGoiByAFQQh0IgUgA3IQFyACQQQ2AhQgAiACQRhqNgIQIAJBATYCPCACQgE3AiwgAkG8iwU2AiggAiACQRBqNgI4IAJBKGohAwsgBEEDRkEBIwEbBEAgAiADEDtBAyMBQQFGDQcaCyMBRQRAIAIoAhwEQCACKAIYECELIAIoAgQhAyAAQQRqIQUgAUEwaiEHIAIoAgghBiACKAIAIQELIARBBEZBASMBGwRAIAUgByABIAYQJ0EEIwFBAUYNBxoLIwFFBEAgAEESNgIAIANFDQYgARAhDAYLCyMBRQRAIABBFjYCACAAIAVB/wFxNgIEDAULCyMBRQRAIABCFjcCAAwECwsjAUUEQCACQQA7ARggAkEoaiEDIAJBGGohBQsgBEEFRkEBIwEbBEAgAyABIAVBAhB5QQUjAUEBRg0EGgsjAUUEQCACLQAoQQRHBEAgAikDKCIIQv8Bg0IEUiIBDQMLIAIvARghASAAQRY2AgAgACABQQh0IAFBCHZyQf//A3E2AgQMAwsLIwFFBEAgAkEANgIYIAJBKGohAyACQRhqIQULIARBBkZBASMBGwRAIAMgASAFQQQQeUEGIwFBAUYNAxoLIwFFBEACQCACLQAoQQRHBEAgAikDKCIIQv8Bg0IEUg0BCyACKAIYIQEgAEEWNgIAIAAgAUEIdkGA/gNxIAFBGHZyIAFBCHRBgID8B3EgAUEYdHJyNgIEDAMLIAIgCDcDKCACQShqIQELIARBB0ZBASMBGwRAIAAgARCXAUEHIwFBAUYNAxoLIwFFDQELIwFFBEAgAiAINwMoIAJBKGohAQsgBEEIRkEBIwEbBEAgACABEJcBQQgjAUEBRg0CGgsLIwFFBEAgAkFAayQACw8LIQQjAigCACAENgIAIwIjAigCAEEEajYCACMCKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIAQgAzYCDCAEIAU2AhAgBCAINwIUIAQgBzYCHCAEIAk3AiAgBCAKNwIoIAQgBjYCMCMCIwIoAgBBNGo2AgALuwoCBn8DfiMBQQJGBEAjAiMCKAIAQTRrNgIAIwIoAgAiBigCACEAIAYoAgQhASAGKAIIIQIgBigCDCEDIAYoAhAhBSAGKQIUIQggBigCHCEHIAYpAiAhCSAGKQIoIQogBigCMCEGCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEECyMBRQRAIwBBQGoiAiQAIAJBKGohAwsgBEVBASMBGwRAIAMgARCUAUEAIwFBAUYNARoLIwFFBEAgAigCKCIDQRZHIQULAkACQAJAIwFFBEAgBQ0BIAItACwhAwsCQAJAIwFFBEAgAw0BIAJBKGohAwsgBEEBRkEBIwEbBEAgAyABEG5BASMBQQFGDQYaCyMBRQRAIAItAChBBEYEQCACLwApIgNBCHYhBQwFCyACKQMoIghCEIghCSAIQgiIIQogCEL/AYNCBFIiAw0CIAmnIQUgCqchAwwECwsjAUUEQCAAQhY3AgAMBAsLIwFFBEAgAiAIQv+BgHiDIAlCCIZCgP4DgyAKQv8Bg4RCCIaEIgg3AyggAkEoaiEBCyAEQQJGQQEjARsEQCAAIAEQlwFBAiMBQQFGDQQaCyMBRQ0CCyMBRQRAIAAgAikALTcABSAAQQxqIAJBNGooAAA2AAAgACACLQAsOgAEIAAgAzYCAAwCCwsjAUUEQCADQf8BcSIDQQJrIQcLAkACQAJAAkACQCMBRQRAAkACQCAHDgMDAQQACyADQSBrDgIEBQALIAJBGGoiByAFQQh0IgUgA3IQFyACQQQ2AhQgAiACQRhqNgIQIAJBATYCPCACQgE3AiwgAkGMiwU2AiggAiACQRBqNgI4IAJBKGohAwsgBEEDRkEBIwEbBEAgAiADEDtBAyMBQQFGDQcaCyMBRQRAIAIoAhwEQCACKAIYECELIAIoAgQhAyAAQQRqIQUgAUEwaiEHIAIoAgghBiACKAIAIQELIARBBEZBASMBGwRAIAUgByABIAYQJ0EEIwFBAUYNBxoLIwFFBEAgAEESNgIAIANFDQYgARAhDAYLCyMBRQRAIABBFjYCACAAIAVB/wFxNgIEDAULCyMBRQRAIABCFjcCAAwECwsjAUUEQCACQQA7ARggAkEoaiEDIAJBGGohBQsgBEEFRkEBIwEbBEAgAyABIAVBAhB5QQUjAUEBRg0EGgsjAUUEQCACLQAoQQRHBEAgAikDKCIIQv8Bg0IEUiIBDQMLIAIvARghASAAQRY2AgAgACABQQh0IAFBCHZyQf//A3E2AgQMAwsLIwFFBEAgAkEANgIYIAJBKGohAyACQRhqIQULIARBBkZBASMBGwRAIAMgASAFQQQQeUEGIwFBAUYNAxoLIwFFBEACQCACLQAoQQRHBEAgAikDKCIIQv8Bg0IEUg0BCyACKAIYIQEgAEEWNgIAIAAgAUEIdkGA/gNxIAFBGHZyIAFBCHRBgID8B3EgAUEYdHJyNgIEDAMLIAIgCDcDKCACQShqIQELIARBB0ZBASMBGwRAIAAgARCXAUEHIwFBAUYNAxoLIwFFDQELIwFFBEAgAiAINwMoIAJBKGohAQsgBEEIRkEBIwEbBEAgACABEJcBQQgjAUEBRg0CGgsLIwFFBEAgAkFAayQACw8LIQQjAigCACAENgIAIwIjAigCAEEEajYCACMCKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIAQgAzYCDCAEIAU2AhAgBCAINwIUIAQgBzYCHCAEIAk3AiAgBCAKNwIoIAQgBjYCMCMCIwIoAgBBNGo2AgAL9gkBC38jAUECRgRAIwIjAigCAEEwazYCACMCKAIAIgEoAgAhACABKAIIIQIgASgCDCEDIAEoAhAhBSABKAIUIQYgASgCGCEHIAEoAhwhCCABKAIgIQkgASgCJCEKIAEoAighDCABKAIsIQ0gASgCBCEBCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACELCyMBRQRAIwBBMGsiBSQAIAVBJGoiAyABNgIAIAVBAzoAKCAFQoCAgICABDcDCCAFIAA2AiAgBUEANgIYIAVBADYCECACKAIIIQxBACEJCwJ/AkACQAJAIwFFBEAgDA0BIAJBFGooAgAiAEUiAQ0CIAIoAhAhASAAQQN0IQggAEEBa0H/////AXFBAWohCSACKAIAIQALA0AjAUUEQCAAQQRqKAIAIgNFIQYLAkAjAUUEQCAGDQEgACgCACEHIAUoAiQoAgwhCiAFKAIgIQYLIAtFQQEjARsEQCAGIAcgAyAKEQEAQQAjAUEBRg0HGiEDCyMBRUEAIAMbDQQLIwFFBEAgBUEIaiEGIAFBBGooAgAhByABKAIAIQMLIAtBAUZBASMBGwRAIAMgBiAHEQAAQQEjAUEBRg0GGiEDCyMBRQRAIAMNBCABQQhqIQEgAEEIaiEAIAhBCGsiCA0BCwsjAUUNAQsjAUUEQCACQQxqKAIAIgFFIgANASABQQV0IQ0gAUEBa0H///8/cUEBaiEJQQAhCCACKAIAIQALA0AjAUUEQCAAQQRqKAIAIgFFIQMLAkAjAUUEQCADDQEgACgCACEGIAUoAiQoAgwhByAFKAIgIQMLIAtBAkZBASMBGwRAIAMgBiABIAcRAQBBAiMBQQFGDQYaIQELIwFFQQAgARsNAwsjAUUEQCAFIAggDGoiAUEcai0AADoAKCAFIAFBBGopAgBCIIk3AwggAUEYaigCACEGIAIoAhAhCkEAIQRBACEDAkACQAJAIAFBFGooAgAiB0EBaw4CAAIBCyAKIAZBA3RqIgdBBGooAgBBCEcNASAHKAIAKAIAIQYLQQEhAwsgBSAGNgIUIAUgAzYCECABQRBqKAIAIQMCQAJAAkAgAUEMaigCAEEBaw4CAAIBCyAKIANBA3RqIgZBBGooAgBBCEciBw0BIAYoAgAoAgAhAwtBASEECyAFIAM2AhwgBSAENgIYIAogASgCAEEDdGoiASgCACEDIAVBCGohBiABKAIEIQELIAtBA0ZBASMBGwRAIAMgBiABEQAAQQMjAUEBRg0FGiEBCyMBRQRAIAENAyAAQQhqIQAgDSAIQSBqIghHIgENAQsLCyMBRQRAIAkgAigCBE8hAAsCQCMBRQRAIAANASACKAIAIAlBA3RqIgAoAgAhAiAAKAIEIQEgBSgCJCgCDCEDIAUoAiAhAAsgC0EERkEBIwEbBEAgACACIAEgAxEBAEEEIwFBAUYNBBohAAsjAUVBACAAGw0BC0EAIwFFDQEaCyABQQEjARsLIQAjAUUEQCAFQTBqJAAgAA8LAAshBCMCKAIAIAQ2AgAjAiMCKAIAQQRqNgIAIwIoAgAiBCAANgIAIAQgATYCBCAEIAI2AgggBCADNgIMIAQgBTYCECAEIAY2AhQgBCAHNgIYIAQgCDYCHCAEIAk2AiAgBCAKNgIkIAQgDDYCKCAEIA02AiwjAiMCKAIAQTBqNgIAQQAL9QgBDX8jAUECRgRAIwIjAigCAEE4azYCACMCKAIAIgEoAgAhACABKAIIIQIgASgCDCEDIAEoAhAhBSABKAIUIQYgASgCGCEHIAEoAhwhCCABKAIgIQkgASgCJCEKIAEoAighCyABKAIsIQwgASgCMCENIAEoAjQhDiABKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQ8LIwFFBEAgACgCBCEMIAAoAgAhDSAAKAIIIQ5BACELQQAhCkEAIQlBACEFCwJAA0ACQAJAIwFFQQAjAUUEQCAJQf8BcQ0EIAIgBUkhAAsgABsNAANAIwFFBEAgASAFaiEIIAIgBWsiB0EISSEACwJAAkAjAUUEQAJAAkACQCAARQRAIAggCEEDakF8cSIARg0CIAcgACAIayIGSyEAIAYgByAAGyIARQ0CQQAhAwNAIAMgCGotAABBCkYiBg0HIAAgA0EBaiIDRw0ACwwBCyACIAVHIgBFBEAgAiEFDAgLQQAhAwNAIAMgCGotAABBCkYiAA0GIAcgA0EBaiIDRyIADQALIAIhBQwHCyAHQQhrIgYgAEkiAw0DDAELIAdBCGshBkEAIQALA0ACQCAAIAhqIgkoAgAiBEF/cyAEQYqUqNAAc0GBgoQIa3FBgIGChHhxIgMNACAJQQRqKAIAIgNBf3MhCSAJIANBipSo0ABzQYGChAhrcUGAgYKEeHEiAw0AIAYgAEEIaiIATyIDDQELCyAAIAdNIgYNAQsgD0VBASMBGwRAIAAgB0HQiAQQ9gFBACMBQQFGDQgaCyMBRQRAAAsLIwFFBEAgACAHRyIGRQRAIAIhBQwECwNAIAAgCGotAABBCkciBkUEQCAAIQMMAwsgByAAQQFqIgBHIgYNAAsgAiEFDAMLCyMBRQRAAkAgAyAFaiIAQQFqIgVFIgYNACACIAVJIgYNACAAIAFqLQAAQQpHDQBBACEJIAUiBiEADAQLIAIgBU8iAA0BCwsLIwFFBEBBASEJIAIiACAKIgZGIgMNAwsLIwFFBEAgDi0AAEUhAwsCQAJAIwFFBEAgAw0BIAwoAgwhAwsgD0EBRkEBIwEbBEAgDUHAhQRBBCADEQEAQQEjAUEBRg0FGiEDCyMBRUEAIAMbDQELIwFFBEAgASAKaiEDIAAgCmshCEEAIQcgACAKRwRAIAMgCGpBAWstAABBCkYhBwsgDiAHOgAAIAYhCiAMKAIMIQALIA9BAkZBASMBGwRAIA0gAyAIIAARAQBBAiMBQQFGDQQaIQALIwFFBEAgAEUiAA0CCwsLIAtBASMBGyELCyMBRQRAIAsPCwALIQQjAigCACAENgIAIwIjAigCAEEEajYCACMCKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIAQgAzYCDCAEIAU2AhAgBCAGNgIUIAQgBzYCGCAEIAg2AhwgBCAJNgIgIAQgCjYCJCAEIAs2AiggBCAMNgIsIAQgDTYCMCAEIA42AjQjAiMCKAIAQThqNgIAQQAL+AQBBH8gACABaiECAkACQAJAIAAoAgQiA0EBcQ0AIANBA3FFDQEgACgCACIDIAFqIQEgACADayIAQZC8BSgCAEYEQCACKAIEQQNxQQNHDQFBiLwFIAE2AgAgAiACKAIEQX5xNgIEIAAgAUEBcjYCBCACIAE2AgAPCyADQYACTwRAIAAQVwwBCyAAQQxqKAIAIgQgAEEIaigCACIFRwRAIAUgBDYCDCAEIAU2AggMAQtB+LgFQfi4BSgCAEF+IANBA3Z3cTYCAAsgAigCBCIDQQJxBEAgAiADQX5xNgIEIAAgAUEBcjYCBCAAIAFqIAE2AgAMAgsCQCACQZS8BSgCAEcEQEGQvAUoAgAgAkcNAUGQvAUgADYCAEGIvAVBiLwFKAIAIAFqIgE2AgAgACABQQFyNgIEIAAgAWogATYCAA8LQZS8BSAANgIAQYy8BUGMvAUoAgAgAWoiATYCACAAIAFBAXI2AgRBkLwFKAIAIABHDQFBiLwFQQA2AgBBkLwFQQA2AgAPCyADQXhxIgQgAWohAQJAIARBgAJPBEAgAhBXDAELIAJBDGooAgAiBCACQQhqKAIAIgJHBEAgAiAENgIMIAQgAjYCCAwBC0H4uAVB+LgFKAIAQX4gA0EDdndxNgIACyAAIAFBAXI2AgQgACABaiABNgIAQZC8BSgCACAARw0BQYi8BSABNgIACw8LIAFBgAJPBEAgACABEFYPCyABQXhxQYC5BWohAgJ/QQEgAUEDdnQiAUH4uAUoAgAiA3EEQCACKAIIDAELQfi4BSABIANyNgIAIAILIQEgAiAANgIIIAEgADYCDCAAIAI2AgwgACABNgIIC5oLAQN/IwFBAkYEQCMCIwIoAgBBDGs2AgAjAigCACICKAIAIQAgAigCBCEBIAIoAgghAgsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBAsjAUUEQCMAQRBrIgIkACAAKAIAIQMLAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQCMBRQRAAkAgA0EBaw4QAgMEBQYHCAkKCwwNDg8QEQALIAIgAEEEajYCDCACQQxqIQALIARFQQEjARsEQCABQfyQBUENIABBiI8FEHVBACMBQQFGDRIaIQALIwFFDRALIwFFBEAgAiAAQQRqNgIMIAJBDGohAAsgBEEBRkEBIwEbBEAgAUHskAVBECAAQYiPBRB1QQEjAUEBRg0RGiEACyMBRQ0PCyMBRQRAIAIgAEEEajYCDCACQQxqIQALIARBAkZBASMBGwRAIAFB25AFQREgAEGIjwUQdUECIwFBAUYNEBohAAsjAUUNDgsjAUUEQCACIABBBGo2AgwgAkEMaiEACyAEQQNGQQEjARsEQCABQc6QBUENIABBiI8FEHVBAyMBQQFGDQ8aIQALIwFFDQ0LIwFFBEAgAiAAQQRqNgIMIAJBDGohAAsgBEEERkEBIwEbBEAgAUG+kAVBECAAQYiPBRB1QQQjAUEBRg0OGiEACyMBRQ0MCyMBRQRAIAIgAEEEajYCDCACQQxqIQALIARBBUZBASMBGwRAIAFBsJAFQQ4gAEGIjwUQdUEFIwFBAUYNDRohAAsjAUUNCwsjAUUEQCACIABBBGo2AgwgAkEMaiEACyAEQQZGQQEjARsEQCABQZ+QBUERIABBiI8FEHVBBiMBQQFGDQwaIQALIwFFDQoLIwFFBEAgAiAAQQRqNgIMIAJBDGohAAsgBEEHRkEBIwEbBEAgAUGOkAVBESAAQYiPBRB1QQcjAUEBRg0LGiEACyMBRQ0JCyMBRQRAIAIgAEEEajYCDCACQQxqIQALIARBCEZBASMBGwRAIAFB/48FQQ8gAEGIjwUQdUEIIwFBAUYNChohAAsjAUUNCAsjAUUEQCACIABBBGo2AgwgAkEMaiEACyAEQQlGQQEjARsEQCABQe+PBUEQIABBiI8FEHVBCSMBQQFGDQkaIQALIwFFDQcLIwFFBEAgAiAAQQRqNgIMIAJBDGohAAsgBEEKRkEBIwEbBEAgAUHfjwVBECAAQYiPBRB1QQojAUEBRg0IGiEACyMBRQ0GCyMBRQRAIAIgAEEEajYCDCACQQxqIQALIARBC0ZBASMBGwRAIAFB0Y8FQQ4gAEGIjwUQdUELIwFBAUYNBxohAAsjAUUNBQsjAUUEQCACIABBBGo2AgwgAkEMaiEACyAEQQxGQQEjARsEQCABQcKPBUEPIABBiI8FEHVBDCMBQQFGDQYaIQALIwFFDQQLIwFFBEAgAiAAQQRqNgIMIAJBDGohAAsgBEENRkEBIwEbBEAgAUGzjwVBDyAAQYiPBRB1QQ0jAUEBRg0FGiEACyMBRQ0DCyMBRQRAIAIgAEEEajYCDCACQQxqIQALIARBDkZBASMBGwRAIAFBpo8FQQ0gAEGIjwUQdUEOIwFBAUYNBBohAAsjAUUNAgsjAUUEQCACIABBBGo2AgwgAkEMaiEACyAEQQ9GQQEjARsEQCABQZiPBUEOIABBiI8FEHVBDyMBQQFGDQMaIQALIwFFDQELIwFFBEAgAiAAQQRqNgIMIAJBDGohAAsgBEEQRkEBIwEbBEAgAUGAjwVBByAAQYiPBRB1QRAjAUEBRg0CGiEACwsjAUUEQCACQRBqJAAgAA8LAAshAyMCKAIAIAM2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAyAANgIAIAMgATYCBCADIAI2AggjAiMCKAIAQQxqNgIAQQALrQoBDH8jAUECRgRAIwIjAigCAEHIAGs2AgAjAigCACIIKAIAIQAgCCgCBCEBIAgoAgghAiAIKAIMIQMgCCgCECEEIAgoAhQhBSAIKAIYIQYgCCgCHCEJIAgoAiAhCiAIKAIkIQsgCCgCKCEMIAgoAiwhDSAIKAIwIQ4gCCgCNCEPIAgoAjghECAIKAI8IREgCCgCQCESIAgoAkQhCAsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBwsjAUUEQCMAIg1BIGsiCyQACwJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAIwFFBEAgBEUEQCAGIQ0MAgtBACEOQQAhCSAGIQ0LA0AjAUUEQCAJIA1LIgoNBSAGIA1JIgoNBiADIA5qIQwgBCAOayEPIAUgCWohECALQRBqIQogDSAJayERCyAHRUEBIwEbBEAgCiABIAIgDCAPIBAgERBIQQAjAUEBRg0PGgsjAUUEQCALLQAcQQRGIgoNAiAOIAsoAhBqIgpBCGohDiAKQXdLIgwNByAEIA5JIgwNCCALKAIUIAlqIRAgC0EIaiACIAMgCmoiERCgASALKAIMIQwgCygCCCIJDQMgDEEDbCIPQQdxIglBA08NCSAMIAogDGoiCUsNCiAEIAlJDQsgECAPQQN2Ig9qIgkgD0kNDCAGIAlJDQ0gC0EQaiESIAUgEGohCAsgB0EBRkEBIwEbBEAgEiABIAIgESAMIAggDxBIQQEjAUEBRg0PGgsjAUUEQCALLQAcIgxBBEciEQ0EIA0gD2pBA2shDSAEIA5LIgoNAQsLCyMBRQRAIABBBDoADCAAIA02AgAMDAsLIwFFBEAgAEEDOgAMIAAgCjYCACAAIBCtIAogDGqtQiCGhDcCBAwLCwsjAUUEQCALKAIYIQEgACAMOgAMIAAgCjYCACAAIBCtIAEgCmqtQiCGhDcCBAwKCwsgB0ECRkEBIwEbBEAgCSANQYSkBBD4AUECIwFBAUYNChoLIwFFBEAACwsgB0EDRkEBIwEbBEAgDSAGQYSkBBD3AUEDIwFBAUYNCRoLIwFFBEAACwsgB0EERkEBIwEbBEAgCiAOQZSkBBD4AUEEIwFBAUYNCBoLIwFFBEAACwsgB0EFRkEBIwEbBEAgDiAEQZSkBBD3AUEFIwFBAUYNBxoLIwFFBEAACwsjAUUEQCALQQA6ABQgCyAMIAlBA25rNgIQIAtBEGohAAsgB0EGRkEBIwEbBEBB96IFQSsgAEH8ogRBpKQEEJsBQQYjAUEBRg0GGgsjAUUEQAALCyAHQQdGQQEjARsEQCAKIAlBtKQEEPgBQQcjAUEBRg0FGgsjAUUEQAALCyAHQQhGQQEjARsEQCAJIARBtKQEEPcBQQgjAUEBRg0EGgsjAUUEQAALCyAHQQlGQQEjARsEQCAQIAlBxKQEEPgBQQkjAUEBRg0DGgsjAUUEQAALCyAHQQpGQQEjARsEQCAJIAZBxKQEEPcBQQojAUEBRg0CGgsjAUUEQAALCyMBRQRAIAtBIGokAAsPCyEHIwIoAgAgBzYCACMCIwIoAgBBBGo2AgAjAigCACIHIAA2AgAgByABNgIEIAcgAjYCCCAHIAM2AgwgByAENgIQIAcgBTYCFCAHIAY2AhggByAJNgIcIAcgCjYCICAHIAs2AiQgByAMNgIoIAcgDTYCLCAHIA42AjAgByAPNgI0IAcgEDYCOCAHIBE2AjwgByASNgJAIAcgCDYCRCMCIwIoAgBByABqNgIAC60KAQx/IwFBAkYEQCMCIwIoAgBByABrNgIAIwIoAgAiCCgCACEAIAgoAgQhASAIKAIIIQIgCCgCDCEDIAgoAhAhBCAIKAIUIQUgCCgCGCEGIAgoAhwhCSAIKAIgIQogCCgCJCELIAgoAighDCAIKAIsIQ0gCCgCMCEOIAgoAjQhDyAIKAI4IRAgCCgCPCERIAgoAkAhEiAIKAJEIQgLAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQcLIwFFBEAjACINQSBrIgskAAsCQAJAAkACQAJAAkACQAJAAkACQAJAAkACQCMBRQRAIARFBEAgBiENDAILQQAhDkEAIQkgBiENCwNAIwFFBEAgCSANSyIKDQUgBiANSSIKDQYgAyAOaiEMIAQgDmshDyAFIAlqIRAgC0EQaiEKIA0gCWshEQsgB0VBASMBGwRAIAogASACIAwgDyAQIBEQQ0EAIwFBAUYNDxoLIwFFBEAgCy0AHEEERiIKDQIgDiALKAIQaiIKQQhqIQ4gCkF3SyIMDQcgBCAOSSIMDQggCygCFCAJaiEQIAtBCGogAiADIApqIhEQoAEgCygCDCEMIAsoAggiCQ0DIAxBA2wiD0EHcSIJQQNPDQkgDCAKIAxqIglLDQogBCAJSQ0LIBAgD0EDdiIPaiIJIA9JDQwgBiAJSQ0NIAtBEGohEiAFIBBqIQgLIAdBAUZBASMBGwRAIBIgASACIBEgDCAIIA8QQ0EBIwFBAUYNDxoLIwFFBEAgCy0AHCIMQQRHIhENBCANIA9qQQNrIQ0gBCAOSyIKDQELCwsjAUUEQCAAQQQ6AAwgACANNgIADAwLCyMBRQRAIABBAzoADCAAIAo2AgAgACAQrSAKIAxqrUIghoQ3AgQMCwsLIwFFBEAgCygCGCEBIAAgDDoADCAAIAo2AgAgACAQrSABIApqrUIghoQ3AgQMCgsLIAdBAkZBASMBGwRAIAkgDUGEpAQQ+AFBAiMBQQFGDQoaCyMBRQRAAAsLIAdBA0ZBASMBGwRAIA0gBkGEpAQQ9wFBAyMBQQFGDQkaCyMBRQRAAAsLIAdBBEZBASMBGwRAIAogDkGUpAQQ+AFBBCMBQQFGDQgaCyMBRQRAAAsLIAdBBUZBASMBGwRAIA4gBEGUpAQQ9wFBBSMBQQFGDQcaCyMBRQRAAAsLIwFFBEAgC0EAOgAUIAsgDCAJQQNuazYCECALQRBqIQALIAdBBkZBASMBGwRAQfeiBUErIABB/KIEQaSkBBCbAUEGIwFBAUYNBhoLIwFFBEAACwsgB0EHRkEBIwEbBEAgCiAJQbSkBBD4AUEHIwFBAUYNBRoLIwFFBEAACwsgB0EIRkEBIwEbBEAgCSAEQbSkBBD3AUEIIwFBAUYNBBoLIwFFBEAACwsgB0EJRkEBIwEbBEAgECAJQcSkBBD4AUEJIwFBAUYNAxoLIwFFBEAACwsgB0EKRkEBIwEbBEAgCSAGQcSkBBD3AUEKIwFBAUYNAhoLIwFFBEAACwsjAUUEQCALQSBqJAALDwshByMCKAIAIAc2AgAjAiMCKAIAQQRqNgIAIwIoAgAiByAANgIAIAcgATYCBCAHIAI2AgggByADNgIMIAcgBDYCECAHIAU2AhQgByAGNgIYIAcgCTYCHCAHIAo2AiAgByALNgIkIAcgDDYCKCAHIA02AiwgByAONgIwIAcgDzYCNCAHIBA2AjggByARNgI8IAcgEjYCQCAHIAg2AkQjAiMCKAIAQcgAajYCAAuqCgEMfyMBQQJGBEAjAiMCKAIAQcgAazYCACMCKAIAIggoAgAhACAIKAIEIQEgCCgCCCECIAgoAgwhAyAIKAIQIQQgCCgCFCEFIAgoAhghBiAIKAIcIQkgCCgCICEKIAgoAiQhCyAIKAIoIQwgCCgCLCENIAgoAjAhDiAIKAI0IQ8gCCgCOCEQIAgoAjwhESAIKAJAIRIgCCgCRCEICwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEHCyMBRQRAIwAiDUEgayILJAALAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAjAUUEQCAERQRAIAYhDQwCC0EAIQ5BACEJIAYhDQsDQCMBRQRAIAkgDUsiCg0FIAYgDUkiCg0GIAMgDmohDCAEIA5rIQ8gBSAJaiEQIAtBEGohCiANIAlrIRELIAdFQQEjARsEQCAKIAEgAiAMIA8gECAREEZBACMBQQFGDQ8aCyMBRQRAIAstABxBBEYiCg0CIA4gCygCEGoiCkEEaiEOIApBe0siDA0HIAQgDkkiDA0IIAsoAhQgCWohECALQQhqIAIgAyAKaiIREJ4BIAsoAgwhDCALKAIIIgkNAyAMQQZsIg9BBnFBBkYiCQ0JIAwgCiAMaiIJSw0KIAQgCUkNCyAQIA9BA3YiD2oiCSAPSQ0MIAYgCUkNDSALQRBqIRIgBSAQaiEICyAHQQFGQQEjARsEQCASIAEgAiARIAwgCCAPEEZBASMBQQFGDQ8aCyMBRQRAIAstABwiDEEERyIRDQQgDSAPakEDayENIAQgDksiCg0BCwsLIwFFBEAgAEEEOgAMIAAgDTYCAAwMCwsjAUUEQCAAQQM6AAwgACAKNgIAIAAgEK0gCiAMaq1CIIaENwIEDAsLCyMBRQRAIAsoAhghASAAIAw6AAwgACAKNgIAIAAgEK0gASAKaq1CIIaENwIEDAoLCyAHQQJGQQEjARsEQCAJIA1BhKQEEPgBQQIjAUEBRg0KGgsjAUUEQAALCyAHQQNGQQEjARsEQCANIAZBhKQEEPcBQQMjAUEBRg0JGgsjAUUEQAALCyAHQQRGQQEjARsEQCAKIA5BlKQEEPgBQQQjAUEBRg0IGgsjAUUEQAALCyAHQQVGQQEjARsEQCAOIARBlKQEEPcBQQUjAUEBRg0HGgsjAUUEQAALCyMBRQRAIAtBADoAFCALIAxBAWs2AhAgC0EQaiEACyAHQQZGQQEjARsEQEH3ogVBKyAAQfyiBEGkpAQQmwFBBiMBQQFGDQYaCyMBRQRAAAsLIAdBB0ZBASMBGwRAIAogCUG0pAQQ+AFBByMBQQFGDQUaCyMBRQRAAAsLIAdBCEZBASMBGwRAIAkgBEG0pAQQ9wFBCCMBQQFGDQQaCyMBRQRAAAsLIAdBCUZBASMBGwRAIBAgCUHEpAQQ+AFBCSMBQQFGDQMaCyMBRQRAAAsLIAdBCkZBASMBGwRAIAkgBkHEpAQQ9wFBCiMBQQFGDQIaCyMBRQRAAAsLIwFFBEAgC0EgaiQACw8LIQcjAigCACAHNgIAIwIjAigCAEEEajYCACMCKAIAIgcgADYCACAHIAE2AgQgByACNgIIIAcgAzYCDCAHIAQ2AhAgByAFNgIUIAcgBjYCGCAHIAk2AhwgByAKNgIgIAcgCzYCJCAHIAw2AiggByANNgIsIAcgDjYCMCAHIA82AjQgByAQNgI4IAcgETYCPCAHIBI2AkAgByAINgJEIwIjAigCAEHIAGo2AgALqgoBDH8jAUECRgRAIwIjAigCAEHIAGs2AgAjAigCACIIKAIAIQAgCCgCBCEBIAgoAgghAiAIKAIMIQMgCCgCECEEIAgoAhQhBSAIKAIYIQYgCCgCHCEJIAgoAiAhCiAIKAIkIQsgCCgCKCEMIAgoAiwhDSAIKAIwIQ4gCCgCNCEPIAgoAjghECAIKAI8IREgCCgCQCESIAgoAkQhCAsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBwsjAUUEQCMAIg1BIGsiCyQACwJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAIwFFBEAgBEUEQCAGIQ0MAgtBACEOQQAhCSAGIQ0LA0AjAUUEQCAJIA1LIgoNBSAGIA1JIgoNBiADIA5qIQwgBCAOayEPIAUgCWohECALQRBqIQogDSAJayERCyAHRUEBIwEbBEAgCiABIAIgDCAPIBAgERBBQQAjAUEBRg0PGgsjAUUEQCALLQAcQQRGIgoNAiAOIAsoAhBqIgpBBGohDiAKQXtLIgwNByAEIA5JIgwNCCALKAIUIAlqIRAgC0EIaiACIAMgCmoiERCeASALKAIMIQwgCygCCCIJDQMgDEEGbCIPQQZxQQZGIgkNCSAMIAogDGoiCUsNCiAEIAlJDQsgECAPQQN2Ig9qIgkgD0kNDCAGIAlJDQ0gC0EQaiESIAUgEGohCAsgB0EBRkEBIwEbBEAgEiABIAIgESAMIAggDxBBQQEjAUEBRg0PGgsjAUUEQCALLQAcIgxBBEciEQ0EIA0gD2pBA2shDSAEIA5LIgoNAQsLCyMBRQRAIABBBDoADCAAIA02AgAMDAsLIwFFBEAgAEEDOgAMIAAgCjYCACAAIBCtIAogDGqtQiCGhDcCBAwLCwsjAUUEQCALKAIYIQEgACAMOgAMIAAgCjYCACAAIBCtIAEgCmqtQiCGhDcCBAwKCwsgB0ECRkEBIwEbBEAgCSANQYSkBBD4AUECIwFBAUYNChoLIwFFBEAACwsgB0EDRkEBIwEbBEAgDSAGQYSkBBD3AUEDIwFBAUYNCRoLIwFFBEAACwsgB0EERkEBIwEbBEAgCiAOQZSkBBD4AUEEIwFBAUYNCBoLIwFFBEAACwsgB0EFRkEBIwEbBEAgDiAEQZSkBBD3AUEFIwFBAUYNBxoLIwFFBEAACwsjAUUEQCALQQA6ABQgCyAMQQFrNgIQIAtBEGohAAsgB0EGRkEBIwEbBEBB96IFQSsgAEH8ogRBpKQEEJsBQQYjAUEBRg0GGgsjAUUEQAALCyAHQQdGQQEjARsEQCAKIAlBtKQEEPgBQQcjAUEBRg0FGgsjAUUEQAALCyAHQQhGQQEjARsEQCAJIARBtKQEEPcBQQgjAUEBRg0EGgsjAUUEQAALCyAHQQlGQQEjARsEQCAQIAlBxKQEEPgBQQkjAUEBRg0DGgsjAUUEQAALCyAHQQpGQQEjARsEQCAJIAZBxKQEEPcBQQojAUEBRg0CGgsjAUUEQAALCyMBRQRAIAtBIGokAAsPCyEHIwIoAgAgBzYCACMCIwIoAgBBBGo2AgAjAigCACIHIAA2AgAgByABNgIEIAcgAjYCCCAHIAM2AgwgByAENgIQIAcgBTYCFCAHIAY2AhggByAJNgIcIAcgCjYCICAHIAs2AiQgByAMNgIoIAcgDTYCLCAHIA42AjAgByAPNgI0IAcgEDYCOCAHIBE2AjwgByASNgJAIAcgCDYCRCMCIwIoAgBByABqNgIAC6oKAQx/IwFBAkYEQCMCIwIoAgBByABrNgIAIwIoAgAiCCgCACEAIAgoAgQhASAIKAIIIQIgCCgCDCEDIAgoAhAhBCAIKAIUIQUgCCgCGCEGIAgoAhwhCSAIKAIgIQogCCgCJCELIAgoAighDCAIKAIsIQ0gCCgCMCEOIAgoAjQhDyAIKAI4IRAgCCgCPCERIAgoAkAhEiAIKAJEIQgLAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQcLIwFFBEAjACINQSBrIgskAAsCQAJAAkACQAJAAkACQAJAAkACQAJAAkACQCMBRQRAIARFBEAgBiENDAILQQAhDkEAIQkgBiENCwNAIwFFBEAgCSANSyIKDQUgBiANSSIKDQYgAyAOaiEMIAQgDmshDyAFIAlqIRAgC0EQaiEKIA0gCWshEQsgB0VBASMBGwRAIAogASACIAwgDyAQIBEQR0EAIwFBAUYNDxoLIwFFBEAgCy0AHEEERiIKDQIgDiALKAIQaiIKQQhqIQ4gCkF3SyIMDQcgBCAOSSIMDQggCygCFCAJaiEQIAtBCGogAiADIApqIhEQnwEgCygCDCEMIAsoAggiCQ0DIAxBBWwiD0EHcUEFTyIJDQkgDCAKIAxqIglLDQogBCAJSQ0LIBAgD0EDdiIPaiIJIA9JDQwgBiAJSQ0NIAtBEGohEiAFIBBqIQgLIAdBAUZBASMBGwRAIBIgASACIBEgDCAIIA8QR0EBIwFBAUYNDxoLIwFFBEAgCy0AHCIMQQRHIhENBCANIA9qQQVrIQ0gBCAOSyIKDQELCwsjAUUEQCAAQQQ6AAwgACANNgIADAwLCyMBRQRAIABBAzoADCAAIAo2AgAgACAQrSAKIAxqrUIghoQ3AgQMCwsLIwFFBEAgCygCGCEBIAAgDDoADCAAIAo2AgAgACAQrSABIApqrUIghoQ3AgQMCgsLIAdBAkZBASMBGwRAIAkgDUGEpAQQ+AFBAiMBQQFGDQoaCyMBRQRAAAsLIAdBA0ZBASMBGwRAIA0gBkGEpAQQ9wFBAyMBQQFGDQkaCyMBRQRAAAsLIAdBBEZBASMBGwRAIAogDkGUpAQQ+AFBBCMBQQFGDQgaCyMBRQRAAAsLIAdBBUZBASMBGwRAIA4gBEGUpAQQ9wFBBSMBQQFGDQcaCyMBRQRAAAsLIwFFBEAgC0EAOgAUIAsgDEEBazYCECALQRBqIQALIAdBBkZBASMBGwRAQfeiBUErIABB/KIEQaSkBBCbAUEGIwFBAUYNBhoLIwFFBEAACwsgB0EHRkEBIwEbBEAgCiAJQbSkBBD4AUEHIwFBAUYNBRoLIwFFBEAACwsgB0EIRkEBIwEbBEAgCSAEQbSkBBD3AUEIIwFBAUYNBBoLIwFFBEAACwsgB0EJRkEBIwEbBEAgECAJQcSkBBD4AUEJIwFBAUYNAxoLIwFFBEAACwsgB0EKRkEBIwEbBEAgCSAGQcSkBBD3AUEKIwFBAUYNAhoLIwFFBEAACwsjAUUEQCALQSBqJAALDwshByMCKAIAIAc2AgAjAiMCKAIAQQRqNgIAIwIoAgAiByAANgIAIAcgATYCBCAHIAI2AgggByADNgIMIAcgBDYCECAHIAU2AhQgByAGNgIYIAcgCTYCHCAHIAo2AiAgByALNgIkIAcgDDYCKCAHIA02AiwgByAONgIwIAcgDzYCNCAHIBA2AjggByARNgI8IAcgEjYCQCAHIAg2AkQjAiMCKAIAQcgAajYCAAuqCgEMfyMBQQJGBEAjAiMCKAIAQcgAazYCACMCKAIAIggoAgAhACAIKAIEIQEgCCgCCCECIAgoAgwhAyAIKAIQIQQgCCgCFCEFIAgoAhghBiAIKAIcIQkgCCgCICEKIAgoAiQhCyAIKAIoIQwgCCgCLCENIAgoAjAhDiAIKAI0IQ8gCCgCOCEQIAgoAjwhESAIKAJAIRIgCCgCRCEICwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEHCyMBRQRAIwAiDUEgayILJAALAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAjAUUEQCAERQRAIAYhDQwCC0EAIQ5BACEJIAYhDQsDQCMBRQRAIAkgDUsiCg0FIAYgDUkiCg0GIAMgDmohDCAEIA5rIQ8gBSAJaiEQIAtBEGohCiANIAlrIRELIAdFQQEjARsEQCAKIAEgAiAMIA8gECAREEJBACMBQQFGDQ8aCyMBRQRAIAstABxBBEYiCg0CIA4gCygCEGoiCkEIaiEOIApBd0siDA0HIAQgDkkiDA0IIAsoAhQgCWohECALQQhqIAIgAyAKaiIREJ8BIAsoAgwhDCALKAIIIgkNAyAMQQVsIg9BB3FBBU8iCQ0JIAwgCiAMaiIJSw0KIAQgCUkNCyAQIA9BA3YiD2oiCSAPSQ0MIAYgCUkNDSALQRBqIRIgBSAQaiEICyAHQQFGQQEjARsEQCASIAEgAiARIAwgCCAPEEJBASMBQQFGDQ8aCyMBRQRAIAstABwiDEEERyIRDQQgDSAPakEFayENIAQgDksiCg0BCwsLIwFFBEAgAEEEOgAMIAAgDTYCAAwMCwsjAUUEQCAAQQM6AAwgACAKNgIAIAAgEK0gCiAMaq1CIIaENwIEDAsLCyMBRQRAIAsoAhghASAAIAw6AAwgACAKNgIAIAAgEK0gASAKaq1CIIaENwIEDAoLCyAHQQJGQQEjARsEQCAJIA1BhKQEEPgBQQIjAUEBRg0KGgsjAUUEQAALCyAHQQNGQQEjARsEQCANIAZBhKQEEPcBQQMjAUEBRg0JGgsjAUUEQAALCyAHQQRGQQEjARsEQCAKIA5BlKQEEPgBQQQjAUEBRg0IGgsjAUUEQAALCyAHQQVGQQEjARsEQCAOIARBlKQEEPcBQQUjAUEBRg0HGgsjAUUEQAALCyMBRQRAIAtBADoAFCALIAxBAWs2AhAgC0EQaiEACyAHQQZGQQEjARsEQEH3ogVBKyAAQfyiBEGkpAQQmwFBBiMBQQFGDQYaCyMBRQRAAAsLIAdBB0ZBASMBGwRAIAogCUG0pAQQ+AFBByMBQQFGDQUaCyMBRQRAAAsLIAdBCEZBASMBGwRAIAkgBEG0pAQQ9wFBCCMBQQFGDQQaCyMBRQRAAAsLIAdBCUZBASMBGwRAIBAgCUHEpAQQ+AFBCSMBQQFGDQMaCyMBRQRAAAsLIAdBCkZBASMBGwRAIAkgBkHEpAQQ9wFBCiMBQQFGDQIaCyMBRQRAAAsLIwFFBEAgC0EgaiQACw8LIQcjAigCACAHNgIAIwIjAigCAEEEajYCACMCKAIAIgcgADYCACAHIAE2AgQgByACNgIIIAcgAzYCDCAHIAQ2AhAgByAFNgIUIAcgBjYCGCAHIAk2AhwgByAKNgIgIAcgCzYCJCAHIAw2AiggByANNgIsIAcgDjYCMCAHIA82AjQgByAQNgI4IAcgETYCPCAHIBI2AkAgByAINgJEIwIjAigCAEHIAGo2AgALkwoBDH8jAUECRgRAIwIjAigCAEHEAGs2AgAjAigCACIHKAIAIQAgBygCBCEBIAcoAgghAiAHKAIMIQMgBygCECEEIAcoAhQhBSAHKAIYIQggBygCHCEJIAcoAiAhCiAHKAIkIQsgBygCKCEMIAcoAiwhDSAHKAIwIQ4gBygCNCEPIAcoAjghECAHKAI8IREgBygCQCEHCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEGCyMBRQRAIwAiC0EgayIKJAALAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAjAUUEQCADRQRAIAUhCwwCC0EAIQ1BACEIIAUhCwsDQCMBRQRAIAggC0siCQ0FIAUgC0kiCQ0GIAIgDWohDCADIA1rIQ4gBCAIaiEPIApBEGohCSALIAhrIRALIAZFQQEjARsEQCAJIAEgDCAOIA8gEBBnQQAjAUEBRg0PGgsjAUUEQCAKLQAcQQRGIgkNAiANIAooAhBqIglBAmohDSAJQX1LDQcgAyANSQ0IIAooAhQgCGohDiAKQQhqIAEgAiAJaiIQEKkBIAooAgwhDCAKKAIIIggNAyAMIAxBfnEiCEcNCSAMIAkgDGoiCEsNCiADIAhJDQsgDiAMQQF2Qf////8BcSIPaiIIIA9JDQwgBSAISQ0NIApBEGohESAEIA5qIQcLIAZBAUZBASMBGwRAIBEgASAQIAwgByAPEGdBASMBQQFGDQ8aCyMBRQRAIAotABwiDEEERyIQDQQgCyAPakEBayELIAMgDUsiCQ0BCwsLIwFFBEAgAEEEOgAMIAAgCzYCAAwMCwsjAUUEQCAAQQM6AAwgACAJNgIAIAAgDq0gCSAMaq1CIIaENwIEDAsLCyMBRQRAIAooAhghASAAIAw6AAwgACAJNgIAIAAgDq0gASAJaq1CIIaENwIEDAoLCyAGQQJGQQEjARsEQCAIIAtBhKQEEPgBQQIjAUEBRg0KGgsjAUUEQAALCyAGQQNGQQEjARsEQCALIAVBhKQEEPcBQQMjAUEBRg0JGgsjAUUEQAALCyAGQQRGQQEjARsEQCAJIA1BlKQEEPgBQQQjAUEBRg0IGgsjAUUEQAALCyAGQQVGQQEjARsEQCANIANBlKQEEPcBQQUjAUEBRg0HGgsjAUUEQAALCyMBRQRAIApBADoAFCAKIAg2AhAgCkEQaiEACyAGQQZGQQEjARsEQEH3ogVBKyAAQfyiBEGkpAQQmwFBBiMBQQFGDQYaCyMBRQRAAAsLIAZBB0ZBASMBGwRAIAkgCEG0pAQQ+AFBByMBQQFGDQUaCyMBRQRAAAsLIAZBCEZBASMBGwRAIAggA0G0pAQQ9wFBCCMBQQFGDQQaCyMBRQRAAAsLIAZBCUZBASMBGwRAIA4gCEHEpAQQ+AFBCSMBQQFGDQMaCyMBRQRAAAsLIAZBCkZBASMBGwRAIAggBUHEpAQQ9wFBCiMBQQFGDQIaCyMBRQRAAAsLIwFFBEAgCkEgaiQACw8LIQYjAigCACAGNgIAIwIjAigCAEEEajYCACMCKAIAIgYgADYCACAGIAE2AgQgBiACNgIIIAYgAzYCDCAGIAQ2AhAgBiAFNgIUIAYgCDYCGCAGIAk2AhwgBiAKNgIgIAYgCzYCJCAGIAw2AiggBiANNgIsIAYgDjYCMCAGIA82AjQgBiAQNgI4IAYgETYCPCAGIAc2AkAjAiMCKAIAQcQAajYCAAuTCgEMfyMBQQJGBEAjAiMCKAIAQcQAazYCACMCKAIAIgcoAgAhACAHKAIEIQEgBygCCCECIAcoAgwhAyAHKAIQIQQgBygCFCEFIAcoAhghCCAHKAIcIQkgBygCICEKIAcoAiQhCyAHKAIoIQwgBygCLCENIAcoAjAhDiAHKAI0IQ8gBygCOCEQIAcoAjwhESAHKAJAIQcLAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQYLIwFFBEAjACILQSBrIgokAAsCQAJAAkACQAJAAkACQAJAAkACQAJAAkACQCMBRQRAIANFBEAgBSELDAILQQAhDUEAIQggBSELCwNAIwFFBEAgCCALSyIJDQUgBSALSSIJDQYgAiANaiEMIAMgDWshDiAEIAhqIQ8gCkEQaiEJIAsgCGshEAsgBkVBASMBGwRAIAkgASAMIA4gDyAQEGZBACMBQQFGDQ8aCyMBRQRAIAotABxBBEYiCQ0CIA0gCigCEGoiCUECaiENIAlBfUsNByADIA1JDQggCigCFCAIaiEOIApBCGogASACIAlqIhAQqQEgCigCDCEMIAooAggiCA0DIAwgDEF+cSIIRw0JIAwgCSAMaiIISw0KIAMgCEkNCyAOIAxBAXZB/////wFxIg9qIgggD0kNDCAFIAhJDQ0gCkEQaiERIAQgDmohBwsgBkEBRkEBIwEbBEAgESABIBAgDCAHIA8QZkEBIwFBAUYNDxoLIwFFBEAgCi0AHCIMQQRHIhANBCALIA9qQQFrIQsgAyANSyIJDQELCwsjAUUEQCAAQQQ6AAwgACALNgIADAwLCyMBRQRAIABBAzoADCAAIAk2AgAgACAOrSAJIAxqrUIghoQ3AgQMCwsLIwFFBEAgCigCGCEBIAAgDDoADCAAIAk2AgAgACAOrSABIAlqrUIghoQ3AgQMCgsLIAZBAkZBASMBGwRAIAggC0GEpAQQ+AFBAiMBQQFGDQoaCyMBRQRAAAsLIAZBA0ZBASMBGwRAIAsgBUGEpAQQ9wFBAyMBQQFGDQkaCyMBRQRAAAsLIAZBBEZBASMBGwRAIAkgDUGUpAQQ+AFBBCMBQQFGDQgaCyMBRQRAAAsLIAZBBUZBASMBGwRAIA0gA0GUpAQQ9wFBBSMBQQFGDQcaCyMBRQRAAAsLIwFFBEAgCkEAOgAUIAogCDYCECAKQRBqIQALIAZBBkZBASMBGwRAQfeiBUErIABB/KIEQaSkBBCbAUEGIwFBAUYNBhoLIwFFBEAACwsgBkEHRkEBIwEbBEAgCSAIQbSkBBD4AUEHIwFBAUYNBRoLIwFFBEAACwsgBkEIRkEBIwEbBEAgCCADQbSkBBD3AUEIIwFBAUYNBBoLIwFFBEAACwsgBkEJRkEBIwEbBEAgDiAIQcSkBBD4AUEJIwFBAUYNAxoLIwFFBEAACwsgBkEKRkEBIwEbBEAgCCAFQcSkBBD3AUEKIwFBAUYNAhoLIwFFBEAACwsjAUUEQCAKQSBqJAALDwshBiMCKAIAIAY2AgAjAiMCKAIAQQRqNgIAIwIoAgAiBiAANgIAIAYgATYCBCAGIAI2AgggBiADNgIMIAYgBDYCECAGIAU2AhQgBiAINgIYIAYgCTYCHCAGIAo2AiAgBiALNgIkIAYgDDYCKCAGIA02AiwgBiAONgIwIAYgDzYCNCAGIBA2AjggBiARNgI8IAYgBzYCQCMCIwIoAgBBxABqNgIAC5MKAQx/IwFBAkYEQCMCIwIoAgBBxABrNgIAIwIoAgAiBygCACEAIAcoAgQhASAHKAIIIQIgBygCDCEDIAcoAhAhBCAHKAIUIQUgBygCGCEIIAcoAhwhCSAHKAIgIQogBygCJCELIAcoAighDCAHKAIsIQ0gBygCMCEOIAcoAjQhDyAHKAI4IRAgBygCPCERIAcoAkAhBwsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBgsjAUUEQCMAIgtBIGsiCiQACwJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAIwFFBEAgA0UEQCAFIQsMAgtBACENQQAhCCAFIQsLA0AjAUUEQCAIIAtLIgkNBSAFIAtJIgkNBiACIA1qIQwgAyANayEOIAQgCGohDyAKQRBqIQkgCyAIayEQCyAGRUEBIwEbBEAgCSABIAwgDiAPIBAQaUEAIwFBAUYNDxoLIwFFBEAgCi0AHEEERiIJDQIgDSAKKAIQaiIJQQRqIQ0gCUF7Sw0HIAMgDUkNCCAKKAIUIAhqIQ4gCkEIaiABIAIgCWoiEBCkASAKKAIMIQwgCigCCCIIDQMgDCAMQXxxIghHDQkgDCAJIAxqIghLDQogAyAISQ0LIA4gDEECdkH/////AXEiD2oiCCAPSQ0MIAUgCEkNDSAKQRBqIREgBCAOaiEHCyAGQQFGQQEjARsEQCARIAEgECAMIAcgDxBpQQEjAUEBRg0PGgsjAUUEQCAKLQAcIgxBBEciEA0EIAsgD2pBAWshCyADIA1LIgkNAQsLCyMBRQRAIABBBDoADCAAIAs2AgAMDAsLIwFFBEAgAEEDOgAMIAAgCTYCACAAIA6tIAkgDGqtQiCGhDcCBAwLCwsjAUUEQCAKKAIYIQEgACAMOgAMIAAgCTYCACAAIA6tIAEgCWqtQiCGhDcCBAwKCwsgBkECRkEBIwEbBEAgCCALQYSkBBD4AUECIwFBAUYNChoLIwFFBEAACwsgBkEDRkEBIwEbBEAgCyAFQYSkBBD3AUEDIwFBAUYNCRoLIwFFBEAACwsgBkEERkEBIwEbBEAgCSANQZSkBBD4AUEEIwFBAUYNCBoLIwFFBEAACwsgBkEFRkEBIwEbBEAgDSADQZSkBBD3AUEFIwFBAUYNBxoLIwFFBEAACwsjAUUEQCAKQQA6ABQgCiAINgIQIApBEGohAAsgBkEGRkEBIwEbBEBB96IFQSsgAEH8ogRBpKQEEJsBQQYjAUEBRg0GGgsjAUUEQAALCyAGQQdGQQEjARsEQCAJIAhBtKQEEPgBQQcjAUEBRg0FGgsjAUUEQAALCyAGQQhGQQEjARsEQCAIIANBtKQEEPcBQQgjAUEBRg0EGgsjAUUEQAALCyAGQQlGQQEjARsEQCAOIAhBxKQEEPgBQQkjAUEBRg0DGgsjAUUEQAALCyAGQQpGQQEjARsEQCAIIAVBxKQEEPcBQQojAUEBRg0CGgsjAUUEQAALCyMBRQRAIApBIGokAAsPCyEGIwIoAgAgBjYCACMCIwIoAgBBBGo2AgAjAigCACIGIAA2AgAgBiABNgIEIAYgAjYCCCAGIAM2AgwgBiAENgIQIAYgBTYCFCAGIAg2AhggBiAJNgIcIAYgCjYCICAGIAs2AiQgBiAMNgIoIAYgDTYCLCAGIA42AjAgBiAPNgI0IAYgEDYCOCAGIBE2AjwgBiAHNgJAIwIjAigCAEHEAGo2AgALkwoBDH8jAUECRgRAIwIjAigCAEHEAGs2AgAjAigCACIHKAIAIQAgBygCBCEBIAcoAgghAiAHKAIMIQMgBygCECEEIAcoAhQhBSAHKAIYIQggBygCHCEJIAcoAiAhCiAHKAIkIQsgBygCKCEMIAcoAiwhDSAHKAIwIQ4gBygCNCEPIAcoAjghECAHKAI8IREgBygCQCEHCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEGCyMBRQRAIwAiC0EgayIKJAALAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAjAUUEQCADRQRAIAUhCwwCC0EAIQ1BACEIIAUhCwsDQCMBRQRAIAggC0siCQ0FIAUgC0kiCQ0GIAIgDWohDCADIA1rIQ4gBCAIaiEPIApBEGohCSALIAhrIRALIAZFQQEjARsEQCAJIAEgDCAOIA8gEBBoQQAjAUEBRg0PGgsjAUUEQCAKLQAcQQRGIgkNAiANIAooAhBqIglBBGohDSAJQXtLDQcgAyANSQ0IIAooAhQgCGohDiAKQQhqIAEgAiAJaiIQEKQBIAooAgwhDCAKKAIIIggNAyAMIAxBfHEiCEcNCSAMIAkgDGoiCEsNCiADIAhJDQsgDiAMQQJ2Qf////8BcSIPaiIIIA9JDQwgBSAISQ0NIApBEGohESAEIA5qIQcLIAZBAUZBASMBGwRAIBEgASAQIAwgByAPEGhBASMBQQFGDQ8aCyMBRQRAIAotABwiDEEERyIQDQQgCyAPakEBayELIAMgDUsiCQ0BCwsLIwFFBEAgAEEEOgAMIAAgCzYCAAwMCwsjAUUEQCAAQQM6AAwgACAJNgIAIAAgDq0gCSAMaq1CIIaENwIEDAsLCyMBRQRAIAooAhghASAAIAw6AAwgACAJNgIAIAAgDq0gASAJaq1CIIaENwIEDAoLCyAGQQJGQQEjARsEQCAIIAtBhKQEEPgBQQIjAUEBRg0KGgsjAUUEQAALCyAGQQNGQQEjARsEQCALIAVBhKQEEPcBQQMjAUEBRg0JGgsjAUUEQAALCyAGQQRGQQEjARsEQCAJIA1BlKQEEPgBQQQjAUEBRg0IGgsjAUUEQAALCyAGQQVGQQEjARsEQCANIANBlKQEEPcBQQUjAUEBRg0HGgsjAUUEQAALCyMBRQRAIApBADoAFCAKIAg2AhAgCkEQaiEACyAGQQZGQQEjARsEQEH3ogVBKyAAQfyiBEGkpAQQmwFBBiMBQQFGDQYaCyMBRQRAAAsLIAZBB0ZBASMBGwRAIAkgCEG0pAQQ+AFBByMBQQFGDQUaCyMBRQRAAAsLIAZBCEZBASMBGwRAIAggA0G0pAQQ9wFBCCMBQQFGDQQaCyMBRQRAAAsLIAZBCUZBASMBGwRAIA4gCEHEpAQQ+AFBCSMBQQFGDQMaCyMBRQRAAAsLIAZBCkZBASMBGwRAIAggBUHEpAQQ9wFBCiMBQQFGDQIaCyMBRQRAAAsLIwFFBEAgCkEgaiQACw8LIQYjAigCACAGNgIAIwIjAigCAEEEajYCACMCKAIAIgYgADYCACAGIAE2AgQgBiACNgIIIAYgAzYCDCAGIAQ2AhAgBiAFNgIUIAYgCDYCGCAGIAk2AhwgBiAKNgIgIAYgCzYCJCAGIAw2AiggBiANNgIsIAYgDjYCMCAGIA82AjQgBiAQNgI4IAYgETYCPCAGIAc2AkAjAiMCKAIAQcQAajYCAAuMCgEMfyMBQQJGBEAjAiMCKAIAQcQAazYCACMCKAIAIgcoAgAhACAHKAIEIQEgBygCCCECIAcoAgwhAyAHKAIQIQQgBygCFCEFIAcoAhghCCAHKAIcIQkgBygCICEKIAcoAiQhCyAHKAIoIQwgBygCLCENIAcoAjAhDiAHKAI0IQ8gBygCOCEQIAcoAjwhESAHKAJAIQcLAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQYLIwFFBEAjACILQSBrIgokAAsCQAJAAkACQAJAAkACQAJAAkACQAJAAkACQCMBRQRAIANFBEAgBSELDAILQQAhDUEAIQggBSELCwNAIwFFBEAgCCALSyIJDQUgBSALSSIJDQYgAiANaiEMIAMgDWshDiAEIAhqIQ8gCkEQaiEJIAsgCGshEAsgBkVBASMBGwRAIAkgASAMIA4gDyAQEGtBACMBQQFGDQ8aCyMBRQRAIAotABxBBEYiCQ0CIA0gCigCEGoiCUEIaiENIAlBd0sNByADIA1JDQggCigCFCAIaiEOIApBCGogASACIAlqIhAQpQEgCigCDCEMIAooAggiCA0DIAwgDEF4cSIIRw0JIAwgCSAMaiIISw0KIAMgCEkNCyAOIAxBA3YiD2oiCCAPSQ0MIAUgCEkNDSAKQRBqIREgBCAOaiEHCyAGQQFGQQEjARsEQCARIAEgECAMIAcgDxBrQQEjAUEBRg0PGgsjAUUEQCAKLQAcIgxBBEciEA0EIAsgD2pBAWshCyADIA1LIgkNAQsLCyMBRQRAIABBBDoADCAAIAs2AgAMDAsLIwFFBEAgAEEDOgAMIAAgCTYCACAAIA6tIAkgDGqtQiCGhDcCBAwLCwsjAUUEQCAKKAIYIQEgACAMOgAMIAAgCTYCACAAIA6tIAEgCWqtQiCGhDcCBAwKCwsgBkECRkEBIwEbBEAgCCALQYSkBBD4AUECIwFBAUYNChoLIwFFBEAACwsgBkEDRkEBIwEbBEAgCyAFQYSkBBD3AUEDIwFBAUYNCRoLIwFFBEAACwsgBkEERkEBIwEbBEAgCSANQZSkBBD4AUEEIwFBAUYNCBoLIwFFBEAACwsgBkEFRkEBIwEbBEAgDSADQZSkBBD3AUEFIwFBAUYNBxoLIwFFBEAACwsjAUUEQCAKQQA6ABQgCiAINgIQIApBEGohAAsgBkEGRkEBIwEbBEBB96IFQSsgAEH8ogRBpKQEEJsBQQYjAUEBRg0GGgsjAUUEQAALCyAGQQdGQQEjARsEQCAJIAhBtKQEEPgBQQcjAUEBRg0FGgsjAUUEQAALCyAGQQhGQQEjARsEQCAIIANBtKQEEPcBQQgjAUEBRg0EGgsjAUUEQAALCyAGQQlGQQEjARsEQCAOIAhBxKQEEPgBQQkjAUEBRg0DGgsjAUUEQAALCyAGQQpGQQEjARsEQCAIIAVBxKQEEPcBQQojAUEBRg0CGgsjAUUEQAALCyMBRQRAIApBIGokAAsPCyEGIwIoAgAgBjYCACMCIwIoAgBBBGo2AgAjAigCACIGIAA2AgAgBiABNgIEIAYgAjYCCCAGIAM2AgwgBiAENgIQIAYgBTYCFCAGIAg2AhggBiAJNgIcIAYgCjYCICAGIAs2AiQgBiAMNgIoIAYgDTYCLCAGIA42AjAgBiAPNgI0IAYgEDYCOCAGIBE2AjwgBiAHNgJAIwIjAigCAEHEAGo2AgALjAoBDH8jAUECRgRAIwIjAigCAEHEAGs2AgAjAigCACIHKAIAIQAgBygCBCEBIAcoAgghAiAHKAIMIQMgBygCECEEIAcoAhQhBSAHKAIYIQggBygCHCEJIAcoAiAhCiAHKAIkIQsgBygCKCEMIAcoAiwhDSAHKAIwIQ4gBygCNCEPIAcoAjghECAHKAI8IREgBygCQCEHCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEGCyMBRQRAIwAiC0EgayIKJAALAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAjAUUEQCADRQRAIAUhCwwCC0EAIQ1BACEIIAUhCwsDQCMBRQRAIAggC0siCQ0FIAUgC0kiCQ0GIAIgDWohDCADIA1rIQ4gBCAIaiEPIApBEGohCSALIAhrIRALIAZFQQEjARsEQCAJIAEgDCAOIA8gEBBqQQAjAUEBRg0PGgsjAUUEQCAKLQAcQQRGIgkNAiANIAooAhBqIglBCGohDSAJQXdLDQcgAyANSQ0IIAooAhQgCGohDiAKQQhqIAEgAiAJaiIQEKUBIAooAgwhDCAKKAIIIggNAyAMIAxBeHEiCEcNCSAMIAkgDGoiCEsNCiADIAhJDQsgDiAMQQN2Ig9qIgggD0kNDCAFIAhJDQ0gCkEQaiERIAQgDmohBwsgBkEBRkEBIwEbBEAgESABIBAgDCAHIA8QakEBIwFBAUYNDxoLIwFFBEAgCi0AHCIMQQRHIhANBCALIA9qQQFrIQsgAyANSyIJDQELCwsjAUUEQCAAQQQ6AAwgACALNgIADAwLCyMBRQRAIABBAzoADCAAIAk2AgAgACAOrSAJIAxqrUIghoQ3AgQMCwsLIwFFBEAgCigCGCEBIAAgDDoADCAAIAk2AgAgACAOrSABIAlqrUIghoQ3AgQMCgsLIAZBAkZBASMBGwRAIAggC0GEpAQQ+AFBAiMBQQFGDQoaCyMBRQRAAAsLIAZBA0ZBASMBGwRAIAsgBUGEpAQQ9wFBAyMBQQFGDQkaCyMBRQRAAAsLIAZBBEZBASMBGwRAIAkgDUGUpAQQ+AFBBCMBQQFGDQgaCyMBRQRAAAsLIAZBBUZBASMBGwRAIA0gA0GUpAQQ9wFBBSMBQQFGDQcaCyMBRQRAAAsLIwFFBEAgCkEAOgAUIAogCDYCECAKQRBqIQALIAZBBkZBASMBGwRAQfeiBUErIABB/KIEQaSkBBCbAUEGIwFBAUYNBhoLIwFFBEAACwsgBkEHRkEBIwEbBEAgCSAIQbSkBBD4AUEHIwFBAUYNBRoLIwFFBEAACwsgBkEIRkEBIwEbBEAgCCADQbSkBBD3AUEIIwFBAUYNBBoLIwFFBEAACwsgBkEJRkEBIwEbBEAgDiAIQcSkBBD4AUEJIwFBAUYNAxoLIwFFBEAACwsgBkEKRkEBIwEbBEAgCCAFQcSkBBD3AUEKIwFBAUYNAhoLIwFFBEAACwsjAUUEQCAKQSBqJAALDwshBiMCKAIAIAY2AgAjAiMCKAIAQQRqNgIAIwIoAgAiBiAANgIAIAYgATYCBCAGIAI2AgggBiADNgIMIAYgBDYCECAGIAU2AhQgBiAINgIYIAYgCTYCHCAGIAo2AiAgBiALNgIkIAYgDDYCKCAGIA02AiwgBiAONgIwIAYgDzYCNCAGIBA2AjggBiARNgI8IAYgBzYCQCMCIwIoAgBBxABqNgIAC/AFAQd/IwFBAkYEQCMCIwIoAgBBDGs2AgAjAigCACIBKAIAIQAgASgCCCEEIAEoAgQhAQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBQsjAUUEQCMAQTBrIgQkACABKAIEIgJFIQMLAkACQCMBRQRAAkACQAJAAkACQAJAIANFBEAgASgCACEGIAJBAWtB/////wFxIgJBAWoiA0EHcSEHAn8gAkEHSQRAQQAhAyAGDAELIAZBPGohAiADQfj///8DcSEIQQAhAwNAIAIoAgAgAkEIaygCACACQRBrKAIAIAJBGGsoAgAgAkEgaygCACACQShrKAIAIAJBMGsoAgAgAkE4aygCACADampqampqamohAyACQUBrIQIgCEEIayIIDQALIAJBPGsLIQIgBwRAIAJBBGohAgNAIAIoAgAgA2ohAyACQQhqIQIgB0EBayIHDQALCyABQRRqKAIADQEgAyECDAQLQQAhAiABQRRqKAIARQ0BQQAhAwwCCyAGKAIEIANBEE9yDQEMAwtBASEDDAMLIAMgAyADaiICSw0BCyACRQ0AIAJBf0wNAyACEA8iAw0BAAtBASEDQQAhAgsgAEEANgIIIAAgAjYCBCAAIAM2AgAgBCAANgIMIARBIGogAUEQaikCADcDACAEQRhqIAFBCGopAgA3AwAgBCABKQIANwMQIARBEGohASAEQQxqIQALIAVFQQEjARsEQCAAQdyBBCABECtBACMBQQFGDQMaIQALIwFFBEAgAEUNAiAEQShqIQALIAVBAUZBASMBGwRAQbyCBEEzIABB8IIEQZiDBBCbAUEBIwFBAUYNAxoLIwFFBEAACwsgBUECRkEBIwEbBEAQ0wFBAiMBQQFGDQIaCyMBRQRAAAsLIwFFBEAgBEEwaiQACw8LIQMjAigCACADNgIAIwIjAigCAEEEajYCACMCKAIAIgMgADYCACADIAE2AgQgAyAENgIIIwIjAigCAEEMajYCAAvWBgEGfyMBQQJGBEAjAiMCKAIAQRBrNgIAIwIoAgAiAygCACEAIAMoAgQhASADKAIIIQIgAygCDCEDCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEECyMBRQRAIwBB8ABrIgIkACABQRRqKAIAIgNFIQULAkAjAUUEQCAFDQEgASADQQFrIgM2AhQgAkEwaiIFIAEoAgwgA0EkbGoiAUEMaikCADcDACACQThqIgMgAUEUaikCADcDACACQUBrIgcgAUEcaikCADcDACACIAEpAgQ3AyggASgCACIBRSEGCwJAIwFFBEAgBg0BIAJBHGogBykDADcCACACQQxqIAUpAwA3AgAgAkEUaiADKQMANwIAIAIgATYCACACIAIpAyg3AgQgAkEgaigCAEUhAQsCQAJAIwFFBEAgAQ0BIAJBBDYCXCACIAJBGGo2AlggAkEBNgI8IAJCATcCLCACQZCeBTYCKCACIAJB2ABqNgI4IAJBKGohAyACQcgAaiEBCyAERUEBIwEbBEAgASADEDtBACMBQQFGDQUaCyMBRQ0BCyMBRQRAIAJBADYCUCACQgE3A0gLCyMBRQRAIAJB7ABqQQQ2AgAgAkHkAGpBBDYCACACQQQ2AlwgAiACQQxqNgJgIAIgAkHIAGo2AmggAiACNgJYIAJBAzYCPCACQgM3AiwgAkGYngU2AiggAiACQdgAajYCOCACQShqIQELIARBAUZBASMBGwRAIAAgARA7QQEjAUEBRg0DGgsjAUUEQCACKAJMBEAgAigCSBAhCyACKAIEBEAgAigCABAhCyACQRBqKAIABEAgAigCDBAhCyACQRxqKAIABEAgAigCGBAhCyACQfAAaiQADwsLIARBAkZBASMBGwRAQcyiBUErQfydBRC/AUECIwFBAUYNAhoLIwFFBEAACwsjAUUEQCACQRRqQQA2AgAgAkHooQU2AhAgAkIBNwIEIAJB+JwFNgIACyAEQQNGQQEjARsEQCACQeydBRDSAUEDIwFBAUYNARoLIwFFBEAACw8LIQQjAigCACAENgIAIwIjAigCAEEEajYCACMCKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIAQgAzYCDCMCIwIoAgBBEGo2AgALhgcBBH8jAUECRgRAIwIjAigCAEEUazYCACMCKAIAIgEoAgAhACABKAIIIQIgASgCDCEDIAEoAhAhBSABKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQYLIAZFQQEjARsEQCABEM4BQQAjAUEBRg0BGiEDCyADIANBAWsjARshAwJ/AkACQAJAAkACQAJAAkACQAJAAkAjAUUEQAJAIAMOBgcGBQQDAgALCyAGQQFGQQEjARsEQEG0pgRBDkHEpgQQvwFBASMBQQFGDQwaCyMBRQRAAAsLIAZBAkZBASMBGwRAIAEQzwFBAiMBQQFGDQsaIQMLIwFFBEAgA0H/AXFFBEAgAkEGbCIDQQN2IQUgAiADQQZxQQZuawwLCyACQQJ2QQNsIQUgAkF8cQwKCwsgBkEDRkEBIwEbBEAgARDPAUEDIwFBAUYNChohAwsjAUUEQCADQf8BcUUiAw0IIAJBA3ZBBWwhBSACQXhxDAkLCyAGQQRGQQEjARsEQCABEM8BQQQjAUEBRg0JGiEDCyMBRQRAIANB/wFxRSIDDQYgAkEBdiEFIAJBfnEMCAsLIAZBBUZBASMBGwRAIAEQzwFBBSMBQQFGDQgaIQMLIwFFBEAgA0H/AXFFIgMNBCACQQN2QQNsIQUgAkF4cQwHCwsgBkEGRkEBIwEbBEAgARDPAUEGIwFBAUYNBxohAwsjAUUEQCADQf8BcUUiAw0CIAJBAnYhBSACQXxxDAYLCyAGQQdGQQEjARsEQCABEM8BQQcjAUEBRg0GGiEDCyMBRQRAIANB/wFxBEAgAkEDdiEFIAJBeHEMBgsgAkEDdiEFIAJBeHEMBQsLIwFFBEAgAkECdkH/////AXEhBSACQXxxDAQLCyMBRQRAIAJBA2wiA0EDdiEFIAIgA0EHcUEDbmsMAwsLIwFFBEAgAkEBdkH/////AXEhBSACQX5xDAILCyMBBH8gAwUgAkEFbCIDQQN2IQUgAiADQQdxQQVuawsLIQMjAUUEQCABQQhqKAIAQYIETUEAIAIgA0cbRQRAIABBBDoABCAAIAU2AgAPCyAAQQA6AAQgACADNgIACw8LIQQjAigCACAENgIAIwIjAigCAEEEajYCACMCKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIAQgAzYCDCAEIAU2AhAjAiMCKAIAQRRqNgIAC/MHAgN/AX4jAUECRgRAIwIjAigCAEEcazYCACMCKAIAIgEoAgAhACABKAIIIQIgASgCDCEDIAEoAhAhBCABKQIUIQYgASgCBCEBCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEFCyMBRQRAIwBBEGsiAyQAIAJBEEkhBAsCQAJAAkACQAJAAkACQCMBRQRAIAQNASACQYCABEkiBA0CIANBCGohBAsgBUVBASMBGwRAIAQgAUEjEKoBQQAjAUEBRg0IGgsjAUUEQCADLQAIQQRGIgQNAyADKQMIIgZC/wGDQgRRIgQNAyADIAY3AwggA0EIaiEBCyAFQQFGQQEjARsEQCAAIAEQmAFBASMBQQFGDQgaCyMBRQ0GCyMBRQRAIANBCGohBCACQQh0QQFyIQILIAVBAkZBASMBGwRAIAQgASACEKoBQQIjAUEBRg0HGgsjAUUEQCADLQAIQQRGIgENAyADKQMIIgZC/wGDQgRRIgENAyADIAY3AwggA0EIaiEBCyAFQQNGQQEjARsEQCAAIAEQmAFBAyMBQQFGDQcaCyMBRQ0FCyAEIANBCGojARshBCAFQQRGQQEjARsEQCAEIAFBIhCqAUEEIwFBAUYNBhoLIwFFBEAgAy0ACEEERwRAIAMpAwgiBkL/AYNCBFINBQsgAyACQQh0IAJBgP4DcUEIdnI7AQQgA0EEaiEEIANBCGohAgsgBUEFRkEBIwEbBEAgAiABIARBAhBgQQUjAUEBRg0GGgsjAUUEQCADLQAIQQRGIgENAiADKQMIIgZC/wGDQgRRIgENAiADIAY3AwggA0EIaiEBCyAFQQZGQQEjARsEQCAAIAEQmAFBBiMBQQFGDQYaCyMBRQ0ECyMBRQRAIAMgAkEIdkGA/gNxIAJBGHZyIAJBCHRBgID8B3EgAkEYdHJyNgIEIANBBGohBCADQQhqIQILIAVBB0ZBASMBGwRAIAIgASAEQQQQYEEHIwFBAUYNBRoLIwFFBEAgAy0ACEEERiIBDQEgAykDCCIGQv8Bg0IEUiIBDQILCyMBRQRAIABBETYCAAwDCwsjAUUEQCADIAY3AwggA0EIaiEBCyAFQQhGQQEjARsEQCAAIAEQmAFBCCMBQQFGDQMaCyMBRQ0BCyMBRQRAIAMgBjcDCCADQQhqIQELIAVBCUZBASMBGwRAIAAgARCYAUEJIwFBAUYNAhoLCyMBRQRAIANBEGokAAsPCyEFIwIoAgAgBTYCACMCIwIoAgBBBGo2AgAjAigCACIFIAA2AgAgBSABNgIEIAUgAjYCCCAFIAM2AgwgBSAENgIQIAUgBjcCFCMCIwIoAgBBHGo2AgAL8wcCA38BfiMBQQJGBEAjAiMCKAIAQRxrNgIAIwIoAgAiASgCACEAIAEoAgghAiABKAIMIQMgASgCECEEIAEpAhQhBiABKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQULIwFFBEAjAEEQayIDJAAgAkEQSSEECwJAAkACQAJAAkACQAJAIwFFBEAgBA0BIAJBgIAESSIEDQIgA0EIaiEECyAFRUEBIwEbBEAgBCABQSEQqgFBACMBQQFGDQgaCyMBRQRAIAMtAAhBBEYiBA0DIAMpAwgiBkL/AYNCBFEiBA0DIAMgBjcDCCADQQhqIQELIAVBAUZBASMBGwRAIAAgARCYAUEBIwFBAUYNCBoLIwFFDQYLIwFFBEAgA0EIaiEEIAJBCHRBAnIhAgsgBUECRkEBIwEbBEAgBCABIAIQqgFBAiMBQQFGDQcaCyMBRQRAIAMtAAhBBEYiAQ0DIAMpAwgiBkL/AYNCBFEiAQ0DIAMgBjcDCCADQQhqIQELIAVBA0ZBASMBGwRAIAAgARCYAUEDIwFBAUYNBxoLIwFFDQULIAQgA0EIaiMBGyEEIAVBBEZBASMBGwRAIAQgAUEgEKoBQQQjAUEBRg0GGgsjAUUEQCADLQAIQQRHBEAgAykDCCIGQv8Bg0IEUg0FCyADIAJBCHQgAkGA/gNxQQh2cjsBBCADQQRqIQQgA0EIaiECCyAFQQVGQQEjARsEQCACIAEgBEECEGBBBSMBQQFGDQYaCyMBRQRAIAMtAAhBBEYiAQ0CIAMpAwgiBkL/AYNCBFEiAQ0CIAMgBjcDCCADQQhqIQELIAVBBkZBASMBGwRAIAAgARCYAUEGIwFBAUYNBhoLIwFFDQQLIwFFBEAgAyACQQh2QYD+A3EgAkEYdnIgAkEIdEGAgPwHcSACQRh0cnI2AgQgA0EEaiEEIANBCGohAgsgBUEHRkEBIwEbBEAgAiABIARBBBBgQQcjAUEBRg0FGgsjAUUEQCADLQAIQQRGIgENASADKQMIIgZC/wGDQgRSIgENAgsLIwFFBEAgAEERNgIADAMLCyMBRQRAIAMgBjcDCCADQQhqIQELIAVBCEZBASMBGwRAIAAgARCYAUEIIwFBAUYNAxoLIwFFDQELIwFFBEAgAyAGNwMIIANBCGohAQsgBUEJRkEBIwEbBEAgACABEJgBQQkjAUEBRg0CGgsLIwFFBEAgA0EQaiQACw8LIQUjAigCACAFNgIAIwIjAigCAEEEajYCACMCKAIAIgUgADYCACAFIAE2AgQgBSACNgIIIAUgAzYCDCAFIAQ2AhAgBSAGNwIUIwIjAigCAEEcajYCAAvCBwEGfyMBQQJGBEAjAiMCKAIAQRxrNgIAIwIoAgAiASgCACEAIAEoAgghAiABKAIMIQMgASgCECEEIAEoAhQhBiABKAIYIQggASgCBCEBCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEHCyMBRQRAIwBBMGsiAyQAQei4BSgCAEH/////B3FFIQQLAkACQCMBRQRAIARFBEAQggJFDQILQdy4BSgCACEEQdy4BUF/NgIACwJAIwFFBEAgBA0BAkACQEHouAUoAgBB/////wdxRQRAQeS4BUEBNgIADAELEIICQeS4BUEBNgIARQ0BC0HouAUoAgBB/////wdxRQ0AEIICDQBB4LgFQQE6AAALQdy4BUEANgIAIANBEGohBAsgB0VBASMBGwRAIAQgABDRAUEAIwFBAUYNBBoLIwFFBEAgA0EIaiEGIAMoAhAhBAsgB0EBRkEBIwEbBEAgBiABENEBQQEjAUEBRg0EGgsjAUUEQCADKAIIIQYLIAdBAkZBASMBGwRAIAQgBhAAQQIjAUEBRg0EGgsgCCAAQQdGIwEbIQgCQAJAAkAjAUUEQCAIDQEgAEENRw0CIARB5/wEQQ0Q/gENAiADIAE2AiwgAyABNgIoIAMgBjYCJCADQo2AgIDQATcCHCADIAQ2AhggA0EYaiEACyAHQQNGQQEjARsEQCAAIAJBARBxQQMjAUEBRg0HGiEACyMBRQ0FCyMBRQRAIARBgv4EQQcQ/gFFDQILCyMBRQRAIAMgATYCLCADIAE2AiggAyAGNgIkIAMgADYCICADIAA2AhwgAyAENgIYIANBGGohAAsgB0EERkEBIwEbBEAgACACQQAQcUEEIwFBAUYNBRohAAsjAUUNAwsjAUUEQCADIAE2AiwgAyABNgIoIAMgBjYCJCADQoeAgIDwADcCHCADIAQ2AhggA0EYaiEACyAHQQVGQQEjARsEQCAAIAJBAhBxQQUjAUEBRg0EGiEACyMBRQ0CCyMBRQRAAAsLIwFFBEAgA0EsakEANgIAIANB6KEFNgIoIANCATcCHCADQaiuBTYCGCADQRhqIQALIAdBBkZBASMBGwRAIABBzK4FENIBQQYjAUEBRg0CGgsjAUUEQAALCyMBRQRAIANBMGokACAADwsACyEFIwIoAgAgBTYCACMCIwIoAgBBBGo2AgAjAigCACIFIAA2AgAgBSABNgIEIAUgAjYCCCAFIAM2AgwgBSAENgIQIAUgBjYCFCAFIAg2AhgjAiMCKAIAQRxqNgIAQQALhwYBB38jAUECRgRAIwIjAigCAEE0azYCACMCKAIAIgkoAgAhACAJKAIEIQEgCSgCCCECIAkoAgwhAyAJKAIQIQQgCSgCFCEFIAkoAhghBiAJKAIcIQcgCSgCICEKIAkoAiQhCyAJKAIoIQwgCSgCLCENIAkoAjAhCQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhCAsjAUUEQCMAIg1BEGsiCyQAIARBAnYiCUEBaiEMQXwhByAFIQoLAkACQAJAA0AgDCAMQQFrIwEbIQwCQCMBRQRAIAwNASAJQQNsIgcgBk0iDQ0DCyAIRUEBIwEbBEAgByAGQeSjBBD2AUEAIwFBAUYNBhoLIwFFBEAACwsjAUUEQCALQQhqIAIgAyAHakEEakEEIApBAxCJASAKQQNqIQogB0EEaiINIQcgCygCCEUNAQsLIwFFBEAgDSALKAIMaiEHDAILCyMBRQRAIAsgAiAEQXxxIgogA2ogBEEDcSAFIAdqIAYgB2sQiQEgCygCAARAIAogCygCBGohBwwCCwJAAkACQCABRQ0AIARBBmwiCkEGcUUNACAEQQFrIQcgBEUNAiACIAMgB2otAABqLQAAQX8gCkEGcXRBf3NBBiAKa0EGcXRxDQELIABBBDoADCAAIAY2AgAMBAsgAEECOgAMIAAgBzYCCCAAIAdBfHE2AgAgACAHQQJ2QQNsNgIEDAMLCyAIQQFGQQEjARsEQCAHQQBB9KMEEKYBQQEjAUEBRg0DGgsjAUUEQAALCyMBRQRAIABBAToADCAAIAc2AgggACAHQXxxNgIAIAAgB0ECdkEDbDYCBAsLIwFFBEAgC0EQaiQACw8LIQgjAigCACAINgIAIwIjAigCAEEEajYCACMCKAIAIgggADYCACAIIAE2AgQgCCACNgIIIAggAzYCDCAIIAQ2AhAgCCAFNgIUIAggBjYCGCAIIAc2AhwgCCAKNgIgIAggCzYCJCAIIAw2AiggCCANNgIsIAggCTYCMCMCIwIoAgBBNGo2AgALhwYBB38jAUECRgRAIwIjAigCAEE0azYCACMCKAIAIgkoAgAhACAJKAIEIQEgCSgCCCECIAkoAgwhAyAJKAIQIQQgCSgCFCEFIAkoAhghBiAJKAIcIQcgCSgCICEKIAkoAiQhCyAJKAIoIQwgCSgCLCENIAkoAjAhCQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhCAsjAUUEQCMAIg1BEGsiCyQAIARBA3YiCUEBaiEMQXghByAFIQoLAkACQAJAA0AgDCAMQQFrIwEbIQwCQCMBRQRAIAwNASAJQQVsIgcgBk0iDQ0DCyAIRUEBIwEbBEAgByAGQeSjBBD2AUEAIwFBAUYNBhoLIwFFBEAACwsjAUUEQCALQQhqIAIgAyAHakEIakEIIApBBRCKASAKQQVqIQogB0EIaiINIQcgCygCCEUNAQsLIwFFBEAgDSALKAIMaiEHDAILCyMBRQRAIAsgAiAEQXhxIgogA2ogBEEHcSAFIAdqIAYgB2sQigEgCygCAARAIAogCygCBGohBwwCCwJAAkACQCABRQ0AIARBBWwiCkEHcUUNACAEQQFrIQcgBEUNAiACIAMgB2otAABqLQAAQX8gCkEHcXRBf3NBBSAKa0EHcXRxDQELIABBBDoADCAAIAY2AgAMBAsgAEECOgAMIAAgBzYCCCAAIAdBeHE2AgAgACAHQQN2QQVsNgIEDAMLCyAIQQFGQQEjARsEQCAHQQBB9KMEEKYBQQEjAUEBRg0DGgsjAUUEQAALCyMBRQRAIABBAToADCAAIAc2AgggACAHQXhxNgIAIAAgB0EDdkEFbDYCBAsLIwFFBEAgC0EQaiQACw8LIQgjAigCACAINgIAIwIjAigCAEEEajYCACMCKAIAIgggADYCACAIIAE2AgQgCCACNgIIIAggAzYCDCAIIAQ2AhAgCCAFNgIUIAggBjYCGCAIIAc2AhwgCCAKNgIgIAggCzYCJCAIIAw2AiggCCANNgIsIAggCTYCMCMCIwIoAgBBNGo2AgALhwYBB38jAUECRgRAIwIjAigCAEE0azYCACMCKAIAIgkoAgAhACAJKAIEIQEgCSgCCCECIAkoAgwhAyAJKAIQIQQgCSgCFCEFIAkoAhghBiAJKAIcIQcgCSgCICEKIAkoAiQhCyAJKAIoIQwgCSgCLCENIAkoAjAhCQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhCAsjAUUEQCMAIg1BEGsiCyQAIARBA3YiCUEBaiEMQXghByAFIQoLAkACQAJAA0AgDCAMQQFrIwEbIQwCQCMBRQRAIAwNASAJQQNsIgcgBk0iDQ0DCyAIRUEBIwEbBEAgByAGQeSjBBD2AUEAIwFBAUYNBhoLIwFFBEAACwsjAUUEQCALQQhqIAIgAyAHakEIakEIIApBAxCMASAKQQNqIQogB0EIaiINIQcgCygCCEUNAQsLIwFFBEAgDSALKAIMaiEHDAILCyMBRQRAIAsgAiAEQXhxIgogA2ogBEEHcSAFIAdqIAYgB2sQjAEgCygCAARAIAogCygCBGohBwwCCwJAAkACQCABRQ0AIARBA2wiCkEHcUUNACAEQQFrIQcgBEUNAiACIAMgB2otAABqLQAAQX8gCkEHcXRBf3NBAyAKa0EHcXRxDQELIABBBDoADCAAIAY2AgAMBAsgAEECOgAMIAAgBzYCCCAAIAdBeHE2AgAgACAHQQN2QQNsNgIEDAMLCyAIQQFGQQEjARsEQCAHQQBB9KMEEKYBQQEjAUEBRg0DGgsjAUUEQAALCyMBRQRAIABBAToADCAAIAc2AgggACAHQXhxNgIAIAAgB0EDdkEDbDYCBAsLIwFFBEAgC0EQaiQACw8LIQgjAigCACAINgIAIwIjAigCAEEEajYCACMCKAIAIgggADYCACAIIAE2AgQgCCACNgIIIAggAzYCDCAIIAQ2AhAgCCAFNgIUIAggBjYCGCAIIAc2AhwgCCAKNgIgIAggCzYCJCAIIAw2AiggCCANNgIsIAggCTYCMCMCIwIoAgBBNGo2AgALtgUBBn8jAUECRgRAIwIjAigCAEEUazYCACMCKAIAIgIoAgAhASACKAIEIQMgAigCCCEEIAIoAgwhBSACKAIQIQILAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQYLIwFFBEAgAEELdCECQQAhAUEhIQRBISEFAkACQANAAkBBfyACIAEgBEEBdmoiA0ECdEGQnARqKAIAQQt0IgRHIAIgBEsbIgRBAUYEQCADIQUMAQsgBEH/AXFB/wFHDQIgA0EBaiEBCyAFIAFrIQQgASAFSQ0ACwwBCyADQQFqIQELIAFBIEshAwsCQAJAAkAjAUUEQCADDQEgAUECdCECQdcFIQUgAUEgRwRAIAJBlJwEaigCAEEVdiEFC0EAIQQgAUEBayIDIAFNBEAgA0EhTyIBDQMgA0ECdEGQnARqKAIAQf///wBxIQQLIAUgAkGQnARqKAIAQRV2IgFBf3NqRQ0DIAAgBGshBCABQdcFIAFB1wVLIgAbIQIgBUEBayEDQQAhBQsDQCAAIAEgAkYjARshAAJAIwFFBEAgAEUEQCAFIAFBlJ0Eai0AAGoiBSAETQ0CDAYLCyAGRUEBIwEbBEAgAkHXBUHsogQQpgFBACMBQQFGDQYaCyMBRQRAAAsLIwFFBEAgAUEBaiIBIANHIgANAQsLIwFFBEAgAyEBDAMLCyAGQQFGQQEjARsEQEEhQSFB7KIEEKYBQQEjAUEBRg0DGgsjAUUEQAALCyAGQQJGQQEjARsEQCADQSFBgJwEEKYBQQIjAUEBRg0CGgsjAUUEQAALCyMBRQRAIAFBAXEPCwALIQAjAigCACAANgIAIwIjAigCAEEEajYCACMCKAIAIgAgATYCACAAIAM2AgQgACAENgIIIAAgBTYCDCAAIAI2AhAjAiMCKAIAQRRqNgIAQQALxAcBCX8jAUECRgRAIwIjAigCAEEkazYCACMCKAIAIgEoAgAhACABKAIIIQIgASgCDCEEIAEoAhAhBSABKAIUIQogASgCGCEHIAEoAhwhCCABKAIgIQkgASgCBCEBCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEGCyMBRQRAIAEoAhghByABQRxqKAIAIgEoAhAhCEEBIQILIAZFQQEjARsEQCAHQScgCBEAAEEAIwFBAUYNARohAQsCQCMBRQRAIAENAUEwIQQgACgCACECQYKAxAAhAQsCQAJAAkACQAJAAkAjAUUEQAJAAkAgAg4oCAEBAQEBAQEBAwUBAQQBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBBwALIAJB3ABGIgANBgsLIAZBAUZBASMBGwRAIAIQREEBIwFBAUYNCBohAAsjAUUEQCAARSIADQQgAkEBcmdBAnYiAEEHcyEEIAIhAQwGCwsjAUUEQEH0ACEEDAULCyMBRQRAQfIAIQQMBAsLIwFFBEBB7gAhBAwDCwsgAUGBgMQAIwEbIQEgBkECRkEBIwEbBEAgAhBlQQIjAUEBRg0EGiEACyMBRQRAIAANASACQQFyZ0ECdiIAQQdzIQQgAiEBDAILCyAEIAIjARshBAsgBUEFIwEbIQUDQCMBRQRAIAUhCUHcACECIAEiAEGAgMQAayIBQQMgAUEDSRshCkGBgMQAIQELAkACQAJAIwFFBEACQAJAAkAgCkEBaw4DAQYAAgtBACEFQf0AIQIgACEBAkACQAJAIAlB/wFxIgpBAWsOBQgGAAECBAtBAiEFQfsAIQIMBgtBAyEFQfUAIQIMBQtBBCEFQdwAIQIMBAtBgIDEACEBIAkhBSAEIgJBgIDEAEciAA0ECwsgBkEDRkEBIwEbBEAgB0EnIAgRAABBAyMBQQFGDQYaIQILIwFFDQQLIwFFBEAgCUEBIAQbIQVBMEHXACAAIARBAnR2QQ9xIgFBCkkbIQIgASACaiECIARBAWsiAUEAIAQbIQQLCyABIAAjARshAQsgBkEERkEBIwEbBEAgByACIAgRAABBBCMBQQFGDQMaIQALIwFFBEAgAEUiAA0BCwsjAUUEQEEBDwsLIwFFBEAgAg8LAAshAyMCKAIAIAM2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAyAANgIAIAMgATYCBCADIAI2AgggAyAENgIMIAMgBTYCECADIAo2AhQgAyAHNgIYIAMgCDYCHCADIAk2AiAjAiMCKAIAQSRqNgIAQQAL/gUBB38jAUECRgRAIwIjAigCAEE0azYCACMCKAIAIgkoAgAhACAJKAIEIQEgCSgCCCECIAkoAgwhAyAJKAIQIQQgCSgCFCEFIAkoAhghBiAJKAIcIQcgCSgCICEKIAkoAiQhCyAJKAIoIQwgCSgCLCENIAkoAjAhCQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhCAsjAUUEQCMAIg1BEGsiCyQAIARBAnYiCUEBaiEMQXwhByAFIQoLAkACQAJAA0AgDCAMQQFrIwEbIQwCQCMBRQRAIAwNASAJQQNsIgcgBk0iDQ0DCyAIRUEBIwEbBEAgByAGQeSjBBD2AUEAIwFBAUYNBhoLIwFFBEAACwsjAUUEQCALQQhqIAIgAyAHakEEakEEIApBAxCFASAKQQNqIQogB0EEaiINIQcgCygCCEUNAQsLIwFFBEAgDSALKAIMaiEHDAILCyMBRQRAIAsgAiAEQXxxIgogA2ogBEEDcSAFIAdqIAYgB2sQhQEgCygCAARAIAogCygCBGohBwwCCwJAAkACQCABRQ0AIARBBmwiCkEGcUUNACAEQQFrIQcgBEUNAiACIAMgB2otAABqLQAAQX8gCkEGcXRBf3NxDQELIABBBDoADCAAIAY2AgAMBAsgAEECOgAMIAAgBzYCCCAAIAdBfHE2AgAgACAHQQJ2QQNsNgIEDAMLCyAIQQFGQQEjARsEQCAHQQBB9KMEEKYBQQEjAUEBRg0DGgsjAUUEQAALCyMBRQRAIABBAToADCAAIAc2AgggACAHQXxxNgIAIAAgB0ECdkEDbDYCBAsLIwFFBEAgC0EQaiQACw8LIQgjAigCACAINgIAIwIjAigCAEEEajYCACMCKAIAIgggADYCACAIIAE2AgQgCCACNgIIIAggAzYCDCAIIAQ2AhAgCCAFNgIUIAggBjYCGCAIIAc2AhwgCCAKNgIgIAggCzYCJCAIIAw2AiggCCANNgIsIAggCTYCMCMCIwIoAgBBNGo2AgAL/gUBB38jAUECRgRAIwIjAigCAEE0azYCACMCKAIAIgkoAgAhACAJKAIEIQEgCSgCCCECIAkoAgwhAyAJKAIQIQQgCSgCFCEFIAkoAhghBiAJKAIcIQcgCSgCICEKIAkoAiQhCyAJKAIoIQwgCSgCLCENIAkoAjAhCQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhCAsjAUUEQCMAIg1BEGsiCyQAIARBA3YiCUEBaiEMQXghByAFIQoLAkACQAJAA0AgDCAMQQFrIwEbIQwCQCMBRQRAIAwNASAJQQVsIgcgBk0iDQ0DCyAIRUEBIwEbBEAgByAGQeSjBBD2AUEAIwFBAUYNBhoLIwFFBEAACwsjAUUEQCALQQhqIAIgAyAHakEIakEIIApBBRCGASAKQQVqIQogB0EIaiINIQcgCygCCEUNAQsLIwFFBEAgDSALKAIMaiEHDAILCyMBRQRAIAsgAiAEQXhxIgogA2ogBEEHcSAFIAdqIAYgB2sQhgEgCygCAARAIAogCygCBGohBwwCCwJAAkACQCABRQ0AIARBBWwiCkEHcUUNACAEQQFrIQcgBEUNAiACIAMgB2otAABqLQAAQX8gCkEHcXRBf3NxDQELIABBBDoADCAAIAY2AgAMBAsgAEECOgAMIAAgBzYCCCAAIAdBeHE2AgAgACAHQQN2QQVsNgIEDAMLCyAIQQFGQQEjARsEQCAHQQBB9KMEEKYBQQEjAUEBRg0DGgsjAUUEQAALCyMBRQRAIABBAToADCAAIAc2AgggACAHQXhxNgIAIAAgB0EDdkEFbDYCBAsLIwFFBEAgC0EQaiQACw8LIQgjAigCACAINgIAIwIjAigCAEEEajYCACMCKAIAIgggADYCACAIIAE2AgQgCCACNgIIIAggAzYCDCAIIAQ2AhAgCCAFNgIUIAggBjYCGCAIIAc2AhwgCCAKNgIgIAggCzYCJCAIIAw2AiggCCANNgIsIAggCTYCMCMCIwIoAgBBNGo2AgAL/gUBB38jAUECRgRAIwIjAigCAEE0azYCACMCKAIAIgkoAgAhACAJKAIEIQEgCSgCCCECIAkoAgwhAyAJKAIQIQQgCSgCFCEFIAkoAhghBiAJKAIcIQcgCSgCICEKIAkoAiQhCyAJKAIoIQwgCSgCLCENIAkoAjAhCQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhCAsjAUUEQCMAIg1BEGsiCyQAIARBA3YiCUEBaiEMQXghByAFIQoLAkACQAJAA0AgDCAMQQFrIwEbIQwCQCMBRQRAIAwNASAJQQNsIgcgBk0iDQ0DCyAIRUEBIwEbBEAgByAGQeSjBBD2AUEAIwFBAUYNBhoLIwFFBEAACwsjAUUEQCALQQhqIAIgAyAHakEIakEIIApBAxCHASAKQQNqIQogB0EIaiINIQcgCygCCEUNAQsLIwFFBEAgDSALKAIMaiEHDAILCyMBRQRAIAsgAiAEQXhxIgogA2ogBEEHcSAFIAdqIAYgB2sQhwEgCygCAARAIAogCygCBGohBwwCCwJAAkACQCABRQ0AIARBA2wiCkEHcUUNACAEQQFrIQcgBEUNAiACIAMgB2otAABqLQAAQX8gCkEHcXRBf3NxDQELIABBBDoADCAAIAY2AgAMBAsgAEECOgAMIAAgBzYCCCAAIAdBeHE2AgAgACAHQQN2QQNsNgIEDAMLCyAIQQFGQQEjARsEQCAHQQBB9KMEEKYBQQEjAUEBRg0DGgsjAUUEQAALCyMBRQRAIABBAToADCAAIAc2AgggACAHQXhxNgIAIAAgB0EDdkEDbDYCBAsLIwFFBEAgC0EQaiQACw8LIQgjAigCACAINgIAIwIjAigCAEEEajYCACMCKAIAIgggADYCACAIIAE2AgQgCCACNgIIIAggAzYCDCAIIAQ2AhAgCCAFNgIUIAggBjYCGCAIIAc2AhwgCCAKNgIgIAggCzYCJCAIIAw2AiggCCANNgIsIAggCTYCMCMCIwIoAgBBNGo2AgALgAYBBH8jAUECRgRAIwIjAigCAEEQazYCACMCKAIAIgEoAgAhACABKAIIIQIgASgCDCEDIAEoAgQhAQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBQsjAUUEQCMAQUBqIgIkACAALQAAIQMLAkACQAJAAkACQCMBRQRAAkAgA0EBaw4DAgMEAAsgAiAAKAIENgIEQRQQDyIARQ0FIABBEGpBya8FKAAANgAAIABBCGpBwa8FKQAANwAAIABBua8FKQAANwAAIAJClICAgMACNwIMIAIgADYCCCACQSRqQRA2AgAgAkERNgIcIAFBHGooAgAhACACIAJBBGo2AiAgAiACQQhqNgIYIAEoAhghASACQQI2AjwgAkIDNwIsIAJB3KoFNgIoIAIgAkEYajYCOCACQShqIQMLIAVFQQEjARsEQCABIAAgAxArQQAjAUEBRg0GGiEACyMBRQRAIAIoAgxFDQQgAigCCBAhDAQLCyMBRQRAIAAtAAEhACACQQs2AgwgAiAAQSBzQT9xQQJ0IgBBuLQFaigCADYCHCACIABBuLYFaigCADYCGCABQRxqKAIAIQAgAiACQRhqNgIIIAEoAhghASACQQE2AjwgAkIBNwIsIAJBkKQFNgIoIAIgAkEIajYCOCACQShqIQMLIAVBAUZBASMBGwRAIAEgACADECtBASMBQQFGDQUaIQALIwFFDQILIwFFBEAgACgCBCIAKAIAIQMgACgCBCEACyAFQQJGQQEjARsEQCABIAMgABATQQIjAUEBRg0EGiEACyMBRQ0BCyMBRQRAIAAoAgQiACgCACEDIABBBGooAgAoAhAhAAsgBUEDRkEBIwEbBEAgAyABIAARAABBAyMBQQFGDQMaIQALCyMBRQRAIAJBQGskACAADwsLIwFFBEAACwALIQQjAigCACAENgIAIwIjAigCAEEEajYCACMCKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIAQgAzYCDCMCIwIoAgBBEGo2AgBBAAvtBAIHfwN+IwFBAkYEQCMCIwIoAgBBGGs2AgAjAigCACICKAIAIQAgAigCBCEDIAIoAgwhBCACKQIQIQkgAigCCCECCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEICyMBRQRAIwBBEGsiBCQAIARBCGpBADsBACAEQgA3AwAgASgCACEGIAEoAgQhA0EAIQILAkACfwJAIwFFBEADQCACIQUgAkEKRgRAIABBATYCACAAQoQCNwIEDAULIANBAEchAiAEIAVqIQcgA0UEQCAHIAYgAhD/ARogAUEANgIEIAEgAiAGajYCACAAQoHKADcCBAwDCyAHIAYsAAAiBzoAACABIAMgAmsiAzYCBCABIAIgBmoiBjYCACAFQQFqIQIgB0EASA0AC0EAIQMgBUEBaiEFCwJAIwFFBEACQANAIAMgBUYEQEIEIQkMBAsgCyADIARqLQAAIgFB/wBxrSAKhiIJhCELIAFBGHRBGHUiAUF/Sg0BIANBAWohAyAKQgd8IgpCxgBSDQALQoQCIQkMAgsgAUUEQEKEBCEJIApCAFINAgsgAiADQQFqIgNPBEAgACALNwMIQQAMBAsLIAhFQQEjARsEQCADIAJBwIEFEPYBQQAjAUEBRg0FGgsjAUUEQAALCyMBRQRAIAAgCTcCBAsLIANBASMBGwshASMBRQRAIAAgATYCAAsLIwFFBEAgBEEQaiQACw8LIQEjAigCACABNgIAIwIjAigCAEEEajYCACMCKAIAIgEgADYCACABIAM2AgQgASACNgIIIAEgBDYCDCABIAk3AhAjAiMCKAIAQRhqNgIAC4UGAQx/IwFBAkYEQCMCIwIoAgBBNGs2AgAjAigCACIDKAIAIQAgAygCCCECIAMoAgwhBCADKAIQIQUgAygCFCEGIAMoAhghByADKAIcIQggAygCICEJIAMoAiQhCiADKAIoIQsgAygCLCEMIAMoAjAhDSADKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQ4LIwFFBEAjAEEQayIEJAAgAUEUagJ/QQAgASgCACIFQQJGIgcNABogAkUEQCABKAIEQQAgBRsMAQsgAUEMaigCAEEAIAEoAggiBRsLIQooAgAhCyABKAIQIQwgAS0AMCEIIARBADYCCCAEQgQ3AwAgAUEgaigCACEGIAEoAhghAgsgDkVBASMBGwRAIARBABB0QQAjAUEBRg0BGgsjAUUEQCAEKAIAIgkgBCgCCCIDQQN0aiIHIAY2AgQgByACNgIAIAQgA0EBaiICNgIIIAEoAiQiBkUhBQsCQCMBRQRAIAUNASABQSxqKAIAIgFFIgUNASAGIAFBDGwiAWohBwsDQCMBRQRAIAYoAgAhBSAEKAIEIAJHIQ0gBkEIaigCACEBCyMBRUEAIA0bRQRAIA5BAUZBASMBGwRAIAQgAhB0QQEjAUEBRg0EGgsjAQR/IAIFIAQoAgAhCSAEKAIICyECCyMBRQRAIAkgAkEDdGoiAiABNgIEIAIgBTYCACAEIAQoAghBAWoiAjYCCCAHIAZBDGoiBkciAQ0BCwsLIwFFBEAgACAEKQMANwIIIAAgCjYCBCAAIAtBiCcgDBs2AgAgAEEQaiAEQQhqKAIANgIAIAAgCCAIQQJHcToAFCAEQRBqJAALDwshAyMCKAIAIAM2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAyAANgIAIAMgATYCBCADIAI2AgggAyAENgIMIAMgBTYCECADIAY2AhQgAyAHNgIYIAMgCDYCHCADIAk2AiAgAyAKNgIkIAMgCzYCKCADIAw2AiwgAyANNgIwIwIjAigCAEE0ajYCAAvKCAIJfwJ+IwFBAkYEQCMCIwIoAgBBMGs2AgAjAigCACIBKAIAIQAgASgCCCECIAEoAgwhAyABKAIQIQQgASgCFCEHIAEoAhghCCABKAIcIQYgASgCICEJIAEoAiQhCiABKAIoIQwgASgCLCENIAEoAgQhAQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhCwsjAUUEQCMAQUBqIggkACAALQAEIQdBASEJCwJAIwFFBEAgBw0BIAAoAgAiBygCACIKQQRxIQkgAC0ABSEGCwJAIwFFBEAgCQ0BQQEhCSAHKAIYIQpByYUEQcuFBCAGQf8BcSIGGyEMIAdBHGooAgAoAgwhDUECQQMgBhshBgsgC0VBASMBGwRAIAogDCAGIA0RAQBBACMBQQFGDQMaIQYLIwFFBEAgBg0CQQEhCSAHKAIcKAIMIQogBygCGCEGCyALQQFGQQEjARsEQCAGIAEgAiAKEQEAQQEjAUEBRg0DGiEBCyMBRQRAIAENAkEBIQkgBygCHCgCDCECIAcoAhghAQsgC0ECRkEBIwEbBEAgAUGzqwVBAiACEQEAQQIjAUEBRg0DGiEBCyMBRQRAIAENAiAEKAIMIQELIAtBA0ZBASMBGwRAIAMgByABEQAAQQMjAUEBRg0DGiEJCyMBRQ0BCyAJIAZB/wFxIwEbIQkCQCMBRQRAIAkNAUEBIQkgB0EcaigCACgCDCEKIAcoAhghBgsgC0EERkEBIwEbBEAgBkHEhQRBAyAKEQEAQQQjAUEBRg0DGiEGCyMBRQRAIAYNAiAHKAIAIQoLCyMBRQRAIAhBAToAFyAIQTRqQaiFBDYCACAIIAo2AhggCCAHKQIYNwMIIAggCEEXajYCECAHKQIIIQ4gBykCECEPIAggBy0AIDoAOCAIIAcoAgQ2AhwgCCAPNwMoIAggDjcDICAIIAhBCGo2AjAgCEEIaiEHQQEhCQsgC0EFRkEBIwEbBEAgByABIAIQLEEFIwFBAUYNAhohAQsjAUUEQCABDQEgCEEIaiEBCyALQQZGQQEjARsEQCABQbOrBUECECxBBiMBQQFGDQIaIQELIwFFBEAgAQ0BIAQoAgwhAiAIQRhqIQELIAtBB0ZBASMBGwRAIAMgASACEQAAQQcjAUEBRg0CGiEBCyMBRQRAIAENASAIKAI0KAIMIQIgCCgCMCEBCyALQQhGQQEjARsEQCABQceFBEECIAIRAQBBCCMBQQFGDQIaIQkLCyMBRQRAIABBAToABSAAIAk6AAQgCEFAayQAIAAPCwALIQUjAigCACAFNgIAIwIjAigCAEEEajYCACMCKAIAIgUgADYCACAFIAE2AgQgBSACNgIIIAUgAzYCDCAFIAQ2AhAgBSAHNgIUIAUgCDYCGCAFIAY2AhwgBSAJNgIgIAUgCjYCJCAFIAw2AiggBSANNgIsIwIjAigCAEEwajYCAEEAC5UFAQd/IwFBAkYEQCMCIwIoAgBBHGs2AgAjAigCACIHKAIAIQAgBygCBCECIAcoAgghBCAHKAIMIQUgBygCECEGIAcoAhQhCCAHKAIYIQcLAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQkLIwFFBEAgAkEBdCABaiELIABBgP4DcSICQQh2IQwgAEH/AXEhDUEAIQgLAkACQAJAIwFFBEADQCABQQJqIQogCCABLQABIgJqIQcgAS0AACIBIAxHBEAgASAMSw0EIAchCCALIAoiAUciAg0BDAQLIAcgCE8EQCAEIAdJDQMgAyAIaiEBAkADQCACRQ0BIAJBAWshAiABLQAAIAFBAWohASANRw0AC0EAIQIMBgsgByEIIAsgCiIBRyICDQEMBAsLCyAJRUEBIwEbBEAgCCAHQbyQBBD4AUEAIwFBAUYNBBoLIwFFBEAACwsgCUEBRkEBIwEbBEAgByAEQbyQBBD3AUEBIwFBAUYNAxoLIwFFBEAACwsjAUUEQCAAQf//A3EhCCAFIAZqIQdBASECAkADQAJ/IAVBAWoiAyAFLQAAIgFBGHRBGHUiCkEATg0AGiADIAdGDQIgBS0AASAKQf8AcUEIdHIhASAFQQJqCyEFIAggAWsiCEEASA0DIAJBAXMhAiAFIAdHDQALDAILCyAJQQJGQQEjARsEQEHMogVBK0HMkAQQvwFBAiMBQQFGDQIaCyMBRQRAAAsLIwFFBEAgAkEBcQ8LAAshASMCKAIAIAE2AgAjAiMCKAIAQQRqNgIAIwIoAgAiASAANgIAIAEgAjYCBCABIAQ2AgggASAFNgIMIAEgBjYCECABIAg2AhQgASAHNgIYIwIjAigCAEEcajYCAEEAC/YFAQh/IwFBAkYEQCMCIwIoAgBBIGs2AgAjAigCACIDKAIAIQAgAygCCCECIAMoAgwhBCADKAIQIQUgAygCFCEGIAMoAhghByADKAIcIQggAygCBCEBCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEJCyMBRQRAIwAiBEEgayIFJAALAkACQAJAIwFFBEAgAkUEQCAAQQA2AgggAEIBNwIADAILIAFBDGohCCACQQxsIgpBDGtBDG4hByABIQQLAkAjAUUEQANAIApFIgYNAiAKQQxrIQogByAHIAQoAghqIgZNIARBDGohBCAGIQcNAAsLIAlFQQEjARsEQEGEmwVBNUGUnAUQ8wFBACMBQQFGDQUaCyMBRQRAAAsLIAQgBUEIaiMBGyEEIAlBAUZBASMBGwRAIAQgBxDRAUEBIwFBAUYNBBoLIwFFBEAgBUEANgIYIAUgBSkDCDcDECAFQRBqIQQgASgCACEGIAEoAgghAQsgCUECRkEBIwEbBEAgBCAGIAEQxAFBAiMBQQFGDQQaCyMBRQRAIAcgBSgCGCIDayEEIAJBAUcEQCADIAUoAhBqIQYgAkEMbEEMayEKA0AgBEUNBCAGQQo6AAAgBEEBayIEIAhBCGooAgAiA0kNBSAEIANrIQQgBkEBaiAIKAIAIAMQ/wEgA2ohBiAIQQxqIQggCkEMayIKDQALCyAAIAUpAxA3AgAgAEEIaiAHIARrNgIACwsjAUUEQCAFQSBqJAAPCwsgCUEDRkEBIwEbBEBBiaIFQSNBhJwFEL8BQQMjAUEBRg0CGgsjAUUEQAALCyAJQQRGQQEjARsEQEGJogVBI0GEnAUQvwFBBCMBQQFGDQEaCyMBRQRAAAsPCyEDIwIoAgAgAzYCACMCIwIoAgBBBGo2AgAjAigCACIDIAA2AgAgAyABNgIEIAMgAjYCCCADIAQ2AgwgAyAFNgIQIAMgBjYCFCADIAc2AhggAyAINgIcIwIjAigCAEEgajYCAAv/BwELfyMBQQJGBEAjAiMCKAIAQRRrNgIAIwIoAgAiAigCACEAIAIoAgghAyACKAIMIQQgAigCECEHIAIoAgQhAQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhCQsjAUUEQCMAQRBrIgQkACABQYABSSEDIAAoAgAhAAsCQAJ/AkAjAUVBACMBRQRAIANFBEAgBEEANgIMIAFBgBBPIgMNAiAEIAFBP3FBgAFyIgM6AA0gBCABQQZ2QcABcjoADEECDAMLIAAoAggiAyAAKAIERyEHCyAHG0UEQCAJRUEBIwEbBEAgACEIIAMhAiMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBQsCQAJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEKCyMBRQRAIwBBIGsiBSQAIAJBAWoiAkUhBgsCQCMBRQRAAkAgBg0AIAhBBGooAgAiC0EBdCIMIAJLIQYgDCACIAYbIgZBCEshAiAGQQggAhsiBkF/c0EfdiECAkAgCwRAIAVBATYCGCAFIAs2AhQgBSAIKAIANgIQDAELIAVBADYCGAsgBSAGIAIgBUEQahCTASAFKAIARQRAIAggBSgCBDYCACAIQQRqIAY2AgAMAwsgBUEIaigCACICQYGAgIB4Rg0CIAJFDQAACwsgCkVBASMBGwRAENMBQQAjAUEBRg0CGgsjAUUEQAALCyMBRQRAIAVBIGokAAsMAQshAiMCKAIAIAI2AgAjAiMCKAIAQQRqNgIAIwIoAgAgBTYCACMCIwIoAgBBBGo2AgALQQAjAUEBRg0FGgsjAQR/IAMFIAAoAggLIQMLIwFFBEAgACADQQFqNgIIIAMgACgCAGogAToAAAwDCwsjAQR/IAEFIAFBgIAETwRAIAQgAUE/cUGAAXI6AA8gBCABQQZ2QT9xQYABcjoADiAEIAFBDHZBP3FBgAFyIgM6AA0gBCABQRJ2QQdxQfABcjoADEEEDAILIAQgAUE/cUGAAXI6AA4gBCABQQx2QeABciIDOgAMIAQgAUEGdkE/cUGAAXI6AA1BAwsLIQEjAUVBACMBBH8gBwUgAEEEaigCACAAKAIIIgNrIAFPCxtFBEAgCUEBRkEBIwEbBEAgACADIAEQekEBIwFBAUYNAxoLIwEEfyADBSAAKAIICyEDCyMBRQRAIAAoAgAgA2ogBEEMaiABEP8BGiAAIAEgA2o2AggLCyMBRQRAIARBEGokAEEADwsACyECIwIoAgAgAjYCACMCIwIoAgBBBGo2AgAjAigCACICIAA2AgAgAiABNgIEIAIgAzYCCCACIAQ2AgwgAiAHNgIQIwIjAigCAEEUajYCAEEAC9EEAQJ/IwFBAkYEQCMCIwIoAgBBEGs2AgAjAigCACIGKAIAIQAgBigCBCEEIAYoAgghBSAGKAIMIQYLAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQcLIwFFBEAjAEHwAGsiBiQAIAYgATYCDCAGIAA2AgggBiADNgIUIAYgAjYCECAGQQI2AhwgBkGYhAQ2AhggBCgCACEACwJAIwFFBEAgAA0BIAZBzABqQQo2AgAgBkHEAGpBCjYCACAGQewAakEDNgIAIAZCBDcCXCAGQfiEBDYCWCAGQQs2AjwgBiAGQThqNgJoIAYgBkEQajYCSCAGIAZBCGo2AkAgBiAGQRhqNgI4IAZB2ABqIQALIAdFQQEjARsEQCAAIAUQ0gFBACMBQQFGDQIaCyMBRQRAAAsLIwFFBEAgBkEwaiAEQRBqKQIANwMAIAZBKGogBEEIaikCADcDACAGIAQpAgA3AyAgBkHsAGpBBDYCACAGQdQAakEMNgIAIAZBzABqQQo2AgAgBkHEAGpBCjYCACAGQgQ3AlwgBkHUhAQ2AlggBkELNgI8IAYgBkE4ajYCaCAGIAZBIGo2AlAgBiAGQRBqNgJIIAYgBkEIajYCQCAGIAZBGGo2AjggBkHYAGohAAsgB0EBRkEBIwEbBEAgACAFENIBQQEjAUEBRg0BGgsjAUUEQAALDwshASMCKAIAIAE2AgAjAiMCKAIAQQRqNgIAIwIoAgAiASAANgIAIAEgBDYCBCABIAU2AgggASAGNgIMIwIjAigCAEEQajYCAAuSBQIFfwF+IwFBAkYEQCMCIwIoAgBBIGs2AgAjAigCACIBKAIAIQAgASgCCCECIAEoAgwhAyABKAIQIQUgASgCFCEGIAEpAhghByABKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQQLIwFFBEAjAEEQayICJAAgAkEANgIEAn8gAUGAAU8EQCABQYAQTwRAIAFBgIAETwRAIAIgAUE/cUGAAXI6AAcgAiABQQZ2QT9xQYABcjoABiACIAFBDHZBP3FBgAFyOgAFIAIgAUESdkEHcUHwAXI6AARBBAwDCyACIAFBP3FBgAFyOgAGIAIgAUEMdkHgAXI6AAQgAiABQQZ2QT9xQYABcjoABUEDDAILIAIgAUE/cUGAAXI6AAUgAiABQQZ2QcABcjoABEECDAELIAIgAToABEEBCyEBIAAoAgAoAgAhBiACQQRqIQUgAkEIaiEDCyAERUEBIwEbBEAgAyAGIAUgARAdQQAjAUEBRg0BGgsjAUUEQCACLQAIIgFBBEYhAwsCQCMBRQRAIAMNASACKQMIIQcgAC0ABEEDRyEDCwJAIwFFBEAgAw0BIABBCGooAgAiAygCACEGIAMoAgQoAgAhBQsgBEEBRkEBIwEbBEAgBiAFEQQAQQEjAUEBRg0DGgsjAUUEQCADKAIEQQRqKAIABEAgAygCABAhCyADECELCyMBRQRAIAAgBzcCBAsLIwFFBEAgAkEQaiQAIAFBBEcPCwALIQQjAigCACAENgIAIwIjAigCAEEEajYCACMCKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIAQgAzYCDCAEIAU2AhAgBCAGNgIUIAQgBzcCGCMCIwIoAgBBIGo2AgBBAAudBAIGfwF+IwFBAkYEQCMCIwIoAgBBFGs2AgAjAigCACICKAIAIQEgAigCCCEDIAIoAgwhBCACKAIQIQYgAigCBCECCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEFCyMBRQRAIwBBMGsiBiQAQSchAwJAIABCkM4AVARAIAAhCQwBCwNAIAMgBkEJamoiBEEEayAAQpDOAIAiCULwsQN+IAB8pyIHQf//A3FB5ABuIghBAXRBioYEai8AADsAACAEQQJrIAhBnH9sIAdqQf//A3FBAXRBioYEai8AADsAACADQQRrIQMgAEL/wdcvViAJIQANAAsLIAmnIgRB4wBLBEAgCaciB0H//wNxQeQAbiIEQZx/bCEIIANBAmsiAyAGQQlqaiAHIAhqQf//A3FBAXRBioYEai8AADsAAAsCQCAEQQpPBEAgA0ECayIDIAZBCWpqIARBAXRBioYEai8AADsAAAwBCyADQQFrIgMgBkEJamogBEEwajoAAAsgAyAGQQlqaiEEQScgA2shAwsgBUVBASMBGwRAIAIgAUHooQVBACAEIAMQKEEAIwFBAUYNARohAwsjAUUEQCAGQTBqJAAgAw8LAAshBSMCKAIAIAU2AgAjAiMCKAIAQQRqNgIAIwIoAgAiBSABNgIAIAUgAjYCBCAFIAM2AgggBSAENgIMIAUgBjYCECMCIwIoAgBBFGo2AgBBAAvHBQIEfwF+IwFBAkYEQCMCIwIoAgBBFGs2AgAjAigCACIEKAIAIQAgBCgCBCEBIAQoAgghAiAEKAIMIQMgBCgCECEECwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEFCyMBRQRAIwBB8ABrIgMkAEEbEA8iBEUhBgsCQCMBRQRAIAYNASAEQRdqQbucBSgAADYAACAEQRBqQbScBSkAADcAACAEQQhqQaycBSkAADcAACAEQaScBSkAADcAAEEuEA8iBkUNASAGQYn0BEEuEP8BIQYgBBAhIANBADYCXCADQgQ3AlQgA0KugICA4AU3AkwgAyAGNgJIIANByABqIQQLIAVFQQEjARsEQCADIAEgAiAEEFtBACMBQQFGDQIaCyAEIANBMGojARshBCAFQQFGQQEjARsEQCAEQeCABUEDQcH0BEEHQdWEBUEXEG1BASMBQQFGDQIaCyABIANByABqIwEbIQEgBUECRkEBIwEbBEAgASADEBxBAiMBQQFGDQIaCyMBRQRAIAMoAkgiAkEWRyEBCwJAAkAjAUUEQCABDQEgA0HoAGogA0HUAGooAgAiAjYCACADIAMpAkwiBzcDYCAAQQxqIAI2AgAgACAHNwIEIANByABqIQELIAVBA0ZBASMBGwRAIAEgBBA8QQMjAUEBRg0EGgsjAUUEQCADKAJMBEAgAygCSBAhCyAAQRY2AgAMAgsLIwFFBEAgA0HoAGogA0HUAGooAgAiATYCACADIAMpAkwiBzcDYCAAQQxqIAE2AgAgACAHNwIEIAAgAjYCAAsLIwFFBEAgAxDZASADQfAAaiQADwsLIwFFBEAACw8LIQUjAigCACAFNgIAIwIjAigCAEEEajYCACMCKAIAIgUgADYCACAFIAE2AgQgBSACNgIIIAUgAzYCDCAFIAQ2AhAjAiMCKAIAQRRqNgIAC98GAgV/An4jAUECRgRAIwIjAigCAEEsazYCACMCKAIAIgEoAgAhACABKAIIIQIgASgCDCEDIAEoAhAhBCABKAIUIQUgASgCGCEHIAEoAhwhCCABKQIgIQogASgCKCEJIAEoAgQhAQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBgsjAUUEQCMAQRBrIgIkAEHouAVB6LgFKAIAIgdBAWo2AgAgB0EASCEFCwJAAkAjAUUEQCAFDQFByLwFQci8BSgCAEEBaiIHNgIAIAdBAksNAUHcuAUoAgAiBUF/TA0BQdy4BSAFQQFqIgU2AgBB5LgFKAIARSEICwJAIwFFBEAgCA0BIAJBCGohBSABKAIQIQELIAZFQQEjARsEQCAFIAAgARECAEEAIwFBAUYNBBoLIwFFBEAgAigCCCEBIAIoAgwoAgwhAAsgBSAGQQFGQQEjARsEQCABIAARCgBBASMBQQFGDQQaIQoLIApCgNK7kPDIiIgsUiMBGyEFAkACQAJ/IwFFBEAgAUEIaiAFRQ0BGgsgBkECRkEBIwEbBEAgASAAEQoAQQIjAUEBRg0HGiEKCyMBBH8gAAUgCkLrkZO19tizovQAUiIADQIgAUEEagsLIQAjAUUEQCABKAIAIQUgACgCACEBCyAGQQNGQQEjARsEQCACIAEQ0QFBAyMBQQFGDQYaCyMBRQRAIAIoAgQhACACKAIAIgIgBSABEP8BIQUMAgsLIwFFBEBBDSEAQQ0QDyICRQ0DIAJBBWpBqZYFKQAANwAAIAJBpJYFKQAANwAAQQ0hAQsLIwFFBEAgAygCACEFIAMoAgQhCCADKAIIIQkgAygCDCEDCyAGQQRGQQEjARsEQCACIAEgBSAIIAkgAxAMQQQjAUEBRg0EGgsjAUUEQCAABEAgAhAhC0HcuAUoAgAhBQsLIwFFBEBB3LgFIAVBAWs2AgAgB0EBSw0BIAQNAgsLIwFFBEAACwsjAUUEQAALDwshBiMCKAIAIAY2AgAjAiMCKAIAQQRqNgIAIwIoAgAiBiAANgIAIAYgATYCBCAGIAI2AgggBiADNgIMIAYgBDYCECAGIAU2AhQgBiAHNgIYIAYgCDYCHCAGIAo3AiAgBiAJNgIoIwIjAigCAEEsajYCAAunBQIFfwF+IwFBAkYEQCMCIwIoAgBBGGs2AgAjAigCACIBKAIAIQAgASgCCCECIAEoAgwhBCABKQIQIQcgASgCBCEBCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEFCyMBRQRAIwBBgAFrIgQkACABKAIAIgJBEHEhAwsCQAJAAkACQAJAIwFFBEAgAw0BIAJBIHEiAg0CIACtIQcLIAVFQQEjARsEQCAHQQEgARBSQQAjAUEBRg0GGiEACyMBRQ0ECyMBRQRAQQAhAgNAQTBB1wAgAEEPcSIDQQpJGyEGIAIgBGpB/wBqIAMgBmo6AAAgAkEBayECIABBD0sgAEEEdiEADQALIAJBgAFqIgBBgQFPDQIgAiAEakGAAWohAEEAIAJrIQILIAVBAUZBASMBGwRAIAFBAUGIhgRBAiAAIAIQKEEBIwFBAUYNBRohAAsjAUUNAwsjAUUEQEEAIQIDQEEwQTcgAEEPcSIDQQpJGyEGIAIgBGpB/wBqIAMgBmo6AAAgAkEBayECIABBD0sgAEEEdiEADQALIAJBgAFqIgBBgQFPDQIgAiAEakGAAWohAEEAIAJrIQILIAVBAkZBASMBGwRAIAFBAUGIhgRBAiAAIAIQKEECIwFBAUYNBBohAAsjAUUNAgsgBUEDRkEBIwEbBEAgAEGAAUH4hQQQ9gFBAyMBQQFGDQMaCyMBRQRAAAsLIAVBBEZBASMBGwRAIABBgAFB+IUEEPYBQQQjAUEBRg0CGgsjAUUEQAALCyMBRQRAIARBgAFqJAAgAA8LAAshAyMCKAIAIAM2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAyAANgIAIAMgATYCBCADIAI2AgggAyAENgIMIAMgBzcCECMCIwIoAgBBGGo2AgBBAAutAgEEf0EfIQIgAEIANwIQIAFB////B00EQCABQQYgAUEIdmciA2t2QQFxIANBAXRrQT5qIQILIAAgAjYCHCACQQJ0QYi7BWohBAJAAkACQAJAQfy4BSgCACIFQQEgAnQiA3EEQCAEKAIAIgMoAgRBeHEgAUcNASADIQIMAgtB/LgFIAMgBXI2AgAgBCAANgIAIAAgBDYCGAwDCyABQQBBGSACQQF2a0EfcSACQR9GG3QhBANAIAMgBEEddkEEcWpBEGoiBSgCACICRQ0CIARBAXQhBCACIQMgAigCBEF4cSABRw0ACwsgAigCCCIBIAA2AgwgAiAANgIIIABBADYCGCAAIAI2AgwgACABNgIIDwsgBSAANgIAIAAgAzYCGAsgACAANgIMIAAgADYCCAuwAgEFfyAAKAIYIQQCQAJAIAAgACgCDCIBRgRAQRRBECAAQRRqIgEoAgAiAxsgAGooAgAiAg0BQQAhAQwCCyAAKAIIIgIgATYCDCABIAI2AggMAQsgASAAQRBqIAMbIQMDQCADIQUgAiIBQRRqIgMoAgAiAkUEQCABQRBqIQMgASgCECECCyACDQALIAVBADYCAAsCQCAERQ0AAkAgACAAKAIcQQJ0QYi7BWoiAigCAEcEQCAEQRBBFCAEKAIQIABGG2ogATYCACABDQEMAgsgAiABNgIAIAENAEH8uAVB/LgFKAIAQX4gACgCHHdxNgIADwsgASAENgIYIAAoAhAiAgRAIAEgAjYCECACIAE2AhgLIABBFGooAgAiAEUNACABQRRqIAA2AgAgACABNgIYCwvZBQEHfyMBQQJGBEAjAiMCKAIAQRBrNgIAIwIoAgAiAygCACEAIAMoAgghAiADKAIMIQQgAygCBCEBCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEFCyMBRQRAIwBBEGsiBCQAIAFBgAFJIQIgACgCACEACwJAAn8CQCMBRUEAIwEEf0EABSACRQRAIARBADYCDCABQYAQTyICDQIgBCABQT9xQYABciICOgANIAQgAUEGdkHAAXI6AAxBAgwDCyAAKAIIIgIgACgCBEcLG0UEQCAFRUEBIwEbBEAgACEIIAIhAwJAAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQYLIwFFBEAjAEEQayIHJAAgB0EIaiAIIANBARB8AkAgBygCDCIDQYGAgIB4RwRAIANFDQEACyAHQRBqJAAMAwsLIAZFQQEjARsEQBDTAUEAIwFBAUYNARoLIwFFBEAACwwBCyEDIwIoAgAgAzYCACMCIwIoAgBBBGo2AgALQQAjAUEBRg0FGgsjAQR/IAIFIAAoAggLIQILIwFFBEAgACgCACACaiABOgAAIAAgACgCCEEBajYCCAwDCwsjAQR/IAEFIAFBgIAETwRAIAQgAUE/cUGAAXI6AA8gBCABQQZ2QT9xQYABcjoADiAEIAFBDHZBP3FBgAFyIgI6AA0gBCABQRJ2QQdxQfABcjoADEEEDAILIAQgAUE/cUGAAXI6AA4gBCABQQx2QeABciICOgAMIAQgAUEGdkE/cUGAAXI6AA1BAwsLIQEgAiAEQQxqIwEbIQIgBUEBRkEBIwEbBEAgACACIAEQxwFBASMBQQFGDQIaCwsjAUUEQCAEQRBqJABBAA8LAAshAyMCKAIAIAM2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAyAANgIAIAMgATYCBCADIAI2AgggAyAENgIMIwIjAigCAEEQajYCAEEAC4kCAAJ/AkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAgACgCAA4VAQIDBAUGBwgJCgsMDQ4PEBESExQVAAsgAEEEagwVCyAAQQRqDBQLIABBBGoMEwsgAEEEagwSCyAAQQRqDBELIABBBGoMEAsgAEEEagwPCyAAQQRqDA4LIABBBGoMDQsgAEEEagwMCyAAQQRqDAsLIABBBGoMCgsgAEEEagwJCyAAQQRqDAgLIABBBGoMBwsgAEEEagwGCyAAQQRqDAULIABBBGoMBAsgAEEEagwDCyAAQQRqDAILIABBBGoMAQsgAEEEagsoAgQEQCAAKAIEECELC7AEAQN/IwFBAkYEQCMCIwIoAgBBEGs2AgAjAigCACICKAIAIQAgAigCBCEBIAIoAgghAyACKAIMIQILAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQQLIwFFBEAjAEEQayICJAAgAUGAAUkhAwsCQAJ/AkAjAUVBACMBBH9BAAUgA0UEQCACQQA2AgwgAUGAEE8iAw0CIAIgAUE/cUGAAXIiAzoADSACIAFBBnZBwAFyOgAMQQIMAwsgACgCCCIDIAAoAgRHCxtFBEAgBEVBASMBGwRAIAAgAxC+AUEAIwFBAUYNBRoLIwEEfyADBSAAKAIICyEDCyMBRQRAIAAgA0EBajYCCCADIAAoAgBqIAE6AAAMAwsLIwEEfyABBSABQYCABE8EQCACIAFBP3FBgAFyOgAPIAIgAUEGdkE/cUGAAXI6AA4gAiABQQx2QT9xQYABciIDOgANIAIgAUESdkEHcUHwAXI6AAxBBAwCCyACIAFBP3FBgAFyOgAOIAIgAUEMdkHgAXIiAzoADCACIAFBBnZBP3FBgAFyOgANQQMLCyEBIAMgAkEMaiMBGyEDIARBAUZBASMBGwRAIAAgAyABEMQBQQEjAUEBRg0CGgsLIwFFBEAgAkEQaiQAQQAPCwALIQQjAigCACAENgIAIwIjAigCAEEEajYCACMCKAIAIgQgADYCACAEIAE2AgQgBCADNgIIIAQgAjYCDCMCIwIoAgBBEGo2AgBBAAuuBQEGfyMBQQJGBEAjAiMCKAIAQSRrNgIAIwIoAgAiBigCACEAIAYoAgQhASAGKAIIIQIgBigCDCEDIAYoAhAhBCAGKAIUIQcgBigCGCEIIAYoAhwhCSAGKAIgIQYLAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQULIwFFBEAjAEHQAGsiBCQAIARBEGohCCADKAIAIQcgA0EIaiIGKAIAIQkLIAVFQQEjARsEQCAIIAcgCRC4AUEAIwFBAUYNARoLIwFFBEAgBEEcaiEIIANBDGooAgAhByADQRRqKAIAIQkLIAVBAUZBASMBGwRAIAggByAJEF5BASMBQQFGDQEaCyMBRQRAIARBOGoiCCADQRBqKQIANwMAIARBMGoiByAGKQIANwMAIAQgAykCADcDKCAEQQhqIQMLIAVBAkZBASMBGwRAIAMgAhDRAUECIwFBAUYNARoLIwFFBEAgBCgCDCEDIAQoAgggASACEP8BIQELAkAjAUUEQCABDQEgBCACNgJEIAQgAzYCQCAEQUBrIQALIAVBA0ZBASMBGwRAQbSGBUEeIABBpIYFQciHBRCbAUEDIwFBAUYNAhoLIwFFBEAACwsjAUUEQCAAIAI2AhAgACADNgIMIAAgATYCCCAAQgA3AwAgACAEKQMoNwIYIABBIGogBykDADcCACAAQShqIAgpAwA3AgAgAEFAayAEQSBqKQMANwIAIABBOGogBEEYaikDADcCACAAIAQpAxA3AjAgBEHQAGokAAsPCyEFIwIoAgAgBTYCACMCIwIoAgBBBGo2AgAjAigCACIFIAA2AgAgBSABNgIEIAUgAjYCCCAFIAM2AgwgBSAENgIQIAUgBzYCFCAFIAg2AhggBSAJNgIcIAUgBjYCICMCIwIoAgBBJGo2AgALsAQBA38jAUECRgRAIwIjAigCAEEQazYCACMCKAIAIgIoAgAhACACKAIEIQEgAigCCCEDIAIoAgwhAgsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBAsjAUUEQCMAQRBrIgIkACABQYABSSEDCwJAAn8CQCMBRUEAIwEEf0EABSADRQRAIAJBADYCDCABQYAQTyIDDQIgAiABQT9xQYABciIDOgANIAIgAUEGdkHAAXI6AAxBAgwDCyAAKAIIIgMgACgCBEcLG0UEQCAERUEBIwEbBEAgACADEL4BQQAjAUEBRg0FGgsjAQR/IAMFIAAoAggLIQMLIwFFBEAgACADQQFqNgIIIAMgACgCAGogAToAAAwDCwsjAQR/IAEFIAFBgIAETwRAIAIgAUE/cUGAAXI6AA8gAiABQQZ2QT9xQYABcjoADiACIAFBDHZBP3FBgAFyIgM6AA0gAiABQRJ2QQdxQfABcjoADEEEDAILIAIgAUE/cUGAAXI6AA4gAiABQQx2QeABciIDOgAMIAIgAUEGdkE/cUGAAXI6AA1BAwsLIQEgAyACQQxqIwEbIQMgBEEBRkEBIwEbBEAgACADIAEQxQFBASMBQQFGDQIaCwsjAUUEQCACQRBqJABBAA8LAAshBCMCKAIAIAQ2AgAjAiMCKAIAQQRqNgIAIwIoAgAiBCAANgIAIAQgATYCBCAEIAM2AgggBCACNgIMIwIjAigCAEEQajYCAEEAC4EFAQh/IwFBAkYEQCMCIwIoAgBBIGs2AgAjAigCACIDKAIAIQAgAygCCCECIAMoAgwhBCADKAIQIQUgAygCFCEGIAMoAhghByADKAIcIQggAygCBCEBCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEKCyMBRQRAIwBBIGsiBSQAIAJBA3QhCSABQQhqIQhBACEHIAEhBAsCQCMBRQRAA0AgCUUiBg0CIAlBCGshCSAHIAcgBCgCBGoiBk0gBEEIaiEEIAYhBw0ACwsgCkVBASMBGwRAQYSbBUE1QZScBRDzAUEAIwFBAUYNAhoLIwFFBEAACwsgBCAFQQhqIwEbIQQgCkEBRkEBIwEbBEAgBCAHENEBQQEjAUEBRg0BGgsjAUUEQCAFQQA2AhggBSAFKQMINwMQIAVBEGohBCABKAIAIQYgASgCBCEBCyAKQQJGQQEjARsEQCAEIAYgARDFAUECIwFBAUYNARoLIwFFBEAgAkEDdEEIayEGIAUoAhgiBCAFKAIQaiEJIAcgBGshBAJAA0AgCEEEaigCACIDIARLDQEgBCADayEEIAkgCCgCACADEP8BIANqIQkgCEEIaiEIIAZBCGsiBg0ACyAAIAUpAxA3AgAgAEEIaiAHIARrNgIAIAVBIGokAA8LCyAKQQNGQQEjARsEQEGJogVBI0GEnAUQvwFBAyMBQQFGDQEaCyMBRQRAAAsPCyEDIwIoAgAgAzYCACMCIwIoAgBBBGo2AgAjAigCACIDIAA2AgAgAyABNgIEIAMgAjYCCCADIAQ2AgwgAyAFNgIQIAMgBjYCFCADIAc2AhggAyAINgIcIwIjAigCAEEgajYCAAu/BQEKfyMBQQJGBEAjAiMCKAIAQTBrNgIAIwIoAgAiBCgCACEAIAQoAgQhASAEKAIIIQIgBCgCDCEGIAQoAhAhByAEKAIUIQggBCgCGCEFIAQoAhwhCSAEKAIgIQogBCgCJCELIAQoAighDCAEKAIsIQQLAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQMLIwFFBEAjACIJQTBrIggkAAsCQAJAAkAjAUUEQCACRQRAIABCBDcCAAwCCyACQePxuBxLDQIgAkEkbCIHQQBIDQIgBwR/IAcQDwUgAkHk8bgcSUECdAsiCkUNAyAAIAI2AgQgACAKNgIAIAJBJGwhByAIQSBqIQsgCEEUaiEMIAIhCQsDQCMBRQRAIAdFDQIgASgCACEFIAhBCGohBiABQQhqKAIAIQQLIANFQQEjARsEQCAGIAUgBBC4AUEAIwFBAUYNBRoLIwFFBEAgAUEMaigCACEGIAFBFGooAgAhBQsgA0EBRkEBIwEbBEAgDCAGIAUQuAFBASMBQQFGDQUaCyMBRQRAIAFBGGooAgAhBiABQSBqKAIAIQULIANBAkZBASMBGwRAIAsgBiAFELgBQQIjAUEBRg0FGgsjAUUEQCAKIAhBCGoiBkEkEP8BQSRqIQogB0EkayEHIAFBJGohASAJQQFrIgkNAQsLCyMBRQRAIAAgAjYCCCAIQTBqJAAPCwsgA0EDRkEBIwEbBEAQ0wFBAyMBQQFGDQIaCyMBRQRAAAsLIwFFBEAACw8LIQMjAigCACADNgIAIwIjAigCAEEEajYCACMCKAIAIgMgADYCACADIAE2AgQgAyACNgIIIAMgBjYCDCADIAc2AhAgAyAINgIUIAMgBTYCGCADIAk2AhwgAyAKNgIgIAMgCzYCJCADIAw2AiggAyAENgIsIwIjAigCAEEwajYCAAveBAEFfyMBQQJGBEAjAiMCKAIAQRRrNgIAIwIoAgAiASgCACEAIAEoAgghAiABKAIMIQMgASgCECEFIAEoAgQhAQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBgsjAUUEQCMAQUBqIgIkACACQQA2AhAgAkIBNwMIIAEoAgBFIQUgAUEEaiEDCwJAAkACQCMBRQRAIAUNASACQQM2AhwgAiADNgIkIAIgAkEkajYCGCACQQE2AjwgAkIBNwIsIAJBkKQFNgIoIAIgAkEYajYCOCACQShqIQUgAkEIaiEDCyAGRUEBIwEbBEAgA0HEiwUgBRArQQAjAUEBRg0EGiEDCyMBRQRAIANFDQIMAwsLIwFFBEAgAkEDNgIcIAIgAzYCJCACIAJBJGo2AhggAkEBNgI8IAJCATcCLCACQZCkBTYCKCACIAJBGGo2AjggAkEoaiEFIAJBCGohAwsgBkEBRkEBIwEbBEAgA0HEiwUgBRArQQEjAUEBRg0DGiEDCyMBRUEAIAMbDQELIwFFBEAgACACKQMINwIEIABBEDYCACAAQQxqIAJBEGooAgA2AgAgAUEIaigCAARAIAEoAgQQIQsgAkFAayQADwsLIAAgAkEoaiMBGyEAIAZBAkZBASMBGwRAQfCWBUE3IABB3IsFQYSYBRCbAUECIwFBAUYNARoLIwFFBEAACw8LIQQjAigCACAENgIAIwIjAigCAEEEajYCACMCKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIAQgAzYCDCAEIAU2AhAjAiMCKAIAQRRqNgIAC9oEAgl/AX4jAUECRgRAIwIjAigCAEEwazYCACMCKAIAIgEoAgAhACABKAIIIQIgASgCDCEDIAEoAhAhBCABKAIUIQYgASgCGCEHIAEoAhwhCCABKAIgIQogASgCJCEJIAEpAighDSABKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQsLIwFFBEAjAEEQayIHJAAgA0UhBAsCQAJAIwFFBEAgBA0BIAEpAwAiDUKAgICAEFohBAsCQAJAIwFFBEAgBA0BIAFBCGohCEF/IA2nIgQgA2oiCSAEIAlLGyIMIAFBDGooAgAiBk0NAiAGIAEoAhAiCmsgDCAKayIGTw0CCyALRUEBIwEbBEAgCCAKIAYQwAFBACMBQQFGDQUaCyMBRQ0BCyMBRQRAIAdBlKEFNgIMIAdBAjoACCAHQQA6AAsgB0EAOwAJIAAgBykDCDcCAAwDCwsjAUUEQAJAIAEoAhAiBiAETwRAIAgoAgAhCAwBCyABKAIIIgggBmpBACAEIAZrEIECIAEgBDYCECAEIQYLIAQgCGogAiADEP8BGiAGIAlJBEAgASAJNgIQCyABIA0gA618NwMACwsjAUUEQCAAQQQ6AAALCyMBRQRAIAdBEGokAAsPCyEFIwIoAgAgBTYCACMCIwIoAgBBBGo2AgAjAigCACIFIAA2AgAgBSABNgIEIAUgAjYCCCAFIAM2AgwgBSAENgIQIAUgBjYCFCAFIAc2AhggBSAINgIcIAUgCjYCICAFIAk2AiQgBSANNwIoIwIjAigCAEEwajYCAAuDBAIGfwF+IwFBAkYEQCMCIwIoAgBBHGs2AgAjAigCACICKAIAIQAgAigCBCEBIAIoAgghAyACKAIMIQUgAigCECEGIAIoAhQhByACKAIYIQILAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQQLIwFFBEAjAEEwayIDJAAgAUEEaiEGIAEoAgQhBQsCQCMBRQRAIAUNASABKAIAIQIgA0EQaiIHQQA2AgAgA0IBNwMIIAMgA0EIajYCFCADQShqIAJBEGopAgA3AwAgA0EgaiACQQhqKQIANwMAIAMgAikCADcDGCADQRRqIQUgA0EYaiECCyAERUEBIwEbBEAgBUHQoQUgAhArGkEAIwFBAUYNAhoLIwFFBEAgBkEIaiAHKAIANgIAIAYgAykDCDcCAAsLIwFFBEAgA0EgaiIEIAZBCGooAgA2AgAgAUEMakEANgIAIAYpAgAhCCABQgE3AgQgAyAINwMYQQwQDyIBRQRAAAsgASADKQMYNwIAIAFBCGogBCgCADYCACAAQeyuBTYCBCAAIAE2AgAgA0EwaiQACw8LIQQjAigCACAENgIAIwIjAigCAEEEajYCACMCKAIAIgQgADYCACAEIAE2AgQgBCADNgIIIAQgBTYCDCAEIAY2AhAgBCAHNgIUIAQgAjYCGCMCIwIoAgBBHGo2AgALxAIBAX9BwAEhAgJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQCAAQf8BcUEBaw4jAAECIwMEBQYHCAkKCwwNDg8QERITFBUWFxgZGhscHR4fICEiCyABQQ9xQYB/cg8LIAFBD3FBkH9yDwsgAUEfcUGgf3IPC0HBAQ8LQcIBDwtBwwEPC0HEAQ8LQcUBDwtBxgEPC0HHAQ8LQcgBDwtByQEPC0HKAQ8LQcsBDwtBzAEPC0HNAQ8LQc4BDwtBzwEPC0HQAQ8LQdEBDwtB0gEPC0HTAQ8LQdQBDwtB1QEPC0HWAQ8LQdcBDwtB2AEPC0HZAQ8LQdoBDwtB2wEPC0HcAQ8LQd0BDwtB3gEPC0HfAQ8LIAEhAgsgAgu5AwEDfyMBQQJGBEAjAiMCKAIAQRBrNgIAIwIoAgAiBCgCACEAIAQoAgQhASAEKAIIIQIgBCgCDCEECwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEDCyMBRQRAIwBBEGsiAiQAIAAoAgAhACACQQA2AgwgAkEMaiEEAn8gAUGAAU8EQCABQYAQTwRAIAFBgIAETwRAIAIgAUE/cUGAAXI6AA8gAiABQQZ2QT9xQYABcjoADiACIAFBDHZBP3FBgAFyOgANIAIgAUESdkEHcUHwAXI6AAxBBAwDCyACIAFBP3FBgAFyOgAOIAIgAUEMdkHgAXI6AAwgAiABQQZ2QT9xQYABcjoADUEDDAILIAIgAUE/cUGAAXI6AA0gAiABQQZ2QcABcjoADEECDAELIAIgAToADEEBCyEBCyADRUEBIwEbBEAgACAEIAEQLEEAIwFBAUYNARohAQsjAUUEQCACQRBqJAAgAQ8LAAshAyMCKAIAIAM2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAyAANgIAIAMgATYCBCADIAI2AgggAyAENgIMIwIjAigCAEEQajYCAEEAC7IDAQN/IwFBAkYEQCMCIwIoAgBBEGs2AgAjAigCACIEKAIAIQAgBCgCBCEBIAQoAgghAiAEKAIMIQQLAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQMLIwFFBEAjAEEQayICJAAgAkEANgIMIAJBDGohBAJ/IAFBgAFPBEAgAUGAEE8EQCABQYCABE8EQCACIAFBP3FBgAFyOgAPIAIgAUEGdkE/cUGAAXI6AA4gAiABQQx2QT9xQYABcjoADSACIAFBEnZBB3FB8AFyOgAMQQQMAwsgAiABQT9xQYABcjoADiACIAFBDHZB4AFyOgAMIAIgAUEGdkE/cUGAAXI6AA1BAwwCCyACIAFBP3FBgAFyOgANIAIgAUEGdkHAAXI6AAxBAgwBCyACIAE6AAxBAQshAQsgA0VBASMBGwRAIAAgBCABECxBACMBQQFGDQEaIQELIwFFBEAgAkEQaiQAIAEPCwALIQMjAigCACADNgIAIwIjAigCAEEEajYCACMCKAIAIgMgADYCACADIAE2AgQgAyACNgIIIAMgBDYCDCMCIwIoAgBBEGo2AgBBAAvVAwEDfyMBQQJGBEAjAiMCKAIAQQhrNgIAIwIoAgAiASgCACEAIAEoAgQhAQsCQAJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEDCyMBRQRAIABBIEkNAkEBIQEgAEH/AEkhAgsCQAJAIwFFBEAgAg0BIABBgIAESQ0CIABBgIAISSEBCwJAIwFFBEAgAUUEQCAAQbDHDGtB0LorSSAAQcumDGtBBUlyDQYgAEGe9AtrQeILSSAAQeHXC2tBnxhJcg0GIABBfnFBnvAKRiAAQaKdC2tBDklyDQYgAEFgcUHgzQpHIgENAgwGCwsgA0VBASMBGwRAIABB+pUEQSxB0pYEQcQBQZaYBEHCAxBNQQAjAUEBRg0EGiEACyMBRQRAIAAPCwsjAUUEQEEAIQEgAEG67gprQQZJDQEgAEGAgMQAayIAQfCDdEkhAQsLIwFFBEAgAQ8LCyADQQFGQQEjARsEQCAAQdyQBEEoQayRBEGfAkHLkwRBrwIQTUEBIwFBAUYNARohAAsjAUUEQCAADwsACyECIwIoAgAgAjYCACMCIwIoAgBBBGo2AgAjAigCACICIAA2AgAgAiABNgIEIwIjAigCAEEIajYCAEEADwtBAAu9BAEGfyMBQQJGBEAjAiMCKAIAQSxrNgIAIwIoAgAiBigCACEAIAYoAgQhASAGKAIIIQIgBigCDCEDIAYoAhAhBCAGKAIUIQUgBigCGCEKIAYoAhwhCCAGKAIgIQkgBigCJCELIAYoAighBgsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBwsjAUUEQCMAQRBrIggkAEF+IQYgA0EBdiILIQogBCEJCwJAAkACfwJAA0ACQCMBRQRAIAoNASAFIAtPIgkNAwsgB0VBASMBGwRAIAsgBUHkowQQ9gFBACMBQQFGDQcaCyMBRQRAAAsLIwFFBEAgCEEIaiABIAZBAmoiBiACakECIAlBARCLASAKQQFrIQogCUEBaiEJIAgoAghFDQELCyMBRQRAIAYgCCgCDGoMAgsLIwEEfyAKBSAIIAEgA0F+cSIBIAJqIANBAXEgBCALaiAFIAtrEIsBIAgoAgBFDQIgASAIKAIEagsLIQEjAUUEQCAAQQE6AAwgACABNgIIIAAgAUEBdjYCBCAAIAFBfnE2AgAMAgsLIwFFBEAgAEEEOgAMIAAgBTYCAAsLIwFFBEAgCEEQaiQACw8LIQcjAigCACAHNgIAIwIjAigCAEEEajYCACMCKAIAIgcgADYCACAHIAE2AgQgByACNgIIIAcgAzYCDCAHIAQ2AhAgByAFNgIUIAcgCjYCGCAHIAg2AhwgByAJNgIgIAcgCzYCJCAHIAY2AigjAiMCKAIAQSxqNgIAC70EAQZ/IwFBAkYEQCMCIwIoAgBBLGs2AgAjAigCACIGKAIAIQAgBigCBCEBIAYoAgghAiAGKAIMIQMgBigCECEEIAYoAhQhBSAGKAIYIQogBigCHCEIIAYoAiAhCSAGKAIkIQsgBigCKCEGCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEHCyMBRQRAIwBBEGsiCCQAQX4hBiADQQF2IgshCiAEIQkLAkACQAJ/AkADQAJAIwFFBEAgCg0BIAUgC08iCQ0DCyAHRUEBIwEbBEAgCyAFQeSjBBD2AUEAIwFBAUYNBxoLIwFFBEAACwsjAUUEQCAIQQhqIAEgBkECaiIGIAJqQQIgCUEBEIMBIApBAWshCiAJQQFqIQkgCCgCCEUNAQsLIwFFBEAgBiAIKAIMagwCCwsjAQR/IAoFIAggASADQX5xIgEgAmogA0EBcSAEIAtqIAUgC2sQgwEgCCgCAEUNAiABIAgoAgRqCwshASMBRQRAIABBAToADCAAIAE2AgggACABQQF2NgIEIAAgAUF+cTYCAAwCCwsjAUUEQCAAQQQ6AAwgACAFNgIACwsjAUUEQCAIQRBqJAALDwshByMCKAIAIAc2AgAjAiMCKAIAQQRqNgIAIwIoAgAiByAANgIAIAcgATYCBCAHIAI2AgggByADNgIMIAcgBDYCECAHIAU2AhQgByAKNgIYIAcgCDYCHCAHIAk2AiAgByALNgIkIAcgBjYCKCMCIwIoAgBBLGo2AgALvQQBBn8jAUECRgRAIwIjAigCAEEsazYCACMCKAIAIgYoAgAhACAGKAIEIQEgBigCCCECIAYoAgwhAyAGKAIQIQQgBigCFCEFIAYoAhghCiAGKAIcIQggBigCICEJIAYoAiQhCyAGKAIoIQYLAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQcLIwFFBEAjAEEQayIIJABBfCEGIANBAnYiCyEKIAQhCQsCQAJAAn8CQANAAkAjAUUEQCAKDQEgBSALTyIJDQMLIAdFQQEjARsEQCALIAVB5KMEEPYBQQAjAUEBRg0HGgsjAUUEQAALCyMBRQRAIAhBCGogASAGQQRqIgYgAmpBBCAJQQEQjQEgCkEBayEKIAlBAWohCSAIKAIIRQ0BCwsjAUUEQCAGIAgoAgxqDAILCyMBBH8gCgUgCCABIANBfHEiASACaiADQQNxIAQgC2ogBSALaxCNASAIKAIARQ0CIAEgCCgCBGoLCyEBIwFFBEAgAEEBOgAMIAAgATYCCCAAIAFBAnY2AgQgACABQXxxNgIADAILCyMBRQRAIABBBDoADCAAIAU2AgALCyMBRQRAIAhBEGokAAsPCyEHIwIoAgAgBzYCACMCIwIoAgBBBGo2AgAjAigCACIHIAA2AgAgByABNgIEIAcgAjYCCCAHIAM2AgwgByAENgIQIAcgBTYCFCAHIAo2AhggByAINgIcIAcgCTYCICAHIAs2AiQgByAGNgIoIwIjAigCAEEsajYCAAu9BAEGfyMBQQJGBEAjAiMCKAIAQSxrNgIAIwIoAgAiBigCACEAIAYoAgQhASAGKAIIIQIgBigCDCEDIAYoAhAhBCAGKAIUIQUgBigCGCEKIAYoAhwhCCAGKAIgIQkgBigCJCELIAYoAighBgsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBwsjAUUEQCMAQRBrIggkAEF8IQYgA0ECdiILIQogBCEJCwJAAkACfwJAA0ACQCMBRQRAIAoNASAFIAtPIgkNAwsgB0VBASMBGwRAIAsgBUHkowQQ9gFBACMBQQFGDQcaCyMBRQRAAAsLIwFFBEAgCEEIaiABIAZBBGoiBiACakEEIAlBARCEASAKQQFrIQogCUEBaiEJIAgoAghFDQELCyMBRQRAIAYgCCgCDGoMAgsLIwEEfyAKBSAIIAEgA0F8cSIBIAJqIANBA3EgBCALaiAFIAtrEIQBIAgoAgBFDQIgASAIKAIEagsLIQEjAUUEQCAAQQE6AAwgACABNgIIIAAgAUECdjYCBCAAIAFBfHE2AgAMAgsLIwFFBEAgAEEEOgAMIAAgBTYCAAsLIwFFBEAgCEEQaiQACw8LIQcjAigCACAHNgIAIwIjAigCAEEEajYCACMCKAIAIgcgADYCACAHIAE2AgQgByACNgIIIAcgAzYCDCAHIAQ2AhAgByAFNgIUIAcgCjYCGCAHIAg2AhwgByAJNgIgIAcgCzYCJCAHIAY2AigjAiMCKAIAQSxqNgIAC70EAQZ/IwFBAkYEQCMCIwIoAgBBLGs2AgAjAigCACIGKAIAIQAgBigCBCEBIAYoAgghAiAGKAIMIQMgBigCECEEIAYoAhQhBSAGKAIYIQogBigCHCEIIAYoAiAhCSAGKAIkIQsgBigCKCEGCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEHCyMBRQRAIwBBEGsiCCQAQXghBiADQQN2IgshCiAEIQkLAkACQAJ/AkADQAJAIwFFBEAgCg0BIAUgC08iCQ0DCyAHRUEBIwEbBEAgCyAFQeSjBBD2AUEAIwFBAUYNBxoLIwFFBEAACwsjAUUEQCAIQQhqIAEgBkEIaiIGIAJqQQggCUEBEJABIApBAWshCiAJQQFqIQkgCCgCCEUNAQsLIwFFBEAgBiAIKAIMagwCCwsjAQR/IAoFIAggASADQXhxIgEgAmogA0EHcSAEIAtqIAUgC2sQkAEgCCgCAEUNAiABIAgoAgRqCwshASMBRQRAIABBAToADCAAIAE2AgggACABQQN2NgIEIAAgAUF4cTYCAAwCCwsjAUUEQCAAQQQ6AAwgACAFNgIACwsjAUUEQCAIQRBqJAALDwshByMCKAIAIAc2AgAjAiMCKAIAQQRqNgIAIwIoAgAiByAANgIAIAcgATYCBCAHIAI2AgggByADNgIMIAcgBDYCECAHIAU2AhQgByAKNgIYIAcgCDYCHCAHIAk2AiAgByALNgIkIAcgBjYCKCMCIwIoAgBBLGo2AgALvQQBBn8jAUECRgRAIwIjAigCAEEsazYCACMCKAIAIgYoAgAhACAGKAIEIQEgBigCCCECIAYoAgwhAyAGKAIQIQQgBigCFCEFIAYoAhghCiAGKAIcIQggBigCICEJIAYoAiQhCyAGKAIoIQYLAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQcLIwFFBEAjAEEQayIIJABBeCEGIANBA3YiCyEKIAQhCQsCQAJAAn8CQANAAkAjAUUEQCAKDQEgBSALTyIJDQMLIAdFQQEjARsEQCALIAVB5KMEEPYBQQAjAUEBRg0HGgsjAUUEQAALCyMBRQRAIAhBCGogASAGQQhqIgYgAmpBCCAJQQEQgQEgCkEBayEKIAlBAWohCSAIKAIIRQ0BCwsjAUUEQCAGIAgoAgxqDAILCyMBBH8gCgUgCCABIANBeHEiASACaiADQQdxIAQgC2ogBSALaxCBASAIKAIARQ0CIAEgCCgCBGoLCyEBIwFFBEAgAEEBOgAMIAAgATYCCCAAIAFBA3Y2AgQgACABQXhxNgIADAILCyMBRQRAIABBBDoADCAAIAU2AgALCyMBRQRAIAhBEGokAAsPCyEHIwIoAgAgBzYCACMCIwIoAgBBBGo2AgAjAigCACIHIAA2AgAgByABNgIEIAcgAjYCCCAHIAM2AgwgByAENgIQIAcgBTYCFCAHIAo2AhggByAINgIcIAcgCTYCICAHIAs2AiQgByAGNgIoIwIjAigCAEEsajYCAAvSAQACfwJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAgACgCAA4QAQIDBAUGBwgJCgsMDQ4PEAALIABBBGoMEAsgAEEEagwPCyAAQQRqDA4LIABBBGoMDQsgAEEEagwMCyAAQQRqDAsLIABBBGoMCgsgAEEEagwJCyAAQQRqDAgLIABBBGoMBwsgAEEEagwGCyAAQQRqDAULIABBBGoMBAsgAEEEagwDCyAAQQRqDAILIABBBGoMAQsgAEEEagsoAgQEQCAAKAIEECELC6MFAQd/IwFBAkYEQCMCIwIoAgBBNGs2AgAjAigCACIIKAIAIQAgCCgCBCEBIAgoAgghAiAIKAIMIQMgCCgCECEEIAgoAhQhBSAIKAIYIQYgCCgCHCEJIAgoAiAhCiAIKAIkIQsgCCgCKCEMIAgoAiwhDSAIKAIwIQgLAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQcLIwFFBEAjAEEgayIJJAAgCUEYaiELCyAHRUEBIwEbBEAgCyACENEBQQAjAUEBRg0BGgsjAUUEQCAJKAIcIQsgCSgCGCABIAIQ/wEhDCAJQRBqIQELIAdBAUZBASMBGwRAIAEgBBDRAUEBIwFBAUYNARoLIwFFBEAgCSgCFCENIAkoAhAgAyAEEP8BIQMgCUEIaiEBCyAHQQJGQQEjARsEQCABIAYQ0QFBAiMBQQFGDQEaCyMBRQRAIAkoAgwhCCAJKAIIIAUgBhD/ASEFIABBFGooAgAiASAAQRBqKAIARyEKCwJAIwFFBEAgCg0BIABBDGohCgsgB0EDRkEBIwEbBEAgCiABEHdBAyMBQQFGDQIaCyMBRQRAIAAoAhQhAQsLIwFFBEAgACABQQFqNgIUIAAoAgwgAUEkbGoiACAGNgIgIAAgCDYCHCAAIAU2AhggACAENgIUIAAgDTYCECAAIAM2AgwgACACNgIIIAAgCzYCBCAAIAw2AgAgCUEgaiQACw8LIQcjAigCACAHNgIAIwIjAigCAEEEajYCACMCKAIAIgcgADYCACAHIAE2AgQgByACNgIIIAcgAzYCDCAHIAQ2AhAgByAFNgIUIAcgBjYCGCAHIAk2AhwgByAKNgIgIAcgCzYCJCAHIAw2AiggByANNgIsIAcgCDYCMCMCIwIoAgBBNGo2AgALqAMCBH8BfiMBQQJGBEAjAiMCKAIAQRRrNgIAIwIoAgAiAygCACEAIAMoAgQhASADKAIIIQQgAygCDCEFIAMoAhAhAwsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhAgsjAUUEQCMAQRBrIgQkACAEQQA6AAcgBEEIaiEFIARBB2ohAwsgAkVBASMBGwRAIAUgASADQQEQeUEAIwFBAUYNARoLIwFFBEACQAJ/AkACQAJAIAQtAAhBBEcEQCAEKQMIIgZC/wGDQgRSDQELQQAgBCwAByIBQX9KDQMaIAFBkH9JDQEgAUGgf0kNAiABQcQAakEkIAFBYHFBQEYbIAFBQE8NAxogAUEfcSEBQQMMAwsgACAGNwIADAMLIAFBD3EhAUEBDAELIAFBD3EhAUECCyECIABBBDoAACAAIAJB/wFxIAFBCHRyOwABCyAEQRBqJAALDwshAiMCKAIAIAI2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAiAANgIAIAIgATYCBCACIAQ2AgggAiAFNgIMIAIgAzYCECMCIwIoAgBBFGo2AgALtQQBB38jAUECRgRAIwIjAigCAEEkazYCACMCKAIAIgQoAgAhACAEKAIEIQEgBCgCCCECIAQoAgwhBSAEKAIQIQYgBCgCFCEHIAQoAhghCCAEKAIcIQkgBCgCICEECwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEDCyMBRQRAIwBBEGsiByQAIAIoAgwiBSACKAIEIgZLIQgLAkACQCMBRQRAIAgNASACKAIAIgggBWpBACAGIAVrEIECIAIgBjYCDCAGIAIoAggiBUkNAiAHQQhqIQkgBSAIaiEIIAYgBWshBAsgA0VBASMBGwRAIAkgASAIIAQQkQFBACMBQQFGDQMaCyMBRQRAAkAgBy0ACCIBQQRGBEAgAiAFIAcoAgxqIgE2AgggAEEEOgAAIAIgBiABIAEgBkkbNgIMDAELIAAgBy8ACTsAASAAQQNqIActAAs6AAAgACAHKAIMNgIEIAAgAToAAAsgB0EQaiQADwsLIANBAUZBASMBGwRAIAUgBkGImgUQ9gFBASMBQQFGDQIaCyMBRQRAAAsLIANBAkZBASMBGwRAIAUgBkGYmgUQ+AFBAiMBQQFGDQEaCyMBRQRAAAsPCyEDIwIoAgAgAzYCACMCIwIoAgBBBGo2AgAjAigCACIDIAA2AgAgAyABNgIEIAMgAjYCCCADIAU2AgwgAyAGNgIQIAMgBzYCFCADIAg2AhggAyAJNgIcIAMgBDYCICMCIwIoAgBBJGo2AgALnwQCBX8CfiMBQQJGBEAjAiMCKAIAQSRrNgIAIwIoAgAiBCgCACEAIAQoAgQhASAEKAIIIQIgBCgCDCEDIAQoAhAhBiAEKQIUIQggBCgCHCEHIAQoAiAhBAsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBQsjAUUEQCMAQSBrIgMkACABKQMAIghCAFIhBgsCQAJAIwFFBEAgBkUEQCAAQQQ6AAAgAEEANgIEDAILIAEoAgghByADQQhqIQYgCEIgIAhCIFQbpyEECyAFRUEBIwEbBEAgBiAHIAIgBBCRAUEAIwFBAUYNAxoLIwFFBEAgAy0ACCICQQRGBEAgAygCDCICrSIJIAhWDQMgACACNgIEIABBBDoAACABIAggCX03AwAMAgsgACADLwAJOwABIABBA2ogAy0ACzoAACAAIAMoAgw2AgQgACACOgAACwsjAUUEQCADQSBqJAAPCwsjAUUEQCADQRxqQQA2AgAgA0HooQU2AhggA0IBNwIMIANB3JoFNgIIIANBCGohAAsgBUEBRkEBIwEbBEAgAEHkmgUQ0gFBASMBQQFGDQEaCyMBRQRAAAsPCyEFIwIoAgAgBTYCACMCIwIoAgBBBGo2AgAjAigCACIFIAA2AgAgBSABNgIEIAUgAjYCCCAFIAM2AgwgBSAGNgIQIAUgCDcCFCAFIAc2AhwgBSAENgIgIwIjAigCAEEkajYCAAvPBAEFfyMBQQJGBEAjAiMCKAIAQRxrNgIAIwIoAgAiBSgCACEAIAUoAgQhASAFKAIIIQIgBSgCDCEDIAUoAhAhBiAFKAIUIQcgBSgCGCEFCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEECyMBRQRAIwBBQGoiAyQACwJAAkAjAUUEQCACDQEgA0EDNgIcIAMgADYCJCADIANBJGo2AhggA0EBNgI8IANCATcCLCADQdCWBTYCKCADIANBGGo2AjggA0EoaiEGIANBCGohAQsgBEVBASMBGwRAIAEgBhA7QQAjAUEBRg0DGgsjAUUEQCADKAIQIQYgAygCCCEBCyAEQQFGQQEjARsEQCABIAYQBUEBIwFBAUYNAxoLIwFFBEAgAygCDEUNAiABECEMAgsLIwFFBEAgACgCDCEHIANBKGohBiAAQRRqKAIAIQULIARBAkZBASMBGwRAIAYgByAFIAEgAhEGAEECIwFBAUYNAhoLIwFFBEAgAygCMCEGIAMoAighAQsgBEEDRkEBIwEbBEAgASAGEAZBAyMBQQFGDQIaCyMBRQRAIAMoAixFDQEgARAhCwsjAUUEQCAAKAIEBEAgACgCABAhCyAAQRBqKAIABEAgACgCDBAhCyADQUBrJAAgAkEARw8LAAshBCMCKAIAIAQ2AgAjAiMCKAIAQQRqNgIAIwIoAgAiBCAANgIAIAQgATYCBCAEIAI2AgggBCADNgIMIAQgBjYCECAEIAc2AhQgBCAFNgIYIwIjAigCAEEcajYCAEEAC/qnAgEffyMBQQJGBEAjAiMCKAIAQSRrNgIAIwIoAgAiBCgCACEAIAQoAgghAiAEKAIMIQMgBCgCECEaIAQoAhQhGyAEKAIYIR4gBCgCHCEfIAQoAiAhISAEKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQULIwFFBEAjAEEgayIaJAAgGkEQaiEbCyAFRUEBIwEbBEAgGyABIAMQPUEAIwFBAUYNARoLIwFFBEAgGi0AFCIeQQRHIR8gGigCECEbCwJAAkAjAUUEQCAfDQEgGkEIaiEeCyAFQQFGQQEjARsEQCAeIBtBARCoAUEBIwFBAUYNAxoLIwFFBEAgGigCDCEeIBpBEGohISAaKAIIIR8LIAVBAkZBASMBGwRAICEhDSABIQQgAiEQIAMhBiAfIRcgGyERQQAhBSMBQQJGBEAjAiMCKAIAQdAAazYCACMCKAIAIgQoAgAhDSAEKAIIIRAgBCgCDCEGIAQoAhAhFyAEKAIUIREgBCgCGCEFIAQoAhwhByAEKAIgIQggBCgCJCEJIAQoAighCiAEKAIsIQwgBCgCMCEOIAQoAjQhDyAEKAI4IRIgBCgCPCETIAQoAkAhFCAEKAJEIRUgBCgCSCEYIAQoAkwhGSAEKAIEIQQLAkACfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhCwsjAUUEQCMAQUBqIgckACAHIBE2AgggB0EEOgAMIAdBMGohBQsgC0VBASMBGwRAIAUgBCAGED1BACMBQQFGDQEaCyMBRQRAIActADRBBEchBQsCQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAjAUUEQCAFDQEgBygCMCARRyIFDQELIAtBAUZBASMBGwRAIAQQzgFBASMBQQFGDYwCGiEFCyMBRQRAIAVBAWsiBQ4GCAcGBQQDAgsLIwFFBEAgB0EANgIQIAdBEGohECAHQQhqIQ0gB0EwaiEECyALQQJGQQEjARsEQCANIRYgBCEgIwFBAkYEQCMCIwIoAgBBDGs2AgAjAigCACIdKAIAIRYgHSgCCCEcIB0oAgQhIAsgECEdAkACfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhIgsjAUUEQCMAQSBrIhwkACAcICA2AgQgHCAWNgIAIBxBGGogHUEQaikCADcDACAcQRBqIB1BCGopAgA3AwAgHCAdKQIANwMIIBxBCGohICAcQQRqIRYLICJFQQEjARsEQCAcQcSnBCAWQcSnBCAgQdSmBBBQQQAjAUEBRg0BGgsjAUUEQAALDAELIR0jAigCACAdNgIAIwIjAigCAEEEajYCACMCKAIAIh0gFjYCACAdICA2AgQgHSAcNgIIIwIjAigCAEEMajYCAAtBAiMBQQFGDYsCGgsjAUUEQAALCyALQQNGQQEjARsEQEG0pgRBDkHkpgQQvwFBAyMBQQFGDYoCGgsjAUUEQAALCyALQQRGQQEjARsEQCAEEMkBQQQjAUEBRg2JAhohCQsgC0EFRkEBIwEbBEAgBBDPAUEFIwFBAUYNiQIaIQgLIwFFBEAgBEEIaigCACEFCyALQQZGQQEjARsEQCAEEMoBQQYjAUEBRg2JAhohDgsgC0EHRkEBIwEbBEAgBBDUAUEHIwFBAUYNiQIaIRILAkACQAJAIwFFBEAgCQ0BIAhB/wFxIgQNAiAFQYIESyIEDQMLIAtBCEZBASMBGwRAIA0gDiASIBAgBiAXIBEQQUEIIwFBAUYNjAIaCyMBRQ2KAgsgBCAIQf8BcSMBGyEEAkACQCMBRQRAIAQNASAFQYIESyIEDQILIAtBCUZBASMBGwRAIA0gDiASIBAgBiAXIBEQRkEJIwFBAUYNjQIaCyMBRQ2LAgsjAUVBACAEIAVBggRLIwEbIgQbRQRAIAtBCkZBASMBGwRAIA0gDiASIBAgBiAXIBEQMUEKIwFBAUYNjQIaCyMBRQ2LAgsgBUEAIwEbIQUCQCMBRQRAIAZFBEBBACEEDAILIAdBKGohGCAHQSBqIRkgB0EYaiEUQQAhBAsDQCMBRQRAIAYgBGsiCEF8cSIJIARqIgogCUkiDA0LIAYgCkkiDA0MIAhBAnZBA2wiCCAFaiEMIAggDEsiDw0NIAwgEUsiDw0OIAQgEGohDyAFIBdqIRMgB0EQaiEMCyALQQtGQQEjARsEQCAMIA4gEiAPIAkgEyAIEDFBCyMBQQFGDY4CGgsjAUUEQCAHKAIQIQkgBy0AHEEERyIIRQRAIAUgCWohBSAKIQQMAwsgBCAJaiIKIAZLIgQNDyAFIAcoAhRqIgwgEUsiBA0QIAYgCmshBSAKIBBqIQkgB0IANwMIIBhCADcDACAZQgA3AwAgFEIANwMAIAdCADcDECARIAxrIQ8gDCAXaiETQQAhCEEAIQQLAkACfwJAAkACQANAIBUgBCAFTyMBGyEVAkAjAUUEQAJAAkAgFQ0AA0AgEiAEIAlqLQAAai0AAEGBAUcNASAFIARBAWoiBEciFQ0ACwwBCyAEIAVHIhUNAgsgCCAIQfz///8HcSIERiIJDQMgBEEISSIQDQULIAtBDEZBASMBGwRAIARBCEGEpQQQpgFBDCMBQQFGDZUCGgsjAUUEQAALCyMBRQRAIAdBEGogCEECdGogBDYCACAEIAVPDRcgB0EIaiAIaiAEIAlqLQAAOgAAIARBAWohBCAIQQFqIghBBEciFQ0BCwsjAUUEQEEEIQhBAyEJDAILCyMBRQRAIAhBCU8iBA0WIAhBAnZBA2whCSAFIQQLCyMBRQRAIAkgD0siBQ0WIAdBCGohDyAHQTBqIQULIAtBDUZBASMBGwRAIAUgDiASIA8gCCATIAkQMUENIwFBAUYNkQIaCyMBRQRAIActADwiBUEERiIIDQMgBygCOCIGQQhPDeIBIAZBAnQiECAHQRBqaiIEKAIADAILCyMBBH8gBgVBACEFIAdBEGoiECAEQQJ0aiIEKAIACwshBiMBRQRAIA0gBToADCANIAYgCmo2AgggDSAMNgIEIA0gCjYCAAyOAgsLIwFFBEAgDCAHKAIwaiEFIAYgBCAKaiIESyIKDQELCwsjAUUEQAJAIAQgBk8NAANAIBIgBCAQai0AAGotAABBgQFHDQEgBiAEQQFqIgRHDQALDIsCCyAEIAZGDYoCIA1BADoADCANIAQ2AgggDSAFNgIEIA0gBDYCAAyLAgsLIAVBACMBGyEFAkAjAUUEQCAGRQRAQQAhBAwCCyAHQShqIRggB0EgaiEZIAdBGGohFEEAIQQLA0AjAUUEQCAGIARrIgpBBmwiCEEGcUEGbiEJIAogCWsiCSAEaiIKIAlJIgwNEyAGIApJIgwNFCAIQQN2IgggBWohDCAIIAxLIg8NFSAMIBFLIg8NFiAEIBBqIQ8gBSAXaiETIAdBEGohDAsgC0EORkEBIwEbBEAgDCAOIBIgDyAJIBMgCBBGQQ4jAUEBRg2NAhoLIwFFBEAgBygCECEJIActABxBBEciCEUEQCAFIAlqIQUgCiEEDAMLIAQgCWoiCiAGSyIEDRcgBSAHKAIUaiIMIBFLIgQNGCAGIAprIQUgCiAQaiEJIAdCADcDCCAYQgA3AwAgGUIANwMAIBRCADcDACAHQgA3AxAgESAMayEPIAwgF2ohE0EAIQhBACEECwJAAn8CQAJAAkADQCAVIAQgBU8jARshFQJAIwFFBEACQAJAIBUNAANAIBIgBCAJai0AAGotAABBgQFHDQEgBSAEQQFqIgRHIhUNAAsMAQsgBCAFRyIVDQILIAhBBmwiBEEGcUEGRyIJDQMgCEEBayIGQQhJIgQNBQsgC0EPRkEBIwEbBEAgBkEIQYSlBBCmAUEPIwFBAUYNlAIaCyMBRQRAAAsLIwFFBEAgB0EQaiAIQQJ0aiAENgIAIAQgBU8NHyAHQQhqIAhqIAQgCWotAAA6AAAgBEEBaiEEIAhBAWoiCEEERyIVDQELCyMBRQRAQQQhCEEDIQkMAgsLIwFFBEAgCEEJTyIJDR4gBEEDdiEJIAUhBAsLIwFFBEAgCSAPSyIFDR4gB0EIaiEPIAdBMGohBQsgC0EQRkEBIwEbBEAgBSAOIBIgDyAIIBMgCRBGQRAjAUEBRg2QAhoLIwFFBEAgBy0APCIFQQRGIggNAyAHKAI4IgZBCE8N4gEgBkECdCIQIAdBEGpqIgQoAgAMAgsLIwEEfyAGBUEAIQUgBkECdCIQIAdBEGpqIgQoAgALCyEGIwFFBEAgDSAFOgAMIA0gBiAKajYCCCANIAw2AgQgDSAKNgIADI0CCwsjAUUEQCAMIAcoAjBqIQUgBiAEIApqIgRLIgoNAQsLCyMBRQRAAkAgBCAGTw0AA0AgEiAEIBBqLQAAai0AAEGBAUcNASAGIARBAWoiBEcNAAsMiQILIAQgBkYNiAIgDUEAOgAMIA0gBDYCCCANIAU2AgQgDSAENgIADIoCCwsjAUVBACAEIAVBggRLIwEbIgQbRQRAIAtBEUZBASMBGwRAIA0gDiASIBAgBiAXIBEQMkERIwFBAUYNiwIaCyMBRQ2JAgsgBUEAIwEbIQUCQCMBRQRAIAZFBEBBACEEDAILIAdBKGohGCAHQSBqIRkgB0EYaiEUQQAhBAsDQCMBRQRAIAYgBGsiCEF8cSIJIARqIgogCUkiDA0bIAYgCkkiDA0cIAhBAnZBA2wiCCAFaiEMIAggDEsiDw0dIAwgEUsiDw0eIAQgEGohDyAFIBdqIRMgB0EQaiEMCyALQRJGQQEjARsEQCAMIA4gEiAPIAkgEyAIEDJBEiMBQQFGDYwCGgsjAUUEQCAHKAIQIQkgBy0AHEEERyIIRQRAIAUgCWohBSAKIQQMAwsgBCAJaiIKIAZLIgQNHyAFIAcoAhRqIgwgEUsiBA0gIAYgCmshBSAKIBBqIQkgB0IANwMIIBhCADcDACAZQgA3AwAgFEIANwMAIAdCADcDECARIAxrIQ8gDCAXaiETQQAhCEEAIQQLAkACfwJAAkACQANAIBUgBCAFTyMBGyEVAkAjAUUEQAJAAkAgFQ0AA0AgEiAEIAlqLQAAai0AAEGBAUcNASAFIARBAWoiBEciFQ0ACwwBCyAEIAVHIhUNAgsgCCAIQfz///8HcSIERiIJDQMgBEEISSIQDQULIAtBE0ZBASMBGwRAIARBCEGEpQQQpgFBEyMBQQFGDZMCGgsjAUUEQAALCyMBRQRAIAdBEGogCEECdGogBDYCACAEIAVPDScgB0EIaiAIaiAEIAlqLQAAOgAAIARBAWohBCAIQQFqIghBBEciFQ0BCwsjAUUEQEEEIQhBAyEJDAILCyMBRQRAIAhBCU8iBA0mIAhBAnZBA2whCSAFIQQLCyMBRQRAIAkgD0siBQ0mIAdBCGohDyAHQTBqIQULIAtBFEZBASMBGwRAIAUgDiASIA8gCCATIAkQMkEUIwFBAUYNjwIaCyMBRQRAIActADwiBUEERiIIDQMgBygCOCIGQQhPDeIBIAZBAnQiECAHQRBqaiIEKAIADAILCyMBBH8gBgVBACEFIAdBEGoiECAEQQJ0aiIEKAIACwshBiMBRQRAIA0gBToADCANIAYgCmo2AgggDSAMNgIEIA0gCjYCAAyMAgsLIwFFBEAgDCAHKAIwaiEFIAYgBCAKaiIESyIKDQELCwsjAUUEQAJAIAQgBk8NAANAIBIgBCAQai0AAGotAABBgQFHDQEgBiAEQQFqIgRHDQALDIcCCyAEIAZGDYYCIA1BADoADCANIAQ2AgggDSAFNgIEIA0gBDYCAAyJAgsLIAVBACMBGyEFAkAjAUUEQCAGRQRAQQAhBAwCCyAHQShqIRggB0EgaiEZIAdBGGohFEEAIQQLA0AjAUUEQCAGIARrIgpBBmwiCEEGcUEGbiEJIAogCWsiCSAEaiIKIAlJIgwNIyAGIApJIgwNJCAIQQN2IgggBWohDCAIIAxLIg8NJSAMIBFLIg8NJiAEIBBqIQ8gBSAXaiETIAdBEGohDAsgC0EVRkEBIwEbBEAgDCAOIBIgDyAJIBMgCBBBQRUjAUEBRg2LAhoLIwFFBEAgBygCECEJIActABxBBEciCEUEQCAFIAlqIQUgCiEEDAMLIAQgCWoiCiAGSyIEDScgBSAHKAIUaiIMIBFLIgQNKCAGIAprIQUgCiAQaiEJIAdCADcDCCAYQgA3AwAgGUIANwMAIBRCADcDACAHQgA3AxAgESAMayEPIAwgF2ohE0EAIQhBACEECwJAAn8CQAJAAkADQCAVIAQgBU8jARshFQJAIwFFBEACQAJAIBUNAANAIBIgBCAJai0AAGotAABBgQFHDQEgBSAEQQFqIgRHIhUNAAsMAQsgBCAFRyIVDQILIAhBBmwiBEEGcUEGRyIJDQMgCEEBayIGQQhJIgQNBQsgC0EWRkEBIwEbBEAgBkEIQYSlBBCmAUEWIwFBAUYNkgIaCyMBRQRAAAsLIwFFBEAgB0EQaiAIQQJ0aiAENgIAIAQgBU8NLyAHQQhqIAhqIAQgCWotAAA6AAAgBEEBaiEEIAhBAWoiCEEERyIVDQELCyMBRQRAQQQhCEEDIQkMAgsLIwFFBEAgCEEJTyIJDS4gBEEDdiEJIAUhBAsLIwFFBEAgCSAPSyIFDS4gB0EIaiEPIAdBMGohBQsgC0EXRkEBIwEbBEAgBSAOIBIgDyAIIBMgCRBBQRcjAUEBRg2OAhoLIwFFBEAgBy0APCIFQQRGIggNAyAHKAI4IgZBCE8N4gEgBkECdCIQIAdBEGpqIgQoAgAMAgsLIwEEfyAGBUEAIQUgBkECdCIQIAdBEGpqIgQoAgALCyEGIwFFBEAgDSAFOgAMIA0gBiAKajYCCCANIAw2AgQgDSAKNgIADIsCCwsjAUUEQCAMIAcoAjBqIQUgBiAEIApqIgRLIgoNAQsLCyMBRQRAAkAgBCAGTw0AA0AgEiAEIBBqLQAAai0AAEGBAUcNASAGIARBAWoiBEcNAAsMhQILIAQgBkYNhAIgDUEAOgAMIA0gBDYCCCANIAU2AgQgDSAENgIADIgCCwsgC0EYRkEBIwEbBEAgBBDJAUEYIwFBAUYNiAIaIQkLIAtBGUZBASMBGwRAIAQQzwFBGSMBQQFGDYgCGiEICyMBRQRAIARBCGooAgAhBQsgC0EaRkEBIwEbBEAgBBDKAUEaIwFBAUYNiAIaIQ4LIAtBG0ZBASMBGwRAIAQQ1AFBGyMBQQFGDYgCGiESCwJAAkACQCMBRQRAIAkNASAIQf8BcSIEDQIgBUGCBEsiBA0DCyALQRxGQQEjARsEQCANIA4gEiAQIAYgFyAREEJBHCMBQQFGDYsCGgsjAUUNiQILIAQgCEH/AXEjARshBAJAAkAjAUUEQCAEDQEgBUGCBEsiBA0CCyALQR1GQQEjARsEQCANIA4gEiAQIAYgFyAREEdBHSMBQQFGDYwCGgsjAUUNigILIwFFQQAgBCAFQYIESyMBGyIEG0UEQCALQR5GQQEjARsEQCANIA4gEiAQIAYgFyAREDNBHiMBQQFGDYwCGgsjAUUNigILIAVBACMBGyEFAkAjAUUEQCAGRQRAQQAhBAwCCyAHQShqIRggB0EgaiEZIAdBGGohFEEAIQQLA0AjAUUEQCAGIARrIghBeHEiCSAEaiIKIAlJIgwNLiAGIApJIgwNLyAIQQN2QQVsIgggBWohDCAIIAxLIg8NMCAMIBFLIg8NMSAEIBBqIQ8gBSAXaiETIAdBEGohDAsgC0EfRkEBIwEbBEAgDCAOIBIgDyAJIBMgCBAzQR8jAUEBRg2NAhoLIwFFBEAgBygCECEJIActABxBBEciCEUEQCAFIAlqIQUgCiEEDAMLIAQgCWoiCiAGSyIEDTIgBSAHKAIUaiIMIBFLIgQNMyAGIAprIQUgCiAQaiEJIAdCADcDCCAYQgA3AwAgGUIANwMAIBRCADcDACAHQgA3AxAgESAMayEPIAwgF2ohE0EAIQhBACEECwJAAn8CQAJAAkADQCAVIAQgBU8jARshFQJAIwFFBEACQAJAIBUNAANAIBIgBCAJai0AAGotAABBgQFHDQEgBSAEQQFqIgRHIhUNAAsMAQsgBCAFRyIVDQILIAggCEH4////B3EiBEYiCQ0DIARFIhANBQsgC0EgRkEBIwEbBEAgBEEIQYSlBBCmAUEgIwFBAUYNlAIaCyMBRQRAAAsLIwFFBEAgB0EQaiAIQQJ0aiAENgIAIAQgBU8NOiAHQQhqIAhqIAQgCWotAAA6AAAgBEEBaiEEIAhBAWoiCEEIRyIVDQELCyMBRQRAQQghCEEFIQkMAgsLIwFFBEAgCEEJTyIEDTkgCEEDdkEFbCEJIAUhBAsLIwFFBEAgCSAPSyIFDTkgB0EIaiEPIAdBMGohBQsgC0EhRkEBIwEbBEAgBSAOIBIgDyAIIBMgCRAzQSEjAUEBRg2QAhoLIwFFBEAgBy0APCIFQQRGIggNAyAHKAI4IgZBCE8N5QEgBkECdCIQIAdBEGpqIgQoAgAMAgsLIwEEfyAGBUEAIQUgBygCEAsLIQYjAUUEQCANIAU6AAwgDSAGIApqNgIIIA0gDDYCBCANIAo2AgAMjQILCyMBRQRAIAwgBygCMGohBSAGIAQgCmoiBEsiCg0BCwsLIwFFBEACQCAEIAZPDQADQCASIAQgEGotAABqLQAAQYEBRw0BIAYgBEEBaiIERw0ACwyGAgsgBCAGRg2FAiANQQA6AAwgDSAENgIIIA0gBTYCBCANIAQ2AgAMigILCyAFQQAjARshBQJAIwFFBEAgBkUEQEEAIQQMAgsgB0EoaiEYIAdBIGohGSAHQRhqIRRBACEECwNAIwFFBEAgBiAEayIKQQVsIghBB3FBBW4hCSAKIAlrIgkgBGoiCiAJSSIMDTYgBiAKSSIMDTcgCEEDdiIIIAVqIQwgCCAMSyIPDTggDCARSyIPDTkgBCAQaiEPIAUgF2ohEyAHQRBqIQwLIAtBIkZBASMBGwRAIAwgDiASIA8gCSATIAgQR0EiIwFBAUYNjAIaCyMBRQRAIAcoAhAhCSAHLQAcQQRHIghFBEAgBSAJaiEFIAohBAwDCyAEIAlqIgogBksiBA06IAUgBygCFGoiDCARSyIEDTsgBiAKayEFIAogEGohCSAHQgA3AwggGEIANwMAIBlCADcDACAUQgA3AwAgB0IANwMQIBEgDGshDyAMIBdqIRNBACEIQQAhBAsCQAJ/AkACQAJAA0AgFSAEIAVPIwEbIRUCQCMBRQRAAkACQCAVDQADQCASIAQgCWotAABqLQAAQYEBRw0BIAUgBEEBaiIERyIVDQALDAELIAQgBUciFQ0CCyAIQQVsIgRBB3FBBUkiCQ0DIAhBAWsiBkEISSIEDQULIAtBI0ZBASMBGwRAIAZBCEGEpQQQpgFBIyMBQQFGDZMCGgsjAUUEQAALCyMBRQRAIAdBEGogCEECdGogBDYCACAEIAVPDUIgB0EIaiAIaiAEIAlqLQAAOgAAIARBAWohBCAIQQFqIghBCEciFQ0BCwsjAUUEQEEIIQhBBSEJDAILCyMBRQRAIAhBCU8iCQ1BIARBA3YhCSAFIQQLCyMBRQRAIAkgD0siBQ1BIAdBCGohDyAHQTBqIQULIAtBJEZBASMBGwRAIAUgDiASIA8gCCATIAkQR0EkIwFBAUYNjwIaCyMBRQRAIActADwiBUEERiIIDQMgBygCOCIGQQhPDeUBIAZBAnQiECAHQRBqaiIEKAIADAILCyMBBH8gBgVBACEFIAZBAnQiECAHQRBqaiIEKAIACwshBiMBRQRAIA0gBToADCANIAYgCmo2AgggDSAMNgIEIA0gCjYCAAyMAgsLIwFFBEAgDCAHKAIwaiEFIAYgBCAKaiIESyIKDQELCwsjAUUEQAJAIAQgBk8NAANAIBIgBCAQai0AAGotAABBgQFHDQEgBiAEQQFqIgRHDQALDIQCCyAEIAZGDYMCIA1BADoADCANIAQ2AgggDSAFNgIEIA0gBDYCAAyJAgsLIwFFQQAgBCAFQYIESyMBGyIEG0UEQCALQSVGQQEjARsEQCANIA4gEiAQIAYgFyAREDRBJSMBQQFGDYoCGgsjAUUNiAILIAVBACMBGyEFAkAjAUUEQCAGRQRAQQAhBAwCCyAHQShqIRggB0EgaiEZIAdBGGohFEEAIQQLA0AjAUUEQCAGIARrIghBeHEiCSAEaiIKIAlJIgwNPiAGIApJIgwNPyAIQQN2QQVsIgggBWohDCAIIAxLIg8NQCAMIBFLIg8NQSAEIBBqIQ8gBSAXaiETIAdBEGohDAsgC0EmRkEBIwEbBEAgDCAOIBIgDyAJIBMgCBA0QSYjAUEBRg2LAhoLIwFFBEAgBygCECEJIActABxBBEciCEUEQCAFIAlqIQUgCiEEDAMLIAQgCWoiCiAGSyIEDUIgBSAHKAIUaiIMIBFLIgQNQyAGIAprIQUgCiAQaiEJIAdCADcDCCAYQgA3AwAgGUIANwMAIBRCADcDACAHQgA3AxAgESAMayEPIAwgF2ohE0EAIQhBACEECwJAAn8CQAJAAkADQCAVIAQgBU8jARshFQJAIwFFBEACQAJAIBUNAANAIBIgBCAJai0AAGotAABBgQFHDQEgBSAEQQFqIgRHIhUNAAsMAQsgBCAFRyIVDQILIAggCEH4////B3EiBEYiCQ0DIARFIhANBQsgC0EnRkEBIwEbBEAgBEEIQYSlBBCmAUEnIwFBAUYNkgIaCyMBRQRAAAsLIwFFBEAgB0EQaiAIQQJ0aiAENgIAIAQgBU8NSiAHQQhqIAhqIAQgCWotAAA6AAAgBEEBaiEEIAhBAWoiCEEIRyIVDQELCyMBRQRAQQghCEEFIQkMAgsLIwFFBEAgCEEJTyIEDUkgCEEDdkEFbCEJIAUhBAsLIwFFBEAgCSAPSyIFDUkgB0EIaiEPIAdBMGohBQsgC0EoRkEBIwEbBEAgBSAOIBIgDyAIIBMgCRA0QSgjAUEBRg2OAhoLIwFFBEAgBy0APCIFQQRGIggNAyAHKAI4IgZBCE8N5QEgBkECdCIQIAdBEGpqIgQoAgAMAgsLIwEEfyAGBUEAIQUgBygCEAsLIQYjAUUEQCANIAU6AAwgDSAGIApqNgIIIA0gDDYCBCANIAo2AgAMiwILCyMBRQRAIAwgBygCMGohBSAGIAQgCmoiBEsiCg0BCwsLIwFFBEACQCAEIAZPDQADQCASIAQgEGotAABqLQAAQYEBRw0BIAYgBEEBaiIERw0ACwyCAgsgBCAGRg2BAiANQQA6AAwgDSAENgIIIA0gBTYCBCANIAQ2AgAMiAILCyAFQQAjARshBQJAIwFFBEAgBkUEQEEAIQQMAgsgB0EoaiEYIAdBIGohGSAHQRhqIRRBACEECwNAIwFFBEAgBiAEayIKQQVsIghBB3FBBW4hCSAKIAlrIgkgBGoiCiAJSSIMDUYgBiAKSSIMDUcgCEEDdiIIIAVqIQwgCCAMSyIPDUggDCARSyIPDUkgBCAQaiEPIAUgF2ohEyAHQRBqIQwLIAtBKUZBASMBGwRAIAwgDiASIA8gCSATIAgQQkEpIwFBAUYNigIaCyMBRQRAIAcoAhAhCSAHLQAcQQRHIghFBEAgBSAJaiEFIAohBAwDCyAEIAlqIgogBksiBA1KIAUgBygCFGoiDCARSyIEDUsgBiAKayEFIAogEGohCSAHQgA3AwggGEIANwMAIBlCADcDACAUQgA3AwAgB0IANwMQIBEgDGshDyAMIBdqIRNBACEIQQAhBAsCQAJ/AkACQAJAA0AgFSAEIAVPIwEbIRUCQCMBRQRAAkACQCAVDQADQCASIAQgCWotAABqLQAAQYEBRw0BIAUgBEEBaiIERyIVDQALDAELIAQgBUciFQ0CCyAIQQVsIgRBB3FBBUkiCQ0DIAhBAWsiBkEISSIEDQULIAtBKkZBASMBGwRAIAZBCEGEpQQQpgFBKiMBQQFGDZECGgsjAUUEQAALCyMBRQRAIAdBEGogCEECdGogBDYCACAEIAVPDVIgB0EIaiAIaiAEIAlqLQAAOgAAIARBAWohBCAIQQFqIghBCEciFQ0BCwsjAUUEQEEIIQhBBSEJDAILCyMBRQRAIAhBCU8iCQ1RIARBA3YhCSAFIQQLCyMBRQRAIAkgD0siBQ1RIAdBCGohDyAHQTBqIQULIAtBK0ZBASMBGwRAIAUgDiASIA8gCCATIAkQQkErIwFBAUYNjQIaCyMBRQRAIActADwiBUEERiIIDQMgBygCOCIGQQhPDeUBIAZBAnQiECAHQRBqaiIEKAIADAILCyMBBH8gBgVBACEFIAZBAnQiECAHQRBqaiIEKAIACwshBiMBRQRAIA0gBToADCANIAYgCmo2AgggDSAMNgIEIA0gCjYCAAyKAgsLIwFFBEAgDCAHKAIwaiEFIAYgBCAKaiIESyIKDQELCwsjAUUEQAJAIAQgBk8NAANAIBIgBCAQai0AAGotAABBgQFHDQEgBiAEQQFqIgRHDQALDIACCyAEIAZGDf8BIA1BADoADCANIAQ2AgggDSAFNgIEIA0gBDYCAAyHAgsLIAtBLEZBASMBGwRAIAQQyQFBLCMBQQFGDYcCGiEJCyALQS1GQQEjARsEQCAEEM8BQS0jAUEBRg2HAhohCAsjAUUEQCAEQQhqKAIAIQULIAtBLkZBASMBGwRAIAQQygFBLiMBQQFGDYcCGiEKCyALQS9GQQEjARsEQCAEENQBQS8jAUEBRg2HAhohEgsCQAJAAkAjAUUEQCAJDQEgCEH/AXEiBA0CIAVBggRLIgQNAwsgC0EwRkEBIwEbBEAgDSASIBAgBiAXIBEQZkEwIwFBAUYNigIaCyMBRQ2IAgsgBCAIQf8BcSMBGyEEAkACQCMBRQRAIAQNASAFQYIESyIEDQILIAtBMUZBASMBGwRAIA0gEiAQIAYgFyAREGdBMSMBQQFGDYsCGgsjAUUNiQILIwFFQQAgBCAFQYIESyMBGyIEG0UEQCALQTJGQQEjARsEQCANIBIgECAGIBcgERA1QTIjAUEBRg2LAhoLIwFFDYkCCyAJQQAjARshCQJAIwFFBEAgBkUEQEEAIQQMAgsgB0EoaiEZIAdBIGohFCAHQRhqIRVBACEECwNAIwFFBEAgBiAEayIIQX5xIgUgBGohDiAFIA5LIgoNUSAGIA5JIgoNUiAJIAhBAXYiCGoiCiAISSIMDVMgCiARSyIMDVQgBCAQaiEMIAkgF2ohDyAHQRBqIQoLIAtBM0ZBASMBGwRAIAogEiAMIAUgDyAIEDVBMyMBQQFGDYwCGgsjAUUEQCAHKAIQIQUgBy0AHEEERyIKRQRAIAUgCWohCSAOIQQMAwsgBCAFaiIPIAZLIgQNVSAJIAcoAhRqIhMgEUsiBA1WIAYgD2shBSAPIBBqIQkgB0IANwMIIBlCADcDACAUQgA3AwAgFUIANwMAIAdCADcDECATIBdqIRhBACEOQQEhDEEAIQhBACEECwJAAkACQCMBRQRAAn8DQAJAAkACQCAEIAVPDQADQCASIAQgCWotAABqLQAAQYEBRw0BIAUgBEEBaiIERyIKDQALDAELIAQgBUciCg0BCyAMQQFxRSIEDQRBACEJQQAMAgsgCEECdCIMIAdBEGpqIAQ2AgAgBCAFTyIKDVwgCCAHQQhqaiAEIAlqLQAAOgAAQQEhCCAEQQFqIQQgDkEBcUEAIQxBASEORSIKDQALIBEgE0YiBQ1cQQIhCSAEIQVBAQshCCAHQQhqIQogB0EwaiEECyALQTRGQQEjARsEQCAEIBIgCiAJIBggCBA1QTQjAUEBRg2PAhoLIwFFBEAgBy0APCIEQQRGIgoNAyAHKAI4IgZBCE8N6AEgB0EQaiAGQQJ0aiIQKAIAIQYMAgsLIwFFBEAgBygCECEGQQAhBAsLIwFFBEAgDSAEOgAMIA0gBiAPajYCCCANIBM2AgQgDSAPNgIADIwCCwsjAUUEQCATIAcoAjBqIQkgBiAFIA9qIgRLIgUNAQsLCyMBRQRAAkAgBCAGTw0AA0AgEiAEIBBqLQAAai0AAEGBAUcNASAGIARBAWoiBEcNAAsMgQILIAQgBkYNgAIgDUEAOgAMIA0gBDYCCCANIAk2AgQgDSAENgIADIkCCwsCQCMBRQRAIAZFBEBBACEJQQAhBAwCCyAHQShqIRggB0EgaiEZIAdBGGohFEEAIQlBACEECwNAIwFFBEAgBiAEayIIQX5xIgUgBGohDiAFIA5LIgoNWCAGIA5JIgoNWSAJIAhBAXZB/////wFxIghqIgogCEkiDA1aIAogEUsiDA1bIAQgEGohDCAJIBdqIQ8gB0EQaiEKCyALQTVGQQEjARsEQCAKIBIgDCAFIA8gCBBnQTUjAUEBRg2LAhoLIwFFBEAgBygCECEFIActABxBBEciCkUEQCAFIAlqIQkgDiEEDAMLIAQgBWoiDCAGSyIEDVwgCSAHKAIUaiIPIBFLIgQNXSAGIAxrIQUgDCAQaiEJIAdCADcDCCAYQgA3AwAgGUIANwMAIBRCADcDACAHQgA3AxAgDyAXaiETQQAhCEEBIQ5BACEECwJAAn8CQAJAIwFFBEADQAJAAkACQCAEIAVPDQADQCASIAQgCWotAABqLQAAQYEBRw0BIAUgBEEBaiIERyIKDQALDAELIAQgBUciCg0BCyAOQQFxIgQNA0EAIQQgBygCEAwFCyAIQQJ0IhUgB0EQamogBDYCACAEIAVPIgoNYyAIIAdBCGpqIAQgCWotAAA6AABBASEIIARBAWohBCAOQQFxIQpBACEOIAoNAAsgDyARRiEFCwJAIwFFBEAgBQ0BIAdBCGohCiAHQTBqIQULIAtBNkZBASMBGwRAIAUgEiAKQQIgE0EBEGdBNiMBQQFGDZACGgsjAUUEQCAEIQUMAwsLIAtBN0ZBASMBGwRAQQFBAEH0pAQQ9wFBNyMBQQFGDY8CGgsjAUUEQAALCyMBRQRAIAdBCGohCiAHQTBqIQQLIAtBOEZBASMBGwRAIAQgEiAKIAggE0EAEGdBOCMBQQFGDY4CGgsLIwEEfyAGBSAHLQA8IgRBBEYiCg0CIAcoAjgiBkEITw1hIAdBEGogBkECdGoiECgCAAsLIQYjAUUEQCANIAQ6AAwgDSAGIAxqNgIIIA0gDzYCBCANIAw2AgAMiwILCyMBRQRAIA8gBygCMGohCSAGIAUgDGoiBEsiBQ0BCwsLIwFFBEACQCAEIAZPDQADQCASIAQgEGotAABqLQAAQYEBRw0BIAYgBEEBaiIERw0ACwz/AQsgBCAGRg3+ASANQQA6AAwgDSAENgIIIA0gCTYCBCANIAQ2AgAMiAILCyMBRUEAIAQgBUGCBEsjARsiBBtFBEAgC0E5RkEBIwEbBEAgDSASIBAgBiAXIBEQNkE5IwFBAUYNiQIaCyMBRQ2HAgsgCUEAIwEbIQkCQCMBRQRAIAZFBEBBACEEDAILIAdBKGohGSAHQSBqIRQgB0EYaiEVQQAhBAsDQCMBRQRAIAYgBGsiCEF+cSIFIARqIQ4gBSAOSyIKDV8gBiAOSSIKDWAgCSAIQQF2IghqIgogCEkiDA1hIAogEUsiDA1iIAQgEGohDCAJIBdqIQ8gB0EQaiEKCyALQTpGQQEjARsEQCAKIBIgDCAFIA8gCBA2QTojAUEBRg2KAhoLIwFFBEAgBygCECEFIActABxBBEciCkUEQCAFIAlqIQkgDiEEDAMLIAQgBWoiDyAGSyIEDWMgCSAHKAIUaiITIBFLIgQNZCAGIA9rIQUgDyAQaiEJIAdCADcDCCAZQgA3AwAgFEIANwMAIBVCADcDACAHQgA3AxAgEyAXaiEYQQAhDkEBIQxBACEIQQAhBAsCQAJAAkAjAUUEQAJ/A0ACQAJAAkAgBCAFTw0AA0AgEiAEIAlqLQAAai0AAEGBAUcNASAFIARBAWoiBEciCg0ACwwBCyAEIAVHIgoNAQsgDEEBcUUiBA0EQQAhCUEADAILIAhBAnQiDCAHQRBqaiAENgIAIAQgBU8iCg1qIAggB0EIamogBCAJai0AADoAAEEBIQggBEEBaiEEIA5BAXFBACEMQQEhDkUiCg0ACyARIBNGIgUNakECIQkgBCEFQQELIQggB0EIaiEKIAdBMGohBAsgC0E7RkEBIwEbBEAgBCASIAogCSAYIAgQNkE7IwFBAUYNjQIaCyMBRQRAIActADwiBEEERiIKDQMgBygCOCIGQQhPDecBIAdBEGogBkECdGoiECgCACEGDAILCyMBRQRAIAcoAhAhBkEAIQQLCyMBRQRAIA0gBDoADCANIAYgD2o2AgggDSATNgIEIA0gDzYCAAyKAgsLIwFFBEAgEyAHKAIwaiEJIAYgBSAPaiIESyIFDQELCwsjAUUEQAJAIAQgBk8NAANAIBIgBCAQai0AAGotAABBgQFHDQEgBiAEQQFqIgRHDQALDP0BCyAEIAZGDfwBIA1BADoADCANIAQ2AgggDSAJNgIEIA0gBDYCAAyHAgsLAkAjAUUEQCAGRQRAQQAhCUEAIQQMAgsgB0EoaiEYIAdBIGohGSAHQRhqIRRBACEJQQAhBAsDQCMBRQRAIAYgBGsiCEF+cSIFIARqIQ4gBSAOSyIKDWYgBiAOSSIKDWcgCSAIQQF2Qf////8BcSIIaiIKIAhJIgwNaCAKIBFLIgwNaSAEIBBqIQwgCSAXaiEPIAdBEGohCgsgC0E8RkEBIwEbBEAgCiASIAwgBSAPIAgQZkE8IwFBAUYNiQIaCyMBRQRAIAcoAhAhBSAHLQAcQQRHIgpFBEAgBSAJaiEJIA4hBAwDCyAEIAVqIgwgBksiBA1qIAkgBygCFGoiDyARSyIEDWsgBiAMayEFIAwgEGohCSAHQgA3AwggGEIANwMAIBlCADcDACAUQgA3AwAgB0IANwMQIA8gF2ohE0EAIQhBASEOQQAhBAsCQAJ/AkACQCMBRQRAA0ACQAJAAkAgBCAFTw0AA0AgEiAEIAlqLQAAai0AAEGBAUcNASAFIARBAWoiBEciCg0ACwwBCyAEIAVHIgoNAQsgDkEBcSIEDQNBACEEIAcoAhAMBQsgCEECdCIVIAdBEGpqIAQ2AgAgBCAFTyIKDXEgCCAHQQhqaiAEIAlqLQAAOgAAQQEhCCAEQQFqIQQgDkEBcSEKQQAhDiAKDQALIA8gEUYhBQsCQCMBRQRAIAUNASAHQQhqIQogB0EwaiEFCyALQT1GQQEjARsEQCAFIBIgCkECIBNBARBmQT0jAUEBRg2OAhoLIwFFBEAgBCEFDAMLCyALQT5GQQEjARsEQEEBQQBB9KQEEPcBQT4jAUEBRg2NAhoLIwFFBEAACwsjAUUEQCAHQQhqIQogB0EwaiEECyALQT9GQQEjARsEQCAEIBIgCiAIIBNBABBmQT8jAUEBRg2MAhoLCyMBBH8gBgUgBy0APCIEQQRGIgoNAiAHKAI4IgZBCE8NbyAHQRBqIAZBAnRqIhAoAgALCyEGIwFFBEAgDSAEOgAMIA0gBiAMajYCCCANIA82AgQgDSAMNgIADIkCCwsjAUUEQCAPIAcoAjBqIQkgBiAFIAxqIgRLIgUNAQsLCyMBRQRAAkAgBCAGTw0AA0AgEiAEIBBqLQAAai0AAEGBAUcNASAGIARBAWoiBEcNAAsM+wELIAQgBkYN+gEgDUEAOgAMIA0gBDYCCCANIAk2AgQgDSAENgIADIYCCwsgC0HAAEZBASMBGwRAIAQQyQFBwAAjAUEBRg2GAhohCQsgC0HBAEZBASMBGwRAIAQQzwFBwQAjAUEBRg2GAhohCAsjAUUEQCAEQQhqKAIAIQULIAtBwgBGQQEjARsEQCAEEMoBQcIAIwFBAUYNhgIaIQ4LIAtBwwBGQQEjARsEQCAEENQBQcMAIwFBAUYNhgIaIRILAkACQAJAIwFFBEAgCQ0BIAhB/wFxIgQNAiAFQYIESyIEDQMLIAtBxABGQQEjARsEQCANIA4gEiAQIAYgFyAREENBxAAjAUEBRg2JAhoLIwFFDYcCCyAEIAhB/wFxIwEbIQQCQAJAIwFFBEAgBA0BIAVBggRLIgQNAgsgC0HFAEZBASMBGwRAIA0gDiASIBAgBiAXIBEQSEHFACMBQQFGDYoCGgsjAUUNiAILIwFFQQAgBCAFQYIESyMBGyIEG0UEQCALQcYARkEBIwEbBEAgDSAOIBIgECAGIBcgERAvQcYAIwFBAUYNigIaCyMBRQ2IAgsgBUEAIwEbIQUCQCMBRQRAIAZFBEBBACEEDAILIAdBKGohGCAHQSBqIRkgB0EYaiEUQQAhBAsDQCMBRQRAIAYgBGsiCEF4cSIJIARqIgogCUkiDA1wIAYgCkkiDA1xIAhBA3ZBA2wiCCAFaiEMIAggDEsiDw1yIAwgEUsiDw1zIAQgEGohDyAFIBdqIRMgB0EQaiEMCyALQccARkEBIwEbBEAgDCAOIBIgDyAJIBMgCBAvQccAIwFBAUYNiwIaCyMBRQRAIAcoAhAhCSAHLQAcQQRHIghFBEAgBSAJaiEFIAohBAwDCyAEIAlqIgogBksiBA10IAUgBygCFGoiDCARSyIEDXUgBiAKayEFIAogEGohCSAHQgA3AwggGEIANwMAIBlCADcDACAUQgA3AwAgB0IANwMQIBEgDGshDyAMIBdqIRNBACEIQQAhBAsCQAJ/AkACQAJAA0AgFSAEIAVPIwEbIRUCQCMBRQRAAkACQCAVDQADQCASIAQgCWotAABqLQAAQYEBRw0BIAUgBEEBaiIERyIVDQALDAELIAQgBUciFQ0CCyAIIAhB+P///wdxIgRGIgkNAyAERSIQDQULIAtByABGQQEjARsEQCAEQQhBhKUEEKYBQcgAIwFBAUYNkgIaCyMBRQRAAAsLIwFFBEAgB0EQaiAIQQJ0aiAENgIAIAQgBU8NfCAHQQhqIAhqIAQgCWotAAA6AAAgBEEBaiEEIAhBAWoiCEEIRyIVDQELCyMBRQRAQQghCEEDIQkMAgsLIwFFBEAgCEEJTyIEDXsgCEEDdkEDbCEJIAUhBAsLIwFFBEAgCSAPSyIFDXsgB0EIaiEPIAdBMGohBQsgC0HJAEZBASMBGwRAIAUgDiASIA8gCCATIAkQL0HJACMBQQFGDY4CGgsjAUUEQCAHLQA8IgVBBEYiCA0DIAcoAjgiBkEITw3pASAGQQJ0IhAgB0EQamoiBCgCAAwCCwsjAQR/IAYFQQAhBSAHKAIQCwshBiMBRQRAIA0gBToADCANIAYgCmo2AgggDSAMNgIEIA0gCjYCAAyLAgsLIwFFBEAgDCAHKAIwaiEFIAYgBCAKaiIESyIKDQELCwsjAUUEQAJAIAQgBk8NAANAIBIgBCAQai0AAGotAABBgQFHDQEgBiAEQQFqIgRHDQALDPwBCyAEIAZGDfsBIA1BADoADCANIAQ2AgggDSAFNgIEIA0gBDYCAAyIAgsLIAVBACMBGyEFAkAjAUUEQCAGRQRAQQAhBAwCCyAHQShqIRggB0EgaiEZIAdBGGohFEEAIQQLA0AjAUUEQCAGIARrIgpBA2wiCEEHcUEDbiEJIAogCWsiCSAEaiIKIAlJIgwNeCAGIApJIgwNeSAIQQN2IgggBWohDCAIIAxLIg8NeiAMIBFLIg8NeyAEIBBqIQ8gBSAXaiETIAdBEGohDAsgC0HKAEZBASMBGwRAIAwgDiASIA8gCSATIAgQSEHKACMBQQFGDYoCGgsjAUUEQCAHKAIQIQkgBy0AHEEERyIIRQRAIAUgCWohBSAKIQQMAwsgBCAJaiIKIAZLIgQNfCAFIAcoAhRqIgwgEUsiBA19IAYgCmshBSAKIBBqIQkgB0IANwMIIBhCADcDACAZQgA3AwAgFEIANwMAIAdCADcDECARIAxrIQ8gDCAXaiETQQAhCEEAIQQLAkACfwJAAkACQANAIBUgBCAFTyMBGyEVAkAjAUUEQAJAAkAgFQ0AA0AgEiAEIAlqLQAAai0AAEGBAUcNASAFIARBAWoiBEcNAAsMAQsgBCAFRyIVDQILIAhBA2wiBEEHcSIJQQNJIhUNAyAIIAlBA25rIgZBCEkiBA0FCyALQcsARkEBIwEbBEAgBkEIQYSlBBCmAUHLACMBQQFGDZECGgsjAUUEQAALCyMBRQRAIAdBEGogCEECdGogBDYCACAEIAVPDYQBIAdBCGogCGogBCAJai0AADoAACAEQQFqIQQgCEEBaiIIQQhHIhUNAQsLIwFFBEBBCCEIQQMhCQwCCwsjAUUEQCAIQQlPIgkNgwEgBEEDdiEJIAUhBAsLIwFFBEAgCSAPSyIFDYMBIAdBCGohDyAHQTBqIQULIAtBzABGQQEjARsEQCAFIA4gEiAPIAggEyAJEEhBzAAjAUEBRg2NAhoLIwFFBEAgBy0APCIFQQRGIggNAyAHKAI4IgZBCE8N6QEgBkECdCIQIAdBEGpqIgQoAgAMAgsLIwEEfyAGBUEAIQUgBkECdCIQIAdBEGpqIgQoAgALCyEGIwFFBEAgDSAFOgAMIA0gBiAKajYCCCANIAw2AgQgDSAKNgIADIoCCwsjAUUEQCAMIAcoAjBqIQUgBiAEIApqIgRLIgoNAQsLCyMBRQRAAkAgBCAGTw0AA0AgEiAEIBBqLQAAai0AAEGBAUcNASAGIARBAWoiBEcNAAsM+gELIAQgBkYN+QEgDUEAOgAMIA0gBDYCCCANIAU2AgQgDSAENgIADIcCCwsjAUVBACAEIAVBggRLIwEbIgQbRQRAIAtBzQBGQQEjARsEQCANIA4gEiAQIAYgFyAREDBBzQAjAUEBRg2IAhoLIwFFDYYCCyAFQQAjARshBQJAIwFFBEAgBkUEQEEAIQQMAgsgB0EoaiEYIAdBIGohGSAHQRhqIRRBACEECwNAIwFFBEAgBiAEayIIQXhxIgkgBGoiCiAJSSIMDYABIAYgCkkiDA2BASAIQQN2QQNsIgggBWohDCAIIAxLIg8NggEgDCARSyIPDYMBIAQgEGohDyAFIBdqIRMgB0EQaiEMCyALQc4ARkEBIwEbBEAgDCAOIBIgDyAJIBMgCBAwQc4AIwFBAUYNiQIaCyMBRQRAIAcoAhAhCSAHLQAcQQRHIghFBEAgBSAJaiEFIAohBAwDCyAEIAlqIgogBksiBA2EASAFIAcoAhRqIgwgEUsiBA2FASAGIAprIQUgCiAQaiEJIAdCADcDCCAYQgA3AwAgGUIANwMAIBRCADcDACAHQgA3AxAgESAMayEPIAwgF2ohE0EAIQhBACEECwJAAn8CQAJAAkADQCAVIAQgBU8jARshFQJAIwFFBEACQAJAIBUNAANAIBIgBCAJai0AAGotAABBgQFHDQEgBSAEQQFqIgRHIhUNAAsMAQsgBCAFRyIVDQILIAggCEH4////B3EiBEYiCQ0DIARFIhANBQsgC0HPAEZBASMBGwRAIARBCEGEpQQQpgFBzwAjAUEBRg2QAhoLIwFFBEAACwsjAUUEQCAHQRBqIAhBAnRqIAQ2AgAgBCAFTw2MASAHQQhqIAhqIAQgCWotAAA6AAAgBEEBaiEEIAhBAWoiCEEIRyIVDQELCyMBRQRAQQghCEEDIQkMAgsLIwFFBEAgCEEJTyIEDYsBIAhBA3ZBA2whCSAFIQQLCyMBRQRAIAkgD0siBQ2LASAHQQhqIQ8gB0EwaiEFCyALQdAARkEBIwEbBEAgBSAOIBIgDyAIIBMgCRAwQdAAIwFBAUYNjAIaCyMBRQRAIActADwiBUEERiIIDQMgBygCOCIGQQhPDekBIAZBAnQiECAHQRBqaiIEKAIADAILCyMBBH8gBgVBACEFIAcoAhALCyEGIwFFBEAgDSAFOgAMIA0gBiAKajYCCCANIAw2AgQgDSAKNgIADIkCCwsjAUUEQCAMIAcoAjBqIQUgBiAEIApqIgRLIgoNAQsLCyMBRQRAAkAgBCAGTw0AA0AgEiAEIBBqLQAAai0AAEGBAUcNASAGIARBAWoiBEcNAAsM+AELIAQgBkYN9wEgDUEAOgAMIA0gBDYCCCANIAU2AgQgDSAENgIADIYCCwsgBUEAIwEbIQUCQCMBRQRAIAZFBEBBACEEDAILIAdBKGohGCAHQSBqIRkgB0EYaiEUQQAhBAsDQCMBRQRAIAYgBGsiCkEDbCIIQQdxQQNuIQkgCiAJayIJIARqIgogCUkiDA2IASAGIApJIgwNiQEgCEEDdiIIIAVqIQwgCCAMSyIPDYoBIAwgEUsiDw2LASAEIBBqIQ8gBSAXaiETIAdBEGohDAsgC0HRAEZBASMBGwRAIAwgDiASIA8gCSATIAgQQ0HRACMBQQFGDYgCGgsjAUUEQCAHKAIQIQkgBy0AHEEERyIIRQRAIAUgCWohBSAKIQQMAwsgBCAJaiIKIAZLIgQNjAEgBSAHKAIUaiIMIBFLIgQNjQEgBiAKayEFIAogEGohCSAHQgA3AwggGEIANwMAIBlCADcDACAUQgA3AwAgB0IANwMQIBEgDGshDyAMIBdqIRNBACEIQQAhBAsCQAJ/AkACQAJAA0AgFSAEIAVPIwEbIRUCQCMBRQRAAkACQCAVDQADQCASIAQgCWotAABqLQAAQYEBRw0BIAUgBEEBaiIERw0ACwwBCyAEIAVHIhUNAgsgCEEDbCIEQQdxIglBA0kiFQ0DIAggCUEDbmsiBkEISSIEDQULIAtB0gBGQQEjARsEQCAGQQhBhKUEEKYBQdIAIwFBAUYNjwIaCyMBRQRAAAsLIwFFBEAgB0EQaiAIQQJ0aiAENgIAIAQgBU8NlAEgB0EIaiAIaiAEIAlqLQAAOgAAIARBAWohBCAIQQFqIghBCEciFQ0BCwsjAUUEQEEIIQhBAyEJDAILCyMBRQRAIAhBCU8iCQ2TASAEQQN2IQkgBSEECwsjAUUEQCAJIA9LIgUNkwEgB0EIaiEPIAdBMGohBQsgC0HTAEZBASMBGwRAIAUgDiASIA8gCCATIAkQQ0HTACMBQQFGDYsCGgsjAUUEQCAHLQA8IgVBBEYiCA0DIAcoAjgiBkEITw3pASAGQQJ0IhAgB0EQamoiBCgCAAwCCwsjAQR/IAYFQQAhBSAGQQJ0IhAgB0EQamoiBCgCAAsLIQYjAUUEQCANIAU6AAwgDSAGIApqNgIIIA0gDDYCBCANIAo2AgAMiAILCyMBRQRAIAwgBygCMGohBSAGIAQgCmoiBEsiCg0BCwsLIwFFBEACQCAEIAZPDQADQCASIAQgEGotAABqLQAAQYEBRw0BIAYgBEEBaiIERw0ACwz2AQsgBCAGRg31ASANQQA6AAwgDSAENgIIIA0gBTYCBCANIAQ2AgAMhQILCyALQdQARkEBIwEbBEAgBBDJAUHUACMBQQFGDYUCGiEJCyALQdUARkEBIwEbBEAgBBDPAUHVACMBQQFGDYUCGiEICyMBRQRAIARBCGooAgAhBQsgC0HWAEZBASMBGwRAIAQQygFB1gAjAUEBRg2FAhohCgsgC0HXAEZBASMBGwRAIAQQ1AFB1wAjAUEBRg2FAhohEgsCQAJAAkAjAUUEQCAJDQEgCEH/AXEiBA0CIAVBggRLIgQNAwsgC0HYAEZBASMBGwRAIA0gEiAQIAYgFyAREGhB2AAjAUEBRg2IAhoLIwFFDYYCCyAEIAhB/wFxIwEbIQQCQAJAIwFFBEAgBA0BIAVBggRLIgQNAgsgC0HZAEZBASMBGwRAIA0gEiAQIAYgFyAREGlB2QAjAUEBRg2JAhoLIwFFDYcCCyMBRUEAIAQgBUGCBEsjARsiBBtFBEAgC0HaAEZBASMBGwRAIA0gEiAQIAYgFyAREDdB2gAjAUEBRg2JAhoLIwFFDYcCCyAFQQAjARshBQJAIwFFBEAgBkUEQEEAIQQMAgsgB0EoaiETIAdBIGohGCAHQRhqIRlBACEECwNAIwFFBEAgBiAEayIIQXxxIgkgBGohDiAJIA5LIgoNkwEgBiAOSSIKDZQBIAhBAnYiCCAFaiIKIAhJIgwNlQEgCiARSyIMDZYBIAQgEGohDCAFIBdqIQ8gB0EQaiEKCyALQdsARkEBIwEbBEAgCiASIAwgCSAPIAgQN0HbACMBQQFGDYoCGgsjAUUEQCAHKAIQIQkgBy0AHEEERyIKRQRAIAUgCWohBSAOIQQMAwsgBCAJaiIOIAZLIgQNlwEgBSAHKAIUaiIKIBFLIgQNmAEgBiAOayEFIA4gEGohCSAHQgA3AwggE0IANwMAIBhCADcDACAZQgA3AwAgB0IANwMQIBEgCmshDCAKIBdqIQ9BACEIQQAhBAsCQAJ/AkACQAJAA0AgFCAEIAVPIwEbIRQCQCMBRQRAAkACQCAUDQADQCASIAQgCWotAABqLQAAQYEBRw0BIAUgBEEBaiIERyIUDQALDAELIAQgBUciFA0CCyAIIAhB/P///wdxIgRGIgkNAyAEQQhJIhANBQsgC0HcAEZBASMBGwRAIARBCEGEpQQQpgFB3AAjAUEBRg2RAhoLIwFFBEAACwsjAUUEQCAHQRBqIAhBAnRqIAQ2AgAgBCAFTw2fASAHQQhqIAhqIAQgCWotAAA6AAAgBEEBaiEEIAhBAWoiCEEERyIUDQELCyMBRQRAQQQhCEEBIQkMAgsLIwFFBEAgCEEJTyIEDZ4BIAhBAnYhCSAFIQQLCyMBRQRAIAkgDEsiBQ2eASAHQQhqIQwgB0EwaiEFCyALQd0ARkEBIwEbBEAgBSASIAwgCCAPIAkQN0HdACMBQQFGDY0CGgsjAUUEQCAHLQA8IgVBBEYiCA0DIAcoAjgiBkEITw3sASAGQQJ0IhAgB0EQamoiBCgCAAwCCwsjAQR/IAYFQQAhBSAHQRBqIhAgBEECdGoiBCgCAAsLIQYjAUUEQCANIAU6AAwgDSAGIA5qNgIIIA0gCjYCBCANIA42AgAMigILCyMBRQRAIAogBygCMGohBSAGIAQgDmoiBEsiCg0BCwsLIwFFBEACQCAEIAZPDQADQCASIAQgEGotAABqLQAAQYEBRw0BIAYgBEEBaiIERw0ACwz3AQsgBCAGRg32ASANQQA6AAwgDSAENgIIIA0gBTYCBCANIAQ2AgAMhwILCyAFQQAjARshBQJAIwFFBEAgBkUEQEEAIQQMAgsgB0EoaiETIAdBIGohGCAHQRhqIRlBACEECwNAIwFFBEAgBiAEayIIQXxxIgkgBGohDiAJIA5LIgoNmwEgBiAOSSIKDZwBIAhBAnZB/////wFxIgggBWoiCiAISSIMDZ0BIAogEUsiDA2eASAEIBBqIQwgBSAXaiEPIAdBEGohCgsgC0HeAEZBASMBGwRAIAogEiAMIAkgDyAIEGlB3gAjAUEBRg2JAhoLIwFFBEAgBygCECEJIActABxBBEciCkUEQCAFIAlqIQUgDiEEDAMLIAQgCWoiDiAGSyIEDZ8BIAUgBygCFGoiCiARSyIEDaABIAYgDmshBSAOIBBqIQkgB0IANwMIIBNCADcDACAYQgA3AwAgGUIANwMAIAdCADcDECARIAprIQwgCiAXaiEPQQAhCEEAIQQLAkACfwJAAkACQANAIBQgBCAFTyMBGyEUAkAjAUUEQAJAAkAgFA0AA0AgEiAEIAlqLQAAai0AAEGBAUcNASAFIARBAWoiBEciFA0ACwwBCyAEIAVHIhQNAgsgCCAIQfz///8HcSIERiIJDQMgBEEISSIQDQULIAtB3wBGQQEjARsEQCAEQQhBhKUEEKYBQd8AIwFBAUYNkAIaCyMBRQRAAAsLIwFFBEAgB0EQaiAIQQJ0aiAENgIAIAQgBU8NpwEgB0EIaiAIaiAEIAlqLQAAOgAAIARBAWohBCAIQQFqIghBBEciFA0BCwsjAUUEQEEEIQhBASEJDAILCyMBRQRAIAhBCU8iBA2mASAIQQJ2IQkgBSEECwsjAUUEQCAJIAxLIgUNpgEgB0EIaiEMIAdBMGohBQsgC0HgAEZBASMBGwRAIAUgEiAMIAggDyAJEGlB4AAjAUEBRg2MAhoLIwFFBEAgBy0APCIFQQRGIggNAyAHKAI4IgZBCE8N7AEgBkECdCIQIAdBEGpqIgQoAgAMAgsLIwEEfyAGBUEAIQUgB0EQaiIQIARBAnRqIgQoAgALCyEGIwFFBEAgDSAFOgAMIA0gBiAOajYCCCANIAo2AgQgDSAONgIADIkCCwsjAUUEQCAKIAcoAjBqIQUgBiAEIA5qIgRLIgoNAQsLCyMBRQRAAkAgBCAGTw0AA0AgEiAEIBBqLQAAai0AAEGBAUcNASAGIARBAWoiBEcNAAsM9QELIAQgBkYN9AEgDUEAOgAMIA0gBDYCCCANIAU2AgQgDSAENgIADIYCCwsjAUVBACAEIAVBggRLIwEbIgQbRQRAIAtB4QBGQQEjARsEQCANIBIgECAGIBcgERA4QeEAIwFBAUYNhwIaCyMBRQ2FAgsgBUEAIwEbIQUCQCMBRQRAIAZFBEBBACEEDAILIAdBKGohEyAHQSBqIRggB0EYaiEZQQAhBAsDQCMBRQRAIAYgBGsiCEF8cSIJIARqIQ4gCSAOSyIKDaMBIAYgDkkiCg2kASAIQQJ2IgggBWoiCiAISSIMDaUBIAogEUsiDA2mASAEIBBqIQwgBSAXaiEPIAdBEGohCgsgC0HiAEZBASMBGwRAIAogEiAMIAkgDyAIEDhB4gAjAUEBRg2IAhoLIwFFBEAgBygCECEJIActABxBBEciCkUEQCAFIAlqIQUgDiEEDAMLIAQgCWoiDiAGSyIEDacBIAUgBygCFGoiCiARSyIEDagBIAYgDmshBSAOIBBqIQkgB0IANwMIIBNCADcDACAYQgA3AwAgGUIANwMAIAdCADcDECARIAprIQwgCiAXaiEPQQAhCEEAIQQLAkACfwJAAkACQANAIBQgBCAFTyMBGyEUAkAjAUUEQAJAAkAgFA0AA0AgEiAEIAlqLQAAai0AAEGBAUcNASAFIARBAWoiBEciFA0ACwwBCyAEIAVHIhQNAgsgCCAIQfz///8HcSIERiIJDQMgBEEISSIQDQULIAtB4wBGQQEjARsEQCAEQQhBhKUEEKYBQeMAIwFBAUYNjwIaCyMBRQRAAAsLIwFFBEAgB0EQaiAIQQJ0aiAENgIAIAQgBU8NrwEgB0EIaiAIaiAEIAlqLQAAOgAAIARBAWohBCAIQQFqIghBBEciFA0BCwsjAUUEQEEEIQhBASEJDAILCyMBRQRAIAhBCU8iBA2uASAIQQJ2IQkgBSEECwsjAUUEQCAJIAxLIgUNrgEgB0EIaiEMIAdBMGohBQsgC0HkAEZBASMBGwRAIAUgEiAMIAggDyAJEDhB5AAjAUEBRg2LAhoLIwFFBEAgBy0APCIFQQRGIggNAyAHKAI4IgZBCE8N7AEgBkECdCIQIAdBEGpqIgQoAgAMAgsLIwEEfyAGBUEAIQUgB0EQaiIQIARBAnRqIgQoAgALCyEGIwFFBEAgDSAFOgAMIA0gBiAOajYCCCANIAo2AgQgDSAONgIADIgCCwsjAUUEQCAKIAcoAjBqIQUgBiAEIA5qIgRLIgoNAQsLCyMBRQRAAkAgBCAGTw0AA0AgEiAEIBBqLQAAai0AAEGBAUcNASAGIARBAWoiBEcNAAsM8wELIAQgBkYN8gEgDUEAOgAMIA0gBDYCCCANIAU2AgQgDSAENgIADIUCCwsgBUEAIwEbIQUCQCMBRQRAIAZFBEBBACEEDAILIAdBKGohEyAHQSBqIRggB0EYaiEZQQAhBAsDQCMBRQRAIAYgBGsiCEF8cSIJIARqIQ4gCSAOSyIKDasBIAYgDkkiCg2sASAIQQJ2Qf////8BcSIIIAVqIgogCEkiDA2tASAKIBFLIgwNrgEgBCAQaiEMIAUgF2ohDyAHQRBqIQoLIAtB5QBGQQEjARsEQCAKIBIgDCAJIA8gCBBoQeUAIwFBAUYNhwIaCyMBRQRAIAcoAhAhCSAHLQAcQQRHIgpFBEAgBSAJaiEFIA4hBAwDCyAEIAlqIg4gBksiBA2vASAFIAcoAhRqIgogEUsiBA2wASAGIA5rIQUgDiAQaiEJIAdCADcDCCATQgA3AwAgGEIANwMAIBlCADcDACAHQgA3AxAgESAKayEMIAogF2ohD0EAIQhBACEECwJAAn8CQAJAAkADQCAUIAQgBU8jARshFAJAIwFFBEACQAJAIBQNAANAIBIgBCAJai0AAGotAABBgQFHDQEgBSAEQQFqIgRHIhQNAAsMAQsgBCAFRyIUDQILIAggCEH8////B3EiBEYiCQ0DIARBCEkiEA0FCyALQeYARkEBIwEbBEAgBEEIQYSlBBCmAUHmACMBQQFGDY4CGgsjAUUEQAALCyMBRQRAIAdBEGogCEECdGogBDYCACAEIAVPDbcBIAdBCGogCGogBCAJai0AADoAACAEQQFqIQQgCEEBaiIIQQRHIhQNAQsLIwFFBEBBBCEIQQEhCQwCCwsjAUUEQCAIQQlPIgQNtgEgCEECdiEJIAUhBAsLIwFFBEAgCSAMSyIFDbYBIAdBCGohDCAHQTBqIQULIAtB5wBGQQEjARsEQCAFIBIgDCAIIA8gCRBoQecAIwFBAUYNigIaCyMBRQRAIActADwiBUEERiIIDQMgBygCOCIGQQhPDewBIAZBAnQiECAHQRBqaiIEKAIADAILCyMBBH8gBgVBACEFIAdBEGoiECAEQQJ0aiIEKAIACwshBiMBRQRAIA0gBToADCANIAYgDmo2AgggDSAKNgIEIA0gDjYCAAyHAgsLIwFFBEAgCiAHKAIwaiEFIAYgBCAOaiIESyIKDQELCwsjAUUEQAJAIAQgBk8NAANAIBIgBCAQai0AAGotAABBgQFHDQEgBiAEQQFqIgRHDQALDPEBCyAEIAZGDfABIA1BADoADCANIAQ2AgggDSAFNgIEIA0gBDYCAAyEAgsLIAtB6ABGQQEjARsEQCAEEMkBQegAIwFBAUYNhAIaIQkLIAtB6QBGQQEjARsEQCAEEM8BQekAIwFBAUYNhAIaIQgLIwFFBEAgBEEIaigCACEFCyALQeoARkEBIwEbBEAgBBDKAUHqACMBQQFGDYQCGiEKCyALQesARkEBIwEbBEAgBBDUAUHrACMBQQFGDYQCGiESCwJAAkACQCMBRQRAIAkNASAIQf8BcSIEDQIgBUGCBEsiBA0DCyALQewARkEBIwEbBEAgDSASIBAgBiAXIBEQakHsACMBQQFGDYcCGgsjAUUNhQILIAQgCEH/AXEjARshBAJAAkAjAUUEQCAEDQEgBUGCBEsiBA0CCyALQe0ARkEBIwEbBEAgDSASIBAgBiAXIBEQa0HtACMBQQFGDYgCGgsjAUUNhgILIwFFQQAgBCAFQYIESyMBGyIEG0UEQCALQe4ARkEBIwEbBEAgDSASIBAgBiAXIBEQOUHuACMBQQFGDYgCGgsjAUUNhgILIAVBACMBGyEFAkAjAUUEQCAGRQRAQQAhBAwCCyAHQShqIRMgB0EgaiEYIAdBGGohGUEAIQQLA0AjAUUEQCAGIARrIghBeHEiCSAEaiEOIAkgDksiCg22ASAGIA5JIgoNtwEgCEEDdiIIIAVqIgogCEkiDA24ASAKIBFLIgwNuQEgBCAQaiEMIAUgF2ohDyAHQRBqIQoLIAtB7wBGQQEjARsEQCAKIBIgDCAJIA8gCBA5Qe8AIwFBAUYNiQIaCyMBRQRAIAcoAhAhCSAHLQAcQQRHIgpFBEAgBSAJaiEFIA4hBAwDCyAEIAlqIg4gBksiBA26ASAFIAcoAhRqIgogEUsiBA27ASAGIA5rIQUgDiAQaiEJIAdCADcDCCATQgA3AwAgGEIANwMAIBlCADcDACAHQgA3AxAgESAKayEMIAogF2ohD0EAIQhBACEECwJAAn8CQAJAAkADQCAUIAQgBU8jARshFAJAIwFFBEACQAJAIBQNAANAIBIgBCAJai0AAGotAABBgQFHDQEgBSAEQQFqIgRHIhQNAAsMAQsgBCAFRyIUDQILIAggCEH4////B3EiBEYiCQ0DIARFIhANBQsgC0HwAEZBASMBGwRAIARBCEGEpQQQpgFB8AAjAUEBRg2QAhoLIwFFBEAACwsjAUUEQCAHQRBqIAhBAnRqIAQ2AgAgBCAFTw3CASAHQQhqIAhqIAQgCWotAAA6AAAgBEEBaiEEIAhBAWoiCEEIRyIUDQELCyMBRQRAQQghCEEBIQkMAgsLIwFFBEAgCEEJTyIEDcEBIAhBA3YhCSAFIQQLCyMBRQRAIAkgDEsiBQ3BASAHQQhqIQwgB0EwaiEFCyALQfEARkEBIwEbBEAgBSASIAwgCCAPIAkQOUHxACMBQQFGDYwCGgsjAUUEQCAHLQA8IgVBBEYiCA0DIAcoAjgiBkEITw3vASAGQQJ0IhAgB0EQamoiBCgCAAwCCwsjAQR/IAYFQQAhBSAHKAIQCwshBiMBRQRAIA0gBToADCANIAYgDmo2AgggDSAKNgIEIA0gDjYCAAyJAgsLIwFFBEAgCiAHKAIwaiEFIAYgBCAOaiIESyIKDQELCwsjAUUEQAJAIAQgBk8NAANAIBIgBCAQai0AAGotAABBgQFHDQEgBiAEQQFqIgRHDQALDPIBCyAEIAZGDfEBIA1BADoADCANIAQ2AgggDSAFNgIEIA0gBDYCAAyGAgsLIAVBACMBGyEFAkAjAUUEQCAGRQRAQQAhBAwCCyAHQShqIRMgB0EgaiEYIAdBGGohGUEAIQQLA0AjAUUEQCAGIARrIghBeHEiCSAEaiEOIAkgDksiCg2+ASAGIA5JIgoNvwEgCEEDdiIIIAVqIgogCEkiDA3AASAKIBFLIgwNwQEgBCAQaiEMIAUgF2ohDyAHQRBqIQoLIAtB8gBGQQEjARsEQCAKIBIgDCAJIA8gCBBrQfIAIwFBAUYNiAIaCyMBRQRAIAcoAhAhCSAHLQAcQQRHIgpFBEAgBSAJaiEFIA4hBAwDCyAEIAlqIg4gBksiBA3CASAFIAcoAhRqIgogEUsiBA3DASAGIA5rIQUgDiAQaiEJIAdCADcDCCATQgA3AwAgGEIANwMAIBlCADcDACAHQgA3AxAgESAKayEMIAogF2ohD0EAIQhBACEECwJAAn8CQAJAAkADQCAUIAQgBU8jARshFAJAIwFFBEACQAJAIBQNAANAIBIgBCAJai0AAGotAABBgQFHDQEgBSAEQQFqIgRHIhQNAAsMAQsgBCAFRyIUDQILIAggCEH4////B3EiBEYiCQ0DIARFIhANBQsgC0HzAEZBASMBGwRAIARBCEGEpQQQpgFB8wAjAUEBRg2PAhoLIwFFBEAACwsjAUUEQCAHQRBqIAhBAnRqIAQ2AgAgBCAFTw3KASAHQQhqIAhqIAQgCWotAAA6AAAgBEEBaiEEIAhBAWoiCEEIRyIUDQELCyMBRQRAQQghCEEBIQkMAgsLIwFFBEAgCEEJTyIEDckBIAhBA3YhCSAFIQQLCyMBRQRAIAkgDEsiBQ3JASAHQQhqIQwgB0EwaiEFCyALQfQARkEBIwEbBEAgBSASIAwgCCAPIAkQa0H0ACMBQQFGDYsCGgsjAUUEQCAHLQA8IgVBBEYiCA0DIAcoAjgiBkEITw3vASAGQQJ0IhAgB0EQamoiBCgCAAwCCwsjAQR/IAYFQQAhBSAHKAIQCwshBiMBRQRAIA0gBToADCANIAYgDmo2AgggDSAKNgIEIA0gDjYCAAyIAgsLIwFFBEAgCiAHKAIwaiEFIAYgBCAOaiIESyIKDQELCwsjAUUEQAJAIAQgBk8NAANAIBIgBCAQai0AAGotAABBgQFHDQEgBiAEQQFqIgRHDQALDPABCyAEIAZGDe8BIA1BADoADCANIAQ2AgggDSAFNgIEIA0gBDYCAAyFAgsLIwFFQQAgBCAFQYIESyMBGyIEG0UEQCALQfUARkEBIwEbBEAgDSASIBAgBiAXIBEQOkH1ACMBQQFGDYYCGgsjAUUNhAILIAVBACMBGyEFAkAjAUUEQCAGRQRAQQAhBAwCCyAHQShqIRMgB0EgaiEYIAdBGGohGUEAIQQLA0AjAUUEQCAGIARrIghBeHEiCSAEaiEOIAkgDksiCg3GASAGIA5JIgoNxwEgCEEDdiIIIAVqIgogCEkiDA3IASAKIBFLIgwNyQEgBCAQaiEMIAUgF2ohDyAHQRBqIQoLIAtB9gBGQQEjARsEQCAKIBIgDCAJIA8gCBA6QfYAIwFBAUYNhwIaCyMBRQRAIAcoAhAhCSAHLQAcQQRHIgpFBEAgBSAJaiEFIA4hBAwDCyAEIAlqIg4gBksiBA3KASAFIAcoAhRqIgogEUsiBA3LASAGIA5rIQUgDiAQaiEJIAdCADcDCCATQgA3AwAgGEIANwMAIBlCADcDACAHQgA3AxAgESAKayEMIAogF2ohD0EAIQhBACEECwJAAn8CQAJAAkADQCAUIAQgBU8jARshFAJAIwFFBEACQAJAIBQNAANAIBIgBCAJai0AAGotAABBgQFHDQEgBSAEQQFqIgRHIhQNAAsMAQsgBCAFRyIUDQILIAggCEH4////B3EiBEYiCQ0DIARFIhANBQsgC0H3AEZBASMBGwRAIARBCEGEpQQQpgFB9wAjAUEBRg2OAhoLIwFFBEAACwsjAUUEQCAHQRBqIAhBAnRqIAQ2AgAgBCAFTw3SASAHQQhqIAhqIAQgCWotAAA6AAAgBEEBaiEEIAhBAWoiCEEIRyIUDQELCyMBRQRAQQghCEEBIQkMAgsLIwFFBEAgCEEJTyIEDdEBIAhBA3YhCSAFIQQLCyMBRQRAIAkgDEsiBQ3RASAHQQhqIQwgB0EwaiEFCyALQfgARkEBIwEbBEAgBSASIAwgCCAPIAkQOkH4ACMBQQFGDYoCGgsjAUUEQCAHLQA8IgVBBEYiCA0DIAcoAjgiBkEITw3vASAGQQJ0IhAgB0EQamoiBCgCAAwCCwsjAQR/IAYFQQAhBSAHKAIQCwshBiMBRQRAIA0gBToADCANIAYgDmo2AgggDSAKNgIEIA0gDjYCAAyHAgsLIwFFBEAgCiAHKAIwaiEFIAYgBCAOaiIESyIKDQELCwsjAUUEQAJAIAQgBk8NAANAIBIgBCAQai0AAGotAABBgQFHDQEgBiAEQQFqIgRHDQALDO4BCyAEIAZGDe0BIA1BADoADCANIAQ2AgggDSAFNgIEIA0gBDYCAAyEAgsLIAVBACMBGyEFAkAjAUUEQCAGRQRAQQAhBAwCCyAHQShqIRMgB0EgaiEYIAdBGGohGUEAIQQLA0AjAUUEQCAGIARrIghBeHEiCSAEaiEOIAkgDksiCg3OASAGIA5JIgoNzwEgCEEDdiIIIAVqIgogCEkiDA3QASAKIBFLIgwN0QEgBCAQaiEMIAUgF2ohDyAHQRBqIQoLIAtB+QBGQQEjARsEQCAKIBIgDCAJIA8gCBBqQfkAIwFBAUYNhgIaCyMBRQRAIAcoAhAhCSAHLQAcQQRHIgpFBEAgBSAJaiEFIA4hBAwDCyAEIAlqIg4gBksiBA3SASAFIAcoAhRqIgogEUsiBA3TASAGIA5rIQUgDiAQaiEJIAdCADcDCCATQgA3AwAgGEIANwMAIBlCADcDACAHQgA3AxAgESAKayEMIAogF2ohD0EAIQhBACEECwJAAn8CQAJAAkADQCAUIAQgBU8jARshFAJAIwFFBEACQAJAIBQNAANAIBIgBCAJai0AAGotAABBgQFHDQEgBSAEQQFqIgRHIhQNAAsMAQsgBCAFRyIUDQILIAggCEH4////B3EiBEYiCQ0DIARFIhANBQsgC0H6AEZBASMBGwRAIARBCEGEpQQQpgFB+gAjAUEBRg2NAhoLIwFFBEAACwsjAUUEQCAHQRBqIAhBAnRqIAQ2AgAgBCAFTw3aASAHQQhqIAhqIAQgCWotAAA6AAAgBEEBaiEEIAhBAWoiCEEIRyIUDQELCyMBRQRAQQghCEEBIQkMAgsLIwFFBEAgCEEJTw3ZASAIQQN2IQkgBSEECwsjAUUEQCAJIAxLIgUN2QEgB0EIaiEMIAdBMGohBQsgC0H7AEZBASMBGwRAIAUgEiAMIAggDyAJEGpB+wAjAUEBRg2JAhoLIwFFBEAgBy0APCIFQQRGIggNAyAHKAI4IgZBCE8N7wEgBkECdCIQIAdBEGpqIgQoAgAMAgsLIwEEfyAGBUEAIQUgBygCEAsLIQYjAUUEQCANIAU6AAwgDSAGIA5qNgIIIA0gCjYCBCANIA42AgAMhgILCyMBRQRAIAogBygCMGohBSAGIAQgDmoiBEsiCg0BCwsLIwFFBEACQCAEIAZPDQADQCASIAQgEGotAABqLQAAQYEBRw0BIAYgBEEBaiIERw0ACwzsAQsgBCAGRg3rASANQQA6AAwgDSAENgIIIA0gBTYCBCANIAQ2AgAMgwILCyALQfwARkEBIwEbBEAgBCAKQaSlBBD4AUH8ACMBQQFGDYMCGgsjAUUEQAALCyALQf0ARkEBIwEbBEAgCiAGQaSlBBD3AUH9ACMBQQFGDYICGgsjAUUEQAALCyALQf4ARkEBIwEbBEAgBSAMQbSlBBD4AUH+ACMBQQFGDYECGgsjAUUEQAALCyALQf8ARkEBIwEbBEAgDCARQbSlBBD3AUH/ACMBQQFGDYACGgsjAUUEQAALCyALQYABRkEBIwEbBEAgCiAGQcSlBBD2AUGAASMBQQFGDf8BGgsjAUUEQAALCyALQYEBRkEBIwEbBEAgDCARQdSlBBD2AUGBASMBQQFGDf4BGgsjAUUEQAALCyALQYIBRkEBIwEbBEAgBCAFQdSkBBCmAUGCASMBQQFGDf0BGgsjAUUEQAALCyALQYMBRkEBIwEbBEAgCEEIQeSkBBD3AUGDASMBQQFGDfwBGgsjAUUEQAALCyALQYQBRkEBIwEbBEAgCSAPQfSkBBD3AUGEASMBQQFGDfsBGgsjAUUEQAALCyALQYUBRkEBIwEbBEAgBCAKQaSlBBD4AUGFASMBQQFGDfoBGgsjAUUEQAALCyALQYYBRkEBIwEbBEAgCiAGQaSlBBD3AUGGASMBQQFGDfkBGgsjAUUEQAALCyALQYcBRkEBIwEbBEAgBSAMQbSlBBD4AUGHASMBQQFGDfgBGgsjAUUEQAALCyALQYgBRkEBIwEbBEAgDCARQbSlBBD3AUGIASMBQQFGDfcBGgsjAUUEQAALCyALQYkBRkEBIwEbBEAgCiAGQcSlBBD2AUGJASMBQQFGDfYBGgsjAUUEQAALCyALQYoBRkEBIwEbBEAgDCARQdSlBBD2AUGKASMBQQFGDfUBGgsjAUUEQAALCyALQYsBRkEBIwEbBEAgBCAFQdSkBBCmAUGLASMBQQFGDfQBGgsjAUUEQAALCyALQYwBRkEBIwEbBEAgCEEIQeSkBBD3AUGMASMBQQFGDfMBGgsjAUUEQAALCyALQY0BRkEBIwEbBEAgCSAPQfSkBBD3AUGNASMBQQFGDfIBGgsjAUUEQAALCyALQY4BRkEBIwEbBEAgBCAKQaSlBBD4AUGOASMBQQFGDfEBGgsjAUUEQAALCyALQY8BRkEBIwEbBEAgCiAGQaSlBBD3AUGPASMBQQFGDfABGgsjAUUEQAALCyALQZABRkEBIwEbBEAgBSAMQbSlBBD4AUGQASMBQQFGDe8BGgsjAUUEQAALCyALQZEBRkEBIwEbBEAgDCARQbSlBBD3AUGRASMBQQFGDe4BGgsjAUUEQAALCyALQZIBRkEBIwEbBEAgCiAGQcSlBBD2AUGSASMBQQFGDe0BGgsjAUUEQAALCyALQZMBRkEBIwEbBEAgDCARQdSlBBD2AUGTASMBQQFGDewBGgsjAUUEQAALCyALQZQBRkEBIwEbBEAgBCAFQdSkBBCmAUGUASMBQQFGDesBGgsjAUUEQAALCyALQZUBRkEBIwEbBEAgCEEIQeSkBBD3AUGVASMBQQFGDeoBGgsjAUUEQAALCyALQZYBRkEBIwEbBEAgCSAPQfSkBBD3AUGWASMBQQFGDekBGgsjAUUEQAALCyALQZcBRkEBIwEbBEAgBCAKQaSlBBD4AUGXASMBQQFGDegBGgsjAUUEQAALCyALQZgBRkEBIwEbBEAgCiAGQaSlBBD3AUGYASMBQQFGDecBGgsjAUUEQAALCyALQZkBRkEBIwEbBEAgBSAMQbSlBBD4AUGZASMBQQFGDeYBGgsjAUUEQAALCyALQZoBRkEBIwEbBEAgDCARQbSlBBD3AUGaASMBQQFGDeUBGgsjAUUEQAALCyALQZsBRkEBIwEbBEAgCiAGQcSlBBD2AUGbASMBQQFGDeQBGgsjAUUEQAALCyALQZwBRkEBIwEbBEAgDCARQdSlBBD2AUGcASMBQQFGDeMBGgsjAUUEQAALCyALQZ0BRkEBIwEbBEAgBCAFQdSkBBCmAUGdASMBQQFGDeIBGgsjAUUEQAALCyALQZ4BRkEBIwEbBEAgCEEIQeSkBBD3AUGeASMBQQFGDeEBGgsjAUUEQAALCyALQZ8BRkEBIwEbBEAgCSAPQfSkBBD3AUGfASMBQQFGDeABGgsjAUUEQAALCyALQaABRkEBIwEbBEAgBCAKQaSlBBD4AUGgASMBQQFGDd8BGgsjAUUEQAALCyALQaEBRkEBIwEbBEAgCiAGQaSlBBD3AUGhASMBQQFGDd4BGgsjAUUEQAALCyALQaIBRkEBIwEbBEAgBSAMQbSlBBD4AUGiASMBQQFGDd0BGgsjAUUEQAALCyALQaMBRkEBIwEbBEAgDCARQbSlBBD3AUGjASMBQQFGDdwBGgsjAUUEQAALCyALQaQBRkEBIwEbBEAgCiAGQcSlBBD2AUGkASMBQQFGDdsBGgsjAUUEQAALCyALQaUBRkEBIwEbBEAgDCARQdSlBBD2AUGlASMBQQFGDdoBGgsjAUUEQAALCyALQaYBRkEBIwEbBEAgBCAFQdSkBBCmAUGmASMBQQFGDdkBGgsjAUUEQAALCyALQacBRkEBIwEbBEAgCEEIQeSkBBD3AUGnASMBQQFGDdgBGgsjAUUEQAALCyALQagBRkEBIwEbBEAgCSAPQfSkBBD3AUGoASMBQQFGDdcBGgsjAUUEQAALCyALQakBRkEBIwEbBEAgBCAKQaSlBBD4AUGpASMBQQFGDdYBGgsjAUUEQAALCyALQaoBRkEBIwEbBEAgCiAGQaSlBBD3AUGqASMBQQFGDdUBGgsjAUUEQAALCyALQasBRkEBIwEbBEAgBSAMQbSlBBD4AUGrASMBQQFGDdQBGgsjAUUEQAALCyALQawBRkEBIwEbBEAgDCARQbSlBBD3AUGsASMBQQFGDdMBGgsjAUUEQAALCyALQa0BRkEBIwEbBEAgCiAGQcSlBBD2AUGtASMBQQFGDdIBGgsjAUUEQAALCyALQa4BRkEBIwEbBEAgDCARQdSlBBD2AUGuASMBQQFGDdEBGgsjAUUEQAALCyALQa8BRkEBIwEbBEAgBCAFQdSkBBCmAUGvASMBQQFGDdABGgsjAUUEQAALCyALQbABRkEBIwEbBEAgCEEIQeSkBBD3AUGwASMBQQFGDc8BGgsjAUUEQAALCyALQbEBRkEBIwEbBEAgCSAPQfSkBBD3AUGxASMBQQFGDc4BGgsjAUUEQAALCyALQbIBRkEBIwEbBEAgBCAKQaSlBBD4AUGyASMBQQFGDc0BGgsjAUUEQAALCyALQbMBRkEBIwEbBEAgCiAGQaSlBBD3AUGzASMBQQFGDcwBGgsjAUUEQAALCyALQbQBRkEBIwEbBEAgBSAMQbSlBBD4AUG0ASMBQQFGDcsBGgsjAUUEQAALCyALQbUBRkEBIwEbBEAgDCARQbSlBBD3AUG1ASMBQQFGDcoBGgsjAUUEQAALCyALQbYBRkEBIwEbBEAgCiAGQcSlBBD2AUG2ASMBQQFGDckBGgsjAUUEQAALCyALQbcBRkEBIwEbBEAgDCARQdSlBBD2AUG3ASMBQQFGDcgBGgsjAUUEQAALCyALQbgBRkEBIwEbBEAgBCAFQdSkBBCmAUG4ASMBQQFGDccBGgsjAUUEQAALCyALQbkBRkEBIwEbBEAgCEEIQeSkBBD3AUG5ASMBQQFGDcYBGgsjAUUEQAALCyALQboBRkEBIwEbBEAgCSAPQfSkBBD3AUG6ASMBQQFGDcUBGgsjAUUEQAALCyALQbsBRkEBIwEbBEAgBCAKQaSlBBD4AUG7ASMBQQFGDcQBGgsjAUUEQAALCyALQbwBRkEBIwEbBEAgCiAGQaSlBBD3AUG8ASMBQQFGDcMBGgsjAUUEQAALCyALQb0BRkEBIwEbBEAgBSAMQbSlBBD4AUG9ASMBQQFGDcIBGgsjAUUEQAALCyALQb4BRkEBIwEbBEAgDCARQbSlBBD3AUG+ASMBQQFGDcEBGgsjAUUEQAALCyALQb8BRkEBIwEbBEAgCiAGQcSlBBD2AUG/ASMBQQFGDcABGgsjAUUEQAALCyALQcABRkEBIwEbBEAgDCARQdSlBBD2AUHAASMBQQFGDb8BGgsjAUUEQAALCyALQcEBRkEBIwEbBEAgBCAFQdSkBBCmAUHBASMBQQFGDb4BGgsjAUUEQAALCyALQcIBRkEBIwEbBEAgCEEIQeSkBBD3AUHCASMBQQFGDb0BGgsjAUUEQAALCyALQcMBRkEBIwEbBEAgCSAPQfSkBBD3AUHDASMBQQFGDbwBGgsjAUUEQAALCyALQcQBRkEBIwEbBEAgBCAOQaSlBBD4AUHEASMBQQFGDbsBGgsjAUUEQAALCyALQcUBRkEBIwEbBEAgDiAGQaSlBBD3AUHFASMBQQFGDboBGgsjAUUEQAALCyALQcYBRkEBIwEbBEAgCSAKQbSlBBD4AUHGASMBQQFGDbkBGgsjAUUEQAALCyALQccBRkEBIwEbBEAgCiARQbSlBBD3AUHHASMBQQFGDbgBGgsjAUUEQAALCyALQcgBRkEBIwEbBEAgDyAGQcSlBBD2AUHIASMBQQFGDbcBGgsjAUUEQAALCyALQckBRkEBIwEbBEAgEyARQdSlBBD2AUHJASMBQQFGDbYBGgsjAUUEQAALCyALQcoBRkEBIwEbBEAgBCAFQdSkBBCmAUHKASMBQQFGDbUBGgsjAUUEQAALCyALQcsBRkEBIwEbBEBBAUEAQfSkBBD3AUHLASMBQQFGDbQBGgsjAUUEQAALCyALQcwBRkEBIwEbBEAgBCAOQaSlBBD4AUHMASMBQQFGDbMBGgsjAUUEQAALCyALQc0BRkEBIwEbBEAgDiAGQaSlBBD3AUHNASMBQQFGDbIBGgsjAUUEQAALCyALQc4BRkEBIwEbBEAgCSAKQbSlBBD4AUHOASMBQQFGDbEBGgsjAUUEQAALCyALQc8BRkEBIwEbBEAgCiARQbSlBBD3AUHPASMBQQFGDbABGgsjAUUEQAALCyALQdABRkEBIwEbBEAgDCAGQcSlBBD2AUHQASMBQQFGDa8BGgsjAUUEQAALCyALQdEBRkEBIwEbBEAgDyARQdSlBBD2AUHRASMBQQFGDa4BGgsjAUUEQAALCyALQdIBRkEBIwEbBEAgBCAFQdSkBBCmAUHSASMBQQFGDa0BGgsjAUUEQAALCyALQdMBRkEBIwEbBEAgBkEIQZSlBBCmAUHTASMBQQFGDawBGgsjAUUEQAALCyALQdQBRkEBIwEbBEAgBCAOQaSlBBD4AUHUASMBQQFGDasBGgsjAUUEQAALCyALQdUBRkEBIwEbBEAgDiAGQaSlBBD3AUHVASMBQQFGDaoBGgsjAUUEQAALCyALQdYBRkEBIwEbBEAgCSAKQbSlBBD4AUHWASMBQQFGDakBGgsjAUUEQAALCyALQdcBRkEBIwEbBEAgCiARQbSlBBD3AUHXASMBQQFGDagBGgsjAUUEQAALCyALQdgBRkEBIwEbBEAgDyAGQcSlBBD2AUHYASMBQQFGDacBGgsjAUUEQAALCyALQdkBRkEBIwEbBEAgEyARQdSlBBD2AUHZASMBQQFGDaYBGgsjAUUEQAALCyALQdoBRkEBIwEbBEAgBCAFQdSkBBCmAUHaASMBQQFGDaUBGgsjAUUEQAALCyALQdsBRkEBIwEbBEBBAUEAQfSkBBD3AUHbASMBQQFGDaQBGgsjAUUEQAALCyALQdwBRkEBIwEbBEAgBCAOQaSlBBD4AUHcASMBQQFGDaMBGgsjAUUEQAALCyALQd0BRkEBIwEbBEAgDiAGQaSlBBD3AUHdASMBQQFGDaIBGgsjAUUEQAALCyALQd4BRkEBIwEbBEAgCSAKQbSlBBD4AUHeASMBQQFGDaEBGgsjAUUEQAALCyALQd8BRkEBIwEbBEAgCiARQbSlBBD3AUHfASMBQQFGDaABGgsjAUUEQAALCyALQeABRkEBIwEbBEAgDCAGQcSlBBD2AUHgASMBQQFGDZ8BGgsjAUUEQAALCyALQeEBRkEBIwEbBEAgDyARQdSlBBD2AUHhASMBQQFGDZ4BGgsjAUUEQAALCyALQeIBRkEBIwEbBEAgBCAFQdSkBBCmAUHiASMBQQFGDZ0BGgsjAUUEQAALCyALQeMBRkEBIwEbBEAgBkEIQZSlBBCmAUHjASMBQQFGDZwBGgsjAUUEQAALCyALQeQBRkEBIwEbBEAgBCAKQaSlBBD4AUHkASMBQQFGDZsBGgsjAUUEQAALCyALQeUBRkEBIwEbBEAgCiAGQaSlBBD3AUHlASMBQQFGDZoBGgsjAUUEQAALCyALQeYBRkEBIwEbBEAgBSAMQbSlBBD4AUHmASMBQQFGDZkBGgsjAUUEQAALCyALQecBRkEBIwEbBEAgDCARQbSlBBD3AUHnASMBQQFGDZgBGgsjAUUEQAALCyALQegBRkEBIwEbBEAgCiAGQcSlBBD2AUHoASMBQQFGDZcBGgsjAUUEQAALCyALQekBRkEBIwEbBEAgDCARQdSlBBD2AUHpASMBQQFGDZYBGgsjAUUEQAALCyALQeoBRkEBIwEbBEAgBCAFQdSkBBCmAUHqASMBQQFGDZUBGgsjAUUEQAALCyALQesBRkEBIwEbBEAgCEEIQeSkBBD3AUHrASMBQQFGDZQBGgsjAUUEQAALCyALQewBRkEBIwEbBEAgCSAPQfSkBBD3AUHsASMBQQFGDZMBGgsjAUUEQAALCyALQe0BRkEBIwEbBEAgBCAKQaSlBBD4AUHtASMBQQFGDZIBGgsjAUUEQAALCyALQe4BRkEBIwEbBEAgCiAGQaSlBBD3AUHuASMBQQFGDZEBGgsjAUUEQAALCyALQe8BRkEBIwEbBEAgBSAMQbSlBBD4AUHvASMBQQFGDZABGgsjAUUEQAALCyALQfABRkEBIwEbBEAgDCARQbSlBBD3AUHwASMBQQFGDY8BGgsjAUUEQAALCyALQfEBRkEBIwEbBEAgCiAGQcSlBBD2AUHxASMBQQFGDY4BGgsjAUUEQAALCyALQfIBRkEBIwEbBEAgDCARQdSlBBD2AUHyASMBQQFGDY0BGgsjAUUEQAALCyALQfMBRkEBIwEbBEAgBCAFQdSkBBCmAUHzASMBQQFGDYwBGgsjAUUEQAALCyALQfQBRkEBIwEbBEAgCEEIQeSkBBD3AUH0ASMBQQFGDYsBGgsjAUUEQAALCyALQfUBRkEBIwEbBEAgCSAPQfSkBBD3AUH1ASMBQQFGDYoBGgsjAUUEQAALCyALQfYBRkEBIwEbBEAgBCAKQaSlBBD4AUH2ASMBQQFGDYkBGgsjAUUEQAALCyALQfcBRkEBIwEbBEAgCiAGQaSlBBD3AUH3ASMBQQFGDYgBGgsjAUUEQAALCyALQfgBRkEBIwEbBEAgBSAMQbSlBBD4AUH4ASMBQQFGDYcBGgsjAUUEQAALCyALQfkBRkEBIwEbBEAgDCARQbSlBBD3AUH5ASMBQQFGDYYBGgsjAUUEQAALCyALQfoBRkEBIwEbBEAgCiAGQcSlBBD2AUH6ASMBQQFGDYUBGgsjAUUEQAALCyALQfsBRkEBIwEbBEAgDCARQdSlBBD2AUH7ASMBQQFGDYQBGgsjAUUEQAALCyALQfwBRkEBIwEbBEAgBCAFQdSkBBCmAUH8ASMBQQFGDYMBGgsjAUUEQAALCyALQf0BRkEBIwEbBEAgCEEIQeSkBBD3AUH9ASMBQQFGDYIBGgsjAUUEQAALCyALQf4BRkEBIwEbBEAgCSAPQfSkBBD3AUH+ASMBQQFGDYEBGgsjAUUEQAALCyALQf8BRkEBIwEbBEAgBCAKQaSlBBD4AUH/ASMBQQFGDYABGgsjAUUEQAALCyALQYACRkEBIwEbBEAgCiAGQaSlBBD3AUGAAiMBQQFGDX8aCyMBRQRAAAsLIAtBgQJGQQEjARsEQCAFIAxBtKUEEPgBQYECIwFBAUYNfhoLIwFFBEAACwsgC0GCAkZBASMBGwRAIAwgEUG0pQQQ9wFBggIjAUEBRg19GgsjAUUEQAALCyALQYMCRkEBIwEbBEAgCiAGQcSlBBD2AUGDAiMBQQFGDXwaCyMBRQRAAAsLIAtBhAJGQQEjARsEQCAMIBFB1KUEEPYBQYQCIwFBAUYNexoLIwFFBEAACwsgC0GFAkZBASMBGwRAIAQgBUHUpAQQpgFBhQIjAUEBRg16GgsjAUUEQAALCyALQYYCRkEBIwEbBEAgCEEIQeSkBBD3AUGGAiMBQQFGDXkaCyMBRQRAAAsLIAtBhwJGQQEjARsEQCAJIA9B9KQEEPcBQYcCIwFBAUYNeBoLIwFFBEAACwsgC0GIAkZBASMBGwRAIAQgDkGkpQQQ+AFBiAIjAUEBRg13GgsjAUUEQAALCyALQYkCRkEBIwEbBEAgDiAGQaSlBBD3AUGJAiMBQQFGDXYaCyMBRQRAAAsLIAtBigJGQQEjARsEQCAFIApBtKUEEPgBQYoCIwFBAUYNdRoLIwFFBEAACwsgC0GLAkZBASMBGwRAIAogEUG0pQQQ9wFBiwIjAUEBRg10GgsjAUUEQAALCyALQYwCRkEBIwEbBEAgDiAGQcSlBBD2AUGMAiMBQQFGDXMaCyMBRQRAAAsLIAtBjQJGQQEjARsEQCAKIBFB1KUEEPYBQY0CIwFBAUYNchoLIwFFBEAACwsgC0GOAkZBASMBGwRAIAQgBUHUpAQQpgFBjgIjAUEBRg1xGgsjAUUEQAALCyALQY8CRkEBIwEbBEAgCEEIQeSkBBD3AUGPAiMBQQFGDXAaCyMBRQRAAAsLIAtBkAJGQQEjARsEQCAJIAxB9KQEEPcBQZACIwFBAUYNbxoLIwFFBEAACwsgC0GRAkZBASMBGwRAIAQgDkGkpQQQ+AFBkQIjAUEBRg1uGgsjAUUEQAALCyALQZICRkEBIwEbBEAgDiAGQaSlBBD3AUGSAiMBQQFGDW0aCyMBRQRAAAsLIAtBkwJGQQEjARsEQCAFIApBtKUEEPgBQZMCIwFBAUYNbBoLIwFFBEAACwsgC0GUAkZBASMBGwRAIAogEUG0pQQQ9wFBlAIjAUEBRg1rGgsjAUUEQAALCyALQZUCRkEBIwEbBEAgDiAGQcSlBBD2AUGVAiMBQQFGDWoaCyMBRQRAAAsLIAtBlgJGQQEjARsEQCAKIBFB1KUEEPYBQZYCIwFBAUYNaRoLIwFFBEAACwsgC0GXAkZBASMBGwRAIAQgBUHUpAQQpgFBlwIjAUEBRg1oGgsjAUUEQAALCyALQZgCRkEBIwEbBEAgCEEIQeSkBBD3AUGYAiMBQQFGDWcaCyMBRQRAAAsLIAtBmQJGQQEjARsEQCAJIAxB9KQEEPcBQZkCIwFBAUYNZhoLIwFFBEAACwsgC0GaAkZBASMBGwRAIAQgDkGkpQQQ+AFBmgIjAUEBRg1lGgsjAUUEQAALCyALQZsCRkEBIwEbBEAgDiAGQaSlBBD3AUGbAiMBQQFGDWQaCyMBRQRAAAsLIAtBnAJGQQEjARsEQCAFIApBtKUEEPgBQZwCIwFBAUYNYxoLIwFFBEAACwsgC0GdAkZBASMBGwRAIAogEUG0pQQQ9wFBnQIjAUEBRg1iGgsjAUUEQAALCyALQZ4CRkEBIwEbBEAgDiAGQcSlBBD2AUGeAiMBQQFGDWEaCyMBRQRAAAsLIAtBnwJGQQEjARsEQCAKIBFB1KUEEPYBQZ8CIwFBAUYNYBoLIwFFBEAACwsgC0GgAkZBASMBGwRAIAQgBUHUpAQQpgFBoAIjAUEBRg1fGgsjAUUEQAALCyALQaECRkEBIwEbBEAgCEEIQeSkBBD3AUGhAiMBQQFGDV4aCyMBRQRAAAsLIAtBogJGQQEjARsEQCAJIAxB9KQEEPcBQaICIwFBAUYNXRoLIwFFBEAACwsgC0GjAkZBASMBGwRAIAQgDkGkpQQQ+AFBowIjAUEBRg1cGgsjAUUEQAALCyALQaQCRkEBIwEbBEAgDiAGQaSlBBD3AUGkAiMBQQFGDVsaCyMBRQRAAAsLIAtBpQJGQQEjARsEQCAFIApBtKUEEPgBQaUCIwFBAUYNWhoLIwFFBEAACwsgC0GmAkZBASMBGwRAIAogEUG0pQQQ9wFBpgIjAUEBRg1ZGgsjAUUEQAALCyALQacCRkEBIwEbBEAgDiAGQcSlBBD2AUGnAiMBQQFGDVgaCyMBRQRAAAsLIAtBqAJGQQEjARsEQCAKIBFB1KUEEPYBQagCIwFBAUYNVxoLIwFFBEAACwsgC0GpAkZBASMBGwRAIAQgBUHUpAQQpgFBqQIjAUEBRg1WGgsjAUUEQAALCyALQaoCRkEBIwEbBEAgCEEIQeSkBBD3AUGqAiMBQQFGDVUaCyMBRQRAAAsLIAtBqwJGQQEjARsEQCAJIAxB9KQEEPcBQasCIwFBAUYNVBoLIwFFBEAACwsgC0GsAkZBASMBGwRAIAQgDkGkpQQQ+AFBrAIjAUEBRg1TGgsjAUUEQAALCyALQa0CRkEBIwEbBEAgDiAGQaSlBBD3AUGtAiMBQQFGDVIaCyMBRQRAAAsLIAtBrgJGQQEjARsEQCAFIApBtKUEEPgBQa4CIwFBAUYNURoLIwFFBEAACwsgC0GvAkZBASMBGwRAIAogEUG0pQQQ9wFBrwIjAUEBRg1QGgsjAUUEQAALCyALQbACRkEBIwEbBEAgDiAGQcSlBBD2AUGwAiMBQQFGDU8aCyMBRQRAAAsLIAtBsQJGQQEjARsEQCAKIBFB1KUEEPYBQbECIwFBAUYNThoLIwFFBEAACwsgC0GyAkZBASMBGwRAIAQgBUHUpAQQpgFBsgIjAUEBRg1NGgsjAUUEQAALCyALQbMCRkEBIwEbBEAgCEEIQeSkBBD3AUGzAiMBQQFGDUwaCyMBRQRAAAsLIAtBtAJGQQEjARsEQCAJIAxB9KQEEPcBQbQCIwFBAUYNSxoLIwFFBEAACwsgC0G1AkZBASMBGwRAIAQgDkGkpQQQ+AFBtQIjAUEBRg1KGgsjAUUEQAALCyALQbYCRkEBIwEbBEAgDiAGQaSlBBD3AUG2AiMBQQFGDUkaCyMBRQRAAAsLIAtBtwJGQQEjARsEQCAFIApBtKUEEPgBQbcCIwFBAUYNSBoLIwFFBEAACwsgC0G4AkZBASMBGwRAIAogEUG0pQQQ9wFBuAIjAUEBRg1HGgsjAUUEQAALCyALQbkCRkEBIwEbBEAgDiAGQcSlBBD2AUG5AiMBQQFGDUYaCyMBRQRAAAsLIAtBugJGQQEjARsEQCAKIBFB1KUEEPYBQboCIwFBAUYNRRoLIwFFBEAACwsgC0G7AkZBASMBGwRAIAQgBUHUpAQQpgFBuwIjAUEBRg1EGgsjAUUEQAALCyALQbwCRkEBIwEbBEAgCEEIQeSkBBD3AUG8AiMBQQFGDUMaCyMBRQRAAAsLIAtBvQJGQQEjARsEQCAJIAxB9KQEEPcBQb0CIwFBAUYNQhoLIwFFBEAACwsgC0G+AkZBASMBGwRAIAQgDkGkpQQQ+AFBvgIjAUEBRg1BGgsjAUUEQAALCyALQb8CRkEBIwEbBEAgDiAGQaSlBBD3AUG/AiMBQQFGDUAaCyMBRQRAAAsLIAtBwAJGQQEjARsEQCAFIApBtKUEEPgBQcACIwFBAUYNPxoLIwFFBEAACwsgC0HBAkZBASMBGwRAIAogEUG0pQQQ9wFBwQIjAUEBRg0+GgsjAUUEQAALCyALQcICRkEBIwEbBEAgDiAGQcSlBBD2AUHCAiMBQQFGDT0aCyMBRQRAAAsLIAtBwwJGQQEjARsEQCAKIBFB1KUEEPYBQcMCIwFBAUYNPBoLIwFFBEAACwsgC0HEAkZBASMBGwRAIAQgBUHUpAQQpgFBxAIjAUEBRg07GgsjAUUEQAALCyALQcUCRkEBIwEbBEAgCEEIQeSkBBD3AUHFAiMBQQFGDToaCyMBRQRAAAsLIAtBxgJGQQEjARsEQCAJIAxB9KQEEPcBQcYCIwFBAUYNORoLIwFFBEAACwsgC0HHAkZBASMBGwRAIAQgDkGkpQQQ+AFBxwIjAUEBRg04GgsjAUUEQAALCyALQcgCRkEBIwEbBEAgDiAGQaSlBBD3AUHIAiMBQQFGDTcaCyMBRQRAAAsLIAtByQJGQQEjARsEQCAFIApBtKUEEPgBQckCIwFBAUYNNhoLIwFFBEAACwsgC0HKAkZBASMBGwRAIAogEUG0pQQQ9wFBygIjAUEBRg01GgsjAUUEQAALCyALQcsCRkEBIwEbBEAgDiAGQcSlBBD2AUHLAiMBQQFGDTQaCyMBRQRAAAsLIAtBzAJGQQEjARsEQCAKIBFB1KUEEPYBQcwCIwFBAUYNMxoLIwFFBEAACwsgC0HNAkZBASMBGwRAIAQgBUHUpAQQpgFBzQIjAUEBRg0yGgsjAUUEQAALCyALQc4CRkEBIwEbBEAgCEEIQeSkBBD3AUHOAiMBQQFGDTEaCyMBRQRAAAsLIAtBzwJGQQEjARsEQCAJIAxB9KQEEPcBQc8CIwFBAUYNMBoLIwFFBEAACwsgC0HQAkZBASMBGwRAIAZBCEGUpQQQpgFB0AIjAUEBRg0vGgsjAUUEQAALCyALQdECRkEBIwEbBEAgBkEIQZSlBBCmAUHRAiMBQQFGDS4aCyMBRQRAAAsLIAtB0gJGQQEjARsEQCAGQQhBlKUEEKYBQdICIwFBAUYNLRoLIwFFBEAACwsgC0HTAkZBASMBGwRAIAZBCEGUpQQQpgFB0wIjAUEBRg0sGgsjAUUEQAALCyALQdQCRkEBIwEbBEAgBkEIQZSlBBCmAUHUAiMBQQFGDSsaCyMBRQRAAAsLIAtB1QJGQQEjARsEQCAGQQhBlKUEEKYBQdUCIwFBAUYNKhoLIwFFBEAACwsgC0HWAkZBASMBGwRAIAZBCEGUpQQQpgFB1gIjAUEBRg0pGgsjAUUEQAALCyALQdcCRkEBIwEbBEAgBkEIQZSlBBCmAUHXAiMBQQFGDSgaCyMBRQRAAAsLIAtB2AJGQQEjARsEQCAGQQhBlKUEEKYBQdgCIwFBAUYNJxoLIwFFBEAACwsgC0HZAkZBASMBGwRAIAZBCEGUpQQQpgFB2QIjAUEBRg0mGgsjAUUEQAALCyALQdoCRkEBIwEbBEAgBkEIQZSlBBCmAUHaAiMBQQFGDSUaCyMBRQRAAAsLIAtB2wJGQQEjARsEQCAGQQhBlKUEEKYBQdsCIwFBAUYNJBoLIwFFBEAACwsgC0HcAkZBASMBGwRAIAZBCEGUpQQQpgFB3AIjAUEBRg0jGgsjAUUEQAALCyALQd0CRkEBIwEbBEAgBkEIQZSlBBCmAUHdAiMBQQFGDSIaCyMBRQRAAAsLIAtB3gJGQQEjARsEQCAGQQhBlKUEEKYBQd4CIwFBAUYNIRoLIwFFBEAACwsgC0HfAkZBASMBGwRAIAZBCEGUpQQQpgFB3wIjAUEBRg0gGgsjAUUEQAALCyALQeACRkEBIwEbBEAgBkEIQZSlBBCmAUHgAiMBQQFGDR8aCyMBRQRAAAsLIAtB4QJGQQEjARsEQCAGQQhBlKUEEKYBQeECIwFBAUYNHhoLIwFFBEAACwsgC0HiAkZBASMBGwRAIAZBCEGUpQQQpgFB4gIjAUEBRg0dGgsjAUUEQAALCyALQeMCRkEBIwEbBEAgBkEIQZSlBBCmAUHjAiMBQQFGDRwaCyMBRQRAAAsLIAtB5AJGQQEjARsEQCAGQQhBlKUEEKYBQeQCIwFBAUYNGxoLIwFFBEAACwsgC0HlAkZBASMBGwRAIAZBCEGUpQQQpgFB5QIjAUEBRg0aGgsjAUUEQAALCyMBRQRAIA1BBDoADCANIAU2AgAMGAsLIwFFBEAgDUEEOgAMIA0gBTYCAAwXCwsjAUUEQCANQQQ6AAwgDSAFNgIADBYLCyMBRQRAIA1BBDoADCANIAU2AgAMFQsLIwFFBEAgDUEEOgAMIA0gBTYCAAwUCwsjAUUEQCANQQQ6AAwgDSAFNgIADBMLCyMBRQRAIA1BBDoADCANIAU2AgAMEgsLIwFFBEAgDUEEOgAMIA0gBTYCAAwRCwsjAUUEQCANQQQ6AAwgDSAFNgIADBALCyMBRQRAIA1BBDoADCANIAU2AgAMDwsLIwFFBEAgDUEEOgAMIA0gBTYCAAwOCwsjAUUEQCANQQQ6AAwgDSAFNgIADA0LCyMBRQRAIA1BBDoADCANIAk2AgAMDAsLIwFFBEAgDUEEOgAMIA0gCTYCAAwLCwsjAUUEQCANQQQ6AAwgDSAJNgIADAoLCyMBRQRAIA1BBDoADCANIAk2AgAMCQsLIwFFBEAgDUEEOgAMIA0gBTYCAAwICwsjAUUEQCANQQQ6AAwgDSAFNgIADAcLCyMBRQRAIA1BBDoADCANIAU2AgAMBgsLIwFFBEAgDUEEOgAMIA0gBTYCAAwFCwsjAUUEQCANQQQ6AAwgDSAFNgIADAQLCyMBRQRAIA1BBDoADCANIAU2AgAMAwsLIwFFBEAgDUEEOgAMIA0gBTYCAAwCCwsjAUUEQCANQQQ6AAwgDSAFNgIACwsjAUUEQCAHQUBrJAALDAELIRYjAigCACAWNgIAIwIjAigCAEEEajYCACMCKAIAIhYgDTYCACAWIAQ2AgQgFiAQNgIIIBYgBjYCDCAWIBc2AhAgFiARNgIUIBYgBTYCGCAWIAc2AhwgFiAINgIgIBYgCTYCJCAWIAo2AiggFiAMNgIsIBYgDjYCMCAWIA82AjQgFiASNgI4IBYgEzYCPCAWIBQ2AkAgFiAVNgJEIBYgGDYCSCAWIBk2AkwjAiMCKAIAQdAAajYCAAtBAiMBQQFGDQMaCyMBRQRAIBotABwiAUEERgRAIBooAhAhASAAIB42AgQgACAfNgIAIAAgGyABIAEgG0sbNgIIDAMLIBooAhghAiAAQQhqIAE6AAAgACACNgIEIABBADYCACAeRQ0CIB8QIQwCCwsjAUUEQCAAIBs2AgQgAEEANgIAIABBCGogHjoAAAsLIwFFBEAgGkEgaiQACw8LIQQjAigCACAENgIAIwIjAigCAEEEajYCACMCKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIAQgAzYCDCAEIBo2AhAgBCAbNgIUIAQgHjYCGCAEIB82AhwgBCAhNgIgIwIjAigCAEEkajYCAAv5AgEFfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhAgsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBgsjAUUEQCMAQSBrIgIkACABQQFqIgFFIQMLAkAjAUUEQAJAIAMNACAAKAIEIgNBAXQiBCABSyEFIAQgASAFGyIBQQRLIQQgAUEEIAQbIgFBDGwhBCABQavVqtUASUECdCEFAkAgAwRAIAJBBDYCGCACIANBDGw2AhQgAiAAKAIANgIQDAELIAJBADYCGAsgAiAEIAUgAkEQahCOASACKAIARQRAIAIoAgQhAyAAIAE2AgQgACADNgIADAMLIAJBCGooAgAiAEGBgICAeEYNAiAARQ0AAAsLIAZFQQEjARsEQBDTAUEAIwFBAUYNAhoLIwFFBEAACwsjAUUEQCACQSBqJAALDwshACMCKAIAIAA2AgAjAiMCKAIAQQRqNgIAIwIoAgAgAjYCACMCIwIoAgBBBGo2AgAL+QIBBX8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQILAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQYLIwFFBEAjAEEgayICJAAgAUEBaiIBRSEDCwJAIwFFBEACQCADDQAgACgCBCIDQQF0IgQgAUshBSAEIAEgBRsiAUEESyEEIAFBBCAEGyIBQQN0IQQgAUGAgICAAUlBAnQhBQJAIAMEQCACQQQ2AhggAiADQQN0NgIUIAIgACgCADYCEAwBCyACQQA2AhgLIAIgBCAFIAJBEGoQjgEgAigCAEUEQCACKAIEIQMgACABNgIEIAAgAzYCAAwDCyACQQhqKAIAIgBBgYCAgHhGDQIgAEUNAAALCyAGRUEBIwEbBEAQ0wFBACMBQQFGDQIaCyMBRQRAAAsLIwFFBEAgAkEgaiQACw8LIQAjAigCACAANgIAIwIjAigCAEEEajYCACMCKAIAIAI2AgAjAiMCKAIAQQRqNgIAC8MLAhF/An4jAUECRgRAIwIjAigCAEEgazYCACMCKAIAIgEoAgAhACABKAIIIQIgASgCDCEDIAEoAhAhBCABKAIUIQsgASgCGCEVIAEoAhwhESABKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIRALIwFFBEAjAEEQayILJAAgAEEcaigCACgCDCEVIAAoAhghEQsgEEVBASMBGwRAIBEgASACIBURAQBBACMBQQFGDQEaIQELIwFFBEAgCyABOgAIIAsgADYCACALQQA6AAkgC0EANgIECyAQQQFGQQEjARsEQAJ/IAshDSADIQYgBCEMQQAhBSMBQQJGBEAjAiMCKAIAQSxrNgIAIwIoAgAiBigCACENIAYoAgghDCAGKAIMIQggBigCECEJIAYoAhQhBSAGKAIYIQogBigCHCEOIAYoAiAhEiAGKAIkIRMgBigCKCEUIAYoAgQhBgsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhDwsjAUUEQCMAQUBqIggkACANLQAIRSEJCwJAIwFFBEAgCUUEQCANKAIEIQ5BASEFDAILIA0oAgQhDiANKAIAIgkoAgAiCkEEcSEFCwJAIwFFBEAgBQ0BIAkoAhghCkHJhQRB2oUEIA4bIRJBAkEBIA4bIRMgCUEcaigCACgCDCEUQQEhBQsgD0VBASMBGwRAIAogEiATIBQRAQBBACMBQQFGDQMaIQoLIwFFBEAgCg0CIAwoAgwhDAsgD0EBRkEBIwEbBEAgBiAJIAwRAABBASMBQQFGDQMaIQULIwFFDQELAkAjAUUEQCAODQEgCUEcaigCACgCDCEKIAkoAhghBQsgD0ECRkEBIwEbBEAgBUHYhQRBAiAKEQEAQQIjAUEBRg0DGiEFCyMBRQRAIAVFIgVFBEBBASEFQQAhDgwDCyAJKAIAIQoLCyMBRQRAIAhBAToAFyAIQTRqQaiFBDYCACAIIAo2AhggCCAJKQIYNwMIIAggCEEXajYCECAJKQIIIRYgCSkCECEXIAggCS0AIDoAOCAIIAkoAgQ2AhwgCCAXNwMoIAggFjcDICAIIAhBCGo2AjAgCEEYaiEJIAwoAgwhDEEBIQULIA9BA0ZBASMBGwRAIAYgCSAMEQAAQQMjAUEBRg0CGiEGCyMBRQRAIAYNASAIKAI0KAIMIQwgCCgCMCEGCyAPQQRGQQEjARsEQCAGQceFBEECIAwRAQBBBCMBQQFGDQIaIQULCyMBRQRAIA0gBToACCANIA5BAWo2AgQgCEFAayQAIA0MAgsACyEHIwIoAgAgBzYCACMCIwIoAgBBBGo2AgAjAigCACIHIA02AgAgByAGNgIEIAcgDDYCCCAHIAg2AgwgByAJNgIQIAcgBTYCFCAHIAo2AhggByAONgIcIAcgEjYCICAHIBM2AiQgByAUNgIoIwIjAigCAEEsajYCAEEAC0EBIwFBAUYNARohAQsjAUUEQCALKAIEIgRFIQIgCy0ACCEACwJAIwFFBEAgAg0BIABB/wFxQQEhAA0BIAEoAgAhAiAEQQFHIQALAkAjAUUEQCAADQEgCy0ACUUiAA0BIAItAABBBHEiAA0BIAIoAhghASACQRxqKAIAKAIMIQNBASEACyAQQQJGQQEjARsEQCABQduFBEEBIAMRAQBBAiMBQQFGDQMaIQELIwFFQQAgARsNAQsjAUUEQCACQRxqKAIAKAIMIQEgAigCGCEACyAQQQNGQQEjARsEQCAAQdmqBUEBIAERAQBBAyMBQQFGDQIaIQALCyMBRQRAIAtBEGokACAAQf8BcUEARw8LAAshBSMCKAIAIAU2AgAjAiMCKAIAQQRqNgIAIwIoAgAiBSAANgIAIAUgATYCBCAFIAI2AgggBSADNgIMIAUgBDYCECAFIAs2AhQgBSAVNgIYIAUgETYCHCMCIwIoAgBBIGo2AgBBAAv5AgEFfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhAgsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBgsjAUUEQCMAQSBrIgIkACABQQFqIgFFIQMLAkAjAUUEQAJAIAMNACAAKAIEIgNBAXQiBCABSyEFIAQgASAFGyIBQQRLIQQgAUEEIAQbIgFBAnQhBCABQYCAgIACSUECdCEFAkAgAwRAIAJBBDYCGCACIANBAnQ2AhQgAiAAKAIANgIQDAELIAJBADYCGAsgAiAEIAUgAkEQahCOASACKAIARQRAIAIoAgQhAyAAIAE2AgQgACADNgIADAMLIAJBCGooAgAiAEGBgICAeEYNAiAARQ0AAAsLIAZFQQEjARsEQBDTAUEAIwFBAUYNAhoLIwFFBEAACwsjAUUEQCACQSBqJAALDwshACMCKAIAIAA2AgAjAiMCKAIAQQRqNgIAIwIoAgAgAjYCACMCIwIoAgBBBGo2AgAL+AIBBX8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQILAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQYLIwFFBEAjAEEgayICJAAgAUEBaiIBRSEDCwJAIwFFBEACQCADDQAgACgCBCIDQQF0IgQgAUshBSAEIAEgBRsiAUEESyEEIAFBBCAEGyIBQSRsIQQgAUHk8bgcSUECdCEFAkAgAwRAIAJBBDYCGCACIANBJGw2AhQgAiAAKAIANgIQDAELIAJBADYCGAsgAiAEIAUgAkEQahCOASACKAIARQRAIAIoAgQhAyAAIAE2AgQgACADNgIADAMLIAJBCGooAgAiAEGBgICAeEYNAiAARQ0AAAsLIAZFQQEjARsEQBDTAUEAIwFBAUYNAhoLIwFFBEAACwsjAUUEQCACQSBqJAALDwshACMCKAIAIAA2AgAjAiMCKAIAQQRqNgIAIwIoAgAgAjYCACMCIwIoAgBBBGo2AgAL1J0BAj5/B34jAUECRgRAIwIjAigCAEE0azYCACMCKAIAIgUoAgAhACAFKAIIIQIgBSgCDCEDIAUoAhAhJSAFKAIUISogBSgCGCEvIAUoAhwhMCAFKAIgITEgBSgCJCE9IAUoAighPiAFKAIsIT8gBSgCMCFAIAUoAgQhAQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhQQsjAUUEQCMAQRBrIiUkACADKAIEQQFqIi9FISoLAkACQAJAIwFFBEAgKg0BIANBEGooAgAiKkEBRiIwIAMtABRBAEdyIT0gA0EIaiE+IAMoAgghPyADKAIAITELA0ACQCMBRUEAID0bRQRAIEFFQQEjARsEQCAlIRIgPiELIAEhDiACIQ8gMSEyQQAhBEEAIQZBACEFQQAhB0EAIQlBACEKQQAhDEEAIQ1BACEQQQAhEUEAIRNBACEUQQAhFkIAIUJBACEXQgAhQ0EAIRhBACEZQQAhGkEAIRtBACEcQQAhHUIAIURBACEeQQAhH0EAISBBACEiQQAhI0EAISRBACEmQQAhJ0EAIShCACFGQQAhKUEAIStBACEsQQAhLUEAIS5BACEzQgAhR0EAITRBACE1QQAhNkEAITdBACE4QQAhOUEAITtBACE8IwFBAkYEQCMCIwIoAgBBxAFrNgIAIwIoAgAiCygCACESIAsoAgghDiALKAIMIQ8gCygCECEyIAsoAhQhBCALKAIYIQYgCygCHCEFIAsoAiAhByALKAIkIQogCygCKCEMIAsoAiwhFCALKAIwIRMgCygCNCEWIAspAjghQiALKAJAIRkgCykCRCFDIAsoAkwhGCALKAJQIRogCygCVCEbIAsoAlghHCALKQJcIUQgCygCZCEeIAsoAmghIiALKAJsISMgCygCcCEmIAsoAnQhJyALKAJ4ISggCykCfCFGIAsoAoQBISkgCygCiAEhKyALKAKMASEsIAsoApABIS0gCygClAEhLiALKAKYASEzIAspApwBIUcgCygCpAEhNCALKAKoASE1IAsoAqwBITYgCygCsAEhNyALKAK0ASE4IAsoArgBITkgCygCvAEhOyALKALAASE8IAsoAgQhCwsCQAJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEJCyMBRQRAIwBB4AJrIgQkACAEQeAAaiEGCyAJRUEBIwEbBEAgBhCCAUEAIwFBAUYNARoLIwFFBEAgBEHsAGooAgAhCiAEQegAaigCACEzIAQoAmQhKyAEKAJgIQYLAkACQAJAAkACQAJAAkACQAJAAkACfwJAAkACQAJ/AkACQAJAAkACQAJAAkACQCMBRQRAIAYNASAEQUBrIQYLIAlBAUZBASMBGwRAIAZBjIEEQSUQJEEBIwFBAUYNGBoLIwFFBEAgBCgCSEUiBg0CIARB4ABqIRQgBCgCQCIMKAIAIQUgDEEIaigCACEGCyAJQQJGQQEjARsEQCAUIAUgBhC4AUECIwFBAUYNGBoLIwFFBEAgBCgCaCEiIAQoAmQhOyAEKAJgISxBACEMIARBADYCWCAEQgQ3A1AgCygCCCIUDQNBBCEFQQAMCQsLIwFFBEAgEiArNgIEIBJBATYCACASQQxqIAo2AgAgEkEIaiAzNgIADBYLCyMBRUEAIwFFBEAgBEEGNgKMAiAEQdirBTYCiAJBuLgFKAIAQQNGIQYLIAYbRQRAIAlBA0ZBASMBGwRAIwFBAkYEQCMCIwIoAgBBCGs2AgAjAigCACIIKAIAISQgCCgCBCEdCwJAAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQ0LIwFFBEAjAEEgayIkJABBuLgFKAIAQQNGIR0LAkAjAUUEQCAdDQEgJEG8uAU2AgggJCAkQRhqNgIMICQgJEEIajYCFCAkQRRqIR0LIA1FQQEjARsEQCAdIRVBACENQQAhCCMBQQJGBEAjAiMCKAIAQRxrNgIAIwIoAgAiCCgCACEVIAgoAgQhDSAIKAIIIRAgCCgCDCERIAgoAhQhHyAIKAIYISAgCCgCECEICwJAAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIRcLIwFFBEAjAEEwayIRJAAgEUEIakECciEfQbi4BSgCACENCwNAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAIwFFBEACQCANDgQCAgADAAsgDUEDcUECRyIQRQRAA0AgDSEIQcC8BSgCAA0PQcC8BUF/NgIAQcS8BSgCACIQRQRAQSAQDyIQRQ0PIBBCgYCAgBA3AwAgEEEQakEANgIAQfC4BSkDACFFA0AgRUIBfCJIUA0OIEVB8LgFKQMAIkVRIQ1B8LgFIEggRSANGzcDACANRQ0ACyAQQQA7ARwgECBINwMIQcS8BSAQNgIAIBBBGGpBADYCAAsgECAQKAIAIg1BAWoiIDYCACANQX9MDQ5BwLwFQcC8BSgCAEEBajYCACAIQbi4BSgCACINRiEgQbi4BSAfIA0gIBsiIDYCACARQQA6ABAgESAQNgIIIBEgCEF8cTYCDAJAIAggDUciEEUEQCARLQAQRQ0BDBILAkAgESgCCCIQRSIIDQAgECAQKAIAIghBAWsiIDYCACAIQQFHDQAgESgCCBC2AQsgDUEDcUECRiIQDQEMEgsLA0BBwLwFKAIADQ1BwLwFQX82AgBBxLwFKAIAIhBFBEBBIBAPIhBFDQ8gEEKBgICAEDcDACAQQRBqQQA2AgBB8LgFKQMAIUUDQCBFQgF8IkhQDQ4gRUHwuAUpAwAiRVEhDUHwuAUgSCBFIA0bNwMAIA1FDQALIBBBADsBHCAQIEg3AwhBxLwFIBA2AgAgEEEYakEANgIACyAQIBAoAgAiDUEBaiIINgIAIA1Bf0wNDkHAvAVBwLwFKAIAQQFqNgIAIBBBGGoiCEEAIAgoAgAiCCAIQQJGIggbIiA2AgAgCEUEQCAQQRxqIggtAAAhDSAIQQE6AAAgESANQQFxIg06ABQgDQ0MQQAhIEHouAUoAgBB/////wdxBEAQggJBAXMhIAsgEC0AHSINDQsgECAQKAIYIg1BASANGzYCGCANRQ0IIA1BAkciDQ0KIBAoAhghDSAQQQA2AhggESANNgIUIA1BAkciDQ0JAkAgIA0AQei4BSgCAEH/////B3FFDQAQggINACAQQQE6AB0LIAhBADoAAAsgECAQKAIAIg1BAWsiCDYCACANQQFGBEAgEBC2AQsgES0AEEUNAAsMDwsLIBdFQQEjARsEQEGosQVBwABBsKwFEL8BQQAjAUEBRg0RGgsjAUUEQAALCyMBRQRAIA1BuLgFKAIAIg1GIQhBuLgFQQIgDSAIGzYCACAIRSIQDQ8gFSgCACIQKAIAIQ0gEEEANgIAIA1FIhUNA0GACBAPIhBFIhUNCyANQgA3AgAgDUEAOgAcIA1BADoAGCANQoAINwIQIA0gEDYCDCANQQA2AghBuLgFKAIAIQ1BuLgFQQM2AgAgESANQQNxIhA2AgggEEECRyIVDQIgDUECayIQRSIVDQELA0AjAUUEQCAQKAIAIQ0gEEEANgIAIA1FIRULAkACQAJAAkACQCMBRQRAIBUNASAQKAIEIQggEEEBOgAIIA1BGGoiECgCACEfIBBBAjYCAAsCQCMBRQRAAkAgHw4DBwIHAAsgEUEsakEANgIAIBFB6KEFNgIoIBFCATcCHCARQZC0BTYCGCARQRhqIRULIBdBAUZBASMBGwRAIBVBmLQFENIBQQEjAUEBRg0XGgsjAUUEQAALCyMBRQRAIA1BHGoiEC0AACEfIBBBAToAACARIB9BAXEiHzoACCAfDQIgEQJ/AkBB6LgFKAIAQf////8HcQRAEIICIR8gDS0AHQ0BIB9FIhUNBwwGCyANLQAdRSIVDQVBAAwBCyAfQQFzCyINOgAcIBEgEDYCGCARQRhqIRULIBdBAkZBASMBGwRAQfeiBUErIBVBuLIFQai0BRCbAUECIwFBAUYNFhoLIwFFBEAACwsgF0EDRkEBIwEbBEBBzKIFQStBqLIFEL8BQQMjAUEBRg0VGgsjAUUEQAALCyMBRQRAIBFBADYCLCARQeihBTYCKCARQgE3AhwgEUHYsAU2AhggEUEIaiEVIBFBGGohDQsgF0EERkEBIwEbBEAgFSANEK0BQQQjAUEBRg0UGgsjAUUEQAALCyMBRQRAQei4BSgCAEH/////B3FFIhUNARCCAiIVDQEgDUEBOgAdCwsjAUUEQCAQQQA6AAALCyMBRQRAIA0gDSgCACIQQQFrNgIAIBBBAUciFUUEQCANELYBCyAIIhANAQsLCyMBRQRAIBFBMGokAAwQCwsjAUUEQCARQQA2AhggEUEIaiEVIBFBGGohDQsgF0EFRkEBIwEbBEAgFSANQZiyBRCvAUEFIwFBAUYNDhoLIwFFBEAACwsgF0EGRkEBIwEbBEBBzKIFQStBwKwFEL8BQQYjAUEBRg0NGgsjAUUEQAALCyMBRQRAIBFBLGpBADYCACARQeihBTYCKCARQgE3AhwgEUHorwU2AhggEUEYaiEVCyAXQQdGQQEjARsEQCAVQaiwBRDSAUEHIwFBAUYNDBoLIwFFBEAACwsjAUUEQCARQQA2AiwgEUHooQU2AiggEUIBNwIcIBFB3LMFNgIYIBFBFGohFSARQRhqIQ0LIBdBCEZBASMBGwRAIBUgDUHkswUQrwFBCCMBQQFGDQsaCyMBRQRAAAsLIwFFBEAgEUEsakEANgIAIBFB6KEFNgIoIBFCATcCHCARQaSzBTYCGCARQRhqIRULIBdBCUZBASMBGwRAIBVBrLMFENIBQQkjAUEBRg0KGgsjAUUEQAALCyMBRQRAIBEgIDoAHCARIAg2AhggEUEYaiEVCyAXQQpGQQEjARsEQEH3ogVBKyAVQbiyBUH8sgUQmwFBCiMBQQFGDQkaCyMBRQRAAAsLIwFFBEAgEUEANgIsIBFB6KEFNgIoIBFCATcCHCARQdiwBTYCGCARQRRqIRUgEUEYaiENCyAXQQtGQQEjARsEQCAVIA0QrQFBCyMBQQFGDQgaCyMBRQRAAAsLIBdBDEZBASMBGwRAQQAhIUEAITojAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAISELAkACfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhOgsjAUUEQCMAQSBrIiEkACAhQRxqQQA2AgAgIUHooQU2AhggIUIBNwIMICFB+KMFNgIIICFBCGohIQsgOkVBASMBGwRAICFBgKQFENIBQQAjAUEBRg0BGgsjAUUEQAALDAELITojAigCACA6NgIAIwIjAigCAEEEajYCACMCKAIAICE2AgAjAiMCKAIAQQRqNgIAC0EMIwFBAUYNBxoLIwFFBEAACwsgFSARQRhqIwEbIRUgF0ENRkEBIwEbBEBB6KEFQRAgFUH4oQVB5K0FEJsBQQ0jAUEBRg0GGgsjAUUEQAALCyMBRQRAAAsLIBUgEUEYaiMBGyEVIBdBDkZBASMBGwRAQeihBUEQIBVB+KEFQeStBRCbAUEOIwFBAUYNBBoLIwFFBEAACwsjAUUEQCARKAIIIg1FIhANASANIA0oAgAiEEEBayIINgIAIBBBAUcNASARKAIIELYBQbi4BSgCACENDAILCyMBRQRAQbi4BSgCACENDAELCwwBCyEXIwIoAgAgFzYCACMCIwIoAgBBBGo2AgAjAigCACIXIBU2AgAgFyANNgIEIBcgEDYCCCAXIBE2AgwgFyAINgIQIBcgHzYCFCAXICA2AhgjAiMCKAIAQRxqNgIAC0EAIwFBAUYNAhoLCyMBRQRAICRBIGokAAsMAQshCCMCKAIAIAg2AgAjAiMCKAIAQQRqNgIAIwIoAgAiCCAkNgIAIAggHTYCBCMCIwIoAgBBCGo2AgALQQMjAUEBRg0XGgsLIwFFBEACQEG8uAUoAgBBvLwFRiIGRQRAQdi4BS0AACEHQQEhDEHYuAVBAToAACAEIAdBAXEiBzoAsAIgBw0EQby4BUG8vAU2AgAMAQtBwLgFKAIAQQFqIgxFIgYNBAtBwLgFIAw2AgAgBEG8uAU2AtgBQQQhDCAEQQQ6ALQCIAQgBEHYAWo2ArACIARBADYCdCAEQeihBTYCcCAEQgE3AmQgBEGQ+QQ2AmAgBEHgAGohBiAEQbACaiEFCyAJQQRGQQEjARsEQCAFQfyrBSAGECtBBCMBQQFGDRYaIQcLIwFFBEAgBC0AtAIhFCAHRSEGCwJAIwFFBEAgBkUEQCAUQf8BcUEERyIGRQRAQvCrBSJDQiCGIUJBAiEMQgAhRAwDCyAEKQK0AiJGQoCAgIBwgyFCIEZCgP7//w+DIUQgRqchDAwCC0IAIUQgFEH/AXFBA0chBgsCQCMBRQRAIAYNASAEQbgCaigCACIHKAIAIQUgBygCBCgCACEGCyAJQQVGQQEjARsEQCAFIAYRBABBBSMBQQFGDRgaCyMBRQRAQQQhDCAHKAIEQQRqKAIABEAgBygCABAhCyAEKAK4AiIGECELCyBCQgAjARshQgsjAUUEQCAEKALYASIHKAIEQQFrIRQgByAUNgIEIBRFBEAgB0EAOgAcIAdBADYCAAsgDEH/AXFBBEciBg0EIAsoAgAhBiALQQhqKAIAIQsLIAlBBkZBASMBGwRAIBIgBiALIA4gDyAyEB5BBiMBQQFGDRYaCyMBRQ0TCyMBRQRAIAsoAgAiByAUQQN0aiEmIARB2AFqIgZBBHIhEwsDQCMBRQRAIAdBBGooAgAhDCAHKAIAIRYgBEE4aiEGCyAJQQdGQQEjARsEQCAGIApBABCoAUEHIwFBAUYNFhoLIwFFBEAgBCgCPCEaIAQoAjggKyAKEP8BIRlBAxAPIhRFIgYNCCAUQQJqIgVB4oAFLQAAOgAAIBRB4IAFLwAAOwAAIARBMGohBgsgCUEIRkEBIwEbBEAgBiAPQQAQqAFBCCMBQQFGDRYaCyMBRQRAIAQoAjQhBiAEQShqIRggBCgCMCAOIA8Q/wEhBQsgCUEJRkEBIwEbBEAgGCAMQQAQqAFBCSMBQQFGDRYaCyMBRQRAIAQgBCgCLDYCcCAEIAQoAigiGzYCbCAbIBYgDBD/ASEYIARBAjYCgAEgBCAyNgJ8IARBATYCeCAEIA82AmggBCAGNgJkIAQgBTYCYCAEIAw2AnQgBEHgAGohBiAEQdgBaiEFCyAJQQpGQQEjARsEQCAFIAYQG0EKIwFBAUYNFhoLIwFFBEAgBCgC2AFBEUciBg0FIARBuAJqIgYgE0EIaigCADYCACAEIBMpAgAiQzcDsAIgBEHgAGoQ2gEgBCgCWCIWIAQoAlRHIQULAkAjAUUEQCAFDQEgBEHQAGohBQsgCUELRkEBIwEbBEAgBSAWEHdBCyMBQQFGDRcaCyMBRQRAIAQoAlghFgsLIwFFBEAgBCgCUCIFIBZBJGwiGGoiCCAUNgIMIAggCjYCCCAIIBo2AgQgCCAZNgIAIAggBCkDsAIiQzcCGCAIQRBqQoOAgIAwNwIAIAhBIGoiFCAGKAIANgIAIAQgFkEBaiIMNgJYICYgB0EIaiIHRiIGDQYMAQsLCyMBRQRAIARBADYCdCAEQeihBTYCcCAEQgE3AmQgBEHYsAU2AmAgBEHgAGohEiAEQbACaiELCyAJQQxGQQEjARsEQCALIBIQrQFBDCMBQQFGDRQaCyMBRQRAAAsLIAlBDUZBASMBGwRAQdusBUEmQaitBRDzAUENIwFBAUYNExoLIwFFBEAACwsjAUUEQCAEIEIgRCAMrUL/AYOEhCJDNwPYASAEQfQAakECNgIAIARBvAJqQQ82AgAgBEICNwJkIARBuKsFNgJgIARBCzYCtAIgBCAEQbACajYCcCAEIARB2AFqNgK4AiAEIARBiAJqNgKwAiAEQeAAaiESCyAJQQ5GQQEjARsEQCASQcirBRDSAUEOIwFBAUYNEhoLIwFFBEAACwsjAUUEQCAEQbgCaiILIARB4AFqKQMANwMAIAQgBCkD2AEiQzcDsAIgBEGwAmohEgsgCUEPRkEBIwEbBEBB96IFQSsgEkGE8ARBjPIEEJsBQQ8jAUEBRg0RGgsjAUUEQAALCyMBBH8gCgUgBCgCVAsLIQojAUUEQEEbEA8iB0UiDw0BIAdBF2oiBkG7nAUoAAA2AAAgB0EQakG0nAUpAAA3AAAgB0EIakGsnAUpAAA3AAAgB0GknAUpAAAiQzcAAEE6EA8iFEUiDw0BIBRB7IQFQToQ/wEhFCAHECEgBEGwAmohDwsgCUEQRkEBIwEbBEAgDyAUQToQuAFBECMBQQFGDQ8aCyAPIARBvAJqIwEbIQ8gCUERRkEBIwEbBEAgD0EEQQAQXkERIwFBAUYNDxoLIwFFBEAgBEGYAWogBEG4AmopAwA3AwAgBEGgAWogBEHAAmopAwA3AwAgBEEANgKMASAEQgQ3AoQBIARCuoCAgKAHNwJ8IAQgFDYCeCAEQQA2AnAgBEIBNwNoIARCADcDYCAEIAQpA7ACIkM3A5ABIARB4ABqIQ8gBEGwAmohBgsgCUESRkEBIwEbBEAgBiAPQQEQPkESIwFBAUYNDxoLIwFFBEAgBCgCsAIiB0ERRyEPCwJ/An8CQAJAAkACQAJAAkACQAJAAkACQAJAIwFFBEAgDw0BIARBkAFqIRQLIAlBE0ZBASMBGwRAIBRBpoUFQQVBq4UFQRNB14MFQRAQbUETIwFBAUYNHBoLIwFFBEAgBEHgAGohDyAEQbACaiEGCyAJQRRGQQEjARsEQCAGIA9BpoUFQQUQIkEUIwFBAUYNHBoLIwFFBEAgBCgCsAIiB0ERRyIPDQEgBEHgAGohDyAEQbACaiEGCyAJQRVGQQEjARsEQCAGIA8gDBA/QRUjAUEBRg0cGgsjAUUEQCAEKAKwAiIHQRFHIg8NASAMRSEPCwJAAkAjAUUEQCAPDQEgBSIHIAxBJGxqIQ8LA0AjAUUEQCAEQbACaiEaIARB4ABqIQYLIAlBFkZBASMBGwRAIBogBkEDED5BFiMBQQFGDR8aCyMBRQRAIAQoArACIhZBEUciBg0GCyAJQRdGQQEjARsEQCAUQfj/BEEDQez7BEEGQdeDBUEQEG1BFyMBQQFGDR8aCyMBRQRAIARBsAJqIRogBEHgAGohBgsgCUEYRkEBIwEbBEAgGiAGQfj/BEEDECJBGCMBQQFGDR8aCyMBRQRAIAQoArACIhZBEUciBg0HIARBsAJqIRMgBEHgAGohGCAHKAIAIRogB0EIaigCACEGCyAJQRlGQQEjARsEQCATIBggGiAGECJBGSMBQQFGDR8aCyMBRQRAIAQoArACIhZBEUciBg0IIARBsAJqIQYLIAlBGkZBASMBGwRAIAYgFBA8QRojAUEBRg0fGgsjAUUEQCAEKAK0AkUiBkUEQCAEKAKwAiIGECELCyAJQRtGQQEjARsEQCAUQbf0BEEGQez7BEEGQdeDBUEQEG1BGyMBQQFGDR8aCyMBRQRAIARBsAJqIRogBEHgAGohBgsgCUEcRkEBIwEbBEAgGiAGQbf0BEEGECJBHCMBQQFGDR8aCyMBRQRAIAQoArACIhZBEUciBg0JIARBsAJqIRMgBEHgAGohGCAHQQxqKAIAIRogB0EUaigCACEGCyAJQR1GQQEjARsEQCATIBggGiAGECJBHSMBQQFGDR8aCyMBRQRAIAQoArACIhZBEUciBg0KIARBsAJqIQYLIAlBHkZBASMBGwRAIAYgFBA8QR4jAUEBRg0fGgsjAUUEQCAEKAK0AkUiBkUEQCAEKAKwAiIGECELCyAJQR9GQQEjARsEQCAUQb30BEEEQcH0BEEHQdeDBUEQEG1BHyMBQQFGDR8aCyMBRQRAIARBsAJqIRogBEHgAGohBgsgCUEgRkEBIwEbBEAgGiAGQb30BEEEECJBICMBQQFGDR8aCyMBRQRAIAQoArACIhZBEUciBg0LIARBsAJqIRMgBEHgAGohGCAHQRhqKAIAIRogB0EgaigCACEGCyAJQSFGQQEjARsEQCATIBggGiAGECVBISMBQQFGDR8aCyMBRQRAIAQoArACIhZBEUciBg0DIARBsAJqIQYLIAlBIkZBASMBGwRAIAYgFBA8QSIjAUEBRg0fGgsjAUUEQCAEKAK0AgRAIAQoArACECELIAdBJGoiByAPRyIGDQELCwsgDyAEQbACaiMBGyEPIAlBI0ZBASMBGwRAIA8gFBA8QSMjAUEBRg0dGgsjAUUEQCAEKAK0AgRAIAQoArACECELIAQoAmghFCAEQSBqIQ8gBCgCcCEHCyAJQSRGQQEjARsEQCAPIAcQ0QFBJCMBQQFGDR0aCyMBRQRAIAQoAiQhFiAEKAIgIBQgBxD/ASEUIARB4ABqENkBIAQgBzYCuAIgBCAWNgK0AiAEIBQ2ArACIARBsAJqIQ8gBEHgAGohBgsgCUElRkEBIwEbBEAgBiAsICJB8YUFQQggDxCPAUElIwFBAUYNHRoLIwFFBEAgBCgCYEUiDw0DIARB7ABqKAIAIQcgBEHoAGooAgAhFiAEKAJkIRQMGAsLIwFFBEAgBEGQAmoiDiAEQbwCaigCACILNgIAIAQgBCkCtAI3A4gCDBULCyMBRQRAIAQoArwCIRQgBCgCuAIhFiAEKAK0AiEPDBULCyMBRQRAIARB7ABqKAIAIRQgBEHoAGooAgAhGSAEKAJkIRpBGxAPIgdFIg8NDCAHQRdqIgZBu5wFKAAANgAAIAdBEGpBtJwFKQAANwAAIAdBCGpBrJwFKQAANwAAIAdBpJwFKQAAIkM3AABBMxAPIhZFIg8NDCAWQb6FBUEzEP8BIRYgBxAhIARBADYCxAIgBEIENwK8AiAEQrOAgICwBjcCtAIgBCAWNgKwAiAEQbACaiEPIARB4ABqIQYLIAlBJkZBASMBGwRAIAYgGiAUIA8QW0EmIwFBAUYNGhoLIBYgBEGQAWojARshFiAJQSdGQQEjARsEQCAWQfGFBUEIQc+DBUEIQdWEBUEXEG1BJyMBQQFGDRoaCyMBRQRAIARB4ABqIQ8gBEGwAmohBgsgCUEoRkEBIwEbBEAgBiAPECpBKCMBQQFGDRoaCyMBRQRAIAQoArACIhNBFkchDwsCQAJAIwFFBEAgDw0BIAQoArQCIQcgBEEANgKQAiAEQgQ3A4gCQQQgB0UNDBogB60hREIAIUJBBCEPQQAhBwsDQCMBRQRAIARBADYC4AEgBEIBNwPYASAEQQM6ANACIARCgICAgIAENwOwAiAEQfT2BDYCzAIgBEEANgLAAiAEQQA2ArgCIAQgBEHYAWo2AsgCIARBsAJqIQYLIAlBKUZBASMBGwRAIEJBASAGEFJBKSMBQQFGDR0aIQYLIwFFBEAgBg0KIAQoAtgBIRQgBCgC4AEhBgsgCUEqRkEBIwEbBEAgFkGUiwVBBiAUIAZBmosFQQEQbUEqIwFBAUYNHRoLIwFFBEAgBCgC3AEEQCAEKALYARAhCyAEQbACaiEUIARB4ABqIQYLIAlBK0ZBASMBGwRAIBQgBhAVQSsjAUEBRg0dGgsjAUUEQCAEKAK0AiEUIAQoArACIhNBFkchBgsCQCMBRQRAIAYNASAHIAQoAowCRyEGCwJAIwFFBEAgBg0BIARBiAJqIQ8LIAlBLEZBASMBGwRAIA8gBxB2QSwjAUEBRg0fGgsjAUUEQCAEKAKIAiEPIAQoApACIQcLCyMBRQRAIEJCAXwhQiAHQQJ0IA9qIBQ2AgAgBCAEKAKQAkEBaiIHNgKQAiAEQbACaiEGCyAJQS1GQQEjARsEQCAGIBYQPEEtIwFBAUYNHhoLIwFFBEAgBCgCtAIEQCAEKAKwAhAhCyBCIERRIgYNDQwCCwsLIwFFBEAgBCkDuAIhQiAEKAKMAkUiBg0CIA8QIQwCCwsjAUUEQCAEKQO4AiFCIAQoArQCIRQLCyMBRQRAIARB4ABqENkBIAQgFDYCZCAEIBM2AmAgBCBCPgJoIAQgQkIgiCJDPgJsIARBADYCuAIgBEIBNwOwAiAEQbACaiEPIARB4ABqIQYLIAlBLkZBASMBGwRAIAYgD0HQgQUQFkEuIwFBAUYNGhohDwsjAUUEQCAPDQggBEHgAGoQ5AEgBCgCsAIhFCAEKAK0AiEWIAQoArgCIQdBAQwLCwsjAUUEQCAEQZACaiIOIARBvAJqKAIAIgs2AgAgBCAEKQK0AjcDiAIMEgsLIwFFBEAgBEGQAmoiDiAEQbwCaigCACILNgIAIAQgBCkCtAI3A4gCDBELCyMBRQRAIARBkAJqIg4gBEG8AmooAgAiCzYCACAEIAQpArQCNwOIAgwQCwsjAUUEQCAEQZACaiIOIARBvAJqKAIAIgs2AgAgBCAEKQK0AjcDiAIMDwsLIwFFBEAgBEGQAmoiDiAEQbwCaigCACILNgIAIAQgBCkCtAI3A4gCDA4LCyMBRQRAIARBkAJqIg4gBEG8AmooAgAiCzYCACAEIAQpArQCNwOIAgwNCwsgEiAEQdgCaiMBGyESIAlBL0ZBASMBGwRAQfCWBUE3IBJBjPcEQYSYBRCbAUEvIwFBAUYNExoLIwFFBEAACwsgEiAEQdgCaiMBGyESIAlBMEZBASMBGwRAQfCWBUE3IBJB6IEFQYSYBRCbAUEwIwFBAUYNEhoLIwFFBEAACwsjAQR/IBQFIAQoAogCCwshFCMBRQRAIAQpAowCIUIgBEGwAmohDwsgCUExRkEBIwEbBEAgDyAWEDxBMSMBQQFGDRAaCyMBBH8gDwUgQkIgiCFEIAQoArQCBEAgBCgCsAIQIQsgRKchByBCpyEWIARB4ABqENkBQQALCyEPIwFFBEAgGUUiBkUEQCAaECELIA8NCiAFIAwQnAEgCgRAIAUQIQsgBEEYaiEPCyAJQTJGQQEjARsEQCAPIAcQlgFBMiMBQQFGDQ8aCyMBRQRAIAQoAhwhDyAEKAIYIgUgFCAHQQJ0EP8BIRpBGxAPIgxFIgYNASAMQRdqIgVBu5wFKAAANgAAIAxBEGpBtJwFKQAANwAAIAxBCGpBrJwFKQAANwAAIAxBpJwFKQAAIkM3AABBOBAPIgpFIgYNASAKQZCDBUE4EP8BIQogDBAhIARBsAJqIQYLIAlBM0ZBASMBGwRAIAYgCkE4ELgBQTMjAUEBRg0PGgsgBiAEQbwCaiMBGyEGIAlBNEZBASMBGwRAIAZBBEEAEF5BNCMBQQFGDQ8aCyMBRQRAIARBmAFqIARBuAJqKQMANwMAIARBoAFqIARBwAJqKQMANwMAIARBADYCjAEgBEIENwKEASAEQriAgICABzcCfCAEIAo2AnggBEEANgJwIARCATcDaCAEQgA3A2AgBCAEKQOwAiJDNwOQASAEQeAAaiEGIARBsAJqIQULIAlBNUZBASMBGwRAIAUgBkECED5BNSMBQQFGDQ8aCyMBRQRAIAQoArACIgxBEUchBgsCfwJAAkACQAJAAkACQAJAAkACQAJAAkAjAUUEQCAGDQEgBEGQAWohEwsgCUE2RkEBIwEbBEAgE0HIgwVBB0HPgwVBCEHXgwVBEBBtQTYjAUEBRg0bGgsjAUUEQCAEQeAAaiEGIARBsAJqIQULIAlBN0ZBASMBGwRAIAUgBkHIgwVBBxAiQTcjAUEBRg0bGgsjAUUEQCAEKAKwAiIMQRFHIgYNASAEQeAAaiEGIARBsAJqIQULIAlBOEZBASMBGwRAIAUgBiAHED9BOCMBQQFGDRsaCyMBRQRAIAQoArACIgxBEUciBg0BIAdFIQYLAkAjAUUEQCAGDQEgB0ECdCEKIBohBwsDQCMBRQRAIARBsAJqIRggBygCACEGIARB4ABqIQULIAlBOUZBASMBGwRAIBggBSAGECBBOSMBQQFGDR0aCyMBRQRAIAQoArACIgxBEUciBg0DIAdBBGohByAKQQRrIgoNAQsLCyAGIARBsAJqIwEbIQYgCUE6RkEBIwEbBEAgBiATEDxBOiMBQQFGDRsaCyMBRQRAIAQoArQCRSIGRQRAIAQoArACIgYQIQsLIAlBO0ZBASMBGwRAIBNB54MFQQpB8YMFQRRB14MFQRAQbUE7IwFBAUYNGxoLIwFFBEAgBEHgAGohBiAEQbACaiEFCyAJQTxGQQEjARsEQCAFIAZB54MFQQoQIkE8IwFBAUYNGxoLIwFFBEAgBCgCsAIiDEERRyIGDQEgBEHgAGohBiAEQbACaiEFCyAJQT1GQQEjARsEQCAFIAZBARAgQT0jAUEBRg0bGgsjAUUEQCAEKAKwAiIMQRFGIgYNAgsLIwFFBEAgBCgCvAIhByAEKAK4AiEKIAQoArQCIRMgBEHgAGoQ2QEgBCAHNgJsIAQgCjYCaCAEIBM2AmQgBCAMNgJgIARBADYCuAIgBEIBNwOwAiAEQbACaiELIARB4ABqIQ4LIAlBPkZBASMBGwRAIA4gC0HQgQUQGUE+IwFBAUYNGhohCwsjAUUEQCALRSILDQIgBEHYAmohEgsgCUE/RkEBIwEbBEBB8JYFQTcgEkHogQVBhJgFEJsBQT8jAUEBRg0aGgsjAUUEQAALCyAGIARBsAJqIwEbIQYgCUHAAEZBASMBGwRAIAYgExA8QcAAIwFBAUYNGRoLIwFFBEAgBCgCtAIEQCAEKAKwAhAhCyAEKAJoIQcgBCgCcCEMIARBEGohBgsgCUHBAEZBASMBGwRAIAYgDBDRAUHBACMBQQFGDRkaCyMBRQRAIAQoAhQhCiAEKAIQIAcgDBD/ASEHIARB4ABqENkBIAQgDDYCuAIgBCAKNgK0AiAEIAc2ArACIARBsAJqIQYgBEHgAGohBQsgCUHCAEZBASMBGwRAIAUgLCAiQbaEBUEGIAYQjwFBwgAjAUEBRg0ZGgsjAUUEQCAEKAJgBEAgBEHsAGooAgAhDCAEQegAaiILKAIAIQogBCgCZCEHDBELIARB7ABqKAIAIQcgBEHoAGooAgAhJiAEKAJkIRtBGxAPIgxFIgYNCyAMQRdqIgVBu5wFKAAANgAAIAxBEGpBtJwFKQAANwAAIAxBCGpBrJwFKQAANwAAIAxBpJwFKQAAIkM3AABBMRAPIgpFIgYNCyAKQYWEBUExEP8BIQogDBAhIARBADYCxAIgBEIENwK8AiAEQrGAgICQBjcCtAIgBCAKNgKwAiAEQbACaiEGIARB4ABqIQULIAlBwwBGQQEjARsEQCAFIBsgByAGEFtBwwAjAUEBRg0ZGgsgEyAEQZABaiMBGyETIAlBxABGQQEjARsEQCATQbaEBUEGQbyEBUEZQdWEBUEXEG1BxAAjAUEBRg0ZGgsjAUUEQCAEQeAAaiEGIARBsAJqIQULIAlBxQBGQQEjARsEQCAFIAYQKkHFACMBQQFGDRkaCyMBRQRAIAQoArACIgxBFkciBg0CIAQoArQCIQwgBEEANgKwASAEQgQ3A6gBIAxFBEBBBCEHDAoLIAytIUcgBEH1AWohNCAEQegBaiE1IARBsAJqQQVyIScgBEGYAmpBAXIhNiAEQYgCakEBciE3IARB2AFqIgZBBXIhOEIAIUILA0AjAUUEQCAEQQA2AuABIARCATcD2AEgBEEDOgDQAiAEQoCAgICABDcDsAIgBEH09gQ2AswCIARBADYCwAIgBEEANgK4AiAEIARB2AFqNgLIAiAEQbACaiEGCyAJQcYARkEBIwEbBEAgQkEBIAYQUkHGACMBQQFGDRoaIQYLAkACQAJAIwFFBEAgBg0BIAQoAuABIQYgBCgC2AEhBQsgCUHHAEZBASMBGwRAIBNBlIsFQQYgBSAGQZqLBUEBEG1BxwAjAUEBRg0dGgsjAUUEQCAEKALcAQRAIAQoAtgBECELIARB4ABqIQYgBEGwAmohBQsgCUHIAEZBASMBGwRAIAUgBhApQcgAIwFBAUYNHRoLIwFFBEAgBCgCtAIhByAEKAKwAiIMQRZHIgYNAiAHRQRAQQAhGEEAIQYMCAsgQkIBfCFCQQUhHEEAIRhBACEtQQAhOUEAIShBACEGCwNAIwFFBEAgBEGwAmohCiAEQeAAaiEFCyAJQckARkEBIwEbBEAgCiAFEBRByQAjAUEBRg0eGgsjAUUEQCAEKAK4AiEZIAQoArQCIQwgBCgCsAIiBUEWRyEeIAQoArwCIQoLAkACQAJAAkACQAJAAkACQAJAAkACQCMBRQRAIB4NASAKQQVrIQULAkACQAJ/AkACQAJAIwFFBEACQCAFDgICAAMLIAxB1vIEQQYQ/gFFIQULAkACQCMBRQRAIAUNASAMQbaEBUEGEP4BRSIFDQJBBiEKIAxB3/IEQQYQ/gEiBQ0ECyAJQcoARkEBIwEbBEAgEyAMQQZB5fIEQRRB7fUEQRwQbUHKACMBQQFGDTEaCyMBRQRAIAQpA2AhRCAEQYgCaiEKIARB4ABqIQULIAlBywBGQQEjARsEQCAKIAUQbkHLACMBQQFGDTEaCyMBRQRAIAQtAIgCQQRHIgUNBSAELQCJAgwGCwsgCUHMAEZBASMBGwRAIBMgDEEGQdzyBEEDQe31BEEcEG1BzAAjAUEBRg0wGgsjAUUEQCAEQbACaiEKIARB4ABqIQULIAlBzQBGQQEjARsEQCAKIAUQFUHNACMBQQFGDTAaCyMBRQRAIAQoArQCIS0gBCgCsAIiCkEWRyEFCwJAIwFFBEAgBQ0BIARBsAJqIQULIAlBzgBGQQEjARsEQCAFIBMQPEHOACMBQQFGDTEaCyMBRQRAIAQoArQCRSIFRQRAIAQoArACIgUQIQtBASE5DBMLCyMBRQRAIARBBjoA9AEgBCAEKQO4AiJDNwPgASAEIC02AtwBIAQgCjYC2AEMEQsLIAlBzwBGQQEjARsEQCATIAxBBkGRgAVBD0Ht9QRBHBBtQc8AIwFBAUYNLxoLIwFFBEAgBEGwAmohCiAEQeAAaiEFCyAJQdAARkEBIwEbBEAgCiAFEJQBQdAAIwFBAUYNLxoLIwFFBEAgBCgCsAIiCkEWRyEeIAQtALQCIQULAkACQCMBRQRAIB4NAUEAIQoLAkACQCMBRQRAIAUNASAEQYgCaiEKIARB4ABqIQULIAlB0QBGQQEjARsEQCAKIAUQHEHRACMBQQFGDTMaCyMBRQRAIAQoAogCQRZHIgUNAiAEKAKUAiE8IAQoApACISMgBCgCjAIhCgsLIwFFBEAgGEUgKUVyRQRAIBgQIQsgBEGwAmohBQsgCUHSAEZBASMBGwRAIAUgExA8QdIAIwFBAUYNMhoLIwFFBEAgBCgCtAJFIgVFBEAgBCgCsAIiBRAhCyAKIRggIyEpDBQLCyMBRQRAIARBuAJqIARBkAJqKQMANwMAIAQgBCkDiAIiQzcDsAIgBEEANgKgAiAEQgE3A5gCIARBsAJqIQogBEGYAmohBQsgCUHTAEZBASMBGwRAIAogBUH8hQUQFkHTACMBQQFGDTEaIQULIwFFBEAgBQ0KIAQoApgCIQcgBCgCnAIhEyAEKAKgAiEFIARBsAJqEOQBQQIhCgwCCwsjAUUEQCAELwC1AiIjIAQtALcCQRB0ckEIdCAFciEHIAQoArgCIRMgBCgCvAIhBQsLIwFFBEAgBCAFNgLkASAEIBM2AuABIAQgBzYC3AEgBEEGOgD0ASAEIAo2AtgBDBALCyMBRQRAQQUhCiAMQbSvBUEFEP4BRSIFDQ4LCyAFIARBCGojARshBSAJQdQARkEBIwEbBEAgBSAKQQAQqAFB1AAjAUEBRg0tGgsjAUUEQCAEIAQoAgw2AuABIAQgBCgCCCIHNgLcASAHIAwgChD/ASEFIARBBjoA9AEgBEERNgLYASAEIAo2AuQBDA4LCyMBBH8gCgUgBCkDiAIiRkL/AYMiQ0IEUiIFDQIgRkIIiCJDpwsLIQojAUUEQCAEIEQ3A2AgCkH/AXEiCkEfSyIFDQJBASAKdEGIgICAfnFFIgUNAgwICwsjAUUEQCAEIEY3A4gCIARBsAJqIQogBEGIAmohBQsgCUHVAEZBASMBGwRAIAogBRCXAUHVACMBQQFGDSoaCyMBRQRAIAQtALQCIQogBCgCsAIiBUEWRyIeDQQgCkH/AXEiBQ0HCwsjAUUEQCAEQbACaiEKIARB4ABqIQULIAlB1gBGQQEjARsEQCAKIAUQFUHWACMBQQFGDSkaCyMBRQRAIAQoArQCIRwgBCgCsAIiCkEWRiIFRQRAIAQgBCkDuAIiQzcD4AEgBCAcNgLcASAEIAo2AtgBDAYLIBxBBkkiBQ0HIARBADYCkAIgBEIBNwOIAiAEQQM6ANACIARCgICAgIAENwOwAiAEQdCBBTYCzAIgBEEANgLAAiAEQQA2ArgCIAQgBEGIAmo2AsgCIBytIkdCACAcrH0gHEF/SiIHGyFDIARBsAJqIQULIAlB1wBGQQEjARsEQCBDIAcgBRBSQdcAIwFBAUYNKRohBQsjAUUEQCAFDQQgBEEENgKsAiAEIARBiAJqNgKoAiAEQQE2AsQCIARCATcCtAIgBEGoggU2ArACIAQgBEGoAmo2AsACIARBmAJqIQogBEGwAmohBQsgCUHYAEZBASMBGwRAIAogBRA7QdgAIwFBAUYNKRoLIwFFBEAgBCgCjAIEQCAEKAKIAhAhCyAEIDYpAAA3A/gBIAQgNkEHaigAADYA/wEgBC0AmAIhByAnIAQpA/gBIkM3AAAgJ0EHaiAEKAD/ATYAACAEIAc6ALQCIARBATYCsAIgBEHYAWohCiAEQbACaiEFCyAJQdkARkEBIwEbBEAgCiAFEF9B2QAjAUEBRg0pGgsjAUUNBAsjAUUEQCAEIAo2AuQBIAQgGTYC4AEgBCAMNgLcASAEIAU2AtgBDBMLCyASIARB2AJqIwEbIRIgCUHaAEZBASMBGwRAQfCWBUE3IBJBlIYFQYSYBRCbAUHaACMBQQFGDScaCyMBRQRAAAsLIwFFBEAgOCAnKQAAIkM3AAAgOEEHaiIjICdBB2ooAAAiBzYAACAEQQY6APQBIAQgCjoA3AEgBCAFNgLYAQwHCwsgEiAEQdgCaiMBGyESIAlB2wBGQQEjARsEQEHwlgVBNyASQeiBBUGEmAUQmwFB2wAjAUEBRg0lGgsjAUUEQAALCyMBRQRAIARBBjoA9AEMBQsLIwFFBEAgBEGwAmohCiAEQeAAaiEFCyAJQdwARkEBIwEbBEAgCiAFEBRB3AAjAUEBRg0jGgsjAUUEQCAEKAK8AiEFIAQoArgCISggBCgCtAIhCgJAAkAgBCgCsAIiHEEWRyIeRQRAAkACQAJAAkAgBUEFayIeDgUDAgAJAQkLIApBsIIFQQcQ/gENBEEAIRwMBQsgCkG+ggVBCRD+AUUEQEECIRwMBQtBCSEFQQMhHCAKQceCBUEJEP4BIh4NBwwEC0EGIQVBBCEcIApB0IIFQQYQ/gEiHg0GDAMLQQUhBUEFIRwgCkHWggVBBRD+AUUiHg0CDAULIAQgBTYC5AEgBCAoNgLgASAEIAo2AtwBIARBBjoA9AEgBCAcNgLYAQwGC0EHIQVBASEcIApBt4IFQQcQ/gEiHg0DCyAoRSIFDQEgChAhCwsgBSAEQbACaiMBGyEFIAlB3QBGQQEjARsEQCAFIBMQPEHdACMBQQFGDSIaCyMBRQRAIAQoArQCRSIFRQRAIAQoArACIgUQIQtBASEoDAQLCyMBRQRAIAQgBTYCrAIgBCAKNgKoAiAEQQk2AvwBIAQgBEGoAmo2AvgBIARBATYCxAIgBEIBNwK0AiAEQYiDBTYCsAIgBCAEQfgBajYCwAIgBEGIAmohByAEQbACaiEFCyAJQd4ARkEBIwEbBEAgByAFEDtB3gAjAUEBRg0hGgsjAUUEQCAELQCIAiEFIAQgN0EHaigAADYAnwIgBCA3KQAANwOYAiAnIAQpA5gCIkM3AAAgJ0EHaiIjIAQoAJ8CNgAAIAQgBToAtAIgBEEANgKwAiAEQdgBaiEHIARBsAJqIQULIAlB3wBGQQEjARsEQCAHIAUQX0HfACMBQQFGDSEaCyMBRQRAIARBBjoA9AEgKEUiBQ0CIAoQIQwCCwsgCUHgAEZBASMBGwRAIBMgDEEFQfv/BEEOQe31BEEcEG1B4AAjAUEBRg0gGgsjAUUEQCAEQbACaiEKIARB4ABqIQULIAlB4QBGQQEjARsEQCAKIAUQlAFB4QAjAUEBRg0gGgsjAUUEQCAEKAKwAiIKQRZHIR4gBC0AtAIhBQsCQAJAAkAjAUUEQCAeDQFBACEKCwJAAkAjAUUEQCAFDQEgBEGIAmohCiAEQeAAaiEFCyAJQeIARkEBIwEbBEAgCiAFEBRB4gAjAUEBRg0lGgsjAUUEQCAEKAKIAkEWRyIFDQIgBCgClAIhLiAEKAKQAiEiIAQoAowCIQoLCyMBRQRAIAZFIgUgDkVyRQRAIAYQIQsgBEGwAmohDgsgCUHjAEZBASMBGwRAIA4gExA8QeMAIwFBAUYNJBoLIwFFBEAgBCgCtAIEQCAEKAKwAhAhCyAKIQYgIiEODAYLCyMBRQRAIARBuAJqIARBkAJqKQMANwMAIAQgBCkDiAIiQzcDsAIgBEEANgKgAiAEQgE3A5gCIARBsAJqIQogBEGYAmohBQsgCUHkAEZBASMBGwRAIAogBUH8hQUQFkHkACMBQQFGDSMaIQULIwFFBEAgBQ0DIAQoApgCIQcgBCgCnAIhEyAEKAKgAiEFIARBsAJqEOQBQQ8hCgwCCwsjAUUEQCAELwC1AiIjIAQtALcCQRB0ckEIdCAFciEHIAQoArgCIRMgBCgCvAIhBQsLIwFFBEAgBCAFNgLkASAEIBM2AuABIAQgBzYC3AEgBEEGOgD0ASAEIAo2AtgBDAILCyASIARB2AJqIwEbIRIgCUHlAEZBASMBGwRAQfCWBUE3IBJBlIYFQYSYBRCbAUHlACMBQQFGDSAaCyMBRQRAAAsLIwFFBEAgGUUiBQ0KIAwQIQwKCwsjAUUEQCAHQQFrIQcgGQRAIAwQIQsgB0UiBQ0EDAELCwsgEiAEQdgCaiMBGyESIAlB5gBGQQEjARsEQEHwlgVBNyASQYz3BEGEmAUQmwFB5gAjAUEBRg0cGgsjAUUEQAALCyMBRQRAIAQgBCkDuAI3A+ABIAQgBzYC3AEgBCAMNgLYAQwHCwsjAUUEQCA5RSIFDQQgKEEBcUUhBQsCQCMBRQRAIAUNASAEIC42AvABIAQgDjYC7AEgBCAGNgLoASAEIDw2AuQBIAQgKTYC4AEgBCAYNgLcASAEIC02AtgBIBxB/wFxQQZGIgYNByAEQcABaiIZIDVBCGooAgA2AgAgBEG2AWoiBiA0QQJqLQAAOgAAIAQgNC8AACIMOwHEASAEIDUpAgAiQzcDuAEgBCAMOwG0ASAEKQPgASFEIAQoAtgBIRggBCgCsAEiCiAEKAKsAUchByAEKALcASEFCwJAIwFFBEAgBw0BIARBqAFqIQcLIAlB5wBGQQEjARsEQCAKIR1BACENQQAhEEEAIRUjAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQ0LIAchCAJAAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIRULIwFFBEAjAEEgayINJAAgHUEBaiIdRSEQCwJAIwFFBEACQCAQDQAgCCgCBCIQQQF0IhEgHUshFyARIB0gFxsiHUEESyERIB1BBCARGyIdQQV0IREgHUGAgIAgSUECdCEXAkAgEARAIA1BBDYCGCANIBBBBXQ2AhQgDSAIKAIANgIQDAELIA1BADYCGAsgDSARIBcgDUEQahCOASANKAIARQRAIA0oAgQhECAIIB02AgQgCCAQNgIADAMLIA1BCGooAgAiCEGBgICAeEYNAiAIRQ0AAAsLIBVFQQEjARsEQBDTAUEAIwFBAUYNAhoLIwFFBEAACwsjAUUEQCANQSBqJAALDAELIQgjAigCACAINgIAIwIjAigCAEEEajYCACMCKAIAIA02AgAjAiMCKAIAQQRqNgIAC0HnACMBQQFGDRwaCyMBRQRAIAQoArABIQoLCyMBRQRAIAQoAqgBIgcgCkEFdGoiDCBENwIIIAwgBTYCBCAMIBg2AgAgDCAEKQO4ASJDNwIQIAwgHDoAHCAMIAQvAbQBOwAdIAxBGGoiGCAZKAIANgIAIAxBH2oiBSAGLQAAOgAAIAQgCkEBajYCsAEgBEGwAmohBgsgCUHoAEZBASMBGwRAIAYgExA8QegAIwFBAUYNGxoLIwFFBEAgBCgCtAIEQCAEKAKwAhAhCyBCIEdRIgYNCwwCCwsLIwFFBEBBHhAPIgxFIgUNCyAEQR42AuABIAQgDDYC3AEgDEEWakGb8wQpAAA3AAAgDEEQakGV8wQpAAA3AAAgDEEIaiIFQY3zBCkAADcAACAMQYXzBCkAADcAACAEQR42AuQBIARBEzYC2AEMBAsLIwFFBEAgBEHgAGoiCxDkASAEKAKwAiEHIAQoArQCIQogBCgCuAIhDAwPCwsjAUUEQCAEKQO4AiFCIAQoArQCIQcMBAsLIwFFBEBBDBAPIgxFIgUNCCAEQQw2AuABIAQgDDYC3AEgDEEIaiIKQYHzBCgAACIFNgAAIAxB+fIEKQAANwAAIARBDDYC5AEgBEETNgLYAQsLIwFFBEAgBkUiBSAORXJFBEAgBhAhCyAYRSIODQEgKUUiDg0BIBgQIQsLIwFFBEAgBEG4AmogBEHgAWopAwA3AwAgBCAEKQPYATcDsAIgBEEANgKQAiAEQgE3A4gCIARBiAJqIQ4gBEGwAmohBgsgCUHpAEZBASMBGwRAIAYgDkGAgAQQFkHpACMBQQFGDRQaIQ4LIwFFBEAgDg0CIAQoAogCIQcgBCkCjAIhQiAEQbACahBZIAQoAqgBIgggBCgCsAEQoQEgBCgCrAFFIg5FBEAgCBAhC0EUIQwLCyMBRQRAIARB4ABqENkBIAQgBzYCZCAEIAw2AmAgBCBCPgJoIAQgQkIgiD4CbCAEQQA2ArgCIARCATcDsAIgBEGwAmohDiAEQeAAaiEGCyAJQeoARkEBIwEbBEAgBiAOQdCBBRAWQeoAIwFBAUYNExohDgsjAUUEQCAODQIgBEHgAGoiDhDkASAEKAKwAiEHIAQoArgCIQxBASETIAQoArQCDAQLCyASIARB2AJqIwEbIRIgCUHrAEZBASMBGwRAQfCWBUE3IBJBmIAEQYSYBRCbAUHrACMBQQFGDRIaCyMBRQRAAAsLIBIgBEHYAmojARshEiAJQewARkEBIwEbBEBB8JYFQTcgEkHogQVBhJgFEJsBQewAIwFBAUYNERoLIwFFBEAACwsjAUUEQCAEKQKsASFCIARBsAJqIQ4LIAlB7QBGQQEjARsEQCAOIBMQPEHtACMBQQFGDRAaCyMBBH8gCgUgQkIgiCFEIAQoArQCBEAgBCgCsAIQIQsgRKchDCAEQeAAaiIOENkBQQAhEyBCpwsLIQojAUUEQCAmRSIORQRAIBsQIQsgEw0GQQAhGSAEQQA2ApACIARCBDcDiAJBBCAMRSIODQQaIAdBEGohEyALKAIAIQUgBEHYAWoiDkEEciEiQQQhJiALKAIIIQtBACEGCwNAAkAjAUUEQCATQQxqLQAAQQJGIQ4LAkACfwJAAkAjAUUEQCAODQEgEygCACIbRSIODQIgE0EIaigCACEOCyAJQe4ARkEBIwEbBEAgIiAbIA4QuAFB7gAjAUEBRg0VGgsjAUUNAwsjAUUEQCATQQxrKAIAIhtFBEBBKhAPIhtFIg4NByAEQSo2AuABIAQgGzYC3AEgG0Gs8gRBKhD/AQwDCyATQQRrKAIAIQsgBEGwAmohDgsgCUHvAEZBASMBGwRAIA4gGyALEFNB7wAjAUEBRg0UGgsjAUUEQCAEKAKwAkEWRiILDQQgBEHoAGoiCyAEQbgCaikDADcDACAEIAQpA7ACNwNgIARB4ABqIRILIAlB8ABGQQEjARsEQEH3ogVBKyASQfTvBEGc8gQQmwFB8AAjAUEBRg0UGgsjAUUEQAALCyMBBH8gDgVBKhAPIhtFIg4NBSAEQSo2AuABIAQgGzYC3AEgG0Gs8gRBKhD/AQsLIQ4jAUUEQCAEQSo2AuQBCwsjAUUEQCAGIAtGIg4NBCAEKALgASEbIARB4ABqIS4gBSgCACEYIAVBBGooAgAhKSAEKALkASEjIAQoAtwBIQ4LIAlB8QBGQQEjARsEQCAuIBggKSAyIA4gIxCVAUHxACMBQQFGDREaCyMBRQRAIBsEQCAOECELIBkgBCgCjAJHIQ4gBkEBaiEGCwJAIwFFBEAgDg0BIARBiAJqIQ4LIAlB8gBGQQEjARsEQCAOIBkQc0HyACMBQQFGDRIaCyMBRQRAIAQoAogCISYgBCgCkAIhGQsLIwFFBEAgJiAZQQxsaiIOIAQpA2A3AgAgDkEIaiIjIARB6ABqKAIANgIAIAQgBCgCkAJBAWoiGTYCkAIgBUEIaiEFIBNBIGohEyAGIAxGIg4NBQwCCwsLIwFFBEAgIiAEKQK0AjcCACAiQQhqIg4gBEG8AmooAgA2AgAgBEEANgLYASASIAQpA9gBNwIAIBJBCGoiCyAEQeABaiISKQMANwIAIAQoAogCIQYMBQsLIwFFBEAACwsgCUHzAEZBASMBGwRAIAsgC0GY+QQQpgFB8wAjAUEBRg0NGgsjAUUEQAALCyMBBH8gBgUgBCgCiAILCyEGIAsgEkEEaiMBGyELIAlB9ABGQQEjARsEQCALIAYgGRBOQfQAIwFBAUYNCxoLIwFFBEAgEkEBNgIACwsjAUUEQCAZBEAgGUEMbCEZIAYhEwNAIBNBBGooAgAEQCATKAIAECELIBNBDGohEyAZQQxrIhkNAAsLIAQoAowCBEAgBhAhCyAHIAwQoQEgCkUiEg0CIAcQIQwCCwsjAUUEQCASIAc2AgQgEkEBNgIAIBJBDGoiCyAMNgIAIBJBCGoiEiAKNgIACwsjAUUEQCAPBEAgGhAhCyAUIQUgFg0EDAULCyMBRQRAIARBvAJqIARBkAJqKAIANgIAIAQgFjYCsAIgBCAEKQOIAjcCtAIgBEEANgLgASAEQgE3A9gBIARB2AFqIQsgBEGwAmohDgsgCUH1AEZBASMBGwRAIA4gC0GAgAQQGUH1ACMBQQFGDQcaIQsLIAsgC0UjARshCwJAIwFFBEAgCw0BIARB2AJqIRILIAlB9gBGQQEjARsEQEHwlgVBNyASQZiABEGEmAUQmwFB9gAjAUEBRg0IGgsjAUUEQAALCyMBRQRAIAQoAtgBIQ8gBCgC3AEhFiAEKALgASEUIARBsAJqIgsQbEEPIQcLCyMBRQRAIARB4ABqENkBIAQgFDYCbCAEIBY2AmggBCAPNgJkIAQgBzYCYCAEQQA2ArgCIARCATcDsAIgBEGwAmohCyAEQeAAaiEOCyAJQfcARkEBIwEbBEAgDiALQdCBBRAZQfcAIwFBAUYNBhohCwsgCyALRSMBGyELAkAjAUUEQCALDQEgBEHYAmohEgsgCUH4AEZBASMBGwRAQfCWBUE3IBJB6IEFQYSYBRCbAUH4ACMBQQFGDQcaCyMBRQRAAAsLIwFFBEAgBEHgAGoQ5AEgBCgCsAIhFCAEKAK0AiEWIAQoArgCIQcLCyMBRQRAIBIgFDYCBCASQQE2AgAgEkEMaiAHNgIAIBJBCGogFjYCACAFIAwQnAEgCkUNAgsLIwFFBEAgBRAhCwsjAUUEQCA7RQ0BICwQIQsLIwFFBEAgBCgCQCEFIAQoAkgiBgRAIAZBDGwhByAFIQwDQCAMQQRqKAIABEAgDCgCABAhCyAMQQxqIQwgB0EMayIHDQALCyAEKAJEBEAgBRAhCyAzRQ0BICsQIQsLIwFFBEAgBEHgAmokAAsMAQshCCMCKAIAIAg2AgAjAiMCKAIAQQRqNgIAIwIoAgAiCCASNgIAIAggCzYCBCAIIA42AgggCCAPNgIMIAggMjYCECAIIAQ2AhQgCCAGNgIYIAggBTYCHCAIIAc2AiAgCCAKNgIkIAggDDYCKCAIIBQ2AiwgCCATNgIwIAggFjYCNCAIIEI3AjggCCAZNgJAIAggQzcCRCAIIBg2AkwgCCAaNgJQIAggGzYCVCAIIBw2AlggCCBENwJcIAggHjYCZCAIICI2AmggCCAjNgJsIAggJjYCcCAIICc2AnQgCCAoNgJ4IAggRjcCfCAIICk2AoQBIAggKzYCiAEgCCAsNgKMASAIIC02ApABIAggLjYClAEgCCAzNgKYASAIIEc3ApwBIAggNDYCpAEgCCA1NgKoASAIIDY2AqwBIAggNzYCsAEgCCA4NgK0ASAIIDk2ArgBIAggOzYCvAEgCCA8NgLAASMCIwIoAgBBxAFqNgIAC0EAIwFBAUYNBxoLIwFFDQELIEFBAUZBASMBGwRAICUgPyAqIAEgAiAxEB5BASMBQQFGDQYaCwsjAUUEQCAlKAIMIUAgJSgCCCEDICUoAgQhMCAlKAIARQ0DIC9BAWshLyADRSIDRQRAIDAQIQsgLw0BCwsLIwFFBEAgAEEANgIADAILCyMBRQRAIAAgQDYCCCAAIAM2AgQgACAwNgIACwsjAUUEQCAlQRBqJAALDwshBSMCKAIAIAU2AgAjAiMCKAIAQQRqNgIAIwIoAgAiBSAANgIAIAUgATYCBCAFIAI2AgggBSADNgIMIAUgJTYCECAFICo2AhQgBSAvNgIYIAUgMDYCHCAFIDE2AiAgBSA9NgIkIAUgPjYCKCAFID82AiwgBSBANgIwIwIjAigCAEE0ajYCAAutBAEEfyMBQQJGBEAjAiMCKAIAQRxrNgIAIwIoAgAiBSgCACEAIAUoAgQhASAFKAIIIQIgBSgCDCEDIAUoAhAhBCAFKAIUIQcgBSgCGCEFCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEGCyMBRQRAIwAiBEEQayIHJAALAkADQCAEIAdBCGojARshBCAGRUEBIwEbBEAgBCABIAIgAxCRAUEAIwFBAUYNAxoLIwFFBEAgBy0ACCIEQQRHIQULAkACQCMBRQRAAkAgBUUEQCAHKAIMIgQNASAAQYyZBTYCBCAAQQI2AgAMBgsCQAJ/AkACQAJAIARBAWsOAwABAgQLIActAAkMAgsgBygCDC0ACAwBCyAHKAIMLQAIC0H/AXFBI0YiBA0DCyAAIAcpAwg3AgAMBQsgAyAESSIFRQRAIAMgBGshAyACIARqIQIMAwsLIAZBAUZBASMBGwRAIAQgA0HgmAUQ9gFBASMBQQFGDQUaCyMBRQRAAAsLIAQgB0EIaiMBGyEEIAZBAkZBASMBGwRAIAQQ0AFBAiMBQQFGDQQaCwsjAUVBACADGw0ACyMBRQRAIABBBDoAAAsLIwFFBEAgB0EQaiQACw8LIQYjAigCACAGNgIAIwIjAigCAEEEajYCACMCKAIAIgYgADYCACAGIAE2AgQgBiACNgIIIAYgAzYCDCAGIAQ2AhAgBiAHNgIUIAYgBTYCGCMCIwIoAgBBHGo2AgAL7wIBBH8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQMLAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQULIwFFBEAjAEEgayIDJAAgASACaiICIAFJIQELAkAjAUUEQAJAIAENACAAQQRqKAIAIgFBAXQiBCACSyEGIAQgAiAGGyICQQhLIQQgAkEIIAQbIgJBf3NBH3YhBAJAIAEEQCADQQE2AhggAyABNgIUIAMgACgCADYCEAwBCyADQQA2AhgLIAMgAiAEIANBEGoQkwEgAygCAEUEQCAAIAMoAgQ2AgAgAEEEaiACNgIADAMLIANBCGooAgAiAEGBgICAeEYNAiAARQ0AAAsLIAVFQQEjARsEQBDTAUEAIwFBAUYNAhoLIwFFBEAACwsjAUUEQCADQSBqJAALDwshACMCKAIAIAA2AgAjAiMCKAIAQQRqNgIAIwIoAgAgAzYCACMCIwIoAgBBBGo2AgALxgEBA38jAEEgayIEJAAgAAJ/QQAgAiADaiIDIAJJDQAaIAEoAgQiAkEBdCIFIANLIQYgBSADIAYbIgNBCEshBSADQQggBRsiBUF/c0EfdiEDAkAgAgRAIARBATYCGCAEIAI2AhQgBCABKAIANgIQDAELIARBADYCGAsgBCAFIAMgBEEQahCOASAEKAIEIQMgBCgCAARAIARBCGooAgAMAQsgASAFNgIEIAEgAzYCAEGBgICAeAs2AgQgACADNgIAIARBIGokAAvGAQEDfyMAQSBrIgQkACAAAn9BACACIANqIgMgAkkNABogASgCBCICQQF0IgUgA0shBiAFIAMgBhsiA0EISyEFIANBCCAFGyIFQX9zQR92IQMCQCACBEAgBEEBNgIYIAQgAjYCFCAEIAEoAgA2AhAMAQsgBEEANgIYCyAEIAUgAyAEQRBqEJIBIAQoAgQhAyAEKAIABEAgBEEIaigCAAwBCyABIAU2AgQgASADNgIAQYGAgIB4CzYCBCAAIAM2AgAgBEEgaiQAC+0FAQ1/QbSnBCEJQQQhCkHKqgUhC0GkpwQhDEEIIQ1BmqcEIQ5BCyEFQY+nBCEEIwFBAkYEQCMCIwIoAgBBOGs2AgAjAigCACIDKAIAIQAgAygCCCEFIAMoAgwhDiADKAIQIQ0gAygCGCEMIAMoAhwhCyADKAIgIQogAygCJCECIAMoAighCSADKAIsIQYgAygCMCEIIAMoAjQhDyADKAIEIQQgAygCFCEBCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEHCyMBRQRAIwBBEGsiBiQAIAAoAhghDyAAQRxqKAIAKAIMIQgLIAdFQQEjARsEQCAPIAQgBSAIEQEAQQAjAUEBRg0BGiEFCyMBRQRAIAZBADoADSAGIAU6AAwgBiAANgIIIAZBCGohAAsgB0EBRkEBIwEbBEAgACAOIA0gASAMEExBASMBQQFGDQEaIQALIAdBAkZBASMBGwRAIAAgCyAKIAIgCRBMQQIjAUEBRg0BGiEFCyMBRQRAIAYtAA1FIQQgBi0ADCEACwJAIwFFBEAgBA0BIABB/wFxQQEhAA0BIAUoAgAiAC0AAEEEcSEECwJAIwFFBEAgBA0BIAAoAhghBCAAQRxqKAIAKAIMIQALIAdBA0ZBASMBGwRAIARB1oUEQQIgABEBAEEDIwFBAUYNAxohAAsjAUUNAQsjAUUEQCAAKAIYIQQgAEEcaigCACgCDCEACyAHQQRGQQEjARsEQCAEQc6FBEEBIAARAQBBBCMBQQFGDQIaIQALCyMBRQRAIAZBEGokACAAQf8BcUEARw8LAAshAyMCKAIAIAM2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAyAANgIAIAMgBDYCBCADIAU2AgggAyAONgIMIAMgDTYCECADIAE2AhQgAyAMNgIYIAMgCzYCHCADIAo2AiAgAyACNgIkIAMgCTYCKCADIAY2AiwgAyAINgIwIAMgDzYCNCMCIwIoAgBBOGo2AgBBAAvtAgEEfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhAwsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBQsjAUUEQCMAQSBrIgMkACABIAJqIgIgAUkhAQsCQCMBRQRAAkAgAQ0AIAAoAgQiAUEBdCIEIAJLIQYgBCACIAYbIgJBCEshBCACQQggBBsiAkF/c0EfdiEEAkAgAQRAIANBATYCGCADIAE2AhQgAyAAKAIANgIQDAELIANBADYCGAsgAyACIAQgA0EQahCSASADKAIARQRAIAMoAgQhASAAIAI2AgQgACABNgIADAMLIANBCGooAgAiAEGBgICAeEYNAiAARQ0AAAsLIAVFQQEjARsEQBDTAUEAIwFBAUYNAhoLIwFFBEAACwsjAUUEQCADQSBqJAALDwshACMCKAIAIAA2AgAjAiMCKAIAQQRqNgIAIwIoAgAgAzYCACMCIwIoAgBBBGo2AgALnwQBA38jAUECRgRAIwIjAigCAEEQazYCACMCKAIAIgEoAgAhACABKAIIIQIgASgCDCEDIAEoAgQhAQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBQsjAUUEQCMAQdAAayIDJAAgAkEIdCECCyAFRUEBIwEbBEAgAyABIAIQqgFBACMBQQFGDQEaCyMBRQRAQREhAiADLQAAQQRGIQELAkACQCMBRQRAIAENASADIAMpAwA3AwggA0EANgIYIANCATcDECADQTxqQdiWBTYCACADQQM6AEAgA0KAgICAgAQ3AyAgA0EANgIwIANBADYCKCADIANBEGo2AjggA0EgaiECIANBCGohAQsgBUEBRkEBIwEbBEAgASACEElBASMBQQFGDQMaIQELIwFFBEAgAQ0CIAAgAykDEDcCBCAAQQxqIANBGGooAgAiAjYCACADQQhqIQELIAVBAkZBASMBGwRAIAEQ0AFBAiMBQQFGDQMaCyACQQEjARshAgsjAUUEQCAAIAI2AgAgA0HQAGokAA8LCyAAIANByABqIwEbIQAgBUEDRkEBIwEbBEBB8JYFQTcgAEGolwVBhJgFEJsBQQMjAUEBRg0BGgsjAUUEQAALDwshBCMCKAIAIAQ2AgAjAiMCKAIAQQRqNgIAIwIoAgAiBCAANgIAIAQgATYCBCAEIAI2AgggBCADNgIMIwIjAigCAEEQajYCAAufBAEFfyMBQQJGBEAjAiMCKAIAQRRrNgIAIwIoAgAiASgCACEAIAEoAgghAiABKAIMIQMgASgCECEGIAEoAgQhAQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBQsjAUUEQCMAQdAAayICJAALIAVFQQEjARsEQCACIAFBBBCqAUEAIwFBAUYNARoLIwFFBEAgAi0AAEEERiEDQREhAQsCQAJAIwFFBEAgAw0BIAIgAikDADcDCCACQQA2AhggAkIBNwMQIAJBPGpB2JYFNgIAIAJBAzoAQCACQoCAgICABDcDICACQQA2AjAgAkEANgIoIAIgAkEQajYCOCACQQhqIQMgAkEgaiEGQQAhAQsgBUEBRkEBIwEbBEAgAyAGEElBASMBQQFGDQMaIQMLIwFFBEAgAw0CIAAgAikDEDcCBCAAQQxqIAJBGGooAgA2AgAgAkEIaiEDCyAFQQJGQQEjARsEQCADENABQQIjAUEBRg0DGgsLIwFFBEAgACABNgIAIAJB0ABqJAAPCwsgACACQcgAaiMBGyEAIAVBA0ZBASMBGwRAQfCWBUE3IABBqJcFQYSYBRCbAUEDIwFBAUYNARoLIwFFBEAACw8LIQQjAigCACAENgIAIwIjAigCAEEEajYCACMCKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIAQgAzYCDCAEIAY2AhAjAiMCKAIAQRRqNgIAC6cBAgJ/An4CQAJAIANFBEAMAQtBACADayEHA0AgAi0AACABajEAACIJQgFYBEAgAkEBaiECIAggCSAGQQdqQT9xrYaEIQggBkEBayIGIAdHDQEMAgsLQQAgBmshA0EBIQYMAQtBACEGIAVFDQBBACECA0AgBCAIIAJBOHGtiDwAACACQThqIQIgBEEBaiEEIAVBAWsiBQ0ACwsgACADNgIEIAAgBjYCAAuGBAEHfyMBQQJGBEAjAiMCKAIAQRxrNgIAIwIoAgAiAigCACEAIAIoAgQhAyACKAIIIQYgAigCDCEEIAIoAhAhBSACKAIUIQcgAigCGCECCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEBCyMBRQRAIwBBMGsiAyQAIANBCGohBgsgAUVBASMBGwRAIAZBsYEEQSsQJEEAIwFBAUYNARoLIwFFBEAgAygCECIGRSEECwJAIwFFBEAgBA0BIAMoAggiBCgCACEHIABBBGohBSAEQQhqKAIAIQILIAFBAUZBASMBGwRAIAUgByACELgBQQEjAUEBRg0CGgsjAUUEQEEAIQUgAEEANgIAIAZBDGwhAQNAIAQgBWoiAEEEaigCAARAIAAoAgAQIQsgBUEMaiIFIAFHDQALIAMoAgwEQCAEECELIANBMGokAA8LCyMBRQRAIANBLGpBADYCACADQeihBTYCKCADQgE3AhwgA0Gc+wQ2AhggA0EYaiEACyABQQJGQQEjARsEQCAAQaT7BBDSAUECIwFBAUYNARoLIwFFBEAACw8LIQEjAigCACABNgIAIwIjAigCAEEEajYCACMCKAIAIgEgADYCACABIAM2AgQgASAGNgIIIAEgBDYCDCABIAU2AhAgASAHNgIUIAEgAjYCGCMCIwIoAgBBHGo2AgALnwECA38CfgJAAkAgA0UEQAwBC0EEIQdBASEIA0AgAiAGai0AACABajEAACIKQg9WBEAgBiEDDAMLIAkgCiAHQTxxrYaEIQkgB0E8aiEHIAZBAWoiBiADRw0ACwtBACEIIAVFDQBBACEGA0AgBCAJIAZBOHGtiDwAACAGQThqIQYgBEEBaiEEIAVBAWsiBQ0ACwsgACADNgIEIAAgCDYCAAufAQIDfwJ+AkACQCADRQRADAELQQYhB0EBIQgDQCACIAZqLQAAIAFqMQAAIgpCA1YEQCAGIQMMAwsgCSAKIAdBPnGthoQhCSAHQT5qIQcgBkEBaiIGIANHDQALC0EAIQggBUUNAEEAIQYDQCAEIAkgBkE4ca2IPAAAIAZBOGohBiAEQQFqIQQgBUEBayIFDQALCyAAIAM2AgQgACAINgIAC5wBAgN/An4CQAJAIANFBEAMAQtBEiEHQQEhCANAIAIgBmotAAAgAWoxAAAiCkI/Vg0CIAkgCiAHQT5xrYaEIQkgB0EGayEHIAZBAWoiBiADRw0ACwtBACEIIAUEQEEQIQYDQCAEIAkgBkE4ca2IPAAAIAZBOGohBiAEQQFqIQQgBUEBayIFDQALCyADIQYLIAAgBjYCBCAAIAg2AgALnAECA38CfgJAAkAgA0UEQAwBC0EjIQdBASEIA0AgAiAGai0AACABajEAACIKQh9WDQIgCSAKIAdBP3GthoQhCSAHQQVrIQcgBkEBaiIGIANHDQALC0EAIQggBQRAQSAhBgNAIAQgCSAGQThxrYg8AAAgBkE4aiEGIARBAWohBCAFQQFrIgUNAAsLIAMhBgsgACAGNgIEIAAgCDYCAAucAQIDfwJ+AkACQCADRQRADAELQRUhB0EBIQgDQCACIAZqLQAAIAFqMQAAIgpCB1YNAiAJIAogB0E/ca2GhCEJIAdBA2shByAGQQFqIgYgA0cNAAsLQQAhCCAFBEBBECEGA0AgBCAJIAZBOHGtiDwAACAGQThqIQYgBEEBaiEEIAVBAWsiBQ0ACwsgAyEGCyAAIAY2AgQgACAINgIAC6ADAQV/IwFBAkYEQCMCIwIoAgBBGGs2AgAjAigCACIDKAIAIQAgAygCBCEBIAMoAgghAiADKAIMIQYgAygCECEFIAMoAhQhAwsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBAsjAUUEQCMAQTBrIgIkACABKAIEIQYgAUEEaiEFCwJAIwFFBEAgBg0BIAEoAgAhASACQRBqIgZBADYCACACQgE3AwggAiACQQhqNgIUIAJBKGogAUEQaikCADcDACACQSBqIAFBCGopAgA3AwAgAiABKQIANwMYIAJBGGohAyACQRRqIQELIARFQQEjARsEQCABQdChBSADECsaQQAjAUEBRg0CGgsjAUUEQCAFQQhqIAYoAgA2AgAgBSACKQMINwIACwsjAUUEQCAAQeyuBTYCBCAAIAU2AgAgAkEwaiQACw8LIQQjAigCACAENgIAIwIjAigCAEEEajYCACMCKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIAQgBjYCDCAEIAU2AhAgBCADNgIUIwIjAigCAEEYajYCAAuVAQIDfwJ+AkAgAwRAQQEhCANAIAIgBmotAAAgAWoxAAAiCkI/VgRAIAYhAwwDCyAJIAogB0E+ca2GhCEJIAdBBmohByAGQQFqIgYgA0cNAAsLQQAhCCAFRQ0AQQAhBgNAIAQgCSAGQThxrYg8AAAgBkEIaiEGIARBAWohBCAFQQFrIgUNAAsLIAAgAzYCBCAAIAg2AgALlQECA38CfgJAIAMEQEEBIQgDQCACIAZqLQAAIAFqMQAAIgpCH1YEQCAGIQMMAwsgCSAKIAdBP3GthoQhCSAHQQVqIQcgBkEBaiIGIANHDQALC0EAIQggBUUNAEEAIQYDQCAEIAkgBkE4ca2IPAAAIAZBCGohBiAEQQFqIQQgBUEBayIFDQALCyAAIAM2AgQgACAINgIAC5UBAgN/An4CQCADBEBBASEIA0AgAiAGai0AACABajEAACIKQg9WBEAgBiEDDAMLIAkgCiAHQTxxrYaEIQkgB0EEaiEHIAZBAWoiBiADRw0ACwtBACEIIAVFDQBBACEGA0AgBCAJIAZBOHGtiDwAACAGQQhqIQYgBEEBaiEEIAVBAWsiBQ0ACwsgACADNgIEIAAgCDYCAAuVAQIDfwJ+AkAgAwRAQQEhCANAIAIgBmotAAAgAWoxAAAiCkIHVgRAIAYhAwwDCyAJIAogB0E/ca2GhCEJIAdBA2ohByAGQQFqIgYgA0cNAAsLQQAhCCAFRQ0AQQAhBgNAIAQgCSAGQThxrYg8AAAgBkEIaiEGIARBAWohBCAFQQFrIgUNAAsLIAAgAzYCBCAAIAg2AgALlQECA38CfgJAIAMEQEEBIQgDQCACIAZqLQAAIAFqMQAAIgpCA1YEQCAGIQMMAwsgCSAKIAdBPnGthoQhCSAHQQJqIQcgBkEBaiIGIANHDQALC0EAIQggBUUNAEEAIQYDQCAEIAkgBkE4ca2IPAAAIAZBCGohBiAEQQFqIQQgBUEBayIFDQALCyAAIAM2AgQgACAINgIAC6IBACAAAn8CQAJ/AkAgAgRAAkACQAJAIAFBAE4EQCADKAIIRQ0CIAMoAgQNASABDQMMBQsgAEEIakEANgIADAYLIAMoAgAgARAjDAQLIAFFDQILIAEQDwwCCyAAIAE2AgQgAEEIakEANgIADAILIAILIgMEQCAAIAM2AgQgAEEIaiABNgIAQQAMAgsgACABNgIEIABBCGogAjYCAAtBAQs2AgAL/QQBBX8jAUECRgRAIwIjAigCAEEkazYCACMCKAIAIgEoAgAhACABKAIIIQIgASgCDCEDIAEoAhAhBCABKAIUIQUgASgCGCEIIAEoAhwhCSABKAIgIQogASgCBCEBCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEHCyMBRQRAIwBBEGsiCCQAIAUoAgghCiAFKAIAIQkLIAdFQQEjARsEQCABIAIgAyAEIAkgChAHQQAjAUEBRg0BGiEBCwJ/IwFFQQAgASABRSMBGyIBG0UEQCAHQQFGQQEjARsEQBAIQQEjAUEBRg0DGiEECyAHQQJGQQEjARsEQCAIIAQQ0QFBAiMBQQFGDQMaCyMBRQRAIAgoAgAhAwsgB0EDRkEBIwEbBEAgAxAJQQMjAUEBRg0DGgsjAUUEQCAAQQxqIAQ2AgAgAEEIaiAENgIAIAAgAzYCBEEADAILCyABIAhBCGojARshASAHQQRGQQEjARsEQBAKQQQjAUEBRg0CGiEECyAHQQVGQQEjARsEQCABIAQQ0QFBBSMBQQFGDQIaCyMBRQRAIAgoAgghAwsgB0EGRkEBIwEbBEAgAxALQQYjAUEBRg0CGgsjAQR/IAQFIABBDGogBDYCACAAQQhqIAQ2AgAgACADNgIEQQELCyEBIwFFBEAgACABNgIAIAUoAgQEQCAJECELIAhBEGokAAsPCyEGIwIoAgAgBjYCACMCIwIoAgBBBGo2AgAjAigCACIGIAA2AgAgBiABNgIEIAYgAjYCCCAGIAM2AgwgBiAENgIQIAYgBTYCFCAGIAg2AhggBiAJNgIcIAYgCjYCICMCIwIoAgBBJGo2AgALlAECAn8CfgJAAkAgA0UEQAwBC0EBIQcDQCACIAZqLQAAIAFqMQAAIglCAVYEQCAGIQMMAwsgCCAJIAZBP3GthoQhCCAGQQFqIgYgA0cNAAsLQQAhByAFRQ0AQQAhBgNAIAQgCCAGQThxrYg8AAAgBkEIaiEGIARBAWohBCAFQQFrIgUNAAsLIAAgAzYCBCAAIAc2AgAL8gICBH8CfiMBQQJGBEAjAiMCKAIAQQhrNgIAIwIoAgAiBCgCACEFIAQoAgQhBAsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBwsjAUUEQCABQRBqKAIAIgWtIgkgASkDACIIViEEIAggCSAEG6ciBCAFSyEGCwJAIwFFBEAgBkUEQCABKAIIIARqIQYgBSAEayIFIANLIQQCQCADIAUgBBsiBUEBRwRAIAIgBiAFEP8BGgwBCyADRQ0DIAIgBi0AADoAAAsgACAFNgIEIABBBDoAACABIAggBa18NwMADwsLIAdFQQEjARsEQCAEIAVBzKAFEPYBQQAjAUEBRg0CGgsjAUUEQAALCyAHQQFGQQEjARsEQEEAQQBB8J8FEKYBQQEjAUEBRg0BGgsjAUUEQAALDwshACMCKAIAIAA2AgAjAiMCKAIAQQRqNgIAIwIoAgAiACAFNgIAIAAgBDYCBCMCIwIoAgBBCGo2AgALhwEAIAACfwJ/AkACQCACBEACQCABQQBOBEAgAygCCA0BDAQLDAILIAMoAgRFDQIgAygCACABECMMAwsgACABNgIECyAAQQhqQQA2AgBBAQwCCyABEA8LIgIEQCAAIAI2AgQgAEEIaiABNgIAQQAMAQsgACABNgIEIABBCGpBATYCAEEBCzYCAAuMAQACfwJAAkAgAgRAAkAgAUEATgRAIAMoAggNAQwECwwCCyADKAIERQ0CIAMoAgAgARAjDAMLIAAgATYCBAsgAEEIakEANgIAIABBATYCAA8LIAEQDwsiAgRAIAAgAjYCBCAAQQhqIAE2AgAgAEEANgIADwsgACABNgIEIABBCGpBATYCACAAQQE2AgALkAMCA38CfiMBQQJGBEAjAiMCKAIAQRhrNgIAIwIoAgAiASgCACEAIAEoAgghBCABKAIMIQMgASkCECEFIAEoAgQhAQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhAgsjAUUEQCMAQRBrIgQkACABKQMAIQUgBEEIaiEDCyACRUEBIwEbBEAgAyABEG5BACMBQQFGDQEaCyMBRQRAIAQtAAhBBEchAwsCQCMBRQRAAkACfyADRQRAIAQtAAkMAQsgBCkDCCIGQv8Bg0IEUg0BIAZCCIinC0EBIQNB/wFxQQRHBEAgASAFNwMAQQAhAwsgAEEWNgIAIAAgAzoABAwCCyAEIAY3AwggBEEIaiEBCyACQQFGQQEjARsEQCAAIAEQlwFBASMBQQFGDQIaCwsjAUUEQCAEQRBqJAALDwshAiMCKAIAIAI2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAiAANgIAIAIgATYCBCACIAQ2AgggAiADNgIMIAIgBTcCECMCIwIoAgBBGGo2AgAL0wIBAn8jAUECRgRAIwIjAigCAEEMazYCACMCKAIAIgYoAgAhACAGKAIEIQEgBigCCCEGCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEHCyMBRQRAIwBB0ABrIgYkACAGIAI2AgwgBiABNgIIIAYgAzYCFCAGIAU2AhwgBiAENgIYIAZBNGpBCTYCACAGQSxqQQU2AgAgBkEJNgIkIAYgBkEYajYCMCAGIAZBFGo2AiggBiAGQQhqNgIgIAZBAzYCTCAGQgM3AjwgBkHo+QQ2AjggBiAGQSBqNgJIIAZBOGohAQsgB0VBASMBGwRAIAAgARA7QQAjAUEBRg0BGgsjAUUEQCAGQdAAaiQACw8LIQIjAigCACACNgIAIwIjAigCAEEEajYCACMCKAIAIgIgADYCACACIAE2AgQgAiAGNgIIIwIjAigCAEEMajYCAAuzAgEDfyMBQQJGBEAjAiMCKAIAQQxrNgIAIwIoAgAiAigCACEAIAIoAgQhASACKAIIIQILAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQQLAkAjAUUEQCABRQRAQQQhAgwCCyABQf////8BSyECCwJAIwFFBEACQCACDQAgAUECdCIDQQBIIgINACABQYCAgIACSUECdCECIANFDQIgAxAPIQIMAgsLIARFQQEjARsEQBDTAUEAIwFBAUYNAxoLIwFFBEAACwsjAUVBACACGw0AIwFFBEAACwsjAUUEQCAAIAE2AgQgACACNgIACw8LIQMjAigCACADNgIAIwIjAigCAEEEajYCACMCKAIAIgMgADYCACADIAE2AgQgAyACNgIIIwIjAigCAEEMajYCAAu4AwEEfyMBQQJGBEAjAiMCKAIAQRBrNgIAIwIoAgAiAygCACEAIAMoAgQhASADKAIIIQIgAygCDCEDCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEFCyMBRQRAIwBBQGoiAiQAIAJBADYCCCACQgE3AwAgAkEsakHEiwU2AgAgAkEDOgAwIAJCgICAgIAENwMQIAJBADYCICACQQA2AhggAiACNgIoIAJBEGohAwsgBUVBASMBGwRAIAEgAxBJQQAjAUEBRg0BGiEDCyADIANFIwEbIQMCQCMBRQRAIAMNASACQThqIQALIAVBAUZBASMBGwRAQfCWBUE3IABB3IsFQYSYBRCbAUEBIwFBAUYNAhoLIwFFBEAACwsjAUUEQCAAIAIpAwA3AgQgAEEVNgIAIABBDGogAkEIaigCADYCAAsgBUECRkEBIwEbBEAgARDQAUECIwFBAUYNARoLIwFFBEAgAkFAayQACw8LIQQjAigCACAENgIAIwIjAigCAEEEajYCACMCKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIAQgAzYCDCMCIwIoAgBBEGo2AgALuAMBBH8jAUECRgRAIwIjAigCAEEQazYCACMCKAIAIgMoAgAhACADKAIEIQEgAygCCCECIAMoAgwhAwsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBQsjAUUEQCMAQUBqIgIkACACQQA2AgggAkIBNwMAIAJBLGpBxIsFNgIAIAJBAzoAMCACQoCAgICABDcDECACQQA2AiAgAkEANgIYIAIgAjYCKCACQRBqIQMLIAVFQQEjARsEQCABIAMQSUEAIwFBAUYNARohAwsgAyADRSMBGyEDAkAjAUUEQCADDQEgAkE4aiEACyAFQQFGQQEjARsEQEHwlgVBNyAAQdyLBUGEmAUQmwFBASMBQQFGDQIaCyMBRQRAAAsLIwFFBEAgACACKQMANwIEIABBEDYCACAAQQxqIAJBCGooAgA2AgALIAVBAkZBASMBGwRAIAEQ0AFBAiMBQQFGDQEaCyMBRQRAIAJBQGskAAsPCyEEIwIoAgAgBDYCACMCIwIoAgBBBGo2AgAjAigCACIEIAA2AgAgBCABNgIEIAQgAjYCCCAEIAM2AgwjAiMCKAIAQRBqNgIAC8kDAgR/AX4jAUECRgRAIwIjAigCAEEgazYCACMCKAIAIgEoAgAhACABKAIIIQIgASgCDCEFIAEoAhAhBCABKAIUIQYgASkCGCEHIAEoAgQhAQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhAwsjAUUEQCMAQRBrIgUkACAFQQhqIQYgACgCACIAKAIAKAIAIQQLIANFQQEjARsEQCAGIAQgASACEB1BACMBQQFGDQEaCyMBRQRAIAUtAAgiAkEERiEBCwJAIwFFBEAgAQ0BIAUpAwghByAALQAEQQNHIQELAkAjAUUEQCABDQEgAEEIaigCACIBKAIAIQYgASgCBCgCACEECyADQQFGQQEjARsEQCAGIAQRBABBASMBQQFGDQMaCyMBRQRAIAEoAgRBBGooAgAEQCABKAIAECELIAEQIQsLIwFFBEAgACAHNwIECwsjAUUEQCAFQRBqJAAgAkEERw8LAAshAyMCKAIAIAM2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAyAANgIAIAMgATYCBCADIAI2AgggAyAFNgIMIAMgBDYCECADIAY2AhQgAyAHNwIYIwIjAigCAEEgajYCAEEAC8QDAgR/AX4jAUECRgRAIwIjAigCAEEgazYCACMCKAIAIgEoAgAhACABKAIIIQIgASgCDCEFIAEoAhAhBCABKAIUIQYgASkCGCEHIAEoAgQhAQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhAwsjAUUEQCMAQRBrIgUkACAFQQhqIQYgACgCACgCACEECyADRUEBIwEbBEAgBiAEIAEgAhAdQQAjAUEBRg0BGgsjAUUEQCAFLQAIIgJBBEYhAQsCQCMBRQRAIAENASAFKQMIIQcgAC0ABEEDRyEBCwJAIwFFBEAgAQ0BIABBCGooAgAiASgCACEGIAEoAgQoAgAhBAsgA0EBRkEBIwEbBEAgBiAEEQQAQQEjAUEBRg0DGgsjAUUEQCABKAIEQQRqKAIABEAgASgCABAhCyABECELCyMBRQRAIAAgBzcCBAsLIwFFBEAgBUEQaiQAIAJBBEcPCwALIQMjAigCACADNgIAIwIjAigCAEEEajYCACMCKAIAIgMgADYCACADIAE2AgQgAyACNgIIIAMgBTYCDCADIAQ2AhAgAyAGNgIUIAMgBzcCGCMCIwIoAgBBIGo2AgBBAAumAgECfyMBQQJGBEAjAiMCKAIAQQhrNgIAIwIoAgAiBCgCACEAIAQoAgQhBAsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBgsjAUUEQCMAQUBqIgUkACAFIAE2AgwgBSAANgIIIAUgAzYCFCAFIAI2AhAgBUEsakECNgIAIAVBPGpBCjYCACAFQgI3AhwgBUGYhQQ2AhggBUELNgI0IAUgBUEwajYCKCAFIAVBEGo2AjggBSAFQQhqNgIwIAVBGGohAAsgBkVBASMBGwRAIAAgBBDSAUEAIwFBAUYNARoLIwFFBEAACw8LIQEjAigCACABNgIAIwIjAigCAEEEajYCACMCKAIAIgEgADYCACABIAQ2AgQjAiMCKAIAQQhqNgIAC2ABAn8gAQRAIAFBJGwhAwNAIAAgAmoiAUEEaigCAARAIAEoAgAQIQsgAUEQaigCAARAIAFBDGooAgAQIQsgAUEcaigCAARAIAFBGGooAgAQIQsgAyACQSRqIgJHDQALCwtxAQJ/AkAgASgCBCIFIAEoAggiBGsgA08NACAERQRAQQAhBAwBC0EAIQQgAUEANgIIIAFBADoADAsgAyAFSQRAIAQgASgCAGogAiADEP8BGiAAQQQ6AAAgASADIARqNgIIDwsgAEIENwIAIAFBADoADAtiAQJ/IAJBA2ohAgNAAkAgAi0AACABai0AAEGCAUcEQCADIQQMAQsgAkEBayECQQQhBCADQQFqIgNBBEcNAQsLIABBBCAEayIBNgIEIAAgAUEGbEEGcUEGRiAEQQRGcjYCAAtiAQJ/IAJBB2ohAgNAAkAgAi0AACABai0AAEGCAUcEQCADIQQMAQsgAkEBayECQQghBCADQQFqIgNBCEcNAQsLIABBCCAEayIBNgIEIAAgBEEIRiABQQVsQQdxQQRLcjYCAAtiAQJ/IAJBB2ohAgNAAkAgAi0AACABai0AAEGCAUcEQCADIQQMAQsgAkEBayECQQghBCADQQFqIgNBCEcNAQsLIABBCCAEayIBNgIEIAAgBEEIRiABQQNsQQdxQQJLcjYCAAtlAQF/IAEEQCABQQV0IQIgAEEUaiEBA0ACQCABQRBrKAIAIgBFDQAgAUEMaygCAEUNACAAECELAkAgAUEEaygCACIARQ0AIAEoAgBFDQAgABAhCyABQSBqIQEgAkEgayICDQALCwvTAwECfyMBQQJGBEAjAiMCKAIAQQhrNgIAIwIoAgAiASgCACEAIAEoAgQhAQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhAwsjAUUEQCAAKAIALQAAIQALAkACQAJAIwFFBEACQCAAQQFrDgMCAwQACyABKAIYIQAgAUEcaigCACgCDCEBCyADRUEBIwEbBEAgAEGJpwRBBiABEQEAQQAjAUEBRg0EGiEACyMBRQRAIAAPCwsjAUUEQCABKAIYIQAgAUEcaigCACgCDCEBCyADQQFGQQEjARsEQCAAQYOnBEEGIAERAQBBASMBQQFGDQMaIQALIwFFBEAgAA8LCyMBRQRAIAEoAhghACABQRxqKAIAKAIMIQELIANBAkZBASMBGwRAIABB+6YEQQggAREBAEECIwFBAUYNAhohAAsjAUUEQCAADwsLIwFFBEAgASgCGCEAIAFBHGooAgAoAgwhAQsgA0EDRkEBIwEbBEAgAEH0pgRBByABEQEAQQMjAUEBRg0BGiEACyMBRQRAIAAPCwALIQIjAigCACACNgIAIwIjAigCAEEEajYCACMCKAIAIgIgADYCACACIAE2AgQjAiMCKAIAQQhqNgIAQQALrQMBB38jAUECRgRAIwIjAigCAEEcazYCACMCKAIAIgEoAgAhACABKAIIIQMgASgCDCEEIAEoAhAhBSABKAIUIQYgASgCGCEIIAEoAgQhAQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBwsjAUUEQCMAQSBrIgQkAEEBIQUgACgCACEDCyAHRUEBIwEbBEAgAyABEFVBACMBQQFGDQEaIQMLAkAjAUUEQCADDQEgAUEcaigCACEDIAEoAhghCCAEQQA2AhwgBEHooQU2AhggBEIBNwIMIARBsIMENgIIIARBCGohBgsgB0EBRkEBIwEbBEAgCCADIAYQK0EBIwFBAUYNAhohAwsjAUUEQCADDQEgACgCBCEACyAHQQJGQQEjARsEQCAAIAEQVUECIwFBAUYNAhohBQsLIwFFBEAgBEEgaiQAIAUPCwALIQIjAigCACACNgIAIwIjAigCAEEEajYCACMCKAIAIgIgADYCACACIAE2AgQgAiADNgIIIAIgBDYCDCACIAU2AhAgAiAGNgIUIAIgCDYCGCMCIwIoAgBBHGo2AgBBAAtfAQJ/IAJBA2ohAgNAAkAgAi0AACABai0AAEGCAUcEQCADIQQMAQsgAkEBayECQQQhBCADQQFqIgNBBEcNAQsLIABBBCAEayIBNgIEIAAgBEEERiABQQNxQQBHcjYCAAtfAQJ/IAJBB2ohAgNAAkAgAi0AACABai0AAEGCAUcEQCADIQQMAQsgAkEBayECQQghBCADQQFqIgNBCEcNAQsLIABBCCAEayIBNgIEIAAgBEEIRiABQQdxQQBHcjYCAAuVAgECfyMBQQJGBEAjAiMCKAIAQQhrNgIAIwIoAgAiAigCACEAIAIoAgQhAgsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBAsjAUUEQCMAQTBrIgMkACADIAE2AgQgAyAANgIAIANBHGpBAjYCACADQSxqQQU2AgAgA0ICNwIMIANB+IMENgIIIANBBTYCJCADIANBIGo2AhggAyADNgIoIAMgA0EEajYCICADQQhqIQALIARFQQEjARsEQCAAIAIQ0gFBACMBQQFGDQEaCyMBRQRAAAsPCyEBIwIoAgAgATYCACMCIwIoAgBBBGo2AgAjAigCACIBIAA2AgAgASACNgIEIwIjAigCAEEIajYCAAumAgEDfyMBQQJGBEAjAiMCKAIAQRBrNgIAIwIoAgAiAygCACEAIAMoAgQhASADKAIIIQQgAygCDCEDCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACECCyMBRQRAIwBBIGsiBCQAIAFBHGooAgAhAyABKAIYIQEgBEEYaiAAQRBqKQIANwMAIARBEGogAEEIaikCADcDACAEIAApAgA3AwggBEEIaiEACyACRUEBIwEbBEAgASADIAAQK0EAIwFBAUYNARohAAsjAUUEQCAEQSBqJAAgAA8LAAshAiMCKAIAIAI2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAiAANgIAIAIgATYCBCACIAQ2AgggAiADNgIMIwIjAigCAEEQajYCAEEAC5oCAQJ/IwFBAkYEQCMCIwIoAgBBDGs2AgAjAigCACIDKAIAIQAgAygCBCEBIAMoAgghAwsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBAsCQCMBRQRAIAFFBEBBASEDDAILIAFBAEgiA0UEQCABEA8hAwJAIAIEQCADRQ0BIANBBGstAABBA3FFDQQgA0EAIAEQgQIMBAsgAw0DCwALCyAERUEBIwEbBEAQ0wFBACMBQQFGDQIaCyMBRQRAAAsLIwFFBEAgACABNgIEIAAgAzYCAAsPCyECIwIoAgAgAjYCACMCIwIoAgBBBGo2AgAjAigCACICIAA2AgAgAiABNgIEIAIgAzYCCCMCIwIoAgBBDGo2AgALXAECfyACQQFqIQIDQAJAIAItAAAgAWotAABBggFHBEAgAyEEDAELIAJBAWshAkECIQQgA0EBaiIDQQJHDQELCyAAQQIgBGsiATYCBCAAIAEgBEECRnJBAXE2AgALrAICAn8BfiMBQQJGBEAjAiMCKAIAQRBrNgIAIwIoAgAiAygCACEAIAMoAgQhASADKAIIIQIgAygCDCEDCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEECyMBRQRAIwBBEGsiAyQAIAMgAiACQYD+A3FBCHYQYjoADyADQQ9qIQILIARFQQEjARsEQCADIAEgAkEBEGBBACMBQQFGDQEaCyMBRQRAAkACQCADLQAAQQRHBEAgAykDACIFQv8Bg0IEUg0BCyAAQQQ6AAAMAQsgACAFNwIACyADQRBqJAALDwshBCMCKAIAIAQ2AgAjAiMCKAIAQQRqNgIAIwIoAgAiBCAANgIAIAQgATYCBCAEIAI2AgggBCADNgIMIwIjAigCAEEQajYCAAuaAgECfyMBQQJGBEAjAiMCKAIAQQxrNgIAIwIoAgAiAigCACEAIAIoAgQhASACKAIIIQILAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQMLIwFFBEAjAEEgayICJAAgAiAAKAIANgIEIAJBGGogAUEQaikCADcDACACQRBqIAFBCGopAgA3AwAgAiABKQIANwMIIAJBCGohASACQQRqIQALIANFQQEjARsEQCAAQdyBBCABECtBACMBQQFGDQEaIQELIwFFBEAgAkEgaiQAIAEPCwALIQMjAigCACADNgIAIwIjAigCAEEEajYCACMCKAIAIgMgADYCACADIAE2AgQgAyACNgIIIwIjAigCAEEMajYCAEEAC5oCAQJ/IwFBAkYEQCMCIwIoAgBBDGs2AgAjAigCACICKAIAIQAgAigCBCEBIAIoAgghAgsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhAwsjAUUEQCMAQSBrIgIkACACIAAoAgA2AgQgAkEYaiABQRBqKQIANwMAIAJBEGogAUEIaikCADcDACACIAEpAgA3AwggAkEIaiEBIAJBBGohAAsgA0VBASMBGwRAIABB1IcEIAEQK0EAIwFBAUYNARohAQsjAUUEQCACQSBqJAAgAQ8LAAshAyMCKAIAIAM2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAyAANgIAIAMgATYCBCADIAI2AggjAiMCKAIAQQxqNgIAQQALnQIBAn8jAUECRgRAIwIjAigCAEEMazYCACMCKAIAIgIoAgAhACACKAIEIQEgAigCCCECCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEDCyMBRQRAIwBBIGsiAiQAIAJBiKIFNgIEIAIgADYCACACQRhqIAFBEGopAgA3AwAgAkEQaiABQQhqKQIANwMAIAIgASkCADcDCCACQQhqIQEgAkEEaiEACyADRUEBIwEbBEAgAkGsogUgAEGsogUgAUGYsQUQUEEAIwFBAUYNARoLIwFFBEAACw8LIQMjAigCACADNgIAIwIjAigCAEEEajYCACMCKAIAIgMgADYCACADIAE2AgQgAyACNgIIIwIjAigCAEEMajYCAAuaAgECfyMBQQJGBEAjAiMCKAIAQQxrNgIAIwIoAgAiAigCACEAIAIoAgQhASACKAIIIQILAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQMLIwFFBEAjAEEgayICJAAgAiAAKAIANgIEIAJBGGogAUEQaikCADcDACACQRBqIAFBCGopAgA3AwAgAiABKQIANwMIIAJBCGohASACQQRqIQALIANFQQEjARsEQCAAQaChBSABECtBACMBQQFGDQEaIQELIwFFBEAgAkEgaiQAIAEPCwALIQMjAigCACADNgIAIwIjAigCAEEEajYCACMCKAIAIgMgADYCACADIAE2AgQgAyACNgIIIwIjAigCAEEMajYCAEEAC6kCAQJ/IwFBAkYEQCMCIwIoAgBBEGs2AgAjAigCACIDKAIAIQAgAygCBCEBIAMoAgghAiADKAIMIQMLAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQQLIwFFBEAjAEEgayIDJAAgA0HosQU2AgQgAyAANgIAIANBGGogAUEQaikCADcDACADQRBqIAFBCGopAgA3AwAgAyABKQIANwMIIANBCGohASADQQRqIQALIARFQQEjARsEQCADQbyiBSAAQbyiBSABIAIQUEEAIwFBAUYNARoLIwFFBEAACw8LIQQjAigCACAENgIAIwIjAigCAEEEajYCACMCKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIAQgAzYCDCMCIwIoAgBBEGo2AgALmgIBAn8jAUECRgRAIwIjAigCAEEMazYCACMCKAIAIgIoAgAhACACKAIEIQEgAigCCCECCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEDCyMBRQRAIwBBIGsiAiQAIAIgACgCADYCBCACQRhqIAFBEGopAgA3AwAgAkEQaiABQQhqKQIANwMAIAIgASkCADcDCCACQQhqIQEgAkEEaiEACyADRUEBIwEbBEAgAEG4oQUgARArQQAjAUEBRg0BGiEBCyMBRQRAIAJBIGokACABDwsACyEDIwIoAgAgAzYCACMCIwIoAgBBBGo2AgAjAigCACIDIAA2AgAgAyABNgIEIAMgAjYCCCMCIwIoAgBBDGo2AgBBAAuaAgECfyMBQQJGBEAjAiMCKAIAQQxrNgIAIwIoAgAiAigCACEAIAIoAgQhASACKAIIIQILAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQMLIwFFBEAjAEEgayICJAAgAiAAKAIANgIEIAJBGGogAUEQaikCADcDACACQRBqIAFBCGopAgA3AwAgAiABKQIANwMIIAJBCGohASACQQRqIQALIANFQQEjARsEQCAAQdChBSABECtBACMBQQFGDQEaIQELIwFFBEAgAkEgaiQAIAEPCwALIQMjAigCACADNgIAIwIjAigCAEEEajYCACMCKAIAIgMgADYCACADIAE2AgQgAyACNgIIIwIjAigCAEEMajYCAEEAC5cCAQJ/IwFBAkYEQCMCIwIoAgBBDGs2AgAjAigCACICKAIAIQAgAigCBCEBIAIoAgghAgsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhAwsjAUUEQCMAQSBrIgIkACACIAA2AgQgAkEYaiABQRBqKQIANwMAIAJBEGogAUEIaikCADcDACACIAEpAgA3AwggAkEIaiEBIAJBBGohAAsgA0VBASMBGwRAIABB1IcEIAEQK0EAIwFBAUYNARohAQsjAUUEQCACQSBqJAAgAQ8LAAshAyMCKAIAIAM2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAyAANgIAIAMgATYCBCADIAI2AggjAiMCKAIAQQxqNgIAQQALlwIBAn8jAUECRgRAIwIjAigCAEEMazYCACMCKAIAIgIoAgAhACACKAIEIQEgAigCCCECCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEDCyMBRQRAIwBBIGsiAiQAIAIgADYCBCACQRhqIAFBEGopAgA3AwAgAkEQaiABQQhqKQIANwMAIAIgASkCADcDCCACQQhqIQEgAkEEaiEACyADRUEBIwEbBEAgAEGgoQUgARArQQAjAUEBRg0BGiEBCyMBRQRAIAJBIGokACABDwsACyEDIwIoAgAgAzYCACMCIwIoAgBBBGo2AgAjAigCACIDIAA2AgAgAyABNgIEIAMgAjYCCCMCIwIoAgBBDGo2AgBBAAuXAgECfyMBQQJGBEAjAiMCKAIAQQxrNgIAIwIoAgAiAigCACEAIAIoAgQhASACKAIIIQILAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQMLIwFFBEAjAEEgayICJAAgAiAANgIEIAJBGGogAUEQaikCADcDACACQRBqIAFBCGopAgA3AwAgAiABKQIANwMIIAJBCGohASACQQRqIQALIANFQQEjARsEQCAAQbihBSABECtBACMBQQFGDQEaIQELIwFFBEAgAkEgaiQAIAEPCwALIQMjAigCACADNgIAIwIjAigCAEEEajYCACMCKAIAIgMgADYCACADIAE2AgQgAyACNgIIIwIjAigCAEEMajYCAEEAC80CAQN/IwFBAkYEQCMCIwIoAgBBDGs2AgAjAigCACICKAIAIQAgAigCBCEBIAIoAgghAgsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBAsjAUUEQCMAQRBrIgIkACAAKAIAIgAtAARBBEchAwsCQAJAIwFFBEAgAw0BIAIgADYCCCACQQhqIQALIARFQQEjARsEQCABQeinBEECIABB7KcEEHVBACMBQQFGDQMaIQALIwFFDQELIwFFBEAgAiAANgIMIAJBDGohAAsgBEEBRkEBIwEbBEAgAUHUpwRBAyAAQdinBBB1QQEjAUEBRg0CGiEACwsjAUUEQCACQRBqJAAgAA8LAAshAyMCKAIAIAM2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAyAANgIAIAMgATYCBCADIAI2AggjAiMCKAIAQQxqNgIAQQALUQEBfwJAIABBEGooAgAiAUUNACABQQA6AAAgAEEUaigCAEUNACAAKAIQECELAkAgAEF/Rg0AIAAgACgCBCIBQQFrNgIEIAFBAUcNACAAECELC4gCAQN/IwFBAkYEQCMCIwIoAgBBEGs2AgAjAigCACIDKAIAIQAgAygCBCEBIAMoAgghBCADKAIMIQMLAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQILIwFFBEAjAEEQayIEJAAgBCAAKAIAIgA2AgggBCAAQQRqNgIMIARBDGohAyAEQQhqIQALIAJFQQEjARsEQCABIAAgAxB9QQAjAUEBRg0BGiEACyMBRQRAIARBEGokACAADwsACyECIwIoAgAgAjYCACMCIwIoAgBBBGo2AgAjAigCACICIAA2AgAgAiABNgIEIAIgBDYCCCACIAM2AgwjAiMCKAIAQRBqNgIAQQALsgEBAX8CfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhAwsCQCMBRQRAAkACQCACRQRAQQEhAwwBCyACQX9MDQEgAhAPIgNFDQMLIAMgASACEP8BIQEgACACNgIIIAAgAjYCBCAAIAE2AgAPCwsgA0VBASMBGwRAENMBQQAjAUEBRg0CGgsjAUUEQAALCyMBRQRAAAsPCyEAIwIoAgAgADYCACMCIwIoAgBBBGo2AgALTAEBfwJAIAAoAhBBAkYNACAAQRxqKAIABEAgACgCGBAhCyAAKAIkIgFFDQAgASAAQSxqKAIAEMsBIABBKGooAgBFDQAgACgCJBAhCwufAgECfyMBQQJGBEAjAiMCKAIAQRBrNgIAIwIoAgAiAygCACEAIAMoAgQhASADKAIIIQIgAygCDCEDCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEECyMBRUEAIwEEf0EABSAAKAIAIgBBBGooAgAgACgCCCIDayACTwsbRQRAIARFQQEjARsEQCAAIAMgAhB6QQAjAUEBRg0CGgsjAQR/IAMFIAAoAggLIQMLIwFFBEAgAyAAKAIAaiABIAIQ/wEaIAAgAiADajYCCEEADwsACyEEIwIoAgAgBDYCACMCIwIoAgBBBGo2AgAjAigCACIEIAA2AgAgBCABNgIEIAQgAjYCCCAEIAM2AgwjAiMCKAIAQRBqNgIAQQALgwIBA38jAUECRgRAIwIjAigCAEEQazYCACMCKAIAIgMoAgAhACADKAIEIQEgAygCCCEEIAMoAgwhAwsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhAgsjAUUEQCMAQRBrIgQkACAEIAA2AgggBCAAQQRqNgIMIARBDGohAyAEQQhqIQALIAJFQQEjARsEQCABIAAgAxB9QQAjAUEBRg0BGiEACyMBRQRAIARBEGokACAADwsACyECIwIoAgAgAjYCACMCIwIoAgBBBGo2AgAjAigCACICIAA2AgAgAiABNgIEIAIgBDYCCCACIAM2AgwjAiMCKAIAQRBqNgIAQQALggIBAn8jAUECRgRAIwIjAigCAEEMazYCACMCKAIAIgMoAgAhACADKAIEIQEgAygCCCEDCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACECCyMBRUEAIwEEf0EABSAAKAIIIgMgACgCBEcLG0UEQCACRUEBIwEbBEAgACADEHZBACMBQQFGDQIaCwsjAUUEQCAAKAIAIQIgAwRAIAJBBGogAiADQQJ0EIACCyACIAE2AgAgACADQQFqNgIICw8LIQIjAigCACACNgIAIwIjAigCAEEEajYCACMCKAIAIgIgADYCACACIAE2AgQgAiADNgIIIwIjAigCAEEMajYCAAvzAQECfyMBQQJGBEAjAiMCKAIAQQhrNgIAIwIoAgAiAigCACEAIAIoAgQhAgsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhAQsjAUVBACMBBH9BAAUgACgCCCICIAAoAgRHCxtFBEAgAUVBASMBGwRAIAAgAkEBEH5BACMBQQFGDQIaCwsjAUUEQCAAKAIAIQEgAgRAIAFBAWogASACEIACCyABQQA6AAAgACACQQFqNgIICw8LIQEjAigCACABNgIAIwIjAigCAEEEajYCACMCKAIAIgEgADYCACABIAI2AgQjAiMCKAIAQQhqNgIAC50BAQJ/An8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQMLIwFFBEAjAEEQayICJAAgAkEIaiAAIAFBARB7AkAgAigCDCIAQYGAgIB4RwRAIABFDQEACyACQRBqJAAPCwsgA0VBASMBGwRAENMBQQAjAUEBRg0BGgsjAUUEQAALDwshACMCKAIAIAA2AgAjAiMCKAIAQQRqNgIAC+wBAQJ/IwFBAkYEQCMCIwIoAgBBCGs2AgAjAigCACIDKAIAIQIgAygCBCEDCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEECyMBRQRAIwBBIGsiAyQAIANBFGpBADYCACADQeihBTYCECADQgE3AgQgAyABNgIcIAMgADYCGCADIANBGGo2AgALIARFQQEjARsEQCADIAIQ0gFBACMBQQFGDQEaCyMBRQRAAAsPCyEAIwIoAgAgADYCACMCIwIoAgBBBGo2AgAjAigCACIAIAI2AgAgACADNgIEIwIjAigCAEEIajYCAAudAQECfwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEECyMBRQRAIwBBEGsiAyQAIANBCGogACABIAIQewJAIAMoAgwiAEGBgICAeEcEQCAARQ0BAAsgA0EQaiQADwsLIARFQQEjARsEQBDTAUEAIwFBAUYNARoLIwFFBEAACw8LIQAjAigCACAANgIAIwIjAigCAEEEajYCAAtCAQF/IABBHGooAgAEQCAAKAIYECELAkAgACgCJCIBRQ0AIAEgAEEsaigCABDLASAAQShqKAIARQ0AIAAoAiQQIQsLoAIBA38jAUECRgRAIwIjAigCAEEMazYCACMCKAIAIgEoAgAhACABKAIIIQMgASgCBCEBCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEECyMBRQRAIwBBEGsiAyQAIABBADYCCCAAQgE3AgALIARFQQEjARsEQCABIABBzO8EEBZBACMBQQFGDQEaIQALIwFFBEAgAEUEQCADQRBqJAAPCyADQQhqIQALIARBAUZBASMBGwRAQfCWBUE3IABB5O8EQYSYBRCbAUEBIwFBAUYNARoLIwFFBEAACw8LIQIjAigCACACNgIAIwIjAigCAEEEajYCACMCKAIAIgIgADYCACACIAE2AgQgAiADNgIIIwIjAigCAEEMajYCAAvsAQECfyMBQQJGBEAjAiMCKAIAQQhrNgIAIwIoAgAiAigCACEBIAIoAgQhAgsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhAwsjAUUEQCMAQSBrIgIkACACQRRqQQE2AgAgAkIBNwIEIAJBkKQFNgIAIAJBBDYCHCACIAA2AhggAiACQRhqNgIQCyADRUEBIwEbBEAgAiABENIBQQAjAUEBRg0BGgsjAUUEQAALDwshACMCKAIAIAA2AgAjAiMCKAIAQQRqNgIAIwIoAgAiACABNgIAIAAgAjYCBCMCIwIoAgBBCGo2AgALkgIBAn8jAUECRgRAIwIjAigCAEEQazYCACMCKAIAIgMoAgAhACADKAIEIQEgAygCCCECIAMoAgwhAwsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBAsjAUVBACMBBH9BAAUgACgCBCAAKAIIIgNrIAJPCxtFBEAgBEVBASMBGwRAIAAgAyACEH5BACMBQQFGDQIaCyMBBH8gAwUgACgCCAshAwsjAUUEQCADIAAoAgBqIAEgAhD/ARogACACIANqNgIICw8LIQQjAigCACAENgIAIwIjAigCAEEEajYCACMCKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIAQgAzYCDCMCIwIoAgBBEGo2AgALkwIBAn8jAUECRgRAIwIjAigCAEEQazYCACMCKAIAIgMoAgAhACADKAIEIQEgAygCCCECIAMoAgwhAwsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBAsjAUVBACMBBH9BAAUgACgCBCAAKAIIIgNrIAJPCxtFBEAgBEVBASMBGwRAIAAgAyACEMABQQAjAUEBRg0CGgsjAQR/IAMFIAAoAggLIQMLIwFFBEAgAyAAKAIAaiABIAIQ/wEaIAAgAiADajYCCAsPCyEEIwIoAgAgBDYCACMCIwIoAgBBBGo2AgAjAigCACIEIAA2AgAgBCABNgIEIAQgAjYCCCAEIAM2AgwjAiMCKAIAQRBqNgIAC4IJAgd/AX4jAUECRgRAIwIjAigCAEEQazYCACMCKAIAIgEoAgAhACABKQIIIQkgASgCBCEBCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEGCyMBRQRAIAEoAgAiA0EQcSECIAAoAgAhAAsCQCMBRQRAIAINASADQSBxIQMLAkAjAUUEQCADDQEgADUCACEJCyAGRUEBIwEbBEAgCUEBIAEQUkEAIwFBAUYNAxohAAsjAUUEQCAADwsLIwFFBEAgACgCACEACyAGQQFGQQEjARsEQAJ/IAAhAiABIQcjAUECRgRAIwIjAigCAEEQazYCACMCKAIAIgMoAgAhAiADKAIIIQUgAygCDCEEIAMoAgQhBwsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhCAsjAUVBACMBBH9BAAUjAEGAAWsiBCQAQQAhBQNAIAQgBWpB/wBqQTBBNyACQQ9xIgNBCkkbIANqOgAAIAVBAWshBSACQQ9LIAJBBHYhAg0ACyAFQYABaiICQYEBSQsbRQRAIAhFQQEjARsEQCACQYABQfiFBBD2AUEAIwFBAUYNAhoLIwFFBEAACwsjAUUEQCAEIAVqQYABaiECQQAgBWshBQsgCEEBRkEBIwEbBEAgB0EBQYiGBEECIAIgBRAoQQEjAUEBRg0BGiECCyMBRQRAIARBgAFqJAAgAgwCCwALIQMjAigCACADNgIAIwIjAigCAEEEajYCACMCKAIAIgMgAjYCACADIAc2AgQgAyAFNgIIIAMgBDYCDCMCIwIoAgBBEGo2AgBBAAtBASMBQQFGDQIaIQALIwFFBEAgAA8LCyMBRQRAIAAoAgAhAAsgBkECRkEBIwEbBEACfyAAIQIgASEHQQAhBEEAIQZBACEIIwFBAkYEQCMCIwIoAgBBEGs2AgAjAigCACIDKAIAIQIgAygCCCEEIAMoAgwhBiADKAIEIQcLAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQgLIwFFQQAjAQR/QQAFIwBBgAFrIgYkAEEAIQQDQCAEIAZqQf8AakEwQdcAIAJBD3EiA0EKSRsgA2o6AAAgBEEBayEEIAJBD0sgAkEEdiECDQALIARBgAFqIgJBgQFJCxtFBEAgCEVBASMBGwRAIAJBgAFB+IUEEPYBQQAjAUEBRg0CGgsjAUUEQAALCyMBRQRAIAQgBmpBgAFqIQJBACAEayEECyAIQQFGQQEjARsEQCAHQQFBiIYEQQIgAiAEEChBASMBQQFGDQEaIQILIwFFBEAgBkGAAWokACACDAILAAshAyMCKAIAIAM2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAyACNgIAIAMgBzYCBCADIAQ2AgggAyAGNgIMIwIjAigCAEEQajYCAEEAC0ECIwFBAUYNARohAAsjAUUEQCAADwsACyECIwIoAgAgAjYCACMCIwIoAgBBBGo2AgAjAigCACICIAA2AgAgAiABNgIEIAIgCTcCCCMCIwIoAgBBEGo2AgBBAAu0AwEGfyMBQQJGBEAjAiMCKAIAQRBrNgIAIwIoAgAiBCgCACEAIAQoAgQhASAEKAIIIQIgBCgCDCEECwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEDCyMBRUEAIwEEf0EABSAAKAIEIAAoAggiBGsgAk8LG0UEQCADRUEBIwEbBEAgACEDIAQhByACIQgCQAJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEFCyMBRQRAIwBBEGsiBiQAIAZBCGogAyAHIAgQfAJAIAYoAgwiA0GBgICAeEcEQCADRQ0BAAsgBkEQaiQADAMLCyAFRUEBIwEbBEAQ0wFBACMBQQFGDQEaCyMBRQRAAAsMAQshAyMCKAIAIAM2AgAjAiMCKAIAQQRqNgIAC0EAIwFBAUYNAhoLIwEEfyAEBSAAKAIICyEECyMBRQRAIAQgACgCAGogASACEP8BGiAAIAIgBGo2AggLDwshAyMCKAIAIAM2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAyAANgIAIAMgATYCBCADIAI2AgggAyAENgIMIwIjAigCAEEQajYCAAuOAgEEfyMBQQJGBEAjAiMCKAIAQRBrNgIAIwIoAgAiASgCACEAIAEoAgQhAyABKAIIIQQgASgCDCEBCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACECCwJAIwFFBEAgAC0ABEEDRw0BIABBCGooAgAiAygCACEBIAMoAgQoAgAhBAsgAkVBASMBGwRAIAEgBBEEAEEAIwFBAUYNAhoLIwFFBEAgAygCBEEEaigCAARAIAMoAgAQIQsgACgCCBAhCwsPCyECIwIoAgAgAjYCACMCIwIoAgBBBGo2AgAjAigCACICIAA2AgAgAiADNgIEIAIgBDYCCCACIAE2AgwjAiMCKAIAQRBqNgIAC9IBAQJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEBCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACECCyMBRQRAIABBCGooAgAiAUGCBE8EQCAAIABBBGogACgCABsoAgAtAIEEQQhxQQN2DwsLIAJFQQEjARsEQEGBBCABQZSmBBCmAUEAIwFBAUYNARoLIwFFBEAACwALIQAjAigCACAANgIAIwIjAigCAEEEajYCACMCKAIAIAE2AgAjAiMCKAIAQQRqNgIAQQAL0gEBAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQILIwFFBEAgAEEIaigCACIBQYIETwRAIAAgAEEEaiAAKAIAGygCAC0AgQRBEHFBBHYPCwsgAkVBASMBGwRAQYEEIAFBhKYEEKYBQQAjAUEBRg0BGgsjAUUEQAALAAshACMCKAIAIAA2AgAjAiMCKAIAQQRqNgIAIwIoAgAgATYCACMCIwIoAgBBBGo2AgBBAAszACABBEAgAUEMbCEBA0AgAEEEaigCAARAIAAoAgAQIQsgAEEMaiEAIAFBDGsiAQ0ACwsLNgAgACgCBARAIAAoAgAQIQsgAEEMaigCACAAQRRqKAIAEJwBIABBEGooAgAEQCAAKAIMECELC/gCAQR/IwFBAkYEQCMCIwIoAgBBHGs2AgAjAigCACIBKAIAIQAgASgCCCECIAEoAgwhAyABKAIQIQQgASgCFCEGIAEoAhghByABKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQgLIAYgAkGAgMQARiMBGyEGAkACQAJAIwFFBEAgBg0BIAEoAhAhB0EBIQYLIAhFQQEjARsEQCAAIAIgBxEAAEEAIwFBAUYNBBohAgsjAUVBACACGw0BCyMBRQRAIAMNAkEAIQYLCyMBRQRAIAYPCwsjAUUEQCABKAIMIQELIAhBAUZBASMBGwRAIAAgAyAEIAERAQBBASMBQQFGDQEaIQALIwFFBEAgAA8LAAshBSMCKAIAIAU2AgAjAiMCKAIAQQRqNgIAIwIoAgAiBSAANgIAIAUgATYCBCAFIAI2AgggBSADNgIMIAUgBDYCECAFIAY2AhQgBSAHNgIYIwIjAigCAEEcajYCAEEAC88BAQJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEBCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACECCyMBRQRAIABBCGooAgAiAUGCBE8EQCAAIABBBGogACgCABsoAgAtAIEEQQdxDwsLIAJFQQEjARsEQEGBBCABQaSmBBCmAUEAIwFBAUYNARoLIwFFBEAACwALIQAjAigCACAANgIAIwIjAigCAEEEajYCACMCKAIAIAE2AgAjAiMCKAIAQQRqNgIAQQALzwEBAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQILIwFFBEAgAEEIaigCACIBQYEETwRAIAAgAEEEaiAAKAIAGygCACwAgARBf0oPCwsgAkVBASMBGwRAQYAEIAFB9KUEEKYBQQAjAUEBRg0BGgsjAUUEQAALAAshACMCKAIAIAA2AgAjAiMCKAIAQQRqNgIAIwIoAgAgATYCACMCIwIoAgBBBGo2AgBBAAuIAgEEfyMBQQJGBEAjAiMCKAIAQRBrNgIAIwIoAgAiASgCACEAIAEoAgQhAyABKAIIIQQgASgCDCEBCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACECCwJAIwFFBEAgAC0AAEEDRw0BIAAoAgQiAygCACEBIAMoAgQoAgAhBAsgAkVBASMBGwRAIAEgBBEEAEEAIwFBAUYNAhoLIwFFBEAgAygCBCgCBARAIAMoAgAQIQsgACgCBBAhCwsPCyECIwIoAgAgAjYCACMCIwIoAgBBBGo2AgAjAigCACICIAA2AgAgAiADNgIEIAIgBDYCCCACIAE2AgwjAiMCKAIAQRBqNgIAC/IBAQJ/IwFBAkYEQCMCIwIoAgBBDGs2AgAjAigCACICKAIAIQAgAigCBCEBIAIoAgghAgsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhAwsCQCMBRQRAIAFFBEBBASECDAILIAFBAEgiAkUEQCABEA8iAg0CAAsLIANFQQEjARsEQBDTAUEAIwFBAUYNAhoLIwFFBEAACwsjAUUEQCAAIAE2AgQgACACNgIACw8LIQMjAigCACADNgIAIwIjAigCAEEEajYCACMCKAIAIgMgADYCACADIAE2AgQgAyACNgIIIwIjAigCAEEMajYCAAv2CAEJfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhAAsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhBwsjAUUEQCMAQSBrIgIkACACQQE6ABggAiABNgIUIAIgADYCECACQYiEBDYCDCACQeihBTYCCCACQQhqIQALIAdFQQEjARsEQCAAIQhBACEBQQAhAkEAIQcjAUECRgRAIwIjAigCAEEMazYCACMCKAIAIgIoAgAhCCACKAIIIQkgAigCBCECCwJAAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQELIwFFQQAjAUUEQCMAQRBrIgIkACAIKAIIIQkLIAkbRQRAIAFFQQEjARsEQEHMogVBK0HcrgUQvwFBACMBQQFGDQIaCyMBRQRAAAsLIwFFBEAgAiAIKAIMNgIIIAIgCDYCBCACIAk2AgALIAFBAUZBASMBGwRAIwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEHCyACIQECQAJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEFCyMBRQRAIwBBEGsiByQAIAdBCGogAUEIaigCADYCACAHIAEpAgA3AwALIAVFQQEjARsEQCAHIQFBACEFIwFBAkYEQCMCIwIoAgBBEGs2AgAjAigCACIEKAIAIQEgBCgCBCEDIAQoAgghBiAEKAIMIQQLAkACfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhCgsjAUUEQCMAQRBrIgQkACABKAIAIgNBFGooAgAhBiADKAIEIQULAkAjAUUEQAJ/AkACQCAFDgIAAQQLIAYNA0EAIQZB6KEFDAELIAYNAiADKAIAIgMoAgQhBiADKAIACyEDIAQgBjYCBCAEIAM2AgAgASgCBCIDKAIIIQYgAy0AECEDIAEoAgghAQsgCkVBASMBGwRAIARBoK8FIAYgASADEFRBACMBQQFGDQIaCyMBRQRAAAsLIwFFBEAgBEEANgIEIAQgAzYCACABKAIEIgMoAgghBiADLQAQIQMgASgCCCEBCyAKQQFGQQEjARsEQCAEQYyvBSAGIAEgAxBUQQEjAUEBRg0BGgsjAUUEQAALDAELIQUjAigCACAFNgIAIwIjAigCAEEEajYCACMCKAIAIgUgATYCACAFIAM2AgQgBSAGNgIIIAUgBDYCDCMCIwIoAgBBEGo2AgALQQAjAUEBRg0BGgsjAUUEQAALDAELIQEjAigCACABNgIAIwIjAigCAEEEajYCACMCKAIAIAc2AgAjAiMCKAIAQQRqNgIAC0EBIwFBAUYNARoLIwFFBEAACwwBCyEBIwIoAgAgATYCACMCIwIoAgBBBGo2AgAjAigCACIBIAg2AgAgASACNgIEIAEgCTYCCCMCIwIoAgBBDGo2AgALQQAjAUEBRg0BGgsjAUUEQAALDwshASMCKAIAIAE2AgAjAiMCKAIAQQRqNgIAIwIoAgAgADYCACMCIwIoAgBBBGo2AgAL1AEBAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQALAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQELIwFFBEAjAEEgayIAJAAgAEEcakEANgIAIABB6KEFNgIYIABCATcCDCAAQaSCBDYCCCAAQQhqIQALIAFFQQEjARsEQCAAQayCBBDSAUEAIwFBAUYNARoLIwFFBEAACw8LIQEjAigCACABNgIAIwIjAigCAEEEajYCACMCKAIAIAA2AgAjAiMCKAIAQQRqNgIAC+0BAQJ/IwFBAkYEQCMCIwIoAgBBCGs2AgAjAigCACIBKAIAIQAgASgCBCEBCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACECCyMBRUEAIwEEf0EABSAAQQhqKAIAIgFB/wNLCxtFBEAgAkVBASMBGwRAQYAEIAFB5KUEEPcBQQAjAUEBRg0CGgsjAUUEQAALCyMBRQRAIAAgAEEEaiAAKAIAGygCAEGAAmoPCwALIQIjAigCACACNgIAIwIjAigCAEEEajYCACMCKAIAIgIgADYCACACIAE2AgQjAiMCKAIAQQhqNgIAQQALOwECfyABKAIEIQIgASgCACEDQQgQDyIBRQRAAAsgASACNgIEIAEgAzYCACAAQfyuBTYCBCAAIAE2AgALPAEBfyAAKAIAIQECQCAAQQRqLQAADQBB6LgFKAIAQf////8HcUUNABCCAg0AIAFBAToAAQsgAUEAOgAAC6MCAQR/IwFBAkYEQCMCIwIoAgBBEGs2AgAjAigCACIBKAIAIQAgASgCCCEDIAEoAgwhBCABKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQULIwFFBEAgASgCGCEDIAFBHGooAgAoAgwhAUEBIQALIAVFQQEjARsEQCADQdCsBUELIAERAQBBACMBQQFGDQEaIQQLIwFFQQAgBBtFBEAgBUEBRkEBIwEbBEAgA0HPhQRBByABEQEAQQEjAUEBRg0CGiEACwsjAUUEQCAADwsACyECIwIoAgAgAjYCACMCIwIoAgBBBGo2AgAjAigCACICIAA2AgAgAiABNgIEIAIgAzYCCCACIAQ2AgwjAiMCKAIAQRBqNgIAQQALLQEBfwJAIAAoAgAiAUUNACABIABBCGooAgAQywEgACgCBEUNACAAKAIAECELCyQAIABBMGoQzAEgAEEMaigCAARAIAAoAggQIQsgAEEYahDMAQsjACAAKAIEBEAgACgCABAhCyAAQRBqKAIABEAgACgCDBAhCwvdAQEDfyMBQQJGBEAjAiMCKAIAQQxrNgIAIwIoAgAiASgCACEAIAEoAgQhAyABKAIIIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQILIwFFBEAjAEEQayIDJAAgA0EIaiEBCyACRUEBIwEbBEAgASAAENEBQQAjAUEBRg0BGgsjAUUEQCADKAIIIANBEGokAA8LAAshAiMCKAIAIAI2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAiAANgIAIAIgAzYCBCACIAE2AggjAiMCKAIAQQxqNgIAQQAL4AECAX8BfiMBQQJGBEAjAiMCKAIAQRBrNgIAIwIoAgAiASgCACEAIAEpAgghAyABKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQILIwFFBEAgACgCACIArUIAIACsfSAAQX9KIgAbIQMLIAJFQQEjARsEQCADIAAgARBSQQAjAUEBRg0BGiEACyMBRQRAIAAPCwALIQIjAigCACACNgIAIwIjAigCAEEEajYCACMCKAIAIgIgADYCACACIAE2AgQgAiADNwIIIwIjAigCAEEQajYCAEEAC+MBAgF/An4jAUECRgRAIwIjAigCAEEQazYCACMCKAIAIgEoAgAhACABKQIIIQMgASgCBCEBCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACECCyMBRQRAIAApAwAiBEI/hyIDIASFIAN9IQMgBEJ/VSEACyACRUEBIwEbBEAgAyAAIAEQUkEAIwFBAUYNARohAAsjAUUEQCAADwsACyECIwIoAgAgAjYCACMCIwIoAgBBBGo2AgAjAigCACICIAA2AgAgAiABNgIEIAIgAzcCCCMCIwIoAgBBEGo2AgBBAAv9AQEDfyMBQQJGBEAjAiMCKAIAQRBrNgIAIwIoAgAiAygCACEAIAMoAgQhASADKAIIIQQgAygCDCEDCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACECCyMBRQRAIAAoAgAiACgCACEEIAFBGGooAgAhAyABQRxqKAIAIQEgACgCCCEACyACRUEBIwEbBEAgBCAAIAMgARAfQQAjAUEBRg0BGiEACyMBRQRAIAAPCwALIQIjAigCACACNgIAIwIjAigCAEEEajYCACMCKAIAIgIgADYCACACIAE2AgQgAiAENgIIIAIgAzYCDCMCIwIoAgBBEGo2AgBBAAv8AQECfyMBQQJGBEAjAiMCKAIAQQhrNgIAIwIoAgAiASgCACEAIAEoAgQhAQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhAwsjAUVBACMBRQRAIAAoAgAtAAAhAAsgABtFBEAgA0VBASMBGwRAIAFBi4gEQQUQE0EAIwFBAUYNAhohAAsjAUUEQCAADwsLIANBAUZBASMBGwRAIAFBh4gEQQQQE0EBIwFBAUYNARohAAsjAUUEQCAADwsACyECIwIoAgAgAjYCACMCIwIoAgBBBGo2AgAjAigCACICIAA2AgAgAiABNgIEIwIjAigCAEEIajYCAEEACyABAX8CQCAAKAIEIgFFDQAgAEEIaigCAEUNACABECELC/gBAQN/IwFBAkYEQCMCIwIoAgBBEGs2AgAjAigCACIDKAIAIQAgAygCBCEBIAMoAgghBCADKAIMIQMLAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQILIwFFBEAgACgCACEEIAFBGGooAgAhAyABQRxqKAIAIQEgACgCCCEACyACRUEBIwEbBEAgBCAAIAMgARAfQQAjAUEBRg0BGiEACyMBRQRAIAAPCwALIQIjAigCACACNgIAIwIjAigCAEEEajYCACMCKAIAIgIgADYCACACIAE2AgQgAiAENgIIIAIgAzYCDCMCIwIoAgBBEGo2AgBBAAv0AQEBfyMBQQJGBEAjAiMCKAIAQRBrNgIAIwIoAgAiAygCACEAIAMoAgQhASADKAIIIQIgAygCDCEDCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEECyMBRUEAIAIbRQRAIARFQQEjARsEQCAAIAEQgAFBACMBQQFGDQIaCyMBRQRADwsLIARBAUZBASMBGwRAIAAgASACIAMQJUEBIwFBAUYNARoLDwshBCMCKAIAIAQ2AgAjAiMCKAIAQQRqNgIAIwIoAgAiBCAANgIAIAQgATYCBCAEIAI2AgggBCADNgIMIwIjAigCAEEQajYCAAvkAQEBfyMBQQJGBEAjAiMCKAIAQQxrNgIAIwIoAgAiAygCACEAIAMoAgQhASADKAIIIQMLAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQQLIwFFQQAgAhtFBEAgBEVBASMBGwRAIAAgARCAAUEAIwFBAUYNAhoLIwFFBEAPCwsgBEEBRkEBIwEbBEAgACABIAMQIEEBIwFBAUYNARoLDwshAiMCKAIAIAI2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAiAANgIAIAIgATYCBCACIAM2AggjAiMCKAIAQQxqNgIACxQAIABBCGooAgAEQCAAKAIEECELC9EBAQF/IwFBAkYEQCMCIwIoAgBBCGs2AgAjAigCACIBKAIAIQAgASgCBCEBCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACECCyMBRQRAIAEoAhghACABQRxqKAIAKAIMIQELIAJFQQEjARsEQCAAQbiDBEEOIAERAQBBACMBQQFGDQEaIQALIwFFBEAgAA8LAAshAiMCKAIAIAI2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAiAANgIAIAIgATYCBCMCIwIoAgBBCGo2AgBBAAvRAQEBfyMBQQJGBEAjAiMCKAIAQQhrNgIAIwIoAgAiASgCACEAIAEoAgQhAQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhAgsjAUUEQCABKAIYIQAgAUEcaigCACgCDCEBCyACRUEBIwEbBEAgAEHFqgVBBSABEQEAQQAjAUEBRg0BGiEACyMBRQRAIAAPCwALIQIjAigCACACNgIAIwIjAigCAEEEajYCACMCKAIAIgIgADYCACACIAE2AgQjAiMCKAIAQQhqNgIAQQALFAAgAEEEaigCAARAIAAoAgAQIQsLEQAgACgCBARAIAAoAgAQIQsL2QEBAn8jAUECRgRAIwIjAigCAEEMazYCACMCKAIAIgMoAgAhACADKAIEIQEgAygCCCEDCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACECCyMBRQRAIAAoAgAiACgCACEDIAAoAgghAAsgAkVBASMBGwRAIAEgAyAAEBNBACMBQQFGDQEaIQALIwFFBEAgAA8LAAshAiMCKAIAIAI2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAiAANgIAIAIgATYCBCACIAM2AggjAiMCKAIAQQxqNgIAQQAL1wEBAn8jAUECRgRAIwIjAigCAEEMazYCACMCKAIAIgMoAgAhACADKAIEIQEgAygCCCEDCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACECCyMBRQRAIAAoAgAhAyAAQQhqKAIAIQALIAJFQQEjARsEQCABIAMgABATQQAjAUEBRg0BGiEACyMBRQRAIAAPCwALIQIjAigCACACNgIAIwIjAigCAEEEajYCACMCKAIAIgIgADYCACACIAE2AgQgAiADNgIIIwIjAigCAEEMajYCAEEAC9gBAQJ/IwFBAkYEQCMCIwIoAgBBDGs2AgAjAigCACIDKAIAIQAgAygCBCEBIAMoAgghAwsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhAgsjAUUEQCAAKAIAIQMgACgCBCgCDCEACyACRUEBIwEbBEAgAyABIAARAABBACMBQQFGDQEaIQALIwFFBEAgAA8LAAshAiMCKAIAIAI2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAiAANgIAIAIgATYCBCACIAM2AggjAiMCKAIAQQxqNgIAQQALwA4BCn8jAUECRgRAIwIjAigCAEEUazYCACMCKAIAIgQoAgAhACAEKAIEIQEgBCgCCCECIAQoAgwhAyAEKAIQIQQLAn8jAUECRgR/IwIjAigCAEEEazYCACMCKAIAKAIABUEAC0VBASMBGwRAIAAhByABIQogAiEIIAMhDSAEIQ4jAUECRgRAIwIjAigCAEEkazYCACMCKAIAIgkoAgAhByAJKAIEIQogCSgCCCEIIAkoAgwhDSAJKAIQIQ4gCSgCFCEFIAkoAhghBiAJKAIcIQsgCSgCICEJCwJAAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQwLIwFFBEAjAEHwAGsiBSQAIAUgDTYCDCAFIAg2AgggCkGBAkkhBgsCQAJAAkAjAUUEQCAFAn8CQAJAIAZFBEBBACEGA0AgBiAHaiELIAZBAWsiCSEGIAtBgAJqLAAAQb9/TA0ACyAJQYECaiIGIApJDQIgCSAKQYECa0ciCQ0FIAUgBjYCFAwBCyAFIAo2AhQLIAUgBzYCEEHooQUhC0EADAELIAcgCWpBgQJqLAAAQb9/TCIJDQIgBSAGNgIUIAUgBzYCEEGIjgQhC0EFCzYCHCAFIAs2AhggCCAKSyEGCwJAAkAjAUUEQCAGDQEgCiANSSIJDQEgCCANSyEGCwJ/AkACQCMBRQRAIAZFBEACQAJAIAhFDQAgCCAKTwRAIAggCkYNAQwCCyAHIAhqLAAAQUBIDQELIA0hCAsgBSAINgIgIAoiBiAITSINRQRAIAhBA2siCyAISyENIAhBAWoiBkEAIAsgDRsiC0kNByAGIAdqIAcgC2prIQYDQCAGQQFrIQYgByAIaiELIAhBAWsiCSEIIAssAABBQEgiDQ0ACyAJQQFqIQYLAkAgBkUiCA0AIAYgCk8EQCAGIApGIggNAQwLCyAGIAdqLAAAQb9/TCIIDQoLIAYgCkYiCg0IAkAgBiAHaiIHLAAAIgtBf0oiCkUEQCAHLQABQT9xIQggC0EfcSEJIAtBX0siCg0BIAggCUEGdHIhBwwFCyAFIAtB/wFxIgc2AiRBAQwFCyAHLQACQT9xIAhBBnRyIQggC0FwTyIKDQIgCCAJQQx0ciEHDAMLIAVB5ABqQQs2AgAgBUHcAGpBCzYCACAFQdQAakEFNgIAIAVBxABqQQQ2AgAgBUIENwI0IAVB7I4ENgIwIAVBBTYCTCAFIAVByABqNgJAIAUgBUEYajYCYCAFIAVBEGo2AlggBSAFQQxqNgJQIAUgBUEIajYCSCAFQTBqIQcLIAxFQQEjARsEQCAHIA4Q0gFBACMBQQFGDQkaCyMBRQRAAAsLIwFFBEAgCUESdEGAgPAAcSAHLQADQT9xIAhBBnRyciIHQYCAxABGIgoNBgsLIwEEfyALBSAFIAc2AiRBASAHQYABSSIKDQEaQQIgB0GAEEkiCg0BGkEDQQQgB0GAgARJIgcbCwshCyMBRQRAIAUgBjYCKCAFIAYgC2o2AiwgBUHEAGpBBTYCACAFQewAakELNgIAIAVB5ABqQQs2AgAgBUHcAGpBDTYCACAFQdQAakEONgIAIAVCBTcCNCAFQcCPBDYCMCAFQQU2AkwgBSAFQcgAajYCQCAFIAVBGGo2AmggBSAFQRBqNgJgIAUgBUEoajYCWCAFIAVBJGo2AlAgBSAFQSBqNgJIIAVBMGohBwsgDEEBRkEBIwEbBEAgByAOENIBQQEjAUEBRg0GGgsjAUUEQAALCyMBRQRAIAUgCCANIAYbNgIoIAVBxABqQQM2AgAgBUHcAGpBCzYCACAFQdQAakELNgIAIAVCAzcCNCAFQbCOBDYCMCAFQQU2AkwgBSAFQcgAajYCQCAFIAVBGGo2AlggBSAFQRBqNgJQIAUgBUEoajYCSCAFQTBqIQcLIAxBAkZBASMBGwRAIAcgDhDSAUECIwFBAUYNBRoLIwFFBEAACwsgDEEDRkEBIwEbBEAgCyAGQYSQBBD4AUEDIwFBAUYNBBoLIwFFBEAACwsgDEEERkEBIwEbBEAgByAKQQAgBiAOEOwBQQQjAUEBRg0DGgsjAUUEQAALCyAMQQVGQQEjARsEQEHMogVBKyAOEL8BQQUjAUEBRg0CGgsjAUUEQAALCyAMQQZGQQEjARsEQCAHIAogBiAKIA4Q7AFBBiMBQQFGDQEaCyMBRQRAAAsMAQshDCMCKAIAIAw2AgAjAiMCKAIAQQRqNgIAIwIoAgAiDCAHNgIAIAwgCjYCBCAMIAg2AgggDCANNgIMIAwgDjYCECAMIAU2AhQgDCAGNgIYIAwgCzYCHCAMIAk2AiAjAiMCKAIAQSRqNgIAC0EAIwFBAUYNARoLIwFFBEAACw8LIQgjAigCACAINgIAIwIjAigCAEEEajYCACMCKAIAIgggADYCACAIIAE2AgQgCCACNgIIIAggAzYCDCAIIAQ2AhAjAiMCKAIAQRRqNgIAC9QBAQJ/IwFBAkYEQCMCIwIoAgBBDGs2AgAjAigCACIDKAIAIQAgAygCBCEBIAMoAgghAwsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhAgsjAUUEQCAAKAIAIQMgACgCCCEACyACRUEBIwEbBEAgASADIAAQE0EAIwFBAUYNARohAAsjAUUEQCAADwsACyECIwIoAgAgAjYCACMCIwIoAgBBBGo2AgAjAigCACICIAA2AgAgAiABNgIEIAIgAzYCCCMCIwIoAgBBDGo2AgBBAAvUAQECfyMBQQJGBEAjAiMCKAIAQQxrNgIAIwIoAgAiAygCACEAIAMoAgQhASADKAIIIQMLAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQILIwFFBEAgACgCACEDIAAoAgQhAAsgAkVBASMBGwRAIAEgAyAAEBNBACMBQQFGDQEaIQALIwFFBEAgAA8LAAshAiMCKAIAIAI2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAiAANgIAIAIgATYCBCACIAM2AggjAiMCKAIAQQxqNgIAQQALCwAgAQRAIAAQIQsLzAEBAX8jAUECRgRAIwIjAigCAEEMazYCACMCKAIAIgIoAgAhACACKAIEIQEgAigCCCECCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEDCyMBRQRAIAAoAgAhAAsgA0VBASMBGwRAIAAgASACEMcBQQAjAUEBRg0BGgsjAUUEQEEADwsACyEDIwIoAgAgAzYCACMCIwIoAgBBBGo2AgAjAigCACIDIAA2AgAgAyABNgIEIAMgAjYCCCMCIwIoAgBBDGo2AgBBAAsSACAAQfyuBTYCBCAAIAE2AgALvgEBAX8jAUECRgRAIwIjAigCAEEMazYCACMCKAIAIgIoAgAhACACKAIEIQEgAigCCCECCwJ/IwFBAkYEfyMCIwIoAgBBBGs2AgAjAigCACgCAAVBAAtFQQEjARsEQCAAIAEgAhDEAUEAIwFBAUYNARoLIwFFBEBBAA8LAAshAyMCKAIAIAM2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAyAANgIAIAMgATYCBCADIAI2AggjAiMCKAIAQQxqNgIAQQAL/QQBBn8jAUECRgRAIwIjAigCAEEMazYCACMCKAIAIgIoAgAhACACKAIEIQEgAigCCCECCwJ/IwFBAkYEfyMCIwIoAgBBBGs2AgAjAigCACgCAAVBAAtFQQEjARsEQCAAIQQgASEGIAIhByMBQQJGBEAjAiMCKAIAQQhrNgIAIwIoAgAiBygCACEEIAcoAgQhBwsCQAJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACEDCyMBRQRAIwBBEGsiBSQAIAUgBjYCDCAFIAQ2AgggBUEIaiEECyADRUEBIwEbBEAgByEGQQAhAyMBQQJGBEAjAiMCKAIAQQhrNgIAIwIoAgAiBSgCACEGIAUoAgQhAwsgBCEFAkACfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhCAsjAUUEQCMAQSBrIgMkACADQRRqQQE2AgAgA0IBNwIEIANBkKQFNgIAIANBCzYCHCADIAU2AhggAyADQRhqNgIQCyAIRUEBIwEbBEAgAyAGENIBQQAjAUEBRg0BGgsjAUUEQAALDAELIQUjAigCACAFNgIAIwIjAigCAEEEajYCACMCKAIAIgUgBjYCACAFIAM2AgQjAiMCKAIAQQhqNgIAC0EAIwFBAUYNARoLIwFFBEAACwwBCyEGIwIoAgAgBjYCACMCIwIoAgBBBGo2AgAjAigCACIGIAQ2AgAgBiAHNgIEIwIjAigCAEEIajYCAAtBACMBQQFGDQEaCyMBRQRAAAsPCyEEIwIoAgAgBDYCACMCIwIoAgBBBGo2AgAjAigCACIEIAA2AgAgBCABNgIEIAQgAjYCCCMCIwIoAgBBDGo2AgALDgAgACgCABoDQAwACwALzwECAX8BfiMBQQJGBEAjAiMCKAIAQRBrNgIAIwIoAgAiASgCACEAIAEpAgghAyABKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQILIwFFBEAgADUCACEDCyACRUEBIwEbBEAgA0EBIAEQUkEAIwFBAUYNARohAAsjAUUEQCAADwsACyECIwIoAgAgAjYCACMCIwIoAgBBBGo2AgAjAigCACICIAA2AgAgAiABNgIEIAIgAzcCCCMCIwIoAgBBEGo2AgBBAAvSAwEFfyMBQQJGBEAjAiMCKAIAQQxrNgIAIwIoAgAiAigCACEAIAIoAgQhASACKAIIIQILAn8jAUECRgR/IwIjAigCAEEEazYCACMCKAIAKAIABUEAC0VBASMBGwRAIAAhBCABIQYgAiEFIwFBAkYEQCMCIwIoAgBBCGs2AgAjAigCACIFKAIAIQQgBSgCBCEFCwJAAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQcLIwFFBEAjAEEwayIDJAAgAyAGNgIEIAMgBDYCACADQRxqQQI2AgAgA0EsakEFNgIAIANCAjcCDCADQbSJBDYCCCADQQU2AiQgAyADQSBqNgIYIAMgA0EEajYCKCADIAM2AiAgA0EIaiEECyAHRUEBIwEbBEAgBCAFENIBQQAjAUEBRg0BGgsjAUUEQAALDAELIQYjAigCACAGNgIAIwIjAigCAEEEajYCACMCKAIAIgYgBDYCACAGIAU2AgQjAiMCKAIAQQhqNgIAC0EAIwFBAUYNARoLIwFFBEAACw8LIQQjAigCACAENgIAIwIjAigCAEEEajYCACMCKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIwIjAigCAEEMajYCAAvSAwEFfyMBQQJGBEAjAiMCKAIAQQxrNgIAIwIoAgAiAigCACEAIAIoAgQhASACKAIIIQILAn8jAUECRgR/IwIjAigCAEEEazYCACMCKAIAKAIABUEAC0VBASMBGwRAIAAhBCABIQYgAiEFIwFBAkYEQCMCIwIoAgBBCGs2AgAjAigCACIFKAIAIQQgBSgCBCEFCwJAAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQcLIwFFBEAjAEEwayIDJAAgAyAGNgIEIAMgBDYCACADQRxqQQI2AgAgA0EsakEFNgIAIANCAjcCDCADQdSJBDYCCCADQQU2AiQgAyADQSBqNgIYIAMgA0EEajYCKCADIAM2AiAgA0EIaiEECyAHRUEBIwEbBEAgBCAFENIBQQAjAUEBRg0BGgsjAUUEQAALDAELIQYjAigCACAGNgIAIwIjAigCAEEEajYCACMCKAIAIgYgBDYCACAGIAU2AgQjAiMCKAIAQQhqNgIAC0EAIwFBAUYNARoLIwFFBEAACw8LIQQjAigCACAENgIAIwIjAigCAEEEajYCACMCKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIwIjAigCAEEMajYCAAvSAwEFfyMBQQJGBEAjAiMCKAIAQQxrNgIAIwIoAgAiAigCACEAIAIoAgQhASACKAIIIQILAn8jAUECRgR/IwIjAigCAEEEazYCACMCKAIAKAIABUEAC0VBASMBGwRAIAAhBCABIQYgAiEFIwFBAkYEQCMCIwIoAgBBCGs2AgAjAigCACIFKAIAIQQgBSgCBCEFCwJAAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQcLIwFFBEAjAEEwayIDJAAgAyAGNgIEIAMgBDYCACADQRxqQQI2AgAgA0EsakEFNgIAIANCAjcCDCADQYiKBDYCCCADQQU2AiQgAyADQSBqNgIYIAMgA0EEajYCKCADIAM2AiAgA0EIaiEECyAHRUEBIwEbBEAgBCAFENIBQQAjAUEBRg0BGgsjAUUEQAALDAELIQYjAigCACAGNgIAIwIjAigCAEEEajYCACMCKAIAIgYgBDYCACAGIAU2AgQjAiMCKAIAQQhqNgIAC0EAIwFBAUYNARoLIwFFBEAACw8LIQQjAigCACAENgIAIwIjAigCAEEEajYCACMCKAIAIgQgADYCACAEIAE2AgQgBCACNgIIIwIjAigCAEEMajYCAAvNAQEBfyMBQQJGBEAjAiMCKAIAQQxrNgIAIwIoAgAiAigCACEAIAIoAgQhASACKAIIIQILAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQMLIwFFBEAgACgCACEACyADRUEBIwEbBEAgACABIAIQLEEAIwFBAUYNARohAAsjAUUEQCAADwsACyEDIwIoAgAgAzYCACMCIwIoAgBBBGo2AgAjAigCACIDIAA2AgAgAyABNgIEIAMgAjYCCCMCIwIoAgBBDGo2AgBBAAvPAQIBfwF+IwFBAkYEQCMCIwIoAgBBEGs2AgAjAigCACIBKAIAIQAgASkCCCEDIAEoAgQhAQsCfyMBQQJGBEAjAiMCKAIAQQRrNgIAIwIoAgAoAgAhAgsjAUUEQCAAMQAAIQMLIAJFQQEjARsEQCADQQEgARBSQQAjAUEBRg0BGiEACyMBRQRAIAAPCwALIQIjAigCACACNgIAIwIjAigCAEEEajYCACMCKAIAIgIgADYCACACIAE2AgQgAiADNwIIIwIjAigCAEEQajYCAEEAC88BAgF/AX4jAUECRgRAIwIjAigCAEEQazYCACMCKAIAIgEoAgAhACABKQIIIQMgASgCBCEBCwJ/IwFBAkYEQCMCIwIoAgBBBGs2AgAjAigCACgCACECCyMBRQRAIAApAwAhAwsgAkVBASMBGwRAIANBASABEFJBACMBQQFGDQEaIQALIwFFBEAgAA8LAAshAiMCKAIAIAI2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAiAANgIAIAIgATYCBCACIAM3AggjAiMCKAIAQRBqNgIAQQALvgEBAX8jAUECRgRAIwIjAigCAEEMazYCACMCKAIAIgIoAgAhACACKAIEIQEgAigCCCECCwJ/IwFBAkYEfyMCIwIoAgBBBGs2AgAjAigCACgCAAVBAAtFQQEjARsEQCAAIAEgAhDFAUEAIwFBAUYNARoLIwFFBEBBAA8LAAshAyMCKAIAIAM2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAyAANgIAIAMgATYCBCADIAI2AggjAiMCKAIAQQxqNgIAQQALvQEBAX8jAUECRgRAIwIjAigCAEEIazYCACMCKAIAIgEoAgAhACABKAIEIQELAn8jAUECRgRAIwIjAigCAEEEazYCACMCKAIAKAIAIQILIwFFBEAgACgCACEACyACRUEBIwEbBEAgACABEFFBACMBQQFGDQEaIQALIwFFBEAgAA8LAAshAiMCKAIAIAI2AgAjAiMCKAIAQQRqNgIAIwIoAgAiAiAANgIAIAIgATYCBCMCIwIoAgBBCGo2AgBBAAtDAQN/AkAgAkUNAANAIAAtAAAiBCABLQAAIgVGBEAgAEEBaiEAIAFBAWohASACQQFrIgINAQwCCwsgBCAFayEDCyADC7MCAQd/AkAgAiIEQQ9NBEAgACECDAELQQAgAGtBA3EiAyAAaiEFIAMEQCAAIQIgASEGA0AgAiAGLQAAOgAAIAZBAWohBiAFIAJBAWoiAksNAAsLIAQgA2siCEF8cSIHIAVqIQICQCABIANqIgNBA3EiBARAIAdBAUgNASADQXxxIgZBBGohAUEAIARBA3QiCWtBGHEhBCAGKAIAIQYDQCAFIAYgCXYgASgCACIGIAR0cjYCACABQQRqIQEgBUEEaiIFIAJJDQALDAELIAdBAUgNACADIQEDQCAFIAEoAgA2AgAgAUEEaiEBIAVBBGoiBSACSQ0ACwsgCEEDcSEEIAMgB2ohAQsgBARAIAIgBGohAwNAIAIgAS0AADoAACABQQFqIQEgAyACQQFqIgJLDQALCyAAC5QFAQd/AkACQAJ/AkAgAiIDIAAgAWtLBEAgASADaiEFIAAgA2ohAiADQQ9LDQEgAAwCCyADQQ9NBEAgACECDAMLQQAgAGtBA3EiBSAAaiEEIAUEQCAAIQIgASEAA0AgAiAALQAAOgAAIABBAWohACACQQFqIgIgBEkNAAsLIAMgBWsiA0F8cSIGIARqIQICQCABIAVqIgVBA3EiAARAIAZBAUgNASAFQXxxIgdBBGohAUEAIABBA3QiCGtBGHEhCSAHKAIAIQADQCAEIAAgCHYgASgCACIAIAl0cjYCACABQQRqIQEgAiAEQQRqIgRLDQALDAELIAZBAUgNACAFIQEDQCAEIAEoAgA2AgAgAUEEaiEBIAIgBEEEaiIESw0ACwsgA0EDcSEDIAUgBmohAQwCCyACQXxxIQBBACACQQNxIgZrIQcgBgRAIAEgA2pBAWshBANAIAJBAWsiAiAELQAAOgAAIARBAWshBCAAIAJJDQALCyAAIAMgBmsiBkF8cSIDayECQQAgA2shAwJAIAUgB2oiBUEDcSIEBEAgA0F/Sg0BIAVBfHEiB0EEayEBQQAgBEEDdCIIa0EYcSEJIAcoAgAhBANAIABBBGsiACAEIAl0IAEoAgAiBCAIdnI2AgAgAUEEayEBIAAgAksNAAsMAQsgA0F/Sg0AIAEgBmpBBGshAQNAIABBBGsiACABKAIANgIAIAFBBGshASAAIAJLDQALCyAGQQNxIgBFDQIgAyAFaiEFIAIgAGsLIQAgBUEBayEBA0AgAkEBayICIAEtAAA6AAAgAUEBayEBIAAgAkkNAAsMAQsgA0UNACACIANqIQADQCACIAEtAAA6AAAgAUEBaiEBIAJBAWoiAiAASQ0ACwsLnAEBAn8gAkEPSwRAQQAgAGtBA3EiAyAAaiEEIAMEQANAIAAgAToAACAEIABBAWoiAEsNAAsLIAQgAiADayICQXxxIgNqIQAgA0EBTgRAIAFB/wFxQYGChAhsIQMDQCAEIAM2AgAgBEEEaiIEIABJDQALCyACQQNxIQILIAIEQCAAIAJqIQIDQCAAIAE6AAAgAiAAQQFqIgBLDQALCwsKAEHIvAUoAgBFCw0AQtqix6Hh6ZaloX8LDABCgNK7kPDIiIgsCw0AQuuRk7X22LOi9AALAwABCxkAQQEkASAAJAIjAigCACMCKAIESwRAAAsLFQBBACQBIwIoAgAjAigCBEsEQAALCxkAQQIkASAAJAIjAigCACMCKAIESwRAAAsLBAAjAQsL3rwBAgBBgIAEC7i4ARMAAAAMAAAABAAAABQAAAAVAAAAFgAAABcAAAAAAAAAAQAAABgAAAAvdXNyL2xvY2FsL2NhcmdvL3JlZ2lzdHJ5L3NyYy9naXRodWIuY29tLTFlY2M2Mjk5ZGI5ZWM4MjMvbXVsdGliYXNlLTAuOS4xL3NyYy9saWIucnMoAAEAVAAAACYAAAAgAAAAd3JhcDovL2Vucy93cmFwcy5ldGg6Y29uY3VycmVudEAxLjAuMHdyYXA6Ly9lbnMvd3JhcHMuZXRoOmlwZnMtaHR0cC1jbGllbnRAMS4wLjAZAAAABAAAAAQAAAAaAAAAGwAAABwAAABsaWJyYXJ5L2FsbG9jL3NyYy9yYXdfdmVjLnJzY2FwYWNpdHkgb3ZlcmZsb3cAAAAQAQEAEQAAAPQAAQAcAAAABgIAAAUAAABhIGZvcm1hdHRpbmcgdHJhaXQgaW1wbGVtZW50YXRpb24gcmV0dXJuZWQgYW4gZXJyb3IAGQAAAAAAAAABAAAAGAAAAGxpYnJhcnkvYWxsb2Mvc3JjL2ZtdC5yc4ABAQAYAAAAZAIAAAkAAAAvaXBmcy8uLq4BAQACAAAAQm9ycm93TXV0RXJyb3JpbmRleCBvdXQgb2YgYm91bmRzOiB0aGUgbGVuIGlzICBidXQgdGhlIGluZGV4IGlzIMYBAQAgAAAA5gEBABIAAAAZAAAAAAAAAAEAAAAdAAAAPT1hc3NlcnRpb24gZmFpbGVkOiBgKGxlZnQgIHJpZ2h0KWAKICBsZWZ0OiBgYCwKIHJpZ2h0OiBgYDogGgIBABkAAAAzAgEAEgAAAEUCAQAMAAAAUQIBAAMAAABgAAAAGgIBABkAAAAzAgEAEgAAAEUCAQAMAAAAdAIBAAEAAADoUAEAAAAAALNVAQACAAAAGQAAAAwAAAAEAAAAHgAAAB8AAAAgAAAAICAgICB7CiwKLCAgeyB9IHsgLi4gfSB9KAooLGxpYnJhcnkvY29yZS9zcmMvZm10L251bS5ycwDcAgEAGwAAAGUAAAAUAAAAMHgwMDAxMDIwMzA0MDUwNjA3MDgwOTEwMTExMjEzMTQxNTE2MTcxODE5MjAyMTIyMjMyNDI1MjYyNzI4MjkzMDMxMzIzMzM0MzUzNjM3MzgzOTQwNDE0MjQzNDQ0NTQ2NDc0ODQ5NTA1MTUyNTM1NDU1NTY1NzU4NTk2MDYxNjI2MzY0NjU2NjY3Njg2OTcwNzE3MjczNzQ3NTc2Nzc3ODc5ODA4MTgyODM4NDg1ODY4Nzg4ODk5MDkxOTI5Mzk0OTU5Njk3OTg5OQAAGQAAAAQAAAAEAAAAIQAAACIAAAAjAAAAbGlicmFyeS9jb3JlL3NyYy9mbXQvbW9kLnJzdHJ1ZWZhbHNl7AMBABsAAAB7CQAAHgAAAOwDAQAbAAAAggkAABYAAABsaWJyYXJ5L2NvcmUvc3JjL3NsaWNlL21lbWNoci5yczAEAQAgAAAAaAAAACcAAAAwBAEAIAAAAIIAAAAaAAAAMAQBACAAAACeAAAABQAAAHJhbmdlIHN0YXJ0IGluZGV4ICBvdXQgb2YgcmFuZ2UgZm9yIHNsaWNlIG9mIGxlbmd0aCCABAEAEgAAAJIEAQAiAAAAcmFuZ2UgZW5kIGluZGV4IMQEAQAQAAAAkgQBACIAAABzbGljZSBpbmRleCBzdGFydHMgYXQgIGJ1dCBlbmRzIGF0IADkBAEAFgAAAPoEAQANAAAAAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIDAwMDAwMDAwMDAwMDAwMDBAQEBAQAAAAAAAAAAAAAAGluY29tcGxldGUgdXRmLTggYnl0ZSBzZXF1ZW5jZSBmcm9tIGluZGV4IAAAGAYBACoAAABpbnZhbGlkIHV0Zi04IHNlcXVlbmNlIG9mICBieXRlcyBmcm9tIGluZGV4IEwGAQAaAAAAZgYBABIAAABsaWJyYXJ5L2NvcmUvc3JjL3N0ci9wYXR0ZXJuLnJzAIgGAQAfAAAAJwUAAAwAAACIBgEAHwAAACcFAAAiAAAAiAYBAB8AAAA7BQAAMAAAAIgGAQAfAAAAGgYAABUAAACIBgEAHwAAAEgGAAAVAAAAiAYBAB8AAABJBgAAFQAAAFsuLi5dYnl0ZSBpbmRleCAgaXMgb3V0IG9mIGJvdW5kcyBvZiBgAAANBwEACwAAABgHAQAWAAAAdAIBAAEAAABiZWdpbiA8PSBlbmQgKCA8PSApIHdoZW4gc2xpY2luZyBgAABIBwEADgAAAFYHAQAEAAAAWgcBABAAAAB0AgEAAQAAACBpcyBub3QgYSBjaGFyIGJvdW5kYXJ5OyBpdCBpcyBpbnNpZGUgIChieXRlcyApIG9mIGANBwEACwAAAIwHAQAmAAAAsgcBAAgAAAC6BwEABgAAAHQCAQABAAAAbGlicmFyeS9jb3JlL3NyYy9zdHIvbW9kLnJzAOgHAQAbAAAABwEAAB0AAABsaWJyYXJ5L2NvcmUvc3JjL3VuaWNvZGUvcHJpbnRhYmxlLnJzAAAAFAgBACUAAAAKAAAAHAAAABQIAQAlAAAAGgAAACgAAAAAAQMFBQYGAgcGCAcJEQocCxkMGg0QDgwPBBADEhITCRYBFwQYARkDGgcbARwCHxYgAysDLQsuATADMQIyAacCqQKqBKsI+gL7Bf0C/gP/Ca14eYuNojBXWIuMkBzdDg9LTPv8Li8/XF1f4oSNjpGSqbG6u8XGycre5OX/AAQREikxNDc6Oz1JSl2EjpKpsbS6u8bKzs/k5QAEDQ4REikxNDo7RUZJSl5kZYSRm53Jzs8NESk6O0VJV1tcXl9kZY2RqbS6u8XJ3+Tl8A0RRUlkZYCEsry+v9XX8PGDhYukpr6/xcfP2ttImL3Nxs7PSU5PV1leX4mOj7G2t7/BxsfXERYXW1z29/7/gG1x3t8OH25vHB1ffX6ur3+7vBYXHh9GR05PWFpcXn5/tcXU1dzw8fVyc490dZYmLi+nr7e/x8/X35pAl5gwjx/S1M7/Tk9aWwcIDxAnL+7vbm83PT9CRZCRU2d1yMnQ0djZ5/7/ACBfIoLfBIJECBsEBhGBrA6AqwUfCYEbAxkIAQQvBDQEBwMBBwYHEQpQDxIHVQcDBBwKCQMIAwcDAgMDAwwEBQMLBgEOFQVOBxsHVwcCBhcMUARDAy0DAQQRBg8MOgQdJV8gbQRqJYDIBYKwAxoGgv0DWQcWCRgJFAwUDGoGCgYaBlkHKwVGCiwEDAQBAzELLAQaBgsDgKwGCgYvMU0DgKQIPAMPAzwHOAgrBYL/ERgILxEtAyEPIQ+AjASClxkLFYiUBS8FOwcCDhgJgL4idAyA1hoMBYD/BYDfDPKdAzcJgVwUgLgIgMsFChg7AwoGOAhGCAwGdAseA1oEWQmAgxgcChYJTASAigarpAwXBDGhBIHaJgcMBQWAphCB9QcBICoGTASAjQSAvgMbAw8NAAYBAQMBBAIFBwcCCAgJAgoFCwIOBBABEQISBRMRFAEVAhcCGQ0cBR0IHwEkAWoEawKvA7ECvALPAtEC1AzVCdYC1wLaAeAF4QLnBOgC7iDwBPgC+gP7AQwnOz5OT4+enp97i5OWorK6hrEGBwk2PT5W89DRBBQYNjdWV3+qrq+9NeASh4mOngQNDhESKTE0OkVGSUpOT2RlXLa3GxwHCAoLFBc2OTqoqdjZCTeQkagHCjs+ZmmPkhFvX7/u71pi9Pz/U1Samy4vJyhVnaCho6SnqK26vMQGCwwVHTo/RVGmp8zNoAcZGiIlPj/n7O//xcYEICMlJigzODpISkxQU1VWWFpcXmBjZWZrc3h9f4qkqq+wwNCur25vvpNeInsFAwQtA2YDAS8ugIIdAzEPHAQkCR4FKwVEBA4qgKoGJAQkBCgINAtOQ4E3CRYKCBg7RTkDYwgJMBYFIQMbBQFAOARLBS8ECgcJB0AgJwQMCTYDOgUaBwQMB1BJNzMNMwcuCAqBJlJLKwgqFhomHBQXCU4EJAlEDRkHCgZICCcJdQtCPioGOwUKBlEGAQUQAwWAi2IeSAgKgKZeIkULCgYNEzoGCjYsBBeAuTxkUwxICQpGRRtICFMNSQcKgPZGCh0DR0k3Aw4ICgY5BwqBNhkHOwMcVgEPMg2Dm2Z1C4DEikxjDYQwEBaPqoJHobmCOQcqBFwGJgpGCigFE4KwW2VLBDkHEUAFCwIOl/gIhNYqCaLngTMPAR0GDgQIgYyJBGsFDQMJBxCSYEcJdDyA9gpzCHAVRnoUDBQMVwkZgIeBRwOFQg8VhFAfBgaA1SsFPiEBcC0DGgQCgUAfEToFAYHQKoLmgPcpTAQKBAKDEURMPYDCPAYBBFUFGzQCgQ4sBGQMVgqArjgdDSwECQcCDgaAmoPYBBEDDQN3BF8GDAQBDwwEOAgKBigIIk6BVAwdAwkHNggOBAkHCQeAyyUKhAZsaWJyYXJ5L2NvcmUvc3JjL3VuaWNvZGUvdW5pY29kZV9kYXRhLnJz2A0BACgAAABXAAAAPgAAAAADAACDBCAAkQVgAF0ToAASFyAfDCBgH+8soCsqMCAsb6bgLAKoYC0e+2AuAP4gNp7/YDb9AeE2AQohNyQN4TerDmE5LxihOTAcYUjzHqFMQDRhUPBqoVFPbyFSnbyhUgDPYVNl0aFTANohVADg4VWu4mFX7OQhWdDooVkgAO5Z8AF/WgBwAAcALQEBAQIBAgEBSAswFRABZQcCBgICAQQjAR4bWws6CQkBGAQBCQEDAQUrAzwIKhgBIDcBAQEECAQBAwcKAh0BOgEBAQIECAEJAQoCGgECAjkBBAIEAgIDAwEeAgMBCwI5AQQFAQIEARQCFgYBAToBAQIBBAgBBwMKAh4BOwEBAQwBCQEoAQMBNwEBAwUDAQQHAgsCHQE6AQIBAgEDAQUCBwILAhwCOQIBAQIECAEJAQoCHQFIAQQBAgMBAQgBUQECBwwIYgECCQsHSQIbAQEBAQE3DgEFAQIFCwEkCQFmBAEGAQICAhkCBAMQBA0BAgIGAQ8BAAMAAx0CHgIeAkACAQcIAQILCQEtAwEBdQIiAXYDBAIJAQYD2wICAToBAQcBAQEBAggGCgIBMB8xBDAHAQEFASgJDAIgBAICAQM4AQECAwEBAzoIAgKYAwENAQcEAQYBAwLGQAABwyEAA40BYCAABmkCAAQBCiACUAIAAQMBBAEZAgUBlwIaEg0BJggZCy4DMAECBAICJwFDBgICAgIMAQgBLwEzAQEDAgIFAgEBKgIIAe4BAgEEAQABABAQEAACAAHiAZUFAAMBAgUEKAMEAaUCAAQAAlADRgsxBHsBNg8pAQICCgMxBAICBwE9AyQFAQg+AQwCNAkKBAIBXwMCAQECBgECAZ0BAwgVAjkCAQEBARYBDgcDBcMIAgMBARcBUQECBgEBAgEBAgEC6wECBAYCAQIbAlUIAgEBAmoBAQECBgEBZQMCBAEFAAkBAvUBCgIBAQQBkAQCAgQBIAooBgIECAEJBgIDLg0BAgAHAQYBAVIWAgcBAgECegYDAQECAQcBAUgCAwEBAQACCwI0BQUBAQEAAQYPAAU7BwABPwRRAQACAC4CFwABAQMEBQgIAgceBJQDADcEMggBDgEWBQEPAAcBEQIHAQIBBWQBoAcAAT0EAAQAB20HAGCA8AAA2A0BACgAAAA/AQAACQAAABcAAAAIAAAABAAAACQAAAAvdXNyL2xvY2FsL2NhcmdvL3JlZ2lzdHJ5L3NyYy9naXRodWIuY29tLTFlY2M2Mjk5ZGI5ZWM4MjMvZGF0YS1lbmNvZGluZy0yLjMuMi9zcmMvbGliLnJzjBEBAFgAAAC+AQAAPQAAAIwRAQBYAAAA0QEAABcAAACMEQEAWAAAAHkCAAASAAAAjBEBAFgAAACIAgAAJQAAAIwRAQBYAAAAjQIAADIAAACMEQEAWAAAAJMCAAAOAAAAjBEBAFgAAACUAgAAEgAAAIwRAQBYAAAAxwIAABcAAACMEQEAWAAAAM8CAAA/AAAAjBEBAFgAAADPAgAAVAAAAIwRAQBYAAAAzAIAABYAAACMEQEAWAAAANMCAAAlAAAAjBEBAFgAAADxAgAADgAAAIwRAQBYAAAA8gIAABIAAACMEQEAWAAAAP8CAAA8AAAAjBEBAFgAAAD/AgAAUgAAAIwRAQBYAAAAywQAABwAAACMEQEAWAAAAM8EAAAMAAAAjBEBAFgAAADXBAAACQAAAIwRAQBYAAAA2wQAAAkAAACMEQEAWAAAAN8EAAAKAAAAZXhwbGljaXQgcGFuaWMAAIwRAQBYAAAAUgUAABwAAACMEQEAWAAAAIUFAAAJAAAAjBEBAFgAAACGBQAACQAAAFBhZGRpbmdUcmFpbGluZ1N5bWJvbExlbmd0aERlY29kZUVycm9ycG9zaXRpb24AABcAAAAEAAAABAAAACUAAAAXAAAABAAAAAQAAAAmAAAAFwAAAAQAAAAEAAAAJwAAAEVycgAXAAAABAAAAAQAAAAoAAAAT2sAABcAAAAEAAAABAAAACUAAAAwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxMDEwMTAxgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAAAGAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIAZAAAAAAAA/BMBAAICAAAwMTIzNDU2NzAxMjM0NTY3MDEyMzQ1NjcwMTIzNDU2NzAxMjM0NTY3MDEyMzQ1NjcwMTIzNDU2NzAxMjM0NTY3MDEyMzQ1NjcwMTIzNDU2NzAxMjM0NTY3MDEyMzQ1NjcwMTIzNDU2NzAxMjM0NTY3MDEyMzQ1NjcwMTIzNDU2NzAxMjM0NTY3MDEyMzQ1NjcwMTIzNDU2NzAxMjM0NTY3MDEyMzQ1NjcwMTIzNDU2NzAxMjM0NTY3MDEyMzQ1NjcwMTIzNDU2NzAxMjM0NTY3MDEyMzQ1NjcwMTIzNDU2NzAxMjM0NTY3MDEyMzQ1NjcwMTIzNDU2NzAxMjM0NTY3gICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAAAECAwQFBgeAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIAbAAAAAAAADBYBAAICAAAwMTIzNDU2Nzg5QUJDREVGR0hJSktMTU5PUFFSU1RVVldYWVphYmNkZWZnaGlqa2xtbm9wcXJzdHV2d3h5ejAxMjM0NTY3ODkrL0FCQ0RFRkdISUpLTE1OT1BRUlNUVVZXWFlaYWJjZGVmZ2hpamtsbW5vcHFyc3R1dnd4eXowMTIzNDU2Nzg5Ky9BQkNERUZHSElKS0xNTk9QUVJTVFVWV1hZWmFiY2RlZmdoaWprbG1ub3BxcnN0dXZ3eHl6MDEyMzQ1Njc4OSsvQUJDREVGR0hJSktMTU5PUFFSU1RVVldYWVphYmNkZWZnaGlqa2xtbm9wcXJzdHV2d3h5ejAxMjM0NTY3ODkrL4CAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIA+gICAPzQ1Njc4OTo7PD2AgICAgICAAAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBmAgICAgIAaGxwdHh8gISIjJCUmJygpKissLS4vMDEyM4CAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAHgAAAAAmGAEAAgIAAHlibmRyZmc4ZWprbWNwcXhvdDF1d2lzemEzNDVoNzY5eWJuZHJmZzhlamttY3BxeG90MXV3aXN6YTM0NWg3Njl5Ym5kcmZnOGVqa21jcHF4b3QxdXdpc3phMzQ1aDc2OXlibmRyZmc4ZWprbWNwcXhvdDF1d2lzemEzNDVoNzY5eWJuZHJmZzhlamttY3BxeG90MXV3aXN6YTM0NWg3Njl5Ym5kcmZnOGVqa21jcHF4b3QxdXdpc3phMzQ1aDc2OXlibmRyZmc4ZWprbWNwcXhvdDF1d2lzemEzNDVoNzY5eWJuZHJmZzhlamttY3BxeG90MXV3aXN6YTM0NWg3NjmAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAEoAZGhseHQcfgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAGAEMAwgFBhwVCQqACwIQDQ4EFhETgBQPABeAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgB0AAAAAAAA0GgEAAgIAADEyMzQ1Njc4OUFCQ0RFRkdISktMTU5QUVJTVFVWV1hZWmFiY2RlZmdoaWprbW5vcHFyc3R1dnd4eXpBQkNERUZHSElKS0xNTk9QUVJTVFVWV1hZWmFiY2RlZmdoaWprbG1ub3BxcnN0dXZ3eHl6MDEyMzQ1Njc4OSsvQUJDREVGR0hJSktMTU5PUFFSU1RVVldYWVphYmNkZWZnaGlqa2xtbm9wcXJzdHV2d3h5ejAxMjM0NTY3ODkrL0FCQ0RFRkdISUpLTE1OT1BRUlNUVVZXWFlaYWJjZGVmZ2hpamtsbW5vcHFyc3R1dnd4eXowMTIzNDU2Nzg5Ky9BQkNERUZHSElKS0xNTk9QUVJTVFVWV1hZWmFiY2RlZmdoaWprbG1ub3BxcnN0dXZ3eHl6MDEyMzQ1Njc4OSsvgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgD6AgIA/NDU2Nzg5Ojs8PYCAgIKAgIAAAQIDBAUGBwgJCgsMDQ4PEBESExQVFhcYGYCAgICAgBobHB0eHyAhIiMkJSYnKCkqKywtLi8wMTIzgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgD0eAAAAAH4cAQACAgAAQUJDREVGR0hJSktMTU5PUFFSU1RVVldYWVphYmNkZWZnaGlqa2xtbm9wcXJzdHV2d3h5ejAxMjM0NTY3ODktX0FCQ0RFRkdISUpLTE1OT1BRUlNUVVZXWFlaYWJjZGVmZ2hpamtsbW5vcHFyc3R1dnd4eXowMTIzNDU2Nzg5LV9BQkNERUZHSElKS0xNTk9QUVJTVFVWV1hZWmFiY2RlZmdoaWprbG1ub3BxcnN0dXZ3eHl6MDEyMzQ1Njc4OS1fQUJDREVGR0hJSktMTU5PUFFSU1RVVldYWVphYmNkZWZnaGlqa2xtbm9wcXJzdHV2d3h5ejAxMjM0NTY3ODktX4CAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgD6AgDQ1Njc4OTo7PD2AgICAgICAAAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBmAgICAP4AaGxwdHh8gISIjJCUmJygpKissLS4vMDEyM4CAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAHgAAAAAAAIweAQACAgAAMDEyMzQ1Njc4OWFiY2RlZjAxMjM0NTY3ODlhYmNkZWYwMTIzNDU2Nzg5YWJjZGVmMDEyMzQ1Njc4OWFiY2RlZjAxMjM0NTY3ODlhYmNkZWYwMTIzNDU2Nzg5YWJjZGVmMDEyMzQ1Njc4OWFiY2RlZjAxMjM0NTY3ODlhYmNkZWYwMTIzNDU2Nzg5YWJjZGVmMDEyMzQ1Njc4OWFiY2RlZjAxMjM0NTY3ODlhYmNkZWYwMTIzNDU2Nzg5YWJjZGVmMDEyMzQ1Njc4OWFiY2RlZjAxMjM0NTY3ODlhYmNkZWYwMTIzNDU2Nzg5YWJjZGVmMDEyMzQ1Njc4OWFiY2RlZoCAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgAABAgMEBQYHCAmAgICAgICACgsMDQ4PgICAgICAgICAgICAgICAgICAgICAgICAgIAKCwwNDg+AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAHAAAAAAAAJwgAQACAgAAMDEyMzQ1Njc4OUFCQ0RFRjAxMjM0NTY3ODlBQkNERUYwMTIzNDU2Nzg5QUJDREVGMDEyMzQ1Njc4OUFCQ0RFRjAxMjM0NTY3ODlBQkNERUYwMTIzNDU2Nzg5QUJDREVGMDEyMzQ1Njc4OUFCQ0RFRjAxMjM0NTY3ODlBQkNERUYwMTIzNDU2Nzg5QUJDREVGMDEyMzQ1Njc4OUFCQ0RFRjAxMjM0NTY3ODlBQkNERUYwMTIzNDU2Nzg5QUJDREVGMDEyMzQ1Njc4OUFCQ0RFRjAxMjM0NTY3ODlBQkNERUYwMTIzNDU2Nzg5QUJDREVGMDEyMzQ1Njc4OUFCQ0RFRoCAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgAABAgMEBQYHCAmAgICAgICACgsMDQ4PgICAgICAgICAgICAgICAgICAgICAgICAgIAKCwwNDg+AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAHAAAAAAAAKwiAQACAgAAYWJjZGVmZ2hpamtsbW5vcHFyc3R1dnd4eXoyMzQ1NjdhYmNkZWZnaGlqa2xtbm9wcXJzdHV2d3h5ejIzNDU2N2FiY2RlZmdoaWprbG1ub3BxcnN0dXZ3eHl6MjM0NTY3YWJjZGVmZ2hpamtsbW5vcHFyc3R1dnd4eXoyMzQ1NjdhYmNkZWZnaGlqa2xtbm9wcXJzdHV2d3h5ejIzNDU2N2FiY2RlZmdoaWprbG1ub3BxcnN0dXZ3eHl6MjM0NTY3YWJjZGVmZ2hpamtsbW5vcHFyc3R1dnd4eXoyMzQ1NjdhYmNkZWZnaGlqa2xtbm9wcXJzdHV2d3h5ejIzNDU2N4CAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAGhscHR4fgICAgICAgICAAAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBmAgICAgIAAAQIDBAUGBwgJCgsMDQ4PEBESExQVFhcYGYCAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAHQAAAAAAALwkAQACAgAAQUJDREVGR0hJSktMTU5PUFFSU1RVVldYWVoyMzQ1NjdBQkNERUZHSElKS0xNTk9QUVJTVFVWV1hZWjIzNDU2N0FCQ0RFRkdISUpLTE1OT1BRUlNUVVZXWFlaMjM0NTY3QUJDREVGR0hJSktMTU5PUFFSU1RVVldYWVoyMzQ1NjdBQkNERUZHSElKS0xNTk9QUVJTVFVWV1hZWjIzNDU2N0FCQ0RFRkdISUpLTE1OT1BRUlNUVVZXWFlaMjM0NTY3QUJDREVGR0hJSktMTU5PUFFSU1RVVldYWVoyMzQ1NjdBQkNERUZHSElKS0xNTk9QUVJTVFVWV1hZWjIzNDU2N4CAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAGhscHR4fgICAgICAgICAAAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBmAgICAgIAAAQIDBAUGBwgJCgsMDQ4PEBESExQVFhcYGYCAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAHQAAAAAAAMwmAQACAgAAMDEyMzQ1Njc4OWFiY2RlZmdoaWprbG1ub3BxcnN0dXZ3eHl6MDEyMzQ1Njc4OUFCQ0RFRkdISUpLTE1OT1BRUlNUVVZXWFlaMTIzNDU2Nzg5YWJjZGVmZ2hpamttbm9wcXJzdHV2d3h5ekFCQ0RFRkdISktMTU5QUVJTVFVWV1hZWkFCQ0RFRkdISUpLTE1OT1BRUlNUVVZXWFlaYWJjZGVmZ2hpamtsbW5vcHFyc3R1dnd4eXowMTIzNDU2Nzg5LV9BQkNERUZHSElKS0xNTk9QUVJTVFVWV1hZWmFiY2RlZmdoaWprbG1ub3BxcnN0dXZ3eHl6MDEyMzQ1Njc4OS1fQUJDREVGR0hJSktMTU5PUFFSU1RVVldYWVphYmNkZWZnaGlqa2xtbm9wcXJzdHV2d3h5ejAxMjM0NTY3ODktX0FCQ0RFRkdISUpLTE1OT1BRUlNUVVZXWFlaYWJjZGVmZ2hpamtsbW5vcHFyc3R1dnd4eXowMTIzNDU2Nzg5LV+AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIA+gIA0NTY3ODk6Ozw9gICAgoCAgAABAgMEBQYHCAkKCwwNDg8QERITFBUWFxgZgICAgD+AGhscHR4fICEiIyQlJicoKSorLC0uLzAxMjOAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAPR4AAAAAXikBAAICAAAwMTIzNDU2Nzg5YWJjZGVmZ2hpamtsbW5vcHFyc3R1djAxMjM0NTY3ODlhYmNkZWZnaGlqa2xtbm9wcXJzdHV2MDEyMzQ1Njc4OWFiY2RlZmdoaWprbG1ub3BxcnN0dXYwMTIzNDU2Nzg5YWJjZGVmZ2hpamtsbW5vcHFyc3R1djAxMjM0NTY3ODlhYmNkZWZnaGlqa2xtbm9wcXJzdHV2MDEyMzQ1Njc4OWFiY2RlZmdoaWprbG1ub3BxcnN0dXYwMTIzNDU2Nzg5YWJjZGVmZ2hpamtsbW5vcHFyc3R1djAxMjM0NTY3ODlhYmNkZWZnaGlqa2xtbm9wcXJzdHV2gICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAAAECAwQFBgcICYCAgICAgIAKCwwNDg8QERITFBUWFxgZGhscHR4fgICAgICAgICAgAoLDA0ODxAREhMUFRYXGBkaGxwdHh+AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIAdAAAAAAAAbCsBAAICAAAwMTIzNDU2Nzg5QUJDREVGR0hJSktMTU5PUFFSU1RVVjAxMjM0NTY3ODlBQkNERUZHSElKS0xNTk9QUVJTVFVWMDEyMzQ1Njc4OUFCQ0RFRkdISUpLTE1OT1BRUlNUVVYwMTIzNDU2Nzg5QUJDREVGR0hJSktMTU5PUFFSU1RVVjAxMjM0NTY3ODlBQkNERUZHSElKS0xNTk9QUVJTVFVWMDEyMzQ1Njc4OUFCQ0RFRkdISUpLTE1OT1BRUlNUVVYwMTIzNDU2Nzg5QUJDREVGR0hJSktMTU5PUFFSU1RVVjAxMjM0NTY3ODlBQkNERUZHSElKS0xNTk9QUVJTVFVWgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAAAECAwQFBgcICYCAgICAgIAKCwwNDg8QERITFBUWFxgZGhscHR4fgICAgICAgICAgAoLDA0ODxAREhMUFRYXGBkaGxwdHh+AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIAdAAAAAAAAfC0BAAICAABhYmNkZWZnaGlqa2xtbm9wcXJzdHV2d3h5ejIzNDU2N2FiY2RlZmdoaWprbG1ub3BxcnN0dXZ3eHl6MjM0NTY3YWJjZGVmZ2hpamtsbW5vcHFyc3R1dnd4eXoyMzQ1NjdhYmNkZWZnaGlqa2xtbm9wcXJzdHV2d3h5ejIzNDU2N2FiY2RlZmdoaWprbG1ub3BxcnN0dXZ3eHl6MjM0NTY3YWJjZGVmZ2hpamtsbW5vcHFyc3R1dnd4eXoyMzQ1NjdhYmNkZWZnaGlqa2xtbm9wcXJzdHV2d3h5ejIzNDU2N2FiY2RlZmdoaWprbG1ub3BxcnN0dXZ3eHl6MjM0NTY3gICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIAaGxwdHh+AgICAgIKAgIAAAQIDBAUGBwgJCgsMDQ4PEBESExQVFhcYGYCAgICAgAABAgMEBQYHCAkKCwwNDg8QERITFBUWFxgZgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgD0dAAAAAAAAjC8BAAICAABBQkNERUZHSElKS0xNTk9QUVJTVFVWV1hZWjIzNDU2N0FCQ0RFRkdISUpLTE1OT1BRUlNUVVZXWFlaMjM0NTY3QUJDREVGR0hJSktMTU5PUFFSU1RVVldYWVoyMzQ1NjdBQkNERUZHSElKS0xNTk9QUVJTVFVWV1hZWjIzNDU2N0FCQ0RFRkdISUpLTE1OT1BRUlNUVVZXWFlaMjM0NTY3QUJDREVGR0hJSktMTU5PUFFSU1RVVldYWVoyMzQ1NjdBQkNERUZHSElKS0xNTk9QUVJTVFVWV1hZWjIzNDU2N0FCQ0RFRkdISUpLTE1OT1BRUlNUVVZXWFlaMjM0NTY3gICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIAaGxwdHh+AgICAgIKAgIAAAQIDBAUGBwgJCgsMDQ4PEBESExQVFhcYGYCAgICAgAABAgMEBQYHCAkKCwwNDg8QERITFBUWFxgZgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgD0dAAAAAAAAnDEBAAICAAAwMTIzNDU2Nzg5YWJjZGVmZ2hpamtsbW5vcHFyc3R1djAxMjM0NTY3ODlhYmNkZWZnaGlqa2xtbm9wcXJzdHV2MDEyMzQ1Njc4OWFiY2RlZmdoaWprbG1ub3BxcnN0dXYwMTIzNDU2Nzg5YWJjZGVmZ2hpamtsbW5vcHFyc3R1djAxMjM0NTY3ODlhYmNkZWZnaGlqa2xtbm9wcXJzdHV2MDEyMzQ1Njc4OWFiY2RlZmdoaWprbG1ub3BxcnN0dXYwMTIzNDU2Nzg5YWJjZGVmZ2hpamtsbW5vcHFyc3R1djAxMjM0NTY3ODlhYmNkZWZnaGlqa2xtbm9wcXJzdHV2gICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAAAECAwQFBgcICYCAgIKAgIAKCwwNDg8QERITFBUWFxgZGhscHR4fgICAgICAgICAgAoLDA0ODxAREhMUFRYXGBkaGxwdHh+AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgD0dAAAAAAAArDMBAAICAAAwMTIzNDU2Nzg5QUJDREVGR0hJSktMTU5PUFFSU1RVVjAxMjM0NTY3ODlBQkNERUZHSElKS0xNTk9QUVJTVFVWMDEyMzQ1Njc4OUFCQ0RFRkdISUpLTE1OT1BRUlNUVVYwMTIzNDU2Nzg5QUJDREVGR0hJSktMTU5PUFFSU1RVVjAxMjM0NTY3ODlBQkNERUZHSElKS0xNTk9QUVJTVFVWMDEyMzQ1Njc4OUFCQ0RFRkdISUpLTE1OT1BRUlNUVVYwMTIzNDU2Nzg5QUJDREVGR0hJSktMTU5PUFFSU1RVVjAxMjM0NTY3ODlBQkNERUZHSElKS0xNTk9QUVJTVFVWgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAAAECAwQFBgcICYCAgIKAgIAKCwwNDg8QERITFBUWFxgZGhscHR4fgICAgICAgICAgAoLDA0ODxAREhMUFRYXGBkaGxwdHh+AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgD0dAAAAAAAAvDUBAAICAAATAAAADAAAAAQAAAAUAAAAFQAAABYAAAAXAAAAAAAAAAEAAAAYAAAAKQAAABAAAAAEAAAAKgAAACkAAAAQAAAABAAAACsAAAAvdXNyL2xvY2FsL2NhcmdvL3JlZ2lzdHJ5L3NyYy9naXRodWIuY29tLTFlY2M2Mjk5ZGI5ZWM4MjMvYmFzZS14LTAuMi4xMS9zcmMvZGVjb2Rlci5ycwAAFDgBAFYAAAAoAAAAFgAAAHNyYy93cmFwL21vZHVsZS93cmFwcGVkLnJzAAB8OAEAGgAAABcAAAAtAAAAfDgBABoAAAAjAAAADQAAAHw4AQAaAAAAIAAAADcAAAB8OAEAGgAAAC0AAAAtAAAAfDgBABoAAAA4AAAADQAAAHw4AQAaAAAANQAAADAAAABzcmMvdXRpbC9leGVjX2NhdC5yc/g4AQAUAAAAHwAAAAwAAAD4OAEAFAAAACYAAABIAAAAUmVjZWl2ZWQgZW1wdHkgcmVzdWx0IGZyb20gY29uY3VycmVudCB0YXNrdGFza0lkaTMyc3RhdHVzQ29uY3VycmVudFRhc2tTdGF0dXN0YXNrSWQ6IEludC5zdGF0dXM6IENvbmN1cnJlbnRfVGFza1N0YXR1cy5TZXJpYWxpemluZyAoZW5jb2RpbmcpIGltcG9ydGVkIG1vZHVsZS10eXBlOiBjYXQgQXJnc2NpZGlwZnNQcm92aWRlcmNhdE9wdGlvbnNPcHRpb248Q2xpZW50Q2F0T3B0aW9ucz5EZXNlcmlhbGl6aW5nIGltcG9ydGVkIG1vZHVsZS10eXBlOiBjYXQgUmVzdWx0bWV0aG9kYXJnc1ZlYzx1OD5JcGZzIHVyaSByZXNvbHZlciByZXF1aXJlcyBhIGNvbmZpZ3VyZWQgRW52c3JjL2xpYi5ycwAAAHM6AQAKAAAACAAAABMAAAAvd3JhcC5pbmZvAADoUAEAAAAAAJA6AQAKAAAAczoBAAoAAAAbAAAAEwAAAERlc2VyaWFsaXppbmcgZW52LXR5cGU6IEVudnJldHJpZXNPcHRpb248UmV0cmllcz50eXBlIGZvdW5kLCByZWFkaW5nIHByb3BlcnR5dGltZW91dE9wdGlvbjx1MzI+ZmFsbGJhY2tQcm92aWRlcnNPcHRpb248VmVjPFN0cmluZz4+ZGlzYWJsZVBhcmFsbGVsUmVxdWVzdHNPcHRpb248Ym9vbD5wcm92aWRlcjogU3RyaW5nLgATAAAADAAAAAQAAAAUAAAAFQAAABYAAAAXAAAAAAAAAAEAAAAYAAAAc3JjL3V0aWwvZXhlYy5yc1BhcmFsbGVsIGV4ZWN1dGlvbiBpcyBub3QgYXZhaWxhYmxlLiBFeGVjdXRpbmcgc2VxdWVudGlhbGx5IGluc3RlYWQuIFBhcmFsbGVsIGV4ZWN1dGlvbiByZXF1aXJlcyBhbiBpbXBsZW1lbnRhdGlvbiBvZiB0aGUgQ29uY3VycmVudCBpbnRlcmZhY2UuIFlvdSBjYW4gZGVjbGFyZSBhbiBpbnRlcmZhY2UgaW1wbGVtZW50YXRpb24gaW4geW91ciBQb2x5d3JhcCBDbGllbnQgY29uZmlndXJhdGlvbi4KAKw7AQDjAAAAnDsBABAAAABHAAAAJgAAAEFuIGVycm9yIG9jY3VycmVkCk9wZXJhdGlvbjogY2F0ClByb3ZpZGVyOiAKVGltZW91dDogCkVycm9yOiAAAACoPAEAKwAAANM8AQAKAAAA3TwBAAgAAABFeGVjdXRpb24gcmVxdWlyZXMgYW4gaW1wbGVtZW50YXRpb24gb2YgdGhlIElQRlMgSFRUUCBDbGllbnQgaW50ZXJmYWNlLiBZb3UgY2FuIGRlY2xhcmUgYW4gaW50ZXJmYWNlIGltcGxlbWVudGF0aW9uIGluIHlvdXIgUG9seXdyYXAgQ2xpZW50IGNvbmZpZ3VyYXRpb24uAAAAPQEAmgAAAJw7AQAQAAAAWAAAAAkAAABEZXNlcmlhbGl6aW5nIG1vZHVsZS10eXBlOiB0cnlfcmVzb2x2ZV91cmkgQXJnc2F1dGhvcml0eVN0cmluZ3R5cGUgZm91bmQsIHJlYWRpbmcgYXJndW1lbnRhdXRob3JpdHk6IFN0cmluZy5wYXRoOiBTdHJpbmcuU2VyaWFsaXppbmcgKGVuY29kaW5nKSBtb2R1bGUtdHlwZTogdHJ5X3Jlc29sdmVfdXJpIFJlc3VsdHRyeVJlc29sdmVVcmlPcHRpb248VXJpUmVzb2x2ZXJNYXliZVVyaU9yTWFuaWZlc3Q+d3JpdGluZyByZXN1bHREZXNlcmlhbGl6aW5nIG1vZHVsZS10eXBlOiBnZXRfZmlsZSBBcmdzU2VyaWFsaXppbmcgKGVuY29kaW5nKSBtb2R1bGUtdHlwZTogZ2V0X2ZpbGUgUmVzdWx0Z2V0RmlsZS91c3IvbG9jYWwvY2FyZ28vcmVnaXN0cnkvc3JjL2dpdGh1Yi5jb20tMWVjYzYyOTlkYjllYzgyMy9jaWQtMC44LjYvc3JjL2NpZC5ycwASAAAAAAAAACAAAAAAAAAAL3J1c3RjLzkwNzQzZTcyOThhY2ExMDdkZGFhMGMyMDJhNGQzNjA0ZTI5YmZlYjYvbGlicmFyeS9jb3JlL3NyYy9zdHIvcGF0dGVybi5ycwBoPwEATwAAAIwFAAAhAAAAaD8BAE8AAACYBQAAFAAAAGg/AQBPAAAAmAUAACEAAAAJPwEATgAAAC4BAAAdAAAAdXJpT3B0aW9uPFN0cmluZz5tYW5pZmVzdE9wdGlvbjxWZWM8dTg+Pm9mZnNldE9wdGlvbjxpMzI+bGVuZ3RoABMAAAAMAAAABAAAABQAAAAVAAAAFgAAABcAAAAAAAAAAQAAABgAAABjYXQvdXNyL2xvY2FsL2NhcmdvL3JlZ2lzdHJ5L3NyYy9naXRodWIuY29tLTFlY2M2Mjk5ZGI5ZWM4MjMvdW5zaWduZWQtdmFyaW50LTAuNy4xL3NyYy9kZWNvZGUucnNjQAEAXQAAAHsAAAAFAAAAEwAAAAwAAAAEAAAAFAAAABUAAAAWAAAAFwAAAAAAAAABAAAAGAAAAEludmFsaWQgdmFsdWUgZm9yIGVudW0gJ0NvbmN1cnJlbnRUYXNrU3RhdHVzJzogAPhAAQAvAAAAUEVORElOR1JVTk5JTkdDT01QTEVURURDQU5DRUxMRURGQUlMRURfTUFYX0ludmFsaWQga2V5IGZvciBlbnVtICdDb25jdXJyZW50VGFza1N0YXR1cyc6IFtBAQAtAAAAU2VyaWFsaXppbmcgKGVuY29kaW5nKSBpbXBvcnRlZCBtb2R1bGUtdHlwZTogcmVzdWx0IEFyZ3N0YXNrSWRzVmVjPGkzMj53cml0aW5nIHByb3BlcnR5cmV0dXJuV2hlbkNvbmN1cnJlbnRSZXR1cm5XaGVuRGVzZXJpYWxpemluZyBpbXBvcnRlZCBtb2R1bGUtdHlwZTogcmVzdWx0IFJlc3VsdHJlc3VsdFZlYzxDb25jdXJyZW50VGFza1Jlc3VsdD5yZWFkaW5nIGZ1bmN0aW9uIG91dHB1dFNlcmlhbGl6aW5nIChlbmNvZGluZykgaW1wb3J0ZWQgbW9kdWxlLXR5cGU6IHNjaGVkdWxlIEFyZ3N0YXNrc1ZlYzxDb25jdXJyZW50VGFzaz5EZXNlcmlhbGl6aW5nIGltcG9ydGVkIG1vZHVsZS10eXBlOiBzY2hlZHVsZSBSZXN1bHRzY2hlZHVsZQAAABMAAAAMAAAABAAAACwAAAAtAAAAFgAAABcAAAAAAAAAAQAAABgAAAATAAAADAAAAAQAAAAuAAAARmFpbGVkIHRvIGNyZWF0ZSBuZXcgZGF0YSB2aWV3L3Vzci9sb2NhbC9jYXJnby9yZWdpc3RyeS9zcmMvZ2l0aHViLmNvbS0xZWNjNjI5OWRiOWVjODIzL3BvbHl3cmFwLXdhc20tcnMtMC4xMC4wLXByZS44L3NyYy9tc2dwYWNrL3JlYWRfZGVjb2Rlci5ycwAAAFJDAQBzAAAAFAAAAC8AAABQcm9wZXJ0eSBtdXN0IGJlIG9mIHR5cGUgJ2ludCcuINhDAQAgAAAAaW50ZWdlciBvdmVyZmxvdzogdmFsdWUgPSA7IGJpdHMgPSA2NAAAAABEAQAaAAAAGkQBAAsAAAB1bnNpZ25lZCBpbnRlZ2VyIGNhbm5vdCBiZSBuZWdhdGl2ZS4gAAAAOEQBACUAAABQcm9wZXJ0eSBtdXN0IGJlIG9mIHR5cGUgJ3VpbnQnLiAAAABoRAEAIQAAAFByb3BlcnR5IG11c3QgYmUgb2YgdHlwZSAnYm9vbCcuIAAAAJREAQAhAAAAOyBiaXRzID0gMzIAAEQBABoAAADARAEACwAAAHVuc2lnbmVkIGludGVnZXIgb3ZlcmZsb3c6IHZhbHVlID0gANxEAQAjAAAAwEQBAAsAAABQcm9wZXJ0eSBtdXN0IGJlIG9mIHR5cGUgJ3N0cmluZycuIAAQRQEAIwAAAFByb3BlcnR5IG11c3QgYmUgb2YgdHlwZSAnYnl0ZXMnLiAAADxFAQAiAAAAUHJvcGVydHkgbXVzdCBiZSBvZiB0eXBlICdhcnJheScuIAAAaEUBACIAAABhcnJheVtdUHJvcGVydHkgbXVzdCBiZSBvZiB0eXBlICdtYXAnLiAAm0UBACAAAAATAAAADAAAAAQAAAAsAAAALQAAABYAAAAXAAAAAAAAAAEAAAAYAAAARm91bmQgJ0ZJWEVYVDE2Jy5Gb3VuZCAnRklYRVhUOCcuRm91bmQgJ0ZJWEVYVDQnLkZvdW5kICdGSVhFWFQyJy5Gb3VuZCAnRklYRVhUMScuRm91bmQgJ2ludDY0Jy5Gb3VuZCAnaW50MzInLkZvdW5kICdpbnQxNicuRm91bmQgJ2ludDgnLkZvdW5kICd1aW50NjQnLkZvdW5kICd1aW50MzInLkZvdW5kICd1aW50MTYnLkZvdW5kICd1aW50OCcuRm91bmQgJ2Zsb2F0NjQnLkZvdW5kICdmbG9hdDMyJy5Gb3VuZCAnRVhUMzInLkZvdW5kICdFWFQxNicuRm91bmQgJ0VYVDgnLkZvdW5kICdCSU4zMicuRm91bmQgJ0JJTjE2Jy5Gb3VuZCAnQklOOCcuRm91bmQgJ2Jvb2wnLkZvdW5kICdyZXNlcnZlZCcuRm91bmQgJ25pbCcuRm91bmQgJ3N0cmluZycuRm91bmQgJ2FycmF5Jy5Gb3VuZCAnbWFwJy5Gb3VuZCAnaW50Jy5JT0Vycm9yABcAAAAEAAAABAAAAC8AAABUeXBlV3JpdGVFcnJvclN0cldyaXRlRXJyb3JJbnQzMldyaXRlRXJyb3JJbnQxNldyaXRlRXJyb3JJbnQ4V3JpdGVFcnJvclVpbnQzMldyaXRlRXJyb3JVaW50MTZXcml0ZUVycm9yVWludDhXcml0ZUVycm9yRmxvYXQ2NFdyaXRlRXJyb3JGbG9hdDMyV3JpdGVFcnJvckpTT05Xcml0ZUVycm9yQmlnSW50V3JpdGVFcnJvckJpbldyaXRlRXJyb3JCb29sZWFuV3JpdGVFcnJvckZvcm1hdFdyaXRlRXJyb3JOaWxXcml0ZUVycm9yVHlwZVJlYWRFcnJvck1pc3NpbmdGaWVsZFdyb25nTXNnUGFja0Zvcm1hdFVua25vd25GaWVsZE5hbWVFbnVtUmVhZEVycm9yU3RyUmVhZEVycm9yRXh0R2VuZXJpY01hcFJlYWRFcnJvck1hcFJlYWRFcnJvckFycmF5UmVhZEVycm9ySW50UmFuZ2VFcnJvckpTT05SZWFkRXJyb3JCaWdOdW1iZXJSZWFkRXJyb3JCaWdJbnRSZWFkRXJyb3JGbG9hdFJlYWRFcnJvclVpbnRSZWFkRXJyb3JJbnRSZWFkRXJyb3JQYXJzZUJpZ051bWJlckVycm9yUGFyc2VCaWdJbnRFcnJvckJ5dGVzUmVhZEVycm9yQm9vbGVhblJlYWRFcnJvckZvdW5kTmlsQnV0RXhwZWN0ZWRNaXNzaW5nIHJlcXVpcmVkIGZpZWxkOiAnJwAAAMNJAQAZAAAA3EkBAAEAAABVbmtub3duRmllbGROYW1lOiAnAPBJAQATAAAA3EkBAAEAAABGb3VuZCBOSUwsIGJ1dCBleHBlY3RlZDogJwAAFEoBABoAAADcSQEAAQAAACkAAAAQAAAABAAAACoAAABfX3dyYXBfZ2V0SW1wbGVtZW50YXRpb25zX3Jlc3VsdFZlYzxTdHJpbmc+X193cmFwX2dldEltcGxlbWVudGF0aW9ucyBzdWNjZXNzZnVsL3Vzci9sb2NhbC9jYXJnby9yZWdpc3RyeS9zcmMvZ2l0aHViLmNvbS0xZWNjNjI5OWRiOWVjODIzL3BvbHl3cmFwLXdhc20tcnMtMC4xMC4wLXByZS44L3NyYy9nZXRfaW1wbGVtZW50YXRpb25zLnJzAAAAn0oBAHIAAAAlAAAANwAAAHVua25vd24gZXJyb3JDb3VsZCBub3QgZmluZCBpbnZva2UgZnVuY3Rpb24gMUsBAB8AAAATAAAADAAAAAQAAAAsAAAALQAAABYAAABhIERpc3BsYXkgaW1wbGVtZW50YXRpb24gcmV0dXJuZWQgYW4gZXJyb3IgdW5leHBlY3RlZGx5ABcAAAAAAAAAAQAAABgAAAAvcnVzdGMvOTA3NDNlNzI5OGFjYTEwN2RkYWEwYzIwMmE0ZDM2MDRlMjliZmViNi9saWJyYXJ5L2FsbG9jL3NyYy9zdHJpbmcucnMAuEsBAEsAAADoCQAACQAAAC9ydXN0Yy85MDc0M2U3Mjk4YWNhMTA3ZGRhYTBjMjAyYTRkMzYwNGUyOWJmZWI2L2xpYnJhcnkvc3RkL3NyYy9pby9tb2QucnMAAAAUTAEASQAAAMcBAAAcAAAAZmFpbGVkIHRvIGZpbGwgd2hvbGUgYnVmZmVyAHBMAQAbAAAAJQAAABRMAQBJAAAAhwEAABsAAAAUTAEASQAAAJYBAAAwAAAAL3J1c3RjLzkwNzQzZTcyOThhY2ExMDdkZGFhMGMyMDJhNGQzNjA0ZTI5YmZlYjYvbGlicmFyeS9zdGQvc3JjL2lvL3JlYWRidWYucnMAAAC4TAEATQAAAP0AAAAWAAAAuEwBAE0AAADTAAAANQAAALhMAQBNAAAAywAAADYAAABudW1iZXIgb2YgcmVhZCBieXRlcyBleGNlZWRzIGxpbWl0AAA4TQEAIgAAABRMAQBJAAAAHgoAAAkAAAAUTAEASQAAADAKAAAmAAAAYXR0ZW1wdCB0byBqb2luIGludG8gY29sbGVjdGlvbiB3aXRoIGxlbiA+IHVzaXplOjpNQVgvcnVzdGMvOTA3NDNlNzI5OGFjYTEwN2RkYWEwYzIwMmE0ZDM2MDRlMjliZmViNi9saWJyYXJ5L2FsbG9jL3NyYy9zdHIucnMAAAC5TQEASAAAALAAAAAWAAAAuU0BAEgAAACUAAAAGAAAAGNvbnRleHQgZGVzY3JpcHRpb24gbm90IHNldEVycm9yOiB0cmllZCB0byBwb3AgYW4gaXRlbSBmcm9tIGFuIGVtcHR5IENvbnRleHQgc3RhY2sAAD9OAQA3AAAAL3Vzci9sb2NhbC9jYXJnby9yZWdpc3RyeS9zcmMvZ2l0aHViLmNvbS0xZWNjNjI5OWRiOWVjODIzL3BvbHl3cmFwLXdhc20tcnMtMC4xMC4wLXByZS44L3NyYy9kZWJ1Zy9jb250ZXh0LnJzgE4BAGwAAAArAAAADQAAAIBOAQBsAAAALQAAACUAAAAgPj4gDE8BAAQAAADoUAEAAAAAALNVAQACAAAA6FABAAAAAAAKIAAAMU8BAAEAAAAAAAAAIAAAAAAAAAACAAAAAAAAAAEAAAABAAAAAwAAADBPAQABAAAAQ29udGV4dDogY29udGV4dCBzdGFjayBpcyBlbXB0eWF0ICA6IAAAAINPAQADAAAAhk8BAAMAAAAMTwEABAAAAC9ydXN0Yy85MDc0M2U3Mjk4YWNhMTA3ZGRhYTBjMjAyYTRkMzYwNGUyOWJmZWI2L2xpYnJhcnkvc3RkL3NyYy9pby9pbXBscy5ycwCkTwEASwAAAPIAAAANAAAAL3J1c3RjLzkwNzQzZTcyOThhY2ExMDdkZGFhMGMyMDJhNGQzNjA0ZTI5YmZlYjYvbGlicmFyeS9zdGQvc3JjL2lvL2N1cnNvci5ycwBQAQBMAAAA6wAAAAoAAABjdXJzb3IgcG9zaXRpb24gZXhjZWVkcyBtYXhpbXVtIHBvc3NpYmxlIHZlY3RvciBsZW5ndGgAAFxQAQA2AAAAFAAAABcAAAAEAAAABAAAADAAAAAxAAAAMgAAABkAAAAEAAAABAAAADMAAAA0AAAANQAAABkAAAAEAAAABAAAADYAAAA3AAAAOAAAAGFscmVhZHkgYm9ycm93ZWQZAAAAAAAAAAEAAAA5AAAAAGFzc2VydGlvbiBmYWlsZWQ6IG1pZCA8PSBzZWxmLmxlbigpGQAAAAQAAAAEAAAAOgAAABkAAAAEAAAABAAAADsAAABjYWxsZWQgYE9wdGlvbjo6dW53cmFwKClgIG9uIGEgYE5vbmVgIHZhbHVlY2FsbGVkIGBSZXN1bHQ6OnVud3JhcCgpYCBvbiBhbiBgRXJyYCB2YWx1ZWxpYnJhcnkvc3RkL3NyYy90aHJlYWQvbW9kLnJzZmFpbGVkIHRvIGdlbmVyYXRlIHVuaXF1ZSB0aHJlYWQgSUQ6IGJpdHNwYWNlIGV4aGF1c3RlZAAAv1EBADcAAACiUQEAHQAAAFYEAAANAAAA6FABAAAAAABsaWJyYXJ5L3N0ZC9zcmMvaW8vYnVmZmVyZWQvbGluZXdyaXRlcnNoaW0ucnMAAAAYUgEALQAAAAEBAAApAAAAdW5jYXRlZ29yaXplZCBlcnJvcm90aGVyIGVycm9yb3V0IG9mIG1lbW9yeXVuZXhwZWN0ZWQgZW5kIG9mIGZpbGV1bnN1cHBvcnRlZG9wZXJhdGlvbiBpbnRlcnJ1cHRlZGFyZ3VtZW50IGxpc3QgdG9vIGxvbmdpbnZhbGlkIGZpbGVuYW1ldG9vIG1hbnkgbGlua3Njcm9zcy1kZXZpY2UgbGluayBvciByZW5hbWVkZWFkbG9ja2V4ZWN1dGFibGUgZmlsZSBidXN5cmVzb3VyY2UgYnVzeWZpbGUgdG9vIGxhcmdlZmlsZXN5c3RlbSBxdW90YSBleGNlZWRlZHNlZWsgb24gdW5zZWVrYWJsZSBmaWxlbm8gc3RvcmFnZSBzcGFjZXdyaXRlIHplcm90aW1lZCBvdXRpbnZhbGlkIGRhdGFpbnZhbGlkIGlucHV0IHBhcmFtZXRlcnN0YWxlIG5ldHdvcmsgZmlsZSBoYW5kbGVmaWxlc3lzdGVtIGxvb3Agb3IgaW5kaXJlY3Rpb24gbGltaXQgKGUuZy4gc3ltbGluayBsb29wKXJlYWQtb25seSBmaWxlc3lzdGVtIG9yIHN0b3JhZ2UgbWVkaXVtZGlyZWN0b3J5IG5vdCBlbXB0eWlzIGEgZGlyZWN0b3J5bm90IGEgZGlyZWN0b3J5b3BlcmF0aW9uIHdvdWxkIGJsb2NrZW50aXR5IGFscmVhZHkgZXhpc3RzYnJva2VuIHBpcGVuZXR3b3JrIGRvd25hZGRyZXNzIG5vdCBhdmFpbGFibGVhZGRyZXNzIGluIHVzZW5vdCBjb25uZWN0ZWRjb25uZWN0aW9uIGFib3J0ZWRuZXR3b3JrIHVucmVhY2hhYmxlaG9zdCB1bnJlYWNoYWJsZWNvbm5lY3Rpb24gcmVzZXRjb25uZWN0aW9uIHJlZnVzZWRwZXJtaXNzaW9uIGRlbmllZGVudGl0eSBub3QgZm91bmRFcnJvcmtpbmQgKG9zIGVycm9yICkAAOhQAQAAAAAATlUBAAsAAABZVQEAAQAAAGxpYnJhcnkvc3RkL3NyYy9pby9zdGRpby5ycwB0VQEAGwAAAOACAAAUAAAAZmFpbGVkIHByaW50aW5nIHRvIDogAAAAoFUBABMAAACzVQEAAgAAAHRVAQAbAAAA8QMAAAkAAABzdGRvdXRmb3JtYXR0ZXIgZXJyb3IAAADeVQEADwAAACgAAAA8AAAADAAAAAQAAAA9AAAAPgAAAD8AAABsaWJyYXJ5L3N0ZC9zcmMvc3luYy9vbmNlLnJzFFYBABwAAADKAAAAFAAAABRWAQAcAAAAygAAACgAAABQb2lzb25FcnJvcmxvY2sgY291bnQgb3ZlcmZsb3cgaW4gcmVlbnRyYW50IG11dGV4bGlicmFyeS9zdGQvc3JjL3N5c19jb21tb24vcmVtdXRleC5ycwAAgVYBACUAAACPAAAAIgAAAGxpYnJhcnkvc3RkL3NyYy9zeXNfY29tbW9uL3RocmVhZF9pbmZvLnJzAAAAuFYBACkAAAAWAAAAMwAAAGNhbm5vdCBtb2RpZnkgdGhlIHBhbmljIGhvb2sgZnJvbSBhIHBhbmlja2luZyB0aHJlYWT0VgEANAAAAGxpYnJhcnkvc3RkL3NyYy9wYW5pY2tpbmcucnMwVwEAHAAAAIYAAAAJAAAAMFcBABwAAAA+AgAADwAAAEAAAAAMAAAABAAAAEEAAAAZAAAACAAAAAQAAABCAAAAQwAAABAAAAAEAAAARAAAAEUAAAAZAAAACAAAAAQAAABGAAAARwAAAGVycm9yb3BlcmF0aW9uIHN1Y2Nlc3NmdWxjb25kdmFyIHdhaXQgbm90IHN1cHBvcnRlZADNVwEAGgAAAGxpYnJhcnkvc3RkL3NyYy9zeXMvd2FzbS8uLi91bnN1cHBvcnRlZC9sb2Nrcy9jb25kdmFyLnJz8FcBADgAAAAWAAAACQAAAGNhbm5vdCByZWN1cnNpdmVseSBhY3F1aXJlIG11dGV4OFgBACAAAABsaWJyYXJ5L3N0ZC9zcmMvc3lzL3dhc20vLi4vdW5zdXBwb3J0ZWQvbG9ja3MvbXV0ZXgucnMAAGBYAQA2AAAAFgAAAAkAAABhc3NlcnRpb24gZmFpbGVkOiBzdGF0ZV9hbmRfcXVldWUuYWRkcigpICYgU1RBVEVfTUFTSyA9PSBSVU5OSU5HAgAAAGxpYnJhcnkvc3RkL3NyYy9zeXNfY29tbW9uL29uY2UvZ2VuZXJpYy5ycwAA7FgBACoAAAD4AAAACQAAAOxYAQAqAAAABQEAAB4AAABIAAAACAAAAAQAAABJAAAAbGlicmFyeS9zdGQvc3JjL3N5c19jb21tb24vdGhyZWFkX3Bhcmtlci9nZW5lcmljLnJzAEhZAQAzAAAAJwAAABUAAABpbmNvbnNpc3RlbnQgcGFyayBzdGF0ZQCMWQEAFwAAAEhZAQAzAAAANQAAABcAAABwYXJrIHN0YXRlIGNoYW5nZWQgdW5leHBlY3RlZGx5ALxZAQAfAAAASFkBADMAAAAyAAAAEQAAAGluY29uc2lzdGVudCBzdGF0ZSBpbiB1bnBhcmv0WQEAHAAAAEhZAQAzAAAAbAAAABIAAABIWQEAMwAAAHoAAAAOAAAADgAAABAAAAAWAAAAFQAAAAsAAAAWAAAADQAAAAsAAAATAAAAEAAAABAAAAAQAAAAEAAAABAAAAAQAAAAEAAAABAAAAAQAAAAEAAAABAAAAAQAAAAEAAAABAAAAAQAAAAEAAAABAAAAAQAAAAEAAAABAAAAAQAAAAEAAAABAAAAAQAAAAEQAAABIAAAAQAAAAEAAAABMAAAASAAAADQAAAA4AAAAVAAAADAAAAAsAAAAVAAAAFQAAAA8AAAAOAAAAEwAAACYAAAA4AAAAGQAAABcAAAAMAAAACQAAAAoAAAAQAAAAFwAAABkAAAAOAAAADQAAABQAAAAIAAAAGwAAAN9SAQDPUgEAuVIBAKRSAQCZUgEAg1IBAHZSAQBrUgEAWFIBADVVAQA1VQEANVUBADVVAQA1VQEANVUBADVVAQA1VQEANVUBADVVAQA1VQEANVUBADVVAQA1VQEANVUBADVVAQA1VQEANVUBADVVAQA1VQEANVUBADVVAQA1VQEANVUBACRVAQASVQEAAlUBAPJUAQDfVAEAzVQBAMBUAQCyVAEAnVQBAJFUAQCGVAEAcVQBAFxUAQBNVAEAP1QBACxUAQAGVAEAzlMBALVTAQCeUwEAklMBAIlTAQB/UwEAb1MBAFhTAQA/UwEAMVMBACRTAQAQUwEACFMBAO1SAQAAQbi4BQuUBAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAfwlwcm9kdWNlcnMCCGxhbmd1YWdlAQRSdXN0AAxwcm9jZXNzZWQtYnkEBXJ1c3RjHTEuNjYuMSAoOTA3NDNlNzI5IDIwMjMtMDEtMTApBndhbHJ1cwYwLjEyLjAMd2FzbS1iaW5kZ2VuBjAuMi44NAl3YXNtLXNuaXAFMC40LjA="
-  )
-}
-
-fn wrap_info() -> Result<Vec<u8>, DecodeError> {
-  decode(
-    "hKd2ZXJzaW9uozAuMaRuYW1lumlwZnMtdXJpLXJlc29sdmVyLXJzLWFzeW5jpHR5cGWkd2FzbaNhYmmIp3ZlcnNpb26jMC4xq29iamVjdFR5cGVzkYSkdHlwZadSZXRyaWVzp2NvbW1lbnTZR051bWJlciBvZiB0aW1lcyB0byByZXRyeSByZXF1ZXN0IG9uIGZhaWx1cmUgKGV4Y2x1ZGluZyBpbml0aWFsIHJlcXVlc3QppGtpbmQBqnByb3BlcnRpZXOShKR0eXBlplVJbnQzMqRuYW1lrXRyeVJlc29sdmVVcmmka2luZCKmc2NhbGFyg6RuYW1lrXRyeVJlc29sdmVVcmmkdHlwZaZVSW50MzKka2luZASEpHR5cGWmVUludDMypG5hbWWnZ2V0RmlsZaRraW5kIqZzY2FsYXKDpG5hbWWnZ2V0RmlsZaR0eXBlplVJbnQzMqRraW5kBKptb2R1bGVUeXBlhadpbXBvcnRz3AAQgaR0eXBlslVyaVJlc29sdmVyX01vZHVsZYGkdHlwZb5VcmlSZXNvbHZlcl9NYXliZVVyaU9yTWFuaWZlc3SBpHR5cGWtQ2xpZW50X01vZHVsZYGkdHlwZbFDbGllbnRfQ2F0T3B0aW9uc4GkdHlwZbVDbGllbnRfUmVzb2x2ZU9wdGlvbnOBpHR5cGW0Q2xpZW50X1Jlc29sdmVSZXN1bHSBpHR5cGWwQ2xpZW50X0ZpbGVFbnRyeYGkdHlwZbFDbGllbnRfQWRkT3B0aW9uc4GkdHlwZbBDbGllbnRfQWRkUmVzdWx0gaR0eXBltUNsaWVudF9EaXJlY3RvcnlFbnRyeYGkdHlwZatDbGllbnRfQmxvYoGkdHlwZbFDb25jdXJyZW50X01vZHVsZYGkdHlwZbVDb25jdXJyZW50X1JldHVybldoZW6BpHR5cGW1Q29uY3VycmVudF9UYXNrUmVzdWx0gaR0eXBltUNvbmN1cnJlbnRfVGFza1N0YXR1c4GkdHlwZa9Db25jdXJyZW50X1Rhc2uqaW50ZXJmYWNlc5GCpHR5cGWyVXJpUmVzb2x2ZXJfTW9kdWxlpGtpbmTNCACkdHlwZaZNb2R1bGWka2luZMyAp21ldGhvZHOSh6RuYW1lrXRyeVJlc29sdmVVcmmmcmV0dXJuhKR0eXBlvlVyaVJlc29sdmVyX01heWJlVXJpT3JNYW5pZmVzdKRuYW1lrXRyeVJlc29sdmVVcmmka2luZCKmb2JqZWN0g6RuYW1lrXRyeVJlc29sdmVVcmmkdHlwZb5VcmlSZXNvbHZlcl9NYXliZVVyaU9yTWFuaWZlc3Ska2luZM0gAKR0eXBlpk1ldGhvZKRraW5kQKhyZXF1aXJlZMOjZW52gahyZXF1aXJlZMKpYXJndW1lbnRzkoWkdHlwZaZTdHJpbmekbmFtZalhdXRob3JpdHmocmVxdWlyZWTDpGtpbmQipnNjYWxhcoSkbmFtZalhdXRob3JpdHmkdHlwZaZTdHJpbmeocmVxdWlyZWTDpGtpbmQEhaR0eXBlplN0cmluZ6RuYW1lpHBhdGiocmVxdWlyZWTDpGtpbmQipnNjYWxhcoSkbmFtZaRwYXRopHR5cGWmU3RyaW5nqHJlcXVpcmVkw6RraW5kBIekbmFtZadnZXRGaWxlpnJldHVyboSkdHlwZaVCeXRlc6RuYW1lp2dldEZpbGWka2luZCKmc2NhbGFyg6RuYW1lp2dldEZpbGWkdHlwZaVCeXRlc6RraW5kBKR0eXBlpk1ldGhvZKRraW5kQKhyZXF1aXJlZMOjZW52gahyZXF1aXJlZMKpYXJndW1lbnRzkYWkdHlwZaZTdHJpbmekbmFtZaRwYXRoqHJlcXVpcmVkw6RraW5kIqZzY2FsYXKEpG5hbWWkcGF0aKR0eXBlplN0cmluZ6hyZXF1aXJlZMOka2luZASuaW50ZXJmYWNlVHlwZXOShqR0eXBlqkNvbmN1cnJlbnSjdXJp2SV3cmFwOi8vZW5zL3dyYXBzLmV0aDpjb25jdXJyZW50QDEuMC4wqW5hbWVzcGFjZapDb25jdXJyZW50rGNhcGFiaWxpdGllc4GyZ2V0SW1wbGVtZW50YXRpb25zgadlbmFibGVkw6RraW5kzYAAqm5hdGl2ZVR5cGWpSW50ZXJmYWNlhqR0eXBlpkNsaWVudKN1cmnZK3dyYXA6Ly9lbnMvd3JhcHMuZXRoOmlwZnMtaHR0cC1jbGllbnRAMS4wLjCpbmFtZXNwYWNlpkNsaWVudKxjYXBhYmlsaXRpZXOBsmdldEltcGxlbWVudGF0aW9uc4GnZW5hYmxlZMOka2luZM2AAKpuYXRpdmVUeXBlqUludGVyZmFjZbNpbXBvcnRlZE9iamVjdFR5cGVzm4akdHlwZb5VcmlSZXNvbHZlcl9NYXliZVVyaU9yTWFuaWZlc3SjdXJp2St3cmFwOi8vZW5zL3dyYXBzLmV0aDp1cmktcmVzb2x2ZXItZXh0QDEuMS4wqW5hbWVzcGFjZatVcmlSZXNvbHZlcqpuYXRpdmVUeXBlsk1heWJlVXJpT3JNYW5pZmVzdKRraW5kzQQBqnByb3BlcnRpZXOShaR0eXBlplN0cmluZ6RuYW1lo3Vyaadjb21tZW502T1BIG5ldyBVUkkgdG8gYmUgdXNlZCBmb3IgcmVzb2x2aW5nIHRoZSB3cmFwcGVyIChyZWRpcmVjdGlvbikupGtpbmQipnNjYWxhcoOkbmFtZaN1cmmkdHlwZaZTdHJpbmeka2luZASFpHR5cGWlQnl0ZXOkbmFtZahtYW5pZmVzdKdjb21tZW502SxUaGUgd3JhcHBlcidzIG1hbmlmZXN0LCBpZiBmb3VuZCAoZmluYWxpdHkpLqRraW5kIqZzY2FsYXKDpG5hbWWobWFuaWZlc3SkdHlwZaVCeXRlc6RraW5kBIakdHlwZbFDbGllbnRfQ2F0T3B0aW9uc6N1cmnZK3dyYXA6Ly9lbnMvd3JhcHMuZXRoOmlwZnMtaHR0cC1jbGllbnRAMS4wLjCpbmFtZXNwYWNlpkNsaWVudKpuYXRpdmVUeXBlqkNhdE9wdGlvbnOka2luZM0EAapwcm9wZXJ0aWVzkoSkdHlwZaVJbnQzMqRuYW1lpm9mZnNldKRraW5kIqZzY2FsYXKDpG5hbWWmb2Zmc2V0pHR5cGWlSW50MzKka2luZASEpHR5cGWlSW50MzKkbmFtZaZsZW5ndGika2luZCKmc2NhbGFyg6RuYW1lpmxlbmd0aKR0eXBlpUludDMypGtpbmQEhqR0eXBltUNsaWVudF9SZXNvbHZlT3B0aW9uc6N1cmnZK3dyYXA6Ly9lbnMvd3JhcHMuZXRoOmlwZnMtaHR0cC1jbGllbnRAMS4wLjCpbmFtZXNwYWNlpkNsaWVudKpuYXRpdmVUeXBlrlJlc29sdmVPcHRpb25zpGtpbmTNBAGqcHJvcGVydGllc5OEpHR5cGWnQm9vbGVhbqRuYW1lqXJlY3Vyc2l2ZaRraW5kIqZzY2FsYXKDpG5hbWWpcmVjdXJzaXZlpHR5cGWnQm9vbGVhbqRraW5kBISkdHlwZaVJbnQzMqRuYW1lrmRodFJlY29yZENvdW50pGtpbmQipnNjYWxhcoOkbmFtZa5kaHRSZWNvcmRDb3VudKR0eXBlpUludDMypGtpbmQEhKR0eXBlplN0cmluZ6RuYW1lqmRodFRpbWVvdXSka2luZCKmc2NhbGFyg6RuYW1lqmRodFRpbWVvdXSkdHlwZaZTdHJpbmeka2luZASGpHR5cGW0Q2xpZW50X1Jlc29sdmVSZXN1bHSjdXJp2St3cmFwOi8vZW5zL3dyYXBzLmV0aDppcGZzLWh0dHAtY2xpZW50QDEuMC4wqW5hbWVzcGFjZaZDbGllbnSqbmF0aXZlVHlwZa1SZXNvbHZlUmVzdWx0pGtpbmTNBAGqcHJvcGVydGllc5KFpHR5cGWmU3RyaW5npG5hbWWjY2lkqHJlcXVpcmVkw6RraW5kIqZzY2FsYXKEpG5hbWWjY2lkpHR5cGWmU3RyaW5nqHJlcXVpcmVkw6RraW5kBIWkdHlwZaZTdHJpbmekbmFtZahwcm92aWRlcqhyZXF1aXJlZMOka2luZCKmc2NhbGFyhKRuYW1lqHByb3ZpZGVypHR5cGWmU3RyaW5nqHJlcXVpcmVkw6RraW5kBIakdHlwZbBDbGllbnRfRmlsZUVudHJ5o3Vyadkrd3JhcDovL2Vucy93cmFwcy5ldGg6aXBmcy1odHRwLWNsaWVudEAxLjAuMKluYW1lc3BhY2WmQ2xpZW50qm5hdGl2ZVR5cGWpRmlsZUVudHJ5pGtpbmTNBAGqcHJvcGVydGllc5KFpHR5cGWmU3RyaW5npG5hbWWkbmFtZahyZXF1aXJlZMOka2luZCKmc2NhbGFyhKRuYW1lpG5hbWWkdHlwZaZTdHJpbmeocmVxdWlyZWTDpGtpbmQEhaR0eXBlpUJ5dGVzpG5hbWWkZGF0YahyZXF1aXJlZMOka2luZCKmc2NhbGFyhKRuYW1lpGRhdGGkdHlwZaVCeXRlc6hyZXF1aXJlZMOka2luZASGpHR5cGWxQ2xpZW50X0FkZE9wdGlvbnOjdXJp2St3cmFwOi8vZW5zL3dyYXBzLmV0aDppcGZzLWh0dHAtY2xpZW50QDEuMC4wqW5hbWVzcGFjZaZDbGllbnSqbmF0aXZlVHlwZapBZGRPcHRpb25zpGtpbmTNBAGqcHJvcGVydGllc5OEpHR5cGWnQm9vbGVhbqRuYW1lo3BpbqRraW5kIqZzY2FsYXKDpG5hbWWjcGlupHR5cGWnQm9vbGVhbqRraW5kBISkdHlwZadCb29sZWFupG5hbWWob25seUhhc2ika2luZCKmc2NhbGFyg6RuYW1lqG9ubHlIYXNopHR5cGWnQm9vbGVhbqRraW5kBISkdHlwZadCb29sZWFupG5hbWWxd3JhcFdpdGhEaXJlY3Rvcnmka2luZCKmc2NhbGFyg6RuYW1lsXdyYXBXaXRoRGlyZWN0b3J5pHR5cGWnQm9vbGVhbqRraW5kBIakdHlwZbBDbGllbnRfQWRkUmVzdWx0o3Vyadkrd3JhcDovL2Vucy93cmFwcy5ldGg6aXBmcy1odHRwLWNsaWVudEAxLjAuMKluYW1lc3BhY2WmQ2xpZW50qm5hdGl2ZVR5cGWpQWRkUmVzdWx0pGtpbmTNBAGqcHJvcGVydGllc5OFpHR5cGWmU3RyaW5npG5hbWWkbmFtZahyZXF1aXJlZMOka2luZCKmc2NhbGFyhKRuYW1lpG5hbWWkdHlwZaZTdHJpbmeocmVxdWlyZWTDpGtpbmQEhaR0eXBlplN0cmluZ6RuYW1lpGhhc2iocmVxdWlyZWTDpGtpbmQipnNjYWxhcoSkbmFtZaRoYXNopHR5cGWmU3RyaW5nqHJlcXVpcmVkw6RraW5kBIWkdHlwZaZTdHJpbmekbmFtZaRzaXplqHJlcXVpcmVkw6RraW5kIqZzY2FsYXKEpG5hbWWkc2l6ZaR0eXBlplN0cmluZ6hyZXF1aXJlZMOka2luZASGpHR5cGW1Q2xpZW50X0RpcmVjdG9yeUVudHJ5o3Vyadkrd3JhcDovL2Vucy93cmFwcy5ldGg6aXBmcy1odHRwLWNsaWVudEAxLjAuMKluYW1lc3BhY2WmQ2xpZW50qm5hdGl2ZVR5cGWuRGlyZWN0b3J5RW50cnmka2luZM0EAapwcm9wZXJ0aWVzk4WkdHlwZaZTdHJpbmekbmFtZaRuYW1lqHJlcXVpcmVkw6RraW5kIqZzY2FsYXKEpG5hbWWkbmFtZaR0eXBlplN0cmluZ6hyZXF1aXJlZMOka2luZASEpHR5cGW3W0NsaWVudF9EaXJlY3RvcnlFbnRyeV2kbmFtZatkaXJlY3Rvcmllc6RraW5kIqVhcnJheYWkbmFtZatkaXJlY3Rvcmllc6R0eXBlt1tDbGllbnRfRGlyZWN0b3J5RW50cnldpm9iamVjdISkbmFtZatkaXJlY3Rvcmllc6hyZXF1aXJlZMOkdHlwZbVDbGllbnRfRGlyZWN0b3J5RW50cnmka2luZM0gAKRraW5kEqRpdGVthKRuYW1lq2RpcmVjdG9yaWVzqHJlcXVpcmVkw6R0eXBltUNsaWVudF9EaXJlY3RvcnlFbnRyeaRraW5kzSAAhKR0eXBlsltDbGllbnRfRmlsZUVudHJ5XaRuYW1lpWZpbGVzpGtpbmQipWFycmF5haRuYW1lpWZpbGVzpHR5cGWyW0NsaWVudF9GaWxlRW50cnldpm9iamVjdISkbmFtZaVmaWxlc6hyZXF1aXJlZMOkdHlwZbBDbGllbnRfRmlsZUVudHJ5pGtpbmTNIACka2luZBKkaXRlbYSkbmFtZaVmaWxlc6hyZXF1aXJlZMOkdHlwZbBDbGllbnRfRmlsZUVudHJ5pGtpbmTNIACGpHR5cGWrQ2xpZW50X0Jsb2KjdXJp2St3cmFwOi8vZW5zL3dyYXBzLmV0aDppcGZzLWh0dHAtY2xpZW50QDEuMC4wqW5hbWVzcGFjZaZDbGllbnSqbmF0aXZlVHlwZaRCbG9ipGtpbmTNBAGqcHJvcGVydGllc5KEpHR5cGW3W0NsaWVudF9EaXJlY3RvcnlFbnRyeV2kbmFtZatkaXJlY3Rvcmllc6RraW5kIqVhcnJheYWkbmFtZatkaXJlY3Rvcmllc6R0eXBlt1tDbGllbnRfRGlyZWN0b3J5RW50cnldpm9iamVjdISkbmFtZatkaXJlY3Rvcmllc6hyZXF1aXJlZMOkdHlwZbVDbGllbnRfRGlyZWN0b3J5RW50cnmka2luZM0gAKRraW5kEqRpdGVthKRuYW1lq2RpcmVjdG9yaWVzqHJlcXVpcmVkw6R0eXBltUNsaWVudF9EaXJlY3RvcnlFbnRyeaRraW5kzSAAhKR0eXBlsltDbGllbnRfRmlsZUVudHJ5XaRuYW1lpWZpbGVzpGtpbmQipWFycmF5haRuYW1lpWZpbGVzpHR5cGWyW0NsaWVudF9GaWxlRW50cnldpm9iamVjdISkbmFtZaVmaWxlc6hyZXF1aXJlZMOkdHlwZbBDbGllbnRfRmlsZUVudHJ5pGtpbmTNIACka2luZBKkaXRlbYSkbmFtZaVmaWxlc6hyZXF1aXJlZMOkdHlwZbBDbGllbnRfRmlsZUVudHJ5pGtpbmTNIACGpHR5cGW1Q29uY3VycmVudF9UYXNrUmVzdWx0o3Vyadkld3JhcDovL2Vucy93cmFwcy5ldGg6Y29uY3VycmVudEAxLjAuMKluYW1lc3BhY2WqQ29uY3VycmVudKpuYXRpdmVUeXBlqlRhc2tSZXN1bHSka2luZM0EAapwcm9wZXJ0aWVzlIWkdHlwZaNJbnSkbmFtZaZ0YXNrSWSocmVxdWlyZWTDpGtpbmQipnNjYWxhcoSkbmFtZaZ0YXNrSWSkdHlwZaNJbnSocmVxdWlyZWTDpGtpbmQEhKR0eXBlpUJ5dGVzpG5hbWWmcmVzdWx0pGtpbmQipnNjYWxhcoOkbmFtZaZyZXN1bHSkdHlwZaVCeXRlc6RraW5kBISkdHlwZaZTdHJpbmekbmFtZaVlcnJvcqRraW5kIqZzY2FsYXKDpG5hbWWlZXJyb3KkdHlwZaZTdHJpbmeka2luZASFpHR5cGW1Q29uY3VycmVudF9UYXNrU3RhdHVzpG5hbWWmc3RhdHVzqHJlcXVpcmVkw6RraW5kIqRlbnVthKRuYW1lpnN0YXR1c6hyZXF1aXJlZMOkdHlwZbVDb25jdXJyZW50X1Rhc2tTdGF0dXOka2luZM1AAIakdHlwZa9Db25jdXJyZW50X1Rhc2ujdXJp2SV3cmFwOi8vZW5zL3dyYXBzLmV0aDpjb25jdXJyZW50QDEuMC4wqW5hbWVzcGFjZapDb25jdXJyZW50qm5hdGl2ZVR5cGWkVGFza6RraW5kzQQBqnByb3BlcnRpZXOThaR0eXBlplN0cmluZ6RuYW1lo3VyaahyZXF1aXJlZMOka2luZCKmc2NhbGFyhKRuYW1lo3VyaaR0eXBlplN0cmluZ6hyZXF1aXJlZMOka2luZASFpHR5cGWmU3RyaW5npG5hbWWmbWV0aG9kqHJlcXVpcmVkw6RraW5kIqZzY2FsYXKEpG5hbWWmbWV0aG9kpHR5cGWmU3RyaW5nqHJlcXVpcmVkw6RraW5kBIWkdHlwZaVCeXRlc6RuYW1lpGFyZ3OocmVxdWlyZWTDpGtpbmQipnNjYWxhcoSkbmFtZaRhcmdzpHR5cGWlQnl0ZXOocmVxdWlyZWTDpGtpbmQEs2ltcG9ydGVkTW9kdWxlVHlwZXOTh6N1cmnZK3dyYXA6Ly9lbnMvd3JhcHMuZXRoOnVyaS1yZXNvbHZlci1leHRAMS4xLjCpbmFtZXNwYWNlq1VyaVJlc29sdmVyqm5hdGl2ZVR5cGWmTW9kdWxlq2lzSW50ZXJmYWNlwqR0eXBlslVyaVJlc29sdmVyX01vZHVsZaRraW5kzQEAp21ldGhvZHOSiKRuYW1lrXRyeVJlc29sdmVVcmmmcmV0dXJuhKR0eXBlvlVyaVJlc29sdmVyX01heWJlVXJpT3JNYW5pZmVzdKRuYW1lrXRyeVJlc29sdmVVcmmka2luZCKmb2JqZWN0g6RuYW1lrXRyeVJlc29sdmVVcmmkdHlwZb5VcmlSZXNvbHZlcl9NYXliZVVyaU9yTWFuaWZlc3Ska2luZM0gAKdjb21tZW502YhBdHRlbXB0IHRvIHJlc29sdmUgYSB3cmFwcGVyIGZyb20gaXRzIFVSSS4KQHBhcmFtIGF1dGhvcml0eSAtIFVSSSBhdXRob3JpdHkgKGV4OiAiZmlsZSIpCkBwYXJhbSBwYXRoIC0gVVJJIHBhdGggKGV4OiAiL3BhdGgvdG8vd3JhcHBlciIppHR5cGWmTWV0aG9kpGtpbmRAqHJlcXVpcmVkw6NlbnaBqHJlcXVpcmVkwqlhcmd1bWVudHOShaR0eXBlplN0cmluZ6RuYW1lqWF1dGhvcml0eahyZXF1aXJlZMOka2luZCKmc2NhbGFyhKRuYW1lqWF1dGhvcml0eaR0eXBlplN0cmluZ6hyZXF1aXJlZMOka2luZASFpHR5cGWmU3RyaW5npG5hbWWkcGF0aKhyZXF1aXJlZMOka2luZCKmc2NhbGFyhKRuYW1lpHBhdGikdHlwZaZTdHJpbmeocmVxdWlyZWTDpGtpbmQEiKRuYW1lp2dldEZpbGWmcmV0dXJuhKR0eXBlpUJ5dGVzpG5hbWWnZ2V0RmlsZaRraW5kIqZzY2FsYXKDpG5hbWWnZ2V0RmlsZaR0eXBlpUJ5dGVzpGtpbmQEp2NvbW1lbnTZUUF0dGVtcHQgdG8gcmVhZCBhIGZpbGUuCkBwYXJhbSBwYXRoIC0gVVJJIHBhdGggKGV4OiAiL3BhdGgvdG8vd3JhcHBlci93cmFwLndhc20iKaR0eXBlpk1ldGhvZKRraW5kQKhyZXF1aXJlZMOjZW52gahyZXF1aXJlZMKpYXJndW1lbnRzkYWkdHlwZaZTdHJpbmekbmFtZaRwYXRoqHJlcXVpcmVkw6RraW5kIqZzY2FsYXKEpG5hbWWkcGF0aKR0eXBlplN0cmluZ6hyZXF1aXJlZMOka2luZASHo3Vyadkrd3JhcDovL2Vucy93cmFwcy5ldGg6aXBmcy1odHRwLWNsaWVudEAxLjAuMKluYW1lc3BhY2WmQ2xpZW50qm5hdGl2ZVR5cGWmTW9kdWxlq2lzSW50ZXJmYWNlw6R0eXBlrUNsaWVudF9Nb2R1bGWka2luZM0BAKdtZXRob2RzlYakbmFtZaNjYXSmcmV0dXJuhaR0eXBlpUJ5dGVzpG5hbWWjY2F0qHJlcXVpcmVkw6RraW5kIqZzY2FsYXKEpG5hbWWjY2F0pHR5cGWlQnl0ZXOocmVxdWlyZWTDpGtpbmQEpHR5cGWmTWV0aG9kpGtpbmRAqHJlcXVpcmVkw6lhcmd1bWVudHOUhaR0eXBlplN0cmluZ6RuYW1lo2NpZKhyZXF1aXJlZMOka2luZCKmc2NhbGFyhKRuYW1lo2NpZKR0eXBlplN0cmluZ6hyZXF1aXJlZMOka2luZASFpHR5cGWmU3RyaW5npG5hbWWsaXBmc1Byb3ZpZGVyqHJlcXVpcmVkw6RraW5kIqZzY2FsYXKEpG5hbWWsaXBmc1Byb3ZpZGVypHR5cGWmU3RyaW5nqHJlcXVpcmVkw6RraW5kBISkdHlwZaZVSW50MzKkbmFtZad0aW1lb3V0pGtpbmQipnNjYWxhcoOkbmFtZad0aW1lb3V0pHR5cGWmVUludDMypGtpbmQEhKR0eXBlsUNsaWVudF9DYXRPcHRpb25zpG5hbWWqY2F0T3B0aW9uc6RraW5kIqZvYmplY3SDpG5hbWWqY2F0T3B0aW9uc6R0eXBlsUNsaWVudF9DYXRPcHRpb25zpGtpbmTNIACGpG5hbWWncmVzb2x2ZaZyZXR1cm6FpHR5cGW0Q2xpZW50X1Jlc29sdmVSZXN1bHSkbmFtZadyZXNvbHZlqHJlcXVpcmVkw6RraW5kIqZvYmplY3SEpG5hbWWncmVzb2x2ZahyZXF1aXJlZMOkdHlwZbRDbGllbnRfUmVzb2x2ZVJlc3VsdKRraW5kzSAApHR5cGWmTWV0aG9kpGtpbmRAqHJlcXVpcmVkw6lhcmd1bWVudHOUhaR0eXBlplN0cmluZ6RuYW1lo2NpZKhyZXF1aXJlZMOka2luZCKmc2NhbGFyhKRuYW1lo2NpZKR0eXBlplN0cmluZ6hyZXF1aXJlZMOka2luZASFpHR5cGWmU3RyaW5npG5hbWWsaXBmc1Byb3ZpZGVyqHJlcXVpcmVkw6RraW5kIqZzY2FsYXKEpG5hbWWsaXBmc1Byb3ZpZGVypHR5cGWmU3RyaW5nqHJlcXVpcmVkw6RraW5kBISkdHlwZaZVSW50MzKkbmFtZad0aW1lb3V0pGtpbmQipnNjYWxhcoOkbmFtZad0aW1lb3V0pHR5cGWmVUludDMypGtpbmQEhKR0eXBltUNsaWVudF9SZXNvbHZlT3B0aW9uc6RuYW1lrnJlc29sdmVPcHRpb25zpGtpbmQipm9iamVjdIOkbmFtZa5yZXNvbHZlT3B0aW9uc6R0eXBltUNsaWVudF9SZXNvbHZlT3B0aW9uc6RraW5kzSAAhqRuYW1lp2FkZEZpbGWmcmV0dXJuhaR0eXBlsENsaWVudF9BZGRSZXN1bHSkbmFtZadhZGRGaWxlqHJlcXVpcmVkw6RraW5kIqZvYmplY3SEpG5hbWWnYWRkRmlsZahyZXF1aXJlZMOkdHlwZbBDbGllbnRfQWRkUmVzdWx0pGtpbmTNIACkdHlwZaZNZXRob2Ska2luZECocmVxdWlyZWTDqWFyZ3VtZW50c5SFpHR5cGWwQ2xpZW50X0ZpbGVFbnRyeaRuYW1lpGRhdGGocmVxdWlyZWTDpGtpbmQipm9iamVjdISkbmFtZaRkYXRhqHJlcXVpcmVkw6R0eXBlsENsaWVudF9GaWxlRW50cnmka2luZM0gAIWkdHlwZaZTdHJpbmekbmFtZaxpcGZzUHJvdmlkZXKocmVxdWlyZWTDpGtpbmQipnNjYWxhcoSkbmFtZaxpcGZzUHJvdmlkZXKkdHlwZaZTdHJpbmeocmVxdWlyZWTDpGtpbmQEhKR0eXBlplVJbnQzMqRuYW1lp3RpbWVvdXSka2luZCKmc2NhbGFyg6RuYW1lp3RpbWVvdXSkdHlwZaZVSW50MzKka2luZASEpHR5cGWxQ2xpZW50X0FkZE9wdGlvbnOkbmFtZaphZGRPcHRpb25zpGtpbmQipm9iamVjdIOkbmFtZaphZGRPcHRpb25zpHR5cGWxQ2xpZW50X0FkZE9wdGlvbnOka2luZM0gAIakbmFtZaZhZGREaXKmcmV0dXJuhaR0eXBlsltDbGllbnRfQWRkUmVzdWx0XaRuYW1lpmFkZERpcqhyZXF1aXJlZMOka2luZCKlYXJyYXmGpHR5cGWyW0NsaWVudF9BZGRSZXN1bHRdpG5hbWWmYWRkRGlyqHJlcXVpcmVkw6ZvYmplY3SEpG5hbWWmYWRkRGlyqHJlcXVpcmVkw6R0eXBlsENsaWVudF9BZGRSZXN1bHSka2luZM0gAKRraW5kEqRpdGVthKRuYW1lpmFkZERpcqhyZXF1aXJlZMOkdHlwZbBDbGllbnRfQWRkUmVzdWx0pGtpbmTNIACkdHlwZaZNZXRob2Ska2luZECocmVxdWlyZWTDqWFyZ3VtZW50c5SFpHR5cGW1Q2xpZW50X0RpcmVjdG9yeUVudHJ5pG5hbWWkZGF0YahyZXF1aXJlZMOka2luZCKmb2JqZWN0hKRuYW1lpGRhdGGocmVxdWlyZWTDpHR5cGW1Q2xpZW50X0RpcmVjdG9yeUVudHJ5pGtpbmTNIACFpHR5cGWmU3RyaW5npG5hbWWsaXBmc1Byb3ZpZGVyqHJlcXVpcmVkw6RraW5kIqZzY2FsYXKEpG5hbWWsaXBmc1Byb3ZpZGVypHR5cGWmU3RyaW5nqHJlcXVpcmVkw6RraW5kBISkdHlwZaZVSW50MzKkbmFtZad0aW1lb3V0pGtpbmQipnNjYWxhcoOkbmFtZad0aW1lb3V0pHR5cGWmVUludDMypGtpbmQEhKR0eXBlsUNsaWVudF9BZGRPcHRpb25zpG5hbWWqYWRkT3B0aW9uc6RraW5kIqZvYmplY3SDpG5hbWWqYWRkT3B0aW9uc6R0eXBlsUNsaWVudF9BZGRPcHRpb25zpGtpbmTNIACGpG5hbWWnYWRkQmxvYqZyZXR1cm6FpHR5cGWyW0NsaWVudF9BZGRSZXN1bHRdpG5hbWWnYWRkQmxvYqhyZXF1aXJlZMOka2luZCKlYXJyYXmGpHR5cGWyW0NsaWVudF9BZGRSZXN1bHRdpG5hbWWnYWRkQmxvYqhyZXF1aXJlZMOmb2JqZWN0hKRuYW1lp2FkZEJsb2KocmVxdWlyZWTDpHR5cGWwQ2xpZW50X0FkZFJlc3VsdKRraW5kzSAApGtpbmQSpGl0ZW2EpG5hbWWnYWRkQmxvYqhyZXF1aXJlZMOkdHlwZbBDbGllbnRfQWRkUmVzdWx0pGtpbmTNIACkdHlwZaZNZXRob2Ska2luZECocmVxdWlyZWTDqWFyZ3VtZW50c5SFpHR5cGWrQ2xpZW50X0Jsb2KkbmFtZaRkYXRhqHJlcXVpcmVkw6RraW5kIqZvYmplY3SEpG5hbWWkZGF0YahyZXF1aXJlZMOkdHlwZatDbGllbnRfQmxvYqRraW5kzSAAhaR0eXBlplN0cmluZ6RuYW1lrGlwZnNQcm92aWRlcqhyZXF1aXJlZMOka2luZCKmc2NhbGFyhKRuYW1lrGlwZnNQcm92aWRlcqR0eXBlplN0cmluZ6hyZXF1aXJlZMOka2luZASEpHR5cGWmVUludDMypG5hbWWndGltZW91dKRraW5kIqZzY2FsYXKDpG5hbWWndGltZW91dKR0eXBlplVJbnQzMqRraW5kBISkdHlwZbFDbGllbnRfQWRkT3B0aW9uc6RuYW1lqmFkZE9wdGlvbnOka2luZCKmb2JqZWN0g6RuYW1lqmFkZE9wdGlvbnOkdHlwZbFDbGllbnRfQWRkT3B0aW9uc6RraW5kzSAAh6N1cmnZJXdyYXA6Ly9lbnMvd3JhcHMuZXRoOmNvbmN1cnJlbnRAMS4wLjCpbmFtZXNwYWNlqkNvbmN1cnJlbnSqbmF0aXZlVHlwZaZNb2R1bGWraXNJbnRlcmZhY2XDpHR5cGWxQ29uY3VycmVudF9Nb2R1bGWka2luZM0BAKdtZXRob2RzlIakbmFtZaZyZXN1bHSmcmV0dXJuhaR0eXBlt1tDb25jdXJyZW50X1Rhc2tSZXN1bHRdpG5hbWWmcmVzdWx0qHJlcXVpcmVkw6RraW5kIqVhcnJheYakdHlwZbdbQ29uY3VycmVudF9UYXNrUmVzdWx0XaRuYW1lpnJlc3VsdKhyZXF1aXJlZMOmb2JqZWN0hKRuYW1lpnJlc3VsdKhyZXF1aXJlZMOkdHlwZbVDb25jdXJyZW50X1Rhc2tSZXN1bHSka2luZM0gAKRraW5kEqRpdGVthKRuYW1lpnJlc3VsdKhyZXF1aXJlZMOkdHlwZbVDb25jdXJyZW50X1Rhc2tSZXN1bHSka2luZM0gAKR0eXBlpk1ldGhvZKRraW5kQKhyZXF1aXJlZMOpYXJndW1lbnRzkoWkdHlwZaVbSW50XaRuYW1lp3Rhc2tJZHOocmVxdWlyZWTDpGtpbmQipWFycmF5hqRuYW1lp3Rhc2tJZHOkdHlwZaVbSW50XahyZXF1aXJlZMOmc2NhbGFyhKRuYW1lp3Rhc2tJZHOkdHlwZaNJbnSocmVxdWlyZWTDpGtpbmQEpGtpbmQSpGl0ZW2EpG5hbWWndGFza0lkc6R0eXBlo0ludKhyZXF1aXJlZMOka2luZASFpHR5cGW1Q29uY3VycmVudF9SZXR1cm5XaGVupG5hbWWqcmV0dXJuV2hlbqhyZXF1aXJlZMOka2luZCKkZW51bYSkbmFtZapyZXR1cm5XaGVuqHJlcXVpcmVkw6R0eXBltUNvbmN1cnJlbnRfUmV0dXJuV2hlbqRraW5kzUAAhqRuYW1lpnN0YXR1c6ZyZXR1cm6FpHR5cGW3W0NvbmN1cnJlbnRfVGFza1N0YXR1c12kbmFtZaZzdGF0dXOocmVxdWlyZWTDpGtpbmQipWFycmF5hqR0eXBlt1tDb25jdXJyZW50X1Rhc2tTdGF0dXNdpG5hbWWmc3RhdHVzqHJlcXVpcmVkw6RlbnVthKRuYW1lpnN0YXR1c6hyZXF1aXJlZMOkdHlwZbVDb25jdXJyZW50X1Rhc2tTdGF0dXOka2luZM1AAKRraW5kEqRpdGVthKRuYW1lpnN0YXR1c6hyZXF1aXJlZMOkdHlwZbVDb25jdXJyZW50X1Rhc2tTdGF0dXOka2luZM1AAKR0eXBlpk1ldGhvZKRraW5kQKhyZXF1aXJlZMOpYXJndW1lbnRzkYWkdHlwZaVbSW50XaRuYW1lp3Rhc2tJZHOocmVxdWlyZWTDpGtpbmQipWFycmF5hqRuYW1lp3Rhc2tJZHOkdHlwZaVbSW50XahyZXF1aXJlZMOmc2NhbGFyhKRuYW1lp3Rhc2tJZHOkdHlwZaNJbnSocmVxdWlyZWTDpGtpbmQEpGtpbmQSpGl0ZW2EpG5hbWWndGFza0lkc6R0eXBlo0ludKhyZXF1aXJlZMOka2luZASGpG5hbWWoc2NoZWR1bGWmcmV0dXJuhaR0eXBlpVtJbnRdpG5hbWWoc2NoZWR1bGWocmVxdWlyZWTDpGtpbmQipWFycmF5hqR0eXBlpVtJbnRdpG5hbWWoc2NoZWR1bGWocmVxdWlyZWTDpnNjYWxhcoSkbmFtZahzY2hlZHVsZaR0eXBlo0ludKhyZXF1aXJlZMOka2luZASka2luZBKkaXRlbYSkbmFtZahzY2hlZHVsZaR0eXBlo0ludKhyZXF1aXJlZMOka2luZASkdHlwZaZNZXRob2Ska2luZECocmVxdWlyZWTDqWFyZ3VtZW50c5GFpHR5cGWxW0NvbmN1cnJlbnRfVGFza12kbmFtZaV0YXNrc6hyZXF1aXJlZMOka2luZCKlYXJyYXmGpG5hbWWldGFza3OkdHlwZbFbQ29uY3VycmVudF9UYXNrXahyZXF1aXJlZMOmb2JqZWN0hKRuYW1lpXRhc2tzqHJlcXVpcmVkw6R0eXBlr0NvbmN1cnJlbnRfVGFza6RraW5kzSAApGtpbmQSpGl0ZW2EpG5hbWWldGFza3OocmVxdWlyZWTDpHR5cGWvQ29uY3VycmVudF9UYXNrpGtpbmTNIACGpG5hbWWlYWJvcnSmcmV0dXJuhaR0eXBlqVtCb29sZWFuXaRuYW1lpWFib3J0qHJlcXVpcmVkw6RraW5kIqVhcnJheYakdHlwZalbQm9vbGVhbl2kbmFtZaVhYm9ydKhyZXF1aXJlZMOmc2NhbGFyhKRuYW1lpWFib3J0pHR5cGWnQm9vbGVhbqhyZXF1aXJlZMOka2luZASka2luZBKkaXRlbYSkbmFtZaVhYm9ydKR0eXBlp0Jvb2xlYW6ocmVxdWlyZWTDpGtpbmQEpHR5cGWmTWV0aG9kpGtpbmRAqHJlcXVpcmVkw6lhcmd1bWVudHORhaR0eXBlqFtTdHJpbmddpG5hbWWndGFza0lkc6hyZXF1aXJlZMOka2luZCKlYXJyYXmGpG5hbWWndGFza0lkc6R0eXBlqFtTdHJpbmddqHJlcXVpcmVkw6ZzY2FsYXKEpG5hbWWndGFza0lkc6R0eXBlplN0cmluZ6hyZXF1aXJlZMOka2luZASka2luZBKkaXRlbYSkbmFtZad0YXNrSWRzpHR5cGWmU3RyaW5nqHJlcXVpcmVkw6RraW5kBLFpbXBvcnRlZEVudW1UeXBlc5KGpHR5cGW1Q29uY3VycmVudF9SZXR1cm5XaGVuqWNvbnN0YW50c5OvRklSU1RfQ09NUExFVEVErUFOWV9DT01QTEVURUStQUxMX0NPTVBMRVRFRKN1cmnZJXdyYXA6Ly9lbnMvd3JhcHMuZXRoOmNvbmN1cnJlbnRAMS4wLjCpbmFtZXNwYWNlqkNvbmN1cnJlbnSqbmF0aXZlVHlwZapSZXR1cm5XaGVupGtpbmTNAgiGpHR5cGW1Q29uY3VycmVudF9UYXNrU3RhdHVzqWNvbnN0YW50c5WnUEVORElOR6dSVU5OSU5HqUNPTVBMRVRFRKlDQU5DRUxMRUSmRkFJTEVEo3Vyadkld3JhcDovL2Vucy93cmFwcy5ldGg6Y29uY3VycmVudEAxLjAuMKluYW1lc3BhY2WqQ29uY3VycmVudKpuYXRpdmVUeXBlqlRhc2tTdGF0dXOka2luZM0CCKdlbnZUeXBlg6R0eXBlo0VudqRraW5kzgABAACqcHJvcGVydGllc5WFpHR5cGWnUmV0cmllc6RuYW1lp3JldHJpZXOnY29tbWVudLhSZXRyeSByZXF1ZXN0IG9uIGZhaWx1cmWka2luZCKmb2JqZWN0g6RuYW1lp3JldHJpZXOkdHlwZadSZXRyaWVzpGtpbmTNIACFpHR5cGWmVUludDMypG5hbWWndGltZW91dKdjb21tZW502SJSZXNwb25zZSB0aW1lb3V0IGZvciBIVFRQIHJlcXVlc3RzpGtpbmQipnNjYWxhcoOkbmFtZad0aW1lb3V0pHR5cGWmVUludDMypGtpbmQEhqR0eXBlplN0cmluZ6RuYW1lqHByb3ZpZGVyp2NvbW1lbnSwRGVmYXVsdCBwcm92aWRlcqhyZXF1aXJlZMOka2luZCKmc2NhbGFyhKRuYW1lqHByb3ZpZGVypHR5cGWmU3RyaW5nqHJlcXVpcmVkw6RraW5kBIWkdHlwZahbU3RyaW5nXaRuYW1lsWZhbGxiYWNrUHJvdmlkZXJzp2NvbW1lbnSyRmFsbGJhY2sgcHJvdmlkZXJzpGtpbmQipWFycmF5haRuYW1lsWZhbGxiYWNrUHJvdmlkZXJzpHR5cGWoW1N0cmluZ12mc2NhbGFyhKRuYW1lsWZhbGxiYWNrUHJvdmlkZXJzpHR5cGWmU3RyaW5nqHJlcXVpcmVkw6RraW5kBKRraW5kEqRpdGVthKRuYW1lsWZhbGxiYWNrUHJvdmlkZXJzpHR5cGWmU3RyaW5nqHJlcXVpcmVkw6RraW5kBIWkdHlwZadCb29sZWFupG5hbWW3ZGlzYWJsZVBhcmFsbGVsUmVxdWVzdHOnY29tbWVudNk6RGlzYWJsZSBxdWVyeWluZyBwcm92aWRlcnMgaW4gcGFyYWxsZWwgd2hlbiByZXNvbHZpbmcgVVJJc6RraW5kIqZzY2FsYXKDpG5hbWW3ZGlzYWJsZVBhcmFsbGVsUmVxdWVzdHOkdHlwZadCb29sZWFupGtpbmQE"
-  )
-}
+use polywrap_wasm::{embed_wrapper, wasm_package::WasmPackage};
 
 pub fn wasm_package() -> WasmPackage {
-  WasmPackage::new(
-    Arc::new(SimpleFileReader::new()),
-    wrap_info().ok(),
-    wrap_wasm().ok(),
-  )
+    embed_wrapper!(".")
 }
@@ -6,13 +6,16 @@ use http_plugin::HttpPlugin;
 use http_resolver_plugin::HttpResolverPlugin;
 use polywrap_core::{resolvers::{uri_resolution_context::UriPackage, static_resolver::{StaticResolverLike, StaticResolver}, uri_resolver_like::UriResolverLike, recursive_resolver::RecursiveResolver, uri_resolver::UriResolver}, uri::Uri, client::ClientConfig};
 use polywrap_plugin::package::PluginPackage;
-use polywrap_resolvers::extendable_uri_resolver::ExtendableUriResolver;
-use serde_json::{Value};
+use polywrap_resolvers::{extendable_uri_resolver::ExtendableUriResolver, lockfile_resolver::LockfileResolver, wrapper_cache_resolver::WrapperCacheResolver};
+use serde_json::{json, Value};
 use polywrap_core::client::UriRedirect;
 use polywrap_core::env::Envs;
 
 
-use crate::types::BuilderConfig;
+use crate::{embeds::{ipfs_http_client, ipfs_resolver}, types::BuilderConfig};
+
+pub const IPFS_HTTP_CLIENT_URI: &str = "wrap://ens/wraps.eth:ipfs-http-client@1.0.0";
+pub const IPFS_RESOLVER_URI: &str = "wrap://ens/wraps.eth:async-ipfs-uri-resolver-ext@1.0.0";
 
 pub fn merge(a: &mut Value, b: &Value) {
     match (a, b) {
@@ -32,27 +35,30 @@ pub fn add_default() -> BuilderConfig {
     interfaces.insert(
         "wrap://ens/uri-resolver.core.polywrap.eth".to_string(), 
         vec![
-            // Uri::try_from("ens/wraps.eth:async-ipfs-uri-resolver-ext@1.0.0").unwrap(),
             Uri::try_from("wrap://ens/fs-resolver.polywrap.eth").unwrap(),
             Uri::try_from("wrap://ens/http-resolver.polywrap.eth").unwrap(),
+            Uri::try_from(IPFS_RESOLVER_URI).unwrap(),
         ]
     );
-    // interfaces.insert(
-    //     "wrap://ens/wraps.eth:ipfs-http-client@1.0.0".to_string(),
-    //     vec![
-    //         Uri::try_from("wrap://ens/wraps.eth:ipfs-http-client@1.0.0").unwrap(),
-    //     ]
-    // );
-
-    let envs: Envs = HashMap::new();
-    // envs.insert(
-    //     "ens/wraps.eth:async-ipfs-uri-resolver-ext@1.0.0".to_string(),
-    //     json!({
-    //         "provider": "https://ipfs.wrappers.io",
-    //         "fallbackProviders": ["https://ipfs.io"],
-    //         "retries": { "tryResolveUri": 2, "getFile": 2 },
-    //     })
-    // );
+    interfaces.insert(
+        IPFS_HTTP_CLIENT_URI.to_string(),
+        vec![Uri::try_from(IPFS_HTTP_CLIENT_URI).unwrap()]
+    );
+
+    // The providers, timeout and retries can be overridden with `add_env`.
+    // Providers are queried one after the other, since there's no
+    // implementation of the concurrent interface to query them in parallel
+    let mut envs: Envs = HashMap::new();
+    envs.insert(
+        IPFS_RESOLVER_URI.to_string(),
+        json!({
+            "provider": "https://ipfs.wrappers.io",
+            "fallbackProviders": ["https://ipfs.io"],
+            "retries": { "tryResolveUri": 2, "getFile": 2 },
+            "timeout": 10000,
+            "disableParallelRequests": true,
+        })
+    );
 
     let mut redirects: Vec<UriRedirect> = Vec::new();
     redirects.push(UriRedirect {
@@ -66,39 +72,48 @@ pub fn add_default() -> BuilderConfig {
         wrappers: None,
         packages: Some(get_default_plugins()),
         redirects: Some(redirects),
-        resolvers: None
+        resolvers: None,
+        validate_args: None,
+        retry_policies: None,
+        circuit_breaker: None,
+        quotas: None,
+        permissions: None,
+        wrapper_cache: None,
+        lockfile: None,
+        integrity: None,
+        extension_options: None
     }
 }
 
 pub fn get_default_plugins() -> Vec<UriPackage> {
-    let fs = FileSystemPlugin { env: Value::Null };
+    let fs = FileSystemPlugin { env: Value::Null, sandbox: None };
     let fs_plugin_package: PluginPackage = fs.into();
     let fs_package = Arc::new(Mutex::new(fs_plugin_package));
 
-    let fs_resolver = FileSystemResolverPlugin { env: Value::Null };
+    let fs_resolver = FileSystemResolverPlugin { env: Value::Null, base_dir: None };
     let fs_resolver_plugin_package: PluginPackage = fs_resolver.into();
     let fs_resolver_package = Arc::new(Mutex::new(fs_resolver_plugin_package));
 
-    let http = HttpPlugin { env: Value::Null };
+    let http = HttpPlugin::new(None);
     let http_plugin_package: PluginPackage = http.into();
     let http_package = Arc::new(Mutex::new(http_plugin_package));
 
-    let http_resolver = HttpResolverPlugin { env: Value::Null };
+    let http_resolver = HttpResolverPlugin { env: Value::Null, cache: Default::default() };
     let http_resolver_plugin_package: PluginPackage = http_resolver.into();
     let http_resolver_package = Arc::new(Mutex::new(http_resolver_plugin_package));
 
-    // let ipfs_http_client_package = Arc::new(Mutex::new(ipfsHttpClientPackage()));
-    // let ipfs_resolver_package = Arc::new(Mutex::new(ipfsResolverPackage()));
+    let ipfs_http_client_package = Arc::new(Mutex::new(ipfs_http_client::wasm_package()));
+    let ipfs_resolver_package = Arc::new(Mutex::new(ipfs_resolver::wasm_package()));
 
     vec![
-        // UriPackage {
-        //     uri: Uri::try_from("wrap://ens/wraps.eth:ipfs-http-client@1.0.0").unwrap(),
-        //     package: ipfs_http_client_package
-        // },
-        // UriPackage {
-        //     uri: Uri::try_from("ens/wraps.eth:async-ipfs-uri-resolver-ext@1.0.0").unwrap(),
-        //     package: ipfs_resolver_package
-        // },
+        UriPackage {
+            uri: Uri::try_from(IPFS_HTTP_CLIENT_URI).unwrap(),
+            package: ipfs_http_client_package
+        },
+        UriPackage {
+            uri: Uri::try_from(IPFS_RESOLVER_URI).unwrap(),
+            package: ipfs_resolver_package
+        },
         UriPackage {
             uri: Uri::try_from("wrap://ens/fs.polywrap.eth").unwrap(),
             package: fs_package
@@ -139,18 +154,39 @@ pub fn build_resolver(builder: BuilderConfig) -> ClientConfig {
         };
     }
     let static_resolver = StaticResolver::from(static_resolvers);
-    let extendable_resolver = ExtendableUriResolver::new(None);
-    
+    let integrity = builder.integrity.unwrap_or_default();
+    let extendable_resolver = ExtendableUriResolver::new(None)
+        .with_integrity(integrity.clone())
+        .with_options(builder.extension_options.unwrap_or_default());
+    // Wrappers from the static resolver are already local, only the ones
+    // fetched by resolver extensions are cached
+    let extendable_resolver: Box<dyn UriResolver> = match builder.wrapper_cache {
+        Some(cache) => Box::new(
+            WrapperCacheResolver::new(Arc::new(extendable_resolver), cache).with_integrity(integrity),
+        ),
+        None => Box::new(extendable_resolver),
+    };
+
     let resolvers = vec![
         UriResolverLike::Resolver(Box::new(static_resolver)),
-        UriResolverLike::Resolver(Box::new(extendable_resolver)),
+        UriResolverLike::Resolver(extendable_resolver),
     ];
     
+    let resolver = Arc::new(RecursiveResolver::from(resolvers)) as Arc<dyn UriResolver>;
+    // Wraps the whole resolution, so that redirects are locked too
+    let resolver = match builder.lockfile {
+        Some((lockfile, mode)) => Arc::new(LockfileResolver::new(resolver, lockfile, mode)),
+        None => resolver,
+    };
+
     ClientConfig {
         envs: builder.envs.clone(),
         interfaces: builder.interfaces.clone(),
-        resolver: Arc::new(
-            RecursiveResolver::from(resolvers)
-        ) as Arc<dyn UriResolver>
+        resolver,
+        validate_args: builder.validate_args.unwrap_or(false),
+        retry_policies: builder.retry_policies.clone(),
+        circuit_breaker: builder.circuit_breaker.clone(),
+        quotas: builder.quotas.clone(),
+        permissions: builder.permissions.clone(),
     }
 }
\ No newline at end of file
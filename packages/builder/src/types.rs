@@ -1,9 +1,18 @@
+use std::path::PathBuf;
+
+use polywrap_resolvers::{extendable_uri_resolver::ExtensionOptions, lockfile::{Lockfile, LockfileMode}, wrapper_cache::WrapperCache};
+
 use polywrap_core::{
     interface_implementation::InterfaceImplementations,
     env::{Envs,Env}, 
     resolvers::{uri_resolution_context::{UriWrapper,UriPackage}, uri_resolver_like::UriResolverLike},
     uri::Uri, 
-    client::{UriRedirect, ClientConfig}
+    client::{UriRedirect, ClientConfig},
+    retry::{RetryPolicies, RetryPolicy},
+    circuit_breaker::CircuitBreakerConfig,
+    quota::{QuotaPolicies, QuotaPolicy},
+    permissions::PermissionPolicy,
+    integrity::IntegrityPolicy
 };
 
 pub struct BuilderConfig {
@@ -13,6 +22,15 @@ pub struct BuilderConfig {
     pub packages: Option<Vec<UriPackage>>,
     pub redirects: Option<Vec<UriRedirect>>,
     pub resolvers: Option<Vec<UriResolverLike>>,
+    pub validate_args: Option<bool>,
+    pub retry_policies: Option<RetryPolicies>,
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
+    pub quotas: Option<QuotaPolicies>,
+    pub permissions: Option<PermissionPolicy>,
+    pub wrapper_cache: Option<WrapperCache>,
+    pub lockfile: Option<(Lockfile, LockfileMode)>,
+    pub integrity: Option<IntegrityPolicy>,
+    pub extension_options: Option<ExtensionOptions>,
 }
 
 pub trait ClientBuilder {
@@ -42,11 +60,24 @@ pub trait ClientBuilder {
     fn add_package(&mut self, package: UriPackage) -> &mut Self;
     fn add_packages(&mut self, packages: Vec<UriPackage>) -> &mut Self;
     fn remove_package(&mut self, uri: Uri) -> &mut Self;
+    fn add_archive(&mut self, uri: Uri, path: PathBuf) -> &mut Self;
     fn add_redirect(&mut self, from: Uri, to: Uri) -> &mut Self;
     fn add_redirects(&mut self, redirects: Vec<UriRedirect>) -> &mut Self;
     fn remove_redirect(&mut self, from: Uri) -> &mut Self;
     fn add_resolver(&mut self, resolver: UriResolverLike) -> &mut Self;
     fn add_resolvers(&mut self, resolver: Vec<UriResolverLike>) -> &mut Self;
+    fn set_validate_args(&mut self, validate_args: bool) -> &mut Self;
+    fn set_retry_policy(&mut self, uri: Uri, method: Option<&str>, policy: RetryPolicy) -> &mut Self;
+    fn add_retry_policies(&mut self, policies: RetryPolicies) -> &mut Self;
+    fn set_circuit_breaker(&mut self, config: CircuitBreakerConfig) -> &mut Self;
+    fn set_uri_quota(&mut self, uri: Uri, policy: QuotaPolicy) -> &mut Self;
+    fn set_authority_quota(&mut self, pattern: &str, policy: QuotaPolicy) -> &mut Self;
+    fn add_quotas(&mut self, quotas: QuotaPolicies) -> &mut Self;
+    fn set_permissions(&mut self, permissions: PermissionPolicy) -> &mut Self;
+    fn set_wrapper_cache(&mut self, cache: WrapperCache) -> &mut Self;
+    fn set_lockfile(&mut self, lockfile: Lockfile, mode: LockfileMode) -> &mut Self;
+    fn set_integrity_policy(&mut self, integrity: IntegrityPolicy) -> &mut Self;
+    fn set_extension_options(&mut self, options: ExtensionOptions) -> &mut Self;
 }
 
 pub trait ClientConfigHandler {
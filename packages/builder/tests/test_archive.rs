@@ -0,0 +1,93 @@
+use std::{fs, io::{Cursor, Write}, path::PathBuf};
+
+use polywrap_client_builder::types::{BuilderConfig, ClientBuilder};
+use polywrap_core::{
+    archive::{ArchiveFileReader, ArchiveKind, MAX_ENTRY_SIZE},
+    file_reader::FileReader,
+    uri::Uri,
+};
+use wrap_manifest_schemas::deserialize::deserialize_wrap_manifest;
+
+fn embedded_file(name: &str) -> Vec<u8> {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src/embeds/ipfs_http_client");
+    fs::read(dir.join(name)).unwrap()
+}
+
+fn archive_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("builder_archive_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write_zip(path: &PathBuf) {
+    let mut zip = zip::ZipWriter::new(fs::File::create(path).unwrap());
+    let options = zip::write::FileOptions::default();
+    for name in ["wrap.info", "wrap.wasm"] {
+        zip.start_file(name, options).unwrap();
+        zip.write_all(&embedded_file(name)).unwrap();
+    }
+    zip.finish().unwrap();
+}
+
+fn write_tar_gz(path: &PathBuf) {
+    let encoder = flate2::write::GzEncoder::new(fs::File::create(path).unwrap(), flate2::Compression::default());
+    let mut tar = tar::Builder::new(encoder);
+    for name in ["wrap.info", "wrap.wasm"] {
+        let contents = embedded_file(name);
+        let mut header = tar::Header::new_gnu();
+        header.set_size(contents.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        tar.append_data(&mut header, format!("ipfs_http_client/{}", name), contents.as_slice()).unwrap();
+    }
+    tar.into_inner().unwrap().finish().unwrap();
+}
+
+#[test]
+fn loads_wrappers_from_archives() {
+    let dir = archive_dir();
+    let zip = dir.join("wrapper.zip");
+    let tar_gz = dir.join("wrapper.tar.gz");
+    write_zip(&zip);
+    write_tar_gz(&tar_gz);
+    let expected = deserialize_wrap_manifest(&embedded_file("wrap.info"), None).unwrap();
+
+    for path in [zip, tar_gz] {
+        let mut builder = BuilderConfig::new(None);
+        builder.add_archive(Uri::new("wrap://ens/archive.eth"), path);
+
+        let packages = builder.packages.unwrap();
+        let package = packages[0].package.lock().unwrap();
+        assert_eq!(package.get_manifest(None).unwrap().name, expected.name);
+        assert!(package.create_wrapper().is_ok());
+    }
+}
+
+#[test]
+fn fails_to_load_missing_or_unsupported_archives() {
+    let dir = archive_dir();
+
+    for path in [dir.join("missing.zip"), dir.join("wrapper.rar")] {
+        let mut builder = BuilderConfig::new(None);
+        builder.add_archive(Uri::new("wrap://ens/archive.eth"), path);
+
+        let packages = builder.packages.unwrap();
+        let package = packages[0].package.lock().unwrap();
+        assert!(package.get_manifest(None).is_err());
+    }
+}
+
+#[test]
+fn rejects_entries_past_the_size_limit() {
+    let mut zip = zip::ZipWriter::new(Cursor::new(vec![]));
+    zip.start_file("wrap.wasm", zip::write::FileOptions::default()).unwrap();
+    let chunk = vec![0; 1024 * 1024];
+    for _ in 0..=(MAX_ENTRY_SIZE / chunk.len() as u64) {
+        zip.write_all(&chunk).unwrap();
+    }
+    let archive = zip.finish().unwrap().into_inner();
+
+    let reader = ArchiveFileReader::from_bytes(archive, ArchiveKind::Zip);
+    let error = reader.read_file("wrap.wasm").unwrap_err();
+    assert!(error.to_string().contains("is larger than"));
+}
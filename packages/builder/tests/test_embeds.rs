@@ -0,0 +1,23 @@
+use polywrap_client_builder::embeds::{ipfs_http_client, ipfs_resolver};
+use polywrap_core::package::WrapPackage;
+use polywrap_wasm::embed_wrapper;
+
+#[test]
+fn embeds_ipfs_wrappers() {
+    let manifest = ipfs_http_client::wasm_package().get_manifest(None).unwrap();
+    assert_eq!(manifest.name, "ipfs-http-client-as");
+
+    let package = ipfs_resolver::wasm_package();
+    assert!(package.get_manifest(None).is_ok());
+    assert_eq!(
+        package.get_wasm_module().unwrap(),
+        include_bytes!("../src/embeds/ipfs_resolver/wrap.wasm")
+    );
+}
+
+#[test]
+fn embeds_wrappers_from_any_directory() {
+    let package = embed_wrapper!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/embeds/ipfs_http_client"));
+    let manifest = package.get_manifest(None).unwrap();
+    assert_eq!(manifest.name, "ipfs-http-client-as");
+}
@@ -1,6 +1,9 @@
-use std::sync::{Arc, Mutex};
+use std::{collections::HashMap, panic::{self, AssertUnwindSafe}, sync::{Arc, Mutex}, thread};
 
 use polywrap_core::{
+    abi::conversion::{json_args_to_msgpack, msgpack_result_to_json},
+    cancellation::CancellationToken,
+    circuit_breaker::{CircuitBreaker, CircuitState},
     client::{Client, ClientConfig},
     error::Error,
     invoke::{Invoker},
@@ -13,9 +16,18 @@ use polywrap_core::{
 };
 use polywrap_msgpack::{decode};
 use serde::de::DeserializeOwned;
+use serde_json::Value;
 
 use crate::{wrapper_invoker::WrapperInvoker, wrapper_loader::WrapperLoader};
 
+#[derive(Clone, Debug)]
+pub struct InvokeBatchItem {
+    pub uri: Uri,
+    pub method: String,
+    pub args: Option<Vec<u8>>,
+    pub env: Option<Env>,
+}
+
 #[derive(Clone)]
 pub struct PolywrapClient {
     pub loader: WrapperLoader,
@@ -24,12 +36,7 @@ pub struct PolywrapClient {
 
 impl PolywrapClient {
     pub fn new(config: ClientConfig) -> Self {
-        let resolver = config.resolver;
-        let loader = WrapperLoader::new(
-            resolver, 
-            config.envs.clone(),
-            config.interfaces.clone()
-        );
+        let loader = WrapperLoader::new(&config);
         let invoker = WrapperInvoker::new(loader.clone());
 
         Self {
@@ -38,6 +45,49 @@ impl PolywrapClient {
         }
     }
 
+    // The token applies to this invocation only, and is seen by its
+    // subinvocations and by the resolver extensions that load its wrappers
+    pub fn invoke_raw_with_cancellation(
+        &self,
+        uri: &Uri,
+        method: &str,
+        args: Option<&[u8]>,
+        env: Option<Env>,
+        resolution_context: Option<&mut UriResolutionContext>,
+        token: &CancellationToken,
+    ) -> Result<Vec<u8>, Error> {
+        let mut loader = self.loader.clone();
+        loader.cancellation_token = Some(token.clone());
+        let client = Self {
+            invoker: WrapperInvoker::new(loader.clone()),
+            loader,
+        };
+
+        client.invoke_raw(uri, method, args, env, resolution_context)
+    }
+
+    pub fn invoke_with_cancellation<T: DeserializeOwned>(
+        &self,
+        uri: &Uri,
+        method: &str,
+        args: Option<&[u8]>,
+        env: Option<Env>,
+        resolution_context: Option<&mut UriResolutionContext>,
+        token: &CancellationToken,
+    ) -> Result<T, Error> {
+        let result = self.invoke_raw_with_cancellation(uri, method, args, env, resolution_context, token)?;
+
+        decode(result.as_slice())
+            .map_err(|e| Error::InvokeError(format!("Failed to decode result: {}", e)))
+    }
+
+    pub fn get_circuit_state(&self, uri: &Uri) -> Option<CircuitState> {
+        self.loader
+            .circuit_breaker
+            .as_ref()
+            .map(|breaker| breaker.get_state(uri))
+    }
+
     pub fn invoke_wrapper<T: DeserializeOwned>(
         &self,
         wrapper: Arc<Mutex<dyn Wrapper>>,
@@ -66,6 +116,149 @@ impl PolywrapClient {
         decode(result.as_slice())
             .map_err(|e| Error::InvokeError(format!("Failed to decode result: {}", e)))
     }
+
+    // Each URI is resolved once, with `resolution_context`, and each item's
+    // invocation steps are added to it after the batch, in item order.
+    // Parallel batches run on a pool of at most `available_parallelism`
+    // threads. Only items with different URIs run in parallel: items with the
+    // same URI share one wrapper, which is locked for each invocation, so they
+    // run one at a time even when `parallel` is set. Wasm wrappers compile
+    // their module once, so these items only pay for a new instance each.
+    // An item that panics fails with an `InvokeError`, in either mode, and the
+    // items after it with the same URI still run
+    pub fn invoke_batch(
+        &self,
+        items: &[InvokeBatchItem],
+        parallel: bool,
+        resolution_context: Option<&mut UriResolutionContext>,
+    ) -> Vec<Result<Vec<u8>, Error>> {
+        let mut empty_res_context = UriResolutionContext::new();
+        let resolution_context = match resolution_context {
+            None => &mut empty_res_context,
+            Some(ctx) => ctx,
+        };
+
+        let mut wrappers: HashMap<String, Result<Arc<Mutex<dyn Wrapper>>, Error>> = HashMap::new();
+        for item in items {
+            if !wrappers.contains_key(&item.uri.uri) {
+                let wrapper = self
+                    .loader
+                    .load_wrapper(&item.uri, Some(resolution_context))
+                    .map_err(|e| Error::LoadWrapperError(e.to_string()));
+                wrappers.insert(item.uri.uri.clone(), wrapper);
+            }
+        }
+
+        let invoke_item = |item: &InvokeBatchItem, context: &mut UriResolutionContext| -> Result<Vec<u8>, Error> {
+            let wrapper = wrappers[&item.uri.uri].clone()?;
+            let env = match &item.env {
+                Some(env) => Some(env.clone()),
+                None => self.loader.get_env_by_uri(&item.uri).map(|env| env.to_owned()),
+            };
+
+            panic::catch_unwind(AssertUnwindSafe(|| {
+                self.invoke_wrapper_raw(
+                    wrapper,
+                    &item.uri,
+                    &item.method,
+                    item.args.as_deref(),
+                    env,
+                    Some(context),
+                )
+            }))
+            .unwrap_or_else(|_| Err(Error::InvokeError("Batch invocation panicked".to_string())))
+        };
+
+        let mut contexts: Vec<UriResolutionContext> = items
+            .iter()
+            .map(|_| resolution_context.create_sub_history_context())
+            .collect();
+
+        let results = if parallel {
+            let workers = thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1)
+                .min(items.len());
+            let queue = Mutex::new(items.iter().zip(contexts.iter_mut()).enumerate());
+            let results = Mutex::new((0..items.len()).map(|_| None).collect::<Vec<_>>());
+
+            thread::scope(|scope| {
+                for _ in 0..workers {
+                    scope.spawn(|| loop {
+                        let Some((index, (item, context))) = queue.lock().unwrap().next() else {
+                            break;
+                        };
+                        let result = invoke_item(item, context);
+                        results.lock().unwrap()[index] = Some(result);
+                    });
+                }
+            });
+
+            results
+                .into_inner()
+                .unwrap()
+                .into_iter()
+                .map(|result| result.expect("every batch item has a result"))
+                .collect()
+        } else {
+            items
+                .iter()
+                .zip(contexts.iter_mut())
+                .map(|(item, context)| invoke_item(item, context))
+                .collect()
+        };
+
+        for context in contexts {
+            for step in context.get_history() {
+                resolution_context.track_step(step.clone());
+            }
+        }
+
+        results
+    }
+
+    pub fn invoke_json(
+        &self,
+        uri: &Uri,
+        method: &str,
+        args: Option<Value>,
+        env: Option<Env>,
+        resolution_context: Option<&mut UriResolutionContext>,
+    ) -> Result<Value, Error> {
+        let mut empty_res_context = UriResolutionContext::new();
+        let resolution_context = match resolution_context {
+            None => &mut empty_res_context,
+            Some(ctx) => ctx,
+        };
+
+        let wrapper = self
+            .loader
+            .load_wrapper(uri, Some(resolution_context))
+            .map_err(|e| Error::LoadWrapperError(e.to_string()))?;
+
+        let abi = wrapper
+            .lock().unwrap()
+            .get_manifest()
+            .map(|manifest| manifest.abi.clone())
+            .unwrap_or_default();
+
+        let args = json_args_to_msgpack(&abi, method, &args.unwrap_or(Value::Null))?;
+        let env = match env {
+            Some(env) => Some(env),
+            None => self.loader.get_env_by_uri(uri).map(|env| env.to_owned()),
+        };
+
+        let result = self.invoke_wrapper_raw(
+            wrapper,
+            uri,
+            method,
+            Some(&args),
+            env,
+            Some(resolution_context),
+        )?;
+
+        msgpack_result_to_json(&abi, method, &result)
+    }
 }
 
 impl Invoker for PolywrapClient {
@@ -105,6 +298,22 @@ impl Invoker for PolywrapClient {
     fn get_interfaces(&self) -> Option<InterfaceImplementations> {
         self.invoker.get_interfaces()
     }
+
+    fn get_cancellation_token(&self) -> Option<CancellationToken> {
+        self.invoker.get_cancellation_token()
+    }
+
+    fn with_cancellation_token(&self, token: CancellationToken) -> Option<Arc<dyn Invoker>> {
+        self.invoker.with_cancellation_token(token)
+    }
+
+    fn get_circuit_breaker(&self) -> Option<CircuitBreaker> {
+        self.invoker.get_circuit_breaker()
+    }
+
+    fn check_subinvoke_permission(&self, uri: &Uri, method: &str) -> Result<(), Error> {
+        self.invoker.check_subinvoke_permission(uri, method)
+    }
 }
 
 impl Client for PolywrapClient {
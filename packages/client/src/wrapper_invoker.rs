@@ -1,10 +1,14 @@
-use std::sync::{Arc, Mutex};
+use std::{sync::{Arc, Mutex, PoisonError}, thread};
 
 use polywrap_core::{
+    abi::validation::{format_validation_errors, validate_args, validate_invocation_env},
+    cancellation::CancellationToken,
+    circuit_breaker::{CircuitBreaker, CircuitState},
     error::Error,
     invoke::{Invoker},
     loader::Loader,
-    resolvers::uri_resolution_context::UriResolutionContext,
+    resolvers::uri_resolution_context::{UriPackageOrWrapper, UriResolutionContext, UriResolutionStep},
+    retry::{RetryErrorKind, RetryPolicy},
     wrapper::Wrapper, uri::Uri, env::{Env}, 
     interface_implementation::InterfaceImplementations
 };
@@ -14,13 +18,230 @@ use crate::wrapper_loader::WrapperLoader;
 #[derive(Clone)]
 pub struct WrapperInvoker {
     pub loader: WrapperLoader,
+    // The wrapper this invoker was handed to, if any: its invocations are
+    // subinvocations and subject to the client's permission policy
+    pub caller: Option<Uri>,
+    // Every wrapper up the call chain, each holding a quota permit until its
+    // invocation returns
+    pub call_chain: Vec<Uri>,
 }
 
 impl WrapperInvoker {
     pub fn new(
         loader: WrapperLoader
     ) -> Self {
-        Self { loader }
+        Self { loader, caller: None, call_chain: vec![] }
+    }
+
+    fn for_caller(&self, caller: &Uri) -> Self {
+        let mut call_chain = self.call_chain.clone();
+        call_chain.push(caller.clone());
+
+        Self {
+            loader: self.loader.clone(),
+            caller: Some(caller.clone()),
+            call_chain,
+        }
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.loader
+            .cancellation_token
+            .as_ref()
+            .is_some_and(|token| token.is_cancelled())
+    }
+
+    fn get_retry_policy(&self, uri: &Uri, method: &str) -> Option<RetryPolicy> {
+        self.loader
+            .retry_policies
+            .as_ref()
+            .and_then(|policies| policies.get_policy(uri, method))
+            .cloned()
+    }
+
+    fn invoke_wrapper_once(
+        &self,
+        wrapper: Arc<Mutex<dyn Wrapper>>,
+        uri: &Uri,
+        method: &str,
+        args: Option<&[u8]>,
+        env: Option<Env>,
+        resolution_context: Option<&mut UriResolutionContext>,
+    ) -> Result<Vec<u8>, Error> {
+        if self.is_cancelled() {
+            return Err(Error::CancelledError(uri.to_string()));
+        }
+
+        // Held until the invocation returns, subinvocations take their own permits
+        let _permit = match &self.loader.quota_manager {
+            Some(quota_manager) => {
+                quota_manager.acquire(uri, &self.call_chain, self.loader.cancellation_token.as_ref())?
+            }
+            None => None,
+        };
+
+        // A wrapper that panicked in an earlier invocation (e.g. a batch item)
+        // is still usable, its lock isn't left poisoned for every later call
+        let mut wrapper = wrapper.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if let Ok(manifest) = wrapper.get_manifest() {
+            validate_invocation_env(&manifest.abi, method, env.as_ref()).map_err(|errors| {
+                Error::EnvValidationError(uri.to_string(), format_validation_errors(&errors))
+            })?;
+
+            if self.loader.validate_args {
+                validate_args(&manifest.abi, method, args).map_err(|errors| {
+                    Error::ArgsValidationError(
+                        uri.to_string(),
+                        method.to_string(),
+                        format_validation_errors(&errors),
+                    )
+                })?;
+            }
+        }
+
+        // A cancelled invocation usually surfaces from the wrapper as a trap or a
+        // failed subinvocation, so the token is checked again once it returns
+        let result = wrapper
+            .invoke(Arc::new(self.for_caller(uri)), uri, method, args, env, resolution_context)
+            .map_err(|e| {
+                if self.is_cancelled() {
+                    Error::CancelledError(uri.to_string())
+                } else {
+                    Error::InvokeError(e.to_string())
+                }
+            })?;
+
+        Ok(result)
+    }
+
+    fn invoke_uri_once(
+        &self,
+        uri: &Uri,
+        method: &str,
+        args: Option<&[u8]>,
+        env: Option<Env>,
+        resolution_context: &mut UriResolutionContext,
+    ) -> Result<Vec<u8>, Error> {
+        if self.is_cancelled() {
+            return Err(Error::CancelledError(uri.to_string()));
+        }
+
+        self.check_subinvoke_permission(uri, method)?;
+
+        self.with_circuit_breaker(uri, resolution_context, |resolution_context| {
+            let (resolved_uri, wrapper) = self
+                .loader
+                .resolve_wrapper(uri, Some(resolution_context))
+                .map_err(|e| Error::LoadWrapperError(e.to_string()))?;
+
+            // A denied wrapper can't be reached through a redirect to it either
+            self.check_subinvoke_permission(&resolved_uri, method)?;
+
+            let mut env = env;
+            if env.is_none() {
+                if let Some(e) = self.loader.get_env_by_uri(&uri.clone()) {
+                    let e = e.to_owned();
+                    env = Some(e);
+                };
+            }
+
+            self.invoke_wrapper_once(wrapper, uri, method, args, env, Some(resolution_context))
+        })
+    }
+
+    // Only failures with a retryable kind count against the circuit: a wrapper
+    // rejecting invalid args or a cancelled invocation says nothing about its health
+    fn with_circuit_breaker(
+        &self,
+        uri: &Uri,
+        resolution_context: &mut UriResolutionContext,
+        invoke: impl FnOnce(&mut UriResolutionContext) -> Result<Vec<u8>, Error>,
+    ) -> Result<Vec<u8>, Error> {
+        let breaker = match &self.loader.circuit_breaker {
+            Some(breaker) => breaker,
+            None => return invoke(resolution_context),
+        };
+
+        let state = match breaker.acquire(uri) {
+            Ok(state) => state,
+            Err(e) => {
+                track_circuit_step(resolution_context, uri, Err(e.clone()), "open, short-circuited");
+                return Err(e);
+            }
+        };
+
+        let result = invoke(resolution_context);
+
+        match &result {
+            Ok(_) => {
+                breaker.record_success(uri);
+                if state == CircuitState::HalfOpen {
+                    track_circuit_step(resolution_context, uri, Ok(()), "probe succeeded, closed");
+                }
+            }
+            Err(e) if RetryErrorKind::from_error(e).is_some() => {
+                if breaker.record_failure(uri) == CircuitState::Open {
+                    let description = match state {
+                        CircuitState::HalfOpen => "probe failed, reopened",
+                        _ => "opened",
+                    };
+                    track_circuit_step(resolution_context, uri, Err(e.clone()), description);
+                }
+            }
+            Err(_) => breaker.release(uri),
+        }
+
+        result
+    }
+
+    fn invoke_with_retries(
+        &self,
+        policy: &RetryPolicy,
+        source_uri: &Uri,
+        uri: &Uri,
+        method: &str,
+        resolution_context: &mut UriResolutionContext,
+        invoke: impl Fn(&mut UriResolutionContext) -> Result<Vec<u8>, Error>,
+    ) -> Result<Vec<u8>, Error> {
+        let max_attempts = policy.max_attempts.max(1);
+        let mut attempt = 1;
+
+        loop {
+            let result = invoke(resolution_context);
+
+            resolution_context.track_step(UriResolutionStep {
+                source_uri: source_uri.clone(),
+                result: match &result {
+                    Ok(_) => Ok(UriPackageOrWrapper::Uri(uri.clone())),
+                    Err(e) => Err(e.clone()),
+                },
+                description: Some(format!(
+                    "Invoke attempt {}/{} of `{}` at {}",
+                    attempt, max_attempts, method, uri
+                )),
+                sub_history: None,
+            });
+
+            match result {
+                Err(e) if attempt < max_attempts && policy.should_retry(&e) => {
+                    // Cancelling the token ends the backoff right away
+                    let backoff = policy.get_backoff(attempt);
+                    let cancelled = match &self.loader.cancellation_token {
+                        Some(token) => token.wait_timeout(backoff),
+                        None => {
+                            thread::sleep(backoff);
+                            false
+                        }
+                    };
+                    if cancelled {
+                        return Err(Error::CancelledError(uri.to_string()));
+                    }
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
     }
 } 
 
@@ -34,11 +255,24 @@ impl Invoker for WrapperInvoker {
         env: Option<Env>,
         resolution_context: Option<&mut UriResolutionContext>,
     ) -> Result<Vec<u8>, Error> {
-        let result = wrapper
-            .lock().unwrap().invoke(Arc::new(self.clone()), uri, method, args, env, resolution_context)
-            .map_err(|e| Error::InvokeError(e.to_string()))?;
+        self.check_subinvoke_permission(uri, method)?;
 
-        Ok(result)
+        let mut empty_res_context = UriResolutionContext::new();
+        let resolution_context = resolution_context.unwrap_or(&mut empty_res_context);
+
+        let invoke = |ctx: &mut UriResolutionContext| {
+            self.with_circuit_breaker(uri, ctx, |ctx| {
+                self.invoke_wrapper_once(wrapper.clone(), uri, method, args, env.clone(), Some(ctx))
+            })
+        };
+
+        // The wrapper is already loaded here, so fallback URIs don't apply
+        match self.get_retry_policy(uri, method) {
+            Some(policy) => {
+                self.invoke_with_retries(&policy, uri, uri, method, resolution_context, invoke)
+            }
+            None => invoke(resolution_context),
+        }
     }
 
     fn invoke_raw(
@@ -50,29 +284,31 @@ impl Invoker for WrapperInvoker {
         resolution_context: Option<&mut UriResolutionContext>,
     ) -> Result<Vec<u8>, Error> {
         let mut empty_res_context = UriResolutionContext::new();
-        let mut resolution_context = match resolution_context {
-            None => &mut empty_res_context,
-            Some(ctx) => ctx,
+        let resolution_context = resolution_context.unwrap_or(&mut empty_res_context);
+
+        // Permissions are checked per candidate, fallbacks included
+        let policy = match self.get_retry_policy(uri, method) {
+            Some(policy) => policy,
+            None => return self.invoke_uri_once(uri, method, args, env, resolution_context),
         };
 
-        let wrapper = self
-            .loader
-            .load_wrapper(uri, Some(&mut resolution_context))
-            .map_err(|e| Error::LoadWrapperError(e.to_string()))?;
-
-        let mut env = env;
-        if env.is_none() {
-            if let Some(e) = self.loader.get_env_by_uri(&uri.clone()) {
-                let e = e.to_owned();
-                env = Some(e);
-            };
-        }
+        let candidates = std::iter::once(uri).chain(policy.fallback_uris.iter());
+        let mut last_error = None;
 
-        let invoke_result = self
-            .invoke_wrapper_raw(wrapper, uri, method, args, env, Some(resolution_context))
-            .map_err(|e| Error::InvokeError(e.to_string()))?;
+        for candidate in candidates {
+            let result = self.invoke_with_retries(&policy, uri, candidate, method, resolution_context, |ctx| {
+                self.invoke_uri_once(candidate, method, args, env.clone(), ctx)
+            });
 
-        Ok(invoke_result)
+            // Errors without a retryable kind (validation, cancellation) would
+            // fail the same way on every fallback
+            match result {
+                Err(e) if policy.should_fallback(&e) => last_error = Some(e),
+                result => return result,
+            }
+        }
+
+        Err(last_error.unwrap())
     }
 
     fn get_implementations(&self, uri: Uri) -> Result<Vec<Uri>, Error> {
@@ -90,4 +326,39 @@ impl Invoker for WrapperInvoker {
 
         None
     }
+
+    fn get_cancellation_token(&self) -> Option<CancellationToken> {
+        self.loader.cancellation_token.clone()
+    }
+
+    fn with_cancellation_token(&self, token: CancellationToken) -> Option<Arc<dyn Invoker>> {
+        let mut invoker = self.clone();
+        invoker.loader.cancellation_token = Some(token);
+        Some(Arc::new(invoker))
+    }
+
+    fn get_circuit_breaker(&self) -> Option<CircuitBreaker> {
+        self.loader.circuit_breaker.clone()
+    }
+
+    fn check_subinvoke_permission(&self, uri: &Uri, method: &str) -> Result<(), Error> {
+        match (&self.caller, &self.loader.permissions) {
+            (Some(caller), Some(permissions)) => permissions.check(caller, uri, method),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn track_circuit_step(
+    resolution_context: &mut UriResolutionContext,
+    uri: &Uri,
+    result: Result<(), Error>,
+    description: &str,
+) {
+    resolution_context.track_step(UriResolutionStep {
+        source_uri: uri.clone(),
+        result: result.map(|_| UriPackageOrWrapper::Uri(uri.clone())),
+        description: Some(format!("Circuit breaker for {}: {}", uri, description)),
+        sub_history: None,
+    });
 }
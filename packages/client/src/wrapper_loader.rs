@@ -7,6 +7,8 @@ use polywrap_core::{
     resolvers::uri_resolution_context::{UriPackageOrWrapper, UriResolutionContext},
     resolvers::uri_resolver::{UriResolver, UriResolverHandler},
     wrapper::Wrapper, env::{Envs, Env}, invoke::Invoker, interface_implementation::InterfaceImplementations,
+    cancellation::CancellationToken, retry::RetryPolicies, circuit_breaker::CircuitBreaker,
+    quota::QuotaManager, permissions::PermissionPolicy, client::ClientConfig,
 };
 
 use crate::wrapper_invoker::WrapperInvoker;
@@ -15,16 +17,53 @@ use crate::wrapper_invoker::WrapperInvoker;
 pub struct WrapperLoader {
     pub resolver: Arc<dyn UriResolver>,
     pub envs: Option<Envs>,
-    pub interfaces: Option<InterfaceImplementations>
+    pub interfaces: Option<InterfaceImplementations>,
+    pub validate_args: bool,
+    pub cancellation_token: Option<CancellationToken>,
+    pub retry_policies: Option<RetryPolicies>,
+    pub circuit_breaker: Option<CircuitBreaker>,
+    pub quota_manager: Option<QuotaManager>,
+    pub permissions: Option<PermissionPolicy>,
 }
 
 impl WrapperLoader {
-    pub fn new(
-        resolver: Arc<dyn UriResolver>, 
-        envs: Option<Envs>,
-        interfaces: Option<InterfaceImplementations>,
-    ) -> Self {
-        Self { resolver, envs, interfaces }
+    pub fn new(config: &ClientConfig) -> Self {
+        Self {
+            resolver: config.resolver.clone(),
+            envs: config.envs.clone(),
+            interfaces: config.interfaces.clone(),
+            validate_args: config.validate_args,
+            cancellation_token: None,
+            retry_policies: config.retry_policies.clone(),
+            circuit_breaker: config.circuit_breaker.clone().map(CircuitBreaker::new),
+            quota_manager: config.quotas.clone().map(QuotaManager::new),
+            permissions: config.permissions.clone(),
+        }
+    }
+
+    // Also returns the URI the wrapper was found at, after redirects
+    pub fn resolve_wrapper(
+        &self,
+        uri: &Uri,
+        resolution_context: Option<&mut UriResolutionContext>,
+    ) -> Result<(Uri, Arc<Mutex<dyn Wrapper>>), Error> {
+        let uri_package_or_wrapper = self
+            .try_resolve_uri(uri, resolution_context)
+            .map_err(|e| Error::ResolutionError(e.to_string()))?;
+
+        match uri_package_or_wrapper {
+            UriPackageOrWrapper::Uri(uri) => Err(Error::InvokeError(format!(
+                "Failed to resolve wrapper: {}",
+                uri
+            ))),
+            UriPackageOrWrapper::Wrapper(uri, wrapper) => Ok((uri, wrapper)),
+            UriPackageOrWrapper::Package(uri, package) => {
+                let wrapper = package
+                    .lock().unwrap()
+                    .create_wrapper().map_err(|e| Error::WrapperCreateError(e.to_string()))?;
+                Ok((uri, wrapper))
+            }
+        }
     }
 }
 
@@ -53,29 +92,7 @@ impl Loader for WrapperLoader {
         uri: &Uri,
         resolution_context: Option<&mut UriResolutionContext>,
     ) -> Result<Arc<Mutex<dyn Wrapper>>, Error> {
-        let mut empty_res_context = UriResolutionContext::new();
-        let mut resolution_ctx = match resolution_context {
-            Some(ctx) => ctx,
-            None => &mut empty_res_context,
-        };
-
-        let uri_package_or_wrapper = self
-            .try_resolve_uri(uri, Some(&mut resolution_ctx))
-            .map_err(|e| Error::ResolutionError(e.to_string()))?;
-
-        match uri_package_or_wrapper {
-            UriPackageOrWrapper::Uri(uri) => Err(Error::InvokeError(format!(
-                "Failed to resolve wrapper: {}",
-                uri
-            ))),
-            UriPackageOrWrapper::Wrapper(_, wrapper) => Ok(wrapper),
-            UriPackageOrWrapper::Package(_, package) => {
-                let wrapper = package
-                    .lock().unwrap()
-                    .create_wrapper().map_err(|e| Error::WrapperCreateError(e.to_string()))?;
-                Ok(wrapper)
-            }
-        }
+        self.resolve_wrapper(uri, resolution_context).map(|(_, wrapper)| wrapper)
     }
 
     fn get_env_by_uri(&self, uri: &Uri) -> Option<&Env> {
@@ -87,8 +104,6 @@ impl Loader for WrapperLoader {
     }
 
     fn get_invoker(&self) -> Result<Arc<dyn Invoker>, Error> {
-        Ok(Arc::new(WrapperInvoker { 
-            loader: self.to_owned()
-        }))
+        Ok(Arc::new(WrapperInvoker::new(self.to_owned())))
     }
 }
@@ -0,0 +1,177 @@
+use std::sync::{Arc, Mutex};
+
+use polywrap_client::client::PolywrapClient;
+use polywrap_core::{invoke::{Invoker}, resolvers::{static_resolver::{StaticResolverLike, StaticResolver}, uri_resolution_context::UriPackage}, uri::Uri, client::ClientConfig, error::Error};
+
+use wrap_manifest_schemas::versions::{WrapManifest, WrapManifestAbi};
+use polywrap_msgpack::msgpack;
+use polywrap_plugin::{error::PluginError, module::PluginModule, package::PluginPackage};
+use polywrap_plugin_macro::{plugin_struct, plugin_impl};
+use serde_json::{Value, from_value, json};
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct RepeatArgs {
+    text: String,
+    times: u16,
+}
+
+#[plugin_struct]
+pub struct RepeatPlugin {}
+
+pub trait Module: PluginModule {
+  fn repeat(&mut self, args: &RepeatArgs, invoker: Arc<dyn Invoker>) -> Result<String, PluginError>;
+}
+
+#[plugin_impl]
+impl Module for RepeatPlugin {
+    fn repeat(
+        &mut self,
+        args: &RepeatArgs,
+        _: Arc<dyn Invoker>
+    ) -> Result<String, PluginError> {
+        Ok(args.text.repeat(args.times as usize))
+    }
+}
+
+pub fn get_manifest() -> WrapManifest {
+    WrapManifest {
+        name: "repeat".to_string(),
+        type_: "plugin".to_string(),
+        version: "0.1".to_string(),
+        abi: from_value::<WrapManifestAbi>(json!({
+            "version": "0.1",
+            "moduleType": {
+                "kind": 128,
+                "type": "Module",
+                "methods": [
+                    {
+                        "kind": 64,
+                        "name": "repeat",
+                        "required": true,
+                        "type": "Method",
+                        "arguments": [
+                            {
+                                "kind": 34,
+                                "name": "text",
+                                "required": true,
+                                "type": "String",
+                                "scalar": { "kind": 4, "name": "text", "required": true, "type": "String" }
+                            },
+                            {
+                                "kind": 34,
+                                "name": "times",
+                                "required": true,
+                                "type": "UInt8",
+                                "scalar": { "kind": 4, "name": "times", "required": true, "type": "UInt8" }
+                            }
+                        ],
+                        "return": {
+                            "kind": 34,
+                            "name": "repeat",
+                            "required": true,
+                            "type": "String",
+                            "scalar": { "kind": 4, "name": "repeat", "required": true, "type": "String" }
+                        }
+                    }
+                ]
+            }
+        })).unwrap()
+    }
+}
+
+fn get_client(validate_args: bool) -> PolywrapClient {
+    let plugin = RepeatPlugin { env: Value::Null };
+    let package: PluginPackage = plugin.into();
+
+    let static_resolver = StaticResolver::from(vec![
+        StaticResolverLike::Package(UriPackage {
+            package: Arc::new(Mutex::new(package)),
+            uri: Uri::try_from("ens/repeat-plugin.eth").unwrap()
+        })
+    ]);
+
+    PolywrapClient::new(
+        ClientConfig {
+            resolver: Arc::new(static_resolver),
+            validate_args,
+            ..Default::default()
+        }
+    )
+}
+
+fn invoke(client: &PolywrapClient, method: &str, args: &[u8]) -> Result<String, Error> {
+    client.invoke::<String>(
+        &Uri::try_from("ens/repeat-plugin.eth").unwrap(),
+        method,
+        Some(args),
+        None,
+        None,
+    )
+}
+
+#[test]
+fn valid_args() {
+    let client = get_client(true);
+
+    let result = invoke(&client, "repeat", &msgpack!({"text": "ab", "times": 3})).unwrap();
+    assert_eq!(result, "ababab");
+}
+
+#[test]
+fn valid_typed_args() {
+    let client = get_client(true);
+
+    let args = polywrap_msgpack::serialize(RepeatArgs { text: "ab".to_string(), times: 2 }).unwrap();
+    let result = invoke(&client, "repeat", &args).unwrap();
+    assert_eq!(result, "abab");
+}
+
+#[test]
+fn invalid_typed_args_report_argument_paths() {
+    let client = get_client(true);
+
+    let args = polywrap_msgpack::serialize(("ab", 256)).unwrap();
+    let error = invoke(&client, "repeat", &args).unwrap_err();
+    assert!(error.to_string().contains("`args.times`: invalid value integer 256 (expected `UInt8!`)"));
+}
+
+#[test]
+fn invalid_args_report_argument_paths() {
+    let client = get_client(true);
+
+    let error = invoke(&client, "repeat", &msgpack!({"times": 256})).unwrap_err();
+    match error {
+        Error::ArgsValidationError(uri, method, issues) => {
+            assert_eq!(uri, "wrap://ens/repeat-plugin.eth");
+            assert_eq!(method, "repeat");
+            assert!(issues.contains("`args.text`: missing required field (expected `String!`)"));
+            assert!(issues.contains("`args.times`: invalid value integer 256 (expected `UInt8!`)"));
+        }
+        e => panic!("Unexpected error: {}", e),
+    }
+}
+
+#[test]
+fn unknown_method() {
+    let client = get_client(true);
+
+    let error = invoke(&client, "repeatTwice", &msgpack!({"text": "ab"})).unwrap_err();
+    assert!(matches!(error, Error::ArgsValidationError(..)));
+    assert!(error.to_string().contains("method `repeatTwice` is not defined in the ABI (expected `repeat`)"));
+}
+
+#[test]
+fn validation_is_opt_in() {
+    let client = get_client(false);
+
+    let result = invoke(&client, "repeat", &msgpack!({"text": "a", "times": 256})).unwrap();
+    assert_eq!(result.len(), 256);
+}
+
+#[test]
+fn undecodable_args_without_validation() {
+    let client = get_client(false);
+
+    let error = invoke(&client, "repeat", &msgpack!({"times": 3})).unwrap_err();
+    assert!(error.to_string().contains("Invalid args for method 'repeat'"));
+}
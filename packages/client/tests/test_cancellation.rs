@@ -0,0 +1,80 @@
+use std::{sync::{Arc, Mutex}, thread, time::Duration};
+
+use polywrap_client::client::PolywrapClient;
+use polywrap_client::core::{
+    cancellation::CancellationToken,
+    client::ClientConfig,
+    error::Error,
+    file_reader::SimpleFileReader,
+    resolvers::{
+        static_resolver::{StaticResolver, StaticResolverLike},
+        uri_resolution_context::UriWrapper,
+    },
+    uri::Uri,
+};
+use polywrap_wasm::wasm_wrapper::WasmWrapper;
+use serde_json::{from_value, json};
+use wrap_manifest_schemas::versions::{WrapManifest, WrapManifestAbi};
+
+// Never calls an import, so it can only be stopped by the interrupt middleware
+const SPINNING_MODULE: &str = r#"
+(module
+  (import "env" "memory" (memory 1))
+  (func (export "_wrap_invoke") (param i32 i32 i32) (result i32)
+    (loop $spin (br $spin))
+    (i32.const 1)))
+"#;
+
+fn get_client() -> PolywrapClient {
+    let manifest = WrapManifest {
+        name: "spinning".to_string(),
+        type_: "wasm".to_string(),
+        version: "0.1".to_string(),
+        abi: from_value::<WrapManifestAbi>(json!({})).unwrap(),
+    };
+    let wrapper = WasmWrapper::new(
+        wat::parse_str(SPINNING_MODULE).unwrap(),
+        Arc::new(SimpleFileReader::new()),
+        manifest,
+    );
+
+    let resolver = StaticResolver::from(vec![StaticResolverLike::Wrapper(UriWrapper {
+        uri: Uri::try_from("ens/spinning.eth").unwrap(),
+        wrapper: Arc::new(Mutex::new(wrapper)),
+    })]);
+    PolywrapClient::new(ClientConfig { resolver: Arc::new(resolver), ..Default::default() })
+}
+
+#[test]
+fn interrupts_compute_bound_wasm() {
+    let token = CancellationToken::new();
+    let canceller = {
+        let token = token.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(200));
+            token.cancel();
+        })
+    };
+
+    let error = get_client()
+        .invoke_raw_with_cancellation(&Uri::try_from("ens/spinning.eth").unwrap(), "spin", None, None, None, &token)
+        .unwrap_err();
+    canceller.join().unwrap();
+    assert!(matches!(error, Error::CancelledError(uri) if uri == "wrap://ens/spinning.eth"));
+}
+
+#[test]
+fn interrupts_wasm_once_a_child_token_times_out() {
+    let parent = CancellationToken::new();
+    let token = parent.child_with_timeout(Duration::from_millis(200));
+
+    let error = get_client()
+        .invoke_raw_with_cancellation(&Uri::try_from("ens/spinning.eth").unwrap(), "spin", None, None, None, &token)
+        .unwrap_err();
+    assert!(matches!(error, Error::CancelledError(..)));
+    assert!(token.is_timed_out());
+    assert!(!parent.is_cancelled());
+
+    parent.cancel();
+    assert!(parent.child().is_cancelled());
+}
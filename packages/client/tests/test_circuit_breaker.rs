@@ -0,0 +1,225 @@
+use std::{collections::HashMap, sync::{Arc, Mutex, atomic::{AtomicBool, AtomicU32, Ordering}}, thread, time::Duration};
+
+use polywrap_client::client::PolywrapClient;
+use polywrap_core::{circuit_breaker::{CircuitBreakerConfig, CircuitState}, invoke::{Invoker}, resolvers::{recursive_resolver::RecursiveResolver, static_resolver::{StaticResolverLike, StaticResolver}, uri_resolution_context::{UriPackage, UriResolutionContext, UriResolutionStep}, uri_resolver_like::UriResolverLike}, uri::Uri, client::ClientConfig, error::Error};
+
+use wrap_manifest_schemas::versions::{WrapManifest, WrapManifestAbi};
+use polywrap_msgpack::msgpack;
+use polywrap_plugin::{error::PluginError, module::PluginModule, package::PluginPackage};
+use polywrap_plugin_macro::{plugin_struct, plugin_impl};
+use polywrap_resolvers::extendable_uri_resolver::ExtendableUriResolver;
+use serde_json::{Value, from_value, json};
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PingArgs {}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ArgsTryResolveUri {
+    authority: String,
+    path: String,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct MaybeUriOrManifest {
+    uri: Option<String>,
+    manifest: Option<Vec<u8>>,
+}
+
+#[plugin_struct]
+pub struct RemotePlugin {
+    healthy: Arc<AtomicBool>,
+    calls: Arc<AtomicU32>,
+}
+
+#[plugin_struct]
+pub struct ResolverExtensionPlugin {
+    healthy: Arc<AtomicBool>,
+    calls: Arc<AtomicU32>,
+}
+
+pub trait RemoteModule: PluginModule {
+  fn ping(&mut self, args: &PingArgs, invoker: Arc<dyn Invoker>) -> Result<bool, PluginError>;
+}
+
+pub trait ResolverModule: PluginModule {
+  fn try_resolve_uri(&mut self, args: &ArgsTryResolveUri, invoker: Arc<dyn Invoker>) -> Result<MaybeUriOrManifest, PluginError>;
+}
+
+fn unavailable(uri: &str, method: &str) -> PluginError {
+    PluginError::InvocationError {
+        uri: uri.to_string(),
+        method: method.to_string(),
+        args: "".to_string(),
+        exception: "service unavailable".to_string(),
+    }
+}
+
+#[plugin_impl]
+impl RemoteModule for RemotePlugin {
+    fn ping(
+        &mut self,
+        _: &PingArgs,
+        _: Arc<dyn Invoker>
+    ) -> Result<bool, PluginError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        if !self.healthy.load(Ordering::SeqCst) {
+            return Err(unavailable("ens/remote.eth", "ping"));
+        }
+        Ok(true)
+    }
+}
+
+#[plugin_impl]
+impl ResolverModule for ResolverExtensionPlugin {
+    fn try_resolve_uri(
+        &mut self,
+        _: &ArgsTryResolveUri,
+        _: Arc<dyn Invoker>
+    ) -> Result<MaybeUriOrManifest, PluginError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        if !self.healthy.load(Ordering::SeqCst) {
+            return Err(unavailable("ens/resolver-extension.eth", "tryResolveUri"));
+        }
+        Ok(MaybeUriOrManifest { uri: None, manifest: None })
+    }
+}
+
+pub fn get_manifest() -> WrapManifest {
+    WrapManifest {
+        name: "remote".to_string(),
+        type_: "plugin".to_string(),
+        version: "0.1".to_string(),
+        abi: from_value::<WrapManifestAbi>(json!({})).unwrap()
+    }
+}
+
+const COOLDOWN: Duration = Duration::from_millis(100);
+
+fn get_client(healthy: Arc<AtomicBool>, calls: Arc<AtomicU32>) -> PolywrapClient {
+    let remote: PluginPackage = RemotePlugin {
+        env: Value::Null,
+        healthy: healthy.clone(),
+        calls: calls.clone(),
+    }.into();
+    let extension: PluginPackage = ResolverExtensionPlugin { env: Value::Null, healthy, calls }.into();
+
+    let static_resolver = StaticResolver::from(vec![
+        StaticResolverLike::Package(UriPackage {
+            package: Arc::new(Mutex::new(remote)),
+            uri: Uri::try_from("ens/remote.eth").unwrap()
+        }),
+        StaticResolverLike::Package(UriPackage {
+            package: Arc::new(Mutex::new(extension)),
+            uri: Uri::try_from("ens/resolver-extension.eth").unwrap()
+        })
+    ]);
+    let resolver = RecursiveResolver::from(vec![
+        UriResolverLike::Resolver(Box::new(static_resolver)),
+        UriResolverLike::Resolver(Box::new(ExtendableUriResolver::new(None))),
+    ]);
+
+    let interfaces = HashMap::from([(
+        "wrap://ens/uri-resolver.core.polywrap.eth".to_string(),
+        vec![Uri::try_from("ens/resolver-extension.eth").unwrap()],
+    )]);
+
+    PolywrapClient::new(
+        ClientConfig {
+            interfaces: Some(interfaces),
+            resolver: Arc::new(resolver),
+            circuit_breaker: Some(CircuitBreakerConfig {
+                failure_threshold: 2,
+                cooldown: COOLDOWN,
+            }),
+            ..Default::default()
+        }
+    )
+}
+
+fn ping(client: &PolywrapClient, uri: &str, ctx: &mut UriResolutionContext) -> Result<bool, Error> {
+    client.invoke::<bool>(
+        &Uri::try_from(uri).unwrap(),
+        "ping",
+        Some(&msgpack!({})),
+        None,
+        Some(ctx),
+    )
+}
+
+fn has_step(history: &[UriResolutionStep], description: &str) -> bool {
+    history.iter().any(|step| {
+        step.description.as_deref().is_some_and(|d| d.ends_with(description))
+            || step.sub_history.as_deref().is_some_and(|sub| has_step(sub, description))
+    })
+}
+
+#[test]
+fn opens_short_circuits_and_recovers() {
+    let healthy = Arc::new(AtomicBool::new(false));
+    let calls = Arc::new(AtomicU32::new(0));
+    let client = get_client(healthy.clone(), calls.clone());
+    let uri = Uri::try_from("ens/remote.eth").unwrap();
+
+    assert!(ping(&client, "ens/remote.eth", &mut UriResolutionContext::new()).is_err());
+    assert_eq!(client.get_circuit_state(&uri), Some(CircuitState::Closed));
+
+    let mut ctx = UriResolutionContext::new();
+    assert!(ping(&client, "ens/remote.eth", &mut ctx).is_err());
+    assert_eq!(client.get_circuit_state(&uri), Some(CircuitState::Open));
+    assert!(has_step(ctx.get_history(), "opened"));
+
+    let mut ctx = UriResolutionContext::new();
+    let error = ping(&client, "ens/remote.eth", &mut ctx).unwrap_err();
+    assert!(matches!(error, Error::CircuitOpenError(uri) if uri == "wrap://ens/remote.eth"));
+    assert!(has_step(ctx.get_history(), "open, short-circuited"));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    thread::sleep(COOLDOWN);
+    assert_eq!(client.get_circuit_state(&uri), Some(CircuitState::HalfOpen));
+    healthy.store(true, Ordering::SeqCst);
+
+    let mut ctx = UriResolutionContext::new();
+    assert!(ping(&client, "ens/remote.eth", &mut ctx).unwrap());
+    assert!(has_step(ctx.get_history(), "probe succeeded, closed"));
+    assert_eq!(client.get_circuit_state(&uri), Some(CircuitState::Closed));
+}
+
+#[test]
+fn failed_probe_reopens_circuit() {
+    let healthy = Arc::new(AtomicBool::new(false));
+    let calls = Arc::new(AtomicU32::new(0));
+    let client = get_client(healthy, calls.clone());
+    let uri = Uri::try_from("ens/remote.eth").unwrap();
+
+    for _ in 0..2 {
+        assert!(ping(&client, "ens/remote.eth", &mut UriResolutionContext::new()).is_err());
+    }
+    thread::sleep(COOLDOWN);
+
+    let mut ctx = UriResolutionContext::new();
+    assert!(matches!(ping(&client, "ens/remote.eth", &mut ctx), Err(Error::InvokeError(_))));
+    assert!(has_step(ctx.get_history(), "probe failed, reopened"));
+    assert_eq!(client.get_circuit_state(&uri), Some(CircuitState::Open));
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn skips_resolver_extensions_with_open_circuits() {
+    let healthy = Arc::new(AtomicBool::new(false));
+    let calls = Arc::new(AtomicU32::new(0));
+    let client = get_client(healthy, calls.clone());
+    let extension_uri = Uri::try_from("ens/resolver-extension.eth").unwrap();
+
+    // Each URI gets its own circuit, so distinct URIs keep them from opening
+    for uri in ["ens/missing-1.eth", "ens/missing-2.eth"] {
+        assert!(ping(&client, uri, &mut UriResolutionContext::new()).is_err());
+    }
+    assert_eq!(client.get_circuit_state(&extension_uri), Some(CircuitState::Open));
+
+    // The extension is skipped and resolution falls through without calling it
+    let mut ctx = UriResolutionContext::new();
+    let error = ping(&client, "ens/missing-3.eth", &mut ctx).unwrap_err();
+    assert!(matches!(error, Error::LoadWrapperError(_)));
+    assert!(has_step(ctx.get_history(), "open, skipped"));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
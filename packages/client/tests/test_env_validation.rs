@@ -0,0 +1,197 @@
+use std::{collections::HashMap, sync::{Arc, Mutex}};
+
+use polywrap_client::client::PolywrapClient;
+use polywrap_core::{invoke::{Invoker}, resolvers::{static_resolver::{StaticResolverLike, StaticResolver}, uri_resolution_context::UriPackage}, uri::Uri, client::ClientConfig, error::Error, env::Env};
+
+use wrap_manifest_schemas::versions::{WrapManifest, WrapManifestAbi};
+use polywrap_msgpack::msgpack;
+use polywrap_plugin::{error::PluginError, module::{PluginModule, PluginWithEnv}, package::PluginPackage};
+use polywrap_plugin_macro::{plugin_struct, plugin_impl};
+use serde_json::{Value, from_value, json};
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct GetEnvArgs {
+    key: String
+}
+
+#[plugin_struct]
+pub struct PluginEnv {}
+
+pub trait Module: PluginModule {
+  fn get_env_value(&mut self, args: &GetEnvArgs, invoker: Arc<dyn Invoker>) -> Result<Option<String>, PluginError>;
+}
+
+#[plugin_impl]
+impl Module for PluginEnv {
+    fn get_env_value(
+        &mut self,
+        args: &GetEnvArgs,
+        _: Arc<dyn Invoker>
+    ) -> Result<Option<String>, PluginError> {
+        Ok(self.get_env(args.key.clone()).map(|v| v.to_string()))
+    }
+}
+
+pub fn get_manifest() -> WrapManifest {
+    WrapManifest {
+        name: "env".to_string(),
+        type_: "plugin".to_string(),
+        version: "0.1".to_string(),
+        abi: from_value::<WrapManifestAbi>(json!({
+            "version": "0.1",
+            "envType": {
+                "kind": 65536,
+                "type": "Env",
+                "properties": [
+                    {
+                        "kind": 34,
+                        "name": "apiKey",
+                        "required": true,
+                        "type": "String",
+                        "scalar": { "kind": 4, "name": "apiKey", "required": true, "type": "String" }
+                    },
+                    {
+                        "kind": 34,
+                        "name": "retries",
+                        "type": "UInt8",
+                        "scalar": { "kind": 4, "name": "retries", "type": "UInt8" }
+                    },
+                    {
+                        "kind": 34,
+                        "name": "endpoint",
+                        "type": "Endpoint",
+                        "object": { "kind": 8192, "name": "endpoint", "type": "Endpoint" }
+                    }
+                ]
+            },
+            "objectTypes": [
+                {
+                    "kind": 1,
+                    "type": "Endpoint",
+                    "properties": [
+                        {
+                            "kind": 34,
+                            "name": "urls",
+                            "required": true,
+                            "type": "[String]",
+                            "array": {
+                                "kind": 18,
+                                "name": "urls",
+                                "required": true,
+                                "type": "[String]",
+                                "item": { "kind": 4, "name": "urls", "required": true, "type": "String" },
+                                "scalar": { "kind": 4, "name": "urls", "required": true, "type": "String" }
+                            }
+                        }
+                    ]
+                }
+            ],
+            "moduleType": {
+                "kind": 128,
+                "type": "Module",
+                "methods": [
+                    {
+                        "kind": 64,
+                        "name": "getEnvValue",
+                        "required": true,
+                        "type": "Method",
+                        "env": { "required": true }
+                    }
+                ]
+            }
+        })).unwrap()
+    }
+}
+
+fn get_client(env: Option<Env>) -> PolywrapClient {
+    let plugin = PluginEnv { env: Value::Null };
+    let package: PluginPackage = plugin.into();
+
+    let static_resolver = StaticResolver::from(vec![
+        StaticResolverLike::Package(UriPackage {
+            package: Arc::new(Mutex::new(package)),
+            uri: Uri::try_from("ens/env-plugin.eth").unwrap()
+        })
+    ]);
+
+    let envs = env.map(|env| HashMap::from([
+        (Uri::try_from("ens/env-plugin.eth").unwrap().uri, env)
+    ]));
+
+    PolywrapClient::new(
+        ClientConfig {
+            envs,
+            resolver: Arc::new(static_resolver),
+            ..Default::default()
+        }
+    )
+}
+
+fn invoke(client: &PolywrapClient) -> Result<Option<String>, Error> {
+    client.invoke::<Option<String>>(
+        &Uri::try_from("ens/env-plugin.eth").unwrap(),
+        "getEnvValue",
+        Some(&msgpack!({"key": "apiKey"})),
+        None,
+        None,
+    )
+}
+
+#[test]
+fn valid_env() {
+    let client = get_client(Some(json!({
+        "apiKey": "secret",
+        "retries": 3,
+        "endpoint": { "urls": ["https://polywrap.io"] }
+    })));
+
+    assert_eq!(invoke(&client).unwrap(), Some("\"secret\"".to_string()));
+}
+
+#[test]
+fn invalid_env_reports_field_paths() {
+    let client = get_client(Some(json!({
+        "apiKy": "secret",
+        "retries": 300,
+        "endpoint": { "urls": ["https://polywrap.io", 1] }
+    })));
+
+    let error = invoke(&client).unwrap_err();
+    match error {
+        Error::EnvValidationError(uri, issues) => {
+            assert_eq!(uri, "wrap://ens/env-plugin.eth");
+            assert!(issues.contains("`env.apiKey`: missing required field (expected `String!`)"));
+            assert!(issues.contains("`env.apiKy`: unexpected field (expected `Env`)"));
+            assert!(issues.contains("`env.retries`: invalid value integer 300 (expected `UInt8`)"));
+            assert!(issues.contains("`env.endpoint.urls[1]`: type mismatch, found integer 1 (expected `String!`)"));
+        }
+        e => panic!("Unexpected error: {}", e),
+    }
+}
+
+#[test]
+fn typo_in_optional_field() {
+    let client = get_client(Some(json!({
+        "apiKey": "secret",
+        "retires": 3,
+        "endpoint": { "urls": ["https://polywrap.io"], "timeout": 10 }
+    })));
+
+    let error = invoke(&client).unwrap_err();
+    match error {
+        Error::EnvValidationError(_, issues) => {
+            assert!(issues.contains("`env.retires`: unexpected field (expected `Env`)"));
+            assert!(issues.contains("`env.endpoint.timeout`: unexpected field (expected `Endpoint`)"));
+        }
+        e => panic!("Unexpected error: {}", e),
+    }
+}
+
+#[test]
+fn missing_required_env() {
+    let client = get_client(None);
+
+    let error = invoke(&client).unwrap_err();
+    assert!(matches!(error, Error::EnvValidationError(..)));
+    assert!(error.to_string().contains("method `getEnvValue` requires an env"));
+}
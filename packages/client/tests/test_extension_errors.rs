@@ -0,0 +1,114 @@
+use std::{fs, path::PathBuf, time::{Duration, Instant}};
+
+use httpmock::prelude::*;
+use polywrap_client::client::PolywrapClient;
+use polywrap_client::builder::{helpers::{add_default, build_resolver}, types::{BuilderConfig, ClientBuilder}};
+use polywrap_client::core::{
+    error::Error,
+    resolvers::{uri_resolution_context::UriPackageOrWrapper, uri_resolver::UriResolverHandler},
+    uri::Uri,
+};
+use polywrap_client::resolvers::extendable_uri_resolver::ExtensionOptions;
+
+const MISSING_RESOLVER: &str = "wrap://ens/missing-resolver.eth";
+const FS_RESOLVER: &str = "wrap://ens/fs-resolver.polywrap.eth";
+const HTTP_RESOLVER: &str = "wrap://ens/http-resolver.polywrap.eth";
+
+fn embedded_file(name: &str) -> Vec<u8> {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../builder/src/embeds/ipfs_http_client");
+    fs::read(dir.join(name)).unwrap()
+}
+
+// The default extensions are replaced rather than added to
+fn get_client(extensions: &[&str], options: ExtensionOptions) -> PolywrapClient {
+    let mut builder = BuilderConfig::new(Some(add_default()));
+    builder.interfaces.get_or_insert_with(Default::default).insert(
+        "wrap://ens/uri-resolver.core.polywrap.eth".to_string(),
+        extensions.iter().map(|uri| Uri::try_from(*uri).unwrap()).collect(),
+    );
+    builder.set_extension_options(options);
+    PolywrapClient::new(build_resolver(builder))
+}
+
+fn wrapper_uri(name: &str) -> Uri {
+    let dir = std::env::temp_dir().join(format!("extension_errors_{}_{}", name, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("wrap.info"), embedded_file("wrap.info")).unwrap();
+    fs::write(dir.join("wrap.wasm"), embedded_file("wrap.wasm")).unwrap();
+    Uri::try_from(format!("wrap://fs/{}", dir.display())).unwrap()
+}
+
+#[test]
+fn fails_fast_by_default() {
+    let uri = wrapper_uri("fail_fast");
+    let client = get_client(&[MISSING_RESOLVER, FS_RESOLVER], ExtensionOptions::default());
+
+    let error = client.try_resolve_uri(&uri, None).err().unwrap();
+    assert!(error.to_string().contains(MISSING_RESOLVER));
+}
+
+#[test]
+fn continues_on_error() {
+    let uri = wrapper_uri("continue");
+    let options = ExtensionOptions { continue_on_error: true, ..Default::default() };
+    let client = get_client(&[MISSING_RESOLVER, FS_RESOLVER], options);
+
+    let result = client.try_resolve_uri(&uri, None).unwrap();
+    assert!(!matches!(result, UriPackageOrWrapper::Uri(..)));
+
+    // Without an extension resolving the URI, every failure is reported
+    let missing = Uri::try_from("wrap://fs/missing/wrapper").unwrap();
+    let error = client.try_resolve_uri(&missing, None).err().unwrap();
+    let Error::ResolversFailedError(failed_uri, failures) = &error else {
+        panic!("Expected an aggregated error, got {}", error);
+    };
+    assert_eq!(failed_uri, &missing.to_string());
+    assert_eq!(failures.len(), 1);
+    assert!(failures[0].starts_with(&format!("UriResolverWrapper: {}", MISSING_RESOLVER)));
+}
+
+#[test]
+fn times_out_slow_extensions() {
+    let server = MockServer::start();
+    for file in ["wrap.info", "wrap.wasm"] {
+        server.mock(|when, then| {
+            when.method(GET).path(format!("/wrapper/{}", file));
+            then.status(200).body(embedded_file(file)).delay(Duration::from_millis(300));
+        });
+    }
+    let uri = Uri::try_from(format!("wrap://http/{}", server.url("/wrapper"))).unwrap();
+
+    let options = ExtensionOptions { timeout: Some(Duration::from_millis(100)), ..Default::default() };
+    let started = Instant::now();
+    let error = get_client(&[HTTP_RESOLVER], options.clone()).try_resolve_uri(&uri, None).err().unwrap();
+    assert!(matches!(error, Error::ExtensionTimeoutError(..)));
+    assert!(error.to_string().contains("timed out after 100ms"));
+    // The extension is interrupted, rather than waited on
+    assert!(started.elapsed() < Duration::from_millis(300));
+
+    let mut options = options;
+    options.timeouts.insert(HTTP_RESOLVER.to_string(), Duration::from_secs(10));
+    let result = get_client(&[HTTP_RESOLVER], options).try_resolve_uri(&uri, None).unwrap();
+    assert!(!matches!(result, UriPackageOrWrapper::Uri(..)));
+}
+
+#[test]
+fn times_out_slow_file_reads() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/wrapper/wrap.info");
+        then.status(200).body(embedded_file("wrap.info"));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/wrapper/wrap.wasm");
+        then.status(200).body(embedded_file("wrap.wasm")).delay(Duration::from_millis(300));
+    });
+    let uri = Uri::try_from(format!("wrap://http/{}", server.url("/wrapper"))).unwrap();
+
+    // Resolving only fetches the manifest, the module is read through getFile
+    let options = ExtensionOptions { timeout: Some(Duration::from_millis(100)), ..Default::default() };
+    let started = Instant::now();
+    let error = get_client(&[HTTP_RESOLVER], options).try_resolve_uri(&uri, None).err().unwrap();
+    assert!(matches!(error, Error::ExtensionTimeoutError(..)));
+    assert!(started.elapsed() < Duration::from_millis(300));
+}
@@ -0,0 +1,58 @@
+use polywrap_client::client::PolywrapClient;
+use polywrap_client::builder::types::{BuilderConfig, ClientBuilder, ClientConfigHandler};
+use polywrap_client::core::{
+    client::UriRedirect,
+    error::Error,
+    redirects::apply_redirects,
+    resolvers::uri_resolver::UriResolverHandler,
+    uri::Uri,
+};
+
+fn uri(uri: &str) -> Uri {
+    Uri::try_from(uri).unwrap()
+}
+
+fn redirect(from: &str, to: &str) -> UriRedirect {
+    UriRedirect { from: uri(from), to: uri(to) }
+}
+
+#[test]
+fn reports_the_resolution_cycle() {
+    let mut builder = BuilderConfig::new(None);
+    builder.add_redirects(vec![
+        redirect("wrap://ens/a.eth", "wrap://ens/b.eth"),
+        redirect("wrap://ens/b.eth", "wrap://ens/c.eth"),
+        redirect("wrap://ens/c.eth", "wrap://ens/a.eth"),
+    ]);
+    let client = PolywrapClient::new(builder.build());
+
+    let error = client.try_resolve_uri(&uri("wrap://ens/b.eth"), None).err().unwrap();
+    let Error::InfiniteLoopError(cycle) = &error else {
+        panic!("Expected an infinite loop error, got {}", error);
+    };
+    assert_eq!(cycle, &["wrap://ens/b.eth", "wrap://ens/c.eth", "wrap://ens/a.eth", "wrap://ens/b.eth"]);
+    assert!(error
+        .to_string()
+        .ends_with("wrap://ens/b.eth → wrap://ens/c.eth → wrap://ens/a.eth → wrap://ens/b.eth"));
+}
+
+#[test]
+fn applies_redirects_from_the_resolved_uri() {
+    let redirects = vec![
+        redirect("wrap://ens/a.eth", "wrap://ens/b.eth"),
+        redirect("wrap://ens/b.eth", "wrap://ens/c.eth"),
+        redirect("wrap://ens/a.eth", "wrap://ens/ignored.eth"),
+    ];
+    let result = apply_redirects(&uri("wrap://ens/a.eth"), &redirects).unwrap();
+    assert_eq!(result.to_string(), "wrap://ens/c.eth");
+
+    let redirects = vec![
+        redirect("wrap://ens/a.eth", "wrap://ens/b.eth"),
+        redirect("wrap://ens/b.eth", "wrap://ens/c.eth"),
+        redirect("wrap://ens/c.eth", "wrap://ens/b.eth"),
+    ];
+    let Err(Error::InfiniteLoopError(cycle)) = apply_redirects(&uri("wrap://ens/a.eth"), &redirects) else {
+        panic!("Expected an infinite loop error");
+    };
+    assert_eq!(cycle, ["wrap://ens/b.eth", "wrap://ens/c.eth", "wrap://ens/b.eth"]);
+}
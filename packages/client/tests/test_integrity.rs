@@ -0,0 +1,252 @@
+use std::{collections::HashMap, fs, path::PathBuf, sync::{Arc, Mutex}};
+
+use polywrap_client::client::PolywrapClient;
+use polywrap_client::builder::types::{BuilderConfig, ClientBuilder, ClientConfigHandler};
+use polywrap_client::core::{
+    client::ClientConfig,
+    error::Error,
+    file_reader::SimpleFileReader,
+    integrity::{sha256, signed_message, ContentHashes, IntegrityPolicy},
+    invoke::Invoker,
+    resolvers::{
+        recursive_resolver::RecursiveResolver,
+        static_resolver::{StaticResolver, StaticResolverLike},
+        uri_resolution_context::{UriPackage, UriPackageOrWrapper, UriResolutionContext, UriResolutionStep},
+        uri_resolver::UriResolverHandler,
+        uri_resolver_like::UriResolverLike,
+    },
+    uri::Uri,
+};
+use polywrap_client::resolvers::extendable_uri_resolver::ExtendableUriResolver;
+use polywrap_plugin::{error::PluginError, module::PluginModule, package::PluginPackage};
+use polywrap_plugin_macro::{plugin_struct, plugin_impl};
+use polywrap_wasm::{file_reader::InMemoryFileReader, wasm_package::WasmPackage};
+use ring::{rand::SystemRandom, signature::{Ed25519KeyPair, KeyPair}};
+use serde_json::{Value, from_value, json};
+use wrap_manifest_schemas::versions::{WrapManifest, WrapManifestAbi};
+
+fn embedded_file(name: &str) -> Vec<u8> {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../builder/src/embeds/ipfs_http_client");
+    fs::read(dir.join(name)).unwrap()
+}
+
+fn setup(name: &str) -> (PathBuf, Uri) {
+    let dir = std::env::temp_dir().join(format!("integrity_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("wrap.info"), embedded_file("wrap.info")).unwrap();
+    fs::write(dir.join("wrap.wasm"), embedded_file("wrap.wasm")).unwrap();
+    let uri = Uri::try_from(format!("wrap://fs/{}", dir.display())).unwrap();
+    (dir, uri)
+}
+
+fn get_client(integrity: IntegrityPolicy) -> PolywrapClient {
+    let mut builder = BuilderConfig::new(None);
+    builder.set_integrity_policy(integrity);
+    PolywrapClient::new(builder.build())
+}
+
+fn generate_key() -> Ed25519KeyPair {
+    let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+    Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap()
+}
+
+fn integrity_steps(history: &[UriResolutionStep]) -> Vec<String> {
+    history
+        .iter()
+        .flat_map(|step| {
+            let description = step.description.clone().filter(|d| d.starts_with("Integrity check"));
+            description.into_iter().chain(integrity_steps(step.sub_history.as_deref().unwrap_or_default()))
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ArgsTryResolveUri {
+    authority: String,
+    path: String,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ArgsGetFile {
+    path: String,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct MaybeUriOrManifest {
+    uri: Option<String>,
+    manifest: Option<Vec<u8>>,
+    reason: Option<String>,
+}
+
+// Redirects `pinned*` paths to `ens/target.eth`, resolves nothing else, and
+// fails to read files under `unreadable*` paths
+#[plugin_struct]
+pub struct ResolverExtensionPlugin {}
+
+pub trait ResolverModule: PluginModule {
+  fn try_resolve_uri(&mut self, args: &ArgsTryResolveUri, invoker: Arc<dyn Invoker>) -> Result<MaybeUriOrManifest, PluginError>;
+  fn get_file(&mut self, args: &ArgsGetFile, invoker: Arc<dyn Invoker>) -> Result<Option<Vec<u8>>, PluginError>;
+}
+
+#[plugin_impl]
+impl ResolverModule for ResolverExtensionPlugin {
+    fn try_resolve_uri(
+        &mut self,
+        args: &ArgsTryResolveUri,
+        _: Arc<dyn Invoker>
+    ) -> Result<MaybeUriOrManifest, PluginError> {
+        let uri = args.path.starts_with("pinned").then(|| "wrap://ens/target.eth".to_string());
+        Ok(MaybeUriOrManifest { uri, manifest: None, reason: None })
+    }
+
+    fn get_file(
+        &mut self,
+        args: &ArgsGetFile,
+        _: Arc<dyn Invoker>
+    ) -> Result<Option<Vec<u8>>, PluginError> {
+        if args.path.starts_with("unreadable") {
+            return Err(PluginError::InvocationError {
+                uri: "ens/resolver-extension.eth".to_string(),
+                method: "getFile".to_string(),
+                args: args.path.clone(),
+                exception: "storage unavailable".to_string(),
+            });
+        }
+        Ok(None)
+    }
+}
+
+pub fn get_manifest() -> WrapManifest {
+    WrapManifest {
+        name: "resolver-extension".to_string(),
+        type_: "plugin".to_string(),
+        version: "0.1".to_string(),
+        abi: from_value::<WrapManifestAbi>(json!({})).unwrap()
+    }
+}
+
+fn get_extension_client(integrity: IntegrityPolicy) -> PolywrapClient {
+    let extension: PluginPackage = ResolverExtensionPlugin { env: Value::Null }.into();
+    let reader = InMemoryFileReader::new(
+        Arc::new(SimpleFileReader::new()),
+        Some(embedded_file("wrap.info")),
+        Some(embedded_file("wrap.wasm")),
+    );
+    let target = WasmPackage::new(Arc::new(reader), None, None);
+
+    let static_resolver = StaticResolver::from(vec![
+        StaticResolverLike::Package(UriPackage {
+            package: Arc::new(Mutex::new(extension)),
+            uri: Uri::try_from("ens/resolver-extension.eth").unwrap()
+        }),
+        StaticResolverLike::Package(UriPackage {
+            package: Arc::new(Mutex::new(target)),
+            uri: Uri::try_from("ens/target.eth").unwrap()
+        })
+    ]);
+    let resolver = RecursiveResolver::from(vec![
+        UriResolverLike::Resolver(Box::new(static_resolver)),
+        UriResolverLike::Resolver(Box::new(ExtendableUriResolver::new(None).with_integrity(integrity))),
+    ]);
+
+    let interfaces = HashMap::from([(
+        "wrap://ens/uri-resolver.core.polywrap.eth".to_string(),
+        vec![Uri::try_from("ens/resolver-extension.eth").unwrap()],
+    )]);
+
+    PolywrapClient::new(ClientConfig {
+        interfaces: Some(interfaces),
+        resolver: Arc::new(resolver),
+        ..Default::default()
+    })
+}
+
+#[test]
+fn verifies_expected_hashes() {
+    let (_, uri) = setup("hashes");
+
+    let hashes = ContentHashes {
+        manifest_sha256: Some(sha256(&embedded_file("wrap.info"))),
+        wasm_sha256: Some(sha256(&embedded_file("wrap.wasm"))),
+    };
+    let client = get_client(IntegrityPolicy::new().expect_hashes(&uri, hashes));
+    let mut context = UriResolutionContext::new();
+    assert!(client.try_resolve_uri(&uri, Some(&mut context)).is_ok());
+    assert_eq!(integrity_steps(context.get_history()).len(), 1);
+    assert!(integrity_steps(context.get_history())[0].ends_with("content hashes match"));
+
+    let hashes = ContentHashes { manifest_sha256: None, wasm_sha256: Some(sha256(b"other")) };
+    let client = get_client(IntegrityPolicy::new().expect_hashes(&uri, hashes));
+    let error = client.try_resolve_uri(&uri, None).err().unwrap();
+    assert!(matches!(error, Error::IntegrityError(..)));
+    assert!(error.to_string().contains(&format!("expected {}", sha256(b"other"))));
+}
+
+#[test]
+fn verifies_signatures_from_trusted_keys() {
+    let (dir, uri) = setup("signatures");
+    let key = generate_key();
+    let policy = IntegrityPolicy::new().trust_key(key.public_key().as_ref());
+
+    let error = get_client(policy.clone()).try_resolve_uri(&uri, None).err().unwrap();
+    assert!(error.to_string().contains("wrap.sig is missing"));
+
+    let message = signed_message(&embedded_file("wrap.info"), &embedded_file("wrap.wasm"));
+    fs::write(dir.join("wrap.sig"), key.sign(&message).as_ref()).unwrap();
+    let mut context = UriResolutionContext::new();
+    assert!(get_client(policy).try_resolve_uri(&uri, Some(&mut context)).is_ok());
+    assert!(integrity_steps(context.get_history())[0].ends_with("signed by trusted key 0"));
+
+    let untrusted = IntegrityPolicy::new().trust_key(generate_key().public_key().as_ref());
+    let error = get_client(untrusted).try_resolve_uri(&uri, None).err().unwrap();
+    assert!(error.to_string().contains("isn't signed by a trusted key"));
+}
+
+#[test]
+fn reads_hashes_from_uri_fragments() {
+    let uri = Uri::try_from("wrap://https/example.com/wrapper#sha256=ABC&wasm-sha256=def").unwrap();
+    let hashes = ContentHashes::from_uri(&uri).unwrap().unwrap();
+    assert_eq!(hashes.manifest_sha256.as_deref(), Some("abc"));
+    assert_eq!(hashes.wasm_sha256.as_deref(), Some("def"));
+
+    assert!(ContentHashes::from_uri(&Uri::try_from("wrap://ens/wrapper.eth").unwrap()).unwrap().is_none());
+    assert!(ContentHashes::from_uri(&Uri::try_from("wrap://https/example.com/wrapper#sha265=abc").unwrap()).is_err());
+    assert!(IntegrityPolicy::new().applies_to(&uri));
+
+    let error = IntegrityPolicy::new().verify(&uri, b"manifest", b"module", None).unwrap_err();
+    assert!(error.contains("wrap.info has sha256"));
+}
+
+#[test]
+fn rejects_redirects_of_pinned_uris() {
+    let client = get_extension_client(IntegrityPolicy::new());
+
+    // Only the pinned URI is refused, the target itself resolves
+    let uri = Uri::try_from(format!("wrap://ens/pinned.eth#sha256={}", sha256(b"other"))).unwrap();
+    let mut context = UriResolutionContext::new();
+    let error = client.try_resolve_uri(&uri, Some(&mut context)).err().unwrap();
+    assert!(matches!(error, Error::IntegrityError(..)));
+    assert!(error.to_string().contains("redirects to wrap://ens/target.eth"));
+    assert!(integrity_steps(context.get_history())[0].contains("redirects to wrap://ens/target.eth"));
+
+    let target = Uri::try_from("wrap://ens/target.eth").unwrap();
+    assert!(matches!(client.try_resolve_uri(&target, None), Ok(UriPackageOrWrapper::Package(..))));
+}
+
+#[test]
+fn fails_when_pinned_files_are_unreadable() {
+    let hashes = ContentHashes { manifest_sha256: Some(sha256(b"other")), wasm_sha256: None };
+    let unreadable = Uri::try_from("wrap://ens/unreadable.eth").unwrap();
+    let missing = Uri::try_from("wrap://ens/missing.eth").unwrap();
+    let client = get_extension_client(
+        IntegrityPolicy::new().expect_hashes(&unreadable, hashes.clone()).expect_hashes(&missing, hashes),
+    );
+
+    let error = client.try_resolve_uri(&unreadable, None).err().unwrap();
+    assert!(error.to_string().contains("storage unavailable"));
+
+    // A file the extension doesn't have means it didn't resolve the URI
+    let result = client.try_resolve_uri(&missing, None).unwrap();
+    assert!(matches!(result, UriPackageOrWrapper::Uri(uri) if uri == missing));
+}
@@ -0,0 +1,176 @@
+use std::sync::{Arc, Mutex, atomic::{AtomicUsize, Ordering}};
+
+use polywrap_client::client::{PolywrapClient, InvokeBatchItem};
+use polywrap_core::{invoke::{Invoker}, loader::Loader, resolvers::{static_resolver::{StaticResolverLike, StaticResolver}, uri_resolution_context::{UriPackage, UriPackageOrWrapper, UriResolutionContext}, uri_resolver::UriResolver}, uri::Uri, client::ClientConfig, error::Error};
+
+use wrap_manifest_schemas::versions::{WrapManifest, WrapManifestAbi};
+use polywrap_msgpack::{decode, msgpack};
+use polywrap_plugin::{error::PluginError, module::PluginModule, package::PluginPackage};
+use polywrap_plugin_macro::{plugin_struct, plugin_impl};
+use serde_json::{Value, from_value, json};
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct DoubleArgs {
+    value: i32
+}
+
+#[plugin_struct]
+pub struct MathPlugin {}
+
+pub trait Module: PluginModule {
+  fn double(&mut self, args: &DoubleArgs, invoker: Arc<dyn Invoker>) -> Result<i32, PluginError>;
+}
+
+#[plugin_impl]
+impl Module for MathPlugin {
+    fn double(
+        &mut self,
+        args: &DoubleArgs,
+        _: Arc<dyn Invoker>
+    ) -> Result<i32, PluginError> {
+        if args.value < 0 {
+            return Err(PluginError::InvocationError {
+                uri: "ens/math-plugin.eth".to_string(),
+                method: "double".to_string(),
+                args: args.value.to_string(),
+                exception: "negative values are not supported".to_string(),
+            });
+        }
+        if args.value == i32::MAX {
+            panic!("value is too large to double");
+        }
+        Ok(args.value * 2)
+    }
+}
+
+pub fn get_manifest() -> WrapManifest {
+    WrapManifest {
+        name: "math".to_string(),
+        type_: "plugin".to_string(),
+        version: "0.1".to_string(),
+        abi: from_value::<WrapManifestAbi>(json!({})).unwrap()
+    }
+}
+
+#[derive(Debug)]
+struct CountingResolver {
+    resolver: StaticResolver,
+    count: Arc<AtomicUsize>,
+}
+
+impl UriResolver for CountingResolver {
+    fn try_resolve_uri(
+        &self,
+        uri: &Uri,
+        loader: &dyn Loader,
+        resolution_context: &mut UriResolutionContext,
+    ) -> Result<UriPackageOrWrapper, Error> {
+        self.count.fetch_add(1, Ordering::SeqCst);
+        self.resolver.try_resolve_uri(uri, loader, resolution_context)
+    }
+}
+
+fn get_client(count: Arc<AtomicUsize>) -> PolywrapClient {
+    let plugin = MathPlugin { env: Value::Null };
+    let package: PluginPackage = plugin.into();
+
+    let resolver = StaticResolver::from(vec![
+        StaticResolverLike::Package(UriPackage {
+            package: Arc::new(Mutex::new(package)),
+            uri: Uri::try_from("ens/math-plugin.eth").unwrap()
+        })
+    ]);
+
+    PolywrapClient::new(
+        ClientConfig {
+            resolver: Arc::new(CountingResolver { resolver, count }),
+            ..Default::default()
+        }
+    )
+}
+
+fn get_items() -> Vec<InvokeBatchItem> {
+    let item = |uri: &str, value: i32| InvokeBatchItem {
+        uri: Uri::try_from(uri).unwrap(),
+        method: "double".to_string(),
+        args: Some(msgpack!({"value": value})),
+        env: None,
+    };
+
+    vec![
+        item("ens/math-plugin.eth", 1),
+        item("ens/math-plugin.eth", -1),
+        item("ens/missing.eth", 2),
+        item("ens/math-plugin.eth", 3),
+    ]
+}
+
+fn assert_results(results: Vec<Result<Vec<u8>, Error>>) {
+    assert_eq!(results.len(), 4);
+    assert_eq!(decode::<i32>(results[0].as_ref().unwrap()).unwrap(), 2);
+    assert!(matches!(results[1], Err(Error::InvokeError(_))));
+    assert!(matches!(results[2], Err(Error::LoadWrapperError(_))));
+    assert_eq!(decode::<i32>(results[3].as_ref().unwrap()).unwrap(), 6);
+}
+
+#[test]
+fn invoke_batch_sequential() {
+    let count = Arc::new(AtomicUsize::new(0));
+    let client = get_client(count.clone());
+
+    assert_results(client.invoke_batch(&get_items(), false, None));
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn invoke_batch_parallel() {
+    let count = Arc::new(AtomicUsize::new(0));
+    let client = get_client(count.clone());
+
+    assert_results(client.invoke_batch(&get_items(), true, None));
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn invoke_batch_reports_panics() {
+    let mut items = get_items();
+    items[3].args = Some(msgpack!({"value": i32::MAX}));
+    items.push(InvokeBatchItem {
+        uri: Uri::try_from("ens/math-plugin.eth").unwrap(),
+        method: "double".to_string(),
+        args: Some(msgpack!({"value": 4})),
+        env: None,
+    });
+
+    // Items and batches after the panic still run on the same wrapper
+    let client = get_client(Arc::new(AtomicUsize::new(0)));
+    for parallel in [false, true] {
+        let results = client.invoke_batch(&items, parallel, None);
+        assert_eq!(decode::<i32>(results[0].as_ref().unwrap()).unwrap(), 2);
+        assert!(matches!(&results[3], Err(Error::InvokeError(e)) if e.contains("panicked")));
+        assert_eq!(decode::<i32>(results[4].as_ref().unwrap()).unwrap(), 8);
+    }
+}
+
+#[test]
+fn invoke_batch_records_history() {
+    let client = get_client(Arc::new(AtomicUsize::new(0)));
+    let items: Vec<InvokeBatchItem> = (0..64)
+        .map(|value| InvokeBatchItem {
+            uri: Uri::try_from("ens/math-plugin.eth").unwrap(),
+            method: "double".to_string(),
+            args: Some(msgpack!({"value": value})),
+            env: None,
+        })
+        .collect();
+
+    let mut context = UriResolutionContext::new();
+    let results = client.invoke_batch(&items, true, Some(&mut context));
+    for (value, result) in results.iter().enumerate() {
+        assert_eq!(decode::<i32>(result.as_ref().unwrap()).unwrap(), value as i32 * 2);
+    }
+    assert!(context
+        .get_history()
+        .iter()
+        .any(|step| step.source_uri.to_string() == "wrap://ens/math-plugin.eth"));
+}
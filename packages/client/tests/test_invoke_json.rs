@@ -0,0 +1,196 @@
+use std::sync::{Arc, Mutex};
+
+use polywrap_client::client::PolywrapClient;
+use polywrap_core::{invoke::{Invoker}, resolvers::{static_resolver::{StaticResolverLike, StaticResolver}, uri_resolution_context::UriPackage}, uri::Uri, client::ClientConfig};
+
+use wrap_manifest_schemas::versions::{WrapManifest, WrapManifestAbi};
+use polywrap_msgpack::{extensions::generic_map::GenericMap, rmpv};
+use polywrap_plugin::{error::PluginError, module::PluginModule, package::PluginPackage};
+use polywrap_plugin_macro::{plugin_struct, plugin_impl};
+use serde_json::{Value, from_value, json};
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct SummarizeArgs {
+    amount: String,
+    counts: GenericMap<String, u32>,
+    color: u32,
+    data: rmpv::Value,
+    metadata: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct Summary {
+    total: String,
+    counts: GenericMap<String, u32>,
+    color: u32,
+    size: u32,
+    metadata: Option<String>,
+}
+
+#[plugin_struct]
+pub struct SummaryPlugin {}
+
+pub trait Module: PluginModule {
+  fn summarize(&mut self, args: &SummarizeArgs, invoker: Arc<dyn Invoker>) -> Result<Summary, PluginError>;
+}
+
+#[plugin_impl]
+impl Module for SummaryPlugin {
+    fn summarize(
+        &mut self,
+        args: &SummarizeArgs,
+        _: Arc<dyn Invoker>
+    ) -> Result<Summary, PluginError> {
+        let size = match &args.data {
+            rmpv::Value::Binary(data) => data.len() as u32,
+            _ => return Err(PluginError::InvocationError {
+                uri: "ens/summary-plugin.eth".to_string(),
+                method: "summarize".to_string(),
+                args: format!("{:?}", args),
+                exception: "data should be bytes".to_string(),
+            }),
+        };
+
+        Ok(Summary {
+            total: (args.amount.parse::<u128>().unwrap() + 1).to_string(),
+            counts: args.counts.clone(),
+            color: args.color,
+            size,
+            metadata: args.metadata.clone(),
+        })
+    }
+}
+
+fn scalar(name: &str, type_: &str, required: bool) -> Value {
+    json!({
+        "kind": 34,
+        "name": name,
+        "required": required,
+        "type": type_,
+        "scalar": { "kind": 4, "name": name, "required": required, "type": type_ }
+    })
+}
+
+fn counts() -> Value {
+    json!({
+        "kind": 34,
+        "name": "counts",
+        "required": true,
+        "type": "Map<String, UInt32>",
+        "map": {
+            "kind": 262146,
+            "name": "counts",
+            "required": true,
+            "type": "Map<String, UInt32>",
+            "key": { "kind": 4, "name": "counts", "required": true, "type": "String" },
+            "value": { "kind": 4, "name": "counts", "required": true, "type": "UInt32" },
+            "scalar": { "kind": 4, "name": "counts", "required": true, "type": "UInt32" }
+        }
+    })
+}
+
+fn color() -> Value {
+    json!({
+        "kind": 34,
+        "name": "color",
+        "required": true,
+        "type": "Color",
+        "enum": { "kind": 16384, "name": "color", "required": true, "type": "Color" }
+    })
+}
+
+pub fn get_manifest() -> WrapManifest {
+    WrapManifest {
+        name: "summary".to_string(),
+        type_: "plugin".to_string(),
+        version: "0.1".to_string(),
+        abi: from_value::<WrapManifestAbi>(json!({
+            "version": "0.1",
+            "enumTypes": [
+                { "kind": 8, "type": "Color", "constants": ["RED", "GREEN", "BLUE"] }
+            ],
+            "objectTypes": [
+                {
+                    "kind": 1,
+                    "type": "Summary",
+                    "properties": [
+                        scalar("total", "BigInt", true),
+                        counts(),
+                        color(),
+                        scalar("size", "UInt32", true),
+                        scalar("metadata", "JSON", false)
+                    ]
+                }
+            ],
+            "moduleType": {
+                "kind": 128,
+                "type": "Module",
+                "methods": [
+                    {
+                        "kind": 64,
+                        "name": "summarize",
+                        "required": true,
+                        "type": "Method",
+                        "arguments": [
+                            scalar("amount", "BigInt", true),
+                            counts(),
+                            color(),
+                            scalar("data", "Bytes", true),
+                            scalar("metadata", "JSON", false)
+                        ],
+                        "return": {
+                            "kind": 34,
+                            "name": "summarize",
+                            "required": true,
+                            "type": "Summary",
+                            "object": { "kind": 8192, "name": "summarize", "required": true, "type": "Summary" }
+                        }
+                    }
+                ]
+            }
+        })).unwrap()
+    }
+}
+
+#[test]
+fn invoke_json() {
+    let plugin = SummaryPlugin { env: Value::Null };
+    let package: PluginPackage = plugin.into();
+
+    let static_resolver = StaticResolver::from(vec![
+        StaticResolverLike::Package(UriPackage {
+            package: Arc::new(Mutex::new(package)),
+            uri: Uri::try_from("ens/summary-plugin.eth").unwrap()
+        })
+    ]);
+
+    let client = PolywrapClient::new(
+        ClientConfig {
+            resolver: Arc::new(static_resolver),
+            validate_args: true,
+            ..Default::default()
+        }
+    );
+
+    let result = client.invoke_json(
+        &Uri::try_from("ens/summary-plugin.eth").unwrap(),
+        "summarize",
+        Some(json!({
+            "amount": 12345678901234_u64,
+            "counts": { "a": 1, "b": 2 },
+            "color": "BLUE",
+            "data": [1, 2, 3],
+            "metadata": { "source": "test" }
+        })),
+        None,
+        None,
+    ).unwrap();
+
+    assert_eq!(result, json!({
+        "total": "12345678901235",
+        "counts": { "a": 1, "b": 2 },
+        "color": 2,
+        "size": 3,
+        "metadata": { "source": "test" }
+    }));
+}
@@ -0,0 +1,48 @@
+use std::{fs, path::PathBuf};
+
+use httpmock::prelude::*;
+use polywrap_client::client::PolywrapClient;
+use polywrap_client::builder::{helpers::IPFS_RESOLVER_URI, types::{BuilderConfig, ClientBuilder, ClientConfigHandler}};
+use polywrap_client::core::{loader::Loader, uri::Uri};
+use serde_json::json;
+
+fn embedded_file(name: &str) -> Vec<u8> {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../builder/src/embeds/ipfs_http_client");
+    fs::read(dir.join(name)).unwrap()
+}
+
+// Stubs the `cat` endpoint of a local node's HTTP API, serving the embedded
+// IPFS HTTP client wrapper itself as `QmWrapper`
+#[test]
+fn resolves_ipfs_uris_from_local_node() {
+    let node = MockServer::start();
+    for file in ["wrap.info", "wrap.wasm"] {
+        node.mock(|when, then| {
+            when.path("/api/v0/cat").query_param("arg", format!("QmWrapper/{}", file));
+            then.status(200).body(embedded_file(file));
+        });
+    }
+    let missing = node.mock(|when, then| {
+        when.any_request();
+        then.status(404);
+    });
+
+    // The first provider is unreachable, so the resolver falls back to the local node
+    let mut builder = BuilderConfig::new(None);
+    builder.add_env(
+        Uri::try_from(IPFS_RESOLVER_URI).unwrap(),
+        json!({
+            "provider": "http://127.0.0.1:1",
+            "fallbackProviders": [node.base_url()],
+            "timeout": 5000,
+            "retries": { "tryResolveUri": 0, "getFile": 0 }
+        }),
+    );
+    let client = PolywrapClient::new(builder.build());
+
+    let wrapper = client.loader.load_wrapper(&Uri::try_from("wrap://ipfs/QmWrapper").unwrap(), None).unwrap();
+    assert_eq!(wrapper.lock().unwrap().get_manifest().unwrap().name, "ipfs-http-client-as");
+
+    assert!(client.loader.load_wrapper(&Uri::try_from("wrap://ipfs/QmMissing").unwrap(), None).is_err());
+    assert!(missing.hits() > 0);
+}
@@ -0,0 +1,115 @@
+use std::{fs, path::PathBuf, sync::{Arc, Mutex}};
+
+use polywrap_client::client::PolywrapClient;
+use polywrap_client::builder::types::{BuilderConfig, ClientBuilder, ClientConfigHandler};
+use polywrap_client::core::{
+    error::Error,
+    file_reader::SimpleFileReader,
+    resolvers::{uri_resolution_context::UriWrapper, uri_resolver::UriResolverHandler},
+    uri::Uri,
+};
+use polywrap_client::resolvers::{helpers::sha256, lockfile::{Lockfile, LockfileMode}};
+use polywrap_wasm::wasm_wrapper::WasmWrapper;
+use wrap_manifest_schemas::deserialize::deserialize_wrap_manifest;
+
+fn embedded_file(name: &str) -> Vec<u8> {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../builder/src/embeds/ipfs_http_client");
+    fs::read(dir.join(name)).unwrap()
+}
+
+fn setup(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("lockfile_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("wrapper")).unwrap();
+    fs::write(dir.join("wrapper/wrap.info"), embedded_file("wrap.info")).unwrap();
+    fs::write(dir.join("wrapper/wrap.wasm"), embedded_file("wrap.wasm")).unwrap();
+    dir
+}
+
+fn get_client(lockfile: &Lockfile, mode: LockfileMode, redirects: Vec<(&str, &Uri)>) -> PolywrapClient {
+    let mut builder = BuilderConfig::new(None);
+    builder.set_lockfile(lockfile.clone(), mode);
+    for (from, to) in redirects {
+        builder.add_redirect(Uri::try_from(from).unwrap(), to.clone());
+    }
+    PolywrapClient::new(builder.build())
+}
+
+#[test]
+fn records_and_enforces_resolutions() {
+    let dir = setup("enforce");
+    let fs_uri = Uri::try_from(format!("wrap://fs/{}", dir.join("wrapper").display())).unwrap();
+    let uri = Uri::try_from("wrap://ens/wrapper.eth").unwrap();
+
+    let lockfile = Lockfile::new();
+    let client = get_client(&lockfile, LockfileMode::Record, vec![("wrap://ens/wrapper.eth", &fs_uri)]);
+    client.try_resolve_uri(&uri, None).unwrap();
+
+    let entry = lockfile.get(&uri).unwrap();
+    assert_eq!(entry.uri, fs_uri.to_string());
+    assert_eq!(entry.redirects, vec![uri.to_string()]);
+    assert_eq!(entry.manifest_sha256, Some(sha256(&embedded_file("wrap.info"))));
+    assert_eq!(entry.wasm_sha256, Some(sha256(&embedded_file("wrap.wasm"))));
+    // The resolver extension is locked too, it's a plugin without files
+    let extension = lockfile.get(&Uri::try_from("wrap://ens/fs-resolver.polywrap.eth").unwrap()).unwrap();
+    assert_eq!(extension.wasm_sha256, None);
+    assert!(extension.redirects.is_empty());
+
+    lockfile.save(dir.join("polywrap.lock")).unwrap();
+    let lockfile = Lockfile::load(dir.join("polywrap.lock")).unwrap();
+
+    let client = get_client(&lockfile, LockfileMode::Strict, vec![("wrap://ens/wrapper.eth", &fs_uri)]);
+    assert!(client.try_resolve_uri(&uri, None).is_ok());
+
+    let unlocked = Uri::try_from("wrap://ens/unlocked.eth").unwrap();
+    let error = client.try_resolve_uri(&unlocked, None).err().unwrap();
+    assert!(matches!(error, Error::LockfileError(..)));
+    assert!(error.to_string().contains("isn't in the lockfile"));
+
+    fs::write(dir.join("wrapper/wrap.wasm"), b"changed").unwrap();
+    let error = client.try_resolve_uri(&uri, None).err().unwrap();
+    assert!(matches!(error, Error::LockfileError(..)));
+    assert!(error.to_string().contains(&format!("{:?}", Some(sha256(b"changed")))));
+}
+
+#[test]
+fn fails_to_record_wasm_wrappers_without_their_files() {
+    // The wrapper wasn't created from its `wrap.info`, and the reader can't
+    // find it either
+    let manifest = deserialize_wrap_manifest(&embedded_file("wrap.info"), None).unwrap();
+    let reader = Arc::new(SimpleFileReader::new());
+    let wrapper = WasmWrapper::new(embedded_file("wrap.wasm"), reader, manifest);
+    let uri = Uri::try_from("wrap://ens/unreadable.eth").unwrap();
+
+    let lockfile = Lockfile::new();
+    let mut builder = BuilderConfig::new(None);
+    builder.set_lockfile(lockfile.clone(), LockfileMode::Record);
+    builder.add_wrapper(UriWrapper { uri: uri.clone(), wrapper: Arc::new(Mutex::new(wrapper)) });
+    let client = PolywrapClient::new(builder.build());
+
+    let error = client.try_resolve_uri(&uri, None).err().unwrap();
+    assert!(matches!(error, Error::FileReadError(..)));
+    assert!(lockfile.get(&uri).is_none());
+}
+
+#[test]
+fn enforces_locked_redirects() {
+    let dir = setup("redirects");
+    let fs_uri = Uri::try_from(format!("wrap://fs/{}", dir.join("wrapper").display())).unwrap();
+    let middle_uri = Uri::try_from("wrap://ens/middle.eth").unwrap();
+    let uri = Uri::try_from("wrap://ens/wrapper.eth").unwrap();
+
+    let lockfile = Lockfile::new();
+    let client = get_client(&lockfile, LockfileMode::Record, vec![
+        ("wrap://ens/wrapper.eth", &middle_uri),
+        ("wrap://ens/middle.eth", &fs_uri),
+    ]);
+    client.try_resolve_uri(&uri, None).unwrap();
+    assert_eq!(lockfile.get(&uri).unwrap().redirects, vec![uri.to_string(), middle_uri.to_string()]);
+
+    // Same wrapper and content, but reached without the locked middle hop
+    let client = get_client(&lockfile, LockfileMode::Strict, vec![("wrap://ens/wrapper.eth", &fs_uri)]);
+    let error = client.try_resolve_uri(&uri, None).err().unwrap();
+    assert!(matches!(error, Error::LockfileError(..)));
+    assert!(error.to_string().contains(&format!("{} -> {} -> {}", uri, middle_uri, fs_uri)));
+}
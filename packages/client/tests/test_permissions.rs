@@ -0,0 +1,204 @@
+use std::sync::{Arc, Mutex, atomic::{AtomicU32, Ordering}};
+
+use polywrap_client::client::PolywrapClient;
+use polywrap_core::{invoke::{Invoker}, permissions::{PermissionPolicy, SubinvokePermissions}, resolvers::{recursive_resolver::RecursiveResolver, static_resolver::{StaticResolverLike, StaticResolver}, uri_resolution_context::UriPackage, uri_resolver_like::UriResolverLike}, uri::Uri, client::{ClientConfig, UriRedirect}, error::Error};
+
+use wrap_manifest_schemas::versions::{WrapManifest, WrapManifestAbi};
+use polywrap_msgpack::msgpack;
+use polywrap_plugin::{error::PluginError, module::PluginModule, package::PluginPackage};
+use polywrap_plugin_macro::{plugin_struct, plugin_impl};
+use serde_json::{Value, from_value, json};
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PathArgs {
+    path: String,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct CallArgs {
+    uri: String,
+    method: String,
+}
+
+#[plugin_struct]
+pub struct FilesPlugin {
+    removed: Arc<AtomicU32>,
+}
+
+#[plugin_struct]
+pub struct CallerPlugin {}
+
+pub trait FilesModule: PluginModule {
+  fn read(&mut self, args: &PathArgs, invoker: Arc<dyn Invoker>) -> Result<String, PluginError>;
+  fn rm(&mut self, args: &PathArgs, invoker: Arc<dyn Invoker>) -> Result<bool, PluginError>;
+}
+
+pub trait CallerModule: PluginModule {
+  fn call(&mut self, args: &CallArgs, invoker: Arc<dyn Invoker>) -> Result<bool, PluginError>;
+}
+
+#[plugin_impl]
+impl FilesModule for FilesPlugin {
+    fn read(
+        &mut self,
+        args: &PathArgs,
+        _: Arc<dyn Invoker>
+    ) -> Result<String, PluginError> {
+        Ok(args.path.clone())
+    }
+
+    fn rm(
+        &mut self,
+        _: &PathArgs,
+        _: Arc<dyn Invoker>
+    ) -> Result<bool, PluginError> {
+        self.removed.fetch_add(1, Ordering::SeqCst);
+        Ok(true)
+    }
+}
+
+#[plugin_impl]
+impl CallerModule for CallerPlugin {
+    fn call(
+        &mut self,
+        args: &CallArgs,
+        invoker: Arc<dyn Invoker>
+    ) -> Result<bool, PluginError> {
+        invoker.invoke_raw(
+            &Uri::try_from(args.uri.as_str()).unwrap(),
+            &args.method,
+            Some(&msgpack!({"path": "/etc/passwd"})),
+            None,
+            None,
+        ).map_err(|e| PluginError::SubinvocationError {
+            uri: args.uri.clone(),
+            method: args.method.clone(),
+            args: "".to_string(),
+            exception: e.to_string(),
+        })?;
+        Ok(true)
+    }
+}
+
+pub fn get_manifest() -> WrapManifest {
+    WrapManifest {
+        name: "permissions".to_string(),
+        type_: "plugin".to_string(),
+        version: "0.1".to_string(),
+        abi: from_value::<WrapManifestAbi>(json!({})).unwrap()
+    }
+}
+
+fn get_client(permissions: PermissionPolicy, removed: Arc<AtomicU32>) -> PolywrapClient {
+    let files: PluginPackage = FilesPlugin { env: Value::Null, removed }.into();
+    let caller: PluginPackage = CallerPlugin { env: Value::Null }.into();
+    let trusted: PluginPackage = CallerPlugin { env: Value::Null }.into();
+
+    let static_resolver = StaticResolver::from(vec![
+        StaticResolverLike::Package(UriPackage {
+            package: Arc::new(Mutex::new(files)),
+            uri: Uri::try_from("ens/fs.polywrap.eth").unwrap()
+        }),
+        StaticResolverLike::Package(UriPackage {
+            package: Arc::new(Mutex::new(caller)),
+            uri: Uri::try_from("ens/caller.eth").unwrap()
+        }),
+        StaticResolverLike::Package(UriPackage {
+            package: Arc::new(Mutex::new(trusted)),
+            uri: Uri::try_from("ens/trusted.polywrap.eth").unwrap()
+        }),
+        StaticResolverLike::Redirect(UriRedirect {
+            from: Uri::try_from("ens/alias.eth").unwrap(),
+            to: Uri::try_from("ens/fs.polywrap.eth").unwrap()
+        })
+    ]);
+    let resolver = RecursiveResolver::from(UriResolverLike::Resolver(Box::new(static_resolver)));
+
+    PolywrapClient::new(
+        ClientConfig {
+            resolver: Arc::new(resolver),
+            permissions: Some(permissions),
+            ..Default::default()
+        }
+    )
+}
+
+fn call(client: &PolywrapClient, caller: &str, method: &str) -> Result<bool, Error> {
+    call_uri(client, caller, "ens/fs.polywrap.eth", method)
+}
+
+fn call_uri(client: &PolywrapClient, caller: &str, uri: &str, method: &str) -> Result<bool, Error> {
+    client.invoke::<bool>(
+        &Uri::try_from(caller).unwrap(),
+        "call",
+        Some(&msgpack!({"uri": uri, "method": method})),
+        None,
+        None,
+    )
+}
+
+#[test]
+fn enforces_allow_and_deny_lists() {
+    let removed = Arc::new(AtomicU32::new(0));
+    let mut permissions = PermissionPolicy::new(
+        SubinvokePermissions::allow_all().deny("ens/fs.polywrap.eth", "rm")
+    );
+    permissions.set_wrapper_permissions(
+        "ens/caller.eth",
+        SubinvokePermissions::deny_all().allow("ens/fs.polywrap.eth", "read*"),
+    );
+    permissions.set_wrapper_permissions("*.polywrap.eth", SubinvokePermissions::allow_all());
+    let client = get_client(permissions, removed.clone());
+
+    assert!(call(&client, "ens/caller.eth", "read").unwrap());
+
+    let error = call(&client, "ens/caller.eth", "rm").unwrap_err();
+    assert!(error.to_string().contains(
+        "`wrap://ens/caller.eth` is not permitted to invoke `rm` on `wrap://ens/fs.polywrap.eth`"
+    ));
+    assert_eq!(removed.load(Ordering::SeqCst), 0);
+
+    // Wrappers matching a caller pattern get that pattern's permissions
+    assert!(call(&client, "ens/trusted.polywrap.eth", "rm").unwrap());
+    assert_eq!(removed.load(Ordering::SeqCst), 1);
+
+    // Top-level invocations aren't subinvocations, so they aren't restricted
+    let removed_directly = client.invoke::<bool>(
+        &Uri::try_from("ens/fs.polywrap.eth").unwrap(),
+        "rm",
+        Some(&msgpack!({"path": "/tmp/file"})),
+        None,
+        None,
+    );
+    assert!(removed_directly.unwrap());
+}
+
+#[test]
+fn default_permissions_apply_to_unlisted_callers() {
+    let removed = Arc::new(AtomicU32::new(0));
+    let permissions = PermissionPolicy::new(
+        SubinvokePermissions::allow_all().deny("ens/fs.polywrap.eth", "*")
+    );
+    let client = get_client(permissions, removed.clone());
+
+    let error = call(&client, "ens/caller.eth", "read").unwrap_err();
+    assert!(error.to_string().contains("is not permitted to invoke `read`"));
+    assert_eq!(removed.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn checks_the_uri_redirects_resolve_to() {
+    let removed = Arc::new(AtomicU32::new(0));
+    let permissions = PermissionPolicy::new(
+        SubinvokePermissions::allow_all().deny("ens/fs.polywrap.eth", "rm")
+    );
+    let client = get_client(permissions, removed.clone());
+
+    let error = call_uri(&client, "ens/caller.eth", "ens/alias.eth", "rm").unwrap_err();
+    assert!(error.to_string().contains(
+        "`wrap://ens/caller.eth` is not permitted to invoke `rm` on `wrap://ens/fs.polywrap.eth`"
+    ));
+    assert_eq!(removed.load(Ordering::SeqCst), 0);
+
+    assert!(call_uri(&client, "ens/caller.eth", "ens/alias.eth", "read").unwrap());
+}
@@ -0,0 +1,148 @@
+use std::sync::{Arc, Mutex};
+
+use polywrap_client::client::PolywrapClient;
+use polywrap_core::{cancellation::CancellationToken, invoke::{Invoker}, resolvers::{static_resolver::{StaticResolverLike, StaticResolver}, uri_resolution_context::UriPackage}, uri::Uri, client::ClientConfig, error::Error};
+
+use wrap_manifest_schemas::versions::{WrapManifest, WrapManifestAbi};
+use polywrap_msgpack::msgpack;
+use polywrap_plugin::{error::PluginError, module::PluginModule, package::PluginPackage};
+use polywrap_plugin_macro::{plugin_struct, plugin_impl};
+use serde_json::{Value, from_value, json};
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct RunArgs {
+    steps: u32,
+    cancel_at: u32,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct StepArgs {
+    step: u32,
+}
+
+#[plugin_struct]
+pub struct WorkerPlugin {}
+
+#[plugin_struct]
+pub struct StepPlugin {}
+
+pub trait WorkerModule: PluginModule {
+  fn run(&mut self, args: &RunArgs, invoker: Arc<dyn Invoker>) -> Result<u32, PluginError>;
+}
+
+pub trait StepModule: PluginModule {
+  fn step(&mut self, args: &StepArgs, invoker: Arc<dyn Invoker>) -> Result<u32, PluginError>;
+}
+
+#[plugin_impl]
+impl WorkerModule for WorkerPlugin {
+    fn run(
+        &mut self,
+        args: &RunArgs,
+        invoker: Arc<dyn Invoker>
+    ) -> Result<u32, PluginError> {
+        let mut completed = 0;
+        for step in 0..args.steps {
+            if args.cancel_at == step {
+                invoker.get_cancellation_token().unwrap().cancel();
+            }
+
+            invoker.invoke_raw(
+                &Uri::try_from("ens/step.eth").unwrap(),
+                "step",
+                Some(&msgpack!({"step": step})),
+                None,
+                None,
+            ).map_err(|e| PluginError::SubinvocationError {
+                uri: "ens/step.eth".to_string(),
+                method: "step".to_string(),
+                args: step.to_string(),
+                exception: e.to_string(),
+            })?;
+            completed += 1;
+        }
+        Ok(completed)
+    }
+}
+
+#[plugin_impl]
+impl StepModule for StepPlugin {
+    fn step(
+        &mut self,
+        args: &StepArgs,
+        _: Arc<dyn Invoker>
+    ) -> Result<u32, PluginError> {
+        Ok(args.step)
+    }
+}
+
+pub fn get_manifest() -> WrapManifest {
+    WrapManifest {
+        name: "worker".to_string(),
+        type_: "plugin".to_string(),
+        version: "0.1".to_string(),
+        abi: from_value::<WrapManifestAbi>(json!({})).unwrap()
+    }
+}
+
+fn get_client() -> PolywrapClient {
+    let worker: PluginPackage = WorkerPlugin { env: Value::Null }.into();
+    let step: PluginPackage = StepPlugin { env: Value::Null }.into();
+
+    let static_resolver = StaticResolver::from(vec![
+        StaticResolverLike::Package(UriPackage {
+            package: Arc::new(Mutex::new(worker)),
+            uri: Uri::try_from("ens/worker.eth").unwrap()
+        }),
+        StaticResolverLike::Package(UriPackage {
+            package: Arc::new(Mutex::new(step)),
+            uri: Uri::try_from("ens/step.eth").unwrap()
+        })
+    ]);
+
+    PolywrapClient::new(
+        ClientConfig {
+            resolver: Arc::new(static_resolver),
+            ..Default::default()
+        }
+    )
+}
+
+fn run(client: &PolywrapClient, cancel_at: u32, token: &CancellationToken) -> Result<u32, Error> {
+    client.invoke_with_cancellation::<u32>(
+        &Uri::try_from("ens/worker.eth").unwrap(),
+        "run",
+        Some(&msgpack!({"steps": 3, "cancelAt": cancel_at})),
+        None,
+        None,
+        token,
+    )
+}
+
+#[test]
+fn runs_to_completion_without_cancellation() {
+    let client = get_client();
+    assert_eq!(run(&client, u32::MAX, &CancellationToken::new()).unwrap(), 3);
+}
+
+#[test]
+fn cancelled_before_invocation() {
+    let token = CancellationToken::new();
+    token.cancel();
+
+    let error = run(&get_client(), u32::MAX, &token).unwrap_err();
+    assert!(matches!(error, Error::CancelledError(uri) if uri == "wrap://ens/worker.eth"));
+}
+
+#[test]
+fn cancellation_propagates_through_subinvocations() {
+    let token = CancellationToken::new();
+    let client = get_client();
+
+    let error = run(&client, 1, &token).unwrap_err();
+    assert!(matches!(error, Error::CancelledError(_)));
+    assert!(token.is_cancelled());
+
+    // Other invocations on the same client aren't affected by the token
+    assert_eq!(run(&client, u32::MAX, &CancellationToken::new()).unwrap(), 3);
+}
@@ -0,0 +1,364 @@
+use std::{sync::{Arc, Mutex}, thread, time::{Duration, Instant}};
+
+use polywrap_client::client::PolywrapClient;
+use polywrap_core::{cancellation::CancellationToken, invoke::{Invoker}, package::{GetManifestOptions, WrapPackage}, wrapper::Wrapper, quota::{QuotaPolicies, QuotaPolicy, QuotaQueue, RateLimit}, resolvers::{static_resolver::{StaticResolverLike, StaticResolver}, uri_resolution_context::UriPackage}, uri::Uri, client::ClientConfig, error::Error};
+
+use wrap_manifest_schemas::versions::{WrapManifest, WrapManifestAbi};
+use polywrap_msgpack::msgpack;
+use polywrap_plugin::{error::PluginError, module::PluginModule, package::PluginPackage, wrapper::PluginWrapper};
+use polywrap_plugin_macro::{plugin_struct, plugin_impl};
+use serde_json::{Value, from_value, json};
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct SleepArgs {
+    millis: u64,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct CallArgs {
+    uri: String,
+    times: u32,
+}
+
+#[plugin_struct]
+pub struct SleeperPlugin {}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct CountdownArgs {
+    from: u32,
+}
+
+#[plugin_struct]
+pub struct CallerPlugin {}
+
+#[plugin_struct]
+pub struct CountdownPlugin {}
+
+pub trait SleeperModule: PluginModule {
+  fn sleep(&mut self, args: &SleepArgs, invoker: Arc<dyn Invoker>) -> Result<bool, PluginError>;
+}
+
+pub trait CallerModule: PluginModule {
+  fn call(&mut self, args: &CallArgs, invoker: Arc<dyn Invoker>) -> Result<u32, PluginError>;
+}
+
+#[plugin_impl]
+impl SleeperModule for SleeperPlugin {
+    fn sleep(
+        &mut self,
+        args: &SleepArgs,
+        _: Arc<dyn Invoker>
+    ) -> Result<bool, PluginError> {
+        thread::sleep(Duration::from_millis(args.millis));
+        Ok(true)
+    }
+}
+
+#[plugin_impl]
+impl CallerModule for CallerPlugin {
+    fn call(
+        &mut self,
+        args: &CallArgs,
+        invoker: Arc<dyn Invoker>
+    ) -> Result<u32, PluginError> {
+        for _ in 0..args.times {
+            invoker.invoke_raw(
+                &Uri::try_from(args.uri.as_str()).unwrap(),
+                "sleep",
+                Some(&msgpack!({"millis": 0})),
+                None,
+                None,
+            ).map_err(|e| PluginError::SubinvocationError {
+                uri: args.uri.clone(),
+                method: "sleep".to_string(),
+                args: "".to_string(),
+                exception: e.to_string(),
+            })?;
+        }
+        Ok(args.times)
+    }
+}
+
+pub trait CountdownModule: PluginModule {
+  fn countdown(&mut self, args: &CountdownArgs, invoker: Arc<dyn Invoker>) -> Result<u32, PluginError>;
+}
+
+#[plugin_impl]
+impl CountdownModule for CountdownPlugin {
+    fn countdown(
+        &mut self,
+        args: &CountdownArgs,
+        invoker: Arc<dyn Invoker>
+    ) -> Result<u32, PluginError> {
+        if args.from == 0 {
+            return Ok(0);
+        }
+        invoker.invoke_raw(
+            &Uri::try_from("limited/countdown").unwrap(),
+            "countdown",
+            Some(&msgpack!({"from": args.from - 1})),
+            None,
+            None,
+        ).map_err(|e| PluginError::SubinvocationError {
+            uri: "limited/countdown".to_string(),
+            method: "countdown".to_string(),
+            args: "".to_string(),
+            exception: e.to_string(),
+        })?;
+        Ok(args.from)
+    }
+}
+
+// Each wrapper gets its own plugin instance, so that a wrapper can subinvoke
+// itself without waiting on the instance it's invoked from
+#[derive(Debug)]
+struct CountdownPackage;
+
+impl WrapPackage for CountdownPackage {
+    fn create_wrapper(&self) -> Result<Arc<Mutex<dyn Wrapper>>, Error> {
+        let wrapper: PluginWrapper = CountdownPlugin { env: Value::Null }.into();
+        Ok(Arc::new(Mutex::new(wrapper)))
+    }
+
+    fn get_manifest(&self, _: Option<GetManifestOptions>) -> Result<WrapManifest, Error> {
+        Ok(get_manifest())
+    }
+}
+
+pub fn get_manifest() -> WrapManifest {
+    WrapManifest {
+        name: "quotas".to_string(),
+        type_: "plugin".to_string(),
+        version: "0.1".to_string(),
+        abi: from_value::<WrapManifestAbi>(json!({})).unwrap()
+    }
+}
+
+fn get_client(quotas: QuotaPolicies) -> PolywrapClient {
+    let sleeper: PluginPackage = SleeperPlugin { env: Value::Null }.into();
+    let caller: PluginPackage = CallerPlugin { env: Value::Null }.into();
+
+    let static_resolver = StaticResolver::from(vec![
+        StaticResolverLike::Package(UriPackage {
+            package: Arc::new(Mutex::new(sleeper)),
+            uri: Uri::try_from("limited/sleeper").unwrap()
+        }),
+        StaticResolverLike::Package(UriPackage {
+            package: Arc::new(Mutex::new(caller)),
+            uri: Uri::try_from("ens/caller.eth").unwrap()
+        }),
+        StaticResolverLike::Package(UriPackage {
+            package: Arc::new(Mutex::new(CountdownPackage)),
+            uri: Uri::try_from("limited/countdown").unwrap()
+        })
+    ]);
+
+    PolywrapClient::new(
+        ClientConfig {
+            resolver: Arc::new(static_resolver),
+            quotas: Some(quotas),
+            ..Default::default()
+        }
+    )
+}
+
+fn sleep(client: &PolywrapClient, millis: u64) -> Result<bool, Error> {
+    client.invoke::<bool>(
+        &Uri::try_from("limited/sleeper").unwrap(),
+        "sleep",
+        Some(&msgpack!({"millis": millis})),
+        None,
+        None,
+    )
+}
+
+fn rate_limit(burst: u32) -> QuotaPolicy {
+    QuotaPolicy {
+        rate_limit: Some(RateLimit { burst, per_second: 0.001 }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn rejects_over_rate_limit() {
+    let mut quotas = QuotaPolicies::new();
+    quotas.set_uri_policy(&Uri::try_from("limited/sleeper").unwrap(), rate_limit(2));
+    let client = get_client(quotas);
+
+    assert!(sleep(&client, 0).unwrap());
+    assert!(sleep(&client, 0).unwrap());
+    let error = sleep(&client, 0).unwrap_err();
+    assert!(matches!(error, Error::QuotaExceededError(uri, _) if uri == "wrap://limited/sleeper"));
+}
+
+#[test]
+fn limits_subinvocations_by_authority() {
+    let mut quotas = QuotaPolicies::new();
+    quotas.set_authority_policy("lim*", rate_limit(1));
+    let client = get_client(quotas);
+
+    let result = client.invoke::<u32>(
+        &Uri::try_from("ens/caller.eth").unwrap(),
+        "call",
+        Some(&msgpack!({"uri": "limited/sleeper", "times": 2})),
+        None,
+        None,
+    );
+    assert!(result.unwrap_err().to_string().contains("Quota exceeded for `wrap://limited/sleeper`"));
+}
+
+#[test]
+fn shares_authority_quota_between_uris() {
+    let mut quotas = QuotaPolicies::new();
+    quotas.set_authority_policy("limited", QuotaPolicy {
+        max_concurrent: Some(1),
+        ..Default::default()
+    });
+    let client = get_client(quotas);
+
+    thread::scope(|s| {
+        let first = s.spawn(|| sleep(&client, 150));
+        thread::sleep(Duration::from_millis(30));
+        let result = client.invoke::<u32>(
+            &Uri::try_from("limited/countdown").unwrap(),
+            "countdown",
+            Some(&msgpack!({"from": 0})),
+            None,
+            None,
+        );
+        assert!(matches!(result, Err(Error::QuotaExceededError(uri, _)) if uri == "wrap://limited/countdown"));
+        assert!(first.join().unwrap().unwrap());
+    });
+}
+
+#[test]
+fn subinvocations_reuse_their_callers_authority_slot() {
+    let mut quotas = QuotaPolicies::new();
+    quotas.set_authority_policy("*", QuotaPolicy {
+        max_concurrent: Some(1),
+        ..Default::default()
+    });
+    let client = get_client(quotas);
+
+    let result = client.invoke::<u32>(
+        &Uri::try_from("ens/caller.eth").unwrap(),
+        "call",
+        Some(&msgpack!({"uri": "limited/sleeper", "times": 2})),
+        None,
+        None,
+    );
+    assert_eq!(result.unwrap(), 2);
+}
+
+#[test]
+fn rejects_concurrent_invocations_without_queue() {
+    let mut quotas = QuotaPolicies::new();
+    quotas.set_uri_policy(&Uri::try_from("limited/sleeper").unwrap(), QuotaPolicy {
+        max_concurrent: Some(1),
+        ..Default::default()
+    });
+    let client = get_client(quotas);
+
+    thread::scope(|s| {
+        let first = s.spawn(|| sleep(&client, 150));
+        thread::sleep(Duration::from_millis(30));
+        assert!(matches!(sleep(&client, 0), Err(Error::QuotaExceededError(_, _))));
+        assert!(first.join().unwrap().unwrap());
+    });
+
+    assert!(sleep(&client, 0).unwrap());
+}
+
+#[test]
+fn self_subinvocations_reuse_their_caller_slot() {
+    let mut quotas = QuotaPolicies::new();
+    quotas.set_uri_policy(&Uri::try_from("limited/countdown").unwrap(), QuotaPolicy {
+        max_concurrent: Some(1),
+        ..Default::default()
+    });
+    let client = get_client(quotas);
+
+    let result = client.invoke::<u32>(
+        &Uri::try_from("limited/countdown").unwrap(),
+        "countdown",
+        Some(&msgpack!({"from": 3})),
+        None,
+        None,
+    );
+    assert_eq!(result.unwrap(), 3);
+}
+
+#[test]
+fn waits_in_bounded_queue() {
+    let mut quotas = QuotaPolicies::new();
+    quotas.set_uri_policy(&Uri::try_from("limited/sleeper").unwrap(), QuotaPolicy {
+        max_concurrent: Some(1),
+        queue: Some(QuotaQueue { max_size: 1, timeout: Duration::from_secs(5) }),
+        ..Default::default()
+    });
+    let client = get_client(quotas);
+
+    thread::scope(|s| {
+        let first = s.spawn(|| sleep(&client, 150));
+        thread::sleep(Duration::from_millis(30));
+        let queued = s.spawn(|| sleep(&client, 0));
+        thread::sleep(Duration::from_millis(30));
+
+        let error = sleep(&client, 0).unwrap_err();
+        assert!(error.to_string().contains("queue of 1 is full"));
+        assert!(first.join().unwrap().unwrap());
+        assert!(queued.join().unwrap().unwrap());
+    });
+}
+
+#[test]
+fn times_out_in_queue() {
+    let mut quotas = QuotaPolicies::new();
+    quotas.set_uri_policy(&Uri::try_from("limited/sleeper").unwrap(), QuotaPolicy {
+        max_concurrent: Some(1),
+        queue: Some(QuotaQueue { max_size: 1, timeout: Duration::from_millis(20) }),
+        ..Default::default()
+    });
+    let client = get_client(quotas);
+
+    thread::scope(|s| {
+        let first = s.spawn(|| sleep(&client, 150));
+        thread::sleep(Duration::from_millis(30));
+        assert!(sleep(&client, 0).unwrap_err().to_string().contains("timed out"));
+        assert!(first.join().unwrap().unwrap());
+    });
+}
+
+#[test]
+fn cancelling_leaves_the_queue() {
+    let mut quotas = QuotaPolicies::new();
+    quotas.set_uri_policy(&Uri::try_from("limited/sleeper").unwrap(), QuotaPolicy {
+        max_concurrent: Some(1),
+        queue: Some(QuotaQueue { max_size: 1, timeout: Duration::from_secs(10) }),
+        ..Default::default()
+    });
+    let client = get_client(quotas);
+    let token = CancellationToken::new();
+
+    thread::scope(|s| {
+        let first = s.spawn(|| sleep(&client, 2000));
+        thread::sleep(Duration::from_millis(30));
+        s.spawn(|| {
+            thread::sleep(Duration::from_millis(50));
+            token.cancel();
+        });
+
+        let started = Instant::now();
+        let result = client.invoke_with_cancellation::<bool>(
+            &Uri::try_from("limited/sleeper").unwrap(),
+            "sleep",
+            Some(&msgpack!({"millis": 0})),
+            None,
+            None,
+            &token,
+        );
+        assert!(matches!(result, Err(Error::CancelledError(_))));
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert!(first.join().unwrap().unwrap());
+    });
+}
@@ -0,0 +1,50 @@
+use std::{fs, path::PathBuf};
+
+use polywrap_client::client::PolywrapClient;
+use polywrap_client::builder::types::{BuilderConfig, ClientConfigHandler};
+use polywrap_client::core::{
+    resolvers::{
+        uri_resolution_context::{UriResolutionContext, UriResolutionStep},
+        uri_resolver::UriResolverHandler,
+    },
+    uri::Uri,
+};
+
+fn embedded_file(name: &str) -> Vec<u8> {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../builder/src/embeds/ipfs_http_client");
+    fs::read(dir.join(name)).unwrap()
+}
+
+fn find_step<'a>(history: &'a [UriResolutionStep], description: &str) -> Option<&'a UriResolutionStep> {
+    history.iter().find_map(|step| {
+        if step.description.as_deref() == Some(description) {
+            return Some(step);
+        }
+        find_step(step.sub_history.as_deref().unwrap_or_default(), description)
+    })
+}
+
+#[test]
+fn nests_resolver_and_extension_loading_steps() {
+    let dir = std::env::temp_dir().join(format!("resolution_history_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("wrap.info"), embedded_file("wrap.info")).unwrap();
+    fs::write(dir.join("wrap.wasm"), embedded_file("wrap.wasm")).unwrap();
+    let uri = Uri::try_from(format!("wrap://fs/{}", dir.display())).unwrap();
+
+    let client = PolywrapClient::new(BuilderConfig::new(None).build());
+    let mut context = UriResolutionContext::new();
+    client.try_resolve_uri(&uri, Some(&mut context)).unwrap();
+
+    // Only the outermost aggregator's step is at the top level
+    let history = context.get_history();
+    assert!(history.iter().all(|step| step.sub_history.is_some()));
+
+    let extensions = find_step(history, "ExtendableUriResolver").unwrap();
+    let extension_steps = extensions.sub_history.as_deref().unwrap();
+    assert!(!extension_steps.is_empty());
+
+    let loading = find_step(extension_steps, "Load resolver extension wrap://ens/fs-resolver.polywrap.eth").unwrap();
+    assert!(loading.result.is_ok());
+    assert!(!loading.sub_history.as_deref().unwrap().is_empty());
+}
@@ -0,0 +1,189 @@
+use std::{sync::{Arc, Mutex, atomic::{AtomicU32, Ordering}}, thread, time::{Duration, Instant}};
+
+use polywrap_client::client::PolywrapClient;
+use polywrap_core::{invoke::{Invoker}, resolvers::{static_resolver::{StaticResolverLike, StaticResolver}, uri_resolution_context::{UriPackage, UriPackageOrWrapper, UriResolutionContext}}, uri::Uri, client::ClientConfig, error::Error, retry::{RetryPolicies, RetryPolicy, RetryErrorKind}, cancellation::CancellationToken};
+
+use wrap_manifest_schemas::versions::{WrapManifest, WrapManifestAbi};
+use polywrap_msgpack::msgpack;
+use polywrap_plugin::{error::PluginError, module::PluginModule, package::PluginPackage};
+use polywrap_plugin_macro::{plugin_struct, plugin_impl};
+use serde_json::{Value, from_value, json};
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct FetchArgs {
+    failures: u32,
+}
+
+#[plugin_struct]
+pub struct FlakyPlugin {
+    calls: Arc<AtomicU32>,
+}
+
+pub trait Module: PluginModule {
+  fn fetch(&mut self, args: &FetchArgs, invoker: Arc<dyn Invoker>) -> Result<u32, PluginError>;
+}
+
+#[plugin_impl]
+impl Module for FlakyPlugin {
+    fn fetch(
+        &mut self,
+        args: &FetchArgs,
+        _: Arc<dyn Invoker>
+    ) -> Result<u32, PluginError> {
+        let calls = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+        if calls <= args.failures {
+            return Err(PluginError::InvocationError {
+                uri: "ens/flaky.eth".to_string(),
+                method: "fetch".to_string(),
+                args: args.failures.to_string(),
+                exception: format!("failed on call {}", calls),
+            });
+        }
+        Ok(calls)
+    }
+}
+
+pub fn get_manifest() -> WrapManifest {
+    WrapManifest {
+        name: "flaky".to_string(),
+        type_: "plugin".to_string(),
+        version: "0.1".to_string(),
+        abi: from_value::<WrapManifestAbi>(json!({})).unwrap()
+    }
+}
+
+fn get_client(calls: Arc<AtomicU32>, policy: RetryPolicy, method: Option<&str>) -> PolywrapClient {
+    let plugin = FlakyPlugin { env: Value::Null, calls };
+    let package: PluginPackage = plugin.into();
+
+    let static_resolver = StaticResolver::from(vec![
+        StaticResolverLike::Package(UriPackage {
+            package: Arc::new(Mutex::new(package)),
+            uri: Uri::try_from("ens/flaky.eth").unwrap()
+        })
+    ]);
+
+    let mut retry_policies = RetryPolicies::new();
+    retry_policies.set_policy(&Uri::try_from("ens/flaky.eth").unwrap(), method, policy.clone());
+    retry_policies.set_policy(&Uri::try_from("ens/missing.eth").unwrap(), method, policy);
+
+    PolywrapClient::new(
+        ClientConfig {
+            resolver: Arc::new(static_resolver),
+            retry_policies: Some(retry_policies),
+            ..Default::default()
+        }
+    )
+}
+
+fn fetch(client: &PolywrapClient, uri: &str, failures: u32, ctx: &mut UriResolutionContext) -> Result<u32, Error> {
+    client.invoke::<u32>(
+        &Uri::try_from(uri).unwrap(),
+        "fetch",
+        Some(&msgpack!({"failures": failures})),
+        None,
+        Some(ctx),
+    )
+}
+
+fn get_attempts(ctx: &UriResolutionContext) -> Vec<(String, bool)> {
+    ctx.get_history()
+        .iter()
+        .filter(|step| step.description.as_deref().is_some_and(|d| d.starts_with("Invoke attempt")))
+        .map(|step| (step.description.clone().unwrap(), step.result.is_ok()))
+        .collect()
+}
+
+#[test]
+fn retries_until_success() {
+    let calls = Arc::new(AtomicU32::new(0));
+    let mut policy = RetryPolicy::new(3);
+    policy.backoff = Duration::from_millis(1);
+    let client = get_client(calls.clone(), policy, Some("fetch"));
+
+    let mut ctx = UriResolutionContext::new();
+    assert_eq!(fetch(&client, "ens/flaky.eth", 2, &mut ctx).unwrap(), 3);
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+    let attempts = get_attempts(&ctx);
+    assert_eq!(attempts.len(), 3);
+    assert_eq!(attempts[0], ("Invoke attempt 1/3 of `fetch` at wrap://ens/flaky.eth".to_string(), false));
+    assert_eq!(attempts[2], ("Invoke attempt 3/3 of `fetch` at wrap://ens/flaky.eth".to_string(), true));
+}
+
+#[test]
+fn fails_after_max_attempts() {
+    let calls = Arc::new(AtomicU32::new(0));
+    let client = get_client(calls.clone(), RetryPolicy::new(2), None);
+
+    let mut ctx = UriResolutionContext::new();
+    let error = fetch(&client, "ens/flaky.eth", 5, &mut ctx).unwrap_err();
+    assert!(matches!(error, Error::InvokeError(_)));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert_eq!(get_attempts(&ctx).len(), 2);
+}
+
+#[test]
+fn falls_back_to_other_uris() {
+    let calls = Arc::new(AtomicU32::new(0));
+    let mut policy = RetryPolicy::new(2);
+    policy.fallback_uris = vec![Uri::try_from("ens/flaky.eth").unwrap()];
+    let client = get_client(calls.clone(), policy, None);
+
+    let mut ctx = UriResolutionContext::new();
+    assert_eq!(fetch(&client, "ens/missing.eth", 0, &mut ctx).unwrap(), 1);
+
+    let attempts = get_attempts(&ctx);
+    assert_eq!(attempts.len(), 3);
+    assert!(attempts[..2].iter().all(|(d, ok)| d.ends_with("ens/missing.eth") && !ok));
+    assert_eq!(attempts[2], ("Invoke attempt 1/2 of `fetch` at wrap://ens/flaky.eth".to_string(), true));
+
+    let fallback_step = ctx.get_history().iter().last().unwrap();
+    assert_eq!(fallback_step.source_uri, Uri::try_from("ens/missing.eth").unwrap());
+    assert!(matches!(&fallback_step.result, Ok(UriPackageOrWrapper::Uri(uri)) if uri.to_string() == "wrap://ens/flaky.eth"));
+}
+
+#[test]
+fn only_retries_configured_error_kinds() {
+    let calls = Arc::new(AtomicU32::new(0));
+    let mut policy = RetryPolicy::new(3);
+    policy.retry_on = vec![RetryErrorKind::Resolution];
+    let client = get_client(calls.clone(), policy, None);
+
+    let mut ctx = UriResolutionContext::new();
+    assert!(fetch(&client, "ens/flaky.eth", 1, &mut ctx).is_err());
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn cancelling_ends_the_backoff() {
+    let calls = Arc::new(AtomicU32::new(0));
+    let mut policy = RetryPolicy::new(3);
+    policy.backoff = Duration::from_secs(10);
+    let client = get_client(calls.clone(), policy, None);
+
+    let token = CancellationToken::new();
+    let canceller = {
+        let token = token.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(100));
+            token.cancel();
+        })
+    };
+
+    let started = Instant::now();
+    let error = client
+        .invoke_with_cancellation::<u32>(
+            &Uri::try_from("ens/flaky.eth").unwrap(),
+            "fetch",
+            Some(&msgpack!({"failures": 5})),
+            None,
+            None,
+            &token,
+        )
+        .unwrap_err();
+    canceller.join().unwrap();
+    assert!(matches!(error, Error::CancelledError(_)));
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
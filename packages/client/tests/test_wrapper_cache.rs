@@ -0,0 +1,236 @@
+use std::{fs, path::PathBuf, sync::{Arc, Mutex}, time::Duration};
+
+use polywrap_client::client::PolywrapClient;
+use polywrap_client::builder::types::{BuilderConfig, ClientBuilder, ClientConfigHandler};
+use polywrap_client::core::{
+    client::ClientConfig,
+    error::Error,
+    file_reader::SimpleFileReader,
+    integrity::{sha256, ContentHashes, IntegrityPolicy},
+    loader::Loader,
+    resolvers::{
+        uri_resolution_context::{UriPackageOrWrapper, UriResolutionContext, UriResolutionStep},
+        uri_resolver::{UriResolver, UriResolverHandler},
+    },
+    uri::Uri,
+};
+use polywrap_client::resolvers::{wrapper_cache::WrapperCache, wrapper_cache_resolver::WrapperCacheResolver};
+use polywrap_wasm::{file_reader::InMemoryFileReader, wasm_package::WasmPackage};
+
+fn embedded_file(name: &str) -> Vec<u8> {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../builder/src/embeds/ipfs_http_client");
+    fs::read(dir.join(name)).unwrap()
+}
+
+fn setup(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("wrapper_cache_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn get_client(cache: &WrapperCache) -> PolywrapClient {
+    let mut builder = BuilderConfig::new(None);
+    builder.set_wrapper_cache(cache.clone());
+    PolywrapClient::new(builder.build())
+}
+
+fn has_step(history: &[UriResolutionStep], description: &str) -> bool {
+    history.iter().any(|step| {
+        step.description.as_deref().is_some_and(|d| d.starts_with(description))
+            || step.sub_history.as_deref().is_some_and(|sub| has_step(sub, description))
+    })
+}
+
+// Stands in for a resolver extension, serving the embedded wrapper for any
+// URI until its files are removed
+#[derive(Debug)]
+struct FetchingResolver {
+    files: Mutex<Option<(Vec<u8>, Vec<u8>)>>,
+}
+
+impl FetchingResolver {
+    fn new() -> Self {
+        Self {
+            files: Mutex::new(Some((embedded_file("wrap.info"), embedded_file("wrap.wasm")))),
+        }
+    }
+}
+
+impl UriResolver for FetchingResolver {
+    fn try_resolve_uri(
+        &self,
+        uri: &Uri,
+        _: &dyn Loader,
+        _: &mut UriResolutionContext,
+    ) -> Result<UriPackageOrWrapper, Error> {
+        match self.files.lock().unwrap().as_ref() {
+            Some((manifest, module)) => {
+                let reader = InMemoryFileReader::new(
+                    Arc::new(SimpleFileReader::new()),
+                    Some(manifest.clone()),
+                    Some(module.clone()),
+                );
+                let package = WasmPackage::new(Arc::new(reader), None, None);
+                Ok(UriPackageOrWrapper::Package(uri.clone(), Arc::new(Mutex::new(package))))
+            }
+            None => Ok(UriPackageOrWrapper::Uri(uri.clone())),
+        }
+    }
+}
+
+fn get_cache_resolver_client(cache: &WrapperCache, resolver: Arc<FetchingResolver>) -> PolywrapClient {
+    get_verifying_client(cache, resolver, IntegrityPolicy::new())
+}
+
+fn get_verifying_client(
+    cache: &WrapperCache,
+    resolver: Arc<FetchingResolver>,
+    integrity: IntegrityPolicy,
+) -> PolywrapClient {
+    let resolver = WrapperCacheResolver::new(resolver, cache.clone()).with_integrity(integrity);
+    PolywrapClient::new(ClientConfig {
+        resolver: Arc::new(resolver),
+        ..Default::default()
+    })
+}
+
+#[test]
+fn reuses_wrappers_fetched_by_resolver_extensions() {
+    let dir = setup("resolver");
+    let cache = WrapperCache::new(dir.join("cache"));
+    let resolver = Arc::new(FetchingResolver::new());
+    let client = get_cache_resolver_client(&cache, resolver.clone());
+
+    let uri = Uri::try_from("wrap://ipfs/QmCached").unwrap();
+    let result = client.try_resolve_uri(&uri, None).unwrap();
+    assert!(matches!(result, UriPackageOrWrapper::Wrapper(..)));
+    assert!(cache.get(&uri).is_some());
+
+    // Once the files are gone, only the cache can resolve the URI
+    *resolver.files.lock().unwrap() = None;
+    let mut context = UriResolutionContext::new();
+    let result = client.try_resolve_uri(&uri, Some(&mut context)).unwrap();
+    let UriPackageOrWrapper::Package(_, package) = result else {
+        panic!("Expected a package from the cache");
+    };
+    assert_eq!(package.lock().unwrap().get_manifest(None).unwrap().name, "ipfs-http-client-as");
+    assert!(has_step(context.get_history(), "WrapperCache"));
+}
+
+#[test]
+fn resolves_local_wrappers_again() {
+    let dir = setup("local");
+    let wrapper_dir = dir.join("wrapper");
+    fs::create_dir_all(&wrapper_dir).unwrap();
+    fs::write(wrapper_dir.join("wrap.info"), embedded_file("wrap.info")).unwrap();
+    fs::write(wrapper_dir.join("wrap.wasm"), embedded_file("wrap.wasm")).unwrap();
+
+    let cache = WrapperCache::new(dir.join("cache"));
+    let uri = Uri::try_from(format!("wrap://fs/{}", wrapper_dir.display())).unwrap();
+    let result = get_client(&cache).try_resolve_uri(&uri, None).unwrap();
+    assert!(matches!(result, UriPackageOrWrapper::Wrapper(..)));
+    assert!(cache.get(&uri).is_none());
+
+    let resolver = Arc::new(FetchingResolver::new());
+    let client = get_cache_resolver_client(&cache, resolver.clone());
+    let uri = Uri::try_from("wrap://file/wrapper").unwrap();
+    client.try_resolve_uri(&uri, None).unwrap();
+    assert!(cache.get(&uri).is_none());
+
+    *resolver.files.lock().unwrap() = None;
+    let result = client.try_resolve_uri(&uri, None).unwrap();
+    assert!(matches!(result, UriPackageOrWrapper::Uri(..)));
+}
+
+#[test]
+fn verifies_cached_wrappers_again() {
+    let dir = setup("integrity");
+    let cache = WrapperCache::new(dir.join("cache"));
+    let resolver = Arc::new(FetchingResolver::new());
+    let uri = Uri::try_from("wrap://ipfs/QmVerified").unwrap();
+    let expect_wasm = |wasm: &[u8]| {
+        let hashes = ContentHashes { manifest_sha256: None, wasm_sha256: Some(sha256(wasm)) };
+        IntegrityPolicy::new().expect_hashes(&uri, hashes)
+    };
+
+    let integrity = expect_wasm(&embedded_file("wrap.wasm"));
+    get_verifying_client(&cache, resolver.clone(), integrity.clone()).try_resolve_uri(&uri, None).unwrap();
+    assert!(cache.get(&uri).is_some());
+
+    *resolver.files.lock().unwrap() = None;
+    let result = get_verifying_client(&cache, resolver.clone(), integrity).try_resolve_uri(&uri, None).unwrap();
+    assert!(matches!(result, UriPackageOrWrapper::Package(..)));
+
+    // Cached files that no longer pass the policy aren't served
+    let mut context = UriResolutionContext::new();
+    let client = get_verifying_client(&cache, resolver, expect_wasm(b"other"));
+    let result = client.try_resolve_uri(&uri, Some(&mut context)).unwrap();
+    assert!(matches!(result, UriPackageOrWrapper::Uri(..)));
+    assert!(has_step(context.get_history(), &format!("Integrity check of {}", uri)));
+    assert!(!has_step(context.get_history(), "WrapperCache"));
+}
+
+#[test]
+fn stores_files_by_content() {
+    let dir = setup("content");
+    let cache = WrapperCache::new(&dir);
+    let first = Uri::try_from("wrap://ipfs/QmFirst").unwrap();
+    let second = Uri::try_from("wrap://ipfs/QmSecond").unwrap();
+
+    cache.insert(&first, b"manifest", b"module").unwrap();
+    cache.insert(&second, b"manifest", b"module").unwrap();
+    assert_eq!(fs::read_dir(dir.join("blobs")).unwrap().count(), 2);
+
+    let cached = cache.get(&second).unwrap();
+    assert_eq!(cached.manifest, b"manifest");
+    assert_eq!(cached.wasm_module, b"module");
+
+    // Files that don't match their hash aren't served
+    let blob = fs::read_dir(dir.join("blobs")).unwrap().next().unwrap().unwrap().path();
+    fs::write(blob, b"corrupted").unwrap();
+    assert!(cache.get(&first).is_none());
+}
+
+#[test]
+fn evicts_expired_and_oldest_entries() {
+    let dir = setup("eviction");
+    let uri = Uri::try_from("wrap://ipfs/QmExpired").unwrap();
+
+    let cache = WrapperCache::new(&dir).with_ttl(Duration::ZERO).with_grace_period(Duration::ZERO);
+    cache.insert(&uri, b"manifest", b"module").unwrap();
+    assert!(cache.get(&uri).is_none());
+    assert_eq!(fs::read_dir(dir.join("blobs")).unwrap().count(), 0);
+
+    let cache = WrapperCache::new(&dir).with_max_size(20);
+    let old = Uri::try_from("wrap://ipfs/QmOld").unwrap();
+    let new = Uri::try_from("wrap://ipfs/QmNew").unwrap();
+    cache.insert(&old, b"old manifest", b"old").unwrap();
+    std::thread::sleep(Duration::from_millis(10));
+    cache.insert(&new, b"new manifest", b"new").unwrap();
+
+    assert!(cache.get(&old).is_none());
+    assert!(cache.get(&new).is_some());
+}
+
+#[test]
+fn keeps_recent_unreferenced_blobs() {
+    let dir = setup("grace_period");
+    let uri = Uri::try_from("wrap://ipfs/QmExpired").unwrap();
+
+    // Blobs written by another process whose entry isn't written yet
+    fs::create_dir_all(dir.join("blobs")).unwrap();
+    let pending = dir.join("blobs").join(sha256(b"pending"));
+    fs::write(&pending, b"pending").unwrap();
+
+    let cache = WrapperCache::new(&dir).with_ttl(Duration::ZERO);
+    cache.insert(&uri, b"manifest", b"module").unwrap();
+    assert!(pending.exists());
+    assert_eq!(fs::read_dir(dir.join("blobs")).unwrap().count(), 3);
+
+    // Past the grace period, they're collected
+    let cache = cache.with_grace_period(Duration::ZERO);
+    cache.insert(&uri, b"manifest", b"module").unwrap();
+    assert!(!pending.exists());
+    assert_eq!(fs::read_dir(dir.join("blobs")).unwrap().count(), 0);
+}
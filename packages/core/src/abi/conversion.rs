@@ -0,0 +1,290 @@
+use polywrap_msgpack::{encode, rmpv::{self, Value}};
+use serde_json::{Map, Number, Value as JsonValue};
+use wrap_manifest_schemas::versions::WrapManifestAbi;
+
+use crate::error::Error;
+
+use super::definitions::{AbiLookup, AbiProperty, AbiType};
+
+// Map values are sent as the msgpack extension type used by `GenericMap`
+const MAP_EXT_TYPE: i8 = 1;
+
+pub fn json_args_to_msgpack(
+    abi: &WrapManifestAbi,
+    method: &str,
+    args: &JsonValue,
+) -> Result<Vec<u8>, Error> {
+    let converter = AbiConverter::new(abi);
+    let arguments = converter
+        .lookup
+        .get_method(method)
+        .map(|method| method.arguments)
+        .unwrap_or_default();
+
+    let value = match args {
+        JsonValue::Object(fields) => converter.object_to_msgpack(&arguments, fields)?,
+        JsonValue::Null => Value::Map(vec![]),
+        args => json_to_msgpack(args),
+    };
+
+    Ok(encode(&value)?)
+}
+
+pub fn msgpack_result_to_json(
+    abi: &WrapManifestAbi,
+    method: &str,
+    result: &[u8],
+) -> Result<JsonValue, Error> {
+    if result.is_empty() {
+        return Ok(JsonValue::Null);
+    }
+
+    let value = rmpv::decode::read_value(&mut &result[..])
+        .map_err(|e| Error::MsgpackError(format!("Failed to decode result: {}", e)))?;
+
+    let converter = AbiConverter::new(abi);
+    let return_type = converter
+        .lookup
+        .get_method(method)
+        .and_then(|method| method.return_type);
+
+    converter.to_json(return_type.as_ref(), &value)
+}
+
+struct AbiConverter<'a> {
+    lookup: AbiLookup<'a>,
+}
+
+impl<'a> AbiConverter<'a> {
+    fn new(abi: &'a WrapManifestAbi) -> Self {
+        Self {
+            lookup: AbiLookup::new(abi),
+        }
+    }
+
+    fn object_to_msgpack(
+        &self,
+        properties: &[AbiProperty],
+        fields: &Map<String, JsonValue>,
+    ) -> Result<Value, Error> {
+        let mut entries = vec![];
+        for (name, field) in fields {
+            let property = properties
+                .iter()
+                .find(|property| property.name.as_deref() == Some(name.as_str()));
+            entries.push((Value::from(name.as_str()), self.to_msgpack(property, field)?));
+        }
+
+        Ok(Value::Map(entries))
+    }
+
+    // Values that don't match the shape declared in the ABI are converted
+    // as-is, leaving it to args validation (or the wrapper) to reject them.
+    fn to_msgpack(&self, property: Option<&AbiProperty>, json: &JsonValue) -> Result<Value, Error> {
+        let property = match property {
+            Some(property) if !json.is_null() => property,
+            _ => return Ok(json_to_msgpack(json)),
+        };
+
+        let value = match (&property.kind, json) {
+            (AbiType::Scalar(type_name), json) => scalar_to_msgpack(type_name, json),
+            (AbiType::Array(item), JsonValue::Array(items)) => Value::Array(
+                items
+                    .iter()
+                    .map(|i| self.to_msgpack(Some(item), i))
+                    .collect::<Result<Vec<Value>, Error>>()?,
+            ),
+            (AbiType::Map(key_type, value_property), JsonValue::Object(entries)) => {
+                let mut map = vec![];
+                for (key, entry) in entries {
+                    map.push((map_key_to_msgpack(key_type, key), self.to_msgpack(Some(value_property), entry)?));
+                }
+                Value::Ext(MAP_EXT_TYPE, encode(&Value::Map(map))?)
+            }
+            (AbiType::Object(type_name), JsonValue::Object(fields)) => {
+                let properties = self.lookup.get_object_properties(type_name).unwrap_or_default();
+                self.object_to_msgpack(&properties, fields)?
+            }
+            (AbiType::Enum(type_name), JsonValue::String(constant)) => {
+                let index = self
+                    .lookup
+                    .get_enum_constants(type_name)
+                    .and_then(|constants| constants.iter().position(|c| c == constant));
+                match index {
+                    Some(index) => Value::from(index as u64),
+                    None => Value::from(constant.as_str()),
+                }
+            }
+            (_, json) => json_to_msgpack(json),
+        };
+
+        Ok(value)
+    }
+
+    fn to_json(&self, property: Option<&AbiProperty>, value: &Value) -> Result<JsonValue, Error> {
+        let kind = match property {
+            Some(property) => &property.kind,
+            None => return msgpack_to_json(value),
+        };
+
+        let json = match (kind, value) {
+            (AbiType::Scalar(type_name), Value::String(s)) if type_name == "JSON" => match s.as_str() {
+                Some(s) => serde_json::from_str(s).unwrap_or_else(|_| JsonValue::String(s.to_string())),
+                None => msgpack_to_json(value)?,
+            },
+            (AbiType::Array(item), Value::Array(items)) => JsonValue::Array(
+                items
+                    .iter()
+                    .map(|i| self.to_json(Some(item), i))
+                    .collect::<Result<Vec<JsonValue>, Error>>()?,
+            ),
+            (AbiType::Map(_, value_property), value) => {
+                let entries = match value {
+                    Value::Ext(MAP_EXT_TYPE, data) => match rmpv::decode::read_value(&mut data.as_slice()) {
+                        Ok(Value::Map(entries)) => entries,
+                        _ => return Err(Error::MsgpackError("Malformed map extension".to_string())),
+                    },
+                    Value::Map(entries) => entries.clone(),
+                    value => return msgpack_to_json(value),
+                };
+
+                let mut map = Map::new();
+                for (key, entry) in entries.iter() {
+                    map.insert(map_key_to_string(key), self.to_json(Some(value_property), entry)?);
+                }
+                JsonValue::Object(map)
+            }
+            (AbiType::Object(type_name), Value::Map(entries)) => {
+                let properties = self.lookup.get_object_properties(type_name).unwrap_or_default();
+                let mut map = Map::new();
+                for (key, entry) in entries.iter() {
+                    let name = map_key_to_string(key);
+                    let property = properties
+                        .iter()
+                        .find(|property| property.name.as_deref() == Some(name.as_str()));
+                    map.insert(name, self.to_json(property, entry)?);
+                }
+                JsonValue::Object(map)
+            }
+            // Structs serialized by plugins are positional, in the order the
+            // ABI declares their properties
+            (AbiType::Object(type_name), Value::Array(items)) => {
+                let properties = self.lookup.get_object_properties(type_name).unwrap_or_default();
+                if properties.len() != items.len() {
+                    return msgpack_to_json(value);
+                }
+
+                let mut map = Map::new();
+                for (property, item) in properties.iter().zip(items) {
+                    let name = property.name.clone().unwrap_or_default();
+                    map.insert(name, self.to_json(Some(property), item)?);
+                }
+                JsonValue::Object(map)
+            }
+            (_, value) => msgpack_to_json(value)?,
+        };
+
+        Ok(json)
+    }
+}
+
+fn scalar_to_msgpack(type_name: &str, json: &JsonValue) -> Value {
+    match (type_name, json) {
+        ("BigInt" | "BigNumber", JsonValue::Number(n)) => Value::from(n.to_string()),
+        ("JSON", JsonValue::String(s)) => Value::from(s.as_str()),
+        ("JSON", json) => Value::from(json.to_string()),
+        ("Bytes", JsonValue::Array(items)) => {
+            let bytes = items
+                .iter()
+                .map(|i| i.as_u64().and_then(|b| u8::try_from(b).ok()))
+                .collect::<Option<Vec<u8>>>();
+            match bytes {
+                Some(bytes) => Value::Binary(bytes),
+                None => json_to_msgpack(json),
+            }
+        }
+        (_, json) => json_to_msgpack(json),
+    }
+}
+
+fn map_key_to_msgpack(key_type: &str, key: &str) -> Value {
+    if key_type.starts_with("UInt") {
+        if let Ok(key) = key.parse::<u64>() {
+            return Value::from(key);
+        }
+    } else if key_type.starts_with("Int") {
+        if let Ok(key) = key.parse::<i64>() {
+            return Value::from(key);
+        }
+    }
+
+    Value::from(key)
+}
+
+fn map_key_to_string(key: &Value) -> String {
+    match key {
+        Value::String(s) => s.as_str().unwrap_or_default().to_string(),
+        key => key.to_string(),
+    }
+}
+
+pub fn json_to_msgpack(json: &JsonValue) -> Value {
+    match json {
+        JsonValue::Null => Value::Nil,
+        JsonValue::Bool(b) => Value::Boolean(*b),
+        JsonValue::Number(n) => {
+            if let Some(n) = n.as_u64() {
+                Value::from(n)
+            } else if let Some(n) = n.as_i64() {
+                Value::from(n)
+            } else {
+                Value::F64(n.as_f64().unwrap_or_default())
+            }
+        }
+        JsonValue::String(s) => Value::from(s.as_str()),
+        JsonValue::Array(items) => Value::Array(items.iter().map(json_to_msgpack).collect()),
+        JsonValue::Object(fields) => Value::Map(
+            fields
+                .iter()
+                .map(|(k, v)| (Value::from(k.as_str()), json_to_msgpack(v)))
+                .collect(),
+        ),
+    }
+}
+
+pub fn msgpack_to_json(value: &Value) -> Result<JsonValue, Error> {
+    let json = match value {
+        Value::Nil => JsonValue::Null,
+        Value::Boolean(b) => JsonValue::Bool(*b),
+        Value::Integer(i) => match (i.as_u64(), i.as_i64()) {
+            (Some(n), _) => JsonValue::from(n),
+            (_, Some(n)) => JsonValue::from(n),
+            _ => JsonValue::Null,
+        },
+        Value::F32(f) => Number::from_f64(*f as f64).map_or(JsonValue::Null, JsonValue::Number),
+        Value::F64(f) => Number::from_f64(*f).map_or(JsonValue::Null, JsonValue::Number),
+        Value::String(s) => JsonValue::String(s.as_str().unwrap_or_default().to_string()),
+        Value::Binary(bytes) => JsonValue::from(bytes.clone()),
+        Value::Array(items) => JsonValue::Array(
+            items
+                .iter()
+                .map(msgpack_to_json)
+                .collect::<Result<Vec<JsonValue>, Error>>()?,
+        ),
+        Value::Map(entries) => {
+            let mut map = Map::new();
+            for (key, entry) in entries {
+                map.insert(map_key_to_string(key), msgpack_to_json(entry)?);
+            }
+            JsonValue::Object(map)
+        }
+        Value::Ext(MAP_EXT_TYPE, data) => {
+            let map = rmpv::decode::read_value(&mut data.as_slice())
+                .map_err(|e| Error::MsgpackError(format!("Malformed map extension: {}", e)))?;
+            msgpack_to_json(&map)?
+        }
+        Value::Ext(_, data) => JsonValue::from(data.clone()),
+    };
+
+    Ok(json)
+}
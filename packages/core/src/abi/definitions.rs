@@ -0,0 +1,180 @@
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+use wrap_manifest_schemas::versions::WrapManifestAbi;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AbiType {
+    Scalar(String),
+    Array(Box<AbiProperty>),
+    Map(String, Box<AbiProperty>),
+    Object(String),
+    Enum(String),
+    Unresolved(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AbiProperty {
+    pub name: Option<String>,
+    pub required: bool,
+    pub kind: AbiType,
+}
+
+impl AbiProperty {
+    pub fn from_definition(definition: &Value) -> Option<Self> {
+        let required = definition["required"].as_bool().unwrap_or(false);
+        Self::parse(definition, required)
+    }
+
+    // Array and map definitions describe their item (or value) with the same
+    // `scalar`, `array`, `map`, `object` and `enum` keys used by properties,
+    // while the requiredness lives in the sibling `item` (or `value`) entry.
+    fn parse(definition: &Value, required: bool) -> Option<Self> {
+        let name = definition["name"].as_str().map(|n| n.to_string());
+
+        let kind = if let Some(scalar) = definition.get("scalar") {
+            AbiType::Scalar(scalar["type"].as_str()?.to_string())
+        } else if let Some(array) = definition.get("array") {
+            let item_required = array["item"]["required"].as_bool().unwrap_or(false);
+            AbiType::Array(Box::new(Self::parse(array, item_required)?))
+        } else if let Some(map) = definition.get("map") {
+            let key = map["key"]["type"].as_str()?.to_string();
+            let value_required = map["value"]["required"].as_bool().unwrap_or(false);
+            AbiType::Map(key, Box::new(Self::parse(map, value_required)?))
+        } else if let Some(object) = definition.get("object") {
+            AbiType::Object(object["type"].as_str()?.to_string())
+        } else if let Some(enum_ref) = definition.get("enum") {
+            AbiType::Enum(enum_ref["type"].as_str()?.to_string())
+        } else if let Some(unresolved) = definition.get("unresolvedObjectOrEnum") {
+            AbiType::Unresolved(unresolved["type"].as_str()?.to_string())
+        } else {
+            return None;
+        };
+
+        Some(Self { name, required, kind })
+    }
+
+    pub fn graphql_type(&self) -> String {
+        let base = match &self.kind {
+            AbiType::Scalar(type_name)
+            | AbiType::Object(type_name)
+            | AbiType::Enum(type_name)
+            | AbiType::Unresolved(type_name) => type_name.clone(),
+            AbiType::Array(item) => format!("[{}]", item.graphql_type()),
+            AbiType::Map(key, value) => format!("Map<{}!, {}>", key, value.graphql_type()),
+        };
+
+        if self.required {
+            format!("{}!", base)
+        } else {
+            base
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AbiMethod {
+    pub name: String,
+    pub arguments: Vec<AbiProperty>,
+    pub env_required: Option<bool>,
+    pub return_type: Option<AbiProperty>,
+}
+
+impl AbiMethod {
+    pub fn from_definition(definition: &Value) -> Option<Self> {
+        let arguments = match definition["arguments"].as_array() {
+            Some(arguments) => arguments
+                .iter()
+                .filter_map(AbiProperty::from_definition)
+                .collect(),
+            None => vec![],
+        };
+
+        Some(Self {
+            name: definition["name"].as_str()?.to_string(),
+            arguments,
+            env_required: definition["env"]["required"].as_bool(),
+            return_type: definition.get("return").and_then(AbiProperty::from_definition),
+        })
+    }
+}
+
+pub struct AbiLookup<'a> {
+    abi: &'a WrapManifestAbi,
+}
+
+impl<'a> AbiLookup<'a> {
+    pub fn new(abi: &'a WrapManifestAbi) -> Self {
+        Self { abi }
+    }
+
+    pub fn get_env_properties(&self) -> Option<Vec<AbiProperty>> {
+        self.abi.env_type.as_ref().map(get_properties)
+    }
+
+    pub fn get_methods(&self) -> Vec<AbiMethod> {
+        let methods = self
+            .abi
+            .module_type
+            .as_ref()
+            .and_then(|module| module.get("methods"))
+            .and_then(|methods| methods.as_array());
+
+        match methods {
+            Some(methods) => methods
+                .iter()
+                .filter_map(AbiMethod::from_definition)
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    pub fn get_method(&self, name: &str) -> Option<AbiMethod> {
+        self.get_methods().into_iter().find(|method| method.name == name)
+    }
+
+    pub fn get_object_properties(&self, type_name: &str) -> Option<Vec<AbiProperty>> {
+        find_definition(&self.abi.object_types, type_name)
+            .or_else(|| find_definition(&self.abi.imported_object_types, type_name))
+            .map(get_properties)
+    }
+
+    pub fn get_enum_constants(&self, type_name: &str) -> Option<Vec<String>> {
+        let definition = find_definition(&self.abi.enum_types, type_name)
+            .or_else(|| find_definition(&self.abi.imported_enum_types, type_name))?;
+
+        let constants = definition
+            .get("constants")
+            .and_then(|constants| constants.as_array())
+            .map(|constants| {
+                constants
+                    .iter()
+                    .filter_map(|constant| constant.as_str().map(|c| c.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Some(constants)
+    }
+}
+
+fn find_definition<'a>(
+    definitions: &'a Option<Vec<BTreeMap<String, Value>>>,
+    type_name: &str,
+) -> Option<&'a BTreeMap<String, Value>> {
+    definitions.as_ref().and_then(|definitions| {
+        definitions
+            .iter()
+            .find(|definition| definition.get("type").and_then(|t| t.as_str()) == Some(type_name))
+    })
+}
+
+fn get_properties(definition: &BTreeMap<String, Value>) -> Vec<AbiProperty> {
+    match definition.get("properties").and_then(|p| p.as_array()) {
+        Some(properties) => properties
+            .iter()
+            .filter_map(AbiProperty::from_definition)
+            .collect(),
+        None => vec![],
+    }
+}
@@ -0,0 +1,3 @@
+pub mod conversion;
+pub mod definitions;
+pub mod validation;
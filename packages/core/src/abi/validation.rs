@@ -0,0 +1,372 @@
+use std::fmt;
+
+use polywrap_msgpack::rmpv::{self, Value};
+use wrap_manifest_schemas::versions::WrapManifestAbi;
+
+use crate::env::Env;
+
+use super::definitions::{AbiLookup, AbiProperty, AbiType};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AbiValidationError {
+    pub path: String,
+    pub expected: String,
+    pub message: String,
+}
+
+impl fmt::Display for AbiValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}`: {} (expected `{}`)", self.path, self.message, self.expected)
+    }
+}
+
+pub fn format_validation_errors(errors: &[AbiValidationError]) -> String {
+    errors
+        .iter()
+        .map(|e| e.to_string())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub fn validate_env(abi: &WrapManifestAbi, env: &Env) -> Result<(), Vec<AbiValidationError>> {
+    let mut validator = AbiValidator::new(abi);
+    let properties = match validator.lookup.get_env_properties() {
+        Some(properties) => properties,
+        None => return Ok(()),
+    };
+
+    let env = rmpv::ext::to_value(env).map_err(|e| {
+        vec![AbiValidationError {
+            path: "env".to_string(),
+            expected: "Env".to_string(),
+            message: format!("unable to encode env: {}", e),
+        }]
+    })?;
+
+    validator.validate_object_fields(&properties, &env, "env", "Env");
+    validator.finish()
+}
+
+pub fn validate_args(
+    abi: &WrapManifestAbi,
+    method: &str,
+    args: Option<&[u8]>,
+) -> Result<(), Vec<AbiValidationError>> {
+    let mut validator = AbiValidator::new(abi);
+    let methods = validator.lookup.get_methods();
+
+    // Wrappers without a module definition (e.g. plugins built with an empty
+    // ABI) can't be checked, so they're passed through untouched.
+    if methods.is_empty() {
+        return Ok(());
+    }
+
+    let method_definition = match methods.iter().find(|m| m.name == method) {
+        Some(method_definition) => method_definition,
+        None => {
+            return Err(vec![AbiValidationError {
+                path: "method".to_string(),
+                expected: methods
+                    .iter()
+                    .map(|m| m.name.clone())
+                    .collect::<Vec<String>>()
+                    .join(" | "),
+                message: format!("method `{}` is not defined in the ABI", method),
+            }]);
+        }
+    };
+
+    let args = match args {
+        Some(args) if !args.is_empty() => rmpv::decode::read_value(&mut &args[..]).map_err(|e| {
+            vec![AbiValidationError {
+                path: "args".to_string(),
+                expected: "Args".to_string(),
+                message: format!("unable to decode args: {}", e),
+            }]
+        })?,
+        _ => Value::Map(vec![]),
+    };
+
+    validator.validate_object_fields(&method_definition.arguments, &args, "args", "Args");
+    validator.finish()
+}
+
+pub fn validate_invocation_env(
+    abi: &WrapManifestAbi,
+    method: &str,
+    env: Option<&Env>,
+) -> Result<(), Vec<AbiValidationError>> {
+    match env {
+        Some(env) => validate_env(abi, env),
+        None => {
+            let env_required = AbiLookup::new(abi)
+                .get_method(method)
+                .and_then(|method| method.env_required)
+                .unwrap_or(false);
+
+            if env_required {
+                Err(vec![AbiValidationError {
+                    path: "env".to_string(),
+                    expected: "Env!".to_string(),
+                    message: format!("method `{}` requires an env, but none was provided", method),
+                }])
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+pub struct AbiValidator<'a> {
+    lookup: AbiLookup<'a>,
+    errors: Vec<AbiValidationError>,
+}
+
+impl<'a> AbiValidator<'a> {
+    pub fn new(abi: &'a WrapManifestAbi) -> Self {
+        Self {
+            lookup: AbiLookup::new(abi),
+            errors: vec![],
+        }
+    }
+
+    pub fn finish(self) -> Result<(), Vec<AbiValidationError>> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self.errors)
+        }
+    }
+
+    fn error(&mut self, path: &str, expected: String, message: String) {
+        self.errors.push(AbiValidationError {
+            path: path.to_string(),
+            expected,
+            message,
+        });
+    }
+
+    pub fn validate_object_fields(
+        &mut self,
+        properties: &[AbiProperty],
+        value: &Value,
+        path: &str,
+        type_name: &str,
+    ) {
+        let field = |index: usize, name: &str| match value {
+            Value::Map(entries) => entries
+                .iter()
+                .find(|(key, _)| key.as_str() == Some(name))
+                .map(|(_, value)| value),
+            // Structs serialized with `polywrap_msgpack::serialize` are
+            // positional, in the order the ABI declares their properties
+            Value::Array(items) => items.get(index),
+            _ => None,
+        };
+
+        match value {
+            // Keys the type doesn't declare are usually typos of optional
+            // fields, which would otherwise be dropped silently
+            Value::Map(entries) => {
+                for (key, _) in entries {
+                    let declared = properties
+                        .iter()
+                        .any(|property| property.name.is_some() && property.name.as_deref() == key.as_str());
+                    if !declared {
+                        let key = key.as_str().map(String::from).unwrap_or_else(|| describe(key));
+                        self.error(
+                            &format!("{}.{}", path, key),
+                            type_name.to_string(),
+                            "unexpected field".to_string(),
+                        );
+                    }
+                }
+            }
+            Value::Array(items) if items.len() <= properties.len() => {}
+            Value::Array(items) => {
+                self.error(
+                    path,
+                    type_name.to_string(),
+                    format!(
+                        "expected an object with {} fields, found array of {}",
+                        properties.len(),
+                        items.len()
+                    ),
+                );
+                return;
+            }
+            _ => {
+                self.error(
+                    path,
+                    type_name.to_string(),
+                    format!("expected an object, found {}", describe(value)),
+                );
+                return;
+            }
+        }
+
+        for (index, property) in properties.iter().enumerate() {
+            let name = match &property.name {
+                Some(name) => name,
+                None => continue,
+            };
+            let field_path = format!("{}.{}", path, name);
+            let field = field(index, name);
+
+            match field {
+                Some(field) => self.validate_value(property, field, &field_path),
+                None if property.required => self.error(
+                    &field_path,
+                    property.graphql_type(),
+                    "missing required field".to_string(),
+                ),
+                None => {}
+            }
+        }
+    }
+
+    pub fn validate_value(&mut self, property: &AbiProperty, value: &Value, path: &str) {
+        if value.is_nil() {
+            if property.required {
+                self.error(path, property.graphql_type(), "required value is null".to_string());
+            }
+            return;
+        }
+
+        match &property.kind {
+            AbiType::Scalar(type_name) => {
+                if let Some(message) = validate_scalar(type_name, value) {
+                    self.error(path, property.graphql_type(), message);
+                }
+            }
+            AbiType::Array(item) => match value {
+                Value::Array(items) => {
+                    for (i, item_value) in items.iter().enumerate() {
+                        self.validate_value(item, item_value, &format!("{}[{}]", path, i));
+                    }
+                }
+                _ => self.error(
+                    path,
+                    property.graphql_type(),
+                    format!("expected an array, found {}", describe(value)),
+                ),
+            },
+            AbiType::Map(key_type, value_property) => {
+                let entries = match value {
+                    Value::Map(entries) => entries.clone(),
+                    Value::Ext(1, data) => match rmpv::decode::read_value(&mut data.as_slice()) {
+                        Ok(Value::Map(entries)) => entries,
+                        _ => {
+                            self.error(
+                                path,
+                                property.graphql_type(),
+                                "malformed map extension".to_string(),
+                            );
+                            return;
+                        }
+                    },
+                    _ => {
+                        self.error(
+                            path,
+                            property.graphql_type(),
+                            format!("expected a map, found {}", describe(value)),
+                        );
+                        return;
+                    }
+                };
+
+                for (key, entry) in entries.iter() {
+                    let entry_path = format!("{}[{}]", path, key);
+                    if let Some(message) = validate_scalar(key_type, key) {
+                        self.error(&entry_path, format!("{}!", key_type), format!("invalid key: {}", message));
+                    }
+                    self.validate_value(value_property, entry, &entry_path);
+                }
+            }
+            AbiType::Object(type_name) => {
+                if let Some(properties) = self.lookup.get_object_properties(type_name) {
+                    self.validate_object_fields(&properties, value, path, &property.graphql_type());
+                }
+            }
+            AbiType::Enum(type_name) => {
+                let constants = match self.lookup.get_enum_constants(type_name) {
+                    Some(constants) => constants,
+                    None => return,
+                };
+                let valid = match value {
+                    Value::String(s) => s.as_str().is_some_and(|s| constants.iter().any(|c| c == s)),
+                    Value::Integer(i) => i.as_u64().is_some_and(|i| (i as usize) < constants.len()),
+                    _ => false,
+                };
+                if !valid {
+                    self.error(
+                        path,
+                        property.graphql_type(),
+                        format!(
+                            "invalid enum value {}, expected one of: {}",
+                            describe(value),
+                            constants.join(", ")
+                        ),
+                    );
+                }
+            }
+            AbiType::Unresolved(_) => {}
+        }
+    }
+}
+
+fn validate_scalar(type_name: &str, value: &Value) -> Option<String> {
+    let in_range = match type_name {
+        "UInt" | "UInt32" => value.as_u64().map(|v| v <= u32::MAX as u64),
+        "UInt8" => value.as_u64().map(|v| v <= u8::MAX as u64),
+        "UInt16" => value.as_u64().map(|v| v <= u16::MAX as u64),
+        "Int" | "Int32" => value.as_i64().map(|v| i32::try_from(v).is_ok()),
+        "Int8" => value.as_i64().map(|v| i8::try_from(v).is_ok()),
+        "Int16" => value.as_i64().map(|v| i16::try_from(v).is_ok()),
+        "String" => value.as_str().map(|_| true),
+        "Boolean" => value.as_bool().map(|_| true),
+        "Bytes" => match value {
+            Value::Binary(_) => Some(true),
+            Value::Array(items) => Some(items.iter().all(|i| i.as_u64().is_some_and(|b| b <= u8::MAX as u64))),
+            _ => None,
+        },
+        "BigInt" => value.as_str().map(is_integer_string),
+        "BigNumber" => value.as_str().map(is_number_string),
+        "JSON" => value
+            .as_str()
+            .map(|s| serde_json::from_str::<serde_json::Value>(s).is_ok()),
+        _ => return None,
+    };
+
+    match in_range {
+        Some(true) => None,
+        Some(false) => Some(format!("invalid value {}", describe(value))),
+        None => Some(format!("type mismatch, found {}", describe(value))),
+    }
+}
+
+fn is_integer_string(s: &str) -> bool {
+    let digits = s.strip_prefix('-').unwrap_or(s);
+    !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_number_string(s: &str) -> bool {
+    !s.is_empty()
+        && s.chars().all(|c| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E'))
+        && s.parse::<f64>().is_ok()
+}
+
+fn describe(value: &Value) -> String {
+    match value {
+        Value::Nil => "null".to_string(),
+        Value::Boolean(b) => format!("boolean {}", b),
+        Value::Integer(i) => format!("integer {}", i),
+        Value::F32(f) => format!("float {}", f),
+        Value::F64(f) => format!("float {}", f),
+        Value::String(s) => format!("string {:?}", s.as_str().unwrap_or_default()),
+        Value::Binary(_) => "bytes".to_string(),
+        Value::Array(_) => "array".to_string(),
+        Value::Map(_) => "map".to_string(),
+        Value::Ext(tag, _) => format!("extension type {}", tag),
+    }
+}
@@ -0,0 +1,153 @@
+use std::{
+    io::{Cursor, Read},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use flate2::read::GzDecoder;
+
+use crate::{error::Error, file_reader::FileReader};
+
+// Entries are decompressed into memory, so their size is capped rather than
+// taken from the (untrusted) archive headers
+pub const MAX_ENTRY_SIZE: u64 = 64 * 1024 * 1024;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ArchiveKind {
+    Zip,
+    TarGz,
+}
+
+impl ArchiveKind {
+    pub fn from_path(path: &str) -> Option<Self> {
+        let path = path.to_ascii_lowercase();
+        if path.ends_with(".zip") {
+            Some(ArchiveKind::Zip)
+        } else if path.ends_with(".tar.gz") || path.ends_with(".tgz") {
+            Some(ArchiveKind::TarGz)
+        } else {
+            None
+        }
+    }
+}
+
+// Splits a path into the archive it points into and the file inside of it,
+// e.g. `wrappers/wrapper.zip/wrap.wasm` into `wrappers/wrapper.zip` and `wrap.wasm`
+pub fn split_archive_path(path: &str) -> Option<(&str, ArchiveKind, &str)> {
+    let mut end = 0;
+    for component in path.split('/') {
+        end += component.len();
+        if let Some(kind) = ArchiveKind::from_path(component) {
+            let file = path[end..].trim_start_matches('/');
+            return Some((&path[..end], kind, file));
+        }
+        end += 1;
+    }
+    None
+}
+
+// Wrappers are either packaged at the root of the archive, or inside a single
+// top-level directory
+pub fn read_entry(archive: &[u8], kind: ArchiveKind, name: &str) -> Result<Option<Vec<u8>>, String> {
+    let matches = |entry: &str| {
+        let entry = entry.trim_start_matches("./");
+        entry == name
+            || entry
+                .split_once('/')
+                .map(|(_, rest)| rest == name)
+                .unwrap_or(false)
+    };
+
+    match kind {
+        ArchiveKind::Zip => {
+            let mut zip = zip::ZipArchive::new(Cursor::new(archive)).map_err(|e| e.to_string())?;
+            let Some(entry) = zip.file_names().find(|entry| matches(entry)).map(String::from) else {
+                return Ok(None);
+            };
+
+            let file = zip.by_name(&entry).map_err(|e| e.to_string())?;
+            read_bounded(file, &entry).map(Some)
+        }
+        ArchiveKind::TarGz => {
+            let mut tar = tar::Archive::new(GzDecoder::new(archive));
+            for entry in tar.entries().map_err(|e| e.to_string())? {
+                let entry = entry.map_err(|e| e.to_string())?;
+                let path = entry.path().map_err(|e| e.to_string())?.to_string_lossy().to_string();
+                if entry.header().entry_type().is_file() && matches(&path) {
+                    return read_bounded(entry, &path).map(Some);
+                }
+            }
+            Ok(None)
+        }
+    }
+}
+
+fn read_bounded(entry: impl Read, name: &str) -> Result<Vec<u8>, String> {
+    let mut contents = Vec::new();
+    entry
+        .take(MAX_ENTRY_SIZE + 1)
+        .read_to_end(&mut contents)
+        .map_err(|e| e.to_string())?;
+    if contents.len() as u64 > MAX_ENTRY_SIZE {
+        return Err(format!("`{}` is larger than {} bytes", name, MAX_ENTRY_SIZE));
+    }
+    Ok(contents)
+}
+
+// Reads the files of a packaged wrapper straight out of a `.zip` or `.tar.gz`,
+// without extracting it. Archives on disk are read once, on first use
+pub struct ArchiveFileReader {
+    path: Option<PathBuf>,
+    kind: Option<ArchiveKind>,
+    archive: Mutex<Option<Arc<Vec<u8>>>>,
+}
+
+impl ArchiveFileReader {
+    pub fn from_path(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        Self {
+            kind: ArchiveKind::from_path(&path.to_string_lossy()),
+            path: Some(path),
+            archive: Mutex::new(None),
+        }
+    }
+
+    pub fn from_bytes(archive: Vec<u8>, kind: ArchiveKind) -> Self {
+        Self {
+            path: None,
+            kind: Some(kind),
+            archive: Mutex::new(Some(Arc::new(archive))),
+        }
+    }
+
+    fn archive(&self) -> Result<Arc<Vec<u8>>, Error> {
+        let mut archive = self.archive.lock().unwrap();
+        if let Some(archive) = archive.as_ref() {
+            return Ok(archive.clone());
+        }
+
+        // Only archives given by path start out unread
+        let path = self.path.as_ref().unwrap();
+        let bytes = std::fs::read(path).map_err(|e| {
+            Error::FileReadError(format!("Failed to read archive `{}`: {}", path.display(), e))
+        })?;
+        let bytes = Arc::new(bytes);
+        *archive = Some(bytes.clone());
+        Ok(bytes)
+    }
+}
+
+impl FileReader for ArchiveFileReader {
+    fn read_file(&self, path: &str) -> Result<Vec<u8>, Error> {
+        let kind = self.kind.ok_or_else(|| {
+            Error::FileReadError(format!(
+                "`{}` isn't a .zip or .tar.gz archive",
+                self.path.as_ref().map(|path| path.display().to_string()).unwrap_or_default()
+            ))
+        })?;
+        let archive = self.archive()?;
+        read_entry(&archive, kind, path)
+            .map_err(Error::FileReadError)?
+            .ok_or_else(|| Error::FileReadError(format!("`{}` isn't in the archive", path)))
+    }
+}
@@ -0,0 +1,89 @@
+use std::{
+    sync::{atomic::{AtomicBool, Ordering}, Arc, Condvar, Mutex},
+    time::{Duration, Instant},
+};
+
+// Shared by a token and all of its children, so that cancelling any of them
+// wakes the threads waiting on the others, which then check their own state
+#[derive(Debug, Default)]
+struct Notifier {
+    lock: Mutex<()>,
+    condvar: Condvar,
+}
+
+// Children are cancelled with their parent, and on their own once their
+// deadline passes, without cancelling the parent
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+    parent: Option<Arc<CancellationToken>>,
+    notifier: Arc<Notifier>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn child(&self) -> Self {
+        Self {
+            parent: Some(Arc::new(self.clone())),
+            notifier: self.notifier.clone(),
+            ..Default::default()
+        }
+    }
+
+    pub fn child_with_timeout(&self, timeout: Duration) -> Self {
+        Self {
+            deadline: Some(Instant::now() + timeout),
+            ..self.child()
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        let _lock = self.notifier.lock.lock().unwrap();
+        self.notifier.condvar.notify_all();
+    }
+
+    /// Sleeps for `timeout`, or until the token is cancelled or its deadline
+    /// passes. Returns whether it was cancelled
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        let end = Instant::now() + timeout;
+        let mut lock = self.notifier.lock.lock().unwrap();
+        loop {
+            if self.is_cancelled() {
+                return true;
+            }
+            let left = end.saturating_duration_since(Instant::now());
+            if left.is_zero() {
+                return false;
+            }
+            // Deadlines don't notify, so the wait ends at the earliest one
+            let wait = self.remaining().map_or(left, |remaining| remaining.min(left));
+            lock = self.notifier.condvar.wait_timeout(lock, wait).unwrap().0;
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+            || self.is_timed_out()
+            || self.parent.as_ref().is_some_and(|parent| parent.is_cancelled())
+    }
+
+    /// Whether this token's own deadline has passed, regardless of its parent's
+    pub fn is_timed_out(&self) -> bool {
+        self.deadline.is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Time left before the earliest deadline of this token and its parents
+    pub fn remaining(&self) -> Option<Duration> {
+        let own = self.deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+        let parent = self.parent.as_ref().and_then(|parent| parent.remaining());
+        match (own, parent) {
+            (Some(own), Some(parent)) => Some(own.min(parent)),
+            (own, parent) => own.or(parent),
+        }
+    }
+}
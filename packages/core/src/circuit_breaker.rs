@@ -0,0 +1,136 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::{error::Error, uri::Uri};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CircuitState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+#[derive(Clone, Debug)]
+pub struct CircuitBreakerConfig {
+    pub failure_threshold: u32,
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Circuit {
+    failures: u32,
+    opened_at: Option<Instant>,
+    probing: bool,
+}
+
+impl Circuit {
+    fn closed() -> Self {
+        Self { failures: 0, opened_at: None, probing: false }
+    }
+
+    fn get_state(&self, cooldown: Duration) -> CircuitState {
+        match self.opened_at {
+            None => CircuitState::Closed,
+            Some(opened_at) if opened_at.elapsed() < cooldown => CircuitState::Open,
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+}
+
+// Circuits are shared between clones, so every invoker created from the same
+// client sees (and updates) the same state
+#[derive(Clone, Debug)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    circuits: Arc<Mutex<HashMap<String, Circuit>>>,
+}
+
+impl CircuitBreaker {
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            circuits: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn get_config(&self) -> &CircuitBreakerConfig {
+        &self.config
+    }
+
+    pub fn get_state(&self, uri: &Uri) -> CircuitState {
+        self.circuits
+            .lock()
+            .unwrap()
+            .get(&uri.uri)
+            .map_or(CircuitState::Closed, |circuit| circuit.get_state(self.config.cooldown))
+    }
+
+    pub fn get_states(&self) -> HashMap<String, CircuitState> {
+        self.circuits
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(uri, circuit)| (uri.clone(), circuit.get_state(self.config.cooldown)))
+            .collect()
+    }
+
+    // Once the cooldown is over, a single request is let through to probe the
+    // wrapper; the circuit stays half-open for everyone else until it completes
+    pub fn acquire(&self, uri: &Uri) -> Result<CircuitState, Error> {
+        let mut circuits = self.circuits.lock().unwrap();
+        let circuit = match circuits.get_mut(&uri.uri) {
+            Some(circuit) => circuit,
+            None => return Ok(CircuitState::Closed),
+        };
+
+        match circuit.get_state(self.config.cooldown) {
+            CircuitState::Closed => Ok(CircuitState::Closed),
+            CircuitState::HalfOpen if !circuit.probing => {
+                circuit.probing = true;
+                Ok(CircuitState::HalfOpen)
+            }
+            _ => Err(Error::CircuitOpenError(uri.to_string())),
+        }
+    }
+
+    pub fn record_success(&self, uri: &Uri) {
+        self.circuits.lock().unwrap().remove(&uri.uri);
+    }
+
+    pub fn record_failure(&self, uri: &Uri) -> CircuitState {
+        let mut circuits = self.circuits.lock().unwrap();
+        let circuit = circuits.entry(uri.uri.clone()).or_insert_with(Circuit::closed);
+
+        circuit.failures += 1;
+        if circuit.probing || circuit.failures >= self.config.failure_threshold {
+            circuit.opened_at = Some(Instant::now());
+            circuit.probing = false;
+        }
+
+        circuit.get_state(self.config.cooldown)
+    }
+
+    // Ends a probe that neither proved nor disproved the wrapper is healthy
+    // (e.g. an invocation rejected by validation)
+    pub fn release(&self, uri: &Uri) {
+        if let Some(circuit) = self.circuits.lock().unwrap().get_mut(&uri.uri) {
+            circuit.probing = false;
+        }
+    }
+
+    pub fn reset(&self, uri: &Uri) {
+        self.record_success(uri);
+    }
+}
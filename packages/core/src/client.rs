@@ -5,7 +5,12 @@ use crate::loader::Loader;
 use crate::uri::Uri;
 use crate::interface_implementation::InterfaceImplementations;
 use crate::resolvers::uri_resolver::{UriResolverHandler, UriResolver};
+use crate::resolvers::uri_resolver_aggregator::UriResolverAggregator;
 use crate::env::{Envs};
+use crate::retry::RetryPolicies;
+use crate::circuit_breaker::CircuitBreakerConfig;
+use crate::quota::QuotaPolicies;
+use crate::permissions::PermissionPolicy;
 
 #[derive(Clone,Debug)]
 pub struct UriRedirect {
@@ -22,7 +27,29 @@ impl UriRedirect {
 pub struct ClientConfig {
   pub resolver: Arc<dyn UriResolver>,
   pub envs: Option<Envs>,
-  pub interfaces: Option<InterfaceImplementations>
+  pub interfaces: Option<InterfaceImplementations>,
+  pub validate_args: bool,
+  pub retry_policies: Option<RetryPolicies>,
+  pub circuit_breaker: Option<CircuitBreakerConfig>,
+  pub quotas: Option<QuotaPolicies>,
+  pub permissions: Option<PermissionPolicy>,
+}
+
+// Resolves nothing, and leaves every policy off, so that configs only set
+// what they use: `ClientConfig { resolver, ..Default::default() }`
+impl Default for ClientConfig {
+  fn default() -> Self {
+    Self {
+      resolver: Arc::new(UriResolverAggregator::new(vec![])),
+      envs: None,
+      interfaces: None,
+      validate_args: false,
+      retry_policies: None,
+      circuit_breaker: None,
+      quotas: None,
+      permissions: None,
+    }
+  }
 }
 
 pub trait Client: Send + Sync + Invoker + UriResolverHandler + Loader {
@@ -30,6 +30,28 @@ pub enum Error {
   ResolverError(String),
   #[error("`{0}`")]
   PluginError(String),
+  #[error("Invalid env for `{0}`:\n{1}")]
+  EnvValidationError(String, String),
+  #[error("Invalid args for `{0}` method `{1}`:\n{2}")]
+  ArgsValidationError(String, String, String),
+  #[error("Invocation of `{0}` was cancelled")]
+  CancelledError(String),
+  #[error("Circuit breaker for `{0}` is open")]
+  CircuitOpenError(String),
+  #[error("Quota exceeded for `{0}`: {1}")]
+  QuotaExceededError(String, String),
+  #[error("`{0}` is not permitted to invoke `{2}` on `{1}`")]
+  PermissionDeniedError(String, String, String),
+  #[error("`{0}` doesn't match the lockfile: {1}")]
+  LockfileError(String, String),
+  #[error("Integrity check failed for `{0}`: {1}")]
+  IntegrityError(String, String),
+  #[error("Resolver extension `{0}` timed out after {1}ms")]
+  ExtensionTimeoutError(String, u128),
+  #[error("Every resolver failed to resolve `{0}`:\n{}", .1.join("\n"))]
+  ResolversFailedError(String, Vec<String>),
+  #[error("Infinite loop while resolving URIs: {}", .0.join(" → "))]
+  InfiniteLoopError(Vec<String>),
 }
 
 impl From<MsgpackError> for Error {
@@ -0,0 +1,24 @@
+// `*` matches any sequence of characters, every other character matches itself
+pub fn matches_glob(pattern: &str, value: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == value,
+        Some((prefix, rest)) => {
+            if !value.starts_with(prefix) {
+                return false;
+            }
+            let value = &value[prefix.len()..];
+            (0..=value.len())
+                .filter(|i| value.is_char_boundary(*i))
+                .any(|i| matches_glob(rest, &value[i..]))
+        }
+    }
+}
+
+// URI patterns can be written with or without the `wrap://` scheme
+pub fn matches_uri_glob(pattern: &str, uri: &str) -> bool {
+    if pattern.starts_with("wrap://") || pattern.starts_with('*') {
+        matches_glob(pattern, uri)
+    } else {
+        matches_glob(&format!("wrap://{}", pattern), uri)
+    }
+}
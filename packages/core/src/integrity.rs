@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+
+use ring::signature::{UnparsedPublicKey, ED25519};
+use sha2::{Digest, Sha256};
+
+use crate::uri::Uri;
+
+pub fn sha256(bytes: &[u8]) -> String {
+    Sha256::digest(bytes).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ContentHashes {
+    pub manifest_sha256: Option<String>,
+    pub wasm_sha256: Option<String>,
+}
+
+impl ContentHashes {
+    // Wrapper URIs can pin the content they resolve to with a fragment, e.g.
+    // `wrap://https/example.com/wrapper#sha256=<hex>&wasm-sha256=<hex>`. The
+    // fragment may be followed by a file path, `...#sha256=<hex>/wrap.wasm`,
+    // and the path is returned without it
+    pub fn split_path(path: &str) -> Result<(String, Self), String> {
+        let Some(start) = path.find('#') else {
+            return Ok((path.to_string(), Self::default()));
+        };
+        let end = path[start..].find('/').map(|i| start + i).unwrap_or(path.len());
+
+        let mut hashes = Self::default();
+        for entry in path[start + 1..end].split('&') {
+            let (key, value) = entry.split_once('=').unwrap_or((entry, ""));
+            let value = Some(value.to_ascii_lowercase());
+            match key {
+                "sha256" => hashes.manifest_sha256 = value,
+                "wasm-sha256" => hashes.wasm_sha256 = value,
+                _ => return Err(format!("Unsupported content hash `{}` in `{}`", entry, path)),
+            }
+        }
+
+        Ok((format!("{}{}", &path[..start], &path[end..]), hashes))
+    }
+
+    pub fn from_uri(uri: &Uri) -> Result<Option<Self>, String> {
+        let (_, hashes) = Self::split_path(&uri.path)?;
+        Ok((hashes != Self::default()).then_some(hashes))
+    }
+
+    pub fn get(&self, file: &str) -> Option<&String> {
+        match file {
+            "wrap.info" => self.manifest_sha256.as_ref(),
+            "wrap.wasm" => self.wasm_sha256.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+// Expected hashes come from the URI's fragment and from `hashes`, and both
+// have to match. With trusted keys, every wrapper fetched by a resolver
+// extension needs a `wrap.sig` signed by one of them
+#[derive(Clone, Debug, Default)]
+pub struct IntegrityPolicy {
+    hashes: HashMap<String, ContentHashes>,
+    trusted_keys: Vec<Vec<u8>>,
+}
+
+impl IntegrityPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn expect_hashes(mut self, uri: &Uri, hashes: ContentHashes) -> Self {
+        self.hashes.insert(uri.to_string(), hashes);
+        self
+    }
+
+    /// Trusts a raw 32 byte ed25519 public key
+    pub fn trust_key(mut self, public_key: &[u8]) -> Self {
+        self.trusted_keys.push(public_key.to_vec());
+        self
+    }
+
+    pub fn requires_signature(&self) -> bool {
+        !self.trusted_keys.is_empty()
+    }
+
+    // A malformed fragment counts as pinned, so that it fails verification
+    pub fn expects_hashes(&self, uri: &Uri) -> bool {
+        self.hashes.contains_key(&uri.to_string()) || !matches!(ContentHashes::from_uri(uri), Ok(None))
+    }
+
+    pub fn applies_to(&self, uri: &Uri) -> bool {
+        self.requires_signature() || self.expects_hashes(uri)
+    }
+
+    /// Returns a description of the checks that passed, or why one failed
+    pub fn verify(
+        &self,
+        uri: &Uri,
+        manifest: &[u8],
+        wasm_module: &[u8],
+        signature: Option<&[u8]>,
+    ) -> Result<String, String> {
+        let expected: Vec<ContentHashes> = ContentHashes::from_uri(uri)?
+            .into_iter()
+            .chain(self.hashes.get(&uri.to_string()).cloned())
+            .collect();
+        let mut checks = vec![];
+
+        for hashes in &expected {
+            verify_hash("wrap.info", hashes.manifest_sha256.as_ref(), manifest)?;
+            verify_hash("wrap.wasm", hashes.wasm_sha256.as_ref(), wasm_module)?;
+        }
+        if !expected.is_empty() {
+            checks.push("content hashes match".to_string());
+        }
+
+        if self.requires_signature() {
+            let signature = signature.ok_or("wrap.sig is missing")?;
+            let message = signed_message(manifest, wasm_module);
+            let key = self
+                .trusted_keys
+                .iter()
+                .position(|key| UnparsedPublicKey::new(&ED25519, key).verify(&message, signature).is_ok())
+                .ok_or("wrap.sig isn't signed by a trusted key")?;
+            checks.push(format!("signed by trusted key {}", key));
+        }
+
+        Ok(checks.join(", "))
+    }
+}
+
+// Signatures are over the sha256 digests of `wrap.info` and `wrap.wasm`,
+// one after the other
+pub fn signed_message(manifest: &[u8], wasm_module: &[u8]) -> Vec<u8> {
+    [Sha256::digest(manifest), Sha256::digest(wasm_module)].concat()
+}
+
+pub fn verify_hash(file: &str, expected: Option<&String>, bytes: &[u8]) -> Result<(), String> {
+    let Some(expected) = expected else {
+        return Ok(());
+    };
+
+    let actual = sha256(bytes);
+    if &actual != expected {
+        return Err(format!("{} has sha256 {}, expected {}", file, actual, expected));
+    }
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use crate::{
-    error::Error, uri::Uri, resolvers::uri_resolution_context::UriResolutionContext, wrapper::Wrapper, env::{Env}, interface_implementation::InterfaceImplementations,
+    cancellation::CancellationToken, circuit_breaker::CircuitBreaker, error::Error, uri::Uri, resolvers::uri_resolution_context::UriResolutionContext, wrapper::Wrapper, env::{Env}, interface_implementation::InterfaceImplementations,
 };
 use std::{sync::{Arc, Mutex}};
 
@@ -23,4 +23,18 @@ pub trait Invoker: Send + Sync {
     ) -> Result<Vec<u8>, Error>;
     fn get_implementations(&self, uri: Uri) -> Result<Vec<Uri>, Error>;
     fn get_interfaces(&self) -> Option<InterfaceImplementations>;
+    fn get_cancellation_token(&self) -> Option<CancellationToken> {
+        None
+    }
+    // The same invoker, with its invocations cancelled through `token`.
+    // Invokers that can't be cancelled return None
+    fn with_cancellation_token(&self, _token: CancellationToken) -> Option<Arc<dyn Invoker>> {
+        None
+    }
+    fn get_circuit_breaker(&self) -> Option<CircuitBreaker> {
+        None
+    }
+    fn check_subinvoke_permission(&self, _uri: &Uri, _method: &str) -> Result<(), Error> {
+        Ok(())
+    }
 }
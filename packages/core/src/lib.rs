@@ -1,4 +1,9 @@
+pub mod abi;
+#[cfg(feature = "archive")]
+pub mod archive;
 pub mod uri;
+pub mod cancellation;
+pub mod circuit_breaker;
 pub mod error;
 pub mod client;
 pub mod wrapper;
@@ -8,5 +13,10 @@ pub mod package;
 pub mod loader;
 pub mod file_reader;
 pub mod env;
+pub mod glob;
+pub mod integrity;
+pub mod permissions;
 pub mod interface_implementation;
 pub mod resolvers;
+pub mod quota;
+pub mod retry;
@@ -0,0 +1,105 @@
+use crate::{error::Error, glob::{matches_glob, matches_uri_glob}, uri::Uri};
+
+#[derive(Clone, Debug)]
+pub struct PermissionRule {
+    pub uri: String,
+    pub method: String,
+}
+
+impl PermissionRule {
+    pub fn new(uri: &str, method: &str) -> Self {
+        Self {
+            uri: uri.to_string(),
+            method: method.to_string(),
+        }
+    }
+
+    pub fn matches(&self, uri: &Uri, method: &str) -> bool {
+        matches_uri_glob(&self.uri, &uri.uri) && matches_glob(&self.method, method)
+    }
+}
+
+// Deny rules take precedence over allow rules. Without an allow list, anything
+// that isn't denied is allowed
+#[derive(Clone, Debug, Default)]
+pub struct SubinvokePermissions {
+    pub allow: Option<Vec<PermissionRule>>,
+    pub deny: Vec<PermissionRule>,
+}
+
+impl SubinvokePermissions {
+    pub fn allow_all() -> Self {
+        Self::default()
+    }
+
+    pub fn deny_all() -> Self {
+        Self {
+            allow: Some(vec![]),
+            deny: vec![],
+        }
+    }
+
+    pub fn allow(mut self, uri: &str, method: &str) -> Self {
+        self.allow
+            .get_or_insert_with(Vec::new)
+            .push(PermissionRule::new(uri, method));
+        self
+    }
+
+    pub fn deny(mut self, uri: &str, method: &str) -> Self {
+        self.deny.push(PermissionRule::new(uri, method));
+        self
+    }
+
+    pub fn is_allowed(&self, uri: &Uri, method: &str) -> bool {
+        if self.deny.iter().any(|rule| rule.matches(uri, method)) {
+            return false;
+        }
+
+        match &self.allow {
+            Some(allow) => allow.iter().any(|rule| rule.matches(uri, method)),
+            None => true,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PermissionPolicy {
+    wrappers: Vec<(String, SubinvokePermissions)>,
+    default: SubinvokePermissions,
+}
+
+impl PermissionPolicy {
+    pub fn new(default: SubinvokePermissions) -> Self {
+        Self {
+            wrappers: vec![],
+            default,
+        }
+    }
+
+    // Callers are matched against the patterns in the order they were added,
+    // falling back to the default permissions
+    pub fn set_wrapper_permissions(&mut self, caller: &str, permissions: SubinvokePermissions) {
+        self.wrappers.retain(|(pattern, _)| pattern != caller);
+        self.wrappers.push((caller.to_string(), permissions));
+    }
+
+    pub fn get_permissions(&self, caller: &Uri) -> &SubinvokePermissions {
+        self.wrappers
+            .iter()
+            .find(|(pattern, _)| matches_uri_glob(pattern, &caller.uri))
+            .map_or(&self.default, |(_, permissions)| permissions)
+    }
+
+    pub fn check(&self, caller: &Uri, uri: &Uri, method: &str) -> Result<(), Error> {
+        if self.get_permissions(caller).is_allowed(uri, method) {
+            Ok(())
+        } else {
+            Err(Error::PermissionDeniedError(
+                caller.to_string(),
+                uri.to_string(),
+                method.to_string(),
+            ))
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Condvar, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::{cancellation::CancellationToken, error::Error, glob::matches_glob, uri::Uri};
+
+// Condvar waits can't be woken by a cancellation token, so queued invocations
+// with a token check it at least this often
+const CANCELLATION_CHECK_INTERVAL: Duration = Duration::from_millis(10);
+
+#[derive(Clone, Debug)]
+pub struct RateLimit {
+    pub burst: u32,
+    pub per_second: f64,
+}
+
+#[derive(Clone, Debug)]
+pub struct QuotaQueue {
+    pub max_size: u32,
+    pub timeout: Duration,
+}
+
+// Without a queue, invocations over the limit are rejected right away
+#[derive(Clone, Debug, Default)]
+pub struct QuotaPolicy {
+    pub rate_limit: Option<RateLimit>,
+    pub max_concurrent: Option<u32>,
+    pub queue: Option<QuotaQueue>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct QuotaPolicies {
+    uris: HashMap<String, QuotaPolicy>,
+    authorities: Vec<(String, QuotaPolicy)>,
+}
+
+impl QuotaPolicies {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_uri_policy(&mut self, uri: &Uri, policy: QuotaPolicy) {
+        self.uris.insert(uri.uri.clone(), policy);
+    }
+
+    // `*` matches any sequence of characters, e.g. `*.eth` or `ipfs`
+    pub fn set_authority_policy(&mut self, pattern: &str, policy: QuotaPolicy) {
+        self.authorities.retain(|(p, _)| p != pattern);
+        self.authorities.push((pattern.to_string(), policy));
+    }
+
+    // A policy set for the URI takes precedence over authority patterns,
+    // which are matched in the order they were added
+    pub fn get_policy(&self, uri: &Uri) -> Option<&QuotaPolicy> {
+        self.find_policy(uri).map(|(_, policy)| policy)
+    }
+
+    // Also returns the key of the quota the policy is tracked under: every URI
+    // matching an authority pattern shares that pattern's quota
+    fn find_policy(&self, uri: &Uri) -> Option<(String, &QuotaPolicy)> {
+        match self.uris.get(&uri.uri) {
+            Some(policy) => Some((uri.uri.clone(), policy)),
+            None => self
+                .authorities
+                .iter()
+                .find(|(pattern, _)| matches_glob(pattern, &uri.authority))
+                .map(|(pattern, policy)| (format!("authority:{}", pattern), policy)),
+        }
+    }
+
+    pub fn extend(&mut self, policies: QuotaPolicies) {
+        self.uris.extend(policies.uris);
+        for (pattern, policy) in policies.authorities {
+            self.set_authority_policy(&pattern, policy);
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Quota {
+    tokens: f64,
+    last_refill: Instant,
+    running: u32,
+    queued: u32,
+}
+
+impl Quota {
+    fn new(policy: &QuotaPolicy) -> Self {
+        Self {
+            tokens: policy.rate_limit.as_ref().map_or(0.0, |limit| limit.burst as f64),
+            last_refill: Instant::now(),
+            running: 0,
+            queued: 0,
+        }
+    }
+
+    // Returns how long to wait before trying again when the quota is exhausted
+    fn try_take(&mut self, policy: &QuotaPolicy, reentrant: bool) -> Result<(), (String, Option<Duration>)> {
+        if let Some(max_concurrent) = policy.max_concurrent {
+            if self.running >= max_concurrent && !reentrant {
+                return Err((format!("max {} concurrent invocations", max_concurrent), None));
+            }
+        }
+
+        if let Some(limit) = &policy.rate_limit {
+            let now = Instant::now();
+            let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+            self.tokens = (self.tokens + elapsed * limit.per_second).min(limit.burst as f64);
+            self.last_refill = now;
+
+            if self.tokens < 1.0 {
+                let wait = if limit.per_second > 0.0 {
+                    Some(Duration::from_secs_f64((1.0 - self.tokens) / limit.per_second))
+                } else {
+                    None
+                };
+                return Err((format!("rate limit of {}/s", limit.per_second), wait));
+            }
+            self.tokens -= 1.0;
+        }
+
+        self.running += 1;
+        Ok(())
+    }
+
+    // An idle quota is no different from a new one, so it can be dropped
+    fn is_idle(&self, policy: &QuotaPolicy) -> bool {
+        let refilled = policy.rate_limit.as_ref().map_or(true, |limit| {
+            let elapsed = self.last_refill.elapsed().as_secs_f64();
+            self.tokens + elapsed * limit.per_second >= limit.burst as f64
+        });
+        self.running == 0 && self.queued == 0 && refilled
+    }
+}
+
+pub struct QuotaPermit {
+    manager: QuotaManager,
+    key: String,
+    policy: QuotaPolicy,
+}
+
+impl Drop for QuotaPermit {
+    fn drop(&mut self) {
+        let (quotas, released) = &*self.manager.quotas;
+        let mut quotas = quotas.lock().unwrap();
+        if let Some(quota) = quotas.get_mut(&self.key) {
+            quota.running = quota.running.saturating_sub(1);
+            if quota.is_idle(&self.policy) {
+                quotas.remove(&self.key);
+            }
+        }
+        released.notify_all();
+    }
+}
+
+// Quotas are tracked per policy and shared between clones of the manager, so
+// top-level invocations and subinvocations draw from the same quota
+#[derive(Clone, Debug)]
+pub struct QuotaManager {
+    policies: QuotaPolicies,
+    quotas: Arc<(Mutex<HashMap<String, Quota>>, Condvar)>,
+}
+
+impl QuotaManager {
+    pub fn new(policies: QuotaPolicies) -> Self {
+        Self {
+            policies,
+            quotas: Arc::new((Mutex::new(HashMap::new()), Condvar::new())),
+        }
+    }
+
+    pub fn get_policies(&self) -> &QuotaPolicies {
+        &self.policies
+    }
+
+    // A reentrant invocation runs within an invocation that holds a slot of the
+    // same quota, which would never be released if the call had to wait for
+    // one, so only the rate limit applies to it
+    pub fn acquire(
+        &self,
+        uri: &Uri,
+        call_chain: &[Uri],
+        cancellation_token: Option<&CancellationToken>,
+    ) -> Result<Option<QuotaPermit>, Error> {
+        let (key, policy) = match self.policies.find_policy(uri) {
+            Some(found) => found,
+            None => return Ok(None),
+        };
+        let reentrant = call_chain
+            .iter()
+            .any(|caller| self.policies.find_policy(caller).is_some_and(|(caller_key, _)| caller_key == key));
+
+        let (quotas, released) = &*self.quotas;
+        let mut quotas = quotas.lock().unwrap();
+        let mut deadline = None;
+
+        loop {
+            let quota = quotas.entry(key.clone()).or_insert_with(|| Quota::new(policy));
+            let (reason, wait) = match quota.try_take(policy, reentrant) {
+                Ok(()) => break,
+                Err(exhausted) => exhausted,
+            };
+
+            let queue = match &policy.queue {
+                Some(queue) => queue,
+                None => return Err(Error::QuotaExceededError(uri.to_string(), reason)),
+            };
+
+            if deadline.is_none() {
+                if quota.queued >= queue.max_size {
+                    return Err(Error::QuotaExceededError(
+                        uri.to_string(),
+                        format!("{}, queue of {} is full", reason, queue.max_size),
+                    ));
+                }
+                quota.queued += 1;
+                deadline = Some(Instant::now() + queue.timeout);
+            }
+
+            if cancellation_token.is_some_and(|token| token.is_cancelled()) {
+                quota.queued -= 1;
+                return Err(Error::CancelledError(uri.to_string()));
+            }
+
+            let remaining = deadline.unwrap().saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                quota.queued -= 1;
+                return Err(Error::QuotaExceededError(
+                    uri.to_string(),
+                    format!("{}, timed out after {:?} in queue", reason, queue.timeout),
+                ));
+            }
+
+            let mut timeout = wait.map_or(remaining, |wait| wait.min(remaining));
+            if cancellation_token.is_some() {
+                timeout = timeout.min(CANCELLATION_CHECK_INTERVAL);
+            }
+            quotas = released.wait_timeout(quotas, timeout).unwrap().0;
+        }
+
+        if deadline.is_some() {
+            if let Some(quota) = quotas.get_mut(&key) {
+                quota.queued -= 1;
+            }
+        }
+
+        Ok(Some(QuotaPermit {
+            manager: self.clone(),
+            key,
+            policy: policy.clone(),
+        }))
+    }
+}
@@ -5,40 +5,24 @@ use crate::{client::UriRedirect, error::Error, uri::Uri};
 pub fn apply_redirects(uri: &Uri, redirects: &Vec<UriRedirect>) -> Result<Uri, Error> {
     let mut redirect_from_to_map = HashMap::new();
 
+    // The first redirect from a URI takes precedence
     for redirect in redirects {
-        if redirect.from.to_string() == uri.to_string() {
-            return Err(Error::RedirectsError(
-                format!(
-                    "Redirect missing the from property.\nEncountered while resolving {}",
-                    uri
-                ),
-                redirect_from_to_map,
-            ));
-        }
-
-        if redirect_from_to_map.contains_key(&redirect.from.to_string()) {
-            continue;
-        }
-
-        redirect_from_to_map.insert(redirect.from.to_string(), redirect.to.to_string());
+        redirect_from_to_map
+            .entry(redirect.from.to_string())
+            .or_insert_with(|| redirect.to.to_string());
     }
 
     let mut final_uri = uri.to_string();
-    let mut visited_uris: HashMap<String, bool> = HashMap::new();
-
-    while redirect_from_to_map.contains_key(&final_uri) {
-        visited_uris.insert(final_uri.to_string(), true);
+    let mut visited_uris: Vec<String> = vec![];
 
-        final_uri = redirect_from_to_map.get(&final_uri).unwrap().to_string();
+    while let Some(to) = redirect_from_to_map.get(&final_uri) {
+        visited_uris.push(final_uri);
+        final_uri = to.to_string();
 
-        if visited_uris.contains_key(&final_uri) {
-            return Err(Error::RedirectsError(
-                format!(
-                    "Redirect loop detected while resolving {}",
-                    uri
-                ),
-                redirect_from_to_map,
-            ));
+        if let Some(start) = visited_uris.iter().position(|visited| *visited == final_uri) {
+            let mut cycle = visited_uris.split_off(start);
+            cycle.push(final_uri);
+            return Err(Error::InfiniteLoopError(cycle));
         }
     }
 
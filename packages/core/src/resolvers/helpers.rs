@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 use crate::{
     file_reader::FileReader,
     invoke::{Invoker},
@@ -32,7 +32,8 @@ fn combine_paths(a: &str, b: &str) -> String {
 pub struct UriResolverExtensionFileReader {
     pub resolver_extension_uri: Uri,
     pub wrapper_uri: Uri,
-    pub invoker: Arc<dyn Invoker>
+    pub invoker: Arc<dyn Invoker>,
+    pub timeout: Option<Duration>,
 }
 
 impl UriResolverExtensionFileReader {
@@ -45,8 +46,15 @@ impl UriResolverExtensionFileReader {
             resolver_extension_uri,
             wrapper_uri,
             invoker,
+            timeout: None,
         } 
     } 
+
+    // Each read is bounded on its own, since files are read lazily
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
 }
 
 impl FileReader for UriResolverExtensionFileReader {
@@ -54,19 +62,59 @@ impl FileReader for UriResolverExtensionFileReader {
         let path = combine_paths(&self.wrapper_uri.path, file_path);
 
         let invoker_args = msgpack!({
-            "path": path
+            "path": path.as_str()
         });
         // TODO: This vec<u8> isn't the file but the msgpack representation of it
-        let result = self.invoker.invoke_raw(
+        let result = invoke_with_timeout(
+            &self.invoker,
             &self.resolver_extension_uri,
-            "getFile",
-            Some(&invoker_args),
-            None,
-            None
+            self.timeout,
+            |invoker| invoker.invoke_raw(
+                &self.resolver_extension_uri,
+                "getFile",
+                Some(&invoker_args),
+                None,
+                None
+            )
         )?;
         
-        let result: Vec<u8> = polywrap_msgpack::decode(&result)?;
-        Ok(result)
+        // Extensions return nothing for files they don't have
+        let result: Option<Vec<u8>> = match result.is_empty() {
+            true => None,
+            false => polywrap_msgpack::decode(&result)?,
+        };
+        result.ok_or_else(|| Error::FileReadError(format!("{} not found by {}", path, self.resolver_extension_uri)))
+    }
+}
+
+// The call gets a child of the invoker's cancellation token that times out.
+// The timeout is cooperative: wasm extensions and http requests are cut
+// short, but plugin extensions that never check the token (like the fs
+// resolver) run to the end. Only failures past the deadline are reported as
+// timeouts, a result that arrives late is still returned
+pub fn invoke_with_timeout(
+    invoker: &Arc<dyn Invoker>,
+    extension_uri: &Uri,
+    timeout: Option<Duration>,
+    invoke: impl FnOnce(Arc<dyn Invoker>) -> Result<Vec<u8>, Error>,
+) -> Result<Vec<u8>, Error> {
+    let timeout = match timeout {
+        Some(timeout) => timeout,
+        None => return invoke(invoker.clone()),
+    };
+
+    let token = invoker
+        .get_cancellation_token()
+        .unwrap_or_default()
+        .child_with_timeout(timeout);
+    let timed_invoker = invoker
+        .with_cancellation_token(token.clone())
+        .unwrap_or_else(|| invoker.clone());
+    match invoke(timed_invoker) {
+        Err(_) if token.is_timed_out() => {
+            Err(Error::ExtensionTimeoutError(extension_uri.to_string(), timeout.as_millis()))
+        }
+        result => result,
     }
 }
 
@@ -80,8 +80,8 @@ impl UriResolver for RecursiveResolver {
         resolution_context: &mut UriResolutionContext,
     ) -> Result<UriPackageOrWrapper, Error> {
         if resolution_context.is_resolving(uri) {
-            //TODO: Handle this error type specifically
-            Err(Error::ResolverError("Infinite loop error".to_string()))
+            let cycle = resolution_context.get_resolution_cycle(uri);
+            Err(Error::InfiniteLoopError(cycle.iter().map(|u| u.to_string()).collect()))
         } else {
             resolution_context.start_resolving(uri);
             let resolver_result = self
@@ -85,6 +85,22 @@ impl UriResolutionContext {
             .collect()
     }
 
+    // The URIs resolved since `uri` started resolving, back to `uri`, e.g.
+    // A → B → C → A. URIs that finished resolving in between aren't part of it
+    pub fn get_resolution_cycle(&self, uri: &Uri) -> Vec<Uri> {
+        let uri = uri.to_string();
+        let start = self.resolution_path.iter().rposition(|u| *u == uri).unwrap_or(self.resolution_path.len());
+        let mut cycle: Vec<String> = vec![];
+        for u in &self.resolution_path[start..] {
+            if self.resolving_uri_map.contains_key(u) && !cycle.contains(u) {
+                cycle.push(u.clone());
+            }
+        }
+        cycle.push(uri);
+
+        cycle.iter().map(|u| Uri::new(u)).collect()
+    }
+
     pub fn create_sub_history_context(&self) -> UriResolutionContext {
         UriResolutionContext {
             resolving_uri_map: self.resolving_uri_map.clone(),
@@ -20,6 +20,11 @@ pub trait UriResolverAggregatorBase: UriResolver + core::fmt::Debug {
         uri: &Uri,
         result: &Result<UriPackageOrWrapper, crate::error::Error>,
     ) -> String;
+    // Otherwise the first error is returned, without trying the resolvers
+    // after the one that failed
+    fn continue_on_error(&self) -> bool {
+        false
+    }
     fn try_resolve_uri_with_resolvers(
         &self,
         uri: &Uri,
@@ -27,10 +32,18 @@ pub trait UriResolverAggregatorBase: UriResolver + core::fmt::Debug {
         resolvers: Vec<Arc<dyn UriResolver>>,
         resolution_context: &mut UriResolutionContext,
     ) -> Result<UriPackageOrWrapper, crate::error::Error> {
-        let sub_context = resolution_context.create_sub_history_context();
+        // The resolvers' steps are nested under this aggregator's step
+        let mut sub_context = resolution_context.create_sub_history_context();
+        let mut errors = vec![];
         for resolver in resolvers.into_iter() {
             let result = resolver
-                .try_resolve_uri(uri, loader, resolution_context);
+                .try_resolve_uri(uri, loader, &mut sub_context);
+            if let Err(e) = &result {
+                if self.continue_on_error() {
+                    errors.push(format!("{:?}: {}", resolver, e));
+                    continue;
+                }
+            }
             let track_and_return = if let Ok(UriPackageOrWrapper::Uri(result_uri)) = &result {
                 uri.to_string() != result_uri.to_string()
             } else {
@@ -49,7 +62,11 @@ pub trait UriResolverAggregatorBase: UriResolver + core::fmt::Debug {
             }
         }
 
-        let result = Ok(UriPackageOrWrapper::Uri(uri.clone()));
+        let result = if errors.is_empty() {
+            Ok(UriPackageOrWrapper::Uri(uri.clone()))
+        } else {
+            Err(crate::error::Error::ResolversFailedError(uri.to_string(), errors))
+        };
 
         resolution_context.track_step(UriResolutionStep {
             source_uri: uri.clone(),
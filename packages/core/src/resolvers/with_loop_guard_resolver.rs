@@ -20,8 +20,8 @@ impl From<UriResolverLike> for ResolverWithLoopGuard {
 impl UriResolver for ResolverWithLoopGuard {
     fn try_resolve_uri(&self, uri: &crate::uri::Uri, loader: &dyn Loader, resolution_context: &mut UriResolutionContext) -> Result<UriPackageOrWrapper, Error> {
         if resolution_context.is_resolving(uri) {
-          //TODO handle this error
-          Err(Error::ResolverError("Infinite Loop".to_string()))
+          let cycle = resolution_context.get_resolution_cycle(uri);
+          Err(Error::InfiniteLoopError(cycle.iter().map(|u| u.to_string()).collect()))
         } else {
           resolution_context.start_resolving(uri);
 
@@ -0,0 +1,99 @@
+use std::{collections::HashMap, time::Duration};
+
+use crate::{error::Error, uri::Uri};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RetryErrorKind {
+    Resolution,
+    Invocation,
+}
+
+impl RetryErrorKind {
+    // Validation and cancellation errors are never retried: they'd fail the
+    // same way on every attempt.
+    pub fn from_error(error: &Error) -> Option<Self> {
+        match error {
+            Error::LoadWrapperError(_)
+            | Error::ResolutionError(_)
+            | Error::WrapperCreateError(_)
+            | Error::CircuitOpenError(_) => Some(RetryErrorKind::Resolution),
+            Error::InvokeError(_)
+            | Error::WrapperError(_)
+            | Error::WasmWrapperError(_)
+            | Error::PluginError(_) => Some(RetryErrorKind::Invocation),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub backoff: Duration,
+    pub backoff_multiplier: u32,
+    pub retry_on: Vec<RetryErrorKind>,
+    pub fallback_uris: Vec<Uri>,
+}
+
+impl RetryPolicy {
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            backoff: Duration::ZERO,
+            backoff_multiplier: 2,
+            retry_on: vec![RetryErrorKind::Resolution, RetryErrorKind::Invocation],
+            fallback_uris: vec![],
+        }
+    }
+
+    pub fn should_retry(&self, error: &Error) -> bool {
+        RetryErrorKind::from_error(error).is_some_and(|kind| self.retry_on.contains(&kind))
+    }
+
+    pub fn should_fallback(&self, error: &Error) -> bool {
+        RetryErrorKind::from_error(error).is_some()
+    }
+
+    // `retry` starts at 1 for the first retry (i.e. the second attempt)
+    pub fn get_backoff(&self, retry: u32) -> Duration {
+        let factor = self.backoff_multiplier.saturating_pow(retry.saturating_sub(1));
+        self.backoff.saturating_mul(factor)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct RetryPolicies {
+    policies: HashMap<String, RetryPolicy>,
+}
+
+impl RetryPolicies {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_policy(&mut self, uri: &Uri, method: Option<&str>, policy: RetryPolicy) {
+        self.policies.insert(policy_key(uri, method), policy);
+    }
+
+    pub fn remove_policy(&mut self, uri: &Uri, method: Option<&str>) {
+        self.policies.remove(&policy_key(uri, method));
+    }
+
+    // A policy set for the method takes precedence over the one set for the URI
+    pub fn get_policy(&self, uri: &Uri, method: &str) -> Option<&RetryPolicy> {
+        self.policies
+            .get(&policy_key(uri, Some(method)))
+            .or_else(|| self.policies.get(&policy_key(uri, None)))
+    }
+
+    pub fn extend(&mut self, policies: RetryPolicies) {
+        self.policies.extend(policies.policies);
+    }
+}
+
+fn policy_key(uri: &Uri, method: Option<&str>) -> String {
+    match method {
+        Some(method) => format!("{}#{}", uri, method),
+        None => uri.to_string(),
+    }
+}
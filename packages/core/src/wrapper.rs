@@ -1,6 +1,8 @@
 use std::{sync::Arc, fmt::Debug, any::Any};
 
-use crate::{error::Error, invoke::{Invoker}, uri::Uri, resolvers::uri_resolution_context::UriResolutionContext, env::Env};
+use wrap_manifest_schemas::versions::WrapManifest;
+
+use crate::{error::Error, integrity::ContentHashes, invoke::{Invoker}, uri::Uri, resolvers::uri_resolution_context::UriResolutionContext, env::Env};
 pub enum Encoding {
     Base64,
     UTF8,
@@ -22,4 +24,13 @@ pub trait Wrapper: Send + Sync + Debug + Any {
         resolution_context: Option<&mut UriResolutionContext>,
     ) -> Result<Vec<u8>, Error>;
     fn get_file(&self, options: &GetFileOptions) -> Result<Vec<u8>, Error>;
+    // Wrappers without a manifest skip args and env validation
+    fn get_manifest(&self) -> Result<&WrapManifest, Error> {
+        Err(Error::ManifestError("Wrapper has no manifest".to_string()))
+    }
+    // Hashes of the `wrap.info` and `wrap.wasm` the wrapper was created from.
+    // Wrappers without files, like plugins, have none
+    fn get_content_hashes(&self) -> Result<Option<ContentHashes>, Error> {
+        Ok(None)
+    }
 }
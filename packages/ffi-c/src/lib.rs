@@ -1,4 +1,4 @@
-use std::{ffi::{CStr}, sync::{Arc, Mutex}, collections::HashMap};
+use std::{ffi::{CStr, CString}, sync::{Arc, Mutex}, collections::HashMap};
 use filesystem_plugin::FileSystemPlugin;
 use fs_resolver_plugin::FileSystemResolverPlugin;
 use http_plugin::HttpPlugin;
@@ -13,7 +13,7 @@ use polywrap_core::{
 };
 use polywrap_plugin::package::PluginPackage;
 use polywrap_resolvers::extendable_uri_resolver::ExtendableUriResolver;
-use serde_json::Value;
+use serde_json::{json, Value};
 
 pub mod builder;
 
@@ -23,6 +23,8 @@ pub struct Buffer {
     pub len: libc::c_int,
 }
 
+// Returns the msgpack encoded result, or a buffer with null data if the
+// arguments can't be read or the invocation fails
 #[no_mangle]
 pub extern "C" fn invoke(
     client_ptr: *const libc::c_char,
@@ -30,43 +32,81 @@ pub extern "C" fn invoke(
     method: *const libc::c_char,
     args: *const libc::c_char,
 ) -> Buffer {
-    let client = unsafe {
-        Box::from_raw(client_ptr as *mut PolywrapClient)
-    };
+    // The client is borrowed, it's still owned by the caller
+    let client = unsafe { &*(client_ptr as *const PolywrapClient) };
 
-    let uri_c_str = unsafe { CStr::from_ptr(uri) };
-    let uri_str = match uri_c_str.to_str() {
-        Ok(u) => u.to_string(),
-        Err(_) => panic!("Couldn't get CStr for URI")
-    };
+    let result = (|| -> Result<Vec<u8>, String> {
+        let uri = Uri::try_from(read_c_str(uri, "URI")?).map_err(|e| e.to_string())?;
+        let method_str = read_c_str(method, "method")?;
+        let json_args: serde_json::Value =
+            serde_json::from_str(read_c_str(args, "args")?).map_err(|e| e.to_string())?;
+        let invoke_args = polywrap_msgpack::serialize(json_args).map_err(|e| e.to_string())?;
 
-    let method_c_str = unsafe { CStr::from_ptr(method) };
-    let method_str = match method_c_str.to_str() {
-        Ok(u) => u.to_string(),
-        Err(_) => panic!("Couldn't get CStr for Method")
-    };
+        client
+            .invoke_raw(&uri, method_str, Some(&invoke_args), None, None)
+            .map_err(|e| e.to_string())
+    })();
+
+    match result {
+        Ok(invoke_result) => {
+            let mut invoke_result = invoke_result.into_boxed_slice();
+            let buffer = Buffer {
+                data: invoke_result.as_mut_ptr(),
+                len: invoke_result.len() as i32
+            };
+            std::mem::forget(invoke_result);
+            buffer
+        }
+        Err(_) => Buffer { data: std::ptr::null_mut(), len: 0 },
+    }
+}
 
-    let args_c_str = unsafe { CStr::from_ptr(args) };
-    let args_str = match args_c_str.to_str() {
-        Ok(u) => u.to_string(),
-        Err(_) => panic!("Couldn't get CStr for Args")
-    };
+// Returns a JSON string to be released with `free_json`: `{"ok":<result>}`,
+// or `{"error":"<message>"}` if the arguments can't be read or the
+// invocation fails
+#[no_mangle]
+pub extern "C" fn invoke_json(
+    client_ptr: *const libc::c_char,
+    uri: *const libc::c_char,
+    method: *const libc::c_char,
+    args: *const libc::c_char,
+) -> *const libc::c_char {
+    // The client is borrowed, it's still owned by the caller
+    let client = unsafe { &*(client_ptr as *const PolywrapClient) };
 
-    let json_args: serde_json::Value = serde_json::from_str(&args_str).unwrap();
-    let invoke_args = polywrap_msgpack::serialize(json_args).unwrap();
+    let result = (|| -> Result<Value, String> {
+        let uri = Uri::try_from(read_c_str(uri, "URI")?).map_err(|e| e.to_string())?;
+        let method_str = read_c_str(method, "method")?;
+        let json_args: serde_json::Value =
+            serde_json::from_str(read_c_str(args, "args")?).map_err(|e| format!("Invalid JSON args: {}", e))?;
 
-    let mut invoke_result =
         client
-            .invoke_raw(&uri_str.try_into().unwrap(), &method_str, Some(&invoke_args), None, None)
-            .unwrap().into_boxed_slice();
+            .invoke_json(&uri, method_str, Some(json_args), None, None)
+            .map_err(|e| e.to_string())
+    })();
 
-    let buffer = Buffer {
-        data: invoke_result.as_mut_ptr(),
-        len: invoke_result.len() as i32
+    let envelope = match result {
+        Ok(result) => json!({ "ok": result }),
+        Err(error) => json!({ "error": error }),
     };
-    std::mem::forget(invoke_result);
+    // Serialized JSON escapes NUL characters, so it's always a valid C string
+    CString::new(envelope.to_string()).unwrap().into_raw()
+}
+
+fn read_c_str<'a>(c_str: *const libc::c_char, name: &str) -> Result<&'a str, String> {
+    if c_str.is_null() {
+        return Err(format!("{} is null", name));
+    }
+    unsafe { CStr::from_ptr(c_str) }
+        .to_str()
+        .map_err(|e| format!("{} isn't valid UTF-8: {}", name, e))
+}
 
-    buffer
+#[no_mangle]
+pub extern "C" fn free_json(json: *mut libc::c_char) {
+    if !json.is_null() {
+        unsafe { drop(CString::from_raw(json)) };
+    }
 }
 
 #[no_mangle]
@@ -86,9 +126,9 @@ pub extern "C" fn create_client(
     );
 
     let client = PolywrapClient::new(ClientConfig {
-        envs: None,
         interfaces: Some(interfaces),
         resolver,
+        ..Default::default()
     });
 
     Box::into_raw(Box::new(client)) as *const libc::c_char
@@ -96,19 +136,19 @@ pub extern "C" fn create_client(
 
 #[no_mangle]
 pub extern "C" fn create_resolver() -> *const libc::c_char {
-    let fs = FileSystemPlugin { env: Value::Null };
+    let fs = FileSystemPlugin { env: Value::Null, sandbox: None };
     let fs_plugin_package: PluginPackage = fs.into();
     let fs_package = Arc::new(Mutex::new(fs_plugin_package));
 
-    let fs_resolver = FileSystemResolverPlugin { env: Value::Null };
+    let fs_resolver = FileSystemResolverPlugin { env: Value::Null, base_dir: None };
     let fs_resolver_plugin_package: PluginPackage = fs_resolver.into();
     let fs_resolver_package = Arc::new(Mutex::new(fs_resolver_plugin_package));
 
-    let http = HttpPlugin { env: Value::Null };
+    let http = HttpPlugin::new(None);
     let http_plugin_package: PluginPackage = http.into();
     let http_package = Arc::new(Mutex::new(http_plugin_package));
 
-    let http_resolver = HttpResolverPlugin { env: Value::Null };
+    let http_resolver = HttpResolverPlugin { env: Value::Null, cache: Default::default() };
     let http_resolver_plugin_package: PluginPackage = http_resolver.into();
     let http_resolver_package = Arc::new(Mutex::new(http_resolver_plugin_package));
 
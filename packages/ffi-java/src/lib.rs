@@ -10,7 +10,6 @@ use polywrap_core::resolvers::recursive_resolver::RecursiveResolver;
 pub use polywrap_core::resolvers::static_resolver::StaticResolver;
 use polywrap_core::{
     client::ClientConfig,
-    invoke::{Invoker},
     uri::Uri,
 };
 use android_logger::Config;
@@ -47,9 +46,9 @@ pub extern "system" fn Java_com_example_polywrapmobile_NativeClient_nCreateClien
     );
 
     let client = PolywrapClient::new(ClientConfig {
-        envs: None,
         interfaces: Some(interfaces),
         resolver,
+        ..Default::default()
     });
 
     Box::into_raw(Box::new(client))
@@ -77,39 +76,31 @@ pub extern "system" fn Java_com_example_polywrapmobile_NativeClient_nInvoke(
     method: JString,
     args: JString,
 ) -> jstring {
-    let client = unsafe {
-        Box::from_raw(client_ptr as *mut PolywrapClient)
-    };
-
-    let uri_str: String = env
-      .get_string(uri)
-      .expect("Couldn't get java string! for URI")
-      .into();
-
-    let uri: Uri = uri_str.try_into().unwrap();
-
-    let method: String = env
-      .get_string(method)
-      .expect("Couldn't get java string! for Method")
-      .into();
-
-    let args: String = env
-      .get_string(args)
-      .expect("Couldn't get java string! for args")
-      .into();
-
-    let uri: Uri = uri.try_into().unwrap();
-    let json_args: serde_json::Value = serde_json::from_str(&args).unwrap();
-
-    let invoke_args = polywrap_msgpack::serialize(json_args).unwrap();
-
-    let invoke_result = 
-        client
-            .invoke_raw(&uri, &method, Some(&invoke_args), None, None)
-            .unwrap();
-
-    let output = env
-    .new_string(format!("Result: {:#?}", invoke_result))
-    .expect("Couldn't create java string!");
-    output.into_raw()
+    // The client is borrowed, it's released with `nDestructClient`
+    let client = unsafe { &*(client_ptr as *const PolywrapClient) };
+
+    let result = (|| -> Result<String, String> {
+        let uri: String = env.get_string(uri).map_err(|e| e.to_string())?.into();
+        let method: String = env.get_string(method).map_err(|e| e.to_string())?.into();
+        let args: String = env.get_string(args).map_err(|e| e.to_string())?.into();
+
+        let uri = Uri::try_from(uri).map_err(|e| e.to_string())?;
+        let json_args: serde_json::Value =
+            serde_json::from_str(&args).map_err(|e| format!("Invalid JSON args: {}", e))?;
+        let invoke_result = client
+            .invoke_json(&uri, &method, Some(json_args), None, None)
+            .map_err(|e| e.to_string())?;
+
+        Ok(invoke_result.to_string())
+    })();
+
+    // Errors are thrown as exceptions on the Java side, rather than panicking
+    // across the FFI boundary
+    match result.and_then(|json| env.new_string(json).map_err(|e| e.to_string())) {
+        Ok(output) => output.into_raw(),
+        Err(error) => {
+            let _ = env.throw_new("java/lang/RuntimeException", error);
+            std::ptr::null_mut()
+        }
+    }
 }
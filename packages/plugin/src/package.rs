@@ -44,6 +44,6 @@ impl WrapPackage for PluginPackage {
     }
 
     fn create_wrapper(&self) -> Result<Arc<Mutex<dyn Wrapper>>, Error> {
-        Ok(Arc::new(Mutex::new(PluginWrapper::new(self.plugin_module.clone()))))
+        Ok(Arc::new(Mutex::new(PluginWrapper::new(self.plugin_module.clone(), self.manifest.clone()))))
     }
 }
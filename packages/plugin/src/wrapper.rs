@@ -1,7 +1,8 @@
-use std::{sync::{Arc, Mutex}, fmt::{Formatter, Debug}};
+use std::{sync::{Arc, Mutex, PoisonError}, fmt::{Formatter, Debug}};
 
 use polywrap_core::{uri::Uri, invoke::Invoker, wrapper::{Wrapper, GetFileOptions}, resolvers::uri_resolution_context::UriResolutionContext, env::Env};
 use serde_json::Value;
+use wrap_manifest_schemas::versions::WrapManifest;
 
 use crate::module::{PluginModule};
 
@@ -9,13 +10,15 @@ type PluginModuleInstance = Arc<Mutex<Box<dyn (PluginModule)>>>;
 
 pub struct PluginWrapper {
     instance: PluginModuleInstance,
+    manifest: WrapManifest,
 }
 
 impl PluginWrapper {
     pub fn new(
         instance: PluginModuleInstance,
+        manifest: WrapManifest,
     ) -> Self {
-        Self { instance }
+        Self { instance, manifest }
     }
 }
 
@@ -29,8 +32,12 @@ impl Wrapper for PluginWrapper {
         env: Option<Env>,
         _: Option<&mut UriResolutionContext>,
     ) -> Result<Vec<u8>, polywrap_core::error::Error> {
+        if invoker.get_cancellation_token().is_some_and(|token| token.is_cancelled()) {
+            return Err(polywrap_core::error::Error::CancelledError(uri.to_string()));
+        }
+
         if let Some(e) = env {
-            self.instance.lock().unwrap().set_env(e);
+            self.instance.lock().unwrap_or_else(PoisonError::into_inner).set_env(e);
         };
 
         let args = match args {
@@ -38,9 +45,11 @@ impl Wrapper for PluginWrapper {
             None => vec![],
         };
 
+        // Instances are shared by every wrapper of the package, so one method
+        // panicking doesn't fail the later invocations
         let result = self
             .instance
-            .lock().unwrap()
+            .lock().unwrap_or_else(PoisonError::into_inner)
             ._wrap_invoke(method, &args, invoker);
 
         match result {
@@ -48,15 +57,23 @@ impl Wrapper for PluginWrapper {
             Err(e) => Err(crate::error::PluginError::InvocationError {
                 uri: uri.to_string(),
                 method: method.to_string(),
-                // Decode the args from msgpack to JSON for better error logging
-                args: polywrap_msgpack::decode::<Value>(&args).unwrap().to_string(),
+                // Decode the args from msgpack to JSON for better error logging,
+                // extension types like maps can't be represented in JSON
+                args: polywrap_msgpack::decode::<Value>(&args)
+                    .map(|args| args.to_string())
+                    .unwrap_or_else(|_| format!("{:?}", args)),
                 exception: e.to_string(),
             }
             .into()),
         }
     }
     fn get_file(&self, _: &GetFileOptions) -> Result<Vec<u8>, polywrap_core::error::Error> {
-        unimplemented!("client.get_file(...) is not implemented for Plugins.")
+        Err(polywrap_core::error::Error::WrapperError(
+            "client.get_file(...) is not implemented for Plugins.".to_string(),
+        ))
+    }
+    fn get_manifest(&self) -> Result<&WrapManifest, polywrap_core::error::Error> {
+        Ok(&self.manifest)
     }
 }
 
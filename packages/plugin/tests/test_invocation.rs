@@ -72,8 +72,8 @@ fn invoke_test() {
     let client = PolywrapClient::new(
         ClientConfig {
             envs: Some(envs),
-            interfaces: None,
             resolver: Arc::new(static_resolver),
+            ..Default::default()
         }
     );
 
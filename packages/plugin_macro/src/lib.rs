@@ -89,8 +89,11 @@ pub fn plugin_impl(args: TokenStream, input: TokenStream) -> TokenStream {
                       let camel_cased_args = polywrap_msgpack::decode::<polywrap_msgpack::rmpv::Value>(params.clone())?;
                       let snake_cased_args = polywrap_plugin::utils::convert_keys_to_snake_case(&camel_cased_args);
                       let encoded_snake_cased_args = polywrap_msgpack::encode(&snake_cased_args)?;
+                      let args = polywrap_msgpack::decode(&encoded_snake_cased_args).map_err(|e| {
+                        polywrap_plugin::error::PluginError::MsgpackError(format!("Invalid args for method '{}': {}", #ident_str, e))
+                      })?;
                       let result = self.#ident(
-                        &polywrap_msgpack::decode(&encoded_snake_cased_args).unwrap(),
+                        &args,
                         invoker,
                       )?;
 
@@ -107,8 +110,11 @@ pub fn plugin_impl(args: TokenStream, input: TokenStream) -> TokenStream {
                     let camel_cased_args = polywrap_msgpack::decode::<polywrap_msgpack::rmpv::Value>(params.clone())?;
                     let snake_cased_args = polywrap_plugin::utils::convert_keys_to_snake_case(&camel_cased_args);
                     let encoded_snake_cased_args = polywrap_msgpack::encode(&snake_cased_args)?;
+                    let args = polywrap_msgpack::decode(&encoded_snake_cased_args).map_err(|e| {
+                      polywrap_plugin::error::PluginError::MsgpackError(format!("Invalid args for method '{}': {}", #ident_str, e))
+                    })?;
                     let result = self.#ident(
-                      &polywrap_msgpack::decode(&encoded_snake_cased_args).unwrap(),
+                      &args,
                       invoker,
                     )?;
     
@@ -146,7 +152,7 @@ pub fn plugin_impl(args: TokenStream, input: TokenStream) -> TokenStream {
       impl From<#struct_ident> for polywrap_plugin::wrapper::PluginWrapper {
         fn from(plugin: #struct_ident) -> polywrap_plugin::wrapper::PluginWrapper {
             let plugin_module = Arc::new(std::sync::Mutex::new(Box::new(plugin) as Box<dyn polywrap_plugin::module::PluginModule>));
-            polywrap_plugin::wrapper::PluginWrapper::new(plugin_module)
+            polywrap_plugin::wrapper::PluginWrapper::new(plugin_module, get_manifest())
         }
       }
     };
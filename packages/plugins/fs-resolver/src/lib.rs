@@ -1,17 +1,123 @@
-use std::{sync::Arc, path::Path};
+use std::{sync::Arc, path::{Path, PathBuf}};
 
-use polywrap_core::{invoke::Invoker};
+use polywrap_core::{archive::{self, ArchiveKind, split_archive_path}, invoke::Invoker};
 use polywrap_plugin_macro::{plugin_struct, plugin_impl};
 use polywrap_plugin::error::PluginError;
+use serde_json::json;
 use wrap::{
     module::{ArgsGetFile, ArgsTryResolveUri, Module},
-    types::{MaybeUriOrManifest, FileSystemModule, FileSystemModuleArgsExists, FileSystemModuleArgsReadFile},
+    types::{Env, MaybeUriOrManifest, FileSystemModule, FileSystemModuleArgsExists, FileSystemModuleArgsReadFile},
 };
 pub mod wrap;
 use crate::wrap::wrap_info::get_manifest;
 
+const MANIFEST_FILE: &str = "wrap.info";
+const MODULE_FILE: &str = "wrap.wasm";
+
+// Relative paths are resolved against the base directory given to the
+// constructor, or the `baseDir` env, and otherwise the working directory
 #[plugin_struct]
 pub struct FileSystemResolverPlugin {
+    pub base_dir: Option<PathBuf>,
+}
+
+impl FileSystemResolverPlugin {
+    fn resolve_path(&self, path: &str) -> Result<PathBuf, String> {
+        let path = match path.strip_prefix('~') {
+            Some(rest) if rest.is_empty() || rest.starts_with('/') => {
+                let home = std::env::var_os("HOME")
+                    .or_else(|| std::env::var_os("USERPROFILE"))
+                    .ok_or_else(|| format!("Can't expand `{}`, the home directory is unknown", path))?;
+                PathBuf::from(home).join(rest.trim_start_matches('/'))
+            }
+            _ => PathBuf::from(path),
+        };
+
+        if path.is_absolute() {
+            return Ok(path);
+        }
+
+        let base_dir = match &self.base_dir {
+            Some(base_dir) => Some(base_dir.clone()),
+            None if self.env.is_null() => None,
+            None => serde_json::from_value::<Env>(self.env.clone())
+                .map_err(|e| format!("Invalid env: {}", e))?
+                .base_dir
+                .map(PathBuf::from),
+        };
+        Ok(match base_dir {
+            Some(base_dir) => base_dir.join(path),
+            None => path,
+        })
+    }
+
+    fn resolve_archive(
+        &self,
+        path: &Path,
+        kind: ArchiveKind,
+        invoker: Arc<dyn Invoker>,
+    ) -> Result<Vec<u8>, String> {
+        let archive = read_file(path, invoker)
+            .map_err(|e| format!("Failed to read wrapper archive `{}`: {}", path.display(), e))?;
+
+        let invalid = |e: String| format!("Invalid wrapper archive `{}`: {}", path.display(), e);
+        if let Some(manifest) = archive::read_entry(&archive, kind, MANIFEST_FILE).map_err(invalid)? {
+            return Ok(manifest);
+        }
+        if archive::read_entry(&archive, kind, MODULE_FILE).map_err(invalid)?.is_some() {
+            return Ok(default_manifest(path));
+        }
+
+        Err(format!("No wrapper found in archive `{}`", path.display()))
+    }
+
+    fn resolve_dir(&self, path: &Path, invoker: Arc<dyn Invoker>) -> Result<Vec<u8>, String> {
+        let manifest_path = path.join(MANIFEST_FILE);
+        if exists(&manifest_path, invoker.clone()) {
+            return read_file(&manifest_path, invoker)
+                .map_err(|e| format!("Failed to read `{}`: {}", manifest_path.display(), e));
+        }
+        if exists(&path.join(MODULE_FILE), invoker) {
+            return Ok(default_manifest(path));
+        }
+
+        Err(format!("No wrapper found at `{}`", path.display()))
+    }
+}
+
+fn exists(path: &Path, invoker: Arc<dyn Invoker>) -> bool {
+    let result = FileSystemModule::exists(
+        &FileSystemModuleArgsExists { path: path.to_string_lossy().to_string() },
+        invoker,
+    );
+    matches!(result, Ok(true))
+}
+
+fn read_file(path: &Path, invoker: Arc<dyn Invoker>) -> Result<Vec<u8>, PluginError> {
+    FileSystemModule::read_file(
+        &FileSystemModuleArgsReadFile { path: path.to_string_lossy().to_string() },
+        invoker,
+    )
+}
+
+// Wrappers without a `wrap.info` get a manifest with an empty ABI, named
+// after their directory or archive
+fn default_manifest(path: &Path) -> Vec<u8> {
+    let file_name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+    let stem = file_name.split('.').next().unwrap_or_default();
+    let name: String = stem
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '-' })
+        .collect();
+    let name = if name.is_empty() { "wrapper".to_string() } else { name };
+
+    polywrap_msgpack::serialize(json!({
+        "version": "0.1",
+        "type": "wasm",
+        "name": name,
+        "abi": { "version": "0.1" }
+    }))
+    .unwrap()
 }
 
 #[plugin_impl]
@@ -24,32 +130,17 @@ impl Module for FileSystemResolverPlugin {
         if args.authority != "fs" && args.authority != "file" {
             return Ok(None);
         };
-        let manifest_search_pattern = "wrap.info";
-
-        let manifest_path = Path::new(&args.path).join(Path::new(manifest_search_pattern)).canonicalize().unwrap();
-        let manifest_exists_result = FileSystemModule::exists(
-          &FileSystemModuleArgsExists {
-            path: manifest_path.to_str().unwrap().to_string()
-          }, invoker.clone());
-          
-        let manifest = if manifest_exists_result.is_ok() {
-          let manifest_result = FileSystemModule::read_file(
-            &FileSystemModuleArgsReadFile {
-              path: manifest_path.to_str().unwrap().to_string()
-            }, invoker);
-
-            if let Ok(manifest) = manifest_result {
-              Some(manifest)
-            } else {
-              None
+
+        let result = self.resolve_path(&args.path).and_then(|path| {
+            match ArchiveKind::from_path(&path.to_string_lossy()) {
+                Some(kind) => self.resolve_archive(&path, kind, invoker),
+                None => self.resolve_dir(&path, invoker),
             }
-        } else {
-          None
-        };
+        });
 
-        Ok(Some(MaybeUriOrManifest {
-            uri: None,
-            manifest,
+        Ok(Some(match result {
+            Ok(manifest) => MaybeUriOrManifest { uri: None, manifest: Some(manifest), reason: None },
+            Err(reason) => MaybeUriOrManifest { uri: None, manifest: None, reason: Some(reason) },
         }))
     }
 
@@ -58,18 +149,19 @@ impl Module for FileSystemResolverPlugin {
         args: &ArgsGetFile,
         invoker: Arc<dyn Invoker>,
     ) -> Result<Option<Vec<u8>>, PluginError> {
-        let resolve_result = FileSystemModule::read_file(
-            &FileSystemModuleArgsReadFile { path: args.path.clone() },
-            invoker,
-        )
-        ;
-
-        let file_result = match resolve_result {
-            Ok(r) => Some(r),
-            Err(_) => None
+        let path = match self.resolve_path(&args.path) {
+            Ok(path) => path,
+            Err(_) => return Ok(None),
         };
+        let path = path.to_string_lossy();
 
-        Ok(file_result)
+        let file = match split_archive_path(&path) {
+            Some((archive, kind, file)) if !file.is_empty() => read_file(Path::new(archive), invoker)
+                .ok()
+                .and_then(|archive| archive::read_entry(&archive, kind, file).ok().flatten()),
+            _ => read_file(Path::new(path.as_ref()), invoker).ok(),
+        };
+
+        Ok(file)
     }
 }
-
@@ -15,6 +15,11 @@ use polywrap_plugin::error::PluginError;
 
 // Env START //
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Env {
+    #[serde(rename = "baseDir")]
+    pub base_dir: Option<String>,
+}
 // Env END //
 
 // Objects START //
@@ -23,6 +28,7 @@ use polywrap_plugin::error::PluginError;
 pub struct MaybeUriOrManifest {
     pub uri: Option<String>,
     pub manifest: Option<Vec<u8>>,
+    pub reason: Option<String>,
 }
 // Objects END //
 
@@ -9,6 +9,23 @@ pub fn get_manifest() -> WrapManifest {
     type_: "plugin".to_string(),
     version: "0.1".to_string(),
     abi: from_value::<WrapManifestAbi>(json!({
+  "envType": {
+    "kind": 65536,
+    "properties": [
+      {
+        "comment": "Directory that relative paths are resolved against, defaults to the working directory",
+        "kind": 34,
+        "name": "baseDir",
+        "scalar": {
+          "kind": 4,
+          "name": "baseDir",
+          "type": "String"
+        },
+        "type": "String"
+      }
+    ],
+    "type": "Env"
+  },
   "importedEnumTypes": [
     {
       "constants": [
@@ -418,6 +435,17 @@ pub fn get_manifest() -> WrapManifest {
             "type": "Bytes"
           },
           "type": "Bytes"
+        },
+        {
+          "comment": "Why the URI couldn't be resolved, when neither `uri` nor `manifest` is set",
+          "kind": 34,
+          "name": "reason",
+          "scalar": {
+            "kind": 4,
+            "name": "reason",
+            "type": "String"
+          },
+          "type": "String"
         }
       ],
       "type": "MaybeUriOrManifest"
@@ -0,0 +1,170 @@
+use std::{fs, io::Write, path::PathBuf, sync::{Arc, Mutex}};
+
+use filesystem_plugin::FileSystemPlugin;
+use fs_resolver_plugin::{wrap::types::MaybeUriOrManifest, FileSystemResolverPlugin};
+use polywrap_client::client::PolywrapClient;
+use polywrap_core::{
+    client::ClientConfig, env::Env, invoke::Invoker,
+    resolvers::{static_resolver::{StaticResolver, StaticResolverLike}, uri_resolution_context::UriPackage},
+    uri::Uri,
+};
+use polywrap_msgpack::msgpack;
+use polywrap_plugin::package::PluginPackage;
+use serde_json::{json, Value};
+use wrap_manifest_schemas::deserialize::deserialize_wrap_manifest;
+
+fn get_client(base_dir: Option<PathBuf>) -> PolywrapClient {
+    let fs: PluginPackage = FileSystemPlugin { env: Value::Null, sandbox: None }.into();
+    let fs_resolver: PluginPackage = FileSystemResolverPlugin { env: Value::Null, base_dir }.into();
+
+    let resolver = StaticResolver::from(vec![
+        StaticResolverLike::Package(UriPackage {
+            uri: Uri::try_from("wrap://ens/fs.polywrap.eth").unwrap(),
+            package: Arc::new(Mutex::new(fs)),
+        }),
+        StaticResolverLike::Package(UriPackage {
+            uri: Uri::try_from("wrap://ens/fs-resolver.polywrap.eth").unwrap(),
+            package: Arc::new(Mutex::new(fs_resolver)),
+        }),
+    ]);
+
+    PolywrapClient::new(ClientConfig {
+        resolver: Arc::new(resolver),
+        ..Default::default()
+    })
+}
+
+fn setup(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("fs_resolver_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn try_resolve_uri(client: &PolywrapClient, path: &str, env: Option<Env>) -> MaybeUriOrManifest {
+    client
+        .invoke::<MaybeUriOrManifest>(
+            &Uri::try_from("wrap://ens/fs-resolver.polywrap.eth").unwrap(),
+            "tryResolveUri",
+            Some(&msgpack!({ "authority": "fs", "path": path })),
+            env,
+            None,
+        )
+        .unwrap()
+}
+
+// Plugins return no bytes at all for `None`
+fn get_file(client: &PolywrapClient, path: &str) -> Option<Vec<u8>> {
+    let result = client
+        .invoke_raw(
+            &Uri::try_from("wrap://ens/fs-resolver.polywrap.eth").unwrap(),
+            "getFile",
+            Some(&msgpack!({ "path": path })),
+            None,
+            None,
+        )
+        .unwrap();
+
+    if result.is_empty() {
+        None
+    } else {
+        Some(polywrap_msgpack::decode(&result).unwrap())
+    }
+}
+
+#[test]
+fn returns_a_reason_for_missing_wrappers() {
+    let dir = setup("missing");
+    let client = get_client(None);
+
+    let result = try_resolve_uri(&client, dir.join("missing").to_str().unwrap(), None);
+    assert!(result.manifest.is_none() && result.uri.is_none());
+    assert!(result.reason.unwrap().contains("No wrapper found"));
+
+    fs::create_dir(dir.join("empty")).unwrap();
+    let result = try_resolve_uri(&client, dir.join("empty").to_str().unwrap(), None);
+    assert!(result.reason.unwrap().contains("No wrapper found"));
+    assert_eq!(get_file(&client, dir.join("empty/wrap.wasm").to_str().unwrap()), None);
+}
+
+#[test]
+fn resolves_relative_paths_against_base_dir() {
+    let dir = setup("relative");
+    fs::create_dir_all(dir.join("wrapper")).unwrap();
+    fs::write(dir.join("wrapper/wrap.info"), "manifest").unwrap();
+
+    let result = try_resolve_uri(&get_client(Some(dir.clone())), "wrapper", None);
+    assert_eq!(result.manifest.unwrap(), b"manifest");
+
+    let env = json!({ "baseDir": dir.to_str().unwrap() });
+    let result = try_resolve_uri(&get_client(None), "./wrapper", Some(env));
+    assert_eq!(result.manifest.unwrap(), b"manifest");
+}
+
+#[test]
+fn expands_home_directory() {
+    let dir = setup("home");
+    fs::create_dir_all(dir.join("wrappers/wrapper")).unwrap();
+    fs::write(dir.join("wrappers/wrapper/wrap.info"), "manifest").unwrap();
+    std::env::set_var("HOME", &dir);
+
+    let result = try_resolve_uri(&get_client(None), "~/wrappers/wrapper", None);
+    assert_eq!(result.manifest.unwrap(), b"manifest");
+}
+
+#[test]
+fn resolves_wasm_only_directories() {
+    let dir = setup("wasm_only");
+    fs::create_dir_all(dir.join("my.wrapper")).unwrap();
+    fs::write(dir.join("my.wrapper/wrap.wasm"), [0, 97, 115, 109]).unwrap();
+
+    let client = get_client(None);
+    let result = try_resolve_uri(&client, dir.join("my.wrapper").to_str().unwrap(), None);
+    let manifest = deserialize_wrap_manifest(&result.manifest.unwrap(), None).unwrap();
+    assert_eq!(manifest.name, "my");
+    assert_eq!(manifest.type_, "wasm");
+}
+
+#[test]
+fn resolves_zip_archives() {
+    let dir = setup("zip");
+    let archive = dir.join("wrapper.zip");
+    let mut zip = zip::ZipWriter::new(fs::File::create(&archive).unwrap());
+    let options = zip::write::FileOptions::default();
+    zip.start_file("wrapper/wrap.info", options).unwrap();
+    zip.write_all(b"manifest").unwrap();
+    zip.start_file("wrapper/wrap.wasm", options).unwrap();
+    zip.write_all(&[0, 97, 115, 109]).unwrap();
+    zip.finish().unwrap();
+
+    let client = get_client(Some(dir));
+    let result = try_resolve_uri(&client, "wrapper.zip", None);
+    assert_eq!(result.manifest.unwrap(), b"manifest");
+    assert_eq!(get_file(&client, "wrapper.zip/wrap.wasm"), Some(vec![0, 97, 115, 109]));
+    assert_eq!(get_file(&client, "wrapper.zip/missing.txt"), None);
+}
+
+#[test]
+fn resolves_tar_gz_archives() {
+    let dir = setup("tar");
+    let archive = dir.join("wrapper.tar.gz");
+    let encoder = flate2::write::GzEncoder::new(fs::File::create(&archive).unwrap(), flate2::Compression::default());
+    let mut tar = tar::Builder::new(encoder);
+    let mut header = tar::Header::new_gnu();
+    header.set_size(4);
+    header.set_mode(0o644);
+    header.set_cksum();
+    tar.append_data(&mut header, "wrap.wasm", &[0, 97, 115, 109][..]).unwrap();
+    tar.into_inner().unwrap().finish().unwrap();
+
+    let client = get_client(None);
+    let result = try_resolve_uri(&client, archive.to_str().unwrap(), None);
+    let manifest = deserialize_wrap_manifest(&result.manifest.unwrap(), None).unwrap();
+    assert_eq!(manifest.name, "wrapper");
+    let file = format!("{}/wrap.wasm", archive.to_str().unwrap());
+    assert_eq!(get_file(&client, &file), Some(vec![0, 97, 115, 109]));
+
+    fs::write(dir.join("broken.zip"), "not a zip").unwrap();
+    let result = try_resolve_uri(&client, dir.join("broken.zip").to_str().unwrap(), None);
+    assert!(result.reason.unwrap().contains("Invalid wrapper archive"));
+}
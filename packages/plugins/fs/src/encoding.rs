@@ -0,0 +1,37 @@
+use polywrap_plugin::error::PluginError;
+
+use crate::wrap::types::Encoding;
+
+// Like Node's `Buffer.toString`, except that bytes which aren't valid in the
+// requested encoding are an error rather than silently replaced
+pub fn decode(bytes: Vec<u8>, encoding: Encoding) -> Result<String, PluginError> {
+    match encoding {
+        Encoding::UTF8 => String::from_utf8(bytes).map_err(|_| invalid(encoding)),
+        Encoding::ASCII => {
+            if bytes.is_ascii() {
+                Ok(bytes.into_iter().map(char::from).collect())
+            } else {
+                Err(invalid(encoding))
+            }
+        }
+        Encoding::UTF16LE | Encoding::UCS2 => {
+            if bytes.len() % 2 != 0 {
+                return Err(invalid(encoding));
+            }
+            let units = bytes
+                .chunks_exact(2)
+                .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+                .collect::<Vec<u16>>();
+            String::from_utf16(&units).map_err(|_| invalid(encoding))
+        }
+        Encoding::LATIN1 | Encoding::BINARY => Ok(bytes.into_iter().map(char::from).collect()),
+        Encoding::BASE64 => Ok(base64::encode(bytes)),
+        Encoding::BASE64URL => Ok(base64::encode_config(bytes, base64::URL_SAFE_NO_PAD)),
+        Encoding::HEX => Ok(bytes.iter().map(|byte| format!("{:02x}", byte)).collect()),
+        Encoding::_MAX_ => Err(PluginError::ModuleError("Unknown encoding".to_string())),
+    }
+}
+
+fn invalid(encoding: Encoding) -> PluginError {
+    PluginError::ModuleError(format!("File contents are not valid {:?}", encoding))
+}
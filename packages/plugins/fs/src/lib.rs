@@ -1,19 +1,81 @@
-use std::{fs, path::Path, sync::Arc};
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 use polywrap_core::{invoke::Invoker};
 use polywrap_plugin_macro::{plugin_struct, plugin_impl};
 use polywrap_plugin::{error::PluginError};
 use wrap::{
     module::{
-        ArgsExists, ArgsMkdir, ArgsReadFile, ArgsReadFileAsString, ArgsRm, ArgsRmdir,
+        ArgsAppendFile, ArgsCopy, ArgsExists, ArgsMkdir, ArgsReadDir, ArgsReadFile,
+        ArgsReadFileAsString, ArgsRename, ArgsRm, ArgsRmdir, ArgsStat, ArgsWatch,
         ArgsWriteFile, Module,
     },
+    types::{DirEntry, Encoding, FileStat, SandboxRoot, WatchEvent},
 };
+use sandbox::Sandbox;
+pub mod encoding;
+pub mod sandbox;
+pub mod watch;
 pub mod wrap;
 use crate::wrap::wrap_info::get_manifest;
 
+const DEFAULT_WATCH_TIMEOUT: u32 = 30_000;
+
+// Without a sandbox, from the constructor or the `roots` env, paths aren't restricted
 #[plugin_struct]
 pub struct FileSystemPlugin {
+    pub sandbox: Option<Sandbox>,
+}
+
+impl FileSystemPlugin {
+    fn resolve_path(&self, path: &str, write: bool) -> Result<PathBuf, PluginError> {
+        if let Some(sandbox) = &self.sandbox {
+            return sandbox.resolve(path, write);
+        }
+
+        match self.env.get("roots") {
+            Some(roots) if !roots.is_null() => {
+                let roots: Vec<SandboxRoot> = serde_json::from_value(roots.clone())?;
+                Sandbox::new(&roots)?.resolve(path, write)
+            }
+            _ => Ok(PathBuf::from(path)),
+        }
+    }
+
+    // Every entry is resolved on its own, so that symlinks inside the source
+    // can't be used to copy files from outside of the sandbox, nor ones already
+    // in the destination to write outside of it
+    fn copy_dir(&self, from: &Path, to: &Path) -> Result<(), PluginError> {
+        fs::create_dir_all(to).map_err(io_error("create directory", to))?;
+
+        for entry in fs::read_dir(from).map_err(io_error("read directory", from))? {
+            let entry = entry.map_err(io_error("read directory", from))?;
+            let source = self.resolve_path(&entry.path().to_string_lossy(), false)?;
+            let destination = self.resolve_path(&to.join(entry.file_name()).to_string_lossy(), true)?;
+
+            if source.is_dir() {
+                self.copy_dir(&source, &destination)?;
+            } else {
+                fs::copy(&source, &destination).map_err(io_error("copy", &source))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn io_error<'a>(operation: &'a str, path: &'a Path) -> impl Fn(io::Error) -> PluginError + 'a {
+    move |e| PluginError::ModuleError(format!("Failed to {} `{}`: {}", operation, path.display(), e))
+}
+
+fn epoch_seconds(time: io::Result<SystemTime>) -> Option<u32> {
+    let elapsed = time.ok()?.duration_since(UNIX_EPOCH).ok()?;
+    Some(u32::try_from(elapsed.as_secs()).unwrap_or(u32::MAX))
 }
 
 #[plugin_impl]
@@ -23,7 +85,8 @@ impl Module for FileSystemPlugin {
         args: &ArgsReadFile,
         _: Arc<dyn Invoker>,
     ) -> Result<Vec<u8>, PluginError> {
-        fs::read(&args.path).map_err(|e| PluginError::ModuleError(e.to_string()))
+        let path = self.resolve_path(&args.path, false)?;
+        fs::read(&path).map_err(io_error("read", &path))
     }
 
     fn read_file_as_string(
@@ -31,11 +94,13 @@ impl Module for FileSystemPlugin {
         args: &ArgsReadFileAsString,
         _: Arc<dyn Invoker>,
     ) -> Result<String, PluginError> {
-        fs::read_to_string(&args.path).map_err(|e| PluginError::ModuleError(e.to_string()))
+        let path = self.resolve_path(&args.path, false)?;
+        let bytes = fs::read(&path).map_err(io_error("read", &path))?;
+        encoding::decode(bytes, args.encoding.unwrap_or(Encoding::UTF8))
     }
 
     fn exists(&mut self, args: &ArgsExists, _: Arc<dyn Invoker>) -> Result<bool, PluginError> {
-        Ok(Path::new(&args.path).exists())
+        Ok(self.resolve_path(&args.path, false)?.exists())
     }
 
     fn write_file(
@@ -43,11 +108,8 @@ impl Module for FileSystemPlugin {
         args: &ArgsWriteFile,
         _: Arc<dyn Invoker>,
     ) -> Result<Option<bool>, PluginError> {
-        fs::write(
-            Path::new(&args.path),
-            String::from_utf8(args.data.clone()).unwrap(),
-        )
-        .unwrap();
+        let path = self.resolve_path(&args.path, true)?;
+        fs::write(&path, &args.data).map_err(io_error("write", &path))?;
 
         Ok(Some(true))
     }
@@ -57,48 +119,43 @@ impl Module for FileSystemPlugin {
         args: &ArgsMkdir,
         _: Arc<dyn Invoker>,
     ) -> Result<Option<bool>, PluginError> {
-        let recursive = if let Some(recursive) = args.recursive {
-            recursive
-        } else {
-            false
-        };
-
-        let path = Path::new(&args.path);
+        let recursive = args.recursive.unwrap_or(false);
+        let path = self.resolve_path(&args.path, true)?;
 
         if recursive {
-            fs::create_dir_all(path).unwrap();
+            fs::create_dir_all(&path)
         } else {
-            fs::create_dir(path).unwrap();
+            fs::create_dir(&path)
         }
+        .map_err(io_error("create directory", &path))?;
 
         Ok(Some(true))
     }
 
     fn rm(&mut self, args: &ArgsRm, _: Arc<dyn Invoker>) -> Result<Option<bool>, PluginError> {
-        let recursive = if let Some(recursive) = args.recursive {
-            recursive
-        } else {
-            false
-        };
+        let recursive = args.recursive.unwrap_or(false);
+        let force = args.force.unwrap_or(false);
+        let path = self.resolve_path(&args.path, true)?;
 
-        let force = if let Some(force) = args.force {
-            force
-        } else {
-            false
+        // Symlinks are removed rather than followed
+        let metadata = match path.symlink_metadata() {
+            Ok(metadata) => metadata,
+            Err(e) if force && e.kind() == io::ErrorKind::NotFound => return Ok(Some(true)),
+            Err(e) => return Err(io_error("remove", &path)(e)),
         };
 
-        let path = Path::new(&args.path);
-
-        if path.is_dir() {
+        if metadata.is_dir() {
             if force {
-                rm_rf::ensure_removed(path).unwrap();
+                rm_rf::ensure_removed(&path).map_err(|e| {
+                    PluginError::ModuleError(format!("Failed to remove `{}`: {}", path.display(), e))
+                })?;
             } else if recursive {
-                fs::remove_dir_all(path).unwrap();
+                fs::remove_dir_all(&path).map_err(io_error("remove", &path))?;
             } else {
-                fs::remove_dir(path).unwrap();
+                fs::remove_dir(&path).map_err(io_error("remove", &path))?;
             }
         } else {
-            fs::remove_file(path).unwrap();
+            fs::remove_file(&path).map_err(io_error("remove", &path))?;
         }
 
         Ok(Some(true))
@@ -109,8 +166,134 @@ impl Module for FileSystemPlugin {
         args: &ArgsRmdir,
         _: Arc<dyn Invoker>,
     ) -> Result<Option<bool>, PluginError> {
-        fs::remove_dir(&args.path).unwrap();
+        let path = self.resolve_path(&args.path, true)?;
+        fs::remove_dir(&path).map_err(io_error("remove directory", &path))?;
+
+        Ok(Some(true))
+    }
+
+    fn append_file(
+        &mut self,
+        args: &ArgsAppendFile,
+        _: Arc<dyn Invoker>,
+    ) -> Result<Option<bool>, PluginError> {
+        let path = self.resolve_path(&args.path, true)?;
+        fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| file.write_all(&args.data))
+            .map_err(io_error("append to", &path))?;
 
         Ok(Some(true))
     }
+
+    fn read_dir(
+        &mut self,
+        args: &ArgsReadDir,
+        _: Arc<dyn Invoker>,
+    ) -> Result<Vec<DirEntry>, PluginError> {
+        let path = self.resolve_path(&args.path, false)?;
+        let mut entries = fs::read_dir(&path)
+            .and_then(|entries| {
+                entries
+                    .map(|entry| {
+                        let entry = entry?;
+                        let file_type = entry.file_type()?;
+                        Ok(DirEntry {
+                            name: entry.file_name().to_string_lossy().to_string(),
+                            path: Path::new(&args.path).join(entry.file_name()).to_string_lossy().to_string(),
+                            is_file: file_type.is_file(),
+                            is_directory: file_type.is_dir(),
+                            is_symlink: file_type.is_symlink(),
+                        })
+                    })
+                    .collect::<io::Result<Vec<DirEntry>>>()
+            })
+            .map_err(io_error("read directory", &path))?;
+
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(entries)
+    }
+
+    fn stat(&mut self, args: &ArgsStat, _: Arc<dyn Invoker>) -> Result<FileStat, PluginError> {
+        let path = self.resolve_path(&args.path, false)?;
+        let link_metadata = path.symlink_metadata().map_err(io_error("stat", &path))?;
+        let metadata = if link_metadata.is_symlink() {
+            path.metadata().map_err(io_error("stat", &path))?
+        } else {
+            link_metadata.clone()
+        };
+
+        Ok(FileStat {
+            size: metadata.len().to_string(),
+            is_file: metadata.is_file(),
+            is_directory: metadata.is_dir(),
+            is_symlink: link_metadata.is_symlink(),
+            read_only: metadata.permissions().readonly(),
+            modified: epoch_seconds(metadata.modified()),
+            created: epoch_seconds(metadata.created()),
+        })
+    }
+
+    fn rename(
+        &mut self,
+        args: &ArgsRename,
+        _: Arc<dyn Invoker>,
+    ) -> Result<Option<bool>, PluginError> {
+        let from = self.resolve_path(&args.from, true)?;
+        let to = self.resolve_path(&args.to, true)?;
+        fs::rename(&from, &to).map_err(io_error("rename", &from))?;
+
+        Ok(Some(true))
+    }
+
+    fn copy(&mut self, args: &ArgsCopy, _: Arc<dyn Invoker>) -> Result<Option<bool>, PluginError> {
+        let from = self.resolve_path(&args.from, false)?;
+        let to = self.resolve_path(&args.to, true)?;
+
+        if from.is_dir() {
+            if !args.recursive.unwrap_or(false) {
+                return Err(PluginError::ModuleError(format!(
+                    "`{}` is a directory, `recursive` is required to copy it",
+                    args.from
+                )));
+            }
+            if to.starts_with(from.canonicalize().map_err(io_error("copy", &from))?) {
+                return Err(PluginError::ModuleError(format!(
+                    "Can't copy `{}` into itself",
+                    args.from
+                )));
+            }
+            self.copy_dir(&from, &to)?;
+        } else {
+            fs::copy(&from, &to).map_err(io_error("copy", &from))?;
+        }
+
+        Ok(Some(true))
+    }
+
+    // The plugin doesn't handle other invocations while it's watching
+    fn watch(
+        &mut self,
+        args: &ArgsWatch,
+        _: Arc<dyn Invoker>,
+    ) -> Result<Vec<WatchEvent>, PluginError> {
+        let path = self.resolve_path(&args.path, false)?;
+        let timeout = Duration::from_millis(args.timeout.unwrap_or(DEFAULT_WATCH_TIMEOUT).into());
+        let events = watch::watch(&path, args.recursive.unwrap_or(false), timeout);
+
+        Ok(events
+            .into_iter()
+            .map(|(changed, kind)| {
+                // Paths are reported relative to the requested one, as given
+                let changed = match changed.strip_prefix(&path) {
+                    Ok(relative) if relative.as_os_str().is_empty() => args.path.clone(),
+                    Ok(relative) => Path::new(&args.path).join(relative).to_string_lossy().to_string(),
+                    Err(_) => changed.to_string_lossy().to_string(),
+                };
+                WatchEvent { path: changed, kind }
+            })
+            .collect())
+    }
 }
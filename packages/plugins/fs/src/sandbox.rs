@@ -0,0 +1,101 @@
+use std::path::{Path, PathBuf};
+
+use polywrap_plugin::error::PluginError;
+
+use crate::wrap::types::{AccessMode, SandboxRoot};
+
+#[derive(Clone, Debug)]
+pub struct Sandbox {
+    roots: Vec<(PathBuf, AccessMode)>,
+}
+
+impl Sandbox {
+    // Roots are canonicalized up front, so they have to exist
+    pub fn new(roots: &[SandboxRoot]) -> Result<Self, PluginError> {
+        let roots = roots
+            .iter()
+            .map(|root| {
+                let path = Path::new(&root.path).canonicalize().map_err(|e| {
+                    PluginError::ModuleError(format!("Invalid sandbox root `{}`: {}", root.path, e))
+                })?;
+                Ok((path, root.mode.unwrap_or(AccessMode::READ_ONLY)))
+            })
+            .collect::<Result<Vec<_>, PluginError>>()?;
+
+        Ok(Self { roots })
+    }
+
+    // Relative paths are resolved against the first root
+    pub fn resolve(&self, path: &str, write: bool) -> Result<PathBuf, PluginError> {
+        let requested = Path::new(path);
+        let absolute = match (requested.is_relative(), self.roots.first()) {
+            (false, _) => requested.to_path_buf(),
+            (true, Some((root, _))) => root.join(requested),
+            (true, None) => return Err(outside_sandbox(path)),
+        };
+
+        let resolved = resolve_parent(&absolute).ok_or_else(|| outside_sandbox(path))?;
+        self.check(&resolved, path, write)?;
+
+        // Reads and writes follow a symlink, so its target has to be inside the
+        // sandbox as well (a dangling one could be used to create files outside)
+        let is_symlink = resolved
+            .symlink_metadata()
+            .map(|metadata| metadata.file_type().is_symlink())
+            .unwrap_or(false);
+        if is_symlink {
+            let target = resolved.canonicalize().map_err(|_| outside_sandbox(path))?;
+            self.check(&target, path, write)?;
+        }
+
+        Ok(resolved)
+    }
+
+    fn check(&self, resolved: &Path, path: &str, write: bool) -> Result<(), PluginError> {
+        let mut inside = false;
+        for (root, mode) in self.roots.iter() {
+            if resolved.starts_with(root) {
+                inside = true;
+                if !write || matches!(mode, AccessMode::READ_WRITE) {
+                    return Ok(());
+                }
+            }
+        }
+
+        if inside {
+            Err(PluginError::ModuleError(format!("Path `{}` is read-only", path)))
+        } else {
+            Err(outside_sandbox(path))
+        }
+    }
+}
+
+// Canonicalizes everything but the final component, so that removing a symlink
+// removes the link itself. Components that don't exist yet (e.g. for a
+// recursive `mkdir`) are appended as-is, and can't be `..`.
+fn resolve_parent(path: &Path) -> Option<PathBuf> {
+    let (parent, name) = match (path.parent(), path.file_name()) {
+        (Some(parent), Some(name)) => (parent, Some(name)),
+        _ => (path, None),
+    };
+
+    let mut existing = parent;
+    let mut missing = vec![];
+    let mut resolved = loop {
+        match existing.canonicalize() {
+            Ok(canonical) => break canonical,
+            Err(_) => {
+                missing.push(existing.file_name()?);
+                existing = existing.parent()?;
+            }
+        }
+    };
+
+    resolved.extend(missing.into_iter().rev());
+    resolved.extend(name);
+    Some(resolved)
+}
+
+fn outside_sandbox(path: &str) -> PluginError {
+    PluginError::ModuleError(format!("Path `{}` is outside of the sandbox roots", path))
+}
@@ -0,0 +1,85 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use crate::wrap::types::WatchEventKind;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+type Snapshot = BTreeMap<PathBuf, (Option<SystemTime>, u64)>;
+
+// Polls the modification time and size of `path` (or of the entries of a
+// directory) until something changes. Returns no events if `timeout` passes first
+pub fn watch(path: &Path, recursive: bool, timeout: Duration) -> Vec<(PathBuf, WatchEventKind)> {
+    let deadline = Instant::now() + timeout;
+    let before = snapshot(path, recursive);
+
+    loop {
+        let after = snapshot(path, recursive);
+        let events = diff(&before, &after);
+        if !events.is_empty() {
+            return events;
+        }
+
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return vec![];
+        }
+        thread::sleep(POLL_INTERVAL.min(remaining));
+    }
+}
+
+fn snapshot(path: &Path, recursive: bool) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    match fs::metadata(path) {
+        Ok(metadata) if metadata.is_dir() => add_entries(path, recursive, &mut snapshot),
+        Ok(metadata) => {
+            snapshot.insert(path.to_path_buf(), (metadata.modified().ok(), metadata.len()));
+        }
+        Err(_) => {}
+    }
+    snapshot
+}
+
+fn add_entries(dir: &Path, recursive: bool, snapshot: &mut Snapshot) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for entry in entries.flatten() {
+        let metadata = match entry.metadata() {
+            Ok(metadata) => metadata,
+            Err(_) => continue,
+        };
+        // Directories are only compared by their entries
+        let len = if metadata.is_dir() { 0 } else { metadata.len() };
+        let modified = if metadata.is_dir() { None } else { metadata.modified().ok() };
+        snapshot.insert(entry.path(), (modified, len));
+
+        if recursive && metadata.is_dir() {
+            add_entries(&entry.path(), recursive, snapshot);
+        }
+    }
+}
+
+fn diff(before: &Snapshot, after: &Snapshot) -> Vec<(PathBuf, WatchEventKind)> {
+    let mut events = vec![];
+    for (path, state) in after.iter() {
+        match before.get(path) {
+            None => events.push((path.clone(), WatchEventKind::CREATED)),
+            Some(previous) if previous != state => events.push((path.clone(), WatchEventKind::MODIFIED)),
+            _ => {}
+        }
+    }
+    for path in before.keys() {
+        if !after.contains_key(path) {
+            events.push((path.clone(), WatchEventKind::REMOVED));
+        }
+    }
+    events
+}
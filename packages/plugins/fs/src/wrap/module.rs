@@ -48,6 +48,42 @@ pub struct ArgsRmdir {
     pub path: String,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ArgsAppendFile {
+    pub path: String,
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ArgsReadDir {
+    pub path: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ArgsStat {
+    pub path: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ArgsRename {
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ArgsCopy {
+    pub from: String,
+    pub to: String,
+    pub recursive: Option<bool>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ArgsWatch {
+    pub path: String,
+    pub timeout: Option<u32>,
+    pub recursive: Option<bool>,
+}
+
 pub trait Module: PluginModule {
   fn read_file(&mut self, args: &ArgsReadFile, invoker: Arc<dyn Invoker>) -> Result<Vec<u8>, PluginError>;
 
@@ -62,4 +98,16 @@ pub trait Module: PluginModule {
   fn rm(&mut self, args: &ArgsRm, invoker: Arc<dyn Invoker>) -> Result<Option<bool>, PluginError>;
 
   fn rmdir(&mut self, args: &ArgsRmdir, invoker: Arc<dyn Invoker>) -> Result<Option<bool>, PluginError>;
+
+  fn append_file(&mut self, args: &ArgsAppendFile, invoker: Arc<dyn Invoker>) -> Result<Option<bool>, PluginError>;
+
+  fn read_dir(&mut self, args: &ArgsReadDir, invoker: Arc<dyn Invoker>) -> Result<Vec<DirEntry>, PluginError>;
+
+  fn stat(&mut self, args: &ArgsStat, invoker: Arc<dyn Invoker>) -> Result<FileStat, PluginError>;
+
+  fn rename(&mut self, args: &ArgsRename, invoker: Arc<dyn Invoker>) -> Result<Option<bool>, PluginError>;
+
+  fn copy(&mut self, args: &ArgsCopy, invoker: Arc<dyn Invoker>) -> Result<Option<bool>, PluginError>;
+
+  fn watch(&mut self, args: &ArgsWatch, invoker: Arc<dyn Invoker>) -> Result<Vec<WatchEvent>, PluginError>;
 }
@@ -11,10 +11,52 @@ use std::collections::BTreeMap as Map;
 
 // Env START //
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Env {
+    pub roots: Option<Vec<SandboxRoot>>,
+}
 // Env END //
 
 // Objects START //
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SandboxRoot {
+    pub path: String,
+    pub mode: Option<AccessMode>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DirEntry {
+    pub name: String,
+    pub path: String,
+    #[serde(rename = "isFile")]
+    pub is_file: bool,
+    #[serde(rename = "isDirectory")]
+    pub is_directory: bool,
+    #[serde(rename = "isSymlink")]
+    pub is_symlink: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileStat {
+    pub size: String,
+    #[serde(rename = "isFile")]
+    pub is_file: bool,
+    #[serde(rename = "isDirectory")]
+    pub is_directory: bool,
+    #[serde(rename = "isSymlink")]
+    pub is_symlink: bool,
+    #[serde(rename = "readOnly")]
+    pub read_only: bool,
+    pub modified: Option<u32>,
+    pub created: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WatchEvent {
+    pub path: String,
+    pub kind: WatchEventKind,
+}
 // Objects END //
 
 // Enums START //
@@ -32,6 +74,19 @@ pub enum Encoding {
     HEX,
     _MAX_
 }
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub enum AccessMode {
+    READ_ONLY,
+    READ_WRITE,
+    _MAX_
+}
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub enum WatchEventKind {
+    CREATED,
+    MODIFIED,
+    REMOVED,
+    _MAX_
+}
 // Enums END //
 
 // Imported objects START //
@@ -24,8 +24,53 @@ pub fn get_manifest() -> WrapManifest {
       ],
       "kind": 8,
       "type": "Encoding"
+    },
+    {
+      "constants": [
+        "READ_ONLY",
+        "READ_WRITE"
+      ],
+      "kind": 8,
+      "type": "AccessMode"
+    },
+    {
+      "constants": [
+        "CREATED",
+        "MODIFIED",
+        "REMOVED"
+      ],
+      "kind": 8,
+      "type": "WatchEventKind"
     }
   ],
+  "envType": {
+    "kind": 65536,
+    "properties": [
+      {
+        "array": {
+          "item": {
+            "kind": 8192,
+            "name": "roots",
+            "required": true,
+            "type": "SandboxRoot"
+          },
+          "kind": 18,
+          "name": "roots",
+          "object": {
+            "kind": 8192,
+            "name": "roots",
+            "required": true,
+            "type": "SandboxRoot"
+          },
+          "type": "[SandboxRoot]"
+        },
+        "kind": 34,
+        "name": "roots",
+        "type": "[SandboxRoot]"
+      }
+    ],
+    "type": "Env"
+  },
   "moduleType": {
     "kind": 128,
     "methods": [
@@ -296,10 +341,502 @@ pub fn get_manifest() -> WrapManifest {
           "type": "Boolean"
         },
         "type": "Method"
+      },
+      {
+        "arguments": [
+          {
+            "kind": 34,
+            "name": "path",
+            "required": true,
+            "scalar": {
+              "kind": 4,
+              "name": "path",
+              "required": true,
+              "type": "String"
+            },
+            "type": "String"
+          },
+          {
+            "kind": 34,
+            "name": "data",
+            "required": true,
+            "scalar": {
+              "kind": 4,
+              "name": "data",
+              "required": true,
+              "type": "Bytes"
+            },
+            "type": "Bytes"
+          }
+        ],
+        "kind": 64,
+        "name": "appendFile",
+        "required": true,
+        "return": {
+          "kind": 34,
+          "name": "appendFile",
+          "scalar": {
+            "kind": 4,
+            "name": "appendFile",
+            "type": "Boolean"
+          },
+          "type": "Boolean"
+        },
+        "type": "Method"
+      },
+      {
+        "arguments": [
+          {
+            "kind": 34,
+            "name": "path",
+            "required": true,
+            "scalar": {
+              "kind": 4,
+              "name": "path",
+              "required": true,
+              "type": "String"
+            },
+            "type": "String"
+          }
+        ],
+        "kind": 64,
+        "name": "readDir",
+        "required": true,
+        "return": {
+          "array": {
+            "item": {
+              "kind": 8192,
+              "name": "readDir",
+              "required": true,
+              "type": "DirEntry"
+            },
+            "kind": 18,
+            "name": "readDir",
+            "object": {
+              "kind": 8192,
+              "name": "readDir",
+              "required": true,
+              "type": "DirEntry"
+            },
+            "required": true,
+            "type": "[DirEntry]"
+          },
+          "kind": 34,
+          "name": "readDir",
+          "required": true,
+          "type": "[DirEntry]"
+        },
+        "type": "Method"
+      },
+      {
+        "arguments": [
+          {
+            "kind": 34,
+            "name": "path",
+            "required": true,
+            "scalar": {
+              "kind": 4,
+              "name": "path",
+              "required": true,
+              "type": "String"
+            },
+            "type": "String"
+          }
+        ],
+        "kind": 64,
+        "name": "stat",
+        "required": true,
+        "return": {
+          "kind": 34,
+          "name": "stat",
+          "object": {
+            "kind": 8192,
+            "name": "stat",
+            "required": true,
+            "type": "FileStat"
+          },
+          "required": true,
+          "type": "FileStat"
+        },
+        "type": "Method"
+      },
+      {
+        "arguments": [
+          {
+            "kind": 34,
+            "name": "from",
+            "required": true,
+            "scalar": {
+              "kind": 4,
+              "name": "from",
+              "required": true,
+              "type": "String"
+            },
+            "type": "String"
+          },
+          {
+            "kind": 34,
+            "name": "to",
+            "required": true,
+            "scalar": {
+              "kind": 4,
+              "name": "to",
+              "required": true,
+              "type": "String"
+            },
+            "type": "String"
+          }
+        ],
+        "kind": 64,
+        "name": "rename",
+        "required": true,
+        "return": {
+          "kind": 34,
+          "name": "rename",
+          "scalar": {
+            "kind": 4,
+            "name": "rename",
+            "type": "Boolean"
+          },
+          "type": "Boolean"
+        },
+        "type": "Method"
+      },
+      {
+        "arguments": [
+          {
+            "kind": 34,
+            "name": "from",
+            "required": true,
+            "scalar": {
+              "kind": 4,
+              "name": "from",
+              "required": true,
+              "type": "String"
+            },
+            "type": "String"
+          },
+          {
+            "kind": 34,
+            "name": "to",
+            "required": true,
+            "scalar": {
+              "kind": 4,
+              "name": "to",
+              "required": true,
+              "type": "String"
+            },
+            "type": "String"
+          },
+          {
+            "kind": 34,
+            "name": "recursive",
+            "scalar": {
+              "kind": 4,
+              "name": "recursive",
+              "type": "Boolean"
+            },
+            "type": "Boolean"
+          }
+        ],
+        "kind": 64,
+        "name": "copy",
+        "required": true,
+        "return": {
+          "kind": 34,
+          "name": "copy",
+          "scalar": {
+            "kind": 4,
+            "name": "copy",
+            "type": "Boolean"
+          },
+          "type": "Boolean"
+        },
+        "type": "Method"
+      },
+      {
+        "arguments": [
+          {
+            "kind": 34,
+            "name": "path",
+            "required": true,
+            "scalar": {
+              "kind": 4,
+              "name": "path",
+              "required": true,
+              "type": "String"
+            },
+            "type": "String"
+          },
+          {
+            "kind": 34,
+            "name": "timeout",
+            "scalar": {
+              "kind": 4,
+              "name": "timeout",
+              "type": "UInt32"
+            },
+            "type": "UInt32"
+          },
+          {
+            "kind": 34,
+            "name": "recursive",
+            "scalar": {
+              "kind": 4,
+              "name": "recursive",
+              "type": "Boolean"
+            },
+            "type": "Boolean"
+          }
+        ],
+        "comment": "Blocks until something under `path` changes, or `timeout` milliseconds have passed",
+        "kind": 64,
+        "name": "watch",
+        "required": true,
+        "return": {
+          "array": {
+            "item": {
+              "kind": 8192,
+              "name": "watch",
+              "required": true,
+              "type": "WatchEvent"
+            },
+            "kind": 18,
+            "name": "watch",
+            "object": {
+              "kind": 8192,
+              "name": "watch",
+              "required": true,
+              "type": "WatchEvent"
+            },
+            "required": true,
+            "type": "[WatchEvent]"
+          },
+          "kind": 34,
+          "name": "watch",
+          "required": true,
+          "type": "[WatchEvent]"
+        },
+        "type": "Method"
       }
     ],
     "type": "Module"
   },
+  "objectTypes": [
+    {
+      "kind": 1,
+      "properties": [
+        {
+          "kind": 34,
+          "name": "path",
+          "required": true,
+          "scalar": {
+            "kind": 4,
+            "name": "path",
+            "required": true,
+            "type": "String"
+          },
+          "type": "String"
+        },
+        {
+          "enum": {
+            "kind": 16384,
+            "name": "mode",
+            "type": "AccessMode"
+          },
+          "kind": 34,
+          "name": "mode",
+          "type": "AccessMode"
+        }
+      ],
+      "type": "SandboxRoot"
+    },
+    {
+      "kind": 1,
+      "properties": [
+        {
+          "kind": 34,
+          "name": "name",
+          "required": true,
+          "scalar": {
+            "kind": 4,
+            "name": "name",
+            "required": true,
+            "type": "String"
+          },
+          "type": "String"
+        },
+        {
+          "kind": 34,
+          "name": "path",
+          "required": true,
+          "scalar": {
+            "kind": 4,
+            "name": "path",
+            "required": true,
+            "type": "String"
+          },
+          "type": "String"
+        },
+        {
+          "kind": 34,
+          "name": "isFile",
+          "required": true,
+          "scalar": {
+            "kind": 4,
+            "name": "isFile",
+            "required": true,
+            "type": "Boolean"
+          },
+          "type": "Boolean"
+        },
+        {
+          "kind": 34,
+          "name": "isDirectory",
+          "required": true,
+          "scalar": {
+            "kind": 4,
+            "name": "isDirectory",
+            "required": true,
+            "type": "Boolean"
+          },
+          "type": "Boolean"
+        },
+        {
+          "kind": 34,
+          "name": "isSymlink",
+          "required": true,
+          "scalar": {
+            "kind": 4,
+            "name": "isSymlink",
+            "required": true,
+            "type": "Boolean"
+          },
+          "type": "Boolean"
+        }
+      ],
+      "type": "DirEntry"
+    },
+    {
+      "kind": 1,
+      "properties": [
+        {
+          "comment": "Size in bytes, as a decimal string since it can exceed the maximum UInt32",
+          "kind": 34,
+          "name": "size",
+          "required": true,
+          "scalar": {
+            "kind": 4,
+            "name": "size",
+            "required": true,
+            "type": "String"
+          },
+          "type": "String"
+        },
+        {
+          "kind": 34,
+          "name": "isFile",
+          "required": true,
+          "scalar": {
+            "kind": 4,
+            "name": "isFile",
+            "required": true,
+            "type": "Boolean"
+          },
+          "type": "Boolean"
+        },
+        {
+          "kind": 34,
+          "name": "isDirectory",
+          "required": true,
+          "scalar": {
+            "kind": 4,
+            "name": "isDirectory",
+            "required": true,
+            "type": "Boolean"
+          },
+          "type": "Boolean"
+        },
+        {
+          "kind": 34,
+          "name": "isSymlink",
+          "required": true,
+          "scalar": {
+            "kind": 4,
+            "name": "isSymlink",
+            "required": true,
+            "type": "Boolean"
+          },
+          "type": "Boolean"
+        },
+        {
+          "kind": 34,
+          "name": "readOnly",
+          "required": true,
+          "scalar": {
+            "kind": 4,
+            "name": "readOnly",
+            "required": true,
+            "type": "Boolean"
+          },
+          "type": "Boolean"
+        },
+        {
+          "comment": "Seconds since the Unix epoch",
+          "kind": 34,
+          "name": "modified",
+          "scalar": {
+            "kind": 4,
+            "name": "modified",
+            "type": "UInt32"
+          },
+          "type": "UInt32"
+        },
+        {
+          "comment": "Seconds since the Unix epoch, if supported by the platform",
+          "kind": 34,
+          "name": "created",
+          "scalar": {
+            "kind": 4,
+            "name": "created",
+            "type": "UInt32"
+          },
+          "type": "UInt32"
+        }
+      ],
+      "type": "FileStat"
+    },
+    {
+      "kind": 1,
+      "properties": [
+        {
+          "kind": 34,
+          "name": "path",
+          "required": true,
+          "scalar": {
+            "kind": 4,
+            "name": "path",
+            "required": true,
+            "type": "String"
+          },
+          "type": "String"
+        },
+        {
+          "enum": {
+            "kind": 16384,
+            "name": "kind",
+            "required": true,
+            "type": "WatchEventKind"
+          },
+          "kind": 34,
+          "name": "kind",
+          "required": true,
+          "type": "WatchEventKind"
+        }
+      ],
+      "type": "WatchEvent"
+    }
+  ],
   "version": "0.1"
 })).unwrap()
   }
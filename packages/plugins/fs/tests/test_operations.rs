@@ -0,0 +1,177 @@
+use std::{fs, path::PathBuf, sync::{Arc, Mutex}, thread, time::Duration};
+
+use filesystem_plugin::{
+    wrap::{module::{ArgsAppendFile, ArgsWriteFile}, types::{DirEntry, FileStat, WatchEvent, WatchEventKind}},
+    FileSystemPlugin,
+};
+use polywrap_client::client::PolywrapClient;
+use polywrap_core::{
+    client::ClientConfig, error::Error,
+    resolvers::{static_resolver::{StaticResolver, StaticResolverLike}, uri_resolution_context::UriPackage},
+    uri::Uri,
+};
+use polywrap_msgpack::{msgpack, serialize};
+use polywrap_plugin::package::PluginPackage;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+fn get_client() -> PolywrapClient {
+    let fs_plugin = FileSystemPlugin { env: Value::Null, sandbox: None };
+    let plugin_pkg: PluginPackage = fs_plugin.into();
+
+    let resolver = StaticResolver::from(vec![StaticResolverLike::Package(UriPackage {
+        uri: Uri::try_from("wrap://ens/fs.polywrap.eth").unwrap(),
+        package: Arc::new(Mutex::new(plugin_pkg)),
+    })]);
+
+    PolywrapClient::new(ClientConfig {
+        resolver: Arc::new(resolver),
+        ..Default::default()
+    })
+}
+
+fn setup(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("fs_operations_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn path(dir: &PathBuf, name: &str) -> String {
+    dir.join(name).to_str().unwrap().to_string()
+}
+
+fn invoke<T: DeserializeOwned>(client: &PolywrapClient, method: &str, args: &[u8]) -> Result<T, Error> {
+    client.invoke::<T>(
+        &Uri::try_from("wrap://ens/fs.polywrap.eth").unwrap(),
+        method,
+        Some(args),
+        None,
+        None,
+    )
+}
+
+#[test]
+fn writes_and_reads_binary_files() {
+    let dir = setup("binary");
+    let client = get_client();
+    let file = path(&dir, "binary.bin");
+    let data = vec![0, 159, 146, 150, 255];
+
+    let args = serialize(&ArgsWriteFile { path: file.clone(), data: data.clone() }).unwrap();
+    assert_eq!(invoke::<Option<bool>>(&client, "writeFile", &args).unwrap(), Some(true));
+    let args = serialize(&ArgsAppendFile { path: file.clone(), data: vec![1, 2] }).unwrap();
+    assert_eq!(invoke::<Option<bool>>(&client, "appendFile", &args).unwrap(), Some(true));
+
+    assert_eq!(fs::read(&file).unwrap(), vec![0, 159, 146, 150, 255, 1, 2]);
+    let read = invoke::<Vec<u8>>(&client, "readFile", &msgpack!({ "path": file.clone() })).unwrap();
+    assert_eq!(read, vec![0, 159, 146, 150, 255, 1, 2]);
+}
+
+#[test]
+fn reads_files_with_encodings() {
+    let dir = setup("encodings");
+    let client = get_client();
+    let file = path(&dir, "file.txt");
+    fs::write(&file, "hé?").unwrap();
+    fs::write(path(&dir, "utf16.txt"), [104, 0, 105, 0]).unwrap();
+
+    let read = |file: &str, encoding: &str| {
+        invoke::<String>(&client, "readFileAsString", &msgpack!({ "path": file, "encoding": encoding }))
+    };
+    assert_eq!(read(&file, "UTF8").unwrap(), "hé?");
+    assert_eq!(read(&file, "HEX").unwrap(), "68c3a93f");
+    assert_eq!(read(&file, "BASE64").unwrap(), "aMOpPw==");
+    assert_eq!(read(&file, "BASE64URL").unwrap(), "aMOpPw");
+    assert_eq!(read(&file, "LATIN1").unwrap(), "hÃ©?");
+    assert_eq!(read(&path(&dir, "utf16.txt"), "UTF16LE").unwrap(), "hi");
+    assert!(read(&file, "ASCII").unwrap_err().to_string().contains("not valid ASCII"));
+}
+
+#[test]
+fn returns_errors_instead_of_panicking() {
+    let dir = setup("errors");
+    let client = get_client();
+    let missing = path(&dir, "missing");
+
+    let error = invoke::<Option<bool>>(&client, "rmdir", &msgpack!({ "path": missing.clone() })).unwrap_err();
+    assert!(error.to_string().contains("Failed to remove directory"));
+    assert!(invoke::<Option<bool>>(&client, "rm", &msgpack!({ "path": missing.clone() })).is_err());
+    assert!(invoke::<Option<bool>>(&client, "rm", &msgpack!({ "path": missing.clone(), "force": true })).is_ok());
+
+    let nested = path(&dir, "a/b");
+    assert!(invoke::<Option<bool>>(&client, "mkdir", &msgpack!({ "path": nested.clone() })).is_err());
+    assert!(invoke::<Option<bool>>(&client, "mkdir", &msgpack!({ "path": nested, "recursive": true })).is_ok());
+
+    let args = serialize(&ArgsWriteFile { path: path(&dir, "missing/file.txt"), data: vec![1] }).unwrap();
+    assert!(invoke::<Option<bool>>(&client, "writeFile", &args).is_err());
+}
+
+#[test]
+fn lists_and_stats_entries() {
+    let dir = setup("list");
+    let client = get_client();
+    fs::write(dir.join("file.txt"), "12345").unwrap();
+    fs::create_dir(dir.join("sub")).unwrap();
+
+    let entries = invoke::<Vec<DirEntry>>(&client, "readDir", &msgpack!({ "path": path(&dir, "") })).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].name, "file.txt");
+    assert!(entries[0].is_file && !entries[0].is_directory);
+    assert_eq!(entries[1].name, "sub");
+    assert!(entries[1].is_directory);
+
+    let stat = invoke::<FileStat>(&client, "stat", &msgpack!({ "path": path(&dir, "file.txt") })).unwrap();
+    assert_eq!(stat.size, "5");
+    assert!(stat.is_file && !stat.is_symlink);
+    assert!(stat.modified.is_some());
+}
+
+#[test]
+fn renames_and_copies() {
+    let dir = setup("copy");
+    let client = get_client();
+    fs::create_dir_all(dir.join("source/nested")).unwrap();
+    fs::write(dir.join("source/nested/file.txt"), "copied").unwrap();
+
+    let args = msgpack!({ "from": path(&dir, "source"), "to": path(&dir, "destination") });
+    let error = invoke::<Option<bool>>(&client, "copy", &args).unwrap_err();
+    assert!(error.to_string().contains("`recursive` is required"));
+
+    let args = msgpack!({ "from": path(&dir, "source"), "to": path(&dir, "destination"), "recursive": true });
+    assert_eq!(invoke::<Option<bool>>(&client, "copy", &args).unwrap(), Some(true));
+    assert_eq!(fs::read_to_string(dir.join("destination/nested/file.txt")).unwrap(), "copied");
+
+    let args = msgpack!({ "from": path(&dir, "source"), "to": path(&dir, "source/nested/inner"), "recursive": true });
+    assert!(invoke::<Option<bool>>(&client, "copy", &args).is_err());
+
+    let args = msgpack!({ "from": path(&dir, "destination"), "to": path(&dir, "renamed") });
+    assert_eq!(invoke::<Option<bool>>(&client, "rename", &args).unwrap(), Some(true));
+    assert!(!dir.join("destination").exists());
+    assert!(dir.join("renamed/nested/file.txt").exists());
+}
+
+#[test]
+fn watches_for_changes() {
+    let dir = setup("watch");
+    let client = get_client();
+    fs::write(dir.join("existing.txt"), "before").unwrap();
+
+    let writer = {
+        let dir = dir.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(200));
+            fs::write(dir.join("created.txt"), "new").unwrap();
+        })
+    };
+    let args = msgpack!({ "path": path(&dir, ""), "timeout": 5000 });
+    let events = invoke::<Vec<WatchEvent>>(&client, "watch", &args).unwrap();
+    writer.join().unwrap();
+
+    assert_eq!(events.len(), 1);
+    assert!(events[0].path.ends_with("created.txt"));
+    assert!(matches!(events[0].kind, WatchEventKind::CREATED));
+
+    let args = msgpack!({ "path": path(&dir, "existing.txt"), "timeout": 100 });
+    assert!(invoke::<Vec<WatchEvent>>(&client, "watch", &args).unwrap().is_empty());
+}
@@ -0,0 +1,160 @@
+use std::{fs, path::PathBuf, sync::{Arc, Mutex}};
+
+use filesystem_plugin::{
+    sandbox::Sandbox,
+    wrap::{module::ArgsWriteFile, types::{AccessMode, SandboxRoot}},
+    FileSystemPlugin,
+};
+use polywrap_client::client::PolywrapClient;
+use polywrap_core::{
+    client::ClientConfig, error::Error, env::Env,
+    resolvers::{static_resolver::{StaticResolver, StaticResolverLike}, uri_resolution_context::UriPackage},
+    uri::Uri,
+};
+use polywrap_msgpack::{msgpack, serialize};
+use polywrap_plugin::package::PluginPackage;
+use serde_json::{json, Value};
+
+fn get_client(sandbox: Option<Sandbox>) -> PolywrapClient {
+    let fs_plugin = FileSystemPlugin { env: Value::Null, sandbox };
+    let plugin_pkg: PluginPackage = fs_plugin.into();
+
+    let resolver = StaticResolver::from(vec![StaticResolverLike::Package(UriPackage {
+        uri: Uri::try_from("wrap://ens/fs.polywrap.eth").unwrap(),
+        package: Arc::new(Mutex::new(plugin_pkg)),
+    })]);
+
+    PolywrapClient::new(ClientConfig {
+        resolver: Arc::new(resolver),
+        ..Default::default()
+    })
+}
+
+// Each test gets its own `root` and `outside` directories
+fn setup(name: &str) -> (PathBuf, PathBuf) {
+    let dir = std::env::temp_dir().join(format!("fs_sandbox_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    let root = dir.join("root");
+    let outside = dir.join("outside");
+    fs::create_dir_all(&root).unwrap();
+    fs::create_dir_all(&outside).unwrap();
+    fs::write(root.join("inside.txt"), "inside").unwrap();
+    fs::write(outside.join("secret.txt"), "secret").unwrap();
+    (root, outside)
+}
+
+fn root(path: &PathBuf, mode: AccessMode) -> SandboxRoot {
+    SandboxRoot { path: path.to_str().unwrap().to_string(), mode: Some(mode) }
+}
+
+fn read(client: &PolywrapClient, path: &str, env: Option<Env>) -> Result<String, Error> {
+    client.invoke::<String>(
+        &Uri::try_from("wrap://ens/fs.polywrap.eth").unwrap(),
+        "readFileAsString",
+        Some(&msgpack!({ "path": path })),
+        env,
+        None,
+    )
+}
+
+fn write(client: &PolywrapClient, path: &str, env: Option<Env>) -> Result<Option<bool>, Error> {
+    let args = ArgsWriteFile { path: path.to_string(), data: b"data".to_vec() };
+    client.invoke::<Option<bool>>(
+        &Uri::try_from("wrap://ens/fs.polywrap.eth").unwrap(),
+        "writeFile",
+        Some(&serialize(&args).unwrap()),
+        env,
+        None,
+    )
+}
+
+#[test]
+fn rejects_paths_outside_of_roots() {
+    let (root_dir, outside) = setup("outside");
+    let sandbox = Sandbox::new(&[root(&root_dir, AccessMode::READ_WRITE)]).unwrap();
+    let client = get_client(Some(sandbox));
+
+    let inside = root_dir.join("inside.txt");
+    assert_eq!(read(&client, inside.to_str().unwrap(), None).unwrap(), "inside");
+    // Relative paths are resolved against the first root
+    assert_eq!(read(&client, "inside.txt", None).unwrap(), "inside");
+
+    let secret = outside.join("secret.txt");
+    let error = read(&client, secret.to_str().unwrap(), None).unwrap_err();
+    assert!(error.to_string().contains("is outside of the sandbox roots"));
+
+    let traversal = format!("{}/../outside/secret.txt", root_dir.to_str().unwrap());
+    assert!(read(&client, &traversal, None).is_err());
+    assert!(read(&client, "../outside/secret.txt", None).is_err());
+    assert!(write(&client, "missing/../../outside/new.txt", None).is_err());
+    assert!(!outside.join("new.txt").exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn rejects_symlinks_escaping_roots() {
+    let (root_dir, outside) = setup("symlink");
+    std::os::unix::fs::symlink(outside.join("secret.txt"), root_dir.join("link.txt")).unwrap();
+    std::os::unix::fs::symlink(&outside, root_dir.join("link_dir")).unwrap();
+    let sandbox = Sandbox::new(&[root(&root_dir, AccessMode::READ_WRITE)]).unwrap();
+    let client = get_client(Some(sandbox));
+
+    assert!(read(&client, "link.txt", None).is_err());
+    assert!(read(&client, "link_dir/secret.txt", None).is_err());
+    assert!(write(&client, "link.txt", None).is_err());
+    assert_eq!(fs::read_to_string(outside.join("secret.txt")).unwrap(), "secret");
+}
+
+#[cfg(unix)]
+#[test]
+fn rejects_copies_through_symlinks_in_the_destination() {
+    let (root_dir, outside) = setup("copy_symlink");
+    fs::create_dir_all(root_dir.join("from")).unwrap();
+    fs::write(root_dir.join("from/secret.txt"), "overwritten").unwrap();
+    fs::create_dir_all(root_dir.join("to")).unwrap();
+    std::os::unix::fs::symlink(outside.join("secret.txt"), root_dir.join("to/secret.txt")).unwrap();
+    let sandbox = Sandbox::new(&[root(&root_dir, AccessMode::READ_WRITE)]).unwrap();
+    let client = get_client(Some(sandbox));
+
+    let copied = client.invoke::<Option<bool>>(
+        &Uri::try_from("wrap://ens/fs.polywrap.eth").unwrap(),
+        "copy",
+        Some(&msgpack!({ "from": "from", "to": "to", "recursive": true })),
+        None,
+        None,
+    );
+    assert!(copied.unwrap_err().to_string().contains("is outside of the sandbox roots"));
+    assert_eq!(fs::read_to_string(outside.join("secret.txt")).unwrap(), "secret");
+}
+
+#[test]
+fn rejects_writes_to_read_only_roots() {
+    let (root_dir, outside) = setup("read_only");
+    let sandbox = Sandbox::new(&[
+        root(&root_dir, AccessMode::READ_ONLY),
+        root(&outside, AccessMode::READ_WRITE),
+    ]).unwrap();
+    let client = get_client(Some(sandbox));
+
+    assert_eq!(read(&client, "inside.txt", None).unwrap(), "inside");
+    let error = write(&client, "inside.txt", None).unwrap_err();
+    assert!(error.to_string().contains("is read-only"));
+    assert_eq!(fs::read_to_string(root_dir.join("inside.txt")).unwrap(), "inside");
+
+    assert_eq!(write(&client, outside.join("new.txt").to_str().unwrap(), None).unwrap(), Some(true));
+}
+
+#[test]
+fn reads_roots_from_env() {
+    let (root_dir, outside) = setup("env");
+    let client = get_client(None);
+    let env = json!({
+        "roots": [{ "path": root_dir.to_str().unwrap(), "mode": "READ_WRITE" }]
+    });
+
+    assert_eq!(write(&client, "new.txt", Some(env.clone())).unwrap(), Some(true));
+    assert_eq!(fs::read_to_string(root_dir.join("new.txt")).unwrap(), "data");
+
+    let secret = outside.join("secret.txt");
+    assert!(read(&client, secret.to_str().unwrap(), Some(env)).is_err());
+}
@@ -0,0 +1,122 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+// Total size of the cached bodies, past which the least recently used are evicted
+pub const DEFAULT_MAX_SIZE: usize = 128 * 1024 * 1024;
+// Bodies larger than this aren't kept, so large modules aren't held in memory
+// twice. Wrappers fetched once are better kept by the client's wrapper cache
+pub const DEFAULT_MAX_ENTRY_SIZE: usize = 8 * 1024 * 1024;
+
+#[derive(Clone, Debug)]
+pub struct CachedResponse {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub body: Vec<u8>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    response: CachedResponse,
+    last_used: u64,
+}
+
+#[derive(Debug, Default)]
+struct Responses {
+    entries: HashMap<String, Entry>,
+    size: usize,
+    clock: u64,
+}
+
+impl Responses {
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+
+    fn remove(&mut self, url: &str) {
+        if let Some(entry) = self.entries.remove(url) {
+            self.size -= entry.response.body.len();
+        }
+    }
+}
+
+// Responses are kept in memory for the lifetime of the plugin, and shared
+// between clones of the cache
+#[derive(Clone, Debug)]
+pub struct HttpCache {
+    responses: Arc<Mutex<Responses>>,
+    max_size: usize,
+    max_entry_size: usize,
+}
+
+impl Default for HttpCache {
+    fn default() -> Self {
+        Self::with_max_size(DEFAULT_MAX_SIZE).with_max_entry_size(DEFAULT_MAX_ENTRY_SIZE)
+    }
+}
+
+impl HttpCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Bodies up to the whole cache's size are kept, unless limited with
+    // `with_max_entry_size`
+    pub fn with_max_size(max_size: usize) -> Self {
+        Self {
+            responses: Default::default(),
+            max_size,
+            max_entry_size: max_size,
+        }
+    }
+
+    pub fn with_max_entry_size(mut self, max_entry_size: usize) -> Self {
+        self.max_entry_size = max_entry_size.min(self.max_size);
+        self
+    }
+
+    pub fn get(&self, url: &str) -> Option<CachedResponse> {
+        let mut responses = self.responses.lock().unwrap();
+        let now = responses.tick();
+        let entry = responses.entries.get_mut(url)?;
+        entry.last_used = now;
+        Some(entry.response.clone())
+    }
+
+    // Bodies larger than the max entry size aren't kept
+    pub fn insert(&self, url: &str, response: CachedResponse) {
+        let mut responses = self.responses.lock().unwrap();
+        responses.remove(url);
+        if response.body.len() > self.max_entry_size {
+            return;
+        }
+
+        while responses.size + response.body.len() > self.max_size {
+            let oldest = responses
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(url, _)| url.clone());
+            match oldest {
+                Some(oldest) => responses.remove(&oldest),
+                None => break,
+            }
+        }
+
+        let last_used = responses.tick();
+        responses.size += response.body.len();
+        responses.entries.insert(url.to_string(), Entry { response, last_used });
+    }
+
+    pub fn remove(&self, url: &str) {
+        self.responses.lock().unwrap().remove(url);
+    }
+
+    pub fn clear(&self) {
+        let mut responses = self.responses.lock().unwrap();
+        responses.entries.clear();
+        responses.size = 0;
+    }
+}
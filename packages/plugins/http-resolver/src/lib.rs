@@ -1,18 +1,190 @@
-use std::sync::Arc;
+use std::{collections::BTreeMap, fmt, sync::Arc};
 
-
-use polywrap_core::{invoke::Invoker};
+use polywrap_core::{integrity::{verify_hash, ContentHashes}, invoke::Invoker};
+use polywrap_msgpack::extensions::generic_map::GenericMap;
 use polywrap_plugin_macro::{plugin_struct, plugin_impl};
 use polywrap_plugin::error::PluginError;
 use wrap::{
     module::{ArgsGetFile, ArgsTryResolveUri, Module},
-    types::{HttpModule, HttpRequest, MaybeUriOrManifest, HttpModuleArgsGet},
+    types::{HttpBinaryResponse, HttpModule, HttpRequest, HttpResponseType, MaybeUriOrManifest, HttpModuleArgsGetBinary},
     wrap_info::get_manifest,
 };
+use cache::{CachedResponse, HttpCache};
+pub mod cache;
 pub mod wrap;
 
+const MANIFEST_FILE: &str = "wrap.info";
+// Files are assembled here, so larger ones are refused rather than buffered
+const MAX_FILE_SIZE: u32 = 64 * 1024 * 1024;
+// Files are requested in ranges of this size, so no single `getBinary` result
+// holds a whole large file. Servers that ignore `Range` send it all at once
+const CHUNK_SIZE: u64 = 4 * 1024 * 1024;
+
+// A missing file is a miss rather than an error for `getFile`
+enum FetchError {
+    NotFound(String),
+    Failed(String),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::NotFound(reason) | FetchError::Failed(reason) => f.write_str(reason),
+        }
+    }
+}
+
 #[plugin_struct]
 pub struct HttpResolverPlugin {
+    pub cache: HttpCache,
+}
+
+impl HttpResolverPlugin {
+    // Cached responses are revalidated with their `ETag` or `Last-Modified`,
+    // and reused when the server answers with `304 Not Modified`
+    fn fetch(&self, url: &str, invoker: Arc<dyn Invoker>) -> Result<Vec<u8>, FetchError> {
+        let cached = self.cache.get(url);
+
+        let mut headers = BTreeMap::new();
+        if let Some(cached) = &cached {
+            if let Some(etag) = &cached.etag {
+                headers.insert("If-None-Match".to_string(), etag.clone());
+            }
+            if let Some(last_modified) = &cached.last_modified {
+                headers.insert("If-Modified-Since".to_string(), last_modified.clone());
+            }
+        }
+
+        let mut ranged_headers = headers.clone();
+        ranged_headers.insert("Range".to_string(), format!("bytes=0-{}", CHUNK_SIZE - 1));
+        let mut response = self.get_binary(url, ranged_headers, invoker.clone())?;
+        // Empty files have no satisfiable range
+        if response.status == 416 {
+            response = self.get_binary(url, headers, invoker.clone())?;
+        }
+
+        let etag = get_header(&response, "etag");
+        let last_modified = get_header(&response, "last-modified");
+
+        let body = match (response.status, cached) {
+            (304, Some(cached)) => return Ok(cached.body),
+            (206, _) => self.fetch_remaining_ranges(url, response, etag.as_ref(), invoker)?,
+            (status, _) if is_success(status) => response.body.unwrap_or_default(),
+            (status, _) => {
+                let reason = format!("`{}` responded with {} {}", url, status, response.status_text);
+                return match status {
+                    404 | 410 => Err(FetchError::NotFound(reason)),
+                    _ => Err(FetchError::Failed(reason)),
+                };
+            }
+        };
+
+        if etag.is_some() || last_modified.is_some() {
+            self.cache.insert(url, CachedResponse { etag, last_modified, body: body.clone() });
+        } else {
+            self.cache.remove(url);
+        }
+        Ok(body)
+    }
+
+    // Appends the rest of the file to the first range, one range at a time. A
+    // file that changes in between fails, through `If-Match`, rather than
+    // mixing two versions
+    fn fetch_remaining_ranges(
+        &self,
+        url: &str,
+        first: HttpBinaryResponse,
+        etag: Option<&String>,
+        invoker: Arc<dyn Invoker>,
+    ) -> Result<Vec<u8>, FetchError> {
+        let failed = |reason: &str| FetchError::Failed(format!("`{}` {}", url, reason));
+
+        let (_, total) = get_content_range(&first)
+            .filter(|(start, _)| *start == 0)
+            .ok_or_else(|| failed("sent a partial response without a valid Content-Range"))?;
+        if total > MAX_FILE_SIZE.into() {
+            return Err(failed(&format!("exceeds the max size of {} bytes", MAX_FILE_SIZE)));
+        }
+
+        let mut body = first.body.unwrap_or_default();
+        body.reserve_exact((total as usize).saturating_sub(body.len()));
+
+        while (body.len() as u64) < total {
+            let start = body.len() as u64;
+            let end = (start + CHUNK_SIZE).min(total) - 1;
+
+            let mut headers = BTreeMap::new();
+            headers.insert("Range".to_string(), format!("bytes={}-{}", start, end));
+            if let Some(etag) = etag {
+                headers.insert("If-Match".to_string(), etag.clone());
+            }
+
+            let response = self.get_binary(url, headers, invoker.clone())?;
+            if response.status != 206 || get_content_range(&response) != Some((start, total)) {
+                return Err(failed(&format!(
+                    "responded with {} {} to a request for bytes {}-{}",
+                    response.status, response.status_text, start, end
+                )));
+            }
+
+            let chunk = response.body.unwrap_or_default();
+            if chunk.is_empty() || start + chunk.len() as u64 > total {
+                return Err(failed(&format!("sent an invalid range for bytes {}-{}", start, end)));
+            }
+            body.extend(chunk);
+        }
+
+        Ok(body)
+    }
+
+    fn get_binary(
+        &self,
+        url: &str,
+        headers: BTreeMap<String, String>,
+        invoker: Arc<dyn Invoker>,
+    ) -> Result<HttpBinaryResponse, FetchError> {
+        HttpModule::get_binary(
+            &HttpModuleArgsGetBinary {
+                url: url.to_string(),
+                request: Some(HttpRequest {
+                    headers: Some(GenericMap(headers)),
+                    url_params: None,
+                    response_type: HttpResponseType::BINARY,
+                    body: None,
+                    body_type: None,
+                    form_data: None,
+                    timeout: None,
+                }),
+                max_size: Some(MAX_FILE_SIZE),
+            },
+            invoker,
+        )
+        .map_err(|e| FetchError::Failed(e.to_string()))?
+        .ok_or_else(|| FetchError::Failed(format!("`{}` returned no response", url)))
+    }
+}
+
+fn get_header(response: &HttpBinaryResponse, name: &str) -> Option<String> {
+    response.headers.as_ref().and_then(|headers| {
+        headers
+            .0
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.clone())
+    })
+}
+
+// The start and total size of a `Content-Range: bytes <start>-<end>/<total>`
+fn get_content_range(response: &HttpBinaryResponse) -> Option<(u64, u64)> {
+    let range = get_header(response, "content-range")?;
+    let (range, total) = range.strip_prefix("bytes ")?.split_once('/')?;
+    let (start, _) = range.split_once('-')?;
+    Some((start.parse().ok()?, total.parse().ok()?))
+}
+
+fn verify(url: &str, bytes: &[u8], expected: Option<&String>) -> Result<(), PluginError> {
+    verify_hash(url, expected, bytes)
+        .map_err(|e| PluginError::ModuleError(format!("Content hash mismatch: {}", e)))
 }
 
 #[plugin_impl]
@@ -26,43 +198,30 @@ impl Module for HttpResolverPlugin {
             return Ok(None);
         };
 
-        let manifest_search_pattern = "wrap.info";
-        let url = format!("{}/{}", args.path, manifest_search_pattern);
-        let get_result = HttpModule::get(
-            &HttpModuleArgsGet {
-                url,
-                request: Some(HttpRequest {
-                    response_type: wrap::types::HttpResponseType::BINARY,
-                    headers: None,
-                    body: None,
-                    url_params: None,
-                    timeout: None,
-                    form_data: None,
-                }),
-            },
-            invoker,
-        );
-
-        let manifest = match get_result {
-            Ok(opt_response) => {
-                if let Some(response) = opt_response {
-                    let body = response.body.unwrap();
-                    Some(base64::decode(body).unwrap())
-                } else {
-                    None
-                }
+        let (path, hashes) = ContentHashes::split_path(&args.path).map_err(PluginError::ModuleError)?;
+        let url = format!("{}/{}", path, MANIFEST_FILE);
+
+        let token = invoker.get_cancellation_token();
+        match self.fetch(&url, invoker) {
+            // A fetch cut short by a cancellation or a timeout says nothing
+            // about the URI, so it's an error rather than a reason
+            Err(reason) if token.is_some_and(|token| token.is_cancelled()) => {
+                Err(PluginError::ModuleError(reason.to_string()))
             }
-            Err(_) => {
-                // TODO: logging
-                // https://github.com/polywrap/monorepo/issues/33
-                None
+            Ok(manifest) => {
+                verify(&url, &manifest, hashes.manifest_sha256.as_ref())?;
+                Ok(Some(MaybeUriOrManifest {
+                    uri: None,
+                    manifest: Some(manifest),
+                    reason: None,
+                }))
             }
-        };
-
-        Ok(Some(MaybeUriOrManifest {
-            uri: None,
-            manifest,
-        }))
+            Err(reason) => Ok(Some(MaybeUriOrManifest {
+                uri: None,
+                manifest: None,
+                reason: Some(reason.to_string()),
+            })),
+        }
     }
 
     fn get_file(
@@ -70,36 +229,21 @@ impl Module for HttpResolverPlugin {
         args: &ArgsGetFile,
         invoker: Arc<dyn Invoker>,
     ) -> Result<Option<Vec<u8>>, PluginError> {
-        let resolve_result = HttpModule::get(
-            &HttpModuleArgsGet {
-                url: args.path.clone(),
-                request: Some(HttpRequest {
-                    response_type: wrap::types::HttpResponseType::BINARY,
-                    headers: None,
-                    body: None,
-                    url_params: None,
-                    timeout: None,
-                    form_data: None,
-                }),
-            },
-            invoker,
-        );
-
-        let file = if let Ok(opt_result) = resolve_result {
-            if let Some(result) = opt_result {
-                result.body.map(|body| {
-                  
-
-                //   println!("URI: {}\n\n{:?}", args.path.clone(), b);
-                  base64::decode(body).unwrap()
-                })
-            } else {
-                None
-            }
-        } else {
-            None
+        let (url, hashes) = ContentHashes::split_path(&args.path).map_err(PluginError::ModuleError)?;
+
+        let file = match self.fetch(&url, invoker) {
+            Ok(file) => file,
+            Err(FetchError::NotFound(_)) => return Ok(None),
+            Err(FetchError::Failed(reason)) => return Err(PluginError::ModuleError(reason)),
         };
 
-        Ok(file)
+        let name = url.rsplit('/').next().unwrap_or_default();
+        verify(&url, &file, hashes.get(name))?;
+
+        Ok(Some(file))
     }
 }
+
+fn is_success(status: i32) -> bool {
+    (200..300).contains(&status)
+}
@@ -1,6 +1,8 @@
 #![allow(unused_imports)]
 #![allow(non_camel_case_types)]
 
+use polywrap_msgpack::extensions::generic_map::GenericMap;
+
 // NOTE: This is an auto-generated file.
 //       All modifications will be overwritten.
 use serde::{Serialize, Deserialize};
@@ -23,6 +25,7 @@ use polywrap_plugin::error::PluginError;
 pub struct MaybeUriOrManifest {
     pub uri: Option<String>,
     pub manifest: Option<Vec<u8>>,
+    pub reason: Option<String>,
 }
 // Objects END //
 
@@ -34,10 +37,12 @@ pub struct MaybeUriOrManifest {
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HttpRequest {
-    pub headers: Option<Map<String, String>>,
-    pub url_params: Option<Map<String, String>>,
+    pub headers: Option<GenericMap<String, String>>,
+    pub url_params: Option<GenericMap<String, String>>,
     pub response_type: HttpResponseType,
     pub body: Option<String>,
+    #[serde(rename = "bodyType", alias = "body_type")]
+    pub body_type: Option<HttpBodyType>,
     pub form_data: Option<Vec<HttpFormDataEntry>>,
     pub timeout: Option<u32>,
 }
@@ -52,10 +57,20 @@ pub struct HttpFormDataEntry {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HttpResponse {
     pub status: i32,
+    #[serde(rename = "statusText")]
     pub status_text: String,
-    pub headers: Option<Map<String, String>>,
+    pub headers: Option<GenericMap<String, String>>,
     pub body: Option<String>,
 }
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct HttpBinaryResponse {
+    pub status: i32,
+    #[serde(rename = "statusText")]
+    pub status_text: String,
+    pub headers: Option<GenericMap<String, String>>,
+    #[serde(with = "serde_bytes")]
+    pub body: Option<Vec<u8>>,
+}
 // Imported objects END //
 
 // Imported envs START //
@@ -70,6 +85,12 @@ pub enum HttpResponseType {
     BINARY,
     _MAX_
 }
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub enum HttpBodyType {
+    TEXT,
+    BINARY,
+    _MAX_
+}
 // Imported enums END //
 
 // Imported Modules START //
@@ -88,6 +109,15 @@ pub struct HttpModuleArgsPost {
     pub request: Option<HttpRequest>,
 }
 
+// URI: "ens/http.polywrap.eth" //
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct HttpModuleArgsGetBinary {
+    pub url: String,
+    pub request: Option<HttpRequest>,
+    #[serde(rename = "maxSize", alias = "max_size")]
+    pub max_size: Option<u32>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HttpModule {}
 
@@ -141,5 +171,27 @@ impl HttpModule {
 
         Ok(Some(decode(result.as_slice())?))
     }
+
+    pub fn get_binary(args: &HttpModuleArgsGetBinary, invoker: Arc<dyn Invoker>) -> Result<Option<HttpBinaryResponse>, PluginError> {
+        let uri = HttpModule::URI;
+        let serialized_args = serialize(args.clone()).unwrap();
+        let opt_args = Some(serialized_args.as_slice());
+        let uri = Uri::try_from(uri).unwrap();
+        let result = invoker.invoke_raw(
+            &uri,
+            "getBinary",
+            opt_args,
+            None,
+            None
+        )
+        .map_err(|e| PluginError::SubinvocationError {
+            uri: uri.to_string(),
+            method: "getBinary".to_string(),
+            args: serde_json::to_string(&args).unwrap(),
+            exception: e.to_string(),
+        })?;
+
+        Ok(Some(decode(result.as_slice())?))
+    }
 }
 // Imported Modules END //
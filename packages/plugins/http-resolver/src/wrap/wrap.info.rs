@@ -106,6 +106,56 @@ pub fn get_manifest() -> WrapManifest {
             "type": "Http_Response"
           },
           "type": "Method"
+        },
+        {
+          "arguments": [
+            {
+              "kind": 34,
+              "name": "url",
+              "required": true,
+              "scalar": {
+                "kind": 4,
+                "name": "url",
+                "required": true,
+                "type": "String"
+              },
+              "type": "String"
+            },
+            {
+              "kind": 34,
+              "name": "request",
+              "object": {
+                "kind": 8192,
+                "name": "request",
+                "type": "Http_Request"
+              },
+              "type": "Http_Request"
+            },
+            {
+              "kind": 34,
+              "name": "maxSize",
+              "scalar": {
+                "kind": 4,
+                "name": "maxSize",
+                "type": "UInt32"
+              },
+              "type": "UInt32"
+            }
+          ],
+          "kind": 64,
+          "name": "getBinary",
+          "required": true,
+          "return": {
+            "kind": 34,
+            "name": "getBinary",
+            "object": {
+              "kind": 8192,
+              "name": "getBinary",
+              "type": "Http_BinaryResponse"
+            },
+            "type": "Http_BinaryResponse"
+          },
+          "type": "Method"
         }
       ],
       "namespace": "Http",
@@ -361,6 +411,77 @@ pub fn get_manifest() -> WrapManifest {
       ],
       "type": "Http_Response",
       "uri": "ens/http.polywrap.eth"
+    },
+    {
+      "kind": 1025,
+      "namespace": "Http",
+      "nativeType": "BinaryResponse",
+      "properties": [
+        {
+          "kind": 34,
+          "name": "status",
+          "required": true,
+          "scalar": {
+            "kind": 4,
+            "name": "status",
+            "required": true,
+            "type": "Int"
+          },
+          "type": "Int"
+        },
+        {
+          "kind": 34,
+          "name": "statusText",
+          "required": true,
+          "scalar": {
+            "kind": 4,
+            "name": "statusText",
+            "required": true,
+            "type": "String"
+          },
+          "type": "String"
+        },
+        {
+          "kind": 34,
+          "map": {
+            "key": {
+              "kind": 4,
+              "name": "headers",
+              "required": true,
+              "type": "String"
+            },
+            "kind": 262146,
+            "name": "headers",
+            "scalar": {
+              "kind": 4,
+              "name": "headers",
+              "required": true,
+              "type": "String"
+            },
+            "type": "Map<String, String>",
+            "value": {
+              "kind": 4,
+              "name": "headers",
+              "required": true,
+              "type": "String"
+            }
+          },
+          "name": "headers",
+          "type": "Map<String, String>"
+        },
+        {
+          "kind": 34,
+          "name": "body",
+          "scalar": {
+            "kind": 4,
+            "name": "body",
+            "type": "Bytes"
+          },
+          "type": "Bytes"
+        }
+      ],
+      "type": "Http_BinaryResponse",
+      "uri": "ens/http.polywrap.eth"
     }
   ],
   "moduleType": {
@@ -481,6 +602,17 @@ pub fn get_manifest() -> WrapManifest {
             "type": "Bytes"
           },
           "type": "Bytes"
+        },
+        {
+          "comment": "Why the URI couldn't be resolved, when neither `uri` nor `manifest` is set",
+          "kind": 34,
+          "name": "reason",
+          "scalar": {
+            "kind": 4,
+            "name": "reason",
+            "type": "String"
+          },
+          "type": "String"
         }
       ],
       "type": "MaybeUriOrManifest"
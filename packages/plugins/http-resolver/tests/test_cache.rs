@@ -0,0 +1,43 @@
+use http_resolver_plugin::cache::{CachedResponse, HttpCache};
+
+fn response(size: usize) -> CachedResponse {
+    CachedResponse { etag: Some("\"v1\"".to_string()), last_modified: None, body: vec![0; size] }
+}
+
+#[test]
+fn evicts_least_recently_used_responses() {
+    let cache = HttpCache::with_max_size(100);
+    cache.insert("a", response(40));
+    cache.insert("b", response(40));
+    assert!(cache.get("a").is_some());
+
+    cache.insert("c", response(40));
+    assert!(cache.get("a").is_some());
+    assert!(cache.get("b").is_none());
+    assert!(cache.get("c").is_some());
+
+    // Replacing a response frees its previous size
+    cache.insert("c", response(60));
+    assert!(cache.get("a").is_some());
+    assert_eq!(cache.get("c").unwrap().body.len(), 60);
+}
+
+#[test]
+fn skips_responses_larger_than_the_cache() {
+    let cache = HttpCache::with_max_size(100);
+    cache.insert("a", response(40));
+    cache.insert("large", response(101));
+
+    assert!(cache.get("large").is_none());
+    assert!(cache.get("a").is_some());
+}
+
+#[test]
+fn skips_responses_larger_than_the_max_entry_size() {
+    let cache = HttpCache::with_max_size(100).with_max_entry_size(50);
+    cache.insert("a", response(50));
+    cache.insert("large", response(51));
+
+    assert!(cache.get("a").is_some());
+    assert!(cache.get("large").is_none());
+}
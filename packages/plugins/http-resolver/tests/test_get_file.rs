@@ -0,0 +1,130 @@
+use std::sync::{Arc, Mutex};
+use http_plugin::HttpPlugin;
+use http_resolver_plugin::HttpResolverPlugin;
+use httpmock::{prelude::*, Method};
+use polywrap_client::client::PolywrapClient;
+use polywrap_core::resolvers::static_resolver::{StaticResolver, StaticResolverLike};
+use polywrap_core::{client::ClientConfig, error::Error, invoke::Invoker, resolvers::uri_resolution_context::UriPackage, uri::Uri};
+use polywrap_msgpack::msgpack;
+use polywrap_plugin::package::PluginPackage;
+use serde_json::Value;
+
+fn get_client() -> PolywrapClient {
+    let http: PluginPackage = HttpPlugin::new(None).into();
+    let http_resolver: PluginPackage = HttpResolverPlugin { env: Value::Null, cache: Default::default() }.into();
+
+    let resolver = StaticResolver::from(vec![
+        StaticResolverLike::Package(UriPackage {
+            uri: Uri::try_from("wrap://ens/http.polywrap.eth").unwrap(),
+            package: Arc::new(Mutex::new(http)),
+        }),
+        StaticResolverLike::Package(UriPackage {
+            uri: Uri::try_from("wrap://ens/http-resolver.polywrap.eth").unwrap(),
+            package: Arc::new(Mutex::new(http_resolver)),
+        }),
+    ]);
+
+    PolywrapClient::new(ClientConfig {
+        resolver: Arc::new(resolver),
+        ..Default::default()
+    })
+}
+
+// Plugins return no bytes at all for `None`
+fn get_file(client: &PolywrapClient, path: &str) -> Result<Option<Vec<u8>>, Error> {
+    let result = client.invoke_raw(
+        &Uri::try_from("wrap://ens/http-resolver.polywrap.eth").unwrap(),
+        "getFile",
+        Some(&msgpack!({ "path": path })),
+        None,
+        None,
+    )?;
+
+    if result.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(polywrap_msgpack::decode(&result).unwrap()))
+    }
+}
+
+#[test]
+fn gets_binary_files() {
+    let server = MockServer::start();
+    let module: Vec<u8> = (0..=255).collect();
+    server.mock(|when, then| {
+        when.method(Method::GET).path("/wrapper/wrap.wasm");
+        then.status(200).body(&module);
+    });
+    server.mock(|when, then| {
+        when.method(Method::GET).path("/missing/wrap.wasm");
+        then.status(404).body("not found");
+    });
+
+    server.mock(|when, then| {
+        when.method(Method::GET).path("/failing/wrap.wasm");
+        then.status(503);
+    });
+
+    let client = get_client();
+    assert_eq!(get_file(&client, &server.url("/wrapper/wrap.wasm")).unwrap(), Some(module));
+    assert_eq!(get_file(&client, &server.url("/missing/wrap.wasm")).unwrap(), None);
+
+    // Only a missing file is a miss
+    let error = get_file(&client, &server.url("/failing/wrap.wasm")).unwrap_err();
+    assert!(error.to_string().contains("responded with 503"));
+}
+
+#[test]
+fn gets_large_files_in_ranges() {
+    let server = MockServer::start();
+    let module: Vec<u8> = (0..4 * 1024 * 1024 + 100).map(|i| (i % 251) as u8).collect();
+    let total = module.len();
+    let first = server.mock(|when, then| {
+        when.method(Method::GET)
+            .path("/wrapper/wrap.wasm")
+            .header("range", "bytes=0-4194303");
+        then.status(206)
+            .header("ETag", "\"v1\"")
+            .header("Content-Range", format!("bytes 0-4194303/{}", total))
+            .body(&module[..4194304]);
+    });
+    let rest = server.mock(|when, then| {
+        when.method(Method::GET)
+            .path("/wrapper/wrap.wasm")
+            .header("range", format!("bytes=4194304-{}", total - 1))
+            .header("if-match", "\"v1\"");
+        then.status(206)
+            .header("ETag", "\"v1\"")
+            .header("Content-Range", format!("bytes 4194304-{}/{}", total - 1, total))
+            .body(&module[4194304..]);
+    });
+
+    let client = get_client();
+    assert_eq!(get_file(&client, &server.url("/wrapper/wrap.wasm")).unwrap(), Some(module));
+    first.assert();
+    rest.assert();
+}
+
+#[test]
+fn fails_when_a_file_changes_between_ranges() {
+    let server = MockServer::start();
+    let module = vec![0; 4 * 1024 * 1024 + 100];
+    server.mock(|when, then| {
+        when.method(Method::GET)
+            .path("/wrapper/wrap.wasm")
+            .header("range", "bytes=0-4194303");
+        then.status(206)
+            .header("ETag", "\"v1\"")
+            .header("Content-Range", format!("bytes 0-4194303/{}", module.len()))
+            .body(&module[..4194304]);
+    });
+    server.mock(|when, then| {
+        when.method(Method::GET)
+            .path("/wrapper/wrap.wasm")
+            .header("if-match", "\"v1\"");
+        then.status(412);
+    });
+
+    let error = get_file(&get_client(), &server.url("/wrapper/wrap.wasm")).unwrap_err();
+    assert!(error.to_string().contains("responded with 412"));
+}
@@ -0,0 +1,128 @@
+use std::sync::{Arc, Mutex};
+use http_plugin::HttpPlugin;
+use http_resolver_plugin::{wrap::types::MaybeUriOrManifest, HttpResolverPlugin};
+use httpmock::{prelude::*, Method};
+use polywrap_client::client::PolywrapClient;
+use polywrap_core::resolvers::static_resolver::{StaticResolver, StaticResolverLike};
+use polywrap_core::{
+    client::ClientConfig, error::Error, integrity::sha256, invoke::Invoker, resolvers::uri_resolution_context::UriPackage, uri::Uri,
+};
+use polywrap_msgpack::msgpack;
+use polywrap_plugin::package::PluginPackage;
+use serde_json::Value;
+
+fn get_client() -> PolywrapClient {
+    let http: PluginPackage = HttpPlugin::new(None).into();
+    let http_resolver: PluginPackage = HttpResolverPlugin { env: Value::Null, cache: Default::default() }.into();
+
+    let resolver = StaticResolver::from(vec![
+        StaticResolverLike::Package(UriPackage {
+            uri: Uri::try_from("wrap://ens/http.polywrap.eth").unwrap(),
+            package: Arc::new(Mutex::new(http)),
+        }),
+        StaticResolverLike::Package(UriPackage {
+            uri: Uri::try_from("wrap://ens/http-resolver.polywrap.eth").unwrap(),
+            package: Arc::new(Mutex::new(http_resolver)),
+        }),
+    ]);
+
+    PolywrapClient::new(ClientConfig {
+        resolver: Arc::new(resolver),
+        ..Default::default()
+    })
+}
+
+fn try_resolve_uri(client: &PolywrapClient, path: &str) -> Result<MaybeUriOrManifest, Error> {
+    client.invoke::<MaybeUriOrManifest>(
+        &Uri::try_from("wrap://ens/http-resolver.polywrap.eth").unwrap(),
+        "tryResolveUri",
+        Some(&msgpack!({ "authority": "http", "path": path })),
+        None,
+        None,
+    )
+}
+
+#[test]
+fn returns_a_reason_for_misses() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(Method::GET).path("/missing/wrap.info");
+        then.status(404).body("not found");
+    });
+
+    let result = try_resolve_uri(&get_client(), &server.url("/missing")).unwrap();
+    assert!(result.manifest.is_none() && result.uri.is_none());
+    assert!(result.reason.unwrap().contains("responded with 404 Not Found"));
+}
+
+#[test]
+fn verifies_content_hashes() {
+    let server = MockServer::start();
+    let manifest = b"manifest".to_vec();
+    let module = b"module".to_vec();
+    server.mock(|when, then| {
+        when.method(Method::GET).path("/wrapper/wrap.info");
+        then.status(200).body(&manifest);
+    });
+    server.mock(|when, then| {
+        when.method(Method::GET).path("/wrapper/wrap.wasm");
+        then.status(200).body(&module);
+    });
+
+    let client = get_client();
+    let pinned = format!("{}#sha256={}&wasm-sha256={}", server.url("/wrapper"), sha256(&manifest), sha256(&module));
+    let result = try_resolve_uri(&client, &pinned).unwrap();
+    assert_eq!(result.manifest.unwrap(), manifest);
+
+    let file = client
+        .invoke_raw(
+            &Uri::try_from("wrap://ens/http-resolver.polywrap.eth").unwrap(),
+            "getFile",
+            Some(&msgpack!({ "path": format!("{}/wrap.wasm", pinned) })),
+            None,
+            None,
+        )
+        .unwrap();
+    assert_eq!(polywrap_msgpack::decode::<Vec<u8>>(&file).unwrap(), module);
+
+    let tampered = format!("{}#sha256={}", server.url("/wrapper"), sha256(b"other"));
+    let error = try_resolve_uri(&client, &tampered).unwrap_err();
+    assert!(error.to_string().contains("Content hash mismatch"));
+
+    let error = client
+        .invoke_raw(
+            &Uri::try_from("wrap://ens/http-resolver.polywrap.eth").unwrap(),
+            "getFile",
+            Some(&msgpack!({ "path": format!("{}#wasm-sha256={}/wrap.wasm", server.url("/wrapper"), sha256(b"other")) })),
+            None,
+            None,
+        )
+        .unwrap_err();
+    assert!(error.to_string().contains("Content hash mismatch"));
+}
+
+#[test]
+fn revalidates_cached_responses() {
+    let server = MockServer::start();
+    let mut fresh = server.mock(|when, then| {
+        when.method(Method::GET).path("/wrapper/wrap.info");
+        then.status(200).header("ETag", "\"v1\"").body("manifest");
+    });
+
+    let client = get_client();
+    let result = try_resolve_uri(&client, &server.url("/wrapper")).unwrap();
+    assert_eq!(result.manifest.unwrap(), b"manifest");
+    fresh.assert();
+    fresh.delete();
+
+    let not_modified = server.mock(|when, then| {
+        when.method(Method::GET)
+            .path("/wrapper/wrap.info")
+            .header("if-none-match", "\"v1\"");
+        then.status(304);
+    });
+
+    let result = try_resolve_uri(&client, &server.url("/wrapper")).unwrap();
+    not_modified.assert();
+    assert_eq!(result.manifest.unwrap(), b"manifest");
+}
@@ -0,0 +1,110 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use polywrap_plugin::error::PluginError;
+use rustls::{
+    pki_types::{pem::PemObject, CertificateDer, PrivateKeyDer},
+    ClientConfig, RootCertStore,
+};
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_MAX_REDIRECTS: u32 = 5;
+
+// Mirrors the schema's `Env` type, which `set_env` passes as plain JSON objects
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpConfig {
+    pub proxy: Option<String>,
+    pub ca_certificates: Option<String>,
+    pub client_certificate: Option<String>,
+    pub client_key: Option<String>,
+    pub default_headers: Option<BTreeMap<String, String>>,
+    pub user_agent: Option<String>,
+    pub max_redirects: Option<u32>,
+    pub max_idle_connections: Option<u32>,
+    pub max_idle_connections_per_host: Option<u32>,
+    pub max_response_size: Option<u32>,
+}
+
+// Idle connections are pooled by the agent, and clones share its pool
+#[derive(Clone, Debug)]
+pub struct HttpAgent {
+    pub config: HttpConfig,
+    agent: ureq::Agent,
+}
+
+impl HttpAgent {
+    pub fn new(config: HttpConfig) -> Result<Self, PluginError> {
+        let mut builder = ureq::AgentBuilder::new()
+            .redirects(config.max_redirects.unwrap_or(DEFAULT_MAX_REDIRECTS));
+
+        if let Some(proxy) = &config.proxy {
+            let proxy = ureq::Proxy::new(proxy)
+                .map_err(|e| PluginError::ModuleError(format!("Invalid proxy `{}`: {}", proxy, e)))?;
+            builder = builder.proxy(proxy);
+        }
+        if let Some(user_agent) = &config.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        if let Some(max) = config.max_idle_connections {
+            builder = builder.max_idle_connections(max as usize);
+        }
+        if let Some(max) = config.max_idle_connections_per_host {
+            builder = builder.max_idle_connections_per_host(max as usize);
+        }
+        if config.ca_certificates.is_some() || config.client_certificate.is_some() {
+            builder = builder.tls_config(Arc::new(tls_config(&config)?));
+        }
+
+        Ok(Self {
+            config,
+            agent: builder.build(),
+        })
+    }
+
+    pub fn request(&self, method: &str, url: &str) -> ureq::Request {
+        let mut request = self.agent.request(method, url);
+        if let Some(headers) = &self.config.default_headers {
+            for (name, value) in headers.iter() {
+                request = request.set(name, value);
+            }
+        }
+        request
+    }
+}
+
+impl Default for HttpAgent {
+    fn default() -> Self {
+        Self::new(HttpConfig::default()).unwrap()
+    }
+}
+
+fn tls_config(config: &HttpConfig) -> Result<ClientConfig, PluginError> {
+    let tls_error = |e: &dyn std::fmt::Display| PluginError::ModuleError(format!("Invalid TLS configuration: {}", e));
+
+    let mut roots = RootCertStore::empty();
+    match &config.ca_certificates {
+        Some(pem) => {
+            for certificate in CertificateDer::pem_slice_iter(pem.as_bytes()) {
+                roots.add(certificate.map_err(|e| tls_error(&e))?).map_err(|e| tls_error(&e))?;
+            }
+        }
+        None => roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned()),
+    }
+
+    let builder = ClientConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+        .with_safe_default_protocol_versions()
+        .map_err(|e| tls_error(&e))?
+        .with_root_certificates(roots);
+
+    match (&config.client_certificate, &config.client_key) {
+        (Some(certificate), Some(key)) => {
+            let chain = CertificateDer::pem_slice_iter(certificate.as_bytes())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| tls_error(&e))?;
+            let key = PrivateKeyDer::from_pem_slice(key.as_bytes()).map_err(|e| tls_error(&e))?;
+            builder.with_client_auth_cert(chain, key).map_err(|e| tls_error(&e))
+        }
+        (None, None) => Ok(builder.with_no_client_auth()),
+        _ => Err(tls_error(&"`clientCertificate` and `clientKey` must be set together")),
+    }
+}
@@ -1,63 +1,222 @@
-use std::sync::Arc;
+use std::{
+    io::Write,
+    sync::{mpsc, Arc},
+    thread,
+    time::Duration,
+};
 
-use mapping::{parse_request, parse_response};
-use polywrap_core::{invoke::Invoker};
+use config::{HttpAgent, HttpConfig};
+use mapping::{
+    parse_binary_response, parse_body, parse_request, parse_response, parse_response_head, stream_body,
+    RequestMethod,
+};
+use polywrap_core::{cancellation::CancellationToken, invoke::Invoker};
 use polywrap_plugin::error::PluginError;
 use polywrap_plugin_macro::{plugin_struct, plugin_impl};
-use wrap::{module::{Module, ArgsGet, ArgsPost}, types::ResponseType};
+use serde_json::Value;
+use wrap::{
+    module::{ArgsDelete, ArgsGet, ArgsGetBinary, ArgsHead, ArgsOptions, ArgsPatch, ArgsPost, ArgsPut, Module},
+    types::{BinaryResponse, Request, Response, ResponseType},
+};
+pub mod config;
 pub mod mapping;
 pub mod wrap;
 use crate::wrap::wrap_info::get_manifest;
 
+// How often a request in flight checks whether its invocation was cancelled
+const CANCELLATION_CHECK_INTERVAL: Duration = Duration::from_millis(10);
+
+// An agent given to the constructor takes precedence over the env's config
 #[plugin_struct]
 pub struct HttpPlugin {
+    pub agent: Option<HttpAgent>,
+    // Built from the env's config, and kept until the config changes
+    env_agent: Option<HttpAgent>,
 }
 
-#[plugin_impl]
-impl Module for HttpPlugin {
-    fn get(
-        &mut self,
-        args: &ArgsGet,
-        _: Arc<dyn Invoker>,
-    ) -> Result<Option<wrap::types::Response>, PluginError> {
-        let response = parse_request(&args.url, args.request.clone(), mapping::RequestMethod::GET)
-            .unwrap()
-            .call()
-            .map_err(|e| PluginError::ModuleError(e.to_string()))?;
-
-        let response_type = if let Some(r) = &args.request {
-            r.response_type
+impl HttpPlugin {
+    pub fn new(agent: Option<HttpAgent>) -> Self {
+        Self {
+            agent,
+            env_agent: None,
+            env: Value::Null,
+        }
+    }
+
+    fn get_agent(&mut self) -> Result<HttpAgent, PluginError> {
+        if let Some(agent) = &self.agent {
+            return Ok(agent.clone());
+        }
+
+        let config = if self.env.is_null() {
+            HttpConfig::default()
         } else {
-            ResponseType::TEXT
+            serde_json::from_value::<HttpConfig>(self.env.clone())?
         };
 
-        let parsed_response = parse_response(response, response_type)?;
+        match &self.env_agent {
+            Some(agent) if agent.config == config => Ok(agent.clone()),
+            _ => {
+                let agent = HttpAgent::new(config)?;
+                self.env_agent = Some(agent.clone());
+                Ok(agent)
+            }
+        }
+    }
 
-        Ok(Some(parsed_response))
+    fn send(
+        &self,
+        agent: &HttpAgent,
+        url: &str,
+        request: &Option<Request>,
+        method: RequestMethod,
+        invoker: &Arc<dyn Invoker>,
+    ) -> Result<ureq::Response, PluginError> {
+        let body = parse_body(request.as_ref())?;
+        let mut request_builder = parse_request(agent, url, request.clone(), method)?;
+        let token = invoker.get_cancellation_token();
+
+        // An invocation with a deadline, like a resolver extension's under a
+        // timeout, doesn't wait on the request past it
+        if let Some(remaining) = token.as_ref().and_then(|token| token.remaining()) {
+            let timeout = request
+                .as_ref()
+                .and_then(|request| request.timeout)
+                .map(|timeout| Duration::from_millis(timeout.into()).min(remaining))
+                .unwrap_or(remaining);
+            request_builder = request_builder.timeout(timeout);
+        }
+
+        let call = move || match body {
+            Some(body) => {
+                if let Some(content_type) = &body.content_type {
+                    request_builder = request_builder.set("Content-Type", content_type);
+                }
+                request_builder.send_bytes(&body.data)
+            }
+            None => request_builder.call(),
+        };
+        let response = match token {
+            Some(token) => call_cancellable(&token, call)?,
+            None => call(),
+        };
+
+        // Error statuses are still responses
+        match response {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => Ok(response),
+            Err(e) => Err(PluginError::ModuleError(e.to_string())),
+        }
     }
 
-    fn post(
+    fn send_text(
         &mut self,
-        args: &ArgsPost,
-        _: Arc<dyn Invoker>,
-    ) -> Result<Option<wrap::types::Response>, PluginError> {
-        let response = parse_request(
-            &args.url,
-            args.request.clone(),
-            mapping::RequestMethod::POST,
-        )
-        .unwrap()
-        .call()
-        .map_err(|e| PluginError::ModuleError(e.to_string()))?;
-
-        let response_type = if let Some(r) = &args.request {
+        url: &str,
+        request: &Option<Request>,
+        method: RequestMethod,
+        invoker: &Arc<dyn Invoker>,
+    ) -> Result<Option<Response>, PluginError> {
+        let agent = self.get_agent()?;
+        let response = self.send(&agent, url, request, method, invoker)?;
+
+        let response_type = if let Some(r) = request {
             r.response_type
         } else {
             ResponseType::TEXT
         };
 
-        let parsed_response = parse_response(response, response_type)?;
+        let token = invoker.get_cancellation_token();
+        let parsed_response =
+            parse_response(response, response_type, agent.config.max_response_size, token.as_ref())?;
 
         Ok(Some(parsed_response))
     }
+
+    // Host-side counterpart of `getBinary` for large bodies: the body is
+    // written to `writer` as it's received instead of being returned, so
+    // callers can stream it to a file or hash it without buffering it whole.
+    // The returned response has no body
+    pub fn stream_binary(
+        &mut self,
+        args: &ArgsGetBinary,
+        writer: &mut impl Write,
+        invoker: Arc<dyn Invoker>,
+    ) -> Result<BinaryResponse, PluginError> {
+        let agent = self.get_agent()?;
+        let response = self.send(&agent, &args.url, &args.request, RequestMethod::GET, &invoker)?;
+        let max_size = args.max_size.or(agent.config.max_response_size);
+
+        let head = parse_response_head(&response);
+        stream_body(response, max_size, writer, invoker.get_cancellation_token().as_ref())?;
+
+        Ok(head)
+    }
+}
+
+#[plugin_impl]
+impl Module for HttpPlugin {
+    fn get(&mut self, args: &ArgsGet, invoker: Arc<dyn Invoker>) -> Result<Option<Response>, PluginError> {
+        self.send_text(&args.url, &args.request, RequestMethod::GET, &invoker)
+    }
+
+    fn post(&mut self, args: &ArgsPost, invoker: Arc<dyn Invoker>) -> Result<Option<Response>, PluginError> {
+        self.send_text(&args.url, &args.request, RequestMethod::POST, &invoker)
+    }
+
+    fn put(&mut self, args: &ArgsPut, invoker: Arc<dyn Invoker>) -> Result<Option<Response>, PluginError> {
+        self.send_text(&args.url, &args.request, RequestMethod::PUT, &invoker)
+    }
+
+    fn patch(&mut self, args: &ArgsPatch, invoker: Arc<dyn Invoker>) -> Result<Option<Response>, PluginError> {
+        self.send_text(&args.url, &args.request, RequestMethod::PATCH, &invoker)
+    }
+
+    fn delete(&mut self, args: &ArgsDelete, invoker: Arc<dyn Invoker>) -> Result<Option<Response>, PluginError> {
+        self.send_text(&args.url, &args.request, RequestMethod::DELETE, &invoker)
+    }
+
+    fn head(&mut self, args: &ArgsHead, invoker: Arc<dyn Invoker>) -> Result<Option<Response>, PluginError> {
+        self.send_text(&args.url, &args.request, RequestMethod::HEAD, &invoker)
+    }
+
+    fn options(&mut self, args: &ArgsOptions, invoker: Arc<dyn Invoker>) -> Result<Option<Response>, PluginError> {
+        self.send_text(&args.url, &args.request, RequestMethod::OPTIONS, &invoker)
+    }
+
+    fn get_binary(
+        &mut self,
+        args: &ArgsGetBinary,
+        invoker: Arc<dyn Invoker>,
+    ) -> Result<Option<BinaryResponse>, PluginError> {
+        let agent = self.get_agent()?;
+        let response = self.send(&agent, &args.url, &args.request, RequestMethod::GET, &invoker)?;
+        let max_size = args.max_size.or(agent.config.max_response_size);
+
+        let token = invoker.get_cancellation_token();
+        Ok(Some(parse_binary_response(response, max_size, token.as_ref())?))
+    }
+}
+
+// ureq can't interrupt a request, so it's sent from another thread, and left
+// to finish there if the invocation is cancelled before the response arrives
+fn call_cancellable<T: Send + 'static>(
+    token: &CancellationToken,
+    call: impl FnOnce() -> T + Send + 'static,
+) -> Result<T, PluginError> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = sender.send(call());
+    });
+
+    loop {
+        match receiver.recv_timeout(CANCELLATION_CHECK_INTERVAL) {
+            Ok(result) => return Ok(result),
+            Err(mpsc::RecvTimeoutError::Timeout) if !token.is_cancelled() => {}
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                return Err(PluginError::ModuleError("Request was cancelled".to_string()))
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                return Err(PluginError::ModuleError("Request thread panicked".to_string()))
+            }
+        }
+    }
 }
@@ -1,17 +1,89 @@
-use crate::wrap::types::{Request, Response, ResponseType};
+use crate::config::HttpAgent;
+use crate::wrap::types::{BinaryResponse, BodyType, FormDataEntry, Request, Response, ResponseType};
+use polywrap_core::cancellation::CancellationToken;
 use polywrap_msgpack::extensions::generic_map::GenericMap;
 use polywrap_plugin::error::PluginError;
-use std::collections::BTreeMap;
+use std::{
+    collections::BTreeMap,
+    io::{self, Read, Write},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 pub enum RequestMethod {
     GET,
     POST,
+    PUT,
+    PATCH,
+    DELETE,
+    HEAD,
+    OPTIONS,
 }
 
+impl RequestMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RequestMethod::GET => "GET",
+            RequestMethod::POST => "POST",
+            RequestMethod::PUT => "PUT",
+            RequestMethod::PATCH => "PATCH",
+            RequestMethod::DELETE => "DELETE",
+            RequestMethod::HEAD => "HEAD",
+            RequestMethod::OPTIONS => "OPTIONS",
+        }
+    }
+}
+
+pub struct RequestBody {
+    pub data: Vec<u8>,
+    pub content_type: Option<String>,
+}
+
+// Bodies are only preallocated up to this size, whatever their `Content-Length`
+const MAX_PREALLOCATED_BODY: u64 = 16 * 1024 * 1024;
+
 pub fn parse_response(
     response: ureq::Response,
     encoding: ResponseType,
+    max_size: Option<u32>,
+    token: Option<&CancellationToken>,
 ) -> Result<Response, PluginError> {
+    let response = parse_binary_response(response, max_size, token)?;
+    let body = response.body.unwrap_or_default();
+
+    let data = match encoding {
+        ResponseType::BINARY => base64::encode(body),
+        _ => String::from_utf8(body)
+            .unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned()),
+    };
+
+    Ok(Response {
+        status: response.status,
+        status_text: response.status_text,
+        headers: response.headers,
+        body: Some(data),
+    })
+}
+
+pub fn parse_binary_response(
+    response: ureq::Response,
+    max_size: Option<u32>,
+    token: Option<&CancellationToken>,
+) -> Result<BinaryResponse, PluginError> {
+    let mut binary_response = parse_response_head(&response);
+
+    let content_length = response
+        .header("content-length")
+        .and_then(|length| length.parse::<u64>().ok());
+    let capacity = content_length.unwrap_or(0).min(MAX_PREALLOCATED_BODY);
+    let mut body = Vec::with_capacity(capacity as usize);
+    stream_body(response, max_size, &mut body, token)?;
+
+    binary_response.body = Some(body);
+    Ok(binary_response)
+}
+
+// The status and headers of a response, without its body
+pub fn parse_response_head(response: &ureq::Response) -> BinaryResponse {
     let headers = response
         .headers_names()
         .iter()
@@ -22,37 +94,73 @@ pub fn parse_response(
             )
         })
         .collect::<BTreeMap<String, String>>();
-    let headers = GenericMap(headers);
-
-    let status = response.status();
-    let status_text = response.status_text().to_string();
 
-    let mut reader = response.into_reader();
-    let mut data = vec![];
-    reader.read_to_end(&mut data).unwrap();
+    BinaryResponse {
+        status: response.status().into(),
+        status_text: response.status_text().to_string(),
+        headers: Some(GenericMap(headers)),
+        body: None,
+    }
+}
 
-    let data = match encoding {
-        ResponseType::BINARY => base64::encode(data),
-        _ => String::from_utf8_lossy(&data).to_string(),
+// Copies the body to `writer` as it's received, so it's never held whole by
+// this function, and fails as soon as it exceeds `max_size`, whether or not
+// the server sent a `Content-Length`. On failure, `writer` may have received
+// part of the body. Returns the size of the body
+// Stops reading once `token` is cancelled
+pub fn stream_body(
+    response: ureq::Response,
+    max_size: Option<u32>,
+    writer: &mut impl Write,
+    token: Option<&CancellationToken>,
+) -> Result<u64, PluginError> {
+    let too_large = |max: u32| {
+        PluginError::ModuleError(format!("Response body exceeds the max size of {} bytes", max))
     };
+    let content_length = response
+        .header("content-length")
+        .and_then(|length| length.parse::<u64>().ok());
+    if let (Some(max), Some(length)) = (max_size, content_length) {
+        if length > max.into() {
+            return Err(too_large(max));
+        }
+    }
 
-    Ok(Response {
-        status: status.into(),
-        status_text,
-        headers: Some(headers),
-        body: Some(data),
-    })
+    // Reads at most one byte past the limit, to tell if the body exceeds it
+    let limit = max_size.map_or(u64::MAX, |max| u64::from(max) + 1);
+    let mut reader = CancellableReader { reader: response.into_reader(), token };
+    let size = io::copy(&mut (&mut reader).take(limit), writer)
+        .map_err(|e| PluginError::ModuleError(e.to_string()))?;
+    if let Some(max) = max_size {
+        if size > max.into() {
+            return Err(too_large(max));
+        }
+    }
+
+    Ok(size)
+}
+
+struct CancellableReader<'a, R> {
+    reader: R,
+    token: Option<&'a CancellationToken>,
+}
+
+impl<R: Read> Read for CancellableReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.token.is_some_and(|token| token.is_cancelled()) {
+            return Err(io::Error::new(io::ErrorKind::Other, "Request was cancelled"));
+        }
+        self.reader.read(buf)
+    }
 }
 
 pub fn parse_request(
+    agent: &HttpAgent,
     url: &str,
     request: Option<Request>,
     method: RequestMethod,
 ) -> Result<ureq::Request, PluginError> {
-    let mut request_builder = match method {
-        RequestMethod::GET => ureq::get(url),
-        RequestMethod::POST => ureq::post(url),
-    };
+    let mut request_builder = agent.request(method.as_str(), url);
 
     if let Some(request) = request {
         if let Some(url_params) = request.url_params {
@@ -66,9 +174,86 @@ pub fn parse_request(
                 request_builder = request_builder.set(name, value)
             }
         }
-    }
 
-    println!("BUILDER {:?}", request_builder);
+        if let Some(timeout) = request.timeout {
+            request_builder = request_builder.timeout(Duration::from_millis(timeout.into()));
+        }
+    }
 
     Ok(request_builder)
 }
+
+// `body` takes precedence over `formData`
+pub fn parse_body(request: Option<&Request>) -> Result<Option<RequestBody>, PluginError> {
+    let request = match request {
+        Some(request) => request,
+        None => return Ok(None),
+    };
+
+    if let Some(body) = &request.body {
+        let data = match request.body_type {
+            Some(BodyType::BINARY) => decode_base64(body, "body")?,
+            _ => body.clone().into_bytes(),
+        };
+        return Ok(Some(RequestBody { data, content_type: None }));
+    }
+
+    match &request.form_data {
+        Some(form_data) => Ok(Some(parse_form_data(form_data)?)),
+        None => Ok(None),
+    }
+}
+
+fn parse_form_data(entries: &[FormDataEntry]) -> Result<RequestBody, PluginError> {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_nanos());
+    let boundary = format!("----PolywrapFormBoundary{:x}", nanos);
+
+    let mut data = vec![];
+    for entry in entries {
+        let mut disposition = format!("form-data; name=\"{}\"", escape_disposition_param(&entry.name));
+        if let Some(file_name) = &entry.file_name {
+            disposition.push_str(&format!("; filename=\"{}\"", escape_disposition_param(file_name)));
+        }
+
+        data.extend(format!("--{}\r\nContent-Disposition: {}\r\n", boundary, disposition).into_bytes());
+        // Values with a MIME type are base64 encoded bytes
+        let value = match (&entry._type, &entry.value) {
+            (Some(_type), Some(value)) => {
+                if _type.contains(['\r', '\n']) {
+                    return Err(PluginError::ModuleError(format!(
+                        "The type of form data entry `{}` can't contain line breaks",
+                        entry.name
+                    )));
+                }
+                data.extend(format!("Content-Type: {}\r\n", _type).into_bytes());
+                decode_base64(value, &entry.name)?
+            }
+            (_, value) => value.clone().unwrap_or_default().into_bytes(),
+        };
+        data.extend(b"\r\n");
+        data.extend(value);
+        data.extend(b"\r\n");
+    }
+    data.extend(format!("--{}--\r\n", boundary).into_bytes());
+
+    Ok(RequestBody {
+        data,
+        content_type: Some(format!("multipart/form-data; boundary={}", boundary)),
+    })
+}
+
+// Percent-encodes the characters that could end the quoted parameter or the
+// header, the way browsers encode form data names
+fn escape_disposition_param(value: &str) -> String {
+    value
+        .replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn decode_base64(value: &str, name: &str) -> Result<Vec<u8>, PluginError> {
+    base64::decode(value)
+        .map_err(|e| PluginError::ModuleError(format!("`{}` is not valid base64: {}", name, e)))
+}
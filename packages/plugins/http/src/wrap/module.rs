@@ -21,8 +21,58 @@ pub struct ArgsPost {
     pub request: Option<Request>,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ArgsPut {
+    pub url: String,
+    pub request: Option<Request>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ArgsPatch {
+    pub url: String,
+    pub request: Option<Request>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ArgsDelete {
+    pub url: String,
+    pub request: Option<Request>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ArgsHead {
+    pub url: String,
+    pub request: Option<Request>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ArgsOptions {
+    pub url: String,
+    pub request: Option<Request>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ArgsGetBinary {
+    pub url: String,
+    pub request: Option<Request>,
+    #[serde(rename = "maxSize", alias = "max_size")]
+    pub max_size: Option<u32>,
+}
+
 pub trait Module: PluginModule {
   fn get(&mut self, args: &ArgsGet, invoker: Arc<dyn Invoker>) -> Result<Option<Response>, PluginError>;
 
   fn post(&mut self, args: &ArgsPost, invoker: Arc<dyn Invoker>) -> Result<Option<Response>, PluginError>;
+
+  fn put(&mut self, args: &ArgsPut, invoker: Arc<dyn Invoker>) -> Result<Option<Response>, PluginError>;
+
+  fn patch(&mut self, args: &ArgsPatch, invoker: Arc<dyn Invoker>) -> Result<Option<Response>, PluginError>;
+
+  fn delete(&mut self, args: &ArgsDelete, invoker: Arc<dyn Invoker>) -> Result<Option<Response>, PluginError>;
+
+  fn head(&mut self, args: &ArgsHead, invoker: Arc<dyn Invoker>) -> Result<Option<Response>, PluginError>;
+
+  fn options(&mut self, args: &ArgsOptions, invoker: Arc<dyn Invoker>) -> Result<Option<Response>, PluginError>;
+
+  fn get_binary(&mut self, args: &ArgsGetBinary, invoker: Arc<dyn Invoker>) -> Result<Option<BinaryResponse>, PluginError>;
 }
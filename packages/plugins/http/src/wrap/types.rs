@@ -18,16 +18,29 @@ use serde_json as JSON;
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Response {
     pub status: i32,
+    #[serde(rename = "statusText")]
     pub status_text: String,
     pub headers: Option<GenericMap<String, String>>,
     pub body: Option<String>,
 }
 #[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BinaryResponse {
+    pub status: i32,
+    #[serde(rename = "statusText")]
+    pub status_text: String,
+    pub headers: Option<GenericMap<String, String>>,
+    #[serde(with = "serde_bytes")]
+    pub body: Option<Vec<u8>>,
+}
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Request {
     pub headers: Option<GenericMap<String, String>>,
     pub url_params: Option<GenericMap<String, String>>,
     pub response_type: ResponseType,
     pub body: Option<String>,
+    // Plugins decode their args with snake_cased keys
+    #[serde(rename = "bodyType", alias = "body_type")]
+    pub body_type: Option<BodyType>,
     pub form_data: Option<Vec<FormDataEntry>>,
     pub timeout: Option<u32>,
 }
@@ -49,6 +62,12 @@ pub enum ResponseType {
     BINARY,
     _MAX_
 }
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub enum BodyType {
+    TEXT,
+    BINARY,
+    _MAX_
+}
 // Enums END //
 
 // Imported objects START //
@@ -1,17 +1,23 @@
 use std::sync::{Arc, Mutex};
-use http_plugin::HttpPlugin;
+use std::{thread, time::{Duration, Instant}};
+use std::collections::BTreeMap;
+use http_plugin::{
+    wrap::{module::{ArgsGet, ArgsGetBinary, ArgsPost}, types::{BinaryResponse, Request, Response, ResponseType}},
+    HttpPlugin,
+};
 use httpmock::{prelude::*, Method};
 use polywrap_client::client::PolywrapClient;
 use polywrap_core::resolvers::static_resolver::{StaticResolver, StaticResolverLike};
 use polywrap_core::{
-    client::ClientConfig, invoke::Invoker, resolvers::uri_resolution_context::UriPackage, uri::Uri,
+    cancellation::CancellationToken, client::ClientConfig, error::Error, invoke::Invoker,
+    resolvers::uri_resolution_context::UriPackage, uri::Uri,
 };
-use polywrap_msgpack::{msgpack};
+use polywrap_msgpack::{extensions::generic_map::GenericMap, msgpack, serialize};
 use polywrap_plugin::package::PluginPackage;
-use serde_json::{json, Value};
+use serde_json::json;
 
 fn get_client() -> PolywrapClient {
-    let http_plugin = HttpPlugin { env: Value::Null };
+    let http_plugin = HttpPlugin::new(None);
     let plugin_pkg: PluginPackage = http_plugin.into();
     let package = Arc::new(Mutex::new(plugin_pkg));
 
@@ -22,65 +28,327 @@ fn get_client() -> PolywrapClient {
 
     PolywrapClient::new(ClientConfig {
         resolver: Arc::new(resolver),
-        interfaces: None,
-        envs: None,
+        ..Default::default()
     })
 }
 
+fn request(headers: &[(&str, &str)], url_params: &[(&str, &str)], body: Option<&str>) -> Request {
+    let to_map = |entries: &[(&str, &str)]| {
+        GenericMap(entries.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect::<BTreeMap<_, _>>())
+    };
+
+    Request {
+        headers: Some(to_map(headers)),
+        url_params: Some(to_map(url_params)),
+        response_type: ResponseType::TEXT,
+        body: body.map(String::from),
+        body_type: None,
+        form_data: None,
+        timeout: None,
+    }
+}
+
+fn invoke(method: &str, args: &[u8]) -> Result<Response, Error> {
+    get_client().invoke::<Response>(
+        &Uri::try_from("wrap://ens/http.polywrap.eth").unwrap(),
+        method,
+        Some(args),
+        None,
+        None,
+    )
+}
+
 #[test]
 fn get_method() {
     let server = MockServer::start();
-    let _mock = server.mock(|when, then| {
+    let mock = server.mock(|when, then| {
         when.method(Method::GET)
             .path("/api")
-            .header("access-control-allow-origin", "*")
-            .header("access-control-allow-credentials", "true");
+            .query_param("page", "2")
+            .header("x-custom-header", "value");
 
         then.status(200).json_body(json!({"data": "test-response"}));
     });
 
-    let response = get_client()
-        .invoke_raw(
-            &Uri::try_from("wrap://ens/http.polywrap.eth").unwrap(),
-            "get",
-            Some(&msgpack!({
-                "url": "https://yesno.wtf/api",
-            })),
-            None,
-            None,
-        )
-        .unwrap();
+    let args = ArgsGet {
+        url: server.url("/api"),
+        request: Some(request(&[("X-Custom-Header", "value")], &[("page", "2")], None)),
+    };
+    let response = invoke("get", &serialize(&args).unwrap()).unwrap();
 
-    println!("{:#?}", response)
+    mock.assert();
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body.unwrap(), "{\"data\":\"test-response\"}");
 }
 
 #[test]
 fn post_method() {
     let server = MockServer::start();
-    let _mock = server.mock(|when, then| {
+    let mock = server.mock(|when, then| {
         when.method(Method::POST)
             .path("/api")
-            .header("access-control-allow-origin", "*")
-            .header("access-control-allow-credentials", "true");
+            .header("content-type", "application/json")
+            .body("{\"id\": \"some\", \"value\": 123}");
 
-        then.status(200).json_body(json!({"data": "test-response"}));
+        then.status(201).body("created");
+    });
+
+    let args = ArgsPost {
+        url: server.url("/api"),
+        request: Some(request(
+            &[("Content-Type", "application/json")],
+            &[],
+            Some("{\"id\": \"some\", \"value\": 123}"),
+        )),
+    };
+    let response = invoke("post", &serialize(&args).unwrap()).unwrap();
+
+    mock.assert();
+    assert_eq!(response.status, 201);
+    assert_eq!(response.body.unwrap(), "created");
+}
+
+#[test]
+fn supports_all_methods() {
+    let server = MockServer::start();
+    let methods = [
+        ("put", Method::PUT),
+        ("patch", Method::PATCH),
+        ("delete", Method::DELETE),
+        ("head", Method::HEAD),
+        ("options", Method::OPTIONS),
+    ];
+
+    for (name, method) in methods {
+        let mock = server.mock(|when, then| {
+            when.method(method).path("/resource");
+            then.status(204);
+        });
+
+        let response = invoke(name, &msgpack!({
+            "url": server.url("/resource"),
+            "request": { "responseType": "TEXT", "body": "payload" }
+        })).unwrap();
+
+        mock.assert();
+        assert_eq!(response.status, 204);
+    }
+}
+
+#[test]
+fn returns_error_statuses_as_responses() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(Method::GET).path("/missing");
+        then.status(404).body("not found");
+    });
+
+    let response = invoke("get", &msgpack!({ "url": server.url("/missing") })).unwrap();
+    assert_eq!(response.status, 404);
+    assert_eq!(response.body.unwrap(), "not found");
+}
+
+fn has_binary_body(request: &HttpMockRequest) -> bool {
+    request.body.as_deref() == Some(&[0, 159, 146, 150, 255][..])
+}
+
+#[test]
+fn sends_binary_bodies() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(Method::PUT).path("/upload").matches(has_binary_body);
+        then.status(200).body([1, 2, 3]);
     });
 
-    let response = get_client()
+    let response = invoke("put", &msgpack!({
+        "url": server.url("/upload"),
+        "request": {
+            "responseType": "BINARY",
+            "body": base64::encode([0, 159, 146, 150, 255]),
+            "bodyType": "BINARY"
+        }
+    })).unwrap();
+
+    mock.assert();
+    assert_eq!(response.body.unwrap(), base64::encode([1, 2, 3]));
+}
+
+#[test]
+fn sends_form_data() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(Method::POST)
+            .path("/form")
+            .header_exists("content-type")
+            .body_contains("Content-Disposition: form-data; name=\"field\"\r\n\r\nvalue\r\n")
+            .body_contains("Content-Disposition: form-data; name=\"file\"; filename=\"a.txt\"\r\nContent-Type: text/plain\r\n\r\nfile contents\r\n");
+        then.status(200);
+    });
+
+    let response = invoke("post", &msgpack!({
+        "url": server.url("/form"),
+        "request": {
+            "responseType": "TEXT",
+            "formData": [
+                { "name": "field", "value": "value" },
+                { "name": "file", "value": base64::encode("file contents"), "fileName": "a.txt", "type": "text/plain" }
+            ]
+        }
+    })).unwrap();
+
+    mock.assert();
+    assert_eq!(response.status, 200);
+}
+
+#[test]
+fn escapes_form_data_names() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(Method::POST)
+            .path("/form")
+            .body_contains("Content-Disposition: form-data; name=\"a%22%0D%0AX-Injected: 1\"; filename=\"b%22.txt\"\r\n\r\nvalue\r\n");
+        then.status(200);
+    });
+
+    let response = invoke("post", &msgpack!({
+        "url": server.url("/form"),
+        "request": {
+            "responseType": "TEXT",
+            "formData": [
+                { "name": "a\"\r\nX-Injected: 1", "value": "value", "fileName": "b\".txt" }
+            ]
+        }
+    })).unwrap();
+    mock.assert();
+    assert_eq!(response.status, 200);
+
+    let error = invoke("post", &msgpack!({
+        "url": server.url("/form"),
+        "request": {
+            "responseType": "TEXT",
+            "formData": [
+                { "name": "file", "value": base64::encode("data"), "type": "text/plain\r\nX-Injected: 1" }
+            ]
+        }
+    })).unwrap_err();
+    assert!(error.to_string().contains("can't contain line breaks"));
+}
+
+#[test]
+fn honors_timeouts() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(Method::GET).path("/slow");
+        then.status(200).delay(Duration::from_millis(500));
+    });
+
+    let error = invoke("get", &msgpack!({
+        "url": server.url("/slow"),
+        "request": { "responseType": "TEXT", "timeout": 50 }
+    })).unwrap_err();
+    assert!(error.to_string().to_lowercase().contains("timed out"));
+}
+
+#[test]
+fn cancels_requests_in_flight() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(Method::GET).path("/slow");
+        then.status(200).delay(Duration::from_secs(5));
+    });
+
+    let token = CancellationToken::new();
+    let canceller = {
+        let token = token.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(100));
+            token.cancel();
+        })
+    };
+
+    let started = Instant::now();
+    let error = get_client()
+        .invoke_with_cancellation::<Response>(
+            &Uri::try_from("wrap://ens/http.polywrap.eth").unwrap(),
+            "get",
+            Some(&msgpack!({ "url": server.url("/slow"), "request": { "responseType": "TEXT" } })),
+            None,
+            None,
+            &token,
+        )
+        .unwrap_err();
+    canceller.join().unwrap();
+    assert!(matches!(error, Error::CancelledError(_)));
+    assert!(started.elapsed() < Duration::from_secs(2));
+}
+
+#[test]
+fn gets_raw_binary_bodies() {
+    let server = MockServer::start();
+    let body: Vec<u8> = (0..1024 * 1024).map(|i| (i % 251) as u8).collect();
+    server.mock(|when, then| {
+        when.method(Method::GET).path("/wrap.wasm");
+        then.status(200).body(&body);
+    });
+
+    let result = get_client()
         .invoke_raw(
             &Uri::try_from("wrap://ens/http.polywrap.eth").unwrap(),
-            "post",
-            Some(&msgpack!({
-                "url": "https://d3y5v.mocklab.io/json",
-                "request": {
-                    "responseType": "TEXT",
-                    "body": "{\"id\": \"some\", \"value\": 123}"
-                }
-            })),
+            "getBinary",
+            Some(&msgpack!({ "url": server.url("/wrap.wasm") })),
             None,
             None,
         )
         .unwrap();
+    // Encoded as msgpack binary, rather than as a base64 string or an array of numbers
+    assert!(result.len() < body.len() + 1024);
+
+    let response: BinaryResponse = polywrap_msgpack::decode(&result).unwrap();
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body.unwrap(), body);
+}
+
+#[test]
+fn limits_binary_body_size() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(Method::GET).path("/wrap.wasm");
+        then.status(200).body([0; 100]);
+    });
+
+    let error = get_client()
+        .invoke::<BinaryResponse>(
+            &Uri::try_from("wrap://ens/http.polywrap.eth").unwrap(),
+            "getBinary",
+            Some(&msgpack!({ "url": server.url("/wrap.wasm"), "maxSize": 99 })),
+            None,
+            None,
+        )
+        .unwrap_err();
+    assert!(error.to_string().contains("exceeds the max size of 99 bytes"));
+}
+
+#[test]
+fn streams_binary_bodies_to_a_writer() {
+    let server = MockServer::start();
+    let body: Vec<u8> = (0..1024 * 1024).map(|i| (i % 251) as u8).collect();
+    server.mock(|when, then| {
+        when.method(Method::GET).path("/wrap.wasm");
+        then.status(200).header("ETag", "\"v1\"").body(&body);
+    });
+
+    let args = |max_size| ArgsGetBinary { url: server.url("/wrap.wasm"), request: None, max_size };
+    let mut plugin = HttpPlugin::new(None);
+
+    let mut streamed = vec![];
+    let response = plugin.stream_binary(&args(None), &mut streamed, Arc::new(get_client())).unwrap();
+    assert_eq!(response.status, 200);
+    assert_eq!(response.headers.unwrap().0.get("etag").unwrap(), "\"v1\"");
+    assert!(response.body.is_none());
+    assert_eq!(streamed, body);
 
-    println!("{:#?}", response)
+    let error = plugin
+        .stream_binary(&args(Some(1024)), &mut vec![], Arc::new(get_client()))
+        .unwrap_err();
+    assert!(error.to_string().contains("exceeds the max size of 1024 bytes"));
 }
@@ -0,0 +1,125 @@
+use std::sync::{Arc, Mutex};
+use http_plugin::{config::{HttpAgent, HttpConfig}, wrap::types::Response, HttpPlugin};
+use httpmock::{prelude::*, Method};
+use polywrap_client::client::PolywrapClient;
+use polywrap_core::resolvers::static_resolver::{StaticResolver, StaticResolverLike};
+use polywrap_core::{
+    client::ClientConfig, env::Env, error::Error, resolvers::uri_resolution_context::UriPackage, uri::Uri,
+};
+use polywrap_msgpack::{msgpack};
+use polywrap_plugin::package::PluginPackage;
+use serde_json::json;
+
+fn get_client(agent: Option<HttpAgent>) -> PolywrapClient {
+    let http_plugin = HttpPlugin::new(agent);
+    let plugin_pkg: PluginPackage = http_plugin.into();
+    let package = Arc::new(Mutex::new(plugin_pkg));
+
+    let resolver = StaticResolver::from(vec![StaticResolverLike::Package(UriPackage {
+        uri: Uri::try_from("wrap://ens/http.polywrap.eth").unwrap(),
+        package,
+    })]);
+
+    PolywrapClient::new(ClientConfig {
+        resolver: Arc::new(resolver),
+        ..Default::default()
+    })
+}
+
+fn get(client: &PolywrapClient, url: &str, env: Option<Env>) -> Result<Response, Error> {
+    client.invoke::<Response>(
+        &Uri::try_from("wrap://ens/http.polywrap.eth").unwrap(),
+        "get",
+        Some(&msgpack!({ "url": url })),
+        env,
+        None,
+    )
+}
+
+#[test]
+fn sends_default_headers_and_user_agent_from_env() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(Method::GET)
+            .path("/api")
+            .header("x-api-key", "secret")
+            .header("user-agent", "polywrap-test");
+        then.status(200);
+    });
+
+    let env = json!({
+        "defaultHeaders": { "X-Api-Key": "secret" },
+        "userAgent": "polywrap-test"
+    });
+    let response = get(&get_client(None), &server.url("/api"), Some(env)).unwrap();
+
+    mock.assert();
+    assert_eq!(response.status, 200);
+}
+
+#[test]
+fn follows_redirect_policy() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(Method::GET).path("/old");
+        then.status(302).header("location", "/new");
+    });
+    server.mock(|when, then| {
+        when.method(Method::GET).path("/new");
+        then.status(200).body("moved");
+    });
+
+    let response = get(&get_client(None), &server.url("/old"), None).unwrap();
+    assert_eq!(response.body.unwrap(), "moved");
+
+    let agent = HttpAgent::new(HttpConfig { max_redirects: Some(0), ..Default::default() }).unwrap();
+    let response = get(&get_client(Some(agent)), &server.url("/old"), None).unwrap();
+    assert_eq!(response.status, 302);
+}
+
+#[test]
+fn limits_response_size() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(Method::GET).path("/large");
+        then.status(200).body("0123456789");
+    });
+
+    let agent = HttpAgent::new(HttpConfig { max_response_size: Some(8), ..Default::default() }).unwrap();
+    let error = get(&get_client(Some(agent)), &server.url("/large"), None).unwrap_err();
+    assert!(error.to_string().contains("exceeds the max size of 8 bytes"));
+
+    let agent = HttpAgent::new(HttpConfig { max_response_size: Some(10), ..Default::default() }).unwrap();
+    let response = get(&get_client(Some(agent)), &server.url("/large"), None).unwrap();
+    assert_eq!(response.body.unwrap(), "0123456789");
+}
+
+#[test]
+fn sends_requests_through_proxy() {
+    let proxy = MockServer::start();
+    let mock = proxy.mock(|when, then| {
+        when.method(Method::GET).path("/through-proxy");
+        then.status(200).body("proxied");
+    });
+
+    let agent = HttpAgent::new(HttpConfig { proxy: Some(proxy.base_url()), ..Default::default() }).unwrap();
+    let response = get(&get_client(Some(agent)), "http://upstream.invalid/through-proxy", None).unwrap();
+
+    mock.assert();
+    assert_eq!(response.body.unwrap(), "proxied");
+}
+
+#[test]
+fn rejects_invalid_tls_config() {
+    let error = HttpAgent::new(HttpConfig {
+        client_certificate: Some("-----BEGIN CERTIFICATE-----".to_string()),
+        ..Default::default()
+    }).unwrap_err();
+    assert!(error.to_string().contains("must be set together"));
+
+    let error = HttpAgent::new(HttpConfig {
+        ca_certificates: Some("-----BEGIN CERTIFICATE-----\nnot base64\n-----END CERTIFICATE-----".to_string()),
+        ..Default::default()
+    }).unwrap_err();
+    assert!(error.to_string().contains("Invalid TLS configuration"));
+}
@@ -1,5 +1,5 @@
 use core::fmt;
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc, time::Duration};
 
 
 use polywrap_core::{
@@ -12,19 +12,55 @@ use polywrap_core::{
         uri_resolver::UriResolver
     },
     uri::Uri, 
+    integrity::IntegrityPolicy,
     loader::Loader,
     error::Error
 };
 
 use crate::uri_resolver_wrapper::UriResolverWrapper;
 
+// By default, the first extension that fails ends the resolution with its
+// error. With `continue_on_error`, the next extensions are tried, and if none
+// of them resolves the URI, the error lists every failure
+#[derive(Clone, Debug, Default)]
+pub struct ExtensionOptions {
+    pub continue_on_error: bool,
+    // Only interrupts extensions that check the cancellation token, see
+    // `invoke_with_timeout`
+    pub timeout: Option<Duration>,
+    // Overrides `timeout` for the extensions with these URIs
+    pub timeouts: HashMap<String, Duration>,
+}
+
+impl ExtensionOptions {
+    pub fn timeout_for(&self, extension_uri: &Uri) -> Option<Duration> {
+        self.timeouts.get(&extension_uri.to_string()).copied().or(self.timeout)
+    }
+}
+
 pub struct ExtendableUriResolver {
-    name: Option<String>
+    name: Option<String>,
+    integrity: IntegrityPolicy,
+    options: ExtensionOptions,
 }
 
 impl ExtendableUriResolver {
     pub fn new(name: Option<String>) -> Self {
-        ExtendableUriResolver { name }
+        ExtendableUriResolver {
+            name,
+            integrity: IntegrityPolicy::default(),
+            options: ExtensionOptions::default(),
+        }
+    }
+
+    pub fn with_integrity(mut self, integrity: IntegrityPolicy) -> Self {
+        self.integrity = integrity;
+        self
+    }
+
+    pub fn with_options(mut self, options: ExtensionOptions) -> Self {
+        self.options = options;
+        self
     }
 }
 
@@ -33,6 +69,10 @@ impl UriResolverAggregatorBase for ExtendableUriResolver {
         self.name.clone()
     }
 
+    fn continue_on_error(&self) -> bool {
+        self.options.continue_on_error
+    }
+
     fn get_uri_resolvers(
         &self,
         _: &Uri,
@@ -46,7 +86,12 @@ impl UriResolverAggregatorBase for ExtendableUriResolver {
 
         let resolvers = implementations.into_iter().filter_map(|implementation| {
             if !resolution_context.is_resolving(&implementation) {
-                let wrapper = Arc::new(UriResolverWrapper::new(implementation));
+                let timeout = self.options.timeout_for(&implementation);
+                let wrapper = Arc::new(
+                    UriResolverWrapper::new(implementation)
+                        .with_integrity(self.integrity.clone())
+                        .with_timeout(timeout)
+                );
                 return Some(wrapper as Arc<dyn UriResolver>);
             }
 
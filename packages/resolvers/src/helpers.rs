@@ -0,0 +1,14 @@
+use std::sync::{Arc, Mutex};
+
+use polywrap_core::{error::Error, wrapper::{GetFileOptions, Wrapper}};
+
+pub use polywrap_core::integrity::sha256;
+
+// Returns the `wrap.info` and `wrap.wasm` of a wasm wrapper. Plugin wrappers
+// have no files, so this fails for them
+pub fn get_wrap_files(wrapper: &Arc<Mutex<dyn Wrapper>>) -> Result<(Vec<u8>, Vec<u8>), Error> {
+    let wrapper = wrapper.lock().unwrap();
+    let read = |path: &str| wrapper.get_file(&GetFileOptions { path: path.to_string(), encoding: None });
+
+    Ok((read("wrap.info")?, read("wrap.wasm")?))
+}
@@ -1,2 +1,7 @@
 pub mod uri_resolver_wrapper;
-pub mod extendable_uri_resolver;
\ No newline at end of file
+pub mod extendable_uri_resolver;
+pub mod helpers;
+pub mod lockfile;
+pub mod lockfile_resolver;
+pub mod wrapper_cache;
+pub mod wrapper_cache_resolver;
\ No newline at end of file
@@ -0,0 +1,66 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use polywrap_core::uri::Uri;
+use serde::{Deserialize, Serialize};
+
+// Plugins have no files, so their entries only pin the final URI and the
+// redirects leading to it
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LockEntry {
+    pub uri: String,
+    // The URIs redirected from before reaching `uri`, in order, starting with
+    // the URI the entry is locked under
+    pub redirects: Vec<String>,
+    pub manifest_sha256: Option<String>,
+    pub wasm_sha256: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LockfileMode {
+    // Every resolved URI is added to the lockfile
+    Record,
+    // Only URIs in the lockfile are resolved, through their locked redirects
+    // to their locked URI and content
+    Strict,
+}
+
+// Entries are shared between clones of the lockfile, so that the one given
+// to the builder can be saved once the client is done resolving
+#[derive(Clone, Debug, Default)]
+pub struct Lockfile {
+    entries: Arc<Mutex<BTreeMap<String, LockEntry>>>,
+}
+
+impl Lockfile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let entries: BTreeMap<String, LockEntry> = serde_json::from_slice(&fs::read(path)?)?;
+        Ok(Self { entries: Arc::new(Mutex::new(entries)) })
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let entries = self.entries.lock().unwrap();
+        fs::write(path, serde_json::to_vec_pretty(&*entries)?)
+    }
+
+    pub fn get(&self, uri: &Uri) -> Option<LockEntry> {
+        self.entries.lock().unwrap().get(&uri.to_string()).cloned()
+    }
+
+    pub fn insert(&self, uri: &Uri, entry: LockEntry) {
+        self.entries.lock().unwrap().insert(uri.to_string(), entry);
+    }
+
+    pub fn entries(&self) -> BTreeMap<String, LockEntry> {
+        self.entries.lock().unwrap().clone()
+    }
+}
@@ -0,0 +1,125 @@
+use core::fmt;
+use std::sync::{Arc, Mutex};
+
+use polywrap_core::{
+    error::Error,
+    loader::Loader,
+    resolvers::{
+        uri_resolution_context::{UriPackageOrWrapper, UriResolutionContext, UriResolutionStep},
+        uri_resolver::UriResolver,
+    },
+    uri::Uri,
+    wrapper::Wrapper,
+};
+
+use crate::lockfile::{LockEntry, Lockfile, LockfileMode};
+
+pub struct LockfileResolver {
+    resolver: Arc<dyn UriResolver>,
+    lockfile: Lockfile,
+    mode: LockfileMode,
+}
+
+impl LockfileResolver {
+    pub fn new(resolver: Arc<dyn UriResolver>, lockfile: Lockfile, mode: LockfileMode) -> Self {
+        Self { resolver, lockfile, mode }
+    }
+
+    fn lock_entry(
+        resolved_uri: &Uri,
+        redirects: Vec<String>,
+        wrapper: &Arc<Mutex<dyn Wrapper>>,
+    ) -> Result<LockEntry, Error> {
+        let hashes = wrapper.lock().unwrap().get_content_hashes()?.unwrap_or_default();
+        Ok(LockEntry {
+            uri: resolved_uri.to_string(),
+            redirects,
+            manifest_sha256: hashes.manifest_sha256,
+            wasm_sha256: hashes.wasm_sha256,
+        })
+    }
+}
+
+// Each redirect is a top level step of the recursive resolution, nested
+// resolutions (e.g. of resolver extensions) only show up in sub histories
+fn get_redirects(steps: &[UriResolutionStep]) -> Vec<String> {
+    steps
+        .iter()
+        .filter_map(|step| match &step.result {
+            Ok(UriPackageOrWrapper::Uri(to)) if *to != step.source_uri => Some(step.source_uri.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn describe(entry: &LockEntry) -> String {
+    let mut path = entry.redirects.clone();
+    path.push(entry.uri.clone());
+    format!(
+        "{} with manifest sha256 {:?} and wasm sha256 {:?}",
+        path.join(" -> "),
+        entry.manifest_sha256,
+        entry.wasm_sha256
+    )
+}
+
+impl UriResolver for LockfileResolver {
+    fn try_resolve_uri(
+        &self,
+        uri: &Uri,
+        loader: &dyn Loader,
+        resolution_context: &mut UriResolutionContext,
+    ) -> Result<UriPackageOrWrapper, Error> {
+        let locked = match self.mode {
+            LockfileMode::Record => None,
+            LockfileMode::Strict => Some(self.lockfile.get(uri).ok_or_else(|| {
+                Error::LockfileError(uri.to_string(), "it isn't in the lockfile".to_string())
+            })?),
+        };
+
+        // Redirects are followed again, so that a changed redirect is caught
+        // even if it ends at the same wrapper
+        let history_start = resolution_context.get_history().len();
+        let result = self.resolver.try_resolve_uri(uri, loader, resolution_context)?;
+        let redirects = get_redirects(&resolution_context.get_history()[history_start..]);
+
+        // Packages don't expose their files, so the wrapper is created here to
+        // hash them
+        let (resolved_uri, wrapper) = match &result {
+            UriPackageOrWrapper::Wrapper(resolved_uri, wrapper) => (resolved_uri.clone(), wrapper.clone()),
+            UriPackageOrWrapper::Package(resolved_uri, package) => {
+                let wrapper = package.lock().unwrap().create_wrapper()?;
+                (resolved_uri.clone(), wrapper)
+            }
+            UriPackageOrWrapper::Uri(resolved_uri) => {
+                if let Some(locked) = locked {
+                    return Err(Error::LockfileError(
+                        uri.to_string(),
+                        format!("expected {}, but {} couldn't be resolved", describe(&locked), resolved_uri),
+                    ));
+                }
+                return Ok(result);
+            }
+        };
+
+        let entry = Self::lock_entry(&resolved_uri, redirects, &wrapper)?;
+        match locked {
+            Some(locked) if locked != entry => {
+                return Err(Error::LockfileError(
+                    uri.to_string(),
+                    format!("expected {}, got {}", describe(&locked), describe(&entry)),
+                ));
+            }
+            Some(_) => {}
+            None => self.lockfile.insert(uri, entry),
+        }
+
+        Ok(UriPackageOrWrapper::Wrapper(resolved_uri, wrapper))
+    }
+}
+
+impl fmt::Debug for LockfileResolver {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LockfileResolver ({:?})", self.mode)
+    }
+}
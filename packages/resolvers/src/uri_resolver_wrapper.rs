@@ -1,31 +1,50 @@
 use core::fmt;
-use std::sync::{Arc, Mutex};
+use std::{sync::{Arc, Mutex}, time::Duration};
 
 use polywrap_core::{
-  resolvers::uri_resolution_context::{UriPackageOrWrapper, UriResolutionContext},
+  resolvers::uri_resolution_context::{UriPackageOrWrapper, UriResolutionContext, UriResolutionStep},
+  file_reader::FileReader,
+  circuit_breaker::CircuitState,
+  integrity::IntegrityPolicy,
   uri::Uri,
   error::Error, 
-  wrapper::Wrapper, loader::Loader, package::WrapPackage, 
+  wrapper::Wrapper, loader::Loader, package::WrapPackage,
 };
 use polywrap_msgpack::{msgpack, decode};
 use polywrap_wasm::wasm_package::{WasmPackage};
 use serde::{Serialize,Deserialize};
 
-use polywrap_core::{resolvers::resolver_with_history::ResolverWithHistory, resolvers::helpers::UriResolverExtensionFileReader};
+use polywrap_core::{
+  resolvers::resolver_with_history::ResolverWithHistory,
+  resolvers::helpers::{invoke_with_timeout, UriResolverExtensionFileReader},
+};
 
 pub struct UriResolverWrapper {
-  pub implementation_uri: Uri
+  pub implementation_uri: Uri,
+  pub integrity: IntegrityPolicy,
+  pub timeout: Option<Duration>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MaybeUriOrManifest {
   pub uri: Option<String>,
   pub manifest: Option<Vec<u8>>,
+  pub reason: Option<String>,
 }
 
 impl UriResolverWrapper {
   pub fn new(implementation_uri: Uri) -> Self {
-    UriResolverWrapper { implementation_uri }
+    UriResolverWrapper { implementation_uri, integrity: IntegrityPolicy::default(), timeout: None }
+  }
+
+  pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+    self.timeout = timeout;
+    self
+  }
+
+  pub fn with_integrity(mut self, integrity: IntegrityPolicy) -> Self {
+    self.integrity = integrity;
+    self
   }
 
   fn try_resolve_uri_with_implementation(
@@ -35,43 +54,89 @@ impl UriResolverWrapper {
     loader: &dyn Loader,
     resolution_context: &mut UriResolutionContext
   ) -> Result<MaybeUriOrManifest, Error> {
-      let mut sub_context = resolution_context.create_sub_context();
+      let mut sub_context = resolution_context.create_sub_history_context();
       let wrapper = self.load_extension(
         uri.clone(), 
         implementation_uri.clone(), 
         loader, 
         &mut sub_context
-      )?;
+      );
+      resolution_context.track_step(UriResolutionStep {
+        source_uri: implementation_uri.clone(),
+        result: match &wrapper {
+          Ok(wrapper) => Ok(UriPackageOrWrapper::Wrapper(implementation_uri.clone(), wrapper.clone())),
+          Err(e) => Err(e.clone()),
+        },
+        description: Some(format!("Load resolver extension {}", implementation_uri)),
+        sub_history: Some(sub_context.get_history().clone()),
+      });
 
+      // Failing to load the extension counts against its circuit, invocation
+      // failures are recorded by the invoker
+      let wrapper = match wrapper {
+        Ok(wrapper) => wrapper,
+        Err(e) => {
+          if let Some(breaker) = loader.get_invoker()?.get_circuit_breaker() {
+            breaker.record_failure(&implementation_uri);
+          }
+          return Err(e);
+        }
+      };
+
+      // The extension is configured through its own env, not the resolved URI's
       let mut env = None;
-      if let Some(e) = loader.get_env_by_uri(&uri) {
+      if let Some(e) = loader.get_env_by_uri(&implementation_uri) {
           let e = e.to_owned();
           env = Some(e);
       };
 
       let invoker = loader.get_invoker()?;
-      let result = invoker.invoke_wrapper_raw(
+      let args = msgpack!({
+        "authority": uri.authority.as_str(),
+        "path": uri.path.as_str(),
+      });
+      let result = invoke_with_timeout(&invoker, &implementation_uri, self.timeout, |invoker| {
+        invoker.invoke_wrapper_raw(
           wrapper, 
           &implementation_uri, 
           "tryResolveUri", 
-          Some(&msgpack!({
-            "authority": uri.authority.as_str(),
-            "path": uri.path.as_str(),
-          })), 
+          Some(&args), 
           env, 
           Some(resolution_context)
-      )?;
+        )
+      })?;
 
       if result.is_empty() {
         Ok(MaybeUriOrManifest {
           uri: None,
-          manifest: None
+          manifest: None,
+          reason: None,
         })
       } else {
         Ok(decode::<MaybeUriOrManifest>(result.as_slice())?)
       }
   }
 
+  fn track_integrity_check(
+    &self,
+    uri: &Uri,
+    verified: &Result<String, String>,
+    resolution_context: &mut UriResolutionContext
+  ) {
+    resolution_context.track_step(UriResolutionStep {
+      source_uri: uri.clone(),
+      result: match verified {
+        Ok(_) => Ok(UriPackageOrWrapper::Uri(uri.clone())),
+        Err(e) => Err(Error::IntegrityError(uri.to_string(), e.clone())),
+      },
+      description: Some(format!(
+        "Integrity check of {} from {}: {}",
+        uri, self.implementation_uri, verified.as_ref().unwrap_or_else(|e| e)
+      )),
+      sub_history: None,
+    });
+  }
+
   fn load_extension(
     &self,
     current_uri: Uri,
@@ -116,18 +181,98 @@ impl ResolverWithHistory for UriResolverWrapper {
       loader: &dyn Loader, 
       resolution_context: &mut UriResolutionContext
     ) ->  Result<UriPackageOrWrapper, Error> {
-      let result = self.try_resolve_uri_with_implementation(
+      // While the extension's circuit is open, resolution falls through to the
+      // next resolver instead of waiting on an extension that's known to be down
+      let invoker = loader.get_invoker()?;
+      if let Some(breaker) = invoker.get_circuit_breaker() {
+        if breaker.get_state(&self.implementation_uri) == CircuitState::Open {
+          resolution_context.track_step(UriResolutionStep {
+            source_uri: uri.clone(),
+            result: Err(Error::CircuitOpenError(self.implementation_uri.to_string())),
+            description: Some(format!(
+              "Circuit breaker for {}: open, skipped",
+              self.implementation_uri
+            )),
+            sub_history: None,
+          });
+          return Ok(UriPackageOrWrapper::Uri(uri.clone()));
+        }
+      }
+
+      let result = match self.try_resolve_uri_with_implementation(
         uri.clone(), 
         self.implementation_uri.clone(), 
         loader, 
         resolution_context
-      )?;
-      let invoker = loader.get_invoker()?;
+      ) {
+        // Another resolution is already probing the half-open circuit
+        Err(Error::CircuitOpenError(_)) => return Ok(UriPackageOrWrapper::Uri(uri.clone())),
+        result => result?,
+      };
+
+      // The extension knows why it couldn't resolve the URI, so there's no
+      // point in probing it for files
+      if let (None, None, Some(reason)) = (&result.uri, &result.manifest, &result.reason) {
+        resolution_context.track_step(UriResolutionStep {
+          source_uri: uri.clone(),
+          result: Ok(UriPackageOrWrapper::Uri(uri.clone())),
+          description: Some(format!(
+            "Resolver extension {}: {}",
+            self.implementation_uri, reason
+          )),
+          sub_history: None,
+        });
+        return Ok(UriPackageOrWrapper::Uri(uri.clone()));
+      }
       let file_reader = UriResolverExtensionFileReader::new(
         self.implementation_uri.clone(),
         uri.clone(),
         invoker
-      );
+      ).with_timeout(self.timeout);
+
+      // Nothing past this check may load files that weren't verified
+      if self.integrity.applies_to(uri) {
+        let manifest = match (&result.manifest, &result.uri) {
+          (Some(manifest), _) => manifest.clone(),
+          // Hashes pinned for this URI can't be checked against the files of
+          // another one, while signatures are checked when it's resolved
+          (None, Some(redirect)) if self.integrity.expects_hashes(uri) => {
+            let error = format!("its content is pinned, but it redirects to {}", redirect);
+            self.track_integrity_check(uri, &Err(error.clone()), resolution_context);
+            return Err(Error::IntegrityError(uri.to_string(), error));
+          }
+          (None, Some(redirect)) => return Ok(UriPackageOrWrapper::Uri(redirect.clone().try_into()?)),
+          // Without a manifest the extension didn't resolve the URI, any other
+          // failure to read it is returned rather than skipping the check
+          (None, None) => match file_reader.read_file("wrap.info") {
+            Ok(manifest) => manifest,
+            Err(Error::FileReadError(_)) => return Ok(UriPackageOrWrapper::Uri(uri.clone())),
+            Err(e) => return Err(e),
+          },
+        };
+
+        let wasm_module = file_reader.read_file("wrap.wasm")?;
+        let signature = match file_reader.read_file("wrap.sig") {
+          Ok(signature) => Some(signature),
+          Err(Error::FileReadError(_)) => None,
+          Err(e) if self.integrity.requires_signature() => return Err(e),
+          Err(_) => None,
+        };
+        let verified = self.integrity.verify(uri, &manifest, &wasm_module, signature.as_deref());
+        self.track_integrity_check(uri, &verified, resolution_context);
+        verified.map_err(|e| Error::IntegrityError(uri.to_string(), e))?;
+
+        // The verified files are used, rather than fetching them again
+        let package = WasmPackage::new(
+          Arc::new(file_reader),
+          Some(manifest),
+          Some(wasm_module)
+        );
+        return Ok(
+          UriPackageOrWrapper::Package(uri.clone(),
+          Arc::new(Mutex::new(package)))
+        );
+      }
 
       if let Some(manifest) = result.manifest {
           let package = WasmPackage::new(
@@ -164,6 +309,6 @@ impl ResolverWithHistory for UriResolverWrapper {
 
 impl fmt::Debug for UriResolverWrapper {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-      write!(f, "UriResolverWrapper: {:?}", self.implementation_uri)
+      write!(f, "UriResolverWrapper: {}", self.implementation_uri)
   }
 }
\ No newline at end of file
@@ -0,0 +1,272 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use polywrap_core::uri::Uri;
+use serde::{Deserialize, Serialize};
+
+use crate::helpers::sha256;
+
+// Unreferenced blobs are only removed once they're older than this, since
+// another process may have written them and not its entry yet
+pub const DEFAULT_GRACE_PERIOD: Duration = Duration::from_secs(60);
+
+pub struct CachedWrapper {
+    pub manifest: Vec<u8>,
+    pub wasm_module: Vec<u8>,
+    pub signature: Option<Vec<u8>>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheEntry {
+    uri: String,
+    manifest: String,
+    wasm_module: String,
+    #[serde(default)]
+    signature: Option<String>,
+    stored_at: u64,
+}
+
+impl CacheEntry {
+    fn blobs(&self) -> impl Iterator<Item = &String> {
+        [&self.manifest, &self.wasm_module].into_iter().chain(self.signature.as_ref())
+    }
+}
+
+// Files are stored once under `blobs/<sha256>` and shared by every URI that
+// resolves to them. Each URI has an entry under `entries/<sha256 of the URI>`
+// pointing to its files.
+#[derive(Clone, Debug)]
+pub struct WrapperCache {
+    dir: PathBuf,
+    ttl: Option<Duration>,
+    max_size: Option<u64>,
+    grace_period: Duration,
+}
+
+impl WrapperCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into(), ttl: None, max_size: None, grace_period: DEFAULT_GRACE_PERIOD }
+    }
+
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Once the files take more than `max_size` bytes, the oldest entries are evicted
+    pub fn with_max_size(mut self, max_size: u64) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    pub fn with_grace_period(mut self, grace_period: Duration) -> Self {
+        self.grace_period = grace_period;
+        self
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn get(&self, uri: &Uri) -> Option<CachedWrapper> {
+        let entry = self.read_entry(&self.entry_path(uri))?;
+        if self.is_expired(&entry) {
+            self.remove(uri);
+            return None;
+        }
+
+        // A corrupted file is a miss, so that it's fetched and stored again
+        let manifest = self.read_blob(&entry.manifest)?;
+        let wasm_module = self.read_blob(&entry.wasm_module)?;
+        let signature = match &entry.signature {
+            Some(signature) => Some(self.read_blob(signature)?),
+            None => None,
+        };
+        Some(CachedWrapper { manifest, wasm_module, signature })
+    }
+
+    pub fn insert(&self, uri: &Uri, manifest: &[u8], wasm_module: &[u8]) -> io::Result<()> {
+        self.insert_signed(uri, manifest, wasm_module, None)
+    }
+
+    /// Keeps the wrapper's `wrap.sig` too, so that cached files can be verified
+    /// against trusted keys
+    pub fn insert_signed(
+        &self,
+        uri: &Uri,
+        manifest: &[u8],
+        wasm_module: &[u8],
+        signature: Option<&[u8]>,
+    ) -> io::Result<()> {
+        let entry = CacheEntry {
+            uri: uri.to_string(),
+            manifest: self.write_blob(manifest)?,
+            wasm_module: self.write_blob(wasm_module)?,
+            signature: signature.map(|signature| self.write_blob(signature)).transpose()?,
+            stored_at: now(),
+        };
+
+        fs::create_dir_all(self.dir.join("entries"))?;
+        write_atomic(&self.entry_path(uri), &serde_json::to_vec(&entry)?)?;
+        self.evict()
+    }
+
+    pub fn remove(&self, uri: &Uri) {
+        let _ = fs::remove_file(self.entry_path(uri));
+        let _ = self.collect_garbage(&self.entries());
+    }
+
+    pub fn clear(&self) -> io::Result<()> {
+        match fs::remove_dir_all(&self.dir) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+
+    fn evict(&self) -> io::Result<()> {
+        let mut entries = vec![];
+        for (path, entry) in self.entries() {
+            if self.is_expired(&entry) {
+                remove_file(&path)?;
+            } else {
+                entries.push((path, entry));
+            }
+        }
+
+        if let Some(max_size) = self.max_size {
+            entries.sort_by_key(|(_, entry)| entry.stored_at);
+            while !entries.is_empty() && self.size_of(&entries) > max_size {
+                let (path, _) = entries.remove(0);
+                remove_file(&path)?;
+            }
+        }
+
+        self.collect_garbage(&entries)
+    }
+
+    fn collect_garbage(&self, entries: &[(PathBuf, CacheEntry)]) -> io::Result<()> {
+        let Ok(blobs) = fs::read_dir(self.dir.join("blobs")) else {
+            return Ok(());
+        };
+
+        for blob in blobs {
+            let blob = blob?;
+            let hash = blob.file_name().to_string_lossy().to_string();
+            // Skips files that are still being written
+            if hash.contains('.') {
+                continue;
+            }
+            let referenced = entries
+                .iter()
+                .any(|(_, entry)| entry.blobs().any(|blob| *blob == hash));
+            if !referenced && !self.is_recent(&blob.path()) {
+                remove_file(&blob.path())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Blobs shared between entries are only counted once
+    fn size_of(&self, entries: &[(PathBuf, CacheEntry)]) -> u64 {
+        let mut hashes: Vec<&String> = entries
+            .iter()
+            .flat_map(|(_, entry)| entry.blobs())
+            .collect();
+        hashes.sort();
+        hashes.dedup();
+
+        hashes
+            .into_iter()
+            .filter_map(|hash| fs::metadata(self.blob_path(hash)).ok())
+            .map(|metadata| metadata.len())
+            .sum()
+    }
+
+    fn entries(&self) -> Vec<(PathBuf, CacheEntry)> {
+        let Ok(entries) = fs::read_dir(self.dir.join("entries")) else {
+            return vec![];
+        };
+
+        entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|extension| extension == "json"))
+            .filter_map(|path| Some((path.clone(), self.read_entry(&path)?)))
+            .collect()
+    }
+
+    fn is_expired(&self, entry: &CacheEntry) -> bool {
+        match self.ttl {
+            Some(ttl) => u128::from(now().saturating_sub(entry.stored_at)) >= ttl.as_millis(),
+            None => false,
+        }
+    }
+
+    // Blobs that are gone already count as recent, so they're left alone
+    fn is_recent(&self, path: &Path) -> bool {
+        match fs::metadata(path).and_then(|metadata| metadata.modified()) {
+            Ok(modified) => modified.elapsed().map_or(true, |age| age < self.grace_period),
+            Err(_) => true,
+        }
+    }
+
+    fn read_entry(&self, path: &Path) -> Option<CacheEntry> {
+        serde_json::from_slice(&fs::read(path).ok()?).ok()
+    }
+
+    fn read_blob(&self, hash: &str) -> Option<Vec<u8>> {
+        let bytes = fs::read(self.blob_path(hash)).ok()?;
+        (sha256(&bytes) == hash).then_some(bytes)
+    }
+
+    fn write_blob(&self, bytes: &[u8]) -> io::Result<String> {
+        let hash = sha256(bytes);
+        let path = self.blob_path(&hash);
+        // Existing blobs are touched, so that they're recent until the entry
+        // referencing them is written
+        match fs::File::options().append(true).open(&path) {
+            Ok(file) => file.set_modified(SystemTime::now())?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                fs::create_dir_all(self.dir.join("blobs"))?;
+                write_atomic(&path, bytes)?;
+            }
+            Err(e) => return Err(e),
+        }
+        Ok(hash)
+    }
+
+    fn entry_path(&self, uri: &Uri) -> PathBuf {
+        self.dir.join("entries").join(format!("{}.json", sha256(uri.to_string().as_bytes())))
+    }
+
+    fn blob_path(&self, hash: &str) -> PathBuf {
+        self.dir.join("blobs").join(hash)
+    }
+}
+
+// Other processes sharing the directory never see partially written files
+fn write_atomic(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    let tmp = path.with_extension(format!("tmp-{}", std::process::id()));
+    fs::write(&tmp, bytes)?;
+    fs::rename(&tmp, path)
+}
+
+// Another process sharing the directory may have removed the file already
+fn remove_file(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
@@ -0,0 +1,150 @@
+use core::fmt;
+use std::sync::{Arc, Mutex};
+
+use polywrap_core::{
+    error::Error,
+    integrity::IntegrityPolicy,
+    loader::Loader,
+    resolvers::{
+        uri_resolution_context::{UriPackageOrWrapper, UriResolutionContext, UriResolutionStep},
+        uri_resolver::UriResolver,
+    },
+    uri::Uri,
+    wrapper::{GetFileOptions, Wrapper},
+};
+use polywrap_wasm::wasm_package::WasmPackage;
+
+use crate::{
+    helpers::get_wrap_files,
+    wrapper_cache::{CachedWrapper, WrapperCache},
+};
+
+// Local wrappers can change on disk under the same URI, so they're always
+// resolved again rather than served from the cache
+const UNCACHED_AUTHORITIES: [&str; 2] = ["fs", "file"];
+
+fn is_cacheable(uri: &Uri) -> bool {
+    !UNCACHED_AUTHORITIES.contains(&uri.authority.as_str())
+}
+
+// Wrappers resolved by the inner resolver are stored in the cache, and later
+// resolutions of the same URI are served from it without calling the resolver.
+// Cached files are checked against the integrity policy again on every hit
+pub struct WrapperCacheResolver {
+    resolver: Arc<dyn UriResolver>,
+    cache: WrapperCache,
+    integrity: IntegrityPolicy,
+}
+
+impl WrapperCacheResolver {
+    pub fn new(resolver: Arc<dyn UriResolver>, cache: WrapperCache) -> Self {
+        Self { resolver, cache, integrity: IntegrityPolicy::default() }
+    }
+
+    pub fn with_integrity(mut self, integrity: IntegrityPolicy) -> Self {
+        self.integrity = integrity;
+        self
+    }
+
+    fn store(&self, uri: &Uri, wrapper: &Arc<Mutex<dyn Wrapper>>) -> Result<(), String> {
+        let (manifest, wasm_module) = get_wrap_files(wrapper).map_err(|e| e.to_string())?;
+        let signature = match self.integrity.requires_signature() {
+            true => wrapper
+                .lock()
+                .unwrap()
+                .get_file(&GetFileOptions { path: "wrap.sig".to_string(), encoding: None })
+                .ok(),
+            false => None,
+        };
+        self.cache
+            .insert_signed(uri, &manifest, &wasm_module, signature.as_deref())
+            .map_err(|e| e.to_string())
+    }
+
+    fn verify(&self, uri: &Uri, cached: &CachedWrapper) -> Result<(), String> {
+        if !self.integrity.applies_to(uri) {
+            return Ok(());
+        }
+        self.integrity
+            .verify(uri, &cached.manifest, &cached.wasm_module, cached.signature.as_deref())
+            .map(|_| ())
+    }
+}
+
+impl UriResolver for WrapperCacheResolver {
+    fn try_resolve_uri(
+        &self,
+        uri: &Uri,
+        loader: &dyn Loader,
+        resolution_context: &mut UriResolutionContext,
+    ) -> Result<UriPackageOrWrapper, Error> {
+        if !is_cacheable(uri) {
+            return self.resolver.try_resolve_uri(uri, loader, resolution_context);
+        }
+
+        // Cached files that fail the check are resolved again, and replaced if
+        // the fresh ones pass it
+        let cached = self.cache.get(uri).filter(|cached| match self.verify(uri, cached) {
+            Ok(_) => true,
+            Err(e) => {
+                resolution_context.track_step(UriResolutionStep {
+                    source_uri: uri.clone(),
+                    result: Err(Error::IntegrityError(uri.to_string(), e.clone())),
+                    description: Some(format!(
+                        "Integrity check of {} from {}: {}",
+                        uri, self.cache.dir().display(), e
+                    )),
+                    sub_history: None,
+                });
+                false
+            }
+        });
+        if let Some(cached) = cached {
+            let package = WasmPackage::from_bytes(&cached.manifest, &cached.wasm_module);
+            let result = UriPackageOrWrapper::Package(uri.clone(), Arc::new(Mutex::new(package)));
+            resolution_context.track_step(UriResolutionStep {
+                source_uri: uri.clone(),
+                result: Ok(result.clone()),
+                description: Some(format!("WrapperCache ({})", self.cache.dir().display())),
+                sub_history: None,
+            });
+            return Ok(result);
+        }
+
+        let result = self.resolver.try_resolve_uri(uri, loader, resolution_context)?;
+        // Packages don't expose their files, so the wrapper is created here
+        // rather than by the loader
+        let (resolved_uri, wrapper) = match &result {
+            UriPackageOrWrapper::Wrapper(resolved_uri, wrapper) => (resolved_uri.clone(), wrapper.clone()),
+            UriPackageOrWrapper::Package(resolved_uri, package) => {
+                match package.lock().unwrap().create_wrapper() {
+                    Ok(wrapper) => (resolved_uri.clone(), wrapper),
+                    Err(_) => return Ok(result.clone()),
+                }
+            }
+            UriPackageOrWrapper::Uri(_) => return Ok(result.clone()),
+        };
+        if !is_cacheable(&resolved_uri) {
+            return Ok(UriPackageOrWrapper::Wrapper(resolved_uri, wrapper));
+        }
+
+        // Plugin wrappers have no files to store, and a cache that can't be
+        // written to shouldn't fail the resolution
+        if let Err(e) = self.store(uri, &wrapper) {
+            resolution_context.track_step(UriResolutionStep {
+                source_uri: uri.clone(),
+                result: Ok(UriPackageOrWrapper::Uri(uri.clone())),
+                description: Some(format!("WrapperCache: not stored, {}", e)),
+                sub_history: None,
+            });
+        }
+
+        Ok(UriPackageOrWrapper::Wrapper(resolved_uri, wrapper))
+    }
+}
+
+impl fmt::Debug for WrapperCacheResolver {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "WrapperCacheResolver ({})", self.cache.dir().display())
+    }
+}
@@ -71,6 +71,12 @@ impl Wrapper for MockWrapper {
     ) -> Result<Vec<u8>, polywrap_core::error::Error> {
         Ok(vec![2])
     }
+
+    fn get_manifest(&self) -> Result<&WrapManifest, polywrap_core::error::Error> {
+        Err(polywrap_core::error::Error::ManifestError(
+            "MockWrapper does not have a manifest".to_string()
+        ))
+    }
 }
 
 pub fn get_mock_package(name: Option<String>) -> Arc<Mutex<dyn WrapPackage>> {
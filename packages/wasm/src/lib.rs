@@ -1,4 +1,5 @@
 pub mod error;
+pub mod macros;
 pub mod runtime;
 pub mod file_reader;
 pub mod wasm_wrapper;
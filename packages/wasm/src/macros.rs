@@ -0,0 +1,16 @@
+/// Embeds the `wrap.info` and `wrap.wasm` files of a wrapper directory into
+/// the binary and creates a `WasmPackage` from them. Like `include_bytes!`,
+/// the directory is relative to the file the macro is invoked from.
+///
+/// ```ignore
+/// let package = embed_wrapper!("../wraps/my-wrapper");
+/// ```
+#[macro_export]
+macro_rules! embed_wrapper {
+    ($dir:expr) => {
+        $crate::wasm_package::WasmPackage::from_bytes(
+            include_bytes!(concat!($dir, "/wrap.info")),
+            include_bytes!(concat!($dir, "/wrap.wasm")),
+        )
+    };
+}
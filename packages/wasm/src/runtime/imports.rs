@@ -1,9 +1,19 @@
 use std::sync::{Mutex, Arc};
 
 
-use wasmer::{Imports, imports, Memory, FunctionEnvMut, Function, FunctionType, Value, Type, FunctionEnv, Store};
+use wasmer::{Imports, imports, Memory, FunctionEnvMut, Function, FunctionType, Value, Type, FunctionEnv, Store, RuntimeError};
 
-use super::instance::State;
+use super::{instance::State, interrupt::{INTERRUPT_CHECK, INTERRUPT_MODULE}};
+
+// Host calls are the only points where a running module hands control back to
+// the client, so a cancelled invocation is turned into a trap here. Modules
+// that don't call any import are checked through the interrupt middleware
+fn check_cancelled(state: &State) -> Result<(), RuntimeError> {
+    match state.invoker.get_cancellation_token() {
+        Some(token) if token.is_cancelled() => Err(RuntimeError::new("Invocation cancelled")),
+        _ => Ok(()),
+    }
+}
 
 pub fn create_imports(
     memory: Memory,
@@ -18,13 +28,14 @@ pub fn create_imports(
 
         let mutable_context = context.as_mut();
         let mutable_state = mutable_context.data().lock().unwrap();
+        check_cancelled(&mutable_state)?;
 
         if mutable_state.method.is_empty() {
-            (mutable_state.abort)("__wrap_invoke_args: method is not set".to_string());
+            return Err((mutable_state.abort)("__wrap_invoke_args: method is not set".to_string()));
         }
 
         if mutable_state.args.is_empty() {
-            (mutable_state.abort)("__wrap_invoke_args: args is not set".to_string());
+            return Err((mutable_state.abort)("__wrap_invoke_args: args is not set".to_string()));
         }
 
         let memory = mutable_state.memory.as_ref().unwrap();
@@ -125,15 +136,17 @@ pub fn create_imports(
 
         let msg = String::from_utf8(msg_buffer).unwrap();
         let file = String::from_utf8(file_buffer).unwrap();
-        (state.abort)(format!(
+
+        // Trapping unwinds the module, and the invocation fails with the message,
+        // so that callers like resolver extensions can recover from it. It goes
+        // through `state.abort`, which adds the URI, method, args and env
+        Err((state.abort)(format!(
             "__wrap_abort: {msg}\nFile: {file}\nLocation: [{line},{column}]",
             msg = msg,
             file = file,
             line = line,
             column = column
-        ));
-
-        Ok(vec![])
+        )))
     };
 
     let wrap_abort = Function::new_with_env(
@@ -173,13 +186,17 @@ pub fn create_imports(
         let uri = String::from_utf8(uri_buffer).unwrap().try_into().unwrap();
         let method = String::from_utf8(method_buffer).unwrap();
 
-        let result = state.invoker.invoke_raw(
-            &uri,
-            &method,
-            Some(&args_buffer),
-            None,
-            None
-        );
+        check_cancelled(&state)?;
+        let result = state.invoker
+            .check_subinvoke_permission(&uri, &method)
+            .and_then(|_| state.invoker.invoke_raw(
+                &uri,
+                &method,
+                Some(&args_buffer),
+                None,
+                None
+            ));
+        check_cancelled(&state)?;
 
         match result {
             Ok(res) => {
@@ -210,9 +227,9 @@ pub fn create_imports(
         let mutable_state = mutable_context.data().lock().unwrap();
 
         if mutable_state.subinvoke.result.is_none() {
-            (mutable_state.abort)(
+            return Err((mutable_state.abort)(
                 "__wrap_subinvoke_result_len: subinvoke.result is not set".to_string(),
-            );
+            ));
         }
         let length = mutable_state.subinvoke.result.as_deref().unwrap().len();
         Ok(vec![Value::I32(length as i32)])
@@ -239,9 +256,9 @@ pub fn create_imports(
         if let Some(result) = &mutable_state.subinvoke.result {
             memory.view(&mutable_context).write(pointer as u64, result).unwrap();
         } else {
-            (mutable_state.abort)(
+            return Err((mutable_state.abort)(
                 "__wrap_subinvoke_result: subinvoke.result is not set".to_string(),
-            );
+            ));
         }
         Ok(vec![])
     };
@@ -263,9 +280,9 @@ pub fn create_imports(
         let mutable_state = mutable_context.data().lock().unwrap();
 
         if mutable_state.subinvoke.error.is_none() {
-            (mutable_state.abort)(
+            return Err((mutable_state.abort)(
                 "__wrap_subinvoke_error_len: subinvoke.error is not set".to_string(),
-            );
+            ));
         }
         let length = mutable_state.subinvoke.error.as_deref().unwrap().len();
         Ok(vec![Value::I32(length as i32)])
@@ -292,9 +309,9 @@ pub fn create_imports(
         if let Some(error) = &mutable_state.subinvoke.error {
             memory.view(&mutable_context).write(pointer as u64, error.as_bytes()).unwrap();
         } else {
-            (mutable_state.abort)(
+            return Err((mutable_state.abort)(
                 "__wrap_subinvoke_error: subinvoke.error is not set".to_string(),
-            );
+            ));
         }
         Ok(vec![])
     };
@@ -343,13 +360,18 @@ pub fn create_imports(
             let uri = String::from_utf8(impl_uri_buffer).unwrap();
             let method = String::from_utf8(method_buffer).unwrap();
 
-            let result = state.invoker.invoke_raw(
-                &uri.try_into().unwrap(),
-                &method,
-                Some(&args_buffer),
-                None,
-                None
-            );
+            let uri = uri.try_into().unwrap();
+            check_cancelled(&state)?;
+            let result = state.invoker
+                .check_subinvoke_permission(&uri, &method)
+                .and_then(|_| state.invoker.invoke_raw(
+                    &uri,
+                    &method,
+                    Some(&args_buffer),
+                    None,
+                    None
+                ));
+            check_cancelled(&state)?;
     
             match result {
                 Ok(r) => {
@@ -385,17 +407,14 @@ pub fn create_imports(
                 let length = r.len();
                 Ok(vec![Value::I32(length as i32)])
             } else {
-                (mutable_state.abort)(
+                Err((mutable_state.abort)(
                     "__wrap_subinvoke_implementation_result_len: subinvoke_implementation.result is not set".to_string(),
-                );
-                Ok(vec![Value::I32(0)])
-
+                ))
             }
         } else {
-            (mutable_state.abort)(
+            Err((mutable_state.abort)(
                 "__wrap_subinvoke_implementation_result_len: subinvoke_implementation is not set".to_string(),
-            );
-            Ok(vec![Value::I32(0)])
+            ))
         }
     };
 
@@ -421,14 +440,14 @@ pub fn create_imports(
             if let Some(r) = &implementation.result {
                 memory.view(&mutable_context).write(pointer.try_into().unwrap(), r).unwrap();
             } else {
-                (mutable_state.abort)(
+                return Err((mutable_state.abort)(
                     "__wrap_subinvoke_implementation_result: subinvoke_implementation.result is not set".to_string(),
-                );
+                ));
             };
         } else {
-            (mutable_state.abort)(
+            return Err((mutable_state.abort)(
                 "__wrap_subinvoke_implementation_result: subinvoke_implementation is not set".to_string(),
-            );
+            ));
         };
         Ok(vec![])
     };
@@ -454,17 +473,14 @@ pub fn create_imports(
                 let length = r.as_bytes().len();
                 Ok(vec![Value::I32(length as i32)])
             } else {
-                (mutable_state.abort)(
+                Err((mutable_state.abort)(
                     "__wrap_subinvoke_implementation_error_len: subinvoke_implementation.error is not set".to_string(),
-                );
-                Ok(vec![Value::I32(0)])
-
+                ))
             }
         } else {
-            (mutable_state.abort)(
+            Err((mutable_state.abort)(
                 "__wrap_subinvoke_implementation_error_len: subinvoke_implementation is not set".to_string(),
-            );
-            Ok(vec![Value::I32(0)])
+            ))
         }
     };
 
@@ -490,14 +506,14 @@ pub fn create_imports(
             if let Some(r) = &implementation.error {
                 memory.view(&mutable_context).write(pointer.try_into().unwrap(), r.as_bytes()).unwrap();
             } else {
-                (mutable_state.abort)(
+                return Err((mutable_state.abort)(
                     "__wrap_subinvoke_implementation_error: subinvoke_implementation.error is not set".to_string(),
-                );
+                ));
             };
         } else {
-            (mutable_state.abort)(
+            return Err((mutable_state.abort)(
                 "__wrap_subinvoke_implementation_error: subinvoke_implementation is not set".to_string(),
-            );
+            ));
         };
         Ok(vec![])
     };
@@ -528,13 +544,12 @@ pub fn create_imports(
         let mut uri_bytes = vec![0; length as usize];
         memory.view(&mutable_context).read(pointer.try_into().unwrap(), &mut uri_bytes).unwrap();
         let uri = String::from_utf8(uri_bytes).unwrap();
-        println!("URI: {}", length);
+        check_cancelled(&state)?;
         let result = state.invoker.get_implementations(uri.try_into().unwrap());
 
         if result.is_err() {
             let result = result.as_ref().err().unwrap();
-            (state.abort)(result.to_string());
-            return Ok(vec![Value::I32(0)]);
+            return Err((state.abort)(result.to_string()));
         }
 
         let implementations = &result.unwrap().into_iter().map(|u| u.to_string()).collect::<Vec<String>>();
@@ -565,13 +580,11 @@ pub fn create_imports(
 
         if let Some(r) = &state.get_implementations_result {
             let length = r.len();
-            println!("POINTER LEN: {}", length);
             Ok(vec![Value::I32(length as i32)])
         } else {
-            (state.abort)(
+            Err((state.abort)(
                 "__wrap_get_implementation_result_len: get_implementation_result is not set".to_string(),
-            );
-            Ok(vec![Value::I32(0)])
+            ))
         }
     };
 
@@ -595,14 +608,12 @@ pub fn create_imports(
         let state = mutable_context.data().lock().unwrap();
         let memory = state.memory.as_ref().unwrap();
 
-        println!("POINTER: {}", pointer);
-
         if let Some(r) = &state.get_implementations_result {
             memory.view(&mutable_context).write(pointer.try_into().unwrap(), r).unwrap();
         } else {
-            (state.abort)(
+            return Err((state.abort)(
                 "__wrap_get_implementation_result: get_implementation_result is not set".to_string(),
-            );
+            ));
         }
         Ok(vec![])
     };
@@ -638,7 +649,23 @@ pub fn create_imports(
         load_env
     );
 
+    let interrupt_check = move |context: FunctionEnvMut<Arc<Mutex<State>>>, _: &[Value]| {
+        let state = context.data().lock().unwrap();
+        check_cancelled(&state)?;
+        Ok(vec![])
+    };
+
+    let wrap_interrupt_check = Function::new_with_env(
+        store,
+        &context,
+        FunctionType::new(vec![], vec![]),
+        interrupt_check
+    );
+
     imports! {
+        INTERRUPT_MODULE => {
+            INTERRUPT_CHECK => wrap_interrupt_check,
+        },
         "wrap" => {
             "__wrap_invoke_args" => wrap_invoke_args,
             "__wrap_invoke_result" => wrap_invoke_result,
@@ -1,10 +1,10 @@
 use std::{sync::{Arc, Mutex}};
 use polywrap_core::invoke::{Invoker};
-use wasmer::{Module, Instance, Store, Memory, MemoryType, Value};
+use wasmer::{Module, Instance, Store, Memory, MemoryType, Value, RuntimeError, Cranelift, CompilerConfig, Engine, EngineBuilder};
 
 use crate::error::WrapperError;
 
-use super::imports::create_imports;
+use super::{imports::create_imports, interrupt::Interrupt};
 
 #[derive(Clone)]
 pub enum WasmModule {
@@ -31,7 +31,7 @@ pub struct State {
     pub env: Vec<u8>,
     pub invoke: InvokeState,
     pub subinvoke: InvokeState,
-    pub abort: Box<dyn Fn(String) + Send + Sync>,
+    pub abort: Box<dyn Fn(String) -> RuntimeError + Send + Sync>,
     pub invoker: Arc<dyn Invoker>,
     pub get_implementations_result: Option<Vec<u8>>,
     pub subinvoke_implementation: Option<SubinvokeImplementationState>,
@@ -41,7 +41,7 @@ pub struct State {
 impl State {
     pub fn new(
         invoker: Arc<dyn Invoker>,
-        abort: Box<dyn Fn(String) + Send + Sync>,
+        abort: Box<dyn Fn(String) -> RuntimeError + Send + Sync>,
         method: &str,
         args: Vec<u8>,
         env: Vec<u8>
@@ -61,6 +61,28 @@ impl State {
     }
 }
 
+// A module compiled once and instantiated for every invocation. Instances need
+// a store on the engine the module was compiled with, so it's kept alongside
+#[derive(Clone)]
+pub struct CompiledModule {
+    engine: Engine,
+    module: Module,
+}
+
+impl CompiledModule {
+    pub fn new(wasm_module: &[u8]) -> Result<Self, WrapperError> {
+        // Each module gets its own middleware, which is tied to the module it's
+        // applied to
+        let mut compiler = Cranelift::default();
+        compiler.push_middleware(Arc::new(Interrupt::default()));
+        let engine: Engine = EngineBuilder::new(compiler).into();
+        let module = Module::new(&engine, wasm_module)
+            .map_err(|e| WrapperError::ModuleReadError(e.to_string()))?;
+
+        Ok(Self { engine, module })
+    }
+}
+
 pub struct WasmInstance {
     instance: Instance,
     pub store: Store,
@@ -68,9 +90,13 @@ pub struct WasmInstance {
 }
 
 impl WasmInstance {
-    pub fn new(wasm_module: &Vec<u8>, state: Arc<Mutex<State>>) -> Result<Self, WrapperError> {
-        let mut store = Store::default();
-        let module = Module::new(&store, wasm_module).unwrap();
+    pub fn new(
+        wasm_module: &[u8],
+        compiled_module: &CompiledModule,
+        state: Arc<Mutex<State>>
+    ) -> Result<Self, WrapperError> {
+        let mut store = Store::new(compiled_module.engine.clone());
+        let module = compiled_module.module.clone();
         let memory = WasmInstance::create_memory(&mut store, wasm_module)?;
         let imports = create_imports(
             memory.clone(),
@@ -129,7 +155,9 @@ impl WasmInstance {
             )));
         }
         let function = export.unwrap();
-        function.call(&mut self.store, params).unwrap();
+        function
+            .call(&mut self.store, params)
+            .map_err(|e| WrapperError::WasmRuntimeError(e.message()))?;
 
         Ok(true)
     }
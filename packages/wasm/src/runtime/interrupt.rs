@@ -0,0 +1,191 @@
+use std::sync::Mutex;
+
+use wasmer::{
+    wasmparser::{BlockType, Operator},
+    FunctionMiddleware, LocalFunctionIndex, MiddlewareError, MiddlewareReaderState, ModuleMiddleware,
+};
+use wasmer_types::{
+    entity::{EntityRef, PrimaryMap},
+    ExportIndex, FunctionIndex, FunctionType, GlobalInit, GlobalType, ImportIndex, ImportKey,
+    ModuleInfo, Mutability, Type,
+};
+
+pub const INTERRUPT_MODULE: &str = "polywrap";
+pub const INTERRUPT_CHECK: &str = "__interrupt_check";
+
+// Function entries and loop headers between two checks
+const CHECK_INTERVAL: i32 = 10_000;
+
+#[derive(Debug, Clone, Copy)]
+struct Indexes {
+    // Function indexes from this one on were shifted by the check's import
+    first_local_function: u32,
+    check_function: u32,
+    countdown_global: u32,
+}
+
+// Makes compute-bound modules interruptible. Every function entry and loop
+// header counts down a global, and once it reaches zero, the module calls the
+// `polywrap.__interrupt_check` host import, which traps if the invocation was
+// cancelled. Modules only hand control back to the host through imports, so
+// without this a loop that never calls one can't be stopped.
+// It's applied to one module only, like wasmer's metering middleware
+#[derive(Debug, Default)]
+pub struct Interrupt {
+    indexes: Mutex<Option<Indexes>>,
+}
+
+impl ModuleMiddleware for Interrupt {
+    fn generate_function_middleware(&self, _: LocalFunctionIndex) -> Box<dyn FunctionMiddleware> {
+        Box::new(FunctionInterrupt {
+            indexes: self
+                .indexes
+                .lock()
+                .unwrap()
+                .expect("Interrupt::transform_module_info must run before functions are compiled"),
+            entered: false,
+        })
+    }
+
+    fn transform_module_info(&self, module_info: &mut ModuleInfo) {
+        let mut indexes = self.indexes.lock().unwrap();
+        assert!(indexes.is_none(), "An Interrupt middleware can only be used for one module");
+
+        // The check is imported after the module's own imports, so every local
+        // function, and every reference to one, moves up by one
+        let first_local = module_info.num_imported_functions;
+        let shift = |index: &mut FunctionIndex| {
+            if index.index() >= first_local {
+                *index = FunctionIndex::new(index.index() + 1);
+            }
+        };
+
+        let signature = FunctionType::new(vec![], vec![]);
+        let signature = match module_info.signatures.iter().find(|(_, s)| **s == signature) {
+            Some((index, _)) => index,
+            None => module_info.signatures.push(signature),
+        };
+        let mut functions = PrimaryMap::with_capacity(module_info.functions.len() + 1);
+        for (index, function) in module_info.functions.iter() {
+            if index.index() == first_local {
+                functions.push(signature);
+            }
+            functions.push(*function);
+        }
+        if module_info.functions.len() == first_local {
+            functions.push(signature);
+        }
+        module_info.functions = functions;
+        module_info.num_imported_functions += 1;
+
+        let check = FunctionIndex::new(first_local);
+        let import_idx = module_info.imports.len() as u32;
+        module_info.imports.insert(
+            ImportKey {
+                module: INTERRUPT_MODULE.to_string(),
+                field: INTERRUPT_CHECK.to_string(),
+                import_idx,
+            },
+            ImportIndex::Function(check),
+        );
+
+        for export in module_info.exports.values_mut() {
+            if let ExportIndex::Function(index) = export {
+                shift(index);
+            }
+        }
+        if let Some(start) = module_info.start_function.as_mut() {
+            shift(start);
+        }
+        for initializer in module_info.table_initializers.iter_mut() {
+            initializer.elements.iter_mut().for_each(shift);
+        }
+        for elements in module_info.passive_elements.values_mut() {
+            elements.iter_mut().for_each(shift);
+        }
+        for (_, init) in module_info.global_initializers.iter_mut() {
+            if let GlobalInit::RefFunc(index) = init {
+                shift(index);
+            }
+        }
+        module_info.function_names = module_info
+            .function_names
+            .drain()
+            .map(|(mut index, name)| {
+                shift(&mut index);
+                (index, name)
+            })
+            .collect();
+
+        let countdown = module_info.globals.push(GlobalType::new(Type::I32, Mutability::Var));
+        module_info.global_initializers.push(GlobalInit::I32Const(CHECK_INTERVAL));
+
+        *indexes = Some(Indexes {
+            first_local_function: first_local as u32,
+            check_function: check.as_u32(),
+            countdown_global: countdown.as_u32(),
+        });
+    }
+}
+
+#[derive(Debug)]
+struct FunctionInterrupt {
+    indexes: Indexes,
+    entered: bool,
+}
+
+impl FunctionInterrupt {
+    fn push_countdown(&self, state: &mut MiddlewareReaderState) {
+        let countdown = self.indexes.countdown_global;
+        state.extend([
+            Operator::GlobalGet { global_index: countdown },
+            Operator::I32Const { value: 1 },
+            Operator::I32Sub,
+            Operator::GlobalSet { global_index: countdown },
+            Operator::GlobalGet { global_index: countdown },
+            Operator::I32Eqz,
+            Operator::If { blockty: BlockType::Empty },
+            Operator::I32Const { value: CHECK_INTERVAL },
+            Operator::GlobalSet { global_index: countdown },
+            Operator::Call { function_index: self.indexes.check_function },
+            Operator::End,
+        ]);
+    }
+
+    fn shift(&self, function_index: u32) -> u32 {
+        if function_index >= self.indexes.first_local_function {
+            function_index + 1
+        } else {
+            function_index
+        }
+    }
+}
+
+impl FunctionMiddleware for FunctionInterrupt {
+    fn feed<'a>(
+        &mut self,
+        operator: Operator<'a>,
+        state: &mut MiddlewareReaderState<'a>,
+    ) -> Result<(), MiddlewareError> {
+        if !self.entered {
+            self.entered = true;
+            self.push_countdown(state);
+        }
+
+        match operator {
+            Operator::Loop { .. } => {
+                state.push_operator(operator);
+                self.push_countdown(state);
+            }
+            Operator::Call { function_index } => {
+                state.push_operator(Operator::Call { function_index: self.shift(function_index) });
+            }
+            Operator::RefFunc { function_index } => {
+                state.push_operator(Operator::RefFunc { function_index: self.shift(function_index) });
+            }
+            operator => state.push_operator(operator),
+        }
+
+        Ok(())
+    }
+}
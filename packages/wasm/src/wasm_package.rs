@@ -1,7 +1,7 @@
 use std::{sync::{Arc, Mutex}, fmt::{Formatter,Debug}};
 
 use polywrap_core::{
-    file_reader::FileReader,
+    file_reader::{FileReader, SimpleFileReader},
     package::{GetManifestOptions, WrapPackage},
     wrapper::Wrapper,
 };
@@ -36,6 +36,21 @@ impl WasmPackage {
         }
     }
 
+    pub fn from_bytes(manifest: &[u8], wasm_module: &[u8]) -> Self {
+        Self::new(
+            Arc::new(SimpleFileReader::new()),
+            Some(manifest.to_vec()),
+            Some(wasm_module.to_vec()),
+        )
+    }
+
+    fn get_encoded_manifest(&self) -> Result<Vec<u8>, polywrap_core::error::Error> {
+        match &self.manifest {
+            Some(manifest) => Ok(manifest.clone()),
+            None => self.file_reader.read_file("wrap.info"),
+        }
+    }
+
     pub fn get_wasm_module(&self) -> Result<Vec<u8>, polywrap_core::error::Error> {
         if self.wasm_module.is_some() {
             return Ok(self.wasm_module.clone().unwrap());
@@ -64,31 +79,32 @@ impl WrapPackage for WasmPackage {
         &self,
         options: Option<GetManifestOptions>,
     ) -> Result<WrapManifest, polywrap_core::error::Error> {
-        let encoded_manifest = match self.manifest.clone() {
-            Some(manifest) => manifest,
-            None => self.file_reader.read_file("wrap.info")?,
-        };
-
-        let opts = options.map(|options| DeserializeManifestOptions {
-                no_validate: options.no_validate,
-                ext_schema: None,
-            });
-        let deserialized_manifest = deserialize_wrap_manifest(&encoded_manifest, opts)
-            .map_err(|e| polywrap_core::error::Error::ManifestError(e.to_string()))?;
-
-        Ok(deserialized_manifest)
+        let encoded_manifest = self.get_encoded_manifest()?;
+        deserialize_manifest(&encoded_manifest, options)
     }
 
     fn create_wrapper(
         &self
     ) -> Result<Arc<Mutex<dyn Wrapper>>, polywrap_core::error::Error> {
         let wasm_module = self.get_wasm_module()?;
-        let manifest = self.get_manifest(None)?;
+        let encoded_manifest = self.get_encoded_manifest()?;
+        let manifest = deserialize_manifest(&encoded_manifest, None)?;
 
-        Ok(Arc::new(Mutex::new(WasmWrapper::new(
-            wasm_module,
-            self.file_reader.clone(),
-            manifest,
-        ))))
+        Ok(Arc::new(Mutex::new(
+            WasmWrapper::new(wasm_module, self.file_reader.clone(), manifest)
+                .with_encoded_manifest(encoded_manifest),
+        )))
     }
 }
+
+fn deserialize_manifest(
+    encoded_manifest: &[u8],
+    options: Option<GetManifestOptions>,
+) -> Result<WrapManifest, polywrap_core::error::Error> {
+    let opts = options.map(|options| DeserializeManifestOptions {
+        no_validate: options.no_validate,
+        ext_schema: None,
+    });
+    deserialize_wrap_manifest(encoded_manifest, opts)
+        .map_err(|e| polywrap_core::error::Error::ManifestError(e.to_string()))
+}
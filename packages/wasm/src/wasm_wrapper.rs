@@ -1,22 +1,23 @@
 use crate::error::WrapperError;
-use crate::runtime::instance::{State,WasmInstance};
+use crate::runtime::instance::{CompiledModule, State, WasmInstance};
 
 
 use polywrap_core::env::Env;
 use polywrap_core::error::Error;
 use polywrap_core::file_reader::FileReader;
+use polywrap_core::integrity::{sha256, ContentHashes};
 use polywrap_core::invoke::Invoker;
 use polywrap_core::resolvers::uri_resolution_context::UriResolutionContext;
 use polywrap_core::uri::Uri;
 use polywrap_core::wrapper::Encoding;
 use polywrap_core::wrapper::GetFileOptions;
 use polywrap_core::wrapper::Wrapper;
-use wasmer::Value;
+use wasmer::{RuntimeError, Value};
 use wrap_manifest_schemas::versions::WrapManifest;
 use polywrap_msgpack::decode;
 use serde::de::DeserializeOwned;
 use std::fmt::Formatter;
-use std::sync::Mutex;
+use std::sync::{Mutex, OnceLock};
 use std::{sync::Arc, fmt::Debug};
 
 #[derive(Clone)]
@@ -24,6 +25,10 @@ pub struct WasmWrapper {
     wasm_module: Vec<u8>,
     file_reader: Arc<dyn FileReader>,
     manifest: WrapManifest,
+    encoded_manifest: Option<Vec<u8>>,
+    content_hashes: OnceLock<ContentHashes>,
+    // Compiled on the first invocation, each invocation gets a fresh instance
+    compiled_module: OnceLock<CompiledModule>,
 }
 
 impl WasmWrapper {
@@ -36,15 +41,30 @@ impl WasmWrapper {
             wasm_module,
             file_reader,
             manifest,
+            encoded_manifest: None,
+            content_hashes: OnceLock::new(),
+            compiled_module: OnceLock::new(),
         }
     }
 
+    // The `wrap.info` the manifest was deserialized from, so that hashing it
+    // doesn't read it again
+    pub fn with_encoded_manifest(mut self, encoded_manifest: Vec<u8>) -> Self {
+        self.encoded_manifest = Some(encoded_manifest);
+        self
+    }
+
     pub fn get_wasm_module(&self) -> Result<&[u8], WrapperError> {
         Ok(&self.wasm_module)
     }
 
-    pub fn get_manifest(&self) -> Result<&WrapManifest, WrapperError> {
-        Ok(&self.manifest)
+    fn get_compiled_module(&self) -> Result<&CompiledModule, WrapperError> {
+        if let Some(compiled_module) = self.compiled_module.get() {
+            return Ok(compiled_module);
+        }
+
+        let compiled_module = CompiledModule::new(&self.wasm_module)?;
+        Ok(self.compiled_module.get_or_init(|| compiled_module))
     }
 
     pub fn invoke_and_decode<T: DeserializeOwned>(
@@ -108,8 +128,8 @@ impl Wrapper for WasmWrapper {
         let abort_args = args.clone();
         let abort_env = env.clone();
 
-        let abort = Box::new(move |msg| {
-            panic!(
+        let abort_message = move |msg: String| {
+            format!(
                 r#"WasmWrapper: Wasm module aborted execution.
               URI: {uri}
               Method: {method}
@@ -122,26 +142,35 @@ impl Wrapper for WasmWrapper {
                 args = abort_args,
                 env = abort_env,
                 message = msg
-            );
-        });
+            )
+        };
 
-        let state = Arc::new(Mutex::new(State::new(invoker, abort.clone(), method, args, env)));
-        let mut wasm_instance = WasmInstance::new(&self.wasm_module, state.clone()).unwrap();
+        // Imports trap with the message, which `call_export` hands back as an error
+        let trap_message = abort_message.clone();
+        let abort = Box::new(move |msg| RuntimeError::new(trap_message(msg)));
 
+        let state = Arc::new(Mutex::new(State::new(invoker, abort, method, args, env)));
+        let mut wasm_instance = WasmInstance::new(&self.wasm_module, self.get_compiled_module()?, state.clone())?;
+
+        // Aborts already carry their framing, and other traps (interrupts,
+        // out-of-bounds accesses) aren't aborts, so messages pass through as-is
         let result = wasm_instance
             .call_export("_wrap_invoke", params)
-            .map_err(|e| Error::WrapperError(e.to_string()))?;
+            .map_err(|e| match e {
+                WrapperError::WasmRuntimeError(message) => Error::WrapperError(message),
+                e => e.into(),
+            })?;
 
         let state = state.lock().unwrap();
         if result {
             if state.invoke.result.is_none() {
-                abort("Invoke result is missing".to_string());
+                return Err(Error::WrapperError(abort_message("Invoke result is missing".to_string())));
             }
 
             Ok(state.invoke.result.as_ref().unwrap().to_vec())
         } else {
             if state.invoke.error.is_none() {
-                abort("Invoke error is missing".to_string());
+                return Err(Error::WrapperError(abort_message("Invoke error is missing".to_string())));
             }
 
             Err(Error::WrapperError(
@@ -172,4 +201,24 @@ impl Wrapper for WasmWrapper {
             )))
         }
     }
+
+    fn get_manifest(&self) -> Result<&WrapManifest, Error> {
+        Ok(&self.manifest)
+    }
+
+    fn get_content_hashes(&self) -> Result<Option<ContentHashes>, Error> {
+        if let Some(hashes) = self.content_hashes.get() {
+            return Ok(Some(hashes.clone()));
+        }
+
+        let manifest_sha256 = match &self.encoded_manifest {
+            Some(manifest) => sha256(manifest),
+            None => sha256(&self.file_reader.read_file("wrap.info")?),
+        };
+        let hashes = ContentHashes {
+            manifest_sha256: Some(manifest_sha256),
+            wasm_sha256: Some(sha256(&self.wasm_module)),
+        };
+        Ok(Some(self.content_hashes.get_or_init(|| hashes).clone()))
+    }
 }
@@ -7,7 +7,8 @@ use polywrap_core::{
     file_reader::{SimpleFileReader}, resolvers::uri_resolution_context::UriResolutionContext, wrapper::Wrapper, env::Env, interface_implementation::InterfaceImplementations
 };
 use wrap_manifest_schemas::{
-    deserialize::deserialize_wrap_manifest
+    deserialize::deserialize_wrap_manifest,
+    versions::{WrapManifest, WrapManifestAbi},
 };
 
 use polywrap_msgpack::msgpack;
@@ -121,3 +122,56 @@ fn invoke_test() {
     assert_eq!(result, [2])
 }
 
+fn empty_manifest() -> WrapManifest {
+    WrapManifest {
+        name: "runtime".to_string(),
+        type_: "wasm".to_string(),
+        version: "0.1".to_string(),
+        abi: serde_json::from_value::<WrapManifestAbi>(serde_json::json!({})).unwrap(),
+    }
+}
+
+#[test]
+fn invalid_module_returns_an_error() {
+    let manifest = empty_manifest();
+
+    let wrapper = WasmWrapper::new(b"not a wasm module".to_vec(), Arc::new(SimpleFileReader::new()), manifest);
+
+    let result = MockInvoker::new(wrapper).invoke_raw(
+        &Uri::try_from("ens/wrapper.eth").unwrap(),
+        "add",
+        Some(&msgpack!({ "a": 1, "b": 1})),
+        None,
+        None
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn abort_reports_the_invocation() {
+    let manifest = empty_manifest();
+
+    let module = wat::parse_str(r#"
+        (module
+          (import "wrap" "__wrap_abort" (func $abort (param i32 i32 i32 i32 i32 i32)))
+          (import "env" "memory" (memory 1))
+          (data (i32.const 0) "boom")
+          (data (i32.const 16) "index.ts")
+          (func (export "_wrap_invoke") (param i32 i32 i32) (result i32)
+            (call $abort (i32.const 0) (i32.const 4) (i32.const 16) (i32.const 8) (i32.const 3) (i32.const 5))
+            (i32.const 1)))
+    "#).unwrap();
+    let wrapper = WasmWrapper::new(module, Arc::new(SimpleFileReader::new()), manifest);
+
+    let error = MockInvoker::new(wrapper).invoke_raw(
+        &Uri::try_from("ens/wrapper.eth").unwrap(),
+        "add",
+        Some(&msgpack!({ "a": 1, "b": 1})),
+        None,
+        None
+    ).unwrap_err().to_string();
+    assert!(error.contains("__wrap_abort: boom"));
+    assert!(error.contains("File: index.ts"));
+    assert!(error.contains("URI: wrap://ens/wrapper.eth"));
+    assert!(error.contains("Method: add"));
+}